@@ -0,0 +1,65 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/diagnostics.dart';
+import '../models/net_info.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `event`, `observe`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EwmaBand`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<AnomalyDetector>>
+                abstract class AnomalyDetector implements RustOpaqueInterface {
+                     double get smoothing;
+
+
+ int get warmupReports;
+
+
+ double get zThreshold;
+
+
+  set smoothing(double smoothing);
+
+
+  set warmupReports(int warmupReports);
+
+
+  set zThreshold(double zThreshold);
+
+
+static Future<AnomalyDetector>  default_()=>RustLib.instance.api.crateApiAnalysisAnomalyAnomalyDetectorDefault();
+
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+/// Creates a detector with an empty baseline.
+static Future<AnomalyDetector>  newInstance({required double smoothing , required double zThreshold , required int warmupReports })=>RustLib.instance.api.crateApiAnalysisAnomalyAnomalyDetectorNew(smoothing: smoothing, zThreshold: zThreshold, warmupReports: warmupReports);
+
+
+/// Feeds one report and returns the anomalies it exhibits (at most one per metric).
+///
+/// Latency is only tracked for connected reports; loss is tracked always,
+/// so that an outage shows up as a loss anomaly.
+ Future<List<AnomalyDetected>>  observe({required NetworkReport report });
+
+
+/// Forgets the baseline, e.g. after the device switched networks.
+ Future<void>  reset();
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
@@ -0,0 +1,31 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/diagnostics.dart';
+import '../models/net_info.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `share`
+
+
+            /// Attributes the degradation of `end_to_end` to the first hop or the rest of
+/// the path, given `gateway` measured over the same connection.
+///
+/// Variance is compared as the square of the standard deviations
+/// ([jitter_ms](LatencyStats::jitter_ms)); all shares are capped at 1.0, as
+/// the first hop cannot account for more than the whole path.
+///
+/// The dominant segment is [PathSegment::Lan] if the first hop accounts for
+/// at least [ATTRIBUTION_LAN_SHARE](LibConstants::ATTRIBUTION_LAN_SHARE) of
+/// the end-to-end loss or variance, and [PathSegment::Isp] otherwise. Variance
+/// only counts once the end-to-end jitter reaches
+/// [ATTRIBUTION_MIN_JITTER_MS](LibConstants::ATTRIBUTION_MIN_JITTER_MS).
+Future<DegradationAttribution>  attributeDegradation({required LatencyStats gateway , required LatencyStats endToEnd }) => RustLib.instance.api.crateApiAnalysisAttributionAttributeDegradation(gateway: gateway, endToEnd: endToEnd);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,34 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/diagnostics.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `failure_share`, `is_degraded`, `latency_ms`, `median_latency`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Cell`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Builds a [LatencyHeatmap] of the target reports in `reports` (any order),
+/// with columns `bucket_ms` wide (0 uses
+/// [DEFAULT_HEATMAP_BUCKET_MS](LibConstants::DEFAULT_HEATMAP_BUCKET_MS)).
+/// Columns are widened as needed to keep their number below
+/// [MAX_HEATMAP_COLUMNS](LibConstants::MAX_HEATMAP_COLUMNS).
+///
+/// A cell is degraded if at least
+/// [HEATMAP_FAILURE_SHARE](LibConstants::HEATMAP_FAILURE_SHARE) of its probes
+/// failed, or if its latency exceeds the median of its row by
+/// [HEATMAP_LATENCY_FACTOR](LibConstants::HEATMAP_LATENCY_FACTOR) and by at
+/// least [HEATMAP_MIN_LATENCY_INCREASE_MS](LibConstants::HEATMAP_MIN_LATENCY_INCREASE_MS).
+/// A column is [DegradationScope::Global] if more than half of the targets
+/// probed in it degraded. Skipped targets are ignored.
+Future<LatencyHeatmap>  latencyHeatmap({required List<NetworkReport> reports , required BigInt bucketMs }) => RustLib.instance.api.crateApiAnalysisHeatmapLatencyHeatmap(reports: reports, bucketMs: bucketMs);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,34 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/config.dart';
+import '../models/net_info.dart';
+import '../models/report.dart';
+import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cascade`, `is_dns_error`, `verdict_for`
+
+
+            /// Builds the per-layer breakdown for a finished check cycle.
+///
+/// # Arguments
+///
+/// * `targets` - The configured targets, in the same order as `reports`.
+/// * `reports` - The per-target results of the check cycle.
+/// * `flags` - The security findings (used for the link and DNS layers).
+/// * `quality` - The final quality verdict (used for the application layer).
+///
+/// # Logic Flow
+///
+/// Every layer is first judged on its own evidence. Afterwards the verdicts are
+/// cascaded: once a layer fails, every layer above it that did not pass on its
+/// own is reported as [LayerStatus::Unknown].
+Future<LayeredDiagnosis>  diagnoseLayers({required List<NetworkTarget> targets , required List<TargetReport> reports , required SecurityFlagsResult flags , required ConnectionQuality quality }) => RustLib.instance.api.crateApiAnalysisLayersDiagnoseLayers(targets: targets, reports: reports, flags: flags, quality: quality);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/diagnostics.dart';
+import '../models/net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `is_better`
+
+
+            /// Compares the path of the active interface against the alternatives.
+///
+/// An alternative is better if the active path failed every handshake while
+/// it did not, if its loss is lower by more than
+/// [PATH_LOSS_MARGIN_PERCENT](LibConstants::PATH_LOSS_MARGIN_PERCENT), or if
+/// it is faster by more than
+/// [PATH_LATENCY_MARGIN_MS](LibConstants::PATH_LATENCY_MARGIN_MS) without
+/// losing more. The best such alternative is returned; `None` if there is
+/// none or the active interface was not measured.
+Future<BetterPathAvailable?>  betterPath({required String activeInterface , required List<PathMeasurement> paths , required BigInt timestampMs }) => RustLib.instance.api.crateApiAnalysisPathsBetterPath(activeInterface: activeInterface, paths: paths, timestampMs: timestampMs);
+
+            
+            
\ No newline at end of file
@@ -3,14 +3,18 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
 import '../../frb_generated.dart';
 import '../models/config.dart';
 import '../models/report.dart';
 import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `downgrade`, `fired_rules`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `QualityRule`, `RuleEffect`
 
-/// Categorizes a single latency measurement against configured thresholds.
+
+            /// Categorizes a single latency measurement against configured thresholds.
 ///
 /// This is a simple threshold-based classifier used for individual probes.
 ///
@@ -23,14 +27,7 @@ import '../models/target.dart';
 ///
 /// A [ConnectionQuality] variant. If latency exceeds the `poor` threshold,
 /// it returns [ConnectionQuality::Offline].
-Future<ConnectionQuality> evaluateQuality({
-  required final BigInt latency,
-  required final QualityThresholds threshold,
-}) =>
-    RustLib.instance.api.crateApiAnalysisQualityEvaluateQuality(
-      latency: latency,
-      threshold: threshold,
-    );
+Future<ConnectionQuality>  evaluateQuality({required BigInt latency , required QualityThresholds threshold }) => RustLib.instance.api.crateApiAnalysisQualityEvaluateQuality(latency: latency, threshold: threshold);
 
 /// Computes the final, consolidated network quality.
 ///
@@ -43,15 +40,17 @@ Future<ConnectionQuality> evaluateQuality({
 ///
 /// * If not connected, returns `Offline`.
 /// * If packet loss exceeds the critical threshold, returns `Unstable`.
-/// * If stability is low, the quality is downgraded by one or more levels.
+/// * If stability is low, the quality is downgraded by one level.
 /// * Otherwise, the quality is primarily determined by speed (latency).
-Future<ConnectionQuality> evaluateNetworkQuality({
-  required final bool isConnected,
-  required final LatencyStats stats,
-  required final NetworkConfiguration config,
-}) =>
-    RustLib.instance.api.crateApiAnalysisQualityEvaluateNetworkQuality(
-      isConnected: isConnected,
-      stats: stats,
-      config: config,
-    );
+Future<ConnectionQuality>  evaluateNetworkQuality({required bool isConnected , required LatencyStats stats , required NetworkConfiguration config }) => RustLib.instance.api.crateApiAnalysisQualityEvaluateNetworkQuality(isConnected: isConnected, stats: stats, config: config);
+
+/// Lists the factors behind the result of [evaluate_network_quality].
+///
+/// Both come from the same rule table: an offline check only yields
+/// [QualityReason::NoTargetsReachable]; otherwise packet loss, a low stability
+/// score and a latency above the `good` threshold are reported in that order.
+/// [QualityReason::CaptivePortal] is added by the engine after the portal probe.
+Future<List<QualityReason>>  qualityReasons({required bool isConnected , required LatencyStats stats , required NetworkConfiguration config }) => RustLib.instance.api.crateApiAnalysisQualityQualityReasons(isConnected: isConnected, stats: stats, config: config);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,26 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/config.dart';
+import '../models/diagnostics.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            /// Compares the access points of two consecutive reports.
+///
+/// Returns an event if both carry a BSSID (see
+/// [PlatformHints::bssid](crate::api::models::PlatformHints::bssid)) and they
+/// differ. The handover counts as a latency spike if the current check found
+/// spikes, if its latency exceeds the previous one by more than
+/// [spike_ratio_threshold](ResilienceConfig::spike_ratio_threshold) (unless
+/// that is 0), or if the device lost its connection in it.
+Future<RoamEvent?>  detectRoam({required NetworkReport previous , required NetworkReport current , required ResilienceConfig resilience }) => RustLib.instance.api.crateApiAnalysisRoamingDetectRoam(previous: previous, current: current, resilience: resilience);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,77 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/config.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `lerp`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+
+
+            /// Stepped scorer: maps a latency value to a 0-100 score based on user thresholds.
+Future<double>  scoreLatency({required double ms , required QualityThresholds thresholds }) => RustLib.instance.api.crateApiAnalysisStabilityScoreLatency(ms: ms, thresholds: thresholds);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<StabilityInputs < 'static>>>
+                abstract class StabilityInputs implements RustOpaqueInterface {
+                    
+
+                    
+                }
+                
+
+
+                abstract class StabilityModel {
+                    /// Scores a check that had at least one successful sample.
+ Future<int>  score({required StabilityInputs inputs });
+
+
+                }
+                
+
+/// The built-in weighted composite model, tuned for modern mobile (4G/5G)
+/// and WiFi networks.
+///
+/// Each component is first mapped to a 0-100 sub-score, then combined with
+/// the weights below. Checks losing more than half of their samples are
+/// scaled down further, as the network is effectively dying.
+class WeightedStabilityModel  {
+                /// Weight of the P95 latency sub-score; penalizes "tail latency".
+final double p95Weight;
+/// Weight of the packet loss sub-score; penalizes unreliability.
+final double lossWeight;
+/// Weight of the mean latency sub-score; baseline speed.
+final double meanWeight;
+/// Weight of the IQR jitter sub-score; arrival consistency.
+final double jitterWeight;
+
+                const WeightedStabilityModel({required this.p95Weight ,required this.lossWeight ,required this.meanWeight ,required this.jitterWeight ,});
+
+                /// P95 35%, loss 30%, mean 20%, jitter 15%.
+static Future<WeightedStabilityModel>  default_()=>RustLib.instance.api.crateApiAnalysisStabilityWeightedStabilityModelDefault();
+
+
+ Future<int>  score({required StabilityInputs inputs })=>RustLib.instance.api.crateApiAnalysisStabilityWeightedStabilityModelScore(that: this, inputs: inputs);
+
+
+                
+
+                
+        @override
+        int get hashCode => p95Weight.hashCode^lossWeight.hashCode^meanWeight.hashCode^jitterWeight.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WeightedStabilityModel &&
+                runtimeType == other.runtimeType
+                && p95Weight == other.p95Weight&& lossWeight == other.lossWeight&& meanWeight == other.meanWeight&& jitterWeight == other.jitterWeight;
+        
+            }
+            
\ No newline at end of file
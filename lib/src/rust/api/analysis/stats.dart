@@ -3,31 +3,30 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
 import '../../frb_generated.dart';
 import '../models/config.dart';
 import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
 
-// These functions are ignored because they are not marked as `pub`: `lerp`
+            // These functions are ignored (category: IgnoreBecauseExplicitAttribute): `apply_spike_detection`, `compute_latency_stats_with_model`
 
-/// Calculates basic statistical metrics for a set of latency samples.
+
+            /// Calculates basic statistical metrics for a set of latency samples.
 ///
 /// Useful for quick analysis of a probe cycle.
 ///
 /// # Returns
 ///
 /// A tuple: `(Min, Max, Mean, StandardDeviation)`.
-Future<(BigInt?, BigInt?, BigInt?, double?)> calculateJitterStats({
-  required final Uint64List latencies,
-}) =>
-    RustLib.instance.api
-        .crateApiAnalysisStatsCalculateJitterStats(latencies: latencies);
+Future<(BigInt?,BigInt?,BigInt?,double?)>  calculateJitterStats({required Uint64List latencies }) => RustLib.instance.api.crateApiAnalysisStatsCalculateJitterStats(latencies: latencies);
 
 /// Computes a comprehensive stability report and health score.
 ///
-/// The scoring system is specifically tuned for modern mobile (4G/5G) and
-/// WiFi networks. It uses a weighted composite model:
+/// The stability score comes from the default
+/// [WeightedStabilityModel](super::stability::WeightedStabilityModel); to
+/// score with another [StabilityModel](super::stability::StabilityModel), see
+/// [compute_latency_stats_with_model]:
 ///
 /// ### Scoring Weights:
 /// * **P95 Latency (35%):** Penalizes "tail latency" (occasional slow packets).
@@ -39,13 +38,14 @@ Future<(BigInt?, BigInt?, BigInt?, double?)> calculateJitterStats({
 /// * `latencies`: Successful probe results.
 /// * `total_expected_samples`: Used to calculate packet loss.
 /// * `thresholds`: User-defined latency boundaries.
-Future<LatencyStats> computeLatencyStats({
-  required final Uint64List latencies,
-  required final int totalExpectedSamples,
-  required final QualityThresholds thresholds,
-}) =>
-    RustLib.instance.api.crateApiAnalysisStatsComputeLatencyStats(
-      latencies: latencies,
-      totalExpectedSamples: totalExpectedSamples,
-      thresholds: thresholds,
-    );
+Future<LatencyStats>  computeLatencyStats({required Uint64List latencies , required int totalExpectedSamples , required QualityThresholds thresholds }) => RustLib.instance.api.crateApiAnalysisStatsComputeLatencyStats(latencies: latencies, totalExpectedSamples: totalExpectedSamples, thresholds: thresholds);
+
+/// Finds samples that exceed the median latency by more than `ratio_threshold`.
+///
+/// Returns their positions in `latencies` (collection order). A threshold of
+/// 0 or less disables detection. Fewer than 3 samples never yield spikes, as
+/// there is no meaningful baseline to compare against.
+Future<Uint32List>  detectLatencySpikes({required Uint64List latencies , required double ratioThreshold }) => RustLib.instance.api.crateApiAnalysisStatsDetectLatencySpikes(latencies: latencies, ratioThreshold: ratioThreshold);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/diagnostics.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `add_aggregate`, `add`, `avg_latency_ms`, `bucket`, `congestion_windows`, `degraded_share`, `is_congested`, `is_degraded`, `local_time`, `window`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Accumulator`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Builds a [TimeOfDayProfile] from `reports` (any order).
+///
+/// `utc_offset_minutes` shifts the report timestamps into the user's local
+/// time (e.g. `120` for UTC+2); Flutter apps can pass
+/// `DateTime.now().timeZoneOffset.inMinutes`.
+///
+/// An hour is part of a congestion window if it was observed on at least
+/// [MIN_CONGESTION_DAYS](LibConstants::MIN_CONGESTION_DAYS) distinct days and
+/// either its mean latency exceeds the overall mean by
+/// [CONGESTION_LATENCY_FACTOR](LibConstants::CONGESTION_LATENCY_FACTOR) or at
+/// least [CONGESTION_DEGRADED_SHARE](LibConstants::CONGESTION_DEGRADED_SHARE)
+/// of its reports were degraded. Adjacent hours are merged, wrapping past midnight.
+Future<TimeOfDayProfile>  profileTimeOfDay({required List<NetworkReport> reports , required int utcOffsetMinutes }) => RustLib.instance.api.crateApiAnalysisTimeOfDayProfileTimeOfDay(reports: reports, utcOffsetMinutes: utcOffsetMinutes);
+
+/// Like [profile_time_of_day], but also counts the reports summarized by
+/// `aggregates`, e.g. those of
+/// [ReachabilityEngine::history_aggregates](crate::api::engine::ReachabilityEngine::history_aggregates).
+/// An aggregate is placed at the middle of its period.
+Future<TimeOfDayProfile>  profileTimeOfDayWithAggregates({required List<NetworkReport> reports , required List<HistoryAggregate> aggregates , required int utcOffsetMinutes }) => RustLib.instance.api.crateApiAnalysisTimeOfDayProfileTimeOfDayWithAggregates(reports: reports, aggregates: aggregates, utcOffsetMinutes: utcOffsetMinutes);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,31 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/diagnostics.dart';
+import '../models/net_info.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            
+
+            /// Compares a baseline report against a report captured after a VPN toggle.
+///
+/// # Arguments
+///
+/// * `before` / `after` - The reports captured on either side of the toggle.
+/// * `resolvers_before` / `resolvers_after` - The system DNS resolvers observed
+///   alongside each report (see [crate::api::probes::system_dns_resolvers]).
+///
+/// # Leak Heuristics
+///
+/// Leaks are only evaluated for the side of the toggle where the VPN is active:
+/// * **DNS leak:** the resolver set is identical to the one used without the VPN.
+/// * **Route leak:** the active interface is not recognised as a tunnel.
+Future<VpnComparison>  compareVpnReports({required NetworkReport before , required NetworkReport after , required List<String> resolversBefore , required List<String> resolversAfter }) => RustLib.instance.api.crateApiAnalysisVpnCompareVpnReports(before: before, after: after, resolversBefore: resolversBefore, resolversAfter: resolversAfter);
+
+            
+            
\ No newline at end of file
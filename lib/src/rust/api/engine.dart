@@ -3,22 +3,62 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
 import '../frb_generated.dart';
 import 'models/config.dart';
+import 'models/error.dart';
 import 'models/report.dart';
 import 'models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_attribution`, `apply_captive_portal_check`, `apply_family_comparison`, `apply_isp_status_check`, `apply_multicast_check`, `apply_path_comparison`, `attribution_gateway`, `finish_timing`, `has_any_route`, `refresh_layers`, `run_base_check`, `run_check`, `run_optional_probes`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `check_network_cancellable`, `check_network_with_limiter`
+
 
-/// The main entry point for running a comprehensive network check.
+            /// The main entry point for running a comprehensive network check.
 ///
 /// This function orchestrates the entire check process:
-/// 1. Collects latency samples via [sampler::collect_network_samples].
-/// 2. Computes statistics via [analysis::compute_latency_stats].
-/// 3. Evaluates quality via [analysis::evaluate_network_quality].
-/// 4. Detects interface security and type.
-/// 5. Compiles a final [NetworkReport].
-Future<NetworkReport> checkNetwork({
-  required final NetworkConfiguration config,
-}) =>
-    RustLib.instance.api.crateApiEngineCheckNetwork(config: config);
+/// 1. Collects latency samples in `sampler::run_samples`. If
+///    [NetworkConfiguration::attribute_degradation] is set, the default
+///    gateway is sampled in the same rounds.
+/// 2. Computes statistics via [compute_latency_stats_with_model] and flags
+///    latency spikes via [apply_spike_detection]. Checks run here are scored
+///    with the default [WeightedStabilityModel]; those of a
+///    [ReachabilityEngine] with [its own](ReachabilityEngine::with_stability_model).
+/// 3. Evaluates quality via [evaluate_network_quality].
+/// 4. Detects interface security and type, overridden by any
+///    [PlatformHints](crate::api::models::PlatformHints) the host supplied.
+/// 5. Checks for captive portals and DNS hijacking, and, if only the LAN is
+///    reachable, asks the configured ISP status endpoints about an outage.
+///    If [NetworkConfiguration::compare_address_families] is set, compares
+///    IPv4 and IPv6 towards the dual-stack targets; if
+///    [NetworkConfiguration::check_multicast] is set, checks LAN multicast; if
+///    [NetworkConfiguration::compare_paths] is set, compares the interfaces; if
+///    [NetworkConfiguration::attribute_degradation] is set, attributes the
+///    degradation from the gateway samples.
+/// 6. Builds a per-layer breakdown via [diagnose_layers].
+/// 7. Determines the [NetworkScope]; if no target answered, the gateway and
+///    LAN targets are probed via [local::check_local_network].
+/// 8. Compiles a final [NetworkReport].
+///
+/// All probe traffic of the check shares one [rate_limit::RateLimiter] built from
+/// [NetworkConfiguration::rate_limit]. To share one across checks, see
+/// [check_network_with_limiter].
+///
+/// For conditional execution of the heavier probes, see [pipeline::CheckPipeline].
+/// For caching, history and a circuit breaker, see [ReachabilityEngine].
+/// To abort a check early, see [check_network_cancellable].
+Future<NetworkReport>  checkNetwork({required NetworkConfiguration config }) => RustLib.instance.api.crateApiEngineCheckNetwork(config: config);
+
+/// [check_network], but fails with [NetworkError::InvalidConfiguration]
+/// instead of checking if `config` does not pass
+/// [validate](NetworkConfiguration::validate).
+Future<NetworkReport>  tryCheckNetwork({required NetworkConfiguration config }) => RustLib.instance.api.crateApiEngineTryCheckNetwork(config: config);
+
+/// Runs a check with the default configuration and sums it up as a traffic
+/// light. See [NetworkReport::simple_status] for the mapping; to use another
+/// configuration, call that on the result of [check_network].
+Future<SimpleStatus>  simpleStatus() => RustLib.instance.api.crateApiEngineSimpleStatus();
+
+            
+            
\ No newline at end of file
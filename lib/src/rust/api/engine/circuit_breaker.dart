@@ -0,0 +1,63 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/config.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `opened_ago`, `record_at`, `restore`, `state_at`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `retry_after`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CircuitBreaker>>
+                abstract class CircuitBreaker implements RustOpaqueInterface {
+                    /// Number of consecutive checks in which an essential target failed.
+ Future<int>  consecutiveFailures();
+
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+/// Creates a closed breaker from the resilience settings.
+///
+/// A `circuit_breaker_threshold` of 0 yields a breaker that never opens.
+static Future<CircuitBreaker>  newInstance({required ResilienceConfig config })=>RustLib.instance.api.crateApiEngineCircuitBreakerCircuitBreakerNew(config: config);
+
+
+/// Feeds the result of a finished check into the breaker.
+///
+/// Any check in which every essential target succeeded closes the breaker.
+ Future<void>  record({required NetworkReport report });
+
+
+/// The current state, taking the cooldown into account.
+ Future<CircuitBreakerState>  state();
+
+
+
+                    
+                }
+                
+
+/// The operational state of a circuit breaker.
+enum CircuitBreakerState {
+                    /// Checks run normally.
+closed,
+/// Essential targets failed repeatedly; checks are suppressed until the
+/// cooldown expires.
+open,
+/// The cooldown expired; the next check decides whether to close or reopen.
+halfOpen,
+                    ;
+                    /// The localization key, e.g. `circuit_breaker.half_open`.
+ String  l10NKey()=>RustLib.instance.api.crateApiEngineCircuitBreakerCircuitBreakerStateL10NKey(that: this, );
+
+
+                }
+            
\ No newline at end of file
@@ -0,0 +1,24 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `epoch`
+
+
+            /// Milliseconds on a process-local monotonic clock.
+///
+/// Only differences between two readings are meaningful; the origin is the
+/// first time the engine looked at the clock. Unaffected by changes to the
+/// system time.
+Future<BigInt>  monotonicMs() => RustLib.instance.api.crateApiEngineClockMonotonicMs();
+
+/// The current UTC wall-clock time in milliseconds since the Unix epoch.
+Future<BigInt>  wallClockMs() => RustLib.instance.api.crateApiEngineClockWallClockMs();
+
+            
+            
\ No newline at end of file
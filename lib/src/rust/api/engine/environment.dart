@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/diagnostics.dart';
+import '../models/net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `address_families`, `limitations`
+
+
+            /// Reports the platform, the available probe modes and address families, and
+/// the enabled features, along with the limitations they imply.
+///
+/// Cheap enough to call at any time: it opens a few sockets and lists the
+/// interfaces, but sends no traffic.
+Future<EnvironmentReport>  environmentReport() => RustLib.instance.api.crateApiEngineEnvironmentEnvironmentReport();
+
+            
+            
\ No newline at end of file
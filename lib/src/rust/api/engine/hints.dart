@@ -0,0 +1,28 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_platform_hints`, `store`, `update_platform_hints`
+
+
+            /// Installs the hints applied to every subsequent report.
+///
+/// Call this whenever the platform reports a network change (e.g. from
+/// `ConnectivityManager.NetworkCallback` or `NWPathMonitor`). Replaces any
+/// previously installed hints.
+void  setPlatformHints({required PlatformHints hints }) => RustLib.instance.api.crateApiEngineHintsSetPlatformHints(hints: hints);
+
+/// Removes the installed hints, reverting to the engine's own detection.
+void  clearPlatformHints() => RustLib.instance.api.crateApiEngineHintsClearPlatformHints();
+
+/// The currently installed hints, if any.
+PlatformHints?  platformHints() => RustLib.instance.api.crateApiEngineHintsPlatformHints();
+
+            
+            
\ No newline at end of file
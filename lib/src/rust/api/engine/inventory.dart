@@ -0,0 +1,45 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/scan.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `devices`
+
+
+            /// Computes the events that turn `previous` into `current`.
+Future<List<DeviceEvent>>  diffDevices({required List<LocalDevice> previous , required List<LocalDevice> current }) => RustLib.instance.api.crateApiEngineInventoryDiffDevices(previous: previous, current: current);
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<DeviceInventory>>
+                abstract class DeviceInventory implements RustOpaqueInterface {
+                    static Future<DeviceInventory>  default_()=>RustLib.instance.api.crateApiEngineInventoryDeviceInventoryDefault();
+
+
+/// Restores an inventory from previously persisted [devices](Self::devices).
+static Future<DeviceInventory>  fromDevices({required List<LocalDevice> devices })=>RustLib.instance.api.crateApiEngineInventoryDeviceInventoryFromDevices(devices: devices);
+
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+/// Creates an empty inventory; the first update reports every device as joined.
+static Future<DeviceInventory>  newInstance()=>RustLib.instance.api.crateApiEngineInventoryDeviceInventoryNew();
+
+
+/// Replaces the stored scan with `scan` and returns what changed.
+///
+/// Devices are matched by MAC address where both scans know it, and by IP
+/// address where either does not. Events are ordered: IP changes, then joins, then
+/// departures.
+ Future<List<DeviceEvent>>  update({required List<LocalDevice> scan });
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
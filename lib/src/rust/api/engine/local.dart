@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/config.dart';
+import '../models/net_info.dart';
+import '../models/report.dart';
+import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `run_local_check`
+
+
+            /// Checks LAN reachability only: the default gateway plus every LAN target.
+///
+/// The scope is [NetworkScope::LocalOnly] if any of them answers and
+/// [NetworkScope::None] otherwise. Use [check_network](super::check_network)
+/// to find out whether the scope extends to [NetworkScope::Internet].
+Future<LocalReachabilityReport>  checkLocalNetwork({required NetworkConfiguration config }) => RustLib.instance.api.crateApiEngineLocalCheckLocalNetwork(config: config);
+
+            
+            
\ No newline at end of file
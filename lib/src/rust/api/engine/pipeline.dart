@@ -0,0 +1,168 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/config.dart';
+import '../models/report.dart';
+import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'pipeline.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `quality_rank`, `run_step`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseType): `run_with_limiter`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<CheckPipeline>>
+                abstract class CheckPipeline implements RustOpaqueInterface {
+                    static Future<CheckPipeline>  default_()=>RustLib.instance.api.crateApiEnginePipelineCheckPipelineDefault();
+
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+/// Creates an empty pipeline that only runs the base check.
+static Future<CheckPipeline>  newInstance()=>RustLib.instance.api.crateApiEnginePipelineCheckPipelineNew();
+
+
+/// Runs the base check followed by every declared step whose condition holds.
+///
+/// All steps share the rate limit budget of the base check.
+ Future<PipelineReport>  run({required NetworkConfiguration config });
+
+
+/// A captive portal probe followed by the DNS hijack check while connected,
+/// mirroring the probes run by [check_network](super::check_network).
+static Future<CheckPipeline>  standard()=>RustLib.instance.api.crateApiEnginePipelineCheckPipelineStandard();
+
+
+/// Appends a step that always runs.
+ Future<CheckPipeline>  step({required PipelineStep step });
+
+
+/// Appends a step that only runs when `condition` is met.
+ Future<CheckPipeline>  stepIf({required PipelineStep step , required StepCondition condition });
+
+
+
+                    
+                }
+                
+
+
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<PipelineReport>>
+                abstract class PipelineReport implements RustOpaqueInterface {
+                     NetworkReport get report;
+
+
+ List<StepOutcome> get steps;
+
+
+  set report(NetworkReport report);
+
+
+  set steps(List<StepOutcome> steps);
+
+
+
+                    
+                }
+                
+
+/// An optional probe that a [CheckPipeline] can run after the base check.
+enum PipelineStep {
+                    /// Probes for a captive portal and downgrades the quality if one is found.
+captivePortal,
+/// Compares system DNS against a trusted resolver to detect hijacking.
+///
+/// Runs regardless of [SecurityConfig::detect_dns_hijack](crate::api::models::SecurityConfig::detect_dns_hijack)
+/// when declared in a pipeline.
+dnsSecurity,
+/// Asks the [isp_status_urls](NetworkConfiguration::isp_status_urls)
+/// whether the ISP has an outage. Only does anything if the gateway
+/// answered but no internet target did.
+ispStatus,
+/// Probes the dual-stack targets over IPv4 and IPv6 separately into
+/// [NetworkReport::family_comparison]. Only does anything while connected.
+addressFamilies,
+/// Checks LAN multicast via the mDNS group into
+/// [NetworkReport::multicast_ok].
+multicast,
+/// Measures every Wi-Fi, cellular and Ethernet interface separately into
+/// [NetworkReport::better_path].
+paths,
+/// Attributes the degradation to the first hop or the rest of the path
+/// into [NetworkReport::attribution]. Declaring this step samples the
+/// gateway alongside the base check, whether or not its condition later
+/// holds. Only does anything while connected.
+attribution,
+/// Traces the route to the first configured target into
+/// [NetworkReport::traceroute], e.g. only while
+/// [StepCondition::QualityBelow] `Good`.
+traceroute,
+                    ;
+                    
+                }
+
+@freezed
+                sealed class StepCondition with _$StepCondition  {
+                    const StepCondition._();
+
+                     /// The step always runs.
+const factory StepCondition.always() = StepCondition_Always;
+ /// Runs only if the base check found the network connected.
+const factory StepCondition.connected() = StepCondition_Connected;
+ /// Runs only if the base check found the network offline.
+const factory StepCondition.offline() = StepCondition_Offline;
+ /// Runs only if the current quality is strictly worse than the given one.
+const factory StepCondition.qualityBelow(  ConnectionQuality field0,) = StepCondition_QualityBelow;
+ /// Runs only if the current quality is the given one or better.
+const factory StepCondition.qualityAtLeast(  ConnectionQuality field0,) = StepCondition_QualityAtLeast;
+ /// Runs only if an earlier step detected a captive portal.
+const factory StepCondition.captivePortalDetected() = StepCondition_CaptivePortalDetected;
+ /// Runs only if no captive portal has been detected (so far).
+const factory StepCondition.captivePortalNotDetected() = StepCondition_CaptivePortalNotDetected;
+ /// Runs only if a VPN interface is active.
+const factory StepCondition.vpnDetected() = StepCondition_VpnDetected;
+
+                    
+
+                    /// Evaluates the condition against the current state of a report.
+ Future<bool>  isMet({required NetworkReport report })=>RustLib.instance.api.crateApiEnginePipelineStepConditionIsMet(that: this, report: report);
+
+
+                }
+
+/// Records whether a declared step ran or was skipped by its condition.
+class StepOutcome  {
+                /// The declared step.
+final PipelineStep step;
+/// The condition that guarded the step.
+final StepCondition condition;
+/// True if the condition was met and the step ran.
+final bool executed;
+
+                const StepOutcome({required this.step ,required this.condition ,required this.executed ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => step.hashCode^condition.hashCode^executed.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is StepOutcome &&
+                runtimeType == other.runtimeType
+                && step == other.step&& condition == other.condition&& executed == other.executed;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,39 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/config.dart';
+import '../models/diagnostics.dart';
+import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `check_udp`, `check_websocket`, `evaluate`, `measure_download_mbps`, `measure_latency`, `optional`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Measurements`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Checks the connection against `requirements`.
+///
+/// Only the probes needed for the set requirements run, all at once; an empty
+/// profile passes immediately without any traffic.
+///
+/// ```ignore
+/// let report = preflight(Requirements {
+///     max_latency_ms: Some(150),
+///     max_loss_percent: Some(2.0),
+///     needs_udp: true,
+///     ..Default::default()
+/// })
+/// .await;
+/// if !report.passed {
+///     warn_user(&report.unmet);
+/// }
+/// ```
+Future<PreflightReport>  preflight({required Requirements requirements }) => RustLib.instance.api.crateApiEnginePreflightPreflight(requirements: requirements);
+
+            
+            
\ No newline at end of file
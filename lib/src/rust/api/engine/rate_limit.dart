@@ -0,0 +1,21 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `new`, `refill`, `reserve`, `reserve`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Buckets`, `RateLimiter`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `acquire`, `default`, `new`, `unlimited`
+
+
+            /// Estimates the on-the-wire size (both directions) of a single probe.
+Future<BigInt>  estimatedProbeBytes({required TargetProtocol protocol }) => RustLib.instance.api.crateApiEngineRateLimitEstimatedProbeBytes(protocol: protocol);
+
+            
+            
\ No newline at end of file
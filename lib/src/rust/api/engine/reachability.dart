@@ -0,0 +1,153 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/config.dart';
+import '../models/diagnostics.dart';
+import '../models/net_info.dart';
+import '../models/report.dart';
+import '../models/target.dart';
+import 'circuit_breaker.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `initial_health`, `push_app_signal`, `record`, `reusable_report`, `run`, `state`, `update_health`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `EngineState`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `cancellation_token`, `config`, `with_rate_limiter`, `with_runtime`, `with_stability_model`, `with_transport`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<ReachabilityEngine>>
+                abstract class ReachabilityEngine implements RustOpaqueInterface {
+                    /// Returns a report, reusing the cached one when possible.
+///
+/// # Logic Flow
+///
+/// 1. While the circuit breaker is open, the last report is returned
+///    without any network I/O.
+/// 2. A report younger than `cache_validity_ms` is returned as-is, unless
+///    the app's own requests contradict it (see [Self::report_app_failure]).
+///    A report confirmed by app successes stays valid for up to
+///    `passive_signal_window_ms`.
+/// 3. Otherwise a new check runs. Concurrent callers wait for it and
+///    share its result instead of starting checks of their own.
+ Future<NetworkReport>  check();
+
+
+/// The current state of this engine's circuit breaker.
+ Future<CircuitBreakerState>  circuitState();
+
+
+/// The retained full reports (at most the last hour), oldest first.
+ Future<List<NetworkReport>>  history();
+
+
+/// Hourly and per-minute summaries of the reports that have aged out of
+/// [Self::history], oldest first. Hours come first, then the minutes of
+/// the last day.
+ Future<List<HistoryAggregate>>  historyAggregates();
+
+
+/// True once [Self::shutdown] was called.
+ Future<bool>  isShutDown();
+
+
+/// The error of the most recent app-reported request, if it failed.
+ Future<String?>  lastAppError();
+
+
+/// The most recent report, if any check has completed.
+ Future<NetworkReport?>  lastReport();
+
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+/// Creates an engine keeping the last
+/// [DEFAULT_HISTORY_CAPACITY](LibConstants::DEFAULT_HISTORY_CAPACITY) reports.
+static Future<ReachabilityEngine>  newInstance({required NetworkConfiguration config })=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineNew(config: config);
+
+
+/// Forces a new check, ignoring the cache and the circuit breaker.
+ Future<NetworkReport>  refresh();
+
+
+/// Tells the engine that a request made by the app itself failed.
+///
+/// `error` is a description for diagnostics; only network failures should
+/// be reported, not HTTP error statuses. After
+/// [passive_failure_threshold](crate::api::models::ResilienceConfig::passive_failure_threshold)
+/// consecutive failures a cached "connected" report is no longer reused.
+ Future<void>  reportAppFailure({required String error });
+
+
+/// Tells the engine that a request made by the app itself succeeded.
+///
+/// Successes let a cached "connected" report be reused for longer and make
+/// a cached "offline" report stale, so the next [Self::check] re-probes.
+ Future<void>  reportAppSuccess();
+
+
+/// Drops the cached report, the history, the anomaly baseline, the target
+/// health and the circuit breaker state.
+ Future<void>  reset();
+
+
+/// Cancels the check under way and waits up to `timeout_ms` (0 uses
+/// [DEFAULT_SHUTDOWN_TIMEOUT_MS](LibConstants::DEFAULT_SHUTDOWN_TIMEOUT_MS))
+/// for it to wind down. Returns false if it did not finish in time.
+///
+/// The cancelled check is returned to its callers flagged as
+/// [cancelled](NetworkReport::cancelled) and is not recorded. Later calls to
+/// [Self::check] still return a cached report, but no longer probe the
+/// network. Name lookups already handed to the OS cannot be interrupted;
+/// they finish on their own within their timeouts.
+ Future<bool>  shutdown({required BigInt timeoutMs });
+
+
+/// Captures the last report, the circuit breaker and the per-target
+/// health, to be persisted and passed to [Self::with_snapshot] later.
+ Future<EngineSnapshot>  snapshot();
+
+
+/// Returns and clears the latency/loss anomalies seen since the last call,
+/// oldest first. See [AnomalyDetector].
+ Future<List<AnomalyDetected>>  takeAnomalies();
+
+
+/// How each configured target fared over the checks so far.
+ Future<List<TargetHealth>>  targetHealth();
+
+
+/// Buckets the retained history, [Self::history_aggregates] included, by
+/// local hour and weekday. See
+/// [profile_time_of_day](crate::api::analysis::time_of_day::profile_time_of_day).
+ Future<TimeOfDayProfile>  timeOfDayProfile({required int utcOffsetMinutes });
+
+
+/// Creates an engine keeping at most `history_capacity` full reports.
+///
+/// Reports older than an hour, or beyond the capacity, are folded into
+/// [Self::history_aggregates] instead of being dropped. A capacity of 0
+/// disables the history.
+static Future<ReachabilityEngine>  withHistoryCapacity({required NetworkConfiguration config , required BigInt historyCapacity })=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineWithHistoryCapacity(config: config, historyCapacity: historyCapacity);
+
+
+/// Restores the state saved by [Self::snapshot], typically by a previous
+/// run of the app.
+///
+/// The restored report is returned by [Self::last_report] right away and,
+/// while younger than `cache_validity_ms` (or while the restored circuit
+/// breaker is open), by [Self::check] too. Health entries of targets that
+/// are no longer configured are dropped.
+ Future<ReachabilityEngine>  withSnapshot({required EngineSnapshot snapshot });
+
+
+
+                    
+                }
+                
+            
\ No newline at end of file
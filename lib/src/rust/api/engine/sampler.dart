@@ -3,26 +3,69 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
 import '../../frb_generated.dart';
 import '../models/config.dart';
+import '../models/net_info.dart';
 import '../models/report.dart';
 import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `fallback_tiers`, `pre_resolve`, `run_round`, `run_sample`, `run_samples`, `sample_record`, `skipped_report`
+// These functions are ignored because they have generic arguments: `select_winner`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `PreResolved`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+// These functions are ignored (category: IgnoreBecauseType): `collect_network_samples_limited`
+
+
+            /// Collects multiple latency samples by running checks against all configured targets.
+///
+/// Probe traffic is throttled according to [NetworkConfiguration::rate_limit].
+Future<(Uint64List,List<TargetReport>)>  collectNetworkSamples({required NetworkConfiguration config }) => RustLib.instance.api.crateApiEngineSamplerCollectNetworkSamples(config: config);
 
-/// Collects multiple latency samples by running checks against all configured targets.
-Future<(Uint64List, List<TargetReport>)> collectNetworkSamples({
-  required final NetworkConfiguration config,
-}) =>
-    RustLib.instance.api
-        .crateApiEngineSamplerCollectNetworkSamples(config: config);
+/// Picks the overall winner of a check: the target that won the most samples.
+///
+/// Ties go to the target that won most recently.
+Future<String?>  overallWinner({required List<String?> winnersPerSample }) => RustLib.instance.api.crateApiEngineSamplerOverallWinner(winnersPerSample: winnersPerSample);
 
 /// Analyzes the results of a single sample run across all targets.
-Future<BigInt?> analyzeSingleSample({
-  required final List<TargetReport> reports,
-  required final NetworkConfiguration config,
-}) =>
-    RustLib.instance.api.crateApiEngineSamplerAnalyzeSingleSample(
-      reports: reports,
-      config: config,
-    );
+Future<BigInt?>  analyzeSingleSample({required List<TargetReport> reports , required NetworkConfiguration config }) => RustLib.instance.api.crateApiEngineSamplerAnalyzeSingleSample(reports: reports, config: config);
+
+            /// Everything a check derives from its sampling rounds.
+class SampleRun  {
+                /// Best latency of every counted sample.
+final Uint64List latencies;
+/// The target reports of the last sample.
+final List<TargetReport> finalReports;
+/// The winner of every sample; `None` where the sample did not count.
+final List<String?> winners;
+/// Per-sample records; empty unless raw samples were requested.
+final List<SampleRecord> records;
+/// True if sampling was cancelled; only completed samples are included.
+final bool cancelled;
+/// The gateway's latency in every completed sample it answered; empty
+/// unless a gateway was sampled.
+final Uint64List gatewayLatencies;
+
+                const SampleRun({required this.latencies ,required this.finalReports ,required this.winners ,required this.records ,required this.cancelled ,required this.gatewayLatencies ,});
+
+                static Future<SampleRun>  default_()=>RustLib.instance.api.crateApiEngineSamplerSampleRunDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => latencies.hashCode^finalReports.hashCode^winners.hashCode^records.hashCode^cancelled.hashCode^gatewayLatencies.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SampleRun &&
+                runtimeType == other.runtimeType
+                && latencies == other.latencies&& finalReports == other.finalReports&& winners == other.winners&& records == other.records&& cancelled == other.cancelled&& gatewayLatencies == other.gatewayLatencies;
+        
+            }
+            
\ No newline at end of file
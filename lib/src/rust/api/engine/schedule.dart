@@ -0,0 +1,29 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/config.dart';
+import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `jittered_interval_with`
+
+
+            /// Returns the delay (ms) to wait before the next periodic check.
+///
+/// Applies [NetworkConfiguration::check_interval_jitter_percent] to
+/// [NetworkConfiguration::check_interval_ms]. Returns 0 if periodic checks are
+/// disabled.
+Future<BigInt>  nextCheckDelayMs({required NetworkConfiguration config }) => RustLib.instance.api.crateApiEngineScheduleNextCheckDelayMs(config: config);
+
+/// Spreads `interval_ms` uniformly over `interval_ms ± jitter_percent%`.
+///
+/// An interval of 0 is returned unchanged so that "disabled" stays disabled;
+/// any other interval jitters to at least 1 ms.
+Future<BigInt>  jitteredIntervalMs({required BigInt intervalMs , required int jitterPercent }) => RustLib.instance.api.crateApiEngineScheduleJitteredIntervalMs(intervalMs: intervalMs, jitterPercent: jitterPercent);
+
+            
+            
\ No newline at end of file
@@ -3,19 +3,31 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
 import '../../frb_generated.dart';
 import '../models/config.dart';
+import '../models/error.dart';
 import '../models/net_info.dart';
+import '../models/report.dart';
 import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `enforce_policy`
+
+
+            /// Runs the DNS hijack check by comparing resolution against trusted resolvers.
+Future<void>  performDnsSecurityCheck({required NetworkConfiguration config , required SecurityFlagsResult flags }) => RustLib.instance.api.crateApiEngineSecurityPerformDnsSecurityCheck(config: config, flags: flags);
+
+/// Runs [check_network](super::check_network) and enforces
+/// [NetworkConfiguration::security] on the result.
+///
+/// Returns the report if the connection complies, or
+/// [NetworkError::PolicyViolation] naming the first broken rule: a VPN while
+/// [block_vpn](SecurityConfig::block_vpn) is set, an active interface outside
+/// [allowed_interfaces](SecurityConfig::allowed_interfaces), or DNS answers
+/// flagged as spoofed. The last needs
+/// [detect_dns_hijack](SecurityConfig::detect_dns_hijack) to be set.
+Future<NetworkReport>  guard({required NetworkConfiguration config }) => RustLib.instance.api.crateApiEngineSecurityGuard(config: config);
 
-/// Runs the DNS hijack check by comparing resolution against trusted resolvers.
-Future<void> performDnsSecurityCheck({
-  required final NetworkConfiguration config,
-  required final SecurityFlagsResult flags,
-}) =>
-    RustLib.instance.api.crateApiEngineSecurityPerformDnsSecurityCheck(
-      config: config,
-      flags: flags,
-    );
+            
+            
\ No newline at end of file
@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/config.dart';
+import '../models/diagnostics.dart';
+import '../models/net_info.dart';
+import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `wait_for_vpn_toggle`
+
+
+            /// Captures a baseline report, waits for the VPN state to flip, then captures
+/// a second report and compares the two.
+///
+/// The function polls the interface table every
+/// [LibConstants::DEFAULT_VPN_TOGGLE_POLL_MS] until the VPN state differs from
+/// the baseline or `wait_timeout_ms` elapses. In the latter case the second
+/// report is still captured, and [VpnComparison::toggle_observed] is `false`.
+Future<VpnComparison>  compareVpnToggle({required NetworkConfiguration config , required BigInt waitTimeoutMs }) => RustLib.instance.api.crateApiEngineVpnCompareVpnToggle(config: config, waitTimeoutMs: waitTimeoutMs);
+
+            
+            
\ No newline at end of file
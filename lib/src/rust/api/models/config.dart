@@ -1,434 +1,771 @@
 // This file is automatically generated, so please do not edit it.
 // @generated by `flutter_rust_bridge`@ 2.12.0.
 
-// ignore_for_file: public_member_api_docs, invalid_use_of_internal_member, unused_import, unnecessary_import
-
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import 'error.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
 import 'target.dart';
+part 'config.freezed.dart';
+
+            // These functions are ignored because they are not marked as `pub`: `invalid_fields`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NetworkConfigurationBuilder`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `builder`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `active_interface`, `add_isp_status_url`, `add_lan_target`, `add_target`, `attribute_degradation`, `build`, `cache_validity_ms`, `check_interval_jitter_percent`, `check_interval_ms`, `check_multicast`, `compare_address_families`, `compare_paths`, `default`, `endpoints`, `fast_fail_offline`, `include_raw_samples`, `new`, `overall_timeout_ms`, `overlap_policy`, `pre_resolve_targets`, `quality_thresholds`, `rate_limit`, `resilience`, `sample_mode`, `security`, `snmp`, `strategy`
+
+
+            
+
+            @freezed
+                sealed class CheckStrategy with _$CheckStrategy  {
+                    const CheckStrategy._();
+
+                     /// The first target to respond successfully determines the result.
+///
+/// # Behavior
+/// In this mode, the engine initiates checks for all configured targets concurrently.
+/// As soon as a single target returns a successful response, the network is marked
+/// as "connected" and the engine returns the result immediately.
+///
+/// # Use Case
+/// This is the fastest strategy and is ideal for performance-sensitive applications
+/// where knowing *any* path to the internet is open is sufficient. It minimizes
+/// latency for the check itself.
+const factory CheckStrategy.race() = CheckStrategy_Race;
+ /// A majority of targets must respond successfully for the check to be considered a success.
+///
+/// # Behavior
+/// The engine waits for a quorum of targets to respond. For example, if 3 targets
+/// are configured, at least 2 must succeed for the overall status to be "connected".
+/// If the majority fails, the network is considered "offline" or "unstable".
+///
+/// # Use Case
+/// This strategy provides high robustness against transient failures of specific
+/// servers (e.g., a specific DNS provider being down). It's best for critical
+/// applications that require high confidence in the network's reliability.
+const factory CheckStrategy.consensus() = CheckStrategy_Consensus;
+ /// Targets are probed in [priority](super::target::NetworkTarget::priority)
+/// order, and lower-priority targets only if all higher ones failed.
+///
+/// # Behavior
+/// Each sample probes the targets with the lowest priority number (plus
+/// every essential target) concurrently. Only if none of them succeeds
+/// does it move on to the next priority level. Targets that were not
+/// needed are reported as [skipped](super::report::TargetReport::skipped).
+/// One success is enough, and the winner is the first successful target
+/// in configuration order.
+///
+/// # Use Case
+/// Primary/backup setups, e.g. a company endpoint with public fallbacks:
+/// the backups cause no traffic while the primary is up, and the winner
+/// does not flip between targets of similar latency.
+const factory CheckStrategy.fallback() = CheckStrategy_Fallback;
+ /// Every target must respond successfully.
+///
+/// # Behavior
+/// A sample only counts if no target failed, so a single unreachable
+/// endpoint is reported as packet loss.
+///
+/// # Use Case
+/// Apps that depend on several services at once (e.g. an API, its auth
+/// server and a CDN) and are only usable when all of them are.
+const factory CheckStrategy.all() = CheckStrategy_All;
+ /// At least this many targets must respond successfully.
+///
+/// # Behavior
+/// Like [`Consensus`](Self::Consensus), but with an explicit count instead
+/// of a majority. Must be between 1 and the number of targets.
+///
+/// # Use Case
+/// Fine-tuning robustness, e.g. "any 2 of these 5 resolvers".
+const factory CheckStrategy.quorum(  int field0,) = CheckStrategy_Quorum;
+ /// Successful targets add their [weight](super::target::NetworkTarget::weight)
+/// to a score, which must reach half of the total weight.
+///
+/// # Behavior
+/// A target of weight 3 counts as much as three targets of weight 1, and
+/// a target of weight 0 never affects the verdict. The winner is the
+/// heaviest successful target, the faster one on ties.
+///
+/// # Use Case
+/// Mixed target sets where some endpoints matter more than others, e.g.
+/// the app's own backend next to generic public targets.
+const factory CheckStrategy.weighted() = CheckStrategy_Weighted;
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
-
-/// Defines the strategy used when evaluating multiple network targets during a check cycle.
-///
-/// When the engine is configured with multiple targets, the `CheckStrategy` determines
-/// the logic for deciding the overall "connected" status of the network.
-enum CheckStrategy {
-  /// The first target to respond successfully determines the result.
-  ///
-  /// # Behavior
-  /// In this mode, the engine initiates checks for all configured targets concurrently.
-  /// As soon as a single target returns a successful response, the network is marked
-  /// as "connected" and the engine returns the result immediately.
-  ///
-  /// # Use Case
-  /// This is the fastest strategy and is ideal for performance-sensitive applications
-  /// where knowing *any* path to the internet is open is sufficient. It minimizes
-  /// latency for the check itself.
-  race,
-
-  /// A majority of targets must respond successfully for the check to be considered a success.
-  ///
-  /// # Behavior
-  /// The engine waits for a quorum of targets to respond. For example, if 3 targets
-  /// are configured, at least 2 must succeed for the overall status to be "connected".
-  /// If the majority fails, the network is considered "offline" or "unstable".
-  ///
-  /// # Use Case
-  /// This strategy provides high robustness against transient failures of specific
-  /// servers (e.g., a specific DNS provider being down). It's best for critical
-  /// applications that require high confidence in the network's reliability.
-  consensus,
-  ;
-}
+                    
+
+                    
+                }
 
 /// Represents the perceived quality of the network connection based on latency, jitter, and stability.
 ///
 /// The engine maps raw metrics (like RTT in milliseconds) to these categories using
 /// the thresholds defined in [`QualityThresholds`].
 enum ConnectionQuality {
-  /// Excellent connection with very low latency and high stability.
-  ///
-  /// Typically < 50ms.
-  /// **User Experience:** Instantaneous page loads, seamless 4K streaming, and
-  /// zero-lag competitive gaming.
-  excellent,
-
-  /// Great connection with low latency.
-  ///
-  /// Typically < 100ms.
-  /// **User Experience:** Fast browsing, high-quality video calls (VoIP/Zoom)
-  /// without noticeable delay.
-  great,
-
-  /// Good, usable connection with acceptable latency.
-  ///
-  /// Typically < 150ms.
-  /// **User Experience:** Reliable for most tasks, though large file uploads
-  /// or fast-paced games might show slight degradation.
-  good,
-
-  /// Moderate connection with noticeable latency.
-  ///
-  /// Typically < 250ms.
-  /// **User Experience:** Noticeable delays when opening new pages. Video streaming
-  /// might occasionally buffer at the start.
-  moderate,
-
-  /// Poor connection with high latency.
-  ///
-  /// Typically < 500ms.
-  /// **User Experience:** Frustratingly slow. Interactive applications feel
-  /// "heavy" and unresponsive.
-  poor,
-
-  /// Connection is active, but high jitter or packet loss makes it unreliable.
-  ///
-  /// This state occurs when latency is technically okay, but the "consistency"
-  /// is missing (e.g., standard deviation of samples is too high).
-  /// **User Experience:** "Stuttering" in calls, frequent disconnects,
-  /// and unpredictable performance.
-  unstable,
-
-  /// A captive portal (login page) was detected.
-  ///
-  /// The device is connected to a WiFi access point, but internet access is
-  /// intercepted by a gateway (common in hotels/airports).
-  /// **User Experience:** No internet access until the user signs in or
-  /// accepts terms.
-  captivePortal,
-
-  /// No connection detected or all essential targets failed.
-  ///
-  /// **User Experience:** The app should enter its "Offline Mode".
-  offline,
-  ;
-}
+                    /// Excellent connection with very low latency and high stability.
+///
+/// Typically < 50ms.
+/// **User Experience:** Instantaneous page loads, seamless 4K streaming, and
+/// zero-lag competitive gaming.
+excellent,
+/// Great connection with low latency.
+///
+/// Typically < 100ms.
+/// **User Experience:** Fast browsing, high-quality video calls (VoIP/Zoom)
+/// without noticeable delay.
+great,
+/// Good, usable connection with acceptable latency.
+///
+/// Typically < 150ms.
+/// **User Experience:** Reliable for most tasks, though large file uploads
+/// or fast-paced games might show slight degradation.
+good,
+/// Moderate connection with noticeable latency.
+///
+/// Typically < 250ms.
+/// **User Experience:** Noticeable delays when opening new pages. Video streaming
+/// might occasionally buffer at the start.
+moderate,
+/// Poor connection with high latency.
+///
+/// Typically < 500ms.
+/// **User Experience:** Frustratingly slow. Interactive applications feel
+/// "heavy" and unresponsive.
+poor,
+/// Connection is active, but high jitter or packet loss makes it unreliable.
+///
+/// This state occurs when latency is technically okay, but the "consistency"
+/// is missing (e.g., standard deviation of samples is too high).
+/// **User Experience:** "Stuttering" in calls, frequent disconnects,
+/// and unpredictable performance.
+unstable,
+/// A captive portal (login page) was detected.
+///
+/// The device is connected to a WiFi access point, but internet access is
+/// intercepted by a gateway (common in hotels/airports).
+/// **User Experience:** No internet access until the user signs in or
+/// accepts terms.
+captivePortal,
+/// No connection detected or all essential targets failed.
+///
+/// **User Experience:** The app should enter its "Offline Mode".
+offline,
+                    ;
+                    /// The localization key, e.g. `quality.captive_portal`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsConfigConnectionQualityL10NKey(that: this, );
+
+
+                }
+
+/// The external services the engine relies on besides the targets.
+///
+/// Each defaults to the public service the engine has always used. Pointing
+/// them at local servers, e.g. a mock HTTP server in tests, keeps a check
+/// from reaching out to the internet on its own.
+class EndpointConfig  {
+                /// The URL fetched to detect a captive portal. It must answer without
+/// redirecting on an open network, so it should be plain HTTP.
+/// *Default: "http://neverssl.com"*
+final String captivePortalUrl;
+/// The URL requested, with certificate validation, to learn whether a
+/// portal's walled garden lets HTTPS through.
+/// *Default: "https://1.1.1.1/"*
+final String walledGardenUrl;
+/// The host whose ports the walled garden probe connects to. It must
+/// accept connections on every port.
+/// *Default: "portquiz.net"*
+final String walledGardenPortHost;
+/// The public DNS server (`ip:port`) the walled garden probe queries
+/// directly, bypassing the one handed out by DHCP.
+/// *Default: "1.1.1.1:53"*
+final String publicDnsServer;
+/// The resolvers (`ip:port`) whose answers are trusted when checking the
+/// system resolver for hijacking (see [SecurityConfig::detect_dns_hijack]).
+/// *Default: empty (Cloudflare)*
+final List<String> trustedDnsServers;
+/// The servers (`ip:port`) whose TCP handshakes measure each interface
+/// when comparing paths (see [NetworkConfiguration::compare_paths]).
+/// *Default: [DEFAULT_TCP_REFERENCE_SERVERS](LibConstants::DEFAULT_TCP_REFERENCE_SERVERS)*
+final List<String> tcpReferenceServers;
+
+                const EndpointConfig({required this.captivePortalUrl ,required this.walledGardenUrl ,required this.walledGardenPortHost ,required this.publicDnsServer ,required this.trustedDnsServers ,required this.tcpReferenceServers ,});
+
+                static Future<EndpointConfig>  default_()=>RustLib.instance.api.crateApiModelsConfigEndpointConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => captivePortalUrl.hashCode^walledGardenUrl.hashCode^walledGardenPortHost.hashCode^publicDnsServer.hashCode^trustedDnsServers.hashCode^tcpReferenceServers.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EndpointConfig &&
+                runtimeType == other.runtimeType
+                && captivePortalUrl == other.captivePortalUrl&& walledGardenUrl == other.walledGardenUrl&& walledGardenPortHost == other.walledGardenPortHost&& publicDnsServer == other.publicDnsServer&& trustedDnsServers == other.trustedDnsServers&& tcpReferenceServers == other.tcpReferenceServers;
+        
+            }
 
 /// The master configuration for the Network Reachability Engine.
 ///
 /// This structure is the entry point for customizing how the engine behaves.
 /// It should be initialized once and passed to the engine during startup.
-class NetworkConfiguration {
-  const NetworkConfiguration({
-    required this.targets,
-    required this.checkIntervalMs,
-    required this.cacheValidityMs,
-    required this.qualityThreshold,
-    required this.security,
-    required this.resilience,
-  });
-
-  /// The list of endpoints ([`NetworkTarget` models](super::target::NetworkTarget))
-  /// to probe.
-  final List<NetworkTarget> targets;
-
-  /// Frequency (ms) of background checks.
-  ///
-  /// If set to 5000, the engine will automatically run a check every 5 seconds
-  /// and stream results. Set to 0 to disable periodic checks.
-  final BigInt checkIntervalMs;
-
-  /// Cache duration (ms) for results.
-  ///
-  /// If a manual check is requested within this window of a previous check,
-  /// the engine will return the cached result instead of performing new
-  /// network I/O. This saves significant battery and data.
-  final BigInt cacheValidityMs;
-
-  /// Thresholds for quality categorization.
-  final QualityThresholds qualityThreshold;
-
-  /// Security policy settings.
-  final SecurityConfig security;
-
-  /// Performance and resilience settings.
-  final ResilienceConfig resilience;
-
-  /// Standard production-ready configuration.
-  ///
-  /// Includes:
-  /// - **Targets:** Cloudflare (HTTP/HTTPS/TCP/ICMP) and Google (TCP/ICMP).
-  /// - **Interval:** 5 seconds.
-  /// - **Cache:** 2 seconds.
-  /// - **Defaults:** Balanced quality and resilience settings.
-  static Future<NetworkConfiguration> default_() =>
-      RustLib.instance.api.crateApiModelsConfigNetworkConfigurationDefault();
+class NetworkConfiguration  {
+                /// The list of endpoints ([`NetworkTarget` models](super::target::NetworkTarget))
+/// to probe.
+final List<NetworkTarget> targets;
+/// Endpoints on the local network (PLCs, printers, NAS, ...) that prove
+/// LAN reachability when the internet is down.
+///
+/// Probed together with the default gateway by [check_local_network](crate::api::engine::local::check_local_network), and by
+/// [check_network](crate::api::engine::check_network) whenever no internet
+/// target answers. Empty by default.
+final List<NetworkTarget> lanTargets;
+/// ISP or downdetector-style status endpoints, consulted when the gateway
+/// answers but no internet target does.
+///
+/// Each URL should return either a Statuspage-style JSON document
+/// (`{"status": {"indicator": "none"}}`) or a plain page mentioning an
+/// "outage". The verdict lands in
+/// [NetworkReport::likely_isp_outage](super::report::NetworkReport::likely_isp_outage).
+/// Empty by default, which skips the probe.
+final List<String> ispStatusUrls;
+/// Frequency (ms) of background checks.
+///
+/// If set to 5000, a [NetworkMonitor](crate::api::monitor::NetworkMonitor)
+/// runs a check every 5 seconds and streams the results. Set to 0 to
+/// disable periodic checks.
+final BigInt checkIntervalMs;
+/// Random spread (±percent) applied to every periodic interval.
+///
+/// With a value of 10 and an interval of 5000ms, each wait is drawn uniformly
+/// from 4500..=5500ms. This keeps large fleets of clients from synchronizing
+/// and probing the same targets in bursts. Set to 0 for a fixed interval.
+/// Values above 100 are treated as 100.
+final int checkIntervalJitterPercent;
+/// Cache duration (ms) for results.
+///
+/// If a manual check is requested within this window of a previous check,
+/// the engine will return the cached result instead of performing new
+/// network I/O. This saves significant battery and data.
+final BigInt cacheValidityMs;
+/// Thresholds for quality categorization.
+final QualityThresholds qualityThreshold;
+/// Security policy settings.
+final SecurityConfig security;
+/// Performance and resilience settings.
+final ResilienceConfig resilience;
+/// Limits on the probe traffic generated per check.
+final RateLimitConfig rateLimit;
+/// SNMP settings for the optional SNMP pipeline step.
+final SnmpConfig snmp;
+/// The captive portal, walled garden and DNS services probed alongside
+/// the targets.
+final EndpointConfig endpoints;
+/// Attach every sampling round to [NetworkReport::raw_samples](super::report::NetworkReport::raw_samples).
+///
+/// Off by default, as it grows each report by one record per sample.
+final bool includeRawSamples;
+/// Resolve every target's host once before sampling and reuse the
+/// addresses for all samples of the check.
+///
+/// Keeps resolver variability out of the latency and jitter figures. The
+/// one-off resolution time is still reported in
+/// [TargetReport::dns_ms](super::report::TargetReport::dns_ms). Targets
+/// whose host cannot be pre-resolved fall back to per-sample resolution.
+/// Off by default.
+final bool preResolveTargets;
+/// Probe dual-stack targets over IPv4 and IPv6 separately and attach the
+/// comparison to [NetworkReport::family_comparison](super::report::NetworkReport::family_comparison).
+///
+/// Only targets whose host name resolves to both families take part; IP
+/// literals do not. Doubles the probe traffic of those targets. Off by default.
+final bool compareAddressFamilies;
+/// Check LAN multicast via the mDNS group and set
+/// [NetworkReport::multicast_ok](super::report::NetworkReport::multicast_ok).
+///
+/// Adds a listening window of
+/// [DEFAULT_MULTICAST_TIMEOUT_MS](LibConstants::DEFAULT_MULTICAST_TIMEOUT_MS)
+/// to the check. Off by default.
+final bool checkMulticast;
+/// If enabled, measures every Wi-Fi, cellular and Ethernet interface
+/// separately and reports a [BetterPathAvailable](super::diagnostics::BetterPathAvailable)
+/// in [NetworkReport::better_path](super::report::NetworkReport::better_path)
+/// when one beats the active interface. Only does anything with more than
+/// one such interface up.
+/// *Default: false*
+final bool comparePaths;
+/// If enabled, samples the gateway after the check and reports in
+/// [NetworkReport::attribution](super::report::NetworkReport::attribution)
+/// how much of the latency variance and loss arise between the device and
+/// its router. Adds one gateway probe per jitter sample. Only does
+/// anything while connected.
+/// *Default: false*
+final bool attributeDegradation;
+/// Upper bound (ms) on the whole of [check_network](crate::api::engine::check_network).
+///
+/// When it runs out, the check stops and returns what it measured so far,
+/// flagged as [truncated](super::report::NetworkReport::truncated). 0 means
+/// no limit beyond the per-target timeouts.
+/// *Default: 0*
+final BigInt overallTimeoutMs;
+/// Report offline at once, without probing, when the OS has no route to
+/// the internet nor to any IP-literal target.
+///
+/// Saves waiting out every target's timeout when all interfaces are down.
+/// Targets given by host name count as needing the internet route.
+/// *Default: true*
+final bool fastFailOffline;
+/// The interface to treat as active (e.g. `wlan0`), instead of detecting
+/// the one carrying the default route.
+///
+/// Decides [NetworkReport::connection_type](super::report::NetworkReport::connection_type),
+/// the security flags and the baseline of [compare_paths](Self::compare_paths),
+/// and takes precedence over the interface facts of
+/// [PlatformHints](super::net_info::PlatformHints). Probes still leave
+/// through whatever link the OS routes them over unless they are bound to
+/// it as well. An interface that is not up is reported as
+/// [ConnectionType::Unknown](super::net_info::ConnectionType::Unknown).
+/// *Default: None (detect)*
+final String? activeInterface;
+
+                const NetworkConfiguration({required this.targets ,required this.lanTargets ,required this.ispStatusUrls ,required this.checkIntervalMs ,required this.checkIntervalJitterPercent ,required this.cacheValidityMs ,required this.qualityThreshold ,required this.security ,required this.resilience ,required this.rateLimit ,required this.snmp ,required this.endpoints ,required this.includeRawSamples ,required this.preResolveTargets ,required this.compareAddressFamilies ,required this.checkMulticast ,required this.comparePaths ,required this.attributeDegradation ,required this.overallTimeoutMs ,required this.fastFailOffline ,this.activeInterface ,});
+
+                /// Standard production-ready configuration.
+///
+/// Includes:
+/// - **Targets:** Cloudflare (HTTP/HTTPS/ICMP) and a DNS query to Google DNS.
+/// - **Interval:** 5 seconds, with ±10% jitter.
+/// - **Cache:** 2 seconds.
+/// - **Defaults:** Balanced quality and resilience settings.
+static Future<NetworkConfiguration>  default_()=>RustLib.instance.api.crateApiModelsConfigNetworkConfigurationDefault();
+
 
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  /// Constructs a full [`NetworkConfiguration`].
-  static Future<NetworkConfiguration> newInstance({
-    required final List<NetworkTarget> targets,
-    required final BigInt checkIntervalMs,
-    required final BigInt cacheValidityMs,
-    required final QualityThresholds qualityThreshold,
-    required final SecurityConfig security,
-    required final ResilienceConfig resilience,
-  }) =>
-      RustLib.instance.api.crateApiModelsConfigNetworkConfigurationNew(
-        targets: targets,
-        checkIntervalMs: checkIntervalMs,
-        cacheValidityMs: cacheValidityMs,
-        qualityThreshold: qualityThreshold,
-        security: security,
-        resilience: resilience,
-      );
-
-  @override
-  int get hashCode =>
-      targets.hashCode ^
-      checkIntervalMs.hashCode ^
-      cacheValidityMs.hashCode ^
-      qualityThreshold.hashCode ^
-      security.hashCode ^
-      resilience.hashCode;
-
-  @override
-  bool operator ==(final Object other) =>
-      identical(this, other) ||
-      other is NetworkConfiguration &&
-          runtimeType == other.runtimeType &&
-          targets == other.targets &&
-          checkIntervalMs == other.checkIntervalMs &&
-          cacheValidityMs == other.cacheValidityMs &&
-          qualityThreshold == other.qualityThreshold &&
-          security == other.security &&
-          resilience == other.resilience;
-}
+/// Constructs a full [`NetworkConfiguration`].
+static Future<NetworkConfiguration>  newInstance({required List<NetworkTarget> targets , required BigInt checkIntervalMs , required BigInt cacheValidityMs , required QualityThresholds qualityThreshold , required SecurityConfig security , required ResilienceConfig resilience })=>RustLib.instance.api.crateApiModelsConfigNetworkConfigurationNew(targets: targets, checkIntervalMs: checkIntervalMs, cacheValidityMs: cacheValidityMs, qualityThreshold: qualityThreshold, security: security, resilience: resilience);
+
+
+/// Checks the configuration for values the engine cannot work with.
+///
+/// Returns every problem found, not just the first.
+ Future<void>  validate()=>RustLib.instance.api.crateApiModelsConfigNetworkConfigurationValidate(that: this, );
+
+
+                
+
+                
+        @override
+        int get hashCode => targets.hashCode^lanTargets.hashCode^ispStatusUrls.hashCode^checkIntervalMs.hashCode^checkIntervalJitterPercent.hashCode^cacheValidityMs.hashCode^qualityThreshold.hashCode^security.hashCode^resilience.hashCode^rateLimit.hashCode^snmp.hashCode^endpoints.hashCode^includeRawSamples.hashCode^preResolveTargets.hashCode^compareAddressFamilies.hashCode^checkMulticast.hashCode^comparePaths.hashCode^attributeDegradation.hashCode^overallTimeoutMs.hashCode^fastFailOffline.hashCode^activeInterface.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NetworkConfiguration &&
+                runtimeType == other.runtimeType
+                && targets == other.targets&& lanTargets == other.lanTargets&& ispStatusUrls == other.ispStatusUrls&& checkIntervalMs == other.checkIntervalMs&& checkIntervalJitterPercent == other.checkIntervalJitterPercent&& cacheValidityMs == other.cacheValidityMs&& qualityThreshold == other.qualityThreshold&& security == other.security&& resilience == other.resilience&& rateLimit == other.rateLimit&& snmp == other.snmp&& endpoints == other.endpoints&& includeRawSamples == other.includeRawSamples&& preResolveTargets == other.preResolveTargets&& compareAddressFamilies == other.compareAddressFamilies&& checkMulticast == other.checkMulticast&& comparePaths == other.comparePaths&& attributeDegradation == other.attributeDegradation&& overallTimeoutMs == other.overallTimeoutMs&& fastFailOffline == other.fastFailOffline&& activeInterface == other.activeInterface;
+        
+            }
+
+/// What a [NetworkMonitor](crate::api::monitor::NetworkMonitor) does when a
+/// check is still running at the time the next one is due.
+///
+/// Checks never overlap; the policy only decides what happens to the cycles
+/// that came due in the meantime. Every cycle that does not get a check of
+/// its own is counted in [MonitorStats::skipped_cycles](crate::api::monitor::MonitorStats::skipped_cycles).
+enum OverlapPolicy {
+                    /// Drops the overdue cycles and waits for the next regular one.
+///
+/// Keeps the configured rate even on a slow network, at the cost of
+/// stale reports for up to one extra interval.
+skip,
+/// Runs every overdue cycle, back to back, as soon as the check ends.
+///
+/// At most [MONITOR_MAX_QUEUED_CHECKS](LibConstants::MONITOR_MAX_QUEUED_CHECKS)
+/// cycles are kept pending, so a network that stays slow cannot build
+/// up an unbounded backlog.
+queue,
+/// Merges all overdue cycles into one check that starts right away.
+coalesce,
+                    ;
+                    /// Returns [`OverlapPolicy::Skip`] as the default.
+static Future<OverlapPolicy>  default_()=>RustLib.instance.api.crateApiModelsConfigOverlapPolicyDefault();
+
+
+/// The localization key, e.g. `overlap_policy.coalesce`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsConfigOverlapPolicyL10NKey(that: this, );
+
+
+                }
 
 /// Defines the latency thresholds (in milliseconds) used to categorize [`ConnectionQuality`].
 ///
 /// These values act as the "buckets" that convert raw Round-Trip Time (RTT) values
 /// into user-friendly quality ratings.
-class QualityThresholds {
-  const QualityThresholds({
-    required this.excellent,
-    required this.great,
-    required this.good,
-    required this.moderate,
-    required this.poor,
-  });
-
-  /// Maximum latency (ms) to be considered [`ConnectionQuality::Excellent`].
-  /// *Default: 50ms*
-  final BigInt excellent;
-
-  /// Maximum latency (ms) to be considered [`ConnectionQuality::Great`].
-  /// *Default: 100ms*
-  final BigInt great;
-
-  /// Maximum latency (ms) to be considered [`ConnectionQuality::Good`].
-  /// *Default: 150ms*
-  final BigInt good;
-
-  /// Maximum latency (ms) to be considered [`ConnectionQuality::Moderate`].
-  /// *Default: 250ms*
-  final BigInt moderate;
-
-  /// Maximum latency (ms) to be considered [`ConnectionQuality::Poor`].
-  /// Anything above this is typically marked as [`ConnectionQuality::Poor`] or
-  /// [`ConnectionQuality::Unstable`].
-  /// *Default: 500ms*
-  final BigInt poor;
-
-  /// Provides industry-standard default latency thresholds for most applications.
-  ///
-  /// These defaults are tuned for general-purpose mobile and web apps:
-  /// - **Excellent:** 50ms (Fiber/High-speed Cable)
-  /// - **Great:** 100ms (Average Broadband)
-  /// - **Good:** 150ms (Stable 4G/LTE)
-  /// - **Moderate:** 250ms (3G/Slower Satellite)
-  /// - **Poor:** 500ms (Highly congested or edge networks)
-  static Future<QualityThresholds> default_() =>
-      RustLib.instance.api.crateApiModelsConfigQualityThresholdsDefault();
+class QualityThresholds  {
+                /// Maximum latency (ms) to be considered [`ConnectionQuality::Excellent`].
+/// *Default: 50ms*
+final BigInt excellent;
+/// Maximum latency (ms) to be considered [`ConnectionQuality::Great`].
+/// *Default: 100ms*
+final BigInt great;
+/// Maximum latency (ms) to be considered [`ConnectionQuality::Good`].
+/// *Default: 150ms*
+final BigInt good;
+/// Maximum latency (ms) to be considered [`ConnectionQuality::Moderate`].
+/// *Default: 250ms*
+final BigInt moderate;
+/// Maximum latency (ms) to be considered [`ConnectionQuality::Poor`].
+/// Anything above this is typically marked as [`ConnectionQuality::Poor`] or
+/// [`ConnectionQuality::Unstable`].
+/// *Default: 500ms*
+final BigInt poor;
+
+                const QualityThresholds({required this.excellent ,required this.great ,required this.good ,required this.moderate ,required this.poor ,});
+
+                /// Provides industry-standard default latency thresholds for most applications.
+///
+/// These defaults are tuned for general-purpose mobile and web apps:
+/// - **Excellent:** 50ms (Fiber/High-speed Cable)
+/// - **Great:** 100ms (Average Broadband)
+/// - **Good:** 150ms (Stable 4G/LTE)
+/// - **Moderate:** 250ms (3G/Slower Satellite)
+/// - **Poor:** 500ms (Highly congested or edge networks)
+static Future<QualityThresholds>  default_()=>RustLib.instance.api.crateApiModelsConfigQualityThresholdsDefault();
+
 
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  /// Creates a new custom [`QualityThresholds`] instance.
-  ///
-  /// # Arguments
-  /// * `excellent` - Threshold for 'Excellent' (ms).
-  /// * `great` - Threshold for 'Great' (ms).
-  /// * `good` - Threshold for 'Good' (ms).
-  /// * `moderate` - Threshold for 'Moderate' (ms).
-  /// * `poor` - Threshold for 'Poor' (ms).
-  static Future<QualityThresholds> newInstance({
-    required final BigInt excellent,
-    required final BigInt great,
-    required final BigInt good,
-    required final BigInt moderate,
-    required final BigInt poor,
-  }) =>
-      RustLib.instance.api.crateApiModelsConfigQualityThresholdsNew(
-        excellent: excellent,
-        great: great,
-        good: good,
-        moderate: moderate,
-        poor: poor,
-      );
-
-  @override
-  int get hashCode =>
-      excellent.hashCode ^
-      great.hashCode ^
-      good.hashCode ^
-      moderate.hashCode ^
-      poor.hashCode;
-
-  @override
-  bool operator ==(final Object other) =>
-      identical(this, other) ||
-      other is QualityThresholds &&
-          runtimeType == other.runtimeType &&
-          excellent == other.excellent &&
-          great == other.great &&
-          good == other.good &&
-          moderate == other.moderate &&
-          poor == other.poor;
-}
+/// Creates a new custom [`QualityThresholds`] instance.
+///
+/// # Arguments
+/// * `excellent` - Threshold for 'Excellent' (ms).
+/// * `great` - Threshold for 'Great' (ms).
+/// * `good` - Threshold for 'Good' (ms).
+/// * `moderate` - Threshold for 'Moderate' (ms).
+/// * `poor` - Threshold for 'Poor' (ms).
+static Future<QualityThresholds>  newInstance({required BigInt excellent , required BigInt great , required BigInt good , required BigInt moderate , required BigInt poor })=>RustLib.instance.api.crateApiModelsConfigQualityThresholdsNew(excellent: excellent, great: great, good: good, moderate: moderate, poor: poor);
+
+
+                
+
+                
+        @override
+        int get hashCode => excellent.hashCode^great.hashCode^good.hashCode^moderate.hashCode^poor.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is QualityThresholds &&
+                runtimeType == other.runtimeType
+                && excellent == other.excellent&& great == other.great&& good == other.good&& moderate == other.moderate&& poor == other.poor;
+        
+            }
+
+/// Limits on the probe traffic generated by the engine.
+///
+/// Both limits are enforced by a token bucket shared by every probe of a check,
+/// so aggressive configurations (many targets, many samples) cannot burst enough
+/// traffic to trip intrusion-detection systems on corporate networks.
+class RateLimitConfig  {
+                /// Maximum number of probes started per second.
+/// *Default: 0 (unlimited)*
+final int maxProbesPerSecond;
+/// Maximum estimated probe traffic, in bytes per second.
+///
+/// Probe sizes are estimated per protocol (a TCP handshake is far cheaper
+/// than a full HTTPS request), so this is an approximate upper bound.
+/// *Default: 0 (unlimited)*
+final BigInt maxBytesPerSecond;
+
+                const RateLimitConfig({required this.maxProbesPerSecond ,required this.maxBytesPerSecond ,});
+
+                static Future<RateLimitConfig>  default_()=>RustLib.instance.api.crateApiModelsConfigRateLimitConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => maxProbesPerSecond.hashCode^maxBytesPerSecond.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RateLimitConfig &&
+                runtimeType == other.runtimeType
+                && maxProbesPerSecond == other.maxProbesPerSecond&& maxBytesPerSecond == other.maxBytesPerSecond;
+        
+            }
+
+/// What an application needs from the connection, for
+/// [preflight](crate::api::engine::preflight::preflight).
+///
+/// Every requirement is optional; only the probes needed to answer the set
+/// requirements are run.
+class Requirements  {
+                /// Minimum download throughput in Mbit/s, measured against `download_url`.
+final double? minDownMbps;
+/// Maximum representative latency in milliseconds, measured against `targets`.
+final BigInt? maxLatencyMs;
+/// Maximum packet loss in percent, measured against `targets`.
+final double? maxLossPercent;
+/// Whether UDP must get through (e.g. for QUIC or WebRTC media), checked
+/// with a DNS query to `udp_dns_server`.
+final bool needsUdp;
+/// Whether WebSocket upgrades must get through, checked with an upgrade
+/// handshake against `websocket_url`.
+final bool needsWebsocket;
+/// The targets for the latency and loss requirements. Empty uses the
+/// targets of [NetworkConfiguration::default].
+final List<NetworkTarget> targets;
+/// A URL serving a large body, for the throughput requirement.
+final String downloadUrl;
+/// A DNS server (`ip:port`) for the UDP requirement.
+final String udpDnsServer;
+/// A WebSocket endpoint (`ws://`, `wss://` or the `http(s)://` equivalent).
+final String websocketUrl;
+
+                const Requirements({this.minDownMbps ,this.maxLatencyMs ,this.maxLossPercent ,required this.needsUdp ,required this.needsWebsocket ,required this.targets ,required this.downloadUrl ,required this.udpDnsServer ,required this.websocketUrl ,});
+
+                static Future<Requirements>  default_()=>RustLib.instance.api.crateApiModelsConfigRequirementsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => minDownMbps.hashCode^maxLatencyMs.hashCode^maxLossPercent.hashCode^needsUdp.hashCode^needsWebsocket.hashCode^targets.hashCode^downloadUrl.hashCode^udpDnsServer.hashCode^websocketUrl.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Requirements &&
+                runtimeType == other.runtimeType
+                && minDownMbps == other.minDownMbps&& maxLatencyMs == other.maxLatencyMs&& maxLossPercent == other.maxLossPercent&& needsUdp == other.needsUdp&& needsWebsocket == other.needsWebsocket&& targets == other.targets&& downloadUrl == other.downloadUrl&& udpDnsServer == other.udpDnsServer&& websocketUrl == other.websocketUrl;
+        
+            }
 
 /// Configuration for network resilience, failure handling, and statistical analysis.
 ///
 /// This struct controls the "brain" of the engine: how it handles noise,
 /// how it reacts to failure, and how it calculates jitter.
-class ResilienceConfig {
-  const ResilienceConfig({
-    required this.strategy,
-    required this.circuitBreakerThreshold,
-    required this.circuitBreakerCooldownMs,
-    required this.numJitterSamples,
-    required this.jitterThresholdPercent,
-    required this.stabilityThershold,
-    required this.criticalPacketLossPrecent,
-  });
-
-  /// The evaluation strategy (Race vs Consensus) for multi-target checks.
-  final CheckStrategy strategy;
-
-  /// Number of consecutive failures before the "Circuit Breaker" opens.
-  ///
-  /// When the circuit breaker is 'Open', the engine stops sending probes to
-  /// save resources and battery, assuming the network is definitely down.
-  /// *Set to 0 to disable.*
-  final int circuitBreakerThreshold;
-
-  /// Duration (ms) the engine waits before attempting a "Half-Open" probe.
-  ///
-  /// Once the circuit breaker is open, it waits for this cooldown before
-  /// trying one more check to see if connectivity has returned.
-  final BigInt circuitBreakerCooldownMs;
-
-  /// Number of packets/samples to send per target for statistical analysis.
-  ///
-  /// Higher values (e.g., 10+) provide extremely accurate jitter and packet loss
-  /// metrics but increase the battery/data usage and duration of each check.
-  /// *Minimum 2 required for jitter calculation.*
-  final int numJitterSamples;
-
-  /// Percentage threshold for jitter classification.
-  ///
-  /// If the standard deviation of latency samples divided by the mean exceeds
-  /// this percentage, the connection is marked as 'Unstable'.
-  final double jitterThresholdPercent;
-
-  /// Minimum stability score (0-100) required for a 'Stable' rating.
-  ///
-  /// A score calculated from packet loss and jitter consistency.
-  final int stabilityThershold;
-
-  /// Critical packet loss percentage (0.0 - 100.0).
-  ///
-  /// If packet loss exceeds this value, the connection is immediately
-  /// downgraded to 'Unstable' or 'Offline'.
-  final double criticalPacketLossPrecent;
-
-  /// Balanced default resilience configuration.
-  ///
-  /// - Strategy: [`CheckStrategy::Race`] (optimized for speed)
-  /// - Jitter Samples: 5 (good balance of accuracy and speed)
-  /// - Circuit Breaker: Disabled by default.
-  static Future<ResilienceConfig> default_() =>
-      RustLib.instance.api.crateApiModelsConfigResilienceConfigDefault();
+class ResilienceConfig  {
+                /// How the results of several targets combine into one verdict per sample.
+final CheckStrategy strategy;
+/// Number of consecutive failures before the "Circuit Breaker" opens.
+///
+/// When the circuit breaker is 'Open', the engine stops sending probes to
+/// save resources and battery, assuming the network is definitely down.
+/// *Set to 0 to disable.*
+final int circuitBreakerThreshold;
+/// Duration (ms) the engine waits before attempting a "Half-Open" probe.
+///
+/// Once the circuit breaker is open, it waits for this cooldown before
+/// trying one more check to see if connectivity has returned.
+final BigInt circuitBreakerCooldownMs;
+/// Number of packets/samples to send per target for statistical analysis.
+///
+/// Higher values (e.g., 10+) provide extremely accurate jitter and packet loss
+/// metrics but increase the battery/data usage and duration of each check.
+/// *Minimum 2 required for jitter calculation.*
+final int numJitterSamples;
+/// Percentage threshold for jitter classification.
+///
+/// If the standard deviation of latency samples divided by the mean exceeds
+/// this percentage, the connection is marked as 'Unstable'.
+final double jitterThresholdPercent;
+/// Minimum stability score (0-100) required for a 'Stable' rating.
+///
+/// A score calculated from packet loss and jitter consistency.
+final int stabilityThershold;
+/// Critical packet loss percentage (0.0 - 100.0).
+///
+/// If packet loss exceeds this value, the connection is immediately
+/// downgraded to 'Unstable' or 'Offline'.
+final double criticalPacketLossPrecent;
+/// Whether samples run one after another or concurrently.
+final SampleMode sampleMode;
+/// Delay (ms) between the starts of consecutive samples in
+/// [SampleMode::Parallel]. Ignored by the other modes.
+final BigInt parallelSampleOffsetMs;
+/// A sample counts as a latency spike if it exceeds the median of the
+/// check by more than this factor (e.g. 2.0 = twice the median).
+///
+/// Set to 0 to disable spike detection.
+final double spikeRatioThreshold;
+/// Points subtracted from the stability score if at least one spike was
+/// detected in a check.
+final int spikePenalty;
+/// Consecutive app-reported failures (see
+/// [ReachabilityEngine::report_app_failure](crate::api::engine::ReachabilityEngine::report_app_failure))
+/// that invalidate a cached "connected" report.
+///
+/// *Set to 0 to ignore app-reported failures.*
+final int passiveFailureThreshold;
+/// How long (ms) an app-reported outcome counts as evidence.
+///
+/// A cached "connected" report confirmed by app successes is reused for up
+/// to this long, instead of only `cache_validity_ms`, saving probe traffic.
+final BigInt passiveSignalWindowMs;
+/// What a monitor does with the cycles that come due while a slow check
+/// is still running.
+final OverlapPolicy overlapPolicy;
+
+                const ResilienceConfig({required this.strategy ,required this.circuitBreakerThreshold ,required this.circuitBreakerCooldownMs ,required this.numJitterSamples ,required this.jitterThresholdPercent ,required this.stabilityThershold ,required this.criticalPacketLossPrecent ,required this.sampleMode ,required this.parallelSampleOffsetMs ,required this.spikeRatioThreshold ,required this.spikePenalty ,required this.passiveFailureThreshold ,required this.passiveSignalWindowMs ,required this.overlapPolicy ,});
+
+                /// Balanced default resilience configuration.
+///
+/// - Strategy: [`CheckStrategy::Race`] (optimized for speed)
+/// - Jitter Samples: 5 (good balance of accuracy and speed), run sequentially
+/// - Circuit Breaker: Disabled by default.
+static Future<ResilienceConfig>  default_()=>RustLib.instance.api.crateApiModelsConfigResilienceConfigDefault();
+
 
   // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
-  /// Creates a new custom [`ResilienceConfig`].
-  static Future<ResilienceConfig> newInstance({
-    required final CheckStrategy strategy,
-    required final int circuitBreakerThreshold,
-    required final BigInt circuitBreakerCooldownMs,
-    required final int numJitterSamples,
-    required final double jitterThresholdPercent,
-    required final int stabilityThershold,
-    required final double criticalPacketLossPrecent,
-  }) =>
-      RustLib.instance.api.crateApiModelsConfigResilienceConfigNew(
-        strategy: strategy,
-        circuitBreakerThreshold: circuitBreakerThreshold,
-        circuitBreakerCooldownMs: circuitBreakerCooldownMs,
-        numJitterSamples: numJitterSamples,
-        jitterThresholdPercent: jitterThresholdPercent,
-        stabilityThershold: stabilityThershold,
-        criticalPacketLossPrecent: criticalPacketLossPrecent,
-      );
-
-  @override
-  int get hashCode =>
-      strategy.hashCode ^
-      circuitBreakerThreshold.hashCode ^
-      circuitBreakerCooldownMs.hashCode ^
-      numJitterSamples.hashCode ^
-      jitterThresholdPercent.hashCode ^
-      stabilityThershold.hashCode ^
-      criticalPacketLossPrecent.hashCode;
-
-  @override
-  bool operator ==(final Object other) =>
-      identical(this, other) ||
-      other is ResilienceConfig &&
-          runtimeType == other.runtimeType &&
-          strategy == other.strategy &&
-          circuitBreakerThreshold == other.circuitBreakerThreshold &&
-          circuitBreakerCooldownMs == other.circuitBreakerCooldownMs &&
-          numJitterSamples == other.numJitterSamples &&
-          jitterThresholdPercent == other.jitterThresholdPercent &&
-          stabilityThershold == other.stabilityThershold &&
-          criticalPacketLossPrecent == other.criticalPacketLossPrecent;
-}
+/// Creates a new custom [`ResilienceConfig`].
+static Future<ResilienceConfig>  newInstance({required CheckStrategy strategy , required int circuitBreakerThreshold , required BigInt circuitBreakerCooldownMs , required int numJitterSamples , required double jitterThresholdPercent , required int stabilityThershold , required double criticalPacketLossPrecent })=>RustLib.instance.api.crateApiModelsConfigResilienceConfigNew(strategy: strategy, circuitBreakerThreshold: circuitBreakerThreshold, circuitBreakerCooldownMs: circuitBreakerCooldownMs, numJitterSamples: numJitterSamples, jitterThresholdPercent: jitterThresholdPercent, stabilityThershold: stabilityThershold, criticalPacketLossPrecent: criticalPacketLossPrecent);
+
+
+                
+
+                
+        @override
+        int get hashCode => strategy.hashCode^circuitBreakerThreshold.hashCode^circuitBreakerCooldownMs.hashCode^numJitterSamples.hashCode^jitterThresholdPercent.hashCode^stabilityThershold.hashCode^criticalPacketLossPrecent.hashCode^sampleMode.hashCode^parallelSampleOffsetMs.hashCode^spikeRatioThreshold.hashCode^spikePenalty.hashCode^passiveFailureThreshold.hashCode^passiveSignalWindowMs.hashCode^overlapPolicy.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ResilienceConfig &&
+                runtimeType == other.runtimeType
+                && strategy == other.strategy&& circuitBreakerThreshold == other.circuitBreakerThreshold&& circuitBreakerCooldownMs == other.circuitBreakerCooldownMs&& numJitterSamples == other.numJitterSamples&& jitterThresholdPercent == other.jitterThresholdPercent&& stabilityThershold == other.stabilityThershold&& criticalPacketLossPrecent == other.criticalPacketLossPrecent&& sampleMode == other.sampleMode&& parallelSampleOffsetMs == other.parallelSampleOffsetMs&& spikeRatioThreshold == other.spikeRatioThreshold&& spikePenalty == other.spikePenalty&& passiveFailureThreshold == other.passiveFailureThreshold&& passiveSignalWindowMs == other.passiveSignalWindowMs&& overlapPolicy == other.overlapPolicy;
+        
+            }
+
+@freezed
+                sealed class SampleMode with _$SampleMode  {
+                    const SampleMode._();
+
+                     /// Each sample starts after the previous one finished.
+///
+/// The check takes roughly `num_jitter_samples` round trips, but every
+/// sample sees an otherwise idle path, which gives the most faithful
+/// jitter figures.
+const factory SampleMode.sequential() = SampleMode_Sequential;
+ /// All samples run concurrently, each started
+/// [parallel_sample_offset_ms](ResilienceConfig::parallel_sample_offset_ms)
+/// after the previous one.
+///
+/// A 10-sample check completes in about one round-trip window. The samples
+/// compete with each other for the path, so jitter is less precise; use it
+/// when a quick answer matters more than latency fidelity.
+const factory SampleMode.parallel() = SampleMode_Parallel;
+ /// Samples start this many milliseconds apart, but never overlap.
+///
+/// The schedule is fixed from the start of the check: a sample that
+/// finishes early waits for its slot, one that overruns delays the next
+/// sample until it finished. Spreading samples over a known window
+/// catches periodic disturbances (e.g. Wi-Fi scans) that back-to-back
+/// samples miss, while each sample still has the path to itself.
+const factory SampleMode.paced(  BigInt field0,) = SampleMode_Paced;
+
+                    
+
+                    
+                }
 
 /// Configuration for security-related network checks and policy enforcement.
 ///
 /// These settings allow the engine to detect environmental factors that might
 /// be undesirable or indicate a compromised connection.
-class SecurityConfig {
-  const SecurityConfig({
-    required this.blockVpn,
-    required this.detectDnsHijack,
-  });
-
-  /// If enabled, the engine will flag connections that originate from a VPN interface.
-  ///
-  /// Useful for applications that enforce geo-fencing or need to prevent
-  /// identity masking.
-  final bool blockVpn;
-
-  /// If enabled, performs deep DNS validation to detect hijacking.
-  ///
-  /// The engine will compare the results of the local system resolver with
-  /// a trusted upstream resolver (like Cloudflare or Google). If they differ
-  /// significantly for static domains, it flags a potential spoofing attempt.
-  final bool detectDnsHijack;
-
-  static Future<SecurityConfig> default_() =>
-      RustLib.instance.api.crateApiModelsConfigSecurityConfigDefault();
-
-  @override
-  int get hashCode => blockVpn.hashCode ^ detectDnsHijack.hashCode;
-
-  @override
-  bool operator ==(final Object other) =>
-      identical(this, other) ||
-      other is SecurityConfig &&
-          runtimeType == other.runtimeType &&
-          blockVpn == other.blockVpn &&
-          detectDnsHijack == other.detectDnsHijack;
-}
+class SecurityConfig  {
+                /// If enabled, the engine will flag connections that originate from a VPN interface.
+///
+/// Useful for applications that enforce geo-fencing or need to prevent
+/// identity masking.
+final bool blockVpn;
+/// If enabled, performs deep DNS validation to detect hijacking.
+///
+/// The engine will compare the results of the local system resolver with
+/// a trusted upstream resolver (like Cloudflare or Google). If they differ
+/// significantly for static domains, it flags a potential spoofing attempt.
+final bool detectDnsHijack;
+/// Names of the interfaces traffic may leave through (e.g. `wlan0`,
+/// `en0`), enforced by [guard](crate::api::engine::guard).
+/// *Default: empty (any interface)*
+final List<String> allowedInterfaces;
+
+                const SecurityConfig({required this.blockVpn ,required this.detectDnsHijack ,required this.allowedInterfaces ,});
+
+                static Future<SecurityConfig>  default_()=>RustLib.instance.api.crateApiModelsConfigSecurityConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => blockVpn.hashCode^detectDnsHijack.hashCode^allowedInterfaces.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SecurityConfig &&
+                runtimeType == other.runtimeType
+                && blockVpn == other.blockVpn&& detectDnsHijack == other.detectDnsHijack&& allowedInterfaces == other.allowedInterfaces;
+        
+            }
+
+/// Settings for SNMP queries against managed network devices.
+///
+/// Only used when the crate is built with the `snmp` feature, by
+/// [PipelineStep::Snmp](crate::api::engine::pipeline::PipelineStep).
+class SnmpConfig  {
+                /// The SNMP v2c community string.
+/// *Default: "public"*
+final String community;
+/// The devices to query (IP addresses). Empty queries the default gateway.
+final List<String> hosts;
+/// The time budget (ms) per device.
+/// *Default: 2000*
+final BigInt timeoutMs;
+
+                const SnmpConfig({required this.community ,required this.hosts ,required this.timeoutMs ,});
+
+                static Future<SnmpConfig>  default_()=>RustLib.instance.api.crateApiModelsConfigSnmpConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => community.hashCode^hosts.hashCode^timeoutMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SnmpConfig &&
+                runtimeType == other.runtimeType
+                && community == other.community&& hosts == other.hosts&& timeoutMs == other.timeoutMs;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,639 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'diagnostics.freezed.dart';
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// A check whose latency or loss fell well outside the recent baseline.
+///
+/// Produced by [AnomalyDetector](crate::api::analysis::anomaly::AnomalyDetector).
+class AnomalyDetected  {
+                /// Which series was unusual.
+final AnomalyMetric metric;
+/// The `timestamp_ms` of the report that triggered the event.
+final BigInt timestampMs;
+/// The observed value (ms for latency, percent for loss).
+final double value;
+/// The smoothed baseline the value was compared against.
+final double expected;
+/// The smoothed standard deviation of the series.
+final double stdDev;
+/// How many standard deviations the value lies from the baseline.
+/// Positive means worse (higher) than usual.
+final double zScore;
+/// The connection type at the time, to tell e.g. a handover from congestion.
+final ConnectionType connectionType;
+
+                const AnomalyDetected({required this.metric ,required this.timestampMs ,required this.value ,required this.expected ,required this.stdDev ,required this.zScore ,required this.connectionType ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => metric.hashCode^timestampMs.hashCode^value.hashCode^expected.hashCode^stdDev.hashCode^zScore.hashCode^connectionType.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is AnomalyDetected &&
+                runtimeType == other.runtimeType
+                && metric == other.metric&& timestampMs == other.timestampMs&& value == other.value&& expected == other.expected&& stdDev == other.stdDev&& zScore == other.zScore&& connectionType == other.connectionType;
+        
+            }
+
+/// The time series an [AnomalyDetected] event refers to.
+enum AnomalyMetric {
+                    /// The representative latency of a check ([LatencyStats::latency_ms](super::report::LatencyStats)).
+latency,
+/// The packet loss of a check, in percent.
+packetLoss,
+                    ;
+                    /// The localization key, e.g. `anomaly_metric.packet_loss`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsDiagnosticsAnomalyMetricL10NKey(that: this, );
+
+
+                }
+
+/// Another interface currently offers a clearly better path than the one the
+/// OS routes through.
+///
+/// Produced by [better_path](crate::api::analysis::paths::better_path). Apps
+/// can suggest switching networks, or bind their own traffic to
+/// [better](Self::better).
+class BetterPathAvailable  {
+                /// The `timestamp_ms` of the check that compared the paths.
+final BigInt timestampMs;
+/// The path of the active interface.
+final PathMeasurement current;
+/// The better alternative.
+final PathMeasurement better;
+/// How much faster the alternative is (ms); `None` if the current path
+/// failed entirely.
+final PlatformInt64? latencyGainMs;
+
+                const BetterPathAvailable({required this.timestampMs ,required this.current ,required this.better ,this.latencyGainMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => timestampMs.hashCode^current.hashCode^better.hashCode^latencyGainMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is BetterPathAvailable &&
+                runtimeType == other.runtimeType
+                && timestampMs == other.timestampMs&& current == other.current&& better == other.better&& latencyGainMs == other.latencyGainMs;
+        
+            }
+
+/// A recurring range of hours during which the connection is noticeably worse.
+///
+/// E.g. `start_hour: 20, end_hour: 23` reads "degrades every evening 8-11 pm".
+class CongestionWindow  {
+                /// First hour of the window (0-23, local time).
+final int startHour;
+/// Hour the window ends, exclusive (1-24). May be lower than `start_hour`
+/// if the window wraps past midnight.
+final int endHour;
+/// Mean latency (ms) inside the window.
+final BigInt? avgLatencyMs;
+/// Mean latency (ms) of all connected reports, for comparison.
+final BigInt? baselineLatencyMs;
+/// Share (0.0 - 1.0) of degraded reports inside the window.
+final double degradedShare;
+/// Number of distinct days the window was observed on.
+final int daysObserved;
+
+                const CongestionWindow({required this.startHour ,required this.endHour ,this.avgLatencyMs ,this.baselineLatencyMs ,required this.degradedShare ,required this.daysObserved ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => startHour.hashCode^endHour.hashCode^avgLatencyMs.hashCode^baselineLatencyMs.hashCode^degradedShare.hashCode^daysObserved.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CongestionWindow &&
+                runtimeType == other.runtimeType
+                && startHour == other.startHour&& endHour == other.endHour&& avgLatencyMs == other.avgLatencyMs&& baselineLatencyMs == other.baselineLatencyMs&& degradedShare == other.degradedShare&& daysObserved == other.daysObserved;
+        
+            }
+
+/// How much of the end-to-end degradation happens on the first hop, between
+/// the device and its router (e.g. a poor Wi-Fi link), rather than beyond it.
+///
+/// A `variance_share` of 0.8 reads "80% of your latency variance happens
+/// between you and your router".
+///
+/// Produced by [attribute_degradation](crate::api::analysis::attribution::attribute_degradation).
+class DegradationAttribution  {
+                /// Mean round-trip time (ms) to the gateway.
+final BigInt gatewayLatencyMs;
+/// Standard deviation (ms) of the gateway round-trip times.
+final BigInt gatewayJitterMs;
+/// Share (0.0 - 100.0) of gateway probes that went unanswered.
+final double gatewayLossPercent;
+/// Share (0.0 - 1.0) of the end-to-end latency spent on the first hop.
+/// `None` if the end-to-end latency is unknown.
+final double? latencyShare;
+/// Share (0.0 - 1.0) of the end-to-end latency variance that the first
+/// hop accounts for. `None` if the end-to-end latency did not vary.
+final double? varianceShare;
+/// Share (0.0 - 1.0) of the end-to-end packet loss already seen on the
+/// first hop. `None` if nothing was lost end to end.
+final double? lossShare;
+/// The segment where most of the variance and loss arise:
+/// [PathSegment::Lan] for the first hop, [PathSegment::Isp] for everything
+/// beyond it. `None` if the connection showed neither.
+final PathSegment? dominantSegment;
+
+                const DegradationAttribution({required this.gatewayLatencyMs ,required this.gatewayJitterMs ,required this.gatewayLossPercent ,this.latencyShare ,this.varianceShare ,this.lossShare ,this.dominantSegment ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => gatewayLatencyMs.hashCode^gatewayJitterMs.hashCode^gatewayLossPercent.hashCode^latencyShare.hashCode^varianceShare.hashCode^lossShare.hashCode^dominantSegment.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DegradationAttribution &&
+                runtimeType == other.runtimeType
+                && gatewayLatencyMs == other.gatewayLatencyMs&& gatewayJitterMs == other.gatewayJitterMs&& gatewayLossPercent == other.gatewayLossPercent&& latencyShare == other.latencyShare&& varianceShare == other.varianceShare&& lossShare == other.lossShare&& dominantSegment == other.dominantSegment;
+        
+            }
+
+/// How widely the degradation in a column of a [LatencyHeatmap] spread.
+enum DegradationScope {
+                    /// No target was probed in the time bucket.
+noData,
+/// Every probed target performed as usual.
+normal,
+/// Some targets degraded while most performed as usual: a problem with
+/// those endpoints or the paths to them.
+endpointSpecific,
+/// Most targets degraded at once: a problem on the local network or the
+/// uplink. With a single target, any degradation counts as global.
+global,
+                    ;
+                    /// The localization key, e.g. `degradation_scope.endpoint_specific`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsDiagnosticsDegradationScopeL10NKey(that: this, );
+
+
+                }
+
+/// Probe results for one DSCP marking towards a single target.
+///
+/// Produced by [compare_dscp_markings](crate::api::probes::qos::compare_dscp_markings).
+class DscpLatency  {
+                /// The DSCP code point the probes were marked with (0 = best effort).
+final int dscp;
+/// Probes sent with this marking.
+final int samples;
+/// Probes that succeeded.
+final int successes;
+/// Median latency of the successful probes.
+final BigInt? medianLatencyMs;
+/// Share of failed probes, in percent.
+final double lossPercent;
+/// Median latency minus that of the unmarked baseline; negative means
+/// the marking is served faster.
+final PlatformInt64? deltaMs;
+
+                const DscpLatency({required this.dscp ,required this.samples ,required this.successes ,this.medianLatencyMs ,required this.lossPercent ,this.deltaMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => dscp.hashCode^samples.hashCode^successes.hashCode^medianLatencyMs.hashCode^lossPercent.hashCode^deltaMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is DscpLatency &&
+                runtimeType == other.runtimeType
+                && dscp == other.dscp&& samples == other.samples&& successes == other.successes&& medianLatencyMs == other.medianLatencyMs&& lossPercent == other.lossPercent&& deltaMs == other.deltaMs;
+        
+            }
+
+/// What the crate can do on the current platform, and why some report fields
+/// stay empty.
+///
+/// Produced by [`environment_report`](crate::api::engine::environment::environment_report).
+class EnvironmentReport  {
+                /// The crate version, e.g. `0.1.0`.
+final String crateVersion;
+/// The operating system, as in [`std::env::consts::OS`] (e.g. `linux`).
+final String os;
+/// The CPU architecture, as in [`std::env::consts::ARCH`] (e.g. `aarch64`).
+final String arch;
+/// The container or WSL environment the process runs in.
+final RuntimeEnvironment runtime;
+/// The raw-socket based probe modes the process may use.
+final ProbeCapabilities capabilities;
+/// An interface has a non-loopback IPv4 address.
+final bool hasIpv4;
+/// An interface has an IPv6 address beyond loopback and link-local.
+final bool hasIpv6;
+/// The optional Cargo features the crate was built with (`serde`, `snmp`,
+/// `testsupport`, `bench`, `fuzzing`).
+final List<String> features;
+/// What the missing capabilities disable, one entry per gap.
+final List<Limitation> limitations;
+
+                const EnvironmentReport({required this.crateVersion ,required this.os ,required this.arch ,required this.runtime ,required this.capabilities ,required this.hasIpv4 ,required this.hasIpv6 ,required this.features ,required this.limitations ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => crateVersion.hashCode^os.hashCode^arch.hashCode^runtime.hashCode^capabilities.hashCode^hasIpv4.hashCode^hasIpv6.hashCode^features.hashCode^limitations.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EnvironmentReport &&
+                runtimeType == other.runtimeType
+                && crateVersion == other.crateVersion&& os == other.os&& arch == other.arch&& runtime == other.runtime&& capabilities == other.capabilities&& hasIpv4 == other.hasIpv4&& hasIpv6 == other.hasIpv6&& features == other.features&& limitations == other.limitations;
+        
+            }
+
+/// Latency per target and time bucket, ready to render as a heatmap.
+///
+/// Rows are targets and columns are time buckets, so `latency_ms[row][column]`
+/// is the cell of `targets[row]` starting at `bucket_starts_ms[column]`.
+///
+/// Produced by [latency_heatmap](crate::api::analysis::heatmap::latency_heatmap).
+class LatencyHeatmap  {
+                /// The target labels, one per row, in order of first appearance.
+final List<String> targets;
+/// Width (ms) of every column.
+final BigInt bucketMs;
+/// Start (Unix ms) of every column. Columns run contiguously from the
+/// oldest report to the newest, so gaps in the history stay visible as
+/// empty columns.
+final Uint64List bucketStartsMs;
+/// Mean latency (ms) of the successful probes of each cell; `None` if
+/// none succeeded.
+final List<List<BigInt?>> latencyMs;
+/// Share (0.0 - 1.0) of failed probes of each cell; `None` if the target
+/// was not probed in that bucket.
+final List<List<double?>> failureShare;
+/// Whether the degradation of each column was global or confined to
+/// some targets.
+final List<DegradationScope> scope;
+
+                const LatencyHeatmap({required this.targets ,required this.bucketMs ,required this.bucketStartsMs ,required this.latencyMs ,required this.failureShare ,required this.scope ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => targets.hashCode^bucketMs.hashCode^bucketStartsMs.hashCode^latencyMs.hashCode^failureShare.hashCode^scope.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LatencyHeatmap &&
+                runtimeType == other.runtimeType
+                && targets == other.targets&& bucketMs == other.bucketMs&& bucketStartsMs == other.bucketStartsMs&& latencyMs == other.latencyMs&& failureShare == other.failureShare&& scope == other.scope;
+        
+            }
+
+/// A gap in an [EnvironmentReport] and the report fields it affects.
+///
+/// Shown to users through its [l10n key](super::l10n) rather than English text.
+enum Limitation {
+                    /// ICMP sockets are not permitted (needs `net.ipv4.ping_group_range` or
+/// `CAP_NET_RAW`): ICMP targets fall back to TCP ping.
+noIcmpSockets,
+/// Raw sockets are not permitted: traceroutes use UDP instead of ICMP.
+udpTracerouteOnly,
+/// Neither raw nor UDP traceroute sockets are available: traceroutes only
+/// show the final hop.
+noTraceroute,
+/// The neighbor table is not readable: MAC addresses and vendors of the
+/// gateway and scanned devices stay empty.
+noNeighborTable,
+/// `TCP_INFO` is Linux-only: ECN and MSS clamping detection are
+/// unavailable.
+noTcpInfo,
+/// No interface has an IPv4 address: IPv4 targets cannot be reached.
+noIpv4,
+/// No interface has a routable IPv6 address: IPv6 targets cannot be
+/// reached and address family comparisons find no IPv6 path.
+noIpv6,
+/// Running in a container or WSL: the connection type and security flags
+/// describe the virtual link, not the host's network.
+virtualized,
+/// Built without the `snmp` feature: `NetworkReport::snmp_devices` stays
+/// empty.
+noSnmp,
+                    ;
+                    /// The localization key, e.g. `limitation.no_neighbor_table`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsDiagnosticsLimitationL10NKey(that: this, );
+
+
+                }
+
+/// How the internet looks through one network interface.
+///
+/// Produced by [measure_paths](crate::api::probes::paths::measure_paths).
+class PathMeasurement  {
+                /// The interface the probes were bound to (e.g. `wlan0`, `rmnet0`).
+final String interfaceName;
+/// The interface's type, from its name.
+final ConnectionType connectionType;
+/// The local address the probes were sent from.
+final String localAddress;
+/// Median TCP handshake time (ms); `None` if every handshake failed.
+final BigInt? latencyMs;
+/// Share of failed handshakes, in percent.
+final double packetLossPercent;
+
+                const PathMeasurement({required this.interfaceName ,required this.connectionType ,required this.localAddress ,this.latencyMs ,required this.packetLossPercent ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => interfaceName.hashCode^connectionType.hashCode^localAddress.hashCode^latencyMs.hashCode^packetLossPercent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PathMeasurement &&
+                runtimeType == other.runtimeType
+                && interfaceName == other.interfaceName&& connectionType == other.connectionType&& localAddress == other.localAddress&& latencyMs == other.latencyMs&& packetLossPercent == other.packetLossPercent;
+        
+            }
+
+/// The answer to "is this connection good enough for X?".
+///
+/// Produced by [preflight](crate::api::engine::preflight::preflight).
+class PreflightReport  {
+                /// True if every requirement was met.
+final bool passed;
+/// The requirements that were not met, in declaration order.
+final List<UnmetRequirement> unmet;
+/// Measured download throughput in Mbit/s, if it was required.
+final double? downMbps;
+/// Measured latency in milliseconds, if latency or loss was required and
+/// any target answered.
+final BigInt? latencyMs;
+/// Measured packet loss in percent, if latency or loss was required.
+final double? packetLossPercent;
+/// How long the preflight took, in milliseconds.
+final BigInt durationMs;
+
+                const PreflightReport({required this.passed ,required this.unmet ,this.downMbps ,this.latencyMs ,this.packetLossPercent ,required this.durationMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => passed.hashCode^unmet.hashCode^downMbps.hashCode^latencyMs.hashCode^packetLossPercent.hashCode^durationMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PreflightReport &&
+                runtimeType == other.runtimeType
+                && passed == other.passed&& unmet == other.unmet&& downMbps == other.downMbps&& latencyMs == other.latencyMs&& packetLossPercent == other.packetLossPercent&& durationMs == other.durationMs;
+        
+            }
+
+/// The device moved to another access point between two checks.
+///
+/// Produced by [detect_roam](crate::api::analysis::roaming::detect_roam).
+class RoamEvent  {
+                /// The `timestamp_ms` of the first report on the new access point.
+final BigInt timestampMs;
+/// The network roamed within, if known.
+final String? ssid;
+/// The BSSID of the previous access point.
+final String fromBssid;
+/// The BSSID of the new access point.
+final String toBssid;
+/// Representative latency of the last check before the handover.
+final BigInt latencyBeforeMs;
+/// Representative latency of the first check after it.
+final BigInt latencyAfterMs;
+/// Whether the handover coincided with a latency spike or an outage,
+/// the signature of sticky-client or slow roaming.
+final bool latencySpike;
+
+                const RoamEvent({required this.timestampMs ,this.ssid ,required this.fromBssid ,required this.toBssid ,required this.latencyBeforeMs ,required this.latencyAfterMs ,required this.latencySpike ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => timestampMs.hashCode^ssid.hashCode^fromBssid.hashCode^toBssid.hashCode^latencyBeforeMs.hashCode^latencyAfterMs.hashCode^latencySpike.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RoamEvent &&
+                runtimeType == other.runtimeType
+                && timestampMs == other.timestampMs&& ssid == other.ssid&& fromBssid == other.fromBssid&& toBssid == other.toBssid&& latencyBeforeMs == other.latencyBeforeMs&& latencyAfterMs == other.latencyAfterMs&& latencySpike == other.latencySpike;
+        
+            }
+
+/// Aggregated check results for one hour of the day or one day of the week.
+class TimeBucket  {
+                /// The hour (0-23) or weekday (0 = Monday ... 6 = Sunday), in local time.
+final int index;
+/// Number of reports that fell into the bucket.
+final int reports;
+/// Mean latency (ms) of the connected reports, or `None` if there were none.
+final BigInt? avgLatencyMs;
+/// Mean packet loss (percent) over all reports.
+final double avgPacketLossPercent;
+/// Share (0.0 - 1.0) of reports that were offline, poor or unstable.
+final double degradedShare;
+
+                const TimeBucket({required this.index ,required this.reports ,this.avgLatencyMs ,required this.avgPacketLossPercent ,required this.degradedShare ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^reports.hashCode^avgLatencyMs.hashCode^avgPacketLossPercent.hashCode^degradedShare.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TimeBucket &&
+                runtimeType == other.runtimeType
+                && index == other.index&& reports == other.reports&& avgLatencyMs == other.avgLatencyMs&& avgPacketLossPercent == other.avgPacketLossPercent&& degradedShare == other.degradedShare;
+        
+            }
+
+/// Latency and quality bucketed by local time, built from a report history.
+///
+/// Produced by [profile_time_of_day](crate::api::analysis::time_of_day::profile_time_of_day).
+class TimeOfDayProfile  {
+                /// 24 buckets, hour 0 first.
+final List<TimeBucket> byHour;
+/// 7 buckets, Monday first.
+final List<TimeBucket> byWeekday;
+/// Recurring bad hours, earliest start first.
+final List<CongestionWindow> congestionWindows;
+
+                const TimeOfDayProfile({required this.byHour ,required this.byWeekday ,required this.congestionWindows ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => byHour.hashCode^byWeekday.hashCode^congestionWindows.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TimeOfDayProfile &&
+                runtimeType == other.runtimeType
+                && byHour == other.byHour&& byWeekday == other.byWeekday&& congestionWindows == other.congestionWindows;
+        
+            }
+
+@freezed
+                sealed class UnmetRequirement with _$UnmetRequirement  {
+                    const UnmetRequirement._();
+
+                     /// Download throughput below `min_down_mbps`.
+const factory UnmetRequirement.download({   required double requiredMbps ,  double? measuredMbps , }) = UnmetRequirement_Download;
+ /// Latency above `max_latency_ms`, or no target answered.
+const factory UnmetRequirement.latency({   required BigInt maxMs ,  BigInt? measuredMs , }) = UnmetRequirement_Latency;
+ /// Packet loss above `max_loss_percent`.
+const factory UnmetRequirement.packetLoss({   required double maxPercent ,  required double measuredPercent , }) = UnmetRequirement_PacketLoss;
+ /// UDP traffic did not get through.
+const factory UnmetRequirement.udp() = UnmetRequirement_Udp;
+ /// The WebSocket upgrade was refused or stripped on the way.
+const factory UnmetRequirement.webSocket() = UnmetRequirement_WebSocket;
+
+                    
+
+                    /// The localization key, e.g. `requirement.websocket`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsDiagnosticsUnmetRequirementL10NKey(that: this, );
+
+
+                }
+
+/// The result of comparing two reports captured before and after a VPN toggle.
+///
+/// Produced by [`compare_vpn_toggle`](crate::api::engine::vpn::compare_vpn_toggle).
+/// All deltas are expressed as `after - before`.
+class VpnComparison  {
+                /// True if a VPN interface was active when the baseline was captured.
+final bool vpnBefore;
+/// True if a VPN interface was active when the second report was captured.
+final bool vpnAfter;
+/// True if the VPN state actually changed between the two captures.
+///
+/// If this is false, the user most likely did not toggle the VPN within the
+/// allotted wait window and the remaining fields describe noise only.
+final bool toggleObserved;
+/// Change of the representative latency, in milliseconds.
+final PlatformInt64 latencyDeltaMs;
+/// Change of the jitter (standard deviation), in milliseconds.
+final PlatformInt64 jitterDeltaMs;
+/// Change of the packet loss, in percentage points.
+final double packetLossDeltaPercent;
+/// The active interface before the toggle (e.g. `wlan0`).
+final String interfaceBefore;
+/// The active interface after the toggle (e.g. `tun0`).
+final String interfaceAfter;
+/// The connection type before the toggle.
+final ConnectionType connectionTypeBefore;
+/// The connection type after the toggle.
+final ConnectionType connectionTypeAfter;
+/// True if traffic leaves through a different interface after the toggle.
+final bool routeChanged;
+/// The system DNS resolvers before the toggle.
+final List<String> resolversBefore;
+/// The system DNS resolvers after the toggle.
+final List<String> resolversAfter;
+/// True if the set of system DNS resolvers changed.
+final bool dnsResolverChanged;
+/// True if the VPN is up but DNS queries still go to the pre-VPN resolvers.
+///
+/// This is the classic "DNS leak": the tunnel carries the traffic, but
+/// name resolution still reveals browsing activity to the local network/ISP.
+final bool dnsLeakSuspected;
+/// True if the VPN is up but the active route does not use the tunnel interface.
+final bool routeLeakSuspected;
+
+                const VpnComparison({required this.vpnBefore ,required this.vpnAfter ,required this.toggleObserved ,required this.latencyDeltaMs ,required this.jitterDeltaMs ,required this.packetLossDeltaPercent ,required this.interfaceBefore ,required this.interfaceAfter ,required this.connectionTypeBefore ,required this.connectionTypeAfter ,required this.routeChanged ,required this.resolversBefore ,required this.resolversAfter ,required this.dnsResolverChanged ,required this.dnsLeakSuspected ,required this.routeLeakSuspected ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => vpnBefore.hashCode^vpnAfter.hashCode^toggleObserved.hashCode^latencyDeltaMs.hashCode^jitterDeltaMs.hashCode^packetLossDeltaPercent.hashCode^interfaceBefore.hashCode^interfaceAfter.hashCode^connectionTypeBefore.hashCode^connectionTypeAfter.hashCode^routeChanged.hashCode^resolversBefore.hashCode^resolversAfter.hashCode^dnsResolverChanged.hashCode^dnsLeakSuspected.hashCode^routeLeakSuspected.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is VpnComparison &&
+                runtimeType == other.runtimeType
+                && vpnBefore == other.vpnBefore&& vpnAfter == other.vpnAfter&& toggleObserved == other.toggleObserved&& latencyDeltaMs == other.latencyDeltaMs&& jitterDeltaMs == other.jitterDeltaMs&& packetLossDeltaPercent == other.packetLossDeltaPercent&& interfaceBefore == other.interfaceBefore&& interfaceAfter == other.interfaceAfter&& connectionTypeBefore == other.connectionTypeBefore&& connectionTypeAfter == other.connectionTypeAfter&& routeChanged == other.routeChanged&& resolversBefore == other.resolversBefore&& resolversAfter == other.resolversAfter&& dnsResolverChanged == other.dnsResolverChanged&& dnsLeakSuspected == other.dnsLeakSuspected&& routeLeakSuspected == other.routeLeakSuspected;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,110 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+import 'report.dart';
+part 'error.freezed.dart';
+
+            // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `ConfigError`, `ParseEnumError`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`, `from`, `from`, `from`
+
+
+            
+
+            @freezed
+                sealed class NetworkError with _$NetworkError implements FrbException {
+                    const NetworkError._();
+
+                     /// Failed to resolve the target hostname to an IP address.
+const factory NetworkError.dnsResolutionError(  String field0,) = NetworkError_DnsResolutionError;
+ /// A general connection failure at the transport or network layer.
+const factory NetworkError.connectionError(  String field0,) = NetworkError_ConnectionError;
+ /// The operation exceeded the allocated [`NetworkTarget::timeout_ms`].
+const factory NetworkError.timeoutError() = NetworkError_TimeoutError;
+ /// An unexpected or unhandled error occurred within the engine.
+const factory NetworkError.unknownError(  String field0,) = NetworkError_UnknownError;
+ /// The OS reported an ICMP "Destination Unreachable" for a UDP probe.
+const factory NetworkError.unreachable(  IcmpUnreachable field0,) = NetworkError_Unreachable;
+ /// The connection breaks the configured [`SecurityConfig`](super::SecurityConfig).
+const factory NetworkError.policyViolation(  PolicyViolation field0,) = NetworkError_PolicyViolation;
+ /// The request cannot be carried out as configured, e.g. an unparsable
+/// subnet or a target without a host.
+const factory NetworkError.invalidConfiguration(  String field0,) = NetworkError_InvalidConfiguration;
+ /// A probe could not be run at all (as opposed to running and finding the
+/// network down), e.g. its HTTP client failed to initialize.
+const factory NetworkError.probeFailed(  String field0,) = NetworkError_ProbeFailed;
+
+                    
+
+                    /// The localization key of the error category, e.g. `error.timeout`.
+///
+/// The detail message carried by some variants is diagnostic text from the
+/// OS and is not localized.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsErrorNetworkErrorL10NKey(that: this, );
+
+
+                }
+
+@freezed
+                sealed class PolicyViolation with _$PolicyViolation  {
+                    const PolicyViolation._();
+
+                     /// A VPN is active while [`block_vpn`](super::SecurityConfig::block_vpn) is set.
+const factory PolicyViolation.vpnDetected() = PolicyViolation_VpnDetected;
+ /// The active interface, named here, is not in
+/// [`allowed_interfaces`](super::SecurityConfig::allowed_interfaces).
+const factory PolicyViolation.interfaceNotAllowed(  String field0,) = PolicyViolation_InterfaceNotAllowed;
+ /// The system resolver's answers were flagged as spoofed.
+const factory PolicyViolation.dnsSpoofed() = PolicyViolation_DnsSpoofed;
+
+                    
+
+                    /// The localization key, e.g. `policy.dns_spoofed`.
+///
+/// [NetworkError::l10n_key] keeps its own `error.policy_*` keys for the
+/// error that wraps a violation.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsErrorPolicyViolationL10NKey(that: this, );
+
+
+                }
+
+@freezed
+                sealed class ValidationIssue with _$ValidationIssue  {
+                    const ValidationIssue._();
+
+                     /// The target at this index of `targets` has an empty host.
+const factory ValidationIssue.emptyHost({   required BigInt target , }) = ValidationIssue_EmptyHost;
+ /// The target at this index has a timeout of 0 or above
+/// [`MAX_TARGET_TIMEOUT_MS`](crate::api::constants::LibConstants::MAX_TARGET_TIMEOUT_MS).
+const factory ValidationIssue.invalidTimeout({   required BigInt target ,  required BigInt timeoutMs , }) = ValidationIssue_InvalidTimeout;
+ /// The quality thresholds are not ascending from `excellent` to `poor`.
+const factory ValidationIssue.thresholdsNotMonotonic() = ValidationIssue_ThresholdsNotMonotonic;
+ /// [`CheckStrategy::Quorum`](super::CheckStrategy::Quorum) asks for 0 or
+/// more successes than there are targets.
+const factory ValidationIssue.invalidQuorum({   required int quorum ,  required BigInt targets , }) = ValidationIssue_InvalidQuorum;
+ /// [`CheckStrategy::Weighted`](super::CheckStrategy::Weighted) is used but
+/// every target has a weight of 0.
+const factory ValidationIssue.zeroTotalWeight() = ValidationIssue_ZeroTotalWeight;
+ /// The [dns_query_name](super::NetworkTarget::dns_query_name) of the
+/// target at this index has a label over 63 bytes or is over 255 bytes
+/// in total.
+const factory ValidationIssue.invalidDnsQueryName({   required BigInt target , }) = ValidationIssue_InvalidDnsQueryName;
+ /// An [`EndpointConfig`](super::EndpointConfig) field is not a usable
+/// HTTP(S) URL, host or `ip:port` socket address.
+const factory ValidationIssue.invalidEndpoint({   required String field ,  required String value , }) = ValidationIssue_InvalidEndpoint;
+
+                    
+
+                    /// The localization key, e.g. `validation.invalid_quorum`.
+///
+/// The target index, field name and rejected value are left for the UI
+/// to fill into the message.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsErrorValidationIssueL10NKey(that: this, );
+
+
+                }
+            
\ No newline at end of file
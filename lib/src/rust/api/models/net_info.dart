@@ -1,162 +1,1251 @@
 // This file is automatically generated, so please do not edit it.
 // @generated by `flutter_rust_bridge`@ 2.12.0.
 
-// ignore_for_file: public_member_api_docs, invalid_use_of_internal_member, unused_import, unnecessary_import
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import '../../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-import '../../frb_generated.dart';
 
-// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `TraceHop`
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+            // These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `BroadcastReply`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `cmp`, `cmp`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `partial_cmp`, `partial_cmp`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SecurityFlagsResult>>
+                abstract class SecurityFlagsResult implements RustOpaqueInterface {
+                     Evidence get dnsSpoofEvidence;
+
+
+ String get interfaceName;
+
+
+ bool get isDnsSpoofed;
+
+
+ bool get isProxyDetected;
+
+
+ bool get isVpnDetected;
+
+
+ Evidence get proxyEvidence;
+
+
+ Evidence get vpnEvidence;
+
+
+  set dnsSpoofEvidence(Evidence dnsSpoofEvidence);
+
+
+  set interfaceName(String interfaceName);
+
+
+  set isDnsSpoofed(bool isDnsSpoofed);
+
+
+  set isProxyDetected(bool isProxyDetected);
+
+
+  set isVpnDetected(bool isVpnDetected);
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<SecurityFlagsResult>>
-abstract class SecurityFlagsResult implements RustOpaqueInterface {
-  String get interfaceName;
 
-  bool get isDnsSpoofed;
+  set proxyEvidence(Evidence proxyEvidence);
 
-  bool get isProxyDetected;
 
-  bool get isVpnDetected;
+  set vpnEvidence(Evidence vpnEvidence);
 
-  set interfaceName(final String interfaceName);
 
-  set isDnsSpoofed(final bool isDnsSpoofed);
+/// Returns a "Safe/Clean" default state where no security issues are detected.
+static Future<SecurityFlagsResult>  default_()=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultDefault();
 
-  set isProxyDetected(final bool isProxyDetected);
 
-  set isVpnDetected(final bool isVpnDetected);
 
-  /// Returns a "Safe/Clean" default state where no security issues are detected.
-  static Future<SecurityFlagsResult> default_() =>
-      RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultDefault();
-}
+                    
+                }
+                
+
+/// The interface type of an Apple `NWPath` (mirrors `NWInterface.InterfaceType`).
+enum ApplePathInterfaceType {
+                    /// A Wi-Fi link.
+wifi,
+/// A cellular link.
+cellular,
+/// A wired Ethernet link.
+wiredEthernet,
+/// The local loopback interface.
+loopback,
+/// Any other type, including VPN (`utun`) and virtual interfaces.
+other,
+                    ;
+                    
+                }
+
+/// A snapshot of the OS network path on macOS/iOS, as reported by
+/// `NWPathMonitor` (or `SCNetworkReachability` on older systems).
+///
+/// The host application pushes these via
+/// [update_apple_path](crate::api::probes::apple_path::update_apple_path),
+/// which installs them as [PlatformHints].
+class ApplePathSnapshot  {
+                /// Whether the path is usable.
+final ApplePathStatus status;
+/// The type of the interface the path currently uses.
+final ApplePathInterfaceType interfaceType;
+/// The BSD name of that interface (e.g. `en0`, `pdp_ip0`, `utun3`).
+final String interfaceName;
+/// True if the path is considered expensive (cellular, personal hotspot).
+final bool isExpensive;
+/// True if the path is in Low Data Mode.
+final bool isConstrained;
+/// True if the path is routed through a VPN (e.g. `utun` with a
+/// `NEVPNManager`/`NETunnelProvider` tunnel up).
+final bool usesVpn;
+
+                const ApplePathSnapshot({required this.status ,required this.interfaceType ,required this.interfaceName ,required this.isExpensive ,required this.isConstrained ,required this.usesVpn ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => status.hashCode^interfaceType.hashCode^interfaceName.hashCode^isExpensive.hashCode^isConstrained.hashCode^usesVpn.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ApplePathSnapshot &&
+                runtimeType == other.runtimeType
+                && status == other.status&& interfaceType == other.interfaceType&& interfaceName == other.interfaceName&& isExpensive == other.isExpensive&& isConstrained == other.isConstrained&& usesVpn == other.usesVpn;
+        
+            }
+
+/// Whether an Apple `NWPath` can currently be used (mirrors `NWPath.Status`).
+enum ApplePathStatus {
+                    /// The path is usable.
+satisfied,
+/// The path is not usable (e.g. airplane mode, no interface).
+unsatisfied,
+/// The path is not currently usable but may become so after a connection
+/// attempt (e.g. on-demand VPN, dial-up cellular).
+requiresConnection,
+                    ;
+                    
+                }
 
 /// The result of a captive portal detection probe.
 ///
-/// Captive portals (intercepting gateways) are common in public spaces like
-/// airports and cafes. They appear as "connected" to the OS but block all
+/// Captive portals (intercepting gateways) are common in public spaces like 
+/// airports and cafes. They appear as "connected" to the OS but block all 
 /// non-authentication traffic.
-class CaptivePortalStatus {
-  const CaptivePortalStatus({
-    required this.isCaptivePortal,
-    this.redirectUrl,
-  });
-
-  /// True if the engine detected that HTTP requests are being redirected.
-  ///
-  /// If this is true, the `ConnectionQuality` will likely be set to
-  /// [`CaptivePortal`](super::config::ConnectionQuality::CaptivePortal).
-  final bool isCaptivePortal;
-
-  /// The destination URL where the traffic was intercepted.
-  ///
-  /// If available, this is usually the login or "Terms of Service" page.
-  /// The application can use this to open a WebView for the user.
-  final String? redirectUrl;
-
-  @override
-  int get hashCode => isCaptivePortal.hashCode ^ redirectUrl.hashCode;
-
-  @override
-  bool operator ==(final Object other) =>
-      identical(this, other) ||
-      other is CaptivePortalStatus &&
-          runtimeType == other.runtimeType &&
-          isCaptivePortal == other.isCaptivePortal &&
-          redirectUrl == other.redirectUrl;
-}
+class CaptivePortalStatus  {
+                /// True if the engine detected that HTTP requests are being redirected.
+///
+/// If this is true, the `ConnectionQuality` will likely be set to 
+/// [`CaptivePortal`](super::config::ConnectionQuality::CaptivePortal).
+final bool isCaptivePortal;
+/// The destination URL where the traffic was intercepted.
+///
+/// If available, this is usually the login or "Terms of Service" page. 
+/// The application can use this to open a WebView for the user.
+final String? redirectUrl;
+/// How sure the probe is about `is_captive_portal`, and why.
+final Evidence evidence;
+/// The `<title>` of the portal page, e.g. "Hotel Guest Wi-Fi".
+final String? pageTitle;
+/// What the portal page asks of the user. [PortalLoginKind::Unknown]
+/// if no portal was detected or its page could not be read.
+final PortalLoginKind loginKind;
+/// Whether the page embeds WISPr XML (`WISPAccessGatewayParam`), which
+/// allows logging in without showing the page.
+final bool wispr;
+
+                const CaptivePortalStatus({required this.isCaptivePortal ,this.redirectUrl ,required this.evidence ,this.pageTitle ,required this.loginKind ,required this.wispr ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => isCaptivePortal.hashCode^redirectUrl.hashCode^evidence.hashCode^pageTitle.hashCode^loginKind.hashCode^wispr.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is CaptivePortalStatus &&
+                runtimeType == other.runtimeType
+                && isCaptivePortal == other.isCaptivePortal&& redirectUrl == other.redirectUrl&& evidence == other.evidence&& pageTitle == other.pageTitle&& loginKind == other.loginKind&& wispr == other.wispr;
+        
+            }
+
+/// How much a probe trusts one of its findings.
+///
+/// Heuristics such as interface-name matching can be wrong in both directions,
+/// so every security flag and captive portal verdict carries one of these.
+enum Confidence {
+                    /// A guess, or the probe could not complete. Do not act on it alone.
+low,
+/// Backed by a heuristic that is usually, but not always, right.
+medium,
+/// Backed by the OS, the host application or an unambiguous response.
+high,
+                    ;
+                    /// The localization key, e.g. `confidence.medium`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsNetInfoConfidenceL10NKey(that: this, );
+
+
+                }
 
 /// Represents the physical or logical medium of the active network connection.
 ///
-/// Understanding the `ConnectionType` is critical for bandwidth management
+/// Understanding the `ConnectionType` is critical for bandwidth management 
 /// (e.g., deferring large downloads on Cellular) and optimizing latency.
 enum ConnectionType {
-  /// Connected via a wireless local area network (802.11 WiFi).
-  /// Typically implies higher bandwidth and lower cost than cellular.
-  wifi,
+                    /// Connected via a wireless local area network (802.11 WiFi).
+/// Typically implies higher bandwidth and lower cost than cellular.
+wifi,
+/// Connected via a mobile carrier network (e.g., LTE, 5G, 3G).
+/// Usually implies metered data and potentially higher latency.
+cellular,
+/// Connected via a physical RJ45 Ethernet cable.
+/// The most stable and high-performance connection type.
+ethernet,
+/// Connected through a Virtual Private Network (VPN) tunnel.
+/// Indicates that traffic is being routed through an encrypted tunnel 
+/// which might mask the true underlying connection type (WiFi/Cellular).
+vpn,
+/// Connected via Bluetooth tethering or a personal area network (PAN).
+/// Characterized by very low bandwidth and limited range.
+bluetooth,
+/// A local loopback interface (typically 127.0.0.1).
+/// Indicates the device is communicating with itself; no external internet.
+loopback,
+/// The connection type could not be identified by the OS or the engine.
+/// This is the fallback for unknown or experimental interface types.
+unknown,
+                    ;
+                    /// Returns [`ConnectionType::Unknown`] as the default state.
+static Future<ConnectionType>  default_()=>RustLib.instance.api.crateApiModelsNetInfoConnectionTypeDefault();
+
+
+/// The localization key, e.g. `connection_type.wifi`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsNetInfoConnectionTypeL10NKey(that: this, );
+
+
+                }
+
+/// What happened to the ECN bits of a marked UDP probe on its way to a
+/// reflection server.
+enum EcnMarking {
+                    /// The probe arrived with the ECN-capable mark it was sent with.
+preserved,
+/// A router marked the probe Congestion Experienced: ECN is not only
+/// preserved but actively used on the path.
+congestionExperienced,
+/// The mark was cleared on the way ("bleached"); ECN cannot work.
+bleached,
+/// No reflection server reported the TOS byte it saw.
+notTested,
+                    ;
+                    /// The localization key, e.g. `ecn.bleached`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsNetInfoEcnMarkingL10NKey(that: this, );
+
+
+                }
+
+/// Whether TCP ECN negotiation with one server succeeded.
+class EcnObservation  {
+                /// The server as passed in (`host:port`).
+final String server;
+/// True if the handshake negotiated ECN; `None` if the connection or
+/// the measurement failed.
+final bool? negotiated;
+/// Why the connection or measurement failed, if it did.
+final String? error;
+
+                const EcnObservation({required this.server ,this.negotiated ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => server.hashCode^negotiated.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EcnObservation &&
+                runtimeType == other.runtimeType
+                && server == other.server&& negotiated == other.negotiated&& error == other.error;
+        
+            }
+
+/// ECN capability of the path.
+///
+/// Produced by [detect_ecn](crate::api::probes::ecn::detect_ecn).
+class EcnReport  {
+                /// Whether the OS requests ECN on outgoing TCP connections; `None` if
+/// unknown. If it does not, `tcp` can only show the servers' side.
+final bool? osRequestsEcn;
+/// One entry per TCP reference server.
+final List<EcnObservation> tcp;
+/// The fate of an ECN-capable UDP probe.
+final EcnMarking udpMarking;
+/// True if ECN was negotiated with any server or the UDP mark survived.
+final bool ecnCapable;
+
+                const EcnReport({this.osRequestsEcn ,required this.tcp ,required this.udpMarking ,required this.ecnCapable ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => osRequestsEcn.hashCode^tcp.hashCode^udpMarking.hashCode^ecnCapable.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EcnReport &&
+                runtimeType == other.runtimeType
+                && osRequestsEcn == other.osRequestsEcn&& tcp == other.tcp&& udpMarking == other.udpMarking&& ecnCapable == other.ecnCapable;
+        
+            }
+
+/// Encapsulation on the path, inferred from how far the TCP MSS falls
+/// short of the Ethernet default.
+enum Encapsulation {
+                    /// Full-size segments: no extra headers on the path.
+none,
+/// 8 bytes of overhead, as added by PPPoE on DSL lines.
+pppoe,
+/// 20 bytes or more of overhead, typical of VPNs and other tunnels
+/// (IPsec, WireGuard, GRE, ...).
+tunnel,
+/// An overhead that matches no known encapsulation, or no measurement.
+unknown,
+                    ;
+                    /// The localization key, e.g. `encapsulation.pppoe`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsNetInfoEncapsulationL10NKey(that: this, );
+
+
+                }
+
+/// The confidence of a finding together with how it was derived.
+class Evidence  {
+                /// How much the finding can be trusted.
+final Confidence confidence;
+/// The mechanism that produced it.
+final EvidenceSource source;
+
+                const Evidence({required this.confidence ,required this.source ,});
+
+                /// Nothing was checked, so nothing is known.
+static Future<Evidence>  default_()=>RustLib.instance.api.crateApiModelsNetInfoEvidenceDefault();
+
+
+  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+/// Pairs a confidence with its source.
+static Future<Evidence>  newInstance({required Confidence confidence , required EvidenceSource source })=>RustLib.instance.api.crateApiModelsNetInfoEvidenceNew(confidence: confidence, source: source);
+
+
+                
+
+                
+        @override
+        int get hashCode => confidence.hashCode^source.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is Evidence &&
+                runtimeType == other.runtimeType
+                && confidence == other.confidence&& source == other.source;
+        
+            }
+
+/// Where a finding (or the absence of one) came from.
+enum EvidenceSource {
+                    /// The probe did not run; the value is the default.
+notChecked,
+/// The probe ran but failed (timeout, resolver or client error).
+probeFailed,
+/// Supplied by the host application via [PlatformHints].
+platformHint,
+/// Read from the OS adapter media type (Windows `IfType`, tunnel type or
+/// driver description).
+adapterType,
+/// Matched against known interface name prefixes (`tun`, `wg`, ...).
+interfaceName,
+/// Compared system DNS answers with a trusted DoH resolver.
+resolverComparison,
+/// The HTTP probe was redirected.
+httpRedirect,
+/// The HTTP probe was answered directly (no redirect).
+httpResponse,
+                    ;
+                    /// The localization key, e.g. `evidence.interface_name`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsNetInfoEvidenceSourceL10NKey(that: this, );
+
+
+                }
+
+/// Reachability of a self-hosted service on the public address.
+///
+/// Produced by [check_hairpin](crate::api::probes::hairpin::check_hairpin).
+class HairpinReport  {
+                /// The public IPv4 address that was tested.
+final String? publicAddress;
+/// The TCP port that was tested.
+final int port;
+/// Connecting to the public address from inside the LAN.
+final ServiceReachability fromLan;
+/// A reflection server connecting to the public address from outside.
+final ServiceReachability fromInternet;
+/// `Some(false)` if the service is reachable from the internet but not
+/// from the LAN: the router does not support hairpin NAT (NAT loopback).
+/// `Some(true)` if the LAN connection succeeded, `None` if undecidable.
+final bool? hairpinSupported;
+
+                const HairpinReport({this.publicAddress ,required this.port ,required this.fromLan ,required this.fromInternet ,this.hairpinSupported ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => publicAddress.hashCode^port.hashCode^fromLan.hashCode^fromInternet.hashCode^hairpinSupported.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HairpinReport &&
+                runtimeType == other.runtimeType
+                && publicAddress == other.publicAddress&& port == other.port&& fromLan == other.fromLan&& fromInternet == other.fromInternet&& hairpinSupported == other.hairpinSupported;
+        
+            }
+
+/// The switch (or router) port the active interface is plugged into, as
+/// announced via LLDP or CDP.
+class LinkNeighbor  {
+                /// The protocol the announcement was received with.
+final NeighborProtocol protocol;
+/// The neighbor's chassis ID, usually its MAC address. For CDP, the device ID.
+final String? chassisId;
+/// The neighbor's host name (e.g. `sw-floor2`).
+final String? systemName;
+/// Free-text description of the neighbor (model, firmware).
+final String? systemDescription;
+/// The neighbor's hardware platform (CDP only).
+final String? platform;
+/// The neighbor's port this device is connected to (e.g. `Gi1/0/17`).
+final String? portId;
+/// The administrator's description of that port (LLDP only).
+final String? portDescription;
+/// An IP address the neighbor can be managed at.
+final String? managementAddress;
+
+                const LinkNeighbor({required this.protocol ,this.chassisId ,this.systemName ,this.systemDescription ,this.platform ,this.portId ,this.portDescription ,this.managementAddress ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => protocol.hashCode^chassisId.hashCode^systemName.hashCode^systemDescription.hashCode^platform.hashCode^portId.hashCode^portDescription.hashCode^managementAddress.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LinkNeighbor &&
+                runtimeType == other.runtimeType
+                && protocol == other.protocol&& chassisId == other.chassisId&& systemName == other.systemName&& systemDescription == other.systemDescription&& platform == other.platform&& portId == other.portId&& portDescription == other.portDescription&& managementAddress == other.managementAddress;
+        
+            }
+
+/// The MSS of one TCP connection to a reference server.
+class MssObservation  {
+                /// The server as passed in (`host:port`).
+final String server;
+/// The maximum segment size the connection settled on, in bytes
+/// (excluding TCP options).
+final int? mss;
+/// The path MTU the kernel uses towards the server.
+final int? pathMtu;
+/// Why the connection or measurement failed, if it did.
+final String? error;
+
+                const MssObservation({required this.server ,this.mss ,this.pathMtu ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => server.hashCode^mss.hashCode^pathMtu.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MssObservation &&
+                runtimeType == other.runtimeType
+                && server == other.server&& mss == other.mss&& pathMtu == other.pathMtu&& error == other.error;
+        
+            }
+
+/// MSS clamping and encapsulation findings.
+///
+/// Produced by [detect_mss_clamping](crate::api::probes::mss::detect_mss_clamping).
+class MssReport  {
+                /// One entry per reference server.
+final List<MssObservation> observations;
+/// The largest MSS seen across servers; a lower value at a single
+/// server is that server's choice, not the path's.
+final int? effectiveMss;
+/// True if every server negotiated an MSS below what the local path
+/// MTU allows: a middlebox rewrites the MSS option.
+final bool clampingDetected;
+/// Bytes missing from `effective_mss` compared with the Ethernet
+/// default (1460 for IPv4, 1440 for IPv6).
+final int? overheadBytes;
+/// The encapsulation `overhead_bytes` points to.
+final Encapsulation encapsulation;
+
+                const MssReport({required this.observations ,this.effectiveMss ,required this.clampingDetected ,this.overheadBytes ,required this.encapsulation ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => observations.hashCode^effectiveMss.hashCode^clampingDetected.hashCode^overheadBytes.hashCode^encapsulation.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MssReport &&
+                runtimeType == other.runtimeType
+                && observations == other.observations&& effectiveMss == other.effectiveMss&& clampingDetected == other.clampingDetected&& overheadBytes == other.overheadBytes&& encapsulation == other.encapsulation;
+        
+            }
+
+/// Several traceroutes compared against each other.
+///
+/// Produced by [trace_routes](crate::api::probes::traceroute::trace_routes).
+class MultiRouteTrace  {
+                /// One trace per requested host, in request order.
+final List<RouteTrace> routes;
+/// The leading hops all successful traces have in common.
+final List<TraceHop> sharedPrefix;
+/// How many of the shared hops are on the LAN.
+final int lanHopCount;
+/// The segment closest to the device in which any trace breaks, or
+/// `None` if every destination was reached.
+final PathSegment? problemSegment;
+
+                const MultiRouteTrace({required this.routes ,required this.sharedPrefix ,required this.lanHopCount ,this.problemSegment ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => routes.hashCode^sharedPrefix.hashCode^lanHopCount.hashCode^problemSegment.hashCode;
+        
 
-  /// Connected via a mobile carrier network (e.g., LTE, 5G, 3G).
-  /// Usually implies metered data and potentially higher latency.
-  cellular,
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MultiRouteTrace &&
+                runtimeType == other.runtimeType
+                && routes == other.routes&& sharedPrefix == other.sharedPrefix&& lanHopCount == other.lanHopCount&& problemSegment == other.problemSegment;
+        
+            }
+
+/// Result of the LAN multicast check.
+///
+/// Produced by [check_multicast](crate::api::probes::multicast::check_multicast).
+class MulticastReport  {
+                /// The multicast group that was tested (`ip:port`).
+final String group;
+/// Whether the OS accepted the group membership (IGMP join).
+final bool joined;
+/// Whether our own query was looped back, i.e. sending to the group works.
+final bool loopbackReceived;
+/// Other LAN hosts that answered the query.
+final List<String> responders;
+/// `Some(true)` if another host answered, `Some(false)` if sending failed
+/// or our own query never came back, `None` if the local stack works but
+/// nobody answered (there may simply be no mDNS devices on the network)
+/// or the mDNS port could not be bound or the group joined (see `error`).
+final bool? multicastOk;
+/// Why the check stopped early, if it did.
+final String? error;
+
+                const MulticastReport({required this.group ,required this.joined ,required this.loopbackReceived ,required this.responders ,this.multicastOk ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => group.hashCode^joined.hashCode^loopbackReceived.hashCode^responders.hashCode^multicastOk.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MulticastReport &&
+                runtimeType == other.runtimeType
+                && group == other.group&& joined == other.joined&& loopbackReceived == other.loopbackReceived&& responders == other.responders&& multicastOk == other.multicastOk&& error == other.error;
+        
+            }
+
+/// NAT and path behavior inferred from reflection servers.
+///
+/// Produced by [probe_nat](crate::api::probes::reflection::probe_nat).
+class NatReport  {
+                /// The mapping behavior of the NAT, if any.
+final NatType natType;
+/// The device's own address on the route to the first server.
+final String? localAddress;
+/// One entry per server that answered.
+final List<ReflectionObservation> observations;
+/// True if the same server saw a different public port for the same
+/// local socket on a repeated probe: the NAT dropped or rebound the
+/// mapping, which breaks long-lived UDP flows.
+final bool rebindingDetected;
+/// True if the forward and reverse hop counts to any server differ by
+/// at least [ASYMMETRIC_ROUTE_HOP_DIFFERENCE](crate::api::constants::LibConstants::ASYMMETRIC_ROUTE_HOP_DIFFERENCE).
+final bool asymmetricRoutingSuspected;
+
+                const NatReport({required this.natType ,this.localAddress ,required this.observations ,required this.rebindingDetected ,required this.asymmetricRoutingSuspected ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => natType.hashCode^localAddress.hashCode^observations.hashCode^rebindingDetected.hashCode^asymmetricRoutingSuspected.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NatReport &&
+                runtimeType == other.runtimeType
+                && natType == other.natType&& localAddress == other.localAddress&& observations == other.observations&& rebindingDetected == other.rebindingDetected&& asymmetricRoutingSuspected == other.asymmetricRoutingSuspected;
+        
+            }
+
+/// How the NAT in front of the device maps outgoing UDP flows.
+enum NatType {
+                    /// No translation: the servers see the device's own address and port.
+none,
+/// One public address/port per local socket, whatever the destination
+/// ("full cone" and friends). Peer-to-peer traffic usually works.
+endpointIndependent,
+/// A new public port per destination ("symmetric" NAT). Peer-to-peer
+/// traffic usually needs a relay.
+endpointDependent,
+/// There is translation, but too few servers answered to tell the
+/// mapping behavior apart.
+unknown,
+                    ;
+                    /// The localization key, e.g. `nat_type.endpoint_dependent`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsNetInfoNatTypeL10NKey(that: this, );
+
+
+                }
+
+/// The discovery protocol a [LinkNeighbor] was announced with.
+enum NeighborProtocol {
+                    /// IEEE 802.1AB Link Layer Discovery Protocol.
+lldp,
+/// Cisco Discovery Protocol.
+cdp,
+                    ;
+                    /// The localization key, e.g. `neighbor_protocol.lldp`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsNetInfoNeighborProtocolL10NKey(that: this, );
+
+
+                }
+
+/// Upstream vs downstream delay towards a timestamping echo server.
+///
+/// Produced by [estimate_one_way_delay](crate::api::probes::one_way_delay::estimate_one_way_delay).
+/// All figures are in milliseconds.
+class OneWayDelayEstimate  {
+                /// The server as passed in (`host:port`).
+final String server;
+/// Number of exchanges that were answered.
+final int samples;
+/// Estimated offset of the server clock relative to ours (server minus
+/// local), taken from the fastest exchange, whose path is assumed to be
+/// least congested and therefore most symmetric.
+final double clockOffsetMs;
+/// The lowest round-trip time seen, excluding server processing time.
+final double minRttMs;
+/// Median device-to-server delay.
+final double upstreamMs;
+/// Median server-to-device delay.
+final double downstreamMs;
+/// Median upstream delay above its minimum: queuing on the way out.
+final double upstreamQueuingMs;
+/// Median downstream delay above its minimum: queuing on the way in.
+final double downstreamQueuingMs;
+
+                const OneWayDelayEstimate({required this.server ,required this.samples ,required this.clockOffsetMs ,required this.minRttMs ,required this.upstreamMs ,required this.downstreamMs ,required this.upstreamQueuingMs ,required this.downstreamQueuingMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => server.hashCode^samples.hashCode^clockOffsetMs.hashCode^minRttMs.hashCode^upstreamMs.hashCode^downstreamMs.hashCode^upstreamQueuingMs.hashCode^downstreamQueuingMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is OneWayDelayEstimate &&
+                runtimeType == other.runtimeType
+                && server == other.server&& samples == other.samples&& clockOffsetMs == other.clockOffsetMs&& minRttMs == other.minRttMs&& upstreamMs == other.upstreamMs&& downstreamMs == other.downstreamMs&& upstreamQueuingMs == other.upstreamQueuingMs&& downstreamQueuingMs == other.downstreamQueuingMs;
+        
+            }
+
+/// The stretch of a network path a hop belongs to.
+enum PathSegment {
+                    /// Private addresses right after the device: the home/office network.
+lan,
+/// Public hops shared by every traced destination: the ISP and its upstream.
+isp,
+/// Hops specific to a single destination.
+destination,
+                    ;
+                    /// The localization key, e.g. `path_segment.isp`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsNetInfoPathSegmentL10NKey(that: this, );
+
+
+                }
+
+/// Authoritative connectivity facts supplied by the host platform.
+///
+/// Mobile OSes know far more about the active link than interface names reveal
+/// (Android `ConnectivityManager`/`NetworkCapabilities`, iOS `NWPath`). The
+/// Flutter layer can pass them in via
+/// [set_platform_hints](crate::api::engine::hints::set_platform_hints). Every
+/// field left as `None` keeps the engine's own detection.
+class PlatformHints  {
+                /// The transport of the default network (e.g. `TRANSPORT_WIFI`).
+final ConnectionType? connectionType;
+/// True if the network is metered (`!NET_CAPABILITY_NOT_METERED` / `isExpensive`).
+final bool? isMetered;
+/// True if the OS restricts background data (Data Saver / Low Data Mode).
+final bool? isConstrained;
+/// True if the default network runs over a VPN (`TRANSPORT_VPN`).
+final bool? isVpn;
+/// The SSID of the connected Wi-Fi network, if the app has permission to read it.
+final String? ssid;
+/// The BSSID (access point MAC address) of the connected Wi-Fi network,
+/// if the app has permission to read it. Enables roaming detection.
+final String? bssid;
+/// The name of the interface the OS routes traffic through.
+final String? interfaceName;
+
+                const PlatformHints({this.connectionType ,this.isMetered ,this.isConstrained ,this.isVpn ,this.ssid ,this.bssid ,this.interfaceName ,});
+
+                static Future<PlatformHints>  default_()=>RustLib.instance.api.crateApiModelsNetInfoPlatformHintsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => connectionType.hashCode^isMetered.hashCode^isConstrained.hashCode^isVpn.hashCode^ssid.hashCode^bssid.hashCode^interfaceName.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PlatformHints &&
+                runtimeType == other.runtimeType
+                && connectionType == other.connectionType&& isMetered == other.isMetered&& isConstrained == other.isConstrained&& isVpn == other.isVpn&& ssid == other.ssid&& bssid == other.bssid&& interfaceName == other.interfaceName;
+        
+            }
+
+/// The verdict of a "check my port" service on a port forward.
+///
+/// Produced by [verify_port_forwarding](crate::api::probes::port_check::verify_port_forwarding).
+class PortForwardCheck  {
+                /// The endpoint as passed in.
+final String endpoint;
+/// The port that was tested.
+final int port;
+/// Our public address as seen by the service, if it reported one.
+final String? publicAddress;
+/// Whether the service could connect back.
+final ServiceReachability reachability;
+/// Duration (ms) of the whole request.
+final BigInt durationMs;
+
+                const PortForwardCheck({required this.endpoint ,required this.port ,this.publicAddress ,required this.reachability ,required this.durationMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => endpoint.hashCode^port.hashCode^publicAddress.hashCode^reachability.hashCode^durationMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PortForwardCheck &&
+                runtimeType == other.runtimeType
+                && endpoint == other.endpoint&& port == other.port&& publicAddress == other.publicAddress&& reachability == other.reachability&& durationMs == other.durationMs;
+        
+            }
+
+/// The outcome of a TCP connection attempt on one port.
+class PortReachability  {
+                /// The destination port.
+final int port;
+/// Whether the handshake completed.
+final ServiceReachability reachability;
+
+                const PortReachability({required this.port ,required this.reachability ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => port.hashCode^reachability.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is PortReachability &&
+                runtimeType == other.runtimeType
+                && port == other.port&& reachability == other.reachability;
+        
+            }
+
+/// What a captive portal's login page asks of the user.
+enum PortalLoginKind {
+                    /// The page was not read or fits neither pattern.
+unknown,
+/// Accepting terms or pressing a button is enough.
+clickThrough,
+/// The page asks for a password, voucher code, room number or the like.
+credentials,
+                    ;
+                    /// The localization key, e.g. `portal_login.click_through`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsNetInfoPortalLoginKindL10NKey(that: this, );
+
+
+                }
+
+/// Which raw-socket based probe modes the process may use.
+///
+/// Produced by
+/// [detect_probe_capabilities](crate::api::probes::capabilities::detect_probe_capabilities).
+/// Probes that lack a capability fall back to an unprivileged method and
+/// note the [ProbeFallback] in their result.
+class ProbeCapabilities  {
+                /// An ICMP socket (unprivileged datagram or raw) can be opened, so ICMP
+/// targets are pinged.
+final bool icmpEcho;
+/// A raw ICMP socket can be opened, which also receives the Time Exceeded
+/// replies ICMP traceroute relies on.
+final bool icmpRaw;
+/// UDP probes can read ICMP errors without privileges (Linux
+/// `IP_RECVERR`), enabling UDP traceroute.
+final bool udpTraceroute;
+/// The OS neighbor (ARP) table is readable; Android 10+ denies it.
+final bool arpTable;
+
+                const ProbeCapabilities({required this.icmpEcho ,required this.icmpRaw ,required this.udpTraceroute ,required this.arpTable ,});
+
+                static Future<ProbeCapabilities>  default_()=>RustLib.instance.api.crateApiModelsNetInfoProbeCapabilitiesDefault();
 
-  /// Connected via a physical RJ45 Ethernet cable.
-  /// The most stable and high-performance connection type.
-  ethernet,
 
-  /// Connected through a Virtual Private Network (VPN) tunnel.
-  /// Indicates that traffic is being routed through an encrypted tunnel
-  /// which might mask the true underlying connection type (WiFi/Cellular).
-  vpn,
+                
 
-  /// Connected via Bluetooth tethering or a personal area network (PAN).
-  /// Characterized by very low bandwidth and limited range.
-  bluetooth,
+                
+        @override
+        int get hashCode => icmpEcho.hashCode^icmpRaw.hashCode^udpTraceroute.hashCode^arpTable.hashCode;
+        
 
-  /// A local loopback interface (typically 127.0.0.1).
-  /// Indicates the device is communicating with itself; no external internet.
-  loopback,
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProbeCapabilities &&
+                runtimeType == other.runtimeType
+                && icmpEcho == other.icmpEcho&& icmpRaw == other.icmpRaw&& udpTraceroute == other.udpTraceroute&& arpTable == other.arpTable;
+        
+            }
 
-  /// The connection type could not be identified by the OS or the engine.
-  /// This is the fallback for unknown or experimental interface types.
-  unknown,
-  ;
+/// An unprivileged probe method used in place of a raw-socket one.
+enum ProbeFallback {
+                    /// An ICMP target (or the hosts of an ICMP sweep) was probed with a TCP
+/// connect; a refused connection still counts as reached.
+tcpPing,
+/// The route was traced with UDP datagrams instead of ICMP echoes.
+udpTraceroute,
+                    ;
+                    /// The localization key, e.g. `probe_fallback.udp_traceroute`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsNetInfoProbeFallbackL10NKey(that: this, );
 
-  /// Returns [`ConnectionType::Unknown`] as the default state.
-  static Future<ConnectionType> default_() =>
-      RustLib.instance.api.crateApiModelsNetInfoConnectionTypeDefault();
-}
+
+                }
+
+/// What one reflection server observed about a probe.
+class ReflectionObservation  {
+                /// The server as passed in (`host:port`).
+final String server;
+/// The source address the server saw.
+final String observedAddress;
+/// The source port the server saw.
+final int observedPort;
+/// The TTL the probe arrived with, if the server reports it.
+final int? observedTtl;
+/// Hops from the device to the server, derived from `observed_ttl`.
+final int? forwardHops;
+/// Hops from the server back to the device, derived from the TTL of its
+/// reply. Only available on Linux/Android.
+final int? reverseHops;
+
+                const ReflectionObservation({required this.server ,required this.observedAddress ,required this.observedPort ,this.observedTtl ,this.forwardHops ,this.reverseHops ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => server.hashCode^observedAddress.hashCode^observedPort.hashCode^observedTtl.hashCode^forwardHops.hashCode^reverseHops.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ReflectionObservation &&
+                runtimeType == other.runtimeType
+                && server == other.server&& observedAddress == other.observedAddress&& observedPort == other.observedPort&& observedTtl == other.observedTtl&& forwardHops == other.forwardHops&& reverseHops == other.reverseHops;
+        
+            }
+
+/// The traceroute of one destination, as returned by
+/// [trace_route](crate::api::probes::trace_route) or within a [MultiRouteTrace].
+class RouteTrace  {
+                /// The host as passed in.
+final String host;
+/// The hops, nearest first.
+/// Empty if the trace could not start.
+final List<TraceHop> hops;
+/// Why the trace could not start (e.g. DNS failure, no ICMP socket).
+final String? error;
+/// True if the destination itself answered.
+final bool reached;
+/// The first hop after the last one that answered, if the destination
+/// was not reached.
+final int? problemHop;
+/// The segment the path breaks in, if the destination was not reached.
+final PathSegment? problemSegment;
+/// The method used instead of ICMP echoes, if the process may not read
+/// the routers' Time Exceeded replies (see [ProbeCapabilities]).
+final ProbeFallback? fallback;
+
+                const RouteTrace({required this.host ,required this.hops ,this.error ,required this.reached ,this.problemHop ,this.problemSegment ,this.fallback ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => host.hashCode^hops.hashCode^error.hashCode^reached.hashCode^problemHop.hashCode^problemSegment.hashCode^fallback.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is RouteTrace &&
+                runtimeType == other.runtimeType
+                && host == other.host&& hops == other.hops&& error == other.error&& reached == other.reached&& problemHop == other.problemHop&& problemSegment == other.problemSegment&& fallback == other.fallback;
+        
+            }
+
+/// Where the library itself runs.
+///
+/// Containers and WSL see virtual interfaces instead of the host's: the
+/// connection type describes the virtual link (usually Ethernet), and a VPN
+/// running on the host is invisible to the interface heuristics.
+enum RuntimeEnvironment {
+                    /// Directly on the host OS, as far as can be told.
+native,
+/// Inside a Docker container.
+docker,
+/// Inside an LXC/LXD container.
+lxc,
+/// Inside the Windows Subsystem for Linux.
+wsl,
+/// Inside another container runtime (Podman, Kubernetes, systemd-nspawn).
+container,
+                    ;
+                    /// Returns [`RuntimeEnvironment::Native`] as the default.
+static Future<RuntimeEnvironment>  default_()=>RustLib.instance.api.crateApiModelsNetInfoRuntimeEnvironmentDefault();
+
+
+/// The localization key, e.g. `runtime_environment.docker`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsNetInfoRuntimeEnvironmentL10NKey(that: this, );
+
+
+                }
 
 /// Internal representation of security-related attributes for the current connection.
 ///
 /// This structure stores raw findings from the engine's security probes.
-class SecurityFlags {
-  const SecurityFlags({
-    required this.isVpnDetected,
-    required this.isDnsSpoofed,
-    required this.isProxyDetected,
-    required this.interfaceName,
-  });
-
-  /// True if the active network interface is identified as a tunnel/VPN.
-  ///
-  /// Detected by looking for interface names like `tun`, `tap`, `ppp`, or `utun`.
-  final bool isVpnDetected;
-
-  /// True if the system DNS appears to be providing malicious or redirected results.
-  ///
-  /// Calculated by comparing local results for known static IPs against
-  /// trusted upstream resolvers.
-  final bool isDnsSpoofed;
-
-  /// True if a system-wide HTTP or SOCKS proxy is active.
-  ///
-  /// Proxies can intercept and modify application traffic.
-  final bool isProxyDetected;
-
-  /// The system-assigned name of the active network interface.
-  /// Examples: `wlan0` (Linux WiFi), `en0` (macOS WiFi), `eth0` (Ethernet).
-  final String interfaceName;
-
-  /// Returns a "Safe/Clean" default state where no security issues are detected.
-  static Future<SecurityFlags> default_() =>
-      RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsDefault();
-
-  @override
-  int get hashCode =>
-      isVpnDetected.hashCode ^
-      isDnsSpoofed.hashCode ^
-      isProxyDetected.hashCode ^
-      interfaceName.hashCode;
-
-  @override
-  bool operator ==(final Object other) =>
-      identical(this, other) ||
-      other is SecurityFlags &&
-          runtimeType == other.runtimeType &&
-          isVpnDetected == other.isVpnDetected &&
-          isDnsSpoofed == other.isDnsSpoofed &&
-          isProxyDetected == other.isProxyDetected &&
-          interfaceName == other.interfaceName;
-}
+class SecurityFlags  {
+                /// True if the active network interface is identified as a tunnel/VPN.
+///
+/// Detected by looking for interface names like `tun`, `tap`, `ppp`, or `utun`.
+final bool isVpnDetected;
+/// True if the system DNS appears to be providing malicious or redirected results.
+///
+/// Calculated by comparing local results for known static IPs against 
+/// trusted upstream resolvers.
+final bool isDnsSpoofed;
+/// True if a system-wide HTTP or SOCKS proxy is active.
+///
+/// Proxies can intercept and modify application traffic.
+final bool isProxyDetected;
+/// The system-assigned name of the active network interface.
+/// Examples: `wlan0` (Linux WiFi), `en0` (macOS WiFi), `eth0` (Ethernet).
+final String interfaceName;
+/// Confidence and source of `is_vpn_detected`.
+final Evidence vpnEvidence;
+/// Confidence and source of `is_dns_spoofed`.
+final Evidence dnsSpoofEvidence;
+/// Confidence and source of `is_proxy_detected`.
+final Evidence proxyEvidence;
+
+                const SecurityFlags({required this.isVpnDetected ,required this.isDnsSpoofed ,required this.isProxyDetected ,required this.interfaceName ,required this.vpnEvidence ,required this.dnsSpoofEvidence ,required this.proxyEvidence ,});
+
+                /// Returns a "Safe/Clean" default state where no security issues are detected.
+static Future<SecurityFlags>  default_()=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => isVpnDetected.hashCode^isDnsSpoofed.hashCode^isProxyDetected.hashCode^interfaceName.hashCode^vpnEvidence.hashCode^dnsSpoofEvidence.hashCode^proxyEvidence.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SecurityFlags &&
+                runtimeType == other.runtimeType
+                && isVpnDetected == other.isVpnDetected&& isDnsSpoofed == other.isDnsSpoofed&& isProxyDetected == other.isProxyDetected&& interfaceName == other.interfaceName&& vpnEvidence == other.vpnEvidence&& dnsSpoofEvidence == other.dnsSpoofEvidence&& proxyEvidence == other.proxyEvidence;
+        
+            }
+
+/// Whether a TCP service answered a connection attempt.
+enum ServiceReachability {
+                    /// The handshake completed.
+reachable,
+/// The connection was actively refused (nothing listens, or a router
+/// rejected it).
+refused,
+/// No answer: typically a firewall or missing port forward dropping it.
+timedOut,
+/// The attempt could not be made, e.g. the public address is unknown.
+notTested,
+                    ;
+                    /// The localization key, e.g. `service_reachability.timed_out`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsNetInfoServiceReachabilityL10NKey(that: this, );
+
+
+                }
+
+/// What an SNMP query of one device returned.
+class SnmpDeviceInfo  {
+                /// The queried device's address.
+final String address;
+/// The device's `sysDescr` (vendor, model and firmware, as free text).
+final String? sysDescr;
+/// The device's interfaces, in `ifIndex` order.
+final List<SnmpInterface> interfaces;
+/// Why the query failed, if it did.
+final String? error;
+
+                const SnmpDeviceInfo({required this.address ,this.sysDescr ,required this.interfaces ,this.error ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => address.hashCode^sysDescr.hashCode^interfaces.hashCode^error.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SnmpDeviceInfo &&
+                runtimeType == other.runtimeType
+                && address == other.address&& sysDescr == other.sysDescr&& interfaces == other.interfaces&& error == other.error;
+        
+            }
+
+/// Interface counters read from a managed device over SNMP.
+class SnmpInterface  {
+                /// The interface's `ifIndex`.
+final int index;
+/// The interface's `ifDescr`, e.g. `eth0` or `GigabitEthernet0/1`.
+final String description;
+/// Inbound packets discarded because of errors (`ifInErrors`).
+final BigInt inErrors;
+/// Outbound packets that could not be sent because of errors (`ifOutErrors`).
+final BigInt outErrors;
+/// Inbound packets dropped without an error, e.g. for lack of buffer space
+/// (`ifInDiscards`).
+final BigInt inDiscards;
+/// Outbound packets dropped without an error (`ifOutDiscards`).
+final BigInt outDiscards;
+
+                const SnmpInterface({required this.index ,required this.description ,required this.inErrors ,required this.outErrors ,required this.inDiscards ,required this.outDiscards ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^description.hashCode^inErrors.hashCode^outErrors.hashCode^inDiscards.hashCode^outDiscards.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SnmpInterface &&
+                runtimeType == other.runtimeType
+                && index == other.index&& description == other.description&& inErrors == other.inErrors&& outErrors == other.outErrors&& inDiscards == other.inDiscards&& outDiscards == other.outDiscards;
+        
+            }
+
+/// Represents a single diagnostic hop in a traceroute operation.
+///
+/// Traceroutes are used to map the path packets take from the device to 
+/// a target, helping identify exactly where congestion or failure occurs.
+class TraceHop  {
+                /// The sequential hop count (Time-To-Live value).
+/// Hop 1 is usually the local router/gateway.
+final int hopNumber;
+/// The IP address of the router at this hop.
+///
+/// If a router at this hop does not respond to ICMP Time Exceeded messages, 
+/// this will be set to `"*"` (asterisk).
+final String ipAddress;
+/// The reverse-DNS hostname of the hop's IP address.
+/// Only populated if a reverse lookup is successful.
+final String? hostname;
+/// The Round-Trip Time (RTT) to this hop in milliseconds.
+/// `None` if the hop timed out.
+final BigInt? latencyMs;
+
+                const TraceHop({required this.hopNumber ,required this.ipAddress ,this.hostname ,this.latencyMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hopNumber.hashCode^ipAddress.hashCode^hostname.hashCode^latencyMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TraceHop &&
+                runtimeType == other.runtimeType
+                && hopNumber == other.hopNumber&& ipAddress == other.ipAddress&& hostname == other.hostname&& latencyMs == other.latencyMs;
+        
+            }
+
+/// What a captive portal's walled garden lets through before login.
+///
+/// Produced by [probe_walled_garden](crate::api::probes::walled_garden::probe_walled_garden).
+class WalledGardenReport  {
+                /// Whether the system resolver resolved a public name. Portals may answer
+/// with their own address, so this only shows that lookups get an answer.
+final bool systemDns;
+/// Whether a public resolver answered a direct UDP query.
+final ServiceReachability publicDns;
+/// Whether a certificate-validated HTTPS request completed. Interception
+/// by the portal reads as [ServiceReachability::Refused].
+final ServiceReachability https;
+/// TCP handshakes towards a host listening on every port, at the address
+/// the trusted resolver (see
+/// [EndpointConfig::trusted_dns_servers](super::EndpointConfig::trusted_dns_servers))
+/// returns for it. [ServiceReachability::NotTested] if that lookup failed.
+final List<PortReachability> ports;
+
+                const WalledGardenReport({required this.systemDns ,required this.publicDns ,required this.https ,required this.ports ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => systemDns.hashCode^publicDns.hashCode^https.hashCode^ports.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WalledGardenReport &&
+                runtimeType == other.runtimeType
+                && systemDns == other.systemDns&& publicDns == other.publicDns&& https == other.https&& ports == other.ports;
+        
+            }
+            
\ No newline at end of file
@@ -1,214 +1,919 @@
 // This file is automatically generated, so please do not edit it.
 // @generated by `flutter_rust_bridge`@ 2.12.0.
 
-// ignore_for_file: public_member_api_docs, invalid_use_of_internal_member, unused_import, unnecessary_import
-
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
 import 'config.dart';
+import 'diagnostics.dart';
 import 'net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `unprobed`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `from`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NetworkReport>>
+                abstract class NetworkReport implements RustOpaqueInterface {
+                     List<AnomalyDetected> get anomalies;
+
+
+ DegradationAttribution? get attribution;
+
+
+ BetterPathAvailable? get betterPath;
+
+
+ String? get bssid;
+
+
+ bool get cancelled;
+
+
+ CaptivePortalStatus? get captivePortal;
+
+
+ ConnectionType get connectionType;
+
+
+ BigInt get durationMs;
+
+
+ RuntimeEnvironment get environment;
+
+
+ FamilyComparison? get familyComparison;
+
+
+ bool get isConstrained;
+
+
+ bool get isExpensive;
+
+
+ LayeredDiagnosis get layers;
+
+
+ bool? get likelyIspOutage;
+
+
+ BigInt get monotonicMs;
+
+
+ bool? get multicastOk;
+
+
+ List<SampleRecord> get rawSamples;
+
+
+ RoamEvent? get roam;
+
+
+ NetworkScope get scope;
+
+
+ SecurityFlagsResult get securityFlagsResult;
+
+
+ List<SnmpDeviceInfo> get snmpDevices;
+
+
+ String? get ssid;
+
+
+ NetworkStatus get status;
+
+
+ CheckStrategy get strategy;
+
+
+ List<TargetReport> get targetReports;
+
+
+ BigInt get timestampMs;
+
+
+ RouteTrace? get traceroute;
+
+
+ bool get truncated;
+
+
+ WalledGardenReport? get walledGarden;
+
+
+  set anomalies(List<AnomalyDetected> anomalies);
+
+
+  set attribution(DegradationAttribution? attribution);
+
+
+  set betterPath(BetterPathAvailable? betterPath);
+
+
+  set bssid(String? bssid);
+
+
+  set cancelled(bool cancelled);
+
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `fmt`, `fmt`, `fmt`, `fmt`
+  set captivePortal(CaptivePortalStatus? captivePortal);
 
-// Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NetworkReport>>
-abstract class NetworkReport implements RustOpaqueInterface {
-  ConnectionType get connectionType;
 
-  SecurityFlagsResult get securityFlagsResult;
+  set connectionType(ConnectionType connectionType);
 
-  NetworkStatus get status;
 
-  List<TargetReport> get targetReports;
+  set durationMs(BigInt durationMs);
 
-  BigInt get timestampMs;
 
-  set connectionType(final ConnectionType connectionType);
+  set environment(RuntimeEnvironment environment);
 
-  set securityFlagsResult(final SecurityFlagsResult securityFlagsResult);
 
-  set status(final NetworkStatus status);
+  set familyComparison(FamilyComparison? familyComparison);
 
-  set targetReports(final List<TargetReport> targetReports);
 
-  set timestampMs(final BigInt timestampMs);
-}
+  set isConstrained(bool isConstrained);
+
+
+  set isExpensive(bool isExpensive);
+
+
+  set layers(LayeredDiagnosis layers);
+
+
+  set likelyIspOutage(bool? likelyIspOutage);
+
+
+  set monotonicMs(BigInt monotonicMs);
+
+
+  set multicastOk(bool? multicastOk);
+
+
+  set rawSamples(List<SampleRecord> rawSamples);
+
+
+  set roam(RoamEvent? roam);
+
+
+  set scope(NetworkScope scope);
+
+
+  set securityFlagsResult(SecurityFlagsResult securityFlagsResult);
+
+
+  set snmpDevices(List<SnmpDeviceInfo> snmpDevices);
+
+
+  set ssid(String? ssid);
+
+
+  set status(NetworkStatus status);
+
+
+  set strategy(CheckStrategy strategy);
+
+
+  set targetReports(List<TargetReport> targetReports);
+
+
+  set timestampMs(BigInt timestampMs);
+
+
+  set traceroute(RouteTrace? traceroute);
+
+
+  set truncated(bool truncated);
+
+
+  set walledGarden(WalledGardenReport? walledGarden);
+
+
+/// Collapses the report into a [SimpleStatus]:
+///
+/// - [Red](SimpleStatus::Red): not connected, only the local network is
+///   reachable, or a captive portal intercepts traffic.
+/// - [Yellow](SimpleStatus::Yellow): connected, but the quality is
+///   [Moderate](ConnectionQuality::Moderate) or worse, or DNS answers
+///   look spoofed.
+/// - [Green](SimpleStatus::Green): everything else, i.e. internet access
+///   of at least [Good](ConnectionQuality::Good) quality.
+ Future<SimpleStatus>  simpleStatus();
+
+
+
+                    
+                }
+                
+
+/// An IP address family.
+enum AddressFamily {
+                    v4,
+v6,
+                    ;
+                    /// The localization key, e.g. `address_family.v6`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsReportAddressFamilyL10NKey(that: this, );
+
+
+                }
+
+/// The state of a [ReachabilityEngine](crate::api::engine::ReachabilityEngine)
+/// worth keeping across process restarts.
+///
+/// Taken with [snapshot](crate::api::engine::ReachabilityEngine::snapshot) and
+/// restored with [with_snapshot](crate::api::engine::ReachabilityEngine::with_snapshot),
+/// so an app can show the last known state right after launch instead of
+/// waiting for its first check. The history and anomaly baseline are not
+/// included.
+class EngineSnapshot  {
+                /// UTC time (milliseconds since epoch) the snapshot was taken.
+final BigInt takenAtMs;
+/// The most recent report, if any check had completed.
+final NetworkReport? lastReport;
+/// Consecutive checks in which an essential target failed.
+final int breakerConsecutiveFailures;
+/// UTC time (milliseconds since epoch) the circuit breaker opened;
+/// `None` while it is closed.
+final BigInt? breakerOpenedAtMs;
+/// Per-target health, in configuration order.
+final List<TargetHealth> targetHealth;
+
+                const EngineSnapshot({required this.takenAtMs ,this.lastReport ,required this.breakerConsecutiveFailures ,this.breakerOpenedAtMs ,required this.targetHealth ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => takenAtMs.hashCode^lastReport.hashCode^breakerConsecutiveFailures.hashCode^breakerOpenedAtMs.hashCode^targetHealth.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is EngineSnapshot &&
+                runtimeType == other.runtimeType
+                && takenAtMs == other.takenAtMs&& lastReport == other.lastReport&& breakerConsecutiveFailures == other.breakerConsecutiveFailures&& breakerOpenedAtMs == other.breakerOpenedAtMs&& targetHealth == other.targetHealth;
+        
+            }
+
+/// IPv4 vs IPv6 towards the same dual-stack targets.
+class FamilyComparison  {
+                /// Labels of the targets that resolved to both families.
+final List<String> targets;
+final FamilyStats ipv4;
+final FamilyStats ipv6;
+/// The family applications should prefer: the one with clearly lower
+/// loss, else clearly lower latency, else IPv6 (as RFC 6724 does).
+/// `None` if neither family worked.
+final AddressFamily? preferredFamily;
+
+                const FamilyComparison({required this.targets ,required this.ipv4 ,required this.ipv6 ,this.preferredFamily ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => targets.hashCode^ipv4.hashCode^ipv6.hashCode^preferredFamily.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FamilyComparison &&
+                runtimeType == other.runtimeType
+                && targets == other.targets&& ipv4 == other.ipv4&& ipv6 == other.ipv6&& preferredFamily == other.preferredFamily;
+        
+            }
+
+/// Probe results over one address family.
+class FamilyStats  {
+                /// The family the probes were sent over.
+final AddressFamily family;
+/// Probes sent.
+final int probes;
+/// Median latency (ms) of the successful probes.
+final BigInt? latencyMs;
+/// Share of failed probes, in percent.
+final double packetLossPercent;
+
+                const FamilyStats({required this.family ,required this.probes ,this.latencyMs ,required this.packetLossPercent ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => family.hashCode^probes.hashCode^latencyMs.hashCode^packetLossPercent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is FamilyStats &&
+                runtimeType == other.runtimeType
+                && family == other.family&& probes == other.probes&& latencyMs == other.latencyMs&& packetLossPercent == other.packetLossPercent;
+        
+            }
+
+/// A summary of the reports of one minute or one hour.
+///
+/// Long-running engines fold old reports into these (see
+/// [ReachabilityEngine::history_aggregates](crate::api::engine::ReachabilityEngine::history_aggregates))
+/// so memory stays bounded while trends remain queryable.
+class HistoryAggregate  {
+                /// UTC start of the period (milliseconds since epoch), aligned to its resolution.
+final BigInt startMs;
+/// Length of the period: 60 000 (minute) or 3 600 000 (hour).
+final BigInt resolutionMs;
+/// Number of reports summarized.
+final int reports;
+/// Number of those reports that were connected.
+final int connectedReports;
+/// Number of those reports that were offline or of poor or unstable
+/// quality.
+final int degradedReports;
+/// Mean latency (ms) of the connected reports.
+final BigInt? avgLatencyMs;
+/// Highest latency (ms) of the connected reports.
+final BigInt? maxLatencyMs;
+/// Mean packet loss (percent) over all reports.
+final double avgPacketLossPercent;
+/// The worst stability score seen in the period.
+final int minStabilityScore;
+
+                const HistoryAggregate({required this.startMs ,required this.resolutionMs ,required this.reports ,required this.connectedReports ,required this.degradedReports ,this.avgLatencyMs ,this.maxLatencyMs ,required this.avgPacketLossPercent ,required this.minStabilityScore ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => startMs.hashCode^resolutionMs.hashCode^reports.hashCode^connectedReports.hashCode^degradedReports.hashCode^avgLatencyMs.hashCode^maxLatencyMs.hashCode^avgPacketLossPercent.hashCode^minStabilityScore.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is HistoryAggregate &&
+                runtimeType == other.runtimeType
+                && startMs == other.startMs&& resolutionMs == other.resolutionMs&& reports == other.reports&& connectedReports == other.connectedReports&& degradedReports == other.degradedReports&& avgLatencyMs == other.avgLatencyMs&& maxLatencyMs == other.maxLatencyMs&& avgPacketLossPercent == other.avgPacketLossPercent&& minStabilityScore == other.minStabilityScore;
+        
+            }
+
+/// The kind of ICMP "Destination Unreachable" received for a probe.
+enum IcmpUnreachable {
+                    /// The host answered but nothing listens on the port.
+port,
+/// A router reported the host itself as unreachable.
+host,
+/// A router has no route to the host's network.
+network,
+                    ;
+                    
+                }
 
 /// A suite of statistical metrics derived from multiple latency samples.
 ///
-/// These metrics provide deep insight into the "health" and consistency
-/// of the connection, helping identify subtle issues like bufferbloat
+/// These metrics provide deep insight into the "health" and consistency 
+/// of the connection, helping identify subtle issues like bufferbloat 
 /// or interference.
-class LatencyStats {
-  const LatencyStats({
-    required this.latencyMs,
-    required this.jitterMs,
-    required this.packetLossPercent,
-    required this.stabilityScore,
-    this.minLatencyMs,
-    this.avgLatencyMs,
-    this.maxLatencyMs,
-  });
-
-  /// The representative latency value (usually the mean of all successful samples).
-  /// Measured in milliseconds.
-  final BigInt latencyMs;
-
-  /// The "Jitter" value in milliseconds.
-  ///
-  /// Jitter is the variation in the delay of received packets (standard deviation).
-  /// High jitter (e.g., > 30ms) causes "stuttering" in real-time applications
-  /// like VoIP, gaming, and video streaming.
-  final BigInt jitterMs;
-
-  /// The percentage of samples (0.0 to 100.0) that failed to return a response.
-  ///
-  /// Even a small amount of packet loss (1-2%) can significantly degrade
-  /// TCP performance and causes noticeable lag in UDP-based services.
-  final double packetLossPercent;
-
-  /// The lowest latency recorded across all samples in the cycle.
-  /// Represents the "best-case" performance of the current link.
-  final BigInt? minLatencyMs;
-
-  /// The arithmetic mean of all successful latency samples.
-  final BigInt? avgLatencyMs;
-
-  /// The highest latency recorded across all samples in the cycle.
-  /// Often indicates transient congestion or "spikes".
-  final BigInt? maxLatencyMs;
-
-  /// A composite score from 0 (broken) to 100 (perfect) representing overall stability.
-  ///
-  /// The engine calculates this based on jitter consistency and packet loss.
-  /// Scores below 70 are typically flagged as [`ConnectionQuality::Unstable`].
-  final int stabilityScore;
-
-  @override
-  int get hashCode =>
-      latencyMs.hashCode ^
-      jitterMs.hashCode ^
-      packetLossPercent.hashCode ^
-      minLatencyMs.hashCode ^
-      avgLatencyMs.hashCode ^
-      maxLatencyMs.hashCode ^
-      stabilityScore.hashCode;
-
-  @override
-  bool operator ==(final Object other) =>
-      identical(this, other) ||
-      other is LatencyStats &&
-          runtimeType == other.runtimeType &&
-          latencyMs == other.latencyMs &&
-          jitterMs == other.jitterMs &&
-          packetLossPercent == other.packetLossPercent &&
-          minLatencyMs == other.minLatencyMs &&
-          avgLatencyMs == other.avgLatencyMs &&
-          maxLatencyMs == other.maxLatencyMs &&
-          stabilityScore == other.stabilityScore;
-}
+class LatencyStats  {
+                /// The representative latency value (usually the mean of all successful samples).
+/// Measured in milliseconds.
+final BigInt latencyMs;
+/// The "Jitter" value in milliseconds.
+///
+/// Jitter is the variation in the delay of received packets (standard deviation).
+/// High jitter (e.g., > 30ms) causes "stuttering" in real-time applications 
+/// like VoIP, gaming, and video streaming.
+final BigInt jitterMs;
+/// The percentage of samples (0.0 to 100.0) that failed to return a response.
+///
+/// Even a small amount of packet loss (1-2%) can significantly degrade 
+/// TCP performance and causes noticeable lag in UDP-based services.
+final double packetLossPercent;
+/// The lowest latency recorded across all samples in the cycle.
+/// Represents the "best-case" performance of the current link.
+final BigInt? minLatencyMs;
+/// The arithmetic mean of all successful latency samples.
+final BigInt? avgLatencyMs;
+/// The highest latency recorded across all samples in the cycle.
+/// Often indicates transient congestion or "spikes".
+final BigInt? maxLatencyMs;
+/// Median latency of the successful samples (linearly interpolated).
+final BigInt? p50Ms;
+/// 90th percentile latency of the successful samples.
+final BigInt? p90Ms;
+/// 95th percentile latency of the successful samples.
+final BigInt? p95Ms;
+/// 99th percentile latency of the successful samples. With the usual
+/// handful of samples per check this is close to the maximum; it gets
+/// meaningful with `num_jitter_samples` of 20 and more.
+final BigInt? p99Ms;
+/// A composite score from 0 (broken) to 100 (perfect) representing overall stability.
+///
+/// The engine calculates this based on jitter consistency and packet loss. 
+/// Scores below 70 are typically flagged as [`ConnectionQuality::Unstable`].
+final int stabilityScore;
+/// Number of samples whose latency exceeded the median by more than
+/// [spike_ratio_threshold](super::config::ResilienceConfig::spike_ratio_threshold).
+final int spikeCount;
+/// Positions (0-based, in collection order among the successful samples)
+/// of the samples counted in `spike_count`.
+final Uint32List spikeSampleIndices;
+
+                const LatencyStats({required this.latencyMs ,required this.jitterMs ,required this.packetLossPercent ,this.minLatencyMs ,this.avgLatencyMs ,this.maxLatencyMs ,this.p50Ms ,this.p90Ms ,this.p95Ms ,this.p99Ms ,required this.stabilityScore ,required this.spikeCount ,required this.spikeSampleIndices ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => latencyMs.hashCode^jitterMs.hashCode^packetLossPercent.hashCode^minLatencyMs.hashCode^avgLatencyMs.hashCode^maxLatencyMs.hashCode^p50Ms.hashCode^p90Ms.hashCode^p95Ms.hashCode^p99Ms.hashCode^stabilityScore.hashCode^spikeCount.hashCode^spikeSampleIndices.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LatencyStats &&
+                runtimeType == other.runtimeType
+                && latencyMs == other.latencyMs&& jitterMs == other.jitterMs&& packetLossPercent == other.packetLossPercent&& minLatencyMs == other.minLatencyMs&& avgLatencyMs == other.avgLatencyMs&& maxLatencyMs == other.maxLatencyMs&& p50Ms == other.p50Ms&& p90Ms == other.p90Ms&& p95Ms == other.p95Ms&& p99Ms == other.p99Ms&& stabilityScore == other.stabilityScore&& spikeCount == other.spikeCount&& spikeSampleIndices == other.spikeSampleIndices;
+        
+            }
+
+/// The verdict for a single rung of the layered connectivity ladder.
+///
+/// UI layers can map these directly to traffic-light colors.
+enum LayerStatus {
+                    /// The layer is working as expected.
+pass,
+/// The layer works, but with partial failures or suspicious results.
+degraded,
+/// The layer is broken.
+fail,
+/// There was not enough evidence to judge the layer (e.g. no probe exercised
+/// it, or a lower layer already failed).
+unknown,
+                    ;
+                    /// The localization key, e.g. `layer_status.degraded`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsReportLayerStatusL10NKey(that: this, );
+
+
+                }
+
+/// A per-layer (OSI-style) breakdown of a check cycle.
+///
+/// Each field answers "does this layer work?" so that a UI can render a simple
+/// ladder (link → network → DNS → transport → application) and point the user
+/// to the lowest failing rung instead of parsing raw flags.
+class LayeredDiagnosis  {
+                /// Physical/data-link layer: is there an active network interface?
+final LayerStatus link;
+/// IP/gateway layer: can packets leave the local network at all?
+final LayerStatus network;
+/// Name resolution: do hostname targets resolve, and can the resolver be trusted?
+final LayerStatus dns;
+/// Transport layer: do raw TCP/ICMP probes succeed?
+final LayerStatus transport;
+/// Application layer: do HTTP(S) probes succeed without interception?
+final LayerStatus application;
+
+                const LayeredDiagnosis({required this.link ,required this.network ,required this.dns ,required this.transport ,required this.application ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => link.hashCode^network.hashCode^dns.hashCode^transport.hashCode^application.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LayeredDiagnosis &&
+                runtimeType == other.runtimeType
+                && link == other.link&& network == other.network&& dns == other.dns&& transport == other.transport&& application == other.application;
+        
+            }
+
+/// The result of a LAN-only check (see [check_local_network](crate::api::engine::local::check_local_network)).
+class LocalReachabilityReport  {
+                /// The UTC timestamp (milliseconds since epoch) when the check began.
+final BigInt timestampMs;
+/// Monotonic clock reading (ms) when the check began; see
+/// [NetworkReport::monotonic_ms].
+final BigInt monotonicMs;
+/// [NetworkScope::LocalOnly] if anything on the LAN answered, else [NetworkScope::None].
+final NetworkScope scope;
+/// The default gateway address, if the platform exposes it.
+final String? gateway;
+/// The gateway probe result; `None` if no gateway was found.
+final TargetReport? gatewayReport;
+/// Results for [NetworkConfiguration::lan_targets](super::config::NetworkConfiguration::lan_targets).
+final List<TargetReport> targetReports;
+
+                const LocalReachabilityReport({required this.timestampMs ,required this.monotonicMs ,required this.scope ,this.gateway ,this.gatewayReport ,required this.targetReports ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => timestampMs.hashCode^monotonicMs.hashCode^scope.hashCode^gateway.hashCode^gatewayReport.hashCode^targetReports.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LocalReachabilityReport &&
+                runtimeType == other.runtimeType
+                && timestampMs == other.timestampMs&& monotonicMs == other.monotonicMs&& scope == other.scope&& gateway == other.gateway&& gatewayReport == other.gatewayReport&& targetReports == other.targetReports;
+        
+            }
+
+/// How far out from the device connectivity reaches.
+enum NetworkScope {
+                    /// Not even the local network is reachable.
+none,
+/// The gateway or a configured LAN target answers, but the internet does not.
+///
+/// Typical for airplane mode with Wi-Fi on, isolated industrial networks or
+/// a router that lost its uplink.
+localOnly,
+/// Internet targets are reachable.
+internet,
+                    ;
+                    /// The localization key, e.g. `scope.local_only`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsReportNetworkScopeL10NKey(that: this, );
+
+
+                }
 
 /// A high-level summary of the network's current state.
 ///
-/// This structure is what most UI layers will use to determine whether to
+/// This structure is what most UI layers will use to determine whether to 
 /// show a "Connected" or "Offline" banner.
-class NetworkStatus {
-  const NetworkStatus({
-    required this.isConnected,
-    required this.quality,
-    required this.latencyStats,
-    required this.winnerTarget,
-  });
-
-  /// True if the network is functionally "up" based on the configured strategy.
-  final bool isConnected;
-
-  /// The categorical quality of the connection (e.g., Excellent, Poor, Offline).
-  ///
-  /// Maps raw metrics to user-friendly buckets based on [`QualityThresholds`](super::config::QualityThresholds).
-  final ConnectionQuality quality;
-
-  /// Detailed statistical breakdown for performance analysis.
-  final LatencyStats latencyStats;
-
-  /// The label of the specific target that responded the fastest in this check.
-  ///
-  /// Useful for debugging and understanding which server is the
-  /// current "closest" endpoint.
-  final String winnerTarget;
-
-  @override
-  int get hashCode =>
-      isConnected.hashCode ^
-      quality.hashCode ^
-      latencyStats.hashCode ^
-      winnerTarget.hashCode;
-
-  @override
-  bool operator ==(final Object other) =>
-      identical(this, other) ||
-      other is NetworkStatus &&
-          runtimeType == other.runtimeType &&
-          isConnected == other.isConnected &&
-          quality == other.quality &&
-          latencyStats == other.latencyStats &&
-          winnerTarget == other.winnerTarget;
-}
+class NetworkStatus  {
+                /// True if the network is functionally "up" based on the configured strategy.
+final bool isConnected;
+/// The categorical quality of the connection (e.g., Excellent, Poor, Offline).
+///
+/// Maps raw metrics to user-friendly buckets based on [`QualityThresholds`](super::config::QualityThresholds).
+final ConnectionQuality quality;
+/// Detailed statistical breakdown for performance analysis.
+final LatencyStats latencyStats;
+/// The label of the target that won the most samples of this check.
+///
+/// A sample's winner is its fastest successful target under
+/// [`CheckStrategy::Race`](super::config::CheckStrategy::Race), or the
+/// median-latency success under
+/// [`CheckStrategy::Consensus`](super::config::CheckStrategy::Consensus)
+/// (see [select_winner](crate::api::engine::sampler::select_winner) for
+/// the other strategies). Useful for debugging and understanding which server is the
+/// current "closest" endpoint. Empty if no sample counted.
+final String winnerTarget;
+/// The winner of every sample, in order; `None` where the sample did not
+/// count towards the result.
+final List<String?> winnersPerSample;
+/// The factors that pulled `quality` down, in order of severity.
+///
+/// Empty if the quality is purely latency-based and at least
+/// [ConnectionQuality::Good].
+final List<QualityReason> reasons;
+
+                const NetworkStatus({required this.isConnected ,required this.quality ,required this.latencyStats ,required this.winnerTarget ,required this.winnersPerSample ,required this.reasons ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => isConnected.hashCode^quality.hashCode^latencyStats.hashCode^winnerTarget.hashCode^winnersPerSample.hashCode^reasons.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NetworkStatus &&
+                runtimeType == other.runtimeType
+                && isConnected == other.isConnected&& quality == other.quality&& latencyStats == other.latencyStats&& winnerTarget == other.winnerTarget&& winnersPerSample == other.winnersPerSample&& reasons == other.reasons;
+        
+            }
+
+/// How much of its timeout budget a probe spent, and on what.
+///
+/// Percentages are relative to the budget, so a `used_percent` that stays
+/// far below 100 on a healthy network means `timeout_ms` can be tightened,
+/// and one near 100 on successes means it is too tight. DNS done up front
+/// (see [pre_resolve_targets](super::config::NetworkConfiguration::pre_resolve_targets))
+/// is outside the budget and not counted.
+class ProbeTimings  {
+                /// The target's `timeout_ms`.
+final BigInt budgetMs;
+/// How long (ms) the probe ran, whether it succeeded or not.
+final BigInt elapsedMs;
+/// Share of the budget spent resolving the host.
+final double dnsPercent;
+/// Share of the budget spent on the TCP handshake or ICMP round trip.
+final double connectPercent;
+/// Share of the budget spent on everything else, mostly waiting for the
+/// target's answer.
+final double waitPercent;
+/// Share of the budget used in total; 100 for a probe that timed out.
+final double usedPercent;
+
+                const ProbeTimings({required this.budgetMs ,required this.elapsedMs ,required this.dnsPercent ,required this.connectPercent ,required this.waitPercent ,required this.usedPercent ,});
+
+                  // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+/// Splits `elapsed_ms` of a `budget_ms` probe into its phases.
+static Future<ProbeTimings>  newInstance({required BigInt budgetMs , required BigInt elapsedMs , BigInt? dnsMs , BigInt? connectMs })=>RustLib.instance.api.crateApiModelsReportProbeTimingsNew(budgetMs: budgetMs, elapsedMs: elapsedMs, dnsMs: dnsMs, connectMs: connectMs);
+
+
+                
+
+                
+        @override
+        int get hashCode => budgetMs.hashCode^elapsedMs.hashCode^dnsPercent.hashCode^connectPercent.hashCode^waitPercent.hashCode^usedPercent.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ProbeTimings &&
+                runtimeType == other.runtimeType
+                && budgetMs == other.budgetMs&& elapsedMs == other.elapsedMs&& dnsPercent == other.dnsPercent&& connectPercent == other.connectPercent&& waitPercent == other.waitPercent&& usedPercent == other.usedPercent;
+        
+            }
+
+/// Why a check ended up with the [ConnectionQuality] it reports.
+///
+/// Reasons are listed in [NetworkStatus::reasons] so a UI can explain a
+/// downgrade ("your connection is unstable because of packet loss") instead of
+/// only showing the bucket.
+enum QualityReason {
+                    /// No target could be reached.
+noTargetsReachable,
+/// Packet loss exceeded the configured critical threshold.
+packetLoss,
+/// Latency varied so much that the stability score forced a downgrade.
+highJitter,
+/// The latency itself only qualifies for a moderate or worse bucket.
+highLatency,
+/// Traffic is intercepted by a captive portal.
+captivePortal,
+/// No interface has a route to the internet or any target, so the check
+/// gave up without probing (see
+/// [fast_fail_offline](super::config::NetworkConfiguration::fast_fail_offline)).
+noRoute,
+                    ;
+                    /// The localization key, e.g. `reason.high_jitter`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsReportQualityReasonL10NKey(that: this, );
+
+
+                }
+
+/// The raw outcome of one sampling round (every target probed once).
+///
+/// Only collected if
+/// [include_raw_samples](super::config::NetworkConfiguration::include_raw_samples)
+/// is set, for users who want to run their own statistics.
+class SampleRecord  {
+                /// 0-based position of the sample within the check.
+final int index;
+/// UTC timestamp (milliseconds since epoch) when the sample started.
+final BigInt timestampMs;
+/// Monotonic clock reading (ms) when the sample started; see
+/// [NetworkReport::monotonic_ms].
+final BigInt monotonicMs;
+/// Per-target results, in configuration order. Targets the sample
+/// skipped under [CheckStrategy::Fallback](super::config::CheckStrategy::Fallback)
+/// are left out.
+final List<SampleTargetLatency> latencies;
+/// The sample's winner (see [NetworkStatus::winner_target]); `None` if the
+/// sample did not count.
+final String? winnerTarget;
+/// True if the sample counted as successful under the configured
+/// [CheckStrategy](super::config::CheckStrategy) and contributed a latency
+/// to [LatencyStats].
+final bool counted;
+
+                const SampleRecord({required this.index ,required this.timestampMs ,required this.monotonicMs ,required this.latencies ,this.winnerTarget ,required this.counted ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => index.hashCode^timestampMs.hashCode^monotonicMs.hashCode^latencies.hashCode^winnerTarget.hashCode^counted.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SampleRecord &&
+                runtimeType == other.runtimeType
+                && index == other.index&& timestampMs == other.timestampMs&& monotonicMs == other.monotonicMs&& latencies == other.latencies&& winnerTarget == other.winnerTarget&& counted == other.counted;
+        
+            }
+
+/// One target's outcome within a single sample.
+class SampleTargetLatency  {
+                /// The target's label.
+final String label;
+/// Latency in milliseconds, or `None` if the probe failed.
+final BigInt? latencyMs;
+
+                const SampleTargetLatency({required this.label ,this.latencyMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => label.hashCode^latencyMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is SampleTargetLatency &&
+                runtimeType == other.runtimeType
+                && label == other.label&& latencyMs == other.latencyMs;
+        
+            }
+
+/// A traffic-light summary of a [NetworkReport], e.g. for a UI badge.
+///
+/// See [NetworkReport::simple_status] for how a report maps onto it.
+enum SimpleStatus {
+                    /// The internet is reachable and works well.
+green,
+/// The internet is reachable, but slow, unreliable or not to be trusted.
+yellow,
+/// The internet is not usable.
+red,
+                    ;
+                    /// The localization key, e.g. `status.yellow`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsReportSimpleStatusL10NKey(that: this, );
+
+
+                }
+
+/// How one target fared over the checks of a
+/// [ReachabilityEngine](crate::api::engine::ReachabilityEngine).
+class TargetHealth  {
+                /// The [label](super::target::NetworkTarget::label) of the target.
+final String label;
+/// Checks in a row in which the target failed; 0 after a success.
+final int consecutiveFailures;
+/// UTC time (milliseconds since epoch) of the last check the target
+/// answered in.
+final BigInt? lastSuccessMs;
+/// UTC time (milliseconds since epoch) of the last check the target
+/// failed in.
+final BigInt? lastFailureMs;
+
+                const TargetHealth({required this.label ,required this.consecutiveFailures ,this.lastSuccessMs ,this.lastFailureMs ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => label.hashCode^consecutiveFailures.hashCode^lastSuccessMs.hashCode^lastFailureMs.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TargetHealth &&
+                runtimeType == other.runtimeType
+                && label == other.label&& consecutiveFailures == other.consecutiveFailures&& lastSuccessMs == other.lastSuccessMs&& lastFailureMs == other.lastFailureMs;
+        
+            }
 
 /// Detailed outcome of a connectivity check against a specific target.
 ///
-/// Each configured target (e.g., Google DNS, Cloudflare) generates its
+/// Each configured target (e.g., Google DNS, Cloudflare) generates its 
 /// own `TargetReport` during a check cycle.
-class TargetReport {
-  const TargetReport({
-    required this.label,
-    required this.success,
-    required this.latencyMs,
-    required this.isEssential,
-    this.error,
-  });
-
-  /// The unique label identifying the target (e.g., "Primary API Gateway").
-  final String label;
-
-  /// Indicates whether the target was successfully reached using the specified protocol.
-  ///
-  /// If `false`, the network is likely either down or this specific
-  /// target is experiencing an outage.
-  final bool success;
-
-  /// The measured latency in milliseconds for the fastest successful sample.
-  ///
-  /// This value is 0 if `success` is false.
-  final BigInt latencyMs;
-
-  /// A descriptive error message provided by the engine if the check failed.
-  ///
-  /// Common errors: "Connection Refused", "DNS Resolution Failed", "Timeout".
-  final String? error;
-
-  /// Indicates if this target is critical for the overall "Connected" status.
-  ///
-  /// Failure of an essential target has a higher weight in the
-  /// [`CheckStrategy`](super::config::CheckStrategy).
-  final bool isEssential;
-
-  @override
-  int get hashCode =>
-      label.hashCode ^
-      success.hashCode ^
-      latencyMs.hashCode ^
-      error.hashCode ^
-      isEssential.hashCode;
-
-  @override
-  bool operator ==(final Object other) =>
-      identical(this, other) ||
-      other is TargetReport &&
-          runtimeType == other.runtimeType &&
-          label == other.label &&
-          success == other.success &&
-          latencyMs == other.latencyMs &&
-          error == other.error &&
-          isEssential == other.isEssential;
-}
+class TargetReport  {
+                /// The unique label identifying the target (e.g., "Primary API Gateway").
+final String label;
+/// Indicates whether the target was successfully reached using the specified protocol.
+///
+/// If `false`, the network is likely either down or this specific 
+/// target is experiencing an outage.
+final bool success;
+/// The measured latency in milliseconds for the fastest successful sample.
+///
+/// This value is 0 if `success` is false.
+final BigInt latencyMs;
+/// A descriptive error message provided by the engine if the check failed.
+///
+/// Common errors: "Connection Refused", "DNS Resolution Failed", "Timeout".
+final String? error;
+/// Indicates if this target is critical for the overall "Connected" status.
+///
+/// Failure of an essential target has a higher weight in the 
+/// [`CheckStrategy`](super::config::CheckStrategy).
+final bool isEssential;
+/// Time (ms) spent resolving the target's hostname.
+///
+/// `None` if the host is an IP literal or resolution never finished.
+final BigInt? dnsMs;
+/// Time (ms) spent establishing the TCP connection, excluding DNS.
+///
+/// Only measured for [TargetProtocol::Tcp](super::target::TargetProtocol::Tcp)
+/// targets, and as the echo round trip for
+/// [TargetProtocol::Icmp](super::target::TargetProtocol::Icmp) ones; `None`
+/// otherwise or if the handshake never completed. HTTP(S) targets leave
+/// it empty, as the client does not expose its handshake. Comparing
+/// it with `dns_ms` tells a slow resolver apart from a slow path.
+final BigInt? connectMs;
+/// Set when the OS reported an ICMP "Destination Unreachable" for the probe.
+///
+/// Only UDP-based probes ([TargetProtocol::Udp](super::target::TargetProtocol::Udp)
+/// and [TargetProtocol::Dns](super::target::TargetProtocol::Dns)) fill this
+/// in, and only on platforms that surface the error on the socket. `None` on
+/// a failed UDP probe means the datagram was dropped silently.
+final IcmpUnreachable? unreachable;
+/// How long (ms) the probe ran before it timed out.
+///
+/// Only set for timed-out probes of targets with
+/// [report_timeout_progress](super::target::NetworkTarget::report_timeout_progress);
+/// `latency_ms` stays 0 either way.
+final BigInt? timedOutAfterMs;
+/// When (ms after the start) a timed-out probe last made progress, e.g.
+/// finished resolving or connecting. Set alongside `timed_out_after_ms`,
+/// and `None` if the probe never got past its first phase.
+///
+/// Close to `timed_out_after_ms`, the target was slow to answer a probe
+/// that otherwise went through; close to 0, it stalled early.
+final BigInt? lastProgressMs;
+/// The IP address the probe was sent to, e.g. `2606:4700::1111`.
+///
+/// Shows which answer of a round-robin record was used and whether the
+/// probe fell back to IPv4. `None` if the host never resolved; for
+/// HTTP(S) targets, the address the connection was made to.
+final String? resolvedAddr;
+/// The family of [resolved_addr](Self::resolved_addr).
+final AddressFamily? addressFamily;
+/// True if the target was not probed because a higher-priority target
+/// answered under [CheckStrategy::Fallback](super::config::CheckStrategy::Fallback).
+/// A skipped target is neither a success nor a failure.
+final bool skipped;
+/// How the probe used its [timeout](super::target::NetworkTarget::timeout_ms)
+/// budget. `None` if the target was not probed.
+final ProbeTimings? timings;
+/// Latency (ms) of this target in every sample of the check that probed
+/// it, oldest first; `None` where it failed. Samples that
+/// [skipped](Self::skipped) it are left out, so they do not count as loss.
+///
+/// The other fields describe the last sample only. Use these to compute
+/// per-target jitter and loss. Empty for reports from outside a sampled
+/// check, e.g. [check_target](crate::api::probes::check_target).
+final List<BigInt?> samples;
+/// The method used instead of the target's protocol, if the process
+/// lacks the privileges for it (e.g. [ProbeFallback::TcpPing] for ICMP
+/// targets without ICMP sockets).
+final ProbeFallback? fallback;
+
+                const TargetReport({required this.label ,required this.success ,required this.latencyMs ,this.error ,required this.isEssential ,this.dnsMs ,this.connectMs ,this.unreachable ,this.timedOutAfterMs ,this.lastProgressMs ,this.resolvedAddr ,this.addressFamily ,required this.skipped ,this.timings ,required this.samples ,this.fallback ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => label.hashCode^success.hashCode^latencyMs.hashCode^error.hashCode^isEssential.hashCode^dnsMs.hashCode^connectMs.hashCode^unreachable.hashCode^timedOutAfterMs.hashCode^lastProgressMs.hashCode^resolvedAddr.hashCode^addressFamily.hashCode^skipped.hashCode^timings.hashCode^samples.hashCode^fallback.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TargetReport &&
+                runtimeType == other.runtimeType
+                && label == other.label&& success == other.success&& latencyMs == other.latencyMs&& error == other.error&& isEssential == other.isEssential&& dnsMs == other.dnsMs&& connectMs == other.connectMs&& unreachable == other.unreachable&& timedOutAfterMs == other.timedOutAfterMs&& lastProgressMs == other.lastProgressMs&& resolvedAddr == other.resolvedAddr&& addressFamily == other.addressFamily&& skipped == other.skipped&& timings == other.timings&& samples == other.samples&& fallback == other.fallback;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,398 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+import 'package:freezed_annotation/freezed_annotation.dart' hide protected;
+part 'scan.freezed.dart';
+
+            // These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`, `fmt`
+
+
+            
+
+            /// One complete entry of the OS neighbor (ARP) table.
+///
+/// Produced by [read_arp_table](crate::api::probes::arp::read_arp_table).
+class ArpEntry  {
+                /// The neighbor's IPv4 address.
+final String ipAddress;
+/// The neighbor's MAC address (lowercase, colon-separated).
+final String macAddress;
+/// The interface the neighbor was seen on, where the platform reports it.
+final String? interface_;
+
+                const ArpEntry({required this.ipAddress ,required this.macAddress ,this.interface_ ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => ipAddress.hashCode^macAddress.hashCode^interface_.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ArpEntry &&
+                runtimeType == other.runtimeType
+                && ipAddress == other.ipAddress&& macAddress == other.macAddress&& interface_ == other.interface_;
+        
+            }
+
+@freezed
+                sealed class DeviceEvent with _$DeviceEvent  {
+                    const DeviceEvent._();
+
+                     /// A device that was not present in the previous scan answered.
+const factory DeviceEvent.joined(  LocalDevice field0,) = DeviceEvent_Joined;
+ /// A device from the previous scan no longer answers.
+const factory DeviceEvent.left(  LocalDevice field0,) = DeviceEvent_Left;
+ /// A device (identified by its MAC address) moved to a new IP address.
+const factory DeviceEvent.ipChanged({   required String macAddress ,  required String oldIp ,  required String newIp , }) = DeviceEvent_IpChanged;
+
+                    
+
+                    
+                }
+
+/// What could be learned about the router behind the default gateway.
+///
+/// Produced by [fingerprint_gateway](crate::api::probes::gateway::fingerprint_gateway).
+/// Every source is best-effort; a router that exposes nothing still yields a
+/// fingerprint with only `ip_address` set.
+class GatewayFingerprint  {
+                /// The gateway's IP address.
+final String ipAddress;
+/// The gateway's MAC address, from the OS neighbor table.
+final String? macAddress;
+/// The vendor registered for the MAC address prefix (OUI).
+final String? macVendor;
+/// The `<title>` of the web admin page.
+final String? httpTitle;
+/// The `Server` header of the web admin page.
+final String? httpServer;
+/// The UPnP `friendlyName`, often the model as marketed.
+final String? upnpFriendlyName;
+/// The UPnP `manufacturer`.
+final String? upnpManufacturer;
+/// The UPnP `modelName`.
+final String? upnpModelName;
+/// The UPnP `modelNumber`.
+final String? upnpModelNumber;
+/// The best guess for the router's vendor: the UPnP manufacturer, else the
+/// MAC vendor.
+final String? vendor;
+/// The best guess for the router's model: the UPnP model name and number,
+/// else the admin page title.
+final String? model;
+
+                const GatewayFingerprint({required this.ipAddress ,this.macAddress ,this.macVendor ,this.httpTitle ,this.httpServer ,this.upnpFriendlyName ,this.upnpManufacturer ,this.upnpModelName ,this.upnpModelNumber ,this.vendor ,this.model ,});
+
+                static Future<GatewayFingerprint>  default_()=>RustLib.instance.api.crateApiModelsScanGatewayFingerprintDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => ipAddress.hashCode^macAddress.hashCode^macVendor.hashCode^httpTitle.hashCode^httpServer.hashCode^upnpFriendlyName.hashCode^upnpManufacturer.hashCode^upnpModelName.hashCode^upnpModelNumber.hashCode^vendor.hashCode^model.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is GatewayFingerprint &&
+                runtimeType == other.runtimeType
+                && ipAddress == other.ipAddress&& macAddress == other.macAddress&& macVendor == other.macVendor&& httpTitle == other.httpTitle&& httpServer == other.httpServer&& upnpFriendlyName == other.upnpFriendlyName&& upnpManufacturer == other.upnpManufacturer&& upnpModelName == other.upnpModelName&& upnpModelNumber == other.upnpModelNumber&& vendor == other.vendor&& model == other.model;
+        
+            }
+
+/// A device found on the local network.
+class LocalDevice  {
+                /// The device's IP address.
+final String ipAddress;
+/// The device's hostname, if it could be resolved (see
+/// [LocalScanConfig::resolve_names] and [LocalScanConfig::lookup_hostnames]).
+final String? hostname;
+/// The device's MAC address, from the OS neighbor table the sweep
+/// populated. Only known for devices on the same link.
+final String? macAddress;
+/// The vendor registered for the MAC address prefix (OUI).
+final String? macVendor;
+/// Time (ms) until the device answered on its fastest port.
+final BigInt latencyMs;
+/// The scanned ports that accepted a connection, in ascending order.
+/// Ports that refused it proved the device is up but are not listed.
+final Uint16List openPorts;
+/// The device answered an ICMP echo request. Always false unless the
+/// sweep used [ScanMethod::IcmpSweep].
+final bool answeredPing;
+/// Services identified on the device's open ports.
+///
+/// Empty unless [LocalScanConfig::grab_banners] is set.
+final List<ServiceInfo> services;
+
+                const LocalDevice({required this.ipAddress ,this.hostname ,this.macAddress ,this.macVendor ,required this.latencyMs ,required this.openPorts ,required this.answeredPing ,required this.services ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => ipAddress.hashCode^hostname.hashCode^macAddress.hashCode^macVendor.hashCode^latencyMs.hashCode^openPorts.hashCode^answeredPing.hashCode^services.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LocalDevice &&
+                runtimeType == other.runtimeType
+                && ipAddress == other.ipAddress&& hostname == other.hostname&& macAddress == other.macAddress&& macVendor == other.macVendor&& latencyMs == other.latencyMs&& openPorts == other.openPorts&& answeredPing == other.answeredPing&& services == other.services;
+        
+            }
+
+/// Settings for a local network sweep (see
+/// [scan_local_network](crate::api::probes::local_scan::scan_local_network)).
+///
+/// # Address Ranges
+///
+/// `allow_ranges` and `exclude_ranges` accept single addresses
+/// (`192.168.1.20`), CIDR blocks (`192.168.1.64/26`) and inclusive ranges
+/// (`192.168.1.100-192.168.1.150`). They apply to every scan mode: an excluded
+/// address is never sent a single packet.
+class LocalScanConfig  {
+                /// The subnet to sweep, in CIDR notation (e.g. `192.168.1.0/24`).
+final String subnet;
+/// The TCP port connected to on every host.
+///
+/// A refused connection also counts as a discovered device, since only a
+/// live host can answer with a reset.
+final int scanPort;
+/// The TCP ports connected to on every host instead of `scan_port`, e.g.
+/// [COMMON_SCAN_PORTS](LibConstants::COMMON_SCAN_PORTS).
+///
+/// A host answering on any of them is a discovered device. The ports of
+/// a host are probed at once, as far as `max_concurrency` allows. Empty by
+/// default, which probes `scan_port`.
+final Uint16List scanPorts;
+/// How hosts are probed.
+/// *Default: [ScanMethod::TcpConnect]*
+final ScanMethod method;
+/// How long (ms) to wait for each host.
+final BigInt timeoutMs;
+/// How many sockets a sweep holds at once, counting every port connect,
+/// ICMP echo and open banner connection. This bounds the file
+/// descriptors a sweep uses, however many ports are scanned.
+final BigInt maxConcurrency;
+/// The most hosts a sweep may cover after applying `allow_ranges` and
+/// `exclude_ranges`. A larger scope fails with
+/// [NetworkError::InvalidConfiguration](crate::api::models::NetworkError::InvalidConfiguration)
+/// instead of sweeping e.g. a whole /16 by accident.
+final BigInt maxHosts;
+/// If non-empty, only addresses inside one of these ranges are scanned.
+final List<String> allowRanges;
+/// Addresses that are never probed, e.g. printers or cameras known to
+/// crash when scanned. Takes precedence over `allow_ranges`.
+final List<String> excludeRanges;
+/// Read a short banner from every open port to identify the service
+/// (SSH, HTTP, RTSP).
+///
+/// Off by default, as it keeps connections open for up to
+/// `banner_timeout_ms` longer.
+final bool grabBanners;
+/// How long (ms) to wait for a banner on each open port.
+final BigInt bannerTimeoutMs;
+/// Look up the name of every discovered device via LLMNR and NetBIOS,
+/// which Windows machines answer even without any DNS record.
+///
+/// Off by default, as it sends every device two more queries. Runs in
+/// the same pass as `lookup_hostnames`, taking up to
+/// `hostname_lookup_timeout_ms` per device.
+final bool resolveNames;
+/// After the sweep, look up the hostname of every device via reverse DNS
+/// and mDNS (`.local` names of Apple devices, printers and most Linux
+/// machines).
+///
+/// Off by default. At most `hostname_lookup_concurrency` devices are
+/// looked up at once, each for up to `hostname_lookup_timeout_ms`.
+final bool lookupHostnames;
+/// How long (ms) to wait for the name of each device, from any of the
+/// sources `resolve_names` and `lookup_hostnames` enable.
+final BigInt hostnameLookupTimeoutMs;
+/// How many devices' names are looked up at once, with `resolve_names`
+/// or `lookup_hostnames`.
+final BigInt hostnameLookupConcurrency;
+
+                const LocalScanConfig({required this.subnet ,required this.scanPort ,required this.scanPorts ,required this.method ,required this.timeoutMs ,required this.maxConcurrency ,required this.maxHosts ,required this.allowRanges ,required this.excludeRanges ,required this.grabBanners ,required this.bannerTimeoutMs ,required this.resolveNames ,required this.lookupHostnames ,required this.hostnameLookupTimeoutMs ,required this.hostnameLookupConcurrency ,});
+
+                /// Sweeps `192.168.1.0/24` on port 80 with a 300 ms per-host timeout.
+static Future<LocalScanConfig>  default_()=>RustLib.instance.api.crateApiModelsScanLocalScanConfigDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => subnet.hashCode^scanPort.hashCode^scanPorts.hashCode^method.hashCode^timeoutMs.hashCode^maxConcurrency.hashCode^maxHosts.hashCode^allowRanges.hashCode^excludeRanges.hashCode^grabBanners.hashCode^bannerTimeoutMs.hashCode^resolveNames.hashCode^lookupHostnames.hashCode^hostnameLookupTimeoutMs.hashCode^hostnameLookupConcurrency.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is LocalScanConfig &&
+                runtimeType == other.runtimeType
+                && subnet == other.subnet&& scanPort == other.scanPort&& scanPorts == other.scanPorts&& method == other.method&& timeoutMs == other.timeoutMs&& maxConcurrency == other.maxConcurrency&& maxHosts == other.maxHosts&& allowRanges == other.allowRanges&& excludeRanges == other.excludeRanges&& grabBanners == other.grabBanners&& bannerTimeoutMs == other.bannerTimeoutMs&& resolveNames == other.resolveNames&& lookupHostnames == other.lookupHostnames&& hostnameLookupTimeoutMs == other.hostnameLookupTimeoutMs&& hostnameLookupConcurrency == other.hostnameLookupConcurrency;
+        
+            }
+
+/// A service announced on the local network via mDNS/DNS-SD (see
+/// [browse_mdns_services](crate::api::probes::mdns::browse_mdns_services)).
+class MdnsService  {
+                /// The human-readable instance name, e.g. `Living Room TV`.
+final String instanceName;
+/// The service type, e.g. `_googlecast._tcp.local` or `_ipp._tcp.local`.
+final String serviceType;
+/// The host offering the service, from its SRV record (`Chromecast-1a2b.local`).
+final String? hostname;
+/// The port of the service, from its SRV record.
+final int? port;
+/// The addresses of `hostname`, or the address the announcement came
+/// from if the responder sent none.
+final List<String> ipAddresses;
+/// The entries of the TXT record (`key=value` or a bare `key`), e.g. the
+/// model (`md=Chromecast`) or the HomeKit pairing state (`sf=1`).
+final List<String> txtRecords;
+
+                const MdnsService({required this.instanceName ,required this.serviceType ,this.hostname ,this.port ,required this.ipAddresses ,required this.txtRecords ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => instanceName.hashCode^serviceType.hashCode^hostname.hashCode^port.hashCode^ipAddresses.hashCode^txtRecords.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MdnsService &&
+                runtimeType == other.runtimeType
+                && instanceName == other.instanceName&& serviceType == other.serviceType&& hostname == other.hostname&& port == other.port&& ipAddresses == other.ipAddresses&& txtRecords == other.txtRecords;
+        
+            }
+
+@freezed
+                sealed class ScanEvent with _$ScanEvent  {
+                    const ScanEvent._();
+
+                     /// A device answered. Its MAC address, vendor and (if enabled) hostname
+/// are already filled in.
+const factory ScanEvent.device(  LocalDevice field0,) = ScanEvent_Device;
+ /// The sweep is still running; sent every
+/// [SCAN_PROGRESS_INTERVAL_MS](crate::api::constants::LibConstants::SCAN_PROGRESS_INTERVAL_MS)
+/// with the counts so far and `cancelled` unset.
+const factory ScanEvent.progress(  ScanSummary field0,) = ScanEvent_Progress;
+ /// The sweep ended; always the last event.
+const factory ScanEvent.finished(  ScanSummary field0,) = ScanEvent_Finished;
+
+                    
+
+                    
+                }
+
+/// How a local network sweep finds devices.
+enum ScanMethod {
+                    /// TCP connects to the scanned ports only.
+tcpConnect,
+/// ICMP echo requests alongside the TCP connects, which also finds
+/// devices without any open or closed TCP port (phones, IoT). Falls back
+/// to TCP connects alone where the process may not send ICMP (see
+/// [detect_probe_capabilities](crate::api::probes::capabilities::detect_probe_capabilities)),
+/// as noted in [ScanSummary::fallback].
+icmpSweep,
+                    ;
+                    /// Returns [`ScanMethod::TcpConnect`] as the default.
+static Future<ScanMethod>  default_()=>RustLib.instance.api.crateApiModelsScanScanMethodDefault();
+
+
+/// The localization key, e.g. `scan_method.icmp_sweep`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsScanScanMethodL10NKey(that: this, );
+
+
+                }
+
+/// The outcome of a streaming sweep, or its state while it runs.
+class ScanSummary  {
+                /// How many hosts the scope covered.
+final int hostsScanned;
+/// How many devices were reported before the sweep ended (or so far).
+final int devicesFound;
+/// Time (ms) from the start of the sweep until it ended (or so far).
+final BigInt durationMs;
+/// The sweep was cancelled before every host was probed.
+final bool cancelled;
+/// [ProbeFallback::TcpPing] if [ScanMethod::IcmpSweep] was asked for but
+/// the process may not send ICMP echoes, so only TCP connects were made.
+final ProbeFallback? fallback;
+
+                const ScanSummary({required this.hostsScanned ,required this.devicesFound ,required this.durationMs ,required this.cancelled ,this.fallback ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => hostsScanned.hashCode^devicesFound.hashCode^durationMs.hashCode^cancelled.hashCode^fallback.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ScanSummary &&
+                runtimeType == other.runtimeType
+                && hostsScanned == other.hostsScanned&& devicesFound == other.devicesFound&& durationMs == other.durationMs&& cancelled == other.cancelled&& fallback == other.fallback;
+        
+            }
+
+/// A service identified from the banner of an open port.
+class ServiceInfo  {
+                /// The TCP port the service listens on.
+final int port;
+/// The protocol recognized from the banner (`ssh`, `http`), if any.
+final String? protocol;
+/// The service's self-description: the SSH version string, the HTTP
+/// `Server` header or the first line of any other greeting.
+final String? banner;
+
+                const ServiceInfo({required this.port ,this.protocol ,this.banner ,});
+
+                
+                
+
+                
+        @override
+        int get hashCode => port.hashCode^protocol.hashCode^banner.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is ServiceInfo &&
+                runtimeType == other.runtimeType
+                && port == other.port&& protocol == other.protocol&& banner == other.banner;
+        
+            }
+            
\ No newline at end of file
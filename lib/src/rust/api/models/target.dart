@@ -1,141 +1,209 @@
 // This file is automatically generated, so please do not edit it.
 // @generated by `flutter_rust_bridge`@ 2.12.0.
 
-// ignore_for_file: public_member_api_docs, invalid_use_of_internal_member, unused_import, unnecessary_import
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import '../../frb_generated.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-import '../../frb_generated.dart';
 
-// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `eq`, `fmt`, `fmt`
+            // These functions are ignored because they are not marked as `pub`: `is_valid_dns_name`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `NetworkTargetBuilder`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `builder`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `build`, `dns_query_name`, `dscp`, `essential`, `label`, `new`, `port`, `priority`, `report_timeout_progress`, `timeout_ms`, `udp_validation`, `weight`
+
+
+            
 
-/// Configuration for a specific network endpoint to be monitored.
+            /// Configuration for a specific network endpoint to be monitored.
 ///
-/// A `NetworkTarget` combines an address, a port, and a protocol to
+/// A `NetworkTarget` combines an address, a port, and a protocol to 
 /// define a unique "probe point".
-class NetworkTarget {
-  const NetworkTarget({
-    required this.label,
-    required this.host,
-    required this.port,
-    required this.protocol,
-    required this.timeoutMs,
-    required this.priority,
-    required this.isEssential,
-  });
-
-  /// A human-readable identifier (e.g., "Google Cloud DNS", "Internal API").
-  /// This label is used in [`TargetReport`](super::report::TargetReport) results.
-  final String label;
-
-  /// The remote address to check.
-  ///
-  /// Can be a fully qualified domain name (e.g., `google.com`) or
-  /// a raw IP address (e.g., `1.1.1.1`).
-  final String host;
-
-  /// The destination port for the check.
-  ///
-  /// Common ports:
-  /// - 80: Default for HTTP
-  /// - 443: Default for HTTPS
-  /// - 53: Default for DNS (TCP)
-  final int port;
-
-  /// The network protocol to use for this specific probe.
-  final TargetProtocol protocol;
-
-  /// The maximum duration (in milliseconds) the engine should wait for a response.
-  ///
-  /// If no response is received within this window, the target is
-  /// marked with a [`TimeoutError`](super::error::NetworkError::TimeoutError).
-  final BigInt timeoutMs;
-
-  /// The relative priority of this target (lower numbers = higher priority).
-  ///
-  /// Currently used for UI sorting and as a hint for the [`Race`](super::config::CheckStrategy::Race)
-  /// strategy to decide which targets to fire first.
-  final int priority;
-
-  /// If true, a failure of this target is treated as a severe network event.
-  ///
-  /// Essential targets are the primary triggers for the engine's
-  /// "Circuit Breaker" mechanism. Failure of an essential target can
-  /// disqualify the entire network status regardless of other successes.
-  final bool isEssential;
-
-  @override
-  int get hashCode =>
-      label.hashCode ^
-      host.hashCode ^
-      port.hashCode ^
-      protocol.hashCode ^
-      timeoutMs.hashCode ^
-      priority.hashCode ^
-      isEssential.hashCode;
-
-  @override
-  bool operator ==(final Object other) =>
-      identical(this, other) ||
-      other is NetworkTarget &&
-          runtimeType == other.runtimeType &&
-          label == other.label &&
-          host == other.host &&
-          port == other.port &&
-          protocol == other.protocol &&
-          timeoutMs == other.timeoutMs &&
-          priority == other.priority &&
-          isEssential == other.isEssential;
-}
+class NetworkTarget  {
+                /// A human-readable identifier (e.g., "Google Cloud DNS", "Internal API").
+/// This label is used in [`TargetReport`](super::report::TargetReport) results.
+final String label;
+/// The remote address to check.
+///
+/// Can be a fully qualified domain name (e.g., `google.com`) or 
+/// a raw IP address (e.g., `1.1.1.1`).
+final String host;
+/// The destination port for the check.
+///
+/// Common ports:
+/// - 80: Default for HTTP
+/// - 443: Default for HTTPS
+/// - 53: Default for DNS
+final int port;
+/// The network protocol to use for this specific probe.
+final TargetProtocol protocol;
+/// The maximum duration (in milliseconds) the engine should wait for a response.
+///
+/// If no response is received within this window, the target is 
+/// marked with a [`TimeoutError`](super::error::NetworkError::TimeoutError).
+final BigInt timeoutMs;
+/// The relative priority of this target (lower numbers = higher priority).
+///
+/// Under [`Fallback`](super::config::CheckStrategy::Fallback), targets are
+/// probed one priority level at a time, starting with the lowest number.
+/// The other strategies probe all targets at once and ignore it.
+final int priority;
+/// If true, a failure of this target is treated as a severe network event.
+///
+/// Essential targets are the primary triggers for the engine's 
+/// "Circuit Breaker" mechanism. Failure of an essential target can 
+/// disqualify the entire network status regardless of other successes.
+final bool isEssential;
+/// How much a success of this target counts under
+/// [`Weighted`](super::config::CheckStrategy::Weighted); ignored by the
+/// other strategies. Defaults to 1.
+final int weight;
+/// Reply validation for [`TargetProtocol::Udp`] targets; ignored otherwise.
+final UdpValidation udpValidation;
+/// The name looked up by [`TargetProtocol::Dns`] targets; ignored otherwise.
+///
+/// `None` uses [`DEFAULT_DNS_QUERY_NAME`](crate::api::constants::LibConstants::DEFAULT_DNS_QUERY_NAME).
+final String? dnsQueryName;
+/// The DSCP code point (0-63) to mark probe packets with, e.g. 46 for
+/// Expedited Forwarding. `None` leaves the OS default (best effort).
+///
+/// Applied to TCP, UDP, DNS and ICMP probes; HTTP(S) probes are sent
+/// unmarked. Some platforms ignore the marking (notably Windows without
+/// a QoS policy) and IPv6 marking is only supported on Linux and Android.
+final int? dscp;
+/// If true, a timed-out probe reports how long it ran and when it last
+/// made progress (see [`TargetReport::timed_out_after_ms`](super::report::TargetReport::timed_out_after_ms))
+/// instead of only a timeout error.
+final bool reportTimeoutProgress;
+
+                const NetworkTarget({required this.label ,required this.host ,required this.port ,required this.protocol ,required this.timeoutMs ,required this.priority ,required this.isEssential ,required this.weight ,required this.udpValidation ,this.dnsQueryName ,this.dscp ,required this.reportTimeoutProgress ,});
+
+                /// A TCP target without a host, whose values fill in the fields a
+/// deserialized target leaves out. The empty host fails validation.
+static Future<NetworkTarget>  default_()=>RustLib.instance.api.crateApiModelsTargetNetworkTargetDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => label.hashCode^host.hashCode^port.hashCode^protocol.hashCode^timeoutMs.hashCode^priority.hashCode^isEssential.hashCode^weight.hashCode^udpValidation.hashCode^dnsQueryName.hashCode^dscp.hashCode^reportTimeoutProgress.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NetworkTarget &&
+                runtimeType == other.runtimeType
+                && label == other.label&& host == other.host&& port == other.port&& protocol == other.protocol&& timeoutMs == other.timeoutMs&& priority == other.priority&& isEssential == other.isEssential&& weight == other.weight&& udpValidation == other.udpValidation&& dnsQueryName == other.dnsQueryName&& dscp == other.dscp&& reportTimeoutProgress == other.reportTimeoutProgress;
+        
+            }
 
 /// Supported network protocols for performing reachability probes.
 ///
-/// Each protocol has different performance characteristics and requires
+/// Each protocol has different performance characteristics and requires 
 /// different system permissions.
 enum TargetProtocol {
-  /// Transmission Control Protocol (TCP).
-  ///
-  /// # Behavior
-  /// Performs a full TCP 3-way handshake (`SYN` -> `SYN-ACK` -> `ACK`).
-  /// If the connection is established, the target is considered "up".
-  ///
-  /// # Pros/Cons
-  /// + Reliable; works through most firewalls on standard ports (80/443).
-  /// - More overhead than ICMP (Ping) as it requires a stateful connection.
-  tcp,
-
-  /// Internet Control Message Protocol (ICMP).
-  ///
-  /// # Behavior
-  /// Sends an "Echo Request" (Ping) and waits for an "Echo Reply".
-  ///
-  /// # Pros/Cons
-  /// + Extremely low overhead; the "purest" measure of network latency.
-  /// - Often blocked by public firewalls or corporate proxies.
-  /// - May require `root` or special capabilities on some Linux systems.
-  icmp,
-
-  /// Hypertext Transfer Protocol (HTTP/1.1 or HTTP/2).
-  ///
-  /// # Behavior
-  /// Performs a full HTTP/1.1 `GET` or `HEAD` request to the target URL.
-  /// The target is considered "up" if it returns a 2xx (Success) or
-  /// 3xx (Redirection) status code.
-  ///
-  /// # Use Case
-  /// Best for validating that a specific web service or API is actually
-  /// functional, not just that its port is open.
-  http,
-
-  /// Secure Hypertext Transfer Protocol (HTTPS).
-  ///
-  /// # Behavior
-  /// Performs an encrypted request over TLS/SSL. The engine validates
-  /// the certificate chain and expiration by default.
-  ///
-  /// # Pros/Cons
-  /// + High confidence; validates that the end-to-end encrypted path is open.
-  /// - Highest overhead; requires a full TLS handshake.
-  https,
-  ;
-}
+                    /// Transmission Control Protocol (TCP).
+///
+/// # Behavior
+/// Performs a full TCP 3-way handshake (`SYN` -> `SYN-ACK` -> `ACK`). 
+/// If the connection is established, the target is considered "up".
+///
+/// # Pros/Cons
+/// + Reliable; works through most firewalls on standard ports (80/443).
+/// - More overhead than ICMP (Ping) as it requires a stateful connection.
+tcp,
+/// Internet Control Message Protocol (ICMP).
+///
+/// # Behavior
+/// Sends an "Echo Request" (Ping) and waits for an "Echo Reply". An
+/// unprivileged ICMP datagram socket is used where the OS allows one,
+/// falling back to a raw socket otherwise. The echo round trip is
+/// reported as the report's `latency_ms` and `connect_ms`, leaving out
+/// name resolution and socket setup.
+///
+/// # Pros/Cons
+/// + Extremely low overhead; the "purest" measure of network latency.
+/// - Often blocked by public firewalls or corporate proxies.
+/// - On Linux, needs either membership of `net.ipv4.ping_group_range` or
+///   `CAP_NET_RAW`.
+icmp,
+/// Hypertext Transfer Protocol (HTTP/1.1 or HTTP/2).
+///
+/// # Behavior
+/// Performs a full HTTP/1.1 `GET` or `HEAD` request to the target URL. 
+/// The target is considered "up" if it returns a 2xx (Success) or 
+/// 3xx (Redirection) status code.
+///
+/// # Use Case
+/// Best for validating that a specific web service or API is actually 
+/// functional, not just that its port is open.
+http,
+/// Secure Hypertext Transfer Protocol (HTTPS).
+///
+/// # Behavior
+/// Performs an encrypted request over TLS/SSL. The engine validates 
+/// the certificate chain and expiration by default.
+///
+/// # Pros/Cons
+/// + High confidence; validates that the end-to-end encrypted path is open.
+/// - Highest overhead; requires a full TLS handshake.
+https,
+/// User Datagram Protocol (UDP).
+///
+/// # Behavior
+/// Sends a single datagram and waits for a reply, which is validated
+/// according to [`NetworkTarget::udp_validation`]. UDP is connectionless,
+/// so only an answer proves the service is reachable.
+///
+/// # Pros/Cons
+/// + Exercises the path used by DNS, NTP, QUIC/HTTP3, VoIP and games.
+/// - Many networks silently drop unexpected UDP; pick a payload the
+///   target actually answers.
+udp,
+/// Domain Name System query (over UDP).
+///
+/// # Behavior
+/// Asks the target, as a DNS server, to resolve
+/// [`NetworkTarget::dns_query_name`] and requires a well-formed answer.
+/// `NXDOMAIN` counts as success (the server works); `SERVFAIL` or
+/// `REFUSED` do not.
+///
+/// # Pros/Cons
+/// + Verifies the DNS service itself, not just an open port.
+/// - Only meaningful for targets that are DNS servers.
+dns,
+                    ;
+                    /// The localization key, e.g. `protocol.https`.
+ String  l10NKey()=>RustLib.instance.api.crateApiModelsTargetTargetProtocolL10NKey(that: this, );
+
+
+                }
+
+/// How the reply to a [`TargetProtocol::Udp`] probe is validated.
+enum UdpValidation {
+                    /// Any datagram sent back by the target counts as success.
+///
+/// The probe payload is a single zero byte, which suits echo-style
+/// services only.
+anyResponse,
+/// Sends a DNS query and requires a well-formed DNS response with the
+/// matching transaction ID (typically port 53).
+dns,
+/// Sends an SNTP client request and requires a server reply echoing our
+/// transmit timestamp (typically port 123).
+ntp,
+/// Sends a QUIC long-header packet with a reserved version and requires
+/// a Version Negotiation packet echoing our connection IDs (typically
+/// port 443). Proves a QUIC/HTTP3 endpoint without a TLS handshake.
+quic,
+                    ;
+                    /// Returns [`UdpValidation::AnyResponse`] as the default.
+static Future<UdpValidation>  default_()=>RustLib.instance.api.crateApiModelsTargetUdpValidationDefault();
+
+
+                }
+            
\ No newline at end of file
@@ -0,0 +1,129 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../frb_generated.dart';
+import 'analysis/anomaly.dart';
+import 'models/config.dart';
+import 'models/report.dart';
+import 'models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `lock`, `next_wait`, `run`, `task`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `assert_fields_are_eq`, `clone`, `clone`, `drop`, `eq`, `eq`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `config`, `reports`, `with_rate_limiter`, `with_runtime`
+
+
+            
+
+            
+                // Rust type: RustOpaqueMoi<flutter_rust_bridge::for_generated::RustAutoOpaqueInner<NetworkMonitor>>
+                abstract class NetworkMonitor implements RustOpaqueInterface {
+                      // HINT: Make it `#[frb(sync)]` to let it become the default constructor of Dart class.
+/// Creates a stopped monitor for `config`.
+static Future<NetworkMonitor>  newInstance({required NetworkConfiguration config })=>RustLib.instance.api.crateApiMonitorNetworkMonitorNew(config: config);
+
+
+/// Suspends the schedule. Does nothing unless running.
+ Future<void>  pause();
+
+
+/// Resumes a paused monitor with an immediate check. Does nothing unless
+/// paused.
+ Future<void>  resume();
+
+
+/// Stops monitoring like [Self::stop], then waits up to `timeout_ms` (0
+/// uses [DEFAULT_SHUTDOWN_TIMEOUT_MS](LibConstants::DEFAULT_SHUTDOWN_TIMEOUT_MS))
+/// for the monitoring task and the check it ran to be dropped. Returns
+/// false if that did not happen in time.
+///
+/// Once it returns true, no task of this monitor is left on the runtime.
+ Future<bool>  shutdown({required BigInt timeoutMs });
+
+
+/// Starts monitoring with an immediate check, or resumes a paused monitor.
+///
+/// The monitoring task runs on the runtime injected via
+/// [Self::with_runtime], else on the caller's runtime, else (e.g. when
+/// called from Dart) on the bridge's runtime.
+ Future<void>  start();
+
+
+/// The current lifecycle state.
+ Future<MonitorState>  state();
+
+
+/// Counters of completed, overrunning and skipped cycles.
+ Future<MonitorStats>  stats();
+
+
+/// Stops monitoring, abandoning a check under way, and ends all report
+/// streams. The monitor can be started again.
+ Future<void>  stop();
+
+
+/// Forwards [Self::reports] to Dart until the monitor stops or the Dart
+/// side cancels its subscription.
+ Stream<NetworkReport>  streamReports();
+
+
+/// Flags anomalies with `detector` instead of a default [AnomalyDetector].
+/// Every start begins with the detector's baseline.
+ Future<NetworkMonitor>  withAnomalyDetector({required AnomalyDetector detector });
+
+
+
+                    
+                }
+                
+
+/// The lifecycle state of a [NetworkMonitor].
+enum MonitorState {
+                    /// No checks are scheduled; report streams have ended.
+stopped,
+/// Checks run on the configured interval.
+running,
+/// The schedule is suspended; a check already under way still completes.
+paused,
+                    ;
+                    /// The localization key, e.g. `monitor_state.paused`.
+ String  l10NKey()=>RustLib.instance.api.crateApiMonitorMonitorStateL10NKey(that: this, );
+
+
+                }
+
+/// Counters of a [NetworkMonitor]'s schedule since the monitor was created.
+class MonitorStats  {
+                /// Checks that completed.
+final BigInt completedChecks;
+/// Checks that were still running when the next one was due.
+final BigInt overruns;
+/// Cycles that got no check of their own, as decided by the
+/// [overlap_policy](crate::api::models::ResilienceConfig::overlap_policy).
+final BigInt skippedCycles;
+
+                const MonitorStats({required this.completedChecks ,required this.overruns ,required this.skippedCycles ,});
+
+                static Future<MonitorStats>  default_()=>RustLib.instance.api.crateApiMonitorMonitorStatsDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => completedChecks.hashCode^overruns.hashCode^skippedCycles.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is MonitorStats &&
+                runtimeType == other.runtimeType
+                && completedChecks == other.completedChecks&& overruns == other.overruns&& skippedCycles == other.skippedCycles;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `channel`, `path_hints`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `subscribe_apple_path`
+
+
+            /// Records the latest OS path and installs its facts as platform hints.
+/// Call this from the `NWPathMonitor` update handler.
+Future<void>  updateApplePath({required ApplePathSnapshot snapshot }) => RustLib.instance.api.crateApiProbesApplePathUpdateApplePath(snapshot: snapshot);
+
+/// Forgets the injected path and the hints it installed, reverting to
+/// interface-name heuristics.
+Future<void>  clearApplePath() => RustLib.instance.api.crateApiProbesApplePathClearApplePath();
+
+/// The most recently injected OS path, if any.
+Future<ApplePathSnapshot?>  currentApplePath() => RustLib.instance.api.crateApiProbesApplePathCurrentApplePath();
+
+            
+            
\ No newline at end of file
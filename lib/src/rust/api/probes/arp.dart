@@ -0,0 +1,21 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/scan.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `format_mac`, `neighbor_mac`, `normalize_mac`, `parse_bsd_arp`, `parse_proc_net_arp`, `read_neighbor_table`
+
+
+            /// Returns the complete entries of the OS neighbor table.
+///
+/// Incomplete entries (resolution still pending or failed) are left out.
+/// MAC addresses are lowercase, colon-separated and zero-padded.
+Future<List<ArpEntry>>  readArpTable() => RustLib.instance.api.crateApiProbesArpReadArpTable();
+
+            
+            
\ No newline at end of file
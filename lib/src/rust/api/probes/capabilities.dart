@@ -0,0 +1,21 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `arp_table_readable`, `can_open`, `ping_fallback`, `traceroute_fallback`
+
+
+            /// Returns the probe modes available to the process.
+///
+/// Every capability is tested by opening (and closing) the socket or file it
+/// needs, once per process.
+Future<ProbeCapabilities>  detectProbeCapabilities() => RustLib.instance.api.crateApiProbesCapabilitiesDetectProbeCapabilities();
+
+            
+            
\ No newline at end of file
@@ -3,31 +3,50 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
 import '../../frb_generated.dart';
+import '../models/error.dart';
 import '../models/net_info.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `classify_response`, `describe_login_page`, `inconclusive`, `no_portal`, `page_title`, `probe_portal`, `read_page`, `wait_until_clear`
+
 
-/// Checks for the presence of a captive portal.
-Future<CaptivePortalStatus> checkForCaptivePortal({
-  required final BigInt timeoutMs,
-}) =>
-    RustLib.instance.api
-        .crateApiProbesCaptivePortalCheckForCaptivePortal(timeoutMs: timeoutMs);
+            /// Checks for the presence of a captive portal.
+///
+/// A probe that could not complete yields "no portal" with
+/// [EvidenceSource::ProbeFailed]; [try_check_for_captive_portal] reports why.
+Future<CaptivePortalStatus>  checkForCaptivePortal({required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesCaptivePortalCheckForCaptivePortal(timeoutMs: timeoutMs);
+
+/// [check_for_captive_portal], failing if the detection request did not
+/// complete: with [NetworkError::DnsResolutionError] if the host did not
+/// resolve, [NetworkError::ConnectionError] if it could not be connected to,
+/// [NetworkError::TimeoutError] if it did not answer in time and
+/// [NetworkError::ProbeFailed] otherwise.
+Future<CaptivePortalStatus>  tryCheckForCaptivePortal({required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesCaptivePortalTryCheckForCaptivePortal(timeoutMs: timeoutMs);
+
+/// [check_for_captive_portal] against `url` instead of
+/// [CAPTIVE_PORTAL_DETECTION_URL](LibConstants::CAPTIVE_PORTAL_DETECTION_URL)
+/// (see [EndpointConfig::captive_portal_url](crate::api::models::EndpointConfig::captive_portal_url)).
+Future<CaptivePortalStatus>  checkForCaptivePortalAt({required String url , required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesCaptivePortalCheckForCaptivePortalAt(url: url, timeoutMs: timeoutMs);
+
+/// [try_check_for_captive_portal] against `url`.
+Future<CaptivePortalStatus>  tryCheckForCaptivePortalAt({required String url , required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesCaptivePortalTryCheckForCaptivePortalAt(url: url, timeoutMs: timeoutMs);
+
+/// Re-checks every `poll_interval_ms` until the portal lets traffic through,
+/// e.g. after the user logged in.
+///
+/// Resolves with the first status that shows real internet access: the probe
+/// went through unredirected. Fails with [NetworkError::TimeoutError] if that
+/// did not happen within `timeout_ms`.
+Future<CaptivePortalStatus>  waitForPortalClearance({required BigInt timeoutMs , required BigInt pollIntervalMs }) => RustLib.instance.api.crateApiProbesCaptivePortalWaitForPortalClearance(timeoutMs: timeoutMs, pollIntervalMs: pollIntervalMs);
 
 /// Web implementation stub (WASM removed).
-Future<CaptivePortalStatus> checkForCaptivePortalWebManual({
-  required final BigInt timeoutMs,
-}) =>
-    RustLib.instance.api
-        .crateApiProbesCaptivePortalCheckForCaptivePortalWebManual(
-      timeoutMs: timeoutMs,
-    );
+Future<CaptivePortalStatus>  checkForCaptivePortalWebManual({required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesCaptivePortalCheckForCaptivePortalWebManual(timeoutMs: timeoutMs);
 
 /// Unified entry point for captive portal check (WASM removed).
-Future<CaptivePortalStatus> checkForCaptivePortalWeb({
-  required final BigInt timeoutMs,
-}) =>
-    RustLib.instance.api.crateApiProbesCaptivePortalCheckForCaptivePortalWeb(
-      timeoutMs: timeoutMs,
-    );
+Future<CaptivePortalStatus>  checkForCaptivePortalWeb({required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesCaptivePortalCheckForCaptivePortalWeb(timeoutMs: timeoutMs);
+
+            
+            
\ No newline at end of file
@@ -3,14 +3,36 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
+import '../../frb_generated.dart';
+import '../models/net_info.dart';
 import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
 
-import '../../frb_generated.dart';
 
-/// Detects potential DNS hijacking.
-Future<bool> detectDnsHijacking({required final String domain}) =>
-    RustLib.instance.api.crateApiProbesDnsDetectDnsHijacking(domain: domain);
+            // These functions are ignored because they are not marked as `pub`: `compare_answers`, `inconclusive`
+
+
+            /// Detects potential DNS hijacking.
+Future<bool>  detectDnsHijacking({required String domain }) => RustLib.instance.api.crateApiProbesDnsDetectDnsHijacking(domain: domain);
+
+/// Detects potential DNS hijacking and grades how sure the verdict is.
+///
+/// Returns `(is_spoofed, evidence)`. A failed lookup yields `false` with
+/// [Confidence::Low], since nothing could be compared.
+Future<(bool,Evidence)>  assessDnsHijacking({required String domain }) => RustLib.instance.api.crateApiProbesDnsAssessDnsHijacking(domain: domain);
+
+/// [assess_dns_hijacking], trusting the answers of `trusted_servers`
+/// (`ip:port`) instead of Cloudflare's. An empty list keeps Cloudflare; an
+/// invalid entry yields an inconclusive verdict.
+Future<(bool,Evidence)>  assessDnsHijackingWith({required String domain , required List<String> trustedServers }) => RustLib.instance.api.crateApiProbesDnsAssessDnsHijackingWith(domain: domain, trustedServers: trustedServers);
 
 /// Web-specific implementation stub (WASM removed).
-Future<bool> detectDnsHijackingWeb({required final String domain}) =>
-    RustLib.instance.api.crateApiProbesDnsDetectDnsHijackingWeb(domain: domain);
+Future<bool>  detectDnsHijackingWeb({required String domain }) => RustLib.instance.api.crateApiProbesDnsDetectDnsHijackingWeb(domain: domain);
+
+/// Returns the IP addresses of the resolvers configured in the operating system.
+///
+/// The list is sorted and de-duplicated (resolvers are usually registered once
+/// per transport). Returns an empty list if the system configuration is unreadable.
+Future<List<String>>  systemDnsResolvers() => RustLib.instance.api.crateApiProbesDnsSystemDnsResolvers();
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,22 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `classify_tos`, `negotiated`, `observe`, `os_requests_ecn`
+
+
+            /// Checks TCP ECN negotiation with `tcp_servers` (`host:port`; empty uses
+/// [DEFAULT_TCP_REFERENCE_SERVERS](LibConstants::DEFAULT_TCP_REFERENCE_SERVERS))
+/// and ECN bleaching via `reflection_servers` (skipped if empty).
+///
+/// `timeout_ms` of 0 uses [DEFAULT_ECN_TIMEOUT_MS](LibConstants::DEFAULT_ECN_TIMEOUT_MS).
+Future<EcnReport>  detectEcn({required List<String> tcpServers , required List<String> reflectionServers , required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesEcnDetectEcn(tcpServers: tcpServers, reflectionServers: reflectionServers, timeoutMs: timeoutMs);
+
+            
+            
\ No newline at end of file
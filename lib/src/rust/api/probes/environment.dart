@@ -0,0 +1,27 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `classify`, `read`, `veth_only`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `RuntimeSignals`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `fmt`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Returns the environment the process runs in.
+///
+/// On Linux the `/proc` markers of WSL, Docker, LXC and other runtimes are
+/// read first. Failing those, a network namespace whose only interfaces are
+/// veth ends is taken as a container, and as Docker if its default gateway
+/// lies in Docker's `172.16.0.0/12` bridge range. Other platforms always
+/// report [RuntimeEnvironment::Native]. Detected once per process.
+Future<RuntimeEnvironment>  detectRuntimeEnvironment() => RustLib.instance.api.crateApiProbesEnvironmentDetectRuntimeEnvironment();
+
+            
+            
\ No newline at end of file
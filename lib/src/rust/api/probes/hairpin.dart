@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `connect`, `hairpin_verdict`
+
+
+            /// Tests TCP `port` on the public address from the LAN and, via the
+/// connect-back test of `reflection_servers`, from the internet.
+///
+/// `public_address` may be left `None` to use the address the first
+/// answering reflection server saw. `timeout_ms` bounds the LAN connection
+/// (0 uses [DEFAULT_HAIRPIN_TIMEOUT_MS](LibConstants::DEFAULT_HAIRPIN_TIMEOUT_MS));
+/// each connect-back test gets
+/// [DEFAULT_CONNECT_BACK_TIMEOUT_MS](LibConstants::DEFAULT_CONNECT_BACK_TIMEOUT_MS).
+Future<HairpinReport>  checkHairpin({required int port , String? publicAddress , required List<String> reflectionServers , required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesHairpinCheckHairpin(port: port, publicAddress: publicAddress, reflectionServers: reflectionServers, timeoutMs: timeoutMs);
+
+            
+            
\ No newline at end of file
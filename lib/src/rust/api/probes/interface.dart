@@ -3,16 +3,30 @@
 
 // ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
-
 import '../../frb_generated.dart';
 import '../models/net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
 
-/// Inspects system network interfaces to detect connection type and potential security flags.
-Future<(SecurityFlagsResult, ConnectionType)> detectSecurityAndNetworkType() =>
-    RustLib.instance.api.crateApiProbesInterfaceDetectSecurityAndNetworkType();
+            // These functions are ignored because they are not marked as `pub`: `classify_interface_name`, `classify_interface`, `classify_sysfs_interface`, `classify_windows_adapter`, `default_route_destination`, `detect_by_interface_name`, `detect_named_interface`, `has_route_to`, `internet_route_destinations`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `default_gateway`, `detect_security_and_network_type_towards`, `route_destination_for`
+
+
+            /// Inspects system network interfaces to detect connection type and potential security flags.
+///
+/// Equivalent to [detect_security_and_network_type_towards] a public address.
+Future<(SecurityFlagsResult,ConnectionType)>  detectSecurityAndNetworkType() => RustLib.instance.api.crateApiProbesInterfaceDetectSecurityAndNetworkType();
+
+/// Builds the detection result for an interface chosen by the caller (see
+/// [NetworkConfiguration::active_interface](crate::api::models::NetworkConfiguration::active_interface)),
+/// skipping route-based detection.
+///
+/// An interface that does not exist keeps its name but yields
+/// [ConnectionType::Unknown].
+Future<(SecurityFlagsResult,ConnectionType)>  detectSecurityAndNetworkTypeOf({required String interfaceName }) => RustLib.instance.api.crateApiProbesInterfaceDetectSecurityAndNetworkTypeOf(interfaceName: interfaceName);
 
 /// Web implementation stub (WASM removed).
-Future<(SecurityFlagsResult, ConnectionType)>
-    detectSecurityAndNetworkTypeWeb() => RustLib.instance.api
-        .crateApiProbesInterfaceDetectSecurityAndNetworkTypeWeb();
+Future<(SecurityFlagsResult,ConnectionType)>  detectSecurityAndNetworkTypeWeb() => RustLib.instance.api.crateApiProbesInterfaceDetectSecurityAndNetworkTypeWeb();
+
+            
+            
\ No newline at end of file
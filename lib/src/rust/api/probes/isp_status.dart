@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `classify_status_body`, `json_string_field`, `query`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `IspStatus`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            /// Queries every URL and combines the answers into an outage hint.
+///
+/// Returns `Some(true)` if any endpoint reports an outage, `Some(false)` if
+/// at least one reports normal operation and none an outage, and `None` if no
+/// endpoint answered.
+Future<bool?>  checkIspStatus({required List<String> urls , required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesIspStatusCheckIspStatus(urls: urls, timeoutMs: timeoutMs);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/error.dart';
+import '../models/net_info.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `cdp_first_ipv4`, `empty_neighbor`, `format_mac`, `lldp_management_address`, `parse_cdp`, `parse_frame`, `parse_lldp`, `subtyped_id`, `text`
+
+
+            /// Listens on `interface` for up to `listen_ms` and returns the first LLDP or
+/// CDP neighbor announced.
+///
+/// An empty `interface` selects the interface carrying the default route.
+/// `Ok(None)` means nothing was announced in time; unmanaged switches never
+/// announce anything.
+Future<LinkNeighbor?>  discoverLinkNeighbor({required String interface_ , required BigInt listenMs }) => RustLib.instance.api.crateApiProbesLldpDiscoverLinkNeighbor(interface_: interface_, listenMs: listenMs);
+
+            
+            
\ No newline at end of file
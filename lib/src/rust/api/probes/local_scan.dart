@@ -0,0 +1,49 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/error.dart';
+import '../models/net_info.dart';
+import '../models/report.dart';
+import '../models/scan.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_neighbor_table`, `contains`, `from_bits`, `from_config`, `from_config`, `host_ranges`, `hosts`, `len`, `parse`, `read_since`, `run`, `to_bits`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `HostProbe`, `IpRange`, `NeighborTable`, `ScanScope`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `clone`, `clone`, `clone`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `scan_local_network_cancellable`, `scan_local_network_stream`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Sweeps `config.subnet` with TCP connects to [LocalScanConfig::scan_port]
+/// (or every one of [LocalScanConfig::scan_ports]) and returns the devices
+/// that answered, with the ports they had open.
+///
+/// Up to [LocalScanConfig::max_concurrency] sockets are open at once. Every
+/// connect makes the OS resolve the host's MAC address, so the neighbor table
+/// read after the sweep names the MAC address (and its vendor) of each device
+/// on the same link. A subnet or range entry that cannot be parsed yields no
+/// devices: scanning a device the caller meant to exclude is worse than
+/// scanning nothing. Use [try_scan_local_network] to tell that apart from an
+/// empty network.
+Future<List<LocalDevice>>  scanLocalNetwork({required LocalScanConfig config }) => RustLib.instance.api.crateApiProbesLocalScanScanLocalNetwork(config: config);
+
+/// [scan_local_network], failing with [NetworkError::InvalidConfiguration]
+/// on an unparsable subnet or range entry, or a scope of more than
+/// [LocalScanConfig::max_hosts] hosts.
+Future<List<LocalDevice>>  tryScanLocalNetwork({required LocalScanConfig config }) => RustLib.instance.api.crateApiProbesLocalScanTryScanLocalNetwork(config: config);
+
+/// Forwards [scan_local_network_stream] to Dart until the sweep ends or the
+/// Dart side cancels its subscription, which also stops the sweep.
+///
+/// A closed subscription is noticed when an event cannot be delivered; the
+/// [ScanEvent::Progress] events make sure that happens within
+/// [SCAN_PROGRESS_INTERVAL_MS](LibConstants::SCAN_PROGRESS_INTERVAL_MS) even
+/// while no device answers.
+Stream<ScanEvent>  streamLocalNetworkScan({required LocalScanConfig config }) => RustLib.instance.api.crateApiProbesLocalScanStreamLocalNetworkScan(config: config);
+
+            
+            
\ No newline at end of file
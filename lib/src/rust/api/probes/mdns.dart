@@ -0,0 +1,35 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/error.dart';
+import '../models/report.dart';
+import '../models/scan.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `absorb`, `dotted`, `is_excluded`, `is_service_type`, `key`, `label_under`, `new`, `on_excluded_host`, `parse_rdata`, `parse_record`, `parse_response`, `query`, `read`, `services`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Browser`, `Name`, `Record`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `assert_fields_are_eq`, `clone`, `clone`, `cmp`, `eq`, `eq`, `fmt`, `fmt`, `fmt`, `fmt`, `hash`, `partial_cmp`
+// These functions are ignored (category: IgnoreBecauseOwnerTyShouldIgnore): `default`
+
+
+            /// Browses the local network for `duration_ms` (0 uses
+/// [DEFAULT_MDNS_BROWSE_MS](LibConstants::DEFAULT_MDNS_BROWSE_MS)) and
+/// returns every service announced, sorted by type and instance name.
+///
+/// `exclude_ranges` takes the entries of
+/// [LocalScanConfig::exclude_ranges](crate::api::models::LocalScanConfig::exclude_ranges):
+/// announcements from those addresses are ignored, and services on a host
+/// with an address inside them are neither asked about nor returned. The
+/// queries themselves go to the mDNS group, which excluded devices still
+/// receive.
+///
+/// Fails with [NetworkError::InvalidConfiguration] on an unparsable range,
+/// and otherwise only if the mDNS group cannot be joined or queried.
+Future<List<MdnsService>>  browseMdnsServices({required BigInt durationMs , required List<String> excludeRanges }) => RustLib.instance.api.crateApiProbesMdnsBrowseMdnsServices(durationMs: durationMs, excludeRanges: excludeRanges);
+
+            
+            
\ No newline at end of file
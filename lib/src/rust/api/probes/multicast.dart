@@ -0,0 +1,20 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `exchange`, `is_response`, `local_addresses`, `mdns_query_labels`, `mdns_query`, `mdns_socket`, `verdict`
+
+
+            /// Joins the mDNS group, queries it and listens for `timeout_ms` (0 uses
+/// [DEFAULT_MULTICAST_TIMEOUT_MS](LibConstants::DEFAULT_MULTICAST_TIMEOUT_MS)),
+/// stopping early once both our own query and a LAN response arrived.
+Future<MulticastReport>  checkMulticast({required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesMulticastCheckMulticast(timeoutMs: timeoutMs);
+
+            
+            
\ No newline at end of file
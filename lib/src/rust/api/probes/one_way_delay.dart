@@ -0,0 +1,29 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/error.dart';
+import '../models/net_info.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `estimate`, `exchange`, `median_ms`, `new`, `now_us`, `offset`, `parse_reply`, `rtt`, `unix_micros`, `us_to_ms`
+// These types are ignored because they are neither used by any `pub` functions nor (for structs and enums) marked `#[frb(unignore)]`: `Exchange`, `LocalClock`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            /// Runs `samples` timestamp exchanges with `server` (`host:port`) and splits
+/// the delay into its upstream and downstream parts.
+///
+/// `samples` of 0 uses
+/// [DEFAULT_ONE_WAY_DELAY_SAMPLES](LibConstants::DEFAULT_ONE_WAY_DELAY_SAMPLES);
+/// `timeout_ms` of 0 uses
+/// [DEFAULT_ONE_WAY_DELAY_TIMEOUT_MS](LibConstants::DEFAULT_ONE_WAY_DELAY_TIMEOUT_MS)
+/// per exchange. Fails if no exchange is answered.
+Future<OneWayDelayEstimate>  estimateOneWayDelay({required String server , required int samples , required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesOneWayDelayEstimateOneWayDelay(server: server, samples: samples, timeoutMs: timeoutMs);
+
+            
+            
\ No newline at end of file
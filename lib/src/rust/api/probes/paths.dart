@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/diagnostics.dart';
+import '../models/net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `candidate_interfaces`, `handshake_via`, `measure_candidates`, `measure_path`, `path_measurement`
+
+
+            /// Measures every active Wi-Fi, cellular and Ethernet interface with TCP
+/// handshakes to the [DEFAULT_TCP_REFERENCE_SERVERS](LibConstants::DEFAULT_TCP_REFERENCE_SERVERS).
+///
+/// `timeout_ms` of 0 uses [DEFAULT_TIMEOUT_MS](LibConstants::DEFAULT_TIMEOUT_MS).
+/// Interfaces are measured concurrently.
+Future<List<PathMeasurement>>  measurePaths({required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesPathsMeasurePaths(timeoutMs: timeoutMs);
+
+            
+            
\ No newline at end of file
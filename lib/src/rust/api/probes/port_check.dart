@@ -0,0 +1,26 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/error.dart';
+import '../models/net_info.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `check_url`, `json_bool_field`, `parse_verdict`
+
+
+            /// Asks the "check my port" service at `endpoint` to connect back to `port`
+/// on our public address.
+///
+/// `timeout_ms` bounds the whole request, including the service's own
+/// connection attempt (0 uses
+/// [DEFAULT_PORT_CHECK_TIMEOUT_MS](LibConstants::DEFAULT_PORT_CHECK_TIMEOUT_MS)).
+/// Fails if the service cannot be reached or its answer is not understood.
+Future<PortForwardCheck>  verifyPortForwarding({required String endpoint , required int port , required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesPortCheckVerifyPortForwarding(endpoint: endpoint, port: port, timeoutMs: timeoutMs);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,33 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/diagnostics.dart';
+import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `apply_dscp`, `connect_tcp`, `ping_marked`, `summarize`
+
+
+            /// Probes `target` with each DSCP marking in `markings` and compares the
+/// results with unmarked (best effort) probes.
+///
+/// The markings are probed round-robin, `samples` times each (0 uses
+/// [DEFAULT_DSCP_COMPARISON_SAMPLES](LibConstants::DEFAULT_DSCP_COMPARISON_SAMPLES)),
+/// so that changing conditions affect all of them alike. The result starts
+/// with the best-effort baseline, followed by `markings` in order.
+/// HTTP(S) targets cannot be marked; use a TCP, UDP, DNS or ICMP target.
+///
+/// ```ignore
+/// let results = compare_dscp_markings(target, vec![LibConstants::DSCP_EF], 0).await;
+/// if results[1].delta_ms.is_some_and(|d| d < -5) {
+///     println!("VoIP traffic is prioritized");
+/// }
+/// ```
+Future<List<DscpLatency>>  compareDscpMarkings({required NetworkTarget target , required List<int> markings , required int samples }) => RustLib.instance.api.crateApiProbesQosCompareDscpMarkings(target: target, markings: markings, samples: samples);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,25 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/error.dart';
+import '../models/net_info.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `classify_nat`, `hops_from_ttl`, `parse_connect_reply`, `parse_reply`, `recv_with_ttl`, `reflect`, `reflected_tos`, `request_connect_back`, `resolve_v4`, `route_local_ip`
+
+
+            /// Probes every server in `servers` (`host:port`) from one UDP socket.
+///
+/// The first server that answers is probed a second time at the end to
+/// detect rebinding. Only IPv4 addresses are used. Fails if no server
+/// answers within `timeout_ms` (0 means
+/// [DEFAULT_REFLECTION_TIMEOUT_MS](LibConstants::DEFAULT_REFLECTION_TIMEOUT_MS)).
+Future<NatReport>  probeNat({required List<String> servers , required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesReflectionProbeNat(servers: servers, timeoutMs: timeoutMs);
+
+            
+            
\ No newline at end of file
@@ -1,48 +1,70 @@
 // This file is automatically generated, so please do not edit it.
 // @generated by `flutter_rust_bridge`@ 2.12.0.
 
-// ignore_for_file: public_member_api_docs, invalid_use_of_internal_member, unused_import, unnecessary_import
-
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
 
 import '../../frb_generated.dart';
+import '../models/net_info.dart';
 import '../models/report.dart';
 import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `check_resolved`, `ping_error`, `resolve_target_addrs`, `resolve_target`, `tcp_ping`
+
 
-/// Performs a network check against a single, specified target.
-Future<TargetReport> checkTarget({required final NetworkTarget target}) =>
-    RustLib.instance.api.crateApiProbesTargetCheckTarget(target: target);
+            /// Performs a network check against a single, specified target.
+Future<TargetReport>  checkTarget({required NetworkTarget target }) => RustLib.instance.api.crateApiProbesTargetCheckTarget(target: target);
 
-/// Native implementation of network reachability checks.
-class NativeProbe {
-  const NativeProbe();
+            /// Native implementation of network reachability checks.
+class NativeProbe  {
+                
 
-  Future<TargetReport> check({required final NetworkTarget target}) =>
-      RustLib.instance.api
-          .crateApiProbesTargetNativeProbeCheck(that: this, target: target);
+                const NativeProbe();
 
-  @override
-  int get hashCode => 0;
+                 Future<TargetReport>  check({required NetworkTarget target })=>RustLib.instance.api.crateApiProbesTargetNativeProbeCheck(that: this, target: target);
 
-  @override
-  bool operator ==(final Object other) =>
-      identical(this, other) ||
-      other is NativeProbe && runtimeType == other.runtimeType;
-}
+
+                
+
+                
+        @override
+        int get hashCode => 0;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is NativeProbe &&
+                runtimeType == other.runtimeType
+                ;
+        
+            }
 
 /// Web-specific implementation stub (WASM removed).
-class WebProbe {
-  const WebProbe();
+class WebProbe  {
+                
+
+                const WebProbe();
+
+                 Future<TargetReport>  check({required NetworkTarget target })=>RustLib.instance.api.crateApiProbesTargetWebProbeCheck(that: this, target: target);
+
 
-  Future<TargetReport> check({required final NetworkTarget target}) =>
-      RustLib.instance.api
-          .crateApiProbesTargetWebProbeCheck(that: this, target: target);
+                
 
-  @override
-  int get hashCode => 0;
+                
+        @override
+        int get hashCode => 0;
+        
 
-  @override
-  bool operator ==(final Object other) =>
-      identical(this, other) ||
-      other is WebProbe && runtimeType == other.runtimeType;
-}
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is WebProbe &&
+                runtimeType == other.runtimeType
+                ;
+        
+            }
+            
\ No newline at end of file
@@ -0,0 +1,63 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `read`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `fmt`
+
+
+            
+
+            /// The leading part of the kernel's `struct tcp_info`; the kernel copies no
+/// more than the length we pass, so later fields can be left out.
+class TcpInfo  {
+                final int state;
+final int caState;
+final int retransmits;
+final int probes;
+final int backoff;
+final int options;
+final int wscale;
+final int flags;
+final int rto;
+final int ato;
+final int sndMss;
+final int rcvMss;
+final int unacked;
+final int sacked;
+final int lost;
+final int retrans;
+final int fackets;
+final int lastDataSent;
+final int lastAckSent;
+final int lastDataRecv;
+final int lastAckRecv;
+final int pmtu;
+
+                const TcpInfo({required this.state ,required this.caState ,required this.retransmits ,required this.probes ,required this.backoff ,required this.options ,required this.wscale ,required this.flags ,required this.rto ,required this.ato ,required this.sndMss ,required this.rcvMss ,required this.unacked ,required this.sacked ,required this.lost ,required this.retrans ,required this.fackets ,required this.lastDataSent ,required this.lastAckSent ,required this.lastDataRecv ,required this.lastAckRecv ,required this.pmtu ,});
+
+                static Future<TcpInfo>  default_()=>RustLib.instance.api.crateApiProbesTcpInfoTcpInfoDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => state.hashCode^caState.hashCode^retransmits.hashCode^probes.hashCode^backoff.hashCode^options.hashCode^wscale.hashCode^flags.hashCode^rto.hashCode^ato.hashCode^sndMss.hashCode^rcvMss.hashCode^unacked.hashCode^sacked.hashCode^lost.hashCode^retrans.hashCode^fackets.hashCode^lastDataSent.hashCode^lastAckSent.hashCode^lastDataRecv.hashCode^lastAckRecv.hashCode^pmtu.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is TcpInfo &&
+                runtimeType == other.runtimeType
+                && state == other.state&& caState == other.caState&& retransmits == other.retransmits&& probes == other.probes&& backoff == other.backoff&& options == other.options&& wscale == other.wscale&& flags == other.flags&& rto == other.rto&& ato == other.ato&& sndMss == other.sndMss&& rcvMss == other.rcvMss&& unacked == other.unacked&& sacked == other.sacked&& lost == other.lost&& retrans == other.retrans&& fackets == other.fackets&& lastDataSent == other.lastDataSent&& lastAckSent == other.lastAckSent&& lastDataRecv == other.lastDataRecv&& lastAckRecv == other.lastAckRecv&& pmtu == other.pmtu;
+        
+            }
+            
\ No newline at end of file
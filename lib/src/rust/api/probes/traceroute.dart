@@ -0,0 +1,51 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/error.dart';
+import '../models/net_info.dart';
+import '../models/report.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `compare_routes`, `is_lan_hop`, `locate_problem`, `probe_hop_icmp`, `probe_hop`, `resolve_hostnames`, `resolve`, `trace`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `trace_route_cancellable`
+
+
+            /// Traces the route to `host` using ICMP echo requests with increasing TTL.
+///
+/// Each hop is probed once; hops that do not answer within `timeout_per_hop_ms`
+/// are reported with an `ip_address` of `"*"`. The trace stops as soon as the
+/// destination itself replies, or after `max_hops` hops (0 means
+/// [DEFAULT_MAX_TRACEROUTE_HOPS](LibConstants::DEFAULT_MAX_TRACEROUTE_HOPS)).
+///
+/// # Platform Notes
+///
+/// Intermediate routers answer with ICMP "Time Exceeded". Unprivileged ICMP
+/// sockets (e.g. on Linux without `CAP_NET_RAW`) usually do not deliver those.
+/// On Linux and Android the trace then sends UDP datagrams instead, whose ICMP
+/// errors any process may read ([ProbeFallback::UdpTraceroute], noted in
+/// [RouteTrace::fallback] by [trace_routes]); elsewhere only the final hop is
+/// visible.
+///
+/// The hops come back as a [RouteTrace], which also tells whether the
+/// destination answered, where the path broke and which fallback was used.
+/// With a single destination the whole path counts as shared, so a break is
+/// placed in [PathSegment::Lan] or [PathSegment::Isp].
+Future<RouteTrace>  traceRoute({required String host , required int maxHops , required BigInt timeoutPerHopMs }) => RustLib.instance.api.crateApiProbesTracerouteTraceRoute(host: host, maxHops: maxHops, timeoutPerHopMs: timeoutPerHopMs);
+
+/// Traces several destinations concurrently and compares their paths.
+///
+/// Hops are split into the LAN (leading private addresses), the shared prefix
+/// all destinations pass through (the ISP), and the destination-specific rest.
+/// A trace that stops short of its destination is attributed to the segment
+/// of its last answering hop, which tells "my Wi-Fi", "my ISP" and "that one
+/// site" apart. With a single host, the whole path counts as shared.
+///
+/// See [trace_route] for the meaning of `max_hops` and `timeout_per_hop_ms`.
+Future<MultiRouteTrace>  traceRoutes({required List<String> hosts , required int maxHops , required BigInt timeoutPerHopMs }) => RustLib.instance.api.crateApiProbesTracerouteTraceRoutes(hosts: hosts, maxHops: maxHops, timeoutPerHopMs: timeoutPerHopMs);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,23 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/target.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `build_dns_query_labels`, `build_dns_query`, `dns_rcode`, `exchange`, `probe_dns_marked`, `probe_udp_marked`, `quic_connection_ids`, `read_dns_labels`, `read_dns_name`, `socket_error`, `validate_dns_response`
+// These functions are ignored (category: IgnoreBecauseExplicitAttribute): `probe_dns`, `probe_udp`
+
+
+            /// Builds the probe payload for `validation`, randomized where the protocol
+/// lets us match the reply to the request.
+Future<Uint8List>  buildRequest({required UdpValidation validation }) => RustLib.instance.api.crateApiProbesUdpBuildRequest(validation: validation);
+
+/// Checks that `reply` is a valid answer to `request`.
+Future<bool>  validateReply({required UdpValidation validation , required List<int> request , required List<int> reply }) => RustLib.instance.api.crateApiProbesUdpValidateReply(validation: validation, request: request, reply: reply);
+
+            
+            
\ No newline at end of file
@@ -0,0 +1,44 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `describe_device`, `discover_location`, `element_text`, `header`, `parse_description`
+// These function are ignored because they are on traits that is not defined in current crate (put an empty `#[frb]` on it to unignore): `clone`, `eq`, `fmt`
+
+
+            
+
+            /// The identifying fields of a UPnP root device description.
+class UpnpDescription  {
+                final String? friendlyName;
+final String? manufacturer;
+final String? modelName;
+final String? modelNumber;
+
+                const UpnpDescription({this.friendlyName ,this.manufacturer ,this.modelName ,this.modelNumber ,});
+
+                static Future<UpnpDescription>  default_()=>RustLib.instance.api.crateApiProbesUpnpUpnpDescriptionDefault();
+
+
+                
+
+                
+        @override
+        int get hashCode => friendlyName.hashCode^manufacturer.hashCode^modelName.hashCode^modelNumber.hashCode;
+        
+
+                
+        @override
+        bool operator ==(Object other) =>
+            identical(this, other) ||
+            other is UpnpDescription &&
+                runtimeType == other.runtimeType
+                && friendlyName == other.friendlyName&& manufacturer == other.manufacturer&& modelName == other.modelName&& modelNumber == other.modelNumber;
+        
+            }
+            
\ No newline at end of file
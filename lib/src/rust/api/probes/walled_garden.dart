@@ -0,0 +1,26 @@
+// This file is automatically generated, so please do not edit it.
+// @generated by `flutter_rust_bridge`@ 2.12.0.
+
+// ignore_for_file: invalid_use_of_internal_member, unused_import, unnecessary_import
+
+import '../../frb_generated.dart';
+import '../models/config.dart';
+import '../models/net_info.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+            // These functions are ignored because they are not marked as `pub`: `https`, `port_matrix`, `public_dns`, `resolve`
+
+
+            /// Probes the walled garden, waiting at most `timeout_ms` per destination
+/// (0 uses [DEFAULT_WALLED_GARDEN_TIMEOUT_MS](LibConstants::DEFAULT_WALLED_GARDEN_TIMEOUT_MS)).
+///
+/// All destinations are tried concurrently.
+Future<WalledGardenReport>  probeWalledGarden({required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesWalledGardenProbeWalledGarden(timeoutMs: timeoutMs);
+
+/// [probe_walled_garden] against the walled garden destinations of
+/// `endpoints`.
+Future<WalledGardenReport>  probeWalledGardenWith({required EndpointConfig endpoints , required BigInt timeoutMs }) => RustLib.instance.api.crateApiProbesWalledGardenProbeWalledGardenWith(endpoints: endpoints, timeoutMs: timeoutMs);
+
+            
+            
\ No newline at end of file
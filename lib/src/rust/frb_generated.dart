@@ -1,3737 +1,13199 @@
 // This file is automatically generated, so please do not edit it.
 // @generated by `flutter_rust_bridge`@ 2.12.0.
 
-// ignore_for_file: public_member_api_docs, unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
-
-import 'dart:async';
-import 'dart:convert';
-
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+// ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
 
+import 'api/analysis/anomaly.dart';
+import 'api/analysis/attribution.dart';
+import 'api/analysis/heatmap.dart';
+import 'api/analysis/layers.dart';
+import 'api/analysis/paths.dart';
 import 'api/analysis/quality.dart';
+import 'api/analysis/roaming.dart';
+import 'api/analysis/stability.dart';
 import 'api/analysis/stats.dart';
+import 'api/analysis/time_of_day.dart';
+import 'api/analysis/vpn.dart';
 import 'api/engine.dart';
+import 'api/engine/circuit_breaker.dart';
+import 'api/engine/clock.dart';
+import 'api/engine/environment.dart';
+import 'api/engine/hints.dart';
+import 'api/engine/inventory.dart';
+import 'api/engine/local.dart';
+import 'api/engine/pipeline.dart';
+import 'api/engine/preflight.dart';
+import 'api/engine/rate_limit.dart';
+import 'api/engine/reachability.dart';
 import 'api/engine/sampler.dart';
+import 'api/engine/schedule.dart';
 import 'api/engine/security.dart';
+import 'api/engine/vpn.dart';
 import 'api/models/config.dart';
+import 'api/models/diagnostics.dart';
+import 'api/models/error.dart';
 import 'api/models/net_info.dart';
 import 'api/models/report.dart';
+import 'api/models/scan.dart';
 import 'api/models/target.dart';
+import 'api/monitor.dart';
+import 'api/probes/apple_path.dart';
+import 'api/probes/arp.dart';
+import 'api/probes/capabilities.dart';
 import 'api/probes/captive_portal.dart';
 import 'api/probes/dns.dart';
+import 'api/probes/ecn.dart';
+import 'api/probes/environment.dart';
+import 'api/probes/hairpin.dart';
 import 'api/probes/interface.dart';
+import 'api/probes/isp_status.dart';
+import 'api/probes/lldp.dart';
+import 'api/probes/local_scan.dart';
+import 'api/probes/mdns.dart';
+import 'api/probes/mss.dart';
+import 'api/probes/multicast.dart';
+import 'api/probes/one_way_delay.dart';
+import 'api/probes/paths.dart';
+import 'api/probes/port_check.dart';
+import 'api/probes/qos.dart';
+import 'api/probes/reflection.dart';
 import 'api/probes/target.dart';
+import 'api/probes/tcp_info.dart';
+import 'api/probes/traceroute.dart';
+import 'api/probes/udp.dart';
+import 'api/probes/upnp.dart';
+import 'api/probes/walled_garden.dart';
+import 'dart:async';
+import 'dart:convert';
 import 'frb_generated.dart';
-import 'frb_generated.io.dart'
-    if (dart.library.js_interop) 'frb_generated.web.dart';
-
-/// Main entrypoint of the Rust API
-class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
-  RustLib._();
-  @internal
-  static final instance = RustLib._();
-
-  /// Initialize flutter_rust_bridge
-  static Future<void> init({
-    final RustLibApi? api,
-    final BaseHandler? handler,
-    final ExternalLibrary? externalLibrary,
-    final bool forceSameCodegenVersion = true,
-  }) async {
-    await instance.initImpl(
-      api: api,
-      handler: handler,
-      externalLibrary: externalLibrary,
-      forceSameCodegenVersion: forceSameCodegenVersion,
-    );
-  }
-
-  /// Initialize flutter_rust_bridge in mock mode.
-  /// No libraries for FFI are loaded.
-  static void initMock({
-    required final RustLibApi api,
-  }) {
-    instance.initMockImpl(
-      api: api,
-    );
-  }
-
-  /// Dispose flutter_rust_bridge
-  ///
-  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
-  /// is automatically disposed when the app stops.
-  static void dispose() => instance.disposeImpl();
-
-  @override
-  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor =>
-      RustLibApiImpl.new;
-
-  @override
-  WireConstructor<RustLibWire> get wireConstructor =>
-      RustLibWire.fromExternalLibrary;
-
-  @override
-  Future<void> executeRustInitializers() async {}
-
-  @override
-  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig =>
-      kDefaultExternalLibraryLoaderConfig;
-
-  @override
-  String get codegenVersion => '2.12.0';
-
-  @override
-  int get rustContentHash => -650198572;
-
-  static const kDefaultExternalLibraryLoaderConfig =
-      ExternalLibraryLoaderConfig(
-    stem: 'network_reachability',
-    ioDirectory: 'rust/target/release/',
-    webPrefix: 'pkg/',
-  );
-}
-
-abstract class RustLibApi extends BaseApi {
-  ConnectionType
-      crateApiModelsReportNetworkReportAutoAccessorGetConnectionType({
-    required final NetworkReport that,
-  });
-
-  SecurityFlagsResult
-      crateApiModelsReportNetworkReportAutoAccessorGetSecurityFlagsResult({
-    required final NetworkReport that,
-  });
-
-  NetworkStatus crateApiModelsReportNetworkReportAutoAccessorGetStatus({
-    required final NetworkReport that,
-  });
-
-  List<TargetReport>
-      crateApiModelsReportNetworkReportAutoAccessorGetTargetReports({
-    required final NetworkReport that,
-  });
-
-  BigInt crateApiModelsReportNetworkReportAutoAccessorGetTimestampMs({
-    required final NetworkReport that,
-  });
-
-  void crateApiModelsReportNetworkReportAutoAccessorSetConnectionType({
-    required final NetworkReport that,
-    required final ConnectionType connectionType,
-  });
-
-  void crateApiModelsReportNetworkReportAutoAccessorSetSecurityFlagsResult({
-    required final NetworkReport that,
-    required final SecurityFlagsResult securityFlagsResult,
-  });
-
-  void crateApiModelsReportNetworkReportAutoAccessorSetStatus({
-    required final NetworkReport that,
-    required final NetworkStatus status,
-  });
-
-  void crateApiModelsReportNetworkReportAutoAccessorSetTargetReports({
-    required final NetworkReport that,
-    required final List<TargetReport> targetReports,
-  });
-
-  void crateApiModelsReportNetworkReportAutoAccessorSetTimestampMs({
-    required final NetworkReport that,
-    required final BigInt timestampMs,
-  });
-
-  String crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetInterfaceName({
-    required final SecurityFlagsResult that,
-  });
-
-  bool crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsDnsSpoofed({
-    required final SecurityFlagsResult that,
-  });
-
-  bool crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsProxyDetected({
-    required final SecurityFlagsResult that,
-  });
-
-  bool crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsVpnDetected({
-    required final SecurityFlagsResult that,
-  });
-
-  void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetInterfaceName({
-    required final SecurityFlagsResult that,
-    required final String interfaceName,
-  });
-
-  void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsDnsSpoofed({
-    required final SecurityFlagsResult that,
-    required final bool isDnsSpoofed,
-  });
-
-  void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsProxyDetected({
-    required final SecurityFlagsResult that,
-    required final bool isProxyDetected,
-  });
-
-  void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsVpnDetected({
-    required final SecurityFlagsResult that,
-    required final bool isVpnDetected,
-  });
-
-  Future<SecurityFlagsResult> crateApiModelsNetInfoSecurityFlagsResultDefault();
-
-  Future<BigInt?> crateApiEngineSamplerAnalyzeSingleSample({
-    required final List<TargetReport> reports,
-    required final NetworkConfiguration config,
-  });
-
-  Future<(BigInt?, BigInt?, BigInt?, double?)>
-      crateApiAnalysisStatsCalculateJitterStats({
-    required final Uint64List latencies,
-  });
-
-  Future<CaptivePortalStatus> crateApiProbesCaptivePortalCheckForCaptivePortal({
-    required final BigInt timeoutMs,
-  });
-
-  Future<CaptivePortalStatus>
-      crateApiProbesCaptivePortalCheckForCaptivePortalWeb({
-    required final BigInt timeoutMs,
-  });
-
-  Future<CaptivePortalStatus>
-      crateApiProbesCaptivePortalCheckForCaptivePortalWebManual({
-    required final BigInt timeoutMs,
-  });
-
-  Future<NetworkReport> crateApiEngineCheckNetwork({
-    required final NetworkConfiguration config,
-  });
-
-  Future<TargetReport> crateApiProbesTargetCheckTarget({
-    required final NetworkTarget target,
-  });
-
-  Future<(Uint64List, List<TargetReport>)>
-      crateApiEngineSamplerCollectNetworkSamples({
-    required final NetworkConfiguration config,
-  });
-
-  Future<LatencyStats> crateApiAnalysisStatsComputeLatencyStats({
-    required final Uint64List latencies,
-    required final int totalExpectedSamples,
-    required final QualityThresholds thresholds,
-  });
-
-  Future<ConnectionType> crateApiModelsNetInfoConnectionTypeDefault();
-
-  Future<bool> crateApiProbesDnsDetectDnsHijacking({
-    required final String domain,
-  });
-
-  Future<bool> crateApiProbesDnsDetectDnsHijackingWeb({
-    required final String domain,
-  });
-
-  Future<(SecurityFlagsResult, ConnectionType)>
-      crateApiProbesInterfaceDetectSecurityAndNetworkType();
-
-  Future<(SecurityFlagsResult, ConnectionType)>
-      crateApiProbesInterfaceDetectSecurityAndNetworkTypeWeb();
-
-  Future<ConnectionQuality> crateApiAnalysisQualityEvaluateNetworkQuality({
-    required final bool isConnected,
-    required final LatencyStats stats,
-    required final NetworkConfiguration config,
-  });
-
-  Future<ConnectionQuality> crateApiAnalysisQualityEvaluateQuality({
-    required final BigInt latency,
-    required final QualityThresholds threshold,
-  });
-
-  Future<TargetReport> crateApiProbesTargetNativeProbeCheck({
-    required final NativeProbe that,
-    required final NetworkTarget target,
-  });
-
-  Future<NetworkConfiguration>
-      crateApiModelsConfigNetworkConfigurationDefault();
-
-  Future<NetworkConfiguration> crateApiModelsConfigNetworkConfigurationNew({
-    required final List<NetworkTarget> targets,
-    required final BigInt checkIntervalMs,
-    required final BigInt cacheValidityMs,
-    required final QualityThresholds qualityThreshold,
-    required final SecurityConfig security,
-    required final ResilienceConfig resilience,
-  });
-
-  Future<void> crateApiEngineSecurityPerformDnsSecurityCheck({
-    required final NetworkConfiguration config,
-    required final SecurityFlagsResult flags,
-  });
-
-  Future<QualityThresholds> crateApiModelsConfigQualityThresholdsDefault();
-
-  Future<QualityThresholds> crateApiModelsConfigQualityThresholdsNew({
-    required final BigInt excellent,
-    required final BigInt great,
-    required final BigInt good,
-    required final BigInt moderate,
-    required final BigInt poor,
-  });
-
-  Future<ResilienceConfig> crateApiModelsConfigResilienceConfigDefault();
-
-  Future<ResilienceConfig> crateApiModelsConfigResilienceConfigNew({
-    required final CheckStrategy strategy,
-    required final int circuitBreakerThreshold,
-    required final BigInt circuitBreakerCooldownMs,
-    required final int numJitterSamples,
-    required final double jitterThresholdPercent,
-    required final int stabilityThershold,
-    required final double criticalPacketLossPrecent,
-  });
-
-  Future<SecurityConfig> crateApiModelsConfigSecurityConfigDefault();
-
-  Future<SecurityFlags> crateApiModelsNetInfoSecurityFlagsDefault();
-
-  Future<TargetReport> crateApiProbesTargetWebProbeCheck({
-    required final WebProbe that,
-    required final NetworkTarget target,
-  });
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_NetworkReport;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_NetworkReport;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_NetworkReportPtr;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_SecurityFlagsResult;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_SecurityFlagsResult;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_SecurityFlagsResultPtr;
-}
-
-class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
-  RustLibApiImpl({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  @override
-  ConnectionType
-      crateApiModelsReportNetworkReportAutoAccessorGetConnectionType({
-    required final NetworkReport that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_connection_type,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsReportNetworkReportAutoAccessorGetConnectionTypeConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsReportNetworkReportAutoAccessorGetConnectionTypeConstMeta =>
-          const TaskConstMeta(
-            debugName: 'NetworkReport_auto_accessor_get_connection_type',
-            argNames: ['that'],
-          );
-
-  @override
-  SecurityFlagsResult
-      crateApiModelsReportNetworkReportAutoAccessorGetSecurityFlagsResult({
-    required final NetworkReport that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsReportNetworkReportAutoAccessorGetSecurityFlagsResultConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsReportNetworkReportAutoAccessorGetSecurityFlagsResultConstMeta =>
-          const TaskConstMeta(
-            debugName: 'NetworkReport_auto_accessor_get_security_flags_result',
-            argNames: ['that'],
-          );
-
-  @override
-  NetworkStatus crateApiModelsReportNetworkReportAutoAccessorGetStatus({
-    required final NetworkReport that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_network_status,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsReportNetworkReportAutoAccessorGetStatusConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsReportNetworkReportAutoAccessorGetStatusConstMeta =>
-          const TaskConstMeta(
-            debugName: 'NetworkReport_auto_accessor_get_status',
-            argNames: ['that'],
-          );
-
-  @override
-  List<TargetReport>
-      crateApiModelsReportNetworkReportAutoAccessorGetTargetReports({
-    required final NetworkReport that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_list_target_report,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsReportNetworkReportAutoAccessorGetTargetReportsConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsReportNetworkReportAutoAccessorGetTargetReportsConstMeta =>
-          const TaskConstMeta(
-            debugName: 'NetworkReport_auto_accessor_get_target_reports',
-            argNames: ['that'],
-          );
-
-  @override
-  BigInt crateApiModelsReportNetworkReportAutoAccessorGetTimestampMs({
-    required final NetworkReport that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_u_64,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsReportNetworkReportAutoAccessorGetTimestampMsConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsReportNetworkReportAutoAccessorGetTimestampMsConstMeta =>
-          const TaskConstMeta(
-            debugName: 'NetworkReport_auto_accessor_get_timestamp_ms',
-            argNames: ['that'],
-          );
-
-  @override
-  void crateApiModelsReportNetworkReportAutoAccessorSetConnectionType({
-    required final NetworkReport that,
-    required final ConnectionType connectionType,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-            that,
-            serializer,
-          );
-          sse_encode_connection_type(connectionType, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsReportNetworkReportAutoAccessorSetConnectionTypeConstMeta,
-        argValues: [that, connectionType],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsReportNetworkReportAutoAccessorSetConnectionTypeConstMeta =>
-          const TaskConstMeta(
-            debugName: 'NetworkReport_auto_accessor_set_connection_type',
-            argNames: ['that', 'connectionType'],
-          );
-
-  @override
-  void crateApiModelsReportNetworkReportAutoAccessorSetSecurityFlagsResult({
-    required final NetworkReport that,
-    required final SecurityFlagsResult securityFlagsResult,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-            that,
-            serializer,
-          );
-          sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-            securityFlagsResult,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsReportNetworkReportAutoAccessorSetSecurityFlagsResultConstMeta,
-        argValues: [that, securityFlagsResult],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsReportNetworkReportAutoAccessorSetSecurityFlagsResultConstMeta =>
-          const TaskConstMeta(
-            debugName: 'NetworkReport_auto_accessor_set_security_flags_result',
-            argNames: ['that', 'securityFlagsResult'],
-          );
-
-  @override
-  void crateApiModelsReportNetworkReportAutoAccessorSetStatus({
-    required final NetworkReport that,
-    required final NetworkStatus status,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-            that,
-            serializer,
-          );
-          sse_encode_network_status(status, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsReportNetworkReportAutoAccessorSetStatusConstMeta,
-        argValues: [that, status],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsReportNetworkReportAutoAccessorSetStatusConstMeta =>
-          const TaskConstMeta(
-            debugName: 'NetworkReport_auto_accessor_set_status',
-            argNames: ['that', 'status'],
-          );
-
-  @override
-  void crateApiModelsReportNetworkReportAutoAccessorSetTargetReports({
-    required final NetworkReport that,
-    required final List<TargetReport> targetReports,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-            that,
-            serializer,
-          );
-          sse_encode_list_target_report(targetReports, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsReportNetworkReportAutoAccessorSetTargetReportsConstMeta,
-        argValues: [that, targetReports],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsReportNetworkReportAutoAccessorSetTargetReportsConstMeta =>
-          const TaskConstMeta(
-            debugName: 'NetworkReport_auto_accessor_set_target_reports',
-            argNames: ['that', 'targetReports'],
-          );
-
-  @override
-  void crateApiModelsReportNetworkReportAutoAccessorSetTimestampMs({
-    required final NetworkReport that,
-    required final BigInt timestampMs,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-            that,
-            serializer,
-          );
-          sse_encode_u_64(timestampMs, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsReportNetworkReportAutoAccessorSetTimestampMsConstMeta,
-        argValues: [that, timestampMs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsReportNetworkReportAutoAccessorSetTimestampMsConstMeta =>
-          const TaskConstMeta(
-            debugName: 'NetworkReport_auto_accessor_set_timestamp_ms',
-            argNames: ['that', 'timestampMs'],
-          );
-
-  @override
-  String crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetInterfaceName({
-    required final SecurityFlagsResult that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_String,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetInterfaceNameConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetInterfaceNameConstMeta =>
-          const TaskConstMeta(
-            debugName: 'SecurityFlagsResult_auto_accessor_get_interface_name',
-            argNames: ['that'],
-          );
-
-  @override
-  bool crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsDnsSpoofed({
-    required final SecurityFlagsResult that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsDnsSpoofedConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsDnsSpoofedConstMeta =>
-          const TaskConstMeta(
-            debugName: 'SecurityFlagsResult_auto_accessor_get_is_dns_spoofed',
-            argNames: ['that'],
-          );
-
-  @override
-  bool crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsProxyDetected({
-    required final SecurityFlagsResult that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsProxyDetectedConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsProxyDetectedConstMeta =>
-          const TaskConstMeta(
-            debugName:
-                'SecurityFlagsResult_auto_accessor_get_is_proxy_detected',
-            argNames: ['that'],
-          );
-
-  @override
-  bool crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsVpnDetected({
-    required final SecurityFlagsResult that,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-            that,
-            serializer,
-          );
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsVpnDetectedConstMeta,
-        argValues: [that],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsVpnDetectedConstMeta =>
-          const TaskConstMeta(
-            debugName: 'SecurityFlagsResult_auto_accessor_get_is_vpn_detected',
-            argNames: ['that'],
-          );
-
-  @override
-  void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetInterfaceName({
-    required final SecurityFlagsResult that,
-    required final String interfaceName,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-            that,
-            serializer,
-          );
-          sse_encode_String(interfaceName, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetInterfaceNameConstMeta,
-        argValues: [that, interfaceName],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetInterfaceNameConstMeta =>
-          const TaskConstMeta(
-            debugName: 'SecurityFlagsResult_auto_accessor_set_interface_name',
-            argNames: ['that', 'interfaceName'],
-          );
-
-  @override
-  void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsDnsSpoofed({
-    required final SecurityFlagsResult that,
-    required final bool isDnsSpoofed,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-            that,
-            serializer,
-          );
-          sse_encode_bool(isDnsSpoofed, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsDnsSpoofedConstMeta,
-        argValues: [that, isDnsSpoofed],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsDnsSpoofedConstMeta =>
-          const TaskConstMeta(
-            debugName: 'SecurityFlagsResult_auto_accessor_set_is_dns_spoofed',
-            argNames: ['that', 'isDnsSpoofed'],
-          );
-
-  @override
-  void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsProxyDetected({
-    required final SecurityFlagsResult that,
-    required final bool isProxyDetected,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-            that,
-            serializer,
-          );
-          sse_encode_bool(isProxyDetected, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsProxyDetectedConstMeta,
-        argValues: [that, isProxyDetected],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsProxyDetectedConstMeta =>
-          const TaskConstMeta(
-            debugName:
-                'SecurityFlagsResult_auto_accessor_set_is_proxy_detected',
-            argNames: ['that', 'isProxyDetected'],
-          );
-
-  @override
-  void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsVpnDetected({
-    required final SecurityFlagsResult that,
-    required final bool isVpnDetected,
-  }) {
-    return handler.executeSync(
-      SyncTask(
-        callFfi: () {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-            that,
-            serializer,
-          );
-          sse_encode_bool(isVpnDetected, serializer);
-          return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18)!;
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsVpnDetectedConstMeta,
-        argValues: [that, isVpnDetected],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsVpnDetectedConstMeta =>
-          const TaskConstMeta(
-            debugName: 'SecurityFlagsResult_auto_accessor_set_is_vpn_detected',
-            argNames: ['that', 'isVpnDetected'],
-          );
-
-  @override
-  Future<SecurityFlagsResult>
-      crateApiModelsNetInfoSecurityFlagsResultDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 19,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelsNetInfoSecurityFlagsResultDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: 'SecurityFlagsResult_default',
-        argNames: [],
-      );
-
-  @override
-  Future<BigInt?> crateApiEngineSamplerAnalyzeSingleSample({
-    required final List<TargetReport> reports,
-    required final NetworkConfiguration config,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_target_report(reports, serializer);
-          sse_encode_box_autoadd_network_configuration(config, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 20,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiEngineSamplerAnalyzeSingleSampleConstMeta,
-        argValues: [reports, config],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiEngineSamplerAnalyzeSingleSampleConstMeta =>
-      const TaskConstMeta(
-        debugName: 'analyze_single_sample',
-        argNames: ['reports', 'config'],
-      );
-
-  @override
-  Future<(BigInt?, BigInt?, BigInt?, double?)>
-      crateApiAnalysisStatsCalculateJitterStats({
-    required final Uint64List latencies,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_64_strict(latencies, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 21,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAnalysisStatsCalculateJitterStatsConstMeta,
-        argValues: [latencies],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAnalysisStatsCalculateJitterStatsConstMeta =>
-      const TaskConstMeta(
-        debugName: 'calculate_jitter_stats',
-        argNames: ['latencies'],
-      );
-
-  @override
-  Future<CaptivePortalStatus> crateApiProbesCaptivePortalCheckForCaptivePortal({
-    required final BigInt timeoutMs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(timeoutMs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 22,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_captive_portal_status,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProbesCaptivePortalCheckForCaptivePortalConstMeta,
-        argValues: [timeoutMs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiProbesCaptivePortalCheckForCaptivePortalConstMeta =>
-          const TaskConstMeta(
-            debugName: 'check_for_captive_portal',
-            argNames: ['timeoutMs'],
-          );
-
-  @override
-  Future<CaptivePortalStatus>
-      crateApiProbesCaptivePortalCheckForCaptivePortalWeb({
-    required final BigInt timeoutMs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(timeoutMs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 23,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_captive_portal_status,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiProbesCaptivePortalCheckForCaptivePortalWebConstMeta,
-        argValues: [timeoutMs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiProbesCaptivePortalCheckForCaptivePortalWebConstMeta =>
-          const TaskConstMeta(
-            debugName: 'check_for_captive_portal_web',
-            argNames: ['timeoutMs'],
-          );
-
-  @override
-  Future<CaptivePortalStatus>
-      crateApiProbesCaptivePortalCheckForCaptivePortalWebManual({
-    required final BigInt timeoutMs,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(timeoutMs, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 24,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_captive_portal_status,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiProbesCaptivePortalCheckForCaptivePortalWebManualConstMeta,
-        argValues: [timeoutMs],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiProbesCaptivePortalCheckForCaptivePortalWebManualConstMeta =>
-          const TaskConstMeta(
-            debugName: 'check_for_captive_portal_web_manual',
-            argNames: ['timeoutMs'],
-          );
-
-  @override
-  Future<NetworkReport> crateApiEngineCheckNetwork({
-    required final NetworkConfiguration config,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_network_configuration(config, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 25,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiEngineCheckNetworkConstMeta,
-        argValues: [config],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiEngineCheckNetworkConstMeta => const TaskConstMeta(
-        debugName: 'check_network',
-        argNames: ['config'],
-      );
-
-  @override
-  Future<TargetReport> crateApiProbesTargetCheckTarget({
-    required final NetworkTarget target,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_network_target(target, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 26,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_target_report,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProbesTargetCheckTargetConstMeta,
-        argValues: [target],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProbesTargetCheckTargetConstMeta =>
-      const TaskConstMeta(
-        debugName: 'check_target',
-        argNames: ['target'],
-      );
-
-  @override
-  Future<(Uint64List, List<TargetReport>)>
-      crateApiEngineSamplerCollectNetworkSamples({
-    required final NetworkConfiguration config,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_network_configuration(config, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 27,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_record_list_prim_u_64_strict_list_target_report,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiEngineSamplerCollectNetworkSamplesConstMeta,
-        argValues: [config],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiEngineSamplerCollectNetworkSamplesConstMeta =>
-      const TaskConstMeta(
-        debugName: 'collect_network_samples',
-        argNames: ['config'],
-      );
-
-  @override
-  Future<LatencyStats> crateApiAnalysisStatsComputeLatencyStats({
-    required final Uint64List latencies,
-    required final int totalExpectedSamples,
-    required final QualityThresholds thresholds,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_prim_u_64_strict(latencies, serializer);
-          sse_encode_u_8(totalExpectedSamples, serializer);
-          sse_encode_box_autoadd_quality_thresholds(thresholds, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 28,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_latency_stats,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAnalysisStatsComputeLatencyStatsConstMeta,
-        argValues: [latencies, totalExpectedSamples, thresholds],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAnalysisStatsComputeLatencyStatsConstMeta =>
-      const TaskConstMeta(
-        debugName: 'compute_latency_stats',
-        argNames: ['latencies', 'totalExpectedSamples', 'thresholds'],
-      );
-
-  @override
-  Future<ConnectionType> crateApiModelsNetInfoConnectionTypeDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 29,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_connection_type,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelsNetInfoConnectionTypeDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelsNetInfoConnectionTypeDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: 'connection_type_default',
-        argNames: [],
-      );
-
-  @override
-  Future<bool> crateApiProbesDnsDetectDnsHijacking({
-    required final String domain,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(domain, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 30,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProbesDnsDetectDnsHijackingConstMeta,
-        argValues: [domain],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProbesDnsDetectDnsHijackingConstMeta =>
-      const TaskConstMeta(
-        debugName: 'detect_dns_hijacking',
-        argNames: ['domain'],
-      );
-
-  @override
-  Future<bool> crateApiProbesDnsDetectDnsHijackingWeb({
-    required final String domain,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_String(domain, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 31,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_bool,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProbesDnsDetectDnsHijackingWebConstMeta,
-        argValues: [domain],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProbesDnsDetectDnsHijackingWebConstMeta =>
-      const TaskConstMeta(
-        debugName: 'detect_dns_hijacking_web',
-        argNames: ['domain'],
-      );
-
-  @override
-  Future<(SecurityFlagsResult, ConnectionType)>
-      crateApiProbesInterfaceDetectSecurityAndNetworkType() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 32,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiProbesInterfaceDetectSecurityAndNetworkTypeConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiProbesInterfaceDetectSecurityAndNetworkTypeConstMeta =>
-          const TaskConstMeta(
-            debugName: 'detect_security_and_network_type',
-            argNames: [],
-          );
-
-  @override
-  Future<(SecurityFlagsResult, ConnectionType)>
-      crateApiProbesInterfaceDetectSecurityAndNetworkTypeWeb() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 33,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData:
-              sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type,
-          decodeErrorData: null,
-        ),
-        constMeta:
-            kCrateApiProbesInterfaceDetectSecurityAndNetworkTypeWebConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta
-      get kCrateApiProbesInterfaceDetectSecurityAndNetworkTypeWebConstMeta =>
-          const TaskConstMeta(
-            debugName: 'detect_security_and_network_type_web',
-            argNames: [],
-          );
-
-  @override
-  Future<ConnectionQuality> crateApiAnalysisQualityEvaluateNetworkQuality({
-    required final bool isConnected,
-    required final LatencyStats stats,
-    required final NetworkConfiguration config,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_bool(isConnected, serializer);
-          sse_encode_box_autoadd_latency_stats(stats, serializer);
-          sse_encode_box_autoadd_network_configuration(config, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 34,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_connection_quality,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAnalysisQualityEvaluateNetworkQualityConstMeta,
-        argValues: [isConnected, stats, config],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAnalysisQualityEvaluateNetworkQualityConstMeta =>
-      const TaskConstMeta(
-        debugName: 'evaluate_network_quality',
-        argNames: ['isConnected', 'stats', 'config'],
-      );
-
-  @override
-  Future<ConnectionQuality> crateApiAnalysisQualityEvaluateQuality({
-    required final BigInt latency,
-    required final QualityThresholds threshold,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(latency, serializer);
-          sse_encode_box_autoadd_quality_thresholds(threshold, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 35,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_connection_quality,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiAnalysisQualityEvaluateQualityConstMeta,
-        argValues: [latency, threshold],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiAnalysisQualityEvaluateQualityConstMeta =>
-      const TaskConstMeta(
-        debugName: 'evaluate_quality',
-        argNames: ['latency', 'threshold'],
-      );
-
-  @override
-  Future<TargetReport> crateApiProbesTargetNativeProbeCheck({
-    required final NativeProbe that,
-    required final NetworkTarget target,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_native_probe(that, serializer);
-          sse_encode_box_autoadd_network_target(target, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 36,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_target_report,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProbesTargetNativeProbeCheckConstMeta,
-        argValues: [that, target],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProbesTargetNativeProbeCheckConstMeta =>
-      const TaskConstMeta(
-        debugName: 'native_probe_check',
-        argNames: ['that', 'target'],
-      );
-
-  @override
-  Future<NetworkConfiguration>
-      crateApiModelsConfigNetworkConfigurationDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 37,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_network_configuration,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelsConfigNetworkConfigurationDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelsConfigNetworkConfigurationDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: 'network_configuration_default',
-        argNames: [],
-      );
-
-  @override
-  Future<NetworkConfiguration> crateApiModelsConfigNetworkConfigurationNew({
-    required final List<NetworkTarget> targets,
-    required final BigInt checkIntervalMs,
-    required final BigInt cacheValidityMs,
-    required final QualityThresholds qualityThreshold,
-    required final SecurityConfig security,
-    required final ResilienceConfig resilience,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_list_network_target(targets, serializer);
-          sse_encode_u_64(checkIntervalMs, serializer);
-          sse_encode_u_64(cacheValidityMs, serializer);
-          sse_encode_box_autoadd_quality_thresholds(
-            qualityThreshold,
-            serializer,
-          );
-          sse_encode_box_autoadd_security_config(security, serializer);
-          sse_encode_box_autoadd_resilience_config(resilience, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 38,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_network_configuration,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelsConfigNetworkConfigurationNewConstMeta,
-        argValues: [
-          targets,
-          checkIntervalMs,
-          cacheValidityMs,
-          qualityThreshold,
-          security,
-          resilience,
-        ],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelsConfigNetworkConfigurationNewConstMeta =>
-      const TaskConstMeta(
-        debugName: 'network_configuration_new',
-        argNames: [
-          'targets',
-          'checkIntervalMs',
-          'cacheValidityMs',
-          'qualityThreshold',
-          'security',
-          'resilience',
-        ],
-      );
-
-  @override
-  Future<void> crateApiEngineSecurityPerformDnsSecurityCheck({
-    required final NetworkConfiguration config,
-    required final SecurityFlagsResult flags,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_network_configuration(config, serializer);
-          sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-            flags,
-            serializer,
-          );
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 39,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_unit,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiEngineSecurityPerformDnsSecurityCheckConstMeta,
-        argValues: [config, flags],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiEngineSecurityPerformDnsSecurityCheckConstMeta =>
-      const TaskConstMeta(
-        debugName: 'perform_dns_security_check',
-        argNames: ['config', 'flags'],
-      );
-
-  @override
-  Future<QualityThresholds> crateApiModelsConfigQualityThresholdsDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 40,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_quality_thresholds,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelsConfigQualityThresholdsDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelsConfigQualityThresholdsDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: 'quality_thresholds_default',
-        argNames: [],
-      );
-
-  @override
-  Future<QualityThresholds> crateApiModelsConfigQualityThresholdsNew({
-    required final BigInt excellent,
-    required final BigInt great,
-    required final BigInt good,
-    required final BigInt moderate,
-    required final BigInt poor,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_u_64(excellent, serializer);
-          sse_encode_u_64(great, serializer);
-          sse_encode_u_64(good, serializer);
-          sse_encode_u_64(moderate, serializer);
-          sse_encode_u_64(poor, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 41,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_quality_thresholds,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelsConfigQualityThresholdsNewConstMeta,
-        argValues: [excellent, great, good, moderate, poor],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelsConfigQualityThresholdsNewConstMeta =>
-      const TaskConstMeta(
-        debugName: 'quality_thresholds_new',
-        argNames: ['excellent', 'great', 'good', 'moderate', 'poor'],
-      );
-
-  @override
-  Future<ResilienceConfig> crateApiModelsConfigResilienceConfigDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 42,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_resilience_config,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelsConfigResilienceConfigDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelsConfigResilienceConfigDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: 'resilience_config_default',
-        argNames: [],
-      );
-
-  @override
-  Future<ResilienceConfig> crateApiModelsConfigResilienceConfigNew({
-    required final CheckStrategy strategy,
-    required final int circuitBreakerThreshold,
-    required final BigInt circuitBreakerCooldownMs,
-    required final int numJitterSamples,
-    required final double jitterThresholdPercent,
-    required final int stabilityThershold,
-    required final double criticalPacketLossPrecent,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_check_strategy(strategy, serializer);
-          sse_encode_u_8(circuitBreakerThreshold, serializer);
-          sse_encode_u_64(circuitBreakerCooldownMs, serializer);
-          sse_encode_u_8(numJitterSamples, serializer);
-          sse_encode_f_64(jitterThresholdPercent, serializer);
-          sse_encode_u_8(stabilityThershold, serializer);
-          sse_encode_f_32(criticalPacketLossPrecent, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 43,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_resilience_config,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelsConfigResilienceConfigNewConstMeta,
-        argValues: [
-          strategy,
-          circuitBreakerThreshold,
-          circuitBreakerCooldownMs,
-          numJitterSamples,
-          jitterThresholdPercent,
-          stabilityThershold,
-          criticalPacketLossPrecent,
-        ],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelsConfigResilienceConfigNewConstMeta =>
-      const TaskConstMeta(
-        debugName: 'resilience_config_new',
-        argNames: [
-          'strategy',
-          'circuitBreakerThreshold',
-          'circuitBreakerCooldownMs',
-          'numJitterSamples',
-          'jitterThresholdPercent',
-          'stabilityThershold',
-          'criticalPacketLossPrecent',
-        ],
-      );
-
-  @override
-  Future<SecurityConfig> crateApiModelsConfigSecurityConfigDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 44,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_security_config,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelsConfigSecurityConfigDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelsConfigSecurityConfigDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: 'security_config_default',
-        argNames: [],
-      );
-
-  @override
-  Future<SecurityFlags> crateApiModelsNetInfoSecurityFlagsDefault() {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 45,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_security_flags,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiModelsNetInfoSecurityFlagsDefaultConstMeta,
-        argValues: [],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsDefaultConstMeta =>
-      const TaskConstMeta(
-        debugName: 'security_flags_default',
-        argNames: [],
-      );
-
-  @override
-  Future<TargetReport> crateApiProbesTargetWebProbeCheck({
-    required final WebProbe that,
-    required final NetworkTarget target,
-  }) {
-    return handler.executeNormal(
-      NormalTask(
-        callFfi: (final port_) {
-          final serializer = SseSerializer(generalizedFrbRustBinding);
-          sse_encode_box_autoadd_web_probe(that, serializer);
-          sse_encode_box_autoadd_network_target(target, serializer);
-          pdeCallFfi(
-            generalizedFrbRustBinding,
-            serializer,
-            funcId: 46,
-            port: port_,
-          );
-        },
-        codec: SseCodec(
-          decodeSuccessData: sse_decode_target_report,
-          decodeErrorData: null,
-        ),
-        constMeta: kCrateApiProbesTargetWebProbeCheckConstMeta,
-        argValues: [that, target],
-        apiImpl: this,
-      ),
-    );
-  }
-
-  TaskConstMeta get kCrateApiProbesTargetWebProbeCheckConstMeta =>
-      const TaskConstMeta(
-        debugName: 'web_probe_check',
-        argNames: ['that', 'target'],
-      );
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_NetworkReport => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_NetworkReport => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport;
-
-  RustArcIncrementStrongCountFnType
-      get rust_arc_increment_strong_count_SecurityFlagsResult => wire
-          .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult;
-
-  RustArcDecrementStrongCountFnType
-      get rust_arc_decrement_strong_count_SecurityFlagsResult => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult;
-
-  @protected
-  NetworkReport
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return NetworkReportImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SecurityFlagsResult
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SecurityFlagsResultImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  NetworkReport
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return NetworkReportImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SecurityFlagsResult
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SecurityFlagsResultImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  NetworkReport
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return NetworkReportImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SecurityFlagsResult
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SecurityFlagsResultImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  NetworkReport
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return NetworkReportImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  SecurityFlagsResult
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return SecurityFlagsResultImpl.frbInternalDcoDecode(raw as List<dynamic>);
-  }
-
-  @protected
-  String dco_decode_String(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as String;
-  }
-
-  @protected
-  bool dco_decode_bool(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as bool;
-  }
-
-  @protected
-  double dco_decode_box_autoadd_f_64(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  LatencyStats dco_decode_box_autoadd_latency_stats(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_latency_stats(raw);
-  }
-
-  @protected
-  NativeProbe dco_decode_box_autoadd_native_probe(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_native_probe(raw);
-  }
-
-  @protected
-  NetworkConfiguration dco_decode_box_autoadd_network_configuration(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_network_configuration(raw);
-  }
-
-  @protected
-  NetworkTarget dco_decode_box_autoadd_network_target(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_network_target(raw);
-  }
-
-  @protected
-  QualityThresholds dco_decode_box_autoadd_quality_thresholds(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_quality_thresholds(raw);
-  }
-
-  @protected
-  ResilienceConfig dco_decode_box_autoadd_resilience_config(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_resilience_config(raw);
-  }
-
-  @protected
-  SecurityConfig dco_decode_box_autoadd_security_config(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_security_config(raw);
-  }
-
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_u_64(raw);
-  }
-
-  @protected
-  WebProbe dco_decode_box_autoadd_web_probe(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dco_decode_web_probe(raw);
-  }
-
-  @protected
-  CaptivePortalStatus dco_decode_captive_portal_status(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return CaptivePortalStatus(
-      isCaptivePortal: dco_decode_bool(arr[0]),
-      redirectUrl: dco_decode_opt_String(arr[1]),
-    );
-  }
-
-  @protected
-  CheckStrategy dco_decode_check_strategy(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return CheckStrategy.values[raw as int];
-  }
-
-  @protected
-  ConnectionQuality dco_decode_connection_quality(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ConnectionQuality.values[raw as int];
-  }
-
-  @protected
-  ConnectionType dco_decode_connection_type(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return ConnectionType.values[raw as int];
-  }
-
-  @protected
-  double dco_decode_f_32(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  double dco_decode_f_64(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as double;
-  }
-
-  @protected
-  int dco_decode_i_32(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  LatencyStats dco_decode_latency_stats(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return LatencyStats(
-      latencyMs: dco_decode_u_64(arr[0]),
-      jitterMs: dco_decode_u_64(arr[1]),
-      packetLossPercent: dco_decode_f_32(arr[2]),
-      minLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
-      avgLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[4]),
-      maxLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[5]),
-      stabilityScore: dco_decode_u_8(arr[6]),
-    );
-  }
-
-  @protected
-  List<NetworkTarget> dco_decode_list_network_target(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_network_target).toList();
-  }
-
-  @protected
-  Uint64List dco_decode_list_prim_u_64_strict(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeUint64List(raw);
-  }
-
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as Uint8List;
-  }
-
-  @protected
-  List<TargetReport> dco_decode_list_target_report(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return (raw as List<dynamic>).map(dco_decode_target_report).toList();
-  }
-
-  @protected
-  NativeProbe dco_decode_native_probe(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.isNotEmpty)
-      throw Exception('unexpected arr length: expect 0 but see ${arr.length}');
-    return const NativeProbe();
-  }
-
-  @protected
-  NetworkConfiguration dco_decode_network_configuration(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 6)
-      throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
-    return NetworkConfiguration(
-      targets: dco_decode_list_network_target(arr[0]),
-      checkIntervalMs: dco_decode_u_64(arr[1]),
-      cacheValidityMs: dco_decode_u_64(arr[2]),
-      qualityThreshold: dco_decode_quality_thresholds(arr[3]),
-      security: dco_decode_security_config(arr[4]),
-      resilience: dco_decode_resilience_config(arr[5]),
-    );
-  }
-
-  @protected
-  NetworkStatus dco_decode_network_status(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return NetworkStatus(
-      isConnected: dco_decode_bool(arr[0]),
-      quality: dco_decode_connection_quality(arr[1]),
-      latencyStats: dco_decode_latency_stats(arr[2]),
-      winnerTarget: dco_decode_String(arr[3]),
-    );
-  }
-
-  @protected
-  NetworkTarget dco_decode_network_target(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return NetworkTarget(
-      label: dco_decode_String(arr[0]),
-      host: dco_decode_String(arr[1]),
-      port: dco_decode_u_16(arr[2]),
-      protocol: dco_decode_target_protocol(arr[3]),
-      timeoutMs: dco_decode_u_64(arr[4]),
-      priority: dco_decode_u_8(arr[5]),
-      isEssential: dco_decode_bool(arr[6]),
-    );
-  }
-
-  @protected
-  String? dco_decode_opt_String(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_String(raw);
-  }
-
-  @protected
-  double? dco_decode_opt_box_autoadd_f_64(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_f_64(raw);
-  }
-
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw == null ? null : dco_decode_box_autoadd_u_64(raw);
-  }
-
-  @protected
-  QualityThresholds dco_decode_quality_thresholds(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return QualityThresholds(
-      excellent: dco_decode_u_64(arr[0]),
-      great: dco_decode_u_64(arr[1]),
-      good: dco_decode_u_64(arr[2]),
-      moderate: dco_decode_u_64(arr[3]),
-      poor: dco_decode_u_64(arr[4]),
-    );
-  }
-
-  @protected
-  (
-    SecurityFlagsResult,
-    ConnectionType
-  ) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-        arr[0],
-      ),
-      dco_decode_connection_type(arr[1]),
-    );
-  }
-
-  @protected
-  (Uint64List, List<TargetReport>)
-      dco_decode_record_list_prim_u_64_strict_list_target_report(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2) {
-      throw Exception('Expected 2 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_list_prim_u_64_strict(arr[0]),
-      dco_decode_list_target_report(arr[1]),
-    );
-  }
-
-  @protected
-  (
-    BigInt?,
-    BigInt?,
-    BigInt?,
-    double?
-  ) dco_decode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64(
-    final dynamic raw,
-  ) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4) {
-      throw Exception('Expected 4 elements, got ${arr.length}');
-    }
-    return (
-      dco_decode_opt_box_autoadd_u_64(arr[0]),
-      dco_decode_opt_box_autoadd_u_64(arr[1]),
-      dco_decode_opt_box_autoadd_u_64(arr[2]),
-      dco_decode_opt_box_autoadd_f_64(arr[3]),
-    );
-  }
-
-  @protected
-  ResilienceConfig dco_decode_resilience_config(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 7)
-      throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
-    return ResilienceConfig(
-      strategy: dco_decode_check_strategy(arr[0]),
-      circuitBreakerThreshold: dco_decode_u_8(arr[1]),
-      circuitBreakerCooldownMs: dco_decode_u_64(arr[2]),
-      numJitterSamples: dco_decode_u_8(arr[3]),
-      jitterThresholdPercent: dco_decode_f_64(arr[4]),
-      stabilityThershold: dco_decode_u_8(arr[5]),
-      criticalPacketLossPrecent: dco_decode_f_32(arr[6]),
-    );
-  }
-
-  @protected
-  SecurityConfig dco_decode_security_config(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 2)
-      throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
-    return SecurityConfig(
-      blockVpn: dco_decode_bool(arr[0]),
-      detectDnsHijack: dco_decode_bool(arr[1]),
-    );
-  }
-
-  @protected
-  SecurityFlags dco_decode_security_flags(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 4)
-      throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
-    return SecurityFlags(
-      isVpnDetected: dco_decode_bool(arr[0]),
-      isDnsSpoofed: dco_decode_bool(arr[1]),
-      isProxyDetected: dco_decode_bool(arr[2]),
-      interfaceName: dco_decode_String(arr[3]),
-    );
-  }
-
-  @protected
-  TargetProtocol dco_decode_target_protocol(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return TargetProtocol.values[raw as int];
-  }
-
-  @protected
-  TargetReport dco_decode_target_report(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.length != 5)
-      throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
-    return TargetReport(
-      label: dco_decode_String(arr[0]),
-      success: dco_decode_bool(arr[1]),
-      latencyMs: dco_decode_u_64(arr[2]),
-      error: dco_decode_opt_String(arr[3]),
-      isEssential: dco_decode_bool(arr[4]),
-    );
-  }
-
-  @protected
-  int dco_decode_u_16(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  BigInt dco_decode_u_64(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  int dco_decode_u_8(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return raw as int;
-  }
-
-  @protected
-  void dco_decode_unit(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return;
-  }
-
-  @protected
-  BigInt dco_decode_usize(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    return dcoDecodeU64(raw);
-  }
-
-  @protected
-  WebProbe dco_decode_web_probe(final dynamic raw) {
-    // Codec=Dco (DartCObject based), see doc to use other codecs
-    final arr = raw as List<dynamic>;
-    if (arr.isNotEmpty)
-      throw Exception('unexpected arr length: expect 0 but see ${arr.length}');
-    return const WebProbe();
-  }
-
-  @protected
-  NetworkReport
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return NetworkReportImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SecurityFlagsResult
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SecurityFlagsResultImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  NetworkReport
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return NetworkReportImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SecurityFlagsResult
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SecurityFlagsResultImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  NetworkReport
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return NetworkReportImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SecurityFlagsResult
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SecurityFlagsResultImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  NetworkReport
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return NetworkReportImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  SecurityFlagsResult
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return SecurityFlagsResultImpl.frbInternalSseDecode(
-      sse_decode_usize(deserializer),
-      sse_decode_i_32(deserializer),
-    );
-  }
-
-  @protected
-  String sse_decode_String(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_list_prim_u_8_strict(deserializer);
-    return utf8.decoder.convert(inner);
-  }
-
-  @protected
-  bool sse_decode_bool(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8() != 0;
-  }
-
-  @protected
-  double sse_decode_box_autoadd_f_64(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_f_64(deserializer));
-  }
-
-  @protected
-  LatencyStats sse_decode_box_autoadd_latency_stats(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_latency_stats(deserializer));
-  }
-
-  @protected
-  NativeProbe sse_decode_box_autoadd_native_probe(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_native_probe(deserializer));
-  }
-
-  @protected
-  NetworkConfiguration sse_decode_box_autoadd_network_configuration(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_network_configuration(deserializer));
-  }
-
-  @protected
-  NetworkTarget sse_decode_box_autoadd_network_target(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_network_target(deserializer));
-  }
-
-  @protected
-  QualityThresholds sse_decode_box_autoadd_quality_thresholds(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_quality_thresholds(deserializer));
-  }
-
-  @protected
-  ResilienceConfig sse_decode_box_autoadd_resilience_config(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_resilience_config(deserializer));
-  }
-
-  @protected
-  SecurityConfig sse_decode_box_autoadd_security_config(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_security_config(deserializer));
-  }
-
-  @protected
-  BigInt sse_decode_box_autoadd_u_64(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_u_64(deserializer));
-  }
-
-  @protected
-  WebProbe sse_decode_box_autoadd_web_probe(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return (sse_decode_web_probe(deserializer));
-  }
-
-  @protected
-  CaptivePortalStatus sse_decode_captive_portal_status(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_isCaptivePortal = sse_decode_bool(deserializer);
-    final var_redirectUrl = sse_decode_opt_String(deserializer);
-    return CaptivePortalStatus(
-      isCaptivePortal: var_isCaptivePortal,
-      redirectUrl: var_redirectUrl,
-    );
-  }
-
-  @protected
-  CheckStrategy sse_decode_check_strategy(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_i_32(deserializer);
-    return CheckStrategy.values[inner];
-  }
-
-  @protected
-  ConnectionQuality sse_decode_connection_quality(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_i_32(deserializer);
-    return ConnectionQuality.values[inner];
-  }
-
-  @protected
-  ConnectionType sse_decode_connection_type(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_i_32(deserializer);
-    return ConnectionType.values[inner];
-  }
-
-  @protected
-  double sse_decode_f_32(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat32();
-  }
-
-  @protected
-  double sse_decode_f_64(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getFloat64();
-  }
-
-  @protected
-  int sse_decode_i_32(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getInt32();
-  }
-
-  @protected
-  LatencyStats sse_decode_latency_stats(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_latencyMs = sse_decode_u_64(deserializer);
-    final var_jitterMs = sse_decode_u_64(deserializer);
-    final var_packetLossPercent = sse_decode_f_32(deserializer);
-    final var_minLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
-    final var_avgLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
-    final var_maxLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
-    final var_stabilityScore = sse_decode_u_8(deserializer);
-    return LatencyStats(
-      latencyMs: var_latencyMs,
-      jitterMs: var_jitterMs,
-      packetLossPercent: var_packetLossPercent,
-      minLatencyMs: var_minLatencyMs,
-      avgLatencyMs: var_avgLatencyMs,
-      maxLatencyMs: var_maxLatencyMs,
-      stabilityScore: var_stabilityScore,
-    );
-  }
-
-  @protected
-  List<NetworkTarget> sse_decode_list_network_target(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <NetworkTarget>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_network_target(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  Uint64List sse_decode_list_prim_u_64_strict(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint64List(len_);
-  }
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final len_ = sse_decode_i_32(deserializer);
-    return deserializer.buffer.getUint8List(len_);
-  }
-
-  @protected
-  List<TargetReport> sse_decode_list_target_report(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    final len_ = sse_decode_i_32(deserializer);
-    final ans_ = <TargetReport>[];
-    for (var idx_ = 0; idx_ < len_; ++idx_) {
-      ans_.add(sse_decode_target_report(deserializer));
-    }
-    return ans_;
-  }
-
-  @protected
-  NativeProbe sse_decode_native_probe(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return const NativeProbe();
-  }
-
-  @protected
-  NetworkConfiguration sse_decode_network_configuration(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_targets = sse_decode_list_network_target(deserializer);
-    final var_checkIntervalMs = sse_decode_u_64(deserializer);
-    final var_cacheValidityMs = sse_decode_u_64(deserializer);
-    final var_qualityThreshold = sse_decode_quality_thresholds(deserializer);
-    final var_security = sse_decode_security_config(deserializer);
-    final var_resilience = sse_decode_resilience_config(deserializer);
-    return NetworkConfiguration(
-      targets: var_targets,
-      checkIntervalMs: var_checkIntervalMs,
-      cacheValidityMs: var_cacheValidityMs,
-      qualityThreshold: var_qualityThreshold,
-      security: var_security,
-      resilience: var_resilience,
-    );
-  }
-
-  @protected
-  NetworkStatus sse_decode_network_status(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_isConnected = sse_decode_bool(deserializer);
-    final var_quality = sse_decode_connection_quality(deserializer);
-    final var_latencyStats = sse_decode_latency_stats(deserializer);
-    final var_winnerTarget = sse_decode_String(deserializer);
-    return NetworkStatus(
-      isConnected: var_isConnected,
-      quality: var_quality,
-      latencyStats: var_latencyStats,
-      winnerTarget: var_winnerTarget,
-    );
-  }
-
-  @protected
-  NetworkTarget sse_decode_network_target(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_label = sse_decode_String(deserializer);
-    final var_host = sse_decode_String(deserializer);
-    final var_port = sse_decode_u_16(deserializer);
-    final var_protocol = sse_decode_target_protocol(deserializer);
-    final var_timeoutMs = sse_decode_u_64(deserializer);
-    final var_priority = sse_decode_u_8(deserializer);
-    final var_isEssential = sse_decode_bool(deserializer);
-    return NetworkTarget(
-      label: var_label,
-      host: var_host,
-      port: var_port,
-      protocol: var_protocol,
-      timeoutMs: var_timeoutMs,
-      priority: var_priority,
-      isEssential: var_isEssential,
-    );
-  }
-
-  @protected
-  String? sse_decode_opt_String(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_String(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  double? sse_decode_opt_box_autoadd_f_64(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_f_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    if (sse_decode_bool(deserializer)) {
-      return (sse_decode_box_autoadd_u_64(deserializer));
-    } else {
-      return null;
-    }
-  }
-
-  @protected
-  QualityThresholds sse_decode_quality_thresholds(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_excellent = sse_decode_u_64(deserializer);
-    final var_great = sse_decode_u_64(deserializer);
-    final var_good = sse_decode_u_64(deserializer);
-    final var_moderate = sse_decode_u_64(deserializer);
-    final var_poor = sse_decode_u_64(deserializer);
-    return QualityThresholds(
-      excellent: var_excellent,
-      great: var_great,
-      good: var_good,
-      moderate: var_moderate,
-      poor: var_poor,
-    );
-  }
-
-  @protected
-  (
-    SecurityFlagsResult,
-    ConnectionType
-  ) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_field0 =
-        sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-      deserializer,
-    );
-    final var_field1 = sse_decode_connection_type(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (Uint64List, List<TargetReport>)
-      sse_decode_record_list_prim_u_64_strict_list_target_report(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_field0 = sse_decode_list_prim_u_64_strict(deserializer);
-    final var_field1 = sse_decode_list_target_report(deserializer);
-    return (var_field0, var_field1);
-  }
-
-  @protected
-  (
-    BigInt?,
-    BigInt?,
-    BigInt?,
-    double?
-  ) sse_decode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_field0 = sse_decode_opt_box_autoadd_u_64(deserializer);
-    final var_field1 = sse_decode_opt_box_autoadd_u_64(deserializer);
-    final var_field2 = sse_decode_opt_box_autoadd_u_64(deserializer);
-    final var_field3 = sse_decode_opt_box_autoadd_f_64(deserializer);
-    return (var_field0, var_field1, var_field2, var_field3);
-  }
-
-  @protected
-  ResilienceConfig sse_decode_resilience_config(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_strategy = sse_decode_check_strategy(deserializer);
-    final var_circuitBreakerThreshold = sse_decode_u_8(deserializer);
-    final var_circuitBreakerCooldownMs = sse_decode_u_64(deserializer);
-    final var_numJitterSamples = sse_decode_u_8(deserializer);
-    final var_jitterThresholdPercent = sse_decode_f_64(deserializer);
-    final var_stabilityThershold = sse_decode_u_8(deserializer);
-    final var_criticalPacketLossPrecent = sse_decode_f_32(deserializer);
-    return ResilienceConfig(
-      strategy: var_strategy,
-      circuitBreakerThreshold: var_circuitBreakerThreshold,
-      circuitBreakerCooldownMs: var_circuitBreakerCooldownMs,
-      numJitterSamples: var_numJitterSamples,
-      jitterThresholdPercent: var_jitterThresholdPercent,
-      stabilityThershold: var_stabilityThershold,
-      criticalPacketLossPrecent: var_criticalPacketLossPrecent,
-    );
-  }
-
-  @protected
-  SecurityConfig sse_decode_security_config(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_blockVpn = sse_decode_bool(deserializer);
-    final var_detectDnsHijack = sse_decode_bool(deserializer);
-    return SecurityConfig(
-      blockVpn: var_blockVpn,
-      detectDnsHijack: var_detectDnsHijack,
-    );
-  }
-
-  @protected
-  SecurityFlags sse_decode_security_flags(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_isVpnDetected = sse_decode_bool(deserializer);
-    final var_isDnsSpoofed = sse_decode_bool(deserializer);
-    final var_isProxyDetected = sse_decode_bool(deserializer);
-    final var_interfaceName = sse_decode_String(deserializer);
-    return SecurityFlags(
-      isVpnDetected: var_isVpnDetected,
-      isDnsSpoofed: var_isDnsSpoofed,
-      isProxyDetected: var_isProxyDetected,
-      interfaceName: var_interfaceName,
-    );
-  }
-
-  @protected
-  TargetProtocol sse_decode_target_protocol(
-    final SseDeserializer deserializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final inner = sse_decode_i_32(deserializer);
-    return TargetProtocol.values[inner];
-  }
-
-  @protected
-  TargetReport sse_decode_target_report(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    final var_label = sse_decode_String(deserializer);
-    final var_success = sse_decode_bool(deserializer);
-    final var_latencyMs = sse_decode_u_64(deserializer);
-    final var_error = sse_decode_opt_String(deserializer);
-    final var_isEssential = sse_decode_bool(deserializer);
-    return TargetReport(
-      label: var_label,
-      success: var_success,
-      latencyMs: var_latencyMs,
-      error: var_error,
-      isEssential: var_isEssential,
-    );
-  }
-
-  @protected
-  int sse_decode_u_16(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint16();
-  }
-
-  @protected
-  BigInt sse_decode_u_64(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  int sse_decode_u_8(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getUint8();
-  }
-
-  @protected
-  void sse_decode_unit(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  BigInt sse_decode_usize(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return deserializer.buffer.getBigUint64();
-  }
-
-  @protected
-  WebProbe sse_decode_web_probe(final SseDeserializer deserializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    return const WebProbe();
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final NetworkReport self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as NetworkReportImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SecurityFlagsResult self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SecurityFlagsResultImpl).frbInternalSseEncode(move: true),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final NetworkReport self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as NetworkReportImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SecurityFlagsResult self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SecurityFlagsResultImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final NetworkReport self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as NetworkReportImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SecurityFlagsResult self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SecurityFlagsResultImpl).frbInternalSseEncode(move: false),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final NetworkReport self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as NetworkReportImpl).frbInternalSseEncode(),
-      serializer,
-    );
-  }
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SecurityFlagsResult self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_usize(
-      (self as SecurityFlagsResultImpl).frbInternalSseEncode(),
-      serializer,
-    );
-  }
-
-  @protected
-  void sse_encode_String(final String self, final SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer);
-  }
-
-  @protected
-  void sse_encode_bool(final bool self, final SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self ? 1 : 0);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_f_64(
-    final double self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_f_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_latency_stats(
-    final LatencyStats self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_latency_stats(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_native_probe(
-    final NativeProbe self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_native_probe(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_network_configuration(
-    final NetworkConfiguration self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_network_configuration(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_network_target(
-    final NetworkTarget self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_network_target(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_quality_thresholds(
-    final QualityThresholds self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_quality_thresholds(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_resilience_config(
-    final ResilienceConfig self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_resilience_config(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_security_config(
-    final SecurityConfig self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_security_config(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_u_64(
-    final BigInt self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self, serializer);
-  }
-
-  @protected
-  void sse_encode_box_autoadd_web_probe(
-    final WebProbe self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_web_probe(self, serializer);
-  }
-
-  @protected
-  void sse_encode_captive_portal_status(
-    final CaptivePortalStatus self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.isCaptivePortal, serializer);
-    sse_encode_opt_String(self.redirectUrl, serializer);
-  }
-
-  @protected
-  void sse_encode_check_strategy(
-    final CheckStrategy self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_connection_quality(
-    final ConnectionQuality self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_connection_type(
-    final ConnectionType self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_f_32(final double self, final SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat32(self);
-  }
-
-  @protected
-  void sse_encode_f_64(final double self, final SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putFloat64(self);
-  }
-
-  @protected
-  void sse_encode_i_32(final int self, final SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putInt32(self);
-  }
-
-  @protected
-  void sse_encode_latency_stats(
-    final LatencyStats self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.latencyMs, serializer);
-    sse_encode_u_64(self.jitterMs, serializer);
-    sse_encode_f_32(self.packetLossPercent, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.minLatencyMs, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.avgLatencyMs, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.maxLatencyMs, serializer);
-    sse_encode_u_8(self.stabilityScore, serializer);
-  }
-
-  @protected
-  void sse_encode_list_network_target(
-    final List<NetworkTarget> self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_network_target(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_list_prim_u_64_strict(
-    final Uint64List self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint64List(self);
-  }
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    final Uint8List self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    serializer.buffer.putUint8List(self);
-  }
-
-  @protected
-  void sse_encode_list_target_report(
-    final List<TargetReport> self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.length, serializer);
-    for (final item in self) {
-      sse_encode_target_report(item, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_native_probe(
-    final NativeProbe self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_network_configuration(
-    final NetworkConfiguration self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_network_target(self.targets, serializer);
-    sse_encode_u_64(self.checkIntervalMs, serializer);
-    sse_encode_u_64(self.cacheValidityMs, serializer);
-    sse_encode_quality_thresholds(self.qualityThreshold, serializer);
-    sse_encode_security_config(self.security, serializer);
-    sse_encode_resilience_config(self.resilience, serializer);
-  }
-
-  @protected
-  void sse_encode_network_status(
-    final NetworkStatus self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.isConnected, serializer);
-    sse_encode_connection_quality(self.quality, serializer);
-    sse_encode_latency_stats(self.latencyStats, serializer);
-    sse_encode_String(self.winnerTarget, serializer);
-  }
-
-  @protected
-  void sse_encode_network_target(
-    final NetworkTarget self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.label, serializer);
-    sse_encode_String(self.host, serializer);
-    sse_encode_u_16(self.port, serializer);
-    sse_encode_target_protocol(self.protocol, serializer);
-    sse_encode_u_64(self.timeoutMs, serializer);
-    sse_encode_u_8(self.priority, serializer);
-    sse_encode_bool(self.isEssential, serializer);
-  }
-
-  @protected
-  void sse_encode_opt_String(
-    final String? self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_String(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_f_64(
-    final double? self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_f_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_64(
-    final BigInt? self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-
-    sse_encode_bool(self != null, serializer);
-    if (self != null) {
-      sse_encode_box_autoadd_u_64(self, serializer);
-    }
-  }
-
-  @protected
-  void sse_encode_quality_thresholds(
-    final QualityThresholds self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_u_64(self.excellent, serializer);
-    sse_encode_u_64(self.great, serializer);
-    sse_encode_u_64(self.good, serializer);
-    sse_encode_u_64(self.moderate, serializer);
-    sse_encode_u_64(self.poor, serializer);
-  }
-
-  @protected
-  void
-      sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type(
-    final (SecurityFlagsResult, ConnectionType) self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-      self.$1,
-      serializer,
-    );
-    sse_encode_connection_type(self.$2, serializer);
-  }
-
-  @protected
-  void sse_encode_record_list_prim_u_64_strict_list_target_report(
-    final (Uint64List, List<TargetReport>) self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_list_prim_u_64_strict(self.$1, serializer);
-    sse_encode_list_target_report(self.$2, serializer);
-  }
-
-  @protected
-  void
-      sse_encode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64(
-    final (BigInt?, BigInt?, BigInt?, double?) self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_opt_box_autoadd_u_64(self.$1, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.$2, serializer);
-    sse_encode_opt_box_autoadd_u_64(self.$3, serializer);
-    sse_encode_opt_box_autoadd_f_64(self.$4, serializer);
-  }
-
-  @protected
-  void sse_encode_resilience_config(
-    final ResilienceConfig self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_check_strategy(self.strategy, serializer);
-    sse_encode_u_8(self.circuitBreakerThreshold, serializer);
-    sse_encode_u_64(self.circuitBreakerCooldownMs, serializer);
-    sse_encode_u_8(self.numJitterSamples, serializer);
-    sse_encode_f_64(self.jitterThresholdPercent, serializer);
-    sse_encode_u_8(self.stabilityThershold, serializer);
-    sse_encode_f_32(self.criticalPacketLossPrecent, serializer);
-  }
-
-  @protected
-  void sse_encode_security_config(
-    final SecurityConfig self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.blockVpn, serializer);
-    sse_encode_bool(self.detectDnsHijack, serializer);
-  }
-
-  @protected
-  void sse_encode_security_flags(
-    final SecurityFlags self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_bool(self.isVpnDetected, serializer);
-    sse_encode_bool(self.isDnsSpoofed, serializer);
-    sse_encode_bool(self.isProxyDetected, serializer);
-    sse_encode_String(self.interfaceName, serializer);
-  }
-
-  @protected
-  void sse_encode_target_protocol(
-    final TargetProtocol self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_i_32(self.index, serializer);
-  }
-
-  @protected
-  void sse_encode_target_report(
-    final TargetReport self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    sse_encode_String(self.label, serializer);
-    sse_encode_bool(self.success, serializer);
-    sse_encode_u_64(self.latencyMs, serializer);
-    sse_encode_opt_String(self.error, serializer);
-    sse_encode_bool(self.isEssential, serializer);
-  }
-
-  @protected
-  void sse_encode_u_16(final int self, final SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint16(self);
-  }
-
-  @protected
-  void sse_encode_u_64(final BigInt self, final SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_u_8(final int self, final SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putUint8(self);
-  }
-
-  @protected
-  void sse_encode_unit(final void self, final SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-
-  @protected
-  void sse_encode_usize(final BigInt self, final SseSerializer serializer) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-    serializer.buffer.putBigUint64(self);
-  }
-
-  @protected
-  void sse_encode_web_probe(
-    final WebProbe self,
-    final SseSerializer serializer,
-  ) {
-    // Codec=Sse (Serialization based), see doc to use other codecs
-  }
-}
-
-@sealed
-class NetworkReportImpl extends RustOpaque implements NetworkReport {
-  // Not to be used by end users
-  NetworkReportImpl.frbInternalDcoDecode(final List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  NetworkReportImpl.frbInternalSseDecode(
-    final BigInt ptr,
-    final int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount:
-        RustLib.instance.api.rust_arc_increment_strong_count_NetworkReport,
-    rustArcDecrementStrongCount:
-        RustLib.instance.api.rust_arc_decrement_strong_count_NetworkReport,
-    rustArcDecrementStrongCountPtr:
-        RustLib.instance.api.rust_arc_decrement_strong_count_NetworkReportPtr,
-  );
-
-  ConnectionType get connectionType => RustLib.instance.api
-          .crateApiModelsReportNetworkReportAutoAccessorGetConnectionType(
-        that: this,
-      );
-
-  SecurityFlagsResult get securityFlagsResult => RustLib.instance.api
-          .crateApiModelsReportNetworkReportAutoAccessorGetSecurityFlagsResult(
-        that: this,
-      );
-
-  NetworkStatus get status => RustLib.instance.api
-          .crateApiModelsReportNetworkReportAutoAccessorGetStatus(
-        that: this,
-      );
-
-  List<TargetReport> get targetReports => RustLib.instance.api
-          .crateApiModelsReportNetworkReportAutoAccessorGetTargetReports(
-        that: this,
-      );
-
-  BigInt get timestampMs => RustLib.instance.api
-          .crateApiModelsReportNetworkReportAutoAccessorGetTimestampMs(
-        that: this,
-      );
-
-  set connectionType(final ConnectionType connectionType) =>
-      RustLib.instance.api
-          .crateApiModelsReportNetworkReportAutoAccessorSetConnectionType(
-        that: this,
-        connectionType: connectionType,
-      );
-
-  set securityFlagsResult(final SecurityFlagsResult securityFlagsResult) =>
-      RustLib.instance.api
-          .crateApiModelsReportNetworkReportAutoAccessorSetSecurityFlagsResult(
-        that: this,
-        securityFlagsResult: securityFlagsResult,
-      );
-
-  set status(final NetworkStatus status) => RustLib.instance.api
-          .crateApiModelsReportNetworkReportAutoAccessorSetStatus(
-        that: this,
-        status: status,
-      );
-
-  set targetReports(final List<TargetReport> targetReports) =>
-      RustLib.instance.api
-          .crateApiModelsReportNetworkReportAutoAccessorSetTargetReports(
-        that: this,
-        targetReports: targetReports,
-      );
-
-  set timestampMs(final BigInt timestampMs) => RustLib.instance.api
-          .crateApiModelsReportNetworkReportAutoAccessorSetTimestampMs(
-        that: this,
-        timestampMs: timestampMs,
-      );
-}
-
-@sealed
-class SecurityFlagsResultImpl extends RustOpaque
-    implements SecurityFlagsResult {
-  // Not to be used by end users
-  SecurityFlagsResultImpl.frbInternalDcoDecode(final List<dynamic> wire)
-      : super.frbInternalDcoDecode(wire, _kStaticData);
-
-  // Not to be used by end users
-  SecurityFlagsResultImpl.frbInternalSseDecode(
-    final BigInt ptr,
-    final int externalSizeOnNative,
-  ) : super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
-
-  static final _kStaticData = RustArcStaticData(
-    rustArcIncrementStrongCount: RustLib
-        .instance.api.rust_arc_increment_strong_count_SecurityFlagsResult,
-    rustArcDecrementStrongCount: RustLib
-        .instance.api.rust_arc_decrement_strong_count_SecurityFlagsResult,
-    rustArcDecrementStrongCountPtr: RustLib
-        .instance.api.rust_arc_decrement_strong_count_SecurityFlagsResultPtr,
-  );
-
-  String get interfaceName => RustLib.instance.api
-          .crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetInterfaceName(
-        that: this,
-      );
-
-  bool get isDnsSpoofed => RustLib.instance.api
-          .crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsDnsSpoofed(
-        that: this,
-      );
-
-  bool get isProxyDetected => RustLib.instance.api
-          .crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsProxyDetected(
-        that: this,
-      );
-
-  bool get isVpnDetected => RustLib.instance.api
-          .crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsVpnDetected(
-        that: this,
-      );
-
-  set interfaceName(final String interfaceName) => RustLib.instance.api
-          .crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetInterfaceName(
-        that: this,
-        interfaceName: interfaceName,
-      );
-
-  set isDnsSpoofed(final bool isDnsSpoofed) => RustLib.instance.api
-          .crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsDnsSpoofed(
-        that: this,
-        isDnsSpoofed: isDnsSpoofed,
-      );
-
-  set isProxyDetected(final bool isProxyDetected) => RustLib.instance.api
-          .crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsProxyDetected(
-        that: this,
-        isProxyDetected: isProxyDetected,
-      );
-
-  set isVpnDetected(final bool isVpnDetected) => RustLib.instance.api
-          .crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsVpnDetected(
-        that: this,
-        isVpnDetected: isVpnDetected,
-      );
-}
+import 'frb_generated.io.dart' if (dart.library.js_interop) 'frb_generated.web.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated.dart';
+
+
+                /// Main entrypoint of the Rust API
+                class RustLib extends BaseEntrypoint<RustLibApi, RustLibApiImpl, RustLibWire> {
+                  @internal
+                  static final instance = RustLib._();
+
+                  RustLib._();
+
+                  /// Initialize flutter_rust_bridge
+                  static Future<void> init({
+                    RustLibApi? api,
+                    BaseHandler? handler,
+                    ExternalLibrary? externalLibrary,
+                    bool forceSameCodegenVersion = true,
+                  }) async {
+                    await instance.initImpl(
+                      api: api,
+                      handler: handler,
+                      externalLibrary: externalLibrary,
+                      forceSameCodegenVersion: forceSameCodegenVersion,
+                    );
+                  }
+
+                  /// Initialize flutter_rust_bridge in mock mode.
+                  /// No libraries for FFI are loaded.
+                  static void initMock({
+                    required RustLibApi api,
+                  }) {
+                    instance.initMockImpl(
+                      api: api,
+                    );
+                  }
+
+                  /// Dispose flutter_rust_bridge
+                  ///
+                  /// The call to this function is optional, since flutter_rust_bridge (and everything else)
+                  /// is automatically disposed when the app stops.
+                  static void dispose() => instance.disposeImpl();
+
+                  @override
+                  ApiImplConstructor<RustLibApiImpl, RustLibWire> get apiImplConstructor => RustLibApiImpl.new;
+
+                  @override
+                  WireConstructor<RustLibWire> get wireConstructor => RustLibWire.fromExternalLibrary;
+
+                  @override
+                  Future<void> executeRustInitializers() async {
+                    
+                  }
+
+                  @override
+                  ExternalLibraryLoaderConfig get defaultExternalLibraryLoaderConfig => kDefaultExternalLibraryLoaderConfig;
+
+                  @override
+                  String get codegenVersion => '2.12.0';
+
+                  @override
+                  int get rustContentHash => -3878198;
+
+                  static const kDefaultExternalLibraryLoaderConfig = ExternalLibraryLoaderConfig(
+                    stem: 'network_reachability',
+                    ioDirectory: 'rust/target/release/',
+                    webPrefix: 'pkg/',
+                    wasmBindgenName: 'wasm_bindgen',
+                  );
+                }
+                
+
+                abstract class RustLibApi extends BaseApi {
+                  double crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetSmoothing({required AnomalyDetector that });
+
+int crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetWarmupReports({required AnomalyDetector that });
+
+double crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetZThreshold({required AnomalyDetector that });
+
+void crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetSmoothing({required AnomalyDetector that , required double smoothing });
+
+void crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetWarmupReports({required AnomalyDetector that , required int warmupReports });
+
+void crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetZThreshold({required AnomalyDetector that , required double zThreshold });
+
+Future<AnomalyDetector> crateApiAnalysisAnomalyAnomalyDetectorDefault();
+
+Future<AnomalyDetector> crateApiAnalysisAnomalyAnomalyDetectorNew({required double smoothing , required double zThreshold , required int warmupReports });
+
+Future<List<AnomalyDetected>> crateApiAnalysisAnomalyAnomalyDetectorObserve({required AnomalyDetector that , required NetworkReport report });
+
+Future<void> crateApiAnalysisAnomalyAnomalyDetectorReset({required AnomalyDetector that });
+
+Future<CheckPipeline> crateApiEnginePipelineCheckPipelineDefault();
+
+Future<CheckPipeline> crateApiEnginePipelineCheckPipelineNew();
+
+Future<PipelineReport> crateApiEnginePipelineCheckPipelineRun({required CheckPipeline that , required NetworkConfiguration config });
+
+Future<CheckPipeline> crateApiEnginePipelineCheckPipelineStandard();
+
+Future<CheckPipeline> crateApiEnginePipelineCheckPipelineStep({required CheckPipeline that , required PipelineStep step });
+
+Future<CheckPipeline> crateApiEnginePipelineCheckPipelineStepIf({required CheckPipeline that , required PipelineStep step , required StepCondition condition });
+
+Future<int> crateApiEngineCircuitBreakerCircuitBreakerConsecutiveFailures({required CircuitBreaker that });
+
+Future<CircuitBreaker> crateApiEngineCircuitBreakerCircuitBreakerNew({required ResilienceConfig config });
+
+Future<void> crateApiEngineCircuitBreakerCircuitBreakerRecord({required CircuitBreaker that , required NetworkReport report });
+
+Future<CircuitBreakerState> crateApiEngineCircuitBreakerCircuitBreakerState({required CircuitBreaker that });
+
+Future<DeviceInventory> crateApiEngineInventoryDeviceInventoryDefault();
+
+Future<DeviceInventory> crateApiEngineInventoryDeviceInventoryFromDevices({required List<LocalDevice> devices });
+
+Future<DeviceInventory> crateApiEngineInventoryDeviceInventoryNew();
+
+Future<List<DeviceEvent>> crateApiEngineInventoryDeviceInventoryUpdate({required DeviceInventory that , required List<LocalDevice> scan });
+
+Future<NetworkMonitor> crateApiMonitorNetworkMonitorNew({required NetworkConfiguration config });
+
+Future<void> crateApiMonitorNetworkMonitorPause({required NetworkMonitor that });
+
+Future<void> crateApiMonitorNetworkMonitorResume({required NetworkMonitor that });
+
+Future<bool> crateApiMonitorNetworkMonitorShutdown({required NetworkMonitor that , required BigInt timeoutMs });
+
+Future<void> crateApiMonitorNetworkMonitorStart({required NetworkMonitor that });
+
+Future<MonitorState> crateApiMonitorNetworkMonitorState({required NetworkMonitor that });
+
+Future<MonitorStats> crateApiMonitorNetworkMonitorStats({required NetworkMonitor that });
+
+Future<void> crateApiMonitorNetworkMonitorStop({required NetworkMonitor that });
+
+Stream<NetworkReport> crateApiMonitorNetworkMonitorStreamReports({required NetworkMonitor that });
+
+Future<NetworkMonitor> crateApiMonitorNetworkMonitorWithAnomalyDetector({required NetworkMonitor that , required AnomalyDetector detector });
+
+List<AnomalyDetected> crateApiModelsReportNetworkReportAutoAccessorGetAnomalies({required NetworkReport that });
+
+DegradationAttribution? crateApiModelsReportNetworkReportAutoAccessorGetAttribution({required NetworkReport that });
+
+BetterPathAvailable? crateApiModelsReportNetworkReportAutoAccessorGetBetterPath({required NetworkReport that });
+
+String? crateApiModelsReportNetworkReportAutoAccessorGetBssid({required NetworkReport that });
+
+bool crateApiModelsReportNetworkReportAutoAccessorGetCancelled({required NetworkReport that });
+
+CaptivePortalStatus? crateApiModelsReportNetworkReportAutoAccessorGetCaptivePortal({required NetworkReport that });
+
+ConnectionType crateApiModelsReportNetworkReportAutoAccessorGetConnectionType({required NetworkReport that });
+
+BigInt crateApiModelsReportNetworkReportAutoAccessorGetDurationMs({required NetworkReport that });
+
+RuntimeEnvironment crateApiModelsReportNetworkReportAutoAccessorGetEnvironment({required NetworkReport that });
+
+FamilyComparison? crateApiModelsReportNetworkReportAutoAccessorGetFamilyComparison({required NetworkReport that });
+
+bool crateApiModelsReportNetworkReportAutoAccessorGetIsConstrained({required NetworkReport that });
+
+bool crateApiModelsReportNetworkReportAutoAccessorGetIsExpensive({required NetworkReport that });
+
+LayeredDiagnosis crateApiModelsReportNetworkReportAutoAccessorGetLayers({required NetworkReport that });
+
+bool? crateApiModelsReportNetworkReportAutoAccessorGetLikelyIspOutage({required NetworkReport that });
+
+BigInt crateApiModelsReportNetworkReportAutoAccessorGetMonotonicMs({required NetworkReport that });
+
+bool? crateApiModelsReportNetworkReportAutoAccessorGetMulticastOk({required NetworkReport that });
+
+List<SampleRecord> crateApiModelsReportNetworkReportAutoAccessorGetRawSamples({required NetworkReport that });
+
+RoamEvent? crateApiModelsReportNetworkReportAutoAccessorGetRoam({required NetworkReport that });
+
+NetworkScope crateApiModelsReportNetworkReportAutoAccessorGetScope({required NetworkReport that });
+
+SecurityFlagsResult crateApiModelsReportNetworkReportAutoAccessorGetSecurityFlagsResult({required NetworkReport that });
+
+List<SnmpDeviceInfo> crateApiModelsReportNetworkReportAutoAccessorGetSnmpDevices({required NetworkReport that });
+
+String? crateApiModelsReportNetworkReportAutoAccessorGetSsid({required NetworkReport that });
+
+NetworkStatus crateApiModelsReportNetworkReportAutoAccessorGetStatus({required NetworkReport that });
+
+CheckStrategy crateApiModelsReportNetworkReportAutoAccessorGetStrategy({required NetworkReport that });
+
+List<TargetReport> crateApiModelsReportNetworkReportAutoAccessorGetTargetReports({required NetworkReport that });
+
+BigInt crateApiModelsReportNetworkReportAutoAccessorGetTimestampMs({required NetworkReport that });
+
+RouteTrace? crateApiModelsReportNetworkReportAutoAccessorGetTraceroute({required NetworkReport that });
+
+bool crateApiModelsReportNetworkReportAutoAccessorGetTruncated({required NetworkReport that });
+
+WalledGardenReport? crateApiModelsReportNetworkReportAutoAccessorGetWalledGarden({required NetworkReport that });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetAnomalies({required NetworkReport that , required List<AnomalyDetected> anomalies });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetAttribution({required NetworkReport that , DegradationAttribution? attribution });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetBetterPath({required NetworkReport that , BetterPathAvailable? betterPath });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetBssid({required NetworkReport that , String? bssid });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetCancelled({required NetworkReport that , required bool cancelled });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetCaptivePortal({required NetworkReport that , CaptivePortalStatus? captivePortal });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetConnectionType({required NetworkReport that , required ConnectionType connectionType });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetDurationMs({required NetworkReport that , required BigInt durationMs });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetEnvironment({required NetworkReport that , required RuntimeEnvironment environment });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetFamilyComparison({required NetworkReport that , FamilyComparison? familyComparison });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetIsConstrained({required NetworkReport that , required bool isConstrained });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetIsExpensive({required NetworkReport that , required bool isExpensive });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetLayers({required NetworkReport that , required LayeredDiagnosis layers });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetLikelyIspOutage({required NetworkReport that , bool? likelyIspOutage });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetMonotonicMs({required NetworkReport that , required BigInt monotonicMs });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetMulticastOk({required NetworkReport that , bool? multicastOk });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetRawSamples({required NetworkReport that , required List<SampleRecord> rawSamples });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetRoam({required NetworkReport that , RoamEvent? roam });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetScope({required NetworkReport that , required NetworkScope scope });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetSecurityFlagsResult({required NetworkReport that , required SecurityFlagsResult securityFlagsResult });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetSnmpDevices({required NetworkReport that , required List<SnmpDeviceInfo> snmpDevices });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetSsid({required NetworkReport that , String? ssid });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetStatus({required NetworkReport that , required NetworkStatus status });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetStrategy({required NetworkReport that , required CheckStrategy strategy });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetTargetReports({required NetworkReport that , required List<TargetReport> targetReports });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetTimestampMs({required NetworkReport that , required BigInt timestampMs });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetTraceroute({required NetworkReport that , RouteTrace? traceroute });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetTruncated({required NetworkReport that , required bool truncated });
+
+void crateApiModelsReportNetworkReportAutoAccessorSetWalledGarden({required NetworkReport that , WalledGardenReport? walledGarden });
+
+Future<SimpleStatus> crateApiModelsReportNetworkReportSimpleStatus({required NetworkReport that });
+
+NetworkReport crateApiEnginePipelinePipelineReportAutoAccessorGetReport({required PipelineReport that });
+
+List<StepOutcome> crateApiEnginePipelinePipelineReportAutoAccessorGetSteps({required PipelineReport that });
+
+void crateApiEnginePipelinePipelineReportAutoAccessorSetReport({required PipelineReport that , required NetworkReport report });
+
+void crateApiEnginePipelinePipelineReportAutoAccessorSetSteps({required PipelineReport that , required List<StepOutcome> steps });
+
+Future<NetworkReport> crateApiEngineReachabilityReachabilityEngineCheck({required ReachabilityEngine that });
+
+Future<CircuitBreakerState> crateApiEngineReachabilityReachabilityEngineCircuitState({required ReachabilityEngine that });
+
+Future<List<NetworkReport>> crateApiEngineReachabilityReachabilityEngineHistory({required ReachabilityEngine that });
+
+Future<List<HistoryAggregate>> crateApiEngineReachabilityReachabilityEngineHistoryAggregates({required ReachabilityEngine that });
+
+Future<bool> crateApiEngineReachabilityReachabilityEngineIsShutDown({required ReachabilityEngine that });
+
+Future<String?> crateApiEngineReachabilityReachabilityEngineLastAppError({required ReachabilityEngine that });
+
+Future<NetworkReport?> crateApiEngineReachabilityReachabilityEngineLastReport({required ReachabilityEngine that });
+
+Future<ReachabilityEngine> crateApiEngineReachabilityReachabilityEngineNew({required NetworkConfiguration config });
+
+Future<NetworkReport> crateApiEngineReachabilityReachabilityEngineRefresh({required ReachabilityEngine that });
+
+Future<void> crateApiEngineReachabilityReachabilityEngineReportAppFailure({required ReachabilityEngine that , required String error });
+
+Future<void> crateApiEngineReachabilityReachabilityEngineReportAppSuccess({required ReachabilityEngine that });
+
+Future<void> crateApiEngineReachabilityReachabilityEngineReset({required ReachabilityEngine that });
+
+Future<bool> crateApiEngineReachabilityReachabilityEngineShutdown({required ReachabilityEngine that , required BigInt timeoutMs });
+
+Future<EngineSnapshot> crateApiEngineReachabilityReachabilityEngineSnapshot({required ReachabilityEngine that });
+
+Future<List<AnomalyDetected>> crateApiEngineReachabilityReachabilityEngineTakeAnomalies({required ReachabilityEngine that });
+
+Future<List<TargetHealth>> crateApiEngineReachabilityReachabilityEngineTargetHealth({required ReachabilityEngine that });
+
+Future<TimeOfDayProfile> crateApiEngineReachabilityReachabilityEngineTimeOfDayProfile({required ReachabilityEngine that , required int utcOffsetMinutes });
+
+Future<ReachabilityEngine> crateApiEngineReachabilityReachabilityEngineWithHistoryCapacity({required NetworkConfiguration config , required BigInt historyCapacity });
+
+Future<ReachabilityEngine> crateApiEngineReachabilityReachabilityEngineWithSnapshot({required ReachabilityEngine that , required EngineSnapshot snapshot });
+
+Evidence crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetDnsSpoofEvidence({required SecurityFlagsResult that });
+
+String crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetInterfaceName({required SecurityFlagsResult that });
+
+bool crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsDnsSpoofed({required SecurityFlagsResult that });
+
+bool crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsProxyDetected({required SecurityFlagsResult that });
+
+bool crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsVpnDetected({required SecurityFlagsResult that });
+
+Evidence crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetProxyEvidence({required SecurityFlagsResult that });
+
+Evidence crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetVpnEvidence({required SecurityFlagsResult that });
+
+void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetDnsSpoofEvidence({required SecurityFlagsResult that , required Evidence dnsSpoofEvidence });
+
+void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetInterfaceName({required SecurityFlagsResult that , required String interfaceName });
+
+void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsDnsSpoofed({required SecurityFlagsResult that , required bool isDnsSpoofed });
+
+void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsProxyDetected({required SecurityFlagsResult that , required bool isProxyDetected });
+
+void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsVpnDetected({required SecurityFlagsResult that , required bool isVpnDetected });
+
+void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetProxyEvidence({required SecurityFlagsResult that , required Evidence proxyEvidence });
+
+void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetVpnEvidence({required SecurityFlagsResult that , required Evidence vpnEvidence });
+
+Future<SecurityFlagsResult> crateApiModelsNetInfoSecurityFlagsResultDefault();
+
+String crateApiModelsReportAddressFamilyL10NKey({required AddressFamily that });
+
+Future<BigInt?> crateApiEngineSamplerAnalyzeSingleSample({required List<TargetReport> reports , required NetworkConfiguration config });
+
+String crateApiModelsDiagnosticsAnomalyMetricL10NKey({required AnomalyMetric that });
+
+Future<(bool,Evidence)> crateApiProbesDnsAssessDnsHijacking({required String domain });
+
+Future<(bool,Evidence)> crateApiProbesDnsAssessDnsHijackingWith({required String domain , required List<String> trustedServers });
+
+Future<DegradationAttribution> crateApiAnalysisAttributionAttributeDegradation({required LatencyStats gateway , required LatencyStats endToEnd });
+
+Future<BetterPathAvailable?> crateApiAnalysisPathsBetterPath({required String activeInterface , required List<PathMeasurement> paths , required BigInt timestampMs });
+
+Future<List<MdnsService>> crateApiProbesMdnsBrowseMdnsServices({required BigInt durationMs , required List<String> excludeRanges });
+
+Future<Uint8List> crateApiProbesUdpBuildRequest({required UdpValidation validation });
+
+Future<(BigInt?,BigInt?,BigInt?,double?)> crateApiAnalysisStatsCalculateJitterStats({required Uint64List latencies });
+
+Future<CaptivePortalStatus> crateApiProbesCaptivePortalCheckForCaptivePortal({required BigInt timeoutMs });
+
+Future<CaptivePortalStatus> crateApiProbesCaptivePortalCheckForCaptivePortalAt({required String url , required BigInt timeoutMs });
+
+Future<CaptivePortalStatus> crateApiProbesCaptivePortalCheckForCaptivePortalWeb({required BigInt timeoutMs });
+
+Future<CaptivePortalStatus> crateApiProbesCaptivePortalCheckForCaptivePortalWebManual({required BigInt timeoutMs });
+
+Future<HairpinReport> crateApiProbesHairpinCheckHairpin({required int port , String? publicAddress , required List<String> reflectionServers , required BigInt timeoutMs });
+
+Future<bool?> crateApiProbesIspStatusCheckIspStatus({required List<String> urls , required BigInt timeoutMs });
+
+Future<LocalReachabilityReport> crateApiEngineLocalCheckLocalNetwork({required NetworkConfiguration config });
+
+Future<MulticastReport> crateApiProbesMulticastCheckMulticast({required BigInt timeoutMs });
+
+Future<NetworkReport> crateApiEngineCheckNetwork({required NetworkConfiguration config });
+
+Future<TargetReport> crateApiProbesTargetCheckTarget({required NetworkTarget target });
+
+String crateApiEngineCircuitBreakerCircuitBreakerStateL10NKey({required CircuitBreakerState that });
+
+Future<void> crateApiProbesApplePathClearApplePath();
+
+void crateApiEngineHintsClearPlatformHints();
+
+Future<(Uint64List,List<TargetReport>)> crateApiEngineSamplerCollectNetworkSamples({required NetworkConfiguration config });
+
+Future<List<DscpLatency>> crateApiProbesQosCompareDscpMarkings({required NetworkTarget target , required List<int> markings , required int samples });
+
+Future<VpnComparison> crateApiAnalysisVpnCompareVpnReports({required NetworkReport before , required NetworkReport after , required List<String> resolversBefore , required List<String> resolversAfter });
+
+Future<VpnComparison> crateApiEngineVpnCompareVpnToggle({required NetworkConfiguration config , required BigInt waitTimeoutMs });
+
+Future<LatencyStats> crateApiAnalysisStatsComputeLatencyStats({required Uint64List latencies , required int totalExpectedSamples , required QualityThresholds thresholds });
+
+String crateApiModelsNetInfoConfidenceL10NKey({required Confidence that });
+
+String crateApiModelsConfigConnectionQualityL10NKey({required ConnectionQuality that });
+
+Future<ConnectionType> crateApiModelsNetInfoConnectionTypeDefault();
+
+String crateApiModelsNetInfoConnectionTypeL10NKey({required ConnectionType that });
+
+Future<ApplePathSnapshot?> crateApiProbesApplePathCurrentApplePath();
+
+String crateApiModelsDiagnosticsDegradationScopeL10NKey({required DegradationScope that });
+
+Future<bool> crateApiProbesDnsDetectDnsHijacking({required String domain });
+
+Future<bool> crateApiProbesDnsDetectDnsHijackingWeb({required String domain });
+
+Future<EcnReport> crateApiProbesEcnDetectEcn({required List<String> tcpServers , required List<String> reflectionServers , required BigInt timeoutMs });
+
+Future<Uint32List> crateApiAnalysisStatsDetectLatencySpikes({required Uint64List latencies , required double ratioThreshold });
+
+Future<MssReport> crateApiProbesMssDetectMssClamping({required List<String> servers , required BigInt timeoutMs });
+
+Future<ProbeCapabilities> crateApiProbesCapabilitiesDetectProbeCapabilities();
+
+Future<RoamEvent?> crateApiAnalysisRoamingDetectRoam({required NetworkReport previous , required NetworkReport current , required ResilienceConfig resilience });
+
+Future<RuntimeEnvironment> crateApiProbesEnvironmentDetectRuntimeEnvironment();
+
+Future<(SecurityFlagsResult,ConnectionType)> crateApiProbesInterfaceDetectSecurityAndNetworkType();
+
+Future<(SecurityFlagsResult,ConnectionType)> crateApiProbesInterfaceDetectSecurityAndNetworkTypeOf({required String interfaceName });
+
+Future<(SecurityFlagsResult,ConnectionType)> crateApiProbesInterfaceDetectSecurityAndNetworkTypeWeb();
+
+Future<LayeredDiagnosis> crateApiAnalysisLayersDiagnoseLayers({required List<NetworkTarget> targets , required List<TargetReport> reports , required SecurityFlagsResult flags , required ConnectionQuality quality });
+
+Future<List<DeviceEvent>> crateApiEngineInventoryDiffDevices({required List<LocalDevice> previous , required List<LocalDevice> current });
+
+Future<LinkNeighbor?> crateApiProbesLldpDiscoverLinkNeighbor({required String interface_ , required BigInt listenMs });
+
+String crateApiModelsNetInfoEcnMarkingL10NKey({required EcnMarking that });
+
+String crateApiModelsNetInfoEncapsulationL10NKey({required Encapsulation that });
+
+Future<EndpointConfig> crateApiModelsConfigEndpointConfigDefault();
+
+Future<EnvironmentReport> crateApiEngineEnvironmentEnvironmentReport();
+
+Future<OneWayDelayEstimate> crateApiProbesOneWayDelayEstimateOneWayDelay({required String server , required int samples , required BigInt timeoutMs });
+
+Future<BigInt> crateApiEngineRateLimitEstimatedProbeBytes({required TargetProtocol protocol });
+
+Future<ConnectionQuality> crateApiAnalysisQualityEvaluateNetworkQuality({required bool isConnected , required LatencyStats stats , required NetworkConfiguration config });
+
+Future<ConnectionQuality> crateApiAnalysisQualityEvaluateQuality({required BigInt latency , required QualityThresholds threshold });
+
+Future<Evidence> crateApiModelsNetInfoEvidenceDefault();
+
+Future<Evidence> crateApiModelsNetInfoEvidenceNew({required Confidence confidence , required EvidenceSource source });
+
+String crateApiModelsNetInfoEvidenceSourceL10NKey({required EvidenceSource that });
+
+Future<GatewayFingerprint> crateApiModelsScanGatewayFingerprintDefault();
+
+Future<NetworkReport> crateApiEngineSecurityGuard({required NetworkConfiguration config });
+
+Future<BigInt> crateApiEngineScheduleJitteredIntervalMs({required BigInt intervalMs , required int jitterPercent });
+
+Future<LatencyHeatmap> crateApiAnalysisHeatmapLatencyHeatmap({required List<NetworkReport> reports , required BigInt bucketMs });
+
+String crateApiModelsReportLayerStatusL10NKey({required LayerStatus that });
+
+String crateApiModelsDiagnosticsLimitationL10NKey({required Limitation that });
+
+Future<LocalScanConfig> crateApiModelsScanLocalScanConfigDefault();
+
+Future<List<PathMeasurement>> crateApiProbesPathsMeasurePaths({required BigInt timeoutMs });
+
+String crateApiMonitorMonitorStateL10NKey({required MonitorState that });
+
+Future<MonitorStats> crateApiMonitorMonitorStatsDefault();
+
+Future<BigInt> crateApiEngineClockMonotonicMs();
+
+String crateApiModelsNetInfoNatTypeL10NKey({required NatType that });
+
+Future<TargetReport> crateApiProbesTargetNativeProbeCheck({required NativeProbe that , required NetworkTarget target });
+
+String crateApiModelsNetInfoNeighborProtocolL10NKey({required NeighborProtocol that });
+
+Future<NetworkConfiguration> crateApiModelsConfigNetworkConfigurationDefault();
+
+Future<NetworkConfiguration> crateApiModelsConfigNetworkConfigurationNew({required List<NetworkTarget> targets , required BigInt checkIntervalMs , required BigInt cacheValidityMs , required QualityThresholds qualityThreshold , required SecurityConfig security , required ResilienceConfig resilience });
+
+Future<void> crateApiModelsConfigNetworkConfigurationValidate({required NetworkConfiguration that });
+
+String crateApiModelsErrorNetworkErrorL10NKey({required NetworkError that });
+
+String crateApiModelsReportNetworkScopeL10NKey({required NetworkScope that });
+
+Future<NetworkTarget> crateApiModelsTargetNetworkTargetDefault();
+
+Future<BigInt> crateApiEngineScheduleNextCheckDelayMs({required NetworkConfiguration config });
+
+Future<String?> crateApiEngineSamplerOverallWinner({required List<String?> winnersPerSample });
+
+Future<OverlapPolicy> crateApiModelsConfigOverlapPolicyDefault();
+
+String crateApiModelsConfigOverlapPolicyL10NKey({required OverlapPolicy that });
+
+String crateApiModelsNetInfoPathSegmentL10NKey({required PathSegment that });
+
+Future<void> crateApiEngineSecurityPerformDnsSecurityCheck({required NetworkConfiguration config , required SecurityFlagsResult flags });
+
+PlatformHints? crateApiEngineHintsPlatformHints();
+
+Future<PlatformHints> crateApiModelsNetInfoPlatformHintsDefault();
+
+String crateApiModelsErrorPolicyViolationL10NKey({required PolicyViolation that });
+
+String crateApiModelsNetInfoPortalLoginKindL10NKey({required PortalLoginKind that });
+
+Future<PreflightReport> crateApiEnginePreflightPreflight({required Requirements requirements });
+
+Future<ProbeCapabilities> crateApiModelsNetInfoProbeCapabilitiesDefault();
+
+String crateApiModelsNetInfoProbeFallbackL10NKey({required ProbeFallback that });
+
+Future<NatReport> crateApiProbesReflectionProbeNat({required List<String> servers , required BigInt timeoutMs });
+
+Future<ProbeTimings> crateApiModelsReportProbeTimingsNew({required BigInt budgetMs , required BigInt elapsedMs , BigInt? dnsMs , BigInt? connectMs });
+
+Future<WalledGardenReport> crateApiProbesWalledGardenProbeWalledGarden({required BigInt timeoutMs });
+
+Future<WalledGardenReport> crateApiProbesWalledGardenProbeWalledGardenWith({required EndpointConfig endpoints , required BigInt timeoutMs });
+
+Future<TimeOfDayProfile> crateApiAnalysisTimeOfDayProfileTimeOfDay({required List<NetworkReport> reports , required int utcOffsetMinutes });
+
+Future<TimeOfDayProfile> crateApiAnalysisTimeOfDayProfileTimeOfDayWithAggregates({required List<NetworkReport> reports , required List<HistoryAggregate> aggregates , required int utcOffsetMinutes });
+
+String crateApiModelsReportQualityReasonL10NKey({required QualityReason that });
+
+Future<List<QualityReason>> crateApiAnalysisQualityQualityReasons({required bool isConnected , required LatencyStats stats , required NetworkConfiguration config });
+
+Future<QualityThresholds> crateApiModelsConfigQualityThresholdsDefault();
+
+Future<QualityThresholds> crateApiModelsConfigQualityThresholdsNew({required BigInt excellent , required BigInt great , required BigInt good , required BigInt moderate , required BigInt poor });
+
+Future<RateLimitConfig> crateApiModelsConfigRateLimitConfigDefault();
+
+Future<List<ArpEntry>> crateApiProbesArpReadArpTable();
+
+Future<Requirements> crateApiModelsConfigRequirementsDefault();
+
+Future<ResilienceConfig> crateApiModelsConfigResilienceConfigDefault();
+
+Future<ResilienceConfig> crateApiModelsConfigResilienceConfigNew({required CheckStrategy strategy , required int circuitBreakerThreshold , required BigInt circuitBreakerCooldownMs , required int numJitterSamples , required double jitterThresholdPercent , required int stabilityThershold , required double criticalPacketLossPrecent });
+
+Future<RuntimeEnvironment> crateApiModelsNetInfoRuntimeEnvironmentDefault();
+
+String crateApiModelsNetInfoRuntimeEnvironmentL10NKey({required RuntimeEnvironment that });
+
+Future<SampleRun> crateApiEngineSamplerSampleRunDefault();
+
+Future<List<LocalDevice>> crateApiProbesLocalScanScanLocalNetwork({required LocalScanConfig config });
+
+Future<ScanMethod> crateApiModelsScanScanMethodDefault();
+
+String crateApiModelsScanScanMethodL10NKey({required ScanMethod that });
+
+Future<double> crateApiAnalysisStabilityScoreLatency({required double ms , required QualityThresholds thresholds });
+
+Future<SecurityConfig> crateApiModelsConfigSecurityConfigDefault();
+
+Future<SecurityFlags> crateApiModelsNetInfoSecurityFlagsDefault();
+
+String crateApiModelsNetInfoServiceReachabilityL10NKey({required ServiceReachability that });
+
+void crateApiEngineHintsSetPlatformHints({required PlatformHints hints });
+
+Future<SimpleStatus> crateApiEngineSimpleStatus();
+
+String crateApiModelsReportSimpleStatusL10NKey({required SimpleStatus that });
+
+Future<SnmpConfig> crateApiModelsConfigSnmpConfigDefault();
+
+Future<bool> crateApiEnginePipelineStepConditionIsMet({required StepCondition that , required NetworkReport report });
+
+Stream<ScanEvent> crateApiProbesLocalScanStreamLocalNetworkScan({required LocalScanConfig config });
+
+Future<List<String>> crateApiProbesDnsSystemDnsResolvers();
+
+String crateApiModelsTargetTargetProtocolL10NKey({required TargetProtocol that });
+
+Future<TcpInfo> crateApiProbesTcpInfoTcpInfoDefault();
+
+Future<RouteTrace> crateApiProbesTracerouteTraceRoute({required String host , required int maxHops , required BigInt timeoutPerHopMs });
+
+Future<MultiRouteTrace> crateApiProbesTracerouteTraceRoutes({required List<String> hosts , required int maxHops , required BigInt timeoutPerHopMs });
+
+Future<CaptivePortalStatus> crateApiProbesCaptivePortalTryCheckForCaptivePortal({required BigInt timeoutMs });
+
+Future<CaptivePortalStatus> crateApiProbesCaptivePortalTryCheckForCaptivePortalAt({required String url , required BigInt timeoutMs });
+
+Future<NetworkReport> crateApiEngineTryCheckNetwork({required NetworkConfiguration config });
+
+Future<List<LocalDevice>> crateApiProbesLocalScanTryScanLocalNetwork({required LocalScanConfig config });
+
+Future<UdpValidation> crateApiModelsTargetUdpValidationDefault();
+
+String crateApiModelsDiagnosticsUnmetRequirementL10NKey({required UnmetRequirement that });
+
+Future<void> crateApiProbesApplePathUpdateApplePath({required ApplePathSnapshot snapshot });
+
+Future<UpnpDescription> crateApiProbesUpnpUpnpDescriptionDefault();
+
+Future<bool> crateApiProbesUdpValidateReply({required UdpValidation validation , required List<int> request , required List<int> reply });
+
+String crateApiModelsErrorValidationIssueL10NKey({required ValidationIssue that });
+
+Future<PortForwardCheck> crateApiProbesPortCheckVerifyPortForwarding({required String endpoint , required int port , required BigInt timeoutMs });
+
+Future<CaptivePortalStatus> crateApiProbesCaptivePortalWaitForPortalClearance({required BigInt timeoutMs , required BigInt pollIntervalMs });
+
+Future<BigInt> crateApiEngineClockWallClockMs();
+
+Future<TargetReport> crateApiProbesTargetWebProbeCheck({required WebProbe that , required NetworkTarget target });
+
+Future<WeightedStabilityModel> crateApiAnalysisStabilityWeightedStabilityModelDefault();
+
+Future<int> crateApiAnalysisStabilityWeightedStabilityModelScore({required WeightedStabilityModel that , required StabilityInputs inputs });
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_AnomalyDetector;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_AnomalyDetector;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AnomalyDetectorPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CheckPipeline;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CheckPipeline;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CheckPipelinePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CircuitBreaker;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CircuitBreaker;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CircuitBreakerPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_DeviceInventory;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_DeviceInventory;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DeviceInventoryPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_NetworkMonitor;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_NetworkMonitor;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_NetworkMonitorPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_NetworkReport;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_NetworkReport;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_NetworkReportPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PipelineReport;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PipelineReport;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PipelineReportPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ReachabilityEngine;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ReachabilityEngine;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ReachabilityEnginePtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SecurityFlagsResult;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SecurityFlagsResult;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SecurityFlagsResultPtr;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_StabilityInputs;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_StabilityInputs;
+
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_StabilityInputsPtr;
+
+
+                }
+                
+
+                class RustLibApiImpl extends RustLibApiImplPlatform implements RustLibApi {
+                  RustLibApiImpl({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
+
+                  @override double crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetSmoothing({required AnomalyDetector that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 1)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetSmoothingConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetSmoothingConstMeta => const TaskConstMeta(
+            debugName: "AnomalyDetector_auto_accessor_get_smoothing",
+            argNames: ["that"],
+        );
+        
+
+@override int crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetWarmupReports({required AnomalyDetector that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 2)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_32,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetWarmupReportsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetWarmupReportsConstMeta => const TaskConstMeta(
+            debugName: "AnomalyDetector_auto_accessor_get_warmup_reports",
+            argNames: ["that"],
+        );
+        
+
+@override double crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetZThreshold({required AnomalyDetector that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 3)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetZThresholdConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetZThresholdConstMeta => const TaskConstMeta(
+            debugName: "AnomalyDetector_auto_accessor_get_z_threshold",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetSmoothing({required AnomalyDetector that , required double smoothing })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(that, serializer);
+sse_encode_f_64(smoothing, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 4)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetSmoothingConstMeta,
+            argValues: [that, smoothing],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetSmoothingConstMeta => const TaskConstMeta(
+            debugName: "AnomalyDetector_auto_accessor_set_smoothing",
+            argNames: ["that", "smoothing"],
+        );
+        
+
+@override void crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetWarmupReports({required AnomalyDetector that , required int warmupReports })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(that, serializer);
+sse_encode_u_32(warmupReports, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 5)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetWarmupReportsConstMeta,
+            argValues: [that, warmupReports],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetWarmupReportsConstMeta => const TaskConstMeta(
+            debugName: "AnomalyDetector_auto_accessor_set_warmup_reports",
+            argNames: ["that", "warmupReports"],
+        );
+        
+
+@override void crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetZThreshold({required AnomalyDetector that , required double zThreshold })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(that, serializer);
+sse_encode_f_64(zThreshold, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 6)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetZThresholdConstMeta,
+            argValues: [that, zThreshold],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetZThresholdConstMeta => const TaskConstMeta(
+            debugName: "AnomalyDetector_auto_accessor_set_z_threshold",
+            argNames: ["that", "zThreshold"],
+        );
+        
+
+@override Future<AnomalyDetector> crateApiAnalysisAnomalyAnomalyDetectorDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 7, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisAnomalyAnomalyDetectorDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisAnomalyAnomalyDetectorDefaultConstMeta => const TaskConstMeta(
+            debugName: "AnomalyDetector_default",
+            argNames: [],
+        );
+        
+
+@override Future<AnomalyDetector> crateApiAnalysisAnomalyAnomalyDetectorNew({required double smoothing , required double zThreshold , required int warmupReports })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_64(smoothing, serializer);
+sse_encode_f_64(zThreshold, serializer);
+sse_encode_u_32(warmupReports, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 8, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisAnomalyAnomalyDetectorNewConstMeta,
+            argValues: [smoothing, zThreshold, warmupReports],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisAnomalyAnomalyDetectorNewConstMeta => const TaskConstMeta(
+            debugName: "AnomalyDetector_new",
+            argNames: ["smoothing", "zThreshold", "warmupReports"],
+        );
+        
+
+@override Future<List<AnomalyDetected>> crateApiAnalysisAnomalyAnomalyDetectorObserve({required AnomalyDetector that , required NetworkReport report })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(report, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 9, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_anomaly_detected,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisAnomalyAnomalyDetectorObserveConstMeta,
+            argValues: [that, report],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisAnomalyAnomalyDetectorObserveConstMeta => const TaskConstMeta(
+            debugName: "AnomalyDetector_observe",
+            argNames: ["that", "report"],
+        );
+        
+
+@override Future<void> crateApiAnalysisAnomalyAnomalyDetectorReset({required AnomalyDetector that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 10, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisAnomalyAnomalyDetectorResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisAnomalyAnomalyDetectorResetConstMeta => const TaskConstMeta(
+            debugName: "AnomalyDetector_reset",
+            argNames: ["that"],
+        );
+        
+
+@override Future<CheckPipeline> crateApiEnginePipelineCheckPipelineDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 11, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnginePipelineCheckPipelineDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnginePipelineCheckPipelineDefaultConstMeta => const TaskConstMeta(
+            debugName: "CheckPipeline_default",
+            argNames: [],
+        );
+        
+
+@override Future<CheckPipeline> crateApiEnginePipelineCheckPipelineNew()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 12, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnginePipelineCheckPipelineNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnginePipelineCheckPipelineNewConstMeta => const TaskConstMeta(
+            debugName: "CheckPipeline_new",
+            argNames: [],
+        );
+        
+
+@override Future<PipelineReport> crateApiEnginePipelineCheckPipelineRun({required CheckPipeline that , required NetworkConfiguration config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(that, serializer);
+sse_encode_box_autoadd_network_configuration(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 13, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnginePipelineCheckPipelineRunConstMeta,
+            argValues: [that, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnginePipelineCheckPipelineRunConstMeta => const TaskConstMeta(
+            debugName: "CheckPipeline_run",
+            argNames: ["that", "config"],
+        );
+        
+
+@override Future<CheckPipeline> crateApiEnginePipelineCheckPipelineStandard()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 14, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnginePipelineCheckPipelineStandardConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnginePipelineCheckPipelineStandardConstMeta => const TaskConstMeta(
+            debugName: "CheckPipeline_standard",
+            argNames: [],
+        );
+        
+
+@override Future<CheckPipeline> crateApiEnginePipelineCheckPipelineStep({required CheckPipeline that , required PipelineStep step })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(that, serializer);
+sse_encode_pipeline_step(step, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 15, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnginePipelineCheckPipelineStepConstMeta,
+            argValues: [that, step],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnginePipelineCheckPipelineStepConstMeta => const TaskConstMeta(
+            debugName: "CheckPipeline_step",
+            argNames: ["that", "step"],
+        );
+        
+
+@override Future<CheckPipeline> crateApiEnginePipelineCheckPipelineStepIf({required CheckPipeline that , required PipelineStep step , required StepCondition condition })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(that, serializer);
+sse_encode_pipeline_step(step, serializer);
+sse_encode_box_autoadd_step_condition(condition, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 16, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnginePipelineCheckPipelineStepIfConstMeta,
+            argValues: [that, step, condition],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnginePipelineCheckPipelineStepIfConstMeta => const TaskConstMeta(
+            debugName: "CheckPipeline_step_if",
+            argNames: ["that", "step", "condition"],
+        );
+        
+
+@override Future<int> crateApiEngineCircuitBreakerCircuitBreakerConsecutiveFailures({required CircuitBreaker that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 17, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineCircuitBreakerCircuitBreakerConsecutiveFailuresConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineCircuitBreakerCircuitBreakerConsecutiveFailuresConstMeta => const TaskConstMeta(
+            debugName: "CircuitBreaker_consecutive_failures",
+            argNames: ["that"],
+        );
+        
+
+@override Future<CircuitBreaker> crateApiEngineCircuitBreakerCircuitBreakerNew({required ResilienceConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_resilience_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 18, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineCircuitBreakerCircuitBreakerNewConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineCircuitBreakerCircuitBreakerNewConstMeta => const TaskConstMeta(
+            debugName: "CircuitBreaker_new",
+            argNames: ["config"],
+        );
+        
+
+@override Future<void> crateApiEngineCircuitBreakerCircuitBreakerRecord({required CircuitBreaker that , required NetworkReport report })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(report, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 19, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineCircuitBreakerCircuitBreakerRecordConstMeta,
+            argValues: [that, report],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineCircuitBreakerCircuitBreakerRecordConstMeta => const TaskConstMeta(
+            debugName: "CircuitBreaker_record",
+            argNames: ["that", "report"],
+        );
+        
+
+@override Future<CircuitBreakerState> crateApiEngineCircuitBreakerCircuitBreakerState({required CircuitBreaker that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 20, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_circuit_breaker_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineCircuitBreakerCircuitBreakerStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineCircuitBreakerCircuitBreakerStateConstMeta => const TaskConstMeta(
+            debugName: "CircuitBreaker_state",
+            argNames: ["that"],
+        );
+        
+
+@override Future<DeviceInventory> crateApiEngineInventoryDeviceInventoryDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 21, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineInventoryDeviceInventoryDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineInventoryDeviceInventoryDefaultConstMeta => const TaskConstMeta(
+            debugName: "DeviceInventory_default",
+            argNames: [],
+        );
+        
+
+@override Future<DeviceInventory> crateApiEngineInventoryDeviceInventoryFromDevices({required List<LocalDevice> devices })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_local_device(devices, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 22, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineInventoryDeviceInventoryFromDevicesConstMeta,
+            argValues: [devices],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineInventoryDeviceInventoryFromDevicesConstMeta => const TaskConstMeta(
+            debugName: "DeviceInventory_from_devices",
+            argNames: ["devices"],
+        );
+        
+
+@override Future<DeviceInventory> crateApiEngineInventoryDeviceInventoryNew()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 23, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineInventoryDeviceInventoryNewConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineInventoryDeviceInventoryNewConstMeta => const TaskConstMeta(
+            debugName: "DeviceInventory_new",
+            argNames: [],
+        );
+        
+
+@override Future<List<DeviceEvent>> crateApiEngineInventoryDeviceInventoryUpdate({required DeviceInventory that , required List<LocalDevice> scan })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(that, serializer);
+sse_encode_list_local_device(scan, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 24, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_device_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineInventoryDeviceInventoryUpdateConstMeta,
+            argValues: [that, scan],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineInventoryDeviceInventoryUpdateConstMeta => const TaskConstMeta(
+            debugName: "DeviceInventory_update",
+            argNames: ["that", "scan"],
+        );
+        
+
+@override Future<NetworkMonitor> crateApiMonitorNetworkMonitorNew({required NetworkConfiguration config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_configuration(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 25, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMonitorNetworkMonitorNewConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMonitorNetworkMonitorNewConstMeta => const TaskConstMeta(
+            debugName: "NetworkMonitor_new",
+            argNames: ["config"],
+        );
+        
+
+@override Future<void> crateApiMonitorNetworkMonitorPause({required NetworkMonitor that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 26, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMonitorNetworkMonitorPauseConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMonitorNetworkMonitorPauseConstMeta => const TaskConstMeta(
+            debugName: "NetworkMonitor_pause",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiMonitorNetworkMonitorResume({required NetworkMonitor that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 27, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMonitorNetworkMonitorResumeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMonitorNetworkMonitorResumeConstMeta => const TaskConstMeta(
+            debugName: "NetworkMonitor_resume",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiMonitorNetworkMonitorShutdown({required NetworkMonitor that , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(that, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 28, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMonitorNetworkMonitorShutdownConstMeta,
+            argValues: [that, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMonitorNetworkMonitorShutdownConstMeta => const TaskConstMeta(
+            debugName: "NetworkMonitor_shutdown",
+            argNames: ["that", "timeoutMs"],
+        );
+        
+
+@override Future<void> crateApiMonitorNetworkMonitorStart({required NetworkMonitor that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 29, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMonitorNetworkMonitorStartConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMonitorNetworkMonitorStartConstMeta => const TaskConstMeta(
+            debugName: "NetworkMonitor_start",
+            argNames: ["that"],
+        );
+        
+
+@override Future<MonitorState> crateApiMonitorNetworkMonitorState({required NetworkMonitor that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 30, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_monitor_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMonitorNetworkMonitorStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMonitorNetworkMonitorStateConstMeta => const TaskConstMeta(
+            debugName: "NetworkMonitor_state",
+            argNames: ["that"],
+        );
+        
+
+@override Future<MonitorStats> crateApiMonitorNetworkMonitorStats({required NetworkMonitor that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 31, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_monitor_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMonitorNetworkMonitorStatsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMonitorNetworkMonitorStatsConstMeta => const TaskConstMeta(
+            debugName: "NetworkMonitor_stats",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiMonitorNetworkMonitorStop({required NetworkMonitor that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 32, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMonitorNetworkMonitorStopConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMonitorNetworkMonitorStopConstMeta => const TaskConstMeta(
+            debugName: "NetworkMonitor_stop",
+            argNames: ["that"],
+        );
+        
+
+@override Stream<NetworkReport> crateApiMonitorNetworkMonitorStreamReports({required NetworkMonitor that })  { 
+            final sink = RustStreamSink<NetworkReport>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(that, serializer);
+sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 33, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMonitorNetworkMonitorStreamReportsConstMeta,
+            argValues: [that, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiMonitorNetworkMonitorStreamReportsConstMeta => const TaskConstMeta(
+            debugName: "NetworkMonitor_stream_reports",
+            argNames: ["that", "sink"],
+        );
+        
+
+@override Future<NetworkMonitor> crateApiMonitorNetworkMonitorWithAnomalyDetector({required NetworkMonitor that , required AnomalyDetector detector })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(detector, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 34, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMonitorNetworkMonitorWithAnomalyDetectorConstMeta,
+            argValues: [that, detector],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMonitorNetworkMonitorWithAnomalyDetectorConstMeta => const TaskConstMeta(
+            debugName: "NetworkMonitor_with_anomaly_detector",
+            argNames: ["that", "detector"],
+        );
+        
+
+@override List<AnomalyDetected> crateApiModelsReportNetworkReportAutoAccessorGetAnomalies({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 35)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_anomaly_detected,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetAnomaliesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetAnomaliesConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_anomalies",
+            argNames: ["that"],
+        );
+        
+
+@override DegradationAttribution? crateApiModelsReportNetworkReportAutoAccessorGetAttribution({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 36)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_degradation_attribution,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetAttributionConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetAttributionConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_attribution",
+            argNames: ["that"],
+        );
+        
+
+@override BetterPathAvailable? crateApiModelsReportNetworkReportAutoAccessorGetBetterPath({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 37)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_better_path_available,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetBetterPathConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetBetterPathConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_better_path",
+            argNames: ["that"],
+        );
+        
+
+@override String? crateApiModelsReportNetworkReportAutoAccessorGetBssid({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 38)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetBssidConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetBssidConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_bssid",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiModelsReportNetworkReportAutoAccessorGetCancelled({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 39)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetCancelledConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetCancelledConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_cancelled",
+            argNames: ["that"],
+        );
+        
+
+@override CaptivePortalStatus? crateApiModelsReportNetworkReportAutoAccessorGetCaptivePortal({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 40)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_captive_portal_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetCaptivePortalConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetCaptivePortalConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_captive_portal",
+            argNames: ["that"],
+        );
+        
+
+@override ConnectionType crateApiModelsReportNetworkReportAutoAccessorGetConnectionType({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 41)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_connection_type,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetConnectionTypeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetConnectionTypeConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_connection_type",
+            argNames: ["that"],
+        );
+        
+
+@override BigInt crateApiModelsReportNetworkReportAutoAccessorGetDurationMs({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 42)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetDurationMsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetDurationMsConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_duration_ms",
+            argNames: ["that"],
+        );
+        
+
+@override RuntimeEnvironment crateApiModelsReportNetworkReportAutoAccessorGetEnvironment({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 43)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_runtime_environment,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetEnvironmentConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetEnvironmentConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_environment",
+            argNames: ["that"],
+        );
+        
+
+@override FamilyComparison? crateApiModelsReportNetworkReportAutoAccessorGetFamilyComparison({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 44)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_family_comparison,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetFamilyComparisonConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetFamilyComparisonConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_family_comparison",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiModelsReportNetworkReportAutoAccessorGetIsConstrained({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 45)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetIsConstrainedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetIsConstrainedConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_is_constrained",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiModelsReportNetworkReportAutoAccessorGetIsExpensive({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 46)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetIsExpensiveConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetIsExpensiveConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_is_expensive",
+            argNames: ["that"],
+        );
+        
+
+@override LayeredDiagnosis crateApiModelsReportNetworkReportAutoAccessorGetLayers({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 47)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_layered_diagnosis,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetLayersConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetLayersConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_layers",
+            argNames: ["that"],
+        );
+        
+
+@override bool? crateApiModelsReportNetworkReportAutoAccessorGetLikelyIspOutage({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 48)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetLikelyIspOutageConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetLikelyIspOutageConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_likely_isp_outage",
+            argNames: ["that"],
+        );
+        
+
+@override BigInt crateApiModelsReportNetworkReportAutoAccessorGetMonotonicMs({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 49)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetMonotonicMsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetMonotonicMsConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_monotonic_ms",
+            argNames: ["that"],
+        );
+        
+
+@override bool? crateApiModelsReportNetworkReportAutoAccessorGetMulticastOk({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 50)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetMulticastOkConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetMulticastOkConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_multicast_ok",
+            argNames: ["that"],
+        );
+        
+
+@override List<SampleRecord> crateApiModelsReportNetworkReportAutoAccessorGetRawSamples({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 51)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_sample_record,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetRawSamplesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetRawSamplesConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_raw_samples",
+            argNames: ["that"],
+        );
+        
+
+@override RoamEvent? crateApiModelsReportNetworkReportAutoAccessorGetRoam({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 52)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_roam_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetRoamConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetRoamConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_roam",
+            argNames: ["that"],
+        );
+        
+
+@override NetworkScope crateApiModelsReportNetworkReportAutoAccessorGetScope({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 53)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_network_scope,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetScopeConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetScopeConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_scope",
+            argNames: ["that"],
+        );
+        
+
+@override SecurityFlagsResult crateApiModelsReportNetworkReportAutoAccessorGetSecurityFlagsResult({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 54)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetSecurityFlagsResultConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetSecurityFlagsResultConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_security_flags_result",
+            argNames: ["that"],
+        );
+        
+
+@override List<SnmpDeviceInfo> crateApiModelsReportNetworkReportAutoAccessorGetSnmpDevices({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 55)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_snmp_device_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetSnmpDevicesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetSnmpDevicesConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_snmp_devices",
+            argNames: ["that"],
+        );
+        
+
+@override String? crateApiModelsReportNetworkReportAutoAccessorGetSsid({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 56)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetSsidConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetSsidConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_ssid",
+            argNames: ["that"],
+        );
+        
+
+@override NetworkStatus crateApiModelsReportNetworkReportAutoAccessorGetStatus({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 57)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_network_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetStatusConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetStatusConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_status",
+            argNames: ["that"],
+        );
+        
+
+@override CheckStrategy crateApiModelsReportNetworkReportAutoAccessorGetStrategy({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 58)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_check_strategy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetStrategyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetStrategyConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_strategy",
+            argNames: ["that"],
+        );
+        
+
+@override List<TargetReport> crateApiModelsReportNetworkReportAutoAccessorGetTargetReports({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 59)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_target_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetTargetReportsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetTargetReportsConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_target_reports",
+            argNames: ["that"],
+        );
+        
+
+@override BigInt crateApiModelsReportNetworkReportAutoAccessorGetTimestampMs({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 60)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetTimestampMsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetTimestampMsConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_timestamp_ms",
+            argNames: ["that"],
+        );
+        
+
+@override RouteTrace? crateApiModelsReportNetworkReportAutoAccessorGetTraceroute({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 61)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_route_trace,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetTracerouteConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetTracerouteConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_traceroute",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiModelsReportNetworkReportAutoAccessorGetTruncated({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 62)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetTruncatedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetTruncatedConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_truncated",
+            argNames: ["that"],
+        );
+        
+
+@override WalledGardenReport? crateApiModelsReportNetworkReportAutoAccessorGetWalledGarden({required NetworkReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 63)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_walled_garden_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorGetWalledGardenConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorGetWalledGardenConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_get_walled_garden",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetAnomalies({required NetworkReport that , required List<AnomalyDetected> anomalies })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_list_anomaly_detected(anomalies, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 64)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetAnomaliesConstMeta,
+            argValues: [that, anomalies],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetAnomaliesConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_anomalies",
+            argNames: ["that", "anomalies"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetAttribution({required NetworkReport that , DegradationAttribution? attribution })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_opt_box_autoadd_degradation_attribution(attribution, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 65)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetAttributionConstMeta,
+            argValues: [that, attribution],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetAttributionConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_attribution",
+            argNames: ["that", "attribution"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetBetterPath({required NetworkReport that , BetterPathAvailable? betterPath })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_opt_box_autoadd_better_path_available(betterPath, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 66)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetBetterPathConstMeta,
+            argValues: [that, betterPath],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetBetterPathConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_better_path",
+            argNames: ["that", "betterPath"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetBssid({required NetworkReport that , String? bssid })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_opt_String(bssid, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 67)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetBssidConstMeta,
+            argValues: [that, bssid],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetBssidConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_bssid",
+            argNames: ["that", "bssid"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetCancelled({required NetworkReport that , required bool cancelled })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_bool(cancelled, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 68)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetCancelledConstMeta,
+            argValues: [that, cancelled],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetCancelledConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_cancelled",
+            argNames: ["that", "cancelled"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetCaptivePortal({required NetworkReport that , CaptivePortalStatus? captivePortal })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_opt_box_autoadd_captive_portal_status(captivePortal, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 69)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetCaptivePortalConstMeta,
+            argValues: [that, captivePortal],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetCaptivePortalConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_captive_portal",
+            argNames: ["that", "captivePortal"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetConnectionType({required NetworkReport that , required ConnectionType connectionType })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_connection_type(connectionType, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 70)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetConnectionTypeConstMeta,
+            argValues: [that, connectionType],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetConnectionTypeConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_connection_type",
+            argNames: ["that", "connectionType"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetDurationMs({required NetworkReport that , required BigInt durationMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_u_64(durationMs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 71)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetDurationMsConstMeta,
+            argValues: [that, durationMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetDurationMsConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_duration_ms",
+            argNames: ["that", "durationMs"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetEnvironment({required NetworkReport that , required RuntimeEnvironment environment })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_runtime_environment(environment, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 72)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetEnvironmentConstMeta,
+            argValues: [that, environment],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetEnvironmentConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_environment",
+            argNames: ["that", "environment"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetFamilyComparison({required NetworkReport that , FamilyComparison? familyComparison })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_opt_box_autoadd_family_comparison(familyComparison, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 73)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetFamilyComparisonConstMeta,
+            argValues: [that, familyComparison],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetFamilyComparisonConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_family_comparison",
+            argNames: ["that", "familyComparison"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetIsConstrained({required NetworkReport that , required bool isConstrained })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_bool(isConstrained, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 74)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetIsConstrainedConstMeta,
+            argValues: [that, isConstrained],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetIsConstrainedConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_is_constrained",
+            argNames: ["that", "isConstrained"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetIsExpensive({required NetworkReport that , required bool isExpensive })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_bool(isExpensive, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 75)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetIsExpensiveConstMeta,
+            argValues: [that, isExpensive],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetIsExpensiveConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_is_expensive",
+            argNames: ["that", "isExpensive"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetLayers({required NetworkReport that , required LayeredDiagnosis layers })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_layered_diagnosis(layers, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 76)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetLayersConstMeta,
+            argValues: [that, layers],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetLayersConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_layers",
+            argNames: ["that", "layers"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetLikelyIspOutage({required NetworkReport that , bool? likelyIspOutage })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_opt_box_autoadd_bool(likelyIspOutage, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 77)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetLikelyIspOutageConstMeta,
+            argValues: [that, likelyIspOutage],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetLikelyIspOutageConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_likely_isp_outage",
+            argNames: ["that", "likelyIspOutage"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetMonotonicMs({required NetworkReport that , required BigInt monotonicMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_u_64(monotonicMs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 78)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetMonotonicMsConstMeta,
+            argValues: [that, monotonicMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetMonotonicMsConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_monotonic_ms",
+            argNames: ["that", "monotonicMs"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetMulticastOk({required NetworkReport that , bool? multicastOk })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_opt_box_autoadd_bool(multicastOk, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 79)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetMulticastOkConstMeta,
+            argValues: [that, multicastOk],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetMulticastOkConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_multicast_ok",
+            argNames: ["that", "multicastOk"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetRawSamples({required NetworkReport that , required List<SampleRecord> rawSamples })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_list_sample_record(rawSamples, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 80)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetRawSamplesConstMeta,
+            argValues: [that, rawSamples],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetRawSamplesConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_raw_samples",
+            argNames: ["that", "rawSamples"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetRoam({required NetworkReport that , RoamEvent? roam })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_opt_box_autoadd_roam_event(roam, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 81)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetRoamConstMeta,
+            argValues: [that, roam],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetRoamConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_roam",
+            argNames: ["that", "roam"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetScope({required NetworkReport that , required NetworkScope scope })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_network_scope(scope, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 82)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetScopeConstMeta,
+            argValues: [that, scope],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetScopeConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_scope",
+            argNames: ["that", "scope"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetSecurityFlagsResult({required NetworkReport that , required SecurityFlagsResult securityFlagsResult })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(securityFlagsResult, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 83)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetSecurityFlagsResultConstMeta,
+            argValues: [that, securityFlagsResult],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetSecurityFlagsResultConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_security_flags_result",
+            argNames: ["that", "securityFlagsResult"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetSnmpDevices({required NetworkReport that , required List<SnmpDeviceInfo> snmpDevices })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_list_snmp_device_info(snmpDevices, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 84)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetSnmpDevicesConstMeta,
+            argValues: [that, snmpDevices],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetSnmpDevicesConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_snmp_devices",
+            argNames: ["that", "snmpDevices"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetSsid({required NetworkReport that , String? ssid })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_opt_String(ssid, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 85)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetSsidConstMeta,
+            argValues: [that, ssid],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetSsidConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_ssid",
+            argNames: ["that", "ssid"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetStatus({required NetworkReport that , required NetworkStatus status })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_network_status(status, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 86)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetStatusConstMeta,
+            argValues: [that, status],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetStatusConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_status",
+            argNames: ["that", "status"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetStrategy({required NetworkReport that , required CheckStrategy strategy })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_check_strategy(strategy, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 87)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetStrategyConstMeta,
+            argValues: [that, strategy],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetStrategyConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_strategy",
+            argNames: ["that", "strategy"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetTargetReports({required NetworkReport that , required List<TargetReport> targetReports })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_list_target_report(targetReports, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 88)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetTargetReportsConstMeta,
+            argValues: [that, targetReports],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetTargetReportsConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_target_reports",
+            argNames: ["that", "targetReports"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetTimestampMs({required NetworkReport that , required BigInt timestampMs })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_u_64(timestampMs, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 89)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetTimestampMsConstMeta,
+            argValues: [that, timestampMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetTimestampMsConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_timestamp_ms",
+            argNames: ["that", "timestampMs"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetTraceroute({required NetworkReport that , RouteTrace? traceroute })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_opt_box_autoadd_route_trace(traceroute, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 90)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetTracerouteConstMeta,
+            argValues: [that, traceroute],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetTracerouteConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_traceroute",
+            argNames: ["that", "traceroute"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetTruncated({required NetworkReport that , required bool truncated })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_bool(truncated, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 91)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetTruncatedConstMeta,
+            argValues: [that, truncated],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetTruncatedConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_truncated",
+            argNames: ["that", "truncated"],
+        );
+        
+
+@override void crateApiModelsReportNetworkReportAutoAccessorSetWalledGarden({required NetworkReport that , WalledGardenReport? walledGarden })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+sse_encode_opt_box_autoadd_walled_garden_report(walledGarden, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 92)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportAutoAccessorSetWalledGardenConstMeta,
+            argValues: [that, walledGarden],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportAutoAccessorSetWalledGardenConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_auto_accessor_set_walled_garden",
+            argNames: ["that", "walledGarden"],
+        );
+        
+
+@override Future<SimpleStatus> crateApiModelsReportNetworkReportSimpleStatus({required NetworkReport that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 93, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_simple_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkReportSimpleStatusConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkReportSimpleStatusConstMeta => const TaskConstMeta(
+            debugName: "NetworkReport_simple_status",
+            argNames: ["that"],
+        );
+        
+
+@override NetworkReport crateApiEnginePipelinePipelineReportAutoAccessorGetReport({required PipelineReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 94)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnginePipelinePipelineReportAutoAccessorGetReportConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnginePipelinePipelineReportAutoAccessorGetReportConstMeta => const TaskConstMeta(
+            debugName: "PipelineReport_auto_accessor_get_report",
+            argNames: ["that"],
+        );
+        
+
+@override List<StepOutcome> crateApiEnginePipelinePipelineReportAutoAccessorGetSteps({required PipelineReport that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 95)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_step_outcome,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnginePipelinePipelineReportAutoAccessorGetStepsConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnginePipelinePipelineReportAutoAccessorGetStepsConstMeta => const TaskConstMeta(
+            debugName: "PipelineReport_auto_accessor_get_steps",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiEnginePipelinePipelineReportAutoAccessorSetReport({required PipelineReport that , required NetworkReport report })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(that, serializer);
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(report, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 96)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnginePipelinePipelineReportAutoAccessorSetReportConstMeta,
+            argValues: [that, report],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnginePipelinePipelineReportAutoAccessorSetReportConstMeta => const TaskConstMeta(
+            debugName: "PipelineReport_auto_accessor_set_report",
+            argNames: ["that", "report"],
+        );
+        
+
+@override void crateApiEnginePipelinePipelineReportAutoAccessorSetSteps({required PipelineReport that , required List<StepOutcome> steps })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(that, serializer);
+sse_encode_list_step_outcome(steps, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 97)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnginePipelinePipelineReportAutoAccessorSetStepsConstMeta,
+            argValues: [that, steps],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnginePipelinePipelineReportAutoAccessorSetStepsConstMeta => const TaskConstMeta(
+            debugName: "PipelineReport_auto_accessor_set_steps",
+            argNames: ["that", "steps"],
+        );
+        
+
+@override Future<NetworkReport> crateApiEngineReachabilityReachabilityEngineCheck({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 98, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineCheckConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineCheckConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_check",
+            argNames: ["that"],
+        );
+        
+
+@override Future<CircuitBreakerState> crateApiEngineReachabilityReachabilityEngineCircuitState({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 99, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_circuit_breaker_state,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineCircuitStateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineCircuitStateConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_circuit_state",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<NetworkReport>> crateApiEngineReachabilityReachabilityEngineHistory({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 100, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineHistoryConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineHistoryConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_history",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<HistoryAggregate>> crateApiEngineReachabilityReachabilityEngineHistoryAggregates({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 101, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_history_aggregate,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineHistoryAggregatesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineHistoryAggregatesConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_history_aggregates",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiEngineReachabilityReachabilityEngineIsShutDown({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 102, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineIsShutDownConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineIsShutDownConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_is_shut_down",
+            argNames: ["that"],
+        );
+        
+
+@override Future<String?> crateApiEngineReachabilityReachabilityEngineLastAppError({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 103, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineLastAppErrorConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineLastAppErrorConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_last_app_error",
+            argNames: ["that"],
+        );
+        
+
+@override Future<NetworkReport?> crateApiEngineReachabilityReachabilityEngineLastReport({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 104, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineLastReportConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineLastReportConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_last_report",
+            argNames: ["that"],
+        );
+        
+
+@override Future<ReachabilityEngine> crateApiEngineReachabilityReachabilityEngineNew({required NetworkConfiguration config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_configuration(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 105, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineNewConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineNewConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_new",
+            argNames: ["config"],
+        );
+        
+
+@override Future<NetworkReport> crateApiEngineReachabilityReachabilityEngineRefresh({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 106, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineRefreshConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineRefreshConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_refresh",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiEngineReachabilityReachabilityEngineReportAppFailure({required ReachabilityEngine that , required String error })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+sse_encode_String(error, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 107, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineReportAppFailureConstMeta,
+            argValues: [that, error],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineReportAppFailureConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_report_app_failure",
+            argNames: ["that", "error"],
+        );
+        
+
+@override Future<void> crateApiEngineReachabilityReachabilityEngineReportAppSuccess({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 108, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineReportAppSuccessConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineReportAppSuccessConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_report_app_success",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiEngineReachabilityReachabilityEngineReset({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 109, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineResetConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineResetConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_reset",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiEngineReachabilityReachabilityEngineShutdown({required ReachabilityEngine that , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 110, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineShutdownConstMeta,
+            argValues: [that, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineShutdownConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_shutdown",
+            argNames: ["that", "timeoutMs"],
+        );
+        
+
+@override Future<EngineSnapshot> crateApiEngineReachabilityReachabilityEngineSnapshot({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 111, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_engine_snapshot,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineSnapshotConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineSnapshotConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_snapshot",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<AnomalyDetected>> crateApiEngineReachabilityReachabilityEngineTakeAnomalies({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 112, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_anomaly_detected,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineTakeAnomaliesConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineTakeAnomaliesConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_take_anomalies",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<TargetHealth>> crateApiEngineReachabilityReachabilityEngineTargetHealth({required ReachabilityEngine that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 113, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_target_health,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineTargetHealthConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineTargetHealthConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_target_health",
+            argNames: ["that"],
+        );
+        
+
+@override Future<TimeOfDayProfile> crateApiEngineReachabilityReachabilityEngineTimeOfDayProfile({required ReachabilityEngine that , required int utcOffsetMinutes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+sse_encode_i_32(utcOffsetMinutes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 114, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_time_of_day_profile,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineTimeOfDayProfileConstMeta,
+            argValues: [that, utcOffsetMinutes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineTimeOfDayProfileConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_time_of_day_profile",
+            argNames: ["that", "utcOffsetMinutes"],
+        );
+        
+
+@override Future<ReachabilityEngine> crateApiEngineReachabilityReachabilityEngineWithHistoryCapacity({required NetworkConfiguration config , required BigInt historyCapacity })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_configuration(config, serializer);
+sse_encode_usize(historyCapacity, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 115, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineWithHistoryCapacityConstMeta,
+            argValues: [config, historyCapacity],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineWithHistoryCapacityConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_with_history_capacity",
+            argNames: ["config", "historyCapacity"],
+        );
+        
+
+@override Future<ReachabilityEngine> crateApiEngineReachabilityReachabilityEngineWithSnapshot({required ReachabilityEngine that , required EngineSnapshot snapshot })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(that, serializer);
+sse_encode_box_autoadd_engine_snapshot(snapshot, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 116, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineReachabilityReachabilityEngineWithSnapshotConstMeta,
+            argValues: [that, snapshot],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineReachabilityReachabilityEngineWithSnapshotConstMeta => const TaskConstMeta(
+            debugName: "ReachabilityEngine_with_snapshot",
+            argNames: ["that", "snapshot"],
+        );
+        
+
+@override Evidence crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetDnsSpoofEvidence({required SecurityFlagsResult that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 117)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_evidence,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetDnsSpoofEvidenceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetDnsSpoofEvidenceConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_get_dns_spoof_evidence",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetInterfaceName({required SecurityFlagsResult that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 118)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetInterfaceNameConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetInterfaceNameConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_get_interface_name",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsDnsSpoofed({required SecurityFlagsResult that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 119)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsDnsSpoofedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsDnsSpoofedConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_get_is_dns_spoofed",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsProxyDetected({required SecurityFlagsResult that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 120)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsProxyDetectedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsProxyDetectedConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_get_is_proxy_detected",
+            argNames: ["that"],
+        );
+        
+
+@override bool crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsVpnDetected({required SecurityFlagsResult that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 121)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsVpnDetectedConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsVpnDetectedConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_get_is_vpn_detected",
+            argNames: ["that"],
+        );
+        
+
+@override Evidence crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetProxyEvidence({required SecurityFlagsResult that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 122)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_evidence,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetProxyEvidenceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetProxyEvidenceConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_get_proxy_evidence",
+            argNames: ["that"],
+        );
+        
+
+@override Evidence crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetVpnEvidence({required SecurityFlagsResult that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 123)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_evidence,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetVpnEvidenceConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetVpnEvidenceConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_get_vpn_evidence",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetDnsSpoofEvidence({required SecurityFlagsResult that , required Evidence dnsSpoofEvidence })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+sse_encode_evidence(dnsSpoofEvidence, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 124)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetDnsSpoofEvidenceConstMeta,
+            argValues: [that, dnsSpoofEvidence],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetDnsSpoofEvidenceConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_set_dns_spoof_evidence",
+            argNames: ["that", "dnsSpoofEvidence"],
+        );
+        
+
+@override void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetInterfaceName({required SecurityFlagsResult that , required String interfaceName })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+sse_encode_String(interfaceName, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 125)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetInterfaceNameConstMeta,
+            argValues: [that, interfaceName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetInterfaceNameConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_set_interface_name",
+            argNames: ["that", "interfaceName"],
+        );
+        
+
+@override void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsDnsSpoofed({required SecurityFlagsResult that , required bool isDnsSpoofed })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+sse_encode_bool(isDnsSpoofed, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 126)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsDnsSpoofedConstMeta,
+            argValues: [that, isDnsSpoofed],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsDnsSpoofedConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_set_is_dns_spoofed",
+            argNames: ["that", "isDnsSpoofed"],
+        );
+        
+
+@override void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsProxyDetected({required SecurityFlagsResult that , required bool isProxyDetected })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+sse_encode_bool(isProxyDetected, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 127)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsProxyDetectedConstMeta,
+            argValues: [that, isProxyDetected],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsProxyDetectedConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_set_is_proxy_detected",
+            argNames: ["that", "isProxyDetected"],
+        );
+        
+
+@override void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsVpnDetected({required SecurityFlagsResult that , required bool isVpnDetected })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+sse_encode_bool(isVpnDetected, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 128)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsVpnDetectedConstMeta,
+            argValues: [that, isVpnDetected],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsVpnDetectedConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_set_is_vpn_detected",
+            argNames: ["that", "isVpnDetected"],
+        );
+        
+
+@override void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetProxyEvidence({required SecurityFlagsResult that , required Evidence proxyEvidence })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+sse_encode_evidence(proxyEvidence, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 129)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetProxyEvidenceConstMeta,
+            argValues: [that, proxyEvidence],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetProxyEvidenceConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_set_proxy_evidence",
+            argNames: ["that", "proxyEvidence"],
+        );
+        
+
+@override void crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetVpnEvidence({required SecurityFlagsResult that , required Evidence vpnEvidence })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(that, serializer);
+sse_encode_evidence(vpnEvidence, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 130)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetVpnEvidenceConstMeta,
+            argValues: [that, vpnEvidence],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetVpnEvidenceConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_auto_accessor_set_vpn_evidence",
+            argNames: ["that", "vpnEvidence"],
+        );
+        
+
+@override Future<SecurityFlagsResult> crateApiModelsNetInfoSecurityFlagsResultDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 131, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsResultDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsResultDefaultConstMeta => const TaskConstMeta(
+            debugName: "SecurityFlagsResult_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsReportAddressFamilyL10NKey({required AddressFamily that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_address_family(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 133)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportAddressFamilyL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportAddressFamilyL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "address_family_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<BigInt?> crateApiEngineSamplerAnalyzeSingleSample({required List<TargetReport> reports , required NetworkConfiguration config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_target_report(reports, serializer);
+sse_encode_box_autoadd_network_configuration(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 134, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineSamplerAnalyzeSingleSampleConstMeta,
+            argValues: [reports, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineSamplerAnalyzeSingleSampleConstMeta => const TaskConstMeta(
+            debugName: "analyze_single_sample",
+            argNames: ["reports", "config"],
+        );
+        
+
+@override String crateApiModelsDiagnosticsAnomalyMetricL10NKey({required AnomalyMetric that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_anomaly_metric(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 135)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsDiagnosticsAnomalyMetricL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsDiagnosticsAnomalyMetricL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "anomaly_metric_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<(bool,Evidence)> crateApiProbesDnsAssessDnsHijacking({required String domain })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(domain, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 136, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_bool_evidence,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesDnsAssessDnsHijackingConstMeta,
+            argValues: [domain],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesDnsAssessDnsHijackingConstMeta => const TaskConstMeta(
+            debugName: "assess_dns_hijacking",
+            argNames: ["domain"],
+        );
+        
+
+@override Future<(bool,Evidence)> crateApiProbesDnsAssessDnsHijackingWith({required String domain , required List<String> trustedServers })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(domain, serializer);
+sse_encode_list_String(trustedServers, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 137, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_bool_evidence,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesDnsAssessDnsHijackingWithConstMeta,
+            argValues: [domain, trustedServers],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesDnsAssessDnsHijackingWithConstMeta => const TaskConstMeta(
+            debugName: "assess_dns_hijacking_with",
+            argNames: ["domain", "trustedServers"],
+        );
+        
+
+@override Future<DegradationAttribution> crateApiAnalysisAttributionAttributeDegradation({required LatencyStats gateway , required LatencyStats endToEnd })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_latency_stats(gateway, serializer);
+sse_encode_box_autoadd_latency_stats(endToEnd, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 138, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_degradation_attribution,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisAttributionAttributeDegradationConstMeta,
+            argValues: [gateway, endToEnd],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisAttributionAttributeDegradationConstMeta => const TaskConstMeta(
+            debugName: "attribute_degradation",
+            argNames: ["gateway", "endToEnd"],
+        );
+        
+
+@override Future<BetterPathAvailable?> crateApiAnalysisPathsBetterPath({required String activeInterface , required List<PathMeasurement> paths , required BigInt timestampMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(activeInterface, serializer);
+sse_encode_list_path_measurement(paths, serializer);
+sse_encode_u_64(timestampMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 139, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_better_path_available,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisPathsBetterPathConstMeta,
+            argValues: [activeInterface, paths, timestampMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisPathsBetterPathConstMeta => const TaskConstMeta(
+            debugName: "better_path",
+            argNames: ["activeInterface", "paths", "timestampMs"],
+        );
+        
+
+@override Future<List<MdnsService>> crateApiProbesMdnsBrowseMdnsServices({required BigInt durationMs , required List<String> excludeRanges })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(durationMs, serializer);
+sse_encode_list_String(excludeRanges, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 140, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_mdns_service,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiProbesMdnsBrowseMdnsServicesConstMeta,
+            argValues: [durationMs, excludeRanges],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesMdnsBrowseMdnsServicesConstMeta => const TaskConstMeta(
+            debugName: "browse_mdns_services",
+            argNames: ["durationMs", "excludeRanges"],
+        );
+        
+
+@override Future<Uint8List> crateApiProbesUdpBuildRequest({required UdpValidation validation })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_udp_validation(validation, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 141, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_8_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesUdpBuildRequestConstMeta,
+            argValues: [validation],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesUdpBuildRequestConstMeta => const TaskConstMeta(
+            debugName: "build_request",
+            argNames: ["validation"],
+        );
+        
+
+@override Future<(BigInt?,BigInt?,BigInt?,double?)> crateApiAnalysisStatsCalculateJitterStats({required Uint64List latencies })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_64_strict(latencies, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 142, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisStatsCalculateJitterStatsConstMeta,
+            argValues: [latencies],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisStatsCalculateJitterStatsConstMeta => const TaskConstMeta(
+            debugName: "calculate_jitter_stats",
+            argNames: ["latencies"],
+        );
+        
+
+@override Future<CaptivePortalStatus> crateApiProbesCaptivePortalCheckForCaptivePortal({required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 143, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_captive_portal_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesCaptivePortalCheckForCaptivePortalConstMeta,
+            argValues: [timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesCaptivePortalCheckForCaptivePortalConstMeta => const TaskConstMeta(
+            debugName: "check_for_captive_portal",
+            argNames: ["timeoutMs"],
+        );
+        
+
+@override Future<CaptivePortalStatus> crateApiProbesCaptivePortalCheckForCaptivePortalAt({required String url , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 144, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_captive_portal_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesCaptivePortalCheckForCaptivePortalAtConstMeta,
+            argValues: [url, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesCaptivePortalCheckForCaptivePortalAtConstMeta => const TaskConstMeta(
+            debugName: "check_for_captive_portal_at",
+            argNames: ["url", "timeoutMs"],
+        );
+        
+
+@override Future<CaptivePortalStatus> crateApiProbesCaptivePortalCheckForCaptivePortalWeb({required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 145, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_captive_portal_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesCaptivePortalCheckForCaptivePortalWebConstMeta,
+            argValues: [timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesCaptivePortalCheckForCaptivePortalWebConstMeta => const TaskConstMeta(
+            debugName: "check_for_captive_portal_web",
+            argNames: ["timeoutMs"],
+        );
+        
+
+@override Future<CaptivePortalStatus> crateApiProbesCaptivePortalCheckForCaptivePortalWebManual({required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 146, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_captive_portal_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesCaptivePortalCheckForCaptivePortalWebManualConstMeta,
+            argValues: [timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesCaptivePortalCheckForCaptivePortalWebManualConstMeta => const TaskConstMeta(
+            debugName: "check_for_captive_portal_web_manual",
+            argNames: ["timeoutMs"],
+        );
+        
+
+@override Future<HairpinReport> crateApiProbesHairpinCheckHairpin({required int port , String? publicAddress , required List<String> reflectionServers , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_16(port, serializer);
+sse_encode_opt_String(publicAddress, serializer);
+sse_encode_list_String(reflectionServers, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 147, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_hairpin_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesHairpinCheckHairpinConstMeta,
+            argValues: [port, publicAddress, reflectionServers, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesHairpinCheckHairpinConstMeta => const TaskConstMeta(
+            debugName: "check_hairpin",
+            argNames: ["port", "publicAddress", "reflectionServers", "timeoutMs"],
+        );
+        
+
+@override Future<bool?> crateApiProbesIspStatusCheckIspStatus({required List<String> urls , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(urls, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 148, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesIspStatusCheckIspStatusConstMeta,
+            argValues: [urls, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesIspStatusCheckIspStatusConstMeta => const TaskConstMeta(
+            debugName: "check_isp_status",
+            argNames: ["urls", "timeoutMs"],
+        );
+        
+
+@override Future<LocalReachabilityReport> crateApiEngineLocalCheckLocalNetwork({required NetworkConfiguration config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_configuration(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 149, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_local_reachability_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineLocalCheckLocalNetworkConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineLocalCheckLocalNetworkConstMeta => const TaskConstMeta(
+            debugName: "check_local_network",
+            argNames: ["config"],
+        );
+        
+
+@override Future<MulticastReport> crateApiProbesMulticastCheckMulticast({required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 150, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_multicast_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesMulticastCheckMulticastConstMeta,
+            argValues: [timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesMulticastCheckMulticastConstMeta => const TaskConstMeta(
+            debugName: "check_multicast",
+            argNames: ["timeoutMs"],
+        );
+        
+
+@override Future<NetworkReport> crateApiEngineCheckNetwork({required NetworkConfiguration config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_configuration(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 151, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineCheckNetworkConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineCheckNetworkConstMeta => const TaskConstMeta(
+            debugName: "check_network",
+            argNames: ["config"],
+        );
+        
+
+@override Future<TargetReport> crateApiProbesTargetCheckTarget({required NetworkTarget target })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_target(target, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 152, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_target_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesTargetCheckTargetConstMeta,
+            argValues: [target],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesTargetCheckTargetConstMeta => const TaskConstMeta(
+            debugName: "check_target",
+            argNames: ["target"],
+        );
+        
+
+@override String crateApiEngineCircuitBreakerCircuitBreakerStateL10NKey({required CircuitBreakerState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_circuit_breaker_state(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 153)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineCircuitBreakerCircuitBreakerStateL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineCircuitBreakerCircuitBreakerStateL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "circuit_breaker_state_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiProbesApplePathClearApplePath()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 154, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesApplePathClearApplePathConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesApplePathClearApplePathConstMeta => const TaskConstMeta(
+            debugName: "clear_apple_path",
+            argNames: [],
+        );
+        
+
+@override void crateApiEngineHintsClearPlatformHints()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 155)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineHintsClearPlatformHintsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineHintsClearPlatformHintsConstMeta => const TaskConstMeta(
+            debugName: "clear_platform_hints",
+            argNames: [],
+        );
+        
+
+@override Future<(Uint64List,List<TargetReport>)> crateApiEngineSamplerCollectNetworkSamples({required NetworkConfiguration config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_configuration(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 156, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_list_prim_u_64_strict_list_target_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineSamplerCollectNetworkSamplesConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineSamplerCollectNetworkSamplesConstMeta => const TaskConstMeta(
+            debugName: "collect_network_samples",
+            argNames: ["config"],
+        );
+        
+
+@override Future<List<DscpLatency>> crateApiProbesQosCompareDscpMarkings({required NetworkTarget target , required List<int> markings , required int samples })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_target(target, serializer);
+sse_encode_list_prim_u_8_loose(markings, serializer);
+sse_encode_u_8(samples, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 157, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_dscp_latency,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesQosCompareDscpMarkingsConstMeta,
+            argValues: [target, markings, samples],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesQosCompareDscpMarkingsConstMeta => const TaskConstMeta(
+            debugName: "compare_dscp_markings",
+            argNames: ["target", "markings", "samples"],
+        );
+        
+
+@override Future<VpnComparison> crateApiAnalysisVpnCompareVpnReports({required NetworkReport before , required NetworkReport after , required List<String> resolversBefore , required List<String> resolversAfter })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(before, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(after, serializer);
+sse_encode_list_String(resolversBefore, serializer);
+sse_encode_list_String(resolversAfter, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 158, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_vpn_comparison,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisVpnCompareVpnReportsConstMeta,
+            argValues: [before, after, resolversBefore, resolversAfter],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisVpnCompareVpnReportsConstMeta => const TaskConstMeta(
+            debugName: "compare_vpn_reports",
+            argNames: ["before", "after", "resolversBefore", "resolversAfter"],
+        );
+        
+
+@override Future<VpnComparison> crateApiEngineVpnCompareVpnToggle({required NetworkConfiguration config , required BigInt waitTimeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_configuration(config, serializer);
+sse_encode_u_64(waitTimeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 159, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_vpn_comparison,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineVpnCompareVpnToggleConstMeta,
+            argValues: [config, waitTimeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineVpnCompareVpnToggleConstMeta => const TaskConstMeta(
+            debugName: "compare_vpn_toggle",
+            argNames: ["config", "waitTimeoutMs"],
+        );
+        
+
+@override Future<LatencyStats> crateApiAnalysisStatsComputeLatencyStats({required Uint64List latencies , required int totalExpectedSamples , required QualityThresholds thresholds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_64_strict(latencies, serializer);
+sse_encode_u_8(totalExpectedSamples, serializer);
+sse_encode_box_autoadd_quality_thresholds(thresholds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 160, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_latency_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisStatsComputeLatencyStatsConstMeta,
+            argValues: [latencies, totalExpectedSamples, thresholds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisStatsComputeLatencyStatsConstMeta => const TaskConstMeta(
+            debugName: "compute_latency_stats",
+            argNames: ["latencies", "totalExpectedSamples", "thresholds"],
+        );
+        
+
+@override String crateApiModelsNetInfoConfidenceL10NKey({required Confidence that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_confidence(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 161)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoConfidenceL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoConfidenceL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "confidence_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiModelsConfigConnectionQualityL10NKey({required ConnectionQuality that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_connection_quality(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 162)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigConnectionQualityL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigConnectionQualityL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "connection_quality_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<ConnectionType> crateApiModelsNetInfoConnectionTypeDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 163, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_connection_type,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoConnectionTypeDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoConnectionTypeDefaultConstMeta => const TaskConstMeta(
+            debugName: "connection_type_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsNetInfoConnectionTypeL10NKey({required ConnectionType that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_connection_type(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 164)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoConnectionTypeL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoConnectionTypeL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "connection_type_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<ApplePathSnapshot?> crateApiProbesApplePathCurrentApplePath()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 165, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_apple_path_snapshot,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesApplePathCurrentApplePathConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesApplePathCurrentApplePathConstMeta => const TaskConstMeta(
+            debugName: "current_apple_path",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsDiagnosticsDegradationScopeL10NKey({required DegradationScope that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_degradation_scope(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 166)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsDiagnosticsDegradationScopeL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsDiagnosticsDegradationScopeL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "degradation_scope_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<bool> crateApiProbesDnsDetectDnsHijacking({required String domain })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(domain, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 167, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesDnsDetectDnsHijackingConstMeta,
+            argValues: [domain],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesDnsDetectDnsHijackingConstMeta => const TaskConstMeta(
+            debugName: "detect_dns_hijacking",
+            argNames: ["domain"],
+        );
+        
+
+@override Future<bool> crateApiProbesDnsDetectDnsHijackingWeb({required String domain })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(domain, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 168, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesDnsDetectDnsHijackingWebConstMeta,
+            argValues: [domain],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesDnsDetectDnsHijackingWebConstMeta => const TaskConstMeta(
+            debugName: "detect_dns_hijacking_web",
+            argNames: ["domain"],
+        );
+        
+
+@override Future<EcnReport> crateApiProbesEcnDetectEcn({required List<String> tcpServers , required List<String> reflectionServers , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(tcpServers, serializer);
+sse_encode_list_String(reflectionServers, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 169, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_ecn_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesEcnDetectEcnConstMeta,
+            argValues: [tcpServers, reflectionServers, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesEcnDetectEcnConstMeta => const TaskConstMeta(
+            debugName: "detect_ecn",
+            argNames: ["tcpServers", "reflectionServers", "timeoutMs"],
+        );
+        
+
+@override Future<Uint32List> crateApiAnalysisStatsDetectLatencySpikes({required Uint64List latencies , required double ratioThreshold })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_prim_u_64_strict(latencies, serializer);
+sse_encode_f_64(ratioThreshold, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 170, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_prim_u_32_strict,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisStatsDetectLatencySpikesConstMeta,
+            argValues: [latencies, ratioThreshold],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisStatsDetectLatencySpikesConstMeta => const TaskConstMeta(
+            debugName: "detect_latency_spikes",
+            argNames: ["latencies", "ratioThreshold"],
+        );
+        
+
+@override Future<MssReport> crateApiProbesMssDetectMssClamping({required List<String> servers , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(servers, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 171, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_mss_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesMssDetectMssClampingConstMeta,
+            argValues: [servers, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesMssDetectMssClampingConstMeta => const TaskConstMeta(
+            debugName: "detect_mss_clamping",
+            argNames: ["servers", "timeoutMs"],
+        );
+        
+
+@override Future<ProbeCapabilities> crateApiProbesCapabilitiesDetectProbeCapabilities()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 172, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_probe_capabilities,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesCapabilitiesDetectProbeCapabilitiesConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesCapabilitiesDetectProbeCapabilitiesConstMeta => const TaskConstMeta(
+            debugName: "detect_probe_capabilities",
+            argNames: [],
+        );
+        
+
+@override Future<RoamEvent?> crateApiAnalysisRoamingDetectRoam({required NetworkReport previous , required NetworkReport current , required ResilienceConfig resilience })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(previous, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(current, serializer);
+sse_encode_box_autoadd_resilience_config(resilience, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 173, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_roam_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisRoamingDetectRoamConstMeta,
+            argValues: [previous, current, resilience],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisRoamingDetectRoamConstMeta => const TaskConstMeta(
+            debugName: "detect_roam",
+            argNames: ["previous", "current", "resilience"],
+        );
+        
+
+@override Future<RuntimeEnvironment> crateApiProbesEnvironmentDetectRuntimeEnvironment()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 174, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_runtime_environment,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesEnvironmentDetectRuntimeEnvironmentConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesEnvironmentDetectRuntimeEnvironmentConstMeta => const TaskConstMeta(
+            debugName: "detect_runtime_environment",
+            argNames: [],
+        );
+        
+
+@override Future<(SecurityFlagsResult,ConnectionType)> crateApiProbesInterfaceDetectSecurityAndNetworkType()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 175, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesInterfaceDetectSecurityAndNetworkTypeConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesInterfaceDetectSecurityAndNetworkTypeConstMeta => const TaskConstMeta(
+            debugName: "detect_security_and_network_type",
+            argNames: [],
+        );
+        
+
+@override Future<(SecurityFlagsResult,ConnectionType)> crateApiProbesInterfaceDetectSecurityAndNetworkTypeOf({required String interfaceName })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(interfaceName, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 176, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesInterfaceDetectSecurityAndNetworkTypeOfConstMeta,
+            argValues: [interfaceName],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesInterfaceDetectSecurityAndNetworkTypeOfConstMeta => const TaskConstMeta(
+            debugName: "detect_security_and_network_type_of",
+            argNames: ["interfaceName"],
+        );
+        
+
+@override Future<(SecurityFlagsResult,ConnectionType)> crateApiProbesInterfaceDetectSecurityAndNetworkTypeWeb()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 177, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesInterfaceDetectSecurityAndNetworkTypeWebConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesInterfaceDetectSecurityAndNetworkTypeWebConstMeta => const TaskConstMeta(
+            debugName: "detect_security_and_network_type_web",
+            argNames: [],
+        );
+        
+
+@override Future<LayeredDiagnosis> crateApiAnalysisLayersDiagnoseLayers({required List<NetworkTarget> targets , required List<TargetReport> reports , required SecurityFlagsResult flags , required ConnectionQuality quality })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_network_target(targets, serializer);
+sse_encode_list_target_report(reports, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(flags, serializer);
+sse_encode_connection_quality(quality, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 178, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_layered_diagnosis,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisLayersDiagnoseLayersConstMeta,
+            argValues: [targets, reports, flags, quality],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisLayersDiagnoseLayersConstMeta => const TaskConstMeta(
+            debugName: "diagnose_layers",
+            argNames: ["targets", "reports", "flags", "quality"],
+        );
+        
+
+@override Future<List<DeviceEvent>> crateApiEngineInventoryDiffDevices({required List<LocalDevice> previous , required List<LocalDevice> current })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_local_device(previous, serializer);
+sse_encode_list_local_device(current, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 179, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_device_event,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineInventoryDiffDevicesConstMeta,
+            argValues: [previous, current],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineInventoryDiffDevicesConstMeta => const TaskConstMeta(
+            debugName: "diff_devices",
+            argNames: ["previous", "current"],
+        );
+        
+
+@override Future<LinkNeighbor?> crateApiProbesLldpDiscoverLinkNeighbor({required String interface_ , required BigInt listenMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(interface_, serializer);
+sse_encode_u_64(listenMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 180, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_link_neighbor,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiProbesLldpDiscoverLinkNeighborConstMeta,
+            argValues: [interface_, listenMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesLldpDiscoverLinkNeighborConstMeta => const TaskConstMeta(
+            debugName: "discover_link_neighbor",
+            argNames: ["interface_", "listenMs"],
+        );
+        
+
+@override String crateApiModelsNetInfoEcnMarkingL10NKey({required EcnMarking that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_ecn_marking(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 181)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoEcnMarkingL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoEcnMarkingL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "ecn_marking_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiModelsNetInfoEncapsulationL10NKey({required Encapsulation that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_encapsulation(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 182)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoEncapsulationL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoEncapsulationL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "encapsulation_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<EndpointConfig> crateApiModelsConfigEndpointConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 183, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_endpoint_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigEndpointConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigEndpointConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "endpoint_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<EnvironmentReport> crateApiEngineEnvironmentEnvironmentReport()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 184, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_environment_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineEnvironmentEnvironmentReportConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineEnvironmentEnvironmentReportConstMeta => const TaskConstMeta(
+            debugName: "environment_report",
+            argNames: [],
+        );
+        
+
+@override Future<OneWayDelayEstimate> crateApiProbesOneWayDelayEstimateOneWayDelay({required String server , required int samples , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(server, serializer);
+sse_encode_u_8(samples, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 185, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_one_way_delay_estimate,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiProbesOneWayDelayEstimateOneWayDelayConstMeta,
+            argValues: [server, samples, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesOneWayDelayEstimateOneWayDelayConstMeta => const TaskConstMeta(
+            debugName: "estimate_one_way_delay",
+            argNames: ["server", "samples", "timeoutMs"],
+        );
+        
+
+@override Future<BigInt> crateApiEngineRateLimitEstimatedProbeBytes({required TargetProtocol protocol })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_target_protocol(protocol, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 186, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineRateLimitEstimatedProbeBytesConstMeta,
+            argValues: [protocol],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineRateLimitEstimatedProbeBytesConstMeta => const TaskConstMeta(
+            debugName: "estimated_probe_bytes",
+            argNames: ["protocol"],
+        );
+        
+
+@override Future<ConnectionQuality> crateApiAnalysisQualityEvaluateNetworkQuality({required bool isConnected , required LatencyStats stats , required NetworkConfiguration config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(isConnected, serializer);
+sse_encode_box_autoadd_latency_stats(stats, serializer);
+sse_encode_box_autoadd_network_configuration(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 187, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_connection_quality,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisQualityEvaluateNetworkQualityConstMeta,
+            argValues: [isConnected, stats, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisQualityEvaluateNetworkQualityConstMeta => const TaskConstMeta(
+            debugName: "evaluate_network_quality",
+            argNames: ["isConnected", "stats", "config"],
+        );
+        
+
+@override Future<ConnectionQuality> crateApiAnalysisQualityEvaluateQuality({required BigInt latency , required QualityThresholds threshold })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(latency, serializer);
+sse_encode_box_autoadd_quality_thresholds(threshold, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 188, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_connection_quality,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisQualityEvaluateQualityConstMeta,
+            argValues: [latency, threshold],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisQualityEvaluateQualityConstMeta => const TaskConstMeta(
+            debugName: "evaluate_quality",
+            argNames: ["latency", "threshold"],
+        );
+        
+
+@override Future<Evidence> crateApiModelsNetInfoEvidenceDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 189, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_evidence,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoEvidenceDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoEvidenceDefaultConstMeta => const TaskConstMeta(
+            debugName: "evidence_default",
+            argNames: [],
+        );
+        
+
+@override Future<Evidence> crateApiModelsNetInfoEvidenceNew({required Confidence confidence , required EvidenceSource source })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_confidence(confidence, serializer);
+sse_encode_evidence_source(source, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 190, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_evidence,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoEvidenceNewConstMeta,
+            argValues: [confidence, source],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoEvidenceNewConstMeta => const TaskConstMeta(
+            debugName: "evidence_new",
+            argNames: ["confidence", "source"],
+        );
+        
+
+@override String crateApiModelsNetInfoEvidenceSourceL10NKey({required EvidenceSource that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_evidence_source(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 191)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoEvidenceSourceL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoEvidenceSourceL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "evidence_source_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<GatewayFingerprint> crateApiModelsScanGatewayFingerprintDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 192, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_gateway_fingerprint,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsScanGatewayFingerprintDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsScanGatewayFingerprintDefaultConstMeta => const TaskConstMeta(
+            debugName: "gateway_fingerprint_default",
+            argNames: [],
+        );
+        
+
+@override Future<NetworkReport> crateApiEngineSecurityGuard({required NetworkConfiguration config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_configuration(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 193, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiEngineSecurityGuardConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineSecurityGuardConstMeta => const TaskConstMeta(
+            debugName: "guard",
+            argNames: ["config"],
+        );
+        
+
+@override Future<BigInt> crateApiEngineScheduleJitteredIntervalMs({required BigInt intervalMs , required int jitterPercent })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(intervalMs, serializer);
+sse_encode_u_8(jitterPercent, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 194, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineScheduleJitteredIntervalMsConstMeta,
+            argValues: [intervalMs, jitterPercent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineScheduleJitteredIntervalMsConstMeta => const TaskConstMeta(
+            debugName: "jittered_interval_ms",
+            argNames: ["intervalMs", "jitterPercent"],
+        );
+        
+
+@override Future<LatencyHeatmap> crateApiAnalysisHeatmapLatencyHeatmap({required List<NetworkReport> reports , required BigInt bucketMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(reports, serializer);
+sse_encode_u_64(bucketMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 195, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_latency_heatmap,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisHeatmapLatencyHeatmapConstMeta,
+            argValues: [reports, bucketMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisHeatmapLatencyHeatmapConstMeta => const TaskConstMeta(
+            debugName: "latency_heatmap",
+            argNames: ["reports", "bucketMs"],
+        );
+        
+
+@override String crateApiModelsReportLayerStatusL10NKey({required LayerStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_layer_status(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 196)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportLayerStatusL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportLayerStatusL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "layer_status_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiModelsDiagnosticsLimitationL10NKey({required Limitation that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_limitation(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 197)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsDiagnosticsLimitationL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsDiagnosticsLimitationL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "limitation_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<LocalScanConfig> crateApiModelsScanLocalScanConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 198, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_local_scan_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsScanLocalScanConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsScanLocalScanConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "local_scan_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<PathMeasurement>> crateApiProbesPathsMeasurePaths({required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 199, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_path_measurement,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesPathsMeasurePathsConstMeta,
+            argValues: [timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesPathsMeasurePathsConstMeta => const TaskConstMeta(
+            debugName: "measure_paths",
+            argNames: ["timeoutMs"],
+        );
+        
+
+@override String crateApiMonitorMonitorStateL10NKey({required MonitorState that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_monitor_state(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 200)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMonitorMonitorStateL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMonitorMonitorStateL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "monitor_state_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<MonitorStats> crateApiMonitorMonitorStatsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 201, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_monitor_stats,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiMonitorMonitorStatsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiMonitorMonitorStatsDefaultConstMeta => const TaskConstMeta(
+            debugName: "monitor_stats_default",
+            argNames: [],
+        );
+        
+
+@override Future<BigInt> crateApiEngineClockMonotonicMs()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 202, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineClockMonotonicMsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineClockMonotonicMsConstMeta => const TaskConstMeta(
+            debugName: "monotonic_ms",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsNetInfoNatTypeL10NKey({required NatType that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_nat_type(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 203)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoNatTypeL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoNatTypeL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "nat_type_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<TargetReport> crateApiProbesTargetNativeProbeCheck({required NativeProbe that , required NetworkTarget target })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_native_probe(that, serializer);
+sse_encode_box_autoadd_network_target(target, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 204, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_target_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesTargetNativeProbeCheckConstMeta,
+            argValues: [that, target],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesTargetNativeProbeCheckConstMeta => const TaskConstMeta(
+            debugName: "native_probe_check",
+            argNames: ["that", "target"],
+        );
+        
+
+@override String crateApiModelsNetInfoNeighborProtocolL10NKey({required NeighborProtocol that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_neighbor_protocol(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 205)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoNeighborProtocolL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoNeighborProtocolL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "neighbor_protocol_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<NetworkConfiguration> crateApiModelsConfigNetworkConfigurationDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 206, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_network_configuration,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigNetworkConfigurationDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigNetworkConfigurationDefaultConstMeta => const TaskConstMeta(
+            debugName: "network_configuration_default",
+            argNames: [],
+        );
+        
+
+@override Future<NetworkConfiguration> crateApiModelsConfigNetworkConfigurationNew({required List<NetworkTarget> targets , required BigInt checkIntervalMs , required BigInt cacheValidityMs , required QualityThresholds qualityThreshold , required SecurityConfig security , required ResilienceConfig resilience })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_network_target(targets, serializer);
+sse_encode_u_64(checkIntervalMs, serializer);
+sse_encode_u_64(cacheValidityMs, serializer);
+sse_encode_box_autoadd_quality_thresholds(qualityThreshold, serializer);
+sse_encode_box_autoadd_security_config(security, serializer);
+sse_encode_box_autoadd_resilience_config(resilience, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 207, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_network_configuration,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigNetworkConfigurationNewConstMeta,
+            argValues: [targets, checkIntervalMs, cacheValidityMs, qualityThreshold, security, resilience],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigNetworkConfigurationNewConstMeta => const TaskConstMeta(
+            debugName: "network_configuration_new",
+            argNames: ["targets", "checkIntervalMs", "cacheValidityMs", "qualityThreshold", "security", "resilience"],
+        );
+        
+
+@override Future<void> crateApiModelsConfigNetworkConfigurationValidate({required NetworkConfiguration that })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_configuration(that, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 208, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_list_validation_issue,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigNetworkConfigurationValidateConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigNetworkConfigurationValidateConstMeta => const TaskConstMeta(
+            debugName: "network_configuration_validate",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiModelsErrorNetworkErrorL10NKey({required NetworkError that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_error(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 209)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsErrorNetworkErrorL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsErrorNetworkErrorL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "network_error_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiModelsReportNetworkScopeL10NKey({required NetworkScope that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_network_scope(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 210)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportNetworkScopeL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportNetworkScopeL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "network_scope_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<NetworkTarget> crateApiModelsTargetNetworkTargetDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 211, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_network_target,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsTargetNetworkTargetDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsTargetNetworkTargetDefaultConstMeta => const TaskConstMeta(
+            debugName: "network_target_default",
+            argNames: [],
+        );
+        
+
+@override Future<BigInt> crateApiEngineScheduleNextCheckDelayMs({required NetworkConfiguration config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_configuration(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 212, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineScheduleNextCheckDelayMsConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineScheduleNextCheckDelayMsConstMeta => const TaskConstMeta(
+            debugName: "next_check_delay_ms",
+            argNames: ["config"],
+        );
+        
+
+@override Future<String?> crateApiEngineSamplerOverallWinner({required List<String?> winnersPerSample })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_opt_String(winnersPerSample, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 213, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineSamplerOverallWinnerConstMeta,
+            argValues: [winnersPerSample],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineSamplerOverallWinnerConstMeta => const TaskConstMeta(
+            debugName: "overall_winner",
+            argNames: ["winnersPerSample"],
+        );
+        
+
+@override Future<OverlapPolicy> crateApiModelsConfigOverlapPolicyDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 214, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_overlap_policy,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigOverlapPolicyDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigOverlapPolicyDefaultConstMeta => const TaskConstMeta(
+            debugName: "overlap_policy_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsConfigOverlapPolicyL10NKey({required OverlapPolicy that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_overlap_policy(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 215)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigOverlapPolicyL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigOverlapPolicyL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "overlap_policy_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiModelsNetInfoPathSegmentL10NKey({required PathSegment that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_path_segment(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 216)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoPathSegmentL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoPathSegmentL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "path_segment_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiEngineSecurityPerformDnsSecurityCheck({required NetworkConfiguration config , required SecurityFlagsResult flags })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_configuration(config, serializer);
+sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(flags, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 217, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineSecurityPerformDnsSecurityCheckConstMeta,
+            argValues: [config, flags],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineSecurityPerformDnsSecurityCheckConstMeta => const TaskConstMeta(
+            debugName: "perform_dns_security_check",
+            argNames: ["config", "flags"],
+        );
+        
+
+@override PlatformHints? crateApiEngineHintsPlatformHints()  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 218)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_opt_box_autoadd_platform_hints,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineHintsPlatformHintsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineHintsPlatformHintsConstMeta => const TaskConstMeta(
+            debugName: "platform_hints",
+            argNames: [],
+        );
+        
+
+@override Future<PlatformHints> crateApiModelsNetInfoPlatformHintsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 219, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_platform_hints,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoPlatformHintsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoPlatformHintsDefaultConstMeta => const TaskConstMeta(
+            debugName: "platform_hints_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsErrorPolicyViolationL10NKey({required PolicyViolation that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_policy_violation(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 220)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsErrorPolicyViolationL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsErrorPolicyViolationL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "policy_violation_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override String crateApiModelsNetInfoPortalLoginKindL10NKey({required PortalLoginKind that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_portal_login_kind(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 221)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoPortalLoginKindL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoPortalLoginKindL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "portal_login_kind_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<PreflightReport> crateApiEnginePreflightPreflight({required Requirements requirements })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_requirements(requirements, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 222, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_preflight_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnginePreflightPreflightConstMeta,
+            argValues: [requirements],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnginePreflightPreflightConstMeta => const TaskConstMeta(
+            debugName: "preflight",
+            argNames: ["requirements"],
+        );
+        
+
+@override Future<ProbeCapabilities> crateApiModelsNetInfoProbeCapabilitiesDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 223, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_probe_capabilities,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoProbeCapabilitiesDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoProbeCapabilitiesDefaultConstMeta => const TaskConstMeta(
+            debugName: "probe_capabilities_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsNetInfoProbeFallbackL10NKey({required ProbeFallback that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_probe_fallback(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 224)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoProbeFallbackL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoProbeFallbackL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "probe_fallback_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<NatReport> crateApiProbesReflectionProbeNat({required List<String> servers , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(servers, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 225, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_nat_report,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiProbesReflectionProbeNatConstMeta,
+            argValues: [servers, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesReflectionProbeNatConstMeta => const TaskConstMeta(
+            debugName: "probe_nat",
+            argNames: ["servers", "timeoutMs"],
+        );
+        
+
+@override Future<ProbeTimings> crateApiModelsReportProbeTimingsNew({required BigInt budgetMs , required BigInt elapsedMs , BigInt? dnsMs , BigInt? connectMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(budgetMs, serializer);
+sse_encode_u_64(elapsedMs, serializer);
+sse_encode_opt_box_autoadd_u_64(dnsMs, serializer);
+sse_encode_opt_box_autoadd_u_64(connectMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 226, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_probe_timings,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportProbeTimingsNewConstMeta,
+            argValues: [budgetMs, elapsedMs, dnsMs, connectMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportProbeTimingsNewConstMeta => const TaskConstMeta(
+            debugName: "probe_timings_new",
+            argNames: ["budgetMs", "elapsedMs", "dnsMs", "connectMs"],
+        );
+        
+
+@override Future<WalledGardenReport> crateApiProbesWalledGardenProbeWalledGarden({required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 227, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_walled_garden_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesWalledGardenProbeWalledGardenConstMeta,
+            argValues: [timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesWalledGardenProbeWalledGardenConstMeta => const TaskConstMeta(
+            debugName: "probe_walled_garden",
+            argNames: ["timeoutMs"],
+        );
+        
+
+@override Future<WalledGardenReport> crateApiProbesWalledGardenProbeWalledGardenWith({required EndpointConfig endpoints , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_endpoint_config(endpoints, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 228, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_walled_garden_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesWalledGardenProbeWalledGardenWithConstMeta,
+            argValues: [endpoints, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesWalledGardenProbeWalledGardenWithConstMeta => const TaskConstMeta(
+            debugName: "probe_walled_garden_with",
+            argNames: ["endpoints", "timeoutMs"],
+        );
+        
+
+@override Future<TimeOfDayProfile> crateApiAnalysisTimeOfDayProfileTimeOfDay({required List<NetworkReport> reports , required int utcOffsetMinutes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(reports, serializer);
+sse_encode_i_32(utcOffsetMinutes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 229, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_time_of_day_profile,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisTimeOfDayProfileTimeOfDayConstMeta,
+            argValues: [reports, utcOffsetMinutes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisTimeOfDayProfileTimeOfDayConstMeta => const TaskConstMeta(
+            debugName: "profile_time_of_day",
+            argNames: ["reports", "utcOffsetMinutes"],
+        );
+        
+
+@override Future<TimeOfDayProfile> crateApiAnalysisTimeOfDayProfileTimeOfDayWithAggregates({required List<NetworkReport> reports , required List<HistoryAggregate> aggregates , required int utcOffsetMinutes })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(reports, serializer);
+sse_encode_list_history_aggregate(aggregates, serializer);
+sse_encode_i_32(utcOffsetMinutes, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 230, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_time_of_day_profile,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisTimeOfDayProfileTimeOfDayWithAggregatesConstMeta,
+            argValues: [reports, aggregates, utcOffsetMinutes],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisTimeOfDayProfileTimeOfDayWithAggregatesConstMeta => const TaskConstMeta(
+            debugName: "profile_time_of_day_with_aggregates",
+            argNames: ["reports", "aggregates", "utcOffsetMinutes"],
+        );
+        
+
+@override String crateApiModelsReportQualityReasonL10NKey({required QualityReason that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_quality_reason(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 231)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportQualityReasonL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportQualityReasonL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "quality_reason_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<List<QualityReason>> crateApiAnalysisQualityQualityReasons({required bool isConnected , required LatencyStats stats , required NetworkConfiguration config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_bool(isConnected, serializer);
+sse_encode_box_autoadd_latency_stats(stats, serializer);
+sse_encode_box_autoadd_network_configuration(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 232, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_quality_reason,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisQualityQualityReasonsConstMeta,
+            argValues: [isConnected, stats, config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisQualityQualityReasonsConstMeta => const TaskConstMeta(
+            debugName: "quality_reasons",
+            argNames: ["isConnected", "stats", "config"],
+        );
+        
+
+@override Future<QualityThresholds> crateApiModelsConfigQualityThresholdsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 233, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_quality_thresholds,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigQualityThresholdsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigQualityThresholdsDefaultConstMeta => const TaskConstMeta(
+            debugName: "quality_thresholds_default",
+            argNames: [],
+        );
+        
+
+@override Future<QualityThresholds> crateApiModelsConfigQualityThresholdsNew({required BigInt excellent , required BigInt great , required BigInt good , required BigInt moderate , required BigInt poor })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(excellent, serializer);
+sse_encode_u_64(great, serializer);
+sse_encode_u_64(good, serializer);
+sse_encode_u_64(moderate, serializer);
+sse_encode_u_64(poor, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 234, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_quality_thresholds,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigQualityThresholdsNewConstMeta,
+            argValues: [excellent, great, good, moderate, poor],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigQualityThresholdsNewConstMeta => const TaskConstMeta(
+            debugName: "quality_thresholds_new",
+            argNames: ["excellent", "great", "good", "moderate", "poor"],
+        );
+        
+
+@override Future<RateLimitConfig> crateApiModelsConfigRateLimitConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 235, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_rate_limit_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigRateLimitConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigRateLimitConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "rate_limit_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<ArpEntry>> crateApiProbesArpReadArpTable()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 236, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_arp_entry,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesArpReadArpTableConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesArpReadArpTableConstMeta => const TaskConstMeta(
+            debugName: "read_arp_table",
+            argNames: [],
+        );
+        
+
+@override Future<Requirements> crateApiModelsConfigRequirementsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 237, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_requirements,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigRequirementsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigRequirementsDefaultConstMeta => const TaskConstMeta(
+            debugName: "requirements_default",
+            argNames: [],
+        );
+        
+
+@override Future<ResilienceConfig> crateApiModelsConfigResilienceConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 238, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_resilience_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigResilienceConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigResilienceConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "resilience_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<ResilienceConfig> crateApiModelsConfigResilienceConfigNew({required CheckStrategy strategy , required int circuitBreakerThreshold , required BigInt circuitBreakerCooldownMs , required int numJitterSamples , required double jitterThresholdPercent , required int stabilityThershold , required double criticalPacketLossPrecent })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_check_strategy(strategy, serializer);
+sse_encode_u_8(circuitBreakerThreshold, serializer);
+sse_encode_u_64(circuitBreakerCooldownMs, serializer);
+sse_encode_u_8(numJitterSamples, serializer);
+sse_encode_f_64(jitterThresholdPercent, serializer);
+sse_encode_u_8(stabilityThershold, serializer);
+sse_encode_f_32(criticalPacketLossPrecent, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 239, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_resilience_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigResilienceConfigNewConstMeta,
+            argValues: [strategy, circuitBreakerThreshold, circuitBreakerCooldownMs, numJitterSamples, jitterThresholdPercent, stabilityThershold, criticalPacketLossPrecent],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigResilienceConfigNewConstMeta => const TaskConstMeta(
+            debugName: "resilience_config_new",
+            argNames: ["strategy", "circuitBreakerThreshold", "circuitBreakerCooldownMs", "numJitterSamples", "jitterThresholdPercent", "stabilityThershold", "criticalPacketLossPrecent"],
+        );
+        
+
+@override Future<RuntimeEnvironment> crateApiModelsNetInfoRuntimeEnvironmentDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 240, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_runtime_environment,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoRuntimeEnvironmentDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoRuntimeEnvironmentDefaultConstMeta => const TaskConstMeta(
+            debugName: "runtime_environment_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsNetInfoRuntimeEnvironmentL10NKey({required RuntimeEnvironment that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_runtime_environment(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 241)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoRuntimeEnvironmentL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoRuntimeEnvironmentL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "runtime_environment_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<SampleRun> crateApiEngineSamplerSampleRunDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 242, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_sample_run,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineSamplerSampleRunDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineSamplerSampleRunDefaultConstMeta => const TaskConstMeta(
+            debugName: "sample_run_default",
+            argNames: [],
+        );
+        
+
+@override Future<List<LocalDevice>> crateApiProbesLocalScanScanLocalNetwork({required LocalScanConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_local_scan_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 243, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_local_device,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesLocalScanScanLocalNetworkConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesLocalScanScanLocalNetworkConstMeta => const TaskConstMeta(
+            debugName: "scan_local_network",
+            argNames: ["config"],
+        );
+        
+
+@override Future<ScanMethod> crateApiModelsScanScanMethodDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 244, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_scan_method,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsScanScanMethodDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsScanScanMethodDefaultConstMeta => const TaskConstMeta(
+            debugName: "scan_method_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsScanScanMethodL10NKey({required ScanMethod that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_scan_method(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 245)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsScanScanMethodL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsScanScanMethodL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "scan_method_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<double> crateApiAnalysisStabilityScoreLatency({required double ms , required QualityThresholds thresholds })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_f_64(ms, serializer);
+sse_encode_box_autoadd_quality_thresholds(thresholds, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 246, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_f_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisStabilityScoreLatencyConstMeta,
+            argValues: [ms, thresholds],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisStabilityScoreLatencyConstMeta => const TaskConstMeta(
+            debugName: "score_latency",
+            argNames: ["ms", "thresholds"],
+        );
+        
+
+@override Future<SecurityConfig> crateApiModelsConfigSecurityConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 247, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_security_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigSecurityConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigSecurityConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "security_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<SecurityFlags> crateApiModelsNetInfoSecurityFlagsDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 248, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_security_flags,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoSecurityFlagsDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoSecurityFlagsDefaultConstMeta => const TaskConstMeta(
+            debugName: "security_flags_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsNetInfoServiceReachabilityL10NKey({required ServiceReachability that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_service_reachability(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 249)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsNetInfoServiceReachabilityL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsNetInfoServiceReachabilityL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "service_reachability_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override void crateApiEngineHintsSetPlatformHints({required PlatformHints hints })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_platform_hints(hints, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 250)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineHintsSetPlatformHintsConstMeta,
+            argValues: [hints],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineHintsSetPlatformHintsConstMeta => const TaskConstMeta(
+            debugName: "set_platform_hints",
+            argNames: ["hints"],
+        );
+        
+
+@override Future<SimpleStatus> crateApiEngineSimpleStatus()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 251, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_simple_status,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineSimpleStatusConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineSimpleStatusConstMeta => const TaskConstMeta(
+            debugName: "simple_status",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsReportSimpleStatusL10NKey({required SimpleStatus that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_simple_status(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 252)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsReportSimpleStatusL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsReportSimpleStatusL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "simple_status_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<SnmpConfig> crateApiModelsConfigSnmpConfigDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 253, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_snmp_config,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsConfigSnmpConfigDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsConfigSnmpConfigDefaultConstMeta => const TaskConstMeta(
+            debugName: "snmp_config_default",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiEnginePipelineStepConditionIsMet({required StepCondition that , required NetworkReport report })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_step_condition(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(report, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 254, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEnginePipelineStepConditionIsMetConstMeta,
+            argValues: [that, report],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEnginePipelineStepConditionIsMetConstMeta => const TaskConstMeta(
+            debugName: "step_condition_is_met",
+            argNames: ["that", "report"],
+        );
+        
+
+@override Stream<ScanEvent> crateApiProbesLocalScanStreamLocalNetworkScan({required LocalScanConfig config })  { 
+            final sink = RustStreamSink<ScanEvent>();
+            unawaited(handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_local_scan_config(config, serializer);
+sse_encode_StreamSink_scan_event_Sse(sink, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 255, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiProbesLocalScanStreamLocalNetworkScanConstMeta,
+            argValues: [config, sink],
+            apiImpl: this,
+        )));
+            return sink.stream;
+             }
+
+
+        TaskConstMeta get kCrateApiProbesLocalScanStreamLocalNetworkScanConstMeta => const TaskConstMeta(
+            debugName: "stream_local_network_scan",
+            argNames: ["config", "sink"],
+        );
+        
+
+@override Future<List<String>> crateApiProbesDnsSystemDnsResolvers()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 256, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesDnsSystemDnsResolversConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesDnsSystemDnsResolversConstMeta => const TaskConstMeta(
+            debugName: "system_dns_resolvers",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsTargetTargetProtocolL10NKey({required TargetProtocol that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_target_protocol(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 257)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsTargetTargetProtocolL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsTargetTargetProtocolL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "target_protocol_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<TcpInfo> crateApiProbesTcpInfoTcpInfoDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 258, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_tcp_info,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesTcpInfoTcpInfoDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesTcpInfoTcpInfoDefaultConstMeta => const TaskConstMeta(
+            debugName: "tcp_info_default",
+            argNames: [],
+        );
+        
+
+@override Future<RouteTrace> crateApiProbesTracerouteTraceRoute({required String host , required int maxHops , required BigInt timeoutPerHopMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(host, serializer);
+sse_encode_u_8(maxHops, serializer);
+sse_encode_u_64(timeoutPerHopMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 259, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_route_trace,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiProbesTracerouteTraceRouteConstMeta,
+            argValues: [host, maxHops, timeoutPerHopMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesTracerouteTraceRouteConstMeta => const TaskConstMeta(
+            debugName: "trace_route",
+            argNames: ["host", "maxHops", "timeoutPerHopMs"],
+        );
+        
+
+@override Future<MultiRouteTrace> crateApiProbesTracerouteTraceRoutes({required List<String> hosts , required int maxHops , required BigInt timeoutPerHopMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_list_String(hosts, serializer);
+sse_encode_u_8(maxHops, serializer);
+sse_encode_u_64(timeoutPerHopMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 260, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_multi_route_trace,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesTracerouteTraceRoutesConstMeta,
+            argValues: [hosts, maxHops, timeoutPerHopMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesTracerouteTraceRoutesConstMeta => const TaskConstMeta(
+            debugName: "trace_routes",
+            argNames: ["hosts", "maxHops", "timeoutPerHopMs"],
+        );
+        
+
+@override Future<CaptivePortalStatus> crateApiProbesCaptivePortalTryCheckForCaptivePortal({required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 261, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_captive_portal_status,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiProbesCaptivePortalTryCheckForCaptivePortalConstMeta,
+            argValues: [timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesCaptivePortalTryCheckForCaptivePortalConstMeta => const TaskConstMeta(
+            debugName: "try_check_for_captive_portal",
+            argNames: ["timeoutMs"],
+        );
+        
+
+@override Future<CaptivePortalStatus> crateApiProbesCaptivePortalTryCheckForCaptivePortalAt({required String url , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(url, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 262, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_captive_portal_status,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiProbesCaptivePortalTryCheckForCaptivePortalAtConstMeta,
+            argValues: [url, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesCaptivePortalTryCheckForCaptivePortalAtConstMeta => const TaskConstMeta(
+            debugName: "try_check_for_captive_portal_at",
+            argNames: ["url", "timeoutMs"],
+        );
+        
+
+@override Future<NetworkReport> crateApiEngineTryCheckNetwork({required NetworkConfiguration config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_network_configuration(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 263, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiEngineTryCheckNetworkConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineTryCheckNetworkConstMeta => const TaskConstMeta(
+            debugName: "try_check_network",
+            argNames: ["config"],
+        );
+        
+
+@override Future<List<LocalDevice>> crateApiProbesLocalScanTryScanLocalNetwork({required LocalScanConfig config })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_local_scan_config(config, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 264, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_list_local_device,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiProbesLocalScanTryScanLocalNetworkConstMeta,
+            argValues: [config],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesLocalScanTryScanLocalNetworkConstMeta => const TaskConstMeta(
+            debugName: "try_scan_local_network",
+            argNames: ["config"],
+        );
+        
+
+@override Future<UdpValidation> crateApiModelsTargetUdpValidationDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 265, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_udp_validation,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsTargetUdpValidationDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsTargetUdpValidationDefaultConstMeta => const TaskConstMeta(
+            debugName: "udp_validation_default",
+            argNames: [],
+        );
+        
+
+@override String crateApiModelsDiagnosticsUnmetRequirementL10NKey({required UnmetRequirement that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_unmet_requirement(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 266)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsDiagnosticsUnmetRequirementL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsDiagnosticsUnmetRequirementL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "unmet_requirement_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<void> crateApiProbesApplePathUpdateApplePath({required ApplePathSnapshot snapshot })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_apple_path_snapshot(snapshot, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 267, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_unit,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesApplePathUpdateApplePathConstMeta,
+            argValues: [snapshot],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesApplePathUpdateApplePathConstMeta => const TaskConstMeta(
+            debugName: "update_apple_path",
+            argNames: ["snapshot"],
+        );
+        
+
+@override Future<UpnpDescription> crateApiProbesUpnpUpnpDescriptionDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 268, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_upnp_description,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesUpnpUpnpDescriptionDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesUpnpUpnpDescriptionDefaultConstMeta => const TaskConstMeta(
+            debugName: "upnp_description_default",
+            argNames: [],
+        );
+        
+
+@override Future<bool> crateApiProbesUdpValidateReply({required UdpValidation validation , required List<int> request , required List<int> reply })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_udp_validation(validation, serializer);
+sse_encode_list_prim_u_8_loose(request, serializer);
+sse_encode_list_prim_u_8_loose(reply, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 269, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_bool,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesUdpValidateReplyConstMeta,
+            argValues: [validation, request, reply],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesUdpValidateReplyConstMeta => const TaskConstMeta(
+            debugName: "validate_reply",
+            argNames: ["validation", "request", "reply"],
+        );
+        
+
+@override String crateApiModelsErrorValidationIssueL10NKey({required ValidationIssue that })  { return handler.executeSync(SyncTask(
+            callFfi: () {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_validation_issue(that, serializer);
+            return pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 270)!;
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_String,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiModelsErrorValidationIssueL10NKeyConstMeta,
+            argValues: [that],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiModelsErrorValidationIssueL10NKeyConstMeta => const TaskConstMeta(
+            debugName: "validation_issue_l10n_key",
+            argNames: ["that"],
+        );
+        
+
+@override Future<PortForwardCheck> crateApiProbesPortCheckVerifyPortForwarding({required String endpoint , required int port , required BigInt timeoutMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_String(endpoint, serializer);
+sse_encode_u_16(port, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 271, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_port_forward_check,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiProbesPortCheckVerifyPortForwardingConstMeta,
+            argValues: [endpoint, port, timeoutMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesPortCheckVerifyPortForwardingConstMeta => const TaskConstMeta(
+            debugName: "verify_port_forwarding",
+            argNames: ["endpoint", "port", "timeoutMs"],
+        );
+        
+
+@override Future<CaptivePortalStatus> crateApiProbesCaptivePortalWaitForPortalClearance({required BigInt timeoutMs , required BigInt pollIntervalMs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_u_64(timeoutMs, serializer);
+sse_encode_u_64(pollIntervalMs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 272, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_captive_portal_status,
+          decodeErrorData: sse_decode_network_error,
+        )
+        ,
+            constMeta: kCrateApiProbesCaptivePortalWaitForPortalClearanceConstMeta,
+            argValues: [timeoutMs, pollIntervalMs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesCaptivePortalWaitForPortalClearanceConstMeta => const TaskConstMeta(
+            debugName: "wait_for_portal_clearance",
+            argNames: ["timeoutMs", "pollIntervalMs"],
+        );
+        
+
+@override Future<BigInt> crateApiEngineClockWallClockMs()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 273, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_64,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiEngineClockWallClockMsConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiEngineClockWallClockMsConstMeta => const TaskConstMeta(
+            debugName: "wall_clock_ms",
+            argNames: [],
+        );
+        
+
+@override Future<TargetReport> crateApiProbesTargetWebProbeCheck({required WebProbe that , required NetworkTarget target })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_web_probe(that, serializer);
+sse_encode_box_autoadd_network_target(target, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 274, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_target_report,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiProbesTargetWebProbeCheckConstMeta,
+            argValues: [that, target],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiProbesTargetWebProbeCheckConstMeta => const TaskConstMeta(
+            debugName: "web_probe_check",
+            argNames: ["that", "target"],
+        );
+        
+
+@override Future<WeightedStabilityModel> crateApiAnalysisStabilityWeightedStabilityModelDefault()  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 275, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_weighted_stability_model,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisStabilityWeightedStabilityModelDefaultConstMeta,
+            argValues: [],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisStabilityWeightedStabilityModelDefaultConstMeta => const TaskConstMeta(
+            debugName: "weighted_stability_model_default",
+            argNames: [],
+        );
+        
+
+@override Future<int> crateApiAnalysisStabilityWeightedStabilityModelScore({required WeightedStabilityModel that , required StabilityInputs inputs })  { return handler.executeNormal(NormalTask(
+            callFfi: (port_) {
+              
+            final serializer = SseSerializer(generalizedFrbRustBinding);sse_encode_box_autoadd_weighted_stability_model(that, serializer);
+sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(inputs, serializer);
+            pdeCallFfi(generalizedFrbRustBinding, serializer, funcId: 276, port: port_);
+            
+            },
+            codec: 
+        SseCodec(
+          decodeSuccessData: sse_decode_u_8,
+          decodeErrorData: null,
+        )
+        ,
+            constMeta: kCrateApiAnalysisStabilityWeightedStabilityModelScoreConstMeta,
+            argValues: [that, inputs],
+            apiImpl: this,
+        )); }
+
+
+        TaskConstMeta get kCrateApiAnalysisStabilityWeightedStabilityModelScoreConstMeta => const TaskConstMeta(
+            debugName: "weighted_stability_model_score",
+            argNames: ["that", "inputs"],
+        );
+        
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_AnomalyDetector => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_AnomalyDetector => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CheckPipeline => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CheckPipeline => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_CircuitBreaker => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_CircuitBreaker => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_DeviceInventory => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_DeviceInventory => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_NetworkMonitor => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_NetworkMonitor => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_NetworkReport => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_NetworkReport => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_PipelineReport => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_PipelineReport => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_ReachabilityEngine => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_ReachabilityEngine => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_SecurityFlagsResult => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_SecurityFlagsResult => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult;
+
+RustArcIncrementStrongCountFnType get rust_arc_increment_strong_count_StabilityInputs => wire.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic;
+
+RustArcDecrementStrongCountFnType get rust_arc_decrement_strong_count_StabilityInputs => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic;
+
+
+
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnyhowException(raw as String); }
+
+@protected AnomalyDetector dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnomalyDetectorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CheckPipeline dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CheckPipelineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CircuitBreaker dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CircuitBreakerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected DeviceInventory dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DeviceInventoryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected NetworkMonitor dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NetworkMonitorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected NetworkReport dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NetworkReportImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PipelineReport dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PipelineReportImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ReachabilityEngine dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ReachabilityEngineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SecurityFlagsResult dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SecurityFlagsResultImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected AnomalyDetector dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnomalyDetectorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CircuitBreaker dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CircuitBreakerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected DeviceInventory dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DeviceInventoryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected NetworkReport dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NetworkReportImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PipelineReport dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PipelineReportImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SecurityFlagsResult dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SecurityFlagsResultImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected AnomalyDetector dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnomalyDetectorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CheckPipeline dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CheckPipelineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CircuitBreaker dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CircuitBreakerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected NetworkMonitor dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NetworkMonitorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected NetworkReport dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NetworkReportImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PipelineReport dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PipelineReportImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ReachabilityEngine dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ReachabilityEngineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SecurityFlagsResult dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SecurityFlagsResultImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected StabilityInputs dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return StabilityInputsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected AnomalyDetector dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnomalyDetectorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CheckPipeline dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CheckPipelineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected CircuitBreaker dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CircuitBreakerImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected DeviceInventory dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DeviceInventoryImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected NetworkMonitor dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NetworkMonitorImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected NetworkReport dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NetworkReportImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected PipelineReport dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PipelineReportImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected ReachabilityEngine dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ReachabilityEngineImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected SecurityFlagsResult dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SecurityFlagsResultImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected StabilityInputs dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return StabilityInputsImpl.frbInternalDcoDecode(raw as List<dynamic>); }
+
+@protected RustStreamSink<NetworkReport> dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected RustStreamSink<ScanEvent> dco_decode_StreamSink_scan_event_Sse(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected String dco_decode_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as String; }
+
+@protected StabilityModel dco_decode_TraitDef_StabilityModel(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+throw UnimplementedError(); }
+
+@protected AddressFamily dco_decode_address_family(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AddressFamily.values[raw as int]; }
+
+@protected AnomalyDetected dco_decode_anomaly_detected(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return AnomalyDetected(metric: dco_decode_anomaly_metric(arr[0]),
+timestampMs: dco_decode_u_64(arr[1]),
+value: dco_decode_f_64(arr[2]),
+expected: dco_decode_f_64(arr[3]),
+stdDev: dco_decode_f_64(arr[4]),
+zScore: dco_decode_f_64(arr[5]),
+connectionType: dco_decode_connection_type(arr[6]),); }
+
+@protected AnomalyMetric dco_decode_anomaly_metric(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return AnomalyMetric.values[raw as int]; }
+
+@protected ApplePathInterfaceType dco_decode_apple_path_interface_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ApplePathInterfaceType.values[raw as int]; }
+
+@protected ApplePathSnapshot dco_decode_apple_path_snapshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ApplePathSnapshot(status: dco_decode_apple_path_status(arr[0]),
+interfaceType: dco_decode_apple_path_interface_type(arr[1]),
+interfaceName: dco_decode_String(arr[2]),
+isExpensive: dco_decode_bool(arr[3]),
+isConstrained: dco_decode_bool(arr[4]),
+usesVpn: dco_decode_bool(arr[5]),); }
+
+@protected ApplePathStatus dco_decode_apple_path_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ApplePathStatus.values[raw as int]; }
+
+@protected ArpEntry dco_decode_arp_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ArpEntry(ipAddress: dco_decode_String(arr[0]),
+macAddress: dco_decode_String(arr[1]),
+interface_: dco_decode_opt_String(arr[2]),); }
+
+@protected BetterPathAvailable dco_decode_better_path_available(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return BetterPathAvailable(timestampMs: dco_decode_u_64(arr[0]),
+current: dco_decode_path_measurement(arr[1]),
+better: dco_decode_path_measurement(arr[2]),
+latencyGainMs: dco_decode_opt_box_autoadd_i_64(arr[3]),); }
+
+@protected bool dco_decode_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected NetworkReport dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(raw); }
+
+@protected AddressFamily dco_decode_box_autoadd_address_family(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_address_family(raw); }
+
+@protected ApplePathSnapshot dco_decode_box_autoadd_apple_path_snapshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_apple_path_snapshot(raw); }
+
+@protected BetterPathAvailable dco_decode_box_autoadd_better_path_available(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_better_path_available(raw); }
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as bool; }
+
+@protected CaptivePortalStatus dco_decode_box_autoadd_captive_portal_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_captive_portal_status(raw); }
+
+@protected CheckStrategy dco_decode_box_autoadd_check_strategy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_check_strategy(raw); }
+
+@protected ConnectionType dco_decode_box_autoadd_connection_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_connection_type(raw); }
+
+@protected DegradationAttribution dco_decode_box_autoadd_degradation_attribution(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_degradation_attribution(raw); }
+
+@protected EndpointConfig dco_decode_box_autoadd_endpoint_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_endpoint_config(raw); }
+
+@protected EngineSnapshot dco_decode_box_autoadd_engine_snapshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_engine_snapshot(raw); }
+
+@protected double dco_decode_box_autoadd_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FamilyComparison dco_decode_box_autoadd_family_comparison(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_family_comparison(raw); }
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_i_64(raw); }
+
+@protected IcmpUnreachable dco_decode_box_autoadd_icmp_unreachable(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_icmp_unreachable(raw); }
+
+@protected LatencyStats dco_decode_box_autoadd_latency_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_latency_stats(raw); }
+
+@protected LinkNeighbor dco_decode_box_autoadd_link_neighbor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_link_neighbor(raw); }
+
+@protected LocalDevice dco_decode_box_autoadd_local_device(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_local_device(raw); }
+
+@protected LocalScanConfig dco_decode_box_autoadd_local_scan_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_local_scan_config(raw); }
+
+@protected NativeProbe dco_decode_box_autoadd_native_probe(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_native_probe(raw); }
+
+@protected NetworkConfiguration dco_decode_box_autoadd_network_configuration(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_network_configuration(raw); }
+
+@protected NetworkError dco_decode_box_autoadd_network_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_network_error(raw); }
+
+@protected NetworkTarget dco_decode_box_autoadd_network_target(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_network_target(raw); }
+
+@protected PathSegment dco_decode_box_autoadd_path_segment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_path_segment(raw); }
+
+@protected PlatformHints dco_decode_box_autoadd_platform_hints(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_platform_hints(raw); }
+
+@protected PolicyViolation dco_decode_box_autoadd_policy_violation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_policy_violation(raw); }
+
+@protected ProbeFallback dco_decode_box_autoadd_probe_fallback(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_probe_fallback(raw); }
+
+@protected ProbeTimings dco_decode_box_autoadd_probe_timings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_probe_timings(raw); }
+
+@protected QualityThresholds dco_decode_box_autoadd_quality_thresholds(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_quality_thresholds(raw); }
+
+@protected Requirements dco_decode_box_autoadd_requirements(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_requirements(raw); }
+
+@protected ResilienceConfig dco_decode_box_autoadd_resilience_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_resilience_config(raw); }
+
+@protected RoamEvent dco_decode_box_autoadd_roam_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_roam_event(raw); }
+
+@protected RouteTrace dco_decode_box_autoadd_route_trace(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_route_trace(raw); }
+
+@protected ScanSummary dco_decode_box_autoadd_scan_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_scan_summary(raw); }
+
+@protected SecurityConfig dco_decode_box_autoadd_security_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_security_config(raw); }
+
+@protected StepCondition dco_decode_box_autoadd_step_condition(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_step_condition(raw); }
+
+@protected TargetReport dco_decode_box_autoadd_target_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_target_report(raw); }
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_u_64(raw); }
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected UnmetRequirement dco_decode_box_autoadd_unmet_requirement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_unmet_requirement(raw); }
+
+@protected ValidationIssue dco_decode_box_autoadd_validation_issue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_validation_issue(raw); }
+
+@protected WalledGardenReport dco_decode_box_autoadd_walled_garden_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_walled_garden_report(raw); }
+
+@protected WebProbe dco_decode_box_autoadd_web_probe(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_web_probe(raw); }
+
+@protected WeightedStabilityModel dco_decode_box_autoadd_weighted_stability_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dco_decode_weighted_stability_model(raw); }
+
+@protected CaptivePortalStatus dco_decode_captive_portal_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CaptivePortalStatus(isCaptivePortal: dco_decode_bool(arr[0]),
+redirectUrl: dco_decode_opt_String(arr[1]),
+evidence: dco_decode_evidence(arr[2]),
+pageTitle: dco_decode_opt_String(arr[3]),
+loginKind: dco_decode_portal_login_kind(arr[4]),
+wispr: dco_decode_bool(arr[5]),); }
+
+@protected CheckStrategy dco_decode_check_strategy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return CheckStrategy_Race();
+case 1: return CheckStrategy_Consensus();
+case 2: return CheckStrategy_Fallback();
+case 3: return CheckStrategy_All();
+case 4: return CheckStrategy_Quorum(dco_decode_u_8(raw[1]),);
+case 5: return CheckStrategy_Weighted();
+                default: throw Exception("unreachable");
+            } }
+
+@protected CircuitBreakerState dco_decode_circuit_breaker_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return CircuitBreakerState.values[raw as int]; }
+
+@protected Confidence dco_decode_confidence(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Confidence.values[raw as int]; }
+
+@protected CongestionWindow dco_decode_congestion_window(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return CongestionWindow(startHour: dco_decode_u_8(arr[0]),
+endHour: dco_decode_u_8(arr[1]),
+avgLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+baselineLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+degradedShare: dco_decode_f_32(arr[4]),
+daysObserved: dco_decode_u_32(arr[5]),); }
+
+@protected ConnectionQuality dco_decode_connection_quality(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConnectionQuality.values[raw as int]; }
+
+@protected ConnectionType dco_decode_connection_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ConnectionType.values[raw as int]; }
+
+@protected DegradationAttribution dco_decode_degradation_attribution(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return DegradationAttribution(gatewayLatencyMs: dco_decode_u_64(arr[0]),
+gatewayJitterMs: dco_decode_u_64(arr[1]),
+gatewayLossPercent: dco_decode_f_32(arr[2]),
+latencyShare: dco_decode_opt_box_autoadd_f_32(arr[3]),
+varianceShare: dco_decode_opt_box_autoadd_f_32(arr[4]),
+lossShare: dco_decode_opt_box_autoadd_f_32(arr[5]),
+dominantSegment: dco_decode_opt_box_autoadd_path_segment(arr[6]),); }
+
+@protected DegradationScope dco_decode_degradation_scope(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return DegradationScope.values[raw as int]; }
+
+@protected DeviceEvent dco_decode_device_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return DeviceEvent_Joined(dco_decode_box_autoadd_local_device(raw[1]),);
+case 1: return DeviceEvent_Left(dco_decode_box_autoadd_local_device(raw[1]),);
+case 2: return DeviceEvent_IpChanged(macAddress: dco_decode_String(raw[1]),oldIp: dco_decode_String(raw[2]),newIp: dco_decode_String(raw[3]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected DscpLatency dco_decode_dscp_latency(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return DscpLatency(dscp: dco_decode_u_8(arr[0]),
+samples: dco_decode_u_32(arr[1]),
+successes: dco_decode_u_32(arr[2]),
+medianLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+lossPercent: dco_decode_f_32(arr[4]),
+deltaMs: dco_decode_opt_box_autoadd_i_64(arr[5]),); }
+
+@protected EcnMarking dco_decode_ecn_marking(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return EcnMarking.values[raw as int]; }
+
+@protected EcnObservation dco_decode_ecn_observation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return EcnObservation(server: dco_decode_String(arr[0]),
+negotiated: dco_decode_opt_box_autoadd_bool(arr[1]),
+error: dco_decode_opt_String(arr[2]),); }
+
+@protected EcnReport dco_decode_ecn_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return EcnReport(osRequestsEcn: dco_decode_opt_box_autoadd_bool(arr[0]),
+tcp: dco_decode_list_ecn_observation(arr[1]),
+udpMarking: dco_decode_ecn_marking(arr[2]),
+ecnCapable: dco_decode_bool(arr[3]),); }
+
+@protected Encapsulation dco_decode_encapsulation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Encapsulation.values[raw as int]; }
+
+@protected EndpointConfig dco_decode_endpoint_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return EndpointConfig(captivePortalUrl: dco_decode_String(arr[0]),
+walledGardenUrl: dco_decode_String(arr[1]),
+walledGardenPortHost: dco_decode_String(arr[2]),
+publicDnsServer: dco_decode_String(arr[3]),
+trustedDnsServers: dco_decode_list_String(arr[4]),
+tcpReferenceServers: dco_decode_list_String(arr[5]),); }
+
+@protected EngineSnapshot dco_decode_engine_snapshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return EngineSnapshot(takenAtMs: dco_decode_u_64(arr[0]),
+lastReport: dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(arr[1]),
+breakerConsecutiveFailures: dco_decode_u_8(arr[2]),
+breakerOpenedAtMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+targetHealth: dco_decode_list_target_health(arr[4]),); }
+
+@protected EnvironmentReport dco_decode_environment_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return EnvironmentReport(crateVersion: dco_decode_String(arr[0]),
+os: dco_decode_String(arr[1]),
+arch: dco_decode_String(arr[2]),
+runtime: dco_decode_runtime_environment(arr[3]),
+capabilities: dco_decode_probe_capabilities(arr[4]),
+hasIpv4: dco_decode_bool(arr[5]),
+hasIpv6: dco_decode_bool(arr[6]),
+features: dco_decode_list_String(arr[7]),
+limitations: dco_decode_list_limitation(arr[8]),); }
+
+@protected Evidence dco_decode_evidence(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return Evidence(confidence: dco_decode_confidence(arr[0]),
+source: dco_decode_evidence_source(arr[1]),); }
+
+@protected EvidenceSource dco_decode_evidence_source(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return EvidenceSource.values[raw as int]; }
+
+@protected double dco_decode_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected double dco_decode_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as double; }
+
+@protected FamilyComparison dco_decode_family_comparison(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return FamilyComparison(targets: dco_decode_list_String(arr[0]),
+ipv4: dco_decode_family_stats(arr[1]),
+ipv6: dco_decode_family_stats(arr[2]),
+preferredFamily: dco_decode_opt_box_autoadd_address_family(arr[3]),); }
+
+@protected FamilyStats dco_decode_family_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return FamilyStats(family: dco_decode_address_family(arr[0]),
+probes: dco_decode_u_32(arr[1]),
+latencyMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+packetLossPercent: dco_decode_f_32(arr[3]),); }
+
+@protected GatewayFingerprint dco_decode_gateway_fingerprint(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 11) throw Exception('unexpected arr length: expect 11 but see ${arr.length}');
+                return GatewayFingerprint(ipAddress: dco_decode_String(arr[0]),
+macAddress: dco_decode_opt_String(arr[1]),
+macVendor: dco_decode_opt_String(arr[2]),
+httpTitle: dco_decode_opt_String(arr[3]),
+httpServer: dco_decode_opt_String(arr[4]),
+upnpFriendlyName: dco_decode_opt_String(arr[5]),
+upnpManufacturer: dco_decode_opt_String(arr[6]),
+upnpModelName: dco_decode_opt_String(arr[7]),
+upnpModelNumber: dco_decode_opt_String(arr[8]),
+vendor: dco_decode_opt_String(arr[9]),
+model: dco_decode_opt_String(arr[10]),); }
+
+@protected HairpinReport dco_decode_hairpin_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return HairpinReport(publicAddress: dco_decode_opt_String(arr[0]),
+port: dco_decode_u_16(arr[1]),
+fromLan: dco_decode_service_reachability(arr[2]),
+fromInternet: dco_decode_service_reachability(arr[3]),
+hairpinSupported: dco_decode_opt_box_autoadd_bool(arr[4]),); }
+
+@protected HistoryAggregate dco_decode_history_aggregate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return HistoryAggregate(startMs: dco_decode_u_64(arr[0]),
+resolutionMs: dco_decode_u_64(arr[1]),
+reports: dco_decode_u_32(arr[2]),
+connectedReports: dco_decode_u_32(arr[3]),
+degradedReports: dco_decode_u_32(arr[4]),
+avgLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[5]),
+maxLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[6]),
+avgPacketLossPercent: dco_decode_f_32(arr[7]),
+minStabilityScore: dco_decode_u_8(arr[8]),); }
+
+@protected int dco_decode_i_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeI64(raw); }
+
+@protected IcmpUnreachable dco_decode_icmp_unreachable(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return IcmpUnreachable.values[raw as int]; }
+
+@protected LatencyHeatmap dco_decode_latency_heatmap(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return LatencyHeatmap(targets: dco_decode_list_String(arr[0]),
+bucketMs: dco_decode_u_64(arr[1]),
+bucketStartsMs: dco_decode_list_prim_u_64_strict(arr[2]),
+latencyMs: dco_decode_list_list_opt_box_autoadd_u_64(arr[3]),
+failureShare: dco_decode_list_list_opt_box_autoadd_f_32(arr[4]),
+scope: dco_decode_list_degradation_scope(arr[5]),); }
+
+@protected LatencyStats dco_decode_latency_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 13) throw Exception('unexpected arr length: expect 13 but see ${arr.length}');
+                return LatencyStats(latencyMs: dco_decode_u_64(arr[0]),
+jitterMs: dco_decode_u_64(arr[1]),
+packetLossPercent: dco_decode_f_32(arr[2]),
+minLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+avgLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[4]),
+maxLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[5]),
+p50Ms: dco_decode_opt_box_autoadd_u_64(arr[6]),
+p90Ms: dco_decode_opt_box_autoadd_u_64(arr[7]),
+p95Ms: dco_decode_opt_box_autoadd_u_64(arr[8]),
+p99Ms: dco_decode_opt_box_autoadd_u_64(arr[9]),
+stabilityScore: dco_decode_u_8(arr[10]),
+spikeCount: dco_decode_u_32(arr[11]),
+spikeSampleIndices: dco_decode_list_prim_u_32_strict(arr[12]),); }
+
+@protected LayerStatus dco_decode_layer_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return LayerStatus.values[raw as int]; }
+
+@protected LayeredDiagnosis dco_decode_layered_diagnosis(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return LayeredDiagnosis(link: dco_decode_layer_status(arr[0]),
+network: dco_decode_layer_status(arr[1]),
+dns: dco_decode_layer_status(arr[2]),
+transport: dco_decode_layer_status(arr[3]),
+application: dco_decode_layer_status(arr[4]),); }
+
+@protected Limitation dco_decode_limitation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return Limitation.values[raw as int]; }
+
+@protected LinkNeighbor dco_decode_link_neighbor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return LinkNeighbor(protocol: dco_decode_neighbor_protocol(arr[0]),
+chassisId: dco_decode_opt_String(arr[1]),
+systemName: dco_decode_opt_String(arr[2]),
+systemDescription: dco_decode_opt_String(arr[3]),
+platform: dco_decode_opt_String(arr[4]),
+portId: dco_decode_opt_String(arr[5]),
+portDescription: dco_decode_opt_String(arr[6]),
+managementAddress: dco_decode_opt_String(arr[7]),); }
+
+@protected List<NetworkReport> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport).toList(); }
+
+@protected List<String> dco_decode_list_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_String).toList(); }
+
+@protected List<AnomalyDetected> dco_decode_list_anomaly_detected(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_anomaly_detected).toList(); }
+
+@protected List<ArpEntry> dco_decode_list_arp_entry(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_arp_entry).toList(); }
+
+@protected List<CongestionWindow> dco_decode_list_congestion_window(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_congestion_window).toList(); }
+
+@protected List<DegradationScope> dco_decode_list_degradation_scope(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_degradation_scope).toList(); }
+
+@protected List<DeviceEvent> dco_decode_list_device_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_device_event).toList(); }
+
+@protected List<DscpLatency> dco_decode_list_dscp_latency(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_dscp_latency).toList(); }
+
+@protected List<EcnObservation> dco_decode_list_ecn_observation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_ecn_observation).toList(); }
+
+@protected List<HistoryAggregate> dco_decode_list_history_aggregate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_history_aggregate).toList(); }
+
+@protected List<Limitation> dco_decode_list_limitation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_limitation).toList(); }
+
+@protected List<List<double?>> dco_decode_list_list_opt_box_autoadd_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_opt_box_autoadd_f_32).toList(); }
+
+@protected List<List<BigInt?>> dco_decode_list_list_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_list_opt_box_autoadd_u_64).toList(); }
+
+@protected List<LocalDevice> dco_decode_list_local_device(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_local_device).toList(); }
+
+@protected List<MdnsService> dco_decode_list_mdns_service(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_mdns_service).toList(); }
+
+@protected List<MssObservation> dco_decode_list_mss_observation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_mss_observation).toList(); }
+
+@protected List<NetworkTarget> dco_decode_list_network_target(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_network_target).toList(); }
+
+@protected List<String?> dco_decode_list_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_opt_String).toList(); }
+
+@protected List<double?> dco_decode_list_opt_box_autoadd_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_opt_box_autoadd_f_32).toList(); }
+
+@protected List<BigInt?> dco_decode_list_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_opt_box_autoadd_u_64).toList(); }
+
+@protected List<PathMeasurement> dco_decode_list_path_measurement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_path_measurement).toList(); }
+
+@protected List<PortReachability> dco_decode_list_port_reachability(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_port_reachability).toList(); }
+
+@protected Uint16List dco_decode_list_prim_u_16_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint16List; }
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint32List; }
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeUint64List(raw); }
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as List<int>; }
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as Uint8List; }
+
+@protected List<QualityReason> dco_decode_list_quality_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_quality_reason).toList(); }
+
+@protected List<ReflectionObservation> dco_decode_list_reflection_observation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_reflection_observation).toList(); }
+
+@protected List<RouteTrace> dco_decode_list_route_trace(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_route_trace).toList(); }
+
+@protected List<SampleRecord> dco_decode_list_sample_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sample_record).toList(); }
+
+@protected List<SampleTargetLatency> dco_decode_list_sample_target_latency(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_sample_target_latency).toList(); }
+
+@protected List<ServiceInfo> dco_decode_list_service_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_service_info).toList(); }
+
+@protected List<SnmpDeviceInfo> dco_decode_list_snmp_device_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_snmp_device_info).toList(); }
+
+@protected List<SnmpInterface> dco_decode_list_snmp_interface(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_snmp_interface).toList(); }
+
+@protected List<StepOutcome> dco_decode_list_step_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_step_outcome).toList(); }
+
+@protected List<TargetHealth> dco_decode_list_target_health(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_target_health).toList(); }
+
+@protected List<TargetReport> dco_decode_list_target_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_target_report).toList(); }
+
+@protected List<TimeBucket> dco_decode_list_time_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_time_bucket).toList(); }
+
+@protected List<TraceHop> dco_decode_list_trace_hop(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_trace_hop).toList(); }
+
+@protected List<UnmetRequirement> dco_decode_list_unmet_requirement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_unmet_requirement).toList(); }
+
+@protected List<ValidationIssue> dco_decode_list_validation_issue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return (raw as List<dynamic>).map(dco_decode_validation_issue).toList(); }
+
+@protected LocalDevice dco_decode_local_device(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return LocalDevice(ipAddress: dco_decode_String(arr[0]),
+hostname: dco_decode_opt_String(arr[1]),
+macAddress: dco_decode_opt_String(arr[2]),
+macVendor: dco_decode_opt_String(arr[3]),
+latencyMs: dco_decode_u_64(arr[4]),
+openPorts: dco_decode_list_prim_u_16_strict(arr[5]),
+answeredPing: dco_decode_bool(arr[6]),
+services: dco_decode_list_service_info(arr[7]),); }
+
+@protected LocalReachabilityReport dco_decode_local_reachability_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return LocalReachabilityReport(timestampMs: dco_decode_u_64(arr[0]),
+monotonicMs: dco_decode_u_64(arr[1]),
+scope: dco_decode_network_scope(arr[2]),
+gateway: dco_decode_opt_String(arr[3]),
+gatewayReport: dco_decode_opt_box_autoadd_target_report(arr[4]),
+targetReports: dco_decode_list_target_report(arr[5]),); }
+
+@protected LocalScanConfig dco_decode_local_scan_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 15) throw Exception('unexpected arr length: expect 15 but see ${arr.length}');
+                return LocalScanConfig(subnet: dco_decode_String(arr[0]),
+scanPort: dco_decode_u_16(arr[1]),
+scanPorts: dco_decode_list_prim_u_16_strict(arr[2]),
+method: dco_decode_scan_method(arr[3]),
+timeoutMs: dco_decode_u_64(arr[4]),
+maxConcurrency: dco_decode_usize(arr[5]),
+maxHosts: dco_decode_usize(arr[6]),
+allowRanges: dco_decode_list_String(arr[7]),
+excludeRanges: dco_decode_list_String(arr[8]),
+grabBanners: dco_decode_bool(arr[9]),
+bannerTimeoutMs: dco_decode_u_64(arr[10]),
+resolveNames: dco_decode_bool(arr[11]),
+lookupHostnames: dco_decode_bool(arr[12]),
+hostnameLookupTimeoutMs: dco_decode_u_64(arr[13]),
+hostnameLookupConcurrency: dco_decode_usize(arr[14]),); }
+
+@protected MdnsService dco_decode_mdns_service(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return MdnsService(instanceName: dco_decode_String(arr[0]),
+serviceType: dco_decode_String(arr[1]),
+hostname: dco_decode_opt_String(arr[2]),
+port: dco_decode_opt_box_autoadd_u_16(arr[3]),
+ipAddresses: dco_decode_list_String(arr[4]),
+txtRecords: dco_decode_list_String(arr[5]),); }
+
+@protected MonitorState dco_decode_monitor_state(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return MonitorState.values[raw as int]; }
+
+@protected MonitorStats dco_decode_monitor_stats(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return MonitorStats(completedChecks: dco_decode_u_64(arr[0]),
+overruns: dco_decode_u_64(arr[1]),
+skippedCycles: dco_decode_u_64(arr[2]),); }
+
+@protected MssObservation dco_decode_mss_observation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return MssObservation(server: dco_decode_String(arr[0]),
+mss: dco_decode_opt_box_autoadd_u_16(arr[1]),
+pathMtu: dco_decode_opt_box_autoadd_u_16(arr[2]),
+error: dco_decode_opt_String(arr[3]),); }
+
+@protected MssReport dco_decode_mss_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return MssReport(observations: dco_decode_list_mss_observation(arr[0]),
+effectiveMss: dco_decode_opt_box_autoadd_u_16(arr[1]),
+clampingDetected: dco_decode_bool(arr[2]),
+overheadBytes: dco_decode_opt_box_autoadd_u_16(arr[3]),
+encapsulation: dco_decode_encapsulation(arr[4]),); }
+
+@protected MultiRouteTrace dco_decode_multi_route_trace(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return MultiRouteTrace(routes: dco_decode_list_route_trace(arr[0]),
+sharedPrefix: dco_decode_list_trace_hop(arr[1]),
+lanHopCount: dco_decode_u_8(arr[2]),
+problemSegment: dco_decode_opt_box_autoadd_path_segment(arr[3]),); }
+
+@protected MulticastReport dco_decode_multicast_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return MulticastReport(group: dco_decode_String(arr[0]),
+joined: dco_decode_bool(arr[1]),
+loopbackReceived: dco_decode_bool(arr[2]),
+responders: dco_decode_list_String(arr[3]),
+multicastOk: dco_decode_opt_box_autoadd_bool(arr[4]),
+error: dco_decode_opt_String(arr[5]),); }
+
+@protected NatReport dco_decode_nat_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return NatReport(natType: dco_decode_nat_type(arr[0]),
+localAddress: dco_decode_opt_String(arr[1]),
+observations: dco_decode_list_reflection_observation(arr[2]),
+rebindingDetected: dco_decode_bool(arr[3]),
+asymmetricRoutingSuspected: dco_decode_bool(arr[4]),); }
+
+@protected NatType dco_decode_nat_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NatType.values[raw as int]; }
+
+@protected NativeProbe dco_decode_native_probe(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 0) throw Exception('unexpected arr length: expect 0 but see ${arr.length}');
+                return NativeProbe(); }
+
+@protected NeighborProtocol dco_decode_neighbor_protocol(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NeighborProtocol.values[raw as int]; }
+
+@protected NetworkConfiguration dco_decode_network_configuration(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 21) throw Exception('unexpected arr length: expect 21 but see ${arr.length}');
+                return NetworkConfiguration(targets: dco_decode_list_network_target(arr[0]),
+lanTargets: dco_decode_list_network_target(arr[1]),
+ispStatusUrls: dco_decode_list_String(arr[2]),
+checkIntervalMs: dco_decode_u_64(arr[3]),
+checkIntervalJitterPercent: dco_decode_u_8(arr[4]),
+cacheValidityMs: dco_decode_u_64(arr[5]),
+qualityThreshold: dco_decode_quality_thresholds(arr[6]),
+security: dco_decode_security_config(arr[7]),
+resilience: dco_decode_resilience_config(arr[8]),
+rateLimit: dco_decode_rate_limit_config(arr[9]),
+snmp: dco_decode_snmp_config(arr[10]),
+endpoints: dco_decode_endpoint_config(arr[11]),
+includeRawSamples: dco_decode_bool(arr[12]),
+preResolveTargets: dco_decode_bool(arr[13]),
+compareAddressFamilies: dco_decode_bool(arr[14]),
+checkMulticast: dco_decode_bool(arr[15]),
+comparePaths: dco_decode_bool(arr[16]),
+attributeDegradation: dco_decode_bool(arr[17]),
+overallTimeoutMs: dco_decode_u_64(arr[18]),
+fastFailOffline: dco_decode_bool(arr[19]),
+activeInterface: dco_decode_opt_String(arr[20]),); }
+
+@protected NetworkError dco_decode_network_error(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return NetworkError_DnsResolutionError(dco_decode_String(raw[1]),);
+case 1: return NetworkError_ConnectionError(dco_decode_String(raw[1]),);
+case 2: return NetworkError_TimeoutError();
+case 3: return NetworkError_UnknownError(dco_decode_String(raw[1]),);
+case 4: return NetworkError_Unreachable(dco_decode_icmp_unreachable(raw[1]),);
+case 5: return NetworkError_PolicyViolation(dco_decode_box_autoadd_policy_violation(raw[1]),);
+case 6: return NetworkError_InvalidConfiguration(dco_decode_String(raw[1]),);
+case 7: return NetworkError_ProbeFailed(dco_decode_String(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected NetworkScope dco_decode_network_scope(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return NetworkScope.values[raw as int]; }
+
+@protected NetworkStatus dco_decode_network_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return NetworkStatus(isConnected: dco_decode_bool(arr[0]),
+quality: dco_decode_connection_quality(arr[1]),
+latencyStats: dco_decode_latency_stats(arr[2]),
+winnerTarget: dco_decode_String(arr[3]),
+winnersPerSample: dco_decode_list_opt_String(arr[4]),
+reasons: dco_decode_list_quality_reason(arr[5]),); }
+
+@protected NetworkTarget dco_decode_network_target(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 12) throw Exception('unexpected arr length: expect 12 but see ${arr.length}');
+                return NetworkTarget(label: dco_decode_String(arr[0]),
+host: dco_decode_String(arr[1]),
+port: dco_decode_u_16(arr[2]),
+protocol: dco_decode_target_protocol(arr[3]),
+timeoutMs: dco_decode_u_64(arr[4]),
+priority: dco_decode_u_8(arr[5]),
+isEssential: dco_decode_bool(arr[6]),
+weight: dco_decode_u_8(arr[7]),
+udpValidation: dco_decode_udp_validation(arr[8]),
+dnsQueryName: dco_decode_opt_String(arr[9]),
+dscp: dco_decode_opt_box_autoadd_u_8(arr[10]),
+reportTimeoutProgress: dco_decode_bool(arr[11]),); }
+
+@protected OneWayDelayEstimate dco_decode_one_way_delay_estimate(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 8) throw Exception('unexpected arr length: expect 8 but see ${arr.length}');
+                return OneWayDelayEstimate(server: dco_decode_String(arr[0]),
+samples: dco_decode_u_32(arr[1]),
+clockOffsetMs: dco_decode_f_64(arr[2]),
+minRttMs: dco_decode_f_64(arr[3]),
+upstreamMs: dco_decode_f_64(arr[4]),
+downstreamMs: dco_decode_f_64(arr[5]),
+upstreamQueuingMs: dco_decode_f_64(arr[6]),
+downstreamQueuingMs: dco_decode_f_64(arr[7]),); }
+
+@protected String? dco_decode_opt_String(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_String(raw); }
+
+@protected NetworkReport? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(raw); }
+
+@protected AddressFamily? dco_decode_opt_box_autoadd_address_family(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_address_family(raw); }
+
+@protected ApplePathSnapshot? dco_decode_opt_box_autoadd_apple_path_snapshot(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_apple_path_snapshot(raw); }
+
+@protected BetterPathAvailable? dco_decode_opt_box_autoadd_better_path_available(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_better_path_available(raw); }
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_bool(raw); }
+
+@protected CaptivePortalStatus? dco_decode_opt_box_autoadd_captive_portal_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_captive_portal_status(raw); }
+
+@protected ConnectionType? dco_decode_opt_box_autoadd_connection_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_connection_type(raw); }
+
+@protected DegradationAttribution? dco_decode_opt_box_autoadd_degradation_attribution(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_degradation_attribution(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_32(raw); }
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_f_64(raw); }
+
+@protected FamilyComparison? dco_decode_opt_box_autoadd_family_comparison(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_family_comparison(raw); }
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_i_64(raw); }
+
+@protected IcmpUnreachable? dco_decode_opt_box_autoadd_icmp_unreachable(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_icmp_unreachable(raw); }
+
+@protected LinkNeighbor? dco_decode_opt_box_autoadd_link_neighbor(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_link_neighbor(raw); }
+
+@protected PathSegment? dco_decode_opt_box_autoadd_path_segment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_path_segment(raw); }
+
+@protected PlatformHints? dco_decode_opt_box_autoadd_platform_hints(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_platform_hints(raw); }
+
+@protected ProbeFallback? dco_decode_opt_box_autoadd_probe_fallback(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_probe_fallback(raw); }
+
+@protected ProbeTimings? dco_decode_opt_box_autoadd_probe_timings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_probe_timings(raw); }
+
+@protected RoamEvent? dco_decode_opt_box_autoadd_roam_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_roam_event(raw); }
+
+@protected RouteTrace? dco_decode_opt_box_autoadd_route_trace(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_route_trace(raw); }
+
+@protected TargetReport? dco_decode_opt_box_autoadd_target_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_target_report(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_16(raw); }
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_64(raw); }
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_u_8(raw); }
+
+@protected WalledGardenReport? dco_decode_opt_box_autoadd_walled_garden_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw == null ? null : dco_decode_box_autoadd_walled_garden_report(raw); }
+
+@protected OverlapPolicy dco_decode_overlap_policy(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return OverlapPolicy.values[raw as int]; }
+
+@protected PathMeasurement dco_decode_path_measurement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PathMeasurement(interfaceName: dco_decode_String(arr[0]),
+connectionType: dco_decode_connection_type(arr[1]),
+localAddress: dco_decode_String(arr[2]),
+latencyMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+packetLossPercent: dco_decode_f_32(arr[4]),); }
+
+@protected PathSegment dco_decode_path_segment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PathSegment.values[raw as int]; }
+
+@protected PipelineStep dco_decode_pipeline_step(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PipelineStep.values[raw as int]; }
+
+@protected PlatformHints dco_decode_platform_hints(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return PlatformHints(connectionType: dco_decode_opt_box_autoadd_connection_type(arr[0]),
+isMetered: dco_decode_opt_box_autoadd_bool(arr[1]),
+isConstrained: dco_decode_opt_box_autoadd_bool(arr[2]),
+isVpn: dco_decode_opt_box_autoadd_bool(arr[3]),
+ssid: dco_decode_opt_String(arr[4]),
+bssid: dco_decode_opt_String(arr[5]),
+interfaceName: dco_decode_opt_String(arr[6]),); }
+
+@protected PolicyViolation dco_decode_policy_violation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return PolicyViolation_VpnDetected();
+case 1: return PolicyViolation_InterfaceNotAllowed(dco_decode_String(raw[1]),);
+case 2: return PolicyViolation_DnsSpoofed();
+                default: throw Exception("unreachable");
+            } }
+
+@protected PortForwardCheck dco_decode_port_forward_check(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return PortForwardCheck(endpoint: dco_decode_String(arr[0]),
+port: dco_decode_u_16(arr[1]),
+publicAddress: dco_decode_opt_String(arr[2]),
+reachability: dco_decode_service_reachability(arr[3]),
+durationMs: dco_decode_u_64(arr[4]),); }
+
+@protected PortReachability dco_decode_port_reachability(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return PortReachability(port: dco_decode_u_16(arr[0]),
+reachability: dco_decode_service_reachability(arr[1]),); }
+
+@protected PortalLoginKind dco_decode_portal_login_kind(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return PortalLoginKind.values[raw as int]; }
+
+@protected PreflightReport dco_decode_preflight_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return PreflightReport(passed: dco_decode_bool(arr[0]),
+unmet: dco_decode_list_unmet_requirement(arr[1]),
+downMbps: dco_decode_opt_box_autoadd_f_64(arr[2]),
+latencyMs: dco_decode_opt_box_autoadd_u_64(arr[3]),
+packetLossPercent: dco_decode_opt_box_autoadd_f_32(arr[4]),
+durationMs: dco_decode_u_64(arr[5]),); }
+
+@protected ProbeCapabilities dco_decode_probe_capabilities(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return ProbeCapabilities(icmpEcho: dco_decode_bool(arr[0]),
+icmpRaw: dco_decode_bool(arr[1]),
+udpTraceroute: dco_decode_bool(arr[2]),
+arpTable: dco_decode_bool(arr[3]),); }
+
+@protected ProbeFallback dco_decode_probe_fallback(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ProbeFallback.values[raw as int]; }
+
+@protected ProbeTimings dco_decode_probe_timings(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ProbeTimings(budgetMs: dco_decode_u_64(arr[0]),
+elapsedMs: dco_decode_u_64(arr[1]),
+dnsPercent: dco_decode_f_32(arr[2]),
+connectPercent: dco_decode_f_32(arr[3]),
+waitPercent: dco_decode_f_32(arr[4]),
+usedPercent: dco_decode_f_32(arr[5]),); }
+
+@protected QualityReason dco_decode_quality_reason(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return QualityReason.values[raw as int]; }
+
+@protected QualityThresholds dco_decode_quality_thresholds(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return QualityThresholds(excellent: dco_decode_u_64(arr[0]),
+great: dco_decode_u_64(arr[1]),
+good: dco_decode_u_64(arr[2]),
+moderate: dco_decode_u_64(arr[3]),
+poor: dco_decode_u_64(arr[4]),); }
+
+@protected RateLimitConfig dco_decode_rate_limit_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return RateLimitConfig(maxProbesPerSecond: dco_decode_u_32(arr[0]),
+maxBytesPerSecond: dco_decode_u_64(arr[1]),); }
+
+@protected (SecurityFlagsResult,ConnectionType) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(arr[0]),dco_decode_connection_type(arr[1]),); }
+
+@protected (bool,Evidence) dco_decode_record_bool_evidence(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_bool(arr[0]),dco_decode_evidence(arr[1]),); }
+
+@protected (Uint64List,List<TargetReport>) dco_decode_record_list_prim_u_64_strict_list_target_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 2) {
+                throw Exception('Expected 2 elements, got ${arr.length}');
+            }
+            return (dco_decode_list_prim_u_64_strict(arr[0]),dco_decode_list_target_report(arr[1]),); }
+
+@protected (BigInt?,BigInt?,BigInt?,double?) dco_decode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+            if (arr.length != 4) {
+                throw Exception('Expected 4 elements, got ${arr.length}');
+            }
+            return (dco_decode_opt_box_autoadd_u_64(arr[0]),dco_decode_opt_box_autoadd_u_64(arr[1]),dco_decode_opt_box_autoadd_u_64(arr[2]),dco_decode_opt_box_autoadd_f_64(arr[3]),); }
+
+@protected ReflectionObservation dco_decode_reflection_observation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return ReflectionObservation(server: dco_decode_String(arr[0]),
+observedAddress: dco_decode_String(arr[1]),
+observedPort: dco_decode_u_16(arr[2]),
+observedTtl: dco_decode_opt_box_autoadd_u_8(arr[3]),
+forwardHops: dco_decode_opt_box_autoadd_u_8(arr[4]),
+reverseHops: dco_decode_opt_box_autoadd_u_8(arr[5]),); }
+
+@protected Requirements dco_decode_requirements(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 9) throw Exception('unexpected arr length: expect 9 but see ${arr.length}');
+                return Requirements(minDownMbps: dco_decode_opt_box_autoadd_f_64(arr[0]),
+maxLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[1]),
+maxLossPercent: dco_decode_opt_box_autoadd_f_32(arr[2]),
+needsUdp: dco_decode_bool(arr[3]),
+needsWebsocket: dco_decode_bool(arr[4]),
+targets: dco_decode_list_network_target(arr[5]),
+downloadUrl: dco_decode_String(arr[6]),
+udpDnsServer: dco_decode_String(arr[7]),
+websocketUrl: dco_decode_String(arr[8]),); }
+
+@protected ResilienceConfig dco_decode_resilience_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 14) throw Exception('unexpected arr length: expect 14 but see ${arr.length}');
+                return ResilienceConfig(strategy: dco_decode_check_strategy(arr[0]),
+circuitBreakerThreshold: dco_decode_u_8(arr[1]),
+circuitBreakerCooldownMs: dco_decode_u_64(arr[2]),
+numJitterSamples: dco_decode_u_8(arr[3]),
+jitterThresholdPercent: dco_decode_f_64(arr[4]),
+stabilityThershold: dco_decode_u_8(arr[5]),
+criticalPacketLossPrecent: dco_decode_f_32(arr[6]),
+sampleMode: dco_decode_sample_mode(arr[7]),
+parallelSampleOffsetMs: dco_decode_u_64(arr[8]),
+spikeRatioThreshold: dco_decode_f_64(arr[9]),
+spikePenalty: dco_decode_u_8(arr[10]),
+passiveFailureThreshold: dco_decode_u_8(arr[11]),
+passiveSignalWindowMs: dco_decode_u_64(arr[12]),
+overlapPolicy: dco_decode_overlap_policy(arr[13]),); }
+
+@protected RoamEvent dco_decode_roam_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return RoamEvent(timestampMs: dco_decode_u_64(arr[0]),
+ssid: dco_decode_opt_String(arr[1]),
+fromBssid: dco_decode_String(arr[2]),
+toBssid: dco_decode_String(arr[3]),
+latencyBeforeMs: dco_decode_u_64(arr[4]),
+latencyAfterMs: dco_decode_u_64(arr[5]),
+latencySpike: dco_decode_bool(arr[6]),); }
+
+@protected RouteTrace dco_decode_route_trace(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return RouteTrace(host: dco_decode_String(arr[0]),
+hops: dco_decode_list_trace_hop(arr[1]),
+error: dco_decode_opt_String(arr[2]),
+reached: dco_decode_bool(arr[3]),
+problemHop: dco_decode_opt_box_autoadd_u_8(arr[4]),
+problemSegment: dco_decode_opt_box_autoadd_path_segment(arr[5]),
+fallback: dco_decode_opt_box_autoadd_probe_fallback(arr[6]),); }
+
+@protected RuntimeEnvironment dco_decode_runtime_environment(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return RuntimeEnvironment.values[raw as int]; }
+
+@protected SampleMode dco_decode_sample_mode(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return SampleMode_Sequential();
+case 1: return SampleMode_Parallel();
+case 2: return SampleMode_Paced(dco_decode_u_64(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected SampleRecord dco_decode_sample_record(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return SampleRecord(index: dco_decode_u_32(arr[0]),
+timestampMs: dco_decode_u_64(arr[1]),
+monotonicMs: dco_decode_u_64(arr[2]),
+latencies: dco_decode_list_sample_target_latency(arr[3]),
+winnerTarget: dco_decode_opt_String(arr[4]),
+counted: dco_decode_bool(arr[5]),); }
+
+@protected SampleRun dco_decode_sample_run(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return SampleRun(latencies: dco_decode_list_prim_u_64_strict(arr[0]),
+finalReports: dco_decode_list_target_report(arr[1]),
+winners: dco_decode_list_opt_String(arr[2]),
+records: dco_decode_list_sample_record(arr[3]),
+cancelled: dco_decode_bool(arr[4]),
+gatewayLatencies: dco_decode_list_prim_u_64_strict(arr[5]),); }
+
+@protected SampleTargetLatency dco_decode_sample_target_latency(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 2) throw Exception('unexpected arr length: expect 2 but see ${arr.length}');
+                return SampleTargetLatency(label: dco_decode_String(arr[0]),
+latencyMs: dco_decode_opt_box_autoadd_u_64(arr[1]),); }
+
+@protected ScanEvent dco_decode_scan_event(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ScanEvent_Device(dco_decode_box_autoadd_local_device(raw[1]),);
+case 1: return ScanEvent_Progress(dco_decode_box_autoadd_scan_summary(raw[1]),);
+case 2: return ScanEvent_Finished(dco_decode_box_autoadd_scan_summary(raw[1]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected ScanMethod dco_decode_scan_method(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ScanMethod.values[raw as int]; }
+
+@protected ScanSummary dco_decode_scan_summary(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return ScanSummary(hostsScanned: dco_decode_u_32(arr[0]),
+devicesFound: dco_decode_u_32(arr[1]),
+durationMs: dco_decode_u_64(arr[2]),
+cancelled: dco_decode_bool(arr[3]),
+fallback: dco_decode_opt_box_autoadd_probe_fallback(arr[4]),); }
+
+@protected SecurityConfig dco_decode_security_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SecurityConfig(blockVpn: dco_decode_bool(arr[0]),
+detectDnsHijack: dco_decode_bool(arr[1]),
+allowedInterfaces: dco_decode_list_String(arr[2]),); }
+
+@protected SecurityFlags dco_decode_security_flags(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 7) throw Exception('unexpected arr length: expect 7 but see ${arr.length}');
+                return SecurityFlags(isVpnDetected: dco_decode_bool(arr[0]),
+isDnsSpoofed: dco_decode_bool(arr[1]),
+isProxyDetected: dco_decode_bool(arr[2]),
+interfaceName: dco_decode_String(arr[3]),
+vpnEvidence: dco_decode_evidence(arr[4]),
+dnsSpoofEvidence: dco_decode_evidence(arr[5]),
+proxyEvidence: dco_decode_evidence(arr[6]),); }
+
+@protected ServiceInfo dco_decode_service_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return ServiceInfo(port: dco_decode_u_16(arr[0]),
+protocol: dco_decode_opt_String(arr[1]),
+banner: dco_decode_opt_String(arr[2]),); }
+
+@protected ServiceReachability dco_decode_service_reachability(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return ServiceReachability.values[raw as int]; }
+
+@protected SimpleStatus dco_decode_simple_status(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return SimpleStatus.values[raw as int]; }
+
+@protected SnmpConfig dco_decode_snmp_config(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return SnmpConfig(community: dco_decode_String(arr[0]),
+hosts: dco_decode_list_String(arr[1]),
+timeoutMs: dco_decode_u_64(arr[2]),); }
+
+@protected SnmpDeviceInfo dco_decode_snmp_device_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return SnmpDeviceInfo(address: dco_decode_String(arr[0]),
+sysDescr: dco_decode_opt_String(arr[1]),
+interfaces: dco_decode_list_snmp_interface(arr[2]),
+error: dco_decode_opt_String(arr[3]),); }
+
+@protected SnmpInterface dco_decode_snmp_interface(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 6) throw Exception('unexpected arr length: expect 6 but see ${arr.length}');
+                return SnmpInterface(index: dco_decode_u_32(arr[0]),
+description: dco_decode_String(arr[1]),
+inErrors: dco_decode_u_64(arr[2]),
+outErrors: dco_decode_u_64(arr[3]),
+inDiscards: dco_decode_u_64(arr[4]),
+outDiscards: dco_decode_u_64(arr[5]),); }
+
+@protected StepCondition dco_decode_step_condition(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return StepCondition_Always();
+case 1: return StepCondition_Connected();
+case 2: return StepCondition_Offline();
+case 3: return StepCondition_QualityBelow(dco_decode_connection_quality(raw[1]),);
+case 4: return StepCondition_QualityAtLeast(dco_decode_connection_quality(raw[1]),);
+case 5: return StepCondition_CaptivePortalDetected();
+case 6: return StepCondition_CaptivePortalNotDetected();
+case 7: return StepCondition_VpnDetected();
+                default: throw Exception("unreachable");
+            } }
+
+@protected StepOutcome dco_decode_step_outcome(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return StepOutcome(step: dco_decode_pipeline_step(arr[0]),
+condition: dco_decode_step_condition(arr[1]),
+executed: dco_decode_bool(arr[2]),); }
+
+@protected TargetHealth dco_decode_target_health(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return TargetHealth(label: dco_decode_String(arr[0]),
+consecutiveFailures: dco_decode_u_32(arr[1]),
+lastSuccessMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+lastFailureMs: dco_decode_opt_box_autoadd_u_64(arr[3]),); }
+
+@protected TargetProtocol dco_decode_target_protocol(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return TargetProtocol.values[raw as int]; }
+
+@protected TargetReport dco_decode_target_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 16) throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+                return TargetReport(label: dco_decode_String(arr[0]),
+success: dco_decode_bool(arr[1]),
+latencyMs: dco_decode_u_64(arr[2]),
+error: dco_decode_opt_String(arr[3]),
+isEssential: dco_decode_bool(arr[4]),
+dnsMs: dco_decode_opt_box_autoadd_u_64(arr[5]),
+connectMs: dco_decode_opt_box_autoadd_u_64(arr[6]),
+unreachable: dco_decode_opt_box_autoadd_icmp_unreachable(arr[7]),
+timedOutAfterMs: dco_decode_opt_box_autoadd_u_64(arr[8]),
+lastProgressMs: dco_decode_opt_box_autoadd_u_64(arr[9]),
+resolvedAddr: dco_decode_opt_String(arr[10]),
+addressFamily: dco_decode_opt_box_autoadd_address_family(arr[11]),
+skipped: dco_decode_bool(arr[12]),
+timings: dco_decode_opt_box_autoadd_probe_timings(arr[13]),
+samples: dco_decode_list_opt_box_autoadd_u_64(arr[14]),
+fallback: dco_decode_opt_box_autoadd_probe_fallback(arr[15]),); }
+
+@protected TcpInfo dco_decode_tcp_info(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 22) throw Exception('unexpected arr length: expect 22 but see ${arr.length}');
+                return TcpInfo(state: dco_decode_u_8(arr[0]),
+caState: dco_decode_u_8(arr[1]),
+retransmits: dco_decode_u_8(arr[2]),
+probes: dco_decode_u_8(arr[3]),
+backoff: dco_decode_u_8(arr[4]),
+options: dco_decode_u_8(arr[5]),
+wscale: dco_decode_u_8(arr[6]),
+flags: dco_decode_u_8(arr[7]),
+rto: dco_decode_u_32(arr[8]),
+ato: dco_decode_u_32(arr[9]),
+sndMss: dco_decode_u_32(arr[10]),
+rcvMss: dco_decode_u_32(arr[11]),
+unacked: dco_decode_u_32(arr[12]),
+sacked: dco_decode_u_32(arr[13]),
+lost: dco_decode_u_32(arr[14]),
+retrans: dco_decode_u_32(arr[15]),
+fackets: dco_decode_u_32(arr[16]),
+lastDataSent: dco_decode_u_32(arr[17]),
+lastAckSent: dco_decode_u_32(arr[18]),
+lastDataRecv: dco_decode_u_32(arr[19]),
+lastAckRecv: dco_decode_u_32(arr[20]),
+pmtu: dco_decode_u_32(arr[21]),); }
+
+@protected TimeBucket dco_decode_time_bucket(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 5) throw Exception('unexpected arr length: expect 5 but see ${arr.length}');
+                return TimeBucket(index: dco_decode_u_8(arr[0]),
+reports: dco_decode_u_32(arr[1]),
+avgLatencyMs: dco_decode_opt_box_autoadd_u_64(arr[2]),
+avgPacketLossPercent: dco_decode_f_32(arr[3]),
+degradedShare: dco_decode_f_32(arr[4]),); }
+
+@protected TimeOfDayProfile dco_decode_time_of_day_profile(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 3) throw Exception('unexpected arr length: expect 3 but see ${arr.length}');
+                return TimeOfDayProfile(byHour: dco_decode_list_time_bucket(arr[0]),
+byWeekday: dco_decode_list_time_bucket(arr[1]),
+congestionWindows: dco_decode_list_congestion_window(arr[2]),); }
+
+@protected TraceHop dco_decode_trace_hop(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return TraceHop(hopNumber: dco_decode_u_8(arr[0]),
+ipAddress: dco_decode_String(arr[1]),
+hostname: dco_decode_opt_String(arr[2]),
+latencyMs: dco_decode_opt_box_autoadd_u_64(arr[3]),); }
+
+@protected int dco_decode_u_16(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected int dco_decode_u_32(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected BigInt dco_decode_u_64(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected int dco_decode_u_8(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return raw as int; }
+
+@protected UdpValidation dco_decode_udp_validation(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return UdpValidation.values[raw as int]; }
+
+@protected void dco_decode_unit(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return; }
+
+@protected UnmetRequirement dco_decode_unmet_requirement(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return UnmetRequirement_Download(requiredMbps: dco_decode_f_64(raw[1]),measuredMbps: dco_decode_opt_box_autoadd_f_64(raw[2]),);
+case 1: return UnmetRequirement_Latency(maxMs: dco_decode_u_64(raw[1]),measuredMs: dco_decode_opt_box_autoadd_u_64(raw[2]),);
+case 2: return UnmetRequirement_PacketLoss(maxPercent: dco_decode_f_32(raw[1]),measuredPercent: dco_decode_f_32(raw[2]),);
+case 3: return UnmetRequirement_Udp();
+case 4: return UnmetRequirement_WebSocket();
+                default: throw Exception("unreachable");
+            } }
+
+@protected UpnpDescription dco_decode_upnp_description(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return UpnpDescription(friendlyName: dco_decode_opt_String(arr[0]),
+manufacturer: dco_decode_opt_String(arr[1]),
+modelName: dco_decode_opt_String(arr[2]),
+modelNumber: dco_decode_opt_String(arr[3]),); }
+
+@protected BigInt dco_decode_usize(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+return dcoDecodeU64(raw); }
+
+@protected ValidationIssue dco_decode_validation_issue(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+switch (raw[0]) {
+                case 0: return ValidationIssue_EmptyHost(target: dco_decode_usize(raw[1]),);
+case 1: return ValidationIssue_InvalidTimeout(target: dco_decode_usize(raw[1]),timeoutMs: dco_decode_u_64(raw[2]),);
+case 2: return ValidationIssue_ThresholdsNotMonotonic();
+case 3: return ValidationIssue_InvalidQuorum(quorum: dco_decode_u_8(raw[1]),targets: dco_decode_usize(raw[2]),);
+case 4: return ValidationIssue_ZeroTotalWeight();
+case 5: return ValidationIssue_InvalidDnsQueryName(target: dco_decode_usize(raw[1]),);
+case 6: return ValidationIssue_InvalidEndpoint(field: dco_decode_String(raw[1]),value: dco_decode_String(raw[2]),);
+                default: throw Exception("unreachable");
+            } }
+
+@protected VpnComparison dco_decode_vpn_comparison(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 16) throw Exception('unexpected arr length: expect 16 but see ${arr.length}');
+                return VpnComparison(vpnBefore: dco_decode_bool(arr[0]),
+vpnAfter: dco_decode_bool(arr[1]),
+toggleObserved: dco_decode_bool(arr[2]),
+latencyDeltaMs: dco_decode_i_64(arr[3]),
+jitterDeltaMs: dco_decode_i_64(arr[4]),
+packetLossDeltaPercent: dco_decode_f_32(arr[5]),
+interfaceBefore: dco_decode_String(arr[6]),
+interfaceAfter: dco_decode_String(arr[7]),
+connectionTypeBefore: dco_decode_connection_type(arr[8]),
+connectionTypeAfter: dco_decode_connection_type(arr[9]),
+routeChanged: dco_decode_bool(arr[10]),
+resolversBefore: dco_decode_list_String(arr[11]),
+resolversAfter: dco_decode_list_String(arr[12]),
+dnsResolverChanged: dco_decode_bool(arr[13]),
+dnsLeakSuspected: dco_decode_bool(arr[14]),
+routeLeakSuspected: dco_decode_bool(arr[15]),); }
+
+@protected WalledGardenReport dco_decode_walled_garden_report(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return WalledGardenReport(systemDns: dco_decode_bool(arr[0]),
+publicDns: dco_decode_service_reachability(arr[1]),
+https: dco_decode_service_reachability(arr[2]),
+ports: dco_decode_list_port_reachability(arr[3]),); }
+
+@protected WebProbe dco_decode_web_probe(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 0) throw Exception('unexpected arr length: expect 0 but see ${arr.length}');
+                return WebProbe(); }
+
+@protected WeightedStabilityModel dco_decode_weighted_stability_model(dynamic raw){ // Codec=Dco (DartCObject based), see doc to use other codecs
+final arr = raw as List<dynamic>;
+                if (arr.length != 4) throw Exception('unexpected arr length: expect 4 but see ${arr.length}');
+                return WeightedStabilityModel(p95Weight: dco_decode_f_64(arr[0]),
+lossWeight: dco_decode_f_64(arr[1]),
+meanWeight: dco_decode_f_64(arr[2]),
+jitterWeight: dco_decode_f_64(arr[3]),); }
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_String(deserializer);
+        return AnyhowException(inner); }
+
+@protected AnomalyDetector sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AnomalyDetectorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CheckPipeline sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CheckPipelineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CircuitBreaker sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CircuitBreakerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected DeviceInventory sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return DeviceInventoryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected NetworkMonitor sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NetworkMonitorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected NetworkReport sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NetworkReportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PipelineReport sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PipelineReportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ReachabilityEngine sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ReachabilityEngineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SecurityFlagsResult sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SecurityFlagsResultImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected AnomalyDetector sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AnomalyDetectorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CircuitBreaker sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CircuitBreakerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected DeviceInventory sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return DeviceInventoryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected NetworkReport sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NetworkReportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PipelineReport sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PipelineReportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SecurityFlagsResult sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SecurityFlagsResultImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected AnomalyDetector sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AnomalyDetectorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CheckPipeline sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CheckPipelineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CircuitBreaker sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CircuitBreakerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected NetworkMonitor sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NetworkMonitorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected NetworkReport sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NetworkReportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PipelineReport sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PipelineReportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ReachabilityEngine sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ReachabilityEngineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SecurityFlagsResult sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SecurityFlagsResultImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected StabilityInputs sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return StabilityInputsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected AnomalyDetector sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return AnomalyDetectorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CheckPipeline sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CheckPipelineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected CircuitBreaker sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return CircuitBreakerImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected DeviceInventory sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return DeviceInventoryImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected NetworkMonitor sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NetworkMonitorImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected NetworkReport sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NetworkReportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected PipelineReport sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return PipelineReportImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected ReachabilityEngine sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return ReachabilityEngineImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected SecurityFlagsResult sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return SecurityFlagsResultImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected StabilityInputs sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return StabilityInputsImpl.frbInternalSseDecode(sse_decode_usize(deserializer), sse_decode_i_32(deserializer)); }
+
+@protected RustStreamSink<NetworkReport> sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected RustStreamSink<ScanEvent> sse_decode_StreamSink_scan_event_Sse(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+throw UnimplementedError('Unreachable ()'); }
+
+@protected String sse_decode_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_list_prim_u_8_strict(deserializer);
+        return utf8.decoder.convert(inner); }
+
+@protected AddressFamily sse_decode_address_family(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AddressFamily.values[inner]; }
+
+@protected AnomalyDetected sse_decode_anomaly_detected(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_metric = sse_decode_anomaly_metric(deserializer);
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_value = sse_decode_f_64(deserializer);
+var var_expected = sse_decode_f_64(deserializer);
+var var_stdDev = sse_decode_f_64(deserializer);
+var var_zScore = sse_decode_f_64(deserializer);
+var var_connectionType = sse_decode_connection_type(deserializer);
+return AnomalyDetected(metric: var_metric, timestampMs: var_timestampMs, value: var_value, expected: var_expected, stdDev: var_stdDev, zScore: var_zScore, connectionType: var_connectionType); }
+
+@protected AnomalyMetric sse_decode_anomaly_metric(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return AnomalyMetric.values[inner]; }
+
+@protected ApplePathInterfaceType sse_decode_apple_path_interface_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ApplePathInterfaceType.values[inner]; }
+
+@protected ApplePathSnapshot sse_decode_apple_path_snapshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_status = sse_decode_apple_path_status(deserializer);
+var var_interfaceType = sse_decode_apple_path_interface_type(deserializer);
+var var_interfaceName = sse_decode_String(deserializer);
+var var_isExpensive = sse_decode_bool(deserializer);
+var var_isConstrained = sse_decode_bool(deserializer);
+var var_usesVpn = sse_decode_bool(deserializer);
+return ApplePathSnapshot(status: var_status, interfaceType: var_interfaceType, interfaceName: var_interfaceName, isExpensive: var_isExpensive, isConstrained: var_isConstrained, usesVpn: var_usesVpn); }
+
+@protected ApplePathStatus sse_decode_apple_path_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ApplePathStatus.values[inner]; }
+
+@protected ArpEntry sse_decode_arp_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_ipAddress = sse_decode_String(deserializer);
+var var_macAddress = sse_decode_String(deserializer);
+var var_interface_ = sse_decode_opt_String(deserializer);
+return ArpEntry(ipAddress: var_ipAddress, macAddress: var_macAddress, interface_: var_interface_); }
+
+@protected BetterPathAvailable sse_decode_better_path_available(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_current = sse_decode_path_measurement(deserializer);
+var var_better = sse_decode_path_measurement(deserializer);
+var var_latencyGainMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+return BetterPathAvailable(timestampMs: var_timestampMs, current: var_current, better: var_better, latencyGainMs: var_latencyGainMs); }
+
+@protected bool sse_decode_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8() != 0; }
+
+@protected NetworkReport sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(deserializer)); }
+
+@protected AddressFamily sse_decode_box_autoadd_address_family(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_address_family(deserializer)); }
+
+@protected ApplePathSnapshot sse_decode_box_autoadd_apple_path_snapshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_apple_path_snapshot(deserializer)); }
+
+@protected BetterPathAvailable sse_decode_box_autoadd_better_path_available(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_better_path_available(deserializer)); }
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_bool(deserializer)); }
+
+@protected CaptivePortalStatus sse_decode_box_autoadd_captive_portal_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_captive_portal_status(deserializer)); }
+
+@protected CheckStrategy sse_decode_box_autoadd_check_strategy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_check_strategy(deserializer)); }
+
+@protected ConnectionType sse_decode_box_autoadd_connection_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_connection_type(deserializer)); }
+
+@protected DegradationAttribution sse_decode_box_autoadd_degradation_attribution(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_degradation_attribution(deserializer)); }
+
+@protected EndpointConfig sse_decode_box_autoadd_endpoint_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_endpoint_config(deserializer)); }
+
+@protected EngineSnapshot sse_decode_box_autoadd_engine_snapshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_engine_snapshot(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_32(deserializer)); }
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_f_64(deserializer)); }
+
+@protected FamilyComparison sse_decode_box_autoadd_family_comparison(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_family_comparison(deserializer)); }
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_i_64(deserializer)); }
+
+@protected IcmpUnreachable sse_decode_box_autoadd_icmp_unreachable(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_icmp_unreachable(deserializer)); }
+
+@protected LatencyStats sse_decode_box_autoadd_latency_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_latency_stats(deserializer)); }
+
+@protected LinkNeighbor sse_decode_box_autoadd_link_neighbor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_link_neighbor(deserializer)); }
+
+@protected LocalDevice sse_decode_box_autoadd_local_device(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_local_device(deserializer)); }
+
+@protected LocalScanConfig sse_decode_box_autoadd_local_scan_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_local_scan_config(deserializer)); }
+
+@protected NativeProbe sse_decode_box_autoadd_native_probe(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_native_probe(deserializer)); }
+
+@protected NetworkConfiguration sse_decode_box_autoadd_network_configuration(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_network_configuration(deserializer)); }
+
+@protected NetworkError sse_decode_box_autoadd_network_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_network_error(deserializer)); }
+
+@protected NetworkTarget sse_decode_box_autoadd_network_target(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_network_target(deserializer)); }
+
+@protected PathSegment sse_decode_box_autoadd_path_segment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_path_segment(deserializer)); }
+
+@protected PlatformHints sse_decode_box_autoadd_platform_hints(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_platform_hints(deserializer)); }
+
+@protected PolicyViolation sse_decode_box_autoadd_policy_violation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_policy_violation(deserializer)); }
+
+@protected ProbeFallback sse_decode_box_autoadd_probe_fallback(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_probe_fallback(deserializer)); }
+
+@protected ProbeTimings sse_decode_box_autoadd_probe_timings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_probe_timings(deserializer)); }
+
+@protected QualityThresholds sse_decode_box_autoadd_quality_thresholds(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_quality_thresholds(deserializer)); }
+
+@protected Requirements sse_decode_box_autoadd_requirements(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_requirements(deserializer)); }
+
+@protected ResilienceConfig sse_decode_box_autoadd_resilience_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_resilience_config(deserializer)); }
+
+@protected RoamEvent sse_decode_box_autoadd_roam_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_roam_event(deserializer)); }
+
+@protected RouteTrace sse_decode_box_autoadd_route_trace(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_route_trace(deserializer)); }
+
+@protected ScanSummary sse_decode_box_autoadd_scan_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_scan_summary(deserializer)); }
+
+@protected SecurityConfig sse_decode_box_autoadd_security_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_security_config(deserializer)); }
+
+@protected StepCondition sse_decode_box_autoadd_step_condition(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_step_condition(deserializer)); }
+
+@protected TargetReport sse_decode_box_autoadd_target_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_target_report(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_16(deserializer)); }
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_64(deserializer)); }
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_u_8(deserializer)); }
+
+@protected UnmetRequirement sse_decode_box_autoadd_unmet_requirement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_unmet_requirement(deserializer)); }
+
+@protected ValidationIssue sse_decode_box_autoadd_validation_issue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_validation_issue(deserializer)); }
+
+@protected WalledGardenReport sse_decode_box_autoadd_walled_garden_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_walled_garden_report(deserializer)); }
+
+@protected WebProbe sse_decode_box_autoadd_web_probe(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_web_probe(deserializer)); }
+
+@protected WeightedStabilityModel sse_decode_box_autoadd_weighted_stability_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return (sse_decode_weighted_stability_model(deserializer)); }
+
+@protected CaptivePortalStatus sse_decode_captive_portal_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_isCaptivePortal = sse_decode_bool(deserializer);
+var var_redirectUrl = sse_decode_opt_String(deserializer);
+var var_evidence = sse_decode_evidence(deserializer);
+var var_pageTitle = sse_decode_opt_String(deserializer);
+var var_loginKind = sse_decode_portal_login_kind(deserializer);
+var var_wispr = sse_decode_bool(deserializer);
+return CaptivePortalStatus(isCaptivePortal: var_isCaptivePortal, redirectUrl: var_redirectUrl, evidence: var_evidence, pageTitle: var_pageTitle, loginKind: var_loginKind, wispr: var_wispr); }
+
+@protected CheckStrategy sse_decode_check_strategy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return CheckStrategy_Race();case 1: return CheckStrategy_Consensus();case 2: return CheckStrategy_Fallback();case 3: return CheckStrategy_All();case 4: var var_field0 = sse_decode_u_8(deserializer);
+return CheckStrategy_Quorum(var_field0);case 5: return CheckStrategy_Weighted(); default: throw UnimplementedError(''); }
+             }
+
+@protected CircuitBreakerState sse_decode_circuit_breaker_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return CircuitBreakerState.values[inner]; }
+
+@protected Confidence sse_decode_confidence(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Confidence.values[inner]; }
+
+@protected CongestionWindow sse_decode_congestion_window(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startHour = sse_decode_u_8(deserializer);
+var var_endHour = sse_decode_u_8(deserializer);
+var var_avgLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_baselineLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_degradedShare = sse_decode_f_32(deserializer);
+var var_daysObserved = sse_decode_u_32(deserializer);
+return CongestionWindow(startHour: var_startHour, endHour: var_endHour, avgLatencyMs: var_avgLatencyMs, baselineLatencyMs: var_baselineLatencyMs, degradedShare: var_degradedShare, daysObserved: var_daysObserved); }
+
+@protected ConnectionQuality sse_decode_connection_quality(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConnectionQuality.values[inner]; }
+
+@protected ConnectionType sse_decode_connection_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ConnectionType.values[inner]; }
+
+@protected DegradationAttribution sse_decode_degradation_attribution(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_gatewayLatencyMs = sse_decode_u_64(deserializer);
+var var_gatewayJitterMs = sse_decode_u_64(deserializer);
+var var_gatewayLossPercent = sse_decode_f_32(deserializer);
+var var_latencyShare = sse_decode_opt_box_autoadd_f_32(deserializer);
+var var_varianceShare = sse_decode_opt_box_autoadd_f_32(deserializer);
+var var_lossShare = sse_decode_opt_box_autoadd_f_32(deserializer);
+var var_dominantSegment = sse_decode_opt_box_autoadd_path_segment(deserializer);
+return DegradationAttribution(gatewayLatencyMs: var_gatewayLatencyMs, gatewayJitterMs: var_gatewayJitterMs, gatewayLossPercent: var_gatewayLossPercent, latencyShare: var_latencyShare, varianceShare: var_varianceShare, lossShare: var_lossShare, dominantSegment: var_dominantSegment); }
+
+@protected DegradationScope sse_decode_degradation_scope(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return DegradationScope.values[inner]; }
+
+@protected DeviceEvent sse_decode_device_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_box_autoadd_local_device(deserializer);
+return DeviceEvent_Joined(var_field0);case 1: var var_field0 = sse_decode_box_autoadd_local_device(deserializer);
+return DeviceEvent_Left(var_field0);case 2: var var_macAddress = sse_decode_String(deserializer);
+var var_oldIp = sse_decode_String(deserializer);
+var var_newIp = sse_decode_String(deserializer);
+return DeviceEvent_IpChanged(macAddress: var_macAddress, oldIp: var_oldIp, newIp: var_newIp); default: throw UnimplementedError(''); }
+             }
+
+@protected DscpLatency sse_decode_dscp_latency(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_dscp = sse_decode_u_8(deserializer);
+var var_samples = sse_decode_u_32(deserializer);
+var var_successes = sse_decode_u_32(deserializer);
+var var_medianLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_lossPercent = sse_decode_f_32(deserializer);
+var var_deltaMs = sse_decode_opt_box_autoadd_i_64(deserializer);
+return DscpLatency(dscp: var_dscp, samples: var_samples, successes: var_successes, medianLatencyMs: var_medianLatencyMs, lossPercent: var_lossPercent, deltaMs: var_deltaMs); }
+
+@protected EcnMarking sse_decode_ecn_marking(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return EcnMarking.values[inner]; }
+
+@protected EcnObservation sse_decode_ecn_observation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_server = sse_decode_String(deserializer);
+var var_negotiated = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return EcnObservation(server: var_server, negotiated: var_negotiated, error: var_error); }
+
+@protected EcnReport sse_decode_ecn_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_osRequestsEcn = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_tcp = sse_decode_list_ecn_observation(deserializer);
+var var_udpMarking = sse_decode_ecn_marking(deserializer);
+var var_ecnCapable = sse_decode_bool(deserializer);
+return EcnReport(osRequestsEcn: var_osRequestsEcn, tcp: var_tcp, udpMarking: var_udpMarking, ecnCapable: var_ecnCapable); }
+
+@protected Encapsulation sse_decode_encapsulation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Encapsulation.values[inner]; }
+
+@protected EndpointConfig sse_decode_endpoint_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_captivePortalUrl = sse_decode_String(deserializer);
+var var_walledGardenUrl = sse_decode_String(deserializer);
+var var_walledGardenPortHost = sse_decode_String(deserializer);
+var var_publicDnsServer = sse_decode_String(deserializer);
+var var_trustedDnsServers = sse_decode_list_String(deserializer);
+var var_tcpReferenceServers = sse_decode_list_String(deserializer);
+return EndpointConfig(captivePortalUrl: var_captivePortalUrl, walledGardenUrl: var_walledGardenUrl, walledGardenPortHost: var_walledGardenPortHost, publicDnsServer: var_publicDnsServer, trustedDnsServers: var_trustedDnsServers, tcpReferenceServers: var_tcpReferenceServers); }
+
+@protected EngineSnapshot sse_decode_engine_snapshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_takenAtMs = sse_decode_u_64(deserializer);
+var var_lastReport = sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(deserializer);
+var var_breakerConsecutiveFailures = sse_decode_u_8(deserializer);
+var var_breakerOpenedAtMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_targetHealth = sse_decode_list_target_health(deserializer);
+return EngineSnapshot(takenAtMs: var_takenAtMs, lastReport: var_lastReport, breakerConsecutiveFailures: var_breakerConsecutiveFailures, breakerOpenedAtMs: var_breakerOpenedAtMs, targetHealth: var_targetHealth); }
+
+@protected EnvironmentReport sse_decode_environment_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_crateVersion = sse_decode_String(deserializer);
+var var_os = sse_decode_String(deserializer);
+var var_arch = sse_decode_String(deserializer);
+var var_runtime = sse_decode_runtime_environment(deserializer);
+var var_capabilities = sse_decode_probe_capabilities(deserializer);
+var var_hasIpv4 = sse_decode_bool(deserializer);
+var var_hasIpv6 = sse_decode_bool(deserializer);
+var var_features = sse_decode_list_String(deserializer);
+var var_limitations = sse_decode_list_limitation(deserializer);
+return EnvironmentReport(crateVersion: var_crateVersion, os: var_os, arch: var_arch, runtime: var_runtime, capabilities: var_capabilities, hasIpv4: var_hasIpv4, hasIpv6: var_hasIpv6, features: var_features, limitations: var_limitations); }
+
+@protected Evidence sse_decode_evidence(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_confidence = sse_decode_confidence(deserializer);
+var var_source = sse_decode_evidence_source(deserializer);
+return Evidence(confidence: var_confidence, source: var_source); }
+
+@protected EvidenceSource sse_decode_evidence_source(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return EvidenceSource.values[inner]; }
+
+@protected double sse_decode_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat32(); }
+
+@protected double sse_decode_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getFloat64(); }
+
+@protected FamilyComparison sse_decode_family_comparison(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_targets = sse_decode_list_String(deserializer);
+var var_ipv4 = sse_decode_family_stats(deserializer);
+var var_ipv6 = sse_decode_family_stats(deserializer);
+var var_preferredFamily = sse_decode_opt_box_autoadd_address_family(deserializer);
+return FamilyComparison(targets: var_targets, ipv4: var_ipv4, ipv6: var_ipv6, preferredFamily: var_preferredFamily); }
+
+@protected FamilyStats sse_decode_family_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_family = sse_decode_address_family(deserializer);
+var var_probes = sse_decode_u_32(deserializer);
+var var_latencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_packetLossPercent = sse_decode_f_32(deserializer);
+return FamilyStats(family: var_family, probes: var_probes, latencyMs: var_latencyMs, packetLossPercent: var_packetLossPercent); }
+
+@protected GatewayFingerprint sse_decode_gateway_fingerprint(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_ipAddress = sse_decode_String(deserializer);
+var var_macAddress = sse_decode_opt_String(deserializer);
+var var_macVendor = sse_decode_opt_String(deserializer);
+var var_httpTitle = sse_decode_opt_String(deserializer);
+var var_httpServer = sse_decode_opt_String(deserializer);
+var var_upnpFriendlyName = sse_decode_opt_String(deserializer);
+var var_upnpManufacturer = sse_decode_opt_String(deserializer);
+var var_upnpModelName = sse_decode_opt_String(deserializer);
+var var_upnpModelNumber = sse_decode_opt_String(deserializer);
+var var_vendor = sse_decode_opt_String(deserializer);
+var var_model = sse_decode_opt_String(deserializer);
+return GatewayFingerprint(ipAddress: var_ipAddress, macAddress: var_macAddress, macVendor: var_macVendor, httpTitle: var_httpTitle, httpServer: var_httpServer, upnpFriendlyName: var_upnpFriendlyName, upnpManufacturer: var_upnpManufacturer, upnpModelName: var_upnpModelName, upnpModelNumber: var_upnpModelNumber, vendor: var_vendor, model: var_model); }
+
+@protected HairpinReport sse_decode_hairpin_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_publicAddress = sse_decode_opt_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_fromLan = sse_decode_service_reachability(deserializer);
+var var_fromInternet = sse_decode_service_reachability(deserializer);
+var var_hairpinSupported = sse_decode_opt_box_autoadd_bool(deserializer);
+return HairpinReport(publicAddress: var_publicAddress, port: var_port, fromLan: var_fromLan, fromInternet: var_fromInternet, hairpinSupported: var_hairpinSupported); }
+
+@protected HistoryAggregate sse_decode_history_aggregate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_startMs = sse_decode_u_64(deserializer);
+var var_resolutionMs = sse_decode_u_64(deserializer);
+var var_reports = sse_decode_u_32(deserializer);
+var var_connectedReports = sse_decode_u_32(deserializer);
+var var_degradedReports = sse_decode_u_32(deserializer);
+var var_avgLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_maxLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_avgPacketLossPercent = sse_decode_f_32(deserializer);
+var var_minStabilityScore = sse_decode_u_8(deserializer);
+return HistoryAggregate(startMs: var_startMs, resolutionMs: var_resolutionMs, reports: var_reports, connectedReports: var_connectedReports, degradedReports: var_degradedReports, avgLatencyMs: var_avgLatencyMs, maxLatencyMs: var_maxLatencyMs, avgPacketLossPercent: var_avgPacketLossPercent, minStabilityScore: var_minStabilityScore); }
+
+@protected int sse_decode_i_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getInt32(); }
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getPlatformInt64(); }
+
+@protected IcmpUnreachable sse_decode_icmp_unreachable(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return IcmpUnreachable.values[inner]; }
+
+@protected LatencyHeatmap sse_decode_latency_heatmap(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_targets = sse_decode_list_String(deserializer);
+var var_bucketMs = sse_decode_u_64(deserializer);
+var var_bucketStartsMs = sse_decode_list_prim_u_64_strict(deserializer);
+var var_latencyMs = sse_decode_list_list_opt_box_autoadd_u_64(deserializer);
+var var_failureShare = sse_decode_list_list_opt_box_autoadd_f_32(deserializer);
+var var_scope = sse_decode_list_degradation_scope(deserializer);
+return LatencyHeatmap(targets: var_targets, bucketMs: var_bucketMs, bucketStartsMs: var_bucketStartsMs, latencyMs: var_latencyMs, failureShare: var_failureShare, scope: var_scope); }
+
+@protected LatencyStats sse_decode_latency_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_latencyMs = sse_decode_u_64(deserializer);
+var var_jitterMs = sse_decode_u_64(deserializer);
+var var_packetLossPercent = sse_decode_f_32(deserializer);
+var var_minLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_avgLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_maxLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_p50Ms = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_p90Ms = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_p95Ms = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_p99Ms = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_stabilityScore = sse_decode_u_8(deserializer);
+var var_spikeCount = sse_decode_u_32(deserializer);
+var var_spikeSampleIndices = sse_decode_list_prim_u_32_strict(deserializer);
+return LatencyStats(latencyMs: var_latencyMs, jitterMs: var_jitterMs, packetLossPercent: var_packetLossPercent, minLatencyMs: var_minLatencyMs, avgLatencyMs: var_avgLatencyMs, maxLatencyMs: var_maxLatencyMs, p50Ms: var_p50Ms, p90Ms: var_p90Ms, p95Ms: var_p95Ms, p99Ms: var_p99Ms, stabilityScore: var_stabilityScore, spikeCount: var_spikeCount, spikeSampleIndices: var_spikeSampleIndices); }
+
+@protected LayerStatus sse_decode_layer_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return LayerStatus.values[inner]; }
+
+@protected LayeredDiagnosis sse_decode_layered_diagnosis(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_link = sse_decode_layer_status(deserializer);
+var var_network = sse_decode_layer_status(deserializer);
+var var_dns = sse_decode_layer_status(deserializer);
+var var_transport = sse_decode_layer_status(deserializer);
+var var_application = sse_decode_layer_status(deserializer);
+return LayeredDiagnosis(link: var_link, network: var_network, dns: var_dns, transport: var_transport, application: var_application); }
+
+@protected Limitation sse_decode_limitation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return Limitation.values[inner]; }
+
+@protected LinkNeighbor sse_decode_link_neighbor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_protocol = sse_decode_neighbor_protocol(deserializer);
+var var_chassisId = sse_decode_opt_String(deserializer);
+var var_systemName = sse_decode_opt_String(deserializer);
+var var_systemDescription = sse_decode_opt_String(deserializer);
+var var_platform = sse_decode_opt_String(deserializer);
+var var_portId = sse_decode_opt_String(deserializer);
+var var_portDescription = sse_decode_opt_String(deserializer);
+var var_managementAddress = sse_decode_opt_String(deserializer);
+return LinkNeighbor(protocol: var_protocol, chassisId: var_chassisId, systemName: var_systemName, systemDescription: var_systemDescription, platform: var_platform, portId: var_portId, portDescription: var_portDescription, managementAddress: var_managementAddress); }
+
+@protected List<NetworkReport> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NetworkReport>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(deserializer)); }
+        return ans_;
+         }
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<AnomalyDetected> sse_decode_list_anomaly_detected(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <AnomalyDetected>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_anomaly_detected(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ArpEntry> sse_decode_list_arp_entry(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ArpEntry>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_arp_entry(deserializer)); }
+        return ans_;
+         }
+
+@protected List<CongestionWindow> sse_decode_list_congestion_window(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <CongestionWindow>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_congestion_window(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DegradationScope> sse_decode_list_degradation_scope(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DegradationScope>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_degradation_scope(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DeviceEvent> sse_decode_list_device_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DeviceEvent>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_device_event(deserializer)); }
+        return ans_;
+         }
+
+@protected List<DscpLatency> sse_decode_list_dscp_latency(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <DscpLatency>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_dscp_latency(deserializer)); }
+        return ans_;
+         }
+
+@protected List<EcnObservation> sse_decode_list_ecn_observation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <EcnObservation>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_ecn_observation(deserializer)); }
+        return ans_;
+         }
+
+@protected List<HistoryAggregate> sse_decode_list_history_aggregate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <HistoryAggregate>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_history_aggregate(deserializer)); }
+        return ans_;
+         }
+
+@protected List<Limitation> sse_decode_list_limitation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <Limitation>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_limitation(deserializer)); }
+        return ans_;
+         }
+
+@protected List<List<double?>> sse_decode_list_list_opt_box_autoadd_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <List<double?>>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_opt_box_autoadd_f_32(deserializer)); }
+        return ans_;
+         }
+
+@protected List<List<BigInt?>> sse_decode_list_list_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <List<BigInt?>>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_list_opt_box_autoadd_u_64(deserializer)); }
+        return ans_;
+         }
+
+@protected List<LocalDevice> sse_decode_list_local_device(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <LocalDevice>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_local_device(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MdnsService> sse_decode_list_mdns_service(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MdnsService>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_mdns_service(deserializer)); }
+        return ans_;
+         }
+
+@protected List<MssObservation> sse_decode_list_mss_observation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <MssObservation>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_mss_observation(deserializer)); }
+        return ans_;
+         }
+
+@protected List<NetworkTarget> sse_decode_list_network_target(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <NetworkTarget>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_network_target(deserializer)); }
+        return ans_;
+         }
+
+@protected List<String?> sse_decode_list_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <String?>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_opt_String(deserializer)); }
+        return ans_;
+         }
+
+@protected List<double?> sse_decode_list_opt_box_autoadd_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <double?>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_opt_box_autoadd_f_32(deserializer)); }
+        return ans_;
+         }
+
+@protected List<BigInt?> sse_decode_list_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <BigInt?>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_opt_box_autoadd_u_64(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PathMeasurement> sse_decode_list_path_measurement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PathMeasurement>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_path_measurement(deserializer)); }
+        return ans_;
+         }
+
+@protected List<PortReachability> sse_decode_list_port_reachability(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <PortReachability>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_port_reachability(deserializer)); }
+        return ans_;
+         }
+
+@protected Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint16List(len_); }
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint32List(len_); }
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint64List(len_); }
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var len_ = sse_decode_i_32(deserializer);
+                return deserializer.buffer.getUint8List(len_); }
+
+@protected List<QualityReason> sse_decode_list_quality_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <QualityReason>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_quality_reason(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ReflectionObservation> sse_decode_list_reflection_observation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ReflectionObservation>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_reflection_observation(deserializer)); }
+        return ans_;
+         }
+
+@protected List<RouteTrace> sse_decode_list_route_trace(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <RouteTrace>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_route_trace(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SampleRecord> sse_decode_list_sample_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SampleRecord>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sample_record(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SampleTargetLatency> sse_decode_list_sample_target_latency(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SampleTargetLatency>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_sample_target_latency(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ServiceInfo> sse_decode_list_service_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ServiceInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_service_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SnmpDeviceInfo> sse_decode_list_snmp_device_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SnmpDeviceInfo>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_snmp_device_info(deserializer)); }
+        return ans_;
+         }
+
+@protected List<SnmpInterface> sse_decode_list_snmp_interface(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <SnmpInterface>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_snmp_interface(deserializer)); }
+        return ans_;
+         }
+
+@protected List<StepOutcome> sse_decode_list_step_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <StepOutcome>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_step_outcome(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TargetHealth> sse_decode_list_target_health(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TargetHealth>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_target_health(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TargetReport> sse_decode_list_target_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TargetReport>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_target_report(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TimeBucket> sse_decode_list_time_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TimeBucket>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_time_bucket(deserializer)); }
+        return ans_;
+         }
+
+@protected List<TraceHop> sse_decode_list_trace_hop(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <TraceHop>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_trace_hop(deserializer)); }
+        return ans_;
+         }
+
+@protected List<UnmetRequirement> sse_decode_list_unmet_requirement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <UnmetRequirement>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_unmet_requirement(deserializer)); }
+        return ans_;
+         }
+
+@protected List<ValidationIssue> sse_decode_list_validation_issue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+        var len_ = sse_decode_i_32(deserializer);
+        var ans_ = <ValidationIssue>[];
+        for (var idx_ = 0; idx_ < len_; ++idx_) { ans_.add(sse_decode_validation_issue(deserializer)); }
+        return ans_;
+         }
+
+@protected LocalDevice sse_decode_local_device(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_ipAddress = sse_decode_String(deserializer);
+var var_hostname = sse_decode_opt_String(deserializer);
+var var_macAddress = sse_decode_opt_String(deserializer);
+var var_macVendor = sse_decode_opt_String(deserializer);
+var var_latencyMs = sse_decode_u_64(deserializer);
+var var_openPorts = sse_decode_list_prim_u_16_strict(deserializer);
+var var_answeredPing = sse_decode_bool(deserializer);
+var var_services = sse_decode_list_service_info(deserializer);
+return LocalDevice(ipAddress: var_ipAddress, hostname: var_hostname, macAddress: var_macAddress, macVendor: var_macVendor, latencyMs: var_latencyMs, openPorts: var_openPorts, answeredPing: var_answeredPing, services: var_services); }
+
+@protected LocalReachabilityReport sse_decode_local_reachability_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_monotonicMs = sse_decode_u_64(deserializer);
+var var_scope = sse_decode_network_scope(deserializer);
+var var_gateway = sse_decode_opt_String(deserializer);
+var var_gatewayReport = sse_decode_opt_box_autoadd_target_report(deserializer);
+var var_targetReports = sse_decode_list_target_report(deserializer);
+return LocalReachabilityReport(timestampMs: var_timestampMs, monotonicMs: var_monotonicMs, scope: var_scope, gateway: var_gateway, gatewayReport: var_gatewayReport, targetReports: var_targetReports); }
+
+@protected LocalScanConfig sse_decode_local_scan_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_subnet = sse_decode_String(deserializer);
+var var_scanPort = sse_decode_u_16(deserializer);
+var var_scanPorts = sse_decode_list_prim_u_16_strict(deserializer);
+var var_method = sse_decode_scan_method(deserializer);
+var var_timeoutMs = sse_decode_u_64(deserializer);
+var var_maxConcurrency = sse_decode_usize(deserializer);
+var var_maxHosts = sse_decode_usize(deserializer);
+var var_allowRanges = sse_decode_list_String(deserializer);
+var var_excludeRanges = sse_decode_list_String(deserializer);
+var var_grabBanners = sse_decode_bool(deserializer);
+var var_bannerTimeoutMs = sse_decode_u_64(deserializer);
+var var_resolveNames = sse_decode_bool(deserializer);
+var var_lookupHostnames = sse_decode_bool(deserializer);
+var var_hostnameLookupTimeoutMs = sse_decode_u_64(deserializer);
+var var_hostnameLookupConcurrency = sse_decode_usize(deserializer);
+return LocalScanConfig(subnet: var_subnet, scanPort: var_scanPort, scanPorts: var_scanPorts, method: var_method, timeoutMs: var_timeoutMs, maxConcurrency: var_maxConcurrency, maxHosts: var_maxHosts, allowRanges: var_allowRanges, excludeRanges: var_excludeRanges, grabBanners: var_grabBanners, bannerTimeoutMs: var_bannerTimeoutMs, resolveNames: var_resolveNames, lookupHostnames: var_lookupHostnames, hostnameLookupTimeoutMs: var_hostnameLookupTimeoutMs, hostnameLookupConcurrency: var_hostnameLookupConcurrency); }
+
+@protected MdnsService sse_decode_mdns_service(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_instanceName = sse_decode_String(deserializer);
+var var_serviceType = sse_decode_String(deserializer);
+var var_hostname = sse_decode_opt_String(deserializer);
+var var_port = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_ipAddresses = sse_decode_list_String(deserializer);
+var var_txtRecords = sse_decode_list_String(deserializer);
+return MdnsService(instanceName: var_instanceName, serviceType: var_serviceType, hostname: var_hostname, port: var_port, ipAddresses: var_ipAddresses, txtRecords: var_txtRecords); }
+
+@protected MonitorState sse_decode_monitor_state(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return MonitorState.values[inner]; }
+
+@protected MonitorStats sse_decode_monitor_stats(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_completedChecks = sse_decode_u_64(deserializer);
+var var_overruns = sse_decode_u_64(deserializer);
+var var_skippedCycles = sse_decode_u_64(deserializer);
+return MonitorStats(completedChecks: var_completedChecks, overruns: var_overruns, skippedCycles: var_skippedCycles); }
+
+@protected MssObservation sse_decode_mss_observation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_server = sse_decode_String(deserializer);
+var var_mss = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_pathMtu = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return MssObservation(server: var_server, mss: var_mss, pathMtu: var_pathMtu, error: var_error); }
+
+@protected MssReport sse_decode_mss_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_observations = sse_decode_list_mss_observation(deserializer);
+var var_effectiveMss = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_clampingDetected = sse_decode_bool(deserializer);
+var var_overheadBytes = sse_decode_opt_box_autoadd_u_16(deserializer);
+var var_encapsulation = sse_decode_encapsulation(deserializer);
+return MssReport(observations: var_observations, effectiveMss: var_effectiveMss, clampingDetected: var_clampingDetected, overheadBytes: var_overheadBytes, encapsulation: var_encapsulation); }
+
+@protected MultiRouteTrace sse_decode_multi_route_trace(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_routes = sse_decode_list_route_trace(deserializer);
+var var_sharedPrefix = sse_decode_list_trace_hop(deserializer);
+var var_lanHopCount = sse_decode_u_8(deserializer);
+var var_problemSegment = sse_decode_opt_box_autoadd_path_segment(deserializer);
+return MultiRouteTrace(routes: var_routes, sharedPrefix: var_sharedPrefix, lanHopCount: var_lanHopCount, problemSegment: var_problemSegment); }
+
+@protected MulticastReport sse_decode_multicast_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_group = sse_decode_String(deserializer);
+var var_joined = sse_decode_bool(deserializer);
+var var_loopbackReceived = sse_decode_bool(deserializer);
+var var_responders = sse_decode_list_String(deserializer);
+var var_multicastOk = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return MulticastReport(group: var_group, joined: var_joined, loopbackReceived: var_loopbackReceived, responders: var_responders, multicastOk: var_multicastOk, error: var_error); }
+
+@protected NatReport sse_decode_nat_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_natType = sse_decode_nat_type(deserializer);
+var var_localAddress = sse_decode_opt_String(deserializer);
+var var_observations = sse_decode_list_reflection_observation(deserializer);
+var var_rebindingDetected = sse_decode_bool(deserializer);
+var var_asymmetricRoutingSuspected = sse_decode_bool(deserializer);
+return NatReport(natType: var_natType, localAddress: var_localAddress, observations: var_observations, rebindingDetected: var_rebindingDetected, asymmetricRoutingSuspected: var_asymmetricRoutingSuspected); }
+
+@protected NatType sse_decode_nat_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return NatType.values[inner]; }
+
+@protected NativeProbe sse_decode_native_probe(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return NativeProbe(); }
+
+@protected NeighborProtocol sse_decode_neighbor_protocol(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return NeighborProtocol.values[inner]; }
+
+@protected NetworkConfiguration sse_decode_network_configuration(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_targets = sse_decode_list_network_target(deserializer);
+var var_lanTargets = sse_decode_list_network_target(deserializer);
+var var_ispStatusUrls = sse_decode_list_String(deserializer);
+var var_checkIntervalMs = sse_decode_u_64(deserializer);
+var var_checkIntervalJitterPercent = sse_decode_u_8(deserializer);
+var var_cacheValidityMs = sse_decode_u_64(deserializer);
+var var_qualityThreshold = sse_decode_quality_thresholds(deserializer);
+var var_security = sse_decode_security_config(deserializer);
+var var_resilience = sse_decode_resilience_config(deserializer);
+var var_rateLimit = sse_decode_rate_limit_config(deserializer);
+var var_snmp = sse_decode_snmp_config(deserializer);
+var var_endpoints = sse_decode_endpoint_config(deserializer);
+var var_includeRawSamples = sse_decode_bool(deserializer);
+var var_preResolveTargets = sse_decode_bool(deserializer);
+var var_compareAddressFamilies = sse_decode_bool(deserializer);
+var var_checkMulticast = sse_decode_bool(deserializer);
+var var_comparePaths = sse_decode_bool(deserializer);
+var var_attributeDegradation = sse_decode_bool(deserializer);
+var var_overallTimeoutMs = sse_decode_u_64(deserializer);
+var var_fastFailOffline = sse_decode_bool(deserializer);
+var var_activeInterface = sse_decode_opt_String(deserializer);
+return NetworkConfiguration(targets: var_targets, lanTargets: var_lanTargets, ispStatusUrls: var_ispStatusUrls, checkIntervalMs: var_checkIntervalMs, checkIntervalJitterPercent: var_checkIntervalJitterPercent, cacheValidityMs: var_cacheValidityMs, qualityThreshold: var_qualityThreshold, security: var_security, resilience: var_resilience, rateLimit: var_rateLimit, snmp: var_snmp, endpoints: var_endpoints, includeRawSamples: var_includeRawSamples, preResolveTargets: var_preResolveTargets, compareAddressFamilies: var_compareAddressFamilies, checkMulticast: var_checkMulticast, comparePaths: var_comparePaths, attributeDegradation: var_attributeDegradation, overallTimeoutMs: var_overallTimeoutMs, fastFailOffline: var_fastFailOffline, activeInterface: var_activeInterface); }
+
+@protected NetworkError sse_decode_network_error(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_String(deserializer);
+return NetworkError_DnsResolutionError(var_field0);case 1: var var_field0 = sse_decode_String(deserializer);
+return NetworkError_ConnectionError(var_field0);case 2: return NetworkError_TimeoutError();case 3: var var_field0 = sse_decode_String(deserializer);
+return NetworkError_UnknownError(var_field0);case 4: var var_field0 = sse_decode_icmp_unreachable(deserializer);
+return NetworkError_Unreachable(var_field0);case 5: var var_field0 = sse_decode_box_autoadd_policy_violation(deserializer);
+return NetworkError_PolicyViolation(var_field0);case 6: var var_field0 = sse_decode_String(deserializer);
+return NetworkError_InvalidConfiguration(var_field0);case 7: var var_field0 = sse_decode_String(deserializer);
+return NetworkError_ProbeFailed(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected NetworkScope sse_decode_network_scope(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return NetworkScope.values[inner]; }
+
+@protected NetworkStatus sse_decode_network_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_isConnected = sse_decode_bool(deserializer);
+var var_quality = sse_decode_connection_quality(deserializer);
+var var_latencyStats = sse_decode_latency_stats(deserializer);
+var var_winnerTarget = sse_decode_String(deserializer);
+var var_winnersPerSample = sse_decode_list_opt_String(deserializer);
+var var_reasons = sse_decode_list_quality_reason(deserializer);
+return NetworkStatus(isConnected: var_isConnected, quality: var_quality, latencyStats: var_latencyStats, winnerTarget: var_winnerTarget, winnersPerSample: var_winnersPerSample, reasons: var_reasons); }
+
+@protected NetworkTarget sse_decode_network_target(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_label = sse_decode_String(deserializer);
+var var_host = sse_decode_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_protocol = sse_decode_target_protocol(deserializer);
+var var_timeoutMs = sse_decode_u_64(deserializer);
+var var_priority = sse_decode_u_8(deserializer);
+var var_isEssential = sse_decode_bool(deserializer);
+var var_weight = sse_decode_u_8(deserializer);
+var var_udpValidation = sse_decode_udp_validation(deserializer);
+var var_dnsQueryName = sse_decode_opt_String(deserializer);
+var var_dscp = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_reportTimeoutProgress = sse_decode_bool(deserializer);
+return NetworkTarget(label: var_label, host: var_host, port: var_port, protocol: var_protocol, timeoutMs: var_timeoutMs, priority: var_priority, isEssential: var_isEssential, weight: var_weight, udpValidation: var_udpValidation, dnsQueryName: var_dnsQueryName, dscp: var_dscp, reportTimeoutProgress: var_reportTimeoutProgress); }
+
+@protected OneWayDelayEstimate sse_decode_one_way_delay_estimate(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_server = sse_decode_String(deserializer);
+var var_samples = sse_decode_u_32(deserializer);
+var var_clockOffsetMs = sse_decode_f_64(deserializer);
+var var_minRttMs = sse_decode_f_64(deserializer);
+var var_upstreamMs = sse_decode_f_64(deserializer);
+var var_downstreamMs = sse_decode_f_64(deserializer);
+var var_upstreamQueuingMs = sse_decode_f_64(deserializer);
+var var_downstreamQueuingMs = sse_decode_f_64(deserializer);
+return OneWayDelayEstimate(server: var_server, samples: var_samples, clockOffsetMs: var_clockOffsetMs, minRttMs: var_minRttMs, upstreamMs: var_upstreamMs, downstreamMs: var_downstreamMs, upstreamQueuingMs: var_upstreamQueuingMs, downstreamQueuingMs: var_downstreamQueuingMs); }
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_String(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected NetworkReport? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected AddressFamily? sse_decode_opt_box_autoadd_address_family(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_address_family(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ApplePathSnapshot? sse_decode_opt_box_autoadd_apple_path_snapshot(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_apple_path_snapshot(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BetterPathAvailable? sse_decode_opt_box_autoadd_better_path_available(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_better_path_available(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_bool(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected CaptivePortalStatus? sse_decode_opt_box_autoadd_captive_portal_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_captive_portal_status(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ConnectionType? sse_decode_opt_box_autoadd_connection_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_connection_type(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected DegradationAttribution? sse_decode_opt_box_autoadd_degradation_attribution(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_degradation_attribution(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_32(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_f_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected FamilyComparison? sse_decode_opt_box_autoadd_family_comparison(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_family_comparison(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_i_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected IcmpUnreachable? sse_decode_opt_box_autoadd_icmp_unreachable(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_icmp_unreachable(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected LinkNeighbor? sse_decode_opt_box_autoadd_link_neighbor(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_link_neighbor(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PathSegment? sse_decode_opt_box_autoadd_path_segment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_path_segment(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected PlatformHints? sse_decode_opt_box_autoadd_platform_hints(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_platform_hints(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ProbeFallback? sse_decode_opt_box_autoadd_probe_fallback(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_probe_fallback(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected ProbeTimings? sse_decode_opt_box_autoadd_probe_timings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_probe_timings(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RoamEvent? sse_decode_opt_box_autoadd_roam_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_roam_event(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected RouteTrace? sse_decode_opt_box_autoadd_route_trace(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_route_trace(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected TargetReport? sse_decode_opt_box_autoadd_target_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_target_report(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_16(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_64(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_u_8(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected WalledGardenReport? sse_decode_opt_box_autoadd_walled_garden_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            if (sse_decode_bool(deserializer)) {
+                return (sse_decode_box_autoadd_walled_garden_report(deserializer));
+            } else {
+                return null;
+            }
+             }
+
+@protected OverlapPolicy sse_decode_overlap_policy(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return OverlapPolicy.values[inner]; }
+
+@protected PathMeasurement sse_decode_path_measurement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_interfaceName = sse_decode_String(deserializer);
+var var_connectionType = sse_decode_connection_type(deserializer);
+var var_localAddress = sse_decode_String(deserializer);
+var var_latencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_packetLossPercent = sse_decode_f_32(deserializer);
+return PathMeasurement(interfaceName: var_interfaceName, connectionType: var_connectionType, localAddress: var_localAddress, latencyMs: var_latencyMs, packetLossPercent: var_packetLossPercent); }
+
+@protected PathSegment sse_decode_path_segment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PathSegment.values[inner]; }
+
+@protected PipelineStep sse_decode_pipeline_step(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PipelineStep.values[inner]; }
+
+@protected PlatformHints sse_decode_platform_hints(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_connectionType = sse_decode_opt_box_autoadd_connection_type(deserializer);
+var var_isMetered = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_isConstrained = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_isVpn = sse_decode_opt_box_autoadd_bool(deserializer);
+var var_ssid = sse_decode_opt_String(deserializer);
+var var_bssid = sse_decode_opt_String(deserializer);
+var var_interfaceName = sse_decode_opt_String(deserializer);
+return PlatformHints(connectionType: var_connectionType, isMetered: var_isMetered, isConstrained: var_isConstrained, isVpn: var_isVpn, ssid: var_ssid, bssid: var_bssid, interfaceName: var_interfaceName); }
+
+@protected PolicyViolation sse_decode_policy_violation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return PolicyViolation_VpnDetected();case 1: var var_field0 = sse_decode_String(deserializer);
+return PolicyViolation_InterfaceNotAllowed(var_field0);case 2: return PolicyViolation_DnsSpoofed(); default: throw UnimplementedError(''); }
+             }
+
+@protected PortForwardCheck sse_decode_port_forward_check(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_endpoint = sse_decode_String(deserializer);
+var var_port = sse_decode_u_16(deserializer);
+var var_publicAddress = sse_decode_opt_String(deserializer);
+var var_reachability = sse_decode_service_reachability(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+return PortForwardCheck(endpoint: var_endpoint, port: var_port, publicAddress: var_publicAddress, reachability: var_reachability, durationMs: var_durationMs); }
+
+@protected PortReachability sse_decode_port_reachability(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_port = sse_decode_u_16(deserializer);
+var var_reachability = sse_decode_service_reachability(deserializer);
+return PortReachability(port: var_port, reachability: var_reachability); }
+
+@protected PortalLoginKind sse_decode_portal_login_kind(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return PortalLoginKind.values[inner]; }
+
+@protected PreflightReport sse_decode_preflight_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_passed = sse_decode_bool(deserializer);
+var var_unmet = sse_decode_list_unmet_requirement(deserializer);
+var var_downMbps = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_latencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_packetLossPercent = sse_decode_opt_box_autoadd_f_32(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+return PreflightReport(passed: var_passed, unmet: var_unmet, downMbps: var_downMbps, latencyMs: var_latencyMs, packetLossPercent: var_packetLossPercent, durationMs: var_durationMs); }
+
+@protected ProbeCapabilities sse_decode_probe_capabilities(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_icmpEcho = sse_decode_bool(deserializer);
+var var_icmpRaw = sse_decode_bool(deserializer);
+var var_udpTraceroute = sse_decode_bool(deserializer);
+var var_arpTable = sse_decode_bool(deserializer);
+return ProbeCapabilities(icmpEcho: var_icmpEcho, icmpRaw: var_icmpRaw, udpTraceroute: var_udpTraceroute, arpTable: var_arpTable); }
+
+@protected ProbeFallback sse_decode_probe_fallback(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ProbeFallback.values[inner]; }
+
+@protected ProbeTimings sse_decode_probe_timings(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_budgetMs = sse_decode_u_64(deserializer);
+var var_elapsedMs = sse_decode_u_64(deserializer);
+var var_dnsPercent = sse_decode_f_32(deserializer);
+var var_connectPercent = sse_decode_f_32(deserializer);
+var var_waitPercent = sse_decode_f_32(deserializer);
+var var_usedPercent = sse_decode_f_32(deserializer);
+return ProbeTimings(budgetMs: var_budgetMs, elapsedMs: var_elapsedMs, dnsPercent: var_dnsPercent, connectPercent: var_connectPercent, waitPercent: var_waitPercent, usedPercent: var_usedPercent); }
+
+@protected QualityReason sse_decode_quality_reason(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return QualityReason.values[inner]; }
+
+@protected QualityThresholds sse_decode_quality_thresholds(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_excellent = sse_decode_u_64(deserializer);
+var var_great = sse_decode_u_64(deserializer);
+var var_good = sse_decode_u_64(deserializer);
+var var_moderate = sse_decode_u_64(deserializer);
+var var_poor = sse_decode_u_64(deserializer);
+return QualityThresholds(excellent: var_excellent, great: var_great, good: var_good, moderate: var_moderate, poor: var_poor); }
+
+@protected RateLimitConfig sse_decode_rate_limit_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_maxProbesPerSecond = sse_decode_u_32(deserializer);
+var var_maxBytesPerSecond = sse_decode_u_64(deserializer);
+return RateLimitConfig(maxProbesPerSecond: var_maxProbesPerSecond, maxBytesPerSecond: var_maxBytesPerSecond); }
+
+@protected (SecurityFlagsResult,ConnectionType) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(deserializer);
+var var_field1 = sse_decode_connection_type(deserializer);
+return (var_field0, var_field1); }
+
+@protected (bool,Evidence) sse_decode_record_bool_evidence(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_bool(deserializer);
+var var_field1 = sse_decode_evidence(deserializer);
+return (var_field0, var_field1); }
+
+@protected (Uint64List,List<TargetReport>) sse_decode_record_list_prim_u_64_strict_list_target_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_list_prim_u_64_strict(deserializer);
+var var_field1 = sse_decode_list_target_report(deserializer);
+return (var_field0, var_field1); }
+
+@protected (BigInt?,BigInt?,BigInt?,double?) sse_decode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_field0 = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_field1 = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_field2 = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_field3 = sse_decode_opt_box_autoadd_f_64(deserializer);
+return (var_field0, var_field1, var_field2, var_field3); }
+
+@protected ReflectionObservation sse_decode_reflection_observation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_server = sse_decode_String(deserializer);
+var var_observedAddress = sse_decode_String(deserializer);
+var var_observedPort = sse_decode_u_16(deserializer);
+var var_observedTtl = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_forwardHops = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_reverseHops = sse_decode_opt_box_autoadd_u_8(deserializer);
+return ReflectionObservation(server: var_server, observedAddress: var_observedAddress, observedPort: var_observedPort, observedTtl: var_observedTtl, forwardHops: var_forwardHops, reverseHops: var_reverseHops); }
+
+@protected Requirements sse_decode_requirements(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_minDownMbps = sse_decode_opt_box_autoadd_f_64(deserializer);
+var var_maxLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_maxLossPercent = sse_decode_opt_box_autoadd_f_32(deserializer);
+var var_needsUdp = sse_decode_bool(deserializer);
+var var_needsWebsocket = sse_decode_bool(deserializer);
+var var_targets = sse_decode_list_network_target(deserializer);
+var var_downloadUrl = sse_decode_String(deserializer);
+var var_udpDnsServer = sse_decode_String(deserializer);
+var var_websocketUrl = sse_decode_String(deserializer);
+return Requirements(minDownMbps: var_minDownMbps, maxLatencyMs: var_maxLatencyMs, maxLossPercent: var_maxLossPercent, needsUdp: var_needsUdp, needsWebsocket: var_needsWebsocket, targets: var_targets, downloadUrl: var_downloadUrl, udpDnsServer: var_udpDnsServer, websocketUrl: var_websocketUrl); }
+
+@protected ResilienceConfig sse_decode_resilience_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_strategy = sse_decode_check_strategy(deserializer);
+var var_circuitBreakerThreshold = sse_decode_u_8(deserializer);
+var var_circuitBreakerCooldownMs = sse_decode_u_64(deserializer);
+var var_numJitterSamples = sse_decode_u_8(deserializer);
+var var_jitterThresholdPercent = sse_decode_f_64(deserializer);
+var var_stabilityThershold = sse_decode_u_8(deserializer);
+var var_criticalPacketLossPrecent = sse_decode_f_32(deserializer);
+var var_sampleMode = sse_decode_sample_mode(deserializer);
+var var_parallelSampleOffsetMs = sse_decode_u_64(deserializer);
+var var_spikeRatioThreshold = sse_decode_f_64(deserializer);
+var var_spikePenalty = sse_decode_u_8(deserializer);
+var var_passiveFailureThreshold = sse_decode_u_8(deserializer);
+var var_passiveSignalWindowMs = sse_decode_u_64(deserializer);
+var var_overlapPolicy = sse_decode_overlap_policy(deserializer);
+return ResilienceConfig(strategy: var_strategy, circuitBreakerThreshold: var_circuitBreakerThreshold, circuitBreakerCooldownMs: var_circuitBreakerCooldownMs, numJitterSamples: var_numJitterSamples, jitterThresholdPercent: var_jitterThresholdPercent, stabilityThershold: var_stabilityThershold, criticalPacketLossPrecent: var_criticalPacketLossPrecent, sampleMode: var_sampleMode, parallelSampleOffsetMs: var_parallelSampleOffsetMs, spikeRatioThreshold: var_spikeRatioThreshold, spikePenalty: var_spikePenalty, passiveFailureThreshold: var_passiveFailureThreshold, passiveSignalWindowMs: var_passiveSignalWindowMs, overlapPolicy: var_overlapPolicy); }
+
+@protected RoamEvent sse_decode_roam_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_ssid = sse_decode_opt_String(deserializer);
+var var_fromBssid = sse_decode_String(deserializer);
+var var_toBssid = sse_decode_String(deserializer);
+var var_latencyBeforeMs = sse_decode_u_64(deserializer);
+var var_latencyAfterMs = sse_decode_u_64(deserializer);
+var var_latencySpike = sse_decode_bool(deserializer);
+return RoamEvent(timestampMs: var_timestampMs, ssid: var_ssid, fromBssid: var_fromBssid, toBssid: var_toBssid, latencyBeforeMs: var_latencyBeforeMs, latencyAfterMs: var_latencyAfterMs, latencySpike: var_latencySpike); }
+
+@protected RouteTrace sse_decode_route_trace(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_host = sse_decode_String(deserializer);
+var var_hops = sse_decode_list_trace_hop(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+var var_reached = sse_decode_bool(deserializer);
+var var_problemHop = sse_decode_opt_box_autoadd_u_8(deserializer);
+var var_problemSegment = sse_decode_opt_box_autoadd_path_segment(deserializer);
+var var_fallback = sse_decode_opt_box_autoadd_probe_fallback(deserializer);
+return RouteTrace(host: var_host, hops: var_hops, error: var_error, reached: var_reached, problemHop: var_problemHop, problemSegment: var_problemSegment, fallback: var_fallback); }
+
+@protected RuntimeEnvironment sse_decode_runtime_environment(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return RuntimeEnvironment.values[inner]; }
+
+@protected SampleMode sse_decode_sample_mode(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return SampleMode_Sequential();case 1: return SampleMode_Parallel();case 2: var var_field0 = sse_decode_u_64(deserializer);
+return SampleMode_Paced(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected SampleRecord sse_decode_sample_record(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_timestampMs = sse_decode_u_64(deserializer);
+var var_monotonicMs = sse_decode_u_64(deserializer);
+var var_latencies = sse_decode_list_sample_target_latency(deserializer);
+var var_winnerTarget = sse_decode_opt_String(deserializer);
+var var_counted = sse_decode_bool(deserializer);
+return SampleRecord(index: var_index, timestampMs: var_timestampMs, monotonicMs: var_monotonicMs, latencies: var_latencies, winnerTarget: var_winnerTarget, counted: var_counted); }
+
+@protected SampleRun sse_decode_sample_run(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_latencies = sse_decode_list_prim_u_64_strict(deserializer);
+var var_finalReports = sse_decode_list_target_report(deserializer);
+var var_winners = sse_decode_list_opt_String(deserializer);
+var var_records = sse_decode_list_sample_record(deserializer);
+var var_cancelled = sse_decode_bool(deserializer);
+var var_gatewayLatencies = sse_decode_list_prim_u_64_strict(deserializer);
+return SampleRun(latencies: var_latencies, finalReports: var_finalReports, winners: var_winners, records: var_records, cancelled: var_cancelled, gatewayLatencies: var_gatewayLatencies); }
+
+@protected SampleTargetLatency sse_decode_sample_target_latency(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_label = sse_decode_String(deserializer);
+var var_latencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return SampleTargetLatency(label: var_label, latencyMs: var_latencyMs); }
+
+@protected ScanEvent sse_decode_scan_event(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_field0 = sse_decode_box_autoadd_local_device(deserializer);
+return ScanEvent_Device(var_field0);case 1: var var_field0 = sse_decode_box_autoadd_scan_summary(deserializer);
+return ScanEvent_Progress(var_field0);case 2: var var_field0 = sse_decode_box_autoadd_scan_summary(deserializer);
+return ScanEvent_Finished(var_field0); default: throw UnimplementedError(''); }
+             }
+
+@protected ScanMethod sse_decode_scan_method(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ScanMethod.values[inner]; }
+
+@protected ScanSummary sse_decode_scan_summary(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hostsScanned = sse_decode_u_32(deserializer);
+var var_devicesFound = sse_decode_u_32(deserializer);
+var var_durationMs = sse_decode_u_64(deserializer);
+var var_cancelled = sse_decode_bool(deserializer);
+var var_fallback = sse_decode_opt_box_autoadd_probe_fallback(deserializer);
+return ScanSummary(hostsScanned: var_hostsScanned, devicesFound: var_devicesFound, durationMs: var_durationMs, cancelled: var_cancelled, fallback: var_fallback); }
+
+@protected SecurityConfig sse_decode_security_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_blockVpn = sse_decode_bool(deserializer);
+var var_detectDnsHijack = sse_decode_bool(deserializer);
+var var_allowedInterfaces = sse_decode_list_String(deserializer);
+return SecurityConfig(blockVpn: var_blockVpn, detectDnsHijack: var_detectDnsHijack, allowedInterfaces: var_allowedInterfaces); }
+
+@protected SecurityFlags sse_decode_security_flags(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_isVpnDetected = sse_decode_bool(deserializer);
+var var_isDnsSpoofed = sse_decode_bool(deserializer);
+var var_isProxyDetected = sse_decode_bool(deserializer);
+var var_interfaceName = sse_decode_String(deserializer);
+var var_vpnEvidence = sse_decode_evidence(deserializer);
+var var_dnsSpoofEvidence = sse_decode_evidence(deserializer);
+var var_proxyEvidence = sse_decode_evidence(deserializer);
+return SecurityFlags(isVpnDetected: var_isVpnDetected, isDnsSpoofed: var_isDnsSpoofed, isProxyDetected: var_isProxyDetected, interfaceName: var_interfaceName, vpnEvidence: var_vpnEvidence, dnsSpoofEvidence: var_dnsSpoofEvidence, proxyEvidence: var_proxyEvidence); }
+
+@protected ServiceInfo sse_decode_service_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_port = sse_decode_u_16(deserializer);
+var var_protocol = sse_decode_opt_String(deserializer);
+var var_banner = sse_decode_opt_String(deserializer);
+return ServiceInfo(port: var_port, protocol: var_protocol, banner: var_banner); }
+
+@protected ServiceReachability sse_decode_service_reachability(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return ServiceReachability.values[inner]; }
+
+@protected SimpleStatus sse_decode_simple_status(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return SimpleStatus.values[inner]; }
+
+@protected SnmpConfig sse_decode_snmp_config(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_community = sse_decode_String(deserializer);
+var var_hosts = sse_decode_list_String(deserializer);
+var var_timeoutMs = sse_decode_u_64(deserializer);
+return SnmpConfig(community: var_community, hosts: var_hosts, timeoutMs: var_timeoutMs); }
+
+@protected SnmpDeviceInfo sse_decode_snmp_device_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_address = sse_decode_String(deserializer);
+var var_sysDescr = sse_decode_opt_String(deserializer);
+var var_interfaces = sse_decode_list_snmp_interface(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+return SnmpDeviceInfo(address: var_address, sysDescr: var_sysDescr, interfaces: var_interfaces, error: var_error); }
+
+@protected SnmpInterface sse_decode_snmp_interface(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_32(deserializer);
+var var_description = sse_decode_String(deserializer);
+var var_inErrors = sse_decode_u_64(deserializer);
+var var_outErrors = sse_decode_u_64(deserializer);
+var var_inDiscards = sse_decode_u_64(deserializer);
+var var_outDiscards = sse_decode_u_64(deserializer);
+return SnmpInterface(index: var_index, description: var_description, inErrors: var_inErrors, outErrors: var_outErrors, inDiscards: var_inDiscards, outDiscards: var_outDiscards); }
+
+@protected StepCondition sse_decode_step_condition(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: return StepCondition_Always();case 1: return StepCondition_Connected();case 2: return StepCondition_Offline();case 3: var var_field0 = sse_decode_connection_quality(deserializer);
+return StepCondition_QualityBelow(var_field0);case 4: var var_field0 = sse_decode_connection_quality(deserializer);
+return StepCondition_QualityAtLeast(var_field0);case 5: return StepCondition_CaptivePortalDetected();case 6: return StepCondition_CaptivePortalNotDetected();case 7: return StepCondition_VpnDetected(); default: throw UnimplementedError(''); }
+             }
+
+@protected StepOutcome sse_decode_step_outcome(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_step = sse_decode_pipeline_step(deserializer);
+var var_condition = sse_decode_step_condition(deserializer);
+var var_executed = sse_decode_bool(deserializer);
+return StepOutcome(step: var_step, condition: var_condition, executed: var_executed); }
+
+@protected TargetHealth sse_decode_target_health(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_label = sse_decode_String(deserializer);
+var var_consecutiveFailures = sse_decode_u_32(deserializer);
+var var_lastSuccessMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_lastFailureMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return TargetHealth(label: var_label, consecutiveFailures: var_consecutiveFailures, lastSuccessMs: var_lastSuccessMs, lastFailureMs: var_lastFailureMs); }
+
+@protected TargetProtocol sse_decode_target_protocol(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return TargetProtocol.values[inner]; }
+
+@protected TargetReport sse_decode_target_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_label = sse_decode_String(deserializer);
+var var_success = sse_decode_bool(deserializer);
+var var_latencyMs = sse_decode_u_64(deserializer);
+var var_error = sse_decode_opt_String(deserializer);
+var var_isEssential = sse_decode_bool(deserializer);
+var var_dnsMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_connectMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_unreachable = sse_decode_opt_box_autoadd_icmp_unreachable(deserializer);
+var var_timedOutAfterMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_lastProgressMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_resolvedAddr = sse_decode_opt_String(deserializer);
+var var_addressFamily = sse_decode_opt_box_autoadd_address_family(deserializer);
+var var_skipped = sse_decode_bool(deserializer);
+var var_timings = sse_decode_opt_box_autoadd_probe_timings(deserializer);
+var var_samples = sse_decode_list_opt_box_autoadd_u_64(deserializer);
+var var_fallback = sse_decode_opt_box_autoadd_probe_fallback(deserializer);
+return TargetReport(label: var_label, success: var_success, latencyMs: var_latencyMs, error: var_error, isEssential: var_isEssential, dnsMs: var_dnsMs, connectMs: var_connectMs, unreachable: var_unreachable, timedOutAfterMs: var_timedOutAfterMs, lastProgressMs: var_lastProgressMs, resolvedAddr: var_resolvedAddr, addressFamily: var_addressFamily, skipped: var_skipped, timings: var_timings, samples: var_samples, fallback: var_fallback); }
+
+@protected TcpInfo sse_decode_tcp_info(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_state = sse_decode_u_8(deserializer);
+var var_caState = sse_decode_u_8(deserializer);
+var var_retransmits = sse_decode_u_8(deserializer);
+var var_probes = sse_decode_u_8(deserializer);
+var var_backoff = sse_decode_u_8(deserializer);
+var var_options = sse_decode_u_8(deserializer);
+var var_wscale = sse_decode_u_8(deserializer);
+var var_flags = sse_decode_u_8(deserializer);
+var var_rto = sse_decode_u_32(deserializer);
+var var_ato = sse_decode_u_32(deserializer);
+var var_sndMss = sse_decode_u_32(deserializer);
+var var_rcvMss = sse_decode_u_32(deserializer);
+var var_unacked = sse_decode_u_32(deserializer);
+var var_sacked = sse_decode_u_32(deserializer);
+var var_lost = sse_decode_u_32(deserializer);
+var var_retrans = sse_decode_u_32(deserializer);
+var var_fackets = sse_decode_u_32(deserializer);
+var var_lastDataSent = sse_decode_u_32(deserializer);
+var var_lastAckSent = sse_decode_u_32(deserializer);
+var var_lastDataRecv = sse_decode_u_32(deserializer);
+var var_lastAckRecv = sse_decode_u_32(deserializer);
+var var_pmtu = sse_decode_u_32(deserializer);
+return TcpInfo(state: var_state, caState: var_caState, retransmits: var_retransmits, probes: var_probes, backoff: var_backoff, options: var_options, wscale: var_wscale, flags: var_flags, rto: var_rto, ato: var_ato, sndMss: var_sndMss, rcvMss: var_rcvMss, unacked: var_unacked, sacked: var_sacked, lost: var_lost, retrans: var_retrans, fackets: var_fackets, lastDataSent: var_lastDataSent, lastAckSent: var_lastAckSent, lastDataRecv: var_lastDataRecv, lastAckRecv: var_lastAckRecv, pmtu: var_pmtu); }
+
+@protected TimeBucket sse_decode_time_bucket(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_index = sse_decode_u_8(deserializer);
+var var_reports = sse_decode_u_32(deserializer);
+var var_avgLatencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+var var_avgPacketLossPercent = sse_decode_f_32(deserializer);
+var var_degradedShare = sse_decode_f_32(deserializer);
+return TimeBucket(index: var_index, reports: var_reports, avgLatencyMs: var_avgLatencyMs, avgPacketLossPercent: var_avgPacketLossPercent, degradedShare: var_degradedShare); }
+
+@protected TimeOfDayProfile sse_decode_time_of_day_profile(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_byHour = sse_decode_list_time_bucket(deserializer);
+var var_byWeekday = sse_decode_list_time_bucket(deserializer);
+var var_congestionWindows = sse_decode_list_congestion_window(deserializer);
+return TimeOfDayProfile(byHour: var_byHour, byWeekday: var_byWeekday, congestionWindows: var_congestionWindows); }
+
+@protected TraceHop sse_decode_trace_hop(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_hopNumber = sse_decode_u_8(deserializer);
+var var_ipAddress = sse_decode_String(deserializer);
+var var_hostname = sse_decode_opt_String(deserializer);
+var var_latencyMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return TraceHop(hopNumber: var_hopNumber, ipAddress: var_ipAddress, hostname: var_hostname, latencyMs: var_latencyMs); }
+
+@protected int sse_decode_u_16(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint16(); }
+
+@protected int sse_decode_u_32(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint32(); }
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected int sse_decode_u_8(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getUint8(); }
+
+@protected UdpValidation sse_decode_udp_validation(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var inner = sse_decode_i_32(deserializer);
+        return UdpValidation.values[inner]; }
+
+@protected void sse_decode_unit(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected UnmetRequirement sse_decode_unmet_requirement(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_requiredMbps = sse_decode_f_64(deserializer);
+var var_measuredMbps = sse_decode_opt_box_autoadd_f_64(deserializer);
+return UnmetRequirement_Download(requiredMbps: var_requiredMbps, measuredMbps: var_measuredMbps);case 1: var var_maxMs = sse_decode_u_64(deserializer);
+var var_measuredMs = sse_decode_opt_box_autoadd_u_64(deserializer);
+return UnmetRequirement_Latency(maxMs: var_maxMs, measuredMs: var_measuredMs);case 2: var var_maxPercent = sse_decode_f_32(deserializer);
+var var_measuredPercent = sse_decode_f_32(deserializer);
+return UnmetRequirement_PacketLoss(maxPercent: var_maxPercent, measuredPercent: var_measuredPercent);case 3: return UnmetRequirement_Udp();case 4: return UnmetRequirement_WebSocket(); default: throw UnimplementedError(''); }
+             }
+
+@protected UpnpDescription sse_decode_upnp_description(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_friendlyName = sse_decode_opt_String(deserializer);
+var var_manufacturer = sse_decode_opt_String(deserializer);
+var var_modelName = sse_decode_opt_String(deserializer);
+var var_modelNumber = sse_decode_opt_String(deserializer);
+return UpnpDescription(friendlyName: var_friendlyName, manufacturer: var_manufacturer, modelName: var_modelName, modelNumber: var_modelNumber); }
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return deserializer.buffer.getBigUint64(); }
+
+@protected ValidationIssue sse_decode_validation_issue(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+            var tag_ = sse_decode_i_32(deserializer);
+            switch (tag_) { case 0: var var_target = sse_decode_usize(deserializer);
+return ValidationIssue_EmptyHost(target: var_target);case 1: var var_target = sse_decode_usize(deserializer);
+var var_timeoutMs = sse_decode_u_64(deserializer);
+return ValidationIssue_InvalidTimeout(target: var_target, timeoutMs: var_timeoutMs);case 2: return ValidationIssue_ThresholdsNotMonotonic();case 3: var var_quorum = sse_decode_u_8(deserializer);
+var var_targets = sse_decode_usize(deserializer);
+return ValidationIssue_InvalidQuorum(quorum: var_quorum, targets: var_targets);case 4: return ValidationIssue_ZeroTotalWeight();case 5: var var_target = sse_decode_usize(deserializer);
+return ValidationIssue_InvalidDnsQueryName(target: var_target);case 6: var var_field = sse_decode_String(deserializer);
+var var_value = sse_decode_String(deserializer);
+return ValidationIssue_InvalidEndpoint(field: var_field, value: var_value); default: throw UnimplementedError(''); }
+             }
+
+@protected VpnComparison sse_decode_vpn_comparison(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_vpnBefore = sse_decode_bool(deserializer);
+var var_vpnAfter = sse_decode_bool(deserializer);
+var var_toggleObserved = sse_decode_bool(deserializer);
+var var_latencyDeltaMs = sse_decode_i_64(deserializer);
+var var_jitterDeltaMs = sse_decode_i_64(deserializer);
+var var_packetLossDeltaPercent = sse_decode_f_32(deserializer);
+var var_interfaceBefore = sse_decode_String(deserializer);
+var var_interfaceAfter = sse_decode_String(deserializer);
+var var_connectionTypeBefore = sse_decode_connection_type(deserializer);
+var var_connectionTypeAfter = sse_decode_connection_type(deserializer);
+var var_routeChanged = sse_decode_bool(deserializer);
+var var_resolversBefore = sse_decode_list_String(deserializer);
+var var_resolversAfter = sse_decode_list_String(deserializer);
+var var_dnsResolverChanged = sse_decode_bool(deserializer);
+var var_dnsLeakSuspected = sse_decode_bool(deserializer);
+var var_routeLeakSuspected = sse_decode_bool(deserializer);
+return VpnComparison(vpnBefore: var_vpnBefore, vpnAfter: var_vpnAfter, toggleObserved: var_toggleObserved, latencyDeltaMs: var_latencyDeltaMs, jitterDeltaMs: var_jitterDeltaMs, packetLossDeltaPercent: var_packetLossDeltaPercent, interfaceBefore: var_interfaceBefore, interfaceAfter: var_interfaceAfter, connectionTypeBefore: var_connectionTypeBefore, connectionTypeAfter: var_connectionTypeAfter, routeChanged: var_routeChanged, resolversBefore: var_resolversBefore, resolversAfter: var_resolversAfter, dnsResolverChanged: var_dnsResolverChanged, dnsLeakSuspected: var_dnsLeakSuspected, routeLeakSuspected: var_routeLeakSuspected); }
+
+@protected WalledGardenReport sse_decode_walled_garden_report(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_systemDns = sse_decode_bool(deserializer);
+var var_publicDns = sse_decode_service_reachability(deserializer);
+var var_https = sse_decode_service_reachability(deserializer);
+var var_ports = sse_decode_list_port_reachability(deserializer);
+return WalledGardenReport(systemDns: var_systemDns, publicDns: var_publicDns, https: var_https, ports: var_ports); }
+
+@protected WebProbe sse_decode_web_probe(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+return WebProbe(); }
+
+@protected WeightedStabilityModel sse_decode_weighted_stability_model(SseDeserializer deserializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+var var_p95Weight = sse_decode_f_64(deserializer);
+var var_lossWeight = sse_decode_f_64(deserializer);
+var var_meanWeight = sse_decode_f_64(deserializer);
+var var_jitterWeight = sse_decode_f_64(deserializer);
+return WeightedStabilityModel(p95Weight: var_p95Weight, lossWeight: var_lossWeight, meanWeight: var_meanWeight, jitterWeight: var_jitterWeight); }
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.message, serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(AnomalyDetector self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AnomalyDetectorImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(CheckPipeline self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CheckPipelineImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(CircuitBreaker self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CircuitBreakerImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(DeviceInventory self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as DeviceInventoryImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(NetworkMonitor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NetworkMonitorImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(NetworkReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NetworkReportImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(PipelineReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PipelineReportImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(ReachabilityEngine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ReachabilityEngineImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SecurityFlagsResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SecurityFlagsResultImpl).frbInternalSseEncode(move: true), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(AnomalyDetector self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AnomalyDetectorImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(CircuitBreaker self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CircuitBreakerImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(DeviceInventory self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as DeviceInventoryImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(NetworkReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NetworkReportImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(PipelineReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PipelineReportImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SecurityFlagsResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SecurityFlagsResultImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(AnomalyDetector self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AnomalyDetectorImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(CheckPipeline self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CheckPipelineImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(CircuitBreaker self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CircuitBreakerImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(NetworkMonitor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NetworkMonitorImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(NetworkReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NetworkReportImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(PipelineReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PipelineReportImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(ReachabilityEngine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ReachabilityEngineImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SecurityFlagsResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SecurityFlagsResultImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(StabilityInputs self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as StabilityInputsImpl).frbInternalSseEncode(move: false), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(AnomalyDetector self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as AnomalyDetectorImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(CheckPipeline self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CheckPipelineImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(CircuitBreaker self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as CircuitBreakerImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(DeviceInventory self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as DeviceInventoryImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(NetworkMonitor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NetworkMonitorImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(NetworkReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as NetworkReportImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(PipelineReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as PipelineReportImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(ReachabilityEngine self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as ReachabilityEngineImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SecurityFlagsResult self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as SecurityFlagsResultImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(StabilityInputs self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_usize((self as StabilityInputsImpl).frbInternalSseEncode(move: null), serializer); }
+
+@protected void sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport_Sse(RustStreamSink<NetworkReport> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_StreamSink_scan_event_Sse(RustStreamSink<ScanEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.setupAndSerialize(codec: SseCodec(
+            decodeSuccessData: sse_decode_scan_event,
+            decodeErrorData: sse_decode_AnyhowException,
+        )), serializer); }
+
+@protected void sse_encode_String(String self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_8_strict(utf8.encoder.convert(self), serializer); }
+
+@protected void sse_encode_address_family(AddressFamily self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_anomaly_detected(AnomalyDetected self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_anomaly_metric(self.metric, serializer);
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_f_64(self.value, serializer);
+sse_encode_f_64(self.expected, serializer);
+sse_encode_f_64(self.stdDev, serializer);
+sse_encode_f_64(self.zScore, serializer);
+sse_encode_connection_type(self.connectionType, serializer);
+ }
+
+@protected void sse_encode_anomaly_metric(AnomalyMetric self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_apple_path_interface_type(ApplePathInterfaceType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_apple_path_snapshot(ApplePathSnapshot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_apple_path_status(self.status, serializer);
+sse_encode_apple_path_interface_type(self.interfaceType, serializer);
+sse_encode_String(self.interfaceName, serializer);
+sse_encode_bool(self.isExpensive, serializer);
+sse_encode_bool(self.isConstrained, serializer);
+sse_encode_bool(self.usesVpn, serializer);
+ }
+
+@protected void sse_encode_apple_path_status(ApplePathStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_arp_entry(ArpEntry self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.ipAddress, serializer);
+sse_encode_String(self.macAddress, serializer);
+sse_encode_opt_String(self.interface_, serializer);
+ }
+
+@protected void sse_encode_better_path_available(BetterPathAvailable self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_path_measurement(self.current, serializer);
+sse_encode_path_measurement(self.better, serializer);
+sse_encode_opt_box_autoadd_i_64(self.latencyGainMs, serializer);
+ }
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self ? 1 : 0); }
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(NetworkReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(self, serializer); }
+
+@protected void sse_encode_box_autoadd_address_family(AddressFamily self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_address_family(self, serializer); }
+
+@protected void sse_encode_box_autoadd_apple_path_snapshot(ApplePathSnapshot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_apple_path_snapshot(self, serializer); }
+
+@protected void sse_encode_box_autoadd_better_path_available(BetterPathAvailable self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_better_path_available(self, serializer); }
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self, serializer); }
+
+@protected void sse_encode_box_autoadd_captive_portal_status(CaptivePortalStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_captive_portal_status(self, serializer); }
+
+@protected void sse_encode_box_autoadd_check_strategy(CheckStrategy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_check_strategy(self, serializer); }
+
+@protected void sse_encode_box_autoadd_connection_type(ConnectionType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_connection_type(self, serializer); }
+
+@protected void sse_encode_box_autoadd_degradation_attribution(DegradationAttribution self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_degradation_attribution(self, serializer); }
+
+@protected void sse_encode_box_autoadd_endpoint_config(EndpointConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_endpoint_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_engine_snapshot(EngineSnapshot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_engine_snapshot(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_32(self, serializer); }
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_family_comparison(FamilyComparison self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_family_comparison(self, serializer); }
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_icmp_unreachable(IcmpUnreachable self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_icmp_unreachable(self, serializer); }
+
+@protected void sse_encode_box_autoadd_latency_stats(LatencyStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_latency_stats(self, serializer); }
+
+@protected void sse_encode_box_autoadd_link_neighbor(LinkNeighbor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_link_neighbor(self, serializer); }
+
+@protected void sse_encode_box_autoadd_local_device(LocalDevice self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_local_device(self, serializer); }
+
+@protected void sse_encode_box_autoadd_local_scan_config(LocalScanConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_local_scan_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_native_probe(NativeProbe self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_native_probe(self, serializer); }
+
+@protected void sse_encode_box_autoadd_network_configuration(NetworkConfiguration self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_network_configuration(self, serializer); }
+
+@protected void sse_encode_box_autoadd_network_error(NetworkError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_network_error(self, serializer); }
+
+@protected void sse_encode_box_autoadd_network_target(NetworkTarget self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_network_target(self, serializer); }
+
+@protected void sse_encode_box_autoadd_path_segment(PathSegment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_path_segment(self, serializer); }
+
+@protected void sse_encode_box_autoadd_platform_hints(PlatformHints self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_platform_hints(self, serializer); }
+
+@protected void sse_encode_box_autoadd_policy_violation(PolicyViolation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_policy_violation(self, serializer); }
+
+@protected void sse_encode_box_autoadd_probe_fallback(ProbeFallback self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_probe_fallback(self, serializer); }
+
+@protected void sse_encode_box_autoadd_probe_timings(ProbeTimings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_probe_timings(self, serializer); }
+
+@protected void sse_encode_box_autoadd_quality_thresholds(QualityThresholds self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_quality_thresholds(self, serializer); }
+
+@protected void sse_encode_box_autoadd_requirements(Requirements self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_requirements(self, serializer); }
+
+@protected void sse_encode_box_autoadd_resilience_config(ResilienceConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_resilience_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_roam_event(RoamEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_roam_event(self, serializer); }
+
+@protected void sse_encode_box_autoadd_route_trace(RouteTrace self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_route_trace(self, serializer); }
+
+@protected void sse_encode_box_autoadd_scan_summary(ScanSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_scan_summary(self, serializer); }
+
+@protected void sse_encode_box_autoadd_security_config(SecurityConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_security_config(self, serializer); }
+
+@protected void sse_encode_box_autoadd_step_condition(StepCondition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_step_condition(self, serializer); }
+
+@protected void sse_encode_box_autoadd_target_report(TargetReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_target_report(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self, serializer); }
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self, serializer); }
+
+@protected void sse_encode_box_autoadd_unmet_requirement(UnmetRequirement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_unmet_requirement(self, serializer); }
+
+@protected void sse_encode_box_autoadd_validation_issue(ValidationIssue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_validation_issue(self, serializer); }
+
+@protected void sse_encode_box_autoadd_walled_garden_report(WalledGardenReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_walled_garden_report(self, serializer); }
+
+@protected void sse_encode_box_autoadd_web_probe(WebProbe self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_web_probe(self, serializer); }
+
+@protected void sse_encode_box_autoadd_weighted_stability_model(WeightedStabilityModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_weighted_stability_model(self, serializer); }
+
+@protected void sse_encode_captive_portal_status(CaptivePortalStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.isCaptivePortal, serializer);
+sse_encode_opt_String(self.redirectUrl, serializer);
+sse_encode_evidence(self.evidence, serializer);
+sse_encode_opt_String(self.pageTitle, serializer);
+sse_encode_portal_login_kind(self.loginKind, serializer);
+sse_encode_bool(self.wispr, serializer);
+ }
+
+@protected void sse_encode_check_strategy(CheckStrategy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case CheckStrategy_Race(): sse_encode_i_32(0, serializer); case CheckStrategy_Consensus(): sse_encode_i_32(1, serializer); case CheckStrategy_Fallback(): sse_encode_i_32(2, serializer); case CheckStrategy_All(): sse_encode_i_32(3, serializer); case CheckStrategy_Quorum(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_u_8(field0, serializer);
+case CheckStrategy_Weighted(): sse_encode_i_32(5, serializer);   } }
+
+@protected void sse_encode_circuit_breaker_state(CircuitBreakerState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_confidence(Confidence self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_congestion_window(CongestionWindow self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.startHour, serializer);
+sse_encode_u_8(self.endHour, serializer);
+sse_encode_opt_box_autoadd_u_64(self.avgLatencyMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.baselineLatencyMs, serializer);
+sse_encode_f_32(self.degradedShare, serializer);
+sse_encode_u_32(self.daysObserved, serializer);
+ }
+
+@protected void sse_encode_connection_quality(ConnectionQuality self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_connection_type(ConnectionType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_degradation_attribution(DegradationAttribution self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.gatewayLatencyMs, serializer);
+sse_encode_u_64(self.gatewayJitterMs, serializer);
+sse_encode_f_32(self.gatewayLossPercent, serializer);
+sse_encode_opt_box_autoadd_f_32(self.latencyShare, serializer);
+sse_encode_opt_box_autoadd_f_32(self.varianceShare, serializer);
+sse_encode_opt_box_autoadd_f_32(self.lossShare, serializer);
+sse_encode_opt_box_autoadd_path_segment(self.dominantSegment, serializer);
+ }
+
+@protected void sse_encode_degradation_scope(DegradationScope self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_device_event(DeviceEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case DeviceEvent_Joined(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_local_device(field0, serializer);
+case DeviceEvent_Left(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_box_autoadd_local_device(field0, serializer);
+case DeviceEvent_IpChanged(macAddress: final macAddress,oldIp: final oldIp,newIp: final newIp): sse_encode_i_32(2, serializer); sse_encode_String(macAddress, serializer);
+sse_encode_String(oldIp, serializer);
+sse_encode_String(newIp, serializer);
+  } }
+
+@protected void sse_encode_dscp_latency(DscpLatency self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.dscp, serializer);
+sse_encode_u_32(self.samples, serializer);
+sse_encode_u_32(self.successes, serializer);
+sse_encode_opt_box_autoadd_u_64(self.medianLatencyMs, serializer);
+sse_encode_f_32(self.lossPercent, serializer);
+sse_encode_opt_box_autoadd_i_64(self.deltaMs, serializer);
+ }
+
+@protected void sse_encode_ecn_marking(EcnMarking self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_ecn_observation(EcnObservation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.server, serializer);
+sse_encode_opt_box_autoadd_bool(self.negotiated, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_ecn_report(EcnReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_bool(self.osRequestsEcn, serializer);
+sse_encode_list_ecn_observation(self.tcp, serializer);
+sse_encode_ecn_marking(self.udpMarking, serializer);
+sse_encode_bool(self.ecnCapable, serializer);
+ }
+
+@protected void sse_encode_encapsulation(Encapsulation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_endpoint_config(EndpointConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.captivePortalUrl, serializer);
+sse_encode_String(self.walledGardenUrl, serializer);
+sse_encode_String(self.walledGardenPortHost, serializer);
+sse_encode_String(self.publicDnsServer, serializer);
+sse_encode_list_String(self.trustedDnsServers, serializer);
+sse_encode_list_String(self.tcpReferenceServers, serializer);
+ }
+
+@protected void sse_encode_engine_snapshot(EngineSnapshot self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.takenAtMs, serializer);
+sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(self.lastReport, serializer);
+sse_encode_u_8(self.breakerConsecutiveFailures, serializer);
+sse_encode_opt_box_autoadd_u_64(self.breakerOpenedAtMs, serializer);
+sse_encode_list_target_health(self.targetHealth, serializer);
+ }
+
+@protected void sse_encode_environment_report(EnvironmentReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.crateVersion, serializer);
+sse_encode_String(self.os, serializer);
+sse_encode_String(self.arch, serializer);
+sse_encode_runtime_environment(self.runtime, serializer);
+sse_encode_probe_capabilities(self.capabilities, serializer);
+sse_encode_bool(self.hasIpv4, serializer);
+sse_encode_bool(self.hasIpv6, serializer);
+sse_encode_list_String(self.features, serializer);
+sse_encode_list_limitation(self.limitations, serializer);
+ }
+
+@protected void sse_encode_evidence(Evidence self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_confidence(self.confidence, serializer);
+sse_encode_evidence_source(self.source, serializer);
+ }
+
+@protected void sse_encode_evidence_source(EvidenceSource self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat32(self); }
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putFloat64(self); }
+
+@protected void sse_encode_family_comparison(FamilyComparison self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.targets, serializer);
+sse_encode_family_stats(self.ipv4, serializer);
+sse_encode_family_stats(self.ipv6, serializer);
+sse_encode_opt_box_autoadd_address_family(self.preferredFamily, serializer);
+ }
+
+@protected void sse_encode_family_stats(FamilyStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_address_family(self.family, serializer);
+sse_encode_u_32(self.probes, serializer);
+sse_encode_opt_box_autoadd_u_64(self.latencyMs, serializer);
+sse_encode_f_32(self.packetLossPercent, serializer);
+ }
+
+@protected void sse_encode_gateway_fingerprint(GatewayFingerprint self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.ipAddress, serializer);
+sse_encode_opt_String(self.macAddress, serializer);
+sse_encode_opt_String(self.macVendor, serializer);
+sse_encode_opt_String(self.httpTitle, serializer);
+sse_encode_opt_String(self.httpServer, serializer);
+sse_encode_opt_String(self.upnpFriendlyName, serializer);
+sse_encode_opt_String(self.upnpManufacturer, serializer);
+sse_encode_opt_String(self.upnpModelName, serializer);
+sse_encode_opt_String(self.upnpModelNumber, serializer);
+sse_encode_opt_String(self.vendor, serializer);
+sse_encode_opt_String(self.model, serializer);
+ }
+
+@protected void sse_encode_hairpin_report(HairpinReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.publicAddress, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_service_reachability(self.fromLan, serializer);
+sse_encode_service_reachability(self.fromInternet, serializer);
+sse_encode_opt_box_autoadd_bool(self.hairpinSupported, serializer);
+ }
+
+@protected void sse_encode_history_aggregate(HistoryAggregate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.startMs, serializer);
+sse_encode_u_64(self.resolutionMs, serializer);
+sse_encode_u_32(self.reports, serializer);
+sse_encode_u_32(self.connectedReports, serializer);
+sse_encode_u_32(self.degradedReports, serializer);
+sse_encode_opt_box_autoadd_u_64(self.avgLatencyMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.maxLatencyMs, serializer);
+sse_encode_f_32(self.avgPacketLossPercent, serializer);
+sse_encode_u_8(self.minStabilityScore, serializer);
+ }
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putInt32(self); }
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putPlatformInt64(self); }
+
+@protected void sse_encode_icmp_unreachable(IcmpUnreachable self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_latency_heatmap(LatencyHeatmap self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_String(self.targets, serializer);
+sse_encode_u_64(self.bucketMs, serializer);
+sse_encode_list_prim_u_64_strict(self.bucketStartsMs, serializer);
+sse_encode_list_list_opt_box_autoadd_u_64(self.latencyMs, serializer);
+sse_encode_list_list_opt_box_autoadd_f_32(self.failureShare, serializer);
+sse_encode_list_degradation_scope(self.scope, serializer);
+ }
+
+@protected void sse_encode_latency_stats(LatencyStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.latencyMs, serializer);
+sse_encode_u_64(self.jitterMs, serializer);
+sse_encode_f_32(self.packetLossPercent, serializer);
+sse_encode_opt_box_autoadd_u_64(self.minLatencyMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.avgLatencyMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.maxLatencyMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.p50Ms, serializer);
+sse_encode_opt_box_autoadd_u_64(self.p90Ms, serializer);
+sse_encode_opt_box_autoadd_u_64(self.p95Ms, serializer);
+sse_encode_opt_box_autoadd_u_64(self.p99Ms, serializer);
+sse_encode_u_8(self.stabilityScore, serializer);
+sse_encode_u_32(self.spikeCount, serializer);
+sse_encode_list_prim_u_32_strict(self.spikeSampleIndices, serializer);
+ }
+
+@protected void sse_encode_layer_status(LayerStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_layered_diagnosis(LayeredDiagnosis self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_layer_status(self.link, serializer);
+sse_encode_layer_status(self.network, serializer);
+sse_encode_layer_status(self.dns, serializer);
+sse_encode_layer_status(self.transport, serializer);
+sse_encode_layer_status(self.application, serializer);
+ }
+
+@protected void sse_encode_limitation(Limitation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_link_neighbor(LinkNeighbor self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_neighbor_protocol(self.protocol, serializer);
+sse_encode_opt_String(self.chassisId, serializer);
+sse_encode_opt_String(self.systemName, serializer);
+sse_encode_opt_String(self.systemDescription, serializer);
+sse_encode_opt_String(self.platform, serializer);
+sse_encode_opt_String(self.portId, serializer);
+sse_encode_opt_String(self.portDescription, serializer);
+sse_encode_opt_String(self.managementAddress, serializer);
+ }
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(List<NetworkReport> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(item, serializer); } }
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_String(item, serializer); } }
+
+@protected void sse_encode_list_anomaly_detected(List<AnomalyDetected> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_anomaly_detected(item, serializer); } }
+
+@protected void sse_encode_list_arp_entry(List<ArpEntry> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_arp_entry(item, serializer); } }
+
+@protected void sse_encode_list_congestion_window(List<CongestionWindow> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_congestion_window(item, serializer); } }
+
+@protected void sse_encode_list_degradation_scope(List<DegradationScope> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_degradation_scope(item, serializer); } }
+
+@protected void sse_encode_list_device_event(List<DeviceEvent> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_device_event(item, serializer); } }
+
+@protected void sse_encode_list_dscp_latency(List<DscpLatency> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_dscp_latency(item, serializer); } }
+
+@protected void sse_encode_list_ecn_observation(List<EcnObservation> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_ecn_observation(item, serializer); } }
+
+@protected void sse_encode_list_history_aggregate(List<HistoryAggregate> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_history_aggregate(item, serializer); } }
+
+@protected void sse_encode_list_limitation(List<Limitation> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_limitation(item, serializer); } }
+
+@protected void sse_encode_list_list_opt_box_autoadd_f_32(List<List<double?>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_opt_box_autoadd_f_32(item, serializer); } }
+
+@protected void sse_encode_list_list_opt_box_autoadd_u_64(List<List<BigInt?>> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_list_opt_box_autoadd_u_64(item, serializer); } }
+
+@protected void sse_encode_list_local_device(List<LocalDevice> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_local_device(item, serializer); } }
+
+@protected void sse_encode_list_mdns_service(List<MdnsService> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_mdns_service(item, serializer); } }
+
+@protected void sse_encode_list_mss_observation(List<MssObservation> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_mss_observation(item, serializer); } }
+
+@protected void sse_encode_list_network_target(List<NetworkTarget> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_network_target(item, serializer); } }
+
+@protected void sse_encode_list_opt_String(List<String?> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_opt_String(item, serializer); } }
+
+@protected void sse_encode_list_opt_box_autoadd_f_32(List<double?> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_opt_box_autoadd_f_32(item, serializer); } }
+
+@protected void sse_encode_list_opt_box_autoadd_u_64(List<BigInt?> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_opt_box_autoadd_u_64(item, serializer); } }
+
+@protected void sse_encode_list_path_measurement(List<PathMeasurement> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_path_measurement(item, serializer); } }
+
+@protected void sse_encode_list_port_reachability(List<PortReachability> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_port_reachability(item, serializer); } }
+
+@protected void sse_encode_list_prim_u_16_strict(Uint16List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint16List(self); }
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint32List(self); }
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint64List(self); }
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self is Uint8List ? self : Uint8List.fromList(self)); }
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+                    serializer.buffer.putUint8List(self); }
+
+@protected void sse_encode_list_quality_reason(List<QualityReason> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_quality_reason(item, serializer); } }
+
+@protected void sse_encode_list_reflection_observation(List<ReflectionObservation> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_reflection_observation(item, serializer); } }
+
+@protected void sse_encode_list_route_trace(List<RouteTrace> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_route_trace(item, serializer); } }
+
+@protected void sse_encode_list_sample_record(List<SampleRecord> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sample_record(item, serializer); } }
+
+@protected void sse_encode_list_sample_target_latency(List<SampleTargetLatency> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_sample_target_latency(item, serializer); } }
+
+@protected void sse_encode_list_service_info(List<ServiceInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_service_info(item, serializer); } }
+
+@protected void sse_encode_list_snmp_device_info(List<SnmpDeviceInfo> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_snmp_device_info(item, serializer); } }
+
+@protected void sse_encode_list_snmp_interface(List<SnmpInterface> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_snmp_interface(item, serializer); } }
+
+@protected void sse_encode_list_step_outcome(List<StepOutcome> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_step_outcome(item, serializer); } }
+
+@protected void sse_encode_list_target_health(List<TargetHealth> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_target_health(item, serializer); } }
+
+@protected void sse_encode_list_target_report(List<TargetReport> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_target_report(item, serializer); } }
+
+@protected void sse_encode_list_time_bucket(List<TimeBucket> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_time_bucket(item, serializer); } }
+
+@protected void sse_encode_list_trace_hop(List<TraceHop> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_trace_hop(item, serializer); } }
+
+@protected void sse_encode_list_unmet_requirement(List<UnmetRequirement> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_unmet_requirement(item, serializer); } }
+
+@protected void sse_encode_list_validation_issue(List<ValidationIssue> self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.length, serializer);
+        for (final item in self) { sse_encode_validation_issue(item, serializer); } }
+
+@protected void sse_encode_local_device(LocalDevice self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.ipAddress, serializer);
+sse_encode_opt_String(self.hostname, serializer);
+sse_encode_opt_String(self.macAddress, serializer);
+sse_encode_opt_String(self.macVendor, serializer);
+sse_encode_u_64(self.latencyMs, serializer);
+sse_encode_list_prim_u_16_strict(self.openPorts, serializer);
+sse_encode_bool(self.answeredPing, serializer);
+sse_encode_list_service_info(self.services, serializer);
+ }
+
+@protected void sse_encode_local_reachability_report(LocalReachabilityReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_u_64(self.monotonicMs, serializer);
+sse_encode_network_scope(self.scope, serializer);
+sse_encode_opt_String(self.gateway, serializer);
+sse_encode_opt_box_autoadd_target_report(self.gatewayReport, serializer);
+sse_encode_list_target_report(self.targetReports, serializer);
+ }
+
+@protected void sse_encode_local_scan_config(LocalScanConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.subnet, serializer);
+sse_encode_u_16(self.scanPort, serializer);
+sse_encode_list_prim_u_16_strict(self.scanPorts, serializer);
+sse_encode_scan_method(self.method, serializer);
+sse_encode_u_64(self.timeoutMs, serializer);
+sse_encode_usize(self.maxConcurrency, serializer);
+sse_encode_usize(self.maxHosts, serializer);
+sse_encode_list_String(self.allowRanges, serializer);
+sse_encode_list_String(self.excludeRanges, serializer);
+sse_encode_bool(self.grabBanners, serializer);
+sse_encode_u_64(self.bannerTimeoutMs, serializer);
+sse_encode_bool(self.resolveNames, serializer);
+sse_encode_bool(self.lookupHostnames, serializer);
+sse_encode_u_64(self.hostnameLookupTimeoutMs, serializer);
+sse_encode_usize(self.hostnameLookupConcurrency, serializer);
+ }
+
+@protected void sse_encode_mdns_service(MdnsService self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.instanceName, serializer);
+sse_encode_String(self.serviceType, serializer);
+sse_encode_opt_String(self.hostname, serializer);
+sse_encode_opt_box_autoadd_u_16(self.port, serializer);
+sse_encode_list_String(self.ipAddresses, serializer);
+sse_encode_list_String(self.txtRecords, serializer);
+ }
+
+@protected void sse_encode_monitor_state(MonitorState self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_monitor_stats(MonitorStats self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.completedChecks, serializer);
+sse_encode_u_64(self.overruns, serializer);
+sse_encode_u_64(self.skippedCycles, serializer);
+ }
+
+@protected void sse_encode_mss_observation(MssObservation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.server, serializer);
+sse_encode_opt_box_autoadd_u_16(self.mss, serializer);
+sse_encode_opt_box_autoadd_u_16(self.pathMtu, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_mss_report(MssReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_mss_observation(self.observations, serializer);
+sse_encode_opt_box_autoadd_u_16(self.effectiveMss, serializer);
+sse_encode_bool(self.clampingDetected, serializer);
+sse_encode_opt_box_autoadd_u_16(self.overheadBytes, serializer);
+sse_encode_encapsulation(self.encapsulation, serializer);
+ }
+
+@protected void sse_encode_multi_route_trace(MultiRouteTrace self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_route_trace(self.routes, serializer);
+sse_encode_list_trace_hop(self.sharedPrefix, serializer);
+sse_encode_u_8(self.lanHopCount, serializer);
+sse_encode_opt_box_autoadd_path_segment(self.problemSegment, serializer);
+ }
+
+@protected void sse_encode_multicast_report(MulticastReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.group, serializer);
+sse_encode_bool(self.joined, serializer);
+sse_encode_bool(self.loopbackReceived, serializer);
+sse_encode_list_String(self.responders, serializer);
+sse_encode_opt_box_autoadd_bool(self.multicastOk, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_nat_report(NatReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_nat_type(self.natType, serializer);
+sse_encode_opt_String(self.localAddress, serializer);
+sse_encode_list_reflection_observation(self.observations, serializer);
+sse_encode_bool(self.rebindingDetected, serializer);
+sse_encode_bool(self.asymmetricRoutingSuspected, serializer);
+ }
+
+@protected void sse_encode_nat_type(NatType self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_native_probe(NativeProbe self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_neighbor_protocol(NeighborProtocol self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_network_configuration(NetworkConfiguration self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_network_target(self.targets, serializer);
+sse_encode_list_network_target(self.lanTargets, serializer);
+sse_encode_list_String(self.ispStatusUrls, serializer);
+sse_encode_u_64(self.checkIntervalMs, serializer);
+sse_encode_u_8(self.checkIntervalJitterPercent, serializer);
+sse_encode_u_64(self.cacheValidityMs, serializer);
+sse_encode_quality_thresholds(self.qualityThreshold, serializer);
+sse_encode_security_config(self.security, serializer);
+sse_encode_resilience_config(self.resilience, serializer);
+sse_encode_rate_limit_config(self.rateLimit, serializer);
+sse_encode_snmp_config(self.snmp, serializer);
+sse_encode_endpoint_config(self.endpoints, serializer);
+sse_encode_bool(self.includeRawSamples, serializer);
+sse_encode_bool(self.preResolveTargets, serializer);
+sse_encode_bool(self.compareAddressFamilies, serializer);
+sse_encode_bool(self.checkMulticast, serializer);
+sse_encode_bool(self.comparePaths, serializer);
+sse_encode_bool(self.attributeDegradation, serializer);
+sse_encode_u_64(self.overallTimeoutMs, serializer);
+sse_encode_bool(self.fastFailOffline, serializer);
+sse_encode_opt_String(self.activeInterface, serializer);
+ }
+
+@protected void sse_encode_network_error(NetworkError self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case NetworkError_DnsResolutionError(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_String(field0, serializer);
+case NetworkError_ConnectionError(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+case NetworkError_TimeoutError(): sse_encode_i_32(2, serializer); case NetworkError_UnknownError(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_String(field0, serializer);
+case NetworkError_Unreachable(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_icmp_unreachable(field0, serializer);
+case NetworkError_PolicyViolation(field0: final field0): sse_encode_i_32(5, serializer); sse_encode_box_autoadd_policy_violation(field0, serializer);
+case NetworkError_InvalidConfiguration(field0: final field0): sse_encode_i_32(6, serializer); sse_encode_String(field0, serializer);
+case NetworkError_ProbeFailed(field0: final field0): sse_encode_i_32(7, serializer); sse_encode_String(field0, serializer);
+  } }
+
+@protected void sse_encode_network_scope(NetworkScope self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_network_status(NetworkStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.isConnected, serializer);
+sse_encode_connection_quality(self.quality, serializer);
+sse_encode_latency_stats(self.latencyStats, serializer);
+sse_encode_String(self.winnerTarget, serializer);
+sse_encode_list_opt_String(self.winnersPerSample, serializer);
+sse_encode_list_quality_reason(self.reasons, serializer);
+ }
+
+@protected void sse_encode_network_target(NetworkTarget self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.label, serializer);
+sse_encode_String(self.host, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_target_protocol(self.protocol, serializer);
+sse_encode_u_64(self.timeoutMs, serializer);
+sse_encode_u_8(self.priority, serializer);
+sse_encode_bool(self.isEssential, serializer);
+sse_encode_u_8(self.weight, serializer);
+sse_encode_udp_validation(self.udpValidation, serializer);
+sse_encode_opt_String(self.dnsQueryName, serializer);
+sse_encode_opt_box_autoadd_u_8(self.dscp, serializer);
+sse_encode_bool(self.reportTimeoutProgress, serializer);
+ }
+
+@protected void sse_encode_one_way_delay_estimate(OneWayDelayEstimate self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.server, serializer);
+sse_encode_u_32(self.samples, serializer);
+sse_encode_f_64(self.clockOffsetMs, serializer);
+sse_encode_f_64(self.minRttMs, serializer);
+sse_encode_f_64(self.upstreamMs, serializer);
+sse_encode_f_64(self.downstreamMs, serializer);
+sse_encode_f_64(self.upstreamQueuingMs, serializer);
+sse_encode_f_64(self.downstreamQueuingMs, serializer);
+ }
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_String(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(NetworkReport? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_address_family(AddressFamily? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_address_family(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_apple_path_snapshot(ApplePathSnapshot? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_apple_path_snapshot(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_better_path_available(BetterPathAvailable? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_better_path_available(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_bool(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_captive_portal_status(CaptivePortalStatus? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_captive_portal_status(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_connection_type(ConnectionType? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_connection_type(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_degradation_attribution(DegradationAttribution? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_degradation_attribution(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_32(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_f_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_family_comparison(FamilyComparison? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_family_comparison(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_i_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_icmp_unreachable(IcmpUnreachable? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_icmp_unreachable(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_link_neighbor(LinkNeighbor? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_link_neighbor(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_path_segment(PathSegment? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_path_segment(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_platform_hints(PlatformHints? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_platform_hints(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_probe_fallback(ProbeFallback? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_probe_fallback(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_probe_timings(ProbeTimings? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_probe_timings(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_roam_event(RoamEvent? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_roam_event(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_route_trace(RouteTrace? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_route_trace(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_target_report(TargetReport? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_target_report(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_16(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_64(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_u_8(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_opt_box_autoadd_walled_garden_report(WalledGardenReport? self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+
+                sse_encode_bool(self != null, serializer);
+                if (self != null) {
+                    sse_encode_box_autoadd_walled_garden_report(self, serializer);
+                }
+                 }
+
+@protected void sse_encode_overlap_policy(OverlapPolicy self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_path_measurement(PathMeasurement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.interfaceName, serializer);
+sse_encode_connection_type(self.connectionType, serializer);
+sse_encode_String(self.localAddress, serializer);
+sse_encode_opt_box_autoadd_u_64(self.latencyMs, serializer);
+sse_encode_f_32(self.packetLossPercent, serializer);
+ }
+
+@protected void sse_encode_path_segment(PathSegment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_pipeline_step(PipelineStep self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_platform_hints(PlatformHints self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_connection_type(self.connectionType, serializer);
+sse_encode_opt_box_autoadd_bool(self.isMetered, serializer);
+sse_encode_opt_box_autoadd_bool(self.isConstrained, serializer);
+sse_encode_opt_box_autoadd_bool(self.isVpn, serializer);
+sse_encode_opt_String(self.ssid, serializer);
+sse_encode_opt_String(self.bssid, serializer);
+sse_encode_opt_String(self.interfaceName, serializer);
+ }
+
+@protected void sse_encode_policy_violation(PolicyViolation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case PolicyViolation_VpnDetected(): sse_encode_i_32(0, serializer); case PolicyViolation_InterfaceNotAllowed(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_String(field0, serializer);
+case PolicyViolation_DnsSpoofed(): sse_encode_i_32(2, serializer);   } }
+
+@protected void sse_encode_port_forward_check(PortForwardCheck self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.endpoint, serializer);
+sse_encode_u_16(self.port, serializer);
+sse_encode_opt_String(self.publicAddress, serializer);
+sse_encode_service_reachability(self.reachability, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+ }
+
+@protected void sse_encode_port_reachability(PortReachability self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self.port, serializer);
+sse_encode_service_reachability(self.reachability, serializer);
+ }
+
+@protected void sse_encode_portal_login_kind(PortalLoginKind self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_preflight_report(PreflightReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.passed, serializer);
+sse_encode_list_unmet_requirement(self.unmet, serializer);
+sse_encode_opt_box_autoadd_f_64(self.downMbps, serializer);
+sse_encode_opt_box_autoadd_u_64(self.latencyMs, serializer);
+sse_encode_opt_box_autoadd_f_32(self.packetLossPercent, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+ }
+
+@protected void sse_encode_probe_capabilities(ProbeCapabilities self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.icmpEcho, serializer);
+sse_encode_bool(self.icmpRaw, serializer);
+sse_encode_bool(self.udpTraceroute, serializer);
+sse_encode_bool(self.arpTable, serializer);
+ }
+
+@protected void sse_encode_probe_fallback(ProbeFallback self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_probe_timings(ProbeTimings self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.budgetMs, serializer);
+sse_encode_u_64(self.elapsedMs, serializer);
+sse_encode_f_32(self.dnsPercent, serializer);
+sse_encode_f_32(self.connectPercent, serializer);
+sse_encode_f_32(self.waitPercent, serializer);
+sse_encode_f_32(self.usedPercent, serializer);
+ }
+
+@protected void sse_encode_quality_reason(QualityReason self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_quality_thresholds(QualityThresholds self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.excellent, serializer);
+sse_encode_u_64(self.great, serializer);
+sse_encode_u_64(self.good, serializer);
+sse_encode_u_64(self.moderate, serializer);
+sse_encode_u_64(self.poor, serializer);
+ }
+
+@protected void sse_encode_rate_limit_config(RateLimitConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.maxProbesPerSecond, serializer);
+sse_encode_u_64(self.maxBytesPerSecond, serializer);
+ }
+
+@protected void sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type((SecurityFlagsResult,ConnectionType) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(self.$1, serializer);
+sse_encode_connection_type(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_bool_evidence((bool,Evidence) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.$1, serializer);
+sse_encode_evidence(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_list_prim_u_64_strict_list_target_report((Uint64List,List<TargetReport>) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_64_strict(self.$1, serializer);
+sse_encode_list_target_report(self.$2, serializer);
+ }
+
+@protected void sse_encode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64((BigInt?,BigInt?,BigInt?,double?) self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_u_64(self.$1, serializer);
+sse_encode_opt_box_autoadd_u_64(self.$2, serializer);
+sse_encode_opt_box_autoadd_u_64(self.$3, serializer);
+sse_encode_opt_box_autoadd_f_64(self.$4, serializer);
+ }
+
+@protected void sse_encode_reflection_observation(ReflectionObservation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.server, serializer);
+sse_encode_String(self.observedAddress, serializer);
+sse_encode_u_16(self.observedPort, serializer);
+sse_encode_opt_box_autoadd_u_8(self.observedTtl, serializer);
+sse_encode_opt_box_autoadd_u_8(self.forwardHops, serializer);
+sse_encode_opt_box_autoadd_u_8(self.reverseHops, serializer);
+ }
+
+@protected void sse_encode_requirements(Requirements self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_box_autoadd_f_64(self.minDownMbps, serializer);
+sse_encode_opt_box_autoadd_u_64(self.maxLatencyMs, serializer);
+sse_encode_opt_box_autoadd_f_32(self.maxLossPercent, serializer);
+sse_encode_bool(self.needsUdp, serializer);
+sse_encode_bool(self.needsWebsocket, serializer);
+sse_encode_list_network_target(self.targets, serializer);
+sse_encode_String(self.downloadUrl, serializer);
+sse_encode_String(self.udpDnsServer, serializer);
+sse_encode_String(self.websocketUrl, serializer);
+ }
+
+@protected void sse_encode_resilience_config(ResilienceConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_check_strategy(self.strategy, serializer);
+sse_encode_u_8(self.circuitBreakerThreshold, serializer);
+sse_encode_u_64(self.circuitBreakerCooldownMs, serializer);
+sse_encode_u_8(self.numJitterSamples, serializer);
+sse_encode_f_64(self.jitterThresholdPercent, serializer);
+sse_encode_u_8(self.stabilityThershold, serializer);
+sse_encode_f_32(self.criticalPacketLossPrecent, serializer);
+sse_encode_sample_mode(self.sampleMode, serializer);
+sse_encode_u_64(self.parallelSampleOffsetMs, serializer);
+sse_encode_f_64(self.spikeRatioThreshold, serializer);
+sse_encode_u_8(self.spikePenalty, serializer);
+sse_encode_u_8(self.passiveFailureThreshold, serializer);
+sse_encode_u_64(self.passiveSignalWindowMs, serializer);
+sse_encode_overlap_policy(self.overlapPolicy, serializer);
+ }
+
+@protected void sse_encode_roam_event(RoamEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_opt_String(self.ssid, serializer);
+sse_encode_String(self.fromBssid, serializer);
+sse_encode_String(self.toBssid, serializer);
+sse_encode_u_64(self.latencyBeforeMs, serializer);
+sse_encode_u_64(self.latencyAfterMs, serializer);
+sse_encode_bool(self.latencySpike, serializer);
+ }
+
+@protected void sse_encode_route_trace(RouteTrace self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.host, serializer);
+sse_encode_list_trace_hop(self.hops, serializer);
+sse_encode_opt_String(self.error, serializer);
+sse_encode_bool(self.reached, serializer);
+sse_encode_opt_box_autoadd_u_8(self.problemHop, serializer);
+sse_encode_opt_box_autoadd_path_segment(self.problemSegment, serializer);
+sse_encode_opt_box_autoadd_probe_fallback(self.fallback, serializer);
+ }
+
+@protected void sse_encode_runtime_environment(RuntimeEnvironment self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_sample_mode(SampleMode self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case SampleMode_Sequential(): sse_encode_i_32(0, serializer); case SampleMode_Parallel(): sse_encode_i_32(1, serializer); case SampleMode_Paced(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_u_64(field0, serializer);
+  } }
+
+@protected void sse_encode_sample_record(SampleRecord self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_u_64(self.timestampMs, serializer);
+sse_encode_u_64(self.monotonicMs, serializer);
+sse_encode_list_sample_target_latency(self.latencies, serializer);
+sse_encode_opt_String(self.winnerTarget, serializer);
+sse_encode_bool(self.counted, serializer);
+ }
+
+@protected void sse_encode_sample_run(SampleRun self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_prim_u_64_strict(self.latencies, serializer);
+sse_encode_list_target_report(self.finalReports, serializer);
+sse_encode_list_opt_String(self.winners, serializer);
+sse_encode_list_sample_record(self.records, serializer);
+sse_encode_bool(self.cancelled, serializer);
+sse_encode_list_prim_u_64_strict(self.gatewayLatencies, serializer);
+ }
+
+@protected void sse_encode_sample_target_latency(SampleTargetLatency self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.label, serializer);
+sse_encode_opt_box_autoadd_u_64(self.latencyMs, serializer);
+ }
+
+@protected void sse_encode_scan_event(ScanEvent self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ScanEvent_Device(field0: final field0): sse_encode_i_32(0, serializer); sse_encode_box_autoadd_local_device(field0, serializer);
+case ScanEvent_Progress(field0: final field0): sse_encode_i_32(1, serializer); sse_encode_box_autoadd_scan_summary(field0, serializer);
+case ScanEvent_Finished(field0: final field0): sse_encode_i_32(2, serializer); sse_encode_box_autoadd_scan_summary(field0, serializer);
+  } }
+
+@protected void sse_encode_scan_method(ScanMethod self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_scan_summary(ScanSummary self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.hostsScanned, serializer);
+sse_encode_u_32(self.devicesFound, serializer);
+sse_encode_u_64(self.durationMs, serializer);
+sse_encode_bool(self.cancelled, serializer);
+sse_encode_opt_box_autoadd_probe_fallback(self.fallback, serializer);
+ }
+
+@protected void sse_encode_security_config(SecurityConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.blockVpn, serializer);
+sse_encode_bool(self.detectDnsHijack, serializer);
+sse_encode_list_String(self.allowedInterfaces, serializer);
+ }
+
+@protected void sse_encode_security_flags(SecurityFlags self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.isVpnDetected, serializer);
+sse_encode_bool(self.isDnsSpoofed, serializer);
+sse_encode_bool(self.isProxyDetected, serializer);
+sse_encode_String(self.interfaceName, serializer);
+sse_encode_evidence(self.vpnEvidence, serializer);
+sse_encode_evidence(self.dnsSpoofEvidence, serializer);
+sse_encode_evidence(self.proxyEvidence, serializer);
+ }
+
+@protected void sse_encode_service_info(ServiceInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_16(self.port, serializer);
+sse_encode_opt_String(self.protocol, serializer);
+sse_encode_opt_String(self.banner, serializer);
+ }
+
+@protected void sse_encode_service_reachability(ServiceReachability self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_simple_status(SimpleStatus self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_snmp_config(SnmpConfig self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.community, serializer);
+sse_encode_list_String(self.hosts, serializer);
+sse_encode_u_64(self.timeoutMs, serializer);
+ }
+
+@protected void sse_encode_snmp_device_info(SnmpDeviceInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.address, serializer);
+sse_encode_opt_String(self.sysDescr, serializer);
+sse_encode_list_snmp_interface(self.interfaces, serializer);
+sse_encode_opt_String(self.error, serializer);
+ }
+
+@protected void sse_encode_snmp_interface(SnmpInterface self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_32(self.index, serializer);
+sse_encode_String(self.description, serializer);
+sse_encode_u_64(self.inErrors, serializer);
+sse_encode_u_64(self.outErrors, serializer);
+sse_encode_u_64(self.inDiscards, serializer);
+sse_encode_u_64(self.outDiscards, serializer);
+ }
+
+@protected void sse_encode_step_condition(StepCondition self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case StepCondition_Always(): sse_encode_i_32(0, serializer); case StepCondition_Connected(): sse_encode_i_32(1, serializer); case StepCondition_Offline(): sse_encode_i_32(2, serializer); case StepCondition_QualityBelow(field0: final field0): sse_encode_i_32(3, serializer); sse_encode_connection_quality(field0, serializer);
+case StepCondition_QualityAtLeast(field0: final field0): sse_encode_i_32(4, serializer); sse_encode_connection_quality(field0, serializer);
+case StepCondition_CaptivePortalDetected(): sse_encode_i_32(5, serializer); case StepCondition_CaptivePortalNotDetected(): sse_encode_i_32(6, serializer); case StepCondition_VpnDetected(): sse_encode_i_32(7, serializer);   } }
+
+@protected void sse_encode_step_outcome(StepOutcome self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_pipeline_step(self.step, serializer);
+sse_encode_step_condition(self.condition, serializer);
+sse_encode_bool(self.executed, serializer);
+ }
+
+@protected void sse_encode_target_health(TargetHealth self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.label, serializer);
+sse_encode_u_32(self.consecutiveFailures, serializer);
+sse_encode_opt_box_autoadd_u_64(self.lastSuccessMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.lastFailureMs, serializer);
+ }
+
+@protected void sse_encode_target_protocol(TargetProtocol self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_target_report(TargetReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_String(self.label, serializer);
+sse_encode_bool(self.success, serializer);
+sse_encode_u_64(self.latencyMs, serializer);
+sse_encode_opt_String(self.error, serializer);
+sse_encode_bool(self.isEssential, serializer);
+sse_encode_opt_box_autoadd_u_64(self.dnsMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.connectMs, serializer);
+sse_encode_opt_box_autoadd_icmp_unreachable(self.unreachable, serializer);
+sse_encode_opt_box_autoadd_u_64(self.timedOutAfterMs, serializer);
+sse_encode_opt_box_autoadd_u_64(self.lastProgressMs, serializer);
+sse_encode_opt_String(self.resolvedAddr, serializer);
+sse_encode_opt_box_autoadd_address_family(self.addressFamily, serializer);
+sse_encode_bool(self.skipped, serializer);
+sse_encode_opt_box_autoadd_probe_timings(self.timings, serializer);
+sse_encode_list_opt_box_autoadd_u_64(self.samples, serializer);
+sse_encode_opt_box_autoadd_probe_fallback(self.fallback, serializer);
+ }
+
+@protected void sse_encode_tcp_info(TcpInfo self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.state, serializer);
+sse_encode_u_8(self.caState, serializer);
+sse_encode_u_8(self.retransmits, serializer);
+sse_encode_u_8(self.probes, serializer);
+sse_encode_u_8(self.backoff, serializer);
+sse_encode_u_8(self.options, serializer);
+sse_encode_u_8(self.wscale, serializer);
+sse_encode_u_8(self.flags, serializer);
+sse_encode_u_32(self.rto, serializer);
+sse_encode_u_32(self.ato, serializer);
+sse_encode_u_32(self.sndMss, serializer);
+sse_encode_u_32(self.rcvMss, serializer);
+sse_encode_u_32(self.unacked, serializer);
+sse_encode_u_32(self.sacked, serializer);
+sse_encode_u_32(self.lost, serializer);
+sse_encode_u_32(self.retrans, serializer);
+sse_encode_u_32(self.fackets, serializer);
+sse_encode_u_32(self.lastDataSent, serializer);
+sse_encode_u_32(self.lastAckSent, serializer);
+sse_encode_u_32(self.lastDataRecv, serializer);
+sse_encode_u_32(self.lastAckRecv, serializer);
+sse_encode_u_32(self.pmtu, serializer);
+ }
+
+@protected void sse_encode_time_bucket(TimeBucket self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.index, serializer);
+sse_encode_u_32(self.reports, serializer);
+sse_encode_opt_box_autoadd_u_64(self.avgLatencyMs, serializer);
+sse_encode_f_32(self.avgPacketLossPercent, serializer);
+sse_encode_f_32(self.degradedShare, serializer);
+ }
+
+@protected void sse_encode_time_of_day_profile(TimeOfDayProfile self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_list_time_bucket(self.byHour, serializer);
+sse_encode_list_time_bucket(self.byWeekday, serializer);
+sse_encode_list_congestion_window(self.congestionWindows, serializer);
+ }
+
+@protected void sse_encode_trace_hop(TraceHop self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_u_8(self.hopNumber, serializer);
+sse_encode_String(self.ipAddress, serializer);
+sse_encode_opt_String(self.hostname, serializer);
+sse_encode_opt_box_autoadd_u_64(self.latencyMs, serializer);
+ }
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint16(self); }
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint32(self); }
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putUint8(self); }
+
+@protected void sse_encode_udp_validation(UdpValidation self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_i_32(self.index, serializer); }
+
+@protected void sse_encode_unit(void self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_unmet_requirement(UnmetRequirement self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case UnmetRequirement_Download(requiredMbps: final requiredMbps,measuredMbps: final measuredMbps): sse_encode_i_32(0, serializer); sse_encode_f_64(requiredMbps, serializer);
+sse_encode_opt_box_autoadd_f_64(measuredMbps, serializer);
+case UnmetRequirement_Latency(maxMs: final maxMs,measuredMs: final measuredMs): sse_encode_i_32(1, serializer); sse_encode_u_64(maxMs, serializer);
+sse_encode_opt_box_autoadd_u_64(measuredMs, serializer);
+case UnmetRequirement_PacketLoss(maxPercent: final maxPercent,measuredPercent: final measuredPercent): sse_encode_i_32(2, serializer); sse_encode_f_32(maxPercent, serializer);
+sse_encode_f_32(measuredPercent, serializer);
+case UnmetRequirement_Udp(): sse_encode_i_32(3, serializer); case UnmetRequirement_WebSocket(): sse_encode_i_32(4, serializer);   } }
+
+@protected void sse_encode_upnp_description(UpnpDescription self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_opt_String(self.friendlyName, serializer);
+sse_encode_opt_String(self.manufacturer, serializer);
+sse_encode_opt_String(self.modelName, serializer);
+sse_encode_opt_String(self.modelNumber, serializer);
+ }
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+serializer.buffer.putBigUint64(self); }
+
+@protected void sse_encode_validation_issue(ValidationIssue self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+switch (self) { case ValidationIssue_EmptyHost(target: final target): sse_encode_i_32(0, serializer); sse_encode_usize(target, serializer);
+case ValidationIssue_InvalidTimeout(target: final target,timeoutMs: final timeoutMs): sse_encode_i_32(1, serializer); sse_encode_usize(target, serializer);
+sse_encode_u_64(timeoutMs, serializer);
+case ValidationIssue_ThresholdsNotMonotonic(): sse_encode_i_32(2, serializer); case ValidationIssue_InvalidQuorum(quorum: final quorum,targets: final targets): sse_encode_i_32(3, serializer); sse_encode_u_8(quorum, serializer);
+sse_encode_usize(targets, serializer);
+case ValidationIssue_ZeroTotalWeight(): sse_encode_i_32(4, serializer); case ValidationIssue_InvalidDnsQueryName(target: final target): sse_encode_i_32(5, serializer); sse_encode_usize(target, serializer);
+case ValidationIssue_InvalidEndpoint(field: final field,value: final value): sse_encode_i_32(6, serializer); sse_encode_String(field, serializer);
+sse_encode_String(value, serializer);
+  } }
+
+@protected void sse_encode_vpn_comparison(VpnComparison self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.vpnBefore, serializer);
+sse_encode_bool(self.vpnAfter, serializer);
+sse_encode_bool(self.toggleObserved, serializer);
+sse_encode_i_64(self.latencyDeltaMs, serializer);
+sse_encode_i_64(self.jitterDeltaMs, serializer);
+sse_encode_f_32(self.packetLossDeltaPercent, serializer);
+sse_encode_String(self.interfaceBefore, serializer);
+sse_encode_String(self.interfaceAfter, serializer);
+sse_encode_connection_type(self.connectionTypeBefore, serializer);
+sse_encode_connection_type(self.connectionTypeAfter, serializer);
+sse_encode_bool(self.routeChanged, serializer);
+sse_encode_list_String(self.resolversBefore, serializer);
+sse_encode_list_String(self.resolversAfter, serializer);
+sse_encode_bool(self.dnsResolverChanged, serializer);
+sse_encode_bool(self.dnsLeakSuspected, serializer);
+sse_encode_bool(self.routeLeakSuspected, serializer);
+ }
+
+@protected void sse_encode_walled_garden_report(WalledGardenReport self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_bool(self.systemDns, serializer);
+sse_encode_service_reachability(self.publicDns, serializer);
+sse_encode_service_reachability(self.https, serializer);
+sse_encode_list_port_reachability(self.ports, serializer);
+ }
+
+@protected void sse_encode_web_probe(WebProbe self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+ }
+
+@protected void sse_encode_weighted_stability_model(WeightedStabilityModel self, SseSerializer serializer){ // Codec=Sse (Serialization based), see doc to use other codecs
+sse_encode_f_64(self.p95Weight, serializer);
+sse_encode_f_64(self.lossWeight, serializer);
+sse_encode_f_64(self.meanWeight, serializer);
+sse_encode_f_64(self.jitterWeight, serializer);
+ }
+                }
+                
+
+            @sealed class AnomalyDetectorImpl extends RustOpaque implements AnomalyDetector {
+                // Not to be used by end users
+                AnomalyDetectorImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                AnomalyDetectorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_AnomalyDetector,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_AnomalyDetector,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_AnomalyDetectorPtr,
+                );
+
+                 double get smoothing=>RustLib.instance.api.crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetSmoothing(that: this, );
+
+
+ int get warmupReports=>RustLib.instance.api.crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetWarmupReports(that: this, );
+
+
+ double get zThreshold=>RustLib.instance.api.crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorGetZThreshold(that: this, );
+
+
+  set smoothing(double smoothing)=>RustLib.instance.api.crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetSmoothing(that: this, smoothing: smoothing);
+
+
+  set warmupReports(int warmupReports)=>RustLib.instance.api.crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetWarmupReports(that: this, warmupReports: warmupReports);
+
+
+  set zThreshold(double zThreshold)=>RustLib.instance.api.crateApiAnalysisAnomalyAnomalyDetectorAutoAccessorSetZThreshold(that: this, zThreshold: zThreshold);
+
+
+/// Feeds one report and returns the anomalies it exhibits (at most one per metric).
+///
+/// Latency is only tracked for connected reports; loss is tracked always,
+/// so that an outage shows up as a loss anomaly.
+ Future<List<AnomalyDetected>>  observe({required NetworkReport report })=>RustLib.instance.api.crateApiAnalysisAnomalyAnomalyDetectorObserve(that: this, report: report);
+
+
+/// Forgets the baseline, e.g. after the device switched networks.
+ Future<void>  reset()=>RustLib.instance.api.crateApiAnalysisAnomalyAnomalyDetectorReset(that: this, );
+
+
+            }
+            @sealed class CheckPipelineImpl extends RustOpaque implements CheckPipeline {
+                // Not to be used by end users
+                CheckPipelineImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CheckPipelineImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CheckPipeline,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CheckPipeline,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CheckPipelinePtr,
+                );
+
+                /// Runs the base check followed by every declared step whose condition holds.
+///
+/// All steps share the rate limit budget of the base check.
+ Future<PipelineReport>  run({required NetworkConfiguration config })=>RustLib.instance.api.crateApiEnginePipelineCheckPipelineRun(that: this, config: config);
+
+
+/// Appends a step that always runs.
+ Future<CheckPipeline>  step({required PipelineStep step })=>RustLib.instance.api.crateApiEnginePipelineCheckPipelineStep(that: this, step: step);
+
+
+/// Appends a step that only runs when `condition` is met.
+ Future<CheckPipeline>  stepIf({required PipelineStep step , required StepCondition condition })=>RustLib.instance.api.crateApiEnginePipelineCheckPipelineStepIf(that: this, step: step, condition: condition);
+
+
+            }
+            @sealed class CircuitBreakerImpl extends RustOpaque implements CircuitBreaker {
+                // Not to be used by end users
+                CircuitBreakerImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                CircuitBreakerImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_CircuitBreaker,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_CircuitBreaker,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_CircuitBreakerPtr,
+                );
+
+                /// Number of consecutive checks in which an essential target failed.
+ Future<int>  consecutiveFailures()=>RustLib.instance.api.crateApiEngineCircuitBreakerCircuitBreakerConsecutiveFailures(that: this, );
+
+
+/// Feeds the result of a finished check into the breaker.
+///
+/// Any check in which every essential target succeeded closes the breaker.
+ Future<void>  record({required NetworkReport report })=>RustLib.instance.api.crateApiEngineCircuitBreakerCircuitBreakerRecord(that: this, report: report);
+
+
+/// The current state, taking the cooldown into account.
+ Future<CircuitBreakerState>  state()=>RustLib.instance.api.crateApiEngineCircuitBreakerCircuitBreakerState(that: this, );
+
+
+            }
+            @sealed class DeviceInventoryImpl extends RustOpaque implements DeviceInventory {
+                // Not to be used by end users
+                DeviceInventoryImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                DeviceInventoryImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_DeviceInventory,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_DeviceInventory,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_DeviceInventoryPtr,
+                );
+
+                /// Replaces the stored scan with `scan` and returns what changed.
+///
+/// Devices are matched by MAC address where both scans know it, and by IP
+/// address where either does not. Events are ordered: IP changes, then joins, then
+/// departures.
+ Future<List<DeviceEvent>>  update({required List<LocalDevice> scan })=>RustLib.instance.api.crateApiEngineInventoryDeviceInventoryUpdate(that: this, scan: scan);
+
+
+            }
+            @sealed class NetworkMonitorImpl extends RustOpaque implements NetworkMonitor {
+                // Not to be used by end users
+                NetworkMonitorImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                NetworkMonitorImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_NetworkMonitor,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_NetworkMonitor,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_NetworkMonitorPtr,
+                );
+
+                /// Suspends the schedule. Does nothing unless running.
+ Future<void>  pause()=>RustLib.instance.api.crateApiMonitorNetworkMonitorPause(that: this, );
+
+
+/// Resumes a paused monitor with an immediate check. Does nothing unless
+/// paused.
+ Future<void>  resume()=>RustLib.instance.api.crateApiMonitorNetworkMonitorResume(that: this, );
+
+
+/// Stops monitoring like [Self::stop], then waits up to `timeout_ms` (0
+/// uses [DEFAULT_SHUTDOWN_TIMEOUT_MS](LibConstants::DEFAULT_SHUTDOWN_TIMEOUT_MS))
+/// for the monitoring task and the check it ran to be dropped. Returns
+/// false if that did not happen in time.
+///
+/// Once it returns true, no task of this monitor is left on the runtime.
+ Future<bool>  shutdown({required BigInt timeoutMs })=>RustLib.instance.api.crateApiMonitorNetworkMonitorShutdown(that: this, timeoutMs: timeoutMs);
+
+
+/// Starts monitoring with an immediate check, or resumes a paused monitor.
+///
+/// The monitoring task runs on the runtime injected via
+/// [Self::with_runtime], else on the caller's runtime, else (e.g. when
+/// called from Dart) on the bridge's runtime.
+ Future<void>  start()=>RustLib.instance.api.crateApiMonitorNetworkMonitorStart(that: this, );
+
+
+/// The current lifecycle state.
+ Future<MonitorState>  state()=>RustLib.instance.api.crateApiMonitorNetworkMonitorState(that: this, );
+
+
+/// Counters of completed, overrunning and skipped cycles.
+ Future<MonitorStats>  stats()=>RustLib.instance.api.crateApiMonitorNetworkMonitorStats(that: this, );
+
+
+/// Stops monitoring, abandoning a check under way, and ends all report
+/// streams. The monitor can be started again.
+ Future<void>  stop()=>RustLib.instance.api.crateApiMonitorNetworkMonitorStop(that: this, );
+
+
+/// Forwards [Self::reports] to Dart until the monitor stops or the Dart
+/// side cancels its subscription.
+ Stream<NetworkReport>  streamReports()=>RustLib.instance.api.crateApiMonitorNetworkMonitorStreamReports(that: this, );
+
+
+/// Flags anomalies with `detector` instead of a default [AnomalyDetector].
+/// Every start begins with the detector's baseline.
+ Future<NetworkMonitor>  withAnomalyDetector({required AnomalyDetector detector })=>RustLib.instance.api.crateApiMonitorNetworkMonitorWithAnomalyDetector(that: this, detector: detector);
+
+
+            }
+            @sealed class NetworkReportImpl extends RustOpaque implements NetworkReport {
+                // Not to be used by end users
+                NetworkReportImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                NetworkReportImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_NetworkReport,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_NetworkReport,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_NetworkReportPtr,
+                );
+
+                 List<AnomalyDetected> get anomalies=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetAnomalies(that: this, );
+
+
+ DegradationAttribution? get attribution=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetAttribution(that: this, );
+
+
+ BetterPathAvailable? get betterPath=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetBetterPath(that: this, );
+
+
+ String? get bssid=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetBssid(that: this, );
+
+
+ bool get cancelled=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetCancelled(that: this, );
+
+
+ CaptivePortalStatus? get captivePortal=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetCaptivePortal(that: this, );
+
+
+ ConnectionType get connectionType=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetConnectionType(that: this, );
+
+
+ BigInt get durationMs=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetDurationMs(that: this, );
+
+
+ RuntimeEnvironment get environment=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetEnvironment(that: this, );
+
+
+ FamilyComparison? get familyComparison=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetFamilyComparison(that: this, );
+
+
+ bool get isConstrained=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetIsConstrained(that: this, );
+
+
+ bool get isExpensive=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetIsExpensive(that: this, );
+
+
+ LayeredDiagnosis get layers=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetLayers(that: this, );
+
+
+ bool? get likelyIspOutage=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetLikelyIspOutage(that: this, );
+
+
+ BigInt get monotonicMs=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetMonotonicMs(that: this, );
+
+
+ bool? get multicastOk=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetMulticastOk(that: this, );
+
+
+ List<SampleRecord> get rawSamples=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetRawSamples(that: this, );
+
+
+ RoamEvent? get roam=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetRoam(that: this, );
+
+
+ NetworkScope get scope=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetScope(that: this, );
+
+
+ SecurityFlagsResult get securityFlagsResult=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetSecurityFlagsResult(that: this, );
+
+
+ List<SnmpDeviceInfo> get snmpDevices=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetSnmpDevices(that: this, );
+
+
+ String? get ssid=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetSsid(that: this, );
+
+
+ NetworkStatus get status=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetStatus(that: this, );
+
+
+ CheckStrategy get strategy=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetStrategy(that: this, );
+
+
+ List<TargetReport> get targetReports=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetTargetReports(that: this, );
+
+
+ BigInt get timestampMs=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetTimestampMs(that: this, );
+
+
+ RouteTrace? get traceroute=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetTraceroute(that: this, );
+
+
+ bool get truncated=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetTruncated(that: this, );
+
+
+ WalledGardenReport? get walledGarden=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorGetWalledGarden(that: this, );
+
+
+  set anomalies(List<AnomalyDetected> anomalies)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetAnomalies(that: this, anomalies: anomalies);
+
+
+  set attribution(DegradationAttribution? attribution)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetAttribution(that: this, attribution: attribution);
+
+
+  set betterPath(BetterPathAvailable? betterPath)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetBetterPath(that: this, betterPath: betterPath);
+
+
+  set bssid(String? bssid)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetBssid(that: this, bssid: bssid);
+
+
+  set cancelled(bool cancelled)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetCancelled(that: this, cancelled: cancelled);
+
+
+  set captivePortal(CaptivePortalStatus? captivePortal)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetCaptivePortal(that: this, captivePortal: captivePortal);
+
+
+  set connectionType(ConnectionType connectionType)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetConnectionType(that: this, connectionType: connectionType);
+
+
+  set durationMs(BigInt durationMs)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetDurationMs(that: this, durationMs: durationMs);
+
+
+  set environment(RuntimeEnvironment environment)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetEnvironment(that: this, environment: environment);
+
+
+  set familyComparison(FamilyComparison? familyComparison)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetFamilyComparison(that: this, familyComparison: familyComparison);
+
+
+  set isConstrained(bool isConstrained)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetIsConstrained(that: this, isConstrained: isConstrained);
+
+
+  set isExpensive(bool isExpensive)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetIsExpensive(that: this, isExpensive: isExpensive);
+
+
+  set layers(LayeredDiagnosis layers)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetLayers(that: this, layers: layers);
+
+
+  set likelyIspOutage(bool? likelyIspOutage)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetLikelyIspOutage(that: this, likelyIspOutage: likelyIspOutage);
+
+
+  set monotonicMs(BigInt monotonicMs)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetMonotonicMs(that: this, monotonicMs: monotonicMs);
+
+
+  set multicastOk(bool? multicastOk)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetMulticastOk(that: this, multicastOk: multicastOk);
+
+
+  set rawSamples(List<SampleRecord> rawSamples)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetRawSamples(that: this, rawSamples: rawSamples);
+
+
+  set roam(RoamEvent? roam)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetRoam(that: this, roam: roam);
+
+
+  set scope(NetworkScope scope)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetScope(that: this, scope: scope);
+
+
+  set securityFlagsResult(SecurityFlagsResult securityFlagsResult)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetSecurityFlagsResult(that: this, securityFlagsResult: securityFlagsResult);
+
+
+  set snmpDevices(List<SnmpDeviceInfo> snmpDevices)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetSnmpDevices(that: this, snmpDevices: snmpDevices);
+
+
+  set ssid(String? ssid)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetSsid(that: this, ssid: ssid);
+
+
+  set status(NetworkStatus status)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetStatus(that: this, status: status);
+
+
+  set strategy(CheckStrategy strategy)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetStrategy(that: this, strategy: strategy);
+
+
+  set targetReports(List<TargetReport> targetReports)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetTargetReports(that: this, targetReports: targetReports);
+
+
+  set timestampMs(BigInt timestampMs)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetTimestampMs(that: this, timestampMs: timestampMs);
+
+
+  set traceroute(RouteTrace? traceroute)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetTraceroute(that: this, traceroute: traceroute);
+
+
+  set truncated(bool truncated)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetTruncated(that: this, truncated: truncated);
+
+
+  set walledGarden(WalledGardenReport? walledGarden)=>RustLib.instance.api.crateApiModelsReportNetworkReportAutoAccessorSetWalledGarden(that: this, walledGarden: walledGarden);
+
+
+/// Collapses the report into a [SimpleStatus]:
+///
+/// - [Red](SimpleStatus::Red): not connected, only the local network is
+///   reachable, or a captive portal intercepts traffic.
+/// - [Yellow](SimpleStatus::Yellow): connected, but the quality is
+///   [Moderate](ConnectionQuality::Moderate) or worse, or DNS answers
+///   look spoofed.
+/// - [Green](SimpleStatus::Green): everything else, i.e. internet access
+///   of at least [Good](ConnectionQuality::Good) quality.
+ Future<SimpleStatus>  simpleStatus()=>RustLib.instance.api.crateApiModelsReportNetworkReportSimpleStatus(that: this, );
+
+
+            }
+            @sealed class PipelineReportImpl extends RustOpaque implements PipelineReport {
+                // Not to be used by end users
+                PipelineReportImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                PipelineReportImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_PipelineReport,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_PipelineReport,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_PipelineReportPtr,
+                );
+
+                 NetworkReport get report=>RustLib.instance.api.crateApiEnginePipelinePipelineReportAutoAccessorGetReport(that: this, );
+
+
+ List<StepOutcome> get steps=>RustLib.instance.api.crateApiEnginePipelinePipelineReportAutoAccessorGetSteps(that: this, );
+
+
+  set report(NetworkReport report)=>RustLib.instance.api.crateApiEnginePipelinePipelineReportAutoAccessorSetReport(that: this, report: report);
+
+
+  set steps(List<StepOutcome> steps)=>RustLib.instance.api.crateApiEnginePipelinePipelineReportAutoAccessorSetSteps(that: this, steps: steps);
+
+
+            }
+            @sealed class ReachabilityEngineImpl extends RustOpaque implements ReachabilityEngine {
+                // Not to be used by end users
+                ReachabilityEngineImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                ReachabilityEngineImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_ReachabilityEngine,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_ReachabilityEngine,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_ReachabilityEnginePtr,
+                );
+
+                /// Returns a report, reusing the cached one when possible.
+///
+/// # Logic Flow
+///
+/// 1. While the circuit breaker is open, the last report is returned
+///    without any network I/O.
+/// 2. A report younger than `cache_validity_ms` is returned as-is, unless
+///    the app's own requests contradict it (see [Self::report_app_failure]).
+///    A report confirmed by app successes stays valid for up to
+///    `passive_signal_window_ms`.
+/// 3. Otherwise a new check runs. Concurrent callers wait for it and
+///    share its result instead of starting checks of their own.
+ Future<NetworkReport>  check()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineCheck(that: this, );
+
+
+/// The current state of this engine's circuit breaker.
+ Future<CircuitBreakerState>  circuitState()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineCircuitState(that: this, );
+
+
+/// The retained full reports (at most the last hour), oldest first.
+ Future<List<NetworkReport>>  history()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineHistory(that: this, );
+
+
+/// Hourly and per-minute summaries of the reports that have aged out of
+/// [Self::history], oldest first. Hours come first, then the minutes of
+/// the last day.
+ Future<List<HistoryAggregate>>  historyAggregates()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineHistoryAggregates(that: this, );
+
+
+/// True once [Self::shutdown] was called.
+ Future<bool>  isShutDown()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineIsShutDown(that: this, );
+
+
+/// The error of the most recent app-reported request, if it failed.
+ Future<String?>  lastAppError()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineLastAppError(that: this, );
+
+
+/// The most recent report, if any check has completed.
+ Future<NetworkReport?>  lastReport()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineLastReport(that: this, );
+
+
+/// Forces a new check, ignoring the cache and the circuit breaker.
+ Future<NetworkReport>  refresh()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineRefresh(that: this, );
+
+
+/// Tells the engine that a request made by the app itself failed.
+///
+/// `error` is a description for diagnostics; only network failures should
+/// be reported, not HTTP error statuses. After
+/// [passive_failure_threshold](crate::api::models::ResilienceConfig::passive_failure_threshold)
+/// consecutive failures a cached "connected" report is no longer reused.
+ Future<void>  reportAppFailure({required String error })=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineReportAppFailure(that: this, error: error);
+
+
+/// Tells the engine that a request made by the app itself succeeded.
+///
+/// Successes let a cached "connected" report be reused for longer and make
+/// a cached "offline" report stale, so the next [Self::check] re-probes.
+ Future<void>  reportAppSuccess()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineReportAppSuccess(that: this, );
+
+
+/// Drops the cached report, the history, the anomaly baseline, the target
+/// health and the circuit breaker state.
+ Future<void>  reset()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineReset(that: this, );
+
+
+/// Cancels the check under way and waits up to `timeout_ms` (0 uses
+/// [DEFAULT_SHUTDOWN_TIMEOUT_MS](LibConstants::DEFAULT_SHUTDOWN_TIMEOUT_MS))
+/// for it to wind down. Returns false if it did not finish in time.
+///
+/// The cancelled check is returned to its callers flagged as
+/// [cancelled](NetworkReport::cancelled) and is not recorded. Later calls to
+/// [Self::check] still return a cached report, but no longer probe the
+/// network. Name lookups already handed to the OS cannot be interrupted;
+/// they finish on their own within their timeouts.
+ Future<bool>  shutdown({required BigInt timeoutMs })=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineShutdown(that: this, timeoutMs: timeoutMs);
+
+
+/// Captures the last report, the circuit breaker and the per-target
+/// health, to be persisted and passed to [Self::with_snapshot] later.
+ Future<EngineSnapshot>  snapshot()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineSnapshot(that: this, );
+
+
+/// Returns and clears the latency/loss anomalies seen since the last call,
+/// oldest first. See [AnomalyDetector].
+ Future<List<AnomalyDetected>>  takeAnomalies()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineTakeAnomalies(that: this, );
+
+
+/// How each configured target fared over the checks so far.
+ Future<List<TargetHealth>>  targetHealth()=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineTargetHealth(that: this, );
+
+
+/// Buckets the retained history, [Self::history_aggregates] included, by
+/// local hour and weekday. See
+/// [profile_time_of_day](crate::api::analysis::time_of_day::profile_time_of_day).
+ Future<TimeOfDayProfile>  timeOfDayProfile({required int utcOffsetMinutes })=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineTimeOfDayProfile(that: this, utcOffsetMinutes: utcOffsetMinutes);
+
+
+/// Restores the state saved by [Self::snapshot], typically by a previous
+/// run of the app.
+///
+/// The restored report is returned by [Self::last_report] right away and,
+/// while younger than `cache_validity_ms` (or while the restored circuit
+/// breaker is open), by [Self::check] too. Health entries of targets that
+/// are no longer configured are dropped.
+ Future<ReachabilityEngine>  withSnapshot({required EngineSnapshot snapshot })=>RustLib.instance.api.crateApiEngineReachabilityReachabilityEngineWithSnapshot(that: this, snapshot: snapshot);
+
+
+            }
+            @sealed class SecurityFlagsResultImpl extends RustOpaque implements SecurityFlagsResult {
+                // Not to be used by end users
+                SecurityFlagsResultImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                SecurityFlagsResultImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_SecurityFlagsResult,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_SecurityFlagsResult,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_SecurityFlagsResultPtr,
+                );
+
+                 Evidence get dnsSpoofEvidence=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetDnsSpoofEvidence(that: this, );
+
+
+ String get interfaceName=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetInterfaceName(that: this, );
+
+
+ bool get isDnsSpoofed=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsDnsSpoofed(that: this, );
+
+
+ bool get isProxyDetected=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsProxyDetected(that: this, );
+
+
+ bool get isVpnDetected=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetIsVpnDetected(that: this, );
+
+
+ Evidence get proxyEvidence=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetProxyEvidence(that: this, );
+
+
+ Evidence get vpnEvidence=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorGetVpnEvidence(that: this, );
+
+
+  set dnsSpoofEvidence(Evidence dnsSpoofEvidence)=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetDnsSpoofEvidence(that: this, dnsSpoofEvidence: dnsSpoofEvidence);
+
+
+  set interfaceName(String interfaceName)=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetInterfaceName(that: this, interfaceName: interfaceName);
+
+
+  set isDnsSpoofed(bool isDnsSpoofed)=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsDnsSpoofed(that: this, isDnsSpoofed: isDnsSpoofed);
+
+
+  set isProxyDetected(bool isProxyDetected)=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsProxyDetected(that: this, isProxyDetected: isProxyDetected);
+
+
+  set isVpnDetected(bool isVpnDetected)=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetIsVpnDetected(that: this, isVpnDetected: isVpnDetected);
+
+
+  set proxyEvidence(Evidence proxyEvidence)=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetProxyEvidence(that: this, proxyEvidence: proxyEvidence);
+
+
+  set vpnEvidence(Evidence vpnEvidence)=>RustLib.instance.api.crateApiModelsNetInfoSecurityFlagsResultAutoAccessorSetVpnEvidence(that: this, vpnEvidence: vpnEvidence);
+
+
+            }
+            @sealed class StabilityInputsImpl extends RustOpaque implements StabilityInputs {
+                // Not to be used by end users
+                StabilityInputsImpl.frbInternalDcoDecode(List<dynamic> wire):
+                    super.frbInternalDcoDecode(wire, _kStaticData);
+
+                // Not to be used by end users
+                StabilityInputsImpl.frbInternalSseDecode(BigInt ptr, int externalSizeOnNative):
+                    super.frbInternalSseDecode(ptr, externalSizeOnNative, _kStaticData);
+
+                static final _kStaticData = RustArcStaticData(
+                    rustArcIncrementStrongCount: RustLib.instance.api.rust_arc_increment_strong_count_StabilityInputs,
+                    rustArcDecrementStrongCount: RustLib.instance.api.rust_arc_decrement_strong_count_StabilityInputs,
+                    rustArcDecrementStrongCountPtr: RustLib.instance.api.rust_arc_decrement_strong_count_StabilityInputsPtr,
+                );
+
+                
+            }
\ No newline at end of file
@@ -1,853 +1,1887 @@
 // This file is automatically generated, so please do not edit it.
 // @generated by `flutter_rust_bridge`@ 2.12.0.
 
-// ignore_for_file: public_member_api_docs, unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
+// ignore_for_file: unused_import, unused_element, unnecessary_import, duplicate_ignore, invalid_use_of_internal_member, annotate_overrides, non_constant_identifier_names, curly_braces_in_flow_control_structures, prefer_const_literals_to_create_immutables, unused_field
+
 
 // Static analysis wrongly picks the IO variant, thus ignore this
 // ignore_for_file: argument_type_not_assignable
 
-import 'dart:async';
-import 'dart:convert';
-
-import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
-
+import 'api/analysis/anomaly.dart';
+import 'api/analysis/attribution.dart';
+import 'api/analysis/heatmap.dart';
+import 'api/analysis/layers.dart';
+import 'api/analysis/paths.dart';
 import 'api/analysis/quality.dart';
+import 'api/analysis/roaming.dart';
+import 'api/analysis/stability.dart';
 import 'api/analysis/stats.dart';
+import 'api/analysis/time_of_day.dart';
+import 'api/analysis/vpn.dart';
 import 'api/engine.dart';
+import 'api/engine/circuit_breaker.dart';
+import 'api/engine/clock.dart';
+import 'api/engine/environment.dart';
+import 'api/engine/hints.dart';
+import 'api/engine/inventory.dart';
+import 'api/engine/local.dart';
+import 'api/engine/pipeline.dart';
+import 'api/engine/preflight.dart';
+import 'api/engine/rate_limit.dart';
+import 'api/engine/reachability.dart';
 import 'api/engine/sampler.dart';
+import 'api/engine/schedule.dart';
 import 'api/engine/security.dart';
+import 'api/engine/vpn.dart';
 import 'api/models/config.dart';
+import 'api/models/diagnostics.dart';
+import 'api/models/error.dart';
 import 'api/models/net_info.dart';
 import 'api/models/report.dart';
+import 'api/models/scan.dart';
 import 'api/models/target.dart';
+import 'api/monitor.dart';
+import 'api/probes/apple_path.dart';
+import 'api/probes/arp.dart';
+import 'api/probes/capabilities.dart';
 import 'api/probes/captive_portal.dart';
 import 'api/probes/dns.dart';
+import 'api/probes/ecn.dart';
+import 'api/probes/environment.dart';
+import 'api/probes/hairpin.dart';
 import 'api/probes/interface.dart';
+import 'api/probes/isp_status.dart';
+import 'api/probes/lldp.dart';
+import 'api/probes/local_scan.dart';
+import 'api/probes/mdns.dart';
+import 'api/probes/mss.dart';
+import 'api/probes/multicast.dart';
+import 'api/probes/one_way_delay.dart';
+import 'api/probes/paths.dart';
+import 'api/probes/port_check.dart';
+import 'api/probes/qos.dart';
+import 'api/probes/reflection.dart';
 import 'api/probes/target.dart';
+import 'api/probes/tcp_info.dart';
+import 'api/probes/traceroute.dart';
+import 'api/probes/udp.dart';
+import 'api/probes/upnp.dart';
+import 'api/probes/walled_garden.dart';
+import 'dart:async';
+import 'dart:convert';
 import 'frb_generated.dart';
+import 'package:flutter_rust_bridge/flutter_rust_bridge_for_generated_web.dart';
 
-abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
-  RustLibApiImplPlatform({
-    required super.handler,
-    required super.wire,
-    required super.generalizedFrbRustBinding,
-    required super.portManager,
-  });
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_NetworkReportPtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport;
-
-  CrossPlatformFinalizerArg
-      get rust_arc_decrement_strong_count_SecurityFlagsResultPtr => wire
-          .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult;
-
-  @protected
-  NetworkReport
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final dynamic raw,
-  );
 
-  @protected
-  SecurityFlagsResult
-      dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final dynamic raw,
-  );
 
-  @protected
-  NetworkReport
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final dynamic raw,
-  );
 
-  @protected
-  SecurityFlagsResult
-      dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final dynamic raw,
-  );
+                abstract class RustLibApiImplPlatform extends BaseApiImpl<RustLibWire> {
+                  RustLibApiImplPlatform({
+                    required super.handler,
+                    required super.wire,
+                    required super.generalizedFrbRustBinding,
+                    required super.portManager,
+                  });
 
-  @protected
-  NetworkReport
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final dynamic raw,
-  );
+                  CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_AnomalyDetectorPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector;
 
-  @protected
-  SecurityFlagsResult
-      dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final dynamic raw,
-  );
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CheckPipelinePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline;
 
-  @protected
-  NetworkReport
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final dynamic raw,
-  );
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_CircuitBreakerPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker;
 
-  @protected
-  SecurityFlagsResult
-      dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final dynamic raw,
-  );
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_DeviceInventoryPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory;
 
-  @protected
-  String dco_decode_String(final dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_NetworkMonitorPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor;
 
-  @protected
-  bool dco_decode_bool(final dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_NetworkReportPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport;
 
-  @protected
-  double dco_decode_box_autoadd_f_64(final dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_PipelineReportPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport;
 
-  @protected
-  LatencyStats dco_decode_box_autoadd_latency_stats(final dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_ReachabilityEnginePtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine;
 
-  @protected
-  NativeProbe dco_decode_box_autoadd_native_probe(final dynamic raw);
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_SecurityFlagsResultPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult;
 
-  @protected
-  NetworkConfiguration dco_decode_box_autoadd_network_configuration(
-    final dynamic raw,
-  );
+CrossPlatformFinalizerArg get rust_arc_decrement_strong_count_StabilityInputsPtr => wire.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic;
 
-  @protected
-  NetworkTarget dco_decode_box_autoadd_network_target(final dynamic raw);
-
-  @protected
-  QualityThresholds dco_decode_box_autoadd_quality_thresholds(
-    final dynamic raw,
-  );
 
-  @protected
-  ResilienceConfig dco_decode_box_autoadd_resilience_config(final dynamic raw);
 
-  @protected
-  SecurityConfig dco_decode_box_autoadd_security_config(final dynamic raw);
+                  @protected AnyhowException dco_decode_AnyhowException(dynamic raw);
 
-  @protected
-  BigInt dco_decode_box_autoadd_u_64(final dynamic raw);
+@protected AnomalyDetector dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(dynamic raw);
 
-  @protected
-  WebProbe dco_decode_box_autoadd_web_probe(final dynamic raw);
+@protected CheckPipeline dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(dynamic raw);
 
-  @protected
-  CaptivePortalStatus dco_decode_captive_portal_status(final dynamic raw);
+@protected CircuitBreaker dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(dynamic raw);
 
-  @protected
-  CheckStrategy dco_decode_check_strategy(final dynamic raw);
+@protected DeviceInventory dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(dynamic raw);
 
-  @protected
-  ConnectionQuality dco_decode_connection_quality(final dynamic raw);
+@protected NetworkMonitor dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(dynamic raw);
 
-  @protected
-  ConnectionType dco_decode_connection_type(final dynamic raw);
+@protected NetworkReport dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw);
 
-  @protected
-  double dco_decode_f_32(final dynamic raw);
+@protected PipelineReport dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(dynamic raw);
 
-  @protected
-  double dco_decode_f_64(final dynamic raw);
+@protected ReachabilityEngine dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(dynamic raw);
 
-  @protected
-  int dco_decode_i_32(final dynamic raw);
+@protected SecurityFlagsResult dco_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(dynamic raw);
 
-  @protected
-  LatencyStats dco_decode_latency_stats(final dynamic raw);
+@protected AnomalyDetector dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(dynamic raw);
 
-  @protected
-  List<NetworkTarget> dco_decode_list_network_target(final dynamic raw);
+@protected CircuitBreaker dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(dynamic raw);
 
-  @protected
-  Uint64List dco_decode_list_prim_u_64_strict(final dynamic raw);
+@protected DeviceInventory dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(dynamic raw);
 
-  @protected
-  Uint8List dco_decode_list_prim_u_8_strict(final dynamic raw);
+@protected NetworkReport dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw);
 
-  @protected
-  List<TargetReport> dco_decode_list_target_report(final dynamic raw);
+@protected PipelineReport dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(dynamic raw);
 
-  @protected
-  NativeProbe dco_decode_native_probe(final dynamic raw);
+@protected SecurityFlagsResult dco_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(dynamic raw);
 
-  @protected
-  NetworkConfiguration dco_decode_network_configuration(final dynamic raw);
+@protected AnomalyDetector dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(dynamic raw);
 
-  @protected
-  NetworkStatus dco_decode_network_status(final dynamic raw);
+@protected CheckPipeline dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(dynamic raw);
 
-  @protected
-  NetworkTarget dco_decode_network_target(final dynamic raw);
+@protected CircuitBreaker dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(dynamic raw);
 
-  @protected
-  String? dco_decode_opt_String(final dynamic raw);
+@protected NetworkMonitor dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(dynamic raw);
 
-  @protected
-  double? dco_decode_opt_box_autoadd_f_64(final dynamic raw);
+@protected NetworkReport dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw);
 
-  @protected
-  BigInt? dco_decode_opt_box_autoadd_u_64(final dynamic raw);
+@protected PipelineReport dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(dynamic raw);
 
-  @protected
-  QualityThresholds dco_decode_quality_thresholds(final dynamic raw);
+@protected ReachabilityEngine dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(dynamic raw);
 
-  @protected
-  (
-    SecurityFlagsResult,
-    ConnectionType
-  ) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type(
-    final dynamic raw,
-  );
+@protected SecurityFlagsResult dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(dynamic raw);
 
-  @protected
-  (Uint64List, List<TargetReport>)
-      dco_decode_record_list_prim_u_64_strict_list_target_report(
-    final dynamic raw,
-  );
+@protected StabilityInputs dco_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(dynamic raw);
 
-  @protected
-  (
-    BigInt?,
-    BigInt?,
-    BigInt?,
-    double?
-  ) dco_decode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64(
-    final dynamic raw,
-  );
+@protected AnomalyDetector dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(dynamic raw);
 
-  @protected
-  ResilienceConfig dco_decode_resilience_config(final dynamic raw);
+@protected CheckPipeline dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(dynamic raw);
 
-  @protected
-  SecurityConfig dco_decode_security_config(final dynamic raw);
+@protected CircuitBreaker dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(dynamic raw);
 
-  @protected
-  SecurityFlags dco_decode_security_flags(final dynamic raw);
+@protected DeviceInventory dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(dynamic raw);
 
-  @protected
-  TargetProtocol dco_decode_target_protocol(final dynamic raw);
+@protected NetworkMonitor dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(dynamic raw);
 
-  @protected
-  TargetReport dco_decode_target_report(final dynamic raw);
+@protected NetworkReport dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw);
 
-  @protected
-  int dco_decode_u_16(final dynamic raw);
+@protected PipelineReport dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(dynamic raw);
 
-  @protected
-  BigInt dco_decode_u_64(final dynamic raw);
+@protected ReachabilityEngine dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(dynamic raw);
 
-  @protected
-  int dco_decode_u_8(final dynamic raw);
-
-  @protected
-  void dco_decode_unit(final dynamic raw);
-
-  @protected
-  BigInt dco_decode_usize(final dynamic raw);
-
-  @protected
-  WebProbe dco_decode_web_probe(final dynamic raw);
-
-  @protected
-  NetworkReport
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  SecurityFlagsResult
-      sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  NetworkReport
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  SecurityFlagsResult
-      sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  NetworkReport
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  SecurityFlagsResult
-      sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  NetworkReport
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  SecurityFlagsResult
-      sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  String sse_decode_String(final SseDeserializer deserializer);
-
-  @protected
-  bool sse_decode_bool(final SseDeserializer deserializer);
-
-  @protected
-  double sse_decode_box_autoadd_f_64(final SseDeserializer deserializer);
-
-  @protected
-  LatencyStats sse_decode_box_autoadd_latency_stats(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  NativeProbe sse_decode_box_autoadd_native_probe(
-    final SseDeserializer deserializer,
-  );
+@protected SecurityFlagsResult dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(dynamic raw);
 
-  @protected
-  NetworkConfiguration sse_decode_box_autoadd_network_configuration(
-    final SseDeserializer deserializer,
-  );
+@protected StabilityInputs dco_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(dynamic raw);
 
-  @protected
-  NetworkTarget sse_decode_box_autoadd_network_target(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  QualityThresholds sse_decode_box_autoadd_quality_thresholds(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  ResilienceConfig sse_decode_box_autoadd_resilience_config(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  SecurityConfig sse_decode_box_autoadd_security_config(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  BigInt sse_decode_box_autoadd_u_64(final SseDeserializer deserializer);
-
-  @protected
-  WebProbe sse_decode_box_autoadd_web_probe(final SseDeserializer deserializer);
-
-  @protected
-  CaptivePortalStatus sse_decode_captive_portal_status(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  CheckStrategy sse_decode_check_strategy(final SseDeserializer deserializer);
-
-  @protected
-  ConnectionQuality sse_decode_connection_quality(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  ConnectionType sse_decode_connection_type(final SseDeserializer deserializer);
-
-  @protected
-  double sse_decode_f_32(final SseDeserializer deserializer);
-
-  @protected
-  double sse_decode_f_64(final SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_i_32(final SseDeserializer deserializer);
-
-  @protected
-  LatencyStats sse_decode_latency_stats(final SseDeserializer deserializer);
-
-  @protected
-  List<NetworkTarget> sse_decode_list_network_target(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  Uint64List sse_decode_list_prim_u_64_strict(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  Uint8List sse_decode_list_prim_u_8_strict(final SseDeserializer deserializer);
-
-  @protected
-  List<TargetReport> sse_decode_list_target_report(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  NativeProbe sse_decode_native_probe(final SseDeserializer deserializer);
-
-  @protected
-  NetworkConfiguration sse_decode_network_configuration(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  NetworkStatus sse_decode_network_status(final SseDeserializer deserializer);
-
-  @protected
-  NetworkTarget sse_decode_network_target(final SseDeserializer deserializer);
-
-  @protected
-  String? sse_decode_opt_String(final SseDeserializer deserializer);
-
-  @protected
-  double? sse_decode_opt_box_autoadd_f_64(final SseDeserializer deserializer);
-
-  @protected
-  BigInt? sse_decode_opt_box_autoadd_u_64(final SseDeserializer deserializer);
-
-  @protected
-  QualityThresholds sse_decode_quality_thresholds(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  (
-    SecurityFlagsResult,
-    ConnectionType
-  ) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  (Uint64List, List<TargetReport>)
-      sse_decode_record_list_prim_u_64_strict_list_target_report(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  (
-    BigInt?,
-    BigInt?,
-    BigInt?,
-    double?
-  ) sse_decode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  ResilienceConfig sse_decode_resilience_config(
-    final SseDeserializer deserializer,
-  );
-
-  @protected
-  SecurityConfig sse_decode_security_config(final SseDeserializer deserializer);
-
-  @protected
-  SecurityFlags sse_decode_security_flags(final SseDeserializer deserializer);
-
-  @protected
-  TargetProtocol sse_decode_target_protocol(final SseDeserializer deserializer);
-
-  @protected
-  TargetReport sse_decode_target_report(final SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_16(final SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_u_64(final SseDeserializer deserializer);
-
-  @protected
-  int sse_decode_u_8(final SseDeserializer deserializer);
-
-  @protected
-  void sse_decode_unit(final SseDeserializer deserializer);
-
-  @protected
-  BigInt sse_decode_usize(final SseDeserializer deserializer);
-
-  @protected
-  WebProbe sse_decode_web_probe(final SseDeserializer deserializer);
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final NetworkReport self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void
-      sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SecurityFlagsResult self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final NetworkReport self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void
-      sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SecurityFlagsResult self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final NetworkReport self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void
-      sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SecurityFlagsResult self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final NetworkReport self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void
-      sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final SecurityFlagsResult self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_String(final String self, final SseSerializer serializer);
-
-  @protected
-  void sse_encode_bool(final bool self, final SseSerializer serializer);
-
-  @protected
-  void sse_encode_box_autoadd_f_64(
-    final double self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_latency_stats(
-    final LatencyStats self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_native_probe(
-    final NativeProbe self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_network_configuration(
-    final NetworkConfiguration self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_network_target(
-    final NetworkTarget self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_quality_thresholds(
-    final QualityThresholds self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_resilience_config(
-    final ResilienceConfig self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_security_config(
-    final SecurityConfig self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_u_64(
-    final BigInt self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_box_autoadd_web_probe(
-    final WebProbe self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_captive_portal_status(
-    final CaptivePortalStatus self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_check_strategy(
-    final CheckStrategy self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_connection_quality(
-    final ConnectionQuality self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_connection_type(
-    final ConnectionType self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_f_32(final double self, final SseSerializer serializer);
-
-  @protected
-  void sse_encode_f_64(final double self, final SseSerializer serializer);
-
-  @protected
-  void sse_encode_i_32(final int self, final SseSerializer serializer);
-
-  @protected
-  void sse_encode_latency_stats(
-    final LatencyStats self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_network_target(
-    final List<NetworkTarget> self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_64_strict(
-    final Uint64List self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_prim_u_8_strict(
-    final Uint8List self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_list_target_report(
-    final List<TargetReport> self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_native_probe(
-    final NativeProbe self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_network_configuration(
-    final NetworkConfiguration self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_network_status(
-    final NetworkStatus self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_network_target(
-    final NetworkTarget self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_String(
-    final String? self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_f_64(
-    final double? self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_opt_box_autoadd_u_64(
-    final BigInt? self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_quality_thresholds(
-    final QualityThresholds self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void
-      sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type(
-    final (SecurityFlagsResult, ConnectionType) self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_record_list_prim_u_64_strict_list_target_report(
-    final (Uint64List, List<TargetReport>) self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void
-      sse_encode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64(
-    final (BigInt?, BigInt?, BigInt?, double?) self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_resilience_config(
-    final ResilienceConfig self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_security_config(
-    final SecurityConfig self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_security_flags(
-    final SecurityFlags self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_target_protocol(
-    final TargetProtocol self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_target_report(
-    final TargetReport self,
-    final SseSerializer serializer,
-  );
-
-  @protected
-  void sse_encode_u_16(final int self, final SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_64(final BigInt self, final SseSerializer serializer);
-
-  @protected
-  void sse_encode_u_8(final int self, final SseSerializer serializer);
-
-  @protected
-  void sse_encode_unit(final void self, final SseSerializer serializer);
-
-  @protected
-  void sse_encode_usize(final BigInt self, final SseSerializer serializer);
-
-  @protected
-  void sse_encode_web_probe(
-    final WebProbe self,
-    final SseSerializer serializer,
-  );
-}
+@protected RustStreamSink<NetworkReport> dco_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport_Sse(dynamic raw);
 
-// Section: wire_class
+@protected RustStreamSink<ScanEvent> dco_decode_StreamSink_scan_event_Sse(dynamic raw);
 
-class RustLibWire implements BaseWire {
-  RustLibWire.fromExternalLibrary(final ExternalLibrary lib);
-
-  void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final int ptr,
-  ) =>
-          wasmModule
-              .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-            ptr,
-          );
-
-  void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final int ptr,
-  ) =>
-          wasmModule
-              .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-            ptr,
-          );
-
-  void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final int ptr,
-  ) =>
-          wasmModule
-              .rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-            ptr,
-          );
-
-  void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final int ptr,
-  ) =>
-          wasmModule
-              .rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-            ptr,
-          );
-}
-
-@JS('wasm_bindgen')
-external RustLibWasmModule get wasmModule;
-
-@JS()
-@anonymous
-extension type RustLibWasmModule._(JSObject _) implements JSObject {
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final int ptr,
-  );
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(
-    final int ptr,
-  );
-
-  external void
-      rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final int ptr,
-  );
-
-  external void
-      rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(
-    final int ptr,
-  );
-}
+@protected String dco_decode_String(dynamic raw);
+
+@protected StabilityModel dco_decode_TraitDef_StabilityModel(dynamic raw);
+
+@protected AddressFamily dco_decode_address_family(dynamic raw);
+
+@protected AnomalyDetected dco_decode_anomaly_detected(dynamic raw);
+
+@protected AnomalyMetric dco_decode_anomaly_metric(dynamic raw);
+
+@protected ApplePathInterfaceType dco_decode_apple_path_interface_type(dynamic raw);
+
+@protected ApplePathSnapshot dco_decode_apple_path_snapshot(dynamic raw);
+
+@protected ApplePathStatus dco_decode_apple_path_status(dynamic raw);
+
+@protected ArpEntry dco_decode_arp_entry(dynamic raw);
+
+@protected BetterPathAvailable dco_decode_better_path_available(dynamic raw);
+
+@protected bool dco_decode_bool(dynamic raw);
+
+@protected NetworkReport dco_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw);
+
+@protected AddressFamily dco_decode_box_autoadd_address_family(dynamic raw);
+
+@protected ApplePathSnapshot dco_decode_box_autoadd_apple_path_snapshot(dynamic raw);
+
+@protected BetterPathAvailable dco_decode_box_autoadd_better_path_available(dynamic raw);
+
+@protected bool dco_decode_box_autoadd_bool(dynamic raw);
+
+@protected CaptivePortalStatus dco_decode_box_autoadd_captive_portal_status(dynamic raw);
+
+@protected CheckStrategy dco_decode_box_autoadd_check_strategy(dynamic raw);
+
+@protected ConnectionType dco_decode_box_autoadd_connection_type(dynamic raw);
+
+@protected DegradationAttribution dco_decode_box_autoadd_degradation_attribution(dynamic raw);
+
+@protected EndpointConfig dco_decode_box_autoadd_endpoint_config(dynamic raw);
+
+@protected EngineSnapshot dco_decode_box_autoadd_engine_snapshot(dynamic raw);
+
+@protected double dco_decode_box_autoadd_f_32(dynamic raw);
+
+@protected double dco_decode_box_autoadd_f_64(dynamic raw);
+
+@protected FamilyComparison dco_decode_box_autoadd_family_comparison(dynamic raw);
+
+@protected PlatformInt64 dco_decode_box_autoadd_i_64(dynamic raw);
+
+@protected IcmpUnreachable dco_decode_box_autoadd_icmp_unreachable(dynamic raw);
+
+@protected LatencyStats dco_decode_box_autoadd_latency_stats(dynamic raw);
+
+@protected LinkNeighbor dco_decode_box_autoadd_link_neighbor(dynamic raw);
+
+@protected LocalDevice dco_decode_box_autoadd_local_device(dynamic raw);
+
+@protected LocalScanConfig dco_decode_box_autoadd_local_scan_config(dynamic raw);
+
+@protected NativeProbe dco_decode_box_autoadd_native_probe(dynamic raw);
+
+@protected NetworkConfiguration dco_decode_box_autoadd_network_configuration(dynamic raw);
+
+@protected NetworkError dco_decode_box_autoadd_network_error(dynamic raw);
+
+@protected NetworkTarget dco_decode_box_autoadd_network_target(dynamic raw);
+
+@protected PathSegment dco_decode_box_autoadd_path_segment(dynamic raw);
+
+@protected PlatformHints dco_decode_box_autoadd_platform_hints(dynamic raw);
+
+@protected PolicyViolation dco_decode_box_autoadd_policy_violation(dynamic raw);
+
+@protected ProbeFallback dco_decode_box_autoadd_probe_fallback(dynamic raw);
+
+@protected ProbeTimings dco_decode_box_autoadd_probe_timings(dynamic raw);
+
+@protected QualityThresholds dco_decode_box_autoadd_quality_thresholds(dynamic raw);
+
+@protected Requirements dco_decode_box_autoadd_requirements(dynamic raw);
+
+@protected ResilienceConfig dco_decode_box_autoadd_resilience_config(dynamic raw);
+
+@protected RoamEvent dco_decode_box_autoadd_roam_event(dynamic raw);
+
+@protected RouteTrace dco_decode_box_autoadd_route_trace(dynamic raw);
+
+@protected ScanSummary dco_decode_box_autoadd_scan_summary(dynamic raw);
+
+@protected SecurityConfig dco_decode_box_autoadd_security_config(dynamic raw);
+
+@protected StepCondition dco_decode_box_autoadd_step_condition(dynamic raw);
+
+@protected TargetReport dco_decode_box_autoadd_target_report(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_16(dynamic raw);
+
+@protected BigInt dco_decode_box_autoadd_u_64(dynamic raw);
+
+@protected int dco_decode_box_autoadd_u_8(dynamic raw);
+
+@protected UnmetRequirement dco_decode_box_autoadd_unmet_requirement(dynamic raw);
+
+@protected ValidationIssue dco_decode_box_autoadd_validation_issue(dynamic raw);
+
+@protected WalledGardenReport dco_decode_box_autoadd_walled_garden_report(dynamic raw);
+
+@protected WebProbe dco_decode_box_autoadd_web_probe(dynamic raw);
+
+@protected WeightedStabilityModel dco_decode_box_autoadd_weighted_stability_model(dynamic raw);
+
+@protected CaptivePortalStatus dco_decode_captive_portal_status(dynamic raw);
+
+@protected CheckStrategy dco_decode_check_strategy(dynamic raw);
+
+@protected CircuitBreakerState dco_decode_circuit_breaker_state(dynamic raw);
+
+@protected Confidence dco_decode_confidence(dynamic raw);
+
+@protected CongestionWindow dco_decode_congestion_window(dynamic raw);
+
+@protected ConnectionQuality dco_decode_connection_quality(dynamic raw);
+
+@protected ConnectionType dco_decode_connection_type(dynamic raw);
+
+@protected DegradationAttribution dco_decode_degradation_attribution(dynamic raw);
+
+@protected DegradationScope dco_decode_degradation_scope(dynamic raw);
+
+@protected DeviceEvent dco_decode_device_event(dynamic raw);
+
+@protected DscpLatency dco_decode_dscp_latency(dynamic raw);
+
+@protected EcnMarking dco_decode_ecn_marking(dynamic raw);
+
+@protected EcnObservation dco_decode_ecn_observation(dynamic raw);
+
+@protected EcnReport dco_decode_ecn_report(dynamic raw);
+
+@protected Encapsulation dco_decode_encapsulation(dynamic raw);
+
+@protected EndpointConfig dco_decode_endpoint_config(dynamic raw);
+
+@protected EngineSnapshot dco_decode_engine_snapshot(dynamic raw);
+
+@protected EnvironmentReport dco_decode_environment_report(dynamic raw);
+
+@protected Evidence dco_decode_evidence(dynamic raw);
+
+@protected EvidenceSource dco_decode_evidence_source(dynamic raw);
+
+@protected double dco_decode_f_32(dynamic raw);
+
+@protected double dco_decode_f_64(dynamic raw);
+
+@protected FamilyComparison dco_decode_family_comparison(dynamic raw);
+
+@protected FamilyStats dco_decode_family_stats(dynamic raw);
+
+@protected GatewayFingerprint dco_decode_gateway_fingerprint(dynamic raw);
+
+@protected HairpinReport dco_decode_hairpin_report(dynamic raw);
+
+@protected HistoryAggregate dco_decode_history_aggregate(dynamic raw);
+
+@protected int dco_decode_i_32(dynamic raw);
+
+@protected PlatformInt64 dco_decode_i_64(dynamic raw);
+
+@protected IcmpUnreachable dco_decode_icmp_unreachable(dynamic raw);
+
+@protected LatencyHeatmap dco_decode_latency_heatmap(dynamic raw);
+
+@protected LatencyStats dco_decode_latency_stats(dynamic raw);
+
+@protected LayerStatus dco_decode_layer_status(dynamic raw);
+
+@protected LayeredDiagnosis dco_decode_layered_diagnosis(dynamic raw);
+
+@protected Limitation dco_decode_limitation(dynamic raw);
+
+@protected LinkNeighbor dco_decode_link_neighbor(dynamic raw);
+
+@protected List<NetworkReport> dco_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw);
+
+@protected List<String> dco_decode_list_String(dynamic raw);
+
+@protected List<AnomalyDetected> dco_decode_list_anomaly_detected(dynamic raw);
+
+@protected List<ArpEntry> dco_decode_list_arp_entry(dynamic raw);
+
+@protected List<CongestionWindow> dco_decode_list_congestion_window(dynamic raw);
+
+@protected List<DegradationScope> dco_decode_list_degradation_scope(dynamic raw);
+
+@protected List<DeviceEvent> dco_decode_list_device_event(dynamic raw);
+
+@protected List<DscpLatency> dco_decode_list_dscp_latency(dynamic raw);
+
+@protected List<EcnObservation> dco_decode_list_ecn_observation(dynamic raw);
+
+@protected List<HistoryAggregate> dco_decode_list_history_aggregate(dynamic raw);
+
+@protected List<Limitation> dco_decode_list_limitation(dynamic raw);
+
+@protected List<List<double?>> dco_decode_list_list_opt_box_autoadd_f_32(dynamic raw);
+
+@protected List<List<BigInt?>> dco_decode_list_list_opt_box_autoadd_u_64(dynamic raw);
+
+@protected List<LocalDevice> dco_decode_list_local_device(dynamic raw);
+
+@protected List<MdnsService> dco_decode_list_mdns_service(dynamic raw);
+
+@protected List<MssObservation> dco_decode_list_mss_observation(dynamic raw);
+
+@protected List<NetworkTarget> dco_decode_list_network_target(dynamic raw);
+
+@protected List<String?> dco_decode_list_opt_String(dynamic raw);
+
+@protected List<double?> dco_decode_list_opt_box_autoadd_f_32(dynamic raw);
+
+@protected List<BigInt?> dco_decode_list_opt_box_autoadd_u_64(dynamic raw);
+
+@protected List<PathMeasurement> dco_decode_list_path_measurement(dynamic raw);
+
+@protected List<PortReachability> dco_decode_list_port_reachability(dynamic raw);
+
+@protected Uint16List dco_decode_list_prim_u_16_strict(dynamic raw);
+
+@protected Uint32List dco_decode_list_prim_u_32_strict(dynamic raw);
+
+@protected Uint64List dco_decode_list_prim_u_64_strict(dynamic raw);
+
+@protected List<int> dco_decode_list_prim_u_8_loose(dynamic raw);
+
+@protected Uint8List dco_decode_list_prim_u_8_strict(dynamic raw);
+
+@protected List<QualityReason> dco_decode_list_quality_reason(dynamic raw);
+
+@protected List<ReflectionObservation> dco_decode_list_reflection_observation(dynamic raw);
+
+@protected List<RouteTrace> dco_decode_list_route_trace(dynamic raw);
+
+@protected List<SampleRecord> dco_decode_list_sample_record(dynamic raw);
+
+@protected List<SampleTargetLatency> dco_decode_list_sample_target_latency(dynamic raw);
+
+@protected List<ServiceInfo> dco_decode_list_service_info(dynamic raw);
+
+@protected List<SnmpDeviceInfo> dco_decode_list_snmp_device_info(dynamic raw);
+
+@protected List<SnmpInterface> dco_decode_list_snmp_interface(dynamic raw);
+
+@protected List<StepOutcome> dco_decode_list_step_outcome(dynamic raw);
+
+@protected List<TargetHealth> dco_decode_list_target_health(dynamic raw);
+
+@protected List<TargetReport> dco_decode_list_target_report(dynamic raw);
+
+@protected List<TimeBucket> dco_decode_list_time_bucket(dynamic raw);
+
+@protected List<TraceHop> dco_decode_list_trace_hop(dynamic raw);
+
+@protected List<UnmetRequirement> dco_decode_list_unmet_requirement(dynamic raw);
+
+@protected List<ValidationIssue> dco_decode_list_validation_issue(dynamic raw);
+
+@protected LocalDevice dco_decode_local_device(dynamic raw);
+
+@protected LocalReachabilityReport dco_decode_local_reachability_report(dynamic raw);
+
+@protected LocalScanConfig dco_decode_local_scan_config(dynamic raw);
+
+@protected MdnsService dco_decode_mdns_service(dynamic raw);
+
+@protected MonitorState dco_decode_monitor_state(dynamic raw);
+
+@protected MonitorStats dco_decode_monitor_stats(dynamic raw);
+
+@protected MssObservation dco_decode_mss_observation(dynamic raw);
+
+@protected MssReport dco_decode_mss_report(dynamic raw);
+
+@protected MultiRouteTrace dco_decode_multi_route_trace(dynamic raw);
+
+@protected MulticastReport dco_decode_multicast_report(dynamic raw);
+
+@protected NatReport dco_decode_nat_report(dynamic raw);
+
+@protected NatType dco_decode_nat_type(dynamic raw);
+
+@protected NativeProbe dco_decode_native_probe(dynamic raw);
+
+@protected NeighborProtocol dco_decode_neighbor_protocol(dynamic raw);
+
+@protected NetworkConfiguration dco_decode_network_configuration(dynamic raw);
+
+@protected NetworkError dco_decode_network_error(dynamic raw);
+
+@protected NetworkScope dco_decode_network_scope(dynamic raw);
+
+@protected NetworkStatus dco_decode_network_status(dynamic raw);
+
+@protected NetworkTarget dco_decode_network_target(dynamic raw);
+
+@protected OneWayDelayEstimate dco_decode_one_way_delay_estimate(dynamic raw);
+
+@protected String? dco_decode_opt_String(dynamic raw);
+
+@protected NetworkReport? dco_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(dynamic raw);
+
+@protected AddressFamily? dco_decode_opt_box_autoadd_address_family(dynamic raw);
+
+@protected ApplePathSnapshot? dco_decode_opt_box_autoadd_apple_path_snapshot(dynamic raw);
+
+@protected BetterPathAvailable? dco_decode_opt_box_autoadd_better_path_available(dynamic raw);
+
+@protected bool? dco_decode_opt_box_autoadd_bool(dynamic raw);
+
+@protected CaptivePortalStatus? dco_decode_opt_box_autoadd_captive_portal_status(dynamic raw);
+
+@protected ConnectionType? dco_decode_opt_box_autoadd_connection_type(dynamic raw);
+
+@protected DegradationAttribution? dco_decode_opt_box_autoadd_degradation_attribution(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_32(dynamic raw);
+
+@protected double? dco_decode_opt_box_autoadd_f_64(dynamic raw);
+
+@protected FamilyComparison? dco_decode_opt_box_autoadd_family_comparison(dynamic raw);
+
+@protected PlatformInt64? dco_decode_opt_box_autoadd_i_64(dynamic raw);
+
+@protected IcmpUnreachable? dco_decode_opt_box_autoadd_icmp_unreachable(dynamic raw);
+
+@protected LinkNeighbor? dco_decode_opt_box_autoadd_link_neighbor(dynamic raw);
+
+@protected PathSegment? dco_decode_opt_box_autoadd_path_segment(dynamic raw);
+
+@protected PlatformHints? dco_decode_opt_box_autoadd_platform_hints(dynamic raw);
+
+@protected ProbeFallback? dco_decode_opt_box_autoadd_probe_fallback(dynamic raw);
+
+@protected ProbeTimings? dco_decode_opt_box_autoadd_probe_timings(dynamic raw);
+
+@protected RoamEvent? dco_decode_opt_box_autoadd_roam_event(dynamic raw);
+
+@protected RouteTrace? dco_decode_opt_box_autoadd_route_trace(dynamic raw);
+
+@protected TargetReport? dco_decode_opt_box_autoadd_target_report(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_16(dynamic raw);
+
+@protected BigInt? dco_decode_opt_box_autoadd_u_64(dynamic raw);
+
+@protected int? dco_decode_opt_box_autoadd_u_8(dynamic raw);
+
+@protected WalledGardenReport? dco_decode_opt_box_autoadd_walled_garden_report(dynamic raw);
+
+@protected OverlapPolicy dco_decode_overlap_policy(dynamic raw);
+
+@protected PathMeasurement dco_decode_path_measurement(dynamic raw);
+
+@protected PathSegment dco_decode_path_segment(dynamic raw);
+
+@protected PipelineStep dco_decode_pipeline_step(dynamic raw);
+
+@protected PlatformHints dco_decode_platform_hints(dynamic raw);
+
+@protected PolicyViolation dco_decode_policy_violation(dynamic raw);
+
+@protected PortForwardCheck dco_decode_port_forward_check(dynamic raw);
+
+@protected PortReachability dco_decode_port_reachability(dynamic raw);
+
+@protected PortalLoginKind dco_decode_portal_login_kind(dynamic raw);
+
+@protected PreflightReport dco_decode_preflight_report(dynamic raw);
+
+@protected ProbeCapabilities dco_decode_probe_capabilities(dynamic raw);
+
+@protected ProbeFallback dco_decode_probe_fallback(dynamic raw);
+
+@protected ProbeTimings dco_decode_probe_timings(dynamic raw);
+
+@protected QualityReason dco_decode_quality_reason(dynamic raw);
+
+@protected QualityThresholds dco_decode_quality_thresholds(dynamic raw);
+
+@protected RateLimitConfig dco_decode_rate_limit_config(dynamic raw);
+
+@protected (SecurityFlagsResult,ConnectionType) dco_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type(dynamic raw);
+
+@protected (bool,Evidence) dco_decode_record_bool_evidence(dynamic raw);
+
+@protected (Uint64List,List<TargetReport>) dco_decode_record_list_prim_u_64_strict_list_target_report(dynamic raw);
+
+@protected (BigInt?,BigInt?,BigInt?,double?) dco_decode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64(dynamic raw);
+
+@protected ReflectionObservation dco_decode_reflection_observation(dynamic raw);
+
+@protected Requirements dco_decode_requirements(dynamic raw);
+
+@protected ResilienceConfig dco_decode_resilience_config(dynamic raw);
+
+@protected RoamEvent dco_decode_roam_event(dynamic raw);
+
+@protected RouteTrace dco_decode_route_trace(dynamic raw);
+
+@protected RuntimeEnvironment dco_decode_runtime_environment(dynamic raw);
+
+@protected SampleMode dco_decode_sample_mode(dynamic raw);
+
+@protected SampleRecord dco_decode_sample_record(dynamic raw);
+
+@protected SampleRun dco_decode_sample_run(dynamic raw);
+
+@protected SampleTargetLatency dco_decode_sample_target_latency(dynamic raw);
+
+@protected ScanEvent dco_decode_scan_event(dynamic raw);
+
+@protected ScanMethod dco_decode_scan_method(dynamic raw);
+
+@protected ScanSummary dco_decode_scan_summary(dynamic raw);
+
+@protected SecurityConfig dco_decode_security_config(dynamic raw);
+
+@protected SecurityFlags dco_decode_security_flags(dynamic raw);
+
+@protected ServiceInfo dco_decode_service_info(dynamic raw);
+
+@protected ServiceReachability dco_decode_service_reachability(dynamic raw);
+
+@protected SimpleStatus dco_decode_simple_status(dynamic raw);
+
+@protected SnmpConfig dco_decode_snmp_config(dynamic raw);
+
+@protected SnmpDeviceInfo dco_decode_snmp_device_info(dynamic raw);
+
+@protected SnmpInterface dco_decode_snmp_interface(dynamic raw);
+
+@protected StepCondition dco_decode_step_condition(dynamic raw);
+
+@protected StepOutcome dco_decode_step_outcome(dynamic raw);
+
+@protected TargetHealth dco_decode_target_health(dynamic raw);
+
+@protected TargetProtocol dco_decode_target_protocol(dynamic raw);
+
+@protected TargetReport dco_decode_target_report(dynamic raw);
+
+@protected TcpInfo dco_decode_tcp_info(dynamic raw);
+
+@protected TimeBucket dco_decode_time_bucket(dynamic raw);
+
+@protected TimeOfDayProfile dco_decode_time_of_day_profile(dynamic raw);
+
+@protected TraceHop dco_decode_trace_hop(dynamic raw);
+
+@protected int dco_decode_u_16(dynamic raw);
+
+@protected int dco_decode_u_32(dynamic raw);
+
+@protected BigInt dco_decode_u_64(dynamic raw);
+
+@protected int dco_decode_u_8(dynamic raw);
+
+@protected UdpValidation dco_decode_udp_validation(dynamic raw);
+
+@protected void dco_decode_unit(dynamic raw);
+
+@protected UnmetRequirement dco_decode_unmet_requirement(dynamic raw);
+
+@protected UpnpDescription dco_decode_upnp_description(dynamic raw);
+
+@protected BigInt dco_decode_usize(dynamic raw);
+
+@protected ValidationIssue dco_decode_validation_issue(dynamic raw);
+
+@protected VpnComparison dco_decode_vpn_comparison(dynamic raw);
+
+@protected WalledGardenReport dco_decode_walled_garden_report(dynamic raw);
+
+@protected WebProbe dco_decode_web_probe(dynamic raw);
+
+@protected WeightedStabilityModel dco_decode_weighted_stability_model(dynamic raw);
+
+@protected AnyhowException sse_decode_AnyhowException(SseDeserializer deserializer);
+
+@protected AnomalyDetector sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(SseDeserializer deserializer);
+
+@protected CheckPipeline sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(SseDeserializer deserializer);
+
+@protected CircuitBreaker sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(SseDeserializer deserializer);
+
+@protected DeviceInventory sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(SseDeserializer deserializer);
+
+@protected NetworkMonitor sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(SseDeserializer deserializer);
+
+@protected NetworkReport sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer);
+
+@protected PipelineReport sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(SseDeserializer deserializer);
+
+@protected ReachabilityEngine sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(SseDeserializer deserializer);
+
+@protected SecurityFlagsResult sse_decode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SseDeserializer deserializer);
+
+@protected AnomalyDetector sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(SseDeserializer deserializer);
+
+@protected CircuitBreaker sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(SseDeserializer deserializer);
+
+@protected DeviceInventory sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(SseDeserializer deserializer);
+
+@protected NetworkReport sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer);
+
+@protected PipelineReport sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(SseDeserializer deserializer);
+
+@protected SecurityFlagsResult sse_decode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SseDeserializer deserializer);
+
+@protected AnomalyDetector sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(SseDeserializer deserializer);
+
+@protected CheckPipeline sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(SseDeserializer deserializer);
+
+@protected CircuitBreaker sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(SseDeserializer deserializer);
+
+@protected NetworkMonitor sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(SseDeserializer deserializer);
+
+@protected NetworkReport sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer);
+
+@protected PipelineReport sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(SseDeserializer deserializer);
+
+@protected ReachabilityEngine sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(SseDeserializer deserializer);
+
+@protected SecurityFlagsResult sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SseDeserializer deserializer);
+
+@protected StabilityInputs sse_decode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(SseDeserializer deserializer);
+
+@protected AnomalyDetector sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(SseDeserializer deserializer);
+
+@protected CheckPipeline sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(SseDeserializer deserializer);
+
+@protected CircuitBreaker sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(SseDeserializer deserializer);
+
+@protected DeviceInventory sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(SseDeserializer deserializer);
+
+@protected NetworkMonitor sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(SseDeserializer deserializer);
+
+@protected NetworkReport sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer);
+
+@protected PipelineReport sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(SseDeserializer deserializer);
+
+@protected ReachabilityEngine sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(SseDeserializer deserializer);
+
+@protected SecurityFlagsResult sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SseDeserializer deserializer);
+
+@protected StabilityInputs sse_decode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(SseDeserializer deserializer);
+
+@protected RustStreamSink<NetworkReport> sse_decode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport_Sse(SseDeserializer deserializer);
+
+@protected RustStreamSink<ScanEvent> sse_decode_StreamSink_scan_event_Sse(SseDeserializer deserializer);
+
+@protected String sse_decode_String(SseDeserializer deserializer);
+
+@protected AddressFamily sse_decode_address_family(SseDeserializer deserializer);
+
+@protected AnomalyDetected sse_decode_anomaly_detected(SseDeserializer deserializer);
+
+@protected AnomalyMetric sse_decode_anomaly_metric(SseDeserializer deserializer);
+
+@protected ApplePathInterfaceType sse_decode_apple_path_interface_type(SseDeserializer deserializer);
+
+@protected ApplePathSnapshot sse_decode_apple_path_snapshot(SseDeserializer deserializer);
+
+@protected ApplePathStatus sse_decode_apple_path_status(SseDeserializer deserializer);
+
+@protected ArpEntry sse_decode_arp_entry(SseDeserializer deserializer);
+
+@protected BetterPathAvailable sse_decode_better_path_available(SseDeserializer deserializer);
+
+@protected bool sse_decode_bool(SseDeserializer deserializer);
+
+@protected NetworkReport sse_decode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer);
+
+@protected AddressFamily sse_decode_box_autoadd_address_family(SseDeserializer deserializer);
+
+@protected ApplePathSnapshot sse_decode_box_autoadd_apple_path_snapshot(SseDeserializer deserializer);
+
+@protected BetterPathAvailable sse_decode_box_autoadd_better_path_available(SseDeserializer deserializer);
+
+@protected bool sse_decode_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected CaptivePortalStatus sse_decode_box_autoadd_captive_portal_status(SseDeserializer deserializer);
+
+@protected CheckStrategy sse_decode_box_autoadd_check_strategy(SseDeserializer deserializer);
+
+@protected ConnectionType sse_decode_box_autoadd_connection_type(SseDeserializer deserializer);
+
+@protected DegradationAttribution sse_decode_box_autoadd_degradation_attribution(SseDeserializer deserializer);
+
+@protected EndpointConfig sse_decode_box_autoadd_endpoint_config(SseDeserializer deserializer);
+
+@protected EngineSnapshot sse_decode_box_autoadd_engine_snapshot(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected FamilyComparison sse_decode_box_autoadd_family_comparison(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected IcmpUnreachable sse_decode_box_autoadd_icmp_unreachable(SseDeserializer deserializer);
+
+@protected LatencyStats sse_decode_box_autoadd_latency_stats(SseDeserializer deserializer);
+
+@protected LinkNeighbor sse_decode_box_autoadd_link_neighbor(SseDeserializer deserializer);
+
+@protected LocalDevice sse_decode_box_autoadd_local_device(SseDeserializer deserializer);
+
+@protected LocalScanConfig sse_decode_box_autoadd_local_scan_config(SseDeserializer deserializer);
+
+@protected NativeProbe sse_decode_box_autoadd_native_probe(SseDeserializer deserializer);
+
+@protected NetworkConfiguration sse_decode_box_autoadd_network_configuration(SseDeserializer deserializer);
+
+@protected NetworkError sse_decode_box_autoadd_network_error(SseDeserializer deserializer);
+
+@protected NetworkTarget sse_decode_box_autoadd_network_target(SseDeserializer deserializer);
+
+@protected PathSegment sse_decode_box_autoadd_path_segment(SseDeserializer deserializer);
+
+@protected PlatformHints sse_decode_box_autoadd_platform_hints(SseDeserializer deserializer);
+
+@protected PolicyViolation sse_decode_box_autoadd_policy_violation(SseDeserializer deserializer);
+
+@protected ProbeFallback sse_decode_box_autoadd_probe_fallback(SseDeserializer deserializer);
+
+@protected ProbeTimings sse_decode_box_autoadd_probe_timings(SseDeserializer deserializer);
+
+@protected QualityThresholds sse_decode_box_autoadd_quality_thresholds(SseDeserializer deserializer);
+
+@protected Requirements sse_decode_box_autoadd_requirements(SseDeserializer deserializer);
+
+@protected ResilienceConfig sse_decode_box_autoadd_resilience_config(SseDeserializer deserializer);
+
+@protected RoamEvent sse_decode_box_autoadd_roam_event(SseDeserializer deserializer);
+
+@protected RouteTrace sse_decode_box_autoadd_route_trace(SseDeserializer deserializer);
+
+@protected ScanSummary sse_decode_box_autoadd_scan_summary(SseDeserializer deserializer);
+
+@protected SecurityConfig sse_decode_box_autoadd_security_config(SseDeserializer deserializer);
+
+@protected StepCondition sse_decode_box_autoadd_step_condition(SseDeserializer deserializer);
+
+@protected TargetReport sse_decode_box_autoadd_target_report(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected UnmetRequirement sse_decode_box_autoadd_unmet_requirement(SseDeserializer deserializer);
+
+@protected ValidationIssue sse_decode_box_autoadd_validation_issue(SseDeserializer deserializer);
+
+@protected WalledGardenReport sse_decode_box_autoadd_walled_garden_report(SseDeserializer deserializer);
+
+@protected WebProbe sse_decode_box_autoadd_web_probe(SseDeserializer deserializer);
+
+@protected WeightedStabilityModel sse_decode_box_autoadd_weighted_stability_model(SseDeserializer deserializer);
+
+@protected CaptivePortalStatus sse_decode_captive_portal_status(SseDeserializer deserializer);
+
+@protected CheckStrategy sse_decode_check_strategy(SseDeserializer deserializer);
+
+@protected CircuitBreakerState sse_decode_circuit_breaker_state(SseDeserializer deserializer);
+
+@protected Confidence sse_decode_confidence(SseDeserializer deserializer);
+
+@protected CongestionWindow sse_decode_congestion_window(SseDeserializer deserializer);
+
+@protected ConnectionQuality sse_decode_connection_quality(SseDeserializer deserializer);
+
+@protected ConnectionType sse_decode_connection_type(SseDeserializer deserializer);
+
+@protected DegradationAttribution sse_decode_degradation_attribution(SseDeserializer deserializer);
+
+@protected DegradationScope sse_decode_degradation_scope(SseDeserializer deserializer);
+
+@protected DeviceEvent sse_decode_device_event(SseDeserializer deserializer);
+
+@protected DscpLatency sse_decode_dscp_latency(SseDeserializer deserializer);
+
+@protected EcnMarking sse_decode_ecn_marking(SseDeserializer deserializer);
+
+@protected EcnObservation sse_decode_ecn_observation(SseDeserializer deserializer);
+
+@protected EcnReport sse_decode_ecn_report(SseDeserializer deserializer);
+
+@protected Encapsulation sse_decode_encapsulation(SseDeserializer deserializer);
+
+@protected EndpointConfig sse_decode_endpoint_config(SseDeserializer deserializer);
+
+@protected EngineSnapshot sse_decode_engine_snapshot(SseDeserializer deserializer);
+
+@protected EnvironmentReport sse_decode_environment_report(SseDeserializer deserializer);
+
+@protected Evidence sse_decode_evidence(SseDeserializer deserializer);
+
+@protected EvidenceSource sse_decode_evidence_source(SseDeserializer deserializer);
+
+@protected double sse_decode_f_32(SseDeserializer deserializer);
+
+@protected double sse_decode_f_64(SseDeserializer deserializer);
+
+@protected FamilyComparison sse_decode_family_comparison(SseDeserializer deserializer);
+
+@protected FamilyStats sse_decode_family_stats(SseDeserializer deserializer);
+
+@protected GatewayFingerprint sse_decode_gateway_fingerprint(SseDeserializer deserializer);
+
+@protected HairpinReport sse_decode_hairpin_report(SseDeserializer deserializer);
+
+@protected HistoryAggregate sse_decode_history_aggregate(SseDeserializer deserializer);
+
+@protected int sse_decode_i_32(SseDeserializer deserializer);
+
+@protected PlatformInt64 sse_decode_i_64(SseDeserializer deserializer);
+
+@protected IcmpUnreachable sse_decode_icmp_unreachable(SseDeserializer deserializer);
+
+@protected LatencyHeatmap sse_decode_latency_heatmap(SseDeserializer deserializer);
+
+@protected LatencyStats sse_decode_latency_stats(SseDeserializer deserializer);
+
+@protected LayerStatus sse_decode_layer_status(SseDeserializer deserializer);
+
+@protected LayeredDiagnosis sse_decode_layered_diagnosis(SseDeserializer deserializer);
+
+@protected Limitation sse_decode_limitation(SseDeserializer deserializer);
+
+@protected LinkNeighbor sse_decode_link_neighbor(SseDeserializer deserializer);
+
+@protected List<NetworkReport> sse_decode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer);
+
+@protected List<String> sse_decode_list_String(SseDeserializer deserializer);
+
+@protected List<AnomalyDetected> sse_decode_list_anomaly_detected(SseDeserializer deserializer);
+
+@protected List<ArpEntry> sse_decode_list_arp_entry(SseDeserializer deserializer);
+
+@protected List<CongestionWindow> sse_decode_list_congestion_window(SseDeserializer deserializer);
+
+@protected List<DegradationScope> sse_decode_list_degradation_scope(SseDeserializer deserializer);
+
+@protected List<DeviceEvent> sse_decode_list_device_event(SseDeserializer deserializer);
+
+@protected List<DscpLatency> sse_decode_list_dscp_latency(SseDeserializer deserializer);
+
+@protected List<EcnObservation> sse_decode_list_ecn_observation(SseDeserializer deserializer);
+
+@protected List<HistoryAggregate> sse_decode_list_history_aggregate(SseDeserializer deserializer);
+
+@protected List<Limitation> sse_decode_list_limitation(SseDeserializer deserializer);
+
+@protected List<List<double?>> sse_decode_list_list_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+@protected List<List<BigInt?>> sse_decode_list_list_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected List<LocalDevice> sse_decode_list_local_device(SseDeserializer deserializer);
+
+@protected List<MdnsService> sse_decode_list_mdns_service(SseDeserializer deserializer);
+
+@protected List<MssObservation> sse_decode_list_mss_observation(SseDeserializer deserializer);
+
+@protected List<NetworkTarget> sse_decode_list_network_target(SseDeserializer deserializer);
+
+@protected List<String?> sse_decode_list_opt_String(SseDeserializer deserializer);
+
+@protected List<double?> sse_decode_list_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+@protected List<BigInt?> sse_decode_list_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected List<PathMeasurement> sse_decode_list_path_measurement(SseDeserializer deserializer);
+
+@protected List<PortReachability> sse_decode_list_port_reachability(SseDeserializer deserializer);
+
+@protected Uint16List sse_decode_list_prim_u_16_strict(SseDeserializer deserializer);
+
+@protected Uint32List sse_decode_list_prim_u_32_strict(SseDeserializer deserializer);
+
+@protected Uint64List sse_decode_list_prim_u_64_strict(SseDeserializer deserializer);
+
+@protected List<int> sse_decode_list_prim_u_8_loose(SseDeserializer deserializer);
+
+@protected Uint8List sse_decode_list_prim_u_8_strict(SseDeserializer deserializer);
+
+@protected List<QualityReason> sse_decode_list_quality_reason(SseDeserializer deserializer);
+
+@protected List<ReflectionObservation> sse_decode_list_reflection_observation(SseDeserializer deserializer);
+
+@protected List<RouteTrace> sse_decode_list_route_trace(SseDeserializer deserializer);
+
+@protected List<SampleRecord> sse_decode_list_sample_record(SseDeserializer deserializer);
+
+@protected List<SampleTargetLatency> sse_decode_list_sample_target_latency(SseDeserializer deserializer);
+
+@protected List<ServiceInfo> sse_decode_list_service_info(SseDeserializer deserializer);
+
+@protected List<SnmpDeviceInfo> sse_decode_list_snmp_device_info(SseDeserializer deserializer);
+
+@protected List<SnmpInterface> sse_decode_list_snmp_interface(SseDeserializer deserializer);
+
+@protected List<StepOutcome> sse_decode_list_step_outcome(SseDeserializer deserializer);
+
+@protected List<TargetHealth> sse_decode_list_target_health(SseDeserializer deserializer);
+
+@protected List<TargetReport> sse_decode_list_target_report(SseDeserializer deserializer);
+
+@protected List<TimeBucket> sse_decode_list_time_bucket(SseDeserializer deserializer);
+
+@protected List<TraceHop> sse_decode_list_trace_hop(SseDeserializer deserializer);
+
+@protected List<UnmetRequirement> sse_decode_list_unmet_requirement(SseDeserializer deserializer);
+
+@protected List<ValidationIssue> sse_decode_list_validation_issue(SseDeserializer deserializer);
+
+@protected LocalDevice sse_decode_local_device(SseDeserializer deserializer);
+
+@protected LocalReachabilityReport sse_decode_local_reachability_report(SseDeserializer deserializer);
+
+@protected LocalScanConfig sse_decode_local_scan_config(SseDeserializer deserializer);
+
+@protected MdnsService sse_decode_mdns_service(SseDeserializer deserializer);
+
+@protected MonitorState sse_decode_monitor_state(SseDeserializer deserializer);
+
+@protected MonitorStats sse_decode_monitor_stats(SseDeserializer deserializer);
+
+@protected MssObservation sse_decode_mss_observation(SseDeserializer deserializer);
+
+@protected MssReport sse_decode_mss_report(SseDeserializer deserializer);
+
+@protected MultiRouteTrace sse_decode_multi_route_trace(SseDeserializer deserializer);
+
+@protected MulticastReport sse_decode_multicast_report(SseDeserializer deserializer);
+
+@protected NatReport sse_decode_nat_report(SseDeserializer deserializer);
+
+@protected NatType sse_decode_nat_type(SseDeserializer deserializer);
+
+@protected NativeProbe sse_decode_native_probe(SseDeserializer deserializer);
+
+@protected NeighborProtocol sse_decode_neighbor_protocol(SseDeserializer deserializer);
+
+@protected NetworkConfiguration sse_decode_network_configuration(SseDeserializer deserializer);
+
+@protected NetworkError sse_decode_network_error(SseDeserializer deserializer);
+
+@protected NetworkScope sse_decode_network_scope(SseDeserializer deserializer);
+
+@protected NetworkStatus sse_decode_network_status(SseDeserializer deserializer);
+
+@protected NetworkTarget sse_decode_network_target(SseDeserializer deserializer);
+
+@protected OneWayDelayEstimate sse_decode_one_way_delay_estimate(SseDeserializer deserializer);
+
+@protected String? sse_decode_opt_String(SseDeserializer deserializer);
+
+@protected NetworkReport? sse_decode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(SseDeserializer deserializer);
+
+@protected AddressFamily? sse_decode_opt_box_autoadd_address_family(SseDeserializer deserializer);
+
+@protected ApplePathSnapshot? sse_decode_opt_box_autoadd_apple_path_snapshot(SseDeserializer deserializer);
+
+@protected BetterPathAvailable? sse_decode_opt_box_autoadd_better_path_available(SseDeserializer deserializer);
+
+@protected bool? sse_decode_opt_box_autoadd_bool(SseDeserializer deserializer);
+
+@protected CaptivePortalStatus? sse_decode_opt_box_autoadd_captive_portal_status(SseDeserializer deserializer);
+
+@protected ConnectionType? sse_decode_opt_box_autoadd_connection_type(SseDeserializer deserializer);
+
+@protected DegradationAttribution? sse_decode_opt_box_autoadd_degradation_attribution(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_32(SseDeserializer deserializer);
+
+@protected double? sse_decode_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected FamilyComparison? sse_decode_opt_box_autoadd_family_comparison(SseDeserializer deserializer);
+
+@protected PlatformInt64? sse_decode_opt_box_autoadd_i_64(SseDeserializer deserializer);
+
+@protected IcmpUnreachable? sse_decode_opt_box_autoadd_icmp_unreachable(SseDeserializer deserializer);
+
+@protected LinkNeighbor? sse_decode_opt_box_autoadd_link_neighbor(SseDeserializer deserializer);
+
+@protected PathSegment? sse_decode_opt_box_autoadd_path_segment(SseDeserializer deserializer);
+
+@protected PlatformHints? sse_decode_opt_box_autoadd_platform_hints(SseDeserializer deserializer);
+
+@protected ProbeFallback? sse_decode_opt_box_autoadd_probe_fallback(SseDeserializer deserializer);
+
+@protected ProbeTimings? sse_decode_opt_box_autoadd_probe_timings(SseDeserializer deserializer);
+
+@protected RoamEvent? sse_decode_opt_box_autoadd_roam_event(SseDeserializer deserializer);
+
+@protected RouteTrace? sse_decode_opt_box_autoadd_route_trace(SseDeserializer deserializer);
+
+@protected TargetReport? sse_decode_opt_box_autoadd_target_report(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_16(SseDeserializer deserializer);
+
+@protected BigInt? sse_decode_opt_box_autoadd_u_64(SseDeserializer deserializer);
+
+@protected int? sse_decode_opt_box_autoadd_u_8(SseDeserializer deserializer);
+
+@protected WalledGardenReport? sse_decode_opt_box_autoadd_walled_garden_report(SseDeserializer deserializer);
+
+@protected OverlapPolicy sse_decode_overlap_policy(SseDeserializer deserializer);
+
+@protected PathMeasurement sse_decode_path_measurement(SseDeserializer deserializer);
+
+@protected PathSegment sse_decode_path_segment(SseDeserializer deserializer);
+
+@protected PipelineStep sse_decode_pipeline_step(SseDeserializer deserializer);
+
+@protected PlatformHints sse_decode_platform_hints(SseDeserializer deserializer);
+
+@protected PolicyViolation sse_decode_policy_violation(SseDeserializer deserializer);
+
+@protected PortForwardCheck sse_decode_port_forward_check(SseDeserializer deserializer);
+
+@protected PortReachability sse_decode_port_reachability(SseDeserializer deserializer);
+
+@protected PortalLoginKind sse_decode_portal_login_kind(SseDeserializer deserializer);
+
+@protected PreflightReport sse_decode_preflight_report(SseDeserializer deserializer);
+
+@protected ProbeCapabilities sse_decode_probe_capabilities(SseDeserializer deserializer);
+
+@protected ProbeFallback sse_decode_probe_fallback(SseDeserializer deserializer);
+
+@protected ProbeTimings sse_decode_probe_timings(SseDeserializer deserializer);
+
+@protected QualityReason sse_decode_quality_reason(SseDeserializer deserializer);
+
+@protected QualityThresholds sse_decode_quality_thresholds(SseDeserializer deserializer);
+
+@protected RateLimitConfig sse_decode_rate_limit_config(SseDeserializer deserializer);
+
+@protected (SecurityFlagsResult,ConnectionType) sse_decode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type(SseDeserializer deserializer);
+
+@protected (bool,Evidence) sse_decode_record_bool_evidence(SseDeserializer deserializer);
+
+@protected (Uint64List,List<TargetReport>) sse_decode_record_list_prim_u_64_strict_list_target_report(SseDeserializer deserializer);
+
+@protected (BigInt?,BigInt?,BigInt?,double?) sse_decode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64(SseDeserializer deserializer);
+
+@protected ReflectionObservation sse_decode_reflection_observation(SseDeserializer deserializer);
+
+@protected Requirements sse_decode_requirements(SseDeserializer deserializer);
+
+@protected ResilienceConfig sse_decode_resilience_config(SseDeserializer deserializer);
+
+@protected RoamEvent sse_decode_roam_event(SseDeserializer deserializer);
+
+@protected RouteTrace sse_decode_route_trace(SseDeserializer deserializer);
+
+@protected RuntimeEnvironment sse_decode_runtime_environment(SseDeserializer deserializer);
+
+@protected SampleMode sse_decode_sample_mode(SseDeserializer deserializer);
+
+@protected SampleRecord sse_decode_sample_record(SseDeserializer deserializer);
+
+@protected SampleRun sse_decode_sample_run(SseDeserializer deserializer);
+
+@protected SampleTargetLatency sse_decode_sample_target_latency(SseDeserializer deserializer);
+
+@protected ScanEvent sse_decode_scan_event(SseDeserializer deserializer);
+
+@protected ScanMethod sse_decode_scan_method(SseDeserializer deserializer);
+
+@protected ScanSummary sse_decode_scan_summary(SseDeserializer deserializer);
+
+@protected SecurityConfig sse_decode_security_config(SseDeserializer deserializer);
+
+@protected SecurityFlags sse_decode_security_flags(SseDeserializer deserializer);
+
+@protected ServiceInfo sse_decode_service_info(SseDeserializer deserializer);
+
+@protected ServiceReachability sse_decode_service_reachability(SseDeserializer deserializer);
+
+@protected SimpleStatus sse_decode_simple_status(SseDeserializer deserializer);
+
+@protected SnmpConfig sse_decode_snmp_config(SseDeserializer deserializer);
+
+@protected SnmpDeviceInfo sse_decode_snmp_device_info(SseDeserializer deserializer);
+
+@protected SnmpInterface sse_decode_snmp_interface(SseDeserializer deserializer);
+
+@protected StepCondition sse_decode_step_condition(SseDeserializer deserializer);
+
+@protected StepOutcome sse_decode_step_outcome(SseDeserializer deserializer);
+
+@protected TargetHealth sse_decode_target_health(SseDeserializer deserializer);
+
+@protected TargetProtocol sse_decode_target_protocol(SseDeserializer deserializer);
+
+@protected TargetReport sse_decode_target_report(SseDeserializer deserializer);
+
+@protected TcpInfo sse_decode_tcp_info(SseDeserializer deserializer);
+
+@protected TimeBucket sse_decode_time_bucket(SseDeserializer deserializer);
+
+@protected TimeOfDayProfile sse_decode_time_of_day_profile(SseDeserializer deserializer);
+
+@protected TraceHop sse_decode_trace_hop(SseDeserializer deserializer);
+
+@protected int sse_decode_u_16(SseDeserializer deserializer);
+
+@protected int sse_decode_u_32(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_u_64(SseDeserializer deserializer);
+
+@protected int sse_decode_u_8(SseDeserializer deserializer);
+
+@protected UdpValidation sse_decode_udp_validation(SseDeserializer deserializer);
+
+@protected void sse_decode_unit(SseDeserializer deserializer);
+
+@protected UnmetRequirement sse_decode_unmet_requirement(SseDeserializer deserializer);
+
+@protected UpnpDescription sse_decode_upnp_description(SseDeserializer deserializer);
+
+@protected BigInt sse_decode_usize(SseDeserializer deserializer);
+
+@protected ValidationIssue sse_decode_validation_issue(SseDeserializer deserializer);
+
+@protected VpnComparison sse_decode_vpn_comparison(SseDeserializer deserializer);
+
+@protected WalledGardenReport sse_decode_walled_garden_report(SseDeserializer deserializer);
+
+@protected WebProbe sse_decode_web_probe(SseDeserializer deserializer);
+
+@protected WeightedStabilityModel sse_decode_weighted_stability_model(SseDeserializer deserializer);
+
+@protected void sse_encode_AnyhowException(AnyhowException self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(AnomalyDetector self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(CheckPipeline self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(CircuitBreaker self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(DeviceInventory self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(NetworkMonitor self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(NetworkReport self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(PipelineReport self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(ReachabilityEngine self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SecurityFlagsResult self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(AnomalyDetector self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(CircuitBreaker self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(DeviceInventory self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(NetworkReport self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(PipelineReport self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_RefMut_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SecurityFlagsResult self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(AnomalyDetector self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(CheckPipeline self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(CircuitBreaker self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(NetworkMonitor self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(NetworkReport self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(PipelineReport self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(ReachabilityEngine self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SecurityFlagsResult self, SseSerializer serializer);
+
+@protected void sse_encode_Auto_Ref_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(StabilityInputs self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(AnomalyDetector self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(CheckPipeline self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(CircuitBreaker self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(DeviceInventory self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(NetworkMonitor self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(NetworkReport self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(PipelineReport self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(ReachabilityEngine self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(SecurityFlagsResult self, SseSerializer serializer);
+
+@protected void sse_encode_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(StabilityInputs self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport_Sse(RustStreamSink<NetworkReport> self, SseSerializer serializer);
+
+@protected void sse_encode_StreamSink_scan_event_Sse(RustStreamSink<ScanEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_String(String self, SseSerializer serializer);
+
+@protected void sse_encode_address_family(AddressFamily self, SseSerializer serializer);
+
+@protected void sse_encode_anomaly_detected(AnomalyDetected self, SseSerializer serializer);
+
+@protected void sse_encode_anomaly_metric(AnomalyMetric self, SseSerializer serializer);
+
+@protected void sse_encode_apple_path_interface_type(ApplePathInterfaceType self, SseSerializer serializer);
+
+@protected void sse_encode_apple_path_snapshot(ApplePathSnapshot self, SseSerializer serializer);
+
+@protected void sse_encode_apple_path_status(ApplePathStatus self, SseSerializer serializer);
+
+@protected void sse_encode_arp_entry(ArpEntry self, SseSerializer serializer);
+
+@protected void sse_encode_better_path_available(BetterPathAvailable self, SseSerializer serializer);
+
+@protected void sse_encode_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(NetworkReport self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_address_family(AddressFamily self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_apple_path_snapshot(ApplePathSnapshot self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_better_path_available(BetterPathAvailable self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_bool(bool self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_captive_portal_status(CaptivePortalStatus self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_check_strategy(CheckStrategy self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_connection_type(ConnectionType self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_degradation_attribution(DegradationAttribution self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_endpoint_config(EndpointConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_engine_snapshot(EngineSnapshot self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_family_comparison(FamilyComparison self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_icmp_unreachable(IcmpUnreachable self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_latency_stats(LatencyStats self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_link_neighbor(LinkNeighbor self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_local_device(LocalDevice self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_local_scan_config(LocalScanConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_native_probe(NativeProbe self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_network_configuration(NetworkConfiguration self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_network_error(NetworkError self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_network_target(NetworkTarget self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_path_segment(PathSegment self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_platform_hints(PlatformHints self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_policy_violation(PolicyViolation self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_probe_fallback(ProbeFallback self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_probe_timings(ProbeTimings self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_quality_thresholds(QualityThresholds self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_requirements(Requirements self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_resilience_config(ResilienceConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_roam_event(RoamEvent self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_route_trace(RouteTrace self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_scan_summary(ScanSummary self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_security_config(SecurityConfig self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_step_condition(StepCondition self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_target_report(TargetReport self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_unmet_requirement(UnmetRequirement self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_validation_issue(ValidationIssue self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_walled_garden_report(WalledGardenReport self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_web_probe(WebProbe self, SseSerializer serializer);
+
+@protected void sse_encode_box_autoadd_weighted_stability_model(WeightedStabilityModel self, SseSerializer serializer);
+
+@protected void sse_encode_captive_portal_status(CaptivePortalStatus self, SseSerializer serializer);
+
+@protected void sse_encode_check_strategy(CheckStrategy self, SseSerializer serializer);
+
+@protected void sse_encode_circuit_breaker_state(CircuitBreakerState self, SseSerializer serializer);
+
+@protected void sse_encode_confidence(Confidence self, SseSerializer serializer);
+
+@protected void sse_encode_congestion_window(CongestionWindow self, SseSerializer serializer);
+
+@protected void sse_encode_connection_quality(ConnectionQuality self, SseSerializer serializer);
+
+@protected void sse_encode_connection_type(ConnectionType self, SseSerializer serializer);
+
+@protected void sse_encode_degradation_attribution(DegradationAttribution self, SseSerializer serializer);
+
+@protected void sse_encode_degradation_scope(DegradationScope self, SseSerializer serializer);
+
+@protected void sse_encode_device_event(DeviceEvent self, SseSerializer serializer);
+
+@protected void sse_encode_dscp_latency(DscpLatency self, SseSerializer serializer);
+
+@protected void sse_encode_ecn_marking(EcnMarking self, SseSerializer serializer);
+
+@protected void sse_encode_ecn_observation(EcnObservation self, SseSerializer serializer);
+
+@protected void sse_encode_ecn_report(EcnReport self, SseSerializer serializer);
+
+@protected void sse_encode_encapsulation(Encapsulation self, SseSerializer serializer);
+
+@protected void sse_encode_endpoint_config(EndpointConfig self, SseSerializer serializer);
+
+@protected void sse_encode_engine_snapshot(EngineSnapshot self, SseSerializer serializer);
+
+@protected void sse_encode_environment_report(EnvironmentReport self, SseSerializer serializer);
+
+@protected void sse_encode_evidence(Evidence self, SseSerializer serializer);
+
+@protected void sse_encode_evidence_source(EvidenceSource self, SseSerializer serializer);
+
+@protected void sse_encode_f_32(double self, SseSerializer serializer);
+
+@protected void sse_encode_f_64(double self, SseSerializer serializer);
+
+@protected void sse_encode_family_comparison(FamilyComparison self, SseSerializer serializer);
+
+@protected void sse_encode_family_stats(FamilyStats self, SseSerializer serializer);
+
+@protected void sse_encode_gateway_fingerprint(GatewayFingerprint self, SseSerializer serializer);
+
+@protected void sse_encode_hairpin_report(HairpinReport self, SseSerializer serializer);
+
+@protected void sse_encode_history_aggregate(HistoryAggregate self, SseSerializer serializer);
+
+@protected void sse_encode_i_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_i_64(PlatformInt64 self, SseSerializer serializer);
+
+@protected void sse_encode_icmp_unreachable(IcmpUnreachable self, SseSerializer serializer);
+
+@protected void sse_encode_latency_heatmap(LatencyHeatmap self, SseSerializer serializer);
+
+@protected void sse_encode_latency_stats(LatencyStats self, SseSerializer serializer);
+
+@protected void sse_encode_layer_status(LayerStatus self, SseSerializer serializer);
+
+@protected void sse_encode_layered_diagnosis(LayeredDiagnosis self, SseSerializer serializer);
+
+@protected void sse_encode_limitation(Limitation self, SseSerializer serializer);
+
+@protected void sse_encode_link_neighbor(LinkNeighbor self, SseSerializer serializer);
+
+@protected void sse_encode_list_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(List<NetworkReport> self, SseSerializer serializer);
+
+@protected void sse_encode_list_String(List<String> self, SseSerializer serializer);
+
+@protected void sse_encode_list_anomaly_detected(List<AnomalyDetected> self, SseSerializer serializer);
+
+@protected void sse_encode_list_arp_entry(List<ArpEntry> self, SseSerializer serializer);
+
+@protected void sse_encode_list_congestion_window(List<CongestionWindow> self, SseSerializer serializer);
+
+@protected void sse_encode_list_degradation_scope(List<DegradationScope> self, SseSerializer serializer);
+
+@protected void sse_encode_list_device_event(List<DeviceEvent> self, SseSerializer serializer);
+
+@protected void sse_encode_list_dscp_latency(List<DscpLatency> self, SseSerializer serializer);
+
+@protected void sse_encode_list_ecn_observation(List<EcnObservation> self, SseSerializer serializer);
+
+@protected void sse_encode_list_history_aggregate(List<HistoryAggregate> self, SseSerializer serializer);
+
+@protected void sse_encode_list_limitation(List<Limitation> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_opt_box_autoadd_f_32(List<List<double?>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_list_opt_box_autoadd_u_64(List<List<BigInt?>> self, SseSerializer serializer);
+
+@protected void sse_encode_list_local_device(List<LocalDevice> self, SseSerializer serializer);
+
+@protected void sse_encode_list_mdns_service(List<MdnsService> self, SseSerializer serializer);
+
+@protected void sse_encode_list_mss_observation(List<MssObservation> self, SseSerializer serializer);
+
+@protected void sse_encode_list_network_target(List<NetworkTarget> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_String(List<String?> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_box_autoadd_f_32(List<double?> self, SseSerializer serializer);
+
+@protected void sse_encode_list_opt_box_autoadd_u_64(List<BigInt?> self, SseSerializer serializer);
+
+@protected void sse_encode_list_path_measurement(List<PathMeasurement> self, SseSerializer serializer);
+
+@protected void sse_encode_list_port_reachability(List<PortReachability> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_16_strict(Uint16List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_32_strict(Uint32List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_64_strict(Uint64List self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_loose(List<int> self, SseSerializer serializer);
+
+@protected void sse_encode_list_prim_u_8_strict(Uint8List self, SseSerializer serializer);
+
+@protected void sse_encode_list_quality_reason(List<QualityReason> self, SseSerializer serializer);
+
+@protected void sse_encode_list_reflection_observation(List<ReflectionObservation> self, SseSerializer serializer);
+
+@protected void sse_encode_list_route_trace(List<RouteTrace> self, SseSerializer serializer);
+
+@protected void sse_encode_list_sample_record(List<SampleRecord> self, SseSerializer serializer);
+
+@protected void sse_encode_list_sample_target_latency(List<SampleTargetLatency> self, SseSerializer serializer);
+
+@protected void sse_encode_list_service_info(List<ServiceInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_snmp_device_info(List<SnmpDeviceInfo> self, SseSerializer serializer);
+
+@protected void sse_encode_list_snmp_interface(List<SnmpInterface> self, SseSerializer serializer);
+
+@protected void sse_encode_list_step_outcome(List<StepOutcome> self, SseSerializer serializer);
+
+@protected void sse_encode_list_target_health(List<TargetHealth> self, SseSerializer serializer);
+
+@protected void sse_encode_list_target_report(List<TargetReport> self, SseSerializer serializer);
+
+@protected void sse_encode_list_time_bucket(List<TimeBucket> self, SseSerializer serializer);
+
+@protected void sse_encode_list_trace_hop(List<TraceHop> self, SseSerializer serializer);
+
+@protected void sse_encode_list_unmet_requirement(List<UnmetRequirement> self, SseSerializer serializer);
+
+@protected void sse_encode_list_validation_issue(List<ValidationIssue> self, SseSerializer serializer);
+
+@protected void sse_encode_local_device(LocalDevice self, SseSerializer serializer);
+
+@protected void sse_encode_local_reachability_report(LocalReachabilityReport self, SseSerializer serializer);
+
+@protected void sse_encode_local_scan_config(LocalScanConfig self, SseSerializer serializer);
+
+@protected void sse_encode_mdns_service(MdnsService self, SseSerializer serializer);
+
+@protected void sse_encode_monitor_state(MonitorState self, SseSerializer serializer);
+
+@protected void sse_encode_monitor_stats(MonitorStats self, SseSerializer serializer);
+
+@protected void sse_encode_mss_observation(MssObservation self, SseSerializer serializer);
+
+@protected void sse_encode_mss_report(MssReport self, SseSerializer serializer);
+
+@protected void sse_encode_multi_route_trace(MultiRouteTrace self, SseSerializer serializer);
+
+@protected void sse_encode_multicast_report(MulticastReport self, SseSerializer serializer);
+
+@protected void sse_encode_nat_report(NatReport self, SseSerializer serializer);
+
+@protected void sse_encode_nat_type(NatType self, SseSerializer serializer);
+
+@protected void sse_encode_native_probe(NativeProbe self, SseSerializer serializer);
+
+@protected void sse_encode_neighbor_protocol(NeighborProtocol self, SseSerializer serializer);
+
+@protected void sse_encode_network_configuration(NetworkConfiguration self, SseSerializer serializer);
+
+@protected void sse_encode_network_error(NetworkError self, SseSerializer serializer);
+
+@protected void sse_encode_network_scope(NetworkScope self, SseSerializer serializer);
+
+@protected void sse_encode_network_status(NetworkStatus self, SseSerializer serializer);
+
+@protected void sse_encode_network_target(NetworkTarget self, SseSerializer serializer);
+
+@protected void sse_encode_one_way_delay_estimate(OneWayDelayEstimate self, SseSerializer serializer);
+
+@protected void sse_encode_opt_String(String? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_Auto_Owned_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(NetworkReport? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_address_family(AddressFamily? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_apple_path_snapshot(ApplePathSnapshot? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_better_path_available(BetterPathAvailable? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_bool(bool? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_captive_portal_status(CaptivePortalStatus? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_connection_type(ConnectionType? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_degradation_attribution(DegradationAttribution? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_32(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_f_64(double? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_family_comparison(FamilyComparison? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_i_64(PlatformInt64? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_icmp_unreachable(IcmpUnreachable? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_link_neighbor(LinkNeighbor? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_path_segment(PathSegment? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_platform_hints(PlatformHints? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_probe_fallback(ProbeFallback? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_probe_timings(ProbeTimings? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_roam_event(RoamEvent? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_route_trace(RouteTrace? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_target_report(TargetReport? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_16(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_64(BigInt? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_u_8(int? self, SseSerializer serializer);
+
+@protected void sse_encode_opt_box_autoadd_walled_garden_report(WalledGardenReport? self, SseSerializer serializer);
+
+@protected void sse_encode_overlap_policy(OverlapPolicy self, SseSerializer serializer);
+
+@protected void sse_encode_path_measurement(PathMeasurement self, SseSerializer serializer);
+
+@protected void sse_encode_path_segment(PathSegment self, SseSerializer serializer);
+
+@protected void sse_encode_pipeline_step(PipelineStep self, SseSerializer serializer);
+
+@protected void sse_encode_platform_hints(PlatformHints self, SseSerializer serializer);
+
+@protected void sse_encode_policy_violation(PolicyViolation self, SseSerializer serializer);
+
+@protected void sse_encode_port_forward_check(PortForwardCheck self, SseSerializer serializer);
+
+@protected void sse_encode_port_reachability(PortReachability self, SseSerializer serializer);
+
+@protected void sse_encode_portal_login_kind(PortalLoginKind self, SseSerializer serializer);
+
+@protected void sse_encode_preflight_report(PreflightReport self, SseSerializer serializer);
+
+@protected void sse_encode_probe_capabilities(ProbeCapabilities self, SseSerializer serializer);
+
+@protected void sse_encode_probe_fallback(ProbeFallback self, SseSerializer serializer);
+
+@protected void sse_encode_probe_timings(ProbeTimings self, SseSerializer serializer);
+
+@protected void sse_encode_quality_reason(QualityReason self, SseSerializer serializer);
+
+@protected void sse_encode_quality_thresholds(QualityThresholds self, SseSerializer serializer);
+
+@protected void sse_encode_rate_limit_config(RateLimitConfig self, SseSerializer serializer);
+
+@protected void sse_encode_record_auto_owned_rust_opaque_flutter_rust_bridgefor_generated_rust_auto_opaque_inner_security_flags_result_connection_type((SecurityFlagsResult,ConnectionType) self, SseSerializer serializer);
+
+@protected void sse_encode_record_bool_evidence((bool,Evidence) self, SseSerializer serializer);
+
+@protected void sse_encode_record_list_prim_u_64_strict_list_target_report((Uint64List,List<TargetReport>) self, SseSerializer serializer);
+
+@protected void sse_encode_record_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_u_64_opt_box_autoadd_f_64((BigInt?,BigInt?,BigInt?,double?) self, SseSerializer serializer);
+
+@protected void sse_encode_reflection_observation(ReflectionObservation self, SseSerializer serializer);
+
+@protected void sse_encode_requirements(Requirements self, SseSerializer serializer);
+
+@protected void sse_encode_resilience_config(ResilienceConfig self, SseSerializer serializer);
+
+@protected void sse_encode_roam_event(RoamEvent self, SseSerializer serializer);
+
+@protected void sse_encode_route_trace(RouteTrace self, SseSerializer serializer);
+
+@protected void sse_encode_runtime_environment(RuntimeEnvironment self, SseSerializer serializer);
+
+@protected void sse_encode_sample_mode(SampleMode self, SseSerializer serializer);
+
+@protected void sse_encode_sample_record(SampleRecord self, SseSerializer serializer);
+
+@protected void sse_encode_sample_run(SampleRun self, SseSerializer serializer);
+
+@protected void sse_encode_sample_target_latency(SampleTargetLatency self, SseSerializer serializer);
+
+@protected void sse_encode_scan_event(ScanEvent self, SseSerializer serializer);
+
+@protected void sse_encode_scan_method(ScanMethod self, SseSerializer serializer);
+
+@protected void sse_encode_scan_summary(ScanSummary self, SseSerializer serializer);
+
+@protected void sse_encode_security_config(SecurityConfig self, SseSerializer serializer);
+
+@protected void sse_encode_security_flags(SecurityFlags self, SseSerializer serializer);
+
+@protected void sse_encode_service_info(ServiceInfo self, SseSerializer serializer);
+
+@protected void sse_encode_service_reachability(ServiceReachability self, SseSerializer serializer);
+
+@protected void sse_encode_simple_status(SimpleStatus self, SseSerializer serializer);
+
+@protected void sse_encode_snmp_config(SnmpConfig self, SseSerializer serializer);
+
+@protected void sse_encode_snmp_device_info(SnmpDeviceInfo self, SseSerializer serializer);
+
+@protected void sse_encode_snmp_interface(SnmpInterface self, SseSerializer serializer);
+
+@protected void sse_encode_step_condition(StepCondition self, SseSerializer serializer);
+
+@protected void sse_encode_step_outcome(StepOutcome self, SseSerializer serializer);
+
+@protected void sse_encode_target_health(TargetHealth self, SseSerializer serializer);
+
+@protected void sse_encode_target_protocol(TargetProtocol self, SseSerializer serializer);
+
+@protected void sse_encode_target_report(TargetReport self, SseSerializer serializer);
+
+@protected void sse_encode_tcp_info(TcpInfo self, SseSerializer serializer);
+
+@protected void sse_encode_time_bucket(TimeBucket self, SseSerializer serializer);
+
+@protected void sse_encode_time_of_day_profile(TimeOfDayProfile self, SseSerializer serializer);
+
+@protected void sse_encode_trace_hop(TraceHop self, SseSerializer serializer);
+
+@protected void sse_encode_u_16(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_32(int self, SseSerializer serializer);
+
+@protected void sse_encode_u_64(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_u_8(int self, SseSerializer serializer);
+
+@protected void sse_encode_udp_validation(UdpValidation self, SseSerializer serializer);
+
+@protected void sse_encode_unit(void self, SseSerializer serializer);
+
+@protected void sse_encode_unmet_requirement(UnmetRequirement self, SseSerializer serializer);
+
+@protected void sse_encode_upnp_description(UpnpDescription self, SseSerializer serializer);
+
+@protected void sse_encode_usize(BigInt self, SseSerializer serializer);
+
+@protected void sse_encode_validation_issue(ValidationIssue self, SseSerializer serializer);
+
+@protected void sse_encode_vpn_comparison(VpnComparison self, SseSerializer serializer);
+
+@protected void sse_encode_walled_garden_report(WalledGardenReport self, SseSerializer serializer);
+
+@protected void sse_encode_web_probe(WebProbe self, SseSerializer serializer);
+
+@protected void sse_encode_weighted_stability_model(WeightedStabilityModel self, SseSerializer serializer);
+                }
+                
+
+
+// Section: wire_class
+
+class RustLibWire implements BaseWire {
+            RustLibWire.fromExternalLibrary(ExternalLibrary lib);
+
+            void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(ptr);
+
+void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(int ptr) => wasmModule.rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(ptr);
+
+void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(int ptr) => wasmModule.rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(ptr);
+        }
+        @JS('wasm_bindgen') external RustLibWasmModule get wasmModule;
+
+        @JS() @anonymous extension type RustLibWasmModule._(JSObject _) implements JSObject {
+            external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerAnomalyDetector(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCheckPipeline(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerCircuitBreaker(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerDeviceInventory(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkMonitor(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerNetworkReport(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerPipelineReport(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerReachabilityEngine(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerSecurityFlagsResult(int ptr);
+
+external void rust_arc_increment_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(int ptr);
+
+external void rust_arc_decrement_strong_count_RustOpaque_flutter_rust_bridgefor_generatedRustAutoOpaqueInnerStabilityInputsstatic(int ptr);
+        }
+        
\ No newline at end of file
@@ -0,0 +1,55 @@
+//! Benchmarks of the probe and sampling hot paths against a loopback server.
+//!
+//! Run with `cargo bench --features bench`.
+
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use network_reachability::api::{
+    bench::{loopback_config, sample_once},
+    engine::sampler::collect_network_samples,
+    models::SampleMode,
+    probes::check_target,
+    testsupport::TestServer,
+};
+use tokio::runtime::Runtime;
+
+fn bench_check_target(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    let server = rt.block_on(TestServer::tcp_echo().start()).unwrap();
+    let target = server.target("loopback");
+
+    c.bench_function("check_target/tcp", |b| {
+        b.to_async(&rt).iter(|| check_target(&target));
+    });
+}
+
+fn bench_sampling(c: &mut Criterion) {
+    let rt = Runtime::new().unwrap();
+    let server = rt.block_on(TestServer::tcp_echo().start()).unwrap();
+    let mut group = c.benchmark_group("sample_once");
+    for targets in [1, 4, 16] {
+        let config = loopback_config(&server, targets);
+        group.bench_with_input(
+            BenchmarkId::from_parameter(targets),
+            &config,
+            |b, config| {
+                b.to_async(&rt).iter(|| sample_once(config));
+            },
+        );
+    }
+    group.finish();
+
+    let mut config = loopback_config(&server, 4);
+    config.resilience.num_jitter_samples = 5;
+    config.resilience.parallel_sample_offset_ms = 0;
+    let mut group = c.benchmark_group("collect_network_samples");
+    for mode in [SampleMode::Sequential, SampleMode::Parallel] {
+        config.resilience.sample_mode = mode;
+        group.bench_with_input(BenchmarkId::from_parameter(mode), &config, |b, config| {
+            b.to_async(&rt).iter(|| collect_network_samples(config));
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_check_target, bench_sampling);
+criterion_main!(benches);
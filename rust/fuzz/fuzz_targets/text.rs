@@ -0,0 +1,6 @@
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+use network_reachability::api::fuzzing::parse_text;
+
+fuzz_target!(|data: &[u8]| parse_text(data));
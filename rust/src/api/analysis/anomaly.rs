@@ -0,0 +1,191 @@
+//! # Online Anomaly Detection
+//!
+//! Flags checks whose latency or packet loss is statistically unusual for the
+//! current network, using exponentially weighted moving averages (EWMA) of the
+//! mean and variance. A value more than `z_threshold` smoothed standard
+//! deviations above the baseline raises an [AnomalyDetected] event.
+//!
+//! Only regressions are flagged: a check that is unusually *good* is not an
+//! anomaly worth surfacing. Every value, anomalous or not, still updates the
+//! baseline, so a lasting shift (e.g. moving from WiFi to cellular) stops
+//! being reported once the bands have adapted.
+
+use crate::api::{
+    constants::LibConstants,
+    models::{AnomalyDetected, AnomalyMetric, NetworkReport},
+};
+use flutter_rust_bridge::frb;
+
+/// EWMA estimate of one series' mean and variance.
+#[frb(ignore)]
+#[derive(Debug, Clone, Default)]
+struct EwmaBand {
+    mean: f64,
+    variance: f64,
+    count: u32,
+}
+
+impl EwmaBand {
+    /// Returns the z-score of `value` against the band *before* absorbing it,
+    /// or `None` while the band is still warming up.
+    fn observe(
+        &mut self,
+        value: f64,
+        alpha: f64,
+        warmup: u32,
+        min_std_dev: f64,
+    ) -> Option<(f64, f64, f64)> {
+        let previous = (self.count >= warmup).then(|| {
+            let std_dev = self.variance.sqrt().max(min_std_dev);
+            (self.mean, std_dev, (value - self.mean) / std_dev)
+        });
+
+        if self.count == 0 {
+            self.mean = value;
+        } else {
+            let delta = value - self.mean;
+            self.mean += alpha * delta;
+            self.variance = (1.0 - alpha) * (self.variance + alpha * delta * delta);
+        }
+        self.count = self.count.saturating_add(1);
+        previous
+    }
+}
+
+/// Streams reports through EWMA control bands for latency and packet loss.
+///
+/// ```ignore
+/// let mut detector = AnomalyDetector::default();
+/// for report in reports {
+///     for event in detector.observe(&report) {
+///         log::warn!("{:?} at {}: z={:.1}", event.metric, event.timestamp_ms, event.z_score);
+///     }
+/// }
+/// ```
+#[frb(opaque)]
+#[derive(Debug, Clone)]
+pub struct AnomalyDetector {
+    /// EWMA smoothing factor in `(0, 1]`; higher reacts faster.
+    pub smoothing: f64,
+
+    /// Standard deviations above the baseline that count as anomalous.
+    pub z_threshold: f64,
+
+    /// Reports absorbed before any event can fire.
+    pub warmup_reports: u32,
+
+    latency: EwmaBand,
+    loss: EwmaBand,
+}
+
+impl Default for AnomalyDetector {
+    fn default() -> Self {
+        Self::new(
+            LibConstants::DEFAULT_ANOMALY_SMOOTHING,
+            LibConstants::DEFAULT_ANOMALY_Z_THRESHOLD,
+            LibConstants::DEFAULT_ANOMALY_WARMUP_REPORTS,
+        )
+    }
+}
+
+impl AnomalyDetector {
+    /// Creates a detector with an empty baseline.
+    pub fn new(smoothing: f64, z_threshold: f64, warmup_reports: u32) -> Self {
+        Self {
+            smoothing: smoothing.clamp(f64::EPSILON, 1.0),
+            z_threshold,
+            warmup_reports,
+            latency: EwmaBand::default(),
+            loss: EwmaBand::default(),
+        }
+    }
+
+    /// Feeds one report and returns the anomalies it exhibits (at most one per metric).
+    ///
+    /// Latency is only tracked for connected reports; loss is tracked always,
+    /// so that an outage shows up as a loss anomaly.
+    pub fn observe(&mut self, report: &NetworkReport) -> Vec<AnomalyDetected> {
+        let stats = &report.status.latency_stats;
+        let mut events = Vec::new();
+
+        if report.status.is_connected {
+            let value = stats.latency_ms as f64;
+            let band = self.latency.observe(
+                value,
+                self.smoothing,
+                self.warmup_reports,
+                LibConstants::ANOMALY_MIN_LATENCY_STD_DEV_MS,
+            );
+            events.extend(self.event(AnomalyMetric::Latency, report, value, band));
+        }
+
+        let value = f64::from(stats.packet_loss_percent);
+        let band = self.loss.observe(
+            value,
+            self.smoothing,
+            self.warmup_reports,
+            LibConstants::ANOMALY_MIN_LOSS_STD_DEV_PERCENT,
+        );
+        events.extend(self.event(AnomalyMetric::PacketLoss, report, value, band));
+
+        events
+    }
+
+    /// Forgets the baseline, e.g. after the device switched networks.
+    pub fn reset(&mut self) {
+        self.latency = EwmaBand::default();
+        self.loss = EwmaBand::default();
+    }
+
+    fn event(
+        &self,
+        metric: AnomalyMetric,
+        report: &NetworkReport,
+        value: f64,
+        band: Option<(f64, f64, f64)>,
+    ) -> Option<AnomalyDetected> {
+        let (expected, std_dev, z_score) = band?;
+        (z_score > self.z_threshold).then_some(AnomalyDetected {
+            metric,
+            timestamp_ms: report.timestamp_ms,
+            value,
+            expected,
+            std_dev,
+            z_score,
+            connection_type: report.connection_type,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observe(detector: &mut AnomalyDetector, latency: u64) -> Vec<AnomalyDetected> {
+        let mut report = NetworkReport::fixture(true);
+        report.status.latency_stats.latency_ms = latency;
+        detector.observe(&report)
+    }
+
+    #[test]
+    fn test_detector_flags_regressions_after_warmup_only() {
+        let mut detector = AnomalyDetector::new(0.2, 3.0, 5);
+
+        // Nothing fires while the baseline is still warming up.
+        for latency in [40, 42, 38, 41, 400] {
+            assert!(observe(&mut detector, latency).is_empty());
+        }
+        detector.reset();
+        for latency in [40, 42, 38, 41, 39, 40, 43, 40] {
+            assert!(observe(&mut detector, latency).is_empty());
+        }
+
+        let events = observe(&mut detector, 400);
+        assert_eq!(events.len(), 1);
+        assert_eq!(events[0].metric, AnomalyMetric::Latency);
+        assert!(events[0].z_score > 3.0);
+
+        // Faster than usual is never an anomaly.
+        assert!(observe(&mut detector, 5).is_empty());
+    }
+}
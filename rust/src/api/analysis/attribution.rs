@@ -0,0 +1,108 @@
+//! # First-Hop Attribution
+//!
+//! Compares the latency statistics towards the gateway with the end-to-end
+//! ones to tell a poor local link (Wi-Fi interference, a saturated router)
+//! from trouble further upstream. Every end-to-end probe crosses the first
+//! hop, so whatever variance and loss the gateway already shows is part of
+//! the end-to-end figures.
+
+use crate::api::{
+    constants::LibConstants,
+    models::{DegradationAttribution, LatencyStats, PathSegment},
+};
+
+/// Attributes the degradation of `end_to_end` to the first hop or the rest of
+/// the path, given `gateway` measured over the same connection.
+///
+/// Variance is compared as the square of the standard deviations
+/// ([jitter_ms](LatencyStats::jitter_ms)); all shares are capped at 1.0, as
+/// the first hop cannot account for more than the whole path.
+///
+/// The dominant segment is [PathSegment::Lan] if the first hop accounts for
+/// at least [ATTRIBUTION_LAN_SHARE](LibConstants::ATTRIBUTION_LAN_SHARE) of
+/// the end-to-end loss or variance, and [PathSegment::Isp] otherwise. Variance
+/// only counts once the end-to-end jitter reaches
+/// [ATTRIBUTION_MIN_JITTER_MS](LibConstants::ATTRIBUTION_MIN_JITTER_MS).
+pub fn attribute_degradation(
+    gateway: &LatencyStats,
+    end_to_end: &LatencyStats,
+) -> DegradationAttribution {
+    let latency_share = end_to_end
+        .avg_latency_ms
+        .filter(|&ms| ms > 0)
+        .map(|ms| share(gateway.latency_ms as f64, ms as f64));
+    let variance_share = (end_to_end.jitter_ms > 0).then(|| {
+        share(
+            (gateway.jitter_ms as f64).powi(2),
+            (end_to_end.jitter_ms as f64).powi(2),
+        )
+    });
+    let loss_share = (end_to_end.packet_loss_percent > 0.0).then(|| {
+        share(
+            gateway.packet_loss_percent as f64,
+            end_to_end.packet_loss_percent as f64,
+        )
+    });
+
+    let significant_variance =
+        variance_share.filter(|_| end_to_end.jitter_ms >= LibConstants::ATTRIBUTION_MIN_JITTER_MS);
+    let dominant_segment = significant_variance
+        .into_iter()
+        .chain(loss_share)
+        .reduce(f32::max)
+        .map(|first_hop| {
+            if first_hop >= LibConstants::ATTRIBUTION_LAN_SHARE {
+                PathSegment::Lan
+            } else {
+                PathSegment::Isp
+            }
+        });
+
+    DegradationAttribution {
+        gateway_latency_ms: gateway.latency_ms,
+        gateway_jitter_ms: gateway.jitter_ms,
+        gateway_loss_percent: gateway.packet_loss_percent,
+        latency_share,
+        variance_share,
+        loss_share,
+        dominant_segment,
+    }
+}
+
+fn share(first_hop: f64, end_to_end: f64) -> f32 {
+    (first_hop / end_to_end).min(1.0) as f32
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{analysis::compute_latency_stats, models::QualityThresholds};
+
+    fn stats(latencies: &[u64], samples: u8) -> LatencyStats {
+        compute_latency_stats(latencies, samples, &QualityThresholds::default())
+    }
+
+    #[test]
+    fn test_attribute_degradation() {
+        // A noisy Wi-Fi link: the gateway already swings as much as the path.
+        let gateway = stats(&[2, 40, 5, 60, 3], 5);
+        let end_to_end = stats(&[22, 65, 24, 80, 25], 5);
+        let wifi = attribute_degradation(&gateway, &end_to_end);
+        assert!(wifi.variance_share.unwrap() > 0.8);
+        assert_eq!(wifi.loss_share, None);
+        assert_eq!(wifi.dominant_segment, Some(PathSegment::Lan));
+
+        // A steady first hop in front of a lossy, jittery uplink.
+        let gateway = stats(&[2, 3, 2, 3, 2], 5);
+        let end_to_end = stats(&[30, 90, 45, 120], 5);
+        let uplink = attribute_degradation(&gateway, &end_to_end);
+        assert_eq!(uplink.loss_share, Some(0.0));
+        assert!(uplink.latency_share.unwrap() < 0.1);
+        assert_eq!(uplink.dominant_segment, Some(PathSegment::Isp));
+
+        // Nothing to attribute on a clean connection.
+        let clean = attribute_degradation(&gateway, &stats(&[20, 20, 20], 3));
+        assert_eq!(clean.variance_share, None);
+        assert_eq!(clean.dominant_segment, None);
+    }
+}
@@ -0,0 +1,219 @@
+//! # Latency Heatmap
+//!
+//! Lays a report history out as a target × time matrix of latencies. Rendered
+//! as a heatmap, it shows at a glance whether a slow period hit every target
+//! at once (the local network or the uplink) or only some of them (those
+//! endpoints or the routes to them); [LatencyHeatmap::scope] spells out the
+//! same verdict per column.
+
+use crate::api::{
+    constants::LibConstants,
+    models::{DegradationScope, LatencyHeatmap, NetworkReport},
+};
+use flutter_rust_bridge::frb;
+
+/// Running sums for one cell.
+#[frb(ignore)]
+#[derive(Default, Clone, Copy)]
+struct Cell {
+    probes: u32,
+    failures: u32,
+    latency_sum: u64,
+}
+
+impl Cell {
+    fn latency_ms(&self) -> Option<u64> {
+        let successes = self.probes - self.failures;
+        (successes > 0).then(|| self.latency_sum / u64::from(successes))
+    }
+
+    fn failure_share(&self) -> Option<f32> {
+        (self.probes > 0).then(|| self.failures as f32 / self.probes as f32)
+    }
+}
+
+/// Builds a [LatencyHeatmap] of the target reports in `reports` (any order),
+/// with columns `bucket_ms` wide (0 uses
+/// [DEFAULT_HEATMAP_BUCKET_MS](LibConstants::DEFAULT_HEATMAP_BUCKET_MS)).
+/// Columns are widened as needed to keep their number below
+/// [MAX_HEATMAP_COLUMNS](LibConstants::MAX_HEATMAP_COLUMNS).
+///
+/// A cell is degraded if at least
+/// [HEATMAP_FAILURE_SHARE](LibConstants::HEATMAP_FAILURE_SHARE) of its probes
+/// failed, or if its latency exceeds the median of its row by
+/// [HEATMAP_LATENCY_FACTOR](LibConstants::HEATMAP_LATENCY_FACTOR) and by at
+/// least [HEATMAP_MIN_LATENCY_INCREASE_MS](LibConstants::HEATMAP_MIN_LATENCY_INCREASE_MS).
+/// A column is [DegradationScope::Global] if more than half of the targets
+/// probed in it degraded. Skipped targets are ignored.
+pub fn latency_heatmap(reports: &[NetworkReport], bucket_ms: u64) -> LatencyHeatmap {
+    let mut bucket_ms = if bucket_ms == 0 {
+        LibConstants::DEFAULT_HEATMAP_BUCKET_MS
+    } else {
+        bucket_ms
+    };
+    let mut sorted: Vec<&NetworkReport> = reports.iter().collect();
+    sorted.sort_by_key(|r| r.timestamp_ms);
+    let (Some(first), Some(last)) = (sorted.first(), sorted.last()) else {
+        return LatencyHeatmap {
+            targets: Vec::new(),
+            bucket_ms,
+            bucket_starts_ms: Vec::new(),
+            latency_ms: Vec::new(),
+            failure_share: Vec::new(),
+            scope: Vec::new(),
+        };
+    };
+    let (first_ms, last_ms) = (first.timestamp_ms, last.timestamp_ms);
+    bucket_ms = bucket_ms.max((last_ms - first_ms) / (LibConstants::MAX_HEATMAP_COLUMNS - 1) + 1);
+    let first_bucket = first_ms / bucket_ms;
+    let columns = (last_ms / bucket_ms - first_bucket + 1) as usize;
+
+    let mut targets: Vec<String> = Vec::new();
+    let mut cells: Vec<Vec<Cell>> = Vec::new();
+    for report in sorted {
+        let column = (report.timestamp_ms / bucket_ms - first_bucket) as usize;
+        for target in report.target_reports.iter().filter(|t| !t.skipped) {
+            let row = match targets.iter().position(|label| *label == target.label) {
+                Some(row) => row,
+                None => {
+                    targets.push(target.label.clone());
+                    cells.push(vec![Cell::default(); columns]);
+                    cells.len() - 1
+                }
+            };
+            let cell = &mut cells[row][column];
+            cell.probes += 1;
+            if target.success {
+                cell.latency_sum += target.latency_ms;
+            } else {
+                cell.failures += 1;
+            }
+        }
+    }
+
+    let medians: Vec<Option<u64>> = cells.iter().map(|row| median_latency(row)).collect();
+    let scope = (0..columns)
+        .map(|column| {
+            let mut probed = 0;
+            let mut degraded = 0;
+            for (row, median) in cells.iter().zip(&medians) {
+                let cell = &row[column];
+                if cell.probes == 0 {
+                    continue;
+                }
+                probed += 1;
+                if is_degraded(cell, *median) {
+                    degraded += 1;
+                }
+            }
+            match (probed, degraded) {
+                (0, _) => DegradationScope::NoData,
+                (_, 0) => DegradationScope::Normal,
+                _ if degraded * 2 > probed => DegradationScope::Global,
+                _ => DegradationScope::EndpointSpecific,
+            }
+        })
+        .collect();
+
+    LatencyHeatmap {
+        targets,
+        bucket_ms,
+        bucket_starts_ms: (0..columns as u64)
+            .map(|column| (first_bucket + column) * bucket_ms)
+            .collect(),
+        latency_ms: cells
+            .iter()
+            .map(|row| row.iter().map(Cell::latency_ms).collect())
+            .collect(),
+        failure_share: cells
+            .iter()
+            .map(|row| row.iter().map(Cell::failure_share).collect())
+            .collect(),
+        scope,
+    }
+}
+
+/// The median of the cell latencies of a row.
+fn median_latency(row: &[Cell]) -> Option<u64> {
+    let mut latencies: Vec<u64> = row.iter().filter_map(Cell::latency_ms).collect();
+    latencies.sort_unstable();
+    latencies.get(latencies.len() / 2).copied()
+}
+
+fn is_degraded(cell: &Cell, median_ms: Option<u64>) -> bool {
+    if cell
+        .failure_share()
+        .is_some_and(|share| share >= LibConstants::HEATMAP_FAILURE_SHARE)
+    {
+        return true;
+    }
+    match (cell.latency_ms(), median_ms) {
+        (Some(latency), Some(median)) => {
+            latency as f64 > median as f64 * LibConstants::HEATMAP_LATENCY_FACTOR
+                && latency >= median + LibConstants::HEATMAP_MIN_LATENCY_INCREASE_MS
+        }
+        _ => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::TargetReport;
+
+    fn target(label: &str, success: bool, latency_ms: u64) -> TargetReport {
+        TargetReport::fixture(label, success, latency_ms)
+    }
+
+    #[test]
+    fn test_heatmap_separates_global_from_endpoint_degradation() {
+        // One report per minute; minute 2 has none, minute 3 only slows down
+        // "cdn", minute 4 hits every target.
+        let latencies = [
+            (0, [(true, 20), (true, 30), (true, 25)]),
+            (1, [(true, 22), (true, 28), (true, 24)]),
+            (3, [(true, 21), (true, 400), (true, 26)]),
+            (4, [(true, 300), (false, 0), (true, 350)]),
+            (5, [(true, 20), (true, 30), (true, 25)]),
+        ];
+        let reports: Vec<NetworkReport> = latencies
+            .iter()
+            .rev()
+            .map(|(minute, cells)| {
+                let mut report = NetworkReport::fixture(true);
+                report.timestamp_ms = 1_000_000 + minute * 60_000;
+                report.target_reports = ["dns", "cdn", "api"]
+                    .iter()
+                    .zip(cells)
+                    .map(|(label, (success, ms))| target(label, *success, *ms))
+                    .collect();
+                report
+            })
+            .collect();
+
+        let heatmap = latency_heatmap(&reports, 60_000);
+        assert_eq!(heatmap.targets, ["dns", "cdn", "api"]);
+        assert_eq!(heatmap.bucket_starts_ms.len(), 6);
+        assert_eq!(heatmap.bucket_starts_ms[0], 960_000);
+        assert_eq!(heatmap.latency_ms[1][3], Some(400));
+        assert_eq!(heatmap.latency_ms[1][4], None);
+        assert_eq!(heatmap.failure_share[1][4], Some(1.0));
+        assert_eq!(heatmap.failure_share[0][2], None);
+        assert_eq!(
+            heatmap.scope,
+            [
+                DegradationScope::Normal,
+                DegradationScope::Normal,
+                DegradationScope::NoData,
+                DegradationScope::EndpointSpecific,
+                DegradationScope::Global,
+                DegradationScope::Normal,
+            ]
+        );
+
+        // Buckets too narrow for the history are widened to stay under the cap.
+        let wide = latency_heatmap(&reports, 1);
+        assert_eq!(wide.bucket_ms, 151);
+        assert!(wide.scope.len() as u64 <= LibConstants::MAX_HEATMAP_COLUMNS);
+    }
+}
@@ -0,0 +1,248 @@
+//! # Layered Connectivity Diagnosis
+//!
+//! This module folds the raw per-target results and security findings of a
+//! check cycle into an OSI-style ladder of [LayerStatus] verdicts, so callers
+//! can locate the lowest broken layer without interpreting individual probes.
+
+use crate::api::{
+    constants::LibConstants,
+    models::{
+        ConnectionQuality, LayerStatus, LayeredDiagnosis, NetworkTarget, SecurityFlagsResult,
+        TargetProtocol, TargetReport,
+    },
+};
+use std::net::IpAddr;
+
+/// Builds the per-layer breakdown for a finished check cycle.
+///
+/// # Arguments
+///
+/// * `targets` - The configured targets, in the same order as `reports`.
+/// * `reports` - The per-target results of the check cycle.
+/// * `flags` - The security findings (used for the link and DNS layers).
+/// * `quality` - The final quality verdict (used for the application layer).
+///
+/// # Logic Flow
+///
+/// Every layer is first judged on its own evidence. Afterwards the verdicts are
+/// cascaded: once a layer fails, every layer above it that did not pass on its
+/// own is reported as [LayerStatus::Unknown].
+pub fn diagnose_layers(
+    targets: &[NetworkTarget],
+    reports: &[TargetReport],
+    flags: &SecurityFlagsResult,
+    quality: ConnectionQuality,
+) -> LayeredDiagnosis {
+    // Skipped targets say nothing about any layer.
+    let pairs: Vec<(&NetworkTarget, &TargetReport)> = targets
+        .iter()
+        .zip(reports)
+        .filter(|(_, r)| !r.skipped)
+        .collect();
+    let any_success = reports.iter().any(|r| r.success);
+
+    // 1. Link: an active interface, or any evidence that packets flowed.
+    let link = if any_success || flags.interface_name != LibConstants::DEFAULT_INTERFACE_NAME {
+        LayerStatus::Pass
+    } else {
+        LayerStatus::Fail
+    };
+
+    // 2. Network: anything reachable at all proves routing beyond the gateway.
+    let network = if any_success {
+        LayerStatus::Pass
+    } else if pairs.is_empty() {
+        LayerStatus::Unknown
+    } else {
+        LayerStatus::Fail
+    };
+
+    // 3. DNS: only targets addressed by hostname exercise the resolver.
+    let dns = {
+        let named: Vec<_> = pairs
+            .iter()
+            .filter(|(t, _)| t.host.parse::<IpAddr>().is_err())
+            .collect();
+        let resolved = named
+            .iter()
+            .any(|(_, r)| r.success || !r.error.as_deref().is_some_and(is_dns_error));
+
+        if flags.is_dns_spoofed {
+            LayerStatus::Degraded
+        } else if named.is_empty() {
+            LayerStatus::Unknown
+        } else if resolved {
+            LayerStatus::Pass
+        } else {
+            LayerStatus::Fail
+        }
+    };
+
+    // 4. Transport: raw TCP handshakes, ICMP echoes and UDP/DNS exchanges.
+    let transport = verdict_for(&pairs, |p| {
+        matches!(
+            p,
+            TargetProtocol::Tcp | TargetProtocol::Icmp | TargetProtocol::Udp | TargetProtocol::Dns
+        )
+    });
+
+    // 5. Application: HTTP(S) probes, downgraded by interception or poor quality.
+    let application = match quality {
+        ConnectionQuality::CaptivePortal => LayerStatus::Fail,
+        _ => {
+            let verdict = verdict_for(&pairs, |p| {
+                matches!(p, TargetProtocol::Http | TargetProtocol::Https)
+            });
+            let is_poor = matches!(
+                quality,
+                ConnectionQuality::Poor | ConnectionQuality::Unstable
+            );
+            if verdict == LayerStatus::Pass && is_poor {
+                LayerStatus::Degraded
+            } else {
+                verdict
+            }
+        }
+    };
+
+    cascade(LayeredDiagnosis {
+        link,
+        network,
+        dns,
+        transport,
+        application,
+    })
+}
+
+/// Judges a layer from the subset of targets whose protocol exercises it.
+fn verdict_for(
+    pairs: &[(&NetworkTarget, &TargetReport)],
+    uses_layer: impl Fn(TargetProtocol) -> bool,
+) -> LayerStatus {
+    let (total, ok) = pairs
+        .iter()
+        .filter(|(t, _)| uses_layer(t.protocol))
+        .fold((0usize, 0usize), |(total, ok), (_, r)| {
+            (total + 1, ok + r.success as usize)
+        });
+
+    if total == 0 {
+        LayerStatus::Unknown
+    } else if ok == total {
+        LayerStatus::Pass
+    } else if ok > 0 {
+        LayerStatus::Degraded
+    } else {
+        LayerStatus::Fail
+    }
+}
+
+/// Hides verdicts above a failed layer unless they passed on their own.
+fn cascade(mut layers: LayeredDiagnosis) -> LayeredDiagnosis {
+    let mut failed_below = false;
+    for layer in [
+        &mut layers.link,
+        &mut layers.network,
+        &mut layers.dns,
+        &mut layers.transport,
+        &mut layers.application,
+    ] {
+        if failed_below && *layer != LayerStatus::Pass {
+            *layer = LayerStatus::Unknown;
+        } else if *layer == LayerStatus::Fail {
+            failed_below = true;
+        }
+    }
+    layers
+}
+
+/// Returns true if a [TargetReport] error string originates from name resolution.
+fn is_dns_error(error: &str) -> bool {
+    error.starts_with("DNS Resolution Error")
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::NetworkConfiguration;
+
+    fn report(label: &str, success: bool, error: Option<&str>) -> TargetReport {
+        TargetReport {
+            error: error.map(Into::into),
+            ..TargetReport::fixture(label, success, if success { 20 } else { 0 })
+        }
+    }
+
+    #[test]
+    fn test_diagnose_layers_all_pass() {
+        let config = NetworkConfiguration::default();
+        let reports: Vec<_> = config
+            .targets
+            .iter()
+            .map(|t| report(&t.label, true, None))
+            .collect();
+        let layers = diagnose_layers(
+            &config.targets,
+            &reports,
+            &SecurityFlagsResult::default(),
+            ConnectionQuality::Excellent,
+        );
+
+        assert_eq!(layers.link, LayerStatus::Pass);
+        assert_eq!(layers.network, LayerStatus::Pass);
+        // Default targets are all IP literals, so the resolver is never exercised.
+        assert_eq!(layers.dns, LayerStatus::Unknown);
+        assert_eq!(layers.transport, LayerStatus::Pass);
+        assert_eq!(layers.application, LayerStatus::Pass);
+    }
+
+    #[test]
+    fn test_diagnose_layers_cascades_failures() {
+        let config = NetworkConfiguration::default();
+        let reports: Vec<_> = config
+            .targets
+            .iter()
+            .map(|t| report(&t.label, false, Some("Timeout Error")))
+            .collect();
+        let layers = diagnose_layers(
+            &config.targets,
+            &reports,
+            &SecurityFlagsResult::default(),
+            ConnectionQuality::Offline,
+        );
+
+        assert_eq!(layers.link, LayerStatus::Fail);
+        assert_eq!(layers.network, LayerStatus::Unknown);
+        assert_eq!(layers.transport, LayerStatus::Unknown);
+        assert_eq!(layers.application, LayerStatus::Unknown);
+    }
+
+    #[test]
+    fn test_diagnose_layers_captive_portal_and_dns() {
+        let mut config = NetworkConfiguration::default();
+        config.targets[0].host = "example.com".into();
+        let reports: Vec<_> = config
+            .targets
+            .iter()
+            .enumerate()
+            .map(|(i, t)| {
+                if i == 0 {
+                    report(&t.label, false, Some("DNS Resolution Error: nxdomain"))
+                } else {
+                    report(&t.label, true, None)
+                }
+            })
+            .collect();
+        let layers = diagnose_layers(
+            &config.targets,
+            &reports,
+            &SecurityFlagsResult::default(),
+            ConnectionQuality::CaptivePortal,
+        );
+
+        assert_eq!(layers.dns, LayerStatus::Fail);
+        // Transport passed on its own evidence, so it survives the cascade.
+        assert_eq!(layers.transport, LayerStatus::Pass);
+        assert_eq!(layers.application, LayerStatus::Unknown);
+    }
+}
@@ -1,7 +1,28 @@
 //! Functions for analyzing raw data from probes to produce insights.
 
+pub mod anomaly;
+pub mod attribution;
+pub mod heatmap;
+pub mod layers;
+pub mod paths;
 pub mod quality;
+pub mod roaming;
+pub mod stability;
 pub mod stats;
+pub mod time_of_day;
+pub mod vpn;
 
-pub use quality::{evaluate_network_quality, evaluate_quality};
-pub use stats::{calculate_jitter_stats, compute_latency_stats};
+pub use anomaly::AnomalyDetector;
+pub use attribution::attribute_degradation;
+pub use heatmap::latency_heatmap;
+pub use layers::diagnose_layers;
+pub use paths::better_path;
+pub use quality::{evaluate_network_quality, evaluate_quality, quality_reasons};
+pub use roaming::detect_roam;
+pub use stability::{StabilityInputs, StabilityModel, WeightedStabilityModel};
+pub use stats::{
+    apply_spike_detection, calculate_jitter_stats, compute_latency_stats,
+    compute_latency_stats_with_model, detect_latency_spikes,
+};
+pub use time_of_day::{profile_time_of_day, profile_time_of_day_with_aggregates};
+pub use vpn::compare_vpn_reports;
@@ -0,0 +1,97 @@
+//! Choosing between simultaneously available network paths.
+
+use crate::api::{
+    constants::LibConstants,
+    models::{BetterPathAvailable, PathMeasurement},
+};
+
+/// Compares the path of the active interface against the alternatives.
+///
+/// An alternative is better if the active path failed every handshake while
+/// it did not, if its loss is lower by more than
+/// [PATH_LOSS_MARGIN_PERCENT](LibConstants::PATH_LOSS_MARGIN_PERCENT), or if
+/// it is faster by more than
+/// [PATH_LATENCY_MARGIN_MS](LibConstants::PATH_LATENCY_MARGIN_MS) without
+/// losing more. The best such alternative is returned; `None` if there is
+/// none or the active interface was not measured.
+pub fn better_path(
+    active_interface: &str,
+    paths: &[PathMeasurement],
+    timestamp_ms: u64,
+) -> Option<BetterPathAvailable> {
+    let current = paths
+        .iter()
+        .find(|p| p.interface_name == active_interface)?;
+    let best = paths
+        .iter()
+        .filter(|p| p.interface_name != active_interface && p.latency_ms.is_some())
+        .filter(|p| is_better(p, current))
+        .min_by(|a, b| {
+            a.packet_loss_percent
+                .total_cmp(&b.packet_loss_percent)
+                .then(a.latency_ms.cmp(&b.latency_ms))
+        })?;
+
+    Some(BetterPathAvailable {
+        timestamp_ms,
+        current: current.clone(),
+        better: best.clone(),
+        latency_gain_ms: match (current.latency_ms, best.latency_ms) {
+            (Some(current), Some(best)) => Some(current as i64 - best as i64),
+            _ => None,
+        },
+    })
+}
+
+fn is_better(candidate: &PathMeasurement, current: &PathMeasurement) -> bool {
+    let Some(current_latency) = current.latency_ms else {
+        return true;
+    };
+    let candidate_latency = candidate.latency_ms.unwrap_or(u64::MAX);
+    let loss_gain = current.packet_loss_percent - candidate.packet_loss_percent;
+    loss_gain > LibConstants::PATH_LOSS_MARGIN_PERCENT
+        || (loss_gain >= 0.0
+            && candidate_latency + LibConstants::PATH_LATENCY_MARGIN_MS < current_latency)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::ConnectionType;
+
+    fn path(name: &str, latency_ms: Option<u64>, loss: f32) -> PathMeasurement {
+        PathMeasurement {
+            interface_name: name.into(),
+            connection_type: if name == "wlan0" {
+                ConnectionType::Wifi
+            } else {
+                ConnectionType::Cellular
+            },
+            local_address: "10.0.0.2".into(),
+            latency_ms,
+            packet_loss_percent: loss,
+        }
+    }
+
+    #[test]
+    fn test_better_path() {
+        let wifi = path("wlan0", Some(40), 0.0);
+        let cellular = path("rmnet0", Some(30), 0.0);
+        assert_eq!(
+            better_path("wlan0", &[wifi.clone(), cellular.clone()], 0),
+            None
+        );
+
+        let slow_wifi = path("wlan0", Some(300), 0.0);
+        let event = better_path("wlan0", &[slow_wifi, cellular.clone()], 7).unwrap();
+        assert_eq!(event.better.interface_name, "rmnet0");
+        assert_eq!(event.latency_gain_ms, Some(270));
+
+        let lossy_wifi = path("wlan0", Some(20), 66.7);
+        assert!(better_path("wlan0", &[lossy_wifi, cellular.clone()], 0).is_some());
+
+        let dead_wifi = path("wlan0", None, 100.0);
+        assert!(better_path("wlan0", &[dead_wifi, cellular], 0).is_some());
+        assert_eq!(better_path("eth0", &[wifi], 0), None);
+    }
+}
@@ -5,7 +5,7 @@
 
 pub use super::stats::calculate_jitter_stats;
 use crate::api::models::{
-    ConnectionQuality, LatencyStats, NetworkConfiguration, QualityThresholds,
+    ConnectionQuality, LatencyStats, NetworkConfiguration, QualityReason, QualityThresholds,
 };
 
 /// Categorizes a single latency measurement against configured thresholds.
@@ -37,6 +37,69 @@ pub fn evaluate_quality(latency: u64, threshold: &QualityThresholds) -> Connecti
     }
 }
 
+/// What a [QualityRule] does to the latency-based grade when it fires.
+enum RuleEffect {
+    /// Replaces the grade and ends the evaluation.
+    Force(ConnectionQuality),
+    /// Lowers the grade by one level.
+    Downgrade,
+    /// Leaves the grade alone; the factor is already part of it.
+    Explain,
+}
+
+/// One factor of [evaluate_network_quality], reported by [quality_reasons]
+/// whenever it fires.
+struct QualityRule {
+    reason: QualityReason,
+    /// Whether the rule applies, given the grade derived from latency alone.
+    fires: fn(&LatencyStats, &NetworkConfiguration, ConnectionQuality) -> bool,
+    effect: RuleEffect,
+}
+
+/// The rules behind both [evaluate_network_quality] and [quality_reasons], in
+/// the order they are applied and reported.
+const QUALITY_RULES: &[QualityRule] = &[
+    QualityRule {
+        reason: QualityReason::PacketLoss,
+        fires: |stats, config, _| {
+            stats.packet_loss_percent > config.resilience.critical_packet_loss_precent
+        },
+        effect: RuleEffect::Force(ConnectionQuality::Unstable),
+    },
+    QualityRule {
+        reason: QualityReason::HighJitter,
+        // Besides the configured threshold, the two best grades ask for a
+        // stricter score of their own.
+        fires: |stats, config, speed| {
+            stats.stability_score < config.resilience.stability_thershold
+                || (speed == ConnectionQuality::Excellent && stats.stability_score < 85)
+                || (speed == ConnectionQuality::Great && stats.stability_score < 70)
+        },
+        effect: RuleEffect::Downgrade,
+    },
+    QualityRule {
+        reason: QualityReason::HighLatency,
+        fires: |stats, config, _| stats.latency_ms > config.quality_threshold.good,
+        effect: RuleEffect::Explain,
+    },
+];
+
+/// The rules of [QUALITY_RULES] that fire for a connected check, with the
+/// grade derived from latency alone.
+fn fired_rules<'a>(
+    stats: &'a LatencyStats,
+    config: &'a NetworkConfiguration,
+) -> (
+    ConnectionQuality,
+    impl Iterator<Item = &'static QualityRule> + 'a,
+) {
+    let speed = evaluate_quality(stats.latency_ms, &config.quality_threshold);
+    let fired = QUALITY_RULES
+        .iter()
+        .filter(move |rule| (rule.fires)(stats, config, speed));
+    (speed, fired)
+}
+
 /// Computes the final, consolidated network quality.
 ///
 /// Unlike [evaluate_quality], this function considers the "Big Three" of networking:
@@ -48,7 +111,7 @@ pub fn evaluate_quality(latency: u64, threshold: &QualityThresholds) -> Connecti
 ///
 /// * If not connected, returns `Offline`.
 /// * If packet loss exceeds the critical threshold, returns `Unstable`.
-/// * If stability is low, the quality is downgraded by one or more levels.
+/// * If stability is low, the quality is downgraded by one level.
 /// * Otherwise, the quality is primarily determined by speed (latency).
 pub fn evaluate_network_quality(
     is_connected: bool,
@@ -59,44 +122,53 @@ pub fn evaluate_network_quality(
         return ConnectionQuality::Offline;
     }
 
-    // 1. Check for critical packet loss first
-    if stats.packet_loss_percent > config.resilience.critical_packet_loss_precent {
-        return ConnectionQuality::Unstable;
-    }
-
-    // 2. Initial assessment based purely on latency
-    let quality_based_on_speed = evaluate_quality(stats.latency_ms, &config.quality_threshold);
-
-    // 3. Apply stability-based downgrades
-    // If stability is below the threshold, we slide the quality down the scale.
-    if stats.stability_score < config.resilience.stability_thershold {
-        return match quality_based_on_speed {
-            ConnectionQuality::Excellent => ConnectionQuality::Great,
-            ConnectionQuality::Great => ConnectionQuality::Good,
-            ConnectionQuality::Good => ConnectionQuality::Moderate,
-            ConnectionQuality::Moderate => ConnectionQuality::Poor,
-            ConnectionQuality::Poor => ConnectionQuality::Unstable,
-            other => other,
-        };
+    let (mut quality, fired) = fired_rules(stats, config);
+    for rule in fired {
+        match rule.effect {
+            RuleEffect::Force(forced) => return forced,
+            RuleEffect::Downgrade => quality = downgrade(quality),
+            RuleEffect::Explain => {}
+        }
     }
+    quality
+}
 
-    // 4. Fine-grained stability checks for high-end connections
-    if quality_based_on_speed == ConnectionQuality::Excellent && stats.stability_score < 85 {
-        return ConnectionQuality::Great;
+/// The next lower grade; `Unstable` and `Offline` stay as they are.
+fn downgrade(quality: ConnectionQuality) -> ConnectionQuality {
+    match quality {
+        ConnectionQuality::Excellent => ConnectionQuality::Great,
+        ConnectionQuality::Great => ConnectionQuality::Good,
+        ConnectionQuality::Good => ConnectionQuality::Moderate,
+        ConnectionQuality::Moderate => ConnectionQuality::Poor,
+        ConnectionQuality::Poor => ConnectionQuality::Unstable,
+        other => other,
     }
+}
 
-    if quality_based_on_speed == ConnectionQuality::Great && stats.stability_score < 70 {
-        return ConnectionQuality::Good;
+/// Lists the factors behind the result of [evaluate_network_quality].
+///
+/// Both come from the same rule table: an offline check only yields
+/// [QualityReason::NoTargetsReachable]; otherwise packet loss, a low stability
+/// score and a latency above the `good` threshold are reported in that order.
+/// [QualityReason::CaptivePortal] is added by the engine after the portal probe.
+pub fn quality_reasons(
+    is_connected: bool,
+    stats: &LatencyStats,
+    config: &NetworkConfiguration,
+) -> Vec<QualityReason> {
+    if !is_connected {
+        return vec![QualityReason::NoTargetsReachable];
     }
 
-    quality_based_on_speed
+    let (_, fired) = fired_rules(stats, config);
+    fired.map(|rule| rule.reason).collect()
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::api::models::{
-        ConnectionQuality, LatencyStats, NetworkConfiguration, QualityThresholds,
+        ConnectionQuality, LatencyStats, NetworkConfiguration, NetworkReport, QualityThresholds,
     };
 
     #[test]
@@ -149,12 +221,9 @@ mod tests {
         // Offline
         let stats = LatencyStats {
             latency_ms: 0,
-            jitter_ms: 0,
             packet_loss_percent: 100.0,
-            min_latency_ms: None,
-            max_latency_ms: None,
-            avg_latency_ms: None,
             stability_score: 0,
+            ..NetworkReport::fixture(true).status.latency_stats
         };
         assert_eq!(
             evaluate_network_quality(false, &stats, &config),
@@ -164,12 +233,9 @@ mod tests {
         // Critical loss
         let stats = LatencyStats {
             latency_ms: 100,
-            jitter_ms: 0,
             packet_loss_percent: 20.0,
-            min_latency_ms: Some(100),
-            max_latency_ms: Some(100),
-            avg_latency_ms: Some(100),
             stability_score: 80,
+            ..NetworkReport::fixture(true).status.latency_stats
         };
         assert_eq!(
             evaluate_network_quality(true, &stats, &config),
@@ -179,16 +245,57 @@ mod tests {
         // Downgraded due to low stability score
         let stats = LatencyStats {
             latency_ms: 100,
-            jitter_ms: 0,
             packet_loss_percent: 0.0,
-            min_latency_ms: Some(100),
-            max_latency_ms: Some(100),
-            avg_latency_ms: Some(100),
             stability_score: 10,
+            ..NetworkReport::fixture(true).status.latency_stats
         };
         assert_eq!(
             evaluate_network_quality(true, &stats, &config),
             ConnectionQuality::Good
         );
     }
+
+    #[test]
+    fn test_quality_reasons() {
+        let mut config = NetworkConfiguration::default();
+        config.resilience.stability_thershold = 50;
+        config.resilience.critical_packet_loss_precent = 10.0;
+
+        let mut stats = LatencyStats {
+            latency_ms: 30,
+            packet_loss_percent: 0.0,
+            stability_score: 100,
+            ..NetworkReport::fixture(true).status.latency_stats
+        };
+        assert!(quality_reasons(true, &stats, &config).is_empty());
+        assert_eq!(
+            quality_reasons(false, &stats, &config),
+            vec![QualityReason::NoTargetsReachable]
+        );
+
+        stats.latency_ms = 500;
+        stats.packet_loss_percent = 20.0;
+        stats.stability_score = 10;
+        assert_eq!(
+            quality_reasons(true, &stats, &config),
+            vec![
+                QualityReason::PacketLoss,
+                QualityReason::HighJitter,
+                QualityReason::HighLatency
+            ]
+        );
+
+        // A reason is listed exactly when its rule shaped the grade.
+        stats.latency_ms = 30;
+        stats.packet_loss_percent = 0.0;
+        stats.stability_score = 80;
+        assert_eq!(
+            quality_reasons(true, &stats, &config),
+            vec![QualityReason::HighJitter]
+        );
+        assert_eq!(
+            evaluate_network_quality(true, &stats, &config),
+            ConnectionQuality::Great
+        );
+    }
 }
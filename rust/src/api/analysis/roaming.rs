@@ -0,0 +1,89 @@
+//! Wi-Fi roaming (BSSID handover) detection.
+//!
+//! A client roams when it moves to another access point of the same network.
+//! Done well it is invisible; "sticky" clients that hang on to a fading access
+//! point too long, or slow handovers, show up as a latency spike right at the
+//! BSSID change. Comparing consecutive reports makes those visible.
+
+use crate::api::models::{NetworkReport, ResilienceConfig, RoamEvent};
+
+/// Compares the access points of two consecutive reports.
+///
+/// Returns an event if both carry a BSSID (see
+/// [PlatformHints::bssid](crate::api::models::PlatformHints::bssid)) and they
+/// differ. The handover counts as a latency spike if the current check found
+/// spikes, if its latency exceeds the previous one by more than
+/// [spike_ratio_threshold](ResilienceConfig::spike_ratio_threshold) (unless
+/// that is 0), or if the device lost its connection in it.
+pub fn detect_roam(
+    previous: &NetworkReport,
+    current: &NetworkReport,
+    resilience: &ResilienceConfig,
+) -> Option<RoamEvent> {
+    let from = previous.bssid.as_deref()?;
+    let to = current.bssid.as_deref()?;
+    if from.eq_ignore_ascii_case(to) {
+        return None;
+    }
+
+    let before = previous.status.latency_stats.latency_ms;
+    let after = current.status.latency_stats.latency_ms;
+    let ratio = resilience.spike_ratio_threshold;
+    let latency_spike = current.status.latency_stats.spike_count > 0
+        || !current.status.is_connected
+        || (ratio > 0.0 && before > 0 && after as f64 > before as f64 * ratio);
+
+    Some(RoamEvent {
+        timestamp_ms: current.timestamp_ms,
+        ssid: current.ssid.clone(),
+        from_bssid: from.to_string(),
+        to_bssid: to.to_string(),
+        latency_before_ms: before,
+        latency_after_ms: after,
+        latency_spike,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_roam() {
+        let mut resilience = ResilienceConfig::default();
+        let mut previous = NetworkReport::fixture(true);
+        previous.bssid = Some("aa:bb:cc:00:00:01".into());
+        previous.status.latency_stats.latency_ms = 20;
+        let mut current = previous.clone();
+        current.status.latency_stats.latency_ms = 25;
+
+        assert_eq!(detect_roam(&previous, &current, &resilience), None);
+
+        current.bssid = Some("aa:bb:cc:00:00:02".into());
+        let event = detect_roam(&previous, &current, &resilience).unwrap();
+        assert!(!event.latency_spike);
+        assert_eq!(event.from_bssid, "aa:bb:cc:00:00:01");
+
+        current.status.latency_stats.latency_ms = 120;
+        assert!(
+            detect_roam(&previous, &current, &resilience)
+                .unwrap()
+                .latency_spike
+        );
+        resilience.spike_ratio_threshold = 10.0;
+        assert!(
+            !detect_roam(&previous, &current, &resilience)
+                .unwrap()
+                .latency_spike
+        );
+        resilience.spike_ratio_threshold = 0.0;
+        assert!(
+            !detect_roam(&previous, &current, &resilience)
+                .unwrap()
+                .latency_spike
+        );
+
+        current.bssid = None;
+        assert_eq!(detect_roam(&previous, &current, &resilience), None);
+    }
+}
@@ -0,0 +1,216 @@
+//! # Pluggable Stability Scoring
+//!
+//! [compute_latency_stats](super::stats::compute_latency_stats) condenses a
+//! check's samples into a single 0-100 stability score. The formula lives
+//! behind the [StabilityModel] trait so apps can tune the weighting, or plug
+//! in a model of their own (e.g. one fitted to their users' networks), without
+//! forking the engine. A model belongs to one
+//! [ReachabilityEngine](crate::api::engine::ReachabilityEngine); other engines
+//! and the free [check_network](crate::api::engine::check_network) keep the
+//! default:
+//!
+//! ```ignore
+//! use std::sync::Arc;
+//!
+//! // Only care about loss, e.g. for a bulk-transfer app.
+//! let engine = ReachabilityEngine::new(config).with_stability_model(Arc::new(
+//!     WeightedStabilityModel {
+//!         p95_weight: 0.1,
+//!         loss_weight: 0.7,
+//!         mean_weight: 0.1,
+//!         jitter_weight: 0.1,
+//!     },
+//! ));
+//! ```
+
+use crate::api::models::QualityThresholds;
+use flutter_rust_bridge::frb;
+
+/// The pre-computed figures a [StabilityModel] scores.
+#[frb(ignore)]
+#[derive(Debug, Clone)]
+pub struct StabilityInputs<'a> {
+    /// Successful sample latencies (ms), sorted ascending. Never empty.
+    pub sorted_latencies: &'a [u64],
+
+    /// Arithmetic mean latency (ms).
+    pub mean_ms: f64,
+
+    /// 95th percentile latency (ms).
+    pub p95_ms: f64,
+
+    /// Interquartile range (p75 - p25) of the latencies (ms).
+    pub iqr_ms: f64,
+
+    /// Sample standard deviation of the latencies (ms).
+    pub std_dev_ms: f64,
+
+    /// Share of samples that failed (0.0 - 100.0).
+    pub packet_loss_percent: f32,
+
+    /// The configured latency buckets.
+    pub thresholds: &'a QualityThresholds,
+}
+
+/// Turns the statistics of one check into a 0 (broken) to 100 (perfect) score.
+///
+/// Implementations must be cheap and deterministic: the score is computed on
+/// every check and compared against
+/// [stability_thershold](crate::api::models::ResilienceConfig::stability_thershold).
+pub trait StabilityModel: Send + Sync + std::fmt::Debug {
+    /// Scores a check that had at least one successful sample.
+    fn score(&self, inputs: &StabilityInputs<'_>) -> u8;
+}
+
+/// The built-in weighted composite model, tuned for modern mobile (4G/5G)
+/// and WiFi networks.
+///
+/// Each component is first mapped to a 0-100 sub-score, then combined with
+/// the weights below. Checks losing more than half of their samples are
+/// scaled down further, as the network is effectively dying.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WeightedStabilityModel {
+    /// Weight of the P95 latency sub-score; penalizes "tail latency".
+    pub p95_weight: f64,
+
+    /// Weight of the packet loss sub-score; penalizes unreliability.
+    pub loss_weight: f64,
+
+    /// Weight of the mean latency sub-score; baseline speed.
+    pub mean_weight: f64,
+
+    /// Weight of the IQR jitter sub-score; arrival consistency.
+    pub jitter_weight: f64,
+}
+
+/// P95 35%, loss 30%, mean 20%, jitter 15%.
+impl Default for WeightedStabilityModel {
+    fn default() -> Self {
+        Self {
+            p95_weight: 0.35,
+            loss_weight: 0.30,
+            mean_weight: 0.20,
+            jitter_weight: 0.15,
+        }
+    }
+}
+
+impl StabilityModel for WeightedStabilityModel {
+    fn score(&self, inputs: &StabilityInputs<'_>) -> u8 {
+        let p95_score = score_latency(inputs.p95_ms, inputs.thresholds);
+        let mean_score = score_latency(inputs.mean_ms, inputs.thresholds);
+
+        // Loss Scorer: sharp drops after 1% loss, zero score after 15%
+        let loss_f64 = inputs.packet_loss_percent as f64;
+        let loss_score: f64 = if loss_f64 <= 0.0 {
+            100.0
+        } else if loss_f64 <= 1.0 {
+            lerp(100.0, 80.0, loss_f64, 0.0, 1.0)
+        } else if loss_f64 <= 5.0 {
+            lerp(80.0, 50.0, loss_f64, 1.0, 5.0)
+        } else if loss_f64 <= 15.0 {
+            lerp(50.0, 15.0, loss_f64, 5.0, 15.0)
+        } else {
+            0.0
+        };
+
+        // Jitter Scorer: penalizes relative variance
+        let jitter_score: f64 = if inputs.mean_ms > f64::EPSILON {
+            let relative_iqr = (inputs.iqr_ms / inputs.mean_ms) * 100.0;
+            if relative_iqr <= 10.0 {
+                lerp(100.0, 90.0, relative_iqr, 0.0, 10.0)
+            } else if relative_iqr <= 30.0 {
+                lerp(90.0, 65.0, relative_iqr, 10.0, 30.0)
+            } else if relative_iqr <= 60.0 {
+                lerp(65.0, 30.0, relative_iqr, 30.0, 60.0)
+            } else if relative_iqr <= 100.0 {
+                lerp(30.0, 5.0, relative_iqr, 60.0, 100.0)
+            } else {
+                0.0
+            }
+        } else {
+            100.0
+        };
+
+        let mut weighted_score = p95_score * self.p95_weight
+            + loss_score * self.loss_weight
+            + mean_score * self.mean_weight
+            + jitter_score * self.jitter_weight;
+
+        // Severe penalty for > 50% loss (network is effectively dying)
+        if inputs.packet_loss_percent > 50.0 {
+            let survival = (100.0 - loss_f64) / 50.0;
+            weighted_score *= survival;
+        }
+
+        weighted_score.clamp(0.0, 100.0) as u8
+    }
+}
+
+/// Stepped scorer: maps a latency value to a 0-100 score based on user thresholds.
+pub fn score_latency(ms: f64, thresholds: &QualityThresholds) -> f64 {
+    let (ex, gr, go, mo, po) = (
+        thresholds.excellent as f64,
+        thresholds.great as f64,
+        thresholds.good as f64,
+        thresholds.moderate as f64,
+        thresholds.poor as f64,
+    );
+
+    let unusable = po * 2.0;
+
+    if ms <= ex {
+        lerp(100.0, 88.0, ms, 0.0, ex)
+    } else if ms <= gr {
+        lerp(88.0, 72.0, ms, ex, gr)
+    } else if ms <= go {
+        lerp(72.0, 52.0, ms, gr, go)
+    } else if ms <= mo {
+        lerp(52.0, 28.0, ms, go, mo)
+    } else if ms <= po {
+        lerp(28.0, 8.0, ms, mo, po)
+    } else {
+        lerp(8.0, 0.0, ms.min(unusable), po, unusable)
+    }
+}
+
+/// Helper: Performs linear interpolation between two points.
+#[inline]
+fn lerp(out_min: f64, out_max: f64, value: f64, in_min: f64, in_max: f64) -> f64 {
+    if (in_max - in_min).abs() < f64::EPSILON {
+        return out_min;
+    }
+    let t = (value - in_min) / (in_max - in_min);
+    (out_min + t * (out_max - out_min)).clamp(out_max.min(out_min), out_max.max(out_min))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_weights_shift_the_score() {
+        let thresholds = QualityThresholds::default();
+        let inputs = StabilityInputs {
+            sorted_latencies: &[20, 20, 20, 20],
+            mean_ms: 20.0,
+            p95_ms: 20.0,
+            iqr_ms: 0.0,
+            std_dev_ms: 0.0,
+            packet_loss_percent: 20.0,
+            thresholds: &thresholds,
+        };
+
+        let balanced = WeightedStabilityModel::default().score(&inputs);
+        let loss_only = WeightedStabilityModel {
+            p95_weight: 0.0,
+            loss_weight: 1.0,
+            mean_weight: 0.0,
+            jitter_weight: 0.0,
+        }
+        .score(&inputs);
+
+        assert!(balanced > 50);
+        assert_eq!(loss_only, 0);
+    }
+}
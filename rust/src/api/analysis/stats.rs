@@ -4,7 +4,9 @@
 //! a collection of raw latency samples into meaningful metrics like Jitter,
 //! P95 Latency, and a consolidated Stability Score.
 
-use crate::api::models::{LatencyStats, QualityThresholds};
+use super::stability::{StabilityInputs, StabilityModel, WeightedStabilityModel};
+use crate::api::models::{LatencyStats, QualityThresholds, ResilienceConfig};
+use flutter_rust_bridge::frb;
 
 /// Calculates basic statistical metrics for a set of latency samples.
 ///
@@ -57,8 +59,10 @@ pub fn calculate_jitter_stats(
 
 /// Computes a comprehensive stability report and health score.
 ///
-/// The scoring system is specifically tuned for modern mobile (4G/5G) and
-/// WiFi networks. It uses a weighted composite model:
+/// The stability score comes from the default
+/// [WeightedStabilityModel](super::stability::WeightedStabilityModel); to
+/// score with another [StabilityModel](super::stability::StabilityModel), see
+/// [compute_latency_stats_with_model]:
 ///
 /// ### Scoring Weights:
 /// * **P95 Latency (35%):** Penalizes "tail latency" (occasional slow packets).
@@ -74,6 +78,23 @@ pub fn compute_latency_stats(
     latencies: &[u64],
     total_expected_samples: u8,
     thresholds: &QualityThresholds,
+) -> LatencyStats {
+    compute_latency_stats_with_model(
+        latencies,
+        total_expected_samples,
+        thresholds,
+        &WeightedStabilityModel::default(),
+    )
+}
+
+/// Same as [compute_latency_stats], but scores with `model` instead of the
+/// default one.
+#[frb(ignore)]
+pub fn compute_latency_stats_with_model(
+    latencies: &[u64],
+    total_expected_samples: u8,
+    thresholds: &QualityThresholds,
+    model: &dyn StabilityModel,
 ) -> LatencyStats {
     let successful_samples = latencies.len() as f32;
     let packet_loss_percent = if total_expected_samples > 0 {
@@ -90,8 +111,14 @@ pub fn compute_latency_stats(
             packet_loss_percent,
             min_latency_ms: None,
             max_latency_ms: None,
+            p50_ms: None,
+            p90_ms: None,
+            p95_ms: None,
+            p99_ms: None,
             avg_latency_ms: None,
             stability_score: 0,
+            spike_count: 0,
+            spike_sample_indices: vec![],
         };
     }
 
@@ -137,79 +164,15 @@ pub fn compute_latency_stats(
     };
     let std_dev = variance.sqrt();
 
-    // Stepped scorer: maps a latency value to a 0-100 score based on user thresholds
-    let score_latency = |ms: f64| -> f64 {
-        let t = &thresholds;
-        let (ex, gr, go, mo, po) = (
-            t.excellent as f64,
-            t.great as f64,
-            t.good as f64,
-            t.moderate as f64,
-            t.poor as f64,
-        );
-
-        let unusable = po * 2.0;
-
-        if ms <= ex {
-            lerp(100.0, 88.0, ms, 0.0, ex)
-        } else if ms <= gr {
-            lerp(88.0, 72.0, ms, ex, gr)
-        } else if ms <= go {
-            lerp(72.0, 52.0, ms, gr, go)
-        } else if ms <= mo {
-            lerp(52.0, 28.0, ms, go, mo)
-        } else if ms <= po {
-            lerp(28.0, 8.0, ms, mo, po)
-        } else {
-            lerp(8.0, 0.0, ms.min(unusable), po, unusable)
-        }
-    };
-
-    let p95_score = score_latency(p95);
-
-    // Loss Scorer: sharp drops after 1% loss, zero score after 15%
-    let loss_f64 = packet_loss_percent as f64;
-    let loss_score: f64 = if loss_f64 <= 0.0 {
-        100.0
-    } else if loss_f64 <= 1.0 {
-        lerp(100.0, 80.0, loss_f64, 0.0, 1.0)
-    } else if loss_f64 <= 5.0 {
-        lerp(80.0, 50.0, loss_f64, 1.0, 5.0)
-    } else if loss_f64 <= 15.0 {
-        lerp(50.0, 15.0, loss_f64, 5.0, 15.0)
-    } else {
-        0.0
-    };
-
-    let mean_score = score_latency(mean_f64);
-
-    // Jitter Scorer: penalizes relative variance
-    let jitter_score: f64 = if mean_f64 > f64::EPSILON {
-        let relative_iqr = (iqr_jitter / mean_f64) * 100.0;
-        if relative_iqr <= 10.0 {
-            lerp(100.0, 90.0, relative_iqr, 0.0, 10.0)
-        } else if relative_iqr <= 30.0 {
-            lerp(90.0, 65.0, relative_iqr, 10.0, 30.0)
-        } else if relative_iqr <= 60.0 {
-            lerp(65.0, 30.0, relative_iqr, 30.0, 60.0)
-        } else if relative_iqr <= 100.0 {
-            lerp(30.0, 5.0, relative_iqr, 60.0, 100.0)
-        } else {
-            0.0
-        }
-    } else {
-        100.0
-    };
-
-    // Calculate Final Weighted Score
-    let mut weighted_score =
-        p95_score * 0.35 + loss_score * 0.30 + mean_score * 0.20 + jitter_score * 0.15;
-
-    // Severe penalty for > 50% loss (network is effectively dying)
-    if packet_loss_percent > 50.0 {
-        let survival = (100.0 - packet_loss_percent as f64) / 50.0;
-        weighted_score *= survival;
-    }
+    let stability_score = model.score(&StabilityInputs {
+        sorted_latencies: &sorted,
+        mean_ms: mean_f64,
+        p95_ms: p95,
+        iqr_ms: iqr_jitter,
+        std_dev_ms: std_dev,
+        packet_loss_percent,
+        thresholds,
+    });
 
     LatencyStats {
         latency_ms: mean_ms,
@@ -217,19 +180,63 @@ pub fn compute_latency_stats(
         packet_loss_percent,
         min_latency_ms: min_lat,
         max_latency_ms: max_lat,
+        p50_ms: Some(percentile(50.0).round() as u64),
+        p90_ms: Some(percentile(90.0).round() as u64),
+        p95_ms: Some(p95.round() as u64),
+        p99_ms: Some(percentile(99.0).round() as u64),
         avg_latency_ms: Some(mean_ms),
-        stability_score: weighted_score.clamp(0.0, 100.0) as u8,
+        stability_score: stability_score.min(100),
+        spike_count: 0,
+        spike_sample_indices: vec![],
+    }
+}
+
+/// Finds samples that exceed the median latency by more than `ratio_threshold`.
+///
+/// Returns their positions in `latencies` (collection order). A threshold of
+/// 0 or less disables detection. Fewer than 3 samples never yield spikes, as
+/// there is no meaningful baseline to compare against.
+pub fn detect_latency_spikes(latencies: &[u64], ratio_threshold: f64) -> Vec<u32> {
+    if ratio_threshold <= 0.0 || latencies.len() < 3 {
+        return Vec::new();
     }
+
+    let mut sorted = latencies.to_vec();
+    sorted.sort_unstable();
+    let mid = sorted.len() / 2;
+    let median = if sorted.len().is_multiple_of(2) {
+        (sorted[mid - 1] + sorted[mid]) as f64 / 2.0
+    } else {
+        sorted[mid] as f64
+    };
+    // Sub-millisecond medians (e.g. loopback) would flag every 1ms sample.
+    let baseline = median.max(1.0);
+
+    latencies
+        .iter()
+        .enumerate()
+        .filter(|(_, &ms)| ms as f64 / baseline > ratio_threshold)
+        .map(|(i, _)| i as u32)
+        .collect()
 }
 
-/// Helper: Performs linear interpolation between two points.
-#[inline]
-fn lerp(out_min: f64, out_max: f64, value: f64, in_min: f64, in_max: f64) -> f64 {
-    if (in_max - in_min).abs() < f64::EPSILON {
-        return out_min;
+/// Records the spikes in `latencies` on `stats` and deducts
+/// [spike_penalty](ResilienceConfig::spike_penalty) from the stability score
+/// if there were any.
+#[frb(ignore)]
+pub fn apply_spike_detection(
+    stats: &mut LatencyStats,
+    latencies: &[u64],
+    resilience: &ResilienceConfig,
+) {
+    let spikes = detect_latency_spikes(latencies, resilience.spike_ratio_threshold);
+    if !spikes.is_empty() {
+        stats.stability_score = stats
+            .stability_score
+            .saturating_sub(resilience.spike_penalty);
     }
-    let t = (value - in_min) / (in_max - in_min);
-    (out_min + t * (out_max - out_min)).clamp(out_max.min(out_min), out_max.max(out_min))
+    stats.spike_count = spikes.len() as u32;
+    stats.spike_sample_indices = spikes;
 }
 
 #[cfg(test)]
@@ -253,4 +260,57 @@ mod tests {
         assert_eq!(mean, Some(100));
         assert_eq!(std_dev, Some(0.0));
     }
+
+    #[derive(Debug)]
+    struct Constant(u8);
+
+    impl StabilityModel for Constant {
+        fn score(&self, _: &StabilityInputs<'_>) -> u8 {
+            self.0
+        }
+    }
+
+    #[test]
+    fn test_compute_latency_stats_uses_given_model() {
+        let thresholds = QualityThresholds::default();
+        let stats = compute_latency_stats_with_model(&[10, 20, 30], 3, &thresholds, &Constant(42));
+        assert_eq!(stats.stability_score, 42);
+        assert_eq!(stats.latency_ms, 20);
+
+        let default = compute_latency_stats(&[10, 20, 30], 3, &thresholds);
+        assert!(default.stability_score > 80);
+    }
+
+    #[test]
+    fn test_compute_latency_stats_percentiles() {
+        let thresholds = QualityThresholds::default();
+        let latencies: Vec<u64> = (1..=100).rev().collect();
+        let stats = compute_latency_stats(&latencies, 100, &thresholds);
+        assert_eq!(stats.p50_ms, Some(51));
+        assert_eq!(stats.p90_ms, Some(90));
+        assert_eq!(stats.p95_ms, Some(95));
+        assert_eq!(stats.p99_ms, Some(99));
+
+        let empty = compute_latency_stats(&[], 5, &thresholds);
+        assert_eq!(empty.p99_ms, None);
+    }
+
+    #[test]
+    fn test_spike_detection_reports_sample_and_penalizes() {
+        assert_eq!(detect_latency_spikes(&[20, 22, 95, 21, 19], 2.0), vec![2]);
+        assert!(detect_latency_spikes(&[20, 22, 95, 21, 19], 0.0).is_empty());
+        assert!(detect_latency_spikes(&[20, 95], 2.0).is_empty());
+
+        let resilience = ResilienceConfig::default();
+        let latencies = [20, 22, 95, 21, 19];
+        let mut stats = compute_latency_stats(&latencies, 5, &QualityThresholds::default());
+        let before = stats.stability_score;
+        apply_spike_detection(&mut stats, &latencies, &resilience);
+        assert_eq!(stats.spike_count, 1);
+        assert_eq!(stats.spike_sample_indices, vec![2]);
+        assert_eq!(
+            stats.stability_score,
+            before.saturating_sub(resilience.spike_penalty)
+        );
+    }
 }
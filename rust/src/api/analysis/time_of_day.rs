@@ -0,0 +1,285 @@
+//! # Time-of-Day Profiling
+//!
+//! Buckets a report history by local hour and weekday and looks for hours that
+//! are consistently worse than the rest of the day, e.g. evening congestion on
+//! a shared cable segment. Only hours seen on several distinct days qualify,
+//! so a single bad evening is not reported as a pattern.
+//!
+//! Full reports rarely span several days, so the profile can also take the
+//! [HistoryAggregate]s a long-running engine downsamples its history into.
+//! Each counts with the weight of the reports it summarizes.
+
+use crate::api::{
+    constants::LibConstants,
+    models::{
+        CongestionWindow, ConnectionQuality, HistoryAggregate, NetworkReport, TimeBucket,
+        TimeOfDayProfile,
+    },
+};
+use flutter_rust_bridge::frb;
+use std::collections::HashSet;
+
+const MS_PER_HOUR: i64 = 3_600_000;
+const MS_PER_DAY: i64 = 24 * MS_PER_HOUR;
+
+/// Running sums for one bucket.
+#[frb(ignore)]
+#[derive(Debug, Default, Clone)]
+struct Accumulator {
+    reports: u32,
+    latency_sum: u64,
+    latency_count: u32,
+    loss_sum: f64,
+    degraded: u32,
+    days: HashSet<i64>,
+}
+
+impl Accumulator {
+    fn add(&mut self, report: &NetworkReport, day: i64) {
+        let stats = &report.status.latency_stats;
+        self.reports += 1;
+        if report.status.is_connected {
+            self.latency_sum += stats.latency_ms;
+            self.latency_count += 1;
+        }
+        self.loss_sum += f64::from(stats.packet_loss_percent);
+        if is_degraded(report) {
+            self.degraded += 1;
+        }
+        self.days.insert(day);
+    }
+
+    fn add_aggregate(&mut self, aggregate: &HistoryAggregate, day: i64) {
+        self.reports += aggregate.reports;
+        if let Some(avg) = aggregate.avg_latency_ms {
+            self.latency_sum += avg * u64::from(aggregate.connected_reports);
+            self.latency_count += aggregate.connected_reports;
+        }
+        self.loss_sum +=
+            f64::from(aggregate.avg_packet_loss_percent) * f64::from(aggregate.reports);
+        self.degraded += aggregate.degraded_reports;
+        self.days.insert(day);
+    }
+
+    fn avg_latency_ms(&self) -> Option<u64> {
+        (self.latency_count > 0).then(|| self.latency_sum / u64::from(self.latency_count))
+    }
+
+    fn degraded_share(&self) -> f32 {
+        if self.reports == 0 {
+            0.0
+        } else {
+            self.degraded as f32 / self.reports as f32
+        }
+    }
+
+    fn bucket(&self, index: u8) -> TimeBucket {
+        TimeBucket {
+            index,
+            reports: self.reports,
+            avg_latency_ms: self.avg_latency_ms(),
+            avg_packet_loss_percent: if self.reports == 0 {
+                0.0
+            } else {
+                (self.loss_sum / f64::from(self.reports)) as f32
+            },
+            degraded_share: self.degraded_share(),
+        }
+    }
+}
+
+/// Whether `report` counts against its hour: offline, or connected at poor or
+/// unstable quality.
+pub(crate) fn is_degraded(report: &NetworkReport) -> bool {
+    !report.status.is_connected
+        || matches!(
+            report.status.quality,
+            ConnectionQuality::Poor | ConnectionQuality::Unstable | ConnectionQuality::Offline
+        )
+}
+
+/// Builds a [TimeOfDayProfile] from `reports` (any order).
+///
+/// `utc_offset_minutes` shifts the report timestamps into the user's local
+/// time (e.g. `120` for UTC+2); Flutter apps can pass
+/// `DateTime.now().timeZoneOffset.inMinutes`.
+///
+/// An hour is part of a congestion window if it was observed on at least
+/// [MIN_CONGESTION_DAYS](LibConstants::MIN_CONGESTION_DAYS) distinct days and
+/// either its mean latency exceeds the overall mean by
+/// [CONGESTION_LATENCY_FACTOR](LibConstants::CONGESTION_LATENCY_FACTOR) or at
+/// least [CONGESTION_DEGRADED_SHARE](LibConstants::CONGESTION_DEGRADED_SHARE)
+/// of its reports were degraded. Adjacent hours are merged, wrapping past midnight.
+pub fn profile_time_of_day(reports: &[NetworkReport], utc_offset_minutes: i32) -> TimeOfDayProfile {
+    profile_time_of_day_with_aggregates(reports, &[], utc_offset_minutes)
+}
+
+/// Like [profile_time_of_day], but also counts the reports summarized by
+/// `aggregates`, e.g. those of
+/// [ReachabilityEngine::history_aggregates](crate::api::engine::ReachabilityEngine::history_aggregates).
+/// An aggregate is placed at the middle of its period.
+pub fn profile_time_of_day_with_aggregates(
+    reports: &[NetworkReport],
+    aggregates: &[HistoryAggregate],
+    utc_offset_minutes: i32,
+) -> TimeOfDayProfile {
+    let mut hours = vec![Accumulator::default(); 24];
+    let mut weekdays = vec![Accumulator::default(); 7];
+    let mut overall = Accumulator::default();
+
+    for report in reports {
+        let (day, hour, weekday) = local_time(report.timestamp_ms, utc_offset_minutes);
+        hours[hour].add(report, day);
+        weekdays[weekday].add(report, day);
+        overall.add(report, day);
+    }
+    for aggregate in aggregates {
+        let midpoint_ms = aggregate.start_ms + aggregate.resolution_ms / 2;
+        let (day, hour, weekday) = local_time(midpoint_ms, utc_offset_minutes);
+        hours[hour].add_aggregate(aggregate, day);
+        weekdays[weekday].add_aggregate(aggregate, day);
+        overall.add_aggregate(aggregate, day);
+    }
+
+    let baseline_latency_ms = overall.avg_latency_ms();
+    let congested: Vec<bool> = hours
+        .iter()
+        .map(|h| is_congested(h, baseline_latency_ms))
+        .collect();
+
+    TimeOfDayProfile {
+        by_hour: hours
+            .iter()
+            .enumerate()
+            .map(|(i, h)| h.bucket(i as u8))
+            .collect(),
+        by_weekday: weekdays
+            .iter()
+            .enumerate()
+            .map(|(i, d)| d.bucket(i as u8))
+            .collect(),
+        congestion_windows: congestion_windows(&hours, &congested, baseline_latency_ms),
+    }
+}
+
+/// The local day number, hour and weekday (Monday = 0) of a UTC timestamp.
+fn local_time(timestamp_ms: u64, utc_offset_minutes: i32) -> (i64, usize, usize) {
+    let local_ms = timestamp_ms as i64 + i64::from(utc_offset_minutes) * 60_000;
+    let day = local_ms.div_euclid(MS_PER_DAY);
+    let hour = local_ms.rem_euclid(MS_PER_DAY) / MS_PER_HOUR;
+    // 1970-01-01 was a Thursday (index 3 with Monday = 0).
+    let weekday = (day + 3).rem_euclid(7);
+    (day, hour as usize, weekday as usize)
+}
+
+fn is_congested(hour: &Accumulator, baseline_latency_ms: Option<u64>) -> bool {
+    if hour.reports < LibConstants::MIN_REPORTS_PER_TIME_BUCKET
+        || hour.days.len() < LibConstants::MIN_CONGESTION_DAYS
+    {
+        return false;
+    }
+
+    let slow = match (hour.avg_latency_ms(), baseline_latency_ms) {
+        (Some(avg), Some(baseline)) => {
+            avg as f64 > baseline as f64 * LibConstants::CONGESTION_LATENCY_FACTOR
+        }
+        _ => false,
+    };
+    slow || hour.degraded_share() >= LibConstants::CONGESTION_DEGRADED_SHARE
+}
+
+/// Merges runs of congested hours, joining a run that ends at 23:00 with one
+/// that starts at 00:00.
+fn congestion_windows(
+    hours: &[Accumulator],
+    congested: &[bool],
+    baseline_latency_ms: Option<u64>,
+) -> Vec<CongestionWindow> {
+    if congested.iter().all(|&c| c) {
+        return vec![window(hours, 0, 24, baseline_latency_ms)];
+    }
+
+    // Start scanning right after a quiet hour so no run is split at midnight.
+    let quiet = congested.iter().position(|&c| !c).unwrap_or(0);
+    let mut windows = Vec::new();
+    let mut run_start = None;
+    for step in 1..=24 {
+        let hour = (quiet + step) % 24;
+        match (congested[hour], run_start) {
+            (true, None) => run_start = Some(hour),
+            (false, Some(start)) => {
+                windows.push(window(hours, start, hour, baseline_latency_ms));
+                run_start = None;
+            }
+            _ => {}
+        }
+    }
+    windows.sort_by_key(|w| w.start_hour);
+    windows
+}
+
+fn window(
+    hours: &[Accumulator],
+    start: usize,
+    end: usize,
+    baseline_latency_ms: Option<u64>,
+) -> CongestionWindow {
+    let len = if end > start {
+        end - start
+    } else {
+        end + 24 - start
+    };
+    let mut merged = Accumulator::default();
+    for hour in (0..len).map(|i| (start + i) % 24) {
+        let h = &hours[hour];
+        merged.reports += h.reports;
+        merged.latency_sum += h.latency_sum;
+        merged.latency_count += h.latency_count;
+        merged.degraded += h.degraded;
+        merged.days.extend(&h.days);
+    }
+
+    CongestionWindow {
+        start_hour: start as u8,
+        end_hour: if end == 0 { 24 } else { end as u8 },
+        avg_latency_ms: merged.avg_latency_ms(),
+        baseline_latency_ms,
+        degraded_share: merged.degraded_share(),
+        days_observed: merged.days.len() as u32,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_profile_finds_recurring_evening_congestion() {
+        let mut reports = Vec::new();
+        // Three days (starting Monday 2024-01-01 UTC), one report every 30 minutes.
+        let monday_ms = 1_704_067_200_000u64;
+        for half_hour in 0..(3 * 48) {
+            let mut report = NetworkReport::fixture(true);
+            report.timestamp_ms = monday_ms + half_hour * 1_800_000;
+            let hour = (half_hour / 2) % 24;
+            report.status.latency_stats.latency_ms =
+                if (20..23).contains(&hour) { 300 } else { 40 };
+            reports.push(report);
+        }
+
+        let profile = profile_time_of_day(&reports, 0);
+        assert_eq!(profile.by_hour.len(), 24);
+        assert_eq!(profile.by_hour[21].avg_latency_ms, Some(300));
+        assert_eq!(profile.by_weekday[0].reports, 48);
+        assert_eq!(profile.by_weekday[3].reports, 0);
+
+        assert_eq!(profile.congestion_windows.len(), 1);
+        let window = &profile.congestion_windows[0];
+        assert_eq!((window.start_hour, window.end_hour), (20, 23));
+        assert_eq!(window.days_observed, 3);
+
+        // Shifting into UTC-1 moves the window an hour earlier.
+        let shifted = profile_time_of_day(&reports, -60);
+        assert_eq!(shifted.congestion_windows[0].start_hour, 19);
+    }
+}
@@ -0,0 +1,121 @@
+//! # VPN Toggle Comparison
+//!
+//! Compares two [NetworkReport]s captured on either side of a VPN toggle and
+//! derives the deltas and leak indicators that VPN client vendors care about.
+
+use crate::api::models::{ConnectionType, NetworkReport, VpnComparison};
+
+/// Compares a baseline report against a report captured after a VPN toggle.
+///
+/// # Arguments
+///
+/// * `before` / `after` - The reports captured on either side of the toggle.
+/// * `resolvers_before` / `resolvers_after` - The system DNS resolvers observed
+///   alongside each report (see [crate::api::probes::system_dns_resolvers]).
+///
+/// # Leak Heuristics
+///
+/// Leaks are only evaluated for the side of the toggle where the VPN is active:
+/// * **DNS leak:** the resolver set is identical to the one used without the VPN.
+/// * **Route leak:** the active interface is not recognised as a tunnel.
+pub fn compare_vpn_reports(
+    before: &NetworkReport,
+    after: &NetworkReport,
+    resolvers_before: &[String],
+    resolvers_after: &[String],
+) -> VpnComparison {
+    let vpn_before = before.security_flags_result.is_vpn_detected;
+    let vpn_after = after.security_flags_result.is_vpn_detected;
+    let toggle_observed = vpn_before != vpn_after;
+
+    let stats_before = &before.status.latency_stats;
+    let stats_after = &after.status.latency_stats;
+
+    let interface_before = before.security_flags_result.interface_name.clone();
+    let interface_after = after.security_flags_result.interface_name.clone();
+
+    let dns_resolver_changed = resolvers_before != resolvers_after;
+
+    // Identify which side ran through the tunnel, if any.
+    let vpn_side = match (vpn_before, vpn_after) {
+        (false, true) => Some(after),
+        (true, false) => Some(before),
+        _ => None,
+    };
+
+    let dns_leak_suspected =
+        toggle_observed && !resolvers_after.is_empty() && !dns_resolver_changed;
+    let route_leak_suspected =
+        vpn_side.is_some_and(|report| report.connection_type != ConnectionType::Vpn);
+
+    VpnComparison {
+        vpn_before,
+        vpn_after,
+        toggle_observed,
+        latency_delta_ms: stats_after.latency_ms as i64 - stats_before.latency_ms as i64,
+        jitter_delta_ms: stats_after.jitter_ms as i64 - stats_before.jitter_ms as i64,
+        packet_loss_delta_percent: stats_after.packet_loss_percent
+            - stats_before.packet_loss_percent,
+        route_changed: interface_before != interface_after,
+        interface_before,
+        interface_after,
+        connection_type_before: before.connection_type,
+        connection_type_after: after.connection_type,
+        resolvers_before: resolvers_before.to_vec(),
+        resolvers_after: resolvers_after.to_vec(),
+        dns_resolver_changed,
+        dns_leak_suspected,
+        route_leak_suspected,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::SecurityFlagsResult;
+
+    fn report(vpn: bool, interface: &str, latency_ms: u64) -> NetworkReport {
+        let mut report = NetworkReport::fixture(true);
+        let stats = &mut report.status.latency_stats;
+        stats.latency_ms = latency_ms;
+        stats.jitter_ms = 5;
+        report.connection_type = if vpn {
+            ConnectionType::Vpn
+        } else {
+            ConnectionType::Wifi
+        };
+        report.security_flags_result = SecurityFlagsResult {
+            is_vpn_detected: vpn,
+            interface_name: interface.into(),
+            ..SecurityFlagsResult::default()
+        };
+        report
+    }
+
+    #[test]
+    fn test_compare_vpn_reports_detects_dns_leak() {
+        let before = report(false, "wlan0", 20);
+        let after = report(true, "tun0", 45);
+        let resolvers = vec!["192.168.1.1".to_string()];
+
+        let cmp = compare_vpn_reports(&before, &after, &resolvers, &resolvers);
+        assert!(cmp.toggle_observed);
+        assert!(cmp.route_changed);
+        assert_eq!(cmp.latency_delta_ms, 25);
+        assert!(!cmp.dns_resolver_changed);
+        assert!(cmp.dns_leak_suspected);
+        assert!(!cmp.route_leak_suspected);
+    }
+
+    #[test]
+    fn test_compare_vpn_reports_without_toggle() {
+        let before = report(false, "wlan0", 20);
+        let after = report(false, "wlan0", 10);
+        let resolvers = vec!["192.168.1.1".to_string()];
+
+        let cmp = compare_vpn_reports(&before, &after, &resolvers, &resolvers);
+        assert!(!cmp.toggle_observed);
+        assert!(!cmp.dns_leak_suspected);
+        assert_eq!(cmp.latency_delta_ms, -10);
+    }
+}
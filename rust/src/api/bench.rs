@@ -0,0 +1,50 @@
+//! Hot loops for the criterion benchmarks.
+//!
+//! Only compiled with the `bench` feature. The benchmarks in `benches/`
+//! measure the engine against a [TestServer], so the numbers reflect the
+//! crate's own overhead rather than the network:
+//!
+//! ```ignore
+//! let server = TestServer::tcp_echo().start().await?;
+//! let config = loopback_config(&server, 4);
+//! let reports = sample_once(&config).await;
+//! ```
+
+use crate::api::{
+    engine::{rate_limit::RateLimiter, sampler::run_sample},
+    models::{NetworkConfiguration, TargetReport},
+    testsupport::TestServer,
+};
+
+/// A configuration with `targets` targets pointing at `server` and otherwise
+/// default settings.
+pub fn loopback_config(server: &TestServer, targets: usize) -> NetworkConfiguration {
+    NetworkConfiguration {
+        targets: (0..targets)
+            .map(|i| server.target(&format!("loopback-{}", i)))
+            .collect(),
+        ..NetworkConfiguration::default()
+    }
+}
+
+/// Runs one sample of `config`: every target probed once, as in the
+/// sampling loop of a check, without rate limiting or pre-resolution.
+pub async fn sample_once(config: &NetworkConfiguration) -> Vec<TargetReport> {
+    let unresolved = vec![None; config.targets.len()];
+    run_sample(config, &RateLimiter::unlimited(), &unresolved)
+        .await
+        .1
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_sample_once_against_loopback_server() {
+        let server = TestServer::tcp_echo().start().await.unwrap();
+        let reports = sample_once(&loopback_config(&server, 3)).await;
+        assert_eq!(reports.len(), 3);
+        assert!(reports.iter().all(|r| r.success), "{:?}", reports);
+    }
+}
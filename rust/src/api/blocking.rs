@@ -0,0 +1,314 @@
+//! Synchronous wrappers around the async API.
+//!
+//! Every function here spins up a scoped, single-threaded tokio runtime, drives
+//! the corresponding async function to completion and tears the runtime down
+//! again. This lets CLI tools and non-async code bases use the crate without
+//! managing tokio themselves:
+//!
+//! ```ignore
+//! use network_reachability::api::{blocking, models::NetworkConfiguration};
+//!
+//! let report = blocking::check_network(NetworkConfiguration::default());
+//! println!("connected: {}", report.status.is_connected);
+//! ```
+//!
+//! # Panics
+//!
+//! These functions must not be called from within an async context (tokio
+//! forbids nesting runtimes), and panic if the runtime cannot be created.
+
+use crate::api::{
+    engine,
+    models::{
+        CaptivePortalStatus, DscpLatency, EcnReport, GatewayFingerprint, HairpinReport,
+        LinkNeighbor, LocalDevice, LocalReachabilityReport, LocalScanConfig, MdnsService,
+        MssReport, MultiRouteTrace, MulticastReport, NatReport, NetworkConfiguration, NetworkError,
+        NetworkReport, NetworkTarget, OneWayDelayEstimate, PathMeasurement, PortForwardCheck,
+        RouteTrace, SimpleStatus, TargetReport, VpnComparison, WalledGardenReport,
+    },
+    probes,
+};
+use flutter_rust_bridge::frb;
+use std::future::Future;
+use tokio_util::sync::CancellationToken;
+
+/// Runs a full network check. See [engine::check_network].
+#[frb(ignore)]
+pub fn check_network(config: NetworkConfiguration) -> NetworkReport {
+    block_on(engine::check_network(config))
+}
+
+/// Runs a full network check that `cancel` can stop from another thread. See
+/// [engine::check_network_cancellable].
+#[frb(ignore)]
+pub fn check_network_cancellable(
+    config: NetworkConfiguration,
+    cancel: CancellationToken,
+) -> NetworkReport {
+    block_on(engine::check_network_cancellable(config, cancel))
+}
+
+/// Validates `config`, then runs a full network check. See [engine::try_check_network].
+#[frb(ignore)]
+pub fn try_check_network(config: NetworkConfiguration) -> Result<NetworkReport, NetworkError> {
+    block_on(engine::try_check_network(config))
+}
+
+/// Runs a default network check as a traffic light. See [engine::simple_status].
+#[frb(ignore)]
+pub fn simple_status() -> SimpleStatus {
+    block_on(engine::simple_status())
+}
+
+/// Runs a full network check and enforces the security policy. See [engine::guard].
+#[frb(ignore)]
+pub fn guard(config: NetworkConfiguration) -> Result<NetworkReport, NetworkError> {
+    block_on(engine::guard(config))
+}
+
+/// Checks LAN reachability only. See [engine::local::check_local_network].
+#[frb(ignore)]
+pub fn check_local_network(config: NetworkConfiguration) -> LocalReachabilityReport {
+    block_on(engine::local::check_local_network(config))
+}
+
+/// Checks a single target. See [probes::check_target].
+#[frb(ignore)]
+pub fn check_target(target: &NetworkTarget) -> TargetReport {
+    block_on(probes::check_target(target))
+}
+
+/// Probes for a captive portal. See [probes::check_for_captive_portal].
+#[frb(ignore)]
+pub fn check_for_captive_portal(timeout_ms: u64) -> CaptivePortalStatus {
+    block_on(probes::check_for_captive_portal(timeout_ms))
+}
+
+/// Probes for a captive portal, reporting why a probe failed. See
+/// [probes::try_check_for_captive_portal].
+#[frb(ignore)]
+pub fn try_check_for_captive_portal(timeout_ms: u64) -> Result<CaptivePortalStatus, NetworkError> {
+    block_on(probes::try_check_for_captive_portal(timeout_ms))
+}
+
+/// Waits until a captive portal lets traffic through. See
+/// [probes::wait_for_portal_clearance].
+#[frb(ignore)]
+pub fn wait_for_portal_clearance(
+    timeout_ms: u64,
+    poll_interval_ms: u64,
+) -> Result<CaptivePortalStatus, NetworkError> {
+    block_on(probes::wait_for_portal_clearance(
+        timeout_ms,
+        poll_interval_ms,
+    ))
+}
+
+/// Compares system DNS against a trusted resolver. See [probes::detect_dns_hijacking].
+#[frb(ignore)]
+pub fn detect_dns_hijacking(domain: &str) -> bool {
+    block_on(probes::detect_dns_hijacking(domain))
+}
+
+/// Sweeps the local network for devices. See [probes::scan_local_network].
+#[frb(ignore)]
+pub fn scan_local_network(config: LocalScanConfig) -> Vec<LocalDevice> {
+    block_on(probes::scan_local_network(config))
+}
+
+/// Sweeps the local network until done or `cancel` is cancelled. See
+/// [probes::scan_local_network_cancellable].
+#[frb(ignore)]
+pub fn scan_local_network_cancellable(
+    config: LocalScanConfig,
+    cancel: CancellationToken,
+) -> Result<Vec<LocalDevice>, NetworkError> {
+    block_on(probes::scan_local_network_cancellable(config, cancel))
+}
+
+/// Sweeps the local network, rejecting invalid scan settings. See
+/// [probes::try_scan_local_network].
+#[frb(ignore)]
+pub fn try_scan_local_network(config: LocalScanConfig) -> Result<Vec<LocalDevice>, NetworkError> {
+    block_on(probes::try_scan_local_network(config))
+}
+
+/// Identifies the router behind a gateway. See [probes::fingerprint_gateway].
+#[frb(ignore)]
+pub fn fingerprint_gateway(gateway: std::net::IpAddr, timeout_ms: u64) -> GatewayFingerprint {
+    block_on(probes::fingerprint_gateway(gateway, timeout_ms))
+}
+
+/// Listens for an LLDP/CDP announcement. See [probes::discover_link_neighbor].
+#[frb(ignore)]
+pub fn discover_link_neighbor(
+    interface: String,
+    listen_ms: u64,
+) -> Result<Option<LinkNeighbor>, NetworkError> {
+    block_on(probes::discover_link_neighbor(interface, listen_ms))
+}
+
+/// Compares probe latency across DSCP markings. See [probes::compare_dscp_markings].
+#[frb(ignore)]
+pub fn compare_dscp_markings(
+    target: NetworkTarget,
+    markings: Vec<u8>,
+    samples: u8,
+) -> Vec<DscpLatency> {
+    block_on(probes::compare_dscp_markings(target, markings, samples))
+}
+
+/// Detects ECN negotiation and bleaching. See [probes::detect_ecn].
+#[frb(ignore)]
+pub fn detect_ecn(
+    tcp_servers: Vec<String>,
+    reflection_servers: Vec<String>,
+    timeout_ms: u64,
+) -> EcnReport {
+    block_on(probes::detect_ecn(
+        tcp_servers,
+        reflection_servers,
+        timeout_ms,
+    ))
+}
+
+/// Tests a self-hosted service from the LAN and the internet. See [probes::check_hairpin].
+#[frb(ignore)]
+pub fn check_hairpin(
+    port: u16,
+    public_address: Option<String>,
+    reflection_servers: Vec<String>,
+    timeout_ms: u64,
+) -> HairpinReport {
+    block_on(probes::check_hairpin(
+        port,
+        public_address,
+        reflection_servers,
+        timeout_ms,
+    ))
+}
+
+/// Verifies a port forward via a "check my port" service. See [probes::verify_port_forwarding].
+#[frb(ignore)]
+pub fn verify_port_forwarding(
+    endpoint: String,
+    port: u16,
+    timeout_ms: u64,
+) -> Result<PortForwardCheck, NetworkError> {
+    block_on(probes::verify_port_forwarding(endpoint, port, timeout_ms))
+}
+
+/// Checks LAN multicast via the mDNS group. See [probes::check_multicast].
+#[frb(ignore)]
+pub fn check_multicast(timeout_ms: u64) -> MulticastReport {
+    block_on(probes::check_multicast(timeout_ms))
+}
+
+/// Browses the LAN for mDNS/DNS-SD services. See [probes::browse_mdns_services].
+#[frb(ignore)]
+pub fn browse_mdns_services(
+    duration_ms: u64,
+    exclude_ranges: Vec<String>,
+) -> Result<Vec<MdnsService>, NetworkError> {
+    block_on(probes::browse_mdns_services(duration_ms, exclude_ranges))
+}
+
+/// Measures every candidate interface separately. See [probes::measure_paths].
+#[frb(ignore)]
+pub fn measure_paths(timeout_ms: u64) -> Vec<PathMeasurement> {
+    block_on(probes::measure_paths(timeout_ms))
+}
+
+/// Probes what a captive portal lets through. See [probes::probe_walled_garden].
+#[frb(ignore)]
+pub fn probe_walled_garden(timeout_ms: u64) -> WalledGardenReport {
+    block_on(probes::probe_walled_garden(timeout_ms))
+}
+
+/// Detects MSS clamping towards reference servers. See [probes::detect_mss_clamping].
+#[frb(ignore)]
+pub fn detect_mss_clamping(servers: Vec<String>, timeout_ms: u64) -> MssReport {
+    block_on(probes::detect_mss_clamping(servers, timeout_ms))
+}
+
+/// Splits the delay to a timestamping echo server by direction.
+/// See [probes::estimate_one_way_delay].
+#[frb(ignore)]
+pub fn estimate_one_way_delay(
+    server: String,
+    samples: u8,
+    timeout_ms: u64,
+) -> Result<OneWayDelayEstimate, NetworkError> {
+    block_on(probes::estimate_one_way_delay(server, samples, timeout_ms))
+}
+
+/// Infers NAT and path behavior from reflection servers. See [probes::probe_nat].
+#[frb(ignore)]
+pub fn probe_nat(servers: Vec<String>, timeout_ms: u64) -> Result<NatReport, NetworkError> {
+    block_on(probes::probe_nat(servers, timeout_ms))
+}
+
+/// Traces the route to a host. See [probes::trace_route].
+#[frb(ignore)]
+pub fn trace_route(
+    host: String,
+    max_hops: u8,
+    timeout_per_hop_ms: u64,
+) -> Result<RouteTrace, NetworkError> {
+    block_on(probes::trace_route(host, max_hops, timeout_per_hop_ms))
+}
+
+/// Traces the route to a host until done or `cancel` is cancelled. See
+/// [probes::trace_route_cancellable].
+#[frb(ignore)]
+pub fn trace_route_cancellable(
+    host: String,
+    max_hops: u8,
+    timeout_per_hop_ms: u64,
+    cancel: CancellationToken,
+) -> Result<RouteTrace, NetworkError> {
+    block_on(probes::trace_route_cancellable(
+        host,
+        max_hops,
+        timeout_per_hop_ms,
+        cancel,
+    ))
+}
+
+/// Traces and compares several routes. See [probes::trace_routes].
+#[frb(ignore)]
+pub fn trace_routes(hosts: Vec<String>, max_hops: u8, timeout_per_hop_ms: u64) -> MultiRouteTrace {
+    block_on(probes::trace_routes(hosts, max_hops, timeout_per_hop_ms))
+}
+
+/// Captures a VPN before/after comparison. See [engine::vpn::compare_vpn_toggle].
+#[frb(ignore)]
+pub fn compare_vpn_toggle(config: NetworkConfiguration, wait_timeout_ms: u64) -> VpnComparison {
+    block_on(engine::vpn::compare_vpn_toggle(config, wait_timeout_ms))
+}
+
+/// Drives `future` to completion on a fresh current-thread runtime.
+fn block_on<F: Future>(future: F) -> F::Output {
+    tokio::runtime::Builder::new_current_thread()
+        .enable_all()
+        .build()
+        .expect("failed to build tokio runtime")
+        .block_on(future)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_blocking_check_network_without_runtime() {
+        let mut config = NetworkConfiguration {
+            targets: vec![],
+            ..NetworkConfiguration::default()
+        };
+        config.security.detect_dns_hijack = false;
+
+        let report = check_network(config);
+        assert!(!report.status.is_connected);
+    }
+}
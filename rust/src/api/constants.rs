@@ -12,13 +12,22 @@ impl LibConstants {
     pub const DEFAULT_INTERFACE_NAME: &'static str = "unknown";
 
     pub const DEFAULT_PORT: u16 = 53;
+    pub const DEFAULT_DNS_QUERY_NAME: &'static str = "example.com";
+    pub const MAX_DNS_LABEL_LEN: usize = 63;
+    /// The limit of a name in DNS wire format, length bytes included.
+    pub const MAX_DNS_NAME_LEN: usize = 255;
     pub const DEFAULT_HTTP_PORT: u16 = 443;
 
     pub const DEFAULT_HTTP_TIMEOUT_MS: u64 = 1500;
     pub const DEFAULT_TIMEOUT_MS: u64 = 1000;
     pub const DEFAULT_CHECK_INTERVAL_MS: u64 = 5000;
+    /// Upper bound of a sane target timeout, enforced by configuration validation.
+    pub const MAX_TARGET_TIMEOUT_MS: u64 = 60_000;
+    /// ±10%
+    pub const DEFAULT_CHECK_INTERVAL_JITTER_PERCENT: u8 = 10;
 
     pub const CLOUDFLARE_DNS: &'static str = "1.1.1.1";
+    pub const CLOUDFLARE_DNS_V6: &'static str = "2606:4700:4700::1111";
     pub const GOOGLE_DNS: &'static str = "8.8.8.8";
 
     pub const DEFAULT_EXCELLENT_THRESHOLD: u64 = 50;
@@ -31,8 +40,20 @@ impl LibConstants {
 
     pub const DEFAULT_JITTER_SAMPLES: u8 = 5;
     pub const DEFAULT_JITTER_THRESHOLD_PERCENT: f64 = 0.2;
+    pub const DEFAULT_PARALLEL_SAMPLE_OFFSET_MS: u64 = 20;
+    pub const DEFAULT_SPIKE_RATIO_THRESHOLD: f64 = 2.0;
+    pub const DEFAULT_SPIKE_PENALTY: u8 = 20;
 
     pub const CAPTIVE_PORTAL_DETECTION_URL: &'static str = "http://neverssl.com";
+    /// Portal pages are read up to this size to describe the login.
+    pub const MAX_PORTAL_PAGE_BYTES: usize = 64 * 1024;
+    /// Other HTTP responses (status pages, router pages, UPnP descriptions,
+    /// HTTP targets) are read up to this size.
+    pub const MAX_RESPONSE_BODY_BYTES: usize = 1024 * 1024;
+    /// Input names that mark a portal login as requiring credentials.
+    pub const PORTAL_CREDENTIAL_FIELDS: &'static [&'static str] = &[
+        "user", "login", "email", "voucher", "code", "pin", "room", "ticket", "pass",
+    ];
 
     /// 40%
     pub const DEFAULT_STABILITY_THRESHOLD: u8 = 40;
@@ -40,6 +61,222 @@ impl LibConstants {
     pub const DEFAULT_CRITICAL_PACKET_LOSS_PRECENT: f32 = 5.0;
 
     pub const DEFAULT_MAX_TRACEROUTE_HOPS: u8 = 30;
+    /// Per-hop timeout of the traceroute run by
+    /// [PipelineStep::Traceroute](crate::api::engine::pipeline::PipelineStep).
+    pub const DEFAULT_TRACEROUTE_HOP_TIMEOUT_MS: u64 = 1000;
+
+    /// First destination port of UDP traceroute probes; hop `n` uses
+    /// `base + n`, as in classic traceroute, so replies map to hops.
+    pub const UDP_TRACEROUTE_BASE_PORT: u16 = 33434;
+
+    /// Port connected to when an ICMP target without a port falls back to
+    /// TCP ping.
+    pub const TCP_PING_FALLBACK_PORT: u16 = 443;
+
+    pub const DEFAULT_VPN_TOGGLE_POLL_MS: u64 = 500;
+
+    /// Full reports kept by a [ReachabilityEngine](crate::api::engine::ReachabilityEngine):
+    /// one hour at the default check interval.
+    pub const DEFAULT_HISTORY_CAPACITY: usize = 720;
+    /// Reports a slow [NetworkMonitor](crate::api::monitor::NetworkMonitor)
+    /// subscriber may fall behind before the oldest are skipped.
+    pub const MONITOR_REPORT_BUFFER: usize = 16;
+    /// How long a shutdown waits for checks under way to wind down.
+    pub const DEFAULT_SHUTDOWN_TIMEOUT_MS: u64 = 2000;
+    /// Overdue checks a monitor with [OverlapPolicy::Queue](crate::api::models::OverlapPolicy::Queue)
+    /// keeps pending; further overdue cycles are skipped.
+    pub const MONITOR_MAX_QUEUED_CHECKS: u32 = 3;
+    /// Age after which full reports are folded into per-minute aggregates.
+    pub const HISTORY_REPORT_RETENTION_MS: u64 = 3_600_000;
+    /// Age after which per-minute aggregates are folded into hourly ones.
+    pub const HISTORY_MINUTE_RETENTION_MS: u64 = 86_400_000;
+    /// Hourly aggregates kept (90 days).
+    pub const MAX_HOURLY_AGGREGATES: usize = 24 * 90;
+
+    /// EWMA smoothing factor of the anomaly detector's baselines.
+    pub const DEFAULT_ANOMALY_SMOOTHING: f64 = 0.1;
+    pub const DEFAULT_ANOMALY_Z_THRESHOLD: f64 = 3.0;
+    pub const DEFAULT_ANOMALY_WARMUP_REPORTS: u32 = 10;
+    /// Floors for the smoothed deviation, so a perfectly flat series does not
+    /// turn every 1 ms wobble into an anomaly.
+    pub const ANOMALY_MIN_LATENCY_STD_DEV_MS: f64 = 5.0;
+    pub const ANOMALY_MIN_LOSS_STD_DEV_PERCENT: f64 = 2.0;
+    /// Undelivered anomaly events kept per engine.
+    pub const MAX_PENDING_ANOMALIES: usize = 64;
+    /// Runtimes whose DNS resolvers are kept; older ones are rebuilt on next use.
+    pub const MAX_RESOLVER_RUNTIMES: usize = 8;
+
+    /// An hour is congested if its mean latency exceeds the overall mean by this factor...
+    pub const CONGESTION_LATENCY_FACTOR: f64 = 1.5;
+    /// ...or if at least this share of its reports were degraded.
+    pub const CONGESTION_DEGRADED_SHARE: f32 = 0.5;
+    pub const MIN_REPORTS_PER_TIME_BUCKET: u32 = 3;
+    /// Distinct days an hour must be seen on before it can form a pattern.
+    pub const MIN_CONGESTION_DAYS: usize = 2;
+
+    /// Column width of a latency heatmap.
+    pub const DEFAULT_HEATMAP_BUCKET_MS: u64 = 15 * 60_000;
+    /// Longer histories get wider columns instead of more of them.
+    pub const MAX_HEATMAP_COLUMNS: u64 = 2000;
+    /// A heatmap cell is degraded if its latency exceeds the target's median
+    /// by this factor and by at least `HEATMAP_MIN_LATENCY_INCREASE_MS`...
+    pub const HEATMAP_LATENCY_FACTOR: f64 = 2.0;
+    pub const HEATMAP_MIN_LATENCY_INCREASE_MS: u64 = 20;
+    /// ...or if at least this share of its probes failed.
+    pub const HEATMAP_FAILURE_SHARE: f32 = 0.5;
+
+    /// End-to-end jitter (ms) below which latency variance is not blamed on
+    /// any segment.
+    pub const ATTRIBUTION_MIN_JITTER_MS: u64 = 5;
+    /// Share of the variance or loss from which the first hop is blamed.
+    pub const ATTRIBUTION_LAN_SHARE: f32 = 0.5;
+
+    pub const DEFAULT_PASSIVE_FAILURE_THRESHOLD: u8 = 3;
+    pub const DEFAULT_PASSIVE_SIGNAL_WINDOW_MS: u64 = 30000;
+    /// App-reported outcomes kept per engine.
+    pub const MAX_PASSIVE_SIGNALS: usize = 64;
+
+    pub const GATEWAY_NAME: &'static str = "Gateway";
+    pub const DEFAULT_GATEWAY_TIMEOUT_MS: u64 = 500;
+    /// Ports a home/office router commonly listens on (DNS, HTTP admin UI).
+    /// A refused connection still proves the gateway is reachable.
+    pub const GATEWAY_TCP_PORTS: &'static [u16] = &[53, 80, 443];
+
+    pub const DEFAULT_SCAN_SUBNET: &'static str = "192.168.1.0/24";
+    pub const DEFAULT_SCAN_PORT: u16 = 80;
+    pub const DEFAULT_SCAN_TIMEOUT_MS: u64 = 300;
+    /// Preset for [LocalScanConfig::scan_ports](crate::api::models::LocalScanConfig::scan_ports):
+    /// SSH, Telnet, DNS, HTTP(S), SMB, RTSP, IPP, RDP, UPnP, alternative HTTP
+    /// and raw printing.
+    pub const COMMON_SCAN_PORTS: &'static [u16] = &[
+        22, 23, 53, 80, 139, 443, 445, 554, 631, 3389, 5000, 8080, 8443, 9100,
+    ];
+    /// Ports whose banner is asked for with an RTSP instead of an HTTP request.
+    pub const RTSP_PORTS: &'static [u16] = &[554, 8554];
+    /// Hosts probed at once; keeps a large sweep well below common
+    /// file-descriptor limits.
+    pub const DEFAULT_SCAN_CONCURRENCY: usize = 128;
+    /// Largest number of hosts a single sweep may cover, a /20.
+    pub const DEFAULT_MAX_SCAN_HOSTS: usize = 4096;
+    pub const DEFAULT_BANNER_TIMEOUT_MS: u64 = 500;
+    /// Per device, for the name lookups of the hostname pass.
+    pub const DEFAULT_HOSTNAME_LOOKUP_TIMEOUT_MS: u64 = 1000;
+    /// Devices whose hostnames are looked up at once.
+    pub const DEFAULT_HOSTNAME_LOOKUP_CONCURRENCY: usize = 16;
+    /// Longest banner kept; anything beyond is cut off.
+    pub const MAX_BANNER_BYTES: usize = 256;
+    /// Interval of the progress events of a streaming sweep, which also
+    /// bounds how long a sweep outlives a closed Dart subscription.
+    pub const SCAN_PROGRESS_INTERVAL_MS: u64 = 1000;
+
+    pub const DEFAULT_REFLECTION_TIMEOUT_MS: u64 = 1000;
+    /// TTL set on reflection probes, so servers' observed TTL yields a hop count.
+    pub const REFLECTION_PROBE_TTL: u8 = 64;
+    /// Forward/reverse hop count difference that hints at asymmetric routing.
+    pub const ASYMMETRIC_ROUTE_HOP_DIFFERENCE: u8 = 3;
+
+    /// Budget for a connect-back test, which includes the server's own
+    /// connection timeout.
+    pub const DEFAULT_CONNECT_BACK_TIMEOUT_MS: u64 = 5000;
+    pub const DEFAULT_HAIRPIN_TIMEOUT_MS: u64 = 2000;
+
+    /// Budget for a "check my port" request, which includes the service's
+    /// own connection attempt.
+    pub const DEFAULT_PORT_CHECK_TIMEOUT_MS: u64 = 10000;
+
+    pub const DEFAULT_ONE_WAY_DELAY_SAMPLES: u8 = 10;
+    pub const DEFAULT_ONE_WAY_DELAY_TIMEOUT_MS: u64 = 1000;
+    /// Pause between timestamp exchanges, so they do not queue behind each other.
+    pub const ONE_WAY_DELAY_SAMPLE_SPACING_MS: u64 = 50;
+
+    /// Cloudflare's speed test endpoint; the size is capped by the time budget below.
+    pub const DEFAULT_PREFLIGHT_DOWNLOAD_URL: &'static str =
+        "https://speed.cloudflare.com/__down?bytes=25000000";
+    pub const DEFAULT_PREFLIGHT_UDP_DNS_SERVER: &'static str = "1.1.1.1:53";
+    pub const DEFAULT_PREFLIGHT_WEBSOCKET_URL: &'static str = "wss://echo.websocket.org/";
+    /// Time budget of the preflight download; throughput is computed from what arrived.
+    pub const PREFLIGHT_DOWNLOAD_MAX_MS: u64 = 5000;
+    pub const PREFLIGHT_UDP_TIMEOUT_MS: u64 = 2000;
+
+    /// DSCP Expedited Forwarding (RFC 3246), used for VoIP media.
+    pub const DSCP_EF: u8 = 46;
+    /// DSCP Assured Forwarding class 4, low drop (RFC 2597), used for video.
+    pub const DSCP_AF41: u8 = 34;
+    /// DSCP Class Selector 0: best effort.
+    pub const DSCP_BEST_EFFORT: u8 = 0;
+    pub const DEFAULT_DSCP_COMPARISON_SAMPLES: u8 = 10;
+
+    /// Reference servers for TCP handshake measurements (MSS, ECN); anycast,
+    /// so they are nearby.
+    pub const DEFAULT_TCP_REFERENCE_SERVERS: &'static [&'static str] =
+        &["1.1.1.1:443", "8.8.8.8:443", "9.9.9.9:443"];
+    pub const DEFAULT_MSS_TIMEOUT_MS: u64 = 2000;
+    pub const ETHERNET_MTU: u16 = 1500;
+    /// PPPoE header plus PPP protocol field.
+    pub const PPPOE_OVERHEAD_BYTES: u16 = 8;
+    /// The smallest outer header of common tunnels (an extra IPv4 header).
+    pub const MIN_TUNNEL_OVERHEAD_BYTES: u16 = 20;
+
+    pub const DEFAULT_ECN_TIMEOUT_MS: u64 = 2000;
+
+    /// Loss difference (percentage points) above which the family with less
+    /// loss is preferred.
+    pub const FAMILY_LOSS_MARGIN_PERCENT: f32 = 5.0;
+    /// Latency difference above which the faster family is preferred.
+    pub const FAMILY_LATENCY_MARGIN_MS: u64 = 10;
+
+    /// Latency advantage above which another interface is the better path.
+    pub const PATH_LATENCY_MARGIN_MS: u64 = 30;
+    /// Loss advantage (percentage points) above which another interface is
+    /// the better path regardless of latency.
+    pub const PATH_LOSS_MARGIN_PERCENT: f32 = 10.0;
+
+    /// Listening window of the multicast check; mDNS responders delay their
+    /// answers by up to 120 ms, plus slow Wi-Fi power-save wakeups.
+    pub const DEFAULT_MULTICAST_TIMEOUT_MS: u64 = 2000;
+    /// Browsing window of mDNS service discovery; each service type found
+    /// takes another round of queries and delayed answers.
+    pub const DEFAULT_MDNS_BROWSE_MS: u64 = 3000;
+
+    /// Per destination of the walled garden probe.
+    pub const DEFAULT_WALLED_GARDEN_TIMEOUT_MS: u64 = 2000;
+    /// Certificate valid for the bare IP, so the check needs no DNS.
+    pub const WALLED_GARDEN_HTTPS_URL: &'static str = "https://1.1.1.1/";
+    /// Listens on every TCP port.
+    pub const WALLED_GARDEN_PORT_HOST: &'static str = "portquiz.net";
+    /// SSH, HTTP, HTTPS, DNS over TLS, IMAPS, XMPP and the usual HTTP alternate.
+    pub const WALLED_GARDEN_PORTS: &'static [u16] = &[22, 80, 443, 853, 993, 5222, 8080];
+
+    pub const DEFAULT_SNMP_COMMUNITY: &'static str = "public";
+    pub const DEFAULT_SNMP_TIMEOUT_MS: u64 = 2000;
+    /// Interfaces read per device; large switches are cut off here.
+    pub const MAX_SNMP_INTERFACES: usize = 64;
+
+    // ── Estimated Probe Sizes (bytes on the wire, both directions) ─────────
+
+    pub const ESTIMATED_TCP_PROBE_BYTES: u64 = 400;
+    pub const ESTIMATED_ICMP_PROBE_BYTES: u64 = 72;
+    pub const ESTIMATED_HTTP_PROBE_BYTES: u64 = 1500;
+    pub const ESTIMATED_HTTPS_PROBE_BYTES: u64 = 6000;
+    /// Sized for the largest payload, a padded QUIC Initial.
+    pub const ESTIMATED_UDP_PROBE_BYTES: u64 = 1400;
+    pub const ESTIMATED_DNS_PROBE_BYTES: u64 = 200;
+    pub const ESTIMATED_CAPTIVE_PORTAL_PROBE_BYTES: u64 = 4000;
+    pub const ESTIMATED_DNS_CHECK_BYTES: u64 = 600;
+    /// Per status URL.
+    pub const ESTIMATED_ISP_STATUS_PROBE_BYTES: u64 = 4000;
+    /// Per device: the system description plus a walk of a small `ifTable`.
+    pub const ESTIMATED_SNMP_QUERY_BYTES: u64 = 8000;
+    /// One mDNS query plus a handful of LAN responses.
+    pub const ESTIMATED_MULTICAST_CHECK_BYTES: u64 = 3000;
+    /// A TLS handshake, a DNS query and one TCP handshake per port.
+    pub const ESTIMATED_WALLED_GARDEN_PROBE_BYTES: u64 = 9000;
+    /// One TCP handshake to a reference server over one interface.
+    pub const ESTIMATED_PATH_COMPARISON_BYTES: u64 = 800;
+    /// One probe and one ICMP error per hop, up to the default hop limit.
+    pub const ESTIMATED_TRACEROUTE_BYTES: u64 = 4400;
+    /// Per gateway sample: an ICMP echo and a TCP handshake per gateway port.
+    pub const ESTIMATED_GATEWAY_SAMPLE_BYTES: u64 = 1300;
 
     // ── Connection Type Detection Prefixes ──────────────────────────────────
 
@@ -149,4 +386,35 @@ impl LibConstants {
     pub const LOOPBACK_PREFIXES: &'static [&'static str] = &["lo"];
 
     pub const BLUETOOTH_PREFIXES: &'static [&'static str] = &["bnep", "bt", "pan"];
+
+    /// Linux sysfs directory with one entry per network interface, used to
+    /// classify interfaces whose names are ambiguous.
+    pub const SYSFS_NET_DIR: &'static str = "/sys/class/net";
+
+    // ── Windows Adapter Descriptions ────────────────────────────────────────
+    //
+    // Most VPN clients on Windows register ordinary Ethernet-type adapters, so
+    // the driver description is the only reliable hint. Matched lowercase.
+
+    pub const WINDOWS_VPN_DESCRIPTIONS: &'static [&'static str] = &[
+        "vpn",
+        "wireguard",
+        "wintun",
+        "tap-windows",
+        "openvpn",
+        "anyconnect",
+        "fortinet",
+        "fortissl",
+        "globalprotect",
+        "pangp",
+        "juniper",
+        "pulse secure",
+        "nordlynx",
+        "tailscale",
+        "zerotier",
+        "mullvad",
+        "proton",
+    ];
+
+    pub const WINDOWS_BLUETOOTH_DESCRIPTIONS: &'static [&'static str] = &["bluetooth"];
 }
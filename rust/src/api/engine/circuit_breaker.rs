@@ -0,0 +1,168 @@
+//! Circuit breaker bookkeeping for a [ReachabilityEngine](super::ReachabilityEngine).
+
+use crate::api::models::{NetworkReport, ResilienceConfig};
+use flutter_rust_bridge::frb;
+use std::time::{Duration, Instant};
+
+/// The operational state of a circuit breaker.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum CircuitBreakerState {
+    /// Checks run normally.
+    Closed,
+
+    /// Essential targets failed repeatedly; checks are suppressed until the
+    /// cooldown expires.
+    Open,
+
+    /// The cooldown expired; the next check decides whether to close or reopen.
+    HalfOpen,
+}
+
+/// Tracks consecutive essential-target failures and the resulting breaker state.
+#[frb(opaque)]
+#[derive(Debug, Clone)]
+pub struct CircuitBreaker {
+    threshold: u8,
+    cooldown: Duration,
+    consecutive_failures: u8,
+    opened_at: Option<Instant>,
+}
+
+impl CircuitBreaker {
+    /// Creates a closed breaker from the resilience settings.
+    ///
+    /// A `circuit_breaker_threshold` of 0 yields a breaker that never opens.
+    pub fn new(config: &ResilienceConfig) -> Self {
+        Self {
+            threshold: config.circuit_breaker_threshold,
+            cooldown: Duration::from_millis(config.circuit_breaker_cooldown_ms),
+            consecutive_failures: 0,
+            opened_at: None,
+        }
+    }
+
+    /// The current state, taking the cooldown into account.
+    pub fn state(&self) -> CircuitBreakerState {
+        self.state_at(Instant::now())
+    }
+
+    fn state_at(&self, now: Instant) -> CircuitBreakerState {
+        match self.opened_at {
+            None => CircuitBreakerState::Closed,
+            Some(t) if now.duration_since(t) < self.cooldown => CircuitBreakerState::Open,
+            Some(_) => CircuitBreakerState::HalfOpen,
+        }
+    }
+
+    /// Time left until an open breaker becomes half-open, if it is open.
+    #[frb(ignore)]
+    pub fn retry_after(&self) -> Option<Duration> {
+        let opened_at = self.opened_at?;
+        self.cooldown.checked_sub(opened_at.elapsed())
+    }
+
+    /// Number of consecutive checks in which an essential target failed.
+    pub fn consecutive_failures(&self) -> u8 {
+        self.consecutive_failures
+    }
+
+    /// How long ago the breaker opened, or `None` while it is closed.
+    pub(crate) fn opened_ago(&self) -> Option<Duration> {
+        self.opened_at.map(|t| t.elapsed())
+    }
+
+    /// Restores the state saved via [Self::consecutive_failures] and
+    /// [Self::opened_ago], e.g. from a previous process.
+    pub(crate) fn restore(&mut self, consecutive_failures: u8, opened_ago: Option<Duration>) {
+        let now = Instant::now();
+        self.consecutive_failures = consecutive_failures;
+        // An instant cannot predate boot on every platform. A breaker opened
+        // that long ago is past its cooldown on any realistic setting, so it
+        // restores as half-open (or, right after boot, open for one cooldown).
+        self.opened_at = opened_ago.map(|ago| {
+            now.checked_sub(ago)
+                .or_else(|| now.checked_sub(self.cooldown))
+                .unwrap_or(now)
+        });
+    }
+
+    /// Feeds the result of a finished check into the breaker.
+    ///
+    /// Any check in which every essential target succeeded closes the breaker.
+    pub fn record(&mut self, report: &NetworkReport) {
+        self.record_at(report, Instant::now());
+    }
+
+    fn record_at(&mut self, report: &NetworkReport, now: Instant) {
+        if self.threshold == 0 {
+            return;
+        }
+
+        let essential_failed = report
+            .target_reports
+            .iter()
+            .any(|r| r.is_essential && !r.success);
+
+        if essential_failed {
+            self.consecutive_failures = self.consecutive_failures.saturating_add(1);
+            // A failed half-open trial restarts the cooldown immediately.
+            if self.consecutive_failures >= self.threshold || self.opened_at.is_some() {
+                self.opened_at = Some(now);
+            }
+        } else {
+            self.consecutive_failures = 0;
+            self.opened_at = None;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::TargetReport;
+
+    fn report(essential_ok: bool) -> NetworkReport {
+        let mut report = NetworkReport::fixture(essential_ok);
+        report.target_reports = vec![TargetReport {
+            is_essential: true,
+            ..TargetReport::fixture("core", essential_ok, 0)
+        }];
+        report
+    }
+
+    fn breaker(threshold: u8) -> CircuitBreaker {
+        CircuitBreaker::new(&ResilienceConfig {
+            circuit_breaker_threshold: threshold,
+            circuit_breaker_cooldown_ms: 1000,
+            ..ResilienceConfig::default()
+        })
+    }
+
+    #[test]
+    fn test_breaker_opens_after_threshold_and_half_opens() {
+        let mut cb = breaker(2);
+        let t0 = Instant::now();
+
+        cb.record_at(&report(false), t0);
+        assert_eq!(cb.state_at(t0), CircuitBreakerState::Closed);
+        cb.record_at(&report(false), t0);
+        assert_eq!(cb.state_at(t0), CircuitBreakerState::Open);
+        assert_eq!(
+            cb.state_at(t0 + Duration::from_millis(1500)),
+            CircuitBreakerState::HalfOpen
+        );
+
+        cb.record_at(&report(true), t0);
+        assert_eq!(cb.state_at(t0), CircuitBreakerState::Closed);
+        assert_eq!(cb.consecutive_failures(), 0);
+    }
+
+    #[test]
+    fn test_breaker_disabled_with_zero_threshold() {
+        let mut cb = breaker(0);
+        for _ in 0..10 {
+            cb.record(&report(false));
+        }
+        assert_eq!(cb.state(), CircuitBreakerState::Closed);
+    }
+}
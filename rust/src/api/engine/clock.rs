@@ -0,0 +1,41 @@
+//! Time sources for checks and reports.
+//!
+//! Every duration is measured on the monotonic clock ([Instant]), so NTP steps
+//! or manual clock changes cannot corrupt latencies or intervals on
+//! long-running monitors. The wall clock is only used to label *when* a report
+//! was taken; each report carries both readings.
+
+use ::chrono::Utc;
+use std::{sync::OnceLock, time::Instant};
+
+fn epoch() -> Instant {
+    static EPOCH: OnceLock<Instant> = OnceLock::new();
+    *EPOCH.get_or_init(Instant::now)
+}
+
+/// Milliseconds on a process-local monotonic clock.
+///
+/// Only differences between two readings are meaningful; the origin is the
+/// first time the engine looked at the clock. Unaffected by changes to the
+/// system time.
+pub fn monotonic_ms() -> u64 {
+    epoch().elapsed().as_millis() as u64
+}
+
+/// The current UTC wall-clock time in milliseconds since the Unix epoch.
+pub fn wall_clock_ms() -> u64 {
+    Utc::now().timestamp_millis() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_monotonic_clock_never_goes_backwards() {
+        let a = monotonic_ms();
+        std::thread::sleep(std::time::Duration::from_millis(5));
+        let b = monotonic_ms();
+        assert!(b >= a + 5);
+    }
+}
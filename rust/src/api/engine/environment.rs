@@ -0,0 +1,129 @@
+//! A self-test of what the crate can do on the current platform.
+//!
+//! Many report fields depend on privileges, the platform or Cargo features,
+//! and stay `None` or empty without them. [environment_report] collects all
+//! of these in one place, e.g. for a debug screen or a bug report.
+
+use crate::api::{
+    models::{EnvironmentReport, Limitation, ProbeCapabilities, RuntimeEnvironment},
+    probes::{
+        detect_probe_capabilities, detect_runtime_environment,
+        transport::{self, TransportInterface},
+    },
+};
+use std::net::IpAddr;
+
+/// Reports the platform, the available probe modes and address families, and
+/// the enabled features, along with the limitations they imply.
+///
+/// Cheap enough to call at any time: it opens a few sockets and lists the
+/// interfaces, but sends no traffic.
+pub fn environment_report() -> EnvironmentReport {
+    let interfaces = transport::current().list_interfaces().unwrap_or_default();
+    let (has_ipv4, has_ipv6) = address_families(&interfaces);
+    let features: Vec<String> = [
+        ("serde", cfg!(feature = "serde")),
+        ("snmp", cfg!(feature = "snmp")),
+        ("testsupport", cfg!(feature = "testsupport")),
+        ("bench", cfg!(feature = "bench")),
+        ("fuzzing", cfg!(feature = "fuzzing")),
+    ]
+    .into_iter()
+    .filter(|(_, enabled)| *enabled)
+    .map(|(name, _)| name.to_string())
+    .collect();
+
+    let mut report = EnvironmentReport {
+        crate_version: env!("CARGO_PKG_VERSION").to_string(),
+        os: std::env::consts::OS.to_string(),
+        arch: std::env::consts::ARCH.to_string(),
+        runtime: detect_runtime_environment(),
+        capabilities: detect_probe_capabilities(),
+        has_ipv4,
+        has_ipv6,
+        features,
+        limitations: Vec::new(),
+    };
+    report.limitations = limitations(&report);
+    report
+}
+
+/// Whether any interface has a usable IPv4 and IPv6 address, respectively.
+fn address_families(interfaces: &[TransportInterface]) -> (bool, bool) {
+    let addrs = || interfaces.iter().flat_map(|iface| &iface.addrs);
+    let has_ipv4 = addrs().any(|ip| matches!(ip, IpAddr::V4(v4) if !v4.is_loopback()));
+    let has_ipv6 = addrs().any(|ip| {
+        matches!(ip, IpAddr::V6(v6) if !v6.is_loopback() && (v6.segments()[0] & 0xffc0) != 0xfe80)
+    });
+    (has_ipv4, has_ipv6)
+}
+
+/// Lists what the gaps in `report` disable.
+fn limitations(report: &EnvironmentReport) -> Vec<Limitation> {
+    let ProbeCapabilities {
+        icmp_echo,
+        icmp_raw,
+        udp_traceroute,
+        arp_table,
+    } = report.capabilities;
+    let linux = matches!(report.os.as_str(), "linux" | "android");
+
+    [
+        (!icmp_echo, Limitation::NoIcmpSockets),
+        (!icmp_raw && udp_traceroute, Limitation::UdpTracerouteOnly),
+        (!icmp_raw && !udp_traceroute, Limitation::NoTraceroute),
+        (!arp_table, Limitation::NoNeighborTable),
+        (!linux, Limitation::NoTcpInfo),
+        (!report.has_ipv4, Limitation::NoIpv4),
+        (!report.has_ipv6, Limitation::NoIpv6),
+        (
+            report.runtime != RuntimeEnvironment::Native,
+            Limitation::Virtualized,
+        ),
+        (
+            !report.features.iter().any(|f| f == "snmp"),
+            Limitation::NoSnmp,
+        ),
+    ]
+    .into_iter()
+    .filter(|(missing, _)| *missing)
+    .map(|(_, limitation)| limitation)
+    .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::probes::{with_transport, MockTransport};
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_environment_report_lists_limitations() {
+        let ips = |ips: &[&str]| ips.iter().map(|ip| ip.parse().unwrap()).collect();
+        let transport = MockTransport::new()
+            .with_interface("lo", ips(&["127.0.0.1", "::1"]))
+            .with_interface("eth0", ips(&["192.168.1.20", "fe80::1"]));
+        let report = with_transport(Arc::new(transport), async { environment_report() }).await;
+        assert!(report.has_ipv4);
+        assert!(!report.has_ipv6);
+        assert!(report.limitations.contains(&Limitation::NoIpv6));
+
+        let unprivileged = EnvironmentReport {
+            capabilities: ProbeCapabilities {
+                icmp_echo: false,
+                icmp_raw: false,
+                udp_traceroute: true,
+                arp_table: true,
+            },
+            has_ipv6: true,
+            features: vec!["snmp".into()],
+            os: "linux".into(),
+            runtime: RuntimeEnvironment::Native,
+            ..report
+        };
+        assert_eq!(
+            limitations(&unprivileged),
+            vec![Limitation::NoIcmpSockets, Limitation::UdpTracerouteOnly]
+        );
+    }
+}
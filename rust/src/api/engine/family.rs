@@ -0,0 +1,151 @@
+//! IPv4 vs IPv6 performance comparison.
+//!
+//! Broken or slow IPv6 is a classic cause of "the internet feels slow":
+//! applications prefer IPv6 and only fall back after a delay. Probing each
+//! dual-stack target over both families, with everything else equal, shows
+//! which family actually performs better on this network.
+
+use super::rate_limit::{estimated_probe_bytes, RateLimiter};
+use crate::api::{
+    constants::LibConstants,
+    models::{AddressFamily, FamilyComparison, FamilyStats, NetworkConfiguration, NetworkTarget},
+    probes::{self, target::NativeProbe},
+};
+use futures::future::join_all;
+use std::net::{IpAddr, SocketAddr};
+
+/// Probes every dual-stack target in `config` over both families, once per
+/// jitter sample. Returns `None` if no target resolves to both families.
+pub(crate) async fn compare_address_families(
+    config: &NetworkConfiguration,
+    limiter: &RateLimiter,
+) -> Option<FamilyComparison> {
+    let resolved = join_all(config.targets.iter().map(resolve_both)).await;
+    let dual_stack: Vec<(&NetworkTarget, SocketAddr, SocketAddr)> = config
+        .targets
+        .iter()
+        .zip(resolved)
+        .filter_map(|(target, addrs)| addrs.map(|(v4, v6)| (target, v4, v6)))
+        .collect();
+    if dual_stack.is_empty() {
+        return None;
+    }
+
+    let rounds = std::cmp::max(1, config.resilience.num_jitter_samples);
+    let mut v4 = Vec::new();
+    let mut v6 = Vec::new();
+    for _ in 0..rounds {
+        let probes = dual_stack
+            .iter()
+            .map(|&(target, v4_addr, v6_addr)| async move {
+                limiter
+                    .acquire(2 * estimated_probe_bytes(target.protocol))
+                    .await;
+                let probe = NativeProbe {};
+                let (v4_report, v6_report) = tokio::join!(
+                    probe.check_resolved(target, Some(v4_addr)),
+                    probe.check_resolved(target, Some(v6_addr)),
+                );
+                (
+                    v4_report.success.then_some(v4_report.latency_ms),
+                    v6_report.success.then_some(v6_report.latency_ms),
+                )
+            });
+        for (a, b) in join_all(probes).await {
+            v4.push(a);
+            v6.push(b);
+        }
+    }
+
+    let ipv4 = family_stats(AddressFamily::V4, &v4);
+    let ipv6 = family_stats(AddressFamily::V6, &v6);
+    Some(FamilyComparison {
+        targets: dual_stack.iter().map(|(t, _, _)| t.label.clone()).collect(),
+        preferred_family: preferred_family(&ipv4, &ipv6),
+        ipv4,
+        ipv6,
+    })
+}
+
+/// The first IPv4 and IPv6 address of `target`, if it has both.
+async fn resolve_both(target: &NetworkTarget) -> Option<(SocketAddr, SocketAddr)> {
+    if target.host.parse::<IpAddr>().is_ok() {
+        return None;
+    }
+    let addrs = probes::resolver::resolve_host(&target.host, target.port)
+        .await
+        .ok()?;
+    let v4 = addrs.iter().find(|a| a.is_ipv4())?;
+    let v6 = addrs.iter().find(|a| a.is_ipv6())?;
+    Some((*v4, *v6))
+}
+
+/// Summarizes one family's probes; `None` entries failed.
+fn family_stats(family: AddressFamily, results: &[Option<u64>]) -> FamilyStats {
+    let mut latencies: Vec<u64> = results.iter().flatten().copied().collect();
+    latencies.sort_unstable();
+    let probes = results.len() as u32;
+    FamilyStats {
+        family,
+        probes,
+        latency_ms: latencies.get(latencies.len() / 2).copied(),
+        packet_loss_percent: if probes == 0 {
+            0.0
+        } else {
+            (probes as usize - latencies.len()) as f32 / probes as f32 * 100.0
+        },
+    }
+}
+
+fn preferred_family(ipv4: &FamilyStats, ipv6: &FamilyStats) -> Option<AddressFamily> {
+    match (ipv4.latency_ms, ipv6.latency_ms) {
+        (None, None) => return None,
+        (Some(_), None) => return Some(AddressFamily::V4),
+        (None, Some(_)) => return Some(AddressFamily::V6),
+        _ => {}
+    }
+
+    let loss_difference = ipv6.packet_loss_percent - ipv4.packet_loss_percent;
+    if loss_difference.abs() > LibConstants::FAMILY_LOSS_MARGIN_PERCENT {
+        return Some(if loss_difference > 0.0 {
+            AddressFamily::V4
+        } else {
+            AddressFamily::V6
+        });
+    }
+
+    let (v4_ms, v6_ms) = (ipv4.latency_ms?, ipv6.latency_ms?);
+    if v6_ms > v4_ms + LibConstants::FAMILY_LATENCY_MARGIN_MS {
+        Some(AddressFamily::V4)
+    } else {
+        Some(AddressFamily::V6)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_preferred_family() {
+        let v4 = family_stats(AddressFamily::V4, &[Some(20), Some(22), Some(21), Some(25)]);
+        assert_eq!(v4.latency_ms, Some(22));
+        assert_eq!(v4.packet_loss_percent, 0.0);
+
+        // Comparable: IPv6 wins the tie.
+        let v6 = family_stats(AddressFamily::V6, &[Some(24), Some(26), Some(23), Some(25)]);
+        assert_eq!(preferred_family(&v4, &v6), Some(AddressFamily::V6));
+
+        // Slow IPv6 path.
+        let v6 = family_stats(AddressFamily::V6, &[Some(60), Some(65), Some(58), Some(61)]);
+        assert_eq!(preferred_family(&v4, &v6), Some(AddressFamily::V4));
+
+        // Lossy IPv6 path, even if faster.
+        let v6 = family_stats(AddressFamily::V6, &[Some(10), None, Some(11), Some(10)]);
+        assert_eq!(v6.packet_loss_percent, 25.0);
+        assert_eq!(preferred_family(&v4, &v6), Some(AddressFamily::V4));
+
+        let dead = family_stats(AddressFamily::V6, &[None, None]);
+        assert_eq!(preferred_family(&v4, &dead), Some(AddressFamily::V4));
+    }
+}
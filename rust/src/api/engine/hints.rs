@@ -0,0 +1,98 @@
+//! Host-supplied platform facts that override heuristic interface detection.
+
+use crate::api::models::{Confidence, Evidence, EvidenceSource, NetworkReport, PlatformHints};
+use flutter_rust_bridge::frb;
+use std::sync::{OnceLock, RwLock};
+
+fn store() -> &'static RwLock<Option<PlatformHints>> {
+    static HINTS: OnceLock<RwLock<Option<PlatformHints>>> = OnceLock::new();
+    HINTS.get_or_init(|| RwLock::new(None))
+}
+
+/// Installs the hints applied to every subsequent report.
+///
+/// Call this whenever the platform reports a network change (e.g. from
+/// `ConnectivityManager.NetworkCallback` or `NWPathMonitor`). Replaces any
+/// previously installed hints.
+#[frb(sync)]
+pub fn set_platform_hints(hints: PlatformHints) {
+    *store().write().unwrap_or_else(|e| e.into_inner()) = Some(hints);
+}
+
+/// Removes the installed hints, reverting to the engine's own detection.
+#[frb(sync)]
+pub fn clear_platform_hints() {
+    *store().write().unwrap_or_else(|e| e.into_inner()) = None;
+}
+
+/// Installs the hints `update` makes of the installed ones (or of empty
+/// hints if none are installed).
+pub(crate) fn update_platform_hints(update: impl FnOnce(&mut PlatformHints)) {
+    let mut store = store().write().unwrap_or_else(|e| e.into_inner());
+    update(store.get_or_insert_with(PlatformHints::default));
+}
+
+/// The currently installed hints, if any.
+#[frb(sync)]
+pub fn platform_hints() -> Option<PlatformHints> {
+    store().read().unwrap_or_else(|e| e.into_inner()).clone()
+}
+
+/// Overwrites the detected interface facts of `report` with every hint that is set.
+pub(crate) fn apply_platform_hints(hints: &PlatformHints, report: &mut NetworkReport) {
+    if let Some(connection_type) = hints.connection_type {
+        report.connection_type = connection_type;
+    }
+    if let Some(is_vpn) = hints.is_vpn {
+        report.security_flags_result.is_vpn_detected = is_vpn;
+        report.security_flags_result.vpn_evidence =
+            Evidence::new(Confidence::High, EvidenceSource::PlatformHint);
+    }
+    if let Some(name) = &hints.interface_name {
+        report.security_flags_result.interface_name = name.clone();
+    }
+    if let Some(is_metered) = hints.is_metered {
+        report.is_expensive = is_metered;
+    }
+    if let Some(is_constrained) = hints.is_constrained {
+        report.is_constrained = is_constrained;
+    }
+    if hints.ssid.is_some() {
+        report.ssid = hints.ssid.clone();
+    }
+    if hints.bssid.is_some() {
+        report.bssid = hints.bssid.clone();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::ConnectionType;
+
+    #[test]
+    fn test_apply_platform_hints_overrides_only_set_fields() {
+        let mut report = NetworkReport::fixture(true);
+        report.security_flags_result.interface_name = "wlan0".into();
+        let detected_interface = report.security_flags_result.interface_name.clone();
+
+        apply_platform_hints(
+            &PlatformHints {
+                connection_type: Some(ConnectionType::Cellular),
+                is_metered: Some(true),
+                ssid: Some("Office".into()),
+                ..PlatformHints::default()
+            },
+            &mut report,
+        );
+
+        assert_eq!(report.connection_type, ConnectionType::Cellular);
+        assert!(report.is_expensive);
+        assert!(!report.is_constrained);
+        assert_eq!(report.ssid.as_deref(), Some("Office"));
+        assert_eq!(
+            report.security_flags_result.interface_name,
+            detected_interface
+        );
+    }
+}
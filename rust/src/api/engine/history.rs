@@ -0,0 +1,195 @@
+//! Tiered report history with automatic downsampling.
+//!
+//! A monitor that runs for months cannot keep every report. [HistoryStore]
+//! keeps three tiers:
+//!
+//! 1. Every report of the last hour (bounded by a report count as well).
+//! 2. One [HistoryAggregate] per minute for the last day.
+//! 3. One [HistoryAggregate] per hour beyond that, up to
+//!    [MAX_HOURLY_AGGREGATES](LibConstants::MAX_HOURLY_AGGREGATES).
+//!
+//! Reports move down a tier as they age, so the store's size only depends on
+//! the check interval, never on the uptime.
+
+use crate::api::{
+    analysis::time_of_day::is_degraded,
+    constants::LibConstants,
+    models::{HistoryAggregate, NetworkReport},
+};
+use std::collections::VecDeque;
+
+const MINUTE_MS: u64 = 60_000;
+const HOUR_MS: u64 = 3_600_000;
+
+/// The report history of one engine.
+#[derive(Debug)]
+pub(crate) struct HistoryStore {
+    capacity: usize,
+    reports: VecDeque<NetworkReport>,
+    minutes: VecDeque<Bucket>,
+    hours: VecDeque<Bucket>,
+}
+
+impl HistoryStore {
+    /// Creates a store keeping at most `capacity` full reports.
+    /// A capacity of 0 disables the history entirely.
+    pub(crate) fn new(capacity: usize) -> Self {
+        Self {
+            capacity,
+            reports: VecDeque::new(),
+            minutes: VecDeque::new(),
+            hours: VecDeque::new(),
+        }
+    }
+
+    /// Appends a report and downsamples whatever aged out of its tier.
+    pub(crate) fn push(&mut self, report: &NetworkReport) {
+        if self.capacity == 0 {
+            return;
+        }
+        let now_monotonic = report.monotonic_ms;
+        let now_ms = report.timestamp_ms;
+        self.reports.push_back(report.clone());
+
+        while let Some(oldest) = self.reports.front() {
+            let expired = now_monotonic.saturating_sub(oldest.monotonic_ms)
+                > LibConstants::HISTORY_REPORT_RETENTION_MS;
+            if !expired && self.reports.len() <= self.capacity {
+                break;
+            }
+            let oldest = self.reports.pop_front().expect("front exists");
+            fold(&mut self.minutes, Bucket::from_report(&oldest), MINUTE_MS);
+        }
+
+        let minute_cutoff = now_ms.saturating_sub(LibConstants::HISTORY_MINUTE_RETENTION_MS);
+        while self
+            .minutes
+            .front()
+            .is_some_and(|m| m.summary.start_ms + MINUTE_MS <= minute_cutoff)
+        {
+            let minute = self.minutes.pop_front().expect("front exists");
+            fold(&mut self.hours, minute, HOUR_MS);
+        }
+
+        while self.hours.len() > LibConstants::MAX_HOURLY_AGGREGATES {
+            self.hours.pop_front();
+        }
+    }
+
+    /// The retained full reports, oldest first.
+    pub(crate) fn reports(&self) -> impl Iterator<Item = &NetworkReport> {
+        self.reports.iter()
+    }
+
+    /// Hourly then per-minute aggregates of everything older than the full
+    /// reports, oldest first.
+    pub(crate) fn aggregates(&self) -> Vec<HistoryAggregate> {
+        self.hours
+            .iter()
+            .chain(&self.minutes)
+            .map(|b| b.summary.clone())
+            .collect()
+    }
+
+    pub(crate) fn clear(&mut self) {
+        self.reports.clear();
+        self.minutes.clear();
+        self.hours.clear();
+    }
+}
+
+/// An aggregate plus the exact sums its averages are derived from, so that
+/// repeated merging does not accumulate rounding errors.
+#[derive(Debug, Clone)]
+struct Bucket {
+    summary: HistoryAggregate,
+    latency_sum_ms: u64,
+    loss_sum_percent: f64,
+}
+
+impl Bucket {
+    /// A single-report bucket; its resolution is set by [fold].
+    fn from_report(report: &NetworkReport) -> Self {
+        let stats = &report.status.latency_stats;
+        let latency = report.status.is_connected.then_some(stats.latency_ms);
+        Self {
+            summary: HistoryAggregate {
+                start_ms: report.timestamp_ms,
+                resolution_ms: 0,
+                reports: 1,
+                connected_reports: u32::from(report.status.is_connected),
+                degraded_reports: u32::from(is_degraded(report)),
+                avg_latency_ms: latency,
+                max_latency_ms: latency,
+                avg_packet_loss_percent: stats.packet_loss_percent,
+                min_stability_score: stats.stability_score,
+            },
+            latency_sum_ms: latency.unwrap_or(0),
+            loss_sum_percent: f64::from(stats.packet_loss_percent),
+        }
+    }
+
+    fn merge(&mut self, other: &Bucket) {
+        let (into, from) = (&mut self.summary, &other.summary);
+        self.latency_sum_ms += other.latency_sum_ms;
+        self.loss_sum_percent += other.loss_sum_percent;
+        into.reports += from.reports;
+        into.connected_reports += from.connected_reports;
+        into.degraded_reports += from.degraded_reports;
+        into.max_latency_ms = into.max_latency_ms.max(from.max_latency_ms);
+        into.min_stability_score = into.min_stability_score.min(from.min_stability_score);
+        into.avg_latency_ms = (into.connected_reports > 0)
+            .then(|| self.latency_sum_ms / u64::from(into.connected_reports));
+        into.avg_packet_loss_percent = (self.loss_sum_percent / f64::from(into.reports)) as f32;
+    }
+}
+
+/// Aligns `item` to `resolution_ms` and merges it into the newest bucket of
+/// `tier` if they cover the same period.
+fn fold(tier: &mut VecDeque<Bucket>, mut item: Bucket, resolution_ms: u64) {
+    item.summary.start_ms -= item.summary.start_ms % resolution_ms;
+    item.summary.resolution_ms = resolution_ms;
+    match tier.back_mut() {
+        Some(last) if last.summary.start_ms == item.summary.start_ms => last.merge(&item),
+        _ => tier.push_back(item),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_history_downsamples_by_age() {
+        let mut store = HistoryStore::new(10_000);
+        let start_ms = 1_704_067_200_000u64;
+        // Two days of reports, one every 30 seconds.
+        let step_ms = 30_000;
+        let count = 2 * 24 * 120;
+        for i in 0..count {
+            let mut report = NetworkReport::fixture(true);
+            report.timestamp_ms = start_ms + i * step_ms;
+            report.monotonic_ms = i * step_ms;
+            report.status.latency_stats.latency_ms = if i % 2 == 0 { 20 } else { 40 };
+            store.push(&report);
+        }
+
+        // The last hour is kept in full.
+        assert_eq!(store.reports().count(), 121);
+
+        let aggregates = store.aggregates();
+        let (hours, minutes): (Vec<_>, Vec<_>) =
+            aggregates.iter().partition(|a| a.resolution_ms == HOUR_MS);
+        assert_eq!(hours.len(), 24);
+        // The minute straddling the one-day cutoff stays at minute resolution.
+        assert_eq!(minutes.len(), 23 * 60 + 1);
+        assert!(aggregates.windows(2).all(|w| w[0].start_ms < w[1].start_ms));
+
+        assert_eq!(hours[0].reports, 120);
+        assert_eq!(hours[0].avg_latency_ms, Some(30));
+        assert_eq!(hours[0].max_latency_ms, Some(40));
+
+        let total: u32 = aggregates.iter().map(|a| a.reports).sum();
+        assert_eq!(total as usize + store.reports().count(), count as usize);
+    }
+}
@@ -0,0 +1,161 @@
+//! Tracks the devices on the local network across scans.
+//!
+//! A [DeviceInventory] remembers the result of the previous
+//! [scan_local_network](crate::api::probes::scan_local_network) and turns
+//! every new scan into [DeviceEvent]s, e.g. to alert the user when an unknown
+//! device joins their Wi-Fi:
+//!
+//! ```ignore
+//! let mut inventory = DeviceInventory::new();
+//! loop {
+//!     let devices = scan_local_network(config.clone()).await;
+//!     for event in inventory.update(devices) {
+//!         if let DeviceEvent::Joined(device) = event {
+//!             notify(&device);
+//!         }
+//!     }
+//! }
+//! ```
+
+use crate::api::models::{DeviceEvent, LocalDevice};
+use flutter_rust_bridge::frb;
+
+/// The devices seen in the most recent scan.
+#[frb(opaque)]
+#[derive(Debug, Clone, Default)]
+pub struct DeviceInventory {
+    devices: Vec<LocalDevice>,
+}
+
+impl DeviceInventory {
+    /// Creates an empty inventory; the first update reports every device as joined.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Restores an inventory from previously persisted [devices](Self::devices).
+    pub fn from_devices(devices: Vec<LocalDevice>) -> Self {
+        Self { devices }
+    }
+
+    /// The devices seen in the most recent scan.
+    #[frb(ignore)]
+    pub fn devices(&self) -> &[LocalDevice] {
+        &self.devices
+    }
+
+    /// Replaces the stored scan with `scan` and returns what changed.
+    ///
+    /// Devices are matched by MAC address where both scans know it, and by IP
+    /// address where either does not. Events are ordered: IP changes, then joins, then
+    /// departures.
+    pub fn update(&mut self, scan: Vec<LocalDevice>) -> Vec<DeviceEvent> {
+        let events = diff_devices(&self.devices, &scan);
+        self.devices = scan;
+        events
+    }
+}
+
+/// Computes the events that turn `previous` into `current`.
+pub fn diff_devices(previous: &[LocalDevice], current: &[LocalDevice]) -> Vec<DeviceEvent> {
+    let mut unmatched: Vec<&LocalDevice> = previous.iter().collect();
+    let mut ip_changes = Vec::new();
+    let mut joined = Vec::new();
+
+    for device in current {
+        let by_mac = device.mac_address.as_ref().and_then(|mac| {
+            unmatched
+                .iter()
+                .position(|d| d.mac_address.as_ref() == Some(mac))
+        });
+        // Two known MACs that differ are two devices, even on a reused IP.
+        let index = by_mac.or_else(|| {
+            unmatched.iter().position(|d| {
+                d.ip_address == device.ip_address
+                    && (d.mac_address.is_none() || device.mac_address.is_none())
+            })
+        });
+
+        match index {
+            Some(i) => {
+                let old = unmatched.swap_remove(i);
+                if let (Some(mac), Some(_)) = (&device.mac_address, by_mac) {
+                    if old.ip_address != device.ip_address {
+                        ip_changes.push(DeviceEvent::IpChanged {
+                            mac_address: mac.clone(),
+                            old_ip: old.ip_address.clone(),
+                            new_ip: device.ip_address.clone(),
+                        });
+                    }
+                }
+            }
+            None => joined.push(DeviceEvent::Joined(device.clone())),
+        }
+    }
+
+    let left = unmatched.into_iter().map(|d| DeviceEvent::Left(d.clone()));
+    ip_changes.into_iter().chain(joined).chain(left).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn device(ip: &str, mac: Option<&str>) -> LocalDevice {
+        LocalDevice {
+            mac_address: mac.map(str::to_string),
+            ..LocalDevice::fixture(ip)
+        }
+    }
+
+    #[test]
+    fn test_inventory_reports_joins_departures_and_ip_changes() {
+        let mut inventory = DeviceInventory::new();
+        let first = vec![
+            device("10.0.0.1", Some("aa:aa:aa:aa:aa:01")),
+            device("10.0.0.2", Some("aa:aa:aa:aa:aa:02")),
+            device("10.0.0.3", None),
+        ];
+        assert_eq!(inventory.update(first).len(), 3);
+
+        let events = inventory.update(vec![
+            device("10.0.0.1", Some("aa:aa:aa:aa:aa:01")),
+            device("10.0.0.9", Some("aa:aa:aa:aa:aa:02")),
+            device("10.0.0.4", None),
+        ]);
+        assert_eq!(
+            events,
+            vec![
+                DeviceEvent::IpChanged {
+                    mac_address: "aa:aa:aa:aa:aa:02".into(),
+                    old_ip: "10.0.0.2".into(),
+                    new_ip: "10.0.0.9".into(),
+                },
+                DeviceEvent::Joined(device("10.0.0.4", None)),
+                DeviceEvent::Left(device("10.0.0.3", None)),
+            ]
+        );
+        assert_eq!(inventory.devices().len(), 3);
+
+        // A MAC resolved only in the later scan is still the same device.
+        let restored = DeviceInventory::from_devices(vec![device("10.0.0.4", None)]);
+        assert!(diff_devices(
+            restored.devices(),
+            &[device("10.0.0.4", Some("aa:aa:aa:aa:aa:04"))]
+        )
+        .is_empty());
+
+        // A new device that took over a reused DHCP address.
+        let events = diff_devices(
+            &[device("10.0.0.5", Some("aa:aa:aa:aa:aa:05"))],
+            &[device("10.0.0.5", Some("bb:bb:bb:bb:bb:05"))],
+        );
+        assert_eq!(
+            events,
+            vec![
+                DeviceEvent::Joined(device("10.0.0.5", Some("bb:bb:bb:bb:bb:05"))),
+                DeviceEvent::Left(device("10.0.0.5", Some("aa:aa:aa:aa:aa:05"))),
+            ]
+        );
+    }
+}
@@ -0,0 +1,92 @@
+//! Gateway-only ("LAN scope") checks.
+//!
+//! Offline-first and industrial apps often care whether the local network
+//! works even when the internet does not. [check_local_network] answers that
+//! by probing the default gateway and the configured
+//! [lan_targets](NetworkConfiguration::lan_targets), without touching any
+//! internet target.
+
+use super::clock;
+use crate::api::{
+    constants::LibConstants,
+    models::{LocalReachabilityReport, NetworkConfiguration, NetworkScope},
+    probes::{
+        self,
+        gateway::{default_gateway, probe_gateway},
+    },
+};
+use futures::future::join_all;
+
+/// Checks LAN reachability only: the default gateway plus every LAN target.
+///
+/// The scope is [NetworkScope::LocalOnly] if any of them answers and
+/// [NetworkScope::None] otherwise. Use [check_network](super::check_network)
+/// to find out whether the scope extends to [NetworkScope::Internet].
+pub async fn check_local_network(config: NetworkConfiguration) -> LocalReachabilityReport {
+    run_local_check(&config).await
+}
+
+pub(crate) async fn run_local_check(config: &NetworkConfiguration) -> LocalReachabilityReport {
+    let timestamp_ms = clock::wall_clock_ms();
+    let monotonic_ms = clock::monotonic_ms();
+    // The gateway comes from the OS routing table.
+    let gateway = if probes::transport::current().uses_system_routes() {
+        default_gateway()
+    } else {
+        None
+    };
+
+    let gateway_probe = async {
+        match gateway {
+            Some(ip) => Some(probe_gateway(ip, LibConstants::DEFAULT_GATEWAY_TIMEOUT_MS).await),
+            None => None,
+        }
+    };
+    let target_probes = join_all(config.lan_targets.iter().map(probes::check_target));
+    let (gateway_report, target_reports) = tokio::join!(gateway_probe, target_probes);
+
+    let reachable = gateway_report.as_ref().is_some_and(|r| r.success)
+        || target_reports.iter().any(|r| r.success);
+
+    LocalReachabilityReport {
+        timestamp_ms,
+        monotonic_ms,
+        scope: if reachable {
+            NetworkScope::LocalOnly
+        } else {
+            NetworkScope::None
+        },
+        gateway: gateway.map(|ip| ip.to_string()),
+        gateway_report,
+        target_reports,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::models::{NetworkTarget, TargetProtocol};
+
+    #[tokio::test]
+    async fn test_lan_target_yields_local_scope() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let _ = tokio::io::AsyncWriteExt::write_all(&mut socket, b"ok").await;
+        });
+
+        let config = NetworkConfiguration {
+            lan_targets: vec![NetworkTarget::builder("127.0.0.1", TargetProtocol::Tcp)
+                .label("plc")
+                .port(port)
+                .timeout_ms(1000)
+                .build()],
+            ..NetworkConfiguration::default()
+        };
+
+        let report = check_local_network(config).await;
+        assert_eq!(report.scope, NetworkScope::LocalOnly);
+        assert!(report.target_reports[0].success);
+    }
+}
@@ -1,69 +1,676 @@
 //! The core orchestration engine for network checks.
 
+pub mod circuit_breaker;
+pub mod clock;
+pub mod environment;
+pub(crate) mod family;
+pub mod hints;
+pub(crate) mod history;
+pub mod inventory;
+pub mod local;
+pub mod passive;
+pub mod pipeline;
+pub mod preflight;
+pub mod rate_limit;
+pub mod reachability;
+pub mod runtime;
 pub mod sampler;
+pub mod schedule;
 pub mod security;
+pub mod vpn;
 
 use crate::api::{
-    analysis::{compute_latency_stats, evaluate_network_quality},
-    models::{ConnectionQuality, NetworkConfiguration, NetworkReport, NetworkStatus},
-    probes::{self, detect_security_and_network_type},
+    analysis::{
+        apply_spike_detection, attribute_degradation, better_path,
+        compute_latency_stats_with_model, diagnose_layers, evaluate_network_quality,
+        quality_reasons, StabilityModel, WeightedStabilityModel,
+    },
+    constants::LibConstants,
+    models::{
+        ConfigError, ConnectionQuality, LatencyStats, NetworkConfiguration, NetworkError,
+        NetworkReport, NetworkScope, NetworkStatus, QualityReason, SimpleStatus,
+    },
+    probes::{self, detect_security_and_network_type_towards, route_destination_for},
 };
-use ::chrono::Utc;
 
-use sampler::collect_network_samples;
+pub use circuit_breaker::CircuitBreakerState;
+pub use environment::environment_report;
+pub use hints::{clear_platform_hints, set_platform_hints};
+pub use preflight::preflight;
+pub use reachability::ReachabilityEngine;
+pub use security::guard;
+
+use flutter_rust_bridge::frb;
+use std::{
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::Duration,
+};
+use tokio_util::sync::CancellationToken;
+
+use rate_limit::RateLimiter;
+use runtime::AbortOnDrop;
+use sampler::{overall_winner, run_samples, SampleRun};
 use security::perform_dns_security_check;
 
 /// The main entry point for running a comprehensive network check.
 ///
 /// This function orchestrates the entire check process:
-/// 1. Collects latency samples via [sampler::collect_network_samples].
-/// 2. Computes statistics via [analysis::compute_latency_stats].
-/// 3. Evaluates quality via [analysis::evaluate_network_quality].
-/// 4. Detects interface security and type.
-/// 5. Compiles a final [NetworkReport].
+/// 1. Collects latency samples in `sampler::run_samples`. If
+///    [NetworkConfiguration::attribute_degradation] is set, the default
+///    gateway is sampled in the same rounds.
+/// 2. Computes statistics via [compute_latency_stats_with_model] and flags
+///    latency spikes via [apply_spike_detection]. Checks run here are scored
+///    with the default [WeightedStabilityModel]; those of a
+///    [ReachabilityEngine] with [its own](ReachabilityEngine::with_stability_model).
+/// 3. Evaluates quality via [evaluate_network_quality].
+/// 4. Detects interface security and type, overridden by any
+///    [PlatformHints](crate::api::models::PlatformHints) the host supplied.
+/// 5. Checks for captive portals and DNS hijacking, and, if only the LAN is
+///    reachable, asks the configured ISP status endpoints about an outage.
+///    If [NetworkConfiguration::compare_address_families] is set, compares
+///    IPv4 and IPv6 towards the dual-stack targets; if
+///    [NetworkConfiguration::check_multicast] is set, checks LAN multicast; if
+///    [NetworkConfiguration::compare_paths] is set, compares the interfaces; if
+///    [NetworkConfiguration::attribute_degradation] is set, attributes the
+///    degradation from the gateway samples.
+/// 6. Builds a per-layer breakdown via [diagnose_layers].
+/// 7. Determines the [NetworkScope]; if no target answered, the gateway and
+///    LAN targets are probed via [local::check_local_network].
+/// 8. Compiles a final [NetworkReport].
+///
+/// All probe traffic of the check shares one [rate_limit::RateLimiter] built from
+/// [NetworkConfiguration::rate_limit]. To share one across checks, see
+/// [check_network_with_limiter].
+///
+/// For conditional execution of the heavier probes, see [pipeline::CheckPipeline].
+/// For caching, history and a circuit breaker, see [ReachabilityEngine].
+/// To abort a check early, see [check_network_cancellable].
 pub async fn check_network(config: NetworkConfiguration) -> NetworkReport {
-    let start_time = Utc::now().timestamp_millis() as u64;
+    check_network_cancellable(config, CancellationToken::new()).await
+}
+
+/// [check_network], stopping early once `cancel` is cancelled.
+///
+/// Samples that already completed still make up the statistics, and probes
+/// that already ran keep their results; everything else is skipped and
+/// [NetworkReport::cancelled] is set. Running out of
+/// [NetworkConfiguration::overall_timeout_ms] stops the check the same way,
+/// but sets [NetworkReport::truncated] instead.
+#[frb(ignore)]
+pub async fn check_network_cancellable(
+    config: NetworkConfiguration,
+    cancel: CancellationToken,
+) -> NetworkReport {
+    let limiter = RateLimiter::new(&config.rate_limit);
+    run_check(config, &limiter, cancel, &WeightedStabilityModel::default()).await
+}
+
+/// [check_network], drawing its probe traffic from `limiter` instead of a
+/// limiter of its own.
+///
+/// A fresh limiter starts with a full second of burst, so checks that run
+/// often are only throttled as a whole if they share one, as those of a
+/// [ReachabilityEngine] or a [NetworkMonitor](crate::api::monitor::NetworkMonitor) do.
+#[frb(ignore)]
+pub async fn check_network_with_limiter(
+    config: NetworkConfiguration,
+    limiter: Arc<RateLimiter>,
+) -> NetworkReport {
+    run_check(
+        config,
+        &limiter,
+        CancellationToken::new(),
+        &WeightedStabilityModel::default(),
+    )
+    .await
+}
+
+/// [check_network_cancellable] with the rate limiter passed in, scoring
+/// stability with `model`.
+pub(crate) async fn run_check(
+    config: NetworkConfiguration,
+    limiter: &RateLimiter,
+    cancel: CancellationToken,
+    model: &dyn StabilityModel,
+) -> NetworkReport {
+    // Cancelled by the caller or by the deadline, whichever comes first.
+    let stop = cancel.child_token();
+    let deadline = (config.overall_timeout_ms > 0).then(|| {
+        let stop = stop.clone();
+        let budget = Duration::from_millis(config.overall_timeout_ms);
+        AbortOnDrop(tokio::spawn(async move {
+            tokio::time::sleep(budget).await;
+            stop.cancel();
+        }))
+    });
 
-    let (all_sample_latencies, final_target_reports) = collect_network_samples(&config).await;
+    let gateway = config
+        .attribute_degradation
+        .then(attribution_gateway)
+        .flatten();
+    let (mut report, gateway_stats) = run_base_check(&config, limiter, &stop, gateway, model).await;
+    let no_route = report.status.reasons.contains(&QualityReason::NoRoute);
+    if !report.cancelled && !no_route {
+        report.cancelled = !run_optional_probes(&config, &mut report, limiter, &stop).await;
+        if config.attribute_degradation {
+            apply_attribution(&mut report, gateway_stats.as_ref());
+        }
+    }
+    drop(deadline);
+    if report.cancelled && !cancel.is_cancelled() {
+        report.cancelled = false;
+        report.truncated = true;
+    }
+    refresh_layers(&config, &mut report);
+    finish_timing(&mut report);
+
+    report
+}
+
+/// Runs the probes [check_network] adds to the base check. Returns false if
+/// `cancel` cut them short.
+async fn run_optional_probes(
+    config: &NetworkConfiguration,
+    report: &mut NetworkReport,
+    limiter: &RateLimiter,
+    cancel: &CancellationToken,
+) -> bool {
+    let probes = async {
+        apply_captive_portal_check(config, report, limiter).await;
+        apply_isp_status_check(config, report, limiter).await;
+        if config.compare_address_families {
+            apply_family_comparison(config, report, limiter).await;
+        }
+        if config.check_multicast {
+            apply_multicast_check(report, limiter).await;
+        }
+        if config.compare_paths {
+            apply_path_comparison(config, report, limiter).await;
+        }
+        if config.security.detect_dns_hijack {
+            limiter
+                .acquire(LibConstants::ESTIMATED_DNS_CHECK_BYTES)
+                .await;
+        }
+        perform_dns_security_check(config, &mut report.security_flags_result).await;
+    };
+    tokio::select! {
+        _ = probes => true,
+        _ = cancel.cancelled() => false,
+    }
+}
+
+/// [check_network], but fails with [NetworkError::InvalidConfiguration]
+/// instead of checking if `config` does not pass
+/// [validate](NetworkConfiguration::validate).
+pub async fn try_check_network(
+    config: NetworkConfiguration,
+) -> Result<NetworkReport, NetworkError> {
+    config.validate().map_err(ConfigError::Invalid)?;
+    Ok(check_network(config).await)
+}
+
+/// Runs a check with the default configuration and sums it up as a traffic
+/// light. See [NetworkReport::simple_status] for the mapping; to use another
+/// configuration, call that on the result of [check_network].
+pub async fn simple_status() -> SimpleStatus {
+    check_network(NetworkConfiguration::default())
+        .await
+        .simple_status()
+}
+
+/// True if the OS has a route to the internet, or to a target or LAN target
+/// given as an IP literal.
+fn has_any_route(config: &NetworkConfiguration) -> bool {
+    let mut destinations = probes::interface::internet_route_destinations().to_vec();
+    destinations.extend(
+        config
+            .targets
+            .iter()
+            .chain(&config.lan_targets)
+            .filter_map(|t| t.host.parse::<IpAddr>().ok()),
+    );
+    probes::interface::has_route_to(&destinations)
+}
+
+/// Runs the mandatory part of a check: sampling, statistics, quality and
+/// interface detection. The returned report is complete but has not been
+/// through any of the optional probes.
+///
+/// With `gateway` set, it is sampled in every round alongside the targets,
+/// and the statistics of its answers are returned for [apply_attribution].
+/// Both are scored with `model`.
+pub(crate) async fn run_base_check(
+    config: &NetworkConfiguration,
+    limiter: &RateLimiter,
+    cancel: &CancellationToken,
+    gateway: Option<IpAddr>,
+    model: &dyn StabilityModel,
+) -> (NetworkReport, Option<LatencyStats>) {
+    let start_time = clock::wall_clock_ms();
+    let start_monotonic = clock::monotonic_ms();
+
+    // A transport with routes of its own decides reachability on its own.
+    let no_route = config.fast_fail_offline
+        && probes::transport::current().uses_system_routes()
+        && !has_any_route(config);
+    let SampleRun {
+        latencies: all_sample_latencies,
+        final_reports: final_target_reports,
+        winners: winners_per_sample,
+        records: raw_samples,
+        cancelled,
+        gateway_latencies,
+    } = if no_route {
+        SampleRun::default()
+    } else {
+        run_samples(config, limiter, cancel, gateway).await
+    };
 
     let is_connected = !all_sample_latencies.is_empty();
-    let num_samples = std::cmp::max(1, config.resilience.num_jitter_samples);
+    // One entry per completed sample, which is fewer than configured after a
+    // cancellation.
+    let num_samples = std::cmp::max(1, winners_per_sample.len() as u8);
 
-    let latency_stats = compute_latency_stats(
+    let mut latency_stats = compute_latency_stats_with_model(
         &all_sample_latencies,
         num_samples,
         &config.quality_threshold,
+        model,
     );
+    apply_spike_detection(
+        &mut latency_stats,
+        &all_sample_latencies,
+        &config.resilience,
+    );
+    // A gateway that never answers is more likely filtering probes than
+    // losing every packet.
+    let gateway_stats = (!gateway_latencies.is_empty()).then(|| {
+        compute_latency_stats_with_model(
+            &gateway_latencies,
+            num_samples,
+            &config.quality_threshold,
+            model,
+        )
+    });
 
-    let mut quality = evaluate_network_quality(is_connected, &latency_stats, &config);
-
-    // If we're ostensibly connected, check for a captive portal to be sure.
-    if is_connected && quality != ConnectionQuality::Offline {
-        let cp_status = probes::check_for_captive_portal(1000).await;
-        if cp_status.is_captive_portal {
-            quality = ConnectionQuality::CaptivePortal;
-        }
-    }
+    let quality = evaluate_network_quality(is_connected, &latency_stats, config);
+    let reasons = if no_route {
+        vec![QualityReason::NoRoute]
+    } else {
+        quality_reasons(is_connected, &latency_stats, config)
+    };
 
-    let (mut security_flags_res, connection_type) = detect_security_and_network_type();
-    perform_dns_security_check(&config, &mut security_flags_res).await;
+    let (security_flags_res, connection_type) = match &config.active_interface {
+        Some(name) => probes::detect_security_and_network_type_of(name),
+        None => detect_security_and_network_type_towards(route_destination_for(&config.targets)),
+    };
 
-    let winner_target = if let Some(r) = final_target_reports.iter().find(|r| r.success) {
-        r.label.clone()
+    let scope = if is_connected {
+        NetworkScope::Internet
+    } else if cancelled || no_route {
+        NetworkScope::None
     } else {
-        String::new()
+        local::run_local_check(config).await.scope
     };
 
-    NetworkReport {
+    let winner_target = overall_winner(&winners_per_sample).unwrap_or_default();
+
+    let layers = diagnose_layers(
+        &config.targets,
+        &final_target_reports,
+        &security_flags_res,
+        quality,
+    );
+
+    let mut report = NetworkReport {
         timestamp_ms: start_time,
+        monotonic_ms: start_monotonic,
+        duration_ms: 0,
         status: NetworkStatus {
             is_connected,
             quality,
             latency_stats,
             winner_target,
+            winners_per_sample,
+            reasons,
         },
         connection_type,
         security_flags_result: security_flags_res,
+        captive_portal: None,
         target_reports: final_target_reports,
+        layers,
+        is_expensive: false,
+        is_constrained: false,
+        ssid: None,
+        bssid: None,
+        scope,
+        environment: probes::environment::detect_runtime_environment(),
+        raw_samples,
+        likely_isp_outage: None,
+        snmp_devices: Vec::new(),
+        family_comparison: None,
+        multicast_ok: None,
+        walled_garden: None,
+        roam: None,
+        anomalies: Vec::new(),
+        better_path: None,
+        attribution: None,
+        traceroute: None,
+        cancelled,
+        truncated: false,
+        strategy: config.resilience.strategy,
+    };
+
+    if let Some(mut platform_hints) = hints::platform_hints() {
+        if config.active_interface.is_some() {
+            platform_hints.interface_name = None;
+            platform_hints.connection_type = None;
+        }
+        hints::apply_platform_hints(&platform_hints, &mut report);
+        refresh_layers(config, &mut report);
+    }
+
+    finish_timing(&mut report);
+    (report, gateway_stats)
+}
+
+/// If we're ostensibly connected, checks for a captive portal to be sure and
+/// downgrades the quality to [ConnectionQuality::CaptivePortal] if one is found.
+/// A found portal's walled garden is then probed into
+/// [NetworkReport::walled_garden].
+pub(crate) async fn apply_captive_portal_check(
+    config: &NetworkConfiguration,
+    report: &mut NetworkReport,
+    limiter: &RateLimiter,
+) {
+    if !report.status.is_connected
+        || report.status.quality == ConnectionQuality::Offline
+        || !probes::transport::current().supports_http()
+    {
+        return;
+    }
+
+    limiter
+        .acquire(LibConstants::ESTIMATED_CAPTIVE_PORTAL_PROBE_BYTES)
+        .await;
+    let endpoints = &config.endpoints;
+    let cp_status = probes::check_for_captive_portal_at(&endpoints.captive_portal_url, 1000).await;
+    if cp_status.is_captive_portal {
+        report.status.quality = ConnectionQuality::CaptivePortal;
+        report
+            .status
+            .reasons
+            .insert(0, QualityReason::CaptivePortal);
+
+        limiter
+            .acquire(LibConstants::ESTIMATED_WALLED_GARDEN_PROBE_BYTES)
+            .await;
+        report.walled_garden = Some(probes::probe_walled_garden_with(endpoints, 0).await);
+    }
+    report.captive_portal = Some(cp_status);
+}
+
+/// If the gateway answers but no internet target does, asks the configured
+/// status endpoints whether the ISP has an outage and sets
+/// [NetworkReport::likely_isp_outage].
+pub(crate) async fn apply_isp_status_check(
+    config: &NetworkConfiguration,
+    report: &mut NetworkReport,
+    limiter: &RateLimiter,
+) {
+    if report.scope != NetworkScope::LocalOnly || config.isp_status_urls.is_empty() {
+        return;
+    }
+
+    limiter
+        .acquire(
+            LibConstants::ESTIMATED_ISP_STATUS_PROBE_BYTES * config.isp_status_urls.len() as u64,
+        )
+        .await;
+    report.likely_isp_outage = probes::check_isp_status(
+        &config.isp_status_urls,
+        LibConstants::DEFAULT_HTTP_TIMEOUT_MS,
+    )
+    .await;
+}
+
+/// If connected, compares IPv4 and IPv6 towards the dual-stack targets and
+/// sets [NetworkReport::family_comparison].
+pub(crate) async fn apply_family_comparison(
+    config: &NetworkConfiguration,
+    report: &mut NetworkReport,
+    limiter: &RateLimiter,
+) {
+    if !report.status.is_connected {
+        return;
+    }
+    report.family_comparison = family::compare_address_families(config, limiter).await;
+}
+
+/// Checks LAN multicast via the mDNS group and sets
+/// [NetworkReport::multicast_ok].
+pub(crate) async fn apply_multicast_check(report: &mut NetworkReport, limiter: &RateLimiter) {
+    limiter
+        .acquire(LibConstants::ESTIMATED_MULTICAST_CHECK_BYTES)
+        .await;
+    report.multicast_ok = probes::check_multicast(0).await.multicast_ok;
+}
+
+/// Measures every candidate interface and sets [NetworkReport::better_path]
+/// if one beats the active interface.
+pub(crate) async fn apply_path_comparison(
+    config: &NetworkConfiguration,
+    report: &mut NetworkReport,
+    limiter: &RateLimiter,
+) {
+    let candidates = probes::paths::candidate_interfaces();
+    if candidates.len() < 2 {
+        return;
+    }
+    let servers: Vec<SocketAddr> = config
+        .endpoints
+        .tcp_reference_servers
+        .iter()
+        .filter_map(|s| s.trim().parse().ok())
+        .collect();
+    limiter
+        .acquire(
+            LibConstants::ESTIMATED_PATH_COMPARISON_BYTES
+                * (candidates.len() * servers.len()) as u64,
+        )
+        .await;
+    let paths = probes::paths::measure_candidates(candidates, &servers, 0).await;
+    report.better_path = better_path(
+        &report.security_flags_result.interface_name,
+        &paths,
+        report.timestamp_ms,
+    );
+}
+
+/// The gateway to sample for [apply_attribution]: the default one from the
+/// OS routing table, so none under a transport with routes of its own.
+pub(crate) fn attribution_gateway() -> Option<IpAddr> {
+    if !probes::transport::current().uses_system_routes() {
+        return None;
+    }
+    probes::gateway::default_gateway()
+}
+
+/// If connected, sets [NetworkReport::attribution] from the statistics of the
+/// gateway samples [run_base_check] took and the end-to-end ones.
+pub(crate) fn apply_attribution(report: &mut NetworkReport, gateway_stats: Option<&LatencyStats>) {
+    let Some(gateway_stats) = gateway_stats else {
+        return;
+    };
+    if report.status.is_connected {
+        report.attribution = Some(attribute_degradation(
+            gateway_stats,
+            &report.status.latency_stats,
+        ));
+    }
+}
+
+/// Recomputes [NetworkReport::layers] after optional probes modified the report.
+pub(crate) fn refresh_layers(config: &NetworkConfiguration, report: &mut NetworkReport) {
+    report.layers = diagnose_layers(
+        &config.targets,
+        &report.target_reports,
+        &report.security_flags_result,
+        report.status.quality,
+    );
+}
+
+/// Sets [NetworkReport::duration_ms] from the report's monotonic start time.
+pub(crate) fn finish_timing(report: &mut NetworkReport) {
+    report.duration_ms = clock::monotonic_ms().saturating_sub(report.monotonic_ms);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{
+        models::{ConnectionType, NetworkTarget, ServiceReachability, TargetProtocol},
+        probes::{with_transport, MockTransport},
+    };
+    use std::{net::SocketAddr, sync::Arc};
+
+    #[tokio::test]
+    async fn test_overall_timeout_truncates_check() {
+        // Accepts the connection but never answers the probe.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (_socket, _) = listener.accept().await.unwrap();
+            tokio::time::sleep(Duration::from_secs(10)).await;
+        });
+
+        let config = NetworkConfiguration::builder()
+            .add_target(
+                NetworkTarget::builder("127.0.0.1", TargetProtocol::Tcp)
+                    .port(port)
+                    .timeout_ms(5000)
+                    .build(),
+            )
+            .overall_timeout_ms(200)
+            .build()
+            .unwrap();
+        let report = tokio::time::timeout(Duration::from_secs(2), check_network(config))
+            .await
+            .unwrap();
+        assert!(report.truncated);
+        assert!(!report.cancelled);
+        assert!(!report.status.is_connected);
+    }
+
+    #[tokio::test]
+    async fn test_captive_portal_check_uses_configured_endpoints() {
+        use crate::api::{models::EndpointConfig, testsupport::TestServer};
+
+        let target = TestServer::tcp_echo().start().await.unwrap();
+        let portal = TestServer::http()
+            .redirect("/", "/login")
+            .respond("/login", 200, "<title>Guest Wi-Fi</title>")
+            .start()
+            .await
+            .unwrap();
+        let config = NetworkConfiguration::builder()
+            .add_target(target.target("local"))
+            .endpoints(EndpointConfig {
+                captive_portal_url: portal.url("/"),
+                walled_garden_url: portal.url("/login"),
+                walled_garden_port_host: "127.0.0.1".into(),
+                public_dns_server: "127.0.0.1:9".into(),
+                ..EndpointConfig::default()
+            })
+            .build()
+            .unwrap();
+
+        let report = check_network(config).await;
+        assert_eq!(report.status.quality, ConnectionQuality::CaptivePortal);
+        let portal_status = report.captive_portal.unwrap();
+        assert_eq!(portal_status.redirect_url, Some(portal.url("/login")));
+        assert_eq!(
+            report.walled_garden.unwrap().https,
+            ServiceReachability::Reachable
+        );
+    }
+
+    #[tokio::test]
+    async fn test_attribution_from_gateway_sampled_in_every_round() {
+        use crate::api::testsupport::TestServer;
+
+        let target = TestServer::tcp_echo().start().await.unwrap();
+        let config = NetworkConfiguration::builder()
+            .add_target(target.target("local"))
+            .build()
+            .unwrap();
+        let limiter = RateLimiter::new(&config.rate_limit);
+        // Loopback refuses the gateway's TCP ports, which counts as an answer.
+        let gateway = Some(IpAddr::from([127, 0, 0, 1]));
+
+        let (mut report, gateway_stats) = run_base_check(
+            &config,
+            &limiter,
+            &CancellationToken::new(),
+            gateway,
+            &WeightedStabilityModel::default(),
+        )
+        .await;
+        let gateway_stats = gateway_stats.unwrap();
+        assert_eq!(gateway_stats.packet_loss_percent, 0.0);
+        assert!(report.attribution.is_none());
+        apply_attribution(&mut report, Some(&gateway_stats));
+        assert!(report.attribution.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_check_network_offline_with_mock_transport() {
+        let web: SocketAddr = "192.0.2.10:443".parse().unwrap();
+        let dns: SocketAddr = "192.0.2.53:53".parse().unwrap();
+        let mock = MockTransport::new()
+            .with_interface("lo", vec!["127.0.0.1".parse().unwrap()])
+            .with_interface("wlan0", vec!["192.168.1.20".parse().unwrap()])
+            .with_host("api.example", vec![web.ip()])
+            .accept_tcp(web)
+            .with_dns_server(dns)
+            .with_latency(Duration::from_millis(5));
+        let config = NetworkConfiguration::builder()
+            .add_target(
+                NetworkTarget::builder("api.example", TargetProtocol::Tcp)
+                    .port(443)
+                    .essential(true)
+                    .build(),
+            )
+            .add_target(NetworkTarget::builder("192.0.2.53", TargetProtocol::Dns).build())
+            .add_target(NetworkTarget::builder("192.0.2.99", TargetProtocol::Tcp).build())
+            .build()
+            .unwrap();
+
+        let report = with_transport(Arc::new(mock), check_network(config)).await;
+        assert!(report.status.is_connected);
+        assert_eq!(report.scope, NetworkScope::Internet);
+        assert_eq!(report.connection_type, ConnectionType::Wifi);
+        assert_eq!(report.security_flags_result.interface_name, "wlan0");
+        let success: Vec<bool> = report.target_reports.iter().map(|r| r.success).collect();
+        assert_eq!(success, [true, true, false]);
+        assert_eq!(
+            report.target_reports[0].resolved_addr.as_deref(),
+            Some("192.0.2.10")
+        );
+        assert!(report.status.latency_stats.latency_ms >= 5);
+        assert!(report.captive_portal.is_none());
+
+        // An empty network: no interfaces, and nothing answers.
+        let report = with_transport(
+            Arc::new(MockTransport::new()),
+            check_network(NetworkConfiguration::default()),
+        )
+        .await;
+        assert!(!report.status.is_connected);
+        assert_eq!(report.status.quality, ConnectionQuality::Offline);
+        assert_eq!(report.scope, NetworkScope::None);
+        assert_eq!(report.connection_type, ConnectionType::Unknown);
     }
 }
@@ -0,0 +1,130 @@
+//! Passive connectivity signals reported by the host app.
+//!
+//! Apps make network requests of their own all the time. Their outcomes are
+//! free evidence: a stream of successful requests confirms a cached
+//! "connected" report without new probes, while a run of failures shows the
+//! cached report is stale before its cache validity runs out. See
+//! [ReachabilityEngine::report_app_success](super::ReachabilityEngine::report_app_success).
+
+use crate::api::models::ResilienceConfig;
+use flutter_rust_bridge::frb;
+use std::time::{Duration, Instant};
+
+/// The outcome of one request made by the host app.
+#[frb(ignore)]
+#[derive(Debug, Clone)]
+pub(crate) struct AppSignal {
+    pub at: Instant,
+    pub success: bool,
+    /// The app's description of a failure.
+    pub error: Option<String>,
+}
+
+/// What the app's recent requests say about a cached report.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum PassiveVerdict {
+    /// No usable evidence; fall back to the cache validity.
+    Neutral,
+    /// The app's requests agree with the report.
+    Confirmed,
+    /// The app's requests contradict the report; it must be refreshed.
+    Contradicted,
+}
+
+/// Judges a report captured at `report_at` against the signals received since.
+///
+/// Signals older than the passive window or than the report are ignored.
+/// A connected report is contradicted by `passive_failure_threshold`
+/// consecutive failures and confirmed by a most recent success; an offline
+/// report is contradicted by any success.
+pub(crate) fn passive_verdict<'a>(
+    signals: impl IntoIterator<Item = &'a AppSignal>,
+    report_at: Instant,
+    is_connected: bool,
+    config: &ResilienceConfig,
+    now: Instant,
+) -> PassiveVerdict {
+    let window = Duration::from_millis(config.passive_signal_window_ms);
+    let recent: Vec<&AppSignal> = signals
+        .into_iter()
+        .filter(|s| s.at >= report_at && now.duration_since(s.at) <= window)
+        .collect();
+    let Some(latest) = recent.last() else {
+        return PassiveVerdict::Neutral;
+    };
+
+    if !is_connected {
+        return if recent.iter().any(|s| s.success) {
+            PassiveVerdict::Contradicted
+        } else {
+            PassiveVerdict::Confirmed
+        };
+    }
+
+    let trailing_failures = recent.iter().rev().take_while(|s| !s.success).count();
+    let threshold = usize::from(config.passive_failure_threshold);
+    if threshold > 0 && trailing_failures >= threshold {
+        PassiveVerdict::Contradicted
+    } else if latest.success {
+        PassiveVerdict::Confirmed
+    } else {
+        PassiveVerdict::Neutral
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_passive_verdict() {
+        let config = ResilienceConfig::default();
+        let t0 = Instant::now();
+        let at = |ms: u64, success: bool| AppSignal {
+            at: t0 + Duration::from_millis(ms),
+            success,
+            error: None,
+        };
+        let now = t0 + Duration::from_millis(1000);
+
+        let verdict = |signals: &[AppSignal], connected: bool| {
+            passive_verdict(signals, t0, connected, &config, now)
+        };
+        assert_eq!(verdict(&[], true), PassiveVerdict::Neutral);
+        assert_eq!(
+            verdict(&[at(10, false), at(20, true)], true),
+            PassiveVerdict::Confirmed
+        );
+        assert_eq!(
+            verdict(&[at(10, true), at(20, false)], true),
+            PassiveVerdict::Neutral
+        );
+        assert_eq!(
+            verdict(
+                &[at(10, true), at(20, false), at(30, false), at(40, false)],
+                true
+            ),
+            PassiveVerdict::Contradicted
+        );
+        assert_eq!(
+            verdict(&[at(10, false), at(20, true)], false),
+            PassiveVerdict::Contradicted
+        );
+        assert_eq!(verdict(&[at(10, false)], false), PassiveVerdict::Confirmed);
+
+        // Signals from before the report or outside the window do not count.
+        let stale = passive_verdict(
+            &[at(10, true)],
+            t0 + Duration::from_millis(20),
+            false,
+            &config,
+            now,
+        );
+        assert_eq!(stale, PassiveVerdict::Neutral);
+        let expired = now + Duration::from_millis(config.passive_signal_window_ms);
+        assert_eq!(
+            passive_verdict(&[at(10, true)], t0, false, &config, expired),
+            PassiveVerdict::Neutral
+        );
+    }
+}
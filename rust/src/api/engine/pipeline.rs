@@ -0,0 +1,372 @@
+//! A small, declarative pipeline for conditionally running the heavier probes.
+//!
+//! Every pipeline starts with the mandatory base check (sampling, statistics,
+//! quality and interface detection). The declared steps then run in order, each
+//! guarded by a [StepCondition] that is evaluated against the report *as it
+//! stands at that point*, so a later step can react to what an earlier one found.
+//!
+//! ```ignore
+//! let outcome = CheckPipeline::new()
+//!     .step(PipelineStep::CaptivePortal)
+//!     .step_if(
+//!         PipelineStep::DnsSecurity,
+//!         StepCondition::CaptivePortalNotDetected,
+//!     )
+//!     .run(config)
+//!     .await;
+//! ```
+
+use super::{
+    apply_attribution, apply_captive_portal_check, apply_family_comparison, apply_isp_status_check,
+    apply_multicast_check, apply_path_comparison, attribution_gateway, finish_timing,
+    rate_limit::RateLimiter, refresh_layers, run_base_check,
+};
+use crate::api::{
+    analysis::WeightedStabilityModel,
+    constants::LibConstants,
+    engine::security::perform_dns_security_check,
+    models::{ConnectionQuality, LatencyStats, NetworkConfiguration, NetworkReport},
+};
+use flutter_rust_bridge::frb;
+use tokio_util::sync::CancellationToken;
+
+/// An optional probe that a [CheckPipeline] can run after the base check.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PipelineStep {
+    /// Probes for a captive portal and downgrades the quality if one is found.
+    CaptivePortal,
+
+    /// Compares system DNS against a trusted resolver to detect hijacking.
+    ///
+    /// Runs regardless of [SecurityConfig::detect_dns_hijack](crate::api::models::SecurityConfig::detect_dns_hijack)
+    /// when declared in a pipeline.
+    DnsSecurity,
+
+    /// Asks the [isp_status_urls](NetworkConfiguration::isp_status_urls)
+    /// whether the ISP has an outage. Only does anything if the gateway
+    /// answered but no internet target did.
+    IspStatus,
+
+    /// Probes the dual-stack targets over IPv4 and IPv6 separately into
+    /// [NetworkReport::family_comparison]. Only does anything while connected.
+    AddressFamilies,
+
+    /// Checks LAN multicast via the mDNS group into
+    /// [NetworkReport::multicast_ok].
+    Multicast,
+
+    /// Measures every Wi-Fi, cellular and Ethernet interface separately into
+    /// [NetworkReport::better_path].
+    Paths,
+
+    /// Attributes the degradation to the first hop or the rest of the path
+    /// into [NetworkReport::attribution]. Declaring this step samples the
+    /// gateway alongside the base check, whether or not its condition later
+    /// holds. Only does anything while connected.
+    Attribution,
+
+    /// Traces the route to the first configured target into
+    /// [NetworkReport::traceroute], e.g. only while
+    /// [StepCondition::QualityBelow] `Good`.
+    Traceroute,
+
+    /// Reads the system description and interface error counters of the
+    /// devices in [NetworkConfiguration::snmp] (default: the gateway) into
+    /// [NetworkReport::snmp_devices].
+    #[cfg(feature = "snmp")]
+    Snmp,
+}
+
+/// A predicate over the in-progress report that decides whether a step runs.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum StepCondition {
+    /// The step always runs.
+    Always,
+
+    /// Runs only if the base check found the network connected.
+    Connected,
+
+    /// Runs only if the base check found the network offline.
+    Offline,
+
+    /// Runs only if the current quality is strictly worse than the given one.
+    QualityBelow(ConnectionQuality),
+
+    /// Runs only if the current quality is the given one or better.
+    QualityAtLeast(ConnectionQuality),
+
+    /// Runs only if an earlier step detected a captive portal.
+    CaptivePortalDetected,
+
+    /// Runs only if no captive portal has been detected (so far).
+    CaptivePortalNotDetected,
+
+    /// Runs only if a VPN interface is active.
+    VpnDetected,
+}
+
+impl StepCondition {
+    /// Evaluates the condition against the current state of a report.
+    pub fn is_met(&self, report: &NetworkReport) -> bool {
+        let quality = report.status.quality;
+        match *self {
+            StepCondition::Always => true,
+            StepCondition::Connected => report.status.is_connected,
+            StepCondition::Offline => !report.status.is_connected,
+            StepCondition::QualityBelow(q) => quality_rank(quality) > quality_rank(q),
+            StepCondition::QualityAtLeast(q) => quality_rank(quality) <= quality_rank(q),
+            StepCondition::CaptivePortalDetected => quality == ConnectionQuality::CaptivePortal,
+            StepCondition::CaptivePortalNotDetected => quality != ConnectionQuality::CaptivePortal,
+            StepCondition::VpnDetected => report.security_flags_result.is_vpn_detected,
+        }
+    }
+}
+
+/// Records whether a declared step ran or was skipped by its condition.
+#[derive(Debug, Clone)]
+pub struct StepOutcome {
+    /// The declared step.
+    pub step: PipelineStep,
+
+    /// The condition that guarded the step.
+    pub condition: StepCondition,
+
+    /// True if the condition was met and the step ran.
+    pub executed: bool,
+}
+
+/// The result of running a [CheckPipeline].
+#[derive(Debug, Clone)]
+pub struct PipelineReport {
+    /// The final report, including the effects of every executed step.
+    pub report: NetworkReport,
+
+    /// One entry per declared step, in declaration order.
+    pub steps: Vec<StepOutcome>,
+}
+
+/// An ordered list of conditional probe steps executed after the base check.
+#[frb(opaque)]
+#[derive(Debug, Clone, Default)]
+pub struct CheckPipeline {
+    steps: Vec<(PipelineStep, StepCondition)>,
+}
+
+impl CheckPipeline {
+    /// Creates an empty pipeline that only runs the base check.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A captive portal probe followed by the DNS hijack check while connected,
+    /// mirroring the probes run by [check_network](super::check_network).
+    pub fn standard() -> Self {
+        Self::new()
+            .step(PipelineStep::CaptivePortal)
+            .step_if(PipelineStep::DnsSecurity, StepCondition::Connected)
+    }
+
+    /// Appends a step that always runs.
+    pub fn step(self, step: PipelineStep) -> Self {
+        self.step_if(step, StepCondition::Always)
+    }
+
+    /// Appends a step that only runs when `condition` is met.
+    pub fn step_if(self, step: PipelineStep, condition: StepCondition) -> Self {
+        let mut steps = self.steps;
+        steps.push((step, condition));
+        Self { steps }
+    }
+
+    /// Runs the base check followed by every declared step whose condition holds.
+    ///
+    /// All steps share the rate limit budget of the base check.
+    pub async fn run(&self, config: NetworkConfiguration) -> PipelineReport {
+        let limiter = RateLimiter::new(&config.rate_limit);
+        self.run_with_limiter(config, &limiter).await
+    }
+
+    /// [Self::run], drawing the probe traffic from `limiter`, e.g. one shared
+    /// with a [ReachabilityEngine](super::ReachabilityEngine).
+    pub async fn run_with_limiter(
+        &self,
+        config: NetworkConfiguration,
+        limiter: &RateLimiter,
+    ) -> PipelineReport {
+        let gateway = self
+            .steps
+            .iter()
+            .any(|&(step, _)| step == PipelineStep::Attribution)
+            .then(attribution_gateway)
+            .flatten();
+        let (mut report, gateway_stats) = run_base_check(
+            &config,
+            limiter,
+            &CancellationToken::new(),
+            gateway,
+            &WeightedStabilityModel::default(),
+        )
+        .await;
+        let mut steps = Vec::with_capacity(self.steps.len());
+
+        for &(step, condition) in &self.steps {
+            let executed = condition.is_met(&report);
+            if executed {
+                run_step(step, &config, &mut report, limiter, gateway_stats.as_ref()).await;
+            }
+            steps.push(StepOutcome {
+                step,
+                condition,
+                executed,
+            });
+        }
+
+        refresh_layers(&config, &mut report);
+        finish_timing(&mut report);
+        PipelineReport { report, steps }
+    }
+}
+
+/// Executes a single step against the in-progress report.
+async fn run_step(
+    step: PipelineStep,
+    config: &NetworkConfiguration,
+    report: &mut NetworkReport,
+    limiter: &RateLimiter,
+    gateway_stats: Option<&LatencyStats>,
+) {
+    match step {
+        PipelineStep::CaptivePortal => apply_captive_portal_check(config, report, limiter).await,
+        PipelineStep::DnsSecurity => {
+            limiter
+                .acquire(LibConstants::ESTIMATED_DNS_CHECK_BYTES)
+                .await;
+            let mut forced = config.clone();
+            forced.security.detect_dns_hijack = true;
+            perform_dns_security_check(&forced, &mut report.security_flags_result).await;
+        }
+        PipelineStep::IspStatus => apply_isp_status_check(config, report, limiter).await,
+        PipelineStep::AddressFamilies => apply_family_comparison(config, report, limiter).await,
+        PipelineStep::Multicast => apply_multicast_check(report, limiter).await,
+        PipelineStep::Paths => apply_path_comparison(config, report, limiter).await,
+        PipelineStep::Attribution => apply_attribution(report, gateway_stats),
+        PipelineStep::Traceroute => {
+            let Some(target) = config.targets.first() else {
+                return;
+            };
+            limiter
+                .acquire(LibConstants::ESTIMATED_TRACEROUTE_BYTES)
+                .await;
+            report.traceroute = crate::api::probes::trace_routes(
+                vec![target.host.clone()],
+                0,
+                LibConstants::DEFAULT_TRACEROUTE_HOP_TIMEOUT_MS,
+            )
+            .await
+            .routes
+            .pop();
+        }
+        #[cfg(feature = "snmp")]
+        PipelineStep::Snmp => report.snmp_devices = query_snmp_devices(config, limiter).await,
+    }
+}
+
+/// Queries every configured SNMP device, or the default gateway if none is set.
+#[cfg(feature = "snmp")]
+async fn query_snmp_devices(
+    config: &NetworkConfiguration,
+    limiter: &RateLimiter,
+) -> Vec<crate::api::models::SnmpDeviceInfo> {
+    use crate::api::{models::SnmpDeviceInfo, probes::snmp::query_snmp_device};
+
+    let snmp = &config.snmp;
+    let hosts: Vec<String> = if snmp.hosts.is_empty() {
+        crate::api::probes::gateway::default_gateway()
+            .map(|ip| ip.to_string())
+            .into_iter()
+            .collect()
+    } else {
+        snmp.hosts.clone()
+    };
+
+    let queries = hosts.into_iter().map(|host| async move {
+        limiter
+            .acquire(LibConstants::ESTIMATED_SNMP_QUERY_BYTES)
+            .await;
+        let result = match host.parse() {
+            Ok(ip) => query_snmp_device(ip, &snmp.community, snmp.timeout_ms).await,
+            Err(_) => Err(crate::api::models::NetworkError::UnknownError(format!(
+                "Invalid SNMP host: {}",
+                host
+            ))),
+        };
+        result.unwrap_or_else(|e| SnmpDeviceInfo {
+            address: host,
+            sys_descr: None,
+            interfaces: Vec::new(),
+            error: Some(e.to_string()),
+        })
+    });
+    futures::future::join_all(queries).await
+}
+
+/// Orders [ConnectionQuality] from best (0) to worst.
+fn quality_rank(quality: ConnectionQuality) -> u8 {
+    match quality {
+        ConnectionQuality::Excellent => 0,
+        ConnectionQuality::Great => 1,
+        ConnectionQuality::Good => 2,
+        ConnectionQuality::Moderate => 3,
+        ConnectionQuality::Poor => 4,
+        ConnectionQuality::Unstable => 5,
+        ConnectionQuality::CaptivePortal => 6,
+        ConnectionQuality::Offline => 7,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{
+        models::{NetworkTarget, TargetProtocol},
+        testsupport::TestServer,
+    };
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[tokio::test]
+    async fn test_traceroute_step_runs_only_below_good() {
+        let pipeline = CheckPipeline::new().step_if(
+            PipelineStep::Traceroute,
+            StepCondition::QualityBelow(ConnectionQuality::Good),
+        );
+
+        let server = TestServer::tcp_echo().start().await.unwrap();
+        let config = NetworkConfiguration::builder()
+            .add_target(server.target("local"))
+            .build()
+            .unwrap();
+        let healthy = pipeline.run(config).await;
+        assert!(!healthy.steps[0].executed);
+        assert!(healthy.report.traceroute.is_none());
+
+        // Nothing listens on the port any more, so the check comes out offline.
+        let port = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap()
+            .port();
+        let config = NetworkConfiguration::builder()
+            .add_target(
+                NetworkTarget::builder("127.0.0.1", TargetProtocol::Tcp)
+                    .port(port)
+                    .build(),
+            )
+            .build()
+            .unwrap();
+        let offline = pipeline.run(config).await;
+        assert!(offline.steps[0].executed);
+        let trace = offline.report.traceroute.unwrap();
+        assert_eq!(trace.host, "127.0.0.1");
+        assert!(trace.reached);
+    }
+}
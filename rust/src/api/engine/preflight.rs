@@ -0,0 +1,278 @@
+//! Connection preflight against application requirements.
+//!
+//! A video call, a large upload or a multiplayer session each care about a
+//! different subset of connection properties. [preflight] measures only the
+//! properties named in a [Requirements] profile, concurrently, and lists the
+//! ones that fall short.
+
+use super::{clock, rate_limit::RateLimiter, sampler::run_samples};
+use crate::api::{
+    analysis::compute_latency_stats,
+    constants::LibConstants,
+    models::{NetworkConfiguration, PreflightReport, Requirements, UnmetRequirement},
+    probes::udp::probe_dns,
+};
+use flutter_rust_bridge::frb;
+use std::{
+    net::SocketAddr,
+    time::{Duration, Instant},
+};
+use tokio::time::timeout;
+use tokio_util::sync::CancellationToken;
+
+/// What [preflight] measured; `None` for anything not required.
+#[frb(ignore)]
+#[derive(Debug, Default, Clone, PartialEq)]
+struct Measurements {
+    down_mbps: Option<f64>,
+    latency_ms: Option<u64>,
+    packet_loss_percent: Option<f32>,
+    udp_ok: Option<bool>,
+    websocket_ok: Option<bool>,
+}
+
+/// Checks the connection against `requirements`.
+///
+/// Only the probes needed for the set requirements run, all at once; an empty
+/// profile passes immediately without any traffic.
+///
+/// ```ignore
+/// let report = preflight(Requirements {
+///     max_latency_ms: Some(150),
+///     max_loss_percent: Some(2.0),
+///     needs_udp: true,
+///     ..Default::default()
+/// })
+/// .await;
+/// if !report.passed {
+///     warn_user(&report.unmet);
+/// }
+/// ```
+pub async fn preflight(requirements: Requirements) -> PreflightReport {
+    let start = clock::monotonic_ms();
+    let needs_samples =
+        requirements.max_latency_ms.is_some() || requirements.max_loss_percent.is_some();
+
+    let (down_mbps, samples, udp_ok, websocket_ok) = tokio::join!(
+        optional(requirements.min_down_mbps.is_some(), async {
+            measure_download_mbps(&requirements.download_url).await
+        }),
+        optional(needs_samples, measure_latency(&requirements)),
+        optional(
+            requirements.needs_udp,
+            check_udp(&requirements.udp_dns_server)
+        ),
+        optional(
+            requirements.needs_websocket,
+            check_websocket(&requirements.websocket_url)
+        ),
+    );
+
+    let (latency_ms, packet_loss_percent) = match samples {
+        Some((latency, loss)) => (latency, Some(loss)),
+        None => (None, None),
+    };
+    let measured = Measurements {
+        down_mbps: down_mbps.flatten(),
+        latency_ms,
+        packet_loss_percent,
+        udp_ok,
+        websocket_ok,
+    };
+    let unmet = evaluate(&requirements, &measured);
+
+    PreflightReport {
+        passed: unmet.is_empty(),
+        unmet,
+        down_mbps: measured.down_mbps,
+        latency_ms: measured.latency_ms,
+        packet_loss_percent: measured.packet_loss_percent,
+        duration_ms: clock::monotonic_ms().saturating_sub(start),
+    }
+}
+
+async fn optional<T>(needed: bool, probe: impl std::future::Future<Output = T>) -> Option<T> {
+    if needed {
+        Some(probe.await)
+    } else {
+        None
+    }
+}
+
+/// Compares the measurements with the requirements.
+fn evaluate(requirements: &Requirements, measured: &Measurements) -> Vec<UnmetRequirement> {
+    let mut unmet = Vec::new();
+
+    if let Some(required_mbps) = requirements.min_down_mbps {
+        if measured.down_mbps.is_none_or(|mbps| mbps < required_mbps) {
+            unmet.push(UnmetRequirement::Download {
+                required_mbps,
+                measured_mbps: measured.down_mbps,
+            });
+        }
+    }
+    if let Some(max_ms) = requirements.max_latency_ms {
+        if measured.latency_ms.is_none_or(|ms| ms > max_ms) {
+            unmet.push(UnmetRequirement::Latency {
+                max_ms,
+                measured_ms: measured.latency_ms,
+            });
+        }
+    }
+    if let Some(max_percent) = requirements.max_loss_percent {
+        let measured_percent = measured.packet_loss_percent.unwrap_or(100.0);
+        if measured_percent > max_percent {
+            unmet.push(UnmetRequirement::PacketLoss {
+                max_percent,
+                measured_percent,
+            });
+        }
+    }
+    if requirements.needs_udp && measured.udp_ok != Some(true) {
+        unmet.push(UnmetRequirement::Udp);
+    }
+    if requirements.needs_websocket && measured.websocket_ok != Some(true) {
+        unmet.push(UnmetRequirement::WebSocket);
+    }
+
+    unmet
+}
+
+/// Downloads from `url` for at most
+/// [PREFLIGHT_DOWNLOAD_MAX_MS](LibConstants::PREFLIGHT_DOWNLOAD_MAX_MS) and
+/// returns the throughput of the body in Mbit/s.
+async fn measure_download_mbps(url: &str) -> Option<f64> {
+    let budget = Duration::from_millis(LibConstants::PREFLIGHT_DOWNLOAD_MAX_MS);
+    let client = reqwest::Client::builder().timeout(budget).build().ok()?;
+    let mut response = client.get(url).send().await.ok()?;
+    if !response.status().is_success() {
+        return None;
+    }
+
+    let start = Instant::now();
+    let mut bytes = 0u64;
+    while let Ok(Ok(Some(chunk))) =
+        timeout(budget.saturating_sub(start.elapsed()), response.chunk()).await
+    {
+        bytes += chunk.len() as u64;
+    }
+
+    let seconds = start.elapsed().as_secs_f64();
+    (bytes > 0 && seconds > 0.0).then_some(bytes as f64 * 8.0 / seconds / 1_000_000.0)
+}
+
+/// Samples the targets like a regular check and returns the latency (if any
+/// target answered) and the packet loss.
+async fn measure_latency(requirements: &Requirements) -> (Option<u64>, f32) {
+    let mut config = NetworkConfiguration::default();
+    if !requirements.targets.is_empty() {
+        config.targets = requirements.targets.clone();
+    }
+
+    let run = run_samples(
+        &config,
+        &RateLimiter::new(&config.rate_limit),
+        &CancellationToken::new(),
+        None,
+    )
+    .await;
+    let stats = compute_latency_stats(
+        &run.latencies,
+        std::cmp::max(1, config.resilience.num_jitter_samples),
+        &config.quality_threshold,
+    );
+    (
+        (!run.latencies.is_empty()).then_some(stats.latency_ms),
+        stats.packet_loss_percent,
+    )
+}
+
+async fn check_udp(server: &str) -> bool {
+    let Ok(addr) = server.parse::<SocketAddr>() else {
+        return false;
+    };
+    matches!(
+        timeout(
+            Duration::from_millis(LibConstants::PREFLIGHT_UDP_TIMEOUT_MS),
+            probe_dns(addr, LibConstants::DEFAULT_DNS_QUERY_NAME),
+        )
+        .await,
+        Ok(Ok(()))
+    )
+}
+
+/// Sends a WebSocket upgrade request and checks for `101 Switching Protocols`.
+///
+/// Transparent proxies that do not understand the upgrade answer with a
+/// plain response or drop the connection instead.
+async fn check_websocket(url: &str) -> bool {
+    let url = if let Some(rest) = url.strip_prefix("wss://") {
+        format!("https://{}", rest)
+    } else if let Some(rest) = url.strip_prefix("ws://") {
+        format!("http://{}", rest)
+    } else {
+        url.to_string()
+    };
+
+    let Ok(client) = reqwest::Client::builder()
+        .http1_only()
+        .timeout(Duration::from_millis(LibConstants::DEFAULT_HTTP_TIMEOUT_MS))
+        .build()
+    else {
+        return false;
+    };
+    let response = client
+        .get(url)
+        .header("Connection", "Upgrade")
+        .header("Upgrade", "websocket")
+        .header("Sec-WebSocket-Version", "13")
+        .header("Sec-WebSocket-Key", "dGhlIHNhbXBsZSBub25jZQ==")
+        .send()
+        .await;
+
+    response.is_ok_and(|r| r.status() == reqwest::StatusCode::SWITCHING_PROTOCOLS)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_preflight_evaluates_only_set_requirements() {
+        // Nothing required: passes without probing anything.
+        let report = preflight(Requirements::default()).await;
+        assert!(report.passed);
+        assert_eq!(report.down_mbps, None);
+        assert_eq!(report.packet_loss_percent, None);
+
+        let requirements = Requirements {
+            min_down_mbps: Some(25.0),
+            max_latency_ms: Some(100),
+            max_loss_percent: Some(1.0),
+            needs_udp: true,
+            needs_websocket: true,
+            ..Default::default()
+        };
+        let measured = Measurements {
+            down_mbps: Some(80.0),
+            latency_ms: Some(240),
+            packet_loss_percent: Some(0.0),
+            udp_ok: Some(false),
+            websocket_ok: Some(true),
+        };
+        assert_eq!(
+            evaluate(&requirements, &measured),
+            vec![
+                UnmetRequirement::Latency {
+                    max_ms: 100,
+                    measured_ms: Some(240),
+                },
+                UnmetRequirement::Udp,
+            ]
+        );
+
+        // A failed measurement never counts as passing.
+        let unmeasured = Measurements::default();
+        assert_eq!(evaluate(&requirements, &unmeasured).len(), 5);
+    }
+}
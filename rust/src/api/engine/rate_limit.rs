@@ -0,0 +1,179 @@
+//! Token-bucket rate limiting for probe traffic.
+
+use crate::api::{
+    constants::LibConstants,
+    models::{RateLimitConfig, TargetProtocol},
+};
+use flutter_rust_bridge::frb;
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// A token bucket refilled continuously at `rate` tokens per second.
+#[derive(Debug)]
+struct Bucket {
+    rate: f64,
+    capacity: f64,
+    tokens: f64,
+}
+
+impl Bucket {
+    fn new(rate: f64) -> Self {
+        // One second worth of burst capacity.
+        Self {
+            rate,
+            capacity: rate,
+            tokens: rate,
+        }
+    }
+
+    fn refill(&mut self, elapsed: Duration) {
+        self.tokens = (self.tokens + elapsed.as_secs_f64() * self.rate).min(self.capacity);
+    }
+
+    /// Reserves `cost` tokens and returns how long the caller must wait until
+    /// the reservation is covered. The balance may go negative, which queues
+    /// later callers behind this one.
+    fn reserve(&mut self, cost: f64) -> Duration {
+        self.tokens -= cost;
+        if self.tokens >= 0.0 {
+            Duration::ZERO
+        } else {
+            Duration::from_secs_f64(-self.tokens / self.rate)
+        }
+    }
+}
+
+#[frb(ignore)]
+#[derive(Debug)]
+struct Buckets {
+    probes: Option<Bucket>,
+    bytes: Option<Bucket>,
+    last_refill: Instant,
+}
+
+/// A rate limiter shared by every probe of a check.
+///
+/// Cloning a `RateLimiter` is cheap and yields a handle to the *same* buckets,
+/// so a single instance can be handed to concurrently running probes.
+#[frb(ignore)]
+#[derive(Debug, Clone, Default)]
+pub struct RateLimiter {
+    inner: Option<Arc<Mutex<Buckets>>>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter enforcing the given [RateLimitConfig].
+    ///
+    /// Returns an unlimited limiter if both limits are disabled.
+    pub fn new(config: &RateLimitConfig) -> Self {
+        let probes = (config.max_probes_per_second > 0)
+            .then(|| Bucket::new(config.max_probes_per_second as f64));
+        let bytes = (config.max_bytes_per_second > 0)
+            .then(|| Bucket::new(config.max_bytes_per_second as f64));
+
+        if probes.is_none() && bytes.is_none() {
+            return Self::unlimited();
+        }
+
+        Self {
+            inner: Some(Arc::new(Mutex::new(Buckets {
+                probes,
+                bytes,
+                last_refill: Instant::now(),
+            }))),
+        }
+    }
+
+    /// Creates a limiter that never waits.
+    pub fn unlimited() -> Self {
+        Self { inner: None }
+    }
+
+    /// Waits until one probe of roughly `bytes` bytes may be sent.
+    pub async fn acquire(&self, bytes: u64) {
+        let wait = self.reserve(bytes);
+        if !wait.is_zero() {
+            tokio::time::sleep(wait).await;
+        }
+    }
+
+    /// Reserves capacity for one probe and returns the required wait time.
+    fn reserve(&self, bytes: u64) -> Duration {
+        let Some(inner) = &self.inner else {
+            return Duration::ZERO;
+        };
+        let mut buckets = inner.lock().unwrap_or_else(|e| e.into_inner());
+
+        let now = Instant::now();
+        let elapsed = now.duration_since(buckets.last_refill);
+        buckets.last_refill = now;
+
+        let probe_wait = buckets.probes.as_mut().map_or(Duration::ZERO, |b| {
+            b.refill(elapsed);
+            b.reserve(1.0)
+        });
+        let bytes_wait = buckets.bytes.as_mut().map_or(Duration::ZERO, |b| {
+            b.refill(elapsed);
+            b.reserve(bytes as f64)
+        });
+
+        probe_wait.max(bytes_wait)
+    }
+}
+
+/// Estimates the on-the-wire size (both directions) of a single probe.
+pub fn estimated_probe_bytes(protocol: TargetProtocol) -> u64 {
+    match protocol {
+        TargetProtocol::Tcp => LibConstants::ESTIMATED_TCP_PROBE_BYTES,
+        TargetProtocol::Icmp => LibConstants::ESTIMATED_ICMP_PROBE_BYTES,
+        TargetProtocol::Http => LibConstants::ESTIMATED_HTTP_PROBE_BYTES,
+        TargetProtocol::Https => LibConstants::ESTIMATED_HTTPS_PROBE_BYTES,
+        TargetProtocol::Udp => LibConstants::ESTIMATED_UDP_PROBE_BYTES,
+        TargetProtocol::Dns => LibConstants::ESTIMATED_DNS_PROBE_BYTES,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_unlimited_never_waits() {
+        let limiter = RateLimiter::new(&RateLimitConfig::default());
+        for _ in 0..1000 {
+            assert_eq!(limiter.reserve(1_000_000), Duration::ZERO);
+        }
+    }
+
+    #[test]
+    fn test_probe_bucket_queues_after_burst() {
+        let limiter = RateLimiter::new(&RateLimitConfig {
+            max_probes_per_second: 10,
+            max_bytes_per_second: 0,
+        });
+
+        // The first second's worth of probes goes out immediately...
+        for _ in 0..10 {
+            assert_eq!(limiter.reserve(0), Duration::ZERO);
+        }
+        // ...after which each probe is queued ~100ms behind the previous one.
+        let first = limiter.reserve(0);
+        let second = limiter.reserve(0);
+        assert!(first > Duration::from_millis(50));
+        assert!(second > first);
+    }
+
+    #[test]
+    fn test_byte_bucket_limits_large_probes() {
+        let limiter = RateLimiter::new(&RateLimitConfig {
+            max_probes_per_second: 0,
+            max_bytes_per_second: 1000,
+        });
+
+        assert_eq!(limiter.reserve(1000), Duration::ZERO);
+        let wait = limiter.reserve(500);
+        assert!(wait >= Duration::from_millis(400) && wait <= Duration::from_millis(500));
+    }
+}
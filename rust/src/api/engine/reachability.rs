@@ -0,0 +1,724 @@
+//! An instantiable engine that owns its own cache, circuit breaker and history.
+//!
+//! [check_network](super::check_network) is stateless: every call probes the
+//! network from scratch. A [ReachabilityEngine] wraps it with the state a
+//! long-running monitor needs. Each instance is fully isolated, so an app can
+//! watch e.g. corporate VPN endpoints and the public internet side by side with
+//! different configurations:
+//!
+//! ```ignore
+//! let corporate = ReachabilityEngine::new(corporate_config);
+//! let public = ReachabilityEngine::new(NetworkConfiguration::default());
+//! let (a, b) = tokio::join!(corporate.check(), public.check());
+//! ```
+
+use super::{
+    circuit_breaker::{CircuitBreaker, CircuitBreakerState},
+    clock,
+    history::HistoryStore,
+    passive::{passive_verdict, AppSignal, PassiveVerdict},
+    rate_limit::RateLimiter,
+    run_check,
+    runtime::ProbeRuntime,
+};
+use crate::api::{
+    analysis::{
+        anomaly::AnomalyDetector, roaming::detect_roam,
+        time_of_day::profile_time_of_day_with_aggregates, StabilityModel, WeightedStabilityModel,
+    },
+    constants::LibConstants,
+    models::{
+        AnomalyDetected, EngineSnapshot, HistoryAggregate, NetworkConfiguration, NetworkReport,
+        TargetHealth, TimeOfDayProfile,
+    },
+    probes::transport::{with_transport, NetworkTransport},
+};
+use flutter_rust_bridge::frb;
+use std::{
+    collections::VecDeque,
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+use tokio_util::sync::CancellationToken;
+
+/// Mutable state of a single engine instance.
+#[derive(Debug)]
+struct EngineState {
+    /// The instant is `None` for a restored report too old to place on the
+    /// monotonic clock; such a report is never reused as a cached result.
+    last_report: Option<(NetworkReport, Option<Instant>)>,
+    /// Incremented after every completed check; used to coalesce waiters.
+    generation: u64,
+    breaker: CircuitBreaker,
+    history: HistoryStore,
+    /// Request outcomes reported by the host app, oldest first.
+    app_signals: VecDeque<AppSignal>,
+    anomaly_detector: AnomalyDetector,
+    /// Anomalies not yet collected via [ReachabilityEngine::take_anomalies].
+    anomalies: VecDeque<AnomalyDetected>,
+    /// One entry per configured target, in configuration order.
+    target_health: Vec<TargetHealth>,
+}
+
+/// A self-contained reachability engine with its own configuration and state.
+#[derive(Debug)]
+pub struct ReachabilityEngine {
+    config: NetworkConfiguration,
+    runtime: ProbeRuntime,
+    /// `None` probes the real network.
+    transport: Option<Arc<dyn NetworkTransport>>,
+    /// Shared by every check, so the rate limit holds across checks.
+    limiter: Arc<RateLimiter>,
+    stability_model: Arc<dyn StabilityModel>,
+    state: Mutex<EngineState>,
+    /// Serializes network I/O so concurrent callers share one check.
+    in_flight: tokio::sync::Mutex<()>,
+    /// Cancelled by [ReachabilityEngine::shutdown].
+    shutdown: CancellationToken,
+}
+
+impl ReachabilityEngine {
+    /// Creates an engine keeping the last
+    /// [DEFAULT_HISTORY_CAPACITY](LibConstants::DEFAULT_HISTORY_CAPACITY) reports.
+    pub fn new(config: NetworkConfiguration) -> Self {
+        Self::with_history_capacity(config, LibConstants::DEFAULT_HISTORY_CAPACITY)
+    }
+
+    /// Creates an engine keeping at most `history_capacity` full reports.
+    ///
+    /// Reports older than an hour, or beyond the capacity, are folded into
+    /// [Self::history_aggregates] instead of being dropped. A capacity of 0
+    /// disables the history.
+    pub fn with_history_capacity(config: NetworkConfiguration, history_capacity: usize) -> Self {
+        let breaker = CircuitBreaker::new(&config.resilience);
+        let target_health = initial_health(&config);
+        let limiter = Arc::new(RateLimiter::new(&config.rate_limit));
+        Self {
+            config,
+            runtime: ProbeRuntime::ambient(),
+            transport: None,
+            limiter,
+            stability_model: Arc::new(WeightedStabilityModel::default()),
+            state: Mutex::new(EngineState {
+                last_report: None,
+                generation: 0,
+                breaker,
+                history: HistoryStore::new(history_capacity),
+                app_signals: VecDeque::new(),
+                anomaly_detector: AnomalyDetector::default(),
+                anomalies: VecDeque::new(),
+                target_health,
+            }),
+            in_flight: tokio::sync::Mutex::new(()),
+            shutdown: CancellationToken::new(),
+        }
+    }
+
+    /// Spawns this engine's probes onto the runtime behind `handle` instead of
+    /// the caller's ambient runtime. See [ProbeRuntime].
+    #[frb(ignore)]
+    pub fn with_runtime(mut self, handle: tokio::runtime::Handle) -> Self {
+        self.runtime = ProbeRuntime::with_handle(handle);
+        self
+    }
+
+    /// Runs this engine's probes through `transport` instead of the real
+    /// network, e.g. a [MockTransport](crate::api::probes::MockTransport) in
+    /// tests. See [with_transport].
+    #[frb(ignore)]
+    pub fn with_transport(mut self, transport: Arc<dyn NetworkTransport>) -> Self {
+        self.transport = Some(transport);
+        self
+    }
+
+    /// Draws this engine's probe traffic from `limiter` instead of a limiter
+    /// built from its own [rate_limit](NetworkConfiguration::rate_limit), so
+    /// several engines (or a [NetworkMonitor](crate::api::monitor::NetworkMonitor))
+    /// can share one budget.
+    #[frb(ignore)]
+    pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
+    /// Scores the stability of this engine's checks with `model` instead of
+    /// the default [WeightedStabilityModel]. Other engines are unaffected.
+    #[frb(ignore)]
+    pub fn with_stability_model(mut self, model: Arc<dyn StabilityModel>) -> Self {
+        self.stability_model = model;
+        self
+    }
+
+    /// Restores the state saved by [Self::snapshot], typically by a previous
+    /// run of the app.
+    ///
+    /// The restored report is returned by [Self::last_report] right away and,
+    /// while younger than `cache_validity_ms` (or while the restored circuit
+    /// breaker is open), by [Self::check] too. Health entries of targets that
+    /// are no longer configured are dropped.
+    pub fn with_snapshot(self, snapshot: EngineSnapshot) -> Self {
+        {
+            let mut state = self.state();
+            let now_ms = clock::wall_clock_ms();
+            let age = |at_ms: u64| Duration::from_millis(now_ms.saturating_sub(at_ms));
+
+            if let Some(report) = snapshot.last_report {
+                let at = Instant::now().checked_sub(age(report.timestamp_ms));
+                state.last_report = Some((report, at));
+            }
+            state.breaker.restore(
+                snapshot.breaker_consecutive_failures,
+                snapshot.breaker_opened_at_ms.map(age),
+            );
+            for health in &mut state.target_health {
+                if let Some(saved) = snapshot
+                    .target_health
+                    .iter()
+                    .find(|h| h.label == health.label)
+                {
+                    *health = saved.clone();
+                }
+            }
+        }
+        self
+    }
+
+    /// Captures the last report, the circuit breaker and the per-target
+    /// health, to be persisted and passed to [Self::with_snapshot] later.
+    pub fn snapshot(&self) -> EngineSnapshot {
+        let state = self.state();
+        let now_ms = clock::wall_clock_ms();
+        EngineSnapshot {
+            taken_at_ms: now_ms,
+            last_report: state.last_report.as_ref().map(|(r, _)| r.clone()),
+            breaker_consecutive_failures: state.breaker.consecutive_failures(),
+            breaker_opened_at_ms: state
+                .breaker
+                .opened_ago()
+                .map(|ago| now_ms.saturating_sub(ago.as_millis() as u64)),
+            target_health: state.target_health.clone(),
+        }
+    }
+
+    /// The configuration this engine was created with.
+    #[frb(ignore)]
+    pub fn config(&self) -> &NetworkConfiguration {
+        &self.config
+    }
+
+    /// Returns a report, reusing the cached one when possible.
+    ///
+    /// # Logic Flow
+    ///
+    /// 1. While the circuit breaker is open, the last report is returned
+    ///    without any network I/O.
+    /// 2. A report younger than `cache_validity_ms` is returned as-is, unless
+    ///    the app's own requests contradict it (see [Self::report_app_failure]).
+    ///    A report confirmed by app successes stays valid for up to
+    ///    `passive_signal_window_ms`.
+    /// 3. Otherwise a new check runs. Concurrent callers wait for it and
+    ///    share its result instead of starting checks of their own.
+    pub async fn check(&self) -> NetworkReport {
+        self.run(false).await
+    }
+
+    /// Forces a new check, ignoring the cache and the circuit breaker.
+    pub async fn refresh(&self) -> NetworkReport {
+        self.run(true).await
+    }
+
+    async fn run(&self, force: bool) -> NetworkReport {
+        let seen = {
+            let state = self.state();
+            if !force {
+                if let Some(report) = self.reusable_report(&state) {
+                    return report;
+                }
+            }
+            state.generation
+        };
+
+        let _guard = self.in_flight.lock().await;
+
+        // Another caller finished a check while we were waiting: share it.
+        {
+            let state = self.state();
+            if state.generation != seen {
+                if let Some((report, _)) = &state.last_report {
+                    return report.clone();
+                }
+            }
+        }
+
+        let check = {
+            let (config, limiter) = (self.config.clone(), self.limiter.clone());
+            let model = self.stability_model.clone();
+            let cancel = self.shutdown.child_token();
+            async move { run_check(config, &limiter, cancel, model.as_ref()).await }
+        };
+        let mut report = match &self.transport {
+            Some(transport) => {
+                self.runtime
+                    .run(with_transport(transport.clone(), check))
+                    .await
+            }
+            None => self.runtime.run(check).await,
+        };
+        // A check cut short by shutdown says nothing about the network.
+        if report.cancelled {
+            return report;
+        }
+        if let Some((previous, _)) = &self.state().last_report {
+            report.roam = detect_roam(previous, &report, &self.config.resilience);
+        }
+        self.record(&mut report);
+        report
+    }
+
+    /// Cancels the check under way and waits up to `timeout_ms` (0 uses
+    /// [DEFAULT_SHUTDOWN_TIMEOUT_MS](LibConstants::DEFAULT_SHUTDOWN_TIMEOUT_MS))
+    /// for it to wind down. Returns false if it did not finish in time.
+    ///
+    /// The cancelled check is returned to its callers flagged as
+    /// [cancelled](NetworkReport::cancelled) and is not recorded. Later calls to
+    /// [Self::check] still return a cached report, but no longer probe the
+    /// network. Name lookups already handed to the OS cannot be interrupted;
+    /// they finish on their own within their timeouts.
+    pub async fn shutdown(&self, timeout_ms: u64) -> bool {
+        self.shutdown.cancel();
+        let wait = Duration::from_millis(if timeout_ms == 0 {
+            LibConstants::DEFAULT_SHUTDOWN_TIMEOUT_MS
+        } else {
+            timeout_ms
+        });
+        tokio::time::timeout(wait, self.in_flight.lock())
+            .await
+            .is_ok()
+    }
+
+    /// True once [Self::shutdown] was called.
+    pub fn is_shut_down(&self) -> bool {
+        self.shutdown.is_cancelled()
+    }
+
+    /// A token cancelled by [Self::shutdown], for work started alongside the
+    /// engine such as
+    /// [scan_local_network_cancellable](crate::api::probes::scan_local_network_cancellable).
+    #[frb(ignore)]
+    pub fn cancellation_token(&self) -> CancellationToken {
+        self.shutdown.child_token()
+    }
+
+    fn reusable_report(&self, state: &EngineState) -> Option<NetworkReport> {
+        let (report, at) = state.last_report.as_ref()?;
+        if state.breaker.state() == CircuitBreakerState::Open {
+            return Some(report.clone());
+        }
+        let at = at.as_ref()?;
+
+        let fresh = at.elapsed().as_millis() < u128::from(self.config.cache_validity_ms);
+        let reusable = match passive_verdict(
+            &state.app_signals,
+            *at,
+            report.status.is_connected,
+            &self.config.resilience,
+            Instant::now(),
+        ) {
+            PassiveVerdict::Neutral => fresh,
+            PassiveVerdict::Confirmed => {
+                fresh
+                    || at.elapsed().as_millis()
+                        < u128::from(self.config.resilience.passive_signal_window_ms)
+            }
+            PassiveVerdict::Contradicted => false,
+        };
+        reusable.then(|| report.clone())
+    }
+
+    /// Tells the engine that a request made by the app itself succeeded.
+    ///
+    /// Successes let a cached "connected" report be reused for longer and make
+    /// a cached "offline" report stale, so the next [Self::check] re-probes.
+    pub fn report_app_success(&self) {
+        self.push_app_signal(None);
+    }
+
+    /// Tells the engine that a request made by the app itself failed.
+    ///
+    /// `error` is a description for diagnostics; only network failures should
+    /// be reported, not HTTP error statuses. After
+    /// [passive_failure_threshold](crate::api::models::ResilienceConfig::passive_failure_threshold)
+    /// consecutive failures a cached "connected" report is no longer reused.
+    pub fn report_app_failure(&self, error: String) {
+        self.push_app_signal(Some(error));
+    }
+
+    /// The error of the most recent app-reported request, if it failed.
+    pub fn last_app_error(&self) -> Option<String> {
+        self.state().app_signals.back()?.error.clone()
+    }
+
+    fn push_app_signal(&self, error: Option<String>) {
+        let mut state = self.state();
+        if state.app_signals.len() == LibConstants::MAX_PASSIVE_SIGNALS {
+            state.app_signals.pop_front();
+        }
+        state.app_signals.push_back(AppSignal {
+            at: Instant::now(),
+            success: error.is_none(),
+            error,
+        });
+    }
+
+    fn record(&self, report: &mut NetworkReport) {
+        let mut state = self.state();
+        report.anomalies = state.anomaly_detector.observe(report);
+        for event in &report.anomalies {
+            if state.anomalies.len() == LibConstants::MAX_PENDING_ANOMALIES {
+                state.anomalies.pop_front();
+            }
+            state.anomalies.push_back(event.clone());
+        }
+
+        state.breaker.record(report);
+        state.last_report = Some((report.clone(), Some(Instant::now())));
+        state.generation += 1;
+        update_health(&mut state.target_health, report);
+        state.history.push(report);
+    }
+
+    /// The most recent report, if any check has completed.
+    pub fn last_report(&self) -> Option<NetworkReport> {
+        self.state().last_report.as_ref().map(|(r, _)| r.clone())
+    }
+
+    /// The retained full reports (at most the last hour), oldest first.
+    pub fn history(&self) -> Vec<NetworkReport> {
+        self.state().history.reports().cloned().collect()
+    }
+
+    /// Hourly and per-minute summaries of the reports that have aged out of
+    /// [Self::history], oldest first. Hours come first, then the minutes of
+    /// the last day.
+    pub fn history_aggregates(&self) -> Vec<HistoryAggregate> {
+        self.state().history.aggregates()
+    }
+
+    /// Buckets the retained history, [Self::history_aggregates] included, by
+    /// local hour and weekday. See
+    /// [profile_time_of_day](crate::api::analysis::time_of_day::profile_time_of_day).
+    pub fn time_of_day_profile(&self, utc_offset_minutes: i32) -> TimeOfDayProfile {
+        let state = self.state();
+        let history: Vec<NetworkReport> = state.history.reports().cloned().collect();
+        profile_time_of_day_with_aggregates(
+            &history,
+            &state.history.aggregates(),
+            utc_offset_minutes,
+        )
+    }
+
+    /// Returns and clears the latency/loss anomalies seen since the last call,
+    /// oldest first. See [AnomalyDetector].
+    pub fn take_anomalies(&self) -> Vec<AnomalyDetected> {
+        self.state().anomalies.drain(..).collect()
+    }
+
+    /// How each configured target fared over the checks so far.
+    pub fn target_health(&self) -> Vec<TargetHealth> {
+        self.state().target_health.clone()
+    }
+
+    /// The current state of this engine's circuit breaker.
+    pub fn circuit_state(&self) -> CircuitBreakerState {
+        self.state().breaker.state()
+    }
+
+    /// Drops the cached report, the history, the anomaly baseline, the target
+    /// health and the circuit breaker state.
+    pub fn reset(&self) {
+        let mut state = self.state();
+        state.last_report = None;
+        state.history.clear();
+        state.app_signals.clear();
+        state.anomaly_detector.reset();
+        state.anomalies.clear();
+        state.breaker = CircuitBreaker::new(&self.config.resilience);
+        state.target_health = initial_health(&self.config);
+    }
+
+    fn state(&self) -> MutexGuard<'_, EngineState> {
+        self.state.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+/// A clean health entry for every configured target.
+fn initial_health(config: &NetworkConfiguration) -> Vec<TargetHealth> {
+    config
+        .targets
+        .iter()
+        .map(|t| TargetHealth {
+            label: t.label.clone(),
+            consecutive_failures: 0,
+            last_success_ms: None,
+            last_failure_ms: None,
+        })
+        .collect()
+}
+
+/// Folds the per-target results of a finished check into `health`.
+/// Targets that were skipped are left untouched.
+fn update_health(health: &mut [TargetHealth], report: &NetworkReport) {
+    for result in report.target_reports.iter().filter(|r| !r.skipped) {
+        let Some(entry) = health.iter_mut().find(|h| h.label == result.label) else {
+            continue;
+        };
+        if result.success {
+            entry.consecutive_failures = 0;
+            entry.last_success_ms = Some(report.timestamp_ms);
+        } else {
+            entry.consecutive_failures = entry.consecutive_failures.saturating_add(1);
+            entry.last_failure_ms = Some(report.timestamp_ms);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn offline_config() -> NetworkConfiguration {
+        // No targets and no optional probes: checks only detect the interfaces
+        // and probe the local gateway, so they finish quickly whatever the
+        // network, but they are not free of network I/O.
+        let mut config = NetworkConfiguration {
+            targets: vec![],
+            cache_validity_ms: 60_000,
+            ..NetworkConfiguration::default()
+        };
+        config.security.detect_dns_hijack = false;
+        config
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_cancels_check_in_flight() {
+        use crate::api::{models::TargetProtocol, testsupport::TestServer};
+
+        // Accepts requests but answers them only after a long delay.
+        let server = TestServer::http().latency_ms(10_000).start().await.unwrap();
+        let mut target = server.target("slow");
+        target.timeout_ms = 10_000;
+        assert_eq!(target.protocol, TargetProtocol::Http);
+        let engine = Arc::new(ReachabilityEngine::new(NetworkConfiguration {
+            targets: vec![target],
+            ..offline_config()
+        }));
+
+        let check = tokio::spawn({
+            let engine = engine.clone();
+            async move { engine.check().await }
+        });
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        let scan = engine.cancellation_token();
+
+        let started = Instant::now();
+        assert!(engine.shutdown(1000).await);
+        assert!(started.elapsed() < Duration::from_secs(1));
+        assert!(check.await.unwrap().cancelled);
+        assert!(scan.is_cancelled());
+        assert!(engine.is_shut_down());
+        assert!(engine.last_report().is_none());
+    }
+
+    #[tokio::test]
+    async fn test_engines_keep_isolated_state() {
+        let a = ReachabilityEngine::with_history_capacity(offline_config(), 2);
+        let b = ReachabilityEngine::new(offline_config());
+
+        let first = a.check().await;
+        let cached = a.check().await;
+        assert_eq!(first.timestamp_ms, cached.timestamp_ms);
+        assert_eq!(a.history().len(), 1);
+
+        a.refresh().await;
+        a.refresh().await;
+        assert_eq!(a.history().len(), 2);
+
+        assert!(b.last_report().is_none());
+        assert!(b.history().is_empty());
+
+        a.reset();
+        assert!(a.last_report().is_none());
+        assert_eq!(a.circuit_state(), CircuitBreakerState::Closed);
+    }
+
+    #[tokio::test]
+    async fn test_rate_limit_holds_across_checks() {
+        use crate::api::{
+            models::{NetworkTarget, TargetProtocol},
+            probes::MockTransport,
+        };
+
+        let web: std::net::SocketAddr = "192.0.2.10:443".parse().unwrap();
+        let mut config = NetworkConfiguration {
+            targets: vec![NetworkTarget::builder("192.0.2.10", TargetProtocol::Tcp)
+                .port(443)
+                .build()],
+            ..offline_config()
+        };
+        config.resilience.num_jitter_samples = 4;
+        config.rate_limit.max_probes_per_second = 4;
+        let engine = ReachabilityEngine::new(config)
+            .with_transport(Arc::new(MockTransport::new().accept_tcp(web)));
+
+        // The first check spends the initial burst; the second has to wait
+        // for the bucket to refill instead of getting a burst of its own.
+        assert!(engine.refresh().await.status.is_connected);
+        let started = Instant::now();
+        assert!(engine.refresh().await.status.is_connected);
+        assert!(started.elapsed() >= Duration::from_millis(500));
+    }
+
+    #[tokio::test]
+    async fn test_stability_model_is_per_engine() {
+        use crate::api::{
+            analysis::StabilityInputs,
+            models::{NetworkTarget, TargetProtocol},
+            probes::MockTransport,
+        };
+
+        #[derive(Debug)]
+        struct Constant;
+
+        impl StabilityModel for Constant {
+            fn score(&self, _: &StabilityInputs<'_>) -> u8 {
+                7
+            }
+        }
+
+        let web: std::net::SocketAddr = "192.0.2.10:443".parse().unwrap();
+        let mut config = NetworkConfiguration {
+            targets: vec![NetworkTarget::builder("192.0.2.10", TargetProtocol::Tcp)
+                .port(443)
+                .build()],
+            ..offline_config()
+        };
+        // A spike on a loaded machine would take points off either score.
+        config.resilience.spike_ratio_threshold = 0.0;
+        let transport = Arc::new(MockTransport::new().accept_tcp(web));
+        let custom = ReachabilityEngine::new(config.clone())
+            .with_transport(transport.clone())
+            .with_stability_model(Arc::new(Constant));
+        let default = ReachabilityEngine::new(config).with_transport(transport);
+
+        let custom = custom.refresh().await.status.latency_stats;
+        let default = default.refresh().await.status.latency_stats;
+        assert_eq!(custom.stability_score, 7);
+        assert!(default.stability_score > 7);
+    }
+
+    #[tokio::test]
+    async fn test_app_success_invalidates_cached_offline_report() {
+        let engine = ReachabilityEngine::new(offline_config());
+        engine.check().await;
+        engine.check().await;
+        assert_eq!(engine.history().len(), 1);
+
+        engine.report_app_failure("connection reset".into());
+        engine.check().await;
+        assert_eq!(engine.history().len(), 1);
+        assert_eq!(engine.last_app_error().as_deref(), Some("connection reset"));
+
+        engine.report_app_success();
+        engine.check().await;
+        assert_eq!(engine.history().len(), 2);
+    }
+
+    #[test]
+    fn test_engine_with_injected_runtime() {
+        let rt = tokio::runtime::Runtime::new().unwrap();
+        let engine = ReachabilityEngine::new(offline_config()).with_runtime(rt.handle().clone());
+
+        let report = futures::executor::block_on(engine.check());
+        assert!(!report.status.is_connected);
+        assert!(engine.last_report().is_some());
+    }
+
+    #[tokio::test]
+    async fn test_snapshot_warm_starts_a_new_engine() {
+        let first = ReachabilityEngine::new(offline_config());
+        let report = first.check().await;
+        let snapshot = first.snapshot();
+        assert_eq!(
+            snapshot.last_report.as_ref().map(|r| r.timestamp_ms),
+            Some(report.timestamp_ms)
+        );
+
+        let second = ReachabilityEngine::new(offline_config()).with_snapshot(snapshot);
+        assert_eq!(
+            second.last_report().map(|r| r.timestamp_ms),
+            Some(report.timestamp_ms)
+        );
+        // Still within the cache validity: served without a new check.
+        assert_eq!(second.check().await.timestamp_ms, report.timestamp_ms);
+        assert!(second.history().is_empty());
+
+        let mut stale = second.snapshot();
+        stale.breaker_consecutive_failures = 3;
+        stale.breaker_opened_at_ms = Some(stale.taken_at_ms);
+        let third = ReachabilityEngine::new(offline_config()).with_snapshot(stale);
+        assert_eq!(third.circuit_state(), CircuitBreakerState::Open);
+        assert_eq!(third.snapshot().breaker_consecutive_failures, 3);
+    }
+
+    #[test]
+    fn test_time_of_day_profile_covers_aggregated_history() {
+        let engine = ReachabilityEngine::new(offline_config());
+        // Three days (starting Monday 2024-01-01 UTC), one report every 5
+        // minutes: all but the last hour is downsampled into aggregates.
+        let monday_ms = 1_704_067_200_000u64;
+        let step_ms = 300_000;
+        for i in 0..(3 * 288) {
+            let mut report = NetworkReport::fixture(true);
+            report.timestamp_ms = monday_ms + i * step_ms;
+            report.monotonic_ms = i * step_ms;
+            let hour = (i / 12) % 24;
+            report.status.latency_stats.latency_ms =
+                if (20..23).contains(&hour) { 300 } else { 40 };
+            engine.state().history.push(&report);
+        }
+        assert_eq!(engine.history().len(), 13);
+
+        let profile = engine.time_of_day_profile(0);
+        assert_eq!(profile.by_weekday[0].reports, 288);
+        assert_eq!(profile.by_hour[21].avg_latency_ms, Some(300));
+        assert_eq!(profile.congestion_windows.len(), 1);
+        let window = &profile.congestion_windows[0];
+        assert_eq!((window.start_hour, window.end_hour), (20, 23));
+        assert_eq!(window.days_observed, 3);
+    }
+
+    #[test]
+    fn test_target_health_tracks_streaks() {
+        let config = NetworkConfiguration::default();
+        let mut health = initial_health(&config);
+        let label = health[0].label.clone();
+
+        let mut report = NetworkReport::fixture(false);
+        let mut result = crate::api::models::TargetReport::fixture(&label, false, 0);
+        report.target_reports = vec![result.clone()];
+        for ms in [1, 2] {
+            report.timestamp_ms = ms;
+            update_health(&mut health, &report);
+        }
+        assert_eq!(health[0].consecutive_failures, 2);
+        assert_eq!(health[0].last_failure_ms, Some(2));
+
+        result.success = true;
+        report.target_reports = vec![result];
+        report.timestamp_ms = 3;
+        update_health(&mut health, &report);
+        assert_eq!(health[0].consecutive_failures, 0);
+        assert_eq!(health[0].last_success_ms, Some(3));
+        assert_eq!(health[1].last_failure_ms, None);
+    }
+}
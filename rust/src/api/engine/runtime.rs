@@ -0,0 +1,133 @@
+//! Control over where probe tasks are executed.
+//!
+//! By default every probe runs on the ambient tokio runtime of the caller. Hosts
+//! with their own runtime topology (a dedicated I/O runtime, a current-thread
+//! runtime on the UI thread, or no tokio at all) can instead hand over a
+//! [Handle] and the probes are spawned there. The resulting future can then be
+//! awaited from any executor:
+//!
+//! ```ignore
+//! let io = tokio::runtime::Runtime::new()?;
+//! let runtime = ProbeRuntime::with_handle(io.handle().clone());
+//! let report = futures::executor::block_on(runtime.run(check_network(config)));
+//! ```
+
+use flutter_rust_bridge::frb;
+use std::{
+    future::Future,
+    pin::Pin,
+    task::{Context, Poll},
+};
+use tokio::{
+    runtime::Handle,
+    task::{JoinError, JoinHandle},
+};
+
+/// Selects the tokio runtime that network probes are spawned on.
+#[frb(ignore)]
+#[derive(Debug, Clone, Default)]
+pub struct ProbeRuntime {
+    handle: Option<Handle>,
+}
+
+impl ProbeRuntime {
+    /// Runs probes inline on whatever runtime polls them (the default).
+    pub fn ambient() -> Self {
+        Self { handle: None }
+    }
+
+    /// Spawns probes onto the runtime behind `handle`.
+    #[frb(ignore)]
+    pub fn with_handle(handle: Handle) -> Self {
+        Self {
+            handle: Some(handle),
+        }
+    }
+
+    /// The injected runtime handle, if any.
+    pub fn handle(&self) -> Option<&Handle> {
+        self.handle.as_ref()
+    }
+
+    /// Drives `future` to completion on the selected runtime.
+    ///
+    /// With an injected handle the future is spawned there and merely awaited
+    /// by the caller, so the caller does not need to be inside a tokio context.
+    /// Dropping the returned future aborts the spawned task.
+    ///
+    /// # Panics
+    ///
+    /// Propagates a panic of the spawned task, and panics if the injected
+    /// runtime shuts down before the task completes.
+    pub async fn run<F>(&self, future: F) -> F::Output
+    where
+        F: Future + Send + 'static,
+        F::Output: Send + 'static,
+    {
+        let Some(handle) = &self.handle else {
+            return future.await;
+        };
+
+        match AbortOnDrop(handle.spawn(future)).await {
+            Ok(output) => output,
+            Err(e) if e.is_panic() => std::panic::resume_unwind(e.into_panic()),
+            Err(e) => panic!("probe task did not complete: {e}"),
+        }
+    }
+}
+
+/// A spawned task that is aborted once its handle is dropped, so it cannot
+/// outlive the future awaiting it.
+#[derive(Debug)]
+pub(crate) struct AbortOnDrop<T>(pub(crate) JoinHandle<T>);
+
+impl<T> Future for AbortOnDrop<T> {
+    type Output = Result<T, JoinError>;
+
+    fn poll(mut self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+        Pin::new(&mut self.0).poll(cx)
+    }
+}
+
+impl<T> Drop for AbortOnDrop<T> {
+    fn drop(&mut self) {
+        self.0.abort();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_run_on_injected_handle_without_ambient_runtime() {
+        let rt = tokio::runtime::Builder::new_multi_thread()
+            .worker_threads(1)
+            .enable_all()
+            .build()
+            .unwrap();
+        let runtime = ProbeRuntime::with_handle(rt.handle().clone());
+
+        // `block_on` from the futures crate provides no tokio context, so the
+        // timer below only works because it is spawned onto `rt`.
+        let value = futures::executor::block_on(runtime.run(async {
+            tokio::time::sleep(std::time::Duration::from_millis(1)).await;
+            42
+        }));
+        assert_eq!(value, 42);
+    }
+
+    #[tokio::test]
+    async fn test_dropped_run_aborts_spawned_task() {
+        let runtime = ProbeRuntime::with_handle(Handle::current());
+        let (tx, rx) = tokio::sync::oneshot::channel::<()>();
+        let run = runtime.run(async move {
+            let _tx = tx;
+            std::future::pending::<()>().await
+        });
+        // Poll once so the task is spawned, then drop the caller's future.
+        let _ = tokio::time::timeout(std::time::Duration::from_millis(10), run).await;
+        // The task was aborted, dropping its end of the channel.
+        assert!(rx.await.is_err());
+    }
+}
@@ -1,39 +1,395 @@
 //! Sampling and result aggregation logic for network probes.
 
+use super::{
+    clock,
+    rate_limit::{estimated_probe_bytes, RateLimiter},
+};
 use crate::api::{
-    models::{CheckStrategy, NetworkConfiguration, TargetReport},
-    probes::check_target,
+    constants::LibConstants,
+    models::{
+        CheckStrategy, NetworkConfiguration, NetworkTarget, SampleMode, SampleRecord,
+        SampleTargetLatency, TargetReport,
+    },
+    probes::{
+        check_target,
+        gateway::probe_gateway,
+        target::{resolve_target, NativeProbe},
+    },
 };
 use futures::future::join_all;
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+use tokio_util::sync::CancellationToken;
 
 /// Collects multiple latency samples by running checks against all configured targets.
+///
+/// Probe traffic is throttled according to [NetworkConfiguration::rate_limit].
 pub async fn collect_network_samples(
     config: &NetworkConfiguration,
 ) -> (Vec<u64>, Vec<TargetReport>) {
-    let mut all_sample_latencies = Vec::new();
-    let mut final_reports = Vec::new();
+    collect_network_samples_limited(config, &RateLimiter::new(&config.rate_limit)).await
+}
+
+/// Same as [collect_network_samples], but draws from an existing [RateLimiter]
+/// so the sampling traffic shares a budget with the other probes of a check.
+///
+/// Samples are scheduled according to the configured [SampleMode].
+pub async fn collect_network_samples_limited(
+    config: &NetworkConfiguration,
+    limiter: &RateLimiter,
+) -> (Vec<u64>, Vec<TargetReport>) {
+    let run = run_samples(config, limiter, &CancellationToken::new(), None).await;
+    (run.latencies, run.final_reports)
+}
 
+/// Everything a check derives from its sampling rounds.
+#[derive(Default)]
+pub(crate) struct SampleRun {
+    /// Best latency of every counted sample.
+    pub latencies: Vec<u64>,
+    /// The target reports of the last sample.
+    pub final_reports: Vec<TargetReport>,
+    /// The winner of every sample; `None` where the sample did not count.
+    pub winners: Vec<Option<String>>,
+    /// Per-sample records; empty unless raw samples were requested.
+    pub records: Vec<SampleRecord>,
+    /// True if sampling was cancelled; only completed samples are included.
+    pub cancelled: bool,
+    /// The gateway's latency in every completed sample it answered; empty
+    /// unless a gateway was sampled.
+    pub gateway_latencies: Vec<u64>,
+}
+
+/// A target address resolved once for the whole check.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct PreResolved {
+    addr: SocketAddr,
+    /// `None` for IP literals.
+    dns_ms: Option<u64>,
+}
+
+/// Runs the configured sampling rounds. With `gateway` set, that address is
+/// probed alongside the targets in every round, so its latencies cover the
+/// same stretch of time as theirs.
+pub(crate) async fn run_samples(
+    config: &NetworkConfiguration,
+    limiter: &RateLimiter,
+    cancel: &CancellationToken,
+    gateway: Option<IpAddr>,
+) -> SampleRun {
     let num_samples = if config.resilience.num_jitter_samples > 1 {
         config.resilience.num_jitter_samples
     } else {
         1
     };
 
-    for sample_num in 0..num_samples {
-        let futures = config.targets.iter().map(check_target);
-        let reports = join_all(futures).await;
+    let resolved = if config.pre_resolve_targets {
+        pre_resolve(config).await
+    } else {
+        vec![None; config.targets.len()]
+    };
+    let resolved = resolved.as_slice();
+    let round = || run_round(config, limiter, resolved, gateway);
 
-        if let Some(best_latency) = analyze_single_sample(&reports, config) {
-            all_sample_latencies.push(best_latency);
+    let samples = match config.resilience.sample_mode {
+        SampleMode::Sequential => {
+            let mut samples = Vec::with_capacity(usize::from(num_samples));
+            for _ in 0..num_samples {
+                tokio::select! {
+                    sample = round() => samples.push(sample),
+                    _ = cancel.cancelled() => break,
+                }
+            }
+            samples
         }
+        SampleMode::Parallel => {
+            let offset = Duration::from_millis(config.resilience.parallel_sample_offset_ms);
+            let staggered = (0..u32::from(num_samples)).map(|i| async move {
+                tokio::select! {
+                    sample = async {
+                        tokio::time::sleep(offset * i).await;
+                        round().await
+                    } => Some(sample),
+                    _ = cancel.cancelled() => None,
+                }
+            });
+            join_all(staggered).await.into_iter().flatten().collect()
+        }
+        SampleMode::Paced(interval_ms) => {
+            let interval = Duration::from_millis(interval_ms);
+            let start = tokio::time::Instant::now();
+            let mut samples = Vec::with_capacity(usize::from(num_samples));
+            for i in 0..u32::from(num_samples) {
+                tokio::select! {
+                    sample = async {
+                        // Slots are fixed from the start, so an overrun does
+                        // not shift the rest of the schedule.
+                        tokio::time::sleep_until(start + interval * i).await;
+                        round().await
+                    } => samples.push(sample),
+                    _ = cancel.cancelled() => break,
+                }
+            }
+            samples
+        }
+    };
+    let cancelled = samples.len() < usize::from(num_samples);
+    let (samples, gateway_latencies): (Vec<_>, Vec<_>) = samples.into_iter().unzip();
+    let gateway_latencies = gateway_latencies.into_iter().flatten().collect();
+
+    let mut latencies = Vec::new();
+    let mut winners = Vec::with_capacity(samples.len());
+    let mut records = Vec::new();
+    for (index, (timestamps, reports)) in samples.iter().enumerate() {
+        let best = analyze_single_sample(reports, config);
+        latencies.extend(best);
+
+        // A sample that did not count has no winner.
+        let winner = best
+            .and_then(|_| select_winner(reports, &config.targets, config.resilience.strategy))
+            .map(|r| r.label.clone());
+        if config.include_raw_samples {
+            records.push(sample_record(
+                index as u32,
+                *timestamps,
+                reports,
+                winner.clone(),
+                best.is_some(),
+            ));
+        }
+        winners.push(winner);
+    }
+
+    // Full reports are kept for the last sample only; the others survive as
+    // per-target latencies. Samples that skipped a target say nothing about
+    // it, so they are left out rather than counted as losses.
+    let per_target: Vec<Vec<Option<u64>>> = (0..config.targets.len())
+        .map(|i| {
+            samples
+                .iter()
+                .filter_map(|(_, reports)| reports.get(i).filter(|r| !r.skipped))
+                .map(|r| r.success.then_some(r.latency_ms))
+                .collect()
+        })
+        .collect();
+    let mut final_reports = samples
+        .into_iter()
+        .last()
+        .map(|(_, reports)| reports)
+        .unwrap_or_default();
+    for (report, samples) in final_reports.iter_mut().zip(per_target) {
+        report.samples = samples;
+    }
+
+    SampleRun {
+        latencies,
+        final_reports,
+        winners,
+        records,
+        cancelled,
+        gateway_latencies,
+    }
+}
+
+/// One sampling round: [run_sample], and the gateway's latency if `gateway`
+/// is set and answered.
+async fn run_round(
+    config: &NetworkConfiguration,
+    limiter: &RateLimiter,
+    resolved: &[Option<PreResolved>],
+    gateway: Option<IpAddr>,
+) -> (((u64, u64), Vec<TargetReport>), Option<u64>) {
+    let gateway = async {
+        let gateway = gateway?;
+        limiter
+            .acquire(LibConstants::ESTIMATED_GATEWAY_SAMPLE_BYTES)
+            .await;
+        let probe = probe_gateway(gateway, LibConstants::DEFAULT_GATEWAY_TIMEOUT_MS).await;
+        probe.success.then_some(probe.latency_ms)
+    };
+    tokio::join!(run_sample(config, limiter, resolved), gateway)
+}
+
+/// Resolves every target once, concurrently, each bounded by its timeout.
+///
+/// Returns `None` for targets that could not be resolved.
+async fn pre_resolve(config: &NetworkConfiguration) -> Vec<Option<PreResolved>> {
+    let lookups = config.targets.iter().map(|target| async move {
+        let start = std::time::Instant::now();
+        let timeout = Duration::from_millis(target.timeout_ms);
+        let addr = tokio::time::timeout(timeout, resolve_target(target))
+            .await
+            .ok()?
+            .ok()?;
+        let is_ip_literal = target.host.parse::<std::net::IpAddr>().is_ok();
+        Some(PreResolved {
+            addr,
+            dns_ms: (!is_ip_literal).then(|| start.elapsed().as_millis() as u64),
+        })
+    });
+    join_all(lookups).await
+}
+
+/// Probes every target once, concurrently; under [CheckStrategy::Fallback]
+/// one priority level at a time instead.
+///
+/// Targets with a pre-resolved address skip DNS resolution. Returns the
+/// wall-clock and monotonic start times and the reports, in target order.
+pub(crate) async fn run_sample(
+    config: &NetworkConfiguration,
+    limiter: &RateLimiter,
+    resolved: &[Option<PreResolved>],
+) -> ((u64, u64), Vec<TargetReport>) {
+    let timestamps = (clock::wall_clock_ms(), clock::monotonic_ms());
+    let probe = |index: usize| async move {
+        let target = &config.targets[index];
+        limiter
+            .acquire(estimated_probe_bytes(target.protocol))
+            .await;
+        match resolved[index] {
+            Some(pre) => {
+                let mut report = NativeProbe {}.check_resolved(target, Some(pre.addr)).await;
+                report.dns_ms = pre.dns_ms;
+                report
+            }
+            None => check_target(target).await,
+        }
+    };
+
+    if config.resilience.strategy != CheckStrategy::Fallback {
+        let reports = join_all((0..config.targets.len()).map(probe)).await;
+        return (timestamps, reports);
+    }
+
+    let mut reports: Vec<Option<TargetReport>> = vec![None; config.targets.len()];
+    for tier in fallback_tiers(&config.targets) {
+        let tier_reports = join_all(tier.iter().map(|&index| probe(index))).await;
+        for (index, report) in tier.into_iter().zip(tier_reports) {
+            reports[index] = Some(report);
+        }
+        if reports.iter().flatten().any(|r| r.success) {
+            break;
+        }
+    }
+    let reports = reports
+        .into_iter()
+        .zip(&config.targets)
+        .map(|(report, target)| report.unwrap_or_else(|| skipped_report(target)))
+        .collect();
+    (timestamps, reports)
+}
+
+/// Groups target indices by ascending priority for [CheckStrategy::Fallback].
+///
+/// Essential targets all join the first group: skipping one would hide the
+/// failure that is meant to invalidate the sample.
+fn fallback_tiers(targets: &[NetworkTarget]) -> Vec<Vec<usize>> {
+    let mut priorities: Vec<u8> = targets.iter().map(|t| t.priority).collect();
+    priorities.sort_unstable();
+    priorities.dedup();
+
+    let mut tiers: Vec<Vec<usize>> = priorities
+        .iter()
+        .map(|&priority| {
+            (0..targets.len())
+                .filter(|&i| targets[i].priority == priority && !targets[i].is_essential)
+                .collect()
+        })
+        .collect();
+    if let Some(first) = tiers.first_mut() {
+        first.extend((0..targets.len()).filter(|&i| targets[i].is_essential));
+        first.sort_unstable();
+    }
+    tiers.retain(|tier| !tier.is_empty());
+    tiers
+}
+
+/// The report of a target that [CheckStrategy::Fallback] did not need to probe.
+fn skipped_report(target: &NetworkTarget) -> TargetReport {
+    TargetReport {
+        skipped: true,
+        ..TargetReport::unprobed(target.label.clone(), target.is_essential)
+    }
+}
+
+fn sample_record(
+    index: u32,
+    (timestamp_ms, monotonic_ms): (u64, u64),
+    reports: &[TargetReport],
+    winner_target: Option<String>,
+    counted: bool,
+) -> SampleRecord {
+    SampleRecord {
+        index,
+        timestamp_ms,
+        monotonic_ms,
+        latencies: reports
+            .iter()
+            .filter(|r| !r.skipped)
+            .map(|r| SampleTargetLatency {
+                label: r.label.clone(),
+                latency_ms: r.success.then_some(r.latency_ms),
+            })
+            .collect(),
+        winner_target,
+        counted,
+    }
+}
 
-        // Only the reports from the very last sample run are stored.
-        if sample_num == num_samples - 1 {
-            final_reports = reports
+/// Picks the target that best represents a sample.
+///
+/// With [CheckStrategy::Race] this is the fastest successful target. With
+/// [CheckStrategy::Consensus] it is the successful target with the median
+/// latency, i.e. the one most representative of the agreeing majority rather
+/// than an outlier that happened to be fast. Ties go to the earlier target.
+/// With [CheckStrategy::Fallback] it is the first successful target, and with
+/// [CheckStrategy::Weighted] the heaviest one, the fastest among equals.
+/// [CheckStrategy::All] and [CheckStrategy::Quorum] pick like Race.
+///
+/// `targets` are the configured targets, in the same order as `reports`.
+pub fn select_winner<'a>(
+    reports: &'a [TargetReport],
+    targets: &[NetworkTarget],
+    strategy: CheckStrategy,
+) -> Option<&'a TargetReport> {
+    let mut successes: Vec<(&TargetReport, u8)> = reports
+        .iter()
+        .zip(targets)
+        .filter(|(r, _)| r.success)
+        .map(|(r, t)| (r, t.weight))
+        .collect();
+    // Stable sorts keep configuration order among equal keys.
+    match strategy {
+        CheckStrategy::Fallback => {}
+        CheckStrategy::Weighted => {
+            successes.sort_by_key(|(r, weight)| (std::cmp::Reverse(*weight), r.latency_ms))
         }
+        _ => successes.sort_by_key(|(r, _)| r.latency_ms),
     }
+    let index = match strategy {
+        CheckStrategy::Consensus => successes.len().saturating_sub(1) / 2,
+        _ => 0,
+    };
+    successes.get(index).map(|(r, _)| *r)
+}
 
-    (all_sample_latencies, final_reports)
+/// Picks the overall winner of a check: the target that won the most samples.
+///
+/// Ties go to the target that won most recently.
+pub fn overall_winner(winners_per_sample: &[Option<String>]) -> Option<String> {
+    let mut best: Option<(&str, usize)> = None;
+    for candidate in winners_per_sample.iter().rev().flatten() {
+        let wins = winners_per_sample
+            .iter()
+            .filter(|w| w.as_deref() == Some(candidate.as_str()))
+            .count();
+        if best.is_none_or(|(_, most)| wins > most) {
+            best = Some((candidate, wins));
+        }
+    }
+    best.map(|(label, _)| label.to_string())
 }
 
 /// Analyzes the results of a single sample run across all targets.
@@ -64,11 +420,22 @@ pub fn analyze_single_sample(
         return None;
     }
 
+    let total = config.targets.len();
     let is_sample_successful = match config.resilience.strategy {
-        CheckStrategy::Race => success_count > 0,
-        CheckStrategy::Consensus => {
-            let total = config.targets.len();
-            success_count >= (total / 2)
+        CheckStrategy::Race | CheckStrategy::Fallback => success_count > 0,
+        CheckStrategy::Consensus => success_count >= (total / 2),
+        CheckStrategy::All => success_count > 0 && success_count == total,
+        CheckStrategy::Quorum(quorum) => success_count >= usize::from(quorum).max(1),
+        CheckStrategy::Weighted => {
+            let (score, total_weight) = reports.iter().zip(&config.targets).fold(
+                (0u32, 0u32),
+                |(score, total_weight), (report, target)| {
+                    let weight = u32::from(target.weight);
+                    let gained = if report.success { weight } else { 0 };
+                    (score + gained, total_weight + weight)
+                },
+            );
+            total_weight > 0 && score > 0 && score * 2 >= total_weight
         }
     };
 
@@ -82,52 +449,304 @@ pub fn analyze_single_sample(
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::api::models::{NetworkConfiguration, TargetReport};
+    use crate::api::models::{NetworkConfiguration, NetworkTarget, TargetProtocol, TargetReport};
+    use tokio::io::AsyncWriteExt;
 
     #[test]
     fn test_analyze_single_sample_logic() {
         let config = NetworkConfiguration::default();
-        let reports = vec![
-            TargetReport {
-                label: "A".into(),
-                success: true,
-                latency_ms: 100,
-                error: None,
-                is_essential: false,
-            },
-            TargetReport {
-                label: "B".into(),
-                success: false,
-                latency_ms: 0,
-                error: Some("fail".into()),
-                is_essential: false,
-            },
-        ];
+        let mut failed = TargetReport::fixture("B", false, 0);
+        failed.error = Some("fail".into());
+        let reports = vec![TargetReport::fixture("A", true, 100), failed.clone()];
 
         // Race strategy: one success is enough
         let res = analyze_single_sample(&reports, &config);
         assert_eq!(res, Some(100));
 
         // Essential failed
-        let reports_essential_fail = vec![
-            TargetReport {
-                label: "A".into(),
-                success: true,
-                latency_ms: 100,
-                error: None,
-                is_essential: false,
-            },
-            TargetReport {
-                label: "B".into(),
-                success: false,
-                latency_ms: 0,
-                error: Some("fail".into()),
-                is_essential: true,
-            },
-        ];
+        failed.is_essential = true;
+        let reports_essential_fail = vec![TargetReport::fixture("A", true, 100), failed];
         assert_eq!(
             analyze_single_sample(&reports_essential_fail, &config),
             None
         );
     }
+
+    #[tokio::test]
+    async fn test_parallel_sample_mode_collects_every_sample() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let _ = socket.write_all(b"ok").await;
+            }
+        });
+
+        let mut config = NetworkConfiguration {
+            targets: vec![NetworkTarget::builder("127.0.0.1", TargetProtocol::Tcp)
+                .label("local")
+                .port(port)
+                .timeout_ms(1000)
+                .build()],
+            ..NetworkConfiguration::default()
+        };
+        config.resilience.num_jitter_samples = 6;
+        config.resilience.sample_mode = SampleMode::Parallel;
+        config.resilience.parallel_sample_offset_ms = 1;
+
+        config.include_raw_samples = true;
+
+        let run = run_samples(
+            &config,
+            &RateLimiter::new(&config.rate_limit),
+            &CancellationToken::new(),
+            None,
+        )
+        .await;
+        assert_eq!(run.latencies.len(), 6);
+        assert_eq!(run.final_reports.len(), 1);
+        assert!(run.final_reports[0].success);
+
+        assert_eq!(run.final_reports[0].samples.len(), 6);
+        assert!(run.final_reports[0].samples.iter().all(Option::is_some));
+
+        assert_eq!(run.records.len(), 6);
+        assert_eq!(run.records[5].index, 5);
+        assert!(run.records.iter().all(|r| r.counted));
+        assert_eq!(run.records[0].winner_target.as_deref(), Some("local"));
+        assert_eq!(run.winners.len(), 6);
+        assert!(run.records[0].latencies[0].latency_ms.is_some());
+
+        config.resilience.num_jitter_samples = 3;
+        config.resilience.sample_mode = SampleMode::Paced(40);
+        let run = run_samples(
+            &config,
+            &RateLimiter::new(&config.rate_limit),
+            &CancellationToken::new(),
+            None,
+        )
+        .await;
+        assert_eq!(run.latencies.len(), 3);
+        assert!(run
+            .records
+            .windows(2)
+            .all(|w| w[1].monotonic_ms >= w[0].monotonic_ms + 35));
+    }
+
+    #[tokio::test]
+    async fn test_pre_resolved_targets_reuse_one_lookup() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let _ = socket.write_all(b"ok").await;
+            }
+        });
+
+        let target = |label: &str, host: &str| {
+            NetworkTarget::builder(host, TargetProtocol::Tcp)
+                .label(label)
+                .port(port)
+                .timeout_ms(1000)
+                .build()
+        };
+        let mut config = NetworkConfiguration {
+            targets: vec![
+                target("name", "127.0.0.1.invalid"),
+                target("ip", "127.0.0.1"),
+            ],
+            pre_resolve_targets: true,
+            ..NetworkConfiguration::default()
+        };
+        config.resilience.num_jitter_samples = 3;
+
+        let resolved = pre_resolve(&config).await;
+        assert!(resolved[0].is_none());
+        assert!(resolved[1].is_some_and(|r| r.dns_ms.is_none()));
+
+        config.targets[0].host = "localhost".into();
+        let run = run_samples(
+            &config,
+            &RateLimiter::new(&config.rate_limit),
+            &CancellationToken::new(),
+            None,
+        )
+        .await;
+        assert_eq!(run.latencies.len(), 3);
+        assert!(run.final_reports.iter().all(|r| r.success));
+        assert!(run.final_reports[0].dns_ms.is_some());
+        assert_eq!(run.final_reports[1].dns_ms, None);
+    }
+
+    fn ok(label: &str, latency_ms: u64) -> TargetReport {
+        TargetReport::fixture(label, true, latency_ms)
+    }
+
+    #[test]
+    fn test_sample_verdict_per_strategy() {
+        let mut config = NetworkConfiguration::default();
+        let mut failed = ok("down", 0);
+        failed.success = false;
+        // Three of the four default targets answer.
+        let reports = vec![ok("a", 30), failed, ok("b", 20), ok("c", 10)];
+        let verdict = |config: &NetworkConfiguration| analyze_single_sample(&reports, config);
+
+        config.resilience.strategy = CheckStrategy::All;
+        assert_eq!(verdict(&config), None);
+        config.resilience.strategy = CheckStrategy::Quorum(3);
+        assert_eq!(verdict(&config), Some(10));
+        config.resilience.strategy = CheckStrategy::Quorum(4);
+        assert_eq!(verdict(&config), None);
+
+        config.resilience.strategy = CheckStrategy::Weighted;
+        config.targets[1].weight = 3;
+        assert_eq!(verdict(&config), Some(10));
+        config.targets[1].weight = 4;
+        assert_eq!(verdict(&config), None);
+    }
+
+    #[test]
+    fn test_winner_selection_per_strategy() {
+        let mut failed = ok("down", 0);
+        failed.success = false;
+        let reports = vec![failed, ok("slow", 90), ok("fast", 10), ok("mid", 40)];
+        let mut targets = NetworkConfiguration::default().targets;
+        targets[3].weight = 2;
+
+        assert_eq!(
+            select_winner(&reports, &targets, CheckStrategy::Race)
+                .unwrap()
+                .label,
+            "fast"
+        );
+        assert_eq!(
+            select_winner(&reports, &targets, CheckStrategy::Consensus)
+                .unwrap()
+                .label,
+            "mid"
+        );
+        assert_eq!(
+            select_winner(&reports, &targets, CheckStrategy::Fallback)
+                .unwrap()
+                .label,
+            "slow"
+        );
+        assert_eq!(
+            select_winner(&reports, &targets, CheckStrategy::Weighted)
+                .unwrap()
+                .label,
+            "mid"
+        );
+        assert!(select_winner(&reports[..1], &targets, CheckStrategy::Race).is_none());
+
+        let winners = vec![
+            Some("a".to_string()),
+            Some("b".to_string()),
+            None,
+            Some("b".to_string()),
+            Some("a".to_string()),
+        ];
+        assert_eq!(overall_winner(&winners).as_deref(), Some("a"));
+        assert_eq!(overall_winner(&winners[..4]).as_deref(), Some("b"));
+        assert_eq!(overall_winner(&[None]), None);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_probes_lower_priorities_only_when_needed() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let _ = socket.write_all(b"ok").await;
+            }
+        });
+
+        let target = |label: &str, priority: u8, is_essential: bool| {
+            NetworkTarget::builder("127.0.0.1", TargetProtocol::Tcp)
+                .label(label)
+                .port(port)
+                .timeout_ms(1000)
+                .priority(priority)
+                .essential(is_essential)
+                .build()
+        };
+        let mut config = NetworkConfiguration {
+            targets: vec![
+                target("backup", 2, false),
+                target("primary", 1, false),
+                target("core", 3, true),
+                target("last", 3, false),
+            ],
+            ..NetworkConfiguration::default()
+        };
+        config.resilience.strategy = CheckStrategy::Fallback;
+        assert_eq!(
+            fallback_tiers(&config.targets),
+            vec![vec![1, 2], vec![0], vec![3]]
+        );
+
+        let limiter = RateLimiter::new(&config.rate_limit);
+        let (_, reports) = run_sample(&config, &limiter, &[None; 4]).await;
+        let skipped: Vec<bool> = reports.iter().map(|r| r.skipped).collect();
+        assert_eq!(skipped, [true, false, false, true]);
+        assert_eq!(
+            select_winner(&reports, &config.targets, CheckStrategy::Fallback)
+                .unwrap()
+                .label,
+            "primary"
+        );
+
+        // With the whole first tier down, the backup tier is probed too.
+        config.targets[1].port = 1;
+        config.targets[2].port = 1;
+        let (_, reports) = run_sample(&config, &limiter, &[None; 4]).await;
+        let skipped: Vec<bool> = reports.iter().map(|r| r.skipped).collect();
+        assert_eq!(skipped, [false, false, false, true]);
+        assert!(reports[0].success && !reports[1].success);
+    }
+
+    #[tokio::test]
+    async fn test_fallback_skips_do_not_count_as_loss() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((mut socket, _)) = listener.accept().await {
+                let _ = socket.write_all(b"ok").await;
+            }
+        });
+
+        let target = |label: &str, priority: u8| {
+            NetworkTarget::builder("127.0.0.1", TargetProtocol::Tcp)
+                .label(label)
+                .port(port)
+                .priority(priority)
+                .build()
+        };
+        let mut config = NetworkConfiguration {
+            targets: vec![target("primary", 1), target("backup", 2)],
+            include_raw_samples: true,
+            ..NetworkConfiguration::default()
+        };
+        config.resilience.strategy = CheckStrategy::Fallback;
+        config.resilience.num_jitter_samples = 3;
+
+        let run = run_samples(
+            &config,
+            &RateLimiter::new(&config.rate_limit),
+            &CancellationToken::new(),
+            None,
+        )
+        .await;
+        let loss = |samples: &[Option<u64>]| samples.iter().filter(|s| s.is_none()).count();
+        let (primary, backup) = (&run.final_reports[0], &run.final_reports[1]);
+        assert_eq!(primary.samples.len(), 3);
+        assert_eq!(loss(&primary.samples), 0);
+        assert!(backup.skipped);
+        assert!(backup.samples.is_empty());
+        assert_eq!(loss(&backup.samples), 0);
+        assert!(run
+            .records
+            .iter()
+            .all(|r| r.latencies.iter().all(|l| l.label == "primary")));
+    }
 }
@@ -0,0 +1,72 @@
+//! Timing helpers for periodic checks.
+
+use crate::api::models::NetworkConfiguration;
+use rand::Rng;
+
+/// Returns the delay (ms) to wait before the next periodic check.
+///
+/// Applies [NetworkConfiguration::check_interval_jitter_percent] to
+/// [NetworkConfiguration::check_interval_ms]. Returns 0 if periodic checks are
+/// disabled.
+pub fn next_check_delay_ms(config: &NetworkConfiguration) -> u64 {
+    jittered_interval_ms(
+        config.check_interval_ms,
+        config.check_interval_jitter_percent,
+    )
+}
+
+/// Spreads `interval_ms` uniformly over `interval_ms ± jitter_percent%`.
+///
+/// An interval of 0 is returned unchanged so that "disabled" stays disabled;
+/// any other interval jitters to at least 1 ms.
+pub fn jittered_interval_ms(interval_ms: u64, jitter_percent: u8) -> u64 {
+    jittered_interval_with(&mut rand::rng(), interval_ms, jitter_percent)
+}
+
+fn jittered_interval_with(rng: &mut impl Rng, interval_ms: u64, jitter_percent: u8) -> u64 {
+    let spread = interval_ms * u64::from(jitter_percent.min(100)) / 100;
+    if interval_ms == 0 || spread == 0 {
+        return interval_ms;
+    }
+
+    rng.random_range(interval_ms - spread..=interval_ms + spread)
+        .max(1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    #[test]
+    fn test_jitter_stays_within_bounds() {
+        let mut rng = rand::rng();
+        let samples: Vec<u64> = (0..1000)
+            .map(|_| jittered_interval_with(&mut rng, 5000, 10))
+            .collect();
+
+        assert!(samples.iter().all(|d| (4500..=5500).contains(d)));
+        // The whole point is to spread the load, so the values must not all agree.
+        assert!(samples.iter().any(|&d| d != samples[0]));
+    }
+
+    #[test]
+    fn test_jitter_disabled_or_interval_disabled() {
+        assert_eq!(jittered_interval_ms(5000, 0), 5000);
+        assert_eq!(jittered_interval_ms(0, 50), 0);
+        // Clamped to 100%, so the delay never underflows.
+        assert!(jittered_interval_ms(1000, 250) <= 2000);
+    }
+
+    #[test]
+    fn test_full_jitter_never_disables_the_interval() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let samples: Vec<u64> = (0..1000)
+            .map(|_| jittered_interval_with(&mut rng, 2, 100))
+            .collect();
+
+        // 0..=4 is drawn, but the lower bound is clamped to 1.
+        assert!(samples.iter().all(|d| (1..=4).contains(d)));
+        assert!(samples.contains(&1));
+    }
+}
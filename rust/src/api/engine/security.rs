@@ -1,7 +1,10 @@
 //! High-level security check orchestration.
 
 use crate::api::{
-    models::{NetworkConfiguration, SecurityFlagsResult},
+    models::{
+        NetworkConfiguration, NetworkError, NetworkReport, PolicyViolation, SecurityConfig,
+        SecurityFlagsResult,
+    },
     probes,
 };
 
@@ -21,8 +24,84 @@ pub async fn perform_dns_security_check(
         .or_else(|| config.targets.first());
 
     if let Some(target) = target_to_check {
-        if probes::detect_dns_hijacking(&target.host).await {
-            flags.is_dns_spoofed = true;
-        }
+        let (is_spoofed, evidence) =
+            probes::assess_dns_hijacking_with(&target.host, &config.endpoints.trusted_dns_servers)
+                .await;
+        flags.is_dns_spoofed = is_spoofed;
+        flags.dns_spoof_evidence = evidence;
+    }
+}
+
+/// Runs [check_network](super::check_network) and enforces
+/// [NetworkConfiguration::security] on the result.
+///
+/// Returns the report if the connection complies, or
+/// [NetworkError::PolicyViolation] naming the first broken rule: a VPN while
+/// [block_vpn](SecurityConfig::block_vpn) is set, an active interface outside
+/// [allowed_interfaces](SecurityConfig::allowed_interfaces), or DNS answers
+/// flagged as spoofed. The last needs
+/// [detect_dns_hijack](SecurityConfig::detect_dns_hijack) to be set.
+pub async fn guard(config: NetworkConfiguration) -> Result<NetworkReport, NetworkError> {
+    let security = config.security.clone();
+    let report = super::check_network(config).await;
+    enforce_policy(&security, &report.security_flags_result)?;
+    Ok(report)
+}
+
+/// Checks `flags` against the rules of `policy`.
+fn enforce_policy(
+    policy: &SecurityConfig,
+    flags: &SecurityFlagsResult,
+) -> Result<(), NetworkError> {
+    let violation = if policy.block_vpn && flags.is_vpn_detected {
+        Some(PolicyViolation::VpnDetected)
+    } else if !policy.allowed_interfaces.is_empty()
+        && !policy.allowed_interfaces.contains(&flags.interface_name)
+    {
+        Some(PolicyViolation::InterfaceNotAllowed(
+            flags.interface_name.clone(),
+        ))
+    } else if flags.is_dns_spoofed {
+        Some(PolicyViolation::DnsSpoofed)
+    } else {
+        None
+    };
+    violation.map_or(Ok(()), |v| Err(NetworkError::PolicyViolation(v)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_enforce_policy() {
+        let mut policy = SecurityConfig::default();
+        let mut flags = SecurityFlagsResult {
+            is_vpn_detected: true,
+            interface_name: "tun0".into(),
+            ..SecurityFlagsResult::default()
+        };
+        assert!(enforce_policy(&policy, &flags).is_ok());
+
+        policy.block_vpn = true;
+        assert!(matches!(
+            enforce_policy(&policy, &flags),
+            Err(NetworkError::PolicyViolation(PolicyViolation::VpnDetected))
+        ));
+
+        policy.block_vpn = false;
+        policy.allowed_interfaces = vec!["wlan0".into()];
+        assert!(matches!(
+            enforce_policy(&policy, &flags),
+            Err(NetworkError::PolicyViolation(PolicyViolation::InterfaceNotAllowed(name))) if name == "tun0"
+        ));
+
+        flags.interface_name = "wlan0".into();
+        assert!(enforce_policy(&policy, &flags).is_ok());
+        flags.is_dns_spoofed = true;
+        assert!(matches!(
+            enforce_policy(&policy, &flags),
+            Err(NetworkError::PolicyViolation(PolicyViolation::DnsSpoofed))
+        ));
     }
 }
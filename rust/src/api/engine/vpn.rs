@@ -0,0 +1,53 @@
+//! VPN before/after comparison orchestration.
+
+use super::check_network;
+use crate::api::{
+    analysis::compare_vpn_reports,
+    constants::LibConstants,
+    models::{NetworkConfiguration, VpnComparison},
+    probes::{detect_security_and_network_type, system_dns_resolvers},
+};
+use std::time::{Duration, Instant};
+
+/// Captures a baseline report, waits for the VPN state to flip, then captures
+/// a second report and compares the two.
+///
+/// The function polls the interface table every
+/// [LibConstants::DEFAULT_VPN_TOGGLE_POLL_MS] until the VPN state differs from
+/// the baseline or `wait_timeout_ms` elapses. In the latter case the second
+/// report is still captured, and [VpnComparison::toggle_observed] is `false`.
+pub async fn compare_vpn_toggle(
+    config: NetworkConfiguration,
+    wait_timeout_ms: u64,
+) -> VpnComparison {
+    let resolvers_before = system_dns_resolvers();
+    let before = check_network(config.clone()).await;
+
+    wait_for_vpn_toggle(
+        before.security_flags_result.is_vpn_detected,
+        Duration::from_millis(wait_timeout_ms),
+    )
+    .await;
+
+    let resolvers_after = system_dns_resolvers();
+    let after = check_network(config).await;
+
+    compare_vpn_reports(&before, &after, &resolvers_before, &resolvers_after)
+}
+
+/// Waits until the detected VPN state differs from `vpn_before`, or until `timeout`.
+///
+/// Returns true if a toggle was observed.
+async fn wait_for_vpn_toggle(vpn_before: bool, timeout: Duration) -> bool {
+    let deadline = Instant::now() + timeout;
+    let poll = Duration::from_millis(LibConstants::DEFAULT_VPN_TOGGLE_POLL_MS);
+
+    while Instant::now() < deadline {
+        let (flags, _) = detect_security_and_network_type();
+        if flags.is_vpn_detected != vpn_before {
+            return true;
+        }
+        tokio::time::sleep(poll.min(deadline.saturating_duration_since(Instant::now()))).await;
+    }
+    false
+}
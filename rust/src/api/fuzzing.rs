@@ -0,0 +1,116 @@
+//! Entry points into the parsers of untrusted input, for the cargo-fuzz
+//! targets in `fuzz/`.
+//!
+//! Only compiled with the `fuzzing` feature. Each function hands the input to
+//! every parser of its kind and discards the results; a panic, overflow or
+//! runaway allocation is a bug in the parser:
+//!
+//! ```text
+//! cd rust && cargo +nightly fuzz run packets
+//! ```
+
+use crate::api::{
+    models::UdpValidation,
+    probes::{
+        banner, captive_portal, gateway, isp_status, lldp, local_scan::IpRange, mdns, names,
+        one_way_delay, port_check, reflection, udp, upnp,
+    },
+};
+
+/// Binary replies: LLDP/CDP frames, DNS, mDNS, LLMNR and NetBIOS answers, UDP
+/// probe replies, SNMP responses and netlink route replies.
+pub fn parse_packet(data: &[u8]) {
+    let id = data
+        .get(..2)
+        .map_or(0, |id| u16::from_be_bytes([id[0], id[1]]));
+    let _ = lldp::parse_frame(data);
+    let _ = names::parse_ptr_answer(data, id);
+    let _ = names::parse_node_status(data, id);
+    let _ = udp::read_dns_name(data, 12);
+    let _ = mdns::parse_response(data);
+    for validation in [
+        UdpValidation::AnyResponse,
+        UdpValidation::Dns,
+        UdpValidation::Ntp,
+        UdpValidation::Quic,
+    ] {
+        let request = udp::build_request(validation);
+        let _ = udp::validate_reply(validation, &request, data);
+    }
+    #[cfg(feature = "snmp")]
+    let _ = crate::api::probes::snmp::parse_response(data, i64::from(id));
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    let _ = crate::api::probes::netlink::parse_reply(data);
+}
+
+/// Text received over the network: service banners, reflection and
+/// timestamp server replies, HTTP headers and bodies, neighbor tables.
+pub fn parse_text(data: &[u8]) {
+    for port in [22, 80, 554] {
+        let _ = banner::identify(port, data);
+    }
+    let _ = reflection::parse_reply(data, "00ff");
+    let _ = reflection::parse_connect_reply(data, "00ff");
+    let _ = one_way_delay::parse_reply(data, "00ff");
+
+    let text = String::from_utf8_lossy(data);
+    let mut portal = captive_portal::no_portal();
+    captive_portal::describe_login_page(&mut portal, &text);
+    let _ = gateway::html_title(&text);
+    let _ = isp_status::classify_status_body(&text);
+    let _ = port_check::parse_verdict(&text);
+    let _ = upnp::header(&text, "location");
+    let _ = upnp::parse_description(&text);
+    let _ = crate::api::probes::arp::parse_proc_net_arp(&text);
+    let _ = crate::api::probes::arp::parse_bsd_arp(&text);
+    let _ = IpRange::parse(&text);
+}
+
+/// Configuration files, as JSON and TOML, including their validation.
+#[cfg(feature = "serde")]
+pub fn parse_config(data: &[u8]) {
+    use crate::api::models::NetworkConfiguration;
+
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    if let Ok(config) = NetworkConfiguration::from_json(text) {
+        let _ = config.validate();
+    }
+    if let Ok(config) = toml::from_str::<NetworkConfiguration>(text) {
+        let _ = config.validate();
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parsers_survive_truncated_and_mangled_input() {
+        // A CDP frame and an LLMNR answer, cut at every length and with every
+        // byte flipped in turn.
+        let mut cdp = vec![0x01, 0x00, 0x0c, 0xcc, 0xcc, 0xcc, 0, 1, 2, 3, 4, 5, 0, 40];
+        cdp.extend_from_slice(&[0xaa, 0xaa, 0x03, 0x00, 0x00, 0x0c, 0x20, 0x00]);
+        cdp.extend_from_slice(&[2, 180, 0, 0, 0x00, 0x01, 0x00, 0x08, b's', b'w', b'0', b'1']);
+        let mut llmnr = udp::build_dns_query(0x1234, "20.1.168.192.in-addr.arpa", 12);
+        llmnr[2] = 0x80;
+        llmnr[7] = 1;
+        llmnr.extend_from_slice(&[0xc0, 12, 0, 12, 0, 1, 0, 0, 0, 30, 0, 4, 2, b'p', b'c', 0]);
+
+        for sample in [&cdp, &llmnr] {
+            for len in 0..=sample.len() {
+                parse_packet(&sample[..len]);
+                parse_text(&sample[..len]);
+            }
+            for i in 0..sample.len() {
+                let mut mangled = sample.clone();
+                mangled[i] ^= 0xff;
+                parse_packet(&mangled);
+            }
+        }
+        // A name that points at itself.
+        assert_eq!(udp::read_dns_name(&[0xc0, 0x00], 0), None);
+        parse_text("<title>\u{212a}</title><input".as_bytes());
+    }
+}
@@ -2,25 +2,58 @@
 
 /// Network analysis and statistical tools.
 pub mod analysis;
+/// Loopback fixtures and hot loops for the criterion benchmarks.
+#[cfg(feature = "bench")]
+pub mod bench;
+/// Synchronous wrappers for callers without an async runtime.
+pub mod blocking;
 /// Internal constants used by the engine.
 pub mod constants;
 /// The core network reachability engine and check logic.
 pub mod engine;
+/// Entry points into the parsers of untrusted input for the fuzz targets.
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod fuzzing;
 /// Data structures for configuration, reports, and status.
 pub mod models;
+/// Periodic monitoring on the configured check interval.
+pub mod monitor;
 /// Individual network probes for DNS, captive portals, etc.
 pub mod probes;
+/// Local TCP, UDP and HTTP servers to point targets at in tests.
+#[cfg(any(test, feature = "testsupport"))]
+pub mod testsupport;
 
 // --- Public API Re-exports ---
 
 // Key functions
-pub use engine::check_network;
+pub use engine::{
+    check_network,
+    circuit_breaker::CircuitBreakerState,
+    environment::environment_report,
+    hints::{clear_platform_hints, set_platform_hints},
+    inventory::DeviceInventory,
+    local::check_local_network,
+    pipeline::{CheckPipeline, PipelineStep, StepCondition},
+    preflight::preflight,
+    reachability::ReachabilityEngine,
+    runtime::ProbeRuntime,
+    vpn::compare_vpn_toggle,
+};
+pub use monitor::{MonitorState, MonitorStats, NetworkMonitor};
 
 // Core data structures
 pub use models::{
-    CheckStrategy, ConnectionQuality, NetworkConfiguration, NetworkReport, NetworkStatus,
-    NetworkTarget, QualityThresholds, ResilienceConfig, SecurityConfig, TargetProtocol,
+    CheckStrategy, Confidence, ConnectionQuality, DeviceEvent, EnvironmentReport, Evidence,
+    EvidenceSource, Limitation, LocalDevice, LocalReachabilityReport, LocalScanConfig,
+    NetworkConfiguration, NetworkReport, NetworkScope, NetworkStatus, NetworkTarget, OverlapPolicy,
+    PlatformHints, PreflightReport, ProbeCapabilities, ProbeFallback, QualityThresholds,
+    RateLimitConfig, Requirements, ResilienceConfig, RuntimeEnvironment, SampleMode, SampleRecord,
+    SecurityConfig, TargetProtocol, UdpValidation, UnmetRequirement,
 };
 
 // Optional, for advanced use
-pub use probes::check_for_captive_portal;
+pub use probes::{check_for_captive_portal, scan_local_network, trace_route};
+
+// Aborts long-running operations, e.g. [engine::check_network_cancellable].
+pub use tokio_util::sync::CancellationToken;
@@ -3,14 +3,20 @@
 //! This module contains the core configuration types that control how the engine
 //! performs network checks, evaluates quality, and handles failures.
 
-use super::target::{NetworkTarget, TargetProtocol};
+use super::{
+    error::{ConfigError, ValidationIssue},
+    target::{is_valid_dns_name, NetworkTarget, TargetProtocol, UdpValidation},
+};
 use crate::api::constants::LibConstants;
+use flutter_rust_bridge::frb;
+use std::net::SocketAddr;
 
 /// Defines the strategy used when evaluating multiple network targets during a check cycle.
 ///
 /// When the engine is configured with multiple targets, the `CheckStrategy` determines
 /// the logic for deciding the overall "connected" status of the network.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum CheckStrategy {
     /// The first target to respond successfully determines the result.
     ///
@@ -37,6 +43,120 @@ pub enum CheckStrategy {
     /// servers (e.g., a specific DNS provider being down). It's best for critical
     /// applications that require high confidence in the network's reliability.
     Consensus,
+
+    /// Targets are probed in [priority](super::target::NetworkTarget::priority)
+    /// order, and lower-priority targets only if all higher ones failed.
+    ///
+    /// # Behavior
+    /// Each sample probes the targets with the lowest priority number (plus
+    /// every essential target) concurrently. Only if none of them succeeds
+    /// does it move on to the next priority level. Targets that were not
+    /// needed are reported as [skipped](super::report::TargetReport::skipped).
+    /// One success is enough, and the winner is the first successful target
+    /// in configuration order.
+    ///
+    /// # Use Case
+    /// Primary/backup setups, e.g. a company endpoint with public fallbacks:
+    /// the backups cause no traffic while the primary is up, and the winner
+    /// does not flip between targets of similar latency.
+    Fallback,
+
+    /// Every target must respond successfully.
+    ///
+    /// # Behavior
+    /// A sample only counts if no target failed, so a single unreachable
+    /// endpoint is reported as packet loss.
+    ///
+    /// # Use Case
+    /// Apps that depend on several services at once (e.g. an API, its auth
+    /// server and a CDN) and are only usable when all of them are.
+    All,
+
+    /// At least this many targets must respond successfully.
+    ///
+    /// # Behavior
+    /// Like [`Consensus`](Self::Consensus), but with an explicit count instead
+    /// of a majority. Must be between 1 and the number of targets.
+    ///
+    /// # Use Case
+    /// Fine-tuning robustness, e.g. "any 2 of these 5 resolvers".
+    Quorum(u8),
+
+    /// Successful targets add their [weight](super::target::NetworkTarget::weight)
+    /// to a score, which must reach half of the total weight.
+    ///
+    /// # Behavior
+    /// A target of weight 3 counts as much as three targets of weight 1, and
+    /// a target of weight 0 never affects the verdict. The winner is the
+    /// heaviest successful target, the faster one on ties.
+    ///
+    /// # Use Case
+    /// Mixed target sets where some endpoints matter more than others, e.g.
+    /// the app's own backend next to generic public targets.
+    Weighted,
+}
+
+/// Controls how the jitter samples of a check are scheduled.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SampleMode {
+    /// Each sample starts after the previous one finished.
+    ///
+    /// The check takes roughly `num_jitter_samples` round trips, but every
+    /// sample sees an otherwise idle path, which gives the most faithful
+    /// jitter figures.
+    Sequential,
+
+    /// All samples run concurrently, each started
+    /// [parallel_sample_offset_ms](ResilienceConfig::parallel_sample_offset_ms)
+    /// after the previous one.
+    ///
+    /// A 10-sample check completes in about one round-trip window. The samples
+    /// compete with each other for the path, so jitter is less precise; use it
+    /// when a quick answer matters more than latency fidelity.
+    Parallel,
+
+    /// Samples start this many milliseconds apart, but never overlap.
+    ///
+    /// The schedule is fixed from the start of the check: a sample that
+    /// finishes early waits for its slot, one that overruns delays the next
+    /// sample until it finished. Spreading samples over a known window
+    /// catches periodic disturbances (e.g. Wi-Fi scans) that back-to-back
+    /// samples miss, while each sample still has the path to itself.
+    Paced(u64),
+}
+
+/// What a [NetworkMonitor](crate::api::monitor::NetworkMonitor) does when a
+/// check is still running at the time the next one is due.
+///
+/// Checks never overlap; the policy only decides what happens to the cycles
+/// that came due in the meantime. Every cycle that does not get a check of
+/// its own is counted in [MonitorStats::skipped_cycles](crate::api::monitor::MonitorStats::skipped_cycles).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum OverlapPolicy {
+    /// Drops the overdue cycles and waits for the next regular one.
+    ///
+    /// Keeps the configured rate even on a slow network, at the cost of
+    /// stale reports for up to one extra interval.
+    Skip,
+
+    /// Runs every overdue cycle, back to back, as soon as the check ends.
+    ///
+    /// At most [MONITOR_MAX_QUEUED_CHECKS](LibConstants::MONITOR_MAX_QUEUED_CHECKS)
+    /// cycles are kept pending, so a network that stays slow cannot build
+    /// up an unbounded backlog.
+    Queue,
+
+    /// Merges all overdue cycles into one check that starts right away.
+    Coalesce,
+}
+
+impl Default for OverlapPolicy {
+    /// Returns [`OverlapPolicy::Skip`] as the default.
+    fn default() -> Self {
+        Self::Skip
+    }
 }
 
 /// Represents the perceived quality of the network connection based on latency, jitter, and stability.
@@ -44,6 +164,7 @@ pub enum CheckStrategy {
 /// The engine maps raw metrics (like RTT in milliseconds) to these categories using
 /// the thresholds defined in [`QualityThresholds`].
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConnectionQuality {
     /// Excellent connection with very low latency and high stability.
     ///
@@ -107,6 +228,8 @@ pub enum ConnectionQuality {
 /// These values act as the "buckets" that convert raw Round-Trip Time (RTT) values
 /// into user-friendly quality ratings.
 #[derive(Debug, Clone, Copy)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct QualityThresholds {
     /// Maximum latency (ms) to be considered [`ConnectionQuality::Excellent`].
     /// *Default: 50ms*
@@ -172,6 +295,8 @@ impl Default for QualityThresholds {
 /// These settings allow the engine to detect environmental factors that might
 /// be undesirable or indicate a compromised connection.
 #[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct SecurityConfig {
     /// If enabled, the engine will flag connections that originate from a VPN interface.
     ///
@@ -185,6 +310,115 @@ pub struct SecurityConfig {
     /// a trusted upstream resolver (like Cloudflare or Google). If they differ
     /// significantly for static domains, it flags a potential spoofing attempt.
     pub detect_dns_hijack: bool,
+
+    /// Names of the interfaces traffic may leave through (e.g. `wlan0`,
+    /// `en0`), enforced by [guard](crate::api::engine::guard).
+    /// *Default: empty (any interface)*
+    pub allowed_interfaces: Vec<String>,
+}
+
+/// Limits on the probe traffic generated by the engine.
+///
+/// Both limits are enforced by a token bucket shared by every probe of a check,
+/// so aggressive configurations (many targets, many samples) cannot burst enough
+/// traffic to trip intrusion-detection systems on corporate networks.
+#[derive(Debug, Clone, Copy, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RateLimitConfig {
+    /// Maximum number of probes started per second.
+    /// *Default: 0 (unlimited)*
+    pub max_probes_per_second: u32,
+
+    /// Maximum estimated probe traffic, in bytes per second.
+    ///
+    /// Probe sizes are estimated per protocol (a TCP handshake is far cheaper
+    /// than a full HTTPS request), so this is an approximate upper bound.
+    /// *Default: 0 (unlimited)*
+    pub max_bytes_per_second: u64,
+}
+
+/// Settings for SNMP queries against managed network devices.
+///
+/// Only used when the crate is built with the `snmp` feature, by
+/// [PipelineStep::Snmp](crate::api::engine::pipeline::PipelineStep).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnmpConfig {
+    /// The SNMP v2c community string.
+    /// *Default: "public"*
+    pub community: String,
+
+    /// The devices to query (IP addresses). Empty queries the default gateway.
+    pub hosts: Vec<String>,
+
+    /// The time budget (ms) per device.
+    /// *Default: 2000*
+    pub timeout_ms: u64,
+}
+
+impl Default for SnmpConfig {
+    fn default() -> Self {
+        Self {
+            community: LibConstants::DEFAULT_SNMP_COMMUNITY.to_string(),
+            hosts: Vec::new(),
+            timeout_ms: LibConstants::DEFAULT_SNMP_TIMEOUT_MS,
+        }
+    }
+}
+
+/// What an application needs from the connection, for
+/// [preflight](crate::api::engine::preflight::preflight).
+///
+/// Every requirement is optional; only the probes needed to answer the set
+/// requirements are run.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Requirements {
+    /// Minimum download throughput in Mbit/s, measured against `download_url`.
+    pub min_down_mbps: Option<f64>,
+
+    /// Maximum representative latency in milliseconds, measured against `targets`.
+    pub max_latency_ms: Option<u64>,
+
+    /// Maximum packet loss in percent, measured against `targets`.
+    pub max_loss_percent: Option<f32>,
+
+    /// Whether UDP must get through (e.g. for QUIC or WebRTC media), checked
+    /// with a DNS query to `udp_dns_server`.
+    pub needs_udp: bool,
+
+    /// Whether WebSocket upgrades must get through, checked with an upgrade
+    /// handshake against `websocket_url`.
+    pub needs_websocket: bool,
+
+    /// The targets for the latency and loss requirements. Empty uses the
+    /// targets of [NetworkConfiguration::default].
+    pub targets: Vec<NetworkTarget>,
+
+    /// A URL serving a large body, for the throughput requirement.
+    pub download_url: String,
+
+    /// A DNS server (`ip:port`) for the UDP requirement.
+    pub udp_dns_server: String,
+
+    /// A WebSocket endpoint (`ws://`, `wss://` or the `http(s)://` equivalent).
+    pub websocket_url: String,
+}
+
+impl Default for Requirements {
+    fn default() -> Self {
+        Self {
+            min_down_mbps: None,
+            max_latency_ms: None,
+            max_loss_percent: None,
+            needs_udp: false,
+            needs_websocket: false,
+            targets: Vec::new(),
+            download_url: LibConstants::DEFAULT_PREFLIGHT_DOWNLOAD_URL.to_string(),
+            udp_dns_server: LibConstants::DEFAULT_PREFLIGHT_UDP_DNS_SERVER.to_string(),
+            websocket_url: LibConstants::DEFAULT_PREFLIGHT_WEBSOCKET_URL.to_string(),
+        }
+    }
 }
 
 /// Configuration for network resilience, failure handling, and statistical analysis.
@@ -192,8 +426,10 @@ pub struct SecurityConfig {
 /// This struct controls the "brain" of the engine: how it handles noise,
 /// how it reacts to failure, and how it calculates jitter.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct ResilienceConfig {
-    /// The evaluation strategy (Race vs Consensus) for multi-target checks.
+    /// How the results of several targets combine into one verdict per sample.
     pub strategy: CheckStrategy,
 
     /// Number of consecutive failures before the "Circuit Breaker" opens.
@@ -232,6 +468,40 @@ pub struct ResilienceConfig {
     /// If packet loss exceeds this value, the connection is immediately
     /// downgraded to 'Unstable' or 'Offline'.
     pub critical_packet_loss_precent: f32,
+
+    /// Whether samples run one after another or concurrently.
+    pub sample_mode: SampleMode,
+
+    /// Delay (ms) between the starts of consecutive samples in
+    /// [SampleMode::Parallel]. Ignored by the other modes.
+    pub parallel_sample_offset_ms: u64,
+
+    /// A sample counts as a latency spike if it exceeds the median of the
+    /// check by more than this factor (e.g. 2.0 = twice the median).
+    ///
+    /// Set to 0 to disable spike detection.
+    pub spike_ratio_threshold: f64,
+
+    /// Points subtracted from the stability score if at least one spike was
+    /// detected in a check.
+    pub spike_penalty: u8,
+
+    /// Consecutive app-reported failures (see
+    /// [ReachabilityEngine::report_app_failure](crate::api::engine::ReachabilityEngine::report_app_failure))
+    /// that invalidate a cached "connected" report.
+    ///
+    /// *Set to 0 to ignore app-reported failures.*
+    pub passive_failure_threshold: u8,
+
+    /// How long (ms) an app-reported outcome counts as evidence.
+    ///
+    /// A cached "connected" report confirmed by app successes is reused for up
+    /// to this long, instead of only `cache_validity_ms`, saving probe traffic.
+    pub passive_signal_window_ms: u64,
+
+    /// What a monitor does with the cycles that come due while a slow check
+    /// is still running.
+    pub overlap_policy: OverlapPolicy,
 }
 
 impl ResilienceConfig {
@@ -253,6 +523,13 @@ impl ResilienceConfig {
             jitter_threshold_percent,
             stability_thershold,
             critical_packet_loss_precent,
+            sample_mode: SampleMode::Sequential,
+            parallel_sample_offset_ms: LibConstants::DEFAULT_PARALLEL_SAMPLE_OFFSET_MS,
+            spike_ratio_threshold: LibConstants::DEFAULT_SPIKE_RATIO_THRESHOLD,
+            spike_penalty: LibConstants::DEFAULT_SPIKE_PENALTY,
+            passive_failure_threshold: LibConstants::DEFAULT_PASSIVE_FAILURE_THRESHOLD,
+            passive_signal_window_ms: LibConstants::DEFAULT_PASSIVE_SIGNAL_WINDOW_MS,
+            overlap_policy: OverlapPolicy::default(),
         }
     }
 }
@@ -260,7 +537,7 @@ impl ResilienceConfig {
 /// Balanced default resilience configuration.
 ///
 /// - Strategy: [`CheckStrategy::Race`] (optimized for speed)
-/// - Jitter Samples: 5 (good balance of accuracy and speed)
+/// - Jitter Samples: 5 (good balance of accuracy and speed), run sequentially
 /// - Circuit Breaker: Disabled by default.
 impl Default for ResilienceConfig {
     fn default() -> Self {
@@ -272,26 +549,157 @@ impl Default for ResilienceConfig {
             jitter_threshold_percent: LibConstants::DEFAULT_JITTER_THRESHOLD_PERCENT,
             stability_thershold: LibConstants::DEFAULT_STABILITY_THRESHOLD,
             critical_packet_loss_precent: LibConstants::DEFAULT_CRITICAL_PACKET_LOSS_PRECENT,
+            sample_mode: SampleMode::Sequential,
+            parallel_sample_offset_ms: LibConstants::DEFAULT_PARALLEL_SAMPLE_OFFSET_MS,
+            spike_ratio_threshold: LibConstants::DEFAULT_SPIKE_RATIO_THRESHOLD,
+            spike_penalty: LibConstants::DEFAULT_SPIKE_PENALTY,
+            passive_failure_threshold: LibConstants::DEFAULT_PASSIVE_FAILURE_THRESHOLD,
+            passive_signal_window_ms: LibConstants::DEFAULT_PASSIVE_SIGNAL_WINDOW_MS,
+            overlap_policy: OverlapPolicy::default(),
+        }
+    }
+}
+
+/// The external services the engine relies on besides the targets.
+///
+/// Each defaults to the public service the engine has always used. Pointing
+/// them at local servers, e.g. a mock HTTP server in tests, keeps a check
+/// from reaching out to the internet on its own.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
+pub struct EndpointConfig {
+    /// The URL fetched to detect a captive portal. It must answer without
+    /// redirecting on an open network, so it should be plain HTTP.
+    /// *Default: "http://neverssl.com"*
+    pub captive_portal_url: String,
+
+    /// The URL requested, with certificate validation, to learn whether a
+    /// portal's walled garden lets HTTPS through.
+    /// *Default: "https://1.1.1.1/"*
+    pub walled_garden_url: String,
+
+    /// The host whose ports the walled garden probe connects to. It must
+    /// accept connections on every port.
+    /// *Default: "portquiz.net"*
+    pub walled_garden_port_host: String,
+
+    /// The public DNS server (`ip:port`) the walled garden probe queries
+    /// directly, bypassing the one handed out by DHCP.
+    /// *Default: "1.1.1.1:53"*
+    pub public_dns_server: String,
+
+    /// The resolvers (`ip:port`) whose answers are trusted when checking the
+    /// system resolver for hijacking (see [SecurityConfig::detect_dns_hijack]).
+    /// *Default: empty (Cloudflare)*
+    pub trusted_dns_servers: Vec<String>,
+
+    /// The servers (`ip:port`) whose TCP handshakes measure each interface
+    /// when comparing paths (see [NetworkConfiguration::compare_paths]).
+    /// *Default: [DEFAULT_TCP_REFERENCE_SERVERS](LibConstants::DEFAULT_TCP_REFERENCE_SERVERS)*
+    pub tcp_reference_servers: Vec<String>,
+}
+
+impl Default for EndpointConfig {
+    fn default() -> Self {
+        Self {
+            captive_portal_url: LibConstants::CAPTIVE_PORTAL_DETECTION_URL.to_string(),
+            walled_garden_url: LibConstants::WALLED_GARDEN_HTTPS_URL.to_string(),
+            walled_garden_port_host: LibConstants::WALLED_GARDEN_PORT_HOST.to_string(),
+            public_dns_server: LibConstants::DEFAULT_PREFLIGHT_UDP_DNS_SERVER.to_string(),
+            trusted_dns_servers: Vec::new(),
+            tcp_reference_servers: LibConstants::DEFAULT_TCP_REFERENCE_SERVERS
+                .iter()
+                .map(|s| s.to_string())
+                .collect(),
         }
     }
 }
 
+impl EndpointConfig {
+    /// The fields that are not a usable URL, host or socket address, with
+    /// their values.
+    fn invalid_fields(&self) -> Vec<(&'static str, &str)> {
+        let is_url = |url: &str| {
+            reqwest::Url::parse(url).is_ok_and(|url| matches!(url.scheme(), "http" | "https"))
+        };
+        let is_socket_addr = |addr: &str| addr.trim().parse::<SocketAddr>().is_ok();
+        let mut invalid = Vec::new();
+        if !is_url(&self.captive_portal_url) {
+            invalid.push(("captive_portal_url", self.captive_portal_url.as_str()));
+        }
+        if !is_url(&self.walled_garden_url) {
+            invalid.push(("walled_garden_url", self.walled_garden_url.as_str()));
+        }
+        if self.walled_garden_port_host.trim().is_empty() {
+            invalid.push(("walled_garden_port_host", ""));
+        }
+        if !is_socket_addr(&self.public_dns_server) {
+            invalid.push(("public_dns_server", self.public_dns_server.as_str()));
+        }
+        for server in self
+            .trusted_dns_servers
+            .iter()
+            .filter(|s| !is_socket_addr(s))
+        {
+            invalid.push(("trusted_dns_servers", server.as_str()));
+        }
+        for server in self
+            .tcp_reference_servers
+            .iter()
+            .filter(|s| !is_socket_addr(s))
+        {
+            invalid.push(("tcp_reference_servers", server.as_str()));
+        }
+        invalid
+    }
+}
+
 /// The master configuration for the Network Reachability Engine.
 ///
 /// This structure is the entry point for customizing how the engine behaves.
 /// It should be initialized once and passed to the engine during startup.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct NetworkConfiguration {
     /// The list of endpoints ([`NetworkTarget` models](super::target::NetworkTarget))
     /// to probe.
     pub targets: Vec<NetworkTarget>,
 
+    /// Endpoints on the local network (PLCs, printers, NAS, ...) that prove
+    /// LAN reachability when the internet is down.
+    ///
+    /// Probed together with the default gateway by [check_local_network](crate::api::engine::local::check_local_network), and by
+    /// [check_network](crate::api::engine::check_network) whenever no internet
+    /// target answers. Empty by default.
+    pub lan_targets: Vec<NetworkTarget>,
+
+    /// ISP or downdetector-style status endpoints, consulted when the gateway
+    /// answers but no internet target does.
+    ///
+    /// Each URL should return either a Statuspage-style JSON document
+    /// (`{"status": {"indicator": "none"}}`) or a plain page mentioning an
+    /// "outage". The verdict lands in
+    /// [NetworkReport::likely_isp_outage](super::report::NetworkReport::likely_isp_outage).
+    /// Empty by default, which skips the probe.
+    pub isp_status_urls: Vec<String>,
+
     /// Frequency (ms) of background checks.
     ///
-    /// If set to 5000, the engine will automatically run a check every 5 seconds
-    /// and stream results. Set to 0 to disable periodic checks.
+    /// If set to 5000, a [NetworkMonitor](crate::api::monitor::NetworkMonitor)
+    /// runs a check every 5 seconds and streams the results. Set to 0 to
+    /// disable periodic checks.
     pub check_interval_ms: u64,
 
+    /// Random spread (±percent) applied to every periodic interval.
+    ///
+    /// With a value of 10 and an interval of 5000ms, each wait is drawn uniformly
+    /// from 4500..=5500ms. This keeps large fleets of clients from synchronizing
+    /// and probing the same targets in bursts. Set to 0 for a fixed interval.
+    /// Values above 100 are treated as 100.
+    pub check_interval_jitter_percent: u8,
+
     /// Cache duration (ms) for results.
     ///
     /// If a manual check is requested within this window of a previous check,
@@ -307,6 +715,91 @@ pub struct NetworkConfiguration {
 
     /// Performance and resilience settings.
     pub resilience: ResilienceConfig,
+
+    /// Limits on the probe traffic generated per check.
+    pub rate_limit: RateLimitConfig,
+
+    /// SNMP settings for the optional SNMP pipeline step.
+    pub snmp: SnmpConfig,
+
+    /// The captive portal, walled garden and DNS services probed alongside
+    /// the targets.
+    pub endpoints: EndpointConfig,
+
+    /// Attach every sampling round to [NetworkReport::raw_samples](super::report::NetworkReport::raw_samples).
+    ///
+    /// Off by default, as it grows each report by one record per sample.
+    pub include_raw_samples: bool,
+
+    /// Resolve every target's host once before sampling and reuse the
+    /// addresses for all samples of the check.
+    ///
+    /// Keeps resolver variability out of the latency and jitter figures. The
+    /// one-off resolution time is still reported in
+    /// [TargetReport::dns_ms](super::report::TargetReport::dns_ms). Targets
+    /// whose host cannot be pre-resolved fall back to per-sample resolution.
+    /// Off by default.
+    pub pre_resolve_targets: bool,
+
+    /// Probe dual-stack targets over IPv4 and IPv6 separately and attach the
+    /// comparison to [NetworkReport::family_comparison](super::report::NetworkReport::family_comparison).
+    ///
+    /// Only targets whose host name resolves to both families take part; IP
+    /// literals do not. Doubles the probe traffic of those targets. Off by default.
+    pub compare_address_families: bool,
+
+    /// Check LAN multicast via the mDNS group and set
+    /// [NetworkReport::multicast_ok](super::report::NetworkReport::multicast_ok).
+    ///
+    /// Adds a listening window of
+    /// [DEFAULT_MULTICAST_TIMEOUT_MS](LibConstants::DEFAULT_MULTICAST_TIMEOUT_MS)
+    /// to the check. Off by default.
+    pub check_multicast: bool,
+
+    /// If enabled, measures every Wi-Fi, cellular and Ethernet interface
+    /// separately and reports a [BetterPathAvailable](super::diagnostics::BetterPathAvailable)
+    /// in [NetworkReport::better_path](super::report::NetworkReport::better_path)
+    /// when one beats the active interface. Only does anything with more than
+    /// one such interface up.
+    /// *Default: false*
+    pub compare_paths: bool,
+
+    /// If enabled, samples the gateway after the check and reports in
+    /// [NetworkReport::attribution](super::report::NetworkReport::attribution)
+    /// how much of the latency variance and loss arise between the device and
+    /// its router. Adds one gateway probe per jitter sample. Only does
+    /// anything while connected.
+    /// *Default: false*
+    pub attribute_degradation: bool,
+
+    /// Upper bound (ms) on the whole of [check_network](crate::api::engine::check_network).
+    ///
+    /// When it runs out, the check stops and returns what it measured so far,
+    /// flagged as [truncated](super::report::NetworkReport::truncated). 0 means
+    /// no limit beyond the per-target timeouts.
+    /// *Default: 0*
+    pub overall_timeout_ms: u64,
+
+    /// Report offline at once, without probing, when the OS has no route to
+    /// the internet nor to any IP-literal target.
+    ///
+    /// Saves waiting out every target's timeout when all interfaces are down.
+    /// Targets given by host name count as needing the internet route.
+    /// *Default: true*
+    pub fast_fail_offline: bool,
+
+    /// The interface to treat as active (e.g. `wlan0`), instead of detecting
+    /// the one carrying the default route.
+    ///
+    /// Decides [NetworkReport::connection_type](super::report::NetworkReport::connection_type),
+    /// the security flags and the baseline of [compare_paths](Self::compare_paths),
+    /// and takes precedence over the interface facts of
+    /// [PlatformHints](super::net_info::PlatformHints). Probes still leave
+    /// through whatever link the OS routes them over unless they are bound to
+    /// it as well. An interface that is not up is reported as
+    /// [ConnectionType::Unknown](super::net_info::ConnectionType::Unknown).
+    /// *Default: None (detect)*
+    pub active_interface: Option<String>,
 }
 
 impl NetworkConfiguration {
@@ -321,11 +814,125 @@ impl NetworkConfiguration {
     ) -> Self {
         Self {
             targets,
+            lan_targets: Vec::new(),
+            isp_status_urls: Vec::new(),
             check_interval_ms,
+            check_interval_jitter_percent: LibConstants::DEFAULT_CHECK_INTERVAL_JITTER_PERCENT,
             cache_validity_ms,
             quality_threshold,
             security,
             resilience,
+            rate_limit: RateLimitConfig::default(),
+            snmp: SnmpConfig::default(),
+            endpoints: EndpointConfig::default(),
+            include_raw_samples: false,
+            pre_resolve_targets: false,
+            compare_address_families: false,
+            check_multicast: false,
+            compare_paths: false,
+            attribute_degradation: false,
+            overall_timeout_ms: 0,
+            fast_fail_offline: true,
+            active_interface: None,
+        }
+    }
+
+    /// Parses a configuration from JSON, as produced by serializing one.
+    ///
+    /// Missing fields take their [Default] values, so `{"check_interval_ms": 10000}`
+    /// is a complete configuration.
+    #[cfg(feature = "serde")]
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+
+    /// Loads and validates a configuration from a `.toml` or `.json` file.
+    ///
+    /// As with [Self::from_json], missing fields take their [Default] values.
+    #[cfg(feature = "serde")]
+    pub fn from_file(path: impl AsRef<std::path::Path>) -> Result<Self, ConfigError> {
+        let path = path.as_ref();
+        let extension = path
+            .extension()
+            .and_then(|e| e.to_str())
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        if !matches!(extension.as_str(), "toml" | "json") {
+            return Err(ConfigError::UnsupportedFormat(extension));
+        }
+        let text = std::fs::read_to_string(path).map_err(|e| ConfigError::Io(e.to_string()))?;
+        let config: Self = if extension == "toml" {
+            toml::from_str(&text).map_err(|e| ConfigError::Parse(e.to_string()))?
+        } else {
+            Self::from_json(&text).map_err(|e| ConfigError::Parse(e.to_string()))?
+        };
+        config.validate().map_err(ConfigError::Invalid)?;
+        Ok(config)
+    }
+
+    /// Starts a [`NetworkConfigurationBuilder`].
+    #[frb(ignore)]
+    pub fn builder() -> NetworkConfigurationBuilder {
+        NetworkConfigurationBuilder::new()
+    }
+
+    /// Checks the configuration for values the engine cannot work with.
+    ///
+    /// Returns every problem found, not just the first.
+    pub fn validate(&self) -> Result<(), Vec<ValidationIssue>> {
+        let mut issues = Vec::new();
+        for (i, target) in self.targets.iter().enumerate() {
+            if target.host.trim().is_empty() {
+                issues.push(ValidationIssue::EmptyHost { target: i });
+            }
+            if target.timeout_ms == 0 || target.timeout_ms > LibConstants::MAX_TARGET_TIMEOUT_MS {
+                issues.push(ValidationIssue::InvalidTimeout {
+                    target: i,
+                    timeout_ms: target.timeout_ms,
+                });
+            }
+            if target
+                .dns_query_name
+                .as_deref()
+                .is_some_and(|name| !is_valid_dns_name(name))
+            {
+                issues.push(ValidationIssue::InvalidDnsQueryName { target: i });
+            }
+        }
+        match self.resilience.strategy {
+            CheckStrategy::Quorum(quorum)
+                if quorum == 0 || usize::from(quorum) > self.targets.len() =>
+            {
+                issues.push(ValidationIssue::InvalidQuorum {
+                    quorum,
+                    targets: self.targets.len(),
+                });
+            }
+            CheckStrategy::Weighted
+                if !self.targets.is_empty() && self.targets.iter().all(|t| t.weight == 0) =>
+            {
+                issues.push(ValidationIssue::ZeroTotalWeight);
+            }
+            _ => {}
+        }
+        for (field, value) in self.endpoints.invalid_fields() {
+            issues.push(ValidationIssue::InvalidEndpoint {
+                field: field.to_string(),
+                value: value.to_string(),
+            });
+        }
+        let t = &self.quality_threshold;
+        if !(t.excellent <= t.great
+            && t.great <= t.good
+            && t.good <= t.moderate
+            && t.moderate <= t.poor)
+        {
+            issues.push(ValidationIssue::ThresholdsNotMonotonic);
+        }
+        if issues.is_empty() {
+            Ok(())
+        } else {
+            Err(issues)
         }
     }
 }
@@ -333,8 +940,8 @@ impl NetworkConfiguration {
 /// Standard production-ready configuration.
 ///
 /// Includes:
-/// - **Targets:** Cloudflare (HTTP/HTTPS/TCP/ICMP) and Google (TCP/ICMP).
-/// - **Interval:** 5 seconds.
+/// - **Targets:** Cloudflare (HTTP/HTTPS/ICMP) and a DNS query to Google DNS.
+/// - **Interval:** 5 seconds, with ±10% jitter.
 /// - **Cache:** 2 seconds.
 /// - **Defaults:** Balanced quality and resilience settings.
 impl Default for NetworkConfiguration {
@@ -349,6 +956,11 @@ impl Default for NetworkConfiguration {
                     timeout_ms: LibConstants::DEFAULT_HTTP_TIMEOUT_MS,
                     priority: 1,
                     is_essential: false,
+                    weight: 1,
+                    udp_validation: UdpValidation::default(),
+                    dns_query_name: None,
+                    dscp: None,
+                    report_timeout_progress: false,
                 },
                 NetworkTarget {
                     label: LibConstants::CLOUDFLARE_NAME_HTTPS.into(),
@@ -358,15 +970,25 @@ impl Default for NetworkConfiguration {
                     timeout_ms: LibConstants::DEFAULT_HTTP_TIMEOUT_MS,
                     priority: 1,
                     is_essential: false,
+                    weight: 1,
+                    udp_validation: UdpValidation::default(),
+                    dns_query_name: None,
+                    dscp: None,
+                    report_timeout_progress: false,
                 },
                 NetworkTarget {
                     label: LibConstants::CLOUDFLARE_NAME.into(),
                     host: LibConstants::GOOGLE_DNS.into(),
                     port: LibConstants::DEFAULT_PORT,
-                    protocol: TargetProtocol::Tcp,
+                    protocol: TargetProtocol::Dns,
                     timeout_ms: LibConstants::DEFAULT_HTTP_TIMEOUT_MS,
                     priority: 1,
                     is_essential: false,
+                    weight: 1,
+                    udp_validation: UdpValidation::default(),
+                    dns_query_name: None,
+                    dscp: None,
+                    report_timeout_progress: false,
                 },
                 NetworkTarget {
                     label: "Cloudflare ICMP".into(),
@@ -376,17 +998,222 @@ impl Default for NetworkConfiguration {
                     timeout_ms: LibConstants::DEFAULT_HTTP_TIMEOUT_MS,
                     priority: 1,
                     is_essential: false,
+                    weight: 1,
+                    udp_validation: UdpValidation::default(),
+                    dns_query_name: None,
+                    dscp: None,
+                    report_timeout_progress: false,
                 },
             ],
+            lan_targets: Vec::new(),
+            isp_status_urls: Vec::new(),
             check_interval_ms: LibConstants::DEFAULT_CHECK_INTERVAL_MS,
+            check_interval_jitter_percent: LibConstants::DEFAULT_CHECK_INTERVAL_JITTER_PERCENT,
             cache_validity_ms: LibConstants::DEFAULT_CACHE_VALIDITY_MS,
             quality_threshold: QualityThresholds::default(),
             security: SecurityConfig::default(),
             resilience: ResilienceConfig::default(),
+            rate_limit: RateLimitConfig::default(),
+            snmp: SnmpConfig::default(),
+            endpoints: EndpointConfig::default(),
+            include_raw_samples: false,
+            pre_resolve_targets: false,
+            compare_address_families: false,
+            check_multicast: false,
+            compare_paths: false,
+            attribute_degradation: false,
+            overall_timeout_ms: 0,
+            fast_fail_offline: true,
+            active_interface: None,
         }
     }
 }
 
+/// Builds a validated [`NetworkConfiguration`] from its defaults.
+///
+/// ```ignore
+/// let config = NetworkConfiguration::builder()
+///     .add_target(NetworkTarget::builder("1.1.1.1", TargetProtocol::Https).build())
+///     .strategy(CheckStrategy::Consensus)
+///     .check_interval_ms(10_000)
+///     .build()?;
+/// ```
+#[frb(ignore)]
+#[derive(Debug, Clone)]
+pub struct NetworkConfigurationBuilder {
+    config: NetworkConfiguration,
+    targets: Vec<NetworkTarget>,
+}
+
+impl Default for NetworkConfigurationBuilder {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl NetworkConfigurationBuilder {
+    /// Starts from [`NetworkConfiguration::default`].
+    pub fn new() -> Self {
+        Self {
+            config: NetworkConfiguration::default(),
+            targets: Vec::new(),
+        }
+    }
+
+    /// Adds an internet target. If none is added, the default targets are kept.
+    pub fn add_target(mut self, target: NetworkTarget) -> Self {
+        self.targets.push(target);
+        self
+    }
+
+    /// See [`NetworkConfiguration::lan_targets`].
+    pub fn add_lan_target(mut self, target: NetworkTarget) -> Self {
+        self.config.lan_targets.push(target);
+        self
+    }
+
+    /// See [`NetworkConfiguration::isp_status_urls`].
+    pub fn add_isp_status_url(mut self, url: impl Into<String>) -> Self {
+        self.config.isp_status_urls.push(url.into());
+        self
+    }
+
+    /// See [`NetworkConfiguration::check_interval_ms`].
+    pub fn check_interval_ms(mut self, interval_ms: u64) -> Self {
+        self.config.check_interval_ms = interval_ms;
+        self
+    }
+
+    /// See [`NetworkConfiguration::check_interval_jitter_percent`].
+    pub fn check_interval_jitter_percent(mut self, percent: u8) -> Self {
+        self.config.check_interval_jitter_percent = percent;
+        self
+    }
+
+    /// See [`NetworkConfiguration::cache_validity_ms`].
+    pub fn cache_validity_ms(mut self, validity_ms: u64) -> Self {
+        self.config.cache_validity_ms = validity_ms;
+        self
+    }
+
+    /// See [`NetworkConfiguration::quality_threshold`].
+    pub fn quality_thresholds(mut self, thresholds: QualityThresholds) -> Self {
+        self.config.quality_threshold = thresholds;
+        self
+    }
+
+    /// See [`NetworkConfiguration::security`].
+    pub fn security(mut self, security: SecurityConfig) -> Self {
+        self.config.security = security;
+        self
+    }
+
+    /// See [`NetworkConfiguration::endpoints`].
+    pub fn endpoints(mut self, endpoints: EndpointConfig) -> Self {
+        self.config.endpoints = endpoints;
+        self
+    }
+
+    /// See [`NetworkConfiguration::resilience`].
+    pub fn resilience(mut self, resilience: ResilienceConfig) -> Self {
+        self.config.resilience = resilience;
+        self
+    }
+
+    /// See [`ResilienceConfig::strategy`].
+    pub fn strategy(mut self, strategy: CheckStrategy) -> Self {
+        self.config.resilience.strategy = strategy;
+        self
+    }
+
+    /// See [`ResilienceConfig::sample_mode`].
+    pub fn sample_mode(mut self, mode: SampleMode) -> Self {
+        self.config.resilience.sample_mode = mode;
+        self
+    }
+
+    /// See [`ResilienceConfig::overlap_policy`].
+    pub fn overlap_policy(mut self, policy: OverlapPolicy) -> Self {
+        self.config.resilience.overlap_policy = policy;
+        self
+    }
+
+    /// See [`NetworkConfiguration::rate_limit`].
+    pub fn rate_limit(mut self, rate_limit: RateLimitConfig) -> Self {
+        self.config.rate_limit = rate_limit;
+        self
+    }
+
+    /// See [`NetworkConfiguration::snmp`].
+    pub fn snmp(mut self, snmp: SnmpConfig) -> Self {
+        self.config.snmp = snmp;
+        self
+    }
+
+    /// See [`NetworkConfiguration::include_raw_samples`].
+    pub fn include_raw_samples(mut self, include: bool) -> Self {
+        self.config.include_raw_samples = include;
+        self
+    }
+
+    /// See [`NetworkConfiguration::pre_resolve_targets`].
+    pub fn pre_resolve_targets(mut self, pre_resolve: bool) -> Self {
+        self.config.pre_resolve_targets = pre_resolve;
+        self
+    }
+
+    /// See [`NetworkConfiguration::compare_address_families`].
+    pub fn compare_address_families(mut self, compare: bool) -> Self {
+        self.config.compare_address_families = compare;
+        self
+    }
+
+    /// See [`NetworkConfiguration::check_multicast`].
+    pub fn check_multicast(mut self, check: bool) -> Self {
+        self.config.check_multicast = check;
+        self
+    }
+
+    /// See [`NetworkConfiguration::compare_paths`].
+    pub fn compare_paths(mut self, compare: bool) -> Self {
+        self.config.compare_paths = compare;
+        self
+    }
+
+    /// See [`NetworkConfiguration::attribute_degradation`].
+    pub fn attribute_degradation(mut self, attribute: bool) -> Self {
+        self.config.attribute_degradation = attribute;
+        self
+    }
+
+    /// See [`NetworkConfiguration::overall_timeout_ms`].
+    pub fn overall_timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.config.overall_timeout_ms = timeout_ms;
+        self
+    }
+
+    /// See [`NetworkConfiguration::fast_fail_offline`].
+    pub fn fast_fail_offline(mut self, fast_fail: bool) -> Self {
+        self.config.fast_fail_offline = fast_fail;
+        self
+    }
+
+    /// See [`NetworkConfiguration::active_interface`].
+    pub fn active_interface(mut self, name: impl Into<String>) -> Self {
+        self.config.active_interface = Some(name.into());
+        self
+    }
+
+    /// Validates and returns the configuration; see [`NetworkConfiguration::validate`].
+    pub fn build(mut self) -> Result<NetworkConfiguration, ConfigError> {
+        if !self.targets.is_empty() {
+            self.config.targets = self.targets;
+        }
+        self.config.validate().map_err(ConfigError::Invalid)?;
+        Ok(self.config)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -414,7 +1241,180 @@ mod tests {
         assert_eq!(config.targets.len(), 4);
         assert_eq!(config.targets[0].label, LibConstants::CLOUDFLARE_NAME_HTTP);
         assert_eq!(config.targets[1].label, LibConstants::CLOUDFLARE_NAME_HTTPS);
+        assert_eq!(config.targets[2].protocol, TargetProtocol::Dns);
         assert_eq!(config.resilience.strategy, CheckStrategy::Race);
         assert!(!config.security.block_vpn);
     }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_json_round_trip() {
+        let config = NetworkConfiguration::from_json(r#"{"check_interval_ms": 10000}"#).unwrap();
+        assert_eq!(config.check_interval_ms, 10000);
+        assert_eq!(config.targets.len(), 4);
+        assert!(NetworkConfiguration::from_json("{\"targets\": 1}").is_err());
+
+        let report = crate::api::models::NetworkReport::fixture(true);
+        let json = report.to_json().unwrap();
+        let parsed: crate::api::models::NetworkReport = serde_json::from_str(&json).unwrap();
+        assert_eq!(parsed.to_json().unwrap(), json);
+    }
+
+    #[test]
+    fn test_validate() {
+        let mut config = NetworkConfiguration::default();
+        assert_eq!(config.validate(), Ok(()));
+
+        config.targets[0].host = " ".into();
+        config.targets[1].timeout_ms = 0;
+        config.quality_threshold.great = 1;
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                ValidationIssue::EmptyHost { target: 0 },
+                ValidationIssue::InvalidTimeout {
+                    target: 1,
+                    timeout_ms: 0
+                },
+                ValidationIssue::ThresholdsNotMonotonic,
+            ])
+        );
+
+        let mut config = NetworkConfiguration::default();
+        config.resilience.strategy = CheckStrategy::Quorum(5);
+        assert_eq!(
+            config.validate(),
+            Err(vec![ValidationIssue::InvalidQuorum {
+                quorum: 5,
+                targets: 4
+            }])
+        );
+        config.resilience.strategy = CheckStrategy::Weighted;
+        config.targets.iter_mut().for_each(|t| t.weight = 0);
+        assert_eq!(
+            config.validate(),
+            Err(vec![ValidationIssue::ZeroTotalWeight])
+        );
+
+        let mut config = NetworkConfiguration::default();
+        config.targets[2].dns_query_name = Some(format!("{}.example", "a".repeat(64)));
+        config.targets[3].dns_query_name = Some(format!("{}.example", "a".repeat(63)));
+        assert_eq!(
+            config.validate(),
+            Err(vec![ValidationIssue::InvalidDnsQueryName { target: 2 }])
+        );
+        config.targets[2].dns_query_name = Some(vec!["a".repeat(60); 5].join("."));
+        assert_eq!(
+            config.validate(),
+            Err(vec![ValidationIssue::InvalidDnsQueryName { target: 2 }])
+        );
+        config.targets[2].dns_query_name = None;
+
+        config.endpoints.captive_portal_url = "neverssl.com".into();
+        config.endpoints.public_dns_server = "1.1.1.1".into();
+        config.endpoints.trusted_dns_servers = vec!["9.9.9.9:53".into(), "dns.example".into()];
+        assert_eq!(
+            config.validate(),
+            Err(vec![
+                ValidationIssue::InvalidEndpoint {
+                    field: "captive_portal_url".into(),
+                    value: "neverssl.com".into(),
+                },
+                ValidationIssue::InvalidEndpoint {
+                    field: "public_dns_server".into(),
+                    value: "1.1.1.1".into(),
+                },
+                ValidationIssue::InvalidEndpoint {
+                    field: "trusted_dns_servers".into(),
+                    value: "dns.example".into(),
+                },
+            ])
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_from_file() {
+        let dir = std::env::temp_dir();
+        let toml = dir.join(format!("nr-config-{}.toml", std::process::id()));
+        std::fs::write(&toml, "check_interval_ms = 10000\n").unwrap();
+        let config = NetworkConfiguration::from_file(&toml).unwrap();
+        assert_eq!(config.check_interval_ms, 10000);
+
+        std::fs::write(&toml, "[quality_threshold]\nexcellent = 900\ngreat = 100\ngood = 150\nmoderate = 250\npoor = 500\n").unwrap();
+        assert_eq!(
+            NetworkConfiguration::from_file(&toml).unwrap_err(),
+            ConfigError::Invalid(vec![ValidationIssue::ThresholdsNotMonotonic])
+        );
+
+        // Partial tables keep the defaults of the fields they leave out.
+        std::fs::write(
+            &toml,
+            "[quality_threshold]\nexcellent = 20\n\n[security]\nblock_vpn = true\n\n\
+             [resilience]\nnum_jitter_samples = 9\n\n[[targets]]\nhost = \"1.1.1.1\"\n",
+        )
+        .unwrap();
+        let config = NetworkConfiguration::from_file(&toml).unwrap();
+        let defaults = NetworkConfiguration::default();
+        assert_eq!(config.quality_threshold.excellent, 20);
+        assert_eq!(
+            config.quality_threshold.poor,
+            defaults.quality_threshold.poor
+        );
+        assert!(config.security.block_vpn);
+        assert_eq!(config.resilience.num_jitter_samples, 9);
+        assert_eq!(config.resilience.strategy, defaults.resilience.strategy);
+        assert_eq!(config.targets.len(), 1);
+        assert_eq!(config.targets[0].port, LibConstants::DEFAULT_HTTP_PORT);
+        assert_eq!(config.targets[0].weight, 1);
+        std::fs::remove_file(&toml).unwrap();
+
+        let yaml = dir.join(format!("nr-config-{}.yaml", std::process::id()));
+        std::fs::write(&yaml, "check_interval_ms: 10000\n").unwrap();
+        assert_eq!(
+            NetworkConfiguration::from_file(&yaml).unwrap_err(),
+            ConfigError::UnsupportedFormat("yaml".into())
+        );
+        std::fs::remove_file(&yaml).unwrap();
+        assert!(matches!(
+            NetworkConfiguration::from_file(dir.join("missing-config.json")),
+            Err(ConfigError::Io(_))
+        ));
+    }
+
+    #[test]
+    fn test_builders() {
+        let target = NetworkTarget::builder("example.com", TargetProtocol::Https)
+            .label("Example")
+            .essential(true)
+            .build();
+        assert_eq!(target.port, LibConstants::DEFAULT_HTTP_PORT);
+        assert_eq!(target.timeout_ms, LibConstants::DEFAULT_HTTP_TIMEOUT_MS);
+
+        let config = NetworkConfiguration::builder()
+            .add_target(target)
+            .strategy(CheckStrategy::Consensus)
+            .check_interval_ms(10_000)
+            .build()
+            .unwrap();
+        assert_eq!(config.targets.len(), 1);
+        assert_eq!(config.targets[0].label, "Example");
+        assert_eq!(config.resilience.strategy, CheckStrategy::Consensus);
+        assert_eq!(
+            NetworkConfiguration::builder()
+                .build()
+                .unwrap()
+                .targets
+                .len(),
+            4
+        );
+
+        let invalid = NetworkConfiguration::builder()
+            .add_target(NetworkTarget::builder("", TargetProtocol::Icmp).build())
+            .build();
+        assert_eq!(
+            invalid.unwrap_err(),
+            ConfigError::Invalid(vec![ValidationIssue::EmptyHost { target: 0 }])
+        );
+    }
 }
@@ -0,0 +1,494 @@
+//! Data structures for higher-level diagnostics built on top of check reports.
+//!
+//! Unlike [`NetworkReport`](super::report::NetworkReport), which describes a
+//! single check cycle, the types in this module compare or interpret several
+//! observations to answer a specific troubleshooting question.
+
+use super::net_info::{ConnectionType, PathSegment, ProbeCapabilities, RuntimeEnvironment};
+
+/// The result of comparing two reports captured before and after a VPN toggle.
+///
+/// Produced by [`compare_vpn_toggle`](crate::api::engine::vpn::compare_vpn_toggle).
+/// All deltas are expressed as `after - before`.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct VpnComparison {
+    /// True if a VPN interface was active when the baseline was captured.
+    pub vpn_before: bool,
+
+    /// True if a VPN interface was active when the second report was captured.
+    pub vpn_after: bool,
+
+    /// True if the VPN state actually changed between the two captures.
+    ///
+    /// If this is false, the user most likely did not toggle the VPN within the
+    /// allotted wait window and the remaining fields describe noise only.
+    pub toggle_observed: bool,
+
+    /// Change of the representative latency, in milliseconds.
+    pub latency_delta_ms: i64,
+
+    /// Change of the jitter (standard deviation), in milliseconds.
+    pub jitter_delta_ms: i64,
+
+    /// Change of the packet loss, in percentage points.
+    pub packet_loss_delta_percent: f32,
+
+    /// The active interface before the toggle (e.g. `wlan0`).
+    pub interface_before: String,
+
+    /// The active interface after the toggle (e.g. `tun0`).
+    pub interface_after: String,
+
+    /// The connection type before the toggle.
+    pub connection_type_before: ConnectionType,
+
+    /// The connection type after the toggle.
+    pub connection_type_after: ConnectionType,
+
+    /// True if traffic leaves through a different interface after the toggle.
+    pub route_changed: bool,
+
+    /// The system DNS resolvers before the toggle.
+    pub resolvers_before: Vec<String>,
+
+    /// The system DNS resolvers after the toggle.
+    pub resolvers_after: Vec<String>,
+
+    /// True if the set of system DNS resolvers changed.
+    pub dns_resolver_changed: bool,
+
+    /// True if the VPN is up but DNS queries still go to the pre-VPN resolvers.
+    ///
+    /// This is the classic "DNS leak": the tunnel carries the traffic, but
+    /// name resolution still reveals browsing activity to the local network/ISP.
+    pub dns_leak_suspected: bool,
+
+    /// True if the VPN is up but the active route does not use the tunnel interface.
+    pub route_leak_suspected: bool,
+}
+
+/// Probe results for one DSCP marking towards a single target.
+///
+/// Produced by [compare_dscp_markings](crate::api::probes::qos::compare_dscp_markings).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DscpLatency {
+    /// The DSCP code point the probes were marked with (0 = best effort).
+    pub dscp: u8,
+
+    /// Probes sent with this marking.
+    pub samples: u32,
+
+    /// Probes that succeeded.
+    pub successes: u32,
+
+    /// Median latency of the successful probes.
+    pub median_latency_ms: Option<u64>,
+
+    /// Share of failed probes, in percent.
+    pub loss_percent: f32,
+
+    /// Median latency minus that of the unmarked baseline; negative means
+    /// the marking is served faster.
+    pub delta_ms: Option<i64>,
+}
+
+/// A [Requirements](super::config::Requirements) entry the connection failed.
+///
+/// Measured values are `None` when the measurement itself failed.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UnmetRequirement {
+    /// Download throughput below `min_down_mbps`.
+    Download {
+        required_mbps: f64,
+        measured_mbps: Option<f64>,
+    },
+
+    /// Latency above `max_latency_ms`, or no target answered.
+    Latency {
+        max_ms: u64,
+        measured_ms: Option<u64>,
+    },
+
+    /// Packet loss above `max_loss_percent`.
+    PacketLoss {
+        max_percent: f32,
+        measured_percent: f32,
+    },
+
+    /// UDP traffic did not get through.
+    Udp,
+
+    /// The WebSocket upgrade was refused or stripped on the way.
+    WebSocket,
+}
+
+/// The answer to "is this connection good enough for X?".
+///
+/// Produced by [preflight](crate::api::engine::preflight::preflight).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PreflightReport {
+    /// True if every requirement was met.
+    pub passed: bool,
+
+    /// The requirements that were not met, in declaration order.
+    pub unmet: Vec<UnmetRequirement>,
+
+    /// Measured download throughput in Mbit/s, if it was required.
+    pub down_mbps: Option<f64>,
+
+    /// Measured latency in milliseconds, if latency or loss was required and
+    /// any target answered.
+    pub latency_ms: Option<u64>,
+
+    /// Measured packet loss in percent, if latency or loss was required.
+    pub packet_loss_percent: Option<f32>,
+
+    /// How long the preflight took, in milliseconds.
+    pub duration_ms: u64,
+}
+
+/// The time series an [AnomalyDetected] event refers to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AnomalyMetric {
+    /// The representative latency of a check ([LatencyStats::latency_ms](super::report::LatencyStats)).
+    Latency,
+
+    /// The packet loss of a check, in percent.
+    PacketLoss,
+}
+
+/// A check whose latency or loss fell well outside the recent baseline.
+///
+/// Produced by [AnomalyDetector](crate::api::analysis::anomaly::AnomalyDetector).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct AnomalyDetected {
+    /// Which series was unusual.
+    pub metric: AnomalyMetric,
+
+    /// The `timestamp_ms` of the report that triggered the event.
+    pub timestamp_ms: u64,
+
+    /// The observed value (ms for latency, percent for loss).
+    pub value: f64,
+
+    /// The smoothed baseline the value was compared against.
+    pub expected: f64,
+
+    /// The smoothed standard deviation of the series.
+    pub std_dev: f64,
+
+    /// How many standard deviations the value lies from the baseline.
+    /// Positive means worse (higher) than usual.
+    pub z_score: f64,
+
+    /// The connection type at the time, to tell e.g. a handover from congestion.
+    pub connection_type: ConnectionType,
+}
+
+/// The device moved to another access point between two checks.
+///
+/// Produced by [detect_roam](crate::api::analysis::roaming::detect_roam).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RoamEvent {
+    /// The `timestamp_ms` of the first report on the new access point.
+    pub timestamp_ms: u64,
+
+    /// The network roamed within, if known.
+    pub ssid: Option<String>,
+
+    /// The BSSID of the previous access point.
+    pub from_bssid: String,
+
+    /// The BSSID of the new access point.
+    pub to_bssid: String,
+
+    /// Representative latency of the last check before the handover.
+    pub latency_before_ms: u64,
+
+    /// Representative latency of the first check after it.
+    pub latency_after_ms: u64,
+
+    /// Whether the handover coincided with a latency spike or an outage,
+    /// the signature of sticky-client or slow roaming.
+    pub latency_spike: bool,
+}
+
+/// How the internet looks through one network interface.
+///
+/// Produced by [measure_paths](crate::api::probes::paths::measure_paths).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PathMeasurement {
+    /// The interface the probes were bound to (e.g. `wlan0`, `rmnet0`).
+    pub interface_name: String,
+
+    /// The interface's type, from its name.
+    pub connection_type: ConnectionType,
+
+    /// The local address the probes were sent from.
+    pub local_address: String,
+
+    /// Median TCP handshake time (ms); `None` if every handshake failed.
+    pub latency_ms: Option<u64>,
+
+    /// Share of failed handshakes, in percent.
+    pub packet_loss_percent: f32,
+}
+
+/// Another interface currently offers a clearly better path than the one the
+/// OS routes through.
+///
+/// Produced by [better_path](crate::api::analysis::paths::better_path). Apps
+/// can suggest switching networks, or bind their own traffic to
+/// [better](Self::better).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BetterPathAvailable {
+    /// The `timestamp_ms` of the check that compared the paths.
+    pub timestamp_ms: u64,
+
+    /// The path of the active interface.
+    pub current: PathMeasurement,
+
+    /// The better alternative.
+    pub better: PathMeasurement,
+
+    /// How much faster the alternative is (ms); `None` if the current path
+    /// failed entirely.
+    pub latency_gain_ms: Option<i64>,
+}
+
+/// Aggregated check results for one hour of the day or one day of the week.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeBucket {
+    /// The hour (0-23) or weekday (0 = Monday ... 6 = Sunday), in local time.
+    pub index: u8,
+
+    /// Number of reports that fell into the bucket.
+    pub reports: u32,
+
+    /// Mean latency (ms) of the connected reports, or `None` if there were none.
+    pub avg_latency_ms: Option<u64>,
+
+    /// Mean packet loss (percent) over all reports.
+    pub avg_packet_loss_percent: f32,
+
+    /// Share (0.0 - 1.0) of reports that were offline, poor or unstable.
+    pub degraded_share: f32,
+}
+
+/// A recurring range of hours during which the connection is noticeably worse.
+///
+/// E.g. `start_hour: 20, end_hour: 23` reads "degrades every evening 8-11 pm".
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CongestionWindow {
+    /// First hour of the window (0-23, local time).
+    pub start_hour: u8,
+
+    /// Hour the window ends, exclusive (1-24). May be lower than `start_hour`
+    /// if the window wraps past midnight.
+    pub end_hour: u8,
+
+    /// Mean latency (ms) inside the window.
+    pub avg_latency_ms: Option<u64>,
+
+    /// Mean latency (ms) of all connected reports, for comparison.
+    pub baseline_latency_ms: Option<u64>,
+
+    /// Share (0.0 - 1.0) of degraded reports inside the window.
+    pub degraded_share: f32,
+
+    /// Number of distinct days the window was observed on.
+    pub days_observed: u32,
+}
+
+/// Latency and quality bucketed by local time, built from a report history.
+///
+/// Produced by [profile_time_of_day](crate::api::analysis::time_of_day::profile_time_of_day).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TimeOfDayProfile {
+    /// 24 buckets, hour 0 first.
+    pub by_hour: Vec<TimeBucket>,
+
+    /// 7 buckets, Monday first.
+    pub by_weekday: Vec<TimeBucket>,
+
+    /// Recurring bad hours, earliest start first.
+    pub congestion_windows: Vec<CongestionWindow>,
+}
+
+/// How widely the degradation in a column of a [LatencyHeatmap] spread.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DegradationScope {
+    /// No target was probed in the time bucket.
+    NoData,
+
+    /// Every probed target performed as usual.
+    Normal,
+
+    /// Some targets degraded while most performed as usual: a problem with
+    /// those endpoints or the paths to them.
+    EndpointSpecific,
+
+    /// Most targets degraded at once: a problem on the local network or the
+    /// uplink. With a single target, any degradation counts as global.
+    Global,
+}
+
+/// Latency per target and time bucket, ready to render as a heatmap.
+///
+/// Rows are targets and columns are time buckets, so `latency_ms[row][column]`
+/// is the cell of `targets[row]` starting at `bucket_starts_ms[column]`.
+///
+/// Produced by [latency_heatmap](crate::api::analysis::heatmap::latency_heatmap).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LatencyHeatmap {
+    /// The target labels, one per row, in order of first appearance.
+    pub targets: Vec<String>,
+
+    /// Width (ms) of every column.
+    pub bucket_ms: u64,
+
+    /// Start (Unix ms) of every column. Columns run contiguously from the
+    /// oldest report to the newest, so gaps in the history stay visible as
+    /// empty columns.
+    pub bucket_starts_ms: Vec<u64>,
+
+    /// Mean latency (ms) of the successful probes of each cell; `None` if
+    /// none succeeded.
+    pub latency_ms: Vec<Vec<Option<u64>>>,
+
+    /// Share (0.0 - 1.0) of failed probes of each cell; `None` if the target
+    /// was not probed in that bucket.
+    pub failure_share: Vec<Vec<Option<f32>>>,
+
+    /// Whether the degradation of each column was global or confined to
+    /// some targets.
+    pub scope: Vec<DegradationScope>,
+}
+
+/// How much of the end-to-end degradation happens on the first hop, between
+/// the device and its router (e.g. a poor Wi-Fi link), rather than beyond it.
+///
+/// A `variance_share` of 0.8 reads "80% of your latency variance happens
+/// between you and your router".
+///
+/// Produced by [attribute_degradation](crate::api::analysis::attribution::attribute_degradation).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DegradationAttribution {
+    /// Mean round-trip time (ms) to the gateway.
+    pub gateway_latency_ms: u64,
+
+    /// Standard deviation (ms) of the gateway round-trip times.
+    pub gateway_jitter_ms: u64,
+
+    /// Share (0.0 - 100.0) of gateway probes that went unanswered.
+    pub gateway_loss_percent: f32,
+
+    /// Share (0.0 - 1.0) of the end-to-end latency spent on the first hop.
+    /// `None` if the end-to-end latency is unknown.
+    pub latency_share: Option<f32>,
+
+    /// Share (0.0 - 1.0) of the end-to-end latency variance that the first
+    /// hop accounts for. `None` if the end-to-end latency did not vary.
+    pub variance_share: Option<f32>,
+
+    /// Share (0.0 - 1.0) of the end-to-end packet loss already seen on the
+    /// first hop. `None` if nothing was lost end to end.
+    pub loss_share: Option<f32>,
+
+    /// The segment where most of the variance and loss arise:
+    /// [PathSegment::Lan] for the first hop, [PathSegment::Isp] for everything
+    /// beyond it. `None` if the connection showed neither.
+    pub dominant_segment: Option<PathSegment>,
+}
+
+/// What the crate can do on the current platform, and why some report fields
+/// stay empty.
+///
+/// Produced by [`environment_report`](crate::api::engine::environment::environment_report).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EnvironmentReport {
+    /// The crate version, e.g. `0.1.0`.
+    pub crate_version: String,
+
+    /// The operating system, as in [`std::env::consts::OS`] (e.g. `linux`).
+    pub os: String,
+
+    /// The CPU architecture, as in [`std::env::consts::ARCH`] (e.g. `aarch64`).
+    pub arch: String,
+
+    /// The container or WSL environment the process runs in.
+    pub runtime: RuntimeEnvironment,
+
+    /// The raw-socket based probe modes the process may use.
+    pub capabilities: ProbeCapabilities,
+
+    /// An interface has a non-loopback IPv4 address.
+    pub has_ipv4: bool,
+
+    /// An interface has an IPv6 address beyond loopback and link-local.
+    pub has_ipv6: bool,
+
+    /// The optional Cargo features the crate was built with (`serde`, `snmp`,
+    /// `testsupport`, `bench`, `fuzzing`).
+    pub features: Vec<String>,
+
+    /// What the missing capabilities disable, one entry per gap.
+    pub limitations: Vec<Limitation>,
+}
+
+/// A gap in an [EnvironmentReport] and the report fields it affects.
+///
+/// Shown to users through its [l10n key](super::l10n) rather than English text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Limitation {
+    /// ICMP sockets are not permitted (needs `net.ipv4.ping_group_range` or
+    /// `CAP_NET_RAW`): ICMP targets fall back to TCP ping.
+    NoIcmpSockets,
+
+    /// Raw sockets are not permitted: traceroutes use UDP instead of ICMP.
+    UdpTracerouteOnly,
+
+    /// Neither raw nor UDP traceroute sockets are available: traceroutes only
+    /// show the final hop.
+    NoTraceroute,
+
+    /// The neighbor table is not readable: MAC addresses and vendors of the
+    /// gateway and scanned devices stay empty.
+    NoNeighborTable,
+
+    /// `TCP_INFO` is Linux-only: ECN and MSS clamping detection are
+    /// unavailable.
+    NoTcpInfo,
+
+    /// No interface has an IPv4 address: IPv4 targets cannot be reached.
+    NoIpv4,
+
+    /// No interface has a routable IPv6 address: IPv6 targets cannot be
+    /// reached and address family comparisons find no IPv6 path.
+    NoIpv6,
+
+    /// Running in a container or WSL: the connection type and security flags
+    /// describe the virtual link, not the host's network.
+    Virtualized,
+
+    /// Built without the `snmp` feature: `NetworkReport::snmp_devices` stays
+    /// empty.
+    NoSnmp,
+}
@@ -4,11 +4,14 @@
 //! failure modes that can occur during network probing, such as DNS resolution 
 //! issues, timeouts, and low-level connection errors.
 
+use super::report::IcmpUnreachable;
+
 /// Represents various types of network-related failures encountered during checks.
 ///
 /// Each variant provides specific context about where and why the probe failed, 
 /// allowing for precise diagnostic reporting and UI feedback.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum NetworkError {
     /// Failed to resolve the target hostname to an IP address.
     DnsResolutionError(String),
@@ -21,6 +24,35 @@ pub enum NetworkError {
 
     /// An unexpected or unhandled error occurred within the engine.
     UnknownError(String),
+
+    /// The OS reported an ICMP "Destination Unreachable" for a UDP probe.
+    Unreachable(IcmpUnreachable),
+
+    /// The connection breaks the configured [`SecurityConfig`](super::SecurityConfig).
+    PolicyViolation(PolicyViolation),
+
+    /// The request cannot be carried out as configured, e.g. an unparsable
+    /// subnet or a target without a host.
+    InvalidConfiguration(String),
+
+    /// A probe could not be run at all (as opposed to running and finding the
+    /// network down), e.g. its HTTP client failed to initialize.
+    ProbeFailed(String),
+}
+
+/// The [`SecurityConfig`](super::SecurityConfig) rule a connection breaks.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PolicyViolation {
+    /// A VPN is active while [`block_vpn`](super::SecurityConfig::block_vpn) is set.
+    VpnDetected,
+
+    /// The active interface, named here, is not in
+    /// [`allowed_interfaces`](super::SecurityConfig::allowed_interfaces).
+    InterfaceNotAllowed(String),
+
+    /// The system resolver's answers were flagged as spoofed.
+    DnsSpoofed,
 }
 
 impl std::fmt::Display for NetworkError {
@@ -31,10 +63,31 @@ impl std::fmt::Display for NetworkError {
             NetworkError::ConnectionError(s) => write!(f, "Connection Error: {}", s),
             NetworkError::TimeoutError => write!(f, "Timeout Error: Target failed to respond within the allotted time."),
             NetworkError::UnknownError(s) => write!(f, "Unknown Error: {}", s),
+            NetworkError::Unreachable(kind) => match kind {
+                IcmpUnreachable::Port => write!(f, "Unreachable: port closed on target host"),
+                IcmpUnreachable::Host => write!(f, "Unreachable: host unreachable"),
+                IcmpUnreachable::Network => write!(f, "Unreachable: network unreachable"),
+            },
+            NetworkError::PolicyViolation(rule) => match rule {
+                PolicyViolation::VpnDetected => write!(f, "Policy Violation: VPN detected"),
+                PolicyViolation::InterfaceNotAllowed(name) => {
+                    write!(f, "Policy Violation: interface {} is not allowed", name)
+                }
+                PolicyViolation::DnsSpoofed => write!(f, "Policy Violation: DNS spoofing detected"),
+            },
+            NetworkError::InvalidConfiguration(s) => write!(f, "Invalid Configuration: {}", s),
+            NetworkError::ProbeFailed(s) => write!(f, "Probe Failed: {}", s),
         }
     }
 }
 
+impl From<ConfigError> for NetworkError {
+    /// Converts configuration loading failures into [`NetworkError::InvalidConfiguration`].
+    fn from(err: ConfigError) -> Self {
+        NetworkError::InvalidConfiguration(err.to_string())
+    }
+}
+
 impl From<std::io::Error> for NetworkError {
     /// Automatically converts standard I/O errors into [`NetworkError::ConnectionError`].
     fn from(err: std::io::Error) -> Self {
@@ -56,6 +109,133 @@ impl From<tokio::time::error::Elapsed> for NetworkError {
     }
 }
 
+/// Why a configuration could not be loaded.
+///
+/// Returned by [`NetworkConfiguration::from_file`](super::NetworkConfiguration::from_file).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ConfigError {
+    /// The file could not be read.
+    Io(String),
+
+    /// The extension is neither `.toml` nor `.json`.
+    UnsupportedFormat(String),
+
+    /// The file is not valid TOML/JSON or does not match the configuration schema.
+    Parse(String),
+
+    /// The file parsed, but describes an unusable configuration.
+    Invalid(Vec<ValidationIssue>),
+}
+
+/// A problem found by [`NetworkConfiguration::validate`](super::NetworkConfiguration::validate).
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ValidationIssue {
+    /// The target at this index of `targets` has an empty host.
+    EmptyHost { target: usize },
+
+    /// The target at this index has a timeout of 0 or above
+    /// [`MAX_TARGET_TIMEOUT_MS`](crate::api::constants::LibConstants::MAX_TARGET_TIMEOUT_MS).
+    InvalidTimeout { target: usize, timeout_ms: u64 },
+
+    /// The quality thresholds are not ascending from `excellent` to `poor`.
+    ThresholdsNotMonotonic,
+
+    /// [`CheckStrategy::Quorum`](super::CheckStrategy::Quorum) asks for 0 or
+    /// more successes than there are targets.
+    InvalidQuorum { quorum: u8, targets: usize },
+
+    /// [`CheckStrategy::Weighted`](super::CheckStrategy::Weighted) is used but
+    /// every target has a weight of 0.
+    ZeroTotalWeight,
+
+    /// The [dns_query_name](super::NetworkTarget::dns_query_name) of the
+    /// target at this index has a label over 63 bytes or is over 255 bytes
+    /// in total.
+    InvalidDnsQueryName { target: usize },
+
+    /// An [`EndpointConfig`](super::EndpointConfig) field is not a usable
+    /// HTTP(S) URL, host or `ip:port` socket address.
+    InvalidEndpoint { field: String, value: String },
+}
+
+impl std::fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConfigError::Io(s) => write!(f, "Config Error: cannot read file: {}", s),
+            ConfigError::UnsupportedFormat(ext) => {
+                write!(f, "Config Error: unsupported file format '{}'", ext)
+            }
+            ConfigError::Parse(s) => write!(f, "Config Error: {}", s),
+            ConfigError::Invalid(issues) => {
+                write!(f, "Config Error: ")?;
+                for (i, issue) in issues.iter().enumerate() {
+                    if i > 0 {
+                        write!(f, "; ")?;
+                    }
+                    write!(f, "{}", issue)?;
+                }
+                Ok(())
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for ValidationIssue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ValidationIssue::EmptyHost { target } => write!(f, "target {} has no host", target),
+            ValidationIssue::InvalidTimeout { target, timeout_ms } => {
+                write!(
+                    f,
+                    "target {} has an invalid timeout of {} ms",
+                    target, timeout_ms
+                )
+            }
+            ValidationIssue::ThresholdsNotMonotonic => {
+                write!(f, "quality thresholds must ascend from excellent to poor")
+            }
+            ValidationIssue::InvalidQuorum { quorum, targets } => {
+                write!(
+                    f,
+                    "quorum of {} is not between 1 and the {} targets",
+                    quorum, targets
+                )
+            }
+            ValidationIssue::ZeroTotalWeight => {
+                write!(f, "weighted strategy needs a target with a weight above 0")
+            }
+            ValidationIssue::InvalidDnsQueryName { target } => {
+                write!(f, "target {} has a DNS query name that is too long", target)
+            }
+            ValidationIssue::InvalidEndpoint { field, value } => {
+                write!(f, "endpoint {} is invalid: '{}'", field, value)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// A string that is not the [wire name](super::wire) of any variant.
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ParseEnumError {
+    /// The enum that was parsed, e.g. `ConnectionQuality`.
+    pub enum_name: String,
+    /// The rejected input.
+    pub value: String,
+}
+
+impl std::fmt::Display for ParseEnumError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "Unknown {} '{}'", self.enum_name, self.value)
+    }
+}
+
+impl std::error::Error for ParseEnumError {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,535 @@
+//! Stable, machine-readable localization keys.
+//!
+//! Every enum a UI is likely to show to a user exposes an `l10n_key()` of the
+//! form `<namespace>.<value>` (e.g. `quality.great`, `reason.high_jitter`).
+//! Flutter apps can feed these straight into their ARB/intl lookups instead of
+//! matching English `Debug` output.
+//!
+//! Keys are part of the public API: once released, a key is never renamed or
+//! reused for a different meaning.
+
+use super::{
+    config::{ConnectionQuality, OverlapPolicy},
+    diagnostics::{AnomalyMetric, DegradationScope, Limitation, UnmetRequirement},
+    error::{NetworkError, PolicyViolation, ValidationIssue},
+    net_info::{
+        Confidence, ConnectionType, EcnMarking, Encapsulation, EvidenceSource, NatType,
+        NeighborProtocol, PathSegment, PortalLoginKind, ProbeFallback, RuntimeEnvironment,
+        ServiceReachability,
+    },
+    report::{
+        AddressFamily, IcmpUnreachable, LayerStatus, NetworkScope, QualityReason, SimpleStatus,
+    },
+    scan::ScanMethod,
+    target::TargetProtocol,
+};
+use crate::api::{engine::CircuitBreakerState, monitor::MonitorState};
+use flutter_rust_bridge::frb;
+
+impl ConnectionQuality {
+    /// The localization key, e.g. `quality.captive_portal`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            ConnectionQuality::Excellent => "quality.excellent",
+            ConnectionQuality::Great => "quality.great",
+            ConnectionQuality::Good => "quality.good",
+            ConnectionQuality::Moderate => "quality.moderate",
+            ConnectionQuality::Poor => "quality.poor",
+            ConnectionQuality::Unstable => "quality.unstable",
+            ConnectionQuality::CaptivePortal => "quality.captive_portal",
+            ConnectionQuality::Offline => "quality.offline",
+        }
+        .to_string()
+    }
+}
+
+impl QualityReason {
+    /// The localization key, e.g. `reason.high_jitter`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            QualityReason::NoTargetsReachable => "reason.no_targets_reachable",
+            QualityReason::PacketLoss => "reason.packet_loss",
+            QualityReason::HighJitter => "reason.high_jitter",
+            QualityReason::HighLatency => "reason.high_latency",
+            QualityReason::CaptivePortal => "reason.captive_portal",
+            QualityReason::NoRoute => "reason.no_route",
+        }
+        .to_string()
+    }
+}
+
+impl ConnectionType {
+    /// The localization key, e.g. `connection_type.wifi`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            ConnectionType::Wifi => "connection_type.wifi",
+            ConnectionType::Cellular => "connection_type.cellular",
+            ConnectionType::Ethernet => "connection_type.ethernet",
+            ConnectionType::Vpn => "connection_type.vpn",
+            ConnectionType::Bluetooth => "connection_type.bluetooth",
+            ConnectionType::Loopback => "connection_type.loopback",
+            ConnectionType::Unknown => "connection_type.unknown",
+        }
+        .to_string()
+    }
+}
+
+impl Confidence {
+    /// The localization key, e.g. `confidence.medium`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            Confidence::Low => "confidence.low",
+            Confidence::Medium => "confidence.medium",
+            Confidence::High => "confidence.high",
+        }
+        .to_string()
+    }
+}
+
+impl EvidenceSource {
+    /// The localization key, e.g. `evidence.interface_name`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            EvidenceSource::NotChecked => "evidence.not_checked",
+            EvidenceSource::ProbeFailed => "evidence.probe_failed",
+            EvidenceSource::PlatformHint => "evidence.platform_hint",
+            EvidenceSource::AdapterType => "evidence.adapter_type",
+            EvidenceSource::InterfaceName => "evidence.interface_name",
+            EvidenceSource::ResolverComparison => "evidence.resolver_comparison",
+            EvidenceSource::HttpRedirect => "evidence.http_redirect",
+            EvidenceSource::HttpResponse => "evidence.http_response",
+        }
+        .to_string()
+    }
+}
+
+impl LayerStatus {
+    /// The localization key, e.g. `layer_status.degraded`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            LayerStatus::Pass => "layer_status.pass",
+            LayerStatus::Degraded => "layer_status.degraded",
+            LayerStatus::Fail => "layer_status.fail",
+            LayerStatus::Unknown => "layer_status.unknown",
+        }
+        .to_string()
+    }
+}
+
+impl NetworkScope {
+    /// The localization key, e.g. `scope.local_only`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            NetworkScope::None => "scope.none",
+            NetworkScope::LocalOnly => "scope.local_only",
+            NetworkScope::Internet => "scope.internet",
+        }
+        .to_string()
+    }
+}
+
+impl TargetProtocol {
+    /// The localization key, e.g. `protocol.https`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            TargetProtocol::Tcp => "protocol.tcp",
+            TargetProtocol::Icmp => "protocol.icmp",
+            TargetProtocol::Http => "protocol.http",
+            TargetProtocol::Https => "protocol.https",
+            TargetProtocol::Udp => "protocol.udp",
+            TargetProtocol::Dns => "protocol.dns",
+        }
+        .to_string()
+    }
+}
+
+impl NetworkError {
+    /// The localization key of the error category, e.g. `error.timeout`.
+    ///
+    /// The detail message carried by some variants is diagnostic text from the
+    /// OS and is not localized.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            NetworkError::DnsResolutionError(_) => "error.dns_resolution",
+            NetworkError::ConnectionError(_) => "error.connection",
+            NetworkError::TimeoutError => "error.timeout",
+            NetworkError::UnknownError(_) => "error.unknown",
+            NetworkError::Unreachable(IcmpUnreachable::Port) => "error.port_unreachable",
+            NetworkError::Unreachable(IcmpUnreachable::Host) => "error.host_unreachable",
+            NetworkError::Unreachable(IcmpUnreachable::Network) => "error.network_unreachable",
+            NetworkError::PolicyViolation(PolicyViolation::VpnDetected) => "error.policy_vpn",
+            NetworkError::PolicyViolation(PolicyViolation::InterfaceNotAllowed(_)) => {
+                "error.policy_interface"
+            }
+            NetworkError::PolicyViolation(PolicyViolation::DnsSpoofed) => {
+                "error.policy_dns_spoofed"
+            }
+            NetworkError::InvalidConfiguration(_) => "error.invalid_configuration",
+            NetworkError::ProbeFailed(_) => "error.probe_failed",
+        }
+        .to_string()
+    }
+}
+
+impl UnmetRequirement {
+    /// The localization key, e.g. `requirement.websocket`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            UnmetRequirement::Download { .. } => "requirement.download",
+            UnmetRequirement::Latency { .. } => "requirement.latency",
+            UnmetRequirement::PacketLoss { .. } => "requirement.packet_loss",
+            UnmetRequirement::Udp => "requirement.udp",
+            UnmetRequirement::WebSocket => "requirement.websocket",
+        }
+        .to_string()
+    }
+}
+
+impl CircuitBreakerState {
+    /// The localization key, e.g. `circuit_breaker.half_open`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            CircuitBreakerState::Closed => "circuit_breaker.closed",
+            CircuitBreakerState::Open => "circuit_breaker.open",
+            CircuitBreakerState::HalfOpen => "circuit_breaker.half_open",
+        }
+        .to_string()
+    }
+}
+
+impl MonitorState {
+    /// The localization key, e.g. `monitor_state.paused`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            MonitorState::Stopped => "monitor_state.stopped",
+            MonitorState::Running => "monitor_state.running",
+            MonitorState::Paused => "monitor_state.paused",
+        }
+        .to_string()
+    }
+}
+
+impl OverlapPolicy {
+    /// The localization key, e.g. `overlap_policy.coalesce`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            OverlapPolicy::Skip => "overlap_policy.skip",
+            OverlapPolicy::Queue => "overlap_policy.queue",
+            OverlapPolicy::Coalesce => "overlap_policy.coalesce",
+        }
+        .to_string()
+    }
+}
+
+impl PortalLoginKind {
+    /// The localization key, e.g. `portal_login.click_through`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            PortalLoginKind::Unknown => "portal_login.unknown",
+            PortalLoginKind::ClickThrough => "portal_login.click_through",
+            PortalLoginKind::Credentials => "portal_login.credentials",
+        }
+        .to_string()
+    }
+}
+
+impl RuntimeEnvironment {
+    /// The localization key, e.g. `runtime_environment.docker`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            RuntimeEnvironment::Native => "runtime_environment.native",
+            RuntimeEnvironment::Docker => "runtime_environment.docker",
+            RuntimeEnvironment::Lxc => "runtime_environment.lxc",
+            RuntimeEnvironment::Wsl => "runtime_environment.wsl",
+            RuntimeEnvironment::Container => "runtime_environment.container",
+        }
+        .to_string()
+    }
+}
+
+impl ProbeFallback {
+    /// The localization key, e.g. `probe_fallback.udp_traceroute`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            ProbeFallback::TcpPing => "probe_fallback.tcp_ping",
+            ProbeFallback::UdpTraceroute => "probe_fallback.udp_traceroute",
+        }
+        .to_string()
+    }
+}
+
+impl ScanMethod {
+    /// The localization key, e.g. `scan_method.icmp_sweep`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            ScanMethod::TcpConnect => "scan_method.tcp_connect",
+            ScanMethod::IcmpSweep => "scan_method.icmp_sweep",
+        }
+        .to_string()
+    }
+}
+
+impl PathSegment {
+    /// The localization key, e.g. `path_segment.isp`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            PathSegment::Lan => "path_segment.lan",
+            PathSegment::Isp => "path_segment.isp",
+            PathSegment::Destination => "path_segment.destination",
+        }
+        .to_string()
+    }
+}
+
+impl NatType {
+    /// The localization key, e.g. `nat_type.endpoint_dependent`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            NatType::None => "nat_type.none",
+            NatType::EndpointIndependent => "nat_type.endpoint_independent",
+            NatType::EndpointDependent => "nat_type.endpoint_dependent",
+            NatType::Unknown => "nat_type.unknown",
+        }
+        .to_string()
+    }
+}
+
+impl ServiceReachability {
+    /// The localization key, e.g. `service_reachability.timed_out`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            ServiceReachability::Reachable => "service_reachability.reachable",
+            ServiceReachability::Refused => "service_reachability.refused",
+            ServiceReachability::TimedOut => "service_reachability.timed_out",
+            ServiceReachability::NotTested => "service_reachability.not_tested",
+        }
+        .to_string()
+    }
+}
+
+impl Encapsulation {
+    /// The localization key, e.g. `encapsulation.pppoe`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            Encapsulation::None => "encapsulation.none",
+            Encapsulation::Pppoe => "encapsulation.pppoe",
+            Encapsulation::Tunnel => "encapsulation.tunnel",
+            Encapsulation::Unknown => "encapsulation.unknown",
+        }
+        .to_string()
+    }
+}
+
+impl EcnMarking {
+    /// The localization key, e.g. `ecn.bleached`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            EcnMarking::Preserved => "ecn.preserved",
+            EcnMarking::CongestionExperienced => "ecn.congestion_experienced",
+            EcnMarking::Bleached => "ecn.bleached",
+            EcnMarking::NotTested => "ecn.not_tested",
+        }
+        .to_string()
+    }
+}
+
+impl NeighborProtocol {
+    /// The localization key, e.g. `neighbor_protocol.lldp`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            NeighborProtocol::Lldp => "neighbor_protocol.lldp",
+            NeighborProtocol::Cdp => "neighbor_protocol.cdp",
+        }
+        .to_string()
+    }
+}
+
+impl SimpleStatus {
+    /// The localization key, e.g. `status.yellow`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            SimpleStatus::Green => "status.green",
+            SimpleStatus::Yellow => "status.yellow",
+            SimpleStatus::Red => "status.red",
+        }
+        .to_string()
+    }
+}
+
+impl AddressFamily {
+    /// The localization key, e.g. `address_family.v6`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            AddressFamily::V4 => "address_family.v4",
+            AddressFamily::V6 => "address_family.v6",
+        }
+        .to_string()
+    }
+}
+
+impl AnomalyMetric {
+    /// The localization key, e.g. `anomaly_metric.packet_loss`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            AnomalyMetric::Latency => "anomaly_metric.latency",
+            AnomalyMetric::PacketLoss => "anomaly_metric.packet_loss",
+        }
+        .to_string()
+    }
+}
+
+impl DegradationScope {
+    /// The localization key, e.g. `degradation_scope.endpoint_specific`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            DegradationScope::NoData => "degradation_scope.no_data",
+            DegradationScope::Normal => "degradation_scope.normal",
+            DegradationScope::EndpointSpecific => "degradation_scope.endpoint_specific",
+            DegradationScope::Global => "degradation_scope.global",
+        }
+        .to_string()
+    }
+}
+
+impl Limitation {
+    /// The localization key, e.g. `limitation.no_neighbor_table`.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            Limitation::NoIcmpSockets => "limitation.no_icmp_sockets",
+            Limitation::UdpTracerouteOnly => "limitation.udp_traceroute_only",
+            Limitation::NoTraceroute => "limitation.no_traceroute",
+            Limitation::NoNeighborTable => "limitation.no_neighbor_table",
+            Limitation::NoTcpInfo => "limitation.no_tcp_info",
+            Limitation::NoIpv4 => "limitation.no_ipv4",
+            Limitation::NoIpv6 => "limitation.no_ipv6",
+            Limitation::Virtualized => "limitation.virtualized",
+            Limitation::NoSnmp => "limitation.no_snmp",
+        }
+        .to_string()
+    }
+}
+
+impl PolicyViolation {
+    /// The localization key, e.g. `policy.dns_spoofed`.
+    ///
+    /// [NetworkError::l10n_key] keeps its own `error.policy_*` keys for the
+    /// error that wraps a violation.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            PolicyViolation::VpnDetected => "policy.vpn_detected",
+            PolicyViolation::InterfaceNotAllowed(_) => "policy.interface_not_allowed",
+            PolicyViolation::DnsSpoofed => "policy.dns_spoofed",
+        }
+        .to_string()
+    }
+}
+
+impl ValidationIssue {
+    /// The localization key, e.g. `validation.invalid_quorum`.
+    ///
+    /// The target index, field name and rejected value are left for the UI
+    /// to fill into the message.
+    #[frb(sync)]
+    pub fn l10n_key(&self) -> String {
+        match self {
+            ValidationIssue::EmptyHost { .. } => "validation.empty_host",
+            ValidationIssue::InvalidTimeout { .. } => "validation.invalid_timeout",
+            ValidationIssue::ThresholdsNotMonotonic => "validation.thresholds_not_monotonic",
+            ValidationIssue::InvalidQuorum { .. } => "validation.invalid_quorum",
+            ValidationIssue::ZeroTotalWeight => "validation.zero_total_weight",
+            ValidationIssue::InvalidDnsQueryName { .. } => "validation.invalid_dns_query_name",
+            ValidationIssue::InvalidEndpoint { .. } => "validation.invalid_endpoint",
+        }
+        .to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_l10n_keys_are_namespaced_and_unique() {
+        let keys = [
+            ConnectionQuality::Great.l10n_key(),
+            ConnectionQuality::CaptivePortal.l10n_key(),
+            QualityReason::HighJitter.l10n_key(),
+            ConnectionType::Wifi.l10n_key(),
+            Confidence::Medium.l10n_key(),
+            EvidenceSource::ResolverComparison.l10n_key(),
+            LayerStatus::Unknown.l10n_key(),
+            NetworkScope::LocalOnly.l10n_key(),
+            TargetProtocol::Https.l10n_key(),
+            NetworkError::TimeoutError.l10n_key(),
+            NetworkError::Unreachable(IcmpUnreachable::Port).l10n_key(),
+            UnmetRequirement::WebSocket.l10n_key(),
+            CircuitBreakerState::HalfOpen.l10n_key(),
+            MonitorState::Paused.l10n_key(),
+            OverlapPolicy::Coalesce.l10n_key(),
+            PortalLoginKind::ClickThrough.l10n_key(),
+            RuntimeEnvironment::Docker.l10n_key(),
+            ProbeFallback::UdpTraceroute.l10n_key(),
+            ScanMethod::IcmpSweep.l10n_key(),
+            PathSegment::Isp.l10n_key(),
+            NatType::EndpointDependent.l10n_key(),
+            ServiceReachability::TimedOut.l10n_key(),
+            Encapsulation::Pppoe.l10n_key(),
+            EcnMarking::Bleached.l10n_key(),
+            NeighborProtocol::Lldp.l10n_key(),
+            SimpleStatus::Yellow.l10n_key(),
+            AddressFamily::V6.l10n_key(),
+            AnomalyMetric::PacketLoss.l10n_key(),
+            DegradationScope::EndpointSpecific.l10n_key(),
+            Limitation::NoNeighborTable.l10n_key(),
+            PolicyViolation::DnsSpoofed.l10n_key(),
+            ValidationIssue::InvalidQuorum {
+                quorum: 0,
+                targets: 1,
+            }
+            .l10n_key(),
+        ];
+        assert_eq!(keys[0], "quality.great");
+        assert_eq!(keys[2], "reason.high_jitter");
+        assert_eq!(AddressFamily::V4.l10n_key(), "address_family.v4");
+
+        let unique: HashSet<_> = keys.iter().collect();
+        assert_eq!(unique.len(), keys.len());
+        for key in keys {
+            let (namespace, value) = key.split_once('.').unwrap();
+            assert!(!namespace.is_empty() && !value.is_empty());
+            assert!(key
+                .chars()
+                .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit() || c == '_' || c == '.'));
+        }
+    }
+}
@@ -13,7 +13,11 @@
 //! - **[`net_info`]**: Low-level interface metadata 
 //!   (connection types, security flags, traceroute).
 //! - **[`report`]**: The final consolidated output of a network check cycle.
+//! - **[`diagnostics`]**: Higher-level troubleshooting results built from
+//!   several reports (e.g. VPN before/after comparisons).
+//! - **[`scan`]**: Local network discovery settings and discovered devices.
 //! - **[`error`]**: Categorized failure types for diagnostics.
+//! - **[`l10n`]**: Stable localization keys for user-facing enums.
 //!
 //! # Common Workflow
 //!
@@ -25,18 +29,28 @@
 
 /// Configuration-related data structures (strategies, thresholds, etc).
 pub mod config;
+/// Higher-level troubleshooting results derived from several reports.
+pub mod diagnostics;
 /// Error-related data structures and categories.
 pub mod error;
+/// Stable localization keys for user-facing enums.
+pub mod l10n;
 /// Network interface metadata and security status flags.
 pub mod net_info;
 /// Consolidated check results and statistical metrics.
 pub mod report;
+/// Local network discovery settings and results.
+pub mod scan;
 /// Definitions for network endpoints and probe protocols.
 pub mod target;
+/// Stable string names for the fieldless enums.
+pub mod wire;
 
 // Re-export all sub-module members for easy access via `crate::api::models::*`
 pub use config::*;
+pub use diagnostics::*;
 pub use error::*;
 pub use net_info::*;
 pub use report::*;
+pub use scan::*;
 pub use target::*;
@@ -12,6 +12,7 @@ use crate::api::constants::LibConstants;
 /// Understanding the `ConnectionType` is critical for bandwidth management 
 /// (e.g., deferring large downloads on Cellular) and optimizing latency.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum ConnectionType {
     /// Connected via a wireless local area network (802.11 WiFi).
     /// Typically implies higher bandwidth and lower cost than cellular.
@@ -50,12 +51,85 @@ impl Default for ConnectionType {
     }
 }
 
+/// How much a probe trusts one of its findings.
+///
+/// Heuristics such as interface-name matching can be wrong in both directions,
+/// so every security flag and captive portal verdict carries one of these.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Confidence {
+    /// A guess, or the probe could not complete. Do not act on it alone.
+    Low,
+
+    /// Backed by a heuristic that is usually, but not always, right.
+    Medium,
+
+    /// Backed by the OS, the host application or an unambiguous response.
+    High,
+}
+
+/// Where a finding (or the absence of one) came from.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EvidenceSource {
+    /// The probe did not run; the value is the default.
+    NotChecked,
+
+    /// The probe ran but failed (timeout, resolver or client error).
+    ProbeFailed,
+
+    /// Supplied by the host application via [PlatformHints].
+    PlatformHint,
+
+    /// Read from the OS adapter media type (Windows `IfType`, tunnel type or
+    /// driver description).
+    AdapterType,
+
+    /// Matched against known interface name prefixes (`tun`, `wg`, ...).
+    InterfaceName,
+
+    /// Compared system DNS answers with a trusted DoH resolver.
+    ResolverComparison,
+
+    /// The HTTP probe was redirected.
+    HttpRedirect,
+
+    /// The HTTP probe was answered directly (no redirect).
+    HttpResponse,
+}
+
+/// The confidence of a finding together with how it was derived.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Evidence {
+    /// How much the finding can be trusted.
+    pub confidence: Confidence,
+
+    /// The mechanism that produced it.
+    pub source: EvidenceSource,
+}
+
+impl Evidence {
+    /// Pairs a confidence with its source.
+    pub fn new(confidence: Confidence, source: EvidenceSource) -> Self {
+        Self { confidence, source }
+    }
+}
+
+impl Default for Evidence {
+    /// Nothing was checked, so nothing is known.
+    fn default() -> Self {
+        Self::new(Confidence::Low, EvidenceSource::NotChecked)
+    }
+}
+
 /// The result of a captive portal detection probe.
 ///
 /// Captive portals (intercepting gateways) are common in public spaces like 
 /// airports and cafes. They appear as "connected" to the OS but block all 
 /// non-authentication traffic.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct CaptivePortalStatus {
     /// True if the engine detected that HTTP requests are being redirected.
     ///
@@ -68,6 +142,158 @@ pub struct CaptivePortalStatus {
     /// If available, this is usually the login or "Terms of Service" page. 
     /// The application can use this to open a WebView for the user.
     pub redirect_url: Option<String>,
+
+    /// How sure the probe is about `is_captive_portal`, and why.
+    pub evidence: Evidence,
+
+    /// The `<title>` of the portal page, e.g. "Hotel Guest Wi-Fi".
+    pub page_title: Option<String>,
+
+    /// What the portal page asks of the user. [PortalLoginKind::Unknown]
+    /// if no portal was detected or its page could not be read.
+    pub login_kind: PortalLoginKind,
+
+    /// Whether the page embeds WISPr XML (`WISPAccessGatewayParam`), which
+    /// allows logging in without showing the page.
+    pub wispr: bool,
+}
+
+/// What a captive portal's login page asks of the user.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PortalLoginKind {
+    /// The page was not read or fits neither pattern.
+    Unknown,
+
+    /// Accepting terms or pressing a button is enough.
+    ClickThrough,
+
+    /// The page asks for a password, voucher code, room number or the like.
+    Credentials,
+}
+
+/// Whether an Apple `NWPath` can currently be used (mirrors `NWPath.Status`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ApplePathStatus {
+    /// The path is usable.
+    Satisfied,
+
+    /// The path is not usable (e.g. airplane mode, no interface).
+    Unsatisfied,
+
+    /// The path is not currently usable but may become so after a connection
+    /// attempt (e.g. on-demand VPN, dial-up cellular).
+    RequiresConnection,
+}
+
+/// The interface type of an Apple `NWPath` (mirrors `NWInterface.InterfaceType`).
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ApplePathInterfaceType {
+    /// A Wi-Fi link.
+    Wifi,
+    /// A cellular link.
+    Cellular,
+    /// A wired Ethernet link.
+    WiredEthernet,
+    /// The local loopback interface.
+    Loopback,
+    /// Any other type, including VPN (`utun`) and virtual interfaces.
+    Other,
+}
+
+/// A snapshot of the OS network path on macOS/iOS, as reported by
+/// `NWPathMonitor` (or `SCNetworkReachability` on older systems).
+///
+/// The host application pushes these via
+/// [update_apple_path](crate::api::probes::apple_path::update_apple_path),
+/// which installs them as [PlatformHints].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ApplePathSnapshot {
+    /// Whether the path is usable.
+    pub status: ApplePathStatus,
+
+    /// The type of the interface the path currently uses.
+    pub interface_type: ApplePathInterfaceType,
+
+    /// The BSD name of that interface (e.g. `en0`, `pdp_ip0`, `utun3`).
+    pub interface_name: String,
+
+    /// True if the path is considered expensive (cellular, personal hotspot).
+    pub is_expensive: bool,
+
+    /// True if the path is in Low Data Mode.
+    pub is_constrained: bool,
+
+    /// True if the path is routed through a VPN (e.g. `utun` with a
+    /// `NEVPNManager`/`NETunnelProvider` tunnel up).
+    pub uses_vpn: bool,
+}
+
+/// Authoritative connectivity facts supplied by the host platform.
+///
+/// Mobile OSes know far more about the active link than interface names reveal
+/// (Android `ConnectivityManager`/`NetworkCapabilities`, iOS `NWPath`). The
+/// Flutter layer can pass them in via
+/// [set_platform_hints](crate::api::engine::hints::set_platform_hints). Every
+/// field left as `None` keeps the engine's own detection.
+#[derive(Debug, Clone, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PlatformHints {
+    /// The transport of the default network (e.g. `TRANSPORT_WIFI`).
+    pub connection_type: Option<ConnectionType>,
+
+    /// True if the network is metered (`!NET_CAPABILITY_NOT_METERED` / `isExpensive`).
+    pub is_metered: Option<bool>,
+
+    /// True if the OS restricts background data (Data Saver / Low Data Mode).
+    pub is_constrained: Option<bool>,
+
+    /// True if the default network runs over a VPN (`TRANSPORT_VPN`).
+    pub is_vpn: Option<bool>,
+
+    /// The SSID of the connected Wi-Fi network, if the app has permission to read it.
+    pub ssid: Option<String>,
+
+    /// The BSSID (access point MAC address) of the connected Wi-Fi network,
+    /// if the app has permission to read it. Enables roaming detection.
+    pub bssid: Option<String>,
+
+    /// The name of the interface the OS routes traffic through.
+    pub interface_name: Option<String>,
+}
+
+/// Where the library itself runs.
+///
+/// Containers and WSL see virtual interfaces instead of the host's: the
+/// connection type describes the virtual link (usually Ethernet), and a VPN
+/// running on the host is invisible to the interface heuristics.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RuntimeEnvironment {
+    /// Directly on the host OS, as far as can be told.
+    Native,
+
+    /// Inside a Docker container.
+    Docker,
+
+    /// Inside an LXC/LXD container.
+    Lxc,
+
+    /// Inside the Windows Subsystem for Linux.
+    Wsl,
+
+    /// Inside another container runtime (Podman, Kubernetes, systemd-nspawn).
+    Container,
+}
+
+impl Default for RuntimeEnvironment {
+    /// Returns [`RuntimeEnvironment::Native`] as the default.
+    fn default() -> Self {
+        Self::Native
+    }
 }
 
 /// Represents a single diagnostic hop in a traceroute operation.
@@ -75,6 +301,7 @@ pub struct CaptivePortalStatus {
 /// Traceroutes are used to map the path packets take from the device to 
 /// a target, helping identify exactly where congestion or failure occurs.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TraceHop {
     /// The sequential hop count (Time-To-Live value).
     /// Hop 1 is usually the local router/gateway.
@@ -95,10 +322,471 @@ pub struct TraceHop {
     pub latency_ms: Option<u64>,
 }
 
+/// The stretch of a network path a hop belongs to.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum PathSegment {
+    /// Private addresses right after the device: the home/office network.
+    Lan,
+
+    /// Public hops shared by every traced destination: the ISP and its upstream.
+    Isp,
+
+    /// Hops specific to a single destination.
+    Destination,
+}
+
+/// The traceroute of one destination, as returned by
+/// [trace_route](crate::api::probes::trace_route) or within a [MultiRouteTrace].
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RouteTrace {
+    /// The host as passed in.
+    pub host: String,
+
+    /// The hops, nearest first.
+    /// Empty if the trace could not start.
+    pub hops: Vec<TraceHop>,
+
+    /// Why the trace could not start (e.g. DNS failure, no ICMP socket).
+    pub error: Option<String>,
+
+    /// True if the destination itself answered.
+    pub reached: bool,
+
+    /// The first hop after the last one that answered, if the destination
+    /// was not reached.
+    pub problem_hop: Option<u8>,
+
+    /// The segment the path breaks in, if the destination was not reached.
+    pub problem_segment: Option<PathSegment>,
+
+    /// The method used instead of ICMP echoes, if the process may not read
+    /// the routers' Time Exceeded replies (see [ProbeCapabilities]).
+    pub fallback: Option<ProbeFallback>,
+}
+
+/// Several traceroutes compared against each other.
+///
+/// Produced by [trace_routes](crate::api::probes::traceroute::trace_routes).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MultiRouteTrace {
+    /// One trace per requested host, in request order.
+    pub routes: Vec<RouteTrace>,
+
+    /// The leading hops all successful traces have in common.
+    pub shared_prefix: Vec<TraceHop>,
+
+    /// How many of the shared hops are on the LAN.
+    pub lan_hop_count: u8,
+
+    /// The segment closest to the device in which any trace breaks, or
+    /// `None` if every destination was reached.
+    pub problem_segment: Option<PathSegment>,
+}
+
+/// Which raw-socket based probe modes the process may use.
+///
+/// Produced by
+/// [detect_probe_capabilities](crate::api::probes::capabilities::detect_probe_capabilities).
+/// Probes that lack a capability fall back to an unprivileged method and
+/// note the [ProbeFallback] in their result.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProbeCapabilities {
+    /// An ICMP socket (unprivileged datagram or raw) can be opened, so ICMP
+    /// targets are pinged.
+    pub icmp_echo: bool,
+
+    /// A raw ICMP socket can be opened, which also receives the Time Exceeded
+    /// replies ICMP traceroute relies on.
+    pub icmp_raw: bool,
+
+    /// UDP probes can read ICMP errors without privileges (Linux
+    /// `IP_RECVERR`), enabling UDP traceroute.
+    pub udp_traceroute: bool,
+
+    /// The OS neighbor (ARP) table is readable; Android 10+ denies it.
+    pub arp_table: bool,
+}
+
+/// An unprivileged probe method used in place of a raw-socket one.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ProbeFallback {
+    /// An ICMP target (or the hosts of an ICMP sweep) was probed with a TCP
+    /// connect; a refused connection still counts as reached.
+    TcpPing,
+
+    /// The route was traced with UDP datagrams instead of ICMP echoes.
+    UdpTraceroute,
+}
+
+/// How the NAT in front of the device maps outgoing UDP flows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NatType {
+    /// No translation: the servers see the device's own address and port.
+    None,
+
+    /// One public address/port per local socket, whatever the destination
+    /// ("full cone" and friends). Peer-to-peer traffic usually works.
+    EndpointIndependent,
+
+    /// A new public port per destination ("symmetric" NAT). Peer-to-peer
+    /// traffic usually needs a relay.
+    EndpointDependent,
+
+    /// There is translation, but too few servers answered to tell the
+    /// mapping behavior apart.
+    Unknown,
+}
+
+/// What one reflection server observed about a probe.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ReflectionObservation {
+    /// The server as passed in (`host:port`).
+    pub server: String,
+
+    /// The source address the server saw.
+    pub observed_address: String,
+
+    /// The source port the server saw.
+    pub observed_port: u16,
+
+    /// The TTL the probe arrived with, if the server reports it.
+    pub observed_ttl: Option<u8>,
+
+    /// Hops from the device to the server, derived from `observed_ttl`.
+    pub forward_hops: Option<u8>,
+
+    /// Hops from the server back to the device, derived from the TTL of its
+    /// reply. Only available on Linux/Android.
+    pub reverse_hops: Option<u8>,
+}
+
+/// NAT and path behavior inferred from reflection servers.
+///
+/// Produced by [probe_nat](crate::api::probes::reflection::probe_nat).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct NatReport {
+    /// The mapping behavior of the NAT, if any.
+    pub nat_type: NatType,
+
+    /// The device's own address on the route to the first server.
+    pub local_address: Option<String>,
+
+    /// One entry per server that answered.
+    pub observations: Vec<ReflectionObservation>,
+
+    /// True if the same server saw a different public port for the same
+    /// local socket on a repeated probe: the NAT dropped or rebound the
+    /// mapping, which breaks long-lived UDP flows.
+    pub rebinding_detected: bool,
+
+    /// True if the forward and reverse hop counts to any server differ by
+    /// at least [ASYMMETRIC_ROUTE_HOP_DIFFERENCE](crate::api::constants::LibConstants::ASYMMETRIC_ROUTE_HOP_DIFFERENCE).
+    pub asymmetric_routing_suspected: bool,
+}
+
+/// Whether a TCP service answered a connection attempt.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ServiceReachability {
+    /// The handshake completed.
+    Reachable,
+
+    /// The connection was actively refused (nothing listens, or a router
+    /// rejected it).
+    Refused,
+
+    /// No answer: typically a firewall or missing port forward dropping it.
+    TimedOut,
+
+    /// The attempt could not be made, e.g. the public address is unknown.
+    NotTested,
+}
+
+/// Reachability of a self-hosted service on the public address.
+///
+/// Produced by [check_hairpin](crate::api::probes::hairpin::check_hairpin).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HairpinReport {
+    /// The public IPv4 address that was tested.
+    pub public_address: Option<String>,
+
+    /// The TCP port that was tested.
+    pub port: u16,
+
+    /// Connecting to the public address from inside the LAN.
+    pub from_lan: ServiceReachability,
+
+    /// A reflection server connecting to the public address from outside.
+    pub from_internet: ServiceReachability,
+
+    /// `Some(false)` if the service is reachable from the internet but not
+    /// from the LAN: the router does not support hairpin NAT (NAT loopback).
+    /// `Some(true)` if the LAN connection succeeded, `None` if undecidable.
+    pub hairpin_supported: Option<bool>,
+}
+
+/// What a captive portal's walled garden lets through before login.
+///
+/// Produced by [probe_walled_garden](crate::api::probes::walled_garden::probe_walled_garden).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct WalledGardenReport {
+    /// Whether the system resolver resolved a public name. Portals may answer
+    /// with their own address, so this only shows that lookups get an answer.
+    pub system_dns: bool,
+
+    /// Whether a public resolver answered a direct UDP query.
+    pub public_dns: ServiceReachability,
+
+    /// Whether a certificate-validated HTTPS request completed. Interception
+    /// by the portal reads as [ServiceReachability::Refused].
+    pub https: ServiceReachability,
+
+    /// TCP handshakes towards a host listening on every port, at the address
+    /// the trusted resolver (see
+    /// [EndpointConfig::trusted_dns_servers](super::EndpointConfig::trusted_dns_servers))
+    /// returns for it. [ServiceReachability::NotTested] if that lookup failed.
+    pub ports: Vec<PortReachability>,
+}
+
+/// The outcome of a TCP connection attempt on one port.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PortReachability {
+    /// The destination port.
+    pub port: u16,
+
+    /// Whether the handshake completed.
+    pub reachability: ServiceReachability,
+}
+
+/// A datagram received in reply to a broadcast probe.
+///
+/// Produced by [broadcast_probe](crate::api::probes::broadcast::broadcast_probe).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct BroadcastReply {
+    /// The sender (`ip:port`).
+    pub from: String,
+
+    /// The raw datagram, for the calling protocol to parse.
+    pub payload: Vec<u8>,
+}
+
+/// Result of the LAN multicast check.
+///
+/// Produced by [check_multicast](crate::api::probes::multicast::check_multicast).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MulticastReport {
+    /// The multicast group that was tested (`ip:port`).
+    pub group: String,
+
+    /// Whether the OS accepted the group membership (IGMP join).
+    pub joined: bool,
+
+    /// Whether our own query was looped back, i.e. sending to the group works.
+    pub loopback_received: bool,
+
+    /// Other LAN hosts that answered the query.
+    pub responders: Vec<String>,
+
+    /// `Some(true)` if another host answered, `Some(false)` if sending failed
+    /// or our own query never came back, `None` if the local stack works but
+    /// nobody answered (there may simply be no mDNS devices on the network)
+    /// or the mDNS port could not be bound or the group joined (see `error`).
+    pub multicast_ok: Option<bool>,
+
+    /// Why the check stopped early, if it did.
+    pub error: Option<String>,
+}
+
+/// The verdict of a "check my port" service on a port forward.
+///
+/// Produced by [verify_port_forwarding](crate::api::probes::port_check::verify_port_forwarding).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PortForwardCheck {
+    /// The endpoint as passed in.
+    pub endpoint: String,
+
+    /// The port that was tested.
+    pub port: u16,
+
+    /// Our public address as seen by the service, if it reported one.
+    pub public_address: Option<String>,
+
+    /// Whether the service could connect back.
+    pub reachability: ServiceReachability,
+
+    /// Duration (ms) of the whole request.
+    pub duration_ms: u64,
+}
+
+/// Upstream vs downstream delay towards a timestamping echo server.
+///
+/// Produced by [estimate_one_way_delay](crate::api::probes::one_way_delay::estimate_one_way_delay).
+/// All figures are in milliseconds.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct OneWayDelayEstimate {
+    /// The server as passed in (`host:port`).
+    pub server: String,
+
+    /// Number of exchanges that were answered.
+    pub samples: u32,
+
+    /// Estimated offset of the server clock relative to ours (server minus
+    /// local), taken from the fastest exchange, whose path is assumed to be
+    /// least congested and therefore most symmetric.
+    pub clock_offset_ms: f64,
+
+    /// The lowest round-trip time seen, excluding server processing time.
+    pub min_rtt_ms: f64,
+
+    /// Median device-to-server delay.
+    pub upstream_ms: f64,
+
+    /// Median server-to-device delay.
+    pub downstream_ms: f64,
+
+    /// Median upstream delay above its minimum: queuing on the way out.
+    pub upstream_queuing_ms: f64,
+
+    /// Median downstream delay above its minimum: queuing on the way in.
+    pub downstream_queuing_ms: f64,
+}
+
+/// Encapsulation on the path, inferred from how far the TCP MSS falls
+/// short of the Ethernet default.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum Encapsulation {
+    /// Full-size segments: no extra headers on the path.
+    None,
+
+    /// 8 bytes of overhead, as added by PPPoE on DSL lines.
+    Pppoe,
+
+    /// 20 bytes or more of overhead, typical of VPNs and other tunnels
+    /// (IPsec, WireGuard, GRE, ...).
+    Tunnel,
+
+    /// An overhead that matches no known encapsulation, or no measurement.
+    Unknown,
+}
+
+/// The MSS of one TCP connection to a reference server.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MssObservation {
+    /// The server as passed in (`host:port`).
+    pub server: String,
+
+    /// The maximum segment size the connection settled on, in bytes
+    /// (excluding TCP options).
+    pub mss: Option<u16>,
+
+    /// The path MTU the kernel uses towards the server.
+    pub path_mtu: Option<u16>,
+
+    /// Why the connection or measurement failed, if it did.
+    pub error: Option<String>,
+}
+
+/// MSS clamping and encapsulation findings.
+///
+/// Produced by [detect_mss_clamping](crate::api::probes::mss::detect_mss_clamping).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MssReport {
+    /// One entry per reference server.
+    pub observations: Vec<MssObservation>,
+
+    /// The largest MSS seen across servers; a lower value at a single
+    /// server is that server's choice, not the path's.
+    pub effective_mss: Option<u16>,
+
+    /// True if every server negotiated an MSS below what the local path
+    /// MTU allows: a middlebox rewrites the MSS option.
+    pub clamping_detected: bool,
+
+    /// Bytes missing from `effective_mss` compared with the Ethernet
+    /// default (1460 for IPv4, 1440 for IPv6).
+    pub overhead_bytes: Option<u16>,
+
+    /// The encapsulation `overhead_bytes` points to.
+    pub encapsulation: Encapsulation,
+}
+
+/// What happened to the ECN bits of a marked UDP probe on its way to a
+/// reflection server.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EcnMarking {
+    /// The probe arrived with the ECN-capable mark it was sent with.
+    Preserved,
+
+    /// A router marked the probe Congestion Experienced: ECN is not only
+    /// preserved but actively used on the path.
+    CongestionExperienced,
+
+    /// The mark was cleared on the way ("bleached"); ECN cannot work.
+    Bleached,
+
+    /// No reflection server reported the TOS byte it saw.
+    NotTested,
+}
+
+/// Whether TCP ECN negotiation with one server succeeded.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EcnObservation {
+    /// The server as passed in (`host:port`).
+    pub server: String,
+
+    /// True if the handshake negotiated ECN; `None` if the connection or
+    /// the measurement failed.
+    pub negotiated: Option<bool>,
+
+    /// Why the connection or measurement failed, if it did.
+    pub error: Option<String>,
+}
+
+/// ECN capability of the path.
+///
+/// Produced by [detect_ecn](crate::api::probes::ecn::detect_ecn).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EcnReport {
+    /// Whether the OS requests ECN on outgoing TCP connections; `None` if
+    /// unknown. If it does not, `tcp` can only show the servers' side.
+    pub os_requests_ecn: Option<bool>,
+
+    /// One entry per TCP reference server.
+    pub tcp: Vec<EcnObservation>,
+
+    /// The fate of an ECN-capable UDP probe.
+    pub udp_marking: EcnMarking,
+
+    /// True if ECN was negotiated with any server or the UDP mark survived.
+    pub ecn_capable: bool,
+}
+
 /// Internal representation of security-related attributes for the current connection.
 ///
 /// This structure stores raw findings from the engine's security probes.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SecurityFlags {
     /// True if the active network interface is identified as a tunnel/VPN.
     ///
@@ -119,6 +807,15 @@ pub struct SecurityFlags {
     /// The system-assigned name of the active network interface.
     /// Examples: `wlan0` (Linux WiFi), `en0` (macOS WiFi), `eth0` (Ethernet).
     pub interface_name: String,
+
+    /// Confidence and source of `is_vpn_detected`.
+    pub vpn_evidence: Evidence,
+
+    /// Confidence and source of `is_dns_spoofed`.
+    pub dns_spoof_evidence: Evidence,
+
+    /// Confidence and source of `is_proxy_detected`.
+    pub proxy_evidence: Evidence,
 }
 
 /// The bridge-exported result containing security-related attributes.
@@ -127,6 +824,7 @@ pub struct SecurityFlags {
 /// and is marked as `opaque` for memory efficiency.
 #[frb(opaque)]
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SecurityFlagsResult {
     /// Indicates if a VPN tunnel is active.
     pub is_vpn_detected: bool,
@@ -136,6 +834,12 @@ pub struct SecurityFlagsResult {
     pub is_proxy_detected: bool,
     /// The name of the primary network interface (e.g., `en0`).
     pub interface_name: String,
+    /// Confidence and source of `is_vpn_detected`.
+    pub vpn_evidence: Evidence,
+    /// Confidence and source of `is_dns_spoofed`.
+    pub dns_spoof_evidence: Evidence,
+    /// Confidence and source of `is_proxy_detected`.
+    pub proxy_evidence: Evidence,
 }
 
 impl Default for SecurityFlagsResult {
@@ -146,6 +850,9 @@ impl Default for SecurityFlagsResult {
             is_dns_spoofed: false,
             is_proxy_detected: false,
             interface_name: LibConstants::DEFAULT_INTERFACE_NAME.to_string(),
+            vpn_evidence: Evidence::default(),
+            dns_spoof_evidence: Evidence::default(),
+            proxy_evidence: Evidence::default(),
         }
     }
 }
@@ -158,6 +865,91 @@ impl Default for SecurityFlags {
             is_dns_spoofed: false,
             is_proxy_detected: false,
             interface_name: LibConstants::DEFAULT_INTERFACE_NAME.to_string(),
+            vpn_evidence: Evidence::default(),
+            dns_spoof_evidence: Evidence::default(),
+            proxy_evidence: Evidence::default(),
         }
     }
 }
+
+/// Interface counters read from a managed device over SNMP.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnmpInterface {
+    /// The interface's `ifIndex`.
+    pub index: u32,
+
+    /// The interface's `ifDescr`, e.g. `eth0` or `GigabitEthernet0/1`.
+    pub description: String,
+
+    /// Inbound packets discarded because of errors (`ifInErrors`).
+    pub in_errors: u64,
+
+    /// Outbound packets that could not be sent because of errors (`ifOutErrors`).
+    pub out_errors: u64,
+
+    /// Inbound packets dropped without an error, e.g. for lack of buffer space
+    /// (`ifInDiscards`).
+    pub in_discards: u64,
+
+    /// Outbound packets dropped without an error (`ifOutDiscards`).
+    pub out_discards: u64,
+}
+
+/// What an SNMP query of one device returned.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SnmpDeviceInfo {
+    /// The queried device's address.
+    pub address: String,
+
+    /// The device's `sysDescr` (vendor, model and firmware, as free text).
+    pub sys_descr: Option<String>,
+
+    /// The device's interfaces, in `ifIndex` order.
+    pub interfaces: Vec<SnmpInterface>,
+
+    /// Why the query failed, if it did.
+    pub error: Option<String>,
+}
+
+/// The discovery protocol a [LinkNeighbor] was announced with.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NeighborProtocol {
+    /// IEEE 802.1AB Link Layer Discovery Protocol.
+    Lldp,
+
+    /// Cisco Discovery Protocol.
+    Cdp,
+}
+
+/// The switch (or router) port the active interface is plugged into, as
+/// announced via LLDP or CDP.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LinkNeighbor {
+    /// The protocol the announcement was received with.
+    pub protocol: NeighborProtocol,
+
+    /// The neighbor's chassis ID, usually its MAC address. For CDP, the device ID.
+    pub chassis_id: Option<String>,
+
+    /// The neighbor's host name (e.g. `sw-floor2`).
+    pub system_name: Option<String>,
+
+    /// Free-text description of the neighbor (model, firmware).
+    pub system_description: Option<String>,
+
+    /// The neighbor's hardware platform (CDP only).
+    pub platform: Option<String>,
+
+    /// The neighbor's port this device is connected to (e.g. `Gi1/0/17`).
+    pub port_id: Option<String>,
+
+    /// The administrator's description of that port (LLDP only).
+    pub port_description: Option<String>,
+
+    /// An IP address the neighbor can be managed at.
+    pub management_address: Option<String>,
+}
@@ -4,15 +4,21 @@
 //! is the final "snapshot" containing high-level status, low-level metrics, 
 //! security findings, and individual target results.
 
+use super::config::{CheckStrategy, ConnectionQuality};
+use super::diagnostics::{AnomalyDetected, BetterPathAvailable, DegradationAttribution, RoamEvent};
+use super::net_info::{
+    CaptivePortalStatus, ConnectionType, ProbeFallback, RouteTrace, RuntimeEnvironment,
+    SnmpDeviceInfo, WalledGardenReport,
+};
 use crate::api::models::SecurityFlagsResult;
-use super::config::ConnectionQuality;
-use super::net_info::ConnectionType;
+use std::net::IpAddr;
 
 /// Detailed outcome of a connectivity check against a specific target.
 ///
 /// Each configured target (e.g., Google DNS, Cloudflare) generates its 
 /// own `TargetReport` during a check cycle.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct TargetReport {
     /// The unique label identifying the target (e.g., "Primary API Gateway").
     pub label: String,
@@ -38,6 +44,149 @@ pub struct TargetReport {
     /// Failure of an essential target has a higher weight in the 
     /// [`CheckStrategy`](super::config::CheckStrategy).
     pub is_essential: bool,
+
+    /// Time (ms) spent resolving the target's hostname.
+    ///
+    /// `None` if the host is an IP literal or resolution never finished.
+    pub dns_ms: Option<u64>,
+
+    /// Time (ms) spent establishing the TCP connection, excluding DNS.
+    ///
+    /// Only measured for [TargetProtocol::Tcp](super::target::TargetProtocol::Tcp)
+    /// targets, and as the echo round trip for
+    /// [TargetProtocol::Icmp](super::target::TargetProtocol::Icmp) ones; `None`
+    /// otherwise or if the handshake never completed. HTTP(S) targets leave
+    /// it empty, as the client does not expose its handshake. Comparing
+    /// it with `dns_ms` tells a slow resolver apart from a slow path.
+    pub connect_ms: Option<u64>,
+
+    /// Set when the OS reported an ICMP "Destination Unreachable" for the probe.
+    ///
+    /// Only UDP-based probes ([TargetProtocol::Udp](super::target::TargetProtocol::Udp)
+    /// and [TargetProtocol::Dns](super::target::TargetProtocol::Dns)) fill this
+    /// in, and only on platforms that surface the error on the socket. `None` on
+    /// a failed UDP probe means the datagram was dropped silently.
+    pub unreachable: Option<IcmpUnreachable>,
+
+    /// How long (ms) the probe ran before it timed out.
+    ///
+    /// Only set for timed-out probes of targets with
+    /// [report_timeout_progress](super::target::NetworkTarget::report_timeout_progress);
+    /// `latency_ms` stays 0 either way.
+    pub timed_out_after_ms: Option<u64>,
+
+    /// When (ms after the start) a timed-out probe last made progress, e.g.
+    /// finished resolving or connecting. Set alongside `timed_out_after_ms`,
+    /// and `None` if the probe never got past its first phase.
+    ///
+    /// Close to `timed_out_after_ms`, the target was slow to answer a probe
+    /// that otherwise went through; close to 0, it stalled early.
+    pub last_progress_ms: Option<u64>,
+
+    /// The IP address the probe was sent to, e.g. `2606:4700::1111`.
+    ///
+    /// Shows which answer of a round-robin record was used and whether the
+    /// probe fell back to IPv4. `None` if the host never resolved; for
+    /// HTTP(S) targets, the address the connection was made to.
+    pub resolved_addr: Option<String>,
+
+    /// The family of [resolved_addr](Self::resolved_addr).
+    pub address_family: Option<AddressFamily>,
+
+    /// True if the target was not probed because a higher-priority target
+    /// answered under [CheckStrategy::Fallback](super::config::CheckStrategy::Fallback).
+    /// A skipped target is neither a success nor a failure.
+    pub skipped: bool,
+
+    /// How the probe used its [timeout](super::target::NetworkTarget::timeout_ms)
+    /// budget. `None` if the target was not probed.
+    pub timings: Option<ProbeTimings>,
+
+    /// Latency (ms) of this target in every sample of the check that probed
+    /// it, oldest first; `None` where it failed. Samples that
+    /// [skipped](Self::skipped) it are left out, so they do not count as loss.
+    ///
+    /// The other fields describe the last sample only. Use these to compute
+    /// per-target jitter and loss. Empty for reports from outside a sampled
+    /// check, e.g. [check_target](crate::api::probes::check_target).
+    pub samples: Vec<Option<u64>>,
+
+    /// The method used instead of the target's protocol, if the process
+    /// lacks the privileges for it (e.g. [ProbeFallback::TcpPing] for ICMP
+    /// targets without ICMP sockets).
+    pub fallback: Option<ProbeFallback>,
+}
+
+/// How much of its timeout budget a probe spent, and on what.
+///
+/// Percentages are relative to the budget, so a `used_percent` that stays
+/// far below 100 on a healthy network means `timeout_ms` can be tightened,
+/// and one near 100 on successes means it is too tight. DNS done up front
+/// (see [pre_resolve_targets](super::config::NetworkConfiguration::pre_resolve_targets))
+/// is outside the budget and not counted.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ProbeTimings {
+    /// The target's `timeout_ms`.
+    pub budget_ms: u64,
+
+    /// How long (ms) the probe ran, whether it succeeded or not.
+    pub elapsed_ms: u64,
+
+    /// Share of the budget spent resolving the host.
+    pub dns_percent: f32,
+
+    /// Share of the budget spent on the TCP handshake or ICMP round trip.
+    pub connect_percent: f32,
+
+    /// Share of the budget spent on everything else, mostly waiting for the
+    /// target's answer.
+    pub wait_percent: f32,
+
+    /// Share of the budget used in total; 100 for a probe that timed out.
+    pub used_percent: f32,
+}
+
+impl ProbeTimings {
+    /// Splits `elapsed_ms` of a `budget_ms` probe into its phases.
+    pub fn new(
+        budget_ms: u64,
+        elapsed_ms: u64,
+        dns_ms: Option<u64>,
+        connect_ms: Option<u64>,
+    ) -> Self {
+        let percent = |ms: u64| {
+            if budget_ms == 0 {
+                0.0
+            } else {
+                (ms as f64 * 100.0 / budget_ms as f64).min(100.0) as f32
+            }
+        };
+        let dns_ms = dns_ms.unwrap_or(0);
+        let connect_ms = connect_ms.unwrap_or(0);
+        Self {
+            budget_ms,
+            elapsed_ms,
+            dns_percent: percent(dns_ms),
+            connect_percent: percent(connect_ms),
+            wait_percent: percent(elapsed_ms.saturating_sub(dns_ms + connect_ms)),
+            used_percent: percent(elapsed_ms),
+        }
+    }
+}
+
+/// The kind of ICMP "Destination Unreachable" received for a probe.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum IcmpUnreachable {
+    /// The host answered but nothing listens on the port.
+    Port,
+
+    /// A router reported the host itself as unreachable.
+    Host,
+
+    /// A router has no route to the host's network.
+    Network,
 }
 
 /// A suite of statistical metrics derived from multiple latency samples.
@@ -46,6 +195,7 @@ pub struct TargetReport {
 /// of the connection, helping identify subtle issues like bufferbloat 
 /// or interference.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct LatencyStats {
     /// The representative latency value (usually the mean of all successful samples).
     /// Measured in milliseconds.
@@ -75,11 +225,62 @@ pub struct LatencyStats {
     /// Often indicates transient congestion or "spikes".
     pub max_latency_ms: Option<u64>,
 
+    /// Median latency of the successful samples (linearly interpolated).
+    pub p50_ms: Option<u64>,
+
+    /// 90th percentile latency of the successful samples.
+    pub p90_ms: Option<u64>,
+
+    /// 95th percentile latency of the successful samples.
+    pub p95_ms: Option<u64>,
+
+    /// 99th percentile latency of the successful samples. With the usual
+    /// handful of samples per check this is close to the maximum; it gets
+    /// meaningful with `num_jitter_samples` of 20 and more.
+    pub p99_ms: Option<u64>,
+
     /// A composite score from 0 (broken) to 100 (perfect) representing overall stability.
     ///
     /// The engine calculates this based on jitter consistency and packet loss. 
     /// Scores below 70 are typically flagged as [`ConnectionQuality::Unstable`].
     pub stability_score: u8,
+
+    /// Number of samples whose latency exceeded the median by more than
+    /// [spike_ratio_threshold](super::config::ResilienceConfig::spike_ratio_threshold).
+    pub spike_count: u32,
+
+    /// Positions (0-based, in collection order among the successful samples)
+    /// of the samples counted in `spike_count`.
+    pub spike_sample_indices: Vec<u32>,
+}
+
+/// Why a check ended up with the [ConnectionQuality] it reports.
+///
+/// Reasons are listed in [NetworkStatus::reasons] so a UI can explain a
+/// downgrade ("your connection is unstable because of packet loss") instead of
+/// only showing the bucket.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum QualityReason {
+    /// No target could be reached.
+    NoTargetsReachable,
+
+    /// Packet loss exceeded the configured critical threshold.
+    PacketLoss,
+
+    /// Latency varied so much that the stability score forced a downgrade.
+    HighJitter,
+
+    /// The latency itself only qualifies for a moderate or worse bucket.
+    HighLatency,
+
+    /// Traffic is intercepted by a captive portal.
+    CaptivePortal,
+
+    /// No interface has a route to the internet or any target, so the check
+    /// gave up without probing (see
+    /// [fast_fail_offline](super::config::NetworkConfiguration::fast_fail_offline)).
+    NoRoute,
 }
 
 /// A high-level summary of the network's current state.
@@ -87,6 +288,7 @@ pub struct LatencyStats {
 /// This structure is what most UI layers will use to determine whether to 
 /// show a "Connected" or "Offline" banner.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkStatus {
     /// True if the network is functionally "up" based on the configured strategy.
     pub is_connected: bool,
@@ -99,11 +301,171 @@ pub struct NetworkStatus {
     /// Detailed statistical breakdown for performance analysis.
     pub latency_stats: LatencyStats,
 
-    /// The label of the specific target that responded the fastest in this check.
+    /// The label of the target that won the most samples of this check.
     ///
-    /// Useful for debugging and understanding which server is the 
-    /// current "closest" endpoint.
+    /// A sample's winner is its fastest successful target under
+    /// [`CheckStrategy::Race`](super::config::CheckStrategy::Race), or the
+    /// median-latency success under
+    /// [`CheckStrategy::Consensus`](super::config::CheckStrategy::Consensus)
+    /// (see [select_winner](crate::api::engine::sampler::select_winner) for
+    /// the other strategies). Useful for debugging and understanding which server is the
+    /// current "closest" endpoint. Empty if no sample counted.
     pub winner_target: String,
+
+    /// The winner of every sample, in order; `None` where the sample did not
+    /// count towards the result.
+    pub winners_per_sample: Vec<Option<String>>,
+
+    /// The factors that pulled `quality` down, in order of severity.
+    ///
+    /// Empty if the quality is purely latency-based and at least
+    /// [ConnectionQuality::Good].
+    pub reasons: Vec<QualityReason>,
+}
+
+/// The verdict for a single rung of the layered connectivity ladder.
+///
+/// UI layers can map these directly to traffic-light colors.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum LayerStatus {
+    /// The layer is working as expected.
+    Pass,
+
+    /// The layer works, but with partial failures or suspicious results.
+    Degraded,
+
+    /// The layer is broken.
+    Fail,
+
+    /// There was not enough evidence to judge the layer (e.g. no probe exercised
+    /// it, or a lower layer already failed).
+    Unknown,
+}
+
+/// A per-layer (OSI-style) breakdown of a check cycle.
+///
+/// Each field answers "does this layer work?" so that a UI can render a simple
+/// ladder (link → network → DNS → transport → application) and point the user
+/// to the lowest failing rung instead of parsing raw flags.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LayeredDiagnosis {
+    /// Physical/data-link layer: is there an active network interface?
+    pub link: LayerStatus,
+
+    /// IP/gateway layer: can packets leave the local network at all?
+    pub network: LayerStatus,
+
+    /// Name resolution: do hostname targets resolve, and can the resolver be trusted?
+    pub dns: LayerStatus,
+
+    /// Transport layer: do raw TCP/ICMP probes succeed?
+    pub transport: LayerStatus,
+
+    /// Application layer: do HTTP(S) probes succeed without interception?
+    pub application: LayerStatus,
+}
+
+/// How far out from the device connectivity reaches.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum NetworkScope {
+    /// Not even the local network is reachable.
+    None,
+
+    /// The gateway or a configured LAN target answers, but the internet does not.
+    ///
+    /// Typical for airplane mode with Wi-Fi on, isolated industrial networks or
+    /// a router that lost its uplink.
+    LocalOnly,
+
+    /// Internet targets are reachable.
+    Internet,
+}
+
+/// A traffic-light summary of a [NetworkReport], e.g. for a UI badge.
+///
+/// See [NetworkReport::simple_status] for how a report maps onto it.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum SimpleStatus {
+    /// The internet is reachable and works well.
+    Green,
+
+    /// The internet is reachable, but slow, unreliable or not to be trusted.
+    Yellow,
+
+    /// The internet is not usable.
+    Red,
+}
+
+/// The result of a LAN-only check (see [check_local_network](crate::api::engine::local::check_local_network)).
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalReachabilityReport {
+    /// The UTC timestamp (milliseconds since epoch) when the check began.
+    pub timestamp_ms: u64,
+
+    /// Monotonic clock reading (ms) when the check began; see
+    /// [NetworkReport::monotonic_ms].
+    pub monotonic_ms: u64,
+
+    /// [NetworkScope::LocalOnly] if anything on the LAN answered, else [NetworkScope::None].
+    pub scope: NetworkScope,
+
+    /// The default gateway address, if the platform exposes it.
+    pub gateway: Option<String>,
+
+    /// The gateway probe result; `None` if no gateway was found.
+    pub gateway_report: Option<TargetReport>,
+
+    /// Results for [NetworkConfiguration::lan_targets](super::config::NetworkConfiguration::lan_targets).
+    pub target_reports: Vec<TargetReport>,
+}
+
+/// One target's outcome within a single sample.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SampleTargetLatency {
+    /// The target's label.
+    pub label: String,
+
+    /// Latency in milliseconds, or `None` if the probe failed.
+    pub latency_ms: Option<u64>,
+}
+
+/// The raw outcome of one sampling round (every target probed once).
+///
+/// Only collected if
+/// [include_raw_samples](super::config::NetworkConfiguration::include_raw_samples)
+/// is set, for users who want to run their own statistics.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SampleRecord {
+    /// 0-based position of the sample within the check.
+    pub index: u32,
+
+    /// UTC timestamp (milliseconds since epoch) when the sample started.
+    pub timestamp_ms: u64,
+
+    /// Monotonic clock reading (ms) when the sample started; see
+    /// [NetworkReport::monotonic_ms].
+    pub monotonic_ms: u64,
+
+    /// Per-target results, in configuration order. Targets the sample
+    /// skipped under [CheckStrategy::Fallback](super::config::CheckStrategy::Fallback)
+    /// are left out.
+    pub latencies: Vec<SampleTargetLatency>,
+
+    /// The sample's winner (see [NetworkStatus::winner_target]); `None` if the
+    /// sample did not count.
+    pub winner_target: Option<String>,
+
+    /// True if the sample counted as successful under the configured
+    /// [CheckStrategy](super::config::CheckStrategy) and contributed a latency
+    /// to [LatencyStats].
+    pub counted: bool,
 }
 
 /// The comprehensive report produced by a network reachability check.
@@ -111,12 +473,25 @@ pub struct NetworkStatus {
 /// This is the final object returned by the engine after a manual check 
 /// or emitted via a stream during periodic checks.
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct NetworkReport {
     /// The UTC timestamp (milliseconds since epoch) when the check began.
     ///
     /// Used to track the "freshness" of the report and for historical analysis.
+    /// Follows the system clock, so it may jump; use `monotonic_ms` to order
+    /// reports or measure the time between them.
     pub timestamp_ms: u64,
 
+    /// Monotonic clock reading (ms) when the check began.
+    ///
+    /// Only differences between readings are meaningful (see
+    /// [monotonic_ms](crate::api::engine::clock::monotonic_ms)), but they are
+    /// immune to NTP steps and manual clock changes.
+    pub monotonic_ms: u64,
+
+    /// How long the check took (ms), measured on the monotonic clock.
+    pub duration_ms: u64,
+
     /// High-level connectivity status and quality assessment.
     pub status: NetworkStatus,
 
@@ -126,9 +501,472 @@ pub struct NetworkReport {
     /// Results of security-specific probes (VPN, DNS spoofing, etc.).
     pub security_flags_result: SecurityFlagsResult,
 
+    /// The captive portal probe's verdict, with its confidence.
+    ///
+    /// `None` if the probe was skipped (e.g. the device is offline).
+    pub captive_portal: Option<CaptivePortalStatus>,
+
     /// An array containing the individual results for every target checked.
     ///
     /// Useful for granular debugging and displaying detailed per-server 
     /// status in a "Network Diagnostics" screen.
     pub target_reports: Vec<TargetReport>,
+
+    /// Per-layer verdicts derived from the target results and security probes.
+    pub layers: LayeredDiagnosis,
+
+    /// True if the OS reports the path as expensive (cellular, personal hotspot).
+    ///
+    /// Only known when the host feeds OS path data into the engine; `false` otherwise.
+    pub is_expensive: bool,
+
+    /// True if the OS reports the path as constrained (e.g. iOS Low Data Mode).
+    ///
+    /// Only known when the host feeds OS path data into the engine; `false` otherwise.
+    pub is_constrained: bool,
+
+    /// The SSID of the connected Wi-Fi network, if supplied via
+    /// [PlatformHints](super::net_info::PlatformHints).
+    pub ssid: Option<String>,
+
+    /// The BSSID of the connected Wi-Fi access point, from
+    /// [PlatformHints](super::net_info::PlatformHints). `None` if unknown.
+    pub bssid: Option<String>,
+
+    /// How far connectivity reaches. Distinguishes a working LAN without
+    /// internet from no network at all.
+    pub scope: NetworkScope,
+
+    /// Whether the check ran inside a container or WSL, where
+    /// [connection_type](Self::connection_type) and
+    /// [security_flags_result](Self::security_flags_result) describe the
+    /// virtual interface rather than the host's network.
+    pub environment: RuntimeEnvironment,
+
+    /// Whether the connection problem most likely lies with the ISP.
+    ///
+    /// Only evaluated when the gateway answers but no internet target does
+    /// ([NetworkScope::LocalOnly]) and
+    /// [isp_status_urls](super::config::NetworkConfiguration::isp_status_urls)
+    /// are configured. `Some(true)` if a status endpoint reports an outage,
+    /// `Some(false)` if one reports normal operation (pointing at the local
+    /// modem or router instead), `None` if nothing could be learned.
+    pub likely_isp_outage: Option<bool>,
+
+    /// Every sampling round of the check, oldest first.
+    ///
+    /// Empty unless
+    /// [include_raw_samples](super::config::NetworkConfiguration::include_raw_samples)
+    /// is set.
+    pub raw_samples: Vec<SampleRecord>,
+
+    /// SNMP readings of the gateway and managed switches.
+    ///
+    /// Only filled by [PipelineStep::Snmp](crate::api::engine::pipeline::PipelineStep)
+    /// (feature `snmp`); empty otherwise.
+    pub snmp_devices: Vec<SnmpDeviceInfo>,
+
+    /// IPv4 vs IPv6 performance towards the dual-stack targets.
+    ///
+    /// Only filled when [compare_address_families](super::config::NetworkConfiguration::compare_address_families)
+    /// is set or [PipelineStep::AddressFamilies](crate::api::engine::pipeline::PipelineStep)
+    /// ran, and at least one target resolves to both families.
+    pub family_comparison: Option<FamilyComparison>,
+
+    /// Whether multicast works on the LAN; see
+    /// [MulticastReport::multicast_ok](super::net_info::MulticastReport::multicast_ok).
+    ///
+    /// Only evaluated when [check_multicast](super::config::NetworkConfiguration::check_multicast)
+    /// is set or [PipelineStep::Multicast](crate::api::engine::pipeline::PipelineStep)
+    /// ran.
+    pub multicast_ok: Option<bool>,
+
+    /// What the walled garden still allows; only probed when a captive portal
+    /// was detected.
+    pub walled_garden: Option<WalledGardenReport>,
+
+    /// Set when the BSSID changed since the previous check of a
+    /// [NetworkMonitor](crate::api::monitor::NetworkMonitor) or
+    /// [ReachabilityEngine](crate::api::engine::ReachabilityEngine).
+    pub roam: Option<RoamEvent>,
+
+    /// Latency and loss anomalies of this check against the baseline of the
+    /// previous checks of a [NetworkMonitor](crate::api::monitor::NetworkMonitor)
+    /// or [ReachabilityEngine](crate::api::engine::ReachabilityEngine). See
+    /// [AnomalyDetector](crate::api::analysis::anomaly::AnomalyDetector).
+    pub anomalies: Vec<AnomalyDetected>,
+
+    /// Another interface that beats the active one. Only evaluated when
+    /// [compare_paths](super::config::NetworkConfiguration::compare_paths) is
+    /// set or [PipelineStep::Paths](crate::api::engine::pipeline::PipelineStep)
+    /// ran.
+    pub better_path: Option<BetterPathAvailable>,
+
+    /// How much of the degradation happens between the device and its
+    /// router. Only evaluated when
+    /// [attribute_degradation](super::config::NetworkConfiguration::attribute_degradation)
+    /// is set or [PipelineStep::Attribution](crate::api::engine::pipeline::PipelineStep)
+    /// ran, and only if the gateway answered.
+    pub attribution: Option<DegradationAttribution>,
+
+    /// The route to the first configured target. Only traced when
+    /// [PipelineStep::Traceroute](crate::api::engine::pipeline::PipelineStep)
+    /// ran.
+    pub traceroute: Option<RouteTrace>,
+
+    /// True if the check was cancelled before it finished. The report then
+    /// covers only the samples and probes that completed.
+    pub cancelled: bool,
+
+    /// True if the check ran out of its
+    /// [overall_timeout_ms](super::config::NetworkConfiguration::overall_timeout_ms)
+    /// budget. Like a cancelled report, it only covers what completed in time.
+    pub truncated: bool,
+
+    /// The [strategy](super::config::ResilienceConfig::strategy) that decided
+    /// which samples counted and which target won.
+    pub strategy: CheckStrategy,
+}
+
+/// A summary of the reports of one minute or one hour.
+///
+/// Long-running engines fold old reports into these (see
+/// [ReachabilityEngine::history_aggregates](crate::api::engine::ReachabilityEngine::history_aggregates))
+/// so memory stays bounded while trends remain queryable.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HistoryAggregate {
+    /// UTC start of the period (milliseconds since epoch), aligned to its resolution.
+    pub start_ms: u64,
+
+    /// Length of the period: 60 000 (minute) or 3 600 000 (hour).
+    pub resolution_ms: u64,
+
+    /// Number of reports summarized.
+    pub reports: u32,
+
+    /// Number of those reports that were connected.
+    pub connected_reports: u32,
+
+    /// Number of those reports that were offline or of poor or unstable
+    /// quality.
+    pub degraded_reports: u32,
+
+    /// Mean latency (ms) of the connected reports.
+    pub avg_latency_ms: Option<u64>,
+
+    /// Highest latency (ms) of the connected reports.
+    pub max_latency_ms: Option<u64>,
+
+    /// Mean packet loss (percent) over all reports.
+    pub avg_packet_loss_percent: f32,
+
+    /// The worst stability score seen in the period.
+    pub min_stability_score: u8,
+}
+
+/// How one target fared over the checks of a
+/// [ReachabilityEngine](crate::api::engine::ReachabilityEngine).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TargetHealth {
+    /// The [label](super::target::NetworkTarget::label) of the target.
+    pub label: String,
+
+    /// Checks in a row in which the target failed; 0 after a success.
+    pub consecutive_failures: u32,
+
+    /// UTC time (milliseconds since epoch) of the last check the target
+    /// answered in.
+    pub last_success_ms: Option<u64>,
+
+    /// UTC time (milliseconds since epoch) of the last check the target
+    /// failed in.
+    pub last_failure_ms: Option<u64>,
+}
+
+/// The state of a [ReachabilityEngine](crate::api::engine::ReachabilityEngine)
+/// worth keeping across process restarts.
+///
+/// Taken with [snapshot](crate::api::engine::ReachabilityEngine::snapshot) and
+/// restored with [with_snapshot](crate::api::engine::ReachabilityEngine::with_snapshot),
+/// so an app can show the last known state right after launch instead of
+/// waiting for its first check. The history and anomaly baseline are not
+/// included.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EngineSnapshot {
+    /// UTC time (milliseconds since epoch) the snapshot was taken.
+    pub taken_at_ms: u64,
+
+    /// The most recent report, if any check had completed.
+    pub last_report: Option<NetworkReport>,
+
+    /// Consecutive checks in which an essential target failed.
+    pub breaker_consecutive_failures: u8,
+
+    /// UTC time (milliseconds since epoch) the circuit breaker opened;
+    /// `None` while it is closed.
+    pub breaker_opened_at_ms: Option<u64>,
+
+    /// Per-target health, in configuration order.
+    pub target_health: Vec<TargetHealth>,
+}
+
+/// An IP address family.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum AddressFamily {
+    V4,
+    V6,
+}
+
+impl From<IpAddr> for AddressFamily {
+    fn from(ip: IpAddr) -> Self {
+        match ip {
+            IpAddr::V4(_) => AddressFamily::V4,
+            IpAddr::V6(_) => AddressFamily::V6,
+        }
+    }
+}
+
+/// Probe results over one address family.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FamilyStats {
+    /// The family the probes were sent over.
+    pub family: AddressFamily,
+
+    /// Probes sent.
+    pub probes: u32,
+
+    /// Median latency (ms) of the successful probes.
+    pub latency_ms: Option<u64>,
+
+    /// Share of failed probes, in percent.
+    pub packet_loss_percent: f32,
+}
+
+/// IPv4 vs IPv6 towards the same dual-stack targets.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct FamilyComparison {
+    /// Labels of the targets that resolved to both families.
+    pub targets: Vec<String>,
+
+    pub ipv4: FamilyStats,
+
+    pub ipv6: FamilyStats,
+
+    /// The family applications should prefer: the one with clearly lower
+    /// loss, else clearly lower latency, else IPv6 (as RFC 6724 does).
+    /// `None` if neither family worked.
+    pub preferred_family: Option<AddressFamily>,
+}
+
+impl NetworkReport {
+    /// Collapses the report into a [SimpleStatus]:
+    ///
+    /// - [Red](SimpleStatus::Red): not connected, only the local network is
+    ///   reachable, or a captive portal intercepts traffic.
+    /// - [Yellow](SimpleStatus::Yellow): connected, but the quality is
+    ///   [Moderate](ConnectionQuality::Moderate) or worse, or DNS answers
+    ///   look spoofed.
+    /// - [Green](SimpleStatus::Green): everything else, i.e. internet access
+    ///   of at least [Good](ConnectionQuality::Good) quality.
+    pub fn simple_status(&self) -> SimpleStatus {
+        let quality = self.status.quality;
+        if !self.status.is_connected
+            || self.scope != NetworkScope::Internet
+            || matches!(
+                quality,
+                ConnectionQuality::Offline | ConnectionQuality::CaptivePortal
+            )
+        {
+            return SimpleStatus::Red;
+        }
+        if self.security_flags_result.is_dns_spoofed
+            || matches!(
+                quality,
+                ConnectionQuality::Moderate | ConnectionQuality::Poor | ConnectionQuality::Unstable
+            )
+        {
+            return SimpleStatus::Yellow;
+        }
+        SimpleStatus::Green
+    }
+}
+
+impl TargetReport {
+    /// A failed report with nothing measured, for callers that fill in the
+    /// outcome of a probe they ran themselves, or skipped.
+    pub(crate) fn unprobed(label: String, is_essential: bool) -> Self {
+        TargetReport {
+            label,
+            success: false,
+            latency_ms: 0,
+            error: None,
+            is_essential,
+            dns_ms: None,
+            connect_ms: None,
+            unreachable: None,
+            timed_out_after_ms: None,
+            last_progress_ms: None,
+            resolved_addr: None,
+            address_family: None,
+            skipped: false,
+            timings: None,
+            samples: Vec::new(),
+            fallback: None,
+        }
+    }
+}
+
+#[cfg(feature = "serde")]
+impl NetworkReport {
+    /// Serializes the report to compact JSON, e.g. to ship it to a backend.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl EngineSnapshot {
+    /// Serializes the snapshot to compact JSON for persisting it.
+    pub fn to_json(&self) -> Result<String, serde_json::Error> {
+        serde_json::to_string(self)
+    }
+
+    /// Parses a snapshot written by [Self::to_json].
+    pub fn from_json(json: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(json)
+    }
+}
+
+#[cfg(test)]
+impl NetworkReport {
+    /// A minimal report for unit tests: no targets, 0 ms latency, no loss.
+    pub(crate) fn fixture(is_connected: bool) -> Self {
+        NetworkReport {
+            timestamp_ms: 0,
+            monotonic_ms: 0,
+            duration_ms: 0,
+            status: NetworkStatus {
+                is_connected,
+                quality: if is_connected {
+                    ConnectionQuality::Good
+                } else {
+                    ConnectionQuality::Offline
+                },
+                latency_stats: LatencyStats {
+                    latency_ms: 0,
+                    jitter_ms: 0,
+                    packet_loss_percent: if is_connected { 0.0 } else { 100.0 },
+                    min_latency_ms: None,
+                    avg_latency_ms: None,
+                    max_latency_ms: None,
+                    p50_ms: None,
+                    p90_ms: None,
+                    p95_ms: None,
+                    p99_ms: None,
+                    stability_score: if is_connected { 100 } else { 0 },
+                    spike_count: 0,
+                    spike_sample_indices: vec![],
+                },
+                winner_target: String::new(),
+                winners_per_sample: vec![],
+                reasons: vec![],
+            },
+            connection_type: ConnectionType::Unknown,
+            security_flags_result: SecurityFlagsResult::default(),
+            captive_portal: None,
+            target_reports: vec![],
+            layers: LayeredDiagnosis {
+                link: LayerStatus::Unknown,
+                network: LayerStatus::Unknown,
+                dns: LayerStatus::Unknown,
+                transport: LayerStatus::Unknown,
+                application: LayerStatus::Unknown,
+            },
+            is_expensive: false,
+            is_constrained: false,
+            ssid: None,
+            bssid: None,
+            scope: if is_connected {
+                NetworkScope::Internet
+            } else {
+                NetworkScope::None
+            },
+            environment: RuntimeEnvironment::Native,
+            raw_samples: vec![],
+            likely_isp_outage: None,
+            snmp_devices: vec![],
+            family_comparison: None,
+            multicast_ok: None,
+            walled_garden: None,
+            roam: None,
+            anomalies: Vec::new(),
+            better_path: None,
+            attribution: None,
+            traceroute: None,
+            cancelled: false,
+            truncated: false,
+            strategy: CheckStrategy::Race,
+        }
+    }
+}
+
+#[cfg(test)]
+impl TargetReport {
+    /// A minimal target report for unit tests: no error, timings or samples.
+    pub(crate) fn fixture(label: &str, success: bool, latency_ms: u64) -> Self {
+        TargetReport {
+            success,
+            latency_ms,
+            ..TargetReport::unprobed(label.to_string(), false)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simple_status() {
+        let mut report = NetworkReport::fixture(true);
+        assert_eq!(report.simple_status(), SimpleStatus::Green);
+
+        report.status.quality = ConnectionQuality::Unstable;
+        assert_eq!(report.simple_status(), SimpleStatus::Yellow);
+
+        report.status.quality = ConnectionQuality::Excellent;
+        report.security_flags_result.is_dns_spoofed = true;
+        assert_eq!(report.simple_status(), SimpleStatus::Yellow);
+
+        report.status.quality = ConnectionQuality::CaptivePortal;
+        assert_eq!(report.simple_status(), SimpleStatus::Red);
+
+        let mut local_only = NetworkReport::fixture(true);
+        local_only.scope = NetworkScope::LocalOnly;
+        assert_eq!(local_only.simple_status(), SimpleStatus::Red);
+        assert_eq!(
+            NetworkReport::fixture(false).simple_status(),
+            SimpleStatus::Red
+        );
+    }
+
+    #[test]
+    fn test_probe_timings_split_the_budget() {
+        let timings = ProbeTimings::new(1000, 400, Some(50), Some(150));
+        assert_eq!(timings.dns_percent, 5.0);
+        assert_eq!(timings.connect_percent, 15.0);
+        assert_eq!(timings.wait_percent, 20.0);
+        assert_eq!(timings.used_percent, 40.0);
+
+        // Timer granularity can push a timed-out probe past its budget.
+        let timed_out = ProbeTimings::new(1000, 1003, None, None);
+        assert_eq!(timed_out.used_percent, 100.0);
+        assert_eq!(timed_out.wait_percent, 100.0);
+    }
 }
@@ -0,0 +1,350 @@
+//! Data structures for discovering devices on the local network.
+
+use super::net_info::ProbeFallback;
+use crate::api::constants::LibConstants;
+
+/// How a local network sweep finds devices.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScanMethod {
+    /// TCP connects to the scanned ports only.
+    TcpConnect,
+
+    /// ICMP echo requests alongside the TCP connects, which also finds
+    /// devices without any open or closed TCP port (phones, IoT). Falls back
+    /// to TCP connects alone where the process may not send ICMP (see
+    /// [detect_probe_capabilities](crate::api::probes::capabilities::detect_probe_capabilities)),
+    /// as noted in [ScanSummary::fallback].
+    IcmpSweep,
+}
+
+impl Default for ScanMethod {
+    /// Returns [`ScanMethod::TcpConnect`] as the default.
+    fn default() -> Self {
+        Self::TcpConnect
+    }
+}
+
+/// Settings for a local network sweep (see
+/// [scan_local_network](crate::api::probes::local_scan::scan_local_network)).
+///
+/// # Address Ranges
+///
+/// `allow_ranges` and `exclude_ranges` accept single addresses
+/// (`192.168.1.20`), CIDR blocks (`192.168.1.64/26`) and inclusive ranges
+/// (`192.168.1.100-192.168.1.150`). They apply to every scan mode: an excluded
+/// address is never sent a single packet.
+#[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalScanConfig {
+    /// The subnet to sweep, in CIDR notation (e.g. `192.168.1.0/24`).
+    pub subnet: String,
+
+    /// The TCP port connected to on every host.
+    ///
+    /// A refused connection also counts as a discovered device, since only a
+    /// live host can answer with a reset.
+    pub scan_port: u16,
+
+    /// The TCP ports connected to on every host instead of `scan_port`, e.g.
+    /// [COMMON_SCAN_PORTS](LibConstants::COMMON_SCAN_PORTS).
+    ///
+    /// A host answering on any of them is a discovered device. The ports of
+    /// a host are probed at once, as far as `max_concurrency` allows. Empty by
+    /// default, which probes `scan_port`.
+    pub scan_ports: Vec<u16>,
+
+    /// How hosts are probed.
+    /// *Default: [ScanMethod::TcpConnect]*
+    pub method: ScanMethod,
+
+    /// How long (ms) to wait for each host.
+    pub timeout_ms: u64,
+
+    /// How many sockets a sweep holds at once, counting every port connect,
+    /// ICMP echo and open banner connection. This bounds the file
+    /// descriptors a sweep uses, however many ports are scanned.
+    pub max_concurrency: usize,
+
+    /// The most hosts a sweep may cover after applying `allow_ranges` and
+    /// `exclude_ranges`. A larger scope fails with
+    /// [NetworkError::InvalidConfiguration](crate::api::models::NetworkError::InvalidConfiguration)
+    /// instead of sweeping e.g. a whole /16 by accident.
+    pub max_hosts: usize,
+
+    /// If non-empty, only addresses inside one of these ranges are scanned.
+    pub allow_ranges: Vec<String>,
+
+    /// Addresses that are never probed, e.g. printers or cameras known to
+    /// crash when scanned. Takes precedence over `allow_ranges`.
+    pub exclude_ranges: Vec<String>,
+
+    /// Read a short banner from every open port to identify the service
+    /// (SSH, HTTP, RTSP).
+    ///
+    /// Off by default, as it keeps connections open for up to
+    /// `banner_timeout_ms` longer.
+    pub grab_banners: bool,
+
+    /// How long (ms) to wait for a banner on each open port.
+    pub banner_timeout_ms: u64,
+
+    /// Look up the name of every discovered device via LLMNR and NetBIOS,
+    /// which Windows machines answer even without any DNS record.
+    ///
+    /// Off by default, as it sends every device two more queries. Runs in
+    /// the same pass as `lookup_hostnames`, taking up to
+    /// `hostname_lookup_timeout_ms` per device.
+    pub resolve_names: bool,
+
+    /// After the sweep, look up the hostname of every device via reverse DNS
+    /// and mDNS (`.local` names of Apple devices, printers and most Linux
+    /// machines).
+    ///
+    /// Off by default. At most `hostname_lookup_concurrency` devices are
+    /// looked up at once, each for up to `hostname_lookup_timeout_ms`.
+    pub lookup_hostnames: bool,
+
+    /// How long (ms) to wait for the name of each device, from any of the
+    /// sources `resolve_names` and `lookup_hostnames` enable.
+    pub hostname_lookup_timeout_ms: u64,
+
+    /// How many devices' names are looked up at once, with `resolve_names`
+    /// or `lookup_hostnames`.
+    pub hostname_lookup_concurrency: usize,
+}
+
+impl Default for LocalScanConfig {
+    /// Sweeps `192.168.1.0/24` on port 80 with a 300 ms per-host timeout.
+    fn default() -> Self {
+        Self {
+            subnet: LibConstants::DEFAULT_SCAN_SUBNET.to_string(),
+            scan_port: LibConstants::DEFAULT_SCAN_PORT,
+            scan_ports: Vec::new(),
+            method: ScanMethod::TcpConnect,
+            timeout_ms: LibConstants::DEFAULT_SCAN_TIMEOUT_MS,
+            max_concurrency: LibConstants::DEFAULT_SCAN_CONCURRENCY,
+            max_hosts: LibConstants::DEFAULT_MAX_SCAN_HOSTS,
+            allow_ranges: Vec::new(),
+            exclude_ranges: Vec::new(),
+            grab_banners: false,
+            banner_timeout_ms: LibConstants::DEFAULT_BANNER_TIMEOUT_MS,
+            resolve_names: false,
+            lookup_hostnames: false,
+            hostname_lookup_timeout_ms: LibConstants::DEFAULT_HOSTNAME_LOOKUP_TIMEOUT_MS,
+            hostname_lookup_concurrency: LibConstants::DEFAULT_HOSTNAME_LOOKUP_CONCURRENCY,
+        }
+    }
+}
+
+/// A device found on the local network.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct LocalDevice {
+    /// The device's IP address.
+    pub ip_address: String,
+
+    /// The device's hostname, if it could be resolved (see
+    /// [LocalScanConfig::resolve_names] and [LocalScanConfig::lookup_hostnames]).
+    pub hostname: Option<String>,
+
+    /// The device's MAC address, from the OS neighbor table the sweep
+    /// populated. Only known for devices on the same link.
+    pub mac_address: Option<String>,
+
+    /// The vendor registered for the MAC address prefix (OUI).
+    pub mac_vendor: Option<String>,
+
+    /// Time (ms) until the device answered on its fastest port.
+    pub latency_ms: u64,
+
+    /// The scanned ports that accepted a connection, in ascending order.
+    /// Ports that refused it proved the device is up but are not listed.
+    pub open_ports: Vec<u16>,
+
+    /// The device answered an ICMP echo request. Always false unless the
+    /// sweep used [ScanMethod::IcmpSweep].
+    pub answered_ping: bool,
+
+    /// Services identified on the device's open ports.
+    ///
+    /// Empty unless [LocalScanConfig::grab_banners] is set.
+    pub services: Vec<ServiceInfo>,
+}
+
+/// An update from a streaming sweep (see
+/// [scan_local_network_stream](crate::api::probes::local_scan::scan_local_network_stream)).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum ScanEvent {
+    /// A device answered. Its MAC address, vendor and (if enabled) hostname
+    /// are already filled in.
+    Device(LocalDevice),
+
+    /// The sweep is still running; sent every
+    /// [SCAN_PROGRESS_INTERVAL_MS](crate::api::constants::LibConstants::SCAN_PROGRESS_INTERVAL_MS)
+    /// with the counts so far and `cancelled` unset.
+    Progress(ScanSummary),
+
+    /// The sweep ended; always the last event.
+    Finished(ScanSummary),
+}
+
+/// The outcome of a streaming sweep, or its state while it runs.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScanSummary {
+    /// How many hosts the scope covered.
+    pub hosts_scanned: u32,
+
+    /// How many devices were reported before the sweep ended (or so far).
+    pub devices_found: u32,
+
+    /// Time (ms) from the start of the sweep until it ended (or so far).
+    pub duration_ms: u64,
+
+    /// The sweep was cancelled before every host was probed.
+    pub cancelled: bool,
+
+    /// [ProbeFallback::TcpPing] if [ScanMethod::IcmpSweep] was asked for but
+    /// the process may not send ICMP echoes, so only TCP connects were made.
+    pub fallback: Option<ProbeFallback>,
+}
+
+/// One complete entry of the OS neighbor (ARP) table.
+///
+/// Produced by [read_arp_table](crate::api::probes::arp::read_arp_table).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ArpEntry {
+    /// The neighbor's IPv4 address.
+    pub ip_address: String,
+
+    /// The neighbor's MAC address (lowercase, colon-separated).
+    pub mac_address: String,
+
+    /// The interface the neighbor was seen on, where the platform reports it.
+    pub interface: Option<String>,
+}
+
+/// A service identified from the banner of an open port.
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ServiceInfo {
+    /// The TCP port the service listens on.
+    pub port: u16,
+
+    /// The protocol recognized from the banner (`ssh`, `http`), if any.
+    pub protocol: Option<String>,
+
+    /// The service's self-description: the SSH version string, the HTTP
+    /// `Server` header or the first line of any other greeting.
+    pub banner: Option<String>,
+}
+
+/// A change between two scans of the same network, as reported by
+/// [DeviceInventory::update](crate::api::engine::inventory::DeviceInventory::update).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum DeviceEvent {
+    /// A device that was not present in the previous scan answered.
+    Joined(LocalDevice),
+
+    /// A device from the previous scan no longer answers.
+    Left(LocalDevice),
+
+    /// A device (identified by its MAC address) moved to a new IP address.
+    IpChanged {
+        mac_address: String,
+        old_ip: String,
+        new_ip: String,
+    },
+}
+
+/// What could be learned about the router behind the default gateway.
+///
+/// Produced by [fingerprint_gateway](crate::api::probes::gateway::fingerprint_gateway).
+/// Every source is best-effort; a router that exposes nothing still yields a
+/// fingerprint with only `ip_address` set.
+#[derive(Debug, Clone, Default, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GatewayFingerprint {
+    /// The gateway's IP address.
+    pub ip_address: String,
+
+    /// The gateway's MAC address, from the OS neighbor table.
+    pub mac_address: Option<String>,
+
+    /// The vendor registered for the MAC address prefix (OUI).
+    pub mac_vendor: Option<String>,
+
+    /// The `<title>` of the web admin page.
+    pub http_title: Option<String>,
+
+    /// The `Server` header of the web admin page.
+    pub http_server: Option<String>,
+
+    /// The UPnP `friendlyName`, often the model as marketed.
+    pub upnp_friendly_name: Option<String>,
+
+    /// The UPnP `manufacturer`.
+    pub upnp_manufacturer: Option<String>,
+
+    /// The UPnP `modelName`.
+    pub upnp_model_name: Option<String>,
+
+    /// The UPnP `modelNumber`.
+    pub upnp_model_number: Option<String>,
+
+    /// The best guess for the router's vendor: the UPnP manufacturer, else the
+    /// MAC vendor.
+    pub vendor: Option<String>,
+
+    /// The best guess for the router's model: the UPnP model name and number,
+    /// else the admin page title.
+    pub model: Option<String>,
+}
+
+/// A service announced on the local network via mDNS/DNS-SD (see
+/// [browse_mdns_services](crate::api::probes::mdns::browse_mdns_services)).
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MdnsService {
+    /// The human-readable instance name, e.g. `Living Room TV`.
+    pub instance_name: String,
+
+    /// The service type, e.g. `_googlecast._tcp.local` or `_ipp._tcp.local`.
+    pub service_type: String,
+
+    /// The host offering the service, from its SRV record (`Chromecast-1a2b.local`).
+    pub hostname: Option<String>,
+
+    /// The port of the service, from its SRV record.
+    pub port: Option<u16>,
+
+    /// The addresses of `hostname`, or the address the announcement came
+    /// from if the responder sent none.
+    pub ip_addresses: Vec<String>,
+
+    /// The entries of the TXT record (`key=value` or a bare `key`), e.g. the
+    /// model (`md=Chromecast`) or the HomeKit pairing state (`sf=1`).
+    pub txt_records: Vec<String>,
+}
+
+#[cfg(test)]
+impl LocalDevice {
+    /// A device for unit tests that answered at `ip` in 1 ms, with nothing
+    /// else known about it.
+    pub(crate) fn fixture(ip: &str) -> Self {
+        LocalDevice {
+            ip_address: ip.to_string(),
+            hostname: None,
+            mac_address: None,
+            mac_vendor: None,
+            latency_ms: 1,
+            open_ports: vec![],
+            answered_ping: false,
+            services: vec![],
+        }
+    }
+}
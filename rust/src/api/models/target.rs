@@ -4,11 +4,15 @@
 //! fundamental unit of configuration for the reachability engine. 
 //! Every endpoint the engine checks must be defined as a target.
 
+use crate::api::constants::LibConstants;
+use flutter_rust_bridge::frb;
+
 /// Supported network protocols for performing reachability probes.
 ///
 /// Each protocol has different performance characteristics and requires 
 /// different system permissions.
 #[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TargetProtocol {
     /// Transmission Control Protocol (TCP).
     ///
@@ -24,12 +28,17 @@ pub enum TargetProtocol {
     /// Internet Control Message Protocol (ICMP).
     ///
     /// # Behavior
-    /// Sends an "Echo Request" (Ping) and waits for an "Echo Reply".
+    /// Sends an "Echo Request" (Ping) and waits for an "Echo Reply". An
+    /// unprivileged ICMP datagram socket is used where the OS allows one,
+    /// falling back to a raw socket otherwise. The echo round trip is
+    /// reported as the report's `latency_ms` and `connect_ms`, leaving out
+    /// name resolution and socket setup.
     ///
     /// # Pros/Cons
     /// + Extremely low overhead; the "purest" measure of network latency.
     /// - Often blocked by public firewalls or corporate proxies.
-    /// - May require `root` or special capabilities on some Linux systems.
+    /// - On Linux, needs either membership of `net.ipv4.ping_group_range` or
+    ///   `CAP_NET_RAW`.
     Icmp,
 
     /// Hypertext Transfer Protocol (HTTP/1.1 or HTTP/2).
@@ -54,6 +63,63 @@ pub enum TargetProtocol {
     /// + High confidence; validates that the end-to-end encrypted path is open.
     /// - Highest overhead; requires a full TLS handshake.
     Https,
+
+    /// User Datagram Protocol (UDP).
+    ///
+    /// # Behavior
+    /// Sends a single datagram and waits for a reply, which is validated
+    /// according to [`NetworkTarget::udp_validation`]. UDP is connectionless,
+    /// so only an answer proves the service is reachable.
+    ///
+    /// # Pros/Cons
+    /// + Exercises the path used by DNS, NTP, QUIC/HTTP3, VoIP and games.
+    /// - Many networks silently drop unexpected UDP; pick a payload the
+    ///   target actually answers.
+    Udp,
+
+    /// Domain Name System query (over UDP).
+    ///
+    /// # Behavior
+    /// Asks the target, as a DNS server, to resolve
+    /// [`NetworkTarget::dns_query_name`] and requires a well-formed answer.
+    /// `NXDOMAIN` counts as success (the server works); `SERVFAIL` or
+    /// `REFUSED` do not.
+    ///
+    /// # Pros/Cons
+    /// + Verifies the DNS service itself, not just an open port.
+    /// - Only meaningful for targets that are DNS servers.
+    Dns,
+}
+
+/// How the reply to a [`TargetProtocol::Udp`] probe is validated.
+#[derive(Debug, Clone, Copy, PartialEq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum UdpValidation {
+    /// Any datagram sent back by the target counts as success.
+    ///
+    /// The probe payload is a single zero byte, which suits echo-style
+    /// services only.
+    AnyResponse,
+
+    /// Sends a DNS query and requires a well-formed DNS response with the
+    /// matching transaction ID (typically port 53).
+    Dns,
+
+    /// Sends an SNTP client request and requires a server reply echoing our
+    /// transmit timestamp (typically port 123).
+    Ntp,
+
+    /// Sends a QUIC long-header packet with a reserved version and requires
+    /// a Version Negotiation packet echoing our connection IDs (typically
+    /// port 443). Proves a QUIC/HTTP3 endpoint without a TLS handshake.
+    Quic,
+}
+
+impl Default for UdpValidation {
+    /// Returns [`UdpValidation::AnyResponse`] as the default.
+    fn default() -> Self {
+        Self::AnyResponse
+    }
 }
 
 /// Configuration for a specific network endpoint to be monitored.
@@ -61,6 +127,8 @@ pub enum TargetProtocol {
 /// A `NetworkTarget` combines an address, a port, and a protocol to 
 /// define a unique "probe point".
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(feature = "serde", serde(default))]
 pub struct NetworkTarget {
     /// A human-readable identifier (e.g., "Google Cloud DNS", "Internal API").
     /// This label is used in [`TargetReport`](super::report::TargetReport) results.
@@ -77,7 +145,7 @@ pub struct NetworkTarget {
     /// Common ports:
     /// - 80: Default for HTTP
     /// - 443: Default for HTTPS
-    /// - 53: Default for DNS (TCP)
+    /// - 53: Default for DNS
     pub port: u16,
 
     /// The network protocol to use for this specific probe.
@@ -91,8 +159,9 @@ pub struct NetworkTarget {
 
     /// The relative priority of this target (lower numbers = higher priority).
     ///
-    /// Currently used for UI sorting and as a hint for the [`Race`](super::config::CheckStrategy::Race) 
-    /// strategy to decide which targets to fire first.
+    /// Under [`Fallback`](super::config::CheckStrategy::Fallback), targets are
+    /// probed one priority level at a time, starting with the lowest number.
+    /// The other strategies probe all targets at once and ignore it.
     pub priority: u8,
 
     /// If true, a failure of this target is treated as a severe network event.
@@ -101,4 +170,178 @@ pub struct NetworkTarget {
     /// "Circuit Breaker" mechanism. Failure of an essential target can 
     /// disqualify the entire network status regardless of other successes.
     pub is_essential: bool,
+
+    /// How much a success of this target counts under
+    /// [`Weighted`](super::config::CheckStrategy::Weighted); ignored by the
+    /// other strategies. Defaults to 1.
+    pub weight: u8,
+
+    /// Reply validation for [`TargetProtocol::Udp`] targets; ignored otherwise.
+    pub udp_validation: UdpValidation,
+
+    /// The name looked up by [`TargetProtocol::Dns`] targets; ignored otherwise.
+    ///
+    /// `None` uses [`DEFAULT_DNS_QUERY_NAME`](crate::api::constants::LibConstants::DEFAULT_DNS_QUERY_NAME).
+    pub dns_query_name: Option<String>,
+
+    /// The DSCP code point (0-63) to mark probe packets with, e.g. 46 for
+    /// Expedited Forwarding. `None` leaves the OS default (best effort).
+    ///
+    /// Applied to TCP, UDP, DNS and ICMP probes; HTTP(S) probes are sent
+    /// unmarked. Some platforms ignore the marking (notably Windows without
+    /// a QoS policy) and IPv6 marking is only supported on Linux and Android.
+    pub dscp: Option<u8>,
+
+    /// If true, a timed-out probe reports how long it ran and when it last
+    /// made progress (see [`TargetReport::timed_out_after_ms`](super::report::TargetReport::timed_out_after_ms))
+    /// instead of only a timeout error.
+    pub report_timeout_progress: bool,
+}
+
+/// Builds a [`NetworkTarget`], filling in defaults for everything but the
+/// host and protocol.
+///
+/// ```ignore
+/// let target = NetworkTarget::builder("example.com", TargetProtocol::Https)
+///     .label("Example")
+///     .essential(true)
+///     .build();
+/// ```
+#[frb(ignore)]
+#[derive(Debug, Clone)]
+pub struct NetworkTargetBuilder {
+    target: NetworkTarget,
+}
+
+impl NetworkTargetBuilder {
+    /// Starts a target towards `host`, labelled with the host name, on the
+    /// protocol's well-known port.
+    pub fn new(host: impl Into<String>, protocol: TargetProtocol) -> Self {
+        let host = host.into();
+        let (port, timeout_ms) = match protocol {
+            TargetProtocol::Http => (80, LibConstants::DEFAULT_HTTP_TIMEOUT_MS),
+            TargetProtocol::Https => (
+                LibConstants::DEFAULT_HTTP_PORT,
+                LibConstants::DEFAULT_HTTP_TIMEOUT_MS,
+            ),
+            TargetProtocol::Dns | TargetProtocol::Udp => {
+                (LibConstants::DEFAULT_PORT, LibConstants::DEFAULT_TIMEOUT_MS)
+            }
+            TargetProtocol::Tcp => (
+                LibConstants::DEFAULT_HTTP_PORT,
+                LibConstants::DEFAULT_TIMEOUT_MS,
+            ),
+            TargetProtocol::Icmp => (0, LibConstants::DEFAULT_TIMEOUT_MS),
+        };
+        Self {
+            target: NetworkTarget {
+                label: host.clone(),
+                host,
+                port,
+                protocol,
+                timeout_ms,
+                priority: 1,
+                is_essential: false,
+                weight: 1,
+                udp_validation: UdpValidation::default(),
+                dns_query_name: None,
+                dscp: None,
+                report_timeout_progress: false,
+            },
+        }
+    }
+
+    /// See [`NetworkTarget::label`].
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.target.label = label.into();
+        self
+    }
+
+    /// See [`NetworkTarget::port`].
+    pub fn port(mut self, port: u16) -> Self {
+        self.target.port = port;
+        self
+    }
+
+    /// See [`NetworkTarget::timeout_ms`].
+    pub fn timeout_ms(mut self, timeout_ms: u64) -> Self {
+        self.target.timeout_ms = timeout_ms;
+        self
+    }
+
+    /// See [`NetworkTarget::priority`].
+    pub fn priority(mut self, priority: u8) -> Self {
+        self.target.priority = priority;
+        self
+    }
+
+    /// See [`NetworkTarget::is_essential`].
+    pub fn essential(mut self, is_essential: bool) -> Self {
+        self.target.is_essential = is_essential;
+        self
+    }
+
+    /// See [`NetworkTarget::weight`].
+    pub fn weight(mut self, weight: u8) -> Self {
+        self.target.weight = weight;
+        self
+    }
+
+    /// See [`NetworkTarget::udp_validation`].
+    pub fn udp_validation(mut self, validation: UdpValidation) -> Self {
+        self.target.udp_validation = validation;
+        self
+    }
+
+    /// See [`NetworkTarget::dns_query_name`].
+    pub fn dns_query_name(mut self, name: impl Into<String>) -> Self {
+        self.target.dns_query_name = Some(name.into());
+        self
+    }
+
+    /// See [`NetworkTarget::report_timeout_progress`].
+    pub fn report_timeout_progress(mut self, report: bool) -> Self {
+        self.target.report_timeout_progress = report;
+        self
+    }
+
+    /// See [`NetworkTarget::dscp`].
+    pub fn dscp(mut self, dscp: u8) -> Self {
+        self.target.dscp = Some(dscp);
+        self
+    }
+
+    /// The finished target. Validated as part of the configuration it is
+    /// added to.
+    pub fn build(self) -> NetworkTarget {
+        self.target
+    }
+}
+
+/// True if `name` fits a DNS question: labels of at most
+/// [MAX_DNS_LABEL_LEN](LibConstants::MAX_DNS_LABEL_LEN) bytes, and at most
+/// [MAX_DNS_NAME_LEN](LibConstants::MAX_DNS_NAME_LEN) bytes on the wire.
+pub(crate) fn is_valid_dns_name(name: &str) -> bool {
+    let labels: Vec<&str> = name.split('.').filter(|l| !l.is_empty()).collect();
+    let wire_len: usize = labels.iter().map(|l| l.len() + 1).sum::<usize>() + 1;
+    labels
+        .iter()
+        .all(|l| l.len() <= LibConstants::MAX_DNS_LABEL_LEN)
+        && wire_len <= LibConstants::MAX_DNS_NAME_LEN
+}
+
+impl NetworkTarget {
+    /// Starts a [`NetworkTargetBuilder`].
+    #[frb(ignore)]
+    pub fn builder(host: impl Into<String>, protocol: TargetProtocol) -> NetworkTargetBuilder {
+        NetworkTargetBuilder::new(host, protocol)
+    }
+}
+
+impl Default for NetworkTarget {
+    /// A TCP target without a host, whose values fill in the fields a
+    /// deserialized target leaves out. The empty host fails validation.
+    fn default() -> Self {
+        NetworkTargetBuilder::new("", TargetProtocol::Tcp).build()
+    }
 }
@@ -0,0 +1,404 @@
+//! Stable string names for the fieldless enums.
+//!
+//! Every enum whose variants carry no data gets `as_str()`, a [FromStr]
+//! implementation that accepts exactly those names, and an `ALL` list of its
+//! variants. Names are `snake_case` and match the value part of the
+//! [l10n](super::l10n) keys where both exist (`quality.captive_portal` is
+//! `captive_portal`), so config files and logs can name a variant without
+//! depending on `Debug` output or the bridge's generated ordinals. The enums
+//! themselves are bridged as Dart enums ([CheckStrategy] and [SampleMode] as
+//! sealed classes). `as_str()` is not bridged; Dart code reads the same name
+//! from the `l10n_key()` value.
+//!
+//! Like the localization keys, names are part of the public API and never
+//! change once released. Enums with data (e.g. [NetworkError](super::NetworkError))
+//! are not covered; their variants are distinguished by `l10n_key()`. The
+//! exceptions are [CheckStrategy] and [SampleMode], whose names are written by
+//! hand because [CheckStrategy::Quorum] and [SampleMode::Paced] carry a value.
+
+use super::{
+    config::{CheckStrategy, ConnectionQuality, OverlapPolicy, SampleMode},
+    diagnostics::{AnomalyMetric, DegradationScope, Limitation},
+    error::ParseEnumError,
+    net_info::{
+        ApplePathInterfaceType, ApplePathStatus, Confidence, ConnectionType, EcnMarking,
+        Encapsulation, EvidenceSource, NatType, NeighborProtocol, PathSegment, PortalLoginKind,
+        ProbeFallback, RuntimeEnvironment, ServiceReachability,
+    },
+    report::{
+        AddressFamily, IcmpUnreachable, LayerStatus, NetworkScope, QualityReason, SimpleStatus,
+    },
+    scan::ScanMethod,
+    target::{TargetProtocol, UdpValidation},
+};
+use crate::api::{
+    engine::{pipeline::PipelineStep, CircuitBreakerState},
+    monitor::MonitorState,
+};
+use flutter_rust_bridge::frb;
+use std::{fmt, str::FromStr};
+
+macro_rules! wire_names {
+    ($($ty:ident { $($(#[$meta:meta])* $variant:ident => $name:literal,)+ })+) => {$(
+        impl $ty {
+            /// Every variant, in declaration order.
+            pub const ALL: &'static [$ty] = &[$($(#[$meta])* $ty::$variant,)+];
+
+            /// The stable name of the variant, e.g. for FFI or config files.
+            #[frb(ignore)]
+            pub fn as_str(&self) -> &'static str {
+                match self {
+                    $($(#[$meta])* $ty::$variant => $name,)+
+                }
+            }
+        }
+
+        impl FromStr for $ty {
+            type Err = ParseEnumError;
+
+            fn from_str(s: &str) -> Result<Self, Self::Err> {
+                match s {
+                    $($(#[$meta])* $name => Ok($ty::$variant),)+
+                    _ => Err(ParseEnumError {
+                        enum_name: stringify!($ty).to_string(),
+                        value: s.to_string(),
+                    }),
+                }
+            }
+        }
+    )+};
+}
+
+impl CheckStrategy {
+    /// The stable name of the strategy, e.g. for FFI or config files.
+    ///
+    /// Every [CheckStrategy::Quorum] is `quorum`; the [Display](fmt::Display)
+    /// form adds the count (`quorum:2`) and is what [FromStr] accepts.
+    #[frb(ignore)]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            CheckStrategy::Race => "race",
+            CheckStrategy::Consensus => "consensus",
+            CheckStrategy::Fallback => "fallback",
+            CheckStrategy::All => "all",
+            CheckStrategy::Quorum(_) => "quorum",
+            CheckStrategy::Weighted => "weighted",
+        }
+    }
+}
+
+impl fmt::Display for CheckStrategy {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CheckStrategy::Quorum(quorum) => write!(f, "quorum:{}", quorum),
+            other => f.write_str(other.as_str()),
+        }
+    }
+}
+
+impl FromStr for CheckStrategy {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "race" => Ok(CheckStrategy::Race),
+            "consensus" => Ok(CheckStrategy::Consensus),
+            "fallback" => Ok(CheckStrategy::Fallback),
+            "all" => Ok(CheckStrategy::All),
+            "weighted" => Ok(CheckStrategy::Weighted),
+            _ => s
+                .strip_prefix("quorum:")
+                .and_then(|n| n.parse().ok())
+                .map(CheckStrategy::Quorum)
+                .ok_or_else(|| ParseEnumError {
+                    enum_name: "CheckStrategy".to_string(),
+                    value: s.to_string(),
+                }),
+        }
+    }
+}
+
+impl SampleMode {
+    /// The stable name of the mode, e.g. for FFI or config files.
+    ///
+    /// Every [SampleMode::Paced] is `paced`; the [Display](fmt::Display) form
+    /// adds the interval (`paced:500`) and is what [FromStr] accepts.
+    #[frb(ignore)]
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            SampleMode::Sequential => "sequential",
+            SampleMode::Parallel => "parallel",
+            SampleMode::Paced(_) => "paced",
+        }
+    }
+}
+
+impl fmt::Display for SampleMode {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            SampleMode::Paced(interval_ms) => write!(f, "paced:{}", interval_ms),
+            other => f.write_str(other.as_str()),
+        }
+    }
+}
+
+impl FromStr for SampleMode {
+    type Err = ParseEnumError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "sequential" => Ok(SampleMode::Sequential),
+            "parallel" => Ok(SampleMode::Parallel),
+            _ => s
+                .strip_prefix("paced:")
+                .and_then(|ms| ms.parse().ok())
+                .map(SampleMode::Paced)
+                .ok_or_else(|| ParseEnumError {
+                    enum_name: "SampleMode".to_string(),
+                    value: s.to_string(),
+                }),
+        }
+    }
+}
+
+wire_names! {
+    OverlapPolicy {
+        Skip => "skip",
+        Queue => "queue",
+        Coalesce => "coalesce",
+    }
+    ConnectionQuality {
+        Excellent => "excellent",
+        Great => "great",
+        Good => "good",
+        Moderate => "moderate",
+        Poor => "poor",
+        Unstable => "unstable",
+        CaptivePortal => "captive_portal",
+        Offline => "offline",
+    }
+    AnomalyMetric {
+        Latency => "latency",
+        PacketLoss => "packet_loss",
+    }
+    ConnectionType {
+        Wifi => "wifi",
+        Cellular => "cellular",
+        Ethernet => "ethernet",
+        Vpn => "vpn",
+        Bluetooth => "bluetooth",
+        Loopback => "loopback",
+        Unknown => "unknown",
+    }
+    Confidence {
+        Low => "low",
+        Medium => "medium",
+        High => "high",
+    }
+    EvidenceSource {
+        NotChecked => "not_checked",
+        ProbeFailed => "probe_failed",
+        PlatformHint => "platform_hint",
+        AdapterType => "adapter_type",
+        InterfaceName => "interface_name",
+        ResolverComparison => "resolver_comparison",
+        HttpRedirect => "http_redirect",
+        HttpResponse => "http_response",
+    }
+    PortalLoginKind {
+        Unknown => "unknown",
+        ClickThrough => "click_through",
+        Credentials => "credentials",
+    }
+    ApplePathStatus {
+        Satisfied => "satisfied",
+        Unsatisfied => "unsatisfied",
+        RequiresConnection => "requires_connection",
+    }
+    ApplePathInterfaceType {
+        Wifi => "wifi",
+        Cellular => "cellular",
+        WiredEthernet => "wired_ethernet",
+        Loopback => "loopback",
+        Other => "other",
+    }
+    RuntimeEnvironment {
+        Native => "native",
+        Docker => "docker",
+        Lxc => "lxc",
+        Wsl => "wsl",
+        Container => "container",
+    }
+    ProbeFallback {
+        TcpPing => "tcp_ping",
+        UdpTraceroute => "udp_traceroute",
+    }
+    ScanMethod {
+        TcpConnect => "tcp_connect",
+        IcmpSweep => "icmp_sweep",
+    }
+    PathSegment {
+        Lan => "lan",
+        Isp => "isp",
+        Destination => "destination",
+    }
+    NatType {
+        None => "none",
+        EndpointIndependent => "endpoint_independent",
+        EndpointDependent => "endpoint_dependent",
+        Unknown => "unknown",
+    }
+    ServiceReachability {
+        Reachable => "reachable",
+        Refused => "refused",
+        TimedOut => "timed_out",
+        NotTested => "not_tested",
+    }
+    Encapsulation {
+        None => "none",
+        Pppoe => "pppoe",
+        Tunnel => "tunnel",
+        Unknown => "unknown",
+    }
+    EcnMarking {
+        Preserved => "preserved",
+        CongestionExperienced => "congestion_experienced",
+        Bleached => "bleached",
+        NotTested => "not_tested",
+    }
+    NeighborProtocol {
+        Lldp => "lldp",
+        Cdp => "cdp",
+    }
+    IcmpUnreachable {
+        Port => "port",
+        Host => "host",
+        Network => "network",
+    }
+    QualityReason {
+        NoTargetsReachable => "no_targets_reachable",
+        PacketLoss => "packet_loss",
+        HighJitter => "high_jitter",
+        HighLatency => "high_latency",
+        CaptivePortal => "captive_portal",
+        NoRoute => "no_route",
+    }
+    LayerStatus {
+        Pass => "pass",
+        Degraded => "degraded",
+        Fail => "fail",
+        Unknown => "unknown",
+    }
+    NetworkScope {
+        None => "none",
+        LocalOnly => "local_only",
+        Internet => "internet",
+    }
+    SimpleStatus {
+        Green => "green",
+        Yellow => "yellow",
+        Red => "red",
+    }
+    AddressFamily {
+        V4 => "v4",
+        V6 => "v6",
+    }
+    TargetProtocol {
+        Tcp => "tcp",
+        Icmp => "icmp",
+        Http => "http",
+        Https => "https",
+        Udp => "udp",
+        Dns => "dns",
+    }
+    UdpValidation {
+        AnyResponse => "any_response",
+        Dns => "dns",
+        Ntp => "ntp",
+        Quic => "quic",
+    }
+    CircuitBreakerState {
+        Closed => "closed",
+        Open => "open",
+        HalfOpen => "half_open",
+    }
+    PipelineStep {
+        CaptivePortal => "captive_portal",
+        DnsSecurity => "dns_security",
+        IspStatus => "isp_status",
+        AddressFamilies => "address_families",
+        Multicast => "multicast",
+        Paths => "paths",
+        Attribution => "attribution",
+        Traceroute => "traceroute",
+        #[cfg(feature = "snmp")]
+        Snmp => "snmp",
+    }
+    MonitorState {
+        Stopped => "stopped",
+        Running => "running",
+        Paused => "paused",
+    }
+    DegradationScope {
+        NoData => "no_data",
+        Normal => "normal",
+        EndpointSpecific => "endpoint_specific",
+        Global => "global",
+    }
+    Limitation {
+        NoIcmpSockets => "no_icmp_sockets",
+        UdpTracerouteOnly => "udp_traceroute_only",
+        NoTraceroute => "no_traceroute",
+        NoNeighborTable => "no_neighbor_table",
+        NoTcpInfo => "no_tcp_info",
+        NoIpv4 => "no_ipv4",
+        NoIpv6 => "no_ipv6",
+        Virtualized => "virtualized",
+        NoSnmp => "no_snmp",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn assert_round_trip<T: FromStr<Err = ParseEnumError> + PartialEq + std::fmt::Debug>(
+        all: &[T],
+        as_str: fn(&T) -> &'static str,
+    ) {
+        for variant in all {
+            assert_eq!(&as_str(variant).parse::<T>().unwrap(), variant);
+        }
+    }
+
+    #[test]
+    fn test_wire_names_round_trip() {
+        assert_round_trip(ConnectionQuality::ALL, ConnectionQuality::as_str);
+        assert_round_trip(EvidenceSource::ALL, EvidenceSource::as_str);
+        assert_round_trip(NatType::ALL, NatType::as_str);
+        assert_round_trip(PipelineStep::ALL, PipelineStep::as_str);
+        assert_round_trip(TargetProtocol::ALL, TargetProtocol::as_str);
+
+        for strategy in [CheckStrategy::Race, CheckStrategy::Quorum(2)] {
+            assert_eq!(strategy.to_string().parse::<CheckStrategy>(), Ok(strategy));
+        }
+        assert!("quorum".parse::<CheckStrategy>().is_err());
+        for mode in [SampleMode::Parallel, SampleMode::Paced(250)] {
+            assert_eq!(mode.to_string().parse::<SampleMode>(), Ok(mode));
+        }
+
+        // Names agree with the value part of the localization keys.
+        for quality in ConnectionQuality::ALL {
+            assert_eq!(quality.l10n_key(), format!("quality.{}", quality.as_str()));
+        }
+        for nat in NatType::ALL {
+            assert_eq!(nat.l10n_key(), format!("nat_type.{}", nat.as_str()));
+        }
+
+        let err = "Captive_Portal".parse::<ConnectionQuality>().unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "Unknown ConnectionQuality 'Captive_Portal'"
+        );
+    }
+}
@@ -0,0 +1,574 @@
+//! Periodic monitoring on the configured check interval.
+//!
+//! A [NetworkMonitor] runs [check_network](crate::api::engine::check_network) every
+//! [check_interval_ms](NetworkConfiguration::check_interval_ms) (spread by
+//! [check_interval_jitter_percent](NetworkConfiguration::check_interval_jitter_percent))
+//! and publishes every report to its subscribers, with the latency and loss
+//! [anomalies](NetworkReport::anomalies) it raised against the previous ones:
+//!
+//! ```ignore
+//! let monitor = NetworkMonitor::new(NetworkConfiguration::default());
+//! let mut reports = Box::pin(monitor.reports());
+//! monitor.start();
+//! while let Some(report) = reports.next().await {
+//!     println!("connected: {}", report.status.is_connected);
+//! }
+//! ```
+//!
+//! Dart receives the same reports through [NetworkMonitor::stream_reports].
+
+use crate::{
+    api::{
+        analysis::{anomaly::AnomalyDetector, detect_roam},
+        constants::LibConstants,
+        engine::{
+            check_network_with_limiter, rate_limit::RateLimiter, schedule::next_check_delay_ms,
+        },
+        models::{NetworkConfiguration, NetworkReport, OverlapPolicy},
+    },
+    frb_generated::{StreamSink, FLUTTER_RUST_BRIDGE_HANDLER},
+};
+use flutter_rust_bridge::{frb, BaseAsyncRuntime};
+use futures::{Stream, StreamExt};
+use std::{
+    sync::{Arc, Mutex, MutexGuard},
+    time::{Duration, Instant},
+};
+use tokio::{
+    runtime::Handle,
+    sync::{
+        broadcast::{self, error::RecvError},
+        watch,
+    },
+    task::JoinHandle,
+};
+
+/// The lifecycle state of a [NetworkMonitor].
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MonitorState {
+    /// No checks are scheduled; report streams have ended.
+    Stopped,
+
+    /// Checks run on the configured interval.
+    Running,
+
+    /// The schedule is suspended; a check already under way still completes.
+    Paused,
+}
+
+/// Counters of a [NetworkMonitor]'s schedule since the monitor was created.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct MonitorStats {
+    /// Checks that completed.
+    pub completed_checks: u64,
+
+    /// Checks that were still running when the next one was due.
+    pub overruns: u64,
+
+    /// Cycles that got no check of their own, as decided by the
+    /// [overlap_policy](crate::api::models::ResilienceConfig::overlap_policy).
+    pub skipped_cycles: u64,
+}
+
+/// Runs [check_network](crate::api::engine::check_network) on the configured interval until stopped.
+///
+/// A `check_interval_ms` of 0 disables periodic checks: the monitor then
+/// runs a single check and stops. Checks start one interval apart; a check
+/// that takes longer is handled according to
+/// [overlap_policy](crate::api::models::ResilienceConfig::overlap_policy).
+#[derive(Debug)]
+pub struct NetworkMonitor {
+    config: NetworkConfiguration,
+    runtime: Option<Handle>,
+    /// Shared by every check, so the rate limit holds across checks.
+    limiter: Arc<RateLimiter>,
+    anomaly_detector: AnomalyDetector,
+    control: Arc<watch::Sender<MonitorState>>,
+    reports: broadcast::Sender<NetworkReport>,
+    stats: Arc<Mutex<MonitorStats>>,
+    task: Mutex<Option<JoinHandle<()>>>,
+}
+
+impl NetworkMonitor {
+    /// Creates a stopped monitor for `config`.
+    pub fn new(config: NetworkConfiguration) -> Self {
+        Self {
+            limiter: Arc::new(RateLimiter::new(&config.rate_limit)),
+            config,
+            runtime: None,
+            anomaly_detector: AnomalyDetector::default(),
+            control: Arc::new(watch::Sender::new(MonitorState::Stopped)),
+            reports: broadcast::channel(LibConstants::MONITOR_REPORT_BUFFER).0,
+            stats: Arc::new(Mutex::new(MonitorStats::default())),
+            task: Mutex::new(None),
+        }
+    }
+
+    /// Spawns the monitoring task onto the runtime behind `handle` instead of
+    /// the ambient runtime of the caller of [Self::start].
+    #[frb(ignore)]
+    pub fn with_runtime(mut self, handle: Handle) -> Self {
+        self.runtime = Some(handle);
+        self
+    }
+
+    /// Draws the probe traffic of the checks from `limiter` instead of a
+    /// limiter built from the monitor's own
+    /// [rate_limit](NetworkConfiguration::rate_limit), e.g. to share one with
+    /// a [ReachabilityEngine](crate::api::engine::ReachabilityEngine).
+    #[frb(ignore)]
+    pub fn with_rate_limiter(mut self, limiter: Arc<RateLimiter>) -> Self {
+        self.limiter = limiter;
+        self
+    }
+
+    /// Flags anomalies with `detector` instead of a default [AnomalyDetector].
+    /// Every start begins with the detector's baseline.
+    pub fn with_anomaly_detector(self, detector: AnomalyDetector) -> Self {
+        let mut monitor = self;
+        monitor.anomaly_detector = detector;
+        monitor
+    }
+
+    /// The configuration this monitor was created with.
+    #[frb(ignore)]
+    pub fn config(&self) -> &NetworkConfiguration {
+        &self.config
+    }
+
+    /// The current lifecycle state.
+    pub fn state(&self) -> MonitorState {
+        *self.control.borrow()
+    }
+
+    /// Counters of completed, overrunning and skipped cycles.
+    pub fn stats(&self) -> MonitorStats {
+        *lock(&self.stats)
+    }
+
+    /// Starts monitoring with an immediate check, or resumes a paused monitor.
+    ///
+    /// The monitoring task runs on the runtime injected via
+    /// [Self::with_runtime], else on the caller's runtime, else (e.g. when
+    /// called from Dart) on the bridge's runtime.
+    pub fn start(&self) {
+        let mut task = self.task();
+        if task.as_ref().is_some_and(|t| !t.is_finished()) {
+            self.resume();
+            return;
+        }
+
+        self.control.send_replace(MonitorState::Running);
+        let run = run(
+            self.config.clone(),
+            Arc::clone(&self.limiter),
+            self.anomaly_detector.clone(),
+            self.reports.clone(),
+            Arc::clone(&self.control),
+            Arc::clone(&self.stats),
+        );
+        *task = Some(match &self.runtime {
+            Some(handle) => handle.spawn(run),
+            None => match Handle::try_current() {
+                Ok(handle) => handle.spawn(run),
+                Err(_) => FLUTTER_RUST_BRIDGE_HANDLER.async_runtime().spawn(run),
+            },
+        });
+    }
+
+    /// Suspends the schedule. Does nothing unless running.
+    pub fn pause(&self) {
+        self.control.send_if_modified(|state| {
+            let running = *state == MonitorState::Running;
+            if running {
+                *state = MonitorState::Paused;
+            }
+            running
+        });
+    }
+
+    /// Resumes a paused monitor with an immediate check. Does nothing unless
+    /// paused.
+    pub fn resume(&self) {
+        self.control.send_if_modified(|state| {
+            let paused = *state == MonitorState::Paused;
+            if paused {
+                *state = MonitorState::Running;
+            }
+            paused
+        });
+    }
+
+    /// Stops monitoring, abandoning a check under way, and ends all report
+    /// streams. The monitor can be started again.
+    pub fn stop(&self) {
+        self.control.send_replace(MonitorState::Stopped);
+        if let Some(task) = self.task().take() {
+            task.abort();
+        }
+    }
+
+    /// Stops monitoring like [Self::stop], then waits up to `timeout_ms` (0
+    /// uses [DEFAULT_SHUTDOWN_TIMEOUT_MS](LibConstants::DEFAULT_SHUTDOWN_TIMEOUT_MS))
+    /// for the monitoring task and the check it ran to be dropped. Returns
+    /// false if that did not happen in time.
+    ///
+    /// Once it returns true, no task of this monitor is left on the runtime.
+    pub async fn shutdown(&self, timeout_ms: u64) -> bool {
+        self.control.send_replace(MonitorState::Stopped);
+        let Some(task) = self.task().take() else {
+            return true;
+        };
+        task.abort();
+        let wait = Duration::from_millis(if timeout_ms == 0 {
+            LibConstants::DEFAULT_SHUTDOWN_TIMEOUT_MS
+        } else {
+            timeout_ms
+        });
+        tokio::time::timeout(wait, task).await.is_ok()
+    }
+
+    /// A stream of the reports of all checks completed from now on.
+    ///
+    /// Ends when the monitor stops. A subscriber that falls more than
+    /// [MONITOR_REPORT_BUFFER](LibConstants::MONITOR_REPORT_BUFFER) reports
+    /// behind skips the oldest ones.
+    #[frb(ignore)]
+    pub fn reports(&self) -> impl Stream<Item = NetworkReport> + Send + 'static {
+        let reports = self.reports.subscribe();
+        let mut control = self.control.subscribe();
+        control.mark_unchanged();
+
+        futures::stream::unfold(
+            (reports, control),
+            |(mut reports, mut control)| async move {
+                loop {
+                    tokio::select! {
+                        // A report sent just before stopping is still delivered.
+                        biased;
+                        received = reports.recv() => match received {
+                            Ok(report) => return Some((report, (reports, control))),
+                            Err(RecvError::Lagged(_)) => continue,
+                            Err(RecvError::Closed) => return None,
+                        },
+                        changed = control.changed() => {
+                            if changed.is_err() || *control.borrow() == MonitorState::Stopped {
+                                return None;
+                            }
+                        }
+                    }
+                }
+            },
+        )
+    }
+
+    /// Forwards [Self::reports] to Dart until the monitor stops or the Dart
+    /// side cancels its subscription.
+    pub async fn stream_reports(&self, sink: StreamSink<NetworkReport>) {
+        let mut reports = Box::pin(self.reports());
+        while let Some(report) = reports.next().await {
+            if sink.add(report).is_err() {
+                break;
+            }
+        }
+    }
+
+    fn task(&self) -> MutexGuard<'_, Option<JoinHandle<()>>> {
+        lock(&self.task)
+    }
+}
+
+fn lock<T>(mutex: &Mutex<T>) -> MutexGuard<'_, T> {
+    mutex.lock().unwrap_or_else(|e| e.into_inner())
+}
+
+impl Drop for NetworkMonitor {
+    fn drop(&mut self) {
+        self.stop();
+    }
+}
+
+/// The monitoring loop: check, publish, wait, repeat.
+async fn run(
+    config: NetworkConfiguration,
+    limiter: Arc<RateLimiter>,
+    mut anomaly_detector: AnomalyDetector,
+    reports: broadcast::Sender<NetworkReport>,
+    control: Arc<watch::Sender<MonitorState>>,
+    stats: Arc<Mutex<MonitorStats>>,
+) {
+    let mut state = control.subscribe();
+    let mut previous: Option<NetworkReport> = None;
+    let mut backlog = 0;
+    loop {
+        match state.wait_for(|s| *s != MonitorState::Paused).await {
+            Ok(s) if *s == MonitorState::Running => {}
+            _ => return,
+        }
+
+        let started = Instant::now();
+        let mut report = check_network_with_limiter(config.clone(), limiter.clone()).await;
+        if let Some(previous) = &previous {
+            report.roam = detect_roam(previous, &report, &config.resilience);
+        }
+        report.anomalies = anomaly_detector.observe(&report);
+        // Nobody listening is fine; the next subscriber gets the next report.
+        let _ = reports.send(report.clone());
+        previous = Some(report);
+
+        lock(&stats).completed_checks += 1;
+
+        if config.check_interval_ms == 0 {
+            control.send_replace(MonitorState::Stopped);
+            return;
+        }
+        let delay = next_check_delay_ms(&config);
+        let wait = {
+            let mut stats = lock(&stats);
+            next_wait(
+                config.resilience.overlap_policy,
+                started.elapsed(),
+                Duration::from_millis(delay),
+                &mut backlog,
+                &mut stats,
+            )
+        };
+        tokio::select! {
+            _ = tokio::time::sleep(wait) => {}
+            changed = state.changed() => {
+                // Running again means a pause and resume since the last check,
+                // and a resume checks at once. A pause is waited out at the
+                // top of the loop.
+                if changed.is_err() || *state.borrow_and_update() == MonitorState::Stopped {
+                    return;
+                }
+            }
+        }
+    }
+}
+
+/// How long to wait before the next check, given that the last one took
+/// `elapsed` and checks are due every `interval`.
+///
+/// `backlog` holds the overdue checks still pending under
+/// [OverlapPolicy::Queue]; `stats` receives the overrun and skipped cycles.
+fn next_wait(
+    policy: OverlapPolicy,
+    elapsed: Duration,
+    interval: Duration,
+    backlog: &mut u32,
+    stats: &mut MonitorStats,
+) -> Duration {
+    if elapsed <= interval {
+        if *backlog > 0 {
+            *backlog -= 1;
+            return Duration::ZERO;
+        }
+        return interval - elapsed;
+    }
+
+    // Every slot that passed while the check ran, the regular next one included.
+    let late = elapsed - interval;
+    let missed = 1 + (late.as_millis() / interval.as_millis().max(1)) as u32;
+    stats.overruns += 1;
+    match policy {
+        OverlapPolicy::Skip => {
+            stats.skipped_cycles += u64::from(missed);
+            interval * (missed + 1) - elapsed
+        }
+        OverlapPolicy::Coalesce => {
+            stats.skipped_cycles += u64::from(missed - 1);
+            Duration::ZERO
+        }
+        OverlapPolicy::Queue => {
+            // One overdue check runs now; the rest wait in the backlog.
+            let pending = *backlog + missed - 1;
+            *backlog = pending.min(LibConstants::MONITOR_MAX_QUEUED_CHECKS);
+            stats.skipped_cycles += u64::from(pending - *backlog);
+            Duration::ZERO
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_monitor_runs_on_interval_and_stops() {
+        // No targets and no optional probes: checks complete without network I/O.
+        let mut config = NetworkConfiguration {
+            targets: vec![],
+            check_interval_ms: 20,
+            check_interval_jitter_percent: 0,
+            ..NetworkConfiguration::default()
+        };
+        config.security.detect_dns_hijack = false;
+
+        let monitor = NetworkMonitor::new(config.clone());
+        let mut reports = Box::pin(monitor.reports());
+        monitor.start();
+        let first = reports.next().await.unwrap();
+        let second = reports.next().await.unwrap();
+        assert!(second.timestamp_ms >= first.timestamp_ms);
+
+        monitor.pause();
+        assert_eq!(monitor.state(), MonitorState::Paused);
+        monitor.resume();
+        assert_eq!(monitor.state(), MonitorState::Running);
+
+        monitor.stop();
+        assert_eq!(monitor.state(), MonitorState::Stopped);
+        while reports.next().await.is_some() {}
+
+        // Without an interval, a single check runs.
+        config.check_interval_ms = 0;
+        let once = NetworkMonitor::new(config);
+        let reports = once.reports();
+        once.start();
+        assert_eq!(reports.collect::<Vec<_>>().await.len(), 1);
+        assert_eq!(once.state(), MonitorState::Stopped);
+        assert_eq!(once.stats().completed_checks, 1);
+    }
+
+    #[test]
+    fn test_start_outside_a_runtime() {
+        // Dart calls start() from a plain thread pool thread.
+        let mut config = NetworkConfiguration {
+            targets: vec![],
+            check_interval_ms: 0,
+            ..NetworkConfiguration::default()
+        };
+        config.security.detect_dns_hijack = false;
+
+        let monitor = NetworkMonitor::new(config);
+        monitor.start();
+        let deadline = Instant::now() + Duration::from_secs(10);
+        while monitor.stats().completed_checks == 0 && Instant::now() < deadline {
+            std::thread::sleep(Duration::from_millis(10));
+        }
+        assert_eq!(monitor.stats().completed_checks, 1);
+    }
+
+    #[tokio::test]
+    async fn test_resume_checks_immediately() {
+        let mut config = NetworkConfiguration {
+            targets: vec![],
+            check_interval_ms: 60_000,
+            check_interval_jitter_percent: 0,
+            ..NetworkConfiguration::default()
+        };
+        config.security.detect_dns_hijack = false;
+
+        let monitor = NetworkMonitor::new(config);
+        let mut reports = Box::pin(monitor.reports());
+        monitor.start();
+        reports.next().await.unwrap();
+
+        monitor.pause();
+        monitor.resume();
+        let resumed = tokio::time::timeout(Duration::from_secs(5), reports.next()).await;
+        assert!(resumed.unwrap().is_some());
+        monitor.stop();
+    }
+
+    #[tokio::test]
+    async fn test_shutdown_drops_running_check() {
+        use crate::api::testsupport::TestServer;
+
+        let server = TestServer::http().latency_ms(10_000).start().await.unwrap();
+        let mut target = server.target("slow");
+        target.timeout_ms = 10_000;
+        let mut config = NetworkConfiguration {
+            targets: vec![target],
+            ..NetworkConfiguration::default()
+        };
+        config.security.detect_dns_hijack = false;
+
+        let monitor = NetworkMonitor::new(config);
+        let reports = monitor.reports();
+        monitor.start();
+        tokio::time::sleep(Duration::from_millis(50)).await;
+
+        assert!(monitor.shutdown(1000).await);
+        assert_eq!(monitor.state(), MonitorState::Stopped);
+        assert!(monitor.task().is_none());
+        assert!(reports.collect::<Vec<_>>().await.is_empty());
+        // A second shutdown has nothing left to wait for.
+        assert!(monitor.shutdown(0).await);
+    }
+
+    #[tokio::test]
+    async fn test_monitor_reports_anomalies() {
+        use crate::api::{models::AnomalyMetric, testsupport::TestServer};
+
+        let server = TestServer::tcp_echo().start().await.unwrap();
+        let mut config = NetworkConfiguration {
+            targets: vec![server.target("echo")],
+            check_interval_ms: 100,
+            check_interval_jitter_percent: 0,
+            ..NetworkConfiguration::default()
+        };
+        config.security.detect_dns_hijack = false;
+
+        let monitor =
+            NetworkMonitor::new(config).with_anomaly_detector(AnomalyDetector::new(0.2, 3.0, 3));
+        let mut reports = Box::pin(monitor.reports());
+        monitor.start();
+        for _ in 0..3 {
+            assert!(reports.next().await.unwrap().anomalies.is_empty());
+        }
+
+        // The target goes away: every probe is lost from now on.
+        drop(server);
+        let report = reports.next().await.unwrap();
+        assert!(!report.status.is_connected);
+        assert_eq!(report.anomalies.len(), 1);
+        assert_eq!(report.anomalies[0].metric, AnomalyMetric::PacketLoss);
+        monitor.stop();
+    }
+
+    #[test]
+    fn test_overlap_policies() {
+        let ms = Duration::from_millis;
+        let wait = |policy, elapsed, backlog: &mut u32, stats: &mut MonitorStats| {
+            next_wait(policy, ms(elapsed), ms(100), backlog, stats)
+        };
+
+        let mut stats = MonitorStats::default();
+        let mut backlog = 0;
+        assert_eq!(
+            wait(OverlapPolicy::Skip, 30, &mut backlog, &mut stats),
+            ms(70)
+        );
+        assert_eq!(stats.overruns, 0);
+
+        // 250 ms: the slots at 100 and 200 ms passed.
+        assert_eq!(
+            wait(OverlapPolicy::Skip, 250, &mut backlog, &mut stats),
+            ms(50)
+        );
+        assert_eq!(stats.skipped_cycles, 2);
+
+        let mut stats = MonitorStats::default();
+        assert_eq!(
+            wait(OverlapPolicy::Coalesce, 250, &mut backlog, &mut stats),
+            ms(0)
+        );
+        assert_eq!(stats.skipped_cycles, 1);
+
+        let mut stats = MonitorStats::default();
+        assert_eq!(
+            wait(OverlapPolicy::Queue, 550, &mut backlog, &mut stats),
+            ms(0)
+        );
+        assert_eq!(backlog, 3);
+        assert_eq!(stats.skipped_cycles, 1);
+        assert_eq!(
+            wait(OverlapPolicy::Queue, 10, &mut backlog, &mut stats),
+            ms(0)
+        );
+        assert_eq!(backlog, 2);
+        assert_eq!(stats.overruns, 1);
+    }
+}
@@ -0,0 +1,126 @@
+//! OS-backed path information for macOS/iOS.
+//!
+//! Apple platforms expose authoritative reachability data through
+//! `NWPathMonitor` (and the older `SCNetworkReachability`). Rather than linking
+//! the Network framework from Rust, the host application (Swift plugin or the
+//! Flutter layer) forwards every path update through [update_apple_path],
+//! which installs the connection type, VPN state, interface and
+//! expensive/constrained flags of the path as [PlatformHints]. SSID and BSSID
+//! hints set via [set_platform_hints](crate::api::engine::set_platform_hints)
+//! are kept.
+//!
+//! Rust consumers can observe path changes via [subscribe_apple_path].
+
+use crate::api::{
+    engine::hints::update_platform_hints,
+    models::{
+        ApplePathInterfaceType, ApplePathSnapshot, ApplePathStatus, ConnectionType, PlatformHints,
+    },
+};
+use flutter_rust_bridge::frb;
+use std::sync::OnceLock;
+use tokio::sync::watch;
+
+fn channel() -> &'static watch::Sender<Option<ApplePathSnapshot>> {
+    static PATH: OnceLock<watch::Sender<Option<ApplePathSnapshot>>> = OnceLock::new();
+    PATH.get_or_init(|| watch::channel(None).0)
+}
+
+/// Records the latest OS path and installs its facts as platform hints.
+/// Call this from the `NWPathMonitor` update handler.
+pub fn update_apple_path(snapshot: ApplePathSnapshot) {
+    let path = path_hints(&snapshot);
+    update_platform_hints(|hints| {
+        *hints = PlatformHints {
+            ssid: hints.ssid.take(),
+            bssid: hints.bssid.take(),
+            ..path
+        }
+    });
+    channel().send_replace(Some(snapshot));
+}
+
+/// Forgets the injected path and the hints it installed, reverting to
+/// interface-name heuristics.
+pub fn clear_apple_path() {
+    update_platform_hints(|hints| {
+        *hints = PlatformHints {
+            ssid: hints.ssid.take(),
+            bssid: hints.bssid.take(),
+            ..PlatformHints::default()
+        }
+    });
+    channel().send_replace(None);
+}
+
+/// The most recently injected OS path, if any.
+pub fn current_apple_path() -> Option<ApplePathSnapshot> {
+    channel().borrow().clone()
+}
+
+/// Returns a receiver that is notified whenever the injected path changes.
+#[frb(ignore)]
+pub fn subscribe_apple_path() -> watch::Receiver<Option<ApplePathSnapshot>> {
+    channel().subscribe()
+}
+
+/// The platform hints `path` supplies; SSID and BSSID are left unset.
+fn path_hints(path: &ApplePathSnapshot) -> PlatformHints {
+    let satisfied = path.status == ApplePathStatus::Satisfied;
+    let connection_type = if !satisfied {
+        ConnectionType::Unknown
+    } else if path.uses_vpn {
+        ConnectionType::Vpn
+    } else {
+        match path.interface_type {
+            ApplePathInterfaceType::Wifi => ConnectionType::Wifi,
+            ApplePathInterfaceType::Cellular => ConnectionType::Cellular,
+            ApplePathInterfaceType::WiredEthernet => ConnectionType::Ethernet,
+            ApplePathInterfaceType::Loopback => ConnectionType::Loopback,
+            ApplePathInterfaceType::Other => ConnectionType::Unknown,
+        }
+    };
+
+    PlatformHints {
+        connection_type: Some(connection_type),
+        is_metered: Some(path.is_expensive),
+        is_constrained: Some(path.is_constrained),
+        is_vpn: Some(path.uses_vpn),
+        interface_name: satisfied.then(|| path.interface_name.clone()),
+        ..PlatformHints::default()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn snapshot(status: ApplePathStatus, uses_vpn: bool) -> ApplePathSnapshot {
+        ApplePathSnapshot {
+            status,
+            interface_type: ApplePathInterfaceType::Cellular,
+            interface_name: "pdp_ip0".into(),
+            is_expensive: true,
+            is_constrained: false,
+            uses_vpn,
+        }
+    }
+
+    #[test]
+    fn test_path_hints() {
+        let hints = path_hints(&snapshot(ApplePathStatus::Satisfied, false));
+        assert_eq!(hints.connection_type, Some(ConnectionType::Cellular));
+        assert_eq!(hints.interface_name.as_deref(), Some("pdp_ip0"));
+        assert_eq!(hints.is_vpn, Some(false));
+        assert_eq!(hints.is_metered, Some(true));
+        assert_eq!(hints.is_constrained, Some(false));
+
+        let hints = path_hints(&snapshot(ApplePathStatus::Satisfied, true));
+        assert_eq!(hints.connection_type, Some(ConnectionType::Vpn));
+        assert_eq!(hints.is_vpn, Some(true));
+
+        let hints = path_hints(&snapshot(ApplePathStatus::Unsatisfied, false));
+        assert_eq!(hints.connection_type, Some(ConnectionType::Unknown));
+        assert_eq!(hints.interface_name, None);
+    }
+}
@@ -0,0 +1,233 @@
+//! Access to the OS neighbor (ARP) table.
+//!
+//! The table maps the IPv4 addresses of recently contacted LAN hosts to their
+//! MAC addresses. Where it comes from depends on the platform:
+//!
+//! - Linux/Android: `/proc/net/arp`. Android 10+ denies apps access to it,
+//!   in which case the table reads as empty.
+//! - Windows: `GetIpNetTable2`.
+//! - macOS: the output of `arp -an`, as the routing socket dump needs more
+//!   privileges than the command-line tool.
+//! - Elsewhere (iOS included) the table is not available and reads as empty.
+
+use crate::api::models::ArpEntry;
+use std::net::IpAddr;
+
+/// Returns the complete entries of the OS neighbor table.
+///
+/// Incomplete entries (resolution still pending or failed) are left out.
+/// MAC addresses are lowercase, colon-separated and zero-padded.
+pub fn read_arp_table() -> Vec<ArpEntry> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        std::fs::read_to_string("/proc/net/arp")
+            .map(|table| parse_proc_net_arp(&table))
+            .unwrap_or_default()
+    }
+    #[cfg(windows)]
+    {
+        windows::read_table()
+    }
+    #[cfg(target_os = "macos")]
+    {
+        std::process::Command::new("/usr/sbin/arp")
+            .arg("-an")
+            .output()
+            .map(|out| parse_bsd_arp(&String::from_utf8_lossy(&out.stdout)))
+            .unwrap_or_default()
+    }
+    #[cfg(not(any(
+        target_os = "linux",
+        target_os = "android",
+        target_os = "macos",
+        windows
+    )))]
+    {
+        Vec::new()
+    }
+}
+
+/// [read_arp_table] on the blocking thread pool, as it may read a file or
+/// run `arp`.
+pub(crate) async fn read_neighbor_table() -> Vec<ArpEntry> {
+    tokio::task::spawn_blocking(read_arp_table)
+        .await
+        .unwrap_or_default()
+}
+
+/// Returns the MAC address the OS has cached for `ip`, if any.
+///
+/// The entry usually exists right after any traffic to `ip`, e.g. a probe of
+/// the gateway.
+pub(crate) async fn neighbor_mac(ip: IpAddr) -> Option<String> {
+    read_neighbor_table()
+        .await
+        .into_iter()
+        .find(|entry| entry.ip_address.parse() == Ok(ip))
+        .map(|entry| entry.mac_address)
+}
+
+/// Parses the complete entries of `/proc/net/arp`.
+///
+/// Columns: `IP address, HW type, Flags, HW address, Mask, Device`.
+/// Incomplete entries (flags `0x0`) carry an all-zero MAC and are skipped.
+#[cfg_attr(
+    not(any(target_os = "linux", target_os = "android", test)),
+    allow(dead_code)
+)]
+pub(crate) fn parse_proc_net_arp(table: &str) -> Vec<ArpEntry> {
+    table
+        .lines()
+        .skip(1)
+        .filter_map(|line| {
+            let fields: Vec<&str> = line.split_whitespace().collect();
+            let ip: IpAddr = fields.first()?.parse().ok()?;
+            let flags = fields.get(2)?;
+            let mac = fields.get(3)?;
+            (*flags != "0x0" && *mac != "00:00:00:00:00:00").then(|| ArpEntry {
+                ip_address: ip.to_string(),
+                mac_address: mac.to_ascii_lowercase(),
+                interface: fields.get(5).map(|d| d.to_string()),
+            })
+        })
+        .collect()
+}
+
+/// Parses `arp -an` output of macOS and the BSDs:
+/// `? (192.168.1.1) at c0:25:6:aa:bb:cc on en0 ifscope [ethernet]`.
+///
+/// These tools drop leading zeros of each octet, which are restored.
+#[cfg_attr(not(any(target_os = "macos", test)), allow(dead_code))]
+pub(crate) fn parse_bsd_arp(output: &str) -> Vec<ArpEntry> {
+    output
+        .lines()
+        .filter_map(|line| {
+            let ip_start = line.find('(')? + 1;
+            let ip_end = ip_start + line[ip_start..].find(')')?;
+            let ip: IpAddr = line[ip_start..ip_end].parse().ok()?;
+
+            let mut rest = line[ip_end..].split_whitespace();
+            if rest.nth(1)? != "at" {
+                return None;
+            }
+            let mac = normalize_mac(rest.next()?)?;
+            let interface = match (rest.next(), rest.next()) {
+                (Some("on"), Some(name)) => Some(name.to_string()),
+                _ => None,
+            };
+            Some(ArpEntry {
+                ip_address: ip.to_string(),
+                mac_address: mac,
+                interface,
+            })
+        })
+        .collect()
+}
+
+/// Lowercase, colon-separated, zero-padded form of a 6-octet MAC address
+/// written with `:` or `-`; `None` for anything else, e.g. `(incomplete)`.
+#[cfg_attr(not(any(target_os = "macos", test)), allow(dead_code))]
+fn normalize_mac(raw: &str) -> Option<String> {
+    let octets: Vec<u8> = raw
+        .split([':', '-'])
+        .map(|octet| u8::from_str_radix(octet, 16).ok())
+        .collect::<Option<_>>()?;
+    (octets.len() == 6 && octets.iter().any(|&b| b != 0)).then(|| format_mac(&octets))
+}
+
+#[cfg_attr(not(any(target_os = "macos", windows, test)), allow(dead_code))]
+fn format_mac(octets: &[u8]) -> String {
+    octets
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+#[cfg(windows)]
+mod windows {
+    //! `GetIpNetTable2`-based neighbor table.
+
+    use super::format_mac;
+    use crate::api::models::ArpEntry;
+    use std::net::Ipv4Addr;
+    use windows_sys::Win32::{
+        Foundation::NO_ERROR,
+        NetworkManagement::IpHelper::{FreeMibTable, GetIpNetTable2, MIB_IPNET_TABLE2},
+        Networking::WinSock::{NlnsIncomplete, NlnsUnreachable, AF_INET},
+    };
+
+    pub(super) fn read_table() -> Vec<ArpEntry> {
+        let mut table: *mut MIB_IPNET_TABLE2 = std::ptr::null_mut();
+        // SAFETY: `table` is a valid out-pointer; on success it points to a
+        // table that stays valid until `FreeMibTable`.
+        if unsafe { GetIpNetTable2(AF_INET, &mut table) } != NO_ERROR || table.is_null() {
+            return Vec::new();
+        }
+
+        let mut entries = Vec::new();
+        // SAFETY: the table holds `NumEntries` rows, and every row of an
+        // AF_INET table carries an IPv4 address.
+        unsafe {
+            let rows =
+                std::slice::from_raw_parts((*table).Table.as_ptr(), (*table).NumEntries as usize);
+            for row in rows {
+                if row.State == NlnsIncomplete || row.State == NlnsUnreachable {
+                    continue;
+                }
+                let len = (row.PhysicalAddressLength as usize).min(row.PhysicalAddress.len());
+                let mac = &row.PhysicalAddress[..len];
+                if len != 6 || mac.iter().all(|&b| b == 0) {
+                    continue;
+                }
+                let octets = row.Address.Ipv4.sin_addr.S_un.S_addr.to_ne_bytes();
+                entries.push(ArpEntry {
+                    ip_address: Ipv4Addr::from(octets).to_string(),
+                    mac_address: format_mac(mac),
+                    interface: None,
+                });
+            }
+            FreeMibTable(table.cast_const().cast());
+        }
+        entries
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_proc_net_arp_parsing() {
+        let table = "\
+IP address       HW type     Flags       HW address            Mask     Device
+192.168.1.1      0x1         0x2         C0:25:06:AA:BB:CC     *        wlan0
+192.168.1.7      0x1         0x0         00:00:00:00:00:00     *        wlan0
+";
+        assert_eq!(
+            parse_proc_net_arp(table),
+            vec![ArpEntry {
+                ip_address: "192.168.1.1".into(),
+                mac_address: "c0:25:06:aa:bb:cc".into(),
+                interface: Some("wlan0".into()),
+            }]
+        );
+
+        let bsd = "\
+? (192.168.1.1) at c0:25:6:aa:bb:cc on en0 ifscope [ethernet]
+? (192.168.1.7) at (incomplete) on en0 ifscope [ethernet]
+";
+        assert_eq!(
+            parse_bsd_arp(bsd),
+            vec![ArpEntry {
+                ip_address: "192.168.1.1".into(),
+                mac_address: "c0:25:06:aa:bb:cc".into(),
+                interface: Some("en0".into()),
+            }]
+        );
+        assert_eq!(
+            normalize_mac("C0-25-06-AA-BB-CC").as_deref(),
+            Some("c0:25:06:aa:bb:cc")
+        );
+    }
+}
@@ -0,0 +1,164 @@
+//! Service identification from the banners of open TCP ports.
+//!
+//! Many services announce themselves as soon as a client connects (SSH, FTP,
+//! SMTP). Others, most notably HTTP and RTSP, wait for a request. A banner
+//! grab first listens briefly and, if the server stays silent, sends a minimal
+//! request: RTSP on the RTSP ports, HTTP everywhere else. Every step is
+//! bounded by a single deadline.
+
+use crate::api::{constants::LibConstants, models::ServiceInfo};
+use flutter_rust_bridge::frb;
+use std::{net::SocketAddr, time::Duration};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::TcpStream,
+    time::{timeout, Instant},
+};
+
+/// Connects to `addr` and identifies the service behind it.
+///
+/// Returns `None` if the port is not open within `timeout_ms`.
+#[frb(ignore)]
+pub async fn grab_banner(addr: SocketAddr, timeout_ms: u64) -> Option<ServiceInfo> {
+    let wait = Duration::from_millis(timeout_ms);
+    let deadline = Instant::now() + wait;
+    let mut stream = timeout(wait, TcpStream::connect(addr)).await.ok()?.ok()?;
+    Some(
+        read_banner(
+            &mut stream,
+            addr.port(),
+            deadline.saturating_duration_since(Instant::now()),
+        )
+        .await,
+    )
+}
+
+/// Reads a banner from an already connected `stream` within `wait`.
+///
+/// Half of `wait` is spent listening for a greeting before falling back to an
+/// HTTP (or, on [RTSP_PORTS](LibConstants::RTSP_PORTS), RTSP) request.
+pub(crate) async fn read_banner(stream: &mut TcpStream, port: u16, wait: Duration) -> ServiceInfo {
+    let deadline = Instant::now() + wait;
+    let mut buf = vec![0u8; LibConstants::MAX_BANNER_BYTES];
+
+    let mut len = read_some(stream, &mut buf, Instant::now() + wait / 2).await;
+    if len == 0 {
+        let request: &[u8] = if LibConstants::RTSP_PORTS.contains(&port) {
+            b"OPTIONS * RTSP/1.0\r\nCSeq: 1\r\n\r\n"
+        } else {
+            b"HEAD / HTTP/1.0\r\n\r\n"
+        };
+        if stream.write_all(request).await.is_ok() {
+            len = read_some(stream, &mut buf, deadline).await;
+        }
+    }
+    identify(port, &buf[..len])
+}
+
+/// Reads until the buffer is full, the peer closes or `deadline` passes.
+async fn read_some(stream: &mut TcpStream, buf: &mut [u8], deadline: Instant) -> usize {
+    let mut len = 0;
+    while len < buf.len() {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        match timeout(remaining, stream.read(&mut buf[len..])).await {
+            Ok(Ok(n)) if n > 0 => len += n,
+            _ => break,
+        }
+        // A complete first line is all we need from a greeting; HTTP and
+        // RTSP responses are read on for the `Server` header.
+        if !is_response(&buf[..len]) && buf[..len].contains(&b'\n') {
+            break;
+        }
+        if buf[..len].windows(4).any(|w| w == b"\r\n\r\n") {
+            break;
+        }
+    }
+    len
+}
+
+/// True if `raw` starts like an HTTP or RTSP response, whose headers follow.
+fn is_response(raw: &[u8]) -> bool {
+    raw.starts_with(b"HTTP/") || raw.starts_with(b"RTSP/")
+}
+
+/// Recognizes the protocol from a raw banner.
+pub(crate) fn identify(port: u16, raw: &[u8]) -> ServiceInfo {
+    let text = String::from_utf8_lossy(raw);
+    let first_line = text.lines().next().map(str::trim).filter(|l| !l.is_empty());
+
+    let (protocol, banner) = match first_line {
+        Some(line) if line.starts_with("SSH-") => (Some("ssh"), Some(line.to_string())),
+        Some(line) if is_response(line.as_bytes()) => {
+            let protocol = if line.starts_with("RTSP/") {
+                "rtsp"
+            } else {
+                "http"
+            };
+            let server = text
+                .lines()
+                .skip(1)
+                .find_map(|l| {
+                    let (name, value) = l.split_once(':')?;
+                    name.trim()
+                        .eq_ignore_ascii_case("server")
+                        .then(|| value.trim().to_string())
+                })
+                .filter(|s| !s.is_empty());
+            (Some(protocol), server.or_else(|| Some(line.to_string())))
+        }
+        Some(line) => (None, Some(line.to_string())),
+        None => (None, None),
+    };
+    ServiceInfo {
+        port,
+        protocol: protocol.map(str::to_string),
+        banner,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::TcpListener;
+
+    #[tokio::test]
+    async fn test_banner_grab_identifies_ssh_and_http() {
+        let ssh = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let ssh_addr = ssh.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = ssh.accept().await.unwrap();
+            let _ = socket.write_all(b"SSH-2.0-OpenSSH_9.6\r\n").await;
+            tokio::time::sleep(Duration::from_secs(1)).await;
+        });
+
+        let http = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let http_addr = http.local_addr().unwrap();
+        tokio::spawn(async move {
+            let (mut socket, _) = http.accept().await.unwrap();
+            let mut request = [0u8; 64];
+            let _ = socket.read(&mut request).await;
+            let _ = socket
+                .write_all(b"HTTP/1.0 200 OK\r\nServer: lighttpd/1.4.59\r\n\r\n")
+                .await;
+        });
+
+        let info = grab_banner(ssh_addr, 1000).await.unwrap();
+        assert_eq!(info.protocol.as_deref(), Some("ssh"));
+        assert_eq!(info.banner.as_deref(), Some("SSH-2.0-OpenSSH_9.6"));
+
+        let info = grab_banner(http_addr, 400).await.unwrap();
+        assert_eq!(info.port, http_addr.port());
+        assert_eq!(info.protocol.as_deref(), Some("http"));
+        assert_eq!(info.banner.as_deref(), Some("lighttpd/1.4.59"));
+    }
+
+    #[test]
+    fn test_identify_rtsp() {
+        let info = identify(
+            554,
+            b"RTSP/1.0 200 OK\r\nCSeq: 1\r\nServer: Hipcam RealServer/V1.0\r\n\r\n",
+        );
+        assert_eq!(info.protocol.as_deref(), Some("rtsp"));
+        assert_eq!(info.banner.as_deref(), Some("Hipcam RealServer/V1.0"));
+    }
+}
@@ -0,0 +1,188 @@
+//! Broadcast UDP probes for discovery protocols.
+//!
+//! NetBIOS, WS-Discovery, vendor "find my device" protocols and the like ask
+//! the whole subnet a question and gather whatever comes back within a short
+//! window. This module provides that building block for the scan modules.
+//!
+//! # Platform Notes
+//!
+//! - `SO_BROADCAST` is required everywhere to send to a broadcast address.
+//! - Linux/Android send the limited broadcast (`255.255.255.255`) only out of
+//!   the interface of the default route, and macOS/iOS refuse it without one,
+//!   so [broadcast_destinations] prefers the directed broadcast address of
+//!   every interface.
+//! - Windows does not always report a broadcast address; it is then derived
+//!   from the netmask.
+//! - Android apps additionally need to hold a `WifiManager.MulticastLock` for
+//!   broadcast replies to be delivered while the screen is off.
+
+use crate::api::models::BroadcastReply;
+use flutter_rust_bridge::frb;
+use socket2::{Domain, Protocol, Socket, Type};
+use std::{
+    io,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    time::Duration,
+};
+use tokio::{
+    net::UdpSocket,
+    time::{timeout_at, Instant},
+};
+
+/// Opens an IPv4 UDP socket that may send to broadcast addresses.
+///
+/// `local_port` of 0 binds an ephemeral port. Protocols that expect a fixed
+/// source port can pass it; the port is then shared with other listeners
+/// (`SO_REUSEADDR`).
+#[frb(ignore)]
+pub fn broadcast_socket(local_port: u16) -> io::Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    socket.set_broadcast(true)?;
+    if local_port != 0 {
+        socket.set_reuse_address(true)?;
+    }
+    socket.bind(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, local_port)).into())?;
+    socket.set_nonblocking(true)?;
+    UdpSocket::from_std(socket.into())
+}
+
+/// The directed broadcast address of every active, non-loopback IPv4
+/// interface on `port`, or the limited broadcast address if there is none.
+#[frb(ignore)]
+pub fn broadcast_destinations(port: u16) -> Vec<SocketAddr> {
+    use network_interface::{Addr, NetworkInterface, NetworkInterfaceConfig};
+
+    let mut destinations: Vec<SocketAddr> = Vec::new();
+    let interfaces = NetworkInterface::show().unwrap_or_default();
+    for addr in interfaces.iter().flat_map(|iface| &iface.addr) {
+        let Addr::V4(v4) = addr else { continue };
+        if v4.ip.is_loopback() || v4.ip.is_link_local() {
+            continue;
+        }
+        let broadcast = v4
+            .broadcast
+            .or_else(|| v4.netmask.map(|mask| directed_broadcast(v4.ip, mask)));
+        let Some(broadcast) = broadcast.filter(|b| *b != v4.ip) else {
+            continue;
+        };
+        let destination = SocketAddr::from((broadcast, port));
+        if !destinations.contains(&destination) {
+            destinations.push(destination);
+        }
+    }
+    if destinations.is_empty() {
+        destinations.push(SocketAddr::from((Ipv4Addr::BROADCAST, port)));
+    }
+    destinations
+}
+
+/// Sends `payload` to every address in `destinations` and collects the
+/// replies that arrive within `window`.
+///
+/// Stops early once `max_replies` datagrams arrived (0 means no limit).
+/// Datagrams identical to `payload` that come from a local address are our
+/// own broadcast looped back and are skipped. Fails only if the socket
+/// cannot be opened or no destination could be sent to.
+#[frb(ignore)]
+pub async fn broadcast_probe(
+    destinations: &[SocketAddr],
+    payload: &[u8],
+    window: Duration,
+    max_replies: usize,
+) -> io::Result<Vec<BroadcastReply>> {
+    let socket = broadcast_socket(0)?;
+    let mut last_error = None;
+    let mut sent = 0;
+    for destination in destinations {
+        match socket.send_to(payload, destination).await {
+            Ok(_) => sent += 1,
+            Err(e) => last_error = Some(e),
+        }
+    }
+    if sent == 0 {
+        return Err(last_error.unwrap_or_else(|| {
+            io::Error::new(io::ErrorKind::InvalidInput, "No broadcast destination")
+        }));
+    }
+    collect_replies(&socket, payload, Instant::now() + window, max_replies).await
+}
+
+/// Receives on `socket` until `deadline` or `max_replies` datagrams.
+pub(crate) async fn collect_replies(
+    socket: &UdpSocket,
+    sent: &[u8],
+    deadline: Instant,
+    max_replies: usize,
+) -> io::Result<Vec<BroadcastReply>> {
+    let mut replies = Vec::new();
+    let mut buf = [0u8; 2048];
+    while let Ok(received) = timeout_at(deadline, socket.recv_from(&mut buf)).await {
+        let (len, from) = match received {
+            Ok(received) => received,
+            // An ICMP error for one destination must not end the window.
+            Err(e) if e.kind() == io::ErrorKind::ConnectionRefused => continue,
+            Err(e) => return Err(e),
+        };
+        if &buf[..len] == sent && is_local(from.ip()) {
+            continue;
+        }
+        replies.push(BroadcastReply {
+            from: from.to_string(),
+            payload: buf[..len].to_vec(),
+        });
+        if max_replies != 0 && replies.len() >= max_replies {
+            break;
+        }
+    }
+    Ok(replies)
+}
+
+/// The broadcast address of the subnet of `ip` with `netmask`.
+fn directed_broadcast(ip: Ipv4Addr, netmask: Ipv4Addr) -> Ipv4Addr {
+    Ipv4Addr::from(u32::from(ip) | !u32::from(netmask))
+}
+
+fn is_local(ip: IpAddr) -> bool {
+    use network_interface::{NetworkInterface, NetworkInterfaceConfig};
+
+    ip.is_loopback()
+        || NetworkInterface::show()
+            .unwrap_or_default()
+            .iter()
+            .any(|iface| iface.addr.iter().any(|a| a.ip() == ip))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_broadcast_probe_collects_replies_within_window() {
+        assert_eq!(
+            directed_broadcast(
+                Ipv4Addr::new(192, 168, 1, 20),
+                Ipv4Addr::new(255, 255, 255, 0)
+            ),
+            Ipv4Addr::new(192, 168, 1, 255)
+        );
+
+        // Two responders on loopback stand in for devices on the subnet.
+        let mut destinations = Vec::new();
+        for reply in [&b"first"[..], &b"second"[..]] {
+            let responder = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+            destinations.push(responder.local_addr().unwrap());
+            tokio::spawn(async move {
+                let mut buf = [0u8; 64];
+                let (_, from) = responder.recv_from(&mut buf).await.unwrap();
+                responder.send_to(reply, from).await.unwrap();
+            });
+        }
+
+        let replies = broadcast_probe(&destinations, b"who is there", Duration::from_secs(2), 2)
+            .await
+            .unwrap();
+        let mut payloads: Vec<&[u8]> = replies.iter().map(|r| r.payload.as_slice()).collect();
+        payloads.sort();
+        assert_eq!(payloads, vec![&b"first"[..], &b"second"[..]]);
+    }
+}
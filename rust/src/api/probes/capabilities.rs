@@ -0,0 +1,100 @@
+//! Detection of the raw-socket probe modes the process may use.
+//!
+//! ICMP echoes, ICMP traceroute and the ARP table need privileges on some
+//! platforms: Linux grants unprivileged ICMP sockets only within
+//! `net.ipv4.ping_group_range` and raw sockets only with `CAP_NET_RAW`, and
+//! Android 10+ hides `/proc/net/arp`. Probes consult [detect_probe_capabilities]
+//! and fall back to an unprivileged method instead of failing.
+
+use crate::api::models::{ProbeCapabilities, ProbeFallback};
+use socket2::{Domain, Protocol, Socket, Type};
+use std::sync::OnceLock;
+
+/// Returns the probe modes available to the process.
+///
+/// Every capability is tested by opening (and closing) the socket or file it
+/// needs, once per process.
+pub fn detect_probe_capabilities() -> ProbeCapabilities {
+    static CAPABILITIES: OnceLock<ProbeCapabilities> = OnceLock::new();
+    *CAPABILITIES.get_or_init(|| {
+        let icmp_raw = can_open(Type::RAW);
+        ProbeCapabilities {
+            icmp_echo: icmp_raw || can_open(Type::DGRAM),
+            icmp_raw,
+            udp_traceroute: cfg!(any(target_os = "linux", target_os = "android")),
+            arp_table: arp_table_readable(),
+        }
+    })
+}
+
+/// The fallback an ICMP echo probe needs under `capabilities`, if any.
+pub(crate) fn ping_fallback(capabilities: &ProbeCapabilities) -> Option<ProbeFallback> {
+    (!capabilities.icmp_echo).then_some(ProbeFallback::TcpPing)
+}
+
+/// The fallback a traceroute needs under `capabilities`, if any.
+///
+/// Without a raw socket the Time Exceeded replies of intermediate routers
+/// never reach an ICMP traceroute, so UDP is preferred wherever it can read
+/// them.
+pub(crate) fn traceroute_fallback(capabilities: &ProbeCapabilities) -> Option<ProbeFallback> {
+    (!capabilities.icmp_raw && capabilities.udp_traceroute).then_some(ProbeFallback::UdpTraceroute)
+}
+
+/// True if an ICMPv4 socket of type `ty` may be opened.
+fn can_open(ty: Type) -> bool {
+    Socket::new(Domain::IPV4, ty, Some(Protocol::ICMPV4)).is_ok()
+}
+
+fn arp_table_readable() -> bool {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        std::fs::File::open("/proc/net/arp").is_ok()
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    {
+        cfg!(any(target_os = "macos", windows))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_fallbacks_follow_capabilities() {
+        let full = ProbeCapabilities {
+            icmp_echo: true,
+            icmp_raw: true,
+            udp_traceroute: true,
+            arp_table: true,
+        };
+        assert_eq!(ping_fallback(&full), None);
+        assert_eq!(traceroute_fallback(&full), None);
+
+        // An unprivileged Linux process with a restrictive ping_group_range.
+        let unprivileged = ProbeCapabilities {
+            icmp_echo: false,
+            icmp_raw: false,
+            ..full
+        };
+        assert_eq!(ping_fallback(&unprivileged), Some(ProbeFallback::TcpPing));
+        assert_eq!(
+            traceroute_fallback(&unprivileged),
+            Some(ProbeFallback::UdpTraceroute)
+        );
+
+        // Without UDP error queues, ICMP still shows the final hop.
+        let elsewhere = ProbeCapabilities {
+            udp_traceroute: false,
+            ..unprivileged
+        };
+        assert_eq!(traceroute_fallback(&elsewhere), None);
+
+        // UDP traceroute depends on the platform alone, not on privileges.
+        assert_eq!(
+            detect_probe_capabilities().udp_traceroute,
+            cfg!(any(target_os = "linux", target_os = "android"))
+        );
+    }
+}
@@ -1,52 +1,420 @@
 //! Probe for detecting captive portals.
 
-use crate::api::{constants::LibConstants, models::CaptivePortalStatus};
+use super::parse::read_body;
+use crate::api::{
+    constants::LibConstants,
+    models::{
+        CaptivePortalStatus, Confidence, Evidence, EvidenceSource, NetworkError, PortalLoginKind,
+    },
+};
+use std::{
+    future::Future,
+    time::{Duration, Instant},
+};
 
 /// Checks for the presence of a captive portal.
+///
+/// A probe that could not complete yields "no portal" with
+/// [EvidenceSource::ProbeFailed]; [try_check_for_captive_portal] reports why.
 pub async fn check_for_captive_portal(timeout_ms: u64) -> CaptivePortalStatus {
-    use std::time::Duration;
-    let client = match reqwest::ClientBuilder::new()
-        .redirect(reqwest::redirect::Policy::limited(5)) // Follow up to 5 redirects
-        .timeout(Duration::from_millis(timeout_ms))
+    try_check_for_captive_portal(timeout_ms)
+        .await
+        .unwrap_or_else(|_| inconclusive())
+}
+
+/// [check_for_captive_portal], failing if the detection request did not
+/// complete: with [NetworkError::DnsResolutionError] if the host did not
+/// resolve, [NetworkError::ConnectionError] if it could not be connected to,
+/// [NetworkError::TimeoutError] if it did not answer in time and
+/// [NetworkError::ProbeFailed] otherwise.
+pub async fn try_check_for_captive_portal(
+    timeout_ms: u64,
+) -> Result<CaptivePortalStatus, NetworkError> {
+    probe_portal(LibConstants::CAPTIVE_PORTAL_DETECTION_URL, timeout_ms).await
+}
+
+/// [check_for_captive_portal] against `url` instead of
+/// [CAPTIVE_PORTAL_DETECTION_URL](LibConstants::CAPTIVE_PORTAL_DETECTION_URL)
+/// (see [EndpointConfig::captive_portal_url](crate::api::models::EndpointConfig::captive_portal_url)).
+pub async fn check_for_captive_portal_at(url: &str, timeout_ms: u64) -> CaptivePortalStatus {
+    try_check_for_captive_portal_at(url, timeout_ms)
+        .await
+        .unwrap_or_else(|_| inconclusive())
+}
+
+/// [try_check_for_captive_portal] against `url`.
+pub async fn try_check_for_captive_portal_at(
+    url: &str,
+    timeout_ms: u64,
+) -> Result<CaptivePortalStatus, NetworkError> {
+    probe_portal(url, timeout_ms).await
+}
+
+/// Fetches `url`, which must not redirect on an open network, and judges the
+/// response.
+async fn probe_portal(url: &str, timeout_ms: u64) -> Result<CaptivePortalStatus, NetworkError> {
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+    let transport = super::transport::current();
+    let mut builder = transport
+        .http_client()
+        .redirect(reqwest::redirect::Policy::limited(5)); // Follow up to 5 redirects
+
+    // Resolved up front, so that a failed lookup is told apart from a failed
+    // connection.
+    let parsed = reqwest::Url::parse(url).map_err(|e| NetworkError::ProbeFailed(e.to_string()))?;
+    if let (Some(host), Some(port)) = (parsed.domain(), parsed.port_or_known_default()) {
+        let addrs = tokio::time::timeout_at(deadline.into(), transport.resolve(host, port))
+            .await
+            .map_err(|_| NetworkError::TimeoutError)?
+            .map_err(|e| NetworkError::DnsResolutionError(e.to_string()))?;
+        builder = builder.resolve_to_addrs(host, &addrs);
+    }
+
+    let client = builder
+        .timeout(deadline.saturating_duration_since(Instant::now()))
         .build()
-    {
-        Ok(c) => c,
-        Err(_) => {
-            return CaptivePortalStatus {
-                is_captive_portal: false,
-                redirect_url: None,
-            };
+        .map_err(|e| NetworkError::ProbeFailed(e.to_string()))?;
+    let mut response = client.get(url).send().await.map_err(|e| {
+        if e.is_timeout() {
+            NetworkError::TimeoutError
+        } else if e.is_connect() {
+            NetworkError::ConnectionError(e.to_string())
+        } else {
+            NetworkError::ProbeFailed(e.to_string())
         }
-    };
+    })?;
 
-    let url = LibConstants::CAPTIVE_PORTAL_DETECTION_URL;
+    let final_url = response.url().clone();
+    let mut status = classify_response(url, &final_url, response.status().as_u16());
+    if status.is_captive_portal {
+        let page = read_page(&mut response).await;
+        describe_login_page(&mut status, &page);
+    }
+    Ok(status)
+}
 
-    match client.get(url).send().await {
-        Ok(response) => {
-            let final_url = response.url().to_string();
-            let is_redirected = final_url != url;
+/// Reads up to [MAX_PORTAL_PAGE_BYTES](LibConstants::MAX_PORTAL_PAGE_BYTES)
+/// of the portal page, keeping what arrived if the transfer fails.
+async fn read_page(response: &mut reqwest::Response) -> String {
+    let mut page = Vec::new();
+    let _ = read_body(response, &mut page, LibConstants::MAX_PORTAL_PAGE_BYTES).await;
+    String::from_utf8_lossy(&page).into_owned()
+}
+
+/// Re-checks every `poll_interval_ms` until the portal lets traffic through,
+/// e.g. after the user logged in.
+///
+/// Resolves with the first status that shows real internet access: the probe
+/// went through unredirected. Fails with [NetworkError::TimeoutError] if that
+/// did not happen within `timeout_ms`.
+pub async fn wait_for_portal_clearance(
+    timeout_ms: u64,
+    poll_interval_ms: u64,
+) -> Result<CaptivePortalStatus, NetworkError> {
+    wait_until_clear(timeout_ms, poll_interval_ms, || {
+        check_for_captive_portal(LibConstants::DEFAULT_HTTP_TIMEOUT_MS)
+    })
+    .await
+}
 
-            CaptivePortalStatus {
-                is_captive_portal: is_redirected,
-                redirect_url: if is_redirected { Some(final_url) } else { None },
+async fn wait_until_clear<F, Fut>(
+    timeout_ms: u64,
+    poll_interval_ms: u64,
+    mut check: F,
+) -> Result<CaptivePortalStatus, NetworkError>
+where
+    F: FnMut() -> Fut,
+    Fut: Future<Output = CaptivePortalStatus>,
+{
+    let poll = async {
+        loop {
+            let status = check().await;
+            // A failed probe is not a clear path; keep polling.
+            if !status.is_captive_portal && status.evidence.source != EvidenceSource::ProbeFailed {
+                return status;
             }
+            tokio::time::sleep(Duration::from_millis(poll_interval_ms)).await;
         }
-        Err(_) => CaptivePortalStatus {
-            is_captive_portal: false,
-            redirect_url: None,
-        },
-    }
+    };
+    Ok(tokio::time::timeout(Duration::from_millis(timeout_ms), poll).await?)
 }
 
 /// Web implementation stub (WASM removed).
 pub async fn check_for_captive_portal_web_manual(_timeout_ms: u64) -> CaptivePortalStatus {
+    no_portal()
+}
+
+/// Unified entry point for captive portal check (WASM removed).
+pub async fn check_for_captive_portal_web(timeout_ms: u64) -> CaptivePortalStatus {
+    check_for_captive_portal_web_manual(timeout_ms).await
+}
+
+/// HTTP 511 Network Authentication Required (RFC 6585), sent by well-behaved portals.
+const STATUS_NETWORK_AUTHENTICATION_REQUIRED: u16 = 511;
+
+/// No portal was found; the starting point of every other verdict.
+pub(crate) fn no_portal() -> CaptivePortalStatus {
     CaptivePortalStatus {
         is_captive_portal: false,
         redirect_url: None,
+        evidence: Evidence::default(),
+        page_title: None,
+        login_kind: PortalLoginKind::Unknown,
+        wispr: false,
     }
 }
 
-/// Unified entry point for captive portal check (WASM removed).
-pub async fn check_for_captive_portal_web(timeout_ms: u64) -> CaptivePortalStatus {
-    check_for_captive_portal_web_manual(timeout_ms).await
+/// The probe could not tell either way.
+fn inconclusive() -> CaptivePortalStatus {
+    CaptivePortalStatus {
+        evidence: Evidence::new(Confidence::Low, EvidenceSource::ProbeFailed),
+        ..no_portal()
+    }
+}
+
+/// Derives the verdict from where the probe ended up and the final status code.
+///
+/// A redirect to another host is a portal with high confidence; a redirect
+/// within the probe's own host (e.g. a path or scheme change) only medium.
+/// An unredirected non-2xx answer might be an intercepting proxy that serves
+/// the login page in place, so "no portal" is then only medium.
+fn classify_response(
+    probe_url: &str,
+    final_url: &reqwest::Url,
+    status: u16,
+) -> CaptivePortalStatus {
+    if status == STATUS_NETWORK_AUTHENTICATION_REQUIRED {
+        return CaptivePortalStatus {
+            is_captive_portal: true,
+            redirect_url: Some(final_url.to_string()),
+            evidence: Evidence::new(Confidence::High, EvidenceSource::HttpResponse),
+            ..no_portal()
+        };
+    }
+
+    // Compare parsed URLs: `reqwest` normalizes `http://host` to `http://host/`.
+    let probe = reqwest::Url::parse(probe_url).ok();
+    let is_redirected = probe.as_ref() != Some(final_url);
+
+    if is_redirected {
+        let probe_host = probe.as_ref().and_then(|u| u.host_str());
+        let confidence = if final_url.host_str() != probe_host {
+            Confidence::High
+        } else {
+            Confidence::Medium
+        };
+        return CaptivePortalStatus {
+            is_captive_portal: true,
+            redirect_url: Some(final_url.to_string()),
+            evidence: Evidence::new(confidence, EvidenceSource::HttpRedirect),
+            ..no_portal()
+        };
+    }
+
+    let confidence = if (200..300).contains(&status) {
+        Confidence::High
+    } else {
+        Confidence::Medium
+    };
+    CaptivePortalStatus {
+        evidence: Evidence::new(confidence, EvidenceSource::HttpResponse),
+        ..no_portal()
+    }
+}
+
+/// Fills in what the portal's login page asks for.
+///
+/// Any password input or an input named like a user name, voucher or room
+/// number means credentials; otherwise a form or a submit button means a
+/// click-through. This is a heuristic over the raw HTML: pages that build
+/// their form in JavaScript stay [PortalLoginKind::Unknown].
+pub(crate) fn describe_login_page(status: &mut CaptivePortalStatus, page: &str) {
+    let lower = page.to_ascii_lowercase();
+    status.page_title = page_title(page, &lower);
+    status.wispr = lower.contains("<wispaccessgatewayparam");
+
+    let inputs: Vec<&str> = lower
+        .match_indices("<input")
+        .map(|(start, _)| {
+            let tag = &lower[start..];
+            &tag[..tag.find('>').unwrap_or(tag.len())]
+        })
+        .collect();
+    let asks_credentials = inputs.iter().any(|input| {
+        input.contains("type=\"password\"")
+            || input.contains("type=password")
+            || LibConstants::PORTAL_CREDENTIAL_FIELDS.iter().any(|field| {
+                input.contains(&format!("name=\"{}", field))
+                    || input.contains(&format!("id=\"{}", field))
+            })
+    });
+    status.login_kind = if asks_credentials {
+        PortalLoginKind::Credentials
+    } else if lower.contains("<form")
+        || lower.contains("<button")
+        || inputs.iter().any(|input| input.contains("submit"))
+    {
+        PortalLoginKind::ClickThrough
+    } else {
+        PortalLoginKind::Unknown
+    };
+}
+
+/// The text of the `<title>` element with whitespace collapsed. `lower` is
+/// `page` lowercased, which keeps byte offsets as only ASCII changes.
+fn page_title(page: &str, lower: &str) -> Option<String> {
+    let open = lower.find("<title")?;
+    let start = open + lower[open..].find('>')? + 1;
+    let end = start + lower[start..].find("</title")?;
+    let title = page[start..end]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!title.is_empty()).then_some(title)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_response_grades_confidence() {
+        let probe = "http://neverssl.com";
+        let url = |s: &str| reqwest::Url::parse(s).unwrap();
+
+        let clean = classify_response(probe, &url("http://neverssl.com"), 200);
+        assert!(!clean.is_captive_portal);
+        assert_eq!(clean.evidence.confidence, Confidence::High);
+
+        let foreign = classify_response(probe, &url("http://login.hotel.example/"), 200);
+        assert!(foreign.is_captive_portal);
+        assert_eq!(
+            foreign.evidence,
+            Evidence::new(Confidence::High, EvidenceSource::HttpRedirect)
+        );
+
+        let same_host = classify_response(probe, &url("http://neverssl.com/online/"), 200);
+        assert!(same_host.is_captive_portal);
+        assert_eq!(same_host.evidence.confidence, Confidence::Medium);
+
+        let auth = classify_response(probe, &url("http://neverssl.com"), 511);
+        assert!(auth.is_captive_portal);
+        assert_eq!(auth.evidence.confidence, Confidence::High);
+
+        let odd = classify_response(probe, &url("http://neverssl.com"), 403);
+        assert!(!odd.is_captive_portal);
+        assert_eq!(odd.evidence.confidence, Confidence::Medium);
+    }
+
+    #[test]
+    fn test_describe_login_page() {
+        let mut status = no_portal();
+        describe_login_page(
+            &mut status,
+            "<html><head><TITLE>\n  Hotel Guest\n Wi-Fi </TITLE></head><body>
+             <form method=post><input type=\"text\" name=\"room_number\">
+             <input type=\"submit\" value=\"Connect\"></form></body></html>",
+        );
+        assert_eq!(status.page_title.as_deref(), Some("Hotel Guest Wi-Fi"));
+        assert_eq!(status.login_kind, PortalLoginKind::Credentials);
+        assert!(!status.wispr);
+
+        let mut status = no_portal();
+        describe_login_page(
+            &mut status,
+            "<html><body><!--<?xml version=\"1.0\"?><WISPAccessGatewayParam>
+             <Redirect><MessageType>100</MessageType></Redirect>
+             </WISPAccessGatewayParam>--><form><input type=\"checkbox\" name=\"terms\">
+             <button>Accept</button></form></body></html>",
+        );
+        assert_eq!(status.page_title, None);
+        assert_eq!(status.login_kind, PortalLoginKind::ClickThrough);
+        assert!(status.wispr);
+    }
+
+    #[tokio::test]
+    async fn test_wait_until_clear() {
+        let portal = CaptivePortalStatus {
+            is_captive_portal: true,
+            ..no_portal()
+        };
+        let mut responses = vec![
+            Evidence::new(Confidence::High, EvidenceSource::HttpResponse),
+            Evidence::new(Confidence::Low, EvidenceSource::ProbeFailed),
+        ]
+        .into_iter()
+        .map(|evidence| CaptivePortalStatus {
+            evidence,
+            ..no_portal()
+        })
+        .collect::<Vec<_>>();
+        responses.push(portal.clone());
+
+        // Portal, then a failed probe, then a clean answer.
+        let cleared = wait_until_clear(2000, 1, || {
+            let next = responses.pop().unwrap();
+            async move { next }
+        })
+        .await
+        .unwrap();
+        assert_eq!(cleared.evidence.source, EvidenceSource::HttpResponse);
+
+        let stuck = wait_until_clear(20, 1, || {
+            let status = portal.clone();
+            async move { status }
+        })
+        .await;
+        assert!(matches!(stuck, Err(NetworkError::TimeoutError)));
+    }
+
+    #[tokio::test]
+    async fn test_probe_portal_against_local_server() {
+        use crate::api::testsupport::TestServer;
+
+        let open = TestServer::http()
+            .respond("/", 204, "")
+            .start()
+            .await
+            .unwrap();
+        let status = probe_portal(&open.url("/"), 2000).await.unwrap();
+        assert!(!status.is_captive_portal);
+
+        let portal = TestServer::http()
+            .redirect("/", "/login")
+            .respond(
+                "/login",
+                200,
+                "<title>Guest Wi-Fi</title><form><input type=password></form>",
+            )
+            .start()
+            .await
+            .unwrap();
+        let status = probe_portal(&portal.url("/"), 2000).await.unwrap();
+        assert!(status.is_captive_portal);
+        assert_eq!(status.redirect_url, Some(portal.url("/login")));
+        assert_eq!(status.login_kind, PortalLoginKind::Credentials);
+    }
+
+    #[tokio::test]
+    async fn test_probe_portal_errors() {
+        use crate::api::probes::{with_transport, MockTransport};
+        use std::sync::Arc;
+
+        let unresolved = with_transport(
+            Arc::new(MockTransport::new()),
+            probe_portal("http://portal.example/", 500),
+        )
+        .await;
+        assert!(matches!(
+            unresolved,
+            Err(NetworkError::DnsResolutionError(_))
+        ));
+
+        let closed = std::net::TcpListener::bind("127.0.0.1:0")
+            .unwrap()
+            .local_addr()
+            .unwrap();
+        let refused = probe_portal(&format!("http://{}/", closed), 500).await;
+        assert!(matches!(refused, Err(NetworkError::ConnectionError(_))));
+    }
 }
@@ -1,42 +1,84 @@
 //! Probe for detecting DNS hijacking.
 
+use super::resolver::{lookup_ips, system_resolver, trusted_resolver_for};
+use crate::api::models::{Confidence, Evidence, EvidenceSource};
+use std::net::IpAddr;
+
 /// Detects potential DNS hijacking.
 pub async fn detect_dns_hijacking(domain: &str) -> bool {
-    use std::net::IpAddr;
-    use tokio::task;
-    use trust_dns_resolver::config::{ResolverConfig, ResolverOpts};
-    use trust_dns_resolver::Resolver;
-
-    // 1. Resolve using the system's default DNS. This is an async operation.
-    let system_ips = match tokio::net::lookup_host(format!("{}:443", domain)).await {
-        Ok(addrs) => addrs.map(|a| a.ip()).collect::<Vec<_>>(),
-        Err(_) => return false,
+    assess_dns_hijacking(domain).await.0
+}
+
+/// Detects potential DNS hijacking and grades how sure the verdict is.
+///
+/// Returns `(is_spoofed, evidence)`. A failed lookup yields `false` with
+/// [Confidence::Low], since nothing could be compared.
+pub async fn assess_dns_hijacking(domain: &str) -> (bool, Evidence) {
+    assess_dns_hijacking_with(domain, &[]).await
+}
+
+/// [assess_dns_hijacking], trusting the answers of `trusted_servers`
+/// (`ip:port`) instead of Cloudflare's. An empty list keeps Cloudflare; an
+/// invalid entry yields an inconclusive verdict.
+pub async fn assess_dns_hijacking_with(
+    domain: &str,
+    trusted_servers: &[String],
+) -> (bool, Evidence) {
+    // 1. Resolve using the system's default DNS.
+    let system_ips = match system_resolver() {
+        Ok(resolver) => lookup_ips(&resolver, domain).await,
+        Err(e) => Err(e),
+    };
+    let system_ips = match system_ips {
+        Ok(ips) if !ips.is_empty() => ips,
+        _ => return inconclusive(),
     };
-    if system_ips.is_empty() {
-        return false;
-    }
 
-    // 2. Resolve using a trusted DoH resolver (Cloudflare).
-    let domain_for_doh = domain.to_string();
-    let doh_ips_res = task::spawn_blocking(move || {
-        let config = ResolverConfig::cloudflare();
-        let doh_resolver = match Resolver::new(config, ResolverOpts::default()) {
-            Ok(r) => r,
-            Err(_) => return Err(()),
-        };
-        Ok(doh_resolver.lookup_ip(&domain_for_doh))
-    })
-    .await;
-
-    let doh_ips = match doh_ips_res {
-        Ok(Ok(Ok(lookup))) => lookup.iter().collect::<Vec<IpAddr>>(),
-        _ => return false,
+    // 2. Resolve using a trusted resolver (Cloudflare unless configured).
+    let Ok(trusted) = trusted_resolver_for(trusted_servers) else {
+        return inconclusive();
+    };
+    let doh_ips = match lookup_ips(&trusted, domain).await {
+        Ok(ips) => ips,
+        Err(_) => return inconclusive(),
     };
 
     // 3. Compare the results
+    compare_answers(&system_ips, &doh_ips)
+}
+
+fn inconclusive() -> (bool, Evidence) {
+    (
+        false,
+        Evidence::new(Confidence::Low, EvidenceSource::ProbeFailed),
+    )
+}
+
+/// Grades the system resolver's answers against the trusted ones.
+///
+/// CDNs legitimately hand out different addresses per resolver, so a plain
+/// mismatch is only medium confidence. A private or unspecified address where
+/// the trusted resolver returned public ones is the classic sinkhole/redirect
+/// pattern and rates high; a partial overlap rates low.
+fn compare_answers(system_ips: &[IpAddr], doh_ips: &[IpAddr]) -> (bool, Evidence) {
+    let source = EvidenceSource::ResolverComparison;
     let is_subset = system_ips.iter().all(|sys_ip| doh_ips.contains(sys_ip));
+    if is_subset {
+        return (false, Evidence::new(Confidence::High, source));
+    }
 
-    !is_subset
+    let is_local = |ip: &IpAddr| match ip {
+        IpAddr::V4(v4) => v4.is_private() || v4.is_loopback() || v4.is_unspecified(),
+        IpAddr::V6(v6) => v6.is_loopback() || v6.is_unspecified(),
+    };
+    let confidence = if system_ips.iter().any(is_local) && !doh_ips.iter().any(is_local) {
+        Confidence::High
+    } else if system_ips.iter().any(|ip| doh_ips.contains(ip)) {
+        Confidence::Low
+    } else {
+        Confidence::Medium
+    };
+    (true, Evidence::new(confidence, source))
 }
 
 /// Web-specific implementation stub (WASM removed).
@@ -44,3 +86,50 @@ pub async fn detect_dns_hijacking_web(_domain: &str) -> bool {
     // Browsers don't expose raw DNS responses or IP addresses easily due to security.
     false
 }
+
+/// Returns the IP addresses of the resolvers configured in the operating system.
+///
+/// The list is sorted and de-duplicated (resolvers are usually registered once
+/// per transport). Returns an empty list if the system configuration is unreadable.
+pub fn system_dns_resolvers() -> Vec<String> {
+    use trust_dns_resolver::system_conf::read_system_conf;
+
+    let Ok((config, _)) = read_system_conf() else {
+        return Vec::new();
+    };
+
+    let mut resolvers: Vec<String> = config
+        .name_servers()
+        .iter()
+        .map(|ns| ns.socket_addr.ip().to_string())
+        .collect();
+    resolvers.sort();
+    resolvers.dedup();
+    resolvers
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_compare_answers_grades_mismatches() {
+        let ip = |s: &str| s.parse::<IpAddr>().unwrap();
+        let trusted = [ip("93.184.215.14"), ip("93.184.215.15")];
+
+        let (spoofed, evidence) = compare_answers(&[ip("93.184.215.14")], &trusted);
+        assert!(!spoofed);
+        assert_eq!(evidence.confidence, Confidence::High);
+
+        let (spoofed, evidence) = compare_answers(&[ip("10.0.0.1")], &trusted);
+        assert!(spoofed);
+        assert_eq!(evidence.confidence, Confidence::High);
+
+        let (spoofed, evidence) = compare_answers(&[ip("104.16.1.1")], &trusted);
+        assert!(spoofed);
+        assert_eq!(evidence.confidence, Confidence::Medium);
+
+        let (_, evidence) = compare_answers(&[ip("93.184.215.14"), ip("104.16.1.1")], &trusted);
+        assert_eq!(evidence.confidence, Confidence::Low);
+    }
+}
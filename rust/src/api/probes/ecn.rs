@@ -0,0 +1,138 @@
+//! ECN support detection.
+//!
+//! Explicit Congestion Notification lets routers signal congestion by marking
+//! packets instead of dropping them, which modern congestion control (L4S,
+//! BBRv2, ...) relies on. It only works if the whole path cooperates:
+//!
+//! - TCP: the handshake must negotiate ECN, which middleboxes that strip the
+//!   ECE/CWR flags prevent. On Linux/Android the outcome is read from
+//!   `TCP_INFO` after connecting.
+//! - IP: the ECN bits of the TOS byte must survive. A UDP probe marked
+//!   ECN-capable is sent to reflection servers that report the TOS they saw
+//!   (see [reflection](super::reflection)); cleared bits mean a router
+//!   "bleaches" them.
+
+use super::reflection::reflected_tos;
+use crate::api::{
+    constants::LibConstants,
+    models::{EcnMarking, EcnObservation, EcnReport},
+};
+use futures::future::join_all;
+use std::time::Duration;
+use tokio::{net::TcpStream, time::timeout};
+
+/// ECN codepoint ECT(0): ECN-capable transport.
+const ECT_0: u8 = 0b10;
+/// ECN codepoint CE: congestion experienced.
+const CE: u8 = 0b11;
+
+/// Checks TCP ECN negotiation with `tcp_servers` (`host:port`; empty uses
+/// [DEFAULT_TCP_REFERENCE_SERVERS](LibConstants::DEFAULT_TCP_REFERENCE_SERVERS))
+/// and ECN bleaching via `reflection_servers` (skipped if empty).
+///
+/// `timeout_ms` of 0 uses [DEFAULT_ECN_TIMEOUT_MS](LibConstants::DEFAULT_ECN_TIMEOUT_MS).
+pub async fn detect_ecn(
+    tcp_servers: Vec<String>,
+    reflection_servers: Vec<String>,
+    timeout_ms: u64,
+) -> EcnReport {
+    let tcp_servers = if tcp_servers.is_empty() {
+        LibConstants::DEFAULT_TCP_REFERENCE_SERVERS
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    } else {
+        tcp_servers
+    };
+    let wait = Duration::from_millis(if timeout_ms == 0 {
+        LibConstants::DEFAULT_ECN_TIMEOUT_MS
+    } else {
+        timeout_ms
+    });
+
+    let (tcp, observed_tos) = tokio::join!(
+        join_all(tcp_servers.iter().map(|server| observe(server, wait))),
+        reflected_tos(&reflection_servers, ECT_0, wait),
+    );
+    let udp_marking = observed_tos.map_or(EcnMarking::NotTested, classify_tos);
+
+    EcnReport {
+        os_requests_ecn: os_requests_ecn(),
+        ecn_capable: tcp.iter().any(|o| o.negotiated == Some(true))
+            || matches!(
+                udp_marking,
+                EcnMarking::Preserved | EcnMarking::CongestionExperienced
+            ),
+        tcp,
+        udp_marking,
+    }
+}
+
+async fn observe(server: &str, wait: Duration) -> EcnObservation {
+    let mut observation = EcnObservation {
+        server: server.to_string(),
+        negotiated: None,
+        error: None,
+    };
+    match timeout(wait, TcpStream::connect(server)).await {
+        Ok(Ok(stream)) => match negotiated(&stream) {
+            Ok(negotiated) => observation.negotiated = Some(negotiated),
+            Err(e) => observation.error = Some(e.to_string()),
+        },
+        Ok(Err(e)) => observation.error = Some(e.to_string()),
+        Err(_) => observation.error = Some("Timeout Error".to_string()),
+    }
+    observation
+}
+
+/// Interprets the TOS byte a reflection server saw on our ECT(0) probe.
+fn classify_tos(tos: u8) -> EcnMarking {
+    match tos & 0b11 {
+        CE => EcnMarking::CongestionExperienced,
+        0 => EcnMarking::Bleached,
+        _ => EcnMarking::Preserved,
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn negotiated(stream: &TcpStream) -> std::io::Result<bool> {
+    use super::tcp_info::{self, TCPI_OPT_ECN};
+    Ok(tcp_info::read(stream)?.options & TCPI_OPT_ECN != 0)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn negotiated(_stream: &TcpStream) -> std::io::Result<bool> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "ECN negotiation can only be read on Linux and Android",
+    ))
+}
+
+/// `net.ipv4.tcp_ecn`: 1 and 3 request ECN on outgoing connections, 2 (the
+/// default) only accepts it on incoming ones.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn os_requests_ecn() -> Option<bool> {
+    let value = std::fs::read_to_string("/proc/sys/net/ipv4/tcp_ecn").ok()?;
+    Some(matches!(value.trim(), "1" | "3"))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn os_requests_ecn() -> Option<bool> {
+    None
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_tos() {
+        assert_eq!(classify_tos(ECT_0), EcnMarking::Preserved);
+        assert_eq!(classify_tos(0b01), EcnMarking::Preserved);
+        assert_eq!(
+            classify_tos(46 << 2 | CE),
+            EcnMarking::CongestionExperienced
+        );
+        assert_eq!(classify_tos(46 << 2), EcnMarking::Bleached);
+    }
+}
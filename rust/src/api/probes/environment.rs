@@ -0,0 +1,192 @@
+//! Detection of containers and WSL.
+//!
+//! Inside them the interface heuristics only see a virtual link, so reports
+//! carry the [RuntimeEnvironment] they were produced in.
+
+use crate::api::models::RuntimeEnvironment;
+use flutter_rust_bridge::frb;
+use std::net::IpAddr;
+
+/// Returns the environment the process runs in.
+///
+/// On Linux the `/proc` markers of WSL, Docker, LXC and other runtimes are
+/// read first. Failing those, a network namespace whose only interfaces are
+/// veth ends is taken as a container, and as Docker if its default gateway
+/// lies in Docker's `172.16.0.0/12` bridge range. Other platforms always
+/// report [RuntimeEnvironment::Native]. Detected once per process.
+pub fn detect_runtime_environment() -> RuntimeEnvironment {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        use std::sync::OnceLock;
+
+        static ENVIRONMENT: OnceLock<RuntimeEnvironment> = OnceLock::new();
+        *ENVIRONMENT.get_or_init(|| classify(&RuntimeSignals::read()))
+    }
+
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    RuntimeEnvironment::Native
+}
+
+/// The hints a Linux system leaves about where a process runs.
+#[frb(ignore)]
+#[derive(Debug, Default)]
+#[cfg_attr(not(any(target_os = "linux", target_os = "android")), allow(dead_code))]
+struct RuntimeSignals {
+    /// `/proc/sys/kernel/osrelease`; WSL kernels name Microsoft.
+    os_release: String,
+    /// `/proc/1/cgroup`; names the runtime on cgroup v1 hosts.
+    init_cgroup: String,
+    /// The `container=` variable systemd-style runtimes pass to init.
+    container_var: Option<String>,
+    /// `/.dockerenv` exists.
+    docker_env: bool,
+    /// `/run/.containerenv` exists (Podman).
+    container_env: bool,
+    /// Every non-loopback interface is one end of a veth pair.
+    veth_only: bool,
+    /// The default gateway, if known.
+    gateway: Option<IpAddr>,
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+impl RuntimeSignals {
+    fn read() -> Self {
+        use crate::api::constants::LibConstants;
+        use std::{fs, path::Path};
+
+        let read = |path: &str| fs::read_to_string(path).unwrap_or_default();
+        // /proc/1/environ is NUL-separated and only readable by root.
+        let container_var = fs::read("/proc/1/environ").ok().and_then(|environ| {
+            environ
+                .split(|&b| b == 0)
+                .find_map(|var| var.strip_prefix(b"container="))
+                .map(|value| String::from_utf8_lossy(value).into_owned())
+        });
+
+        Self {
+            os_release: read("/proc/sys/kernel/osrelease"),
+            init_cgroup: read("/proc/1/cgroup"),
+            container_var,
+            docker_env: Path::new("/.dockerenv").exists(),
+            container_env: Path::new("/run/.containerenv").exists(),
+            veth_only: veth_only(Path::new(LibConstants::SYSFS_NET_DIR)),
+            gateway: super::interface::default_gateway(),
+        }
+    }
+}
+
+/// True if every interface under `root` except loopback is a veth end, i.e.
+/// a virtual interface linked to a peer (`iflink` differs from `ifindex`)
+/// without a physical device behind it.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn veth_only(root: &std::path::Path) -> bool {
+    let Ok(entries) = std::fs::read_dir(root) else {
+        return false;
+    };
+    let read = |dir: &std::path::Path, file: &str| {
+        std::fs::read_to_string(dir.join(file))
+            .ok()
+            .and_then(|s| s.trim().parse::<u32>().ok())
+    };
+
+    let mut seen = false;
+    for entry in entries.flatten() {
+        let dir = entry.path();
+        if entry.file_name() == "lo" {
+            continue;
+        }
+        let is_veth = !dir.join("device").exists()
+            && matches!(
+                (read(&dir, "ifindex"), read(&dir, "iflink")),
+                (Some(index), Some(link)) if link != 0 && link != index
+            );
+        if !is_veth {
+            return false;
+        }
+        seen = true;
+    }
+    seen
+}
+
+/// Decides the environment from the collected signals; explicit markers win
+/// over the network layout.
+#[cfg_attr(not(any(target_os = "linux", target_os = "android")), allow(dead_code))]
+fn classify(signals: &RuntimeSignals) -> RuntimeEnvironment {
+    let os_release = signals.os_release.to_lowercase();
+    let cgroup = signals.init_cgroup.as_str();
+    let container_var = signals.container_var.as_deref();
+
+    if os_release.contains("microsoft") || os_release.contains("wsl") {
+        RuntimeEnvironment::Wsl
+    } else if signals.docker_env || container_var == Some("docker") || cgroup.contains("docker") {
+        RuntimeEnvironment::Docker
+    } else if container_var == Some("lxc") || cgroup.contains("/lxc") {
+        RuntimeEnvironment::Lxc
+    } else if signals.container_env
+        || container_var.is_some()
+        || ["kubepods", "containerd", "libpod"]
+            .iter()
+            .any(|runtime| cgroup.contains(runtime))
+    {
+        RuntimeEnvironment::Container
+    } else if signals.veth_only {
+        match signals.gateway {
+            Some(IpAddr::V4(gw)) if gw.octets()[0] == 172 && (gw.octets()[1] & 0xf0) == 16 => {
+                RuntimeEnvironment::Docker
+            }
+            _ => RuntimeEnvironment::Container,
+        }
+    } else {
+        RuntimeEnvironment::Native
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_runtime_environment() {
+        let native = RuntimeSignals {
+            os_release: "6.8.0-45-generic".into(),
+            init_cgroup: "0::/init.scope\n".into(),
+            ..RuntimeSignals::default()
+        };
+        assert_eq!(classify(&native), RuntimeEnvironment::Native);
+
+        let wsl = RuntimeSignals {
+            os_release: "5.15.153.1-microsoft-standard-WSL2".into(),
+            ..RuntimeSignals::default()
+        };
+        assert_eq!(classify(&wsl), RuntimeEnvironment::Wsl);
+
+        let lxc = RuntimeSignals {
+            container_var: Some("lxc".into()),
+            ..RuntimeSignals::default()
+        };
+        assert_eq!(classify(&lxc), RuntimeEnvironment::Lxc);
+
+        let podman = RuntimeSignals {
+            container_env: true,
+            ..RuntimeSignals::default()
+        };
+        assert_eq!(classify(&podman), RuntimeEnvironment::Container);
+
+        // Without markers (cgroup v2 hides the runtime) the veth-only
+        // namespace and Docker's bridge gateway give it away.
+        let docker = RuntimeSignals {
+            init_cgroup: "0::/\n".into(),
+            veth_only: true,
+            gateway: Some("172.17.0.1".parse().unwrap()),
+            ..RuntimeSignals::default()
+        };
+        assert_eq!(classify(&docker), RuntimeEnvironment::Docker);
+
+        let pod = RuntimeSignals {
+            veth_only: true,
+            gateway: Some("10.244.0.1".parse().unwrap()),
+            ..RuntimeSignals::default()
+        };
+        assert_eq!(classify(&pod), RuntimeEnvironment::Container);
+    }
+}
@@ -0,0 +1,174 @@
+//! Probes for the default gateway (the first-hop router).
+//!
+//! Reaching the gateway proves the local network works even when nothing
+//! beyond it does, e.g. in airplane mode with Wi-Fi on or on isolated
+//! industrial LANs.
+
+use super::{arp::neighbor_mac, oui::lookup_vendor, parse::read_text, upnp::describe_device};
+use crate::api::{
+    constants::LibConstants,
+    models::{GatewayFingerprint, TargetReport},
+};
+use flutter_rust_bridge::frb;
+use futures::future::{select_ok, BoxFuture, FutureExt};
+use std::{
+    io::ErrorKind,
+    net::{IpAddr, SocketAddr},
+    time::{Duration, Instant},
+};
+use tokio::{net::TcpStream, time::timeout};
+
+pub use super::interface::default_gateway;
+
+/// Checks whether `gateway` answers at all.
+///
+/// An ICMP echo and TCP connects to
+/// [GATEWAY_TCP_PORTS](LibConstants::GATEWAY_TCP_PORTS) race each other; the
+/// first answer wins. A refused TCP connection counts as an answer, since only
+/// a live host can send the reset.
+#[frb(ignore)]
+pub async fn probe_gateway(gateway: IpAddr, timeout_ms: u64) -> TargetReport {
+    let start = Instant::now();
+
+    let mut attempts: Vec<BoxFuture<'static, Result<(), String>>> =
+        vec![async move { ping(gateway).await }.boxed()];
+    for &port in LibConstants::GATEWAY_TCP_PORTS {
+        attempts.push(async move { connect(SocketAddr::new(gateway, port)).await }.boxed());
+    }
+
+    let error = match timeout(Duration::from_millis(timeout_ms), select_ok(attempts)).await {
+        Ok(Ok(_)) => None,
+        Ok(Err(e)) => Some(e),
+        Err(_) => Some("Timeout".to_string()),
+    };
+
+    TargetReport {
+        success: error.is_none(),
+        latency_ms: if error.is_none() {
+            start.elapsed().as_millis() as u64
+        } else {
+            0
+        },
+        error,
+        resolved_addr: Some(gateway.to_string()),
+        address_family: Some(gateway.into()),
+        ..TargetReport::unprobed(LibConstants::GATEWAY_NAME.to_string(), false)
+    }
+}
+
+/// Identifies the router behind `gateway` for support diagnostics.
+///
+/// The web admin page and the UPnP device description are queried
+/// concurrently, each bounded by `timeout_ms`; the MAC address comes from the
+/// OS neighbor table, which those requests populate.
+#[frb(ignore)]
+pub async fn fingerprint_gateway(gateway: IpAddr, timeout_ms: u64) -> GatewayFingerprint {
+    let wait = Duration::from_millis(timeout_ms);
+    let ((http_title, http_server), upnp) =
+        tokio::join!(admin_page(gateway, wait), describe_device(gateway, wait));
+    let upnp = upnp.unwrap_or_default();
+
+    let mac_address = neighbor_mac(gateway).await;
+    let mac_vendor = mac_address
+        .as_deref()
+        .and_then(lookup_vendor)
+        .map(str::to_string);
+
+    let upnp_model = match (&upnp.model_name, &upnp.model_number) {
+        (Some(name), Some(number)) if !name.contains(number.as_str()) => {
+            Some(format!("{} {}", name, number))
+        }
+        (Some(name), _) => Some(name.clone()),
+        (None, number) => number.clone(),
+    };
+
+    GatewayFingerprint {
+        ip_address: gateway.to_string(),
+        vendor: upnp.manufacturer.clone().or_else(|| mac_vendor.clone()),
+        model: upnp_model.or_else(|| http_title.clone()),
+        mac_address,
+        mac_vendor,
+        http_title,
+        http_server,
+        upnp_friendly_name: upnp.friendly_name,
+        upnp_manufacturer: upnp.manufacturer,
+        upnp_model_name: upnp.model_name,
+        upnp_model_number: upnp.model_number,
+    }
+}
+
+/// Fetches the gateway's web root and returns its `<title>` and `Server` header.
+async fn admin_page(gateway: IpAddr, wait: Duration) -> (Option<String>, Option<String>) {
+    let Ok(client) = reqwest::Client::builder()
+        .danger_accept_invalid_certs(true)
+        .timeout(wait)
+        .build()
+    else {
+        return (None, None);
+    };
+    let url = format!("http://{}/", SocketAddr::new(gateway, 80));
+    let Ok(response) = client.get(url).send().await else {
+        return (None, None);
+    };
+
+    let server = response
+        .headers()
+        .get(reqwest::header::SERVER)
+        .and_then(|v| v.to_str().ok())
+        .map(|v| v.trim().to_string())
+        .filter(|v| !v.is_empty());
+    let title = read_text(response, LibConstants::MAX_RESPONSE_BODY_BYTES)
+        .await
+        .ok()
+        .and_then(|body| html_title(&body));
+    (title, server)
+}
+
+/// The trimmed text of the first `<title>` element, matched case-insensitively.
+pub(crate) fn html_title(html: &str) -> Option<String> {
+    let lower = html.to_ascii_lowercase();
+    let open = lower.find("<title")?;
+    let start = open + lower[open..].find('>')? + 1;
+    let end = start + lower[start..].find("</title>")?;
+    let title = html[start..end]
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ");
+    (!title.is_empty()).then_some(title)
+}
+
+async fn ping(gateway: IpAddr) -> Result<(), String> {
+    surge_ping::ping(gateway, &[0u8; 8])
+        .await
+        .map(|_| ())
+        .map_err(|e| format!("Ping failed: {}", e))
+}
+
+async fn connect(addr: SocketAddr) -> Result<(), String> {
+    match TcpStream::connect(addr).await {
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == ErrorKind::ConnectionRefused => Ok(()),
+        Err(e) => Err(e.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_probe_gateway_counts_refused_connection_as_reachable() {
+        // Loopback refuses unused ports immediately, which proves liveness.
+        let report = probe_gateway("127.0.0.1".parse().unwrap(), 1000).await;
+        assert!(report.success, "{:?}", report.error);
+        assert_eq!(report.label, LibConstants::GATEWAY_NAME);
+    }
+
+    #[test]
+    fn test_admin_page_title_extraction() {
+        let html = "<html><HEAD><Title lang=\"en\">\n  Archer C7\n  Login </Title></HEAD></html>";
+        assert_eq!(html_title(html).as_deref(), Some("Archer C7 Login"));
+        assert_eq!(html_title("<title> </title>"), None);
+        assert_eq!(html_title("<html></html>"), None);
+    }
+}
@@ -0,0 +1,122 @@
+//! Reachability of self-hosted services on the public address.
+//!
+//! Someone running a server at home wants to know two things: can the
+//! internet reach it through the port forward, and can devices on the LAN
+//! reach it under the public address? The latter needs the router to loop
+//! the connection back inside ("hairpin NAT" or "NAT loopback"), which many
+//! consumer routers do not support, so the service works for everyone except
+//! its owner.
+
+use super::reflection::request_connect_back;
+use crate::api::{
+    constants::LibConstants,
+    models::{HairpinReport, ServiceReachability},
+};
+use std::{
+    io::ErrorKind,
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+use tokio::{net::TcpStream, time::timeout};
+
+/// Tests TCP `port` on the public address from the LAN and, via the
+/// connect-back test of `reflection_servers`, from the internet.
+///
+/// `public_address` may be left `None` to use the address the first
+/// answering reflection server saw. `timeout_ms` bounds the LAN connection
+/// (0 uses [DEFAULT_HAIRPIN_TIMEOUT_MS](LibConstants::DEFAULT_HAIRPIN_TIMEOUT_MS));
+/// each connect-back test gets
+/// [DEFAULT_CONNECT_BACK_TIMEOUT_MS](LibConstants::DEFAULT_CONNECT_BACK_TIMEOUT_MS).
+pub async fn check_hairpin(
+    port: u16,
+    public_address: Option<String>,
+    reflection_servers: Vec<String>,
+    timeout_ms: u64,
+) -> HairpinReport {
+    let wait = Duration::from_millis(if timeout_ms == 0 {
+        LibConstants::DEFAULT_HAIRPIN_TIMEOUT_MS
+    } else {
+        timeout_ms
+    });
+
+    let mut public_ip: Option<IpAddr> = public_address.as_deref().and_then(|a| a.parse().ok());
+    let mut from_internet = ServiceReachability::NotTested;
+    for server in &reflection_servers {
+        let answer = request_connect_back(
+            server,
+            port,
+            Duration::from_millis(LibConstants::DEFAULT_CONNECT_BACK_TIMEOUT_MS),
+        )
+        .await;
+        if let Some((observed_ip, outcome)) = answer {
+            public_ip.get_or_insert(observed_ip);
+            from_internet = outcome;
+            break;
+        }
+    }
+
+    let from_lan = match public_ip {
+        Some(ip) => connect(SocketAddr::new(ip, port), wait).await,
+        None => ServiceReachability::NotTested,
+    };
+
+    HairpinReport {
+        public_address: public_ip.map(|ip| ip.to_string()),
+        port,
+        from_lan,
+        from_internet,
+        hairpin_supported: hairpin_verdict(from_lan, from_internet),
+    }
+}
+
+pub(crate) async fn connect(addr: SocketAddr, wait: Duration) -> ServiceReachability {
+    match timeout(wait, TcpStream::connect(addr)).await {
+        Ok(Ok(_)) => ServiceReachability::Reachable,
+        Ok(Err(e)) if e.kind() == ErrorKind::TimedOut => ServiceReachability::TimedOut,
+        Ok(Err(_)) => ServiceReachability::Refused,
+        Err(_) => ServiceReachability::TimedOut,
+    }
+}
+
+fn hairpin_verdict(
+    from_lan: ServiceReachability,
+    from_internet: ServiceReachability,
+) -> Option<bool> {
+    match (from_lan, from_internet) {
+        (ServiceReachability::Reachable, _) => Some(true),
+        (
+            ServiceReachability::Refused | ServiceReachability::TimedOut,
+            ServiceReachability::Reachable,
+        ) => Some(false),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_check_hairpin_against_local_listener() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+
+        let report = check_hairpin(port, Some("127.0.0.1".into()), Vec::new(), 1000).await;
+        assert_eq!(report.from_lan, ServiceReachability::Reachable);
+        assert_eq!(report.from_internet, ServiceReachability::NotTested);
+        assert_eq!(report.hairpin_supported, Some(true));
+
+        // Reachable from outside only: the router lacks NAT loopback.
+        assert_eq!(
+            hairpin_verdict(
+                ServiceReachability::TimedOut,
+                ServiceReachability::Reachable
+            ),
+            Some(false)
+        );
+        assert_eq!(
+            hairpin_verdict(ServiceReachability::Refused, ServiceReachability::Refused),
+            None
+        );
+    }
+}
@@ -1,15 +1,140 @@
-use crate::api::models::{ConnectionType, SecurityFlagsResult};
+use crate::api::{
+    constants::LibConstants,
+    models::{
+        Confidence, ConnectionType, Evidence, EvidenceSource, NetworkTarget, SecurityFlagsResult,
+    },
+};
+use flutter_rust_bridge::frb;
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, UdpSocket};
 
 /// Inspects system network interfaces to detect connection type and potential security flags.
+///
+/// Equivalent to [detect_security_and_network_type_towards] a public address.
 pub fn detect_security_and_network_type() -> (SecurityFlagsResult, ConnectionType) {
-    use crate::api::constants::LibConstants;
-    use network_interface::{NetworkInterface, NetworkInterfaceConfig};
+    detect_security_and_network_type_towards(default_route_destination())
+}
 
-    let interfaces = NetworkInterface::show().unwrap_or_default();
+/// Detects the connection type of the interface that carries traffic to `destination`.
+///
+/// On Windows the adapter media type (`IfType`) of the adapter `GetBestInterfaceEx` picks for
+/// `destination` (IPv4 or IPv6) is used. On Linux/Android the kernel routing table is queried via netlink for
+/// the outgoing interface. Everywhere else, and if the platform source is
+/// unavailable, the active interfaces are classified via [classify_interface].
+/// Under a [transport](super::transport) that does not use the system routes
+/// only its interface list is used.
+#[frb(ignore)]
+pub fn detect_security_and_network_type_towards(
+    destination: IpAddr,
+) -> (SecurityFlagsResult, ConnectionType) {
+    if !super::transport::current().uses_system_routes() {
+        return detect_by_interface_name();
+    }
 
-    let mut security_flags_res = SecurityFlagsResult::default();
-    let mut conn_type = ConnectionType::Unknown;
+    #[cfg(windows)]
+    if let Some(detected) = windows::detect(destination) {
+        return detected;
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    if let Some(detected) =
+        super::netlink::route_interface(destination).and_then(|name| detect_named_interface(&name))
+    {
+        return detected;
+    }
+
+    let _ = destination;
+    detect_by_interface_name()
+}
+
+/// Builds the detection result for an interface chosen by the caller (see
+/// [NetworkConfiguration::active_interface](crate::api::models::NetworkConfiguration::active_interface)),
+/// skipping route-based detection.
+///
+/// An interface that does not exist keeps its name but yields
+/// [ConnectionType::Unknown].
+pub fn detect_security_and_network_type_of(
+    interface_name: &str,
+) -> (SecurityFlagsResult, ConnectionType) {
+    detect_named_interface(interface_name).unwrap_or_else(|| {
+        let flags = SecurityFlagsResult {
+            interface_name: interface_name.to_string(),
+            ..SecurityFlagsResult::default()
+        };
+        (flags, ConnectionType::Unknown)
+    })
+}
+
+/// Picks the address whose route decides the "active" interface.
+///
+/// The first target addressed by an IP literal is used, so that multi-homed
+/// hosts report the interface the probes actually leave through. Falls back to
+/// a well-known public resolver.
+#[frb(ignore)]
+pub fn route_destination_for(targets: &[NetworkTarget]) -> IpAddr {
+    targets
+        .iter()
+        .find_map(|t| t.host.parse().ok())
+        .unwrap_or_else(default_route_destination)
+}
+
+/// Returns the router the default route points at, if the platform exposes it.
+///
+/// Uses the kernel routing table on Linux/Android and the gateway list of the
+/// default-route adapter on Windows. Returns `None` elsewhere.
+#[frb(ignore)]
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub fn default_gateway() -> Option<IpAddr> {
+    super::netlink::route_gateway(default_route_destination())
+}
+
+/// Returns the router the default route points at, if the platform exposes it.
+#[frb(ignore)]
+#[cfg(windows)]
+pub fn default_gateway() -> Option<IpAddr> {
+    windows::default_gateway(default_route_destination())
+}
 
+/// Returns the router the default route points at, if the platform exposes it.
+#[frb(ignore)]
+#[cfg(not(any(target_os = "linux", target_os = "android", windows)))]
+pub fn default_gateway() -> Option<IpAddr> {
+    None
+}
+
+/// True if the OS has a route towards any of `destinations`.
+///
+/// Connecting a UDP socket sends nothing, but makes the kernel pick a route
+/// and fail at once (e.g. with `ENETUNREACH`) if there is none.
+pub(crate) fn has_route_to(destinations: &[IpAddr]) -> bool {
+    destinations.iter().any(|&ip| {
+        let local: SocketAddr = match ip {
+            IpAddr::V4(_) => (Ipv4Addr::UNSPECIFIED, 0).into(),
+            IpAddr::V6(_) => (Ipv6Addr::UNSPECIFIED, 0).into(),
+        };
+        UdpSocket::bind(local)
+            .and_then(|socket| socket.connect((ip, 9)))
+            .is_ok()
+    })
+}
+
+/// The public addresses whose routes stand for "the internet": one per family.
+pub(crate) fn internet_route_destinations() -> [IpAddr; 2] {
+    [
+        default_route_destination(),
+        LibConstants::CLOUDFLARE_DNS_V6
+            .parse()
+            .expect("CLOUDFLARE_DNS_V6 is a valid IP address"),
+    ]
+}
+
+fn default_route_destination() -> IpAddr {
+    LibConstants::CLOUDFLARE_DNS
+        .parse()
+        .expect("CLOUDFLARE_DNS is a valid IP address")
+}
+
+/// Classifies a single interface name. VPN prefixes take precedence.
+fn classify_interface_name(name: &str) -> Option<ConnectionType> {
     // Keywords to identify different types of network interfaces.
     // Order matters: VPN check should be first.
     let type_map: &[(&[&str], ConnectionType)] = &[
@@ -21,34 +146,465 @@ pub fn detect_security_and_network_type() -> (SecurityFlagsResult, ConnectionTyp
         (LibConstants::LOOPBACK_PREFIXES, ConnectionType::Loopback),
     ];
 
+    let name_lower = name.to_lowercase();
+    type_map
+        .iter()
+        .find(|(prefixes, _)| prefixes.iter().any(|prefix| name_lower.contains(prefix)))
+        .map(|&(_, ctype)| ctype)
+}
+
+/// Classifies a local interface, preferring what the OS reports about its
+/// hardware over the name.
+///
+/// Names alone are ambiguous: macOS calls its Wi-Fi adapter `en0`, and Linux
+/// drivers may pick any name. On Linux/Android sysfs is consulted, on macOS
+/// the media type reported by the driver; elsewhere, or if that source knows
+/// nothing, [classify_interface_name] decides.
+pub(crate) fn classify_interface(name: &str) -> Option<ConnectionType> {
+    // Interfaces of a transport with routes of its own only exist by name.
+    if !super::transport::current().uses_system_routes() {
+        return classify_interface_name(name);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    if let Some(ctype) =
+        classify_sysfs_interface(std::path::Path::new(LibConstants::SYSFS_NET_DIR), name)
+    {
+        return Some(ctype);
+    }
+
+    #[cfg(target_os = "macos")]
+    if let Some(ctype) = ifmedia::classify(name) {
+        return Some(ctype);
+    }
+
+    classify_interface_name(name)
+}
+
+/// Classifies an interface from its sysfs entry under `root` (normally
+/// `/sys/class/net`).
+///
+/// The `DEVTYPE` announced by the driver and a `wireless` or `phy80211` entry
+/// are definitive. Otherwise the ARP hardware type separates loopback and
+/// tunnels from Ethernet; only Ethernet backed by a physical `device` counts,
+/// since bridges, veth pairs and TAP devices are Ethernet-typed too.
+#[cfg_attr(not(any(target_os = "linux", target_os = "android")), allow(dead_code))]
+fn classify_sysfs_interface(root: &std::path::Path, name: &str) -> Option<ConnectionType> {
+    // ARPHRD_* values (linux/if_arp.h).
+    const ETHER: u32 = 1;
+    const PPP: u32 = 512;
+    const TUNNEL: u32 = 768;
+    const TUNNEL6: u32 = 769;
+    const LOOPBACK: u32 = 772;
+    const SIT: u32 = 776;
+    const IPGRE: u32 = 778;
+    const NONE: u32 = 65534; // tun, WireGuard
+
+    let dir = root.join(name);
+    let devtype = std::fs::read_to_string(dir.join("uevent"))
+        .ok()
+        .and_then(|uevent| {
+            uevent
+                .lines()
+                .find_map(|line| line.strip_prefix("DEVTYPE=").map(str::to_owned))
+        });
+    match devtype.as_deref() {
+        Some("wlan") => return Some(ConnectionType::Wifi),
+        Some("wwan") => return Some(ConnectionType::Cellular),
+        Some("bluetooth") => return Some(ConnectionType::Bluetooth),
+        _ => {}
+    }
+    if dir.join("wireless").exists() || dir.join("phy80211").exists() {
+        return Some(ConnectionType::Wifi);
+    }
+
+    let arphrd: u32 = std::fs::read_to_string(dir.join("type"))
+        .ok()?
+        .trim()
+        .parse()
+        .ok()?;
+    // Modems present PPP and raw-IP links (`rmnet`, `ccmni`) that look like
+    // tunnels; their names tell them apart.
+    let lower = name.to_lowercase();
+    let cellular_name = LibConstants::CELLULAR_PREFIXES
+        .iter()
+        .any(|prefix| lower.starts_with(prefix));
+    match arphrd {
+        LOOPBACK => Some(ConnectionType::Loopback),
+        PPP | NONE if cellular_name => Some(ConnectionType::Cellular),
+        PPP | TUNNEL | TUNNEL6 | SIT | IPGRE | NONE => Some(ConnectionType::Vpn),
+        ETHER if dir.join("device").exists() => Some(ConnectionType::Ethernet),
+        _ => None,
+    }
+}
+
+/// Builds the detection result for a known outgoing interface.
+///
+/// A VPN interface that is up but does not carry the route still sets
+/// [SecurityFlagsResult::is_vpn_detected] (split tunnelling).
+fn detect_named_interface(name: &str) -> Option<(SecurityFlagsResult, ConnectionType)> {
+    let interfaces = super::transport::current().list_interfaces().ok()?;
+    interfaces.iter().find(|iface| iface.name == name)?;
+
+    let conn_type = classify_interface(name).unwrap_or(ConnectionType::Unknown);
+    let any_vpn_up = interfaces.iter().any(|iface| {
+        !iface.addrs.is_empty() && classify_interface(&iface.name) == Some(ConnectionType::Vpn)
+    });
+
+    // The routed interface is known, so only the name match itself is a
+    // heuristic; a tunnel that is merely up elsewhere is weaker evidence.
+    let confidence = if conn_type != ConnectionType::Vpn && any_vpn_up {
+        Confidence::Low
+    } else {
+        Confidence::Medium
+    };
+    let flags = SecurityFlagsResult {
+        is_vpn_detected: conn_type == ConnectionType::Vpn || any_vpn_up,
+        interface_name: name.to_string(),
+        vpn_evidence: Evidence::new(confidence, EvidenceSource::InterfaceName),
+        ..SecurityFlagsResult::default()
+    };
+    Some((flags, conn_type))
+}
+
+/// Classifies interfaces by matching their names against known prefixes.
+fn detect_by_interface_name() -> (SecurityFlagsResult, ConnectionType) {
+    let interfaces = super::transport::current()
+        .list_interfaces()
+        .unwrap_or_default();
+
+    // Without a route lookup the "active" interface is itself a guess.
+    let mut security_flags_res = SecurityFlagsResult {
+        vpn_evidence: Evidence::new(Confidence::Low, EvidenceSource::InterfaceName),
+        ..SecurityFlagsResult::default()
+    };
+    let mut conn_type = ConnectionType::Unknown;
+
     // Find the active, non-loopback interface
     for iface in interfaces {
         // Skip loopback and interfaces without an IP (inactive)
-        if iface.name.contains("lo") || iface.addr.is_empty() {
+        if iface.name.contains("lo") || iface.addrs.is_empty() {
             continue;
         }
 
-        let name_lower = iface.name.to_lowercase();
-
-        for &(prefixes, ref ctype) in type_map {
-            // Check if name contains prefix or matches common patterns
-            if prefixes.iter().any(|prefix| name_lower.contains(prefix)) {
-                if *ctype == ConnectionType::Vpn {
-                    security_flags_res.is_vpn_detected = true;
-                    security_flags_res.interface_name = iface.name.clone();
-                    conn_type = ConnectionType::Vpn;
-                    return (security_flags_res, conn_type);
-                } else if conn_type == ConnectionType::Unknown {
-                    conn_type = *ctype;
-                    security_flags_res.interface_name = iface.name.clone();
-                }
+        match classify_interface(&iface.name) {
+            Some(ConnectionType::Vpn) => {
+                security_flags_res.is_vpn_detected = true;
+                security_flags_res.interface_name = iface.name.clone();
+                return (security_flags_res, ConnectionType::Vpn);
+            }
+            Some(ctype) if conn_type == ConnectionType::Unknown => {
+                conn_type = ctype;
+                security_flags_res.interface_name = iface.name.clone();
             }
+            _ => {}
         }
     }
 
     (security_flags_res, conn_type)
 }
 
+/// Maps a Windows adapter to a [ConnectionType].
+///
+/// `if_type` is the IANA interface type reported by `GetAdaptersAddresses`,
+/// `is_tunnel` is true if the adapter reports a tunnel type, and `description`
+/// is the driver description (e.g. "WireGuard Tunnel").
+#[cfg_attr(not(windows), allow(dead_code))]
+fn classify_windows_adapter(if_type: u32, is_tunnel: bool, description: &str) -> ConnectionType {
+    // IANA ifType values (ipifcons.h).
+    const ETHERNET_CSMACD: u32 = 6;
+    const PPP: u32 = 23;
+    const SOFTWARE_LOOPBACK: u32 = 24;
+    const PROP_VIRTUAL: u32 = 53;
+    const IEEE80211: u32 = 71;
+    const TUNNEL: u32 = 131;
+    const WWANPP: u32 = 243;
+    const WWANPP2: u32 = 244;
+
+    let description = description.to_lowercase();
+    let matches_any = |keywords: &[&str]| keywords.iter().any(|k| description.contains(k));
+
+    match if_type {
+        SOFTWARE_LOOPBACK => ConnectionType::Loopback,
+        TUNNEL | PPP => ConnectionType::Vpn,
+        _ if is_tunnel || matches_any(LibConstants::WINDOWS_VPN_DESCRIPTIONS) => {
+            ConnectionType::Vpn
+        }
+        IEEE80211 => ConnectionType::Wifi,
+        WWANPP | WWANPP2 => ConnectionType::Cellular,
+        _ if matches_any(LibConstants::WINDOWS_BLUETOOTH_DESCRIPTIONS) => ConnectionType::Bluetooth,
+        ETHERNET_CSMACD => ConnectionType::Ethernet,
+        PROP_VIRTUAL => ConnectionType::Vpn,
+        _ => ConnectionType::Unknown,
+    }
+}
+
+#[cfg(target_os = "macos")]
+mod ifmedia {
+    //! `SIOCGIFMEDIA`: the media type the IOKit network driver reports for an
+    //! interface, which tells the Wi-Fi `en0` apart from a wired `en1`.
+
+    use crate::api::models::ConnectionType;
+    use std::{net::UdpSocket, os::fd::AsRawFd};
+
+    // net/if_media.h and sys/sockio.h.
+    const SIOCGIFMEDIA: libc::c_ulong = 0xc030_6938;
+    const IFM_NMASK: libc::c_int = 0xe0;
+    const IFM_ETHER: libc::c_int = 0x20;
+    const IFM_IEEE80211: libc::c_int = 0x80;
+
+    /// `struct ifmediareq`.
+    #[repr(C)]
+    struct IfMediaReq {
+        ifm_name: [libc::c_char; libc::IFNAMSIZ],
+        ifm_current: libc::c_int,
+        ifm_mask: libc::c_int,
+        ifm_status: libc::c_int,
+        ifm_active: libc::c_int,
+        ifm_count: libc::c_int,
+        ifm_ulist: *mut libc::c_int,
+    }
+
+    pub(super) fn classify(name: &str) -> Option<ConnectionType> {
+        if name.len() >= libc::IFNAMSIZ {
+            return None;
+        }
+        // Any socket will do; the request only names the interface.
+        let socket = UdpSocket::bind("0.0.0.0:0").ok()?;
+
+        // SAFETY: an all-zero `ifmediareq` is valid (null `ifm_ulist`, count 0).
+        let mut req: IfMediaReq = unsafe { std::mem::zeroed() };
+        for (dst, src) in req.ifm_name.iter_mut().zip(name.bytes()) {
+            *dst = src as libc::c_char;
+        }
+        // SAFETY: `req` is a properly sized, NUL-terminated `ifmediareq` that
+        // outlives the call; with `ifm_count` 0 the kernel writes no list.
+        let result = unsafe { libc::ioctl(socket.as_raw_fd(), SIOCGIFMEDIA, &mut req) };
+        if result < 0 {
+            // Interfaces without media (utun, bridges, loopback) refuse the request.
+            return None;
+        }
+
+        let media = if req.ifm_active != 0 {
+            req.ifm_active
+        } else {
+            req.ifm_current
+        };
+        match media & IFM_NMASK {
+            IFM_IEEE80211 => Some(ConnectionType::Wifi),
+            IFM_ETHER => Some(ConnectionType::Ethernet),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(windows)]
+mod windows {
+    //! `GetAdaptersAddresses`-based adapter inspection.
+
+    use super::classify_windows_adapter;
+    use crate::api::models::{
+        Confidence, ConnectionType, Evidence, EvidenceSource, SecurityFlagsResult,
+    };
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+    use windows_sys::Win32::{
+        Foundation::{ERROR_BUFFER_OVERFLOW, NO_ERROR},
+        NetworkManagement::{
+            IpHelper::{
+                GetAdaptersAddresses, GetBestInterfaceEx, GAA_FLAG_INCLUDE_GATEWAYS,
+                GAA_FLAG_SKIP_ANYCAST, GAA_FLAG_SKIP_DNS_SERVER, GAA_FLAG_SKIP_MULTICAST,
+                IP_ADAPTER_ADDRESSES_LH,
+            },
+            Ndis::{IfOperStatusUp, TUNNEL_TYPE_NONE},
+        },
+        Networking::WinSock::{
+            AF_INET, AF_INET6, AF_UNSPEC, IN6_ADDR, IN6_ADDR_0, IN_ADDR, IN_ADDR_0, SOCKADDR,
+            SOCKADDR_IN, SOCKADDR_IN6, SOCKADDR_INET, SOCKET_ADDRESS,
+        },
+    };
+
+    /// A snapshot of the fields we need from one `IP_ADAPTER_ADDRESSES_LH`.
+    struct Adapter {
+        if_index: u32,
+        friendly_name: String,
+        connection_type: ConnectionType,
+        gateway: Option<IpAddr>,
+    }
+
+    /// Detects the adapter carrying the route to `destination` and its media
+    /// type.
+    ///
+    /// Returns `None` if the adapter list cannot be read, so that the caller
+    /// can fall back to name matching.
+    pub(super) fn detect(destination: IpAddr) -> Option<(SecurityFlagsResult, ConnectionType)> {
+        let adapters = active_adapters()?;
+        let route_index = route_index(destination);
+
+        let mut flags = SecurityFlagsResult::default();
+        let primary = route_index
+            .and_then(|idx| adapters.iter().find(|a| a.if_index == idx))
+            .or_else(|| {
+                adapters
+                    .iter()
+                    .find(|a| a.connection_type != ConnectionType::Loopback)
+            })?;
+
+        // A VPN adapter that is up but does not carry the default route still
+        // indicates an active (split) tunnel.
+        flags.is_vpn_detected = adapters
+            .iter()
+            .any(|a| a.connection_type == ConnectionType::Vpn);
+        let confidence = if primary.connection_type == ConnectionType::Vpn {
+            Confidence::High
+        } else {
+            Confidence::Medium
+        };
+        flags.vpn_evidence = Evidence::new(confidence, EvidenceSource::AdapterType);
+        flags.interface_name = primary.friendly_name.clone();
+
+        Some((flags, primary.connection_type))
+    }
+
+    /// The first gateway of the adapter carrying the route to `destination`.
+    pub(super) fn default_gateway(destination: IpAddr) -> Option<IpAddr> {
+        let route_index = route_index(destination)?;
+        active_adapters()?
+            .into_iter()
+            .find(|a| a.if_index == route_index)?
+            .gateway
+    }
+
+    /// The interface index Windows would use to reach `destination`, over
+    /// IPv4 or IPv6.
+    fn route_index(destination: IpAddr) -> Option<u32> {
+        let mut address = SOCKADDR_INET::default();
+        match destination {
+            IpAddr::V4(ip) => {
+                address.Ipv4 = SOCKADDR_IN {
+                    sin_family: AF_INET,
+                    sin_addr: IN_ADDR {
+                        S_un: IN_ADDR_0 {
+                            S_addr: u32::from_ne_bytes(ip.octets()),
+                        },
+                    },
+                    ..Default::default()
+                }
+            }
+            IpAddr::V6(ip) => {
+                address.Ipv6 = SOCKADDR_IN6 {
+                    sin6_family: AF_INET6,
+                    sin6_addr: IN6_ADDR {
+                        u: IN6_ADDR_0 { Byte: ip.octets() },
+                    },
+                    ..Default::default()
+                }
+            }
+        }
+        let mut index = 0u32;
+        // SAFETY: `address` holds a socket address of the family it announces
+        // and is large enough for either; `index` is a valid out-pointer for
+        // the duration of the call.
+        let status = unsafe {
+            GetBestInterfaceEx(
+                (&address as *const SOCKADDR_INET).cast::<SOCKADDR>(),
+                &mut index,
+            )
+        };
+        (status == NO_ERROR).then_some(index)
+    }
+
+    /// Lists every adapter that is operationally up and has a unicast address.
+    fn active_adapters() -> Option<Vec<Adapter>> {
+        let flags = GAA_FLAG_SKIP_ANYCAST
+            | GAA_FLAG_SKIP_MULTICAST
+            | GAA_FLAG_SKIP_DNS_SERVER
+            | GAA_FLAG_INCLUDE_GATEWAYS;
+        // Microsoft recommends starting with a 15 KB buffer.
+        let mut size: u32 = 15 * 1024;
+        let mut buffer: Vec<u64>;
+
+        loop {
+            // u64 elements keep the buffer aligned for IP_ADAPTER_ADDRESSES_LH.
+            buffer = vec![0u64; (size as usize).div_ceil(8)];
+            // SAFETY: `buffer` holds at least `size` writable bytes.
+            let status = unsafe {
+                GetAdaptersAddresses(
+                    u32::from(AF_UNSPEC),
+                    flags,
+                    std::ptr::null(),
+                    buffer.as_mut_ptr().cast::<IP_ADAPTER_ADDRESSES_LH>(),
+                    &mut size,
+                )
+            };
+            match status {
+                NO_ERROR => break,
+                ERROR_BUFFER_OVERFLOW => continue,
+                _ => return None,
+            }
+        }
+
+        let mut adapters = Vec::new();
+        let mut current = buffer.as_ptr().cast::<IP_ADAPTER_ADDRESSES_LH>();
+        // SAFETY: on success the buffer contains a valid, null-terminated
+        // linked list of adapters that lives as long as `buffer`.
+        while let Some(adapter) = unsafe { current.as_ref() } {
+            if adapter.OperStatus == IfOperStatusUp && !adapter.FirstUnicastAddress.is_null() {
+                let description = unsafe { wide_to_string(adapter.Description) };
+                adapters.push(Adapter {
+                    if_index: unsafe { adapter.Anonymous1.Anonymous.IfIndex },
+                    friendly_name: unsafe { wide_to_string(adapter.FriendlyName) },
+                    connection_type: classify_windows_adapter(
+                        adapter.IfType,
+                        adapter.TunnelType != TUNNEL_TYPE_NONE,
+                        &description,
+                    ),
+                    // SAFETY: the gateway list is part of the same buffer.
+                    gateway: unsafe { adapter.FirstGatewayAddress.as_ref() }
+                        .and_then(|gw| unsafe { socket_address_to_ip(&gw.Address) }),
+                });
+            }
+            current = adapter.Next.cast_const();
+        }
+
+        Some(adapters)
+    }
+
+    /// Converts a `SOCKET_ADDRESS` holding an IPv4 or IPv6 address into an [IpAddr].
+    ///
+    /// # Safety
+    ///
+    /// `address.lpSockaddr` must be null or point to a valid socket address of
+    /// `address.iSockaddrLength` bytes.
+    unsafe fn socket_address_to_ip(address: &SOCKET_ADDRESS) -> Option<IpAddr> {
+        let sockaddr = address.lpSockaddr.as_ref()?;
+        match sockaddr.sa_family {
+            AF_INET => {
+                let v4 = &*address.lpSockaddr.cast::<SOCKADDR_IN>();
+                let octets = v4.sin_addr.S_un.S_addr.to_ne_bytes();
+                Some(IpAddr::V4(Ipv4Addr::from(octets)))
+            }
+            AF_INET6 => {
+                let v6 = &*address.lpSockaddr.cast::<SOCKADDR_IN6>();
+                Some(IpAddr::V6(Ipv6Addr::from(v6.sin6_addr.u.Byte)))
+            }
+            _ => None,
+        }
+    }
+
+    /// Converts a null-terminated UTF-16 string into a [String].
+    ///
+    /// # Safety
+    ///
+    /// `ptr` must be null or point to a valid, null-terminated UTF-16 string.
+    unsafe fn wide_to_string(ptr: *const u16) -> String {
+        if ptr.is_null() {
+            return String::new();
+        }
+        let len = (0..).take_while(|&i| *ptr.add(i) != 0).count();
+        String::from_utf16_lossy(std::slice::from_raw_parts(ptr, len))
+    }
+}
+
 /// Web implementation stub (WASM removed).
 pub fn detect_security_and_network_type_web() -> (SecurityFlagsResult, ConnectionType) {
     (SecurityFlagsResult::default(), ConnectionType::Unknown)
@@ -64,4 +620,112 @@ mod tests {
         assert!(!flags.interface_name.is_empty());
         assert_ne!(flags.interface_name, "unknown");
     }
+
+    #[test]
+    fn test_classify_sysfs_interface() {
+        let root = std::env::temp_dir().join(format!("nr-sysfs-{}", std::process::id()));
+        let add = |name: &str, arphrd: &str, entries: &[&str], uevent: &str| {
+            let dir = root.join(name);
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(dir.join("type"), arphrd).unwrap();
+            std::fs::write(dir.join("uevent"), uevent).unwrap();
+            for entry in entries {
+                std::fs::create_dir_all(dir.join(entry)).unwrap();
+            }
+        };
+        // Names that defeat the keyword heuristic.
+        add("en0", "1\n", &["device", "wireless"], "INTERFACE=en0\n");
+        add(
+            "wlp2s0",
+            "1\n",
+            &["device"],
+            "DEVTYPE=wlan\nINTERFACE=wlp2s0\n",
+        );
+        add("wan0", "1\n", &["device"], "INTERFACE=wan0\n");
+        add("modem", "1\n", &["device"], "DEVTYPE=wwan\n");
+        add("corp", "65534\n", &[], "INTERFACE=corp\n");
+        add("rmnet_data0", "65534\n", &[], "INTERFACE=rmnet_data0\n");
+        add("wwan0", "512\n", &[], "INTERFACE=wwan0\n");
+        add("br0", "1\n", &[], "DEVTYPE=bridge\n");
+
+        let classify = |name| classify_sysfs_interface(&root, name);
+        assert_eq!(classify("en0"), Some(ConnectionType::Wifi));
+        assert_eq!(classify("wlp2s0"), Some(ConnectionType::Wifi));
+        assert_eq!(classify("wan0"), Some(ConnectionType::Ethernet));
+        assert_eq!(classify("modem"), Some(ConnectionType::Cellular));
+        assert_eq!(classify("corp"), Some(ConnectionType::Vpn));
+        assert_eq!(classify("rmnet_data0"), Some(ConnectionType::Cellular));
+        assert_eq!(classify("wwan0"), Some(ConnectionType::Cellular));
+        // Virtual Ethernet and unknown interfaces are left to the name match.
+        assert_eq!(classify("br0"), None);
+        assert_eq!(classify("missing"), None);
+
+        std::fs::remove_dir_all(&root).unwrap();
+    }
+
+    #[test]
+    fn test_detect_pinned_interface() {
+        let (flags, conn_type) = detect_security_and_network_type_of("nr-missing0");
+        assert_eq!(flags.interface_name, "nr-missing0");
+        assert_eq!(conn_type, ConnectionType::Unknown);
+        assert!(!flags.is_vpn_detected);
+
+        #[cfg(target_os = "linux")]
+        {
+            let (flags, conn_type) = detect_security_and_network_type_of("lo");
+            assert_eq!(flags.interface_name, "lo");
+            assert_eq!(conn_type, ConnectionType::Loopback);
+        }
+    }
+
+    #[test]
+    fn test_route_destination_prefers_ip_targets() {
+        let mut targets = crate::api::models::NetworkConfiguration::default().targets;
+        targets[0].host = "example.com".into();
+        targets[1].host = "192.0.2.1".into();
+        assert_eq!(
+            route_destination_for(&targets),
+            "192.0.2.1".parse::<IpAddr>().unwrap()
+        );
+        assert_eq!(route_destination_for(&[]), default_route_destination());
+    }
+
+    #[test]
+    fn test_has_route_to() {
+        assert!(has_route_to(&["127.0.0.1".parse().unwrap()]));
+        assert!(!has_route_to(&[]));
+    }
+
+    #[test]
+    fn test_classify_windows_adapter() {
+        // "Ethernet 2" and "Wi-Fi" friendly names are irrelevant: only the media type counts.
+        assert_eq!(
+            classify_windows_adapter(6, false, "Intel(R) Ethernet Connection I219-V"),
+            ConnectionType::Ethernet
+        );
+        assert_eq!(
+            classify_windows_adapter(71, false, "Intel(R) Wi-Fi 6 AX201 160MHz"),
+            ConnectionType::Wifi
+        );
+        assert_eq!(
+            classify_windows_adapter(6, false, "WireGuard Tunnel"),
+            ConnectionType::Vpn
+        );
+        assert_eq!(
+            classify_windows_adapter(131, true, "Teredo Tunneling Pseudo-Interface"),
+            ConnectionType::Vpn
+        );
+        assert_eq!(
+            classify_windows_adapter(6, false, "Bluetooth Device (Personal Area Network)"),
+            ConnectionType::Bluetooth
+        );
+        assert_eq!(
+            classify_windows_adapter(243, false, "Generic Mobile Broadband Adapter"),
+            ConnectionType::Cellular
+        );
+        assert_eq!(
+            classify_windows_adapter(24, false, "Software Loopback Interface 1"),
+            ConnectionType::Loopback
+        );
+    }
 }
@@ -0,0 +1,149 @@
+//! Probes for ISP and downdetector-style status endpoints.
+//!
+//! When the gateway answers but the internet does not, the fault lies either
+//! with the local modem/router or with the ISP. Many ISPs host their status
+//! page inside their own network, so it often stays reachable during an
+//! upstream outage and can tell the two cases apart.
+
+use super::parse::read_text;
+use crate::api::constants::LibConstants;
+use futures::future::join_all;
+use std::time::Duration;
+
+/// What a single status endpoint said.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum IspStatus {
+    /// The endpoint reports an incident.
+    Outage,
+    /// The endpoint reports normal operation.
+    Operational,
+    /// The endpoint could not be reached or answered with an error.
+    Unreachable,
+}
+
+/// Queries every URL and combines the answers into an outage hint.
+///
+/// Returns `Some(true)` if any endpoint reports an outage, `Some(false)` if
+/// at least one reports normal operation and none an outage, and `None` if no
+/// endpoint answered.
+pub async fn check_isp_status(urls: &[String], timeout_ms: u64) -> Option<bool> {
+    let client = reqwest::Client::builder()
+        .timeout(Duration::from_millis(timeout_ms))
+        .build()
+        .ok()?;
+
+    let statuses = join_all(urls.iter().map(|url| query(&client, url))).await;
+    if statuses.contains(&IspStatus::Outage) {
+        Some(true)
+    } else if statuses.contains(&IspStatus::Operational) {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+async fn query(client: &reqwest::Client, url: &str) -> IspStatus {
+    let Ok(response) = client.get(url).send().await else {
+        return IspStatus::Unreachable;
+    };
+    if !response.status().is_success() {
+        return IspStatus::Unreachable;
+    }
+    match read_text(response, LibConstants::MAX_RESPONSE_BODY_BYTES).await {
+        Ok(body) => classify_status_body(&body),
+        Err(_) => IspStatus::Unreachable,
+    }
+}
+
+/// Phrases that report an incident, as lowercase words.
+const INCIDENT_PHRASES: &[&[&str]] = &[
+    &["outage"],
+    &["outages"],
+    &["service", "disruption"],
+    &["service", "disruptions"],
+    &["major", "incident"],
+];
+
+/// Words that turn a following incident phrase into an all-clear, as in
+/// "No outages reported" or "We are not aware of any outage".
+const NEGATIONS: &[&str] = &["no", "not", "without", "zero"];
+
+/// How many words before a phrase are searched for a negation.
+const NEGATION_WINDOW: usize = 4;
+
+/// Interprets a status page body.
+///
+/// Statuspage-style JSON is judged by its `"indicator"` (`"none"` means
+/// operational). Anything else counts as an outage if it mentions one as a
+/// whole phrase that is not negated by one of the few words before it.
+pub(crate) fn classify_status_body(body: &str) -> IspStatus {
+    if let Some(indicator) = json_string_field(body, "indicator") {
+        return if indicator.eq_ignore_ascii_case("none") {
+            IspStatus::Operational
+        } else {
+            IspStatus::Outage
+        };
+    }
+
+    let lower = body.to_ascii_lowercase();
+    let words: Vec<&str> = lower
+        .split(|c: char| !c.is_ascii_alphanumeric())
+        .filter(|w| !w.is_empty())
+        .collect();
+    let reports_incident = (0..words.len()).any(|i| {
+        let phrase_here = INCIDENT_PHRASES
+            .iter()
+            .any(|phrase| words[i..].starts_with(phrase));
+        let negated = words[i.saturating_sub(NEGATION_WINDOW)..i]
+            .iter()
+            .any(|w| NEGATIONS.contains(w));
+        phrase_here && !negated
+    });
+    if reports_incident {
+        IspStatus::Outage
+    } else {
+        IspStatus::Operational
+    }
+}
+
+/// The string value of the first `"key": "value"` pair, without a JSON parser.
+pub(crate) fn json_string_field<'a>(body: &'a str, key: &str) -> Option<&'a str> {
+    let needle = format!("\"{}\"", key);
+    let rest = &body[body.find(&needle)? + needle.len()..];
+    let rest = rest.trim_start().strip_prefix(':')?.trim_start();
+    let rest = rest.strip_prefix('"')?;
+    Some(&rest[..rest.find('"')?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_classify_status_body() {
+        let ok = r#"{"page":{"id":"x"},"status":{"indicator": "none","description":"All Systems Operational"}}"#;
+        assert_eq!(classify_status_body(ok), IspStatus::Operational);
+
+        let major = r#"{"status":{"indicator":"major","description":"Partial System Outage"}}"#;
+        assert_eq!(classify_status_body(major), IspStatus::Outage);
+
+        let page = "<h1>Known outage in your area</h1><p>Engineers are working on it.</p>";
+        assert_eq!(classify_status_body(page), IspStatus::Outage);
+        assert_eq!(
+            classify_status_body("<h1>All services running</h1>"),
+            IspStatus::Operational
+        );
+
+        let negated =
+            "<h1>No outages reported</h1><p>We are not aware of any service disruption.</p>";
+        assert_eq!(classify_status_body(negated), IspStatus::Operational);
+        assert_eq!(
+            classify_status_body("<p>Major incident in the north region. No ETA yet.</p>"),
+            IspStatus::Outage
+        );
+        assert_eq!(
+            classify_status_body("<p>All clear. Report problems at outagemap.example</p>"),
+            IspStatus::Operational
+        );
+    }
+}
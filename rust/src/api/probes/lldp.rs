@@ -0,0 +1,354 @@
+//! Passive LLDP/CDP neighbor discovery.
+//!
+//! Managed switches announce themselves on every port: LLDP (IEEE 802.1AB)
+//! every 30 s by default, Cisco's CDP every 60 s. Listening for one of these
+//! frames on the active interface reveals which switch, and which port of it,
+//! the device is plugged into.
+//!
+//! # Platform Notes
+//!
+//! Capturing link-layer frames requires a raw `AF_PACKET` socket, i.e. Linux or
+//! Android with `CAP_NET_RAW`. Other platforms report
+//! [NetworkError::UnknownError].
+
+use super::parse::Reader;
+use crate::api::models::{LinkNeighbor, NeighborProtocol, NetworkError};
+
+const ETHERTYPE_LLDP: u16 = 0x88cc;
+const LLDP_MULTICAST: [u8; 6] = [0x01, 0x80, 0xc2, 0x00, 0x00, 0x0e];
+const CDP_MULTICAST: [u8; 6] = [0x01, 0x00, 0x0c, 0xcc, 0xcc, 0xcc];
+/// LLC/SNAP header of a CDP frame: DSAP, SSAP, control, Cisco OUI, protocol ID.
+const CDP_SNAP_HEADER: [u8; 8] = [0xaa, 0xaa, 0x03, 0x00, 0x00, 0x0c, 0x20, 0x00];
+
+/// Listens on `interface` for up to `listen_ms` and returns the first LLDP or
+/// CDP neighbor announced.
+///
+/// An empty `interface` selects the interface carrying the default route.
+/// `Ok(None)` means nothing was announced in time; unmanaged switches never
+/// announce anything.
+pub async fn discover_link_neighbor(
+    interface: String,
+    listen_ms: u64,
+) -> Result<Option<LinkNeighbor>, NetworkError> {
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        let interface = if interface.is_empty() {
+            super::netlink::route_interface(super::route_destination_for(&[])).ok_or_else(|| {
+                NetworkError::UnknownError("No active interface found".to_string())
+            })?
+        } else {
+            interface
+        };
+        tokio::task::spawn_blocking(move || capture::listen(&interface, listen_ms))
+            .await
+            .map_err(|e| NetworkError::UnknownError(e.to_string()))?
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    {
+        let _ = (interface, listen_ms);
+        Err(NetworkError::UnknownError(
+            "LLDP/CDP capture is not supported on this platform".to_string(),
+        ))
+    }
+}
+
+/// Parses a captured Ethernet frame as LLDP or CDP.
+pub(crate) fn parse_frame(frame: &[u8]) -> Option<LinkNeighbor> {
+    let mut reader = Reader::new(frame);
+    let destination = reader.bytes(6)?;
+    reader.skip(6)?;
+    if reader.u16_be()? == ETHERTYPE_LLDP {
+        parse_lldp(reader.rest())
+    } else if destination == CDP_MULTICAST && reader.bytes(8)? == CDP_SNAP_HEADER {
+        parse_cdp(reader.rest())
+    } else {
+        None
+    }
+}
+
+/// Parses an LLDPDU (the Ethernet payload).
+fn parse_lldp(pdu: &[u8]) -> Option<LinkNeighbor> {
+    let mut neighbor = empty_neighbor(NeighborProtocol::Lldp);
+    let mut tlvs = Reader::new(pdu);
+    while tlvs.remaining() >= 2 {
+        let header = tlvs.u16_be()?;
+        let (tlv_type, len) = (header >> 9, usize::from(header & 0x01ff));
+        let value = tlvs.bytes(len)?;
+        match tlv_type {
+            0 => break,
+            1 => neighbor.chassis_id = subtyped_id(value, 4),
+            2 => neighbor.port_id = subtyped_id(value, 3),
+            4 => neighbor.port_description = text(value),
+            5 => neighbor.system_name = text(value),
+            6 => neighbor.system_description = text(value),
+            8 => neighbor.management_address = lldp_management_address(value),
+            _ => {}
+        }
+    }
+    neighbor.chassis_id.is_some().then_some(neighbor)
+}
+
+/// Formats a chassis/port ID: `mac_subtype` IDs as MAC addresses, others as text.
+fn subtyped_id(value: &[u8], mac_subtype: u8) -> Option<String> {
+    let (&subtype, id) = value.split_first()?;
+    if subtype == mac_subtype && id.len() == 6 {
+        Some(format_mac(id))
+    } else {
+        text(id)
+    }
+}
+
+/// Decodes the address of a Management Address TLV (IPv4 or IPv6).
+fn lldp_management_address(value: &[u8]) -> Option<String> {
+    let len = usize::from(*value.first()?);
+    let address = value.get(2..1 + len)?;
+    match (value.get(1)?, address.len()) {
+        (1, 4) => Some(std::net::Ipv4Addr::from(<[u8; 4]>::try_from(address).ok()?).to_string()),
+        (2, 16) => Some(std::net::Ipv6Addr::from(<[u8; 16]>::try_from(address).ok()?).to_string()),
+        _ => None,
+    }
+}
+
+/// Parses a CDP packet (after the LLC/SNAP header).
+fn parse_cdp(packet: &[u8]) -> Option<LinkNeighbor> {
+    // Version, TTL and checksum precede the TLVs.
+    let mut tlvs = Reader::at(packet, 4);
+    let mut neighbor = empty_neighbor(NeighborProtocol::Cdp);
+    while tlvs.remaining() >= 4 {
+        let tlv_type = tlvs.u16_be()?;
+        // The length includes the 4-byte type and length.
+        let len = usize::from(tlvs.u16_be()?);
+        let value = tlvs.bytes(len.checked_sub(4)?)?;
+        match tlv_type {
+            0x0001 => neighbor.system_name = text(value),
+            0x0002 => neighbor.management_address = cdp_first_ipv4(value),
+            0x0003 => neighbor.port_id = text(value),
+            0x0005 => neighbor.system_description = text(value),
+            0x0006 => neighbor.platform = text(value),
+            _ => {}
+        }
+    }
+    neighbor.chassis_id = neighbor.system_name.clone();
+    neighbor.chassis_id.is_some().then_some(neighbor)
+}
+
+/// Decodes the first IPv4 address of a CDP Addresses TLV.
+fn cdp_first_ipv4(value: &[u8]) -> Option<String> {
+    // Count (4 bytes), then protocol type, length, protocol (0xcc = IP),
+    // address length (2 bytes) and the address.
+    let entry = value.get(4..)?;
+    if entry.get(..3)? != [0x01, 0x01, 0xcc] || entry.get(3..5)? != [0x00, 0x04] {
+        return None;
+    }
+    let address: [u8; 4] = entry.get(5..9)?.try_into().ok()?;
+    Some(std::net::Ipv4Addr::from(address).to_string())
+}
+
+fn empty_neighbor(protocol: NeighborProtocol) -> LinkNeighbor {
+    LinkNeighbor {
+        protocol,
+        chassis_id: None,
+        system_name: None,
+        system_description: None,
+        platform: None,
+        port_id: None,
+        port_description: None,
+        management_address: None,
+    }
+}
+
+fn text(value: &[u8]) -> Option<String> {
+    let text = String::from_utf8_lossy(value)
+        .trim_matches(char::from(0))
+        .trim()
+        .to_string();
+    (!text.is_empty()).then_some(text)
+}
+
+fn format_mac(bytes: &[u8]) -> String {
+    bytes
+        .iter()
+        .map(|b| format!("{:02x}", b))
+        .collect::<Vec<_>>()
+        .join(":")
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod capture {
+    use super::{parse_frame, CDP_MULTICAST, LLDP_MULTICAST};
+    use crate::api::models::{LinkNeighbor, NetworkError};
+    use std::{
+        ffi::CString,
+        io,
+        os::fd::{AsRawFd, FromRawFd, OwnedFd},
+        time::{Duration, Instant},
+    };
+
+    /// Blocks for up to `listen_ms` reading frames from `interface`.
+    pub(super) fn listen(
+        interface: &str,
+        listen_ms: u64,
+    ) -> Result<Option<LinkNeighbor>, NetworkError> {
+        let name = CString::new(interface)
+            .map_err(|_| NetworkError::UnknownError("Invalid interface name".to_string()))?;
+        // SAFETY: `name` is a valid null-terminated string.
+        let index = unsafe { libc::if_nametoindex(name.as_ptr()) };
+        if index == 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+
+        let protocol = (libc::ETH_P_ALL as u16).to_be();
+        // SAFETY: plain socket(2) call; the descriptor is owned below.
+        let fd = unsafe {
+            libc::socket(
+                libc::AF_PACKET,
+                libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+                i32::from(protocol),
+            )
+        };
+        if fd < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+        // SAFETY: `fd` is a freshly created, valid descriptor.
+        let socket = unsafe { OwnedFd::from_raw_fd(fd) };
+
+        // SAFETY: an all-zero sockaddr_ll is valid; the fields are set below.
+        let mut addr: libc::sockaddr_ll = unsafe { std::mem::zeroed() };
+        addr.sll_family = libc::AF_PACKET as u16;
+        addr.sll_protocol = protocol;
+        addr.sll_ifindex = index as i32;
+        // SAFETY: `addr` is a valid sockaddr_ll of the given size.
+        let bound = unsafe {
+            libc::bind(
+                socket.as_raw_fd(),
+                (&addr as *const libc::sockaddr_ll).cast(),
+                std::mem::size_of::<libc::sockaddr_ll>() as libc::socklen_t,
+            )
+        };
+        if bound < 0 {
+            return Err(io::Error::last_os_error().into());
+        }
+
+        // The NIC drops these link-local multicast frames unless asked not to.
+        for group in [LLDP_MULTICAST, CDP_MULTICAST] {
+            // SAFETY: an all-zero packet_mreq is valid; the fields are set below.
+            let mut mreq: libc::packet_mreq = unsafe { std::mem::zeroed() };
+            mreq.mr_ifindex = index as i32;
+            mreq.mr_type = libc::PACKET_MR_MULTICAST as u16;
+            mreq.mr_alen = 6;
+            mreq.mr_address[..6].copy_from_slice(&group);
+            // SAFETY: `mreq` is a valid packet_mreq of the given size.
+            unsafe {
+                libc::setsockopt(
+                    socket.as_raw_fd(),
+                    libc::SOL_PACKET,
+                    libc::PACKET_ADD_MEMBERSHIP,
+                    (&mreq as *const libc::packet_mreq).cast(),
+                    std::mem::size_of::<libc::packet_mreq>() as libc::socklen_t,
+                );
+            }
+        }
+
+        let deadline = Instant::now() + Duration::from_millis(listen_ms);
+        let mut frame = [0u8; 2048];
+        loop {
+            let remaining = deadline.saturating_duration_since(Instant::now());
+            if remaining.is_zero() {
+                return Ok(None);
+            }
+            let tv = libc::timeval {
+                tv_sec: remaining.as_secs() as libc::time_t,
+                tv_usec: remaining.subsec_micros().max(1) as libc::suseconds_t,
+            };
+            // SAFETY: `tv` is a valid timeval for SO_RCVTIMEO.
+            unsafe {
+                libc::setsockopt(
+                    socket.as_raw_fd(),
+                    libc::SOL_SOCKET,
+                    libc::SO_RCVTIMEO,
+                    (&tv as *const libc::timeval).cast(),
+                    std::mem::size_of::<libc::timeval>() as libc::socklen_t,
+                );
+            }
+            // SAFETY: `frame` is a valid, writable buffer of the given length.
+            let received = unsafe {
+                libc::recv(
+                    socket.as_raw_fd(),
+                    frame.as_mut_ptr().cast(),
+                    frame.len(),
+                    0,
+                )
+            };
+            if received < 0 {
+                let err = io::Error::last_os_error();
+                match err.kind() {
+                    io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => return Ok(None),
+                    io::ErrorKind::Interrupted => continue,
+                    _ => return Err(err.into()),
+                }
+            }
+            if let Some(neighbor) = parse_frame(&frame[..received as usize]) {
+                return Ok(Some(neighbor));
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn lldp_tlv(tlv_type: u16, value: &[u8]) -> Vec<u8> {
+        let header = (tlv_type << 9) | value.len() as u16;
+        [header.to_be_bytes().as_slice(), value].concat()
+    }
+
+    #[test]
+    fn test_lldp_and_cdp_frame_parsing() {
+        let mut frame = [
+            LLDP_MULTICAST.as_slice(),
+            &[0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+            &[0x88, 0xcc],
+        ]
+        .concat();
+        frame.extend(lldp_tlv(1, &[4, 0x00, 0x1b, 0x54, 0xaa, 0xbb, 0xcc]));
+        frame.extend(lldp_tlv(2, b"\x05Gi1/0/17"));
+        frame.extend(lldp_tlv(3, &[0x00, 0x78]));
+        frame.extend(lldp_tlv(4, b"Office 2.14"));
+        frame.extend(lldp_tlv(5, b"sw-floor2"));
+        frame.extend(lldp_tlv(8, &[5, 1, 10, 0, 2, 1, 2, 0, 0, 0, 1, 0]));
+        frame.extend(lldp_tlv(0, &[]));
+
+        let neighbor = parse_frame(&frame).unwrap();
+        assert_eq!(neighbor.protocol, NeighborProtocol::Lldp);
+        assert_eq!(neighbor.chassis_id.as_deref(), Some("00:1b:54:aa:bb:cc"));
+        assert_eq!(neighbor.port_id.as_deref(), Some("Gi1/0/17"));
+        assert_eq!(neighbor.port_description.as_deref(), Some("Office 2.14"));
+        assert_eq!(neighbor.system_name.as_deref(), Some("sw-floor2"));
+        assert_eq!(neighbor.management_address.as_deref(), Some("10.0.2.1"));
+
+        let mut cdp = [
+            CDP_MULTICAST.as_slice(),
+            &[0x00, 0x11, 0x22, 0x33, 0x44, 0x55],
+            &[0x00, 0x40],
+        ]
+        .concat();
+        cdp.extend(CDP_SNAP_HEADER);
+        cdp.extend([0x02, 0xb4, 0x00, 0x00]);
+        cdp.extend([0x00, 0x01, 0x00, 0x0c]);
+        cdp.extend(b"sw-core1");
+        cdp.extend([
+            0x00, 0x02, 0x00, 0x11, 0, 0, 0, 1, 0x01, 0x01, 0xcc, 0x00, 0x04, 10, 0, 0, 2,
+        ]);
+        cdp.extend([0x00, 0x03, 0x00, 0x0b]);
+        cdp.extend(b"Gi0/12\0");
+
+        let neighbor = parse_frame(&cdp).unwrap();
+        assert_eq!(neighbor.protocol, NeighborProtocol::Cdp);
+        assert_eq!(neighbor.system_name.as_deref(), Some("sw-core1"));
+        assert_eq!(neighbor.management_address.as_deref(), Some("10.0.0.2"));
+        assert_eq!(neighbor.port_id.as_deref(), Some("Gi0/12"));
+
+        assert!(parse_frame(&cdp[..20]).is_none());
+    }
+}
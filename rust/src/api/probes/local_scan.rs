@@ -0,0 +1,798 @@
+//! Discovery of devices on the local network.
+//!
+//! Every scan mode decides which addresses it may touch through a single
+//! [ScanScope], so allow and exclude lists hold no matter how a device is
+//! discovered.
+
+use super::{
+    arp::read_neighbor_table,
+    banner::read_banner,
+    capabilities::{detect_probe_capabilities, ping_fallback},
+    names::NameLookup,
+    oui::lookup_vendor,
+    qos::ping_marked,
+};
+use crate::{
+    api::{
+        constants::LibConstants,
+        models::{
+            ArpEntry, LocalDevice, LocalScanConfig, NetworkError, ProbeFallback, ScanEvent,
+            ScanMethod, ScanSummary,
+        },
+    },
+    frb_generated::StreamSink,
+};
+use flutter_rust_bridge::frb;
+use futures::{future::join_all, Stream, StreamExt};
+use ipnet::IpNet;
+use std::{
+    io::ErrorKind,
+    net::{IpAddr, SocketAddr},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+use tokio::{
+    net::TcpStream,
+    sync::Semaphore,
+    time::{timeout, MissedTickBehavior},
+};
+use tokio_util::sync::CancellationToken;
+
+/// Sweeps `config.subnet` with TCP connects to [LocalScanConfig::scan_port]
+/// (or every one of [LocalScanConfig::scan_ports]) and returns the devices
+/// that answered, with the ports they had open.
+///
+/// Up to [LocalScanConfig::max_concurrency] sockets are open at once. Every
+/// connect makes the OS resolve the host's MAC address, so the neighbor table
+/// read after the sweep names the MAC address (and its vendor) of each device
+/// on the same link. A subnet or range entry that cannot be parsed yields no
+/// devices: scanning a device the caller meant to exclude is worse than
+/// scanning nothing. Use [try_scan_local_network] to tell that apart from an
+/// empty network.
+pub async fn scan_local_network(config: LocalScanConfig) -> Vec<LocalDevice> {
+    try_scan_local_network(config).await.unwrap_or_default()
+}
+
+/// [scan_local_network], failing with [NetworkError::InvalidConfiguration]
+/// on an unparsable subnet or range entry, or a scope of more than
+/// [LocalScanConfig::max_hosts] hosts.
+pub async fn try_scan_local_network(
+    config: LocalScanConfig,
+) -> Result<Vec<LocalDevice>, NetworkError> {
+    scan_local_network_cancellable(config, CancellationToken::new()).await
+}
+
+/// [try_scan_local_network], stopping once `cancel` is cancelled. The
+/// devices found until then are returned; hosts still being probed are
+/// dropped, as are hostnames still being looked up.
+#[frb(ignore)]
+pub async fn scan_local_network_cancellable(
+    config: LocalScanConfig,
+    cancel: CancellationToken,
+) -> Result<Vec<LocalDevice>, NetworkError> {
+    let scope = ScanScope::from_config(&config)?;
+    let probe = HostProbe::from_config(&config);
+
+    let mut probes = futures::stream::iter(scope.hosts())
+        .map(|ip| {
+            let probe = probe.clone();
+            async move { (ip, probe.run(ip).await) }
+        })
+        .buffer_unordered(config.max_concurrency.max(1));
+
+    // Probes finish out of order; on cancellation the ones still running are
+    // dropped with the stream.
+    let mut found = Vec::new();
+    loop {
+        tokio::select! {
+            probe = probes.next() => match probe {
+                Some((ip, Some(device))) => found.push((ip, device)),
+                Some((_, None)) => {}
+                None => break,
+            },
+            _ = cancel.cancelled() => break,
+        }
+    }
+    drop(probes);
+    found.sort_by_key(|(ip, _)| *ip);
+    let mut devices: Vec<LocalDevice> = found.into_iter().map(|(_, device)| device).collect();
+    apply_neighbor_table(&mut devices, &read_neighbor_table().await);
+
+    if let Some(names) = NameLookup::from_config(&config).filter(|_| !cancel.is_cancelled()) {
+        tokio::select! {
+            _ = names.fill(&mut devices, config.hostname_lookup_concurrency) => {}
+            _ = cancel.cancelled() => {}
+        }
+    }
+    Ok(devices)
+}
+
+/// Sweeps like [scan_local_network_cancellable], but yields every device as
+/// soon as it answers instead of once the sweep is done.
+///
+/// Each device comes with its MAC address and vendor, and with its name if
+/// [LocalScanConfig::resolve_names] or [LocalScanConfig::lookup_hostnames]
+/// is set, so devices arrive in no
+/// particular order. In between, a [ScanEvent::Progress] reports the counts
+/// so far every
+/// [SCAN_PROGRESS_INTERVAL_MS](LibConstants::SCAN_PROGRESS_INTERVAL_MS). The
+/// last event is always [ScanEvent::Finished]; after `cancel` is cancelled it
+/// follows at once, marked as cancelled.
+#[frb(ignore)]
+pub fn scan_local_network_stream(
+    config: LocalScanConfig,
+    cancel: CancellationToken,
+) -> Result<impl Stream<Item = ScanEvent> + Send + 'static, NetworkError> {
+    let scope = ScanScope::from_config(&config)?;
+    let hosts: Vec<IpAddr> = scope.hosts().collect();
+    let hosts_scanned = hosts.len() as u32;
+    let start = Instant::now();
+
+    let probe = HostProbe::from_config(&config);
+    let fallback = probe.fallback;
+    let names = NameLookup::from_config(&config);
+
+    let neighbors = NeighborTable::default();
+    let devices = futures::stream::iter(hosts)
+        .map(move |ip| probe.clone().run(ip))
+        .buffer_unordered(config.max_concurrency.max(1))
+        .filter_map(|device| futures::future::ready(device.map(|d| (Instant::now(), d))))
+        .map(move |(found, mut device)| {
+            let neighbors = neighbors.clone();
+            async move {
+                // The connect just made the OS resolve the device's MAC address.
+                let table = neighbors.read_since(found).await;
+                apply_neighbor_table(std::slice::from_mut(&mut device), &table);
+                if let (Some(names), Ok(ip)) = (names, device.ip_address.parse()) {
+                    device.hostname = names.name(ip).await;
+                }
+                device
+            }
+        })
+        .buffer_unordered(config.hostname_lookup_concurrency.max(1));
+
+    let summary = move |devices_found, cancelled| ScanSummary {
+        hosts_scanned,
+        devices_found,
+        duration_ms: start.elapsed().as_millis() as u64,
+        cancelled,
+        fallback,
+    };
+    let period = Duration::from_millis(LibConstants::SCAN_PROGRESS_INTERVAL_MS);
+    let mut progress = tokio::time::interval_at((start + period).into(), period);
+    progress.set_missed_tick_behavior(MissedTickBehavior::Delay);
+    Ok(futures::stream::unfold(
+        Some((Box::pin(devices), 0, cancel, progress)),
+        move |state| async move {
+            let (mut devices, found, cancel, mut progress) = state?;
+            tokio::select! {
+                device = devices.next() => match device {
+                    Some(device) => Some((
+                        ScanEvent::Device(device),
+                        Some((devices, found + 1, cancel, progress)),
+                    )),
+                    None => Some((ScanEvent::Finished(summary(found, false)), None)),
+                },
+                _ = progress.tick() => Some((
+                    ScanEvent::Progress(summary(found, false)),
+                    Some((devices, found, cancel, progress)),
+                )),
+                _ = cancel.cancelled() => Some((ScanEvent::Finished(summary(found, true)), None)),
+            }
+        },
+    ))
+}
+
+/// Forwards [scan_local_network_stream] to Dart until the sweep ends or the
+/// Dart side cancels its subscription, which also stops the sweep.
+///
+/// A closed subscription is noticed when an event cannot be delivered; the
+/// [ScanEvent::Progress] events make sure that happens within
+/// [SCAN_PROGRESS_INTERVAL_MS](LibConstants::SCAN_PROGRESS_INTERVAL_MS) even
+/// while no device answers.
+pub async fn stream_local_network_scan(
+    config: LocalScanConfig,
+    sink: StreamSink<ScanEvent>,
+) -> Result<(), NetworkError> {
+    let cancel = CancellationToken::new();
+    let mut events = Box::pin(scan_local_network_stream(config, cancel.clone())?);
+    while let Some(event) = events.next().await {
+        if sink.add(event).is_err() {
+            cancel.cancel();
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// The neighbor table as last read during a streaming sweep, shared by its
+/// devices so that the table is read once per batch of answers rather than
+/// once per device.
+#[frb(ignore)]
+#[derive(Debug, Clone, Default)]
+struct NeighborTable(Arc<tokio::sync::Mutex<Option<NeighborRead>>>);
+
+/// When the neighbor table was read, and what it held.
+type NeighborRead = (Instant, Arc<[ArpEntry]>);
+
+impl NeighborTable {
+    /// The table as read no earlier than `since`, reading it again if the
+    /// last read is older.
+    async fn read_since(&self, since: Instant) -> Arc<[ArpEntry]> {
+        let mut last = self.0.lock().await;
+        if let Some((read_at, table)) = last.as_ref() {
+            if *read_at >= since {
+                return table.clone();
+            }
+        }
+        let read_at = Instant::now();
+        let table: Arc<[ArpEntry]> = read_neighbor_table().await.into();
+        *last = Some((read_at, table.clone()));
+        table
+    }
+}
+
+/// Fills the MAC address and vendor of every device found in `table`.
+fn apply_neighbor_table(devices: &mut [LocalDevice], table: &[ArpEntry]) {
+    for device in devices.iter_mut() {
+        let Some(entry) = table.iter().find(|e| e.ip_address == device.ip_address) else {
+            continue;
+        };
+        device.mac_vendor = lookup_vendor(&entry.mac_address).map(str::to_string);
+        device.mac_address = Some(entry.mac_address.clone());
+    }
+}
+
+/// How every host of a sweep is probed.
+#[derive(Debug, Clone)]
+struct HostProbe {
+    ports: Arc<[u16]>,
+    /// One permit per socket, shared by every host of the sweep.
+    sockets: Arc<Semaphore>,
+    /// Also send an ICMP echo request.
+    ping: bool,
+    /// Set if an ICMP sweep was asked for but only TCP connects are made.
+    fallback: Option<ProbeFallback>,
+    wait: Duration,
+    banner_wait: Option<Duration>,
+}
+
+impl HostProbe {
+    fn from_config(config: &LocalScanConfig) -> Self {
+        let mut ports = if config.scan_ports.is_empty() {
+            vec![config.scan_port]
+        } else {
+            config.scan_ports.clone()
+        };
+        ports.sort_unstable();
+        ports.dedup();
+        let fallback = match config.method {
+            ScanMethod::IcmpSweep => ping_fallback(&detect_probe_capabilities()),
+            ScanMethod::TcpConnect => None,
+        };
+        Self {
+            ports: ports.into(),
+            sockets: Arc::new(Semaphore::new(config.max_concurrency.max(1))),
+            ping: config.method == ScanMethod::IcmpSweep && fallback.is_none(),
+            fallback,
+            wait: Duration::from_millis(config.timeout_ms),
+            banner_wait: config
+                .grab_banners
+                .then(|| Duration::from_millis(config.banner_timeout_ms)),
+        }
+    }
+
+    /// Connects to every port of `ip` at once, and pings it meanwhile if
+    /// `ping` is set, as far as the sweep's socket permits allow. A refused
+    /// connection still proves the host is up.
+    ///
+    /// With `banner_wait` set, every open port is also asked for its banner
+    /// before its socket is released.
+    async fn run(self, ip: IpAddr) -> Option<LocalDevice> {
+        let start = Instant::now();
+        let sockets = &self.sockets;
+        let connects = self.ports.iter().map(|&port| async move {
+            let _permit = sockets.acquire().await.ok()?;
+            let mut stream =
+                match timeout(self.wait, TcpStream::connect(SocketAddr::new(ip, port))).await {
+                    Ok(Ok(stream)) => Some(stream),
+                    Ok(Err(e)) if e.kind() == ErrorKind::ConnectionRefused => None,
+                    _ => return None,
+                };
+            let rtt = start.elapsed();
+            let service = match (&mut stream, self.banner_wait) {
+                (Some(stream), Some(wait)) => Some(read_banner(stream, port, wait).await),
+                _ => None,
+            };
+            Some((port, stream.is_some(), rtt, service))
+        });
+        let echo = async {
+            if !self.ping {
+                return None;
+            }
+            let _permit = sockets.acquire().await.ok()?;
+            match timeout(self.wait, ping_marked(ip, &[0u8; 8], None)).await {
+                Ok(Ok((_packet, rtt))) => Some(rtt),
+                _ => None,
+            }
+        };
+        let (answers, echo) = tokio::join!(join_all(connects), echo);
+        let answers: Vec<_> = answers.into_iter().flatten().collect();
+        let latency_ms = answers
+            .iter()
+            .map(|(_, _, rtt, _)| *rtt)
+            .chain(echo)
+            .min()?
+            .as_millis() as u64;
+
+        let open_ports = answers
+            .iter()
+            .filter(|(_, open, _, _)| *open)
+            .map(|(port, ..)| *port)
+            .collect();
+        let services = answers
+            .into_iter()
+            .filter_map(|(_, _, _, service)| service)
+            .collect();
+        Some(LocalDevice {
+            ip_address: ip.to_string(),
+            hostname: None,
+            mac_address: None,
+            mac_vendor: None,
+            latency_ms,
+            open_ports,
+            answered_ping: echo.is_some(),
+            services,
+        })
+    }
+}
+
+/// The set of addresses a scan may probe.
+#[derive(Debug, Clone)]
+pub(crate) struct ScanScope {
+    /// The permitted hosts of the subnet as disjoint ranges, in ascending
+    /// order, so that neither counting nor listing them walks the subnet.
+    hosts: Vec<IpRange>,
+}
+
+impl ScanScope {
+    /// Parses the subnet and range lists, naming the first invalid entry, and
+    /// rejects scopes of more than `config.max_hosts` hosts.
+    pub(crate) fn from_config(config: &LocalScanConfig) -> Result<Self, NetworkError> {
+        let invalid = |what: &str, entry: &str| {
+            NetworkError::InvalidConfiguration(format!("invalid {}: '{}'", what, entry))
+        };
+        let parse_all = |what: &str, entries: &[String]| -> Result<Vec<IpRange>, NetworkError> {
+            entries
+                .iter()
+                .map(|e| IpRange::parse(e).ok_or_else(|| invalid(what, e)))
+                .collect()
+        };
+        let subnet: IpNet = config
+            .subnet
+            .trim()
+            .parse()
+            .map_err(|_| invalid("subnet", &config.subnet))?;
+        let allow = parse_all("allow range", &config.allow_ranges)?;
+        let exclude = parse_all("exclude range", &config.exclude_ranges)?;
+        let scope = Self {
+            hosts: host_ranges(subnet, &allow, &exclude),
+        };
+        let limit = config.max_hosts;
+        let count = scope
+            .hosts
+            .iter()
+            .fold(0u128, |sum, r| sum.saturating_add(r.len()));
+        if count > limit as u128 {
+            return Err(NetworkError::InvalidConfiguration(format!(
+                "subnet '{}' covers more than {} hosts; narrow it with allow_ranges or raise max_hosts",
+                config.subnet, limit
+            )));
+        }
+        Ok(scope)
+    }
+
+    /// The permitted host addresses of the subnet, in ascending order.
+    pub(crate) fn hosts(&self) -> impl Iterator<Item = IpAddr> + '_ {
+        self.hosts.iter().flat_map(|r| {
+            let v4 = r.start.is_ipv4();
+            (to_bits(r.start)..=to_bits(r.end)).map(move |bits| from_bits(bits, v4))
+        })
+    }
+}
+
+/// The host addresses of `subnet` inside any of `allow` (all of them if
+/// empty) and outside all of `exclude`, as disjoint ascending ranges. Ranges
+/// of the other address family are ignored.
+fn host_ranges(subnet: IpNet, allow: &[IpRange], exclude: &[IpRange]) -> Vec<IpRange> {
+    let mut subnet_hosts = subnet.hosts();
+    let first = subnet_hosts.clone().next().unwrap_or(subnet.network());
+    let last = subnet_hosts.next_back().unwrap_or(first);
+    let v4 = first.is_ipv4();
+    let (low, high) = (to_bits(first), to_bits(last));
+    let same_family = |r: &&IpRange| r.start.is_ipv4() == v4;
+
+    let mut allowed: Vec<(u128, u128)> = if allow.is_empty() {
+        vec![(low, high)]
+    } else {
+        allow
+            .iter()
+            .filter(same_family)
+            .map(|r| (to_bits(r.start).max(low), to_bits(r.end).min(high)))
+            .filter(|(start, end)| start <= end)
+            .collect()
+    };
+    allowed.sort_unstable();
+    let mut ranges: Vec<(u128, u128)> = Vec::with_capacity(allowed.len());
+    for (start, end) in allowed {
+        match ranges.last_mut() {
+            Some(last) if start <= last.1.saturating_add(1) => last.1 = last.1.max(end),
+            _ => ranges.push((start, end)),
+        }
+    }
+
+    for range in exclude.iter().filter(same_family) {
+        let (cut_start, cut_end) = (to_bits(range.start), to_bits(range.end));
+        ranges = ranges
+            .into_iter()
+            .flat_map(|(start, end)| {
+                if cut_end < start || cut_start > end {
+                    return [Some((start, end)), None];
+                }
+                [
+                    (cut_start > start).then(|| (start, cut_start - 1)),
+                    (cut_end < end).then(|| (cut_end + 1, end)),
+                ]
+            })
+            .flatten()
+            .collect();
+    }
+
+    ranges
+        .into_iter()
+        .map(|(start, end)| IpRange {
+            start: from_bits(start, v4),
+            end: from_bits(end, v4),
+        })
+        .collect()
+}
+
+fn to_bits(ip: IpAddr) -> u128 {
+    match ip {
+        IpAddr::V4(ip) => u128::from(u32::from(ip)),
+        IpAddr::V6(ip) => u128::from(ip),
+    }
+}
+
+fn from_bits(bits: u128, v4: bool) -> IpAddr {
+    if v4 {
+        IpAddr::V4((bits as u32).into())
+    } else {
+        IpAddr::V6(bits.into())
+    }
+}
+
+/// An inclusive range of addresses of one family.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct IpRange {
+    start: IpAddr,
+    end: IpAddr,
+}
+
+impl IpRange {
+    /// Parses `a.b.c.d`, `a.b.c.d/n` or `a.b.c.d-e.f.g.h` (IPv6 alike).
+    pub(crate) fn parse(entry: &str) -> Option<Self> {
+        let entry = entry.trim();
+        let (start, end) = if let Some((start, end)) = entry.split_once('-') {
+            (start.trim().parse().ok()?, end.trim().parse().ok()?)
+        } else if let Ok(net) = entry.parse::<IpNet>() {
+            (net.network(), net.broadcast())
+        } else {
+            let ip = entry.parse().ok()?;
+            (ip, ip)
+        };
+        let same_family = matches!(
+            (start, end),
+            (IpAddr::V4(_), IpAddr::V4(_)) | (IpAddr::V6(_), IpAddr::V6(_))
+        );
+        (same_family && start <= end).then_some(Self { start, end })
+    }
+
+    /// Whether `ip` lies inside the range.
+    pub(crate) fn contains(&self, ip: IpAddr) -> bool {
+        self.start <= ip && ip <= self.end
+    }
+
+    /// The number of addresses in the range, saturating for a full IPv6 range.
+    fn len(&self) -> u128 {
+        (to_bits(self.end) - to_bits(self.start)).saturating_add(1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_scan_honors_allow_and_exclude_lists() {
+        let config = LocalScanConfig {
+            subnet: "10.0.0.0/24".into(),
+            allow_ranges: vec!["10.0.0.0/28".into(), "10.0.0.200-10.0.0.201".into()],
+            exclude_ranges: vec!["10.0.0.5".into(), "10.0.0.8/30".into()],
+            ..LocalScanConfig::default()
+        };
+        let scope = ScanScope::from_config(&config).unwrap();
+        let hosts: Vec<String> = scope.hosts().map(|ip| ip.to_string()).collect();
+        assert_eq!(
+            hosts,
+            [1, 2, 3, 4, 6, 7, 12, 13, 14, 15, 200, 201].map(|n| format!("10.0.0.{}", n))
+        );
+
+        let mut invalid = config.clone();
+        invalid.exclude_ranges.push("10.0.0.9-10.0.0.3".into());
+        assert!(matches!(
+            try_scan_local_network(invalid).await,
+            Err(NetworkError::InvalidConfiguration(e)) if e.contains("10.0.0.9-10.0.0.3")
+        ));
+
+        // A /16 is refused up front unless narrowed to max_hosts.
+        let mut wide = LocalScanConfig {
+            subnet: "10.1.0.0/16".into(),
+            ..LocalScanConfig::default()
+        };
+        assert!(matches!(
+            ScanScope::from_config(&wide),
+            Err(NetworkError::InvalidConfiguration(e)) if e.contains("max_hosts")
+        ));
+        wide.allow_ranges = vec!["10.1.4.0/22".into()];
+        assert_eq!(ScanScope::from_config(&wide).unwrap().hosts().count(), 1024);
+
+        // An IPv6 /64 narrowed to a few hosts is sized without walking it.
+        let mut v6 = LocalScanConfig {
+            subnet: "fd00::/64".into(),
+            ..LocalScanConfig::default()
+        };
+        assert!(ScanScope::from_config(&v6).is_err());
+        v6.allow_ranges = vec![
+            "fd00::10-fd00::1f".into(),
+            "fd00:1::/120".into(),
+            "10.0.0.1".into(),
+        ];
+        v6.exclude_ranges = vec!["fd00::18/126".into()];
+        let scope = ScanScope::from_config(&v6).unwrap();
+        let hosts: Vec<IpAddr> = scope.hosts().collect();
+        assert_eq!(hosts.len(), 12);
+        assert_eq!(hosts[0], "fd00::10".parse::<IpAddr>().unwrap());
+        assert_eq!(hosts[8], "fd00::1c".parse::<IpAddr>().unwrap());
+        assert_eq!(hosts[11], "fd00::1f".parse::<IpAddr>().unwrap());
+
+        // Loopback refuses the connection at once, which proves liveness.
+        let devices = scan_local_network(LocalScanConfig {
+            subnet: "127.0.0.0/30".into(),
+            scan_port: 9,
+            max_concurrency: 1,
+            exclude_ranges: vec!["127.0.0.2".into()],
+            ..LocalScanConfig::default()
+        })
+        .await;
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].ip_address, "127.0.0.1");
+
+        // A cancelled scan returns at once instead of waiting out the timeout.
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let scan = scan_local_network_cancellable(
+            LocalScanConfig {
+                subnet: "10.255.0.0/24".into(),
+                timeout_ms: 5000,
+                ..LocalScanConfig::default()
+            },
+            cancel,
+        );
+        let devices = timeout(Duration::from_secs(1), scan)
+            .await
+            .unwrap()
+            .unwrap();
+        assert!(devices.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_scan_records_open_ports_and_their_services() {
+        use crate::api::testsupport::TestServer;
+
+        let server = TestServer::tcp_echo().start().await.unwrap();
+        let port = server.addr().port();
+        let devices = try_scan_local_network(LocalScanConfig {
+            subnet: "127.0.0.0/30".into(),
+            allow_ranges: vec!["127.0.0.1".into()],
+            scan_ports: vec![port, 9, port],
+            // A single socket at a time still covers every port.
+            max_concurrency: 1,
+            grab_banners: true,
+            resolve_names: false,
+            ..LocalScanConfig::default()
+        })
+        .await
+        .unwrap();
+
+        assert_eq!(devices.len(), 1);
+        assert_eq!(devices[0].open_ports, [port]);
+        // The echo server repeats the HTTP request it is sent.
+        assert_eq!(devices[0].services.len(), 1);
+        assert_eq!(devices[0].services[0].port, port);
+        assert_eq!(
+            devices[0].services[0].banner.as_deref(),
+            Some("HEAD / HTTP/1.0")
+        );
+    }
+
+    #[tokio::test]
+    async fn test_icmp_sweep_merges_with_tcp_connects() {
+        let config = LocalScanConfig {
+            subnet: "127.0.0.0/30".into(),
+            allow_ranges: vec!["127.0.0.1".into()],
+            scan_port: 9,
+            resolve_names: false,
+            ..LocalScanConfig::default()
+        };
+        let tcp = try_scan_local_network(config.clone()).await.unwrap();
+        assert_eq!(tcp.len(), 1);
+        assert!(!tcp[0].answered_ping);
+
+        let sweep = try_scan_local_network(LocalScanConfig {
+            method: ScanMethod::IcmpSweep,
+            ..config.clone()
+        })
+        .await
+        .unwrap();
+        // Without ICMP sockets the sweep falls back to the TCP connects.
+        assert_eq!(sweep.len(), 1);
+        assert_eq!(
+            sweep[0].answered_ping,
+            detect_probe_capabilities().icmp_echo
+        );
+
+        // The streaming sweep says which method it really used.
+        let events: Vec<ScanEvent> = scan_local_network_stream(
+            LocalScanConfig {
+                method: ScanMethod::IcmpSweep,
+                ..config.clone()
+            },
+            CancellationToken::new(),
+        )
+        .unwrap()
+        .collect()
+        .await;
+        let Some(ScanEvent::Finished(summary)) = events.last() else {
+            panic!("{:?}", events);
+        };
+        assert_eq!(
+            summary.fallback,
+            (!detect_probe_capabilities().icmp_echo).then_some(ProbeFallback::TcpPing)
+        );
+        let events: Vec<ScanEvent> = scan_local_network_stream(config, CancellationToken::new())
+            .unwrap()
+            .collect()
+            .await;
+        assert!(matches!(
+            events.last(),
+            Some(ScanEvent::Finished(ScanSummary { fallback: None, .. }))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_scan_stream_yields_devices_then_summary() {
+        let config = LocalScanConfig {
+            subnet: "127.0.0.0/29".into(),
+            scan_port: 9,
+            allow_ranges: vec!["127.0.0.1-127.0.0.3".into()],
+            resolve_names: false,
+            ..LocalScanConfig::default()
+        };
+        let events: Vec<ScanEvent> = scan_local_network_stream(config, CancellationToken::new())
+            .unwrap()
+            .collect()
+            .await;
+
+        let (last, devices) = events.split_last().unwrap();
+        assert_eq!(devices.len(), 3);
+        assert!(devices.iter().all(|e| matches!(e, ScanEvent::Device(_))));
+        assert!(matches!(
+            last,
+            ScanEvent::Finished(ScanSummary {
+                hosts_scanned: 3,
+                devices_found: 3,
+                cancelled: false,
+                ..
+            })
+        ));
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let config = LocalScanConfig {
+            subnet: "10.255.0.0/24".into(),
+            timeout_ms: 5000,
+            ..LocalScanConfig::default()
+        };
+        let events: Vec<ScanEvent> = scan_local_network_stream(config, cancel)
+            .unwrap()
+            .collect()
+            .await;
+        assert!(matches!(
+            events[..],
+            [ScanEvent::Finished(ScanSummary {
+                devices_found: 0,
+                cancelled: true,
+                ..
+            })]
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_scan_stream_reports_progress_while_devices_stall() {
+        // The server accepts but never greets, so the banner read holds the
+        // only device back past the first progress event.
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let mut held = Vec::new();
+            while let Ok((socket, _)) = listener.accept().await {
+                held.push(socket);
+            }
+        });
+        let config = LocalScanConfig {
+            subnet: "127.0.0.1/32".into(),
+            scan_port: port,
+            grab_banners: true,
+            banner_timeout_ms: 10_000,
+            ..LocalScanConfig::default()
+        };
+        let cancel = CancellationToken::new();
+        let mut events = Box::pin(scan_local_network_stream(config, cancel.clone()).unwrap());
+
+        let first = timeout(
+            Duration::from_millis(LibConstants::SCAN_PROGRESS_INTERVAL_MS * 3),
+            events.next(),
+        )
+        .await
+        .unwrap();
+        assert!(matches!(
+            first,
+            Some(ScanEvent::Progress(ScanSummary {
+                hosts_scanned: 1,
+                devices_found: 0,
+                cancelled: false,
+                ..
+            }))
+        ));
+
+        // What the Dart forwarder does once its sink is closed.
+        cancel.cancel();
+        assert!(matches!(
+            events.next().await,
+            Some(ScanEvent::Finished(ScanSummary {
+                cancelled: true,
+                ..
+            }))
+        ));
+        assert!(events.next().await.is_none());
+    }
+
+    #[test]
+    fn test_apply_neighbor_table() {
+        let mut devices = vec![
+            LocalDevice::fixture("192.168.1.1"),
+            LocalDevice::fixture("192.168.1.7"),
+        ];
+        let table = vec![ArpEntry {
+            ip_address: "192.168.1.1".into(),
+            mac_address: "c0:25:06:aa:bb:cc".into(),
+            interface: Some("wlan0".into()),
+        }];
+        apply_neighbor_table(&mut devices, &table);
+
+        assert_eq!(devices[0].mac_address.as_deref(), Some("c0:25:06:aa:bb:cc"));
+        assert_eq!(devices[0].mac_vendor.as_deref(), Some("AVM"));
+        assert_eq!(devices[1].mac_address, None);
+    }
+}
@@ -0,0 +1,549 @@
+//! mDNS/DNS-SD service discovery (RFC 6762, RFC 6763).
+//!
+//! Printers, Chromecasts, AirPlay speakers and HomeKit accessories announce
+//! what they offer over multicast DNS rather than on a well-known port, so a
+//! TCP sweep finds the device but not its services. Browsing asks the mDNS
+//! group which service types exist (`_services._dns-sd._udp.local`), then for
+//! the instances of every type found, and collects the SRV, TXT and address
+//! records responders attach to their answers, asking for whatever they left
+//! out.
+//!
+//! Needs working LAN multicast (see [super::multicast]); Android apps must
+//! hold a `WifiManager.MulticastLock` to receive the answers.
+
+use super::{
+    local_scan::IpRange,
+    multicast::{mdns_query, mdns_query_labels, mdns_socket, DNS_SD_SERVICES, MDNS_GROUP},
+    parse::Reader,
+    udp::{read_dns_labels, DNS_TYPE_A},
+};
+use crate::api::{
+    constants::LibConstants,
+    models::{MdnsService, NetworkError},
+};
+use flutter_rust_bridge::frb;
+use std::{
+    collections::{BTreeMap, HashMap, HashSet},
+    fmt, io,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    time::Duration,
+};
+use tokio::time::{timeout_at, Instant};
+
+const QTYPE_PTR: u16 = 12;
+const QTYPE_TXT: u16 = 16;
+const QTYPE_AAAA: u16 = 28;
+const QTYPE_SRV: u16 = 33;
+const QTYPE_ANY: u16 = 255;
+
+/// Browses the local network for `duration_ms` (0 uses
+/// [DEFAULT_MDNS_BROWSE_MS](LibConstants::DEFAULT_MDNS_BROWSE_MS)) and
+/// returns every service announced, sorted by type and instance name.
+///
+/// `exclude_ranges` takes the entries of
+/// [LocalScanConfig::exclude_ranges](crate::api::models::LocalScanConfig::exclude_ranges):
+/// announcements from those addresses are ignored, and services on a host
+/// with an address inside them are neither asked about nor returned. The
+/// queries themselves go to the mDNS group, which excluded devices still
+/// receive.
+///
+/// Fails with [NetworkError::InvalidConfiguration] on an unparsable range,
+/// and otherwise only if the mDNS group cannot be joined or queried.
+pub async fn browse_mdns_services(
+    duration_ms: u64,
+    exclude_ranges: Vec<String>,
+) -> Result<Vec<MdnsService>, NetworkError> {
+    let exclude = exclude_ranges
+        .iter()
+        .map(|entry| {
+            IpRange::parse(entry).ok_or_else(|| {
+                NetworkError::InvalidConfiguration(format!("invalid exclude range: '{}'", entry))
+            })
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+    let wait = Duration::from_millis(if duration_ms == 0 {
+        LibConstants::DEFAULT_MDNS_BROWSE_MS
+    } else {
+        duration_ms
+    });
+    let socket_error = |e: io::Error| NetworkError::ConnectionError(format!("mDNS: {}", e));
+    let socket = mdns_socket().map_err(socket_error)?;
+    socket
+        .send_to(&mdns_query(DNS_SD_SERVICES, QTYPE_PTR), MDNS_GROUP)
+        .await
+        .map_err(socket_error)?;
+
+    let mut browser = Browser::new(exclude);
+    let deadline = Instant::now() + wait;
+    // mDNS messages may fill a jumbo frame.
+    let mut buf = vec![0u8; 9000];
+    while let Ok(received) = timeout_at(deadline, socket.recv_from(&mut buf)).await {
+        // A failed read, such as an ICMP error for an earlier send, only
+        // costs that datagram.
+        let Ok((len, from)) = received else {
+            continue;
+        };
+        let Some(records) = parse_response(&buf[..len]) else {
+            continue;
+        };
+        for (name, qtype) in browser.absorb(records, from.ip()) {
+            // A lost query only costs the answers to it.
+            let _ = socket.send_to(&name.query(qtype), MDNS_GROUP).await;
+        }
+    }
+    Ok(browser.services())
+}
+
+/// A DNS name as the labels it was sent as. An instance name may itself
+/// contain dots (`Dr. Who._ipp._tcp.local`), which the dotted form of the
+/// name cannot tell apart from those between labels.
+#[derive(Debug, Clone, PartialEq, Eq, Hash, PartialOrd, Ord)]
+pub(crate) struct Name(Vec<Vec<u8>>);
+
+impl Name {
+    /// Reads the name at `offset` of `message`, as [read_dns_labels].
+    fn read(message: &[u8], offset: usize) -> Option<(Self, usize)> {
+        let (labels, end) = read_dns_labels(message, offset)?;
+        Some((Self(labels.into_iter().map(<[u8]>::to_vec).collect()), end))
+    }
+
+    /// The name spelled `name`, whose labels contain no dots.
+    fn dotted(name: &str) -> Self {
+        Self(name.split('.').map(|l| l.as_bytes().to_vec()).collect())
+    }
+
+    /// The name lowercased, as DNS names are compared case-insensitively.
+    fn key(&self) -> Self {
+        Self(self.0.iter().map(|l| l.to_ascii_lowercase()).collect())
+    }
+
+    /// An mDNS query for this name.
+    fn query(&self, qtype: u16) -> Vec<u8> {
+        mdns_query_labels(self.0.iter().map(Vec::as_slice), qtype)
+    }
+
+    /// Service types start with an underscore; reverse lookups
+    /// (`in-addr.arpa`) do not.
+    fn is_service_type(&self) -> bool {
+        self.0.first().is_some_and(|l| l.starts_with(b"_"))
+    }
+
+    /// `Living Room TV` for `Living Room TV._googlecast._tcp.local` under
+    /// `_googlecast._tcp.local`; the whole name if it is not directly under
+    /// `parent`.
+    fn label_under(&self, parent: &Name) -> String {
+        match self.0.split_first() {
+            Some((first, rest)) if Self(rest.to_vec()).key() == parent.key() => {
+                String::from_utf8_lossy(first).into_owned()
+            }
+            _ => self.to_string(),
+        }
+    }
+}
+
+impl fmt::Display for Name {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (i, label) in self.0.iter().enumerate() {
+            if i > 0 {
+                f.write_str(".")?;
+            }
+            f.write_str(&String::from_utf8_lossy(label))?;
+        }
+        Ok(())
+    }
+}
+
+/// A resource record of interest in an mDNS response.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum Record {
+    Ptr { name: Name, target: Name },
+    Srv { name: Name, target: Name, port: u16 },
+    Txt { name: Name, entries: Vec<String> },
+    Address { name: Name, ip: IpAddr },
+}
+
+/// The records of an mDNS response, answers and additional records alike.
+///
+/// `None` for a query. Goodbyes (TTL 0) are skipped, as is everything after
+/// a malformed record.
+pub(crate) fn parse_response(message: &[u8]) -> Option<Vec<Record>> {
+    let mut header = Reader::new(message);
+    header.skip(2)?;
+    if header.u8()? & 0x80 == 0 {
+        return None;
+    }
+    header.skip(1)?;
+    let questions = header.u16_be()?;
+    let mut count = 0u32;
+    for _ in 0..3 {
+        count += u32::from(header.u16_be()?);
+    }
+    let mut offset = header.position();
+    for _ in 0..questions {
+        offset = read_dns_labels(message, offset)?.1 + 4;
+    }
+
+    let mut records = Vec::new();
+    for _ in 0..count {
+        let Some((record, next)) = parse_record(message, offset) else {
+            break;
+        };
+        records.extend(record);
+        offset = next;
+    }
+    Some(records)
+}
+
+/// The record at `offset`, if of interest, and the offset of the next one.
+fn parse_record(message: &[u8], offset: usize) -> Option<(Option<Record>, usize)> {
+    let (name, after_name) = Name::read(message, offset)?;
+    let mut fields = Reader::at(message, after_name);
+    let rtype = fields.u16_be()?;
+    // The class, whose top bit is the cache-flush flag.
+    fields.skip(2)?;
+    let ttl = fields.u32_be()?;
+    let len = usize::from(fields.u16_be()?);
+    let rdata_at = fields.position();
+    let rdata = fields.bytes(len)?;
+    let record = (ttl != 0)
+        .then(|| parse_rdata(message, rtype, name, rdata_at, rdata))
+        .flatten();
+    Some((record, fields.position()))
+}
+
+/// Decodes the data of a record of type `rtype`, found at `rdata_at`. Names
+/// inside it may point anywhere into `message`.
+fn parse_rdata(
+    message: &[u8],
+    rtype: u16,
+    name: Name,
+    rdata_at: usize,
+    rdata: &[u8],
+) -> Option<Record> {
+    match rtype {
+        QTYPE_PTR => {
+            let (target, _) = Name::read(message, rdata_at)?;
+            Some(Record::Ptr { name, target })
+        }
+        QTYPE_SRV => {
+            // Priority and weight precede the port.
+            let port = Reader::at(rdata, 4).u16_be()?;
+            let (target, _) = Name::read(message, rdata_at + 6)?;
+            Some(Record::Srv { name, target, port })
+        }
+        QTYPE_TXT => {
+            let mut strings = Reader::new(rdata);
+            let mut entries = Vec::new();
+            while let Some(len) = strings.u8() {
+                let entry = strings.bytes(usize::from(len))?;
+                if !entry.is_empty() {
+                    entries.push(String::from_utf8_lossy(entry).into_owned());
+                }
+            }
+            Some(Record::Txt { name, entries })
+        }
+        DNS_TYPE_A => {
+            let ip = IpAddr::V4(Ipv4Addr::from(<[u8; 4]>::try_from(rdata).ok()?));
+            Some(Record::Address { name, ip })
+        }
+        QTYPE_AAAA => {
+            let ip = IpAddr::V6(Ipv6Addr::from(<[u8; 16]>::try_from(rdata).ok()?));
+            Some(Record::Address { name, ip })
+        }
+        _ => None,
+    }
+}
+
+/// What the responses so far announced. Maps are keyed by [Name::key].
+#[frb(ignore)]
+#[derive(Debug, Default)]
+struct Browser {
+    /// Addresses whose announcements are ignored.
+    exclude: Vec<IpRange>,
+    /// Service types already asked for.
+    types: HashSet<Name>,
+    /// Instance → (instance name, service type).
+    instances: BTreeMap<Name, (Name, Name)>,
+    srv: HashMap<Name, (Name, u16)>,
+    txt: HashMap<Name, Vec<String>>,
+    addresses: HashMap<Name, Vec<IpAddr>>,
+    /// Hosts with an address inside `exclude`.
+    excluded_hosts: HashSet<Name>,
+    /// The address each instance was first announced from.
+    origins: HashMap<Name, IpAddr>,
+    /// Follow-up queries already sent.
+    asked: HashSet<(Name, u16)>,
+}
+
+impl Browser {
+    fn new(exclude: Vec<IpRange>) -> Self {
+        Self {
+            exclude,
+            ..Self::default()
+        }
+    }
+
+    fn is_excluded(&self, ip: IpAddr) -> bool {
+        self.exclude.iter().any(|range| range.contains(ip))
+    }
+
+    /// Whether the instance `key` runs on an excluded host.
+    fn on_excluded_host(&self, key: &Name) -> bool {
+        self.srv
+            .get(key)
+            .is_some_and(|(host, _)| self.excluded_hosts.contains(&host.key()))
+    }
+
+    /// Takes in the records of a response from `from` and returns the
+    /// queries still needed: the instances of new service types, and the
+    /// details of instances and hosts their responder left out.
+    fn absorb(&mut self, records: Vec<Record>, from: IpAddr) -> Vec<(Name, u16)> {
+        if self.is_excluded(from) {
+            return Vec::new();
+        }
+        let services = Name::dotted(DNS_SD_SERVICES);
+        let mut queries = Vec::new();
+        for record in records {
+            match record {
+                Record::Ptr { name, target } if name.key() == services => {
+                    if self.types.insert(target.key()) {
+                        queries.push((target, QTYPE_PTR));
+                    }
+                }
+                Record::Ptr { name, target } if name.is_service_type() => {
+                    let key = target.key();
+                    self.origins.entry(key.clone()).or_insert(from);
+                    self.instances.entry(key).or_insert((target, name));
+                }
+                Record::Ptr { .. } => {}
+                Record::Srv { name, target, port } => {
+                    self.srv.insert(name.key(), (target, port));
+                }
+                Record::Txt { name, entries } => {
+                    self.txt.insert(name.key(), entries);
+                }
+                Record::Address { name, ip } if self.is_excluded(ip) => {
+                    self.excluded_hosts.insert(name.key());
+                }
+                Record::Address { name, ip } => {
+                    let known = self.addresses.entry(name.key()).or_default();
+                    if !known.contains(&ip) {
+                        known.push(ip);
+                    }
+                }
+            }
+        }
+
+        for (key, (name, _)) in &self.instances {
+            if self.on_excluded_host(key) {
+                continue;
+            }
+            match self.srv.get(key) {
+                None => queries.push((name.clone(), QTYPE_ANY)),
+                Some((host, _)) if !self.addresses.contains_key(&host.key()) => {
+                    queries.push((host.clone(), DNS_TYPE_A))
+                }
+                Some(_) => {}
+            }
+        }
+        queries.retain(|(name, qtype)| self.asked.insert((name.key(), *qtype)));
+        queries
+    }
+
+    fn services(self) -> Vec<MdnsService> {
+        let mut services: Vec<MdnsService> = self
+            .instances
+            .iter()
+            .filter(|(key, _)| !self.on_excluded_host(key))
+            .map(|(key, (name, service_type))| {
+                let srv = self.srv.get(key);
+                let mut ips = srv
+                    .and_then(|(host, _)| self.addresses.get(&host.key()))
+                    .cloned()
+                    .unwrap_or_default();
+                if ips.is_empty() {
+                    ips.extend(self.origins.get(key));
+                }
+                MdnsService {
+                    instance_name: name.label_under(service_type),
+                    service_type: service_type.to_string(),
+                    hostname: srv.map(|(host, _)| host.to_string()),
+                    port: srv.map(|(_, port)| *port),
+                    ip_addresses: ips.iter().map(IpAddr::to_string).collect(),
+                    txt_records: self.txt.get(key).cloned().unwrap_or_default(),
+                }
+            })
+            .collect();
+        services.sort_by(|a, b| {
+            (&a.service_type, &a.instance_name).cmp(&(&b.service_type, &b.instance_name))
+        });
+        services
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Appends an uncompressed name.
+    fn push_name(out: &mut Vec<u8>, name: &str) {
+        for label in name.split('.') {
+            out.push(label.len() as u8);
+            out.extend_from_slice(label.as_bytes());
+        }
+        out.push(0);
+    }
+
+    fn push_record(out: &mut Vec<u8>, name: &str, rtype: u16, ttl: u32, rdata: &[u8]) {
+        push_name(out, name);
+        out.extend_from_slice(&rtype.to_be_bytes());
+        out.extend_from_slice(&0x8001u16.to_be_bytes());
+        out.extend_from_slice(&ttl.to_be_bytes());
+        out.extend_from_slice(&(rdata.len() as u16).to_be_bytes());
+        out.extend_from_slice(rdata);
+    }
+
+    fn name(name: &str) -> Vec<u8> {
+        let mut out = Vec::new();
+        push_name(&mut out, name);
+        out
+    }
+
+    #[test]
+    fn test_browse_assembles_services_from_records() {
+        let tv = "Living Room TV._googlecast._tcp.local";
+        let from: IpAddr = "192.168.1.30".parse().unwrap();
+        let mut browser = Browser::default();
+
+        // The answer to the service type enumeration.
+        let mut types = vec![0, 0, 0x84, 0, 0, 0, 0, 1, 0, 0, 0, 0];
+        push_record(
+            &mut types,
+            DNS_SD_SERVICES,
+            QTYPE_PTR,
+            4500,
+            &name("_googlecast._tcp.local"),
+        );
+        let records = parse_response(&types).unwrap();
+        assert_eq!(
+            browser.absorb(records, from),
+            [(Name::dotted("_googlecast._tcp.local"), QTYPE_PTR)]
+        );
+        assert_eq!(
+            parse_response(&mdns_query(DNS_SD_SERVICES, QTYPE_PTR)),
+            None
+        );
+
+        // The instance, with SRV and TXT but no address, and a goodbye.
+        let mut instance = vec![0, 0, 0x84, 0, 0, 0, 0, 2, 0, 0, 0, 2];
+        push_record(
+            &mut instance,
+            "_googlecast._tcp.local",
+            QTYPE_PTR,
+            120,
+            &name(tv),
+        );
+        push_record(
+            &mut instance,
+            "_googlecast._tcp.local",
+            QTYPE_PTR,
+            0,
+            &name("Old._googlecast._tcp.local"),
+        );
+        let mut srv = vec![0, 0, 0, 0, 0x1f, 0x49];
+        srv.extend(name("Chromecast-1a2b.local"));
+        push_record(&mut instance, tv, QTYPE_SRV, 120, &srv);
+        push_record(
+            &mut instance,
+            tv,
+            QTYPE_TXT,
+            4500,
+            b"\x0dmd=Chromecast\x00\x04rs=1",
+        );
+        let records = parse_response(&instance).unwrap();
+        assert_eq!(records.len(), 3);
+        assert_eq!(
+            browser.absorb(records.clone(), from),
+            [(Name::dotted("Chromecast-1a2b.local"), DNS_TYPE_A)]
+        );
+        // Asked once only.
+        assert_eq!(browser.absorb(records, from), []);
+
+        let services = browser.services();
+        assert_eq!(
+            services,
+            [MdnsService {
+                instance_name: "Living Room TV".into(),
+                service_type: "_googlecast._tcp.local".into(),
+                hostname: Some("Chromecast-1a2b.local".into()),
+                port: Some(8009),
+                ip_addresses: vec!["192.168.1.30".into()],
+                txt_records: vec!["md=Chromecast".into(), "rs=1".into()],
+            }]
+        );
+    }
+
+    #[test]
+    fn test_browse_keeps_dotted_labels_and_skips_excluded_hosts() {
+        let service_type = Name::dotted("_ipp._tcp.local");
+        let mut printer_name = vec![7];
+        printer_name.extend_from_slice(b"Dr. Who");
+        printer_name.extend(name("_ipp._tcp.local"));
+        let nas = "NAS._ipp._tcp.local";
+        let exclude = vec![IpRange::parse("192.168.1.50").unwrap()];
+        let mut browser = Browser::new(exclude);
+
+        let mut message = vec![0, 0, 0x84, 0, 0, 0, 0, 2, 0, 0, 0, 4];
+        push_record(
+            &mut message,
+            "_ipp._tcp.local",
+            QTYPE_PTR,
+            120,
+            &printer_name,
+        );
+        push_record(&mut message, "_ipp._tcp.local", QTYPE_PTR, 120, &name(nas));
+        let mut srv = vec![0, 0, 0, 0, 0x02, 0x77];
+        srv.extend(name("printer.local"));
+        message.extend(&printer_name);
+        message.extend_from_slice(&QTYPE_SRV.to_be_bytes());
+        message.extend_from_slice(&[0x80, 1, 0, 0, 0, 120]);
+        message.extend_from_slice(&(srv.len() as u16).to_be_bytes());
+        message.extend(&srv);
+        let mut srv = vec![0, 0, 0, 0, 0x02, 0x77];
+        srv.extend(name("nas.local"));
+        push_record(&mut message, nas, QTYPE_SRV, 120, &srv);
+        push_record(
+            &mut message,
+            "printer.local",
+            DNS_TYPE_A,
+            120,
+            &[192, 168, 1, 40],
+        );
+        push_record(
+            &mut message,
+            "nas.local",
+            DNS_TYPE_A,
+            120,
+            &[192, 168, 1, 50],
+        );
+        let records = parse_response(&message).unwrap();
+        assert_eq!(browser.absorb(records, "192.168.1.40".parse().unwrap()), []);
+        // Announcements from an excluded address are dropped outright.
+        let mut other = vec![0, 0, 0x84, 0, 0, 0, 0, 1, 0, 0, 0, 0];
+        push_record(
+            &mut other,
+            "_ipp._tcp.local",
+            QTYPE_PTR,
+            120,
+            &name("Other._ipp._tcp.local"),
+        );
+        let records = parse_response(&other).unwrap();
+        assert_eq!(browser.absorb(records, "192.168.1.50".parse().unwrap()), []);
+
+        let services = browser.services();
+        assert_eq!(services.len(), 1);
+        assert_eq!(services[0].instance_name, "Dr. Who");
+        assert_eq!(services[0].service_type, service_type.to_string());
+        assert_eq!(services[0].ip_addresses, ["192.168.1.40"]);
+    }
+}
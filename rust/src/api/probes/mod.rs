@@ -1,19 +1,123 @@
 //! Functions for performing individual network checks (probes).
 
+/// OS path data injected by the host on macOS/iOS.
+pub mod apple_path;
+/// Access to the OS neighbor (ARP) table.
+pub mod arp;
+/// Service identification from the banners of open ports.
+pub mod banner;
 /// The base trait for all network probes.
 pub mod base;
+/// Broadcast UDP probes for discovery protocols.
+pub mod broadcast;
+/// Detection of the raw-socket probe modes the process may use.
+pub mod capabilities;
 /// Probes for detecting captive portals (login pages).
 pub mod captive_portal;
 /// Probes for DNS integrity and hijacking detection.
 pub mod dns;
+/// ECN negotiation and bleaching detection.
+pub mod ecn;
+/// Container and WSL detection.
+pub mod environment;
+/// Default gateway discovery and LAN reachability probes.
+pub mod gateway;
+/// Reachability of self-hosted services from the LAN and the internet.
+pub mod hairpin;
 /// System-level network interface inspection.
 pub mod interface;
+/// ISP and downdetector-style status endpoint probes.
+pub mod isp_status;
+/// Passive LLDP/CDP neighbor discovery.
+pub mod lldp;
+/// Discovery of devices on the local network.
+pub mod local_scan;
+/// mDNS/DNS-SD service discovery.
+pub mod mdns;
+/// TCP MSS clamping and path encapsulation detection.
+pub mod mss;
+/// LAN multicast (mDNS group) functionality check.
+pub mod multicast;
+/// Device name resolution via LLMNR and NetBIOS.
+pub(crate) mod names;
+/// Kernel route lookups via netlink.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) mod netlink;
+/// Upstream/downstream delay estimation against timestamping echo servers.
+pub mod one_way_delay;
+/// Vendor lookup from MAC address prefixes.
+pub mod oui;
+/// Bounds-checked reading of untrusted input.
+pub(crate) mod parse;
+/// Per-interface path measurements.
+pub mod paths;
+/// Port forwarding verification via "check my port" web services.
+pub mod port_check;
+/// DSCP/QoS marking of probe packets.
+pub mod qos;
+/// NAT type and path inference via UDP reflection servers.
+pub mod reflection;
+/// Shared async DNS resolvers.
+pub(crate) mod resolver;
+/// Probing inside a network namespace or VRF.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub mod routing_domain;
+/// SNMP v2c queries against managed network devices.
+#[cfg(feature = "snmp")]
+pub mod snmp;
 /// Probes for individual target reachability.
 pub mod target;
+/// `TCP_INFO` of connected sockets.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub(crate) mod tcp_info;
+/// Hop-by-hop path discovery (traceroute).
+pub mod traceroute;
+/// The network primitives probes use, swappable for offline tests.
+pub mod transport;
+/// UDP probes with application-level reply validation.
+pub mod udp;
+/// UPnP device discovery and description parsing.
+pub(crate) mod upnp;
+/// What a captive portal's walled garden lets through.
+pub mod walled_garden;
 
 // Re-export public functions for easy access from the engine
-pub use captive_portal::{check_for_captive_portal, check_for_captive_portal_web};
-pub use dns::{detect_dns_hijacking, detect_dns_hijacking_web};
-pub use interface::{detect_security_and_network_type, detect_security_and_network_type_web};
+pub use arp::read_arp_table;
+pub use capabilities::detect_probe_capabilities;
+pub use captive_portal::{
+    check_for_captive_portal, check_for_captive_portal_at, check_for_captive_portal_web,
+    try_check_for_captive_portal, try_check_for_captive_portal_at, wait_for_portal_clearance,
+};
+pub use dns::{
+    assess_dns_hijacking, assess_dns_hijacking_with, detect_dns_hijacking,
+    detect_dns_hijacking_web, system_dns_resolvers,
+};
+pub use ecn::detect_ecn;
+pub use environment::detect_runtime_environment;
+pub use gateway::fingerprint_gateway;
+pub use hairpin::check_hairpin;
+pub use interface::{
+    detect_security_and_network_type, detect_security_and_network_type_of,
+    detect_security_and_network_type_towards, detect_security_and_network_type_web,
+    route_destination_for,
+};
+pub use isp_status::check_isp_status;
+pub use lldp::discover_link_neighbor;
+pub use local_scan::{
+    scan_local_network, scan_local_network_cancellable, scan_local_network_stream,
+    stream_local_network_scan, try_scan_local_network,
+};
+pub use mdns::browse_mdns_services;
+pub use mss::detect_mss_clamping;
+pub use multicast::check_multicast;
+pub use one_way_delay::estimate_one_way_delay;
+pub use paths::measure_paths;
+pub use port_check::verify_port_forwarding;
+pub use qos::compare_dscp_markings;
+pub use reflection::probe_nat;
+#[cfg(any(target_os = "linux", target_os = "android"))]
+pub use routing_domain::RoutingDomainTransport;
 pub use target::check_target;
-// pub use traceroute::trace_route;
+pub use traceroute::{trace_route, trace_route_cancellable, trace_routes};
+pub use transport::{with_transport, MockTransport, NetworkTransport, TokioTransport};
+pub use walled_garden::{probe_walled_garden, probe_walled_garden_with};
@@ -0,0 +1,182 @@
+//! TCP MSS clamping detection.
+//!
+//! Routers in front of PPPoE links and VPN tunnels often rewrite the MSS
+//! option of TCP handshakes ("MSS clamping") so segments fit the smaller
+//! MTU. A reduced MSS towards several unrelated servers therefore reveals the
+//! encapsulation, and explains throughput oddities such as stalls on large
+//! transfers when clamping is missing somewhere.
+//!
+//! The MSS is read from the kernel after connecting, which is only possible
+//! on Linux and Android; elsewhere every observation carries an error.
+
+use crate::api::{
+    constants::LibConstants,
+    models::{Encapsulation, MssObservation, MssReport},
+};
+use futures::future::join_all;
+use std::{net::SocketAddr, time::Duration};
+use tokio::{net::TcpStream, time::timeout};
+
+const IPV4_TCP_HEADERS: u16 = 40;
+const IPV6_TCP_HEADERS: u16 = 60;
+
+/// Connects to every server in `servers` (`host:port`; empty uses
+/// [DEFAULT_TCP_REFERENCE_SERVERS](LibConstants::DEFAULT_TCP_REFERENCE_SERVERS))
+/// and compares the negotiated MSS with the local path MTU.
+///
+/// `timeout_ms` of 0 uses [DEFAULT_MSS_TIMEOUT_MS](LibConstants::DEFAULT_MSS_TIMEOUT_MS).
+pub async fn detect_mss_clamping(servers: Vec<String>, timeout_ms: u64) -> MssReport {
+    let servers = if servers.is_empty() {
+        LibConstants::DEFAULT_TCP_REFERENCE_SERVERS
+            .iter()
+            .map(|s| s.to_string())
+            .collect()
+    } else {
+        servers
+    };
+    let wait = Duration::from_millis(if timeout_ms == 0 {
+        LibConstants::DEFAULT_MSS_TIMEOUT_MS
+    } else {
+        timeout_ms
+    });
+
+    let measured = join_all(servers.iter().map(|server| observe(server, wait))).await;
+    let ipv4 = measured
+        .iter()
+        .all(|(addr, _)| addr.is_none_or(|a| a.is_ipv4()));
+    summarize(measured.into_iter().map(|(_, o)| o).collect(), ipv4)
+}
+
+async fn observe(server: &str, wait: Duration) -> (Option<SocketAddr>, MssObservation) {
+    let mut observation = MssObservation {
+        server: server.to_string(),
+        mss: None,
+        path_mtu: None,
+        error: None,
+    };
+    let stream = match timeout(wait, TcpStream::connect(server)).await {
+        Ok(Ok(stream)) => stream,
+        Ok(Err(e)) => {
+            observation.error = Some(e.to_string());
+            return (None, observation);
+        }
+        Err(_) => {
+            observation.error = Some("Timeout Error".to_string());
+            return (None, observation);
+        }
+    };
+
+    let peer = stream.peer_addr().ok();
+    match segment_info(&stream) {
+        Ok((mss, path_mtu)) => {
+            observation.mss = Some(mss);
+            observation.path_mtu = path_mtu;
+        }
+        Err(e) => observation.error = Some(e.to_string()),
+    }
+    (peer, observation)
+}
+
+/// Combines the observations. `ipv4` selects the header size the MSS
+/// figures are compared against.
+fn summarize(observations: Vec<MssObservation>, ipv4: bool) -> MssReport {
+    let headers = if ipv4 {
+        IPV4_TCP_HEADERS
+    } else {
+        IPV6_TCP_HEADERS
+    };
+    let measured: Vec<&MssObservation> = observations.iter().filter(|o| o.mss.is_some()).collect();
+    let effective_mss = measured.iter().filter_map(|o| o.mss).max();
+
+    let clamping_detected = !measured.is_empty()
+        && measured.iter().all(|o| match (o.mss, o.path_mtu) {
+            (Some(mss), Some(mtu)) => mss < mtu.saturating_sub(headers),
+            _ => false,
+        });
+    let overhead_bytes =
+        effective_mss.map(|mss| (LibConstants::ETHERNET_MTU - headers).saturating_sub(mss));
+
+    MssReport {
+        encapsulation: overhead_bytes.map_or(Encapsulation::Unknown, classify_overhead),
+        observations,
+        effective_mss,
+        clamping_detected,
+        overhead_bytes,
+    }
+}
+
+fn classify_overhead(overhead: u16) -> Encapsulation {
+    match overhead {
+        0 => Encapsulation::None,
+        LibConstants::PPPOE_OVERHEAD_BYTES => Encapsulation::Pppoe,
+        o if o >= LibConstants::MIN_TUNNEL_OVERHEAD_BYTES => Encapsulation::Tunnel,
+        _ => Encapsulation::Unknown,
+    }
+}
+
+/// The peer-facing MSS and the path MTU of a connected socket.
+///
+/// `TCP_INFO` reports the send MSS net of TCP options; the 12 bytes of the
+/// timestamp option are added back so the value matches the MSS option of
+/// the handshake.
+#[cfg(any(target_os = "linux", target_os = "android"))]
+fn segment_info(stream: &TcpStream) -> std::io::Result<(u16, Option<u16>)> {
+    use super::tcp_info::{self, TCPI_OPT_TIMESTAMPS};
+    const TIMESTAMP_OPTION_BYTES: u32 = 12;
+
+    let info = tcp_info::read(stream)?;
+    let mut mss = info.snd_mss;
+    if info.options & TCPI_OPT_TIMESTAMPS != 0 {
+        mss += TIMESTAMP_OPTION_BYTES;
+    }
+    Ok((
+        u16::try_from(mss).unwrap_or(u16::MAX),
+        u16::try_from(info.pmtu).ok().filter(|&mtu| mtu > 0),
+    ))
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+fn segment_info(_stream: &TcpStream) -> std::io::Result<(u16, Option<u16>)> {
+    Err(std::io::Error::new(
+        std::io::ErrorKind::Unsupported,
+        "MSS measurement is only supported on Linux and Android",
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn observation(mss: Option<u16>) -> MssObservation {
+        MssObservation {
+            server: "test:443".into(),
+            mss,
+            path_mtu: Some(1500),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_summarize_detects_pppoe_clamping() {
+        let report = summarize(
+            vec![
+                observation(Some(1452)),
+                observation(Some(1400)),
+                observation(None),
+            ],
+            true,
+        );
+        assert!(report.clamping_detected);
+        assert_eq!(report.effective_mss, Some(1452));
+        assert_eq!(report.overhead_bytes, Some(8));
+        assert_eq!(report.encapsulation, Encapsulation::Pppoe);
+
+        // One full-size server rules out clamping on our side.
+        let report = summarize(vec![observation(Some(1460)), observation(Some(1380))], true);
+        assert!(!report.clamping_detected);
+        assert_eq!(report.encapsulation, Encapsulation::None);
+
+        assert_eq!(classify_overhead(80), Encapsulation::Tunnel);
+        assert_eq!(classify_overhead(3), Encapsulation::Unknown);
+    }
+}
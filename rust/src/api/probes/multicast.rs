@@ -0,0 +1,189 @@
+//! LAN multicast functionality check.
+//!
+//! Casting, printer discovery and smart-home pairing all rely on multicast,
+//! and when it breaks (IGMP snooping without a querier, "multicast
+//! optimisation" on Wi-Fi access points, client isolation) they fail
+//! silently. The check joins the mDNS group, sends a DNS-SD service
+//! enumeration query to it and listens for:
+//!
+//! - our own query, looped back by the local stack, which shows that the
+//!   join and send worked;
+//! - responses from other LAN hosts, which shows that multicast actually
+//!   crosses the network.
+
+use super::udp::build_dns_query_labels;
+use crate::api::{constants::LibConstants, models::MulticastReport};
+use socket2::{Domain, Protocol, Socket, Type};
+use std::{
+    io,
+    net::{IpAddr, Ipv4Addr, SocketAddr, SocketAddrV4},
+    time::Duration,
+};
+use tokio::{
+    net::UdpSocket,
+    time::{timeout_at, Instant},
+};
+
+pub(crate) const MDNS_GROUP: SocketAddrV4 = SocketAddrV4::new(Ipv4Addr::new(224, 0, 0, 251), 5353);
+pub(crate) const DNS_SD_SERVICES: &str = "_services._dns-sd._udp.local";
+const QTYPE_PTR: u16 = 12;
+
+/// Joins the mDNS group, queries it and listens for `timeout_ms` (0 uses
+/// [DEFAULT_MULTICAST_TIMEOUT_MS](LibConstants::DEFAULT_MULTICAST_TIMEOUT_MS)),
+/// stopping early once both our own query and a LAN response arrived.
+pub async fn check_multicast(timeout_ms: u64) -> MulticastReport {
+    let wait = Duration::from_millis(if timeout_ms == 0 {
+        LibConstants::DEFAULT_MULTICAST_TIMEOUT_MS
+    } else {
+        timeout_ms
+    });
+
+    let mut report = MulticastReport {
+        group: MDNS_GROUP.to_string(),
+        joined: false,
+        loopback_received: false,
+        responders: Vec::new(),
+        multicast_ok: None,
+        error: None,
+    };
+    if let Err(e) = exchange(MDNS_GROUP, wait, &mut report).await {
+        report.error = Some(e.to_string());
+    }
+    report.multicast_ok = verdict(&report);
+    report
+}
+
+async fn exchange(
+    group: SocketAddrV4,
+    wait: Duration,
+    report: &mut MulticastReport,
+) -> io::Result<()> {
+    let socket = mdns_socket()?;
+    report.joined = true;
+
+    let query = mdns_query(DNS_SD_SERVICES, QTYPE_PTR);
+    socket.send_to(&query, group).await?;
+
+    let local = local_addresses();
+    let deadline = Instant::now() + wait;
+    let mut buf = [0u8; 1500];
+    while let Ok(received) = timeout_at(deadline, socket.recv_from(&mut buf)).await {
+        let (len, from) = received?;
+        let message = &buf[..len];
+        let from_us = local.is_empty() || local.contains(&from.ip());
+        if message == query.as_slice() && from_us {
+            report.loopback_received = true;
+        } else if is_response(message) && !local.contains(&from.ip()) {
+            let responder = from.ip().to_string();
+            if !report.responders.contains(&responder) {
+                report.responders.push(responder);
+            }
+        }
+        if report.loopback_received && !report.responders.is_empty() {
+            break;
+        }
+    }
+    Ok(())
+}
+
+/// A socket on the mDNS port that joined the group, with our own queries
+/// looped back.
+pub(crate) fn mdns_socket() -> io::Result<UdpSocket> {
+    let socket = Socket::new(Domain::IPV4, Type::DGRAM, Some(Protocol::UDP))?;
+    // Shares the port with a system mDNS responder, if one runs.
+    socket.set_reuse_address(true)?;
+    socket.bind(&SocketAddr::from((Ipv4Addr::UNSPECIFIED, MDNS_GROUP.port())).into())?;
+    socket.join_multicast_v4(MDNS_GROUP.ip(), &Ipv4Addr::UNSPECIFIED)?;
+    socket.set_multicast_loop_v4(true)?;
+    socket.set_multicast_ttl_v4(255)?;
+    socket.set_nonblocking(true)?;
+    UdpSocket::from_std(socket.into())
+}
+
+/// An mDNS query for `name`; unlike unicast DNS it carries no flags.
+pub(crate) fn mdns_query(name: &str, qtype: u16) -> Vec<u8> {
+    let labels = name.split('.').filter(|l| !l.is_empty());
+    mdns_query_labels(labels.map(str::as_bytes), qtype)
+}
+
+/// [mdns_query] for the name made of `labels`.
+pub(crate) fn mdns_query_labels<'a>(
+    labels: impl IntoIterator<Item = &'a [u8]>,
+    qtype: u16,
+) -> Vec<u8> {
+    let mut query = build_dns_query_labels(0, labels, qtype);
+    query[2..4].fill(0);
+    query
+}
+
+/// Whether `message` is a DNS response (QR bit set).
+fn is_response(message: &[u8]) -> bool {
+    message.len() >= 12 && message[2] & 0x80 != 0
+}
+
+/// The IPv4 addresses of this host, to tell our own traffic apart.
+fn local_addresses() -> Vec<IpAddr> {
+    use network_interface::{Addr, NetworkInterface, NetworkInterfaceConfig};
+
+    NetworkInterface::show()
+        .unwrap_or_default()
+        .iter()
+        .flat_map(|iface| &iface.addr)
+        .filter_map(|addr| match addr {
+            Addr::V4(v4) => Some(IpAddr::V4(v4.ip)),
+            Addr::V6(_) => None,
+        })
+        .collect()
+}
+
+/// `Some(true)` once another host answered, `Some(false)` if not even our own
+/// query came back, `None` if the local stack works but no LAN host answered.
+///
+/// Also `None` if the socket could not be bound or the group not joined: a
+/// port held exclusively by another responder or a sandbox that forbids
+/// multicast says nothing about the network, and the reason is kept in
+/// [MulticastReport::error].
+fn verdict(report: &MulticastReport) -> Option<bool> {
+    if !report.joined {
+        None
+    } else if !report.responders.is_empty() {
+        Some(true)
+    } else if !report.loopback_received {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verdict() {
+        let mut report = MulticastReport {
+            group: "224.0.0.251:5353".into(),
+            joined: true,
+            loopback_received: true,
+            responders: Vec::new(),
+            multicast_ok: None,
+            error: None,
+        };
+        assert_eq!(verdict(&report), None);
+
+        report.responders.push("192.168.1.20".into());
+        assert_eq!(verdict(&report), Some(true));
+
+        report.responders.clear();
+        report.loopback_received = false;
+        assert_eq!(verdict(&report), Some(false));
+
+        report.joined = false;
+        assert_eq!(verdict(&report), None);
+
+        let mut query = mdns_query(DNS_SD_SERVICES, QTYPE_PTR);
+        assert!(!is_response(&query));
+        query[2] |= 0x84;
+        assert!(is_response(&query));
+    }
+}
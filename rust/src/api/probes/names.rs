@@ -0,0 +1,322 @@
+//! Device name resolution for LAN scans.
+//!
+//! Home routers rarely publish PTR records for their DHCP clients, and
+//! Windows machines do not announce themselves over mDNS, so their names
+//! only come from Windows' own protocols
+//! ([resolve_names](crate::api::models::LocalScanConfig::resolve_names)):
+//!
+//! - LLMNR (RFC 4795): a reverse (PTR) query sent unicast to the host's port
+//!   5355, which only the owner of the address answers.
+//! - NetBIOS Name Service (RFC 1002): a unicast node status (`NBSTAT`)
+//!   request to UDP port 137, answered with the names the host registered.
+//!
+//! The names everything else publishes come from
+//! ([lookup_hostnames](crate::api::models::LocalScanConfig::lookup_hostnames)):
+//!
+//! - mDNS (RFC 6762): a one-shot PTR query sent straight to the host's port
+//!   5353, answered with its `.local` name.
+//! - Reverse DNS via the system resolver, which knows the DHCP clients of
+//!   routers that do register them.
+//!
+//! Every enabled source is asked at once, in one pass after the sweep. The
+//! names the device chose itself win: LLMNR (not limited to 15 uppercase
+//! characters like NetBIOS), then mDNS, NetBIOS and reverse DNS.
+
+use super::{
+    parse::Reader,
+    resolver,
+    udp::{build_dns_query, read_dns_name},
+};
+use crate::api::models::{LocalDevice, LocalScanConfig};
+use futures::StreamExt;
+use std::{
+    net::{IpAddr, Ipv4Addr},
+    time::Duration,
+};
+use tokio::{
+    net::UdpSocket,
+    time::{timeout, timeout_at, Instant},
+};
+
+const LLMNR_PORT: u16 = 5355;
+const NETBIOS_NS_PORT: u16 = 137;
+const MDNS_PORT: u16 = 5353;
+const QTYPE_PTR: u16 = 12;
+const QTYPE_NBSTAT: u16 = 0x21;
+
+/// The name sources a scan asks, each with its waiting time.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct NameLookup {
+    /// LLMNR and NetBIOS.
+    windows: Option<Duration>,
+    /// mDNS and reverse DNS.
+    published: Option<Duration>,
+}
+
+impl NameLookup {
+    /// The lookup `config` asks for, or `None` if it asks for no names.
+    pub(crate) fn from_config(config: &LocalScanConfig) -> Option<Self> {
+        let wait = Duration::from_millis(config.hostname_lookup_timeout_ms);
+        let lookup = Self {
+            windows: config.resolve_names.then_some(wait),
+            published: config.lookup_hostnames.then_some(wait),
+        };
+        (lookup.windows.is_some() || lookup.published.is_some()).then_some(lookup)
+    }
+
+    /// Looks up the name of `ip` from every enabled source at once.
+    pub(crate) async fn name(self, ip: IpAddr) -> Option<String> {
+        let windows = async {
+            let (IpAddr::V4(ip), Some(wait)) = (ip, self.windows) else {
+                return (None, None);
+            };
+            let deadline = Instant::now() + wait;
+            tokio::join!(llmnr_name(ip, deadline), netbios_name(ip, deadline))
+        };
+        let published = async {
+            let Some(wait) = self.published else {
+                return (None, None);
+            };
+            let (mdns, dns) = tokio::join!(
+                mdns_name(ip, wait),
+                timeout(wait, resolver::reverse_name(ip))
+            );
+            (mdns, dns.ok().flatten())
+        };
+        let ((llmnr, netbios), (mdns, dns)) = tokio::join!(windows, published);
+        llmnr.or(mdns).or(netbios).or(dns)
+    }
+
+    /// Fills in the name of every device that has none, looking up at most
+    /// `concurrency` devices at once.
+    ///
+    /// Names are filled in as they arrive, so a pass cut short keeps the
+    /// ones found so far.
+    pub(crate) async fn fill(self, devices: &mut [LocalDevice], concurrency: usize) {
+        let pending: Vec<(usize, IpAddr)> = devices
+            .iter()
+            .enumerate()
+            .filter(|(_, device)| device.hostname.is_none())
+            .filter_map(|(i, device)| Some((i, device.ip_address.parse().ok()?)))
+            .collect();
+        let mut lookups = futures::stream::iter(pending)
+            .map(|(i, ip)| async move { (i, self.name(ip).await) })
+            .buffer_unordered(concurrency.max(1));
+        while let Some((i, hostname)) = lookups.next().await {
+            devices[i].hostname = hostname;
+        }
+    }
+}
+
+/// Asks the mDNS responder of `ip` for its name. The query comes from an
+/// ephemeral port, so the responder answers it directly (RFC 6762, 6.7).
+async fn mdns_name(ip: IpAddr, wait: Duration) -> Option<String> {
+    let IpAddr::V4(ip) = ip else {
+        return None;
+    };
+    let id: u16 = rand::random();
+    let mut query = build_dns_query(id, &reverse_name(ip), QTYPE_PTR);
+    // mDNS ignores the flags of queries; send none, like LLMNR.
+    query[2..4].fill(0);
+
+    let socket = UdpSocket::bind("0.0.0.0:0").await.ok()?;
+    socket.send_to(&query, (ip, MDNS_PORT)).await.ok()?;
+    receive_from(&socket, ip, Instant::now() + wait, |reply| {
+        parse_ptr_answer(reply, id)
+    })
+    .await
+}
+
+/// Asks the LLMNR responder of `ip` for its name. Sent unicast (RFC 4795,
+/// 2.4), so no other host on the link sees the query.
+async fn llmnr_name(ip: Ipv4Addr, deadline: Instant) -> Option<String> {
+    let id: u16 = rand::random();
+    let mut query = build_dns_query(id, &reverse_name(ip), QTYPE_PTR);
+    // LLMNR queries carry no flags.
+    query[2..4].fill(0);
+
+    let socket = UdpSocket::bind("0.0.0.0:0").await.ok()?;
+    socket.send_to(&query, (ip, LLMNR_PORT)).await.ok()?;
+    receive_from(&socket, ip, deadline, |reply| parse_ptr_answer(reply, id)).await
+}
+
+async fn netbios_name(ip: Ipv4Addr, deadline: Instant) -> Option<String> {
+    let id: u16 = rand::random();
+    let socket = UdpSocket::bind("0.0.0.0:0").await.ok()?;
+    socket
+        .send_to(&node_status_request(id), (ip, NETBIOS_NS_PORT))
+        .await
+        .ok()?;
+    receive_from(&socket, ip, deadline, |reply| parse_node_status(reply, id)).await
+}
+
+/// Waits for a datagram from `ip` that `parse` accepts.
+async fn receive_from(
+    socket: &UdpSocket,
+    ip: Ipv4Addr,
+    deadline: Instant,
+    parse: impl Fn(&[u8]) -> Option<String>,
+) -> Option<String> {
+    let mut buf = [0u8; 1500];
+    loop {
+        let (len, from) = timeout_at(deadline, socket.recv_from(&mut buf))
+            .await
+            .ok()?
+            .ok()?;
+        if from.ip() != IpAddr::V4(ip) {
+            continue;
+        }
+        if let Some(name) = parse(&buf[..len]) {
+            return Some(name);
+        }
+    }
+}
+
+/// `4.3.2.1.in-addr.arpa` for `1.2.3.4`.
+fn reverse_name(ip: Ipv4Addr) -> String {
+    let [a, b, c, d] = ip.octets();
+    format!("{}.{}.{}.{}.in-addr.arpa", d, c, b, a)
+}
+
+/// The host name in an LLMNR or mDNS answer to our PTR query `id`.
+pub(crate) fn parse_ptr_answer(reply: &[u8], id: u16) -> Option<String> {
+    let (offset, answer_type) = first_answer(reply, id)?;
+    if answer_type != QTYPE_PTR {
+        return None;
+    }
+    let (name, _) = read_dns_name(reply, offset + 10)?;
+    let name = name.trim_end_matches('.');
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+/// A NetBIOS node status request for the wildcard name `*`.
+fn node_status_request(id: u16) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(50);
+    packet.extend_from_slice(&id.to_be_bytes());
+    // Flags: plain query; QDCOUNT = 1.
+    packet.extend_from_slice(&[0, 0, 0, 1, 0, 0, 0, 0, 0, 0]);
+    // First-level encoding of "*" padded with NULs to 16 bytes: every
+    // nibble becomes 'A' + nibble.
+    packet.push(32);
+    let mut wildcard = [0u8; 16];
+    wildcard[0] = b'*';
+    for byte in wildcard {
+        packet.push(b'A' + (byte >> 4));
+        packet.push(b'A' + (byte & 0x0f));
+    }
+    packet.push(0);
+    packet.extend_from_slice(&QTYPE_NBSTAT.to_be_bytes());
+    packet.extend_from_slice(&1u16.to_be_bytes());
+    packet
+}
+
+/// The workstation name in a node status response to request `id`: the
+/// first unique name with suffix `0x00`.
+pub(crate) fn parse_node_status(reply: &[u8], id: u16) -> Option<String> {
+    const ENTRY_LEN: usize = 18;
+    const GROUP_NAME: u16 = 0x8000;
+
+    let (offset, answer_type) = first_answer(reply, id)?;
+    if answer_type != QTYPE_NBSTAT {
+        return None;
+    }
+    let mut names = Reader::at(reply, offset + 10);
+    let count = names.u8()?;
+    (0..count).find_map(|_| {
+        let entry = names.bytes(ENTRY_LEN)?;
+        let flags = u16::from_be_bytes([entry[16], entry[17]]);
+        if entry[15] != 0 || flags & GROUP_NAME != 0 {
+            return None;
+        }
+        let name = String::from_utf8_lossy(&entry[..15]).trim_end().to_string();
+        (!name.is_empty()).then_some(name)
+    })
+}
+
+/// Checks the header of a response to `id` and locates its first answer
+/// record. Returns the offset of the record's TYPE field and the type.
+fn first_answer(reply: &[u8], id: u16) -> Option<(usize, u16)> {
+    let mut header = Reader::new(reply);
+    if header.u16_be()? != id || header.u8()? & 0x80 == 0 {
+        return None;
+    }
+    header.skip(1)?;
+    let questions = header.u16_be()?;
+    let answers = header.u16_be()?;
+    if answers == 0 {
+        return None;
+    }
+    let mut offset = 12;
+    for _ in 0..questions {
+        offset = read_dns_name(reply, offset)?.1 + 4;
+    }
+    offset = read_dns_name(reply, offset)?.1;
+    let answer_type = Reader::at(reply, offset).u16_be()?;
+    Some((offset, answer_type))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_names_are_opt_in() {
+        let config = LocalScanConfig::default();
+        assert!(NameLookup::from_config(&config).is_none());
+        let lookup = NameLookup::from_config(&LocalScanConfig {
+            resolve_names: true,
+            hostname_lookup_timeout_ms: 250,
+            ..config
+        })
+        .unwrap();
+        assert_eq!(lookup.windows, Some(Duration::from_millis(250)));
+        assert!(lookup.published.is_none());
+    }
+
+    #[test]
+    fn test_parse_llmnr_and_netbios_replies() {
+        let ip = Ipv4Addr::new(192, 168, 1, 20);
+        assert_eq!(reverse_name(ip), "20.1.168.192.in-addr.arpa");
+
+        // LLMNR: the question echoed, then a PTR answer pointing at it.
+        let mut reply = build_dns_query(0x1234, &reverse_name(ip), QTYPE_PTR);
+        reply[2] = 0x80;
+        reply[7] = 1;
+        reply.extend_from_slice(&[0xc0, 12, 0, 12, 0, 1, 0, 0, 0, 30, 0, 13]);
+        reply.extend_from_slice(b"\x0bDESKTOP-7QX\x00");
+        assert_eq!(
+            parse_ptr_answer(&reply, 0x1234).as_deref(),
+            Some("DESKTOP-7QX")
+        );
+        assert_eq!(parse_ptr_answer(&reply, 0x4321), None);
+
+        // mDNS: authoritative, cache-flush class, a `.local` name.
+        let mut reply = build_dns_query(0x0707, &reverse_name(ip), QTYPE_PTR);
+        reply[2] = 0x84;
+        reply[7] = 1;
+        reply.extend_from_slice(&[0xc0, 12, 0, 12, 0x80, 1, 0, 0, 0, 10, 0, 15]);
+        reply.extend_from_slice(b"\x07printer\x05local\x00");
+        assert_eq!(
+            parse_ptr_answer(&reply, 0x0707).as_deref(),
+            Some("printer.local")
+        );
+
+        // NetBIOS: no question, one NBSTAT answer listing a group name first.
+        let request = node_status_request(0x0042);
+        assert_eq!(&request[13..15], b"CK");
+        let mut reply = vec![0x00, 0x42, 0x84, 0x00, 0, 0, 0, 1, 0, 0, 0, 0];
+        reply.extend_from_slice(&request[12..46]);
+        reply.extend_from_slice(&[0, 0x21, 0, 1, 0, 0, 0, 0, 0, 37, 2]);
+        let mut entry = |name: &str, suffix: u8, flags: u16| {
+            reply.extend_from_slice(format!("{:<15}", name).as_bytes());
+            reply.push(suffix);
+            reply.extend_from_slice(&flags.to_be_bytes());
+        };
+        entry("WORKGROUP", 0x00, 0x8400);
+        entry("OFFICE-PC", 0x00, 0x0400);
+        assert_eq!(
+            parse_node_status(&reply, 0x0042).as_deref(),
+            Some("OFFICE-PC")
+        );
+    }
+}
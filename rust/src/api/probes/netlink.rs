@@ -0,0 +1,219 @@
+//! Route lookups over an `AF_NETLINK`/`NETLINK_ROUTE` socket (Linux/Android).
+
+use super::parse::Reader;
+use flutter_rust_bridge::frb;
+use std::{
+    ffi::CStr,
+    net::{IpAddr, Ipv4Addr, Ipv6Addr},
+    time::Duration,
+};
+
+// Kernel ABI constants (linux/netlink.h, linux/rtnetlink.h).
+const NLMSG_HDRLEN: usize = 16;
+const RTMSG_LEN: usize = 12;
+const RTA_HDRLEN: usize = 4;
+const NLMSG_ERROR: u16 = 2;
+const NLM_F_REQUEST: u16 = 1;
+const RTM_NEWROUTE: u16 = 24;
+const RTM_GETROUTE: u16 = 26;
+const RTA_DST: u16 = 1;
+const RTA_OIF: u16 = 4;
+const RTA_GATEWAY: u16 = 5;
+
+/// The parts of a kernel route we care about.
+#[frb(ignore)]
+#[derive(Debug, Clone, Copy, PartialEq, Default)]
+pub(crate) struct RouteInfo {
+    /// Index of the outgoing interface.
+    pub oif: Option<u32>,
+    /// Next-hop router, if the destination is not on-link.
+    pub gateway: Option<IpAddr>,
+}
+
+/// Returns the name of the interface the kernel would use to reach `destination`.
+pub(crate) fn route_interface(destination: IpAddr) -> Option<String> {
+    let index = route_lookup(destination)?.oif?;
+    let mut buf = [0 as libc::c_char; libc::IF_NAMESIZE];
+    // SAFETY: `buf` has the IF_NAMESIZE bytes if_indextoname may write.
+    let ptr = unsafe { libc::if_indextoname(index, buf.as_mut_ptr()) };
+    if ptr.is_null() {
+        return None;
+    }
+    // SAFETY: on success `buf` holds a null-terminated name.
+    let name = unsafe { CStr::from_ptr(buf.as_ptr()) };
+    Some(name.to_string_lossy().into_owned())
+}
+
+/// Returns the next-hop router the kernel would use to reach `destination`.
+pub(crate) fn route_gateway(destination: IpAddr) -> Option<IpAddr> {
+    route_lookup(destination)?.gateway
+}
+
+/// Asks the kernel for the route it would use to reach `destination`.
+pub(crate) fn route_lookup(destination: IpAddr) -> Option<RouteInfo> {
+    use std::os::fd::{AsRawFd, FromRawFd, OwnedFd};
+
+    // SAFETY: plain socket(2) call; the descriptor is owned below.
+    let fd = unsafe {
+        libc::socket(
+            libc::AF_NETLINK,
+            libc::SOCK_RAW | libc::SOCK_CLOEXEC,
+            libc::NETLINK_ROUTE,
+        )
+    };
+    if fd < 0 {
+        return None;
+    }
+    // SAFETY: `fd` is a freshly created, valid descriptor.
+    let socket = unsafe { OwnedFd::from_raw_fd(fd) };
+
+    let timeout = Duration::from_secs(1);
+    let tv = libc::timeval {
+        tv_sec: timeout.as_secs() as libc::time_t,
+        tv_usec: 0,
+    };
+    // SAFETY: `tv` is a valid timeval for SO_RCVTIMEO.
+    unsafe {
+        libc::setsockopt(
+            socket.as_raw_fd(),
+            libc::SOL_SOCKET,
+            libc::SO_RCVTIMEO,
+            (&tv as *const libc::timeval).cast(),
+            std::mem::size_of::<libc::timeval>() as libc::socklen_t,
+        );
+    }
+
+    let request = build_request(destination, std::process::id());
+    // SAFETY: `request` is a valid buffer of the given length. The kernel
+    // is the default destination of an unbound netlink socket.
+    let sent = unsafe {
+        libc::send(
+            socket.as_raw_fd(),
+            request.as_ptr().cast(),
+            request.len(),
+            0,
+        )
+    };
+    if sent < 0 {
+        return None;
+    }
+
+    let mut reply = [0u8; 4096];
+    // SAFETY: `reply` is a valid, writable buffer of the given length.
+    let received = unsafe {
+        libc::recv(
+            socket.as_raw_fd(),
+            reply.as_mut_ptr().cast(),
+            reply.len(),
+            0,
+        )
+    };
+    if received <= 0 {
+        return None;
+    }
+
+    parse_reply(&reply[..received as usize])
+}
+
+/// Serializes an `RTM_GETROUTE` request for a single destination.
+pub(crate) fn build_request(destination: IpAddr, seq: u32) -> Vec<u8> {
+    let (family, addr): (u8, Vec<u8>) = match destination {
+        IpAddr::V4(ip) => (libc::AF_INET as u8, ip.octets().to_vec()),
+        IpAddr::V6(ip) => (libc::AF_INET6 as u8, ip.octets().to_vec()),
+    };
+    let rta_len = RTA_HDRLEN + addr.len();
+    let total = NLMSG_HDRLEN + RTMSG_LEN + rta_len;
+
+    let mut msg = Vec::with_capacity(total);
+    // struct nlmsghdr
+    msg.extend_from_slice(&(total as u32).to_ne_bytes());
+    msg.extend_from_slice(&RTM_GETROUTE.to_ne_bytes());
+    msg.extend_from_slice(&NLM_F_REQUEST.to_ne_bytes());
+    msg.extend_from_slice(&seq.to_ne_bytes());
+    msg.extend_from_slice(&0u32.to_ne_bytes());
+    // struct rtmsg: family, dst_len, src_len, tos, table, protocol, scope, type, flags
+    msg.extend_from_slice(&[family, (addr.len() * 8) as u8, 0, 0, 0, 0, 0, 0]);
+    msg.extend_from_slice(&0u32.to_ne_bytes());
+    // struct rtattr RTA_DST + address
+    msg.extend_from_slice(&(rta_len as u16).to_ne_bytes());
+    msg.extend_from_slice(&RTA_DST.to_ne_bytes());
+    msg.extend_from_slice(&addr);
+    msg
+}
+
+/// Extracts `RTA_OIF` and `RTA_GATEWAY` from an `RTM_NEWROUTE` reply.
+pub(crate) fn parse_reply(buf: &[u8]) -> Option<RouteInfo> {
+    let mut header = Reader::new(buf);
+    let msg_len = usize::try_from(header.u32_ne()?).ok()?;
+    let msg_type = header.u16_ne()?;
+    if msg_type == NLMSG_ERROR || msg_type != RTM_NEWROUTE {
+        return None;
+    }
+    let msg = buf.get(..msg_len)?;
+
+    let mut info = RouteInfo::default();
+    let mut attributes = Reader::at(msg, NLMSG_HDRLEN + RTMSG_LEN);
+    while attributes.remaining() >= RTA_HDRLEN {
+        let rta_len = usize::from(attributes.u16_ne()?);
+        let rta_type = attributes.u16_ne()?;
+        let payload = attributes.bytes(rta_len.checked_sub(RTA_HDRLEN)?)?;
+        match rta_type {
+            RTA_OIF => info.oif = Reader::new(payload).u32_ne(),
+            RTA_GATEWAY => {
+                info.gateway = match payload.len() {
+                    4 => Some(IpAddr::V4(Ipv4Addr::from(
+                        <[u8; 4]>::try_from(payload).ok()?,
+                    ))),
+                    16 => Some(IpAddr::V6(Ipv6Addr::from(
+                        <[u8; 16]>::try_from(payload).ok()?,
+                    ))),
+                    _ => None,
+                }
+            }
+            _ => {}
+        }
+        // Attributes are 4-byte aligned; the last one may lack its padding.
+        let _ = attributes.skip(rta_len.next_multiple_of(4) - rta_len);
+    }
+    Some(info)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_netlink_route_reply_parsing() {
+        let request = build_request("10.0.0.1".parse().unwrap(), 7);
+        assert_eq!(request.len(), 16 + 12 + 8);
+        assert_eq!(u32::from_ne_bytes(request[0..4].try_into().unwrap()), 36);
+
+        // RTM_NEWROUTE carrying RTA_TABLE (15), RTA_GATEWAY (5) and RTA_OIF (4).
+        let mut reply = Vec::new();
+        reply.extend_from_slice(&52u32.to_ne_bytes());
+        reply.extend_from_slice(&24u16.to_ne_bytes());
+        reply.extend_from_slice(&[0u8; 10]);
+        reply.extend_from_slice(&[2, 32, 0, 0, 254, 0, 0, 1, 0, 0, 0, 0]);
+        reply.extend_from_slice(&8u16.to_ne_bytes());
+        reply.extend_from_slice(&15u16.to_ne_bytes());
+        reply.extend_from_slice(&254u32.to_ne_bytes());
+        reply.extend_from_slice(&8u16.to_ne_bytes());
+        reply.extend_from_slice(&5u16.to_ne_bytes());
+        reply.extend_from_slice(&[192, 168, 1, 1]);
+        reply.extend_from_slice(&8u16.to_ne_bytes());
+        reply.extend_from_slice(&4u16.to_ne_bytes());
+        reply.extend_from_slice(&3u32.to_ne_bytes());
+        assert_eq!(
+            parse_reply(&reply),
+            Some(RouteInfo {
+                oif: Some(3),
+                gateway: Some("192.168.1.1".parse().unwrap()),
+            })
+        );
+
+        // NLMSG_ERROR (e.g. no route to host).
+        let mut error = reply.clone();
+        error[4..6].copy_from_slice(&2u16.to_ne_bytes());
+        assert_eq!(parse_reply(&error), None);
+    }
+}
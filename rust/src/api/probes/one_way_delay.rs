@@ -0,0 +1,283 @@
+//! One-way delay estimation against timestamping echo servers.
+//!
+//! RTT hides *where* delay builds up: a saturated uplink and a saturated
+//! downlink look the same. A cooperative server that timestamps each probe on
+//! receipt and on reply allows an NTP-style exchange:
+//!
+//! ```text
+//! t1  client sends          t2  server receives
+//! t4  client receives       t3  server replies
+//!
+//! rtt    = (t4 - t1) - (t3 - t2)
+//! offset = ((t2 - t1) + (t3 - t4)) / 2
+//! ```
+//!
+//! The offset is only exact on a symmetric path, so it is taken from the
+//! fastest exchange, which saw the least queuing in either direction. Every
+//! exchange is then split into an upstream and a downstream delay using that
+//! offset; queuing shows up as delay above each direction's own minimum.
+//!
+//! # Wire Format
+//!
+//! Both messages are single lines of ASCII, fields separated by spaces:
+//!
+//! ```text
+//! request: NRTIME1 <nonce>
+//! reply:   NRTIME1 <nonce> <t2> <t3>
+//! ```
+//!
+//! `t2` and `t3` are the server's receive and transmit times in microseconds
+//! since the Unix epoch.
+
+use super::resolver;
+use crate::api::{
+    constants::LibConstants,
+    models::{NetworkError, OneWayDelayEstimate},
+};
+use std::{
+    net::SocketAddr,
+    time::{Duration, Instant, SystemTime, UNIX_EPOCH},
+};
+use tokio::{net::UdpSocket, time::timeout};
+
+const MAGIC: &str = "NRTIME1";
+
+/// The four timestamps of one exchange, in microseconds since the Unix epoch.
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct Exchange {
+    t1: i64,
+    t2: i64,
+    t3: i64,
+    t4: i64,
+}
+
+impl Exchange {
+    fn rtt(&self) -> i64 {
+        (self.t4 - self.t1) - (self.t3 - self.t2)
+    }
+
+    fn offset(&self) -> i64 {
+        ((self.t2 - self.t1) + (self.t3 - self.t4)) / 2
+    }
+}
+
+/// Runs `samples` timestamp exchanges with `server` (`host:port`) and splits
+/// the delay into its upstream and downstream parts.
+///
+/// `samples` of 0 uses
+/// [DEFAULT_ONE_WAY_DELAY_SAMPLES](LibConstants::DEFAULT_ONE_WAY_DELAY_SAMPLES);
+/// `timeout_ms` of 0 uses
+/// [DEFAULT_ONE_WAY_DELAY_TIMEOUT_MS](LibConstants::DEFAULT_ONE_WAY_DELAY_TIMEOUT_MS)
+/// per exchange. Fails if no exchange is answered.
+pub async fn estimate_one_way_delay(
+    server: String,
+    samples: u8,
+    timeout_ms: u64,
+) -> Result<OneWayDelayEstimate, NetworkError> {
+    let samples = if samples == 0 {
+        LibConstants::DEFAULT_ONE_WAY_DELAY_SAMPLES
+    } else {
+        samples
+    };
+    let wait = Duration::from_millis(if timeout_ms == 0 {
+        LibConstants::DEFAULT_ONE_WAY_DELAY_TIMEOUT_MS
+    } else {
+        timeout_ms
+    });
+
+    let addr: SocketAddr = resolver::resolve_server(&server)
+        .await
+        .ok()
+        .and_then(|addrs| addrs.into_iter().next())
+        .ok_or_else(|| NetworkError::DnsResolutionError(server.clone()))?;
+    let socket = UdpSocket::bind(if addr.is_ipv4() {
+        "0.0.0.0:0"
+    } else {
+        "[::]:0"
+    })
+    .await?;
+    socket.connect(addr).await?;
+
+    // Local timestamps follow the monotonic clock from a single wall-clock
+    // reading, so a clock step mid-run cannot corrupt an exchange.
+    let clock = LocalClock::new();
+    let mut exchanges = Vec::with_capacity(usize::from(samples));
+    for i in 0..samples {
+        if i > 0 {
+            tokio::time::sleep(Duration::from_millis(
+                LibConstants::ONE_WAY_DELAY_SAMPLE_SPACING_MS,
+            ))
+            .await;
+        }
+        if let Ok(Some(exchange)) = timeout(wait, exchange(&socket, &clock)).await {
+            exchanges.push(exchange);
+        }
+    }
+
+    estimate(server, &exchanges).ok_or(NetworkError::TimeoutError)
+}
+
+/// Sends one request and waits for the matching reply.
+///
+/// Returns `None` if sending or receiving fails; the caller bounds the wait.
+async fn exchange(socket: &UdpSocket, clock: &LocalClock) -> Option<Exchange> {
+    let nonce = format!("{:016x}", rand::random::<u64>());
+    let t1 = clock.now_us();
+    socket
+        .send(format!("{} {}\n", MAGIC, nonce).as_bytes())
+        .await
+        .ok()?;
+
+    let mut buf = [0u8; 256];
+    loop {
+        let len = socket.recv(&mut buf).await.ok()?;
+        let t4 = clock.now_us();
+        if let Some((t2, t3)) = parse_reply(&buf[..len], &nonce) {
+            return Some(Exchange { t1, t2, t3, t4 });
+        }
+    }
+}
+
+/// Parses a reply to the request carrying `nonce` into `(t2, t3)`.
+pub(crate) fn parse_reply(reply: &[u8], nonce: &str) -> Option<(i64, i64)> {
+    // Far beyond any real clock, but keeps the delay arithmetic on them from
+    // overflowing.
+    const PLAUSIBLE_US: std::ops::RangeInclusive<i64> = 0..=i64::MAX / 4;
+    let text = std::str::from_utf8(reply).ok()?;
+    let mut fields = text.split_whitespace();
+    if fields.next()? != MAGIC || fields.next()? != nonce {
+        return None;
+    }
+    let t2: i64 = fields.next()?.parse().ok()?;
+    let t3: i64 = fields.next()?.parse().ok()?;
+    (PLAUSIBLE_US.contains(&t2) && PLAUSIBLE_US.contains(&t3) && t3 >= t2).then_some((t2, t3))
+}
+
+/// Combines the answered exchanges into an estimate.
+fn estimate(server: String, exchanges: &[Exchange]) -> Option<OneWayDelayEstimate> {
+    let fastest = exchanges.iter().min_by_key(|e| e.rtt())?;
+    let offset = fastest.offset();
+
+    let mut upstream: Vec<i64> = exchanges.iter().map(|e| e.t2 - e.t1 - offset).collect();
+    let mut downstream: Vec<i64> = exchanges.iter().map(|e| e.t4 - e.t3 + offset).collect();
+    let upstream_ms = median_ms(&mut upstream);
+    let downstream_ms = median_ms(&mut downstream);
+    // Both vectors are sorted now.
+    let min_up = upstream[0];
+    let min_down = downstream[0];
+
+    Some(OneWayDelayEstimate {
+        server,
+        samples: exchanges.len() as u32,
+        clock_offset_ms: us_to_ms(offset),
+        min_rtt_ms: us_to_ms(fastest.rtt()),
+        upstream_ms,
+        downstream_ms,
+        upstream_queuing_ms: upstream_ms - us_to_ms(min_up),
+        downstream_queuing_ms: downstream_ms - us_to_ms(min_down),
+    })
+}
+
+/// Sorts `values` and returns their median in milliseconds.
+fn median_ms(values: &mut [i64]) -> f64 {
+    values.sort_unstable();
+    let mid = values.len() / 2;
+    if values.len().is_multiple_of(2) {
+        (us_to_ms(values[mid - 1]) + us_to_ms(values[mid])) / 2.0
+    } else {
+        us_to_ms(values[mid])
+    }
+}
+
+fn us_to_ms(us: i64) -> f64 {
+    us as f64 / 1000.0
+}
+
+/// Wall-clock microseconds advanced by a monotonic clock.
+struct LocalClock {
+    wall_us: i64,
+    start: Instant,
+}
+
+impl LocalClock {
+    fn new() -> Self {
+        Self {
+            wall_us: unix_micros(),
+            start: Instant::now(),
+        }
+    }
+
+    fn now_us(&self) -> i64 {
+        self.wall_us + self.start.elapsed().as_micros() as i64
+    }
+}
+
+fn unix_micros() -> i64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_micros() as i64)
+        .unwrap_or(0)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_estimate_separates_upstream_queuing() {
+        // Server clock 100 ms ahead, 10 ms each way, 1 ms processing time,
+        // extra delay (up, down) in ms per exchange.
+        let extra = [(0, 0), (30, 0), (25, 0), (40, 2), (0, 1)];
+        let exchanges: Vec<Exchange> = extra
+            .iter()
+            .enumerate()
+            .map(|(i, &(up, down))| {
+                let t1 = 1_000_000 * i as i64;
+                let t2 = t1 + 100_000 + (10 + up) * 1000;
+                let t3 = t2 + 1000;
+                let t4 = t3 - 100_000 + (10 + down) * 1000;
+                Exchange { t1, t2, t3, t4 }
+            })
+            .collect();
+
+        let estimate = estimate("echo:9".into(), &exchanges).unwrap();
+        assert_eq!(estimate.samples, 5);
+        assert_eq!(estimate.clock_offset_ms, 100.0);
+        assert_eq!(estimate.min_rtt_ms, 20.0);
+        assert_eq!(estimate.upstream_ms, 35.0);
+        assert_eq!(estimate.downstream_ms, 10.0);
+        assert_eq!(estimate.upstream_queuing_ms, 25.0);
+        assert_eq!(estimate.downstream_queuing_ms, 0.0);
+
+        assert_eq!(
+            parse_reply(b"NRTIME1 00ff 1700000000000000 1700000000000150\n", "00ff"),
+            Some((1_700_000_000_000_000, 1_700_000_000_000_150))
+        );
+        assert_eq!(parse_reply(b"NRTIME1 00ff 20 10", "00ff"), None);
+        let hostile = format!("NRTIME1 00ff {} {}", i64::MIN, i64::MAX);
+        assert_eq!(parse_reply(hostile.as_bytes(), "00ff"), None);
+    }
+
+    #[tokio::test]
+    async fn test_estimate_against_loopback_echo_server() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 128];
+            while let Ok((len, from)) = server.recv_from(&mut buf).await {
+                let t2 = unix_micros();
+                let request = std::str::from_utf8(&buf[..len]).unwrap();
+                let nonce = request.split_whitespace().nth(1).unwrap().to_string();
+                let reply = format!("{} {} {} {}", MAGIC, nonce, t2, unix_micros());
+                server.send_to(reply.as_bytes(), from).await.unwrap();
+            }
+        });
+
+        let estimate = estimate_one_way_delay(addr.to_string(), 3, 1000)
+            .await
+            .unwrap();
+        assert_eq!(estimate.samples, 3);
+        assert!(estimate.upstream_queuing_ms >= 0.0);
+        assert!(estimate.downstream_queuing_ms >= 0.0);
+    }
+}
@@ -0,0 +1,81 @@
+//! Best-effort vendor lookup from the OUI (first three bytes) of a MAC address.
+//!
+//! The built-in table only covers vendors of common home and office routers
+//! and access points. Locally administered (randomized) addresses never match.
+
+use flutter_rust_bridge::frb;
+
+/// `(OUI, vendor)` pairs, sorted by OUI.
+const OUI_VENDORS: &[(u32, &str)] = &[
+    (0x00000C, "Cisco"),
+    (0x00040E, "AVM"),
+    (0x00055D, "D-Link"),
+    (0x000C42, "MikroTik"),
+    (0x000D88, "D-Link"),
+    (0x001349, "Zyxel"),
+    (0x00146C, "Netgear"),
+    (0x001A92, "ASUSTek"),
+    (0x001B2F, "Netgear"),
+    (0x001D7E, "Linksys"),
+    (0x00259C, "Linksys"),
+    (0x00A0C5, "Zyxel"),
+    (0x00E0FC, "Huawei"),
+    (0x04D4C4, "ASUSTek"),
+    (0x14CC20, "TP-Link"),
+    (0x1C7EE5, "D-Link"),
+    (0x204E7F, "Netgear"),
+    (0x24A43C, "Ubiquiti"),
+    (0x2C56DC, "ASUSTek"),
+    (0x3CA62F, "AVM"),
+    (0x4C5E0C, "MikroTik"),
+    (0x50C7BF, "TP-Link"),
+    (0x60E327, "TP-Link"),
+    (0x6C3B6B, "MikroTik"),
+    (0x788A20, "Ubiquiti"),
+    (0x7CFF4D, "AVM"),
+    (0x802AA8, "Ubiquiti"),
+    (0x9C3DCF, "Netgear"),
+    (0xA040A0, "Netgear"),
+    (0xAC9E17, "ASUSTek"),
+    (0xC02506, "AVM"),
+    (0xC03F0E, "Netgear"),
+    (0xC0C1C0, "Linksys"),
+    (0xD4CA6D, "MikroTik"),
+    (0xE48D8C, "MikroTik"),
+    (0xEC086B, "TP-Link"),
+    (0xF09FC2, "Ubiquiti"),
+    (0xF4F26D, "TP-Link"),
+    (0xFCECDA, "Ubiquiti"),
+];
+
+/// Returns the vendor registered for `mac` (`aa:bb:cc:dd:ee:ff` or
+/// `aa-bb-cc-dd-ee-ff`, any case), if it is in the built-in table.
+#[frb(ignore)]
+pub fn lookup_vendor(mac: &str) -> Option<&'static str> {
+    let hex: String = mac
+        .chars()
+        .filter(|c| !matches!(c, ':' | '-' | '.'))
+        .take(6)
+        .collect();
+    let oui = u32::from_str_radix(&hex, 16)
+        .ok()
+        .filter(|_| hex.len() == 6)?;
+    OUI_VENDORS
+        .binary_search_by_key(&oui, |(prefix, _)| *prefix)
+        .ok()
+        .map(|i| OUI_VENDORS[i].1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_oui_lookup() {
+        assert!(OUI_VENDORS.windows(2).all(|w| w[0].0 < w[1].0));
+        assert_eq!(lookup_vendor("c0:25:06:aa:bb:cc"), Some("AVM"));
+        assert_eq!(lookup_vendor("50-C7-BF-00-11-22"), Some("TP-Link"));
+        assert_eq!(lookup_vendor("02:00:00:00:00:01"), None);
+        assert_eq!(lookup_vendor("c0:25"), None);
+    }
+}
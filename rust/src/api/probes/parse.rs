@@ -0,0 +1,136 @@
+//! Bounds-checked reading of untrusted input.
+//!
+//! Packets, banners and HTTP bodies come from whoever answers, so parsers
+//! never index or slice them unchecked. Binary formats are read through a
+//! [Reader], whose reads return `None` once the input runs out instead of
+//! panicking, and HTTP bodies through [read_body], which stops downloading at
+//! a size limit. The parsers are fuzzed via [crate::api::fuzzing].
+
+/// A cursor over a byte slice whose every read is bounds-checked.
+#[derive(Debug, Clone)]
+pub(crate) struct Reader<'a> {
+    buf: &'a [u8],
+    position: usize,
+}
+
+impl<'a> Reader<'a> {
+    pub(crate) fn new(buf: &'a [u8]) -> Self {
+        Self::at(buf, 0)
+    }
+
+    /// A reader starting at `position`, which may lie past the end.
+    pub(crate) fn at(buf: &'a [u8], position: usize) -> Self {
+        Self { buf, position }
+    }
+
+    /// The offset of the next read.
+    pub(crate) fn position(&self) -> usize {
+        self.position
+    }
+
+    pub(crate) fn remaining(&self) -> usize {
+        self.buf.len().saturating_sub(self.position)
+    }
+
+    /// The next `len` bytes.
+    pub(crate) fn bytes(&mut self, len: usize) -> Option<&'a [u8]> {
+        let end = self.position.checked_add(len)?;
+        let bytes = self.buf.get(self.position..end)?;
+        self.position = end;
+        Some(bytes)
+    }
+
+    pub(crate) fn skip(&mut self, len: usize) -> Option<()> {
+        self.bytes(len).map(|_| ())
+    }
+
+    /// Everything not read yet.
+    pub(crate) fn rest(&mut self) -> &'a [u8] {
+        let rest = self.buf.get(self.position..).unwrap_or_default();
+        self.position = self.position.max(self.buf.len());
+        rest
+    }
+
+    pub(crate) fn array<const N: usize>(&mut self) -> Option<[u8; N]> {
+        self.bytes(N)?.try_into().ok()
+    }
+
+    pub(crate) fn u8(&mut self) -> Option<u8> {
+        self.array::<1>().map(|[b]| b)
+    }
+
+    pub(crate) fn u16_be(&mut self) -> Option<u16> {
+        self.array().map(u16::from_be_bytes)
+    }
+
+    pub(crate) fn u32_be(&mut self) -> Option<u32> {
+        self.array().map(u32::from_be_bytes)
+    }
+
+    pub(crate) fn u16_ne(&mut self) -> Option<u16> {
+        self.array().map(u16::from_ne_bytes)
+    }
+
+    pub(crate) fn u32_ne(&mut self) -> Option<u32> {
+        self.array().map(u32::from_ne_bytes)
+    }
+}
+
+/// Appends the body of `response` to `body` until it holds `limit` bytes;
+/// the rest is never downloaded. On a transfer error `body` keeps what
+/// arrived.
+pub(crate) async fn read_body(
+    response: &mut reqwest::Response,
+    body: &mut Vec<u8>,
+    limit: usize,
+) -> reqwest::Result<()> {
+    while body.len() < limit {
+        let Some(chunk) = response.chunk().await? else {
+            break;
+        };
+        let take = chunk.len().min(limit - body.len());
+        body.extend_from_slice(&chunk[..take]);
+    }
+    Ok(())
+}
+
+/// The body of `response` as text, cut at `limit` bytes; see [read_body].
+pub(crate) async fn read_text(
+    mut response: reqwest::Response,
+    limit: usize,
+) -> reqwest::Result<String> {
+    let mut body = Vec::new();
+    read_body(&mut response, &mut body, limit).await?;
+    Ok(String::from_utf8_lossy(&body).into_owned())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::testsupport::TestServer;
+
+    #[test]
+    fn test_reader_stops_at_the_end() {
+        let mut reader = Reader::new(&[0x12, 0x34, 0x56]);
+        assert_eq!(reader.u16_be(), Some(0x1234));
+        assert_eq!(reader.u16_be(), None);
+        assert_eq!(reader.position(), 2);
+        assert_eq!(reader.bytes(usize::MAX), None);
+        assert_eq!(reader.rest(), [0x56]);
+        assert_eq!((reader.u8(), reader.remaining()), (None, 0));
+
+        let mut past_end = Reader::at(&[1, 2], 7);
+        assert_eq!((past_end.rest(), past_end.remaining()), (&[][..], 0));
+    }
+
+    #[tokio::test]
+    async fn test_read_text_cuts_at_the_limit() {
+        let server = TestServer::http()
+            .respond("/", 200, "x".repeat(10_000))
+            .start()
+            .await
+            .unwrap();
+        let response = reqwest::get(server.url("/")).await.unwrap();
+        assert_eq!(read_text(response, 100).await.unwrap(), "x".repeat(100));
+    }
+}
@@ -0,0 +1,189 @@
+//! Per-interface path measurements.
+//!
+//! With Wi-Fi and cellular up at the same time, the OS routes everything over
+//! one of them (usually Wi-Fi) even when the other is doing far better. Each
+//! candidate interface is measured on its own by binding the probe sockets to
+//! it, so the two can be compared.
+//!
+//! # Platform Notes
+//!
+//! - Linux/Android: sockets are bound to the interface itself
+//!   (`SO_BINDTODEVICE`) when permitted, and to its address otherwise.
+//!   Android apps only see the cellular interface while Wi-Fi is up if
+//!   "mobile data always active" is enabled or they requested it.
+//! - Elsewhere sockets are bound to the interface's address, which the weak
+//!   host model of macOS and Windows may still route via the default
+//!   interface; such measurements then mirror the default path.
+
+use super::interface::classify_interface;
+use crate::api::{
+    constants::LibConstants,
+    models::{ConnectionType, PathMeasurement},
+};
+use futures::future::join_all;
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::{Duration, Instant},
+};
+use tokio::{net::TcpSocket, time::timeout};
+
+/// Measures every active Wi-Fi, cellular and Ethernet interface with TCP
+/// handshakes to the [DEFAULT_TCP_REFERENCE_SERVERS](LibConstants::DEFAULT_TCP_REFERENCE_SERVERS).
+///
+/// `timeout_ms` of 0 uses [DEFAULT_TIMEOUT_MS](LibConstants::DEFAULT_TIMEOUT_MS).
+/// Interfaces are measured concurrently.
+pub async fn measure_paths(timeout_ms: u64) -> Vec<PathMeasurement> {
+    let servers: Vec<SocketAddr> = LibConstants::DEFAULT_TCP_REFERENCE_SERVERS
+        .iter()
+        .filter_map(|s| s.parse().ok())
+        .collect();
+    measure_candidates(candidate_interfaces(), &servers, timeout_ms).await
+}
+
+/// [measure_paths] over `candidates`, as listed by [candidate_interfaces],
+/// towards `servers`.
+pub(crate) async fn measure_candidates(
+    candidates: Vec<(String, ConnectionType, IpAddr)>,
+    servers: &[SocketAddr],
+    timeout_ms: u64,
+) -> Vec<PathMeasurement> {
+    let wait = Duration::from_millis(if timeout_ms == 0 {
+        LibConstants::DEFAULT_TIMEOUT_MS
+    } else {
+        timeout_ms
+    });
+
+    join_all(
+        candidates
+            .into_iter()
+            .map(|(name, kind, local)| measure_path(name, kind, local, servers, wait)),
+    )
+    .await
+}
+
+/// Active, addressed interfaces that can carry internet traffic.
+pub(crate) fn candidate_interfaces() -> Vec<(String, ConnectionType, IpAddr)> {
+    use network_interface::{Addr, NetworkInterface, NetworkInterfaceConfig};
+
+    let mut candidates = Vec::new();
+    for iface in NetworkInterface::show().unwrap_or_default() {
+        let kind = match classify_interface(&iface.name) {
+            Some(
+                kind @ (ConnectionType::Wifi | ConnectionType::Cellular | ConnectionType::Ethernet),
+            ) => kind,
+            _ => continue,
+        };
+        let local = iface.addr.iter().find_map(|addr| match addr {
+            Addr::V4(v4) if !v4.ip.is_loopback() && !v4.ip.is_link_local() => {
+                Some(IpAddr::V4(v4.ip))
+            }
+            _ => None,
+        });
+        if let Some(local) = local {
+            if !candidates.iter().any(|(name, _, _)| *name == iface.name) {
+                candidates.push((iface.name, kind, local));
+            }
+        }
+    }
+    candidates
+}
+
+async fn measure_path(
+    interface_name: String,
+    connection_type: ConnectionType,
+    local: IpAddr,
+    servers: &[SocketAddr],
+    wait: Duration,
+) -> PathMeasurement {
+    let rtts = join_all(
+        servers
+            .iter()
+            .map(|&server| handshake_via(&interface_name, local, server, wait)),
+    )
+    .await;
+    path_measurement(interface_name, connection_type, local, &rtts)
+}
+
+/// Folds per-server handshake times into one measurement: the median of the
+/// successful handshakes and the share of failed ones.
+fn path_measurement(
+    interface_name: String,
+    connection_type: ConnectionType,
+    local: IpAddr,
+    rtts: &[Option<u64>],
+) -> PathMeasurement {
+    let mut ok: Vec<u64> = rtts.iter().flatten().copied().collect();
+    ok.sort_unstable();
+    let failed = rtts.len() - ok.len();
+    PathMeasurement {
+        interface_name,
+        connection_type,
+        local_address: local.to_string(),
+        latency_ms: ok.get(ok.len() / 2).copied(),
+        packet_loss_percent: if rtts.is_empty() {
+            100.0
+        } else {
+            failed as f32 * 100.0 / rtts.len() as f32
+        },
+    }
+}
+
+/// Time (ms) of one TCP handshake to `server` leaving through the interface.
+async fn handshake_via(
+    interface_name: &str,
+    local: IpAddr,
+    server: SocketAddr,
+    wait: Duration,
+) -> Option<u64> {
+    if local.is_ipv4() != server.is_ipv4() {
+        return None;
+    }
+    let socket = match local {
+        IpAddr::V4(_) => TcpSocket::new_v4(),
+        IpAddr::V6(_) => TcpSocket::new_v6(),
+    }
+    .ok()?;
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        // Needs CAP_NET_RAW on older kernels; the address binding remains.
+        let _ = socket.bind_device(Some(interface_name.as_bytes()));
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    let _ = interface_name;
+    socket.bind(SocketAddr::new(local, 0)).ok()?;
+
+    let started = Instant::now();
+    match timeout(wait, socket.connect(server)).await {
+        Ok(Ok(_)) => Some(started.elapsed().as_millis() as u64),
+        _ => None,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[tokio::test]
+    async fn test_path_measurement() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let server = listener.local_addr().unwrap();
+        let local = IpAddr::V4(Ipv4Addr::LOCALHOST);
+
+        let rtt = handshake_via("lo", local, server, Duration::from_secs(2)).await;
+        assert!(rtt.is_some());
+
+        let path = path_measurement(
+            "wlan0".into(),
+            ConnectionType::Wifi,
+            local,
+            &[Some(30), None, Some(10), Some(20)],
+        );
+        assert_eq!(path.latency_ms, Some(20));
+        assert_eq!(path.packet_loss_percent, 25.0);
+        assert_eq!(
+            path_measurement("rmnet0".into(), ConnectionType::Cellular, local, &[None]).latency_ms,
+            None
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Port forwarding verification via "check my port" web services.
+//!
+//! Such a service connects back to the caller's public address on a given
+//! port and reports whether it got through, which verifies a port forward
+//! end to end without needing a machine outside the network. Unlike the
+//! connect-back test of [reflection](super::reflection), it only needs
+//! plain HTTPS.
+//!
+//! # Endpoint Format
+//!
+//! The endpoint is a URL in which `{port}` is replaced by the port to test;
+//! without the placeholder, a `port` query parameter is appended. The
+//! service is expected to answer with JSON such as:
+//!
+//! ```text
+//! {"ip": "203.0.113.7", "port": 8080, "status": "open"}
+//! {"ip": "203.0.113.7", "port": 8080, "open": false}
+//! ```
+//!
+//! `status` may be `open`, `closed`/`refused` or `timeout`/`filtered`; a
+//! boolean `open` (or `reachable`) field is understood as well. A bare
+//! `open`/`closed` text body is accepted for the simplest services.
+
+use super::{isp_status::json_string_field, parse::read_text};
+use crate::api::{
+    constants::LibConstants,
+    models::{NetworkError, PortForwardCheck, ServiceReachability},
+};
+use std::time::{Duration, Instant};
+
+/// Asks the "check my port" service at `endpoint` to connect back to `port`
+/// on our public address.
+///
+/// `timeout_ms` bounds the whole request, including the service's own
+/// connection attempt (0 uses
+/// [DEFAULT_PORT_CHECK_TIMEOUT_MS](LibConstants::DEFAULT_PORT_CHECK_TIMEOUT_MS)).
+/// Fails if the service cannot be reached or its answer is not understood.
+pub async fn verify_port_forwarding(
+    endpoint: String,
+    port: u16,
+    timeout_ms: u64,
+) -> Result<PortForwardCheck, NetworkError> {
+    let wait = Duration::from_millis(if timeout_ms == 0 {
+        LibConstants::DEFAULT_PORT_CHECK_TIMEOUT_MS
+    } else {
+        timeout_ms
+    });
+    let client = reqwest::Client::builder()
+        .timeout(wait)
+        .build()
+        .map_err(|e| NetworkError::ConnectionError(e.to_string()))?;
+
+    let start = Instant::now();
+    let response = client
+        .get(check_url(&endpoint, port))
+        .send()
+        .await
+        .map_err(|e| {
+            if e.is_timeout() {
+                NetworkError::TimeoutError
+            } else {
+                NetworkError::ConnectionError(e.to_string())
+            }
+        })?;
+    if !response.status().is_success() {
+        return Err(NetworkError::ConnectionError(format!(
+            "Port check service answered {}",
+            response.status()
+        )));
+    }
+    let body = read_text(response, LibConstants::MAX_RESPONSE_BODY_BYTES)
+        .await
+        .map_err(|e| NetworkError::ConnectionError(e.to_string()))?;
+
+    let reachability = parse_verdict(&body).ok_or_else(|| {
+        NetworkError::UnknownError("Unrecognized port check response".to_string())
+    })?;
+    Ok(PortForwardCheck {
+        endpoint,
+        port,
+        public_address: json_string_field(&body, "ip").map(str::to_string),
+        reachability,
+        duration_ms: start.elapsed().as_millis() as u64,
+    })
+}
+
+/// The request URL for `port`.
+fn check_url(endpoint: &str, port: u16) -> String {
+    if endpoint.contains("{port}") {
+        endpoint.replace("{port}", &port.to_string())
+    } else {
+        let separator = if endpoint.contains('?') { '&' } else { '?' };
+        format!("{}{}port={}", endpoint, separator, port)
+    }
+}
+
+/// Interprets the service's answer; `None` if it matches no known shape.
+pub(crate) fn parse_verdict(body: &str) -> Option<ServiceReachability> {
+    if let Some(status) = json_string_field(body, "status") {
+        return match status.to_ascii_lowercase().as_str() {
+            "open" | "reachable" | "success" => Some(ServiceReachability::Reachable),
+            "closed" | "refused" => Some(ServiceReachability::Refused),
+            "timeout" | "filtered" => Some(ServiceReachability::TimedOut),
+            _ => None,
+        };
+    }
+    if let Some(open) = json_bool_field(body, "open").or_else(|| json_bool_field(body, "reachable"))
+    {
+        return Some(if open {
+            ServiceReachability::Reachable
+        } else {
+            ServiceReachability::Refused
+        });
+    }
+    match body.trim().to_ascii_lowercase().as_str() {
+        "open" => Some(ServiceReachability::Reachable),
+        "closed" => Some(ServiceReachability::Refused),
+        _ => None,
+    }
+}
+
+/// The value of the first `"key": true|false` pair, without a JSON parser.
+fn json_bool_field(body: &str, key: &str) -> Option<bool> {
+    let needle = format!("\"{}\"", key);
+    let rest = &body[body.find(&needle)? + needle.len()..];
+    let rest = rest.trim_start().strip_prefix(':')?.trim_start();
+    if rest.starts_with("true") {
+        Some(true)
+    } else if rest.starts_with("false") {
+        Some(false)
+    } else {
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_verify_port_forwarding_against_mock_service() {
+        let mut server = mockito::Server::new_async().await;
+        let mock = server
+            .mock("GET", "/check/8080")
+            .with_body(r#"{"ip": "203.0.113.7", "port": 8080, "status": "open"}"#)
+            .create_async()
+            .await;
+
+        let endpoint = format!("{}/check/{{port}}", server.url());
+        let check = verify_port_forwarding(endpoint, 8080, 2000).await.unwrap();
+        mock.assert_async().await;
+        assert_eq!(check.reachability, ServiceReachability::Reachable);
+        assert_eq!(check.public_address.as_deref(), Some("203.0.113.7"));
+
+        assert_eq!(
+            check_url("https://example.test/api?v=1", 22),
+            "https://example.test/api?v=1&port=22"
+        );
+        assert_eq!(
+            parse_verdict(r#"{"open": false}"#),
+            Some(ServiceReachability::Refused)
+        );
+        assert_eq!(
+            parse_verdict(r#"{"status":"filtered"}"#),
+            Some(ServiceReachability::TimedOut)
+        );
+        assert_eq!(parse_verdict("<html>rate limited</html>"), None);
+    }
+}
@@ -0,0 +1,208 @@
+//! DSCP (QoS) marking of probe packets.
+//!
+//! Networks with QoS policies queue packets by their DSCP code point, e.g.
+//! Expedited Forwarding for VoIP. Probing the same target with different
+//! markings shows whether those priority queues exist and actually help.
+//!
+//! The marking is written to the upper six bits of the IPv4 TOS byte or the
+//! IPv6 traffic class; the ECN bits are left at zero.
+
+use crate::api::{
+    constants::LibConstants,
+    models::{DscpLatency, NetworkTarget},
+    probes::check_target,
+};
+use socket2::SockRef;
+use std::{
+    io,
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+use surge_ping::{Client, Config, IcmpPacket, PingIdentifier, PingSequence, SurgeError, ICMP};
+use tokio::net::{TcpSocket, TcpStream};
+
+/// Sets the DSCP code point of `socket`.
+pub(crate) fn apply_dscp(socket: SockRef<'_>, ipv4: bool, dscp: u8) -> io::Result<()> {
+    let tos = u32::from(dscp.min(63)) << 2;
+    if ipv4 {
+        return socket.set_tos_v4(tos);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    {
+        use std::os::fd::AsRawFd;
+        let value = tos as libc::c_int;
+        // SAFETY: `value` is a valid c_int for the duration of the call.
+        let result = unsafe {
+            libc::setsockopt(
+                socket.as_raw_fd(),
+                libc::IPPROTO_IPV6,
+                libc::IPV6_TCLASS,
+                (&value as *const libc::c_int).cast(),
+                std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+            )
+        };
+        if result < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+    #[cfg(not(any(target_os = "linux", target_os = "android")))]
+    {
+        let _ = socket;
+        Err(io::Error::new(
+            io::ErrorKind::Unsupported,
+            "IPv6 traffic class marking is not supported on this platform",
+        ))
+    }
+}
+
+/// Opens a TCP connection whose packets carry `dscp`, if given.
+pub(crate) async fn connect_tcp(addr: SocketAddr, dscp: Option<u8>) -> io::Result<TcpStream> {
+    let Some(dscp) = dscp else {
+        return TcpStream::connect(addr).await;
+    };
+    let socket = if addr.is_ipv4() {
+        TcpSocket::new_v4()?
+    } else {
+        TcpSocket::new_v6()?
+    };
+    apply_dscp(SockRef::from(&socket), addr.is_ipv4(), dscp)?;
+    socket.connect(addr).await
+}
+
+/// Sends one ICMP echo request marked with `dscp`, if given.
+pub(crate) async fn ping_marked(
+    host: IpAddr,
+    payload: &[u8],
+    dscp: Option<u8>,
+) -> Result<(IcmpPacket, Duration), SurgeError> {
+    let Some(dscp) = dscp else {
+        return surge_ping::ping(host, payload).await;
+    };
+    let config = match host {
+        IpAddr::V4(_) => Config::default(),
+        IpAddr::V6(_) => Config::builder().kind(ICMP::V6).build(),
+    };
+    let client = Client::new(&config)?;
+    let native = client.get_socket().get_native_sock();
+    // SAFETY: the socket is owned by `client`, which outlives the borrow.
+    #[cfg(unix)]
+    let borrowed = unsafe { std::os::fd::BorrowedFd::borrow_raw(native) };
+    #[cfg(windows)]
+    let borrowed = unsafe { std::os::windows::io::BorrowedSocket::borrow_raw(native) };
+    apply_dscp(SockRef::from(&borrowed), host.is_ipv4(), dscp)?;
+
+    let mut pinger = client.pinger(host, PingIdentifier(rand::random())).await;
+    pinger.ping(PingSequence(0), payload).await
+}
+
+/// Probes `target` with each DSCP marking in `markings` and compares the
+/// results with unmarked (best effort) probes.
+///
+/// The markings are probed round-robin, `samples` times each (0 uses
+/// [DEFAULT_DSCP_COMPARISON_SAMPLES](LibConstants::DEFAULT_DSCP_COMPARISON_SAMPLES)),
+/// so that changing conditions affect all of them alike. The result starts
+/// with the best-effort baseline, followed by `markings` in order.
+/// HTTP(S) targets cannot be marked; use a TCP, UDP, DNS or ICMP target.
+///
+/// ```ignore
+/// let results = compare_dscp_markings(target, vec![LibConstants::DSCP_EF], 0).await;
+/// if results[1].delta_ms.is_some_and(|d| d < -5) {
+///     println!("VoIP traffic is prioritized");
+/// }
+/// ```
+pub async fn compare_dscp_markings(
+    target: NetworkTarget,
+    markings: Vec<u8>,
+    samples: u8,
+) -> Vec<DscpLatency> {
+    let samples = if samples == 0 {
+        LibConstants::DEFAULT_DSCP_COMPARISON_SAMPLES
+    } else {
+        samples
+    };
+    let mut codes = vec![LibConstants::DSCP_BEST_EFFORT];
+    codes.extend(
+        markings
+            .into_iter()
+            .filter(|&m| m != LibConstants::DSCP_BEST_EFFORT),
+    );
+
+    let mut latencies: Vec<Vec<u64>> = vec![Vec::new(); codes.len()];
+    for _ in 0..samples {
+        for (i, &dscp) in codes.iter().enumerate() {
+            let marked = NetworkTarget {
+                dscp: Some(dscp),
+                ..target.clone()
+            };
+            let report = check_target(&marked).await;
+            if report.success {
+                latencies[i].push(report.latency_ms);
+            }
+        }
+    }
+
+    summarize(&codes, latencies, u32::from(samples))
+}
+
+/// Builds one [DscpLatency] per code from the successful latencies.
+fn summarize(codes: &[u8], mut latencies: Vec<Vec<u64>>, samples: u32) -> Vec<DscpLatency> {
+    let median = |l: &mut Vec<u64>| {
+        l.sort_unstable();
+        (!l.is_empty()).then(|| l[l.len() / 2])
+    };
+    let baseline = median(&mut latencies[0]);
+
+    codes
+        .iter()
+        .zip(latencies.iter_mut())
+        .map(|(&dscp, l)| {
+            let successes = l.len() as u32;
+            let median_latency_ms = median(l);
+            DscpLatency {
+                dscp,
+                samples,
+                successes,
+                median_latency_ms,
+                loss_percent: if samples == 0 {
+                    0.0
+                } else {
+                    samples.saturating_sub(successes) as f32 / samples as f32 * 100.0
+                },
+                delta_ms: median_latency_ms
+                    .zip(baseline)
+                    .map(|(m, b)| m as i64 - b as i64),
+            }
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize_compares_against_best_effort() {
+        let codes = [LibConstants::DSCP_BEST_EFFORT, LibConstants::DSCP_EF];
+        let latencies = vec![vec![42, 40, 45, 41], vec![21, 20, 22]];
+        let results = summarize(&codes, latencies, 4);
+
+        assert_eq!(results[0].median_latency_ms, Some(42));
+        assert_eq!(results[0].delta_ms, Some(0));
+        assert_eq!(results[1].dscp, 46);
+        assert_eq!(results[1].successes, 3);
+        assert_eq!(results[1].loss_percent, 25.0);
+        assert_eq!(results[1].delta_ms, Some(-21));
+    }
+
+    #[tokio::test]
+    async fn test_marked_tcp_connect() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let addr = listener.local_addr().unwrap();
+        let stream = connect_tcp(addr, Some(LibConstants::DSCP_EF))
+            .await
+            .unwrap();
+        assert_eq!(SockRef::from(&stream).tos_v4().unwrap(), 46 << 2);
+    }
+}
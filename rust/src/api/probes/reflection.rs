@@ -0,0 +1,459 @@
+//! NAT and path inference via reflection servers.
+//!
+//! A reflection server answers a UDP probe with the source address, port and
+//! TTL it observed. Comparing the answers of several servers, sent from one
+//! local socket, reveals whether and how a NAT rewrites the flow; comparing
+//! the observed TTL with the TTL of the reply hints at asymmetric routing.
+//!
+//! # Wire Format
+//!
+//! Both messages are single lines of ASCII, fields separated by spaces:
+//!
+//! ```text
+//! request: NRREFLECT1 <nonce>
+//! reply:   NRREFLECT1 <nonce> <source-ip> <source-port> <ttl or -> [<tos or ->]
+//! ```
+//!
+//! `<nonce>` is echoed verbatim so stale replies can be told apart. The
+//! optional last field is the TOS byte (DSCP and ECN bits) the probe arrived
+//! with, used by [ecn](super::ecn) to spot bleached ECN marks.
+//!
+//! Servers may also offer a connect-back test, used by [hairpin](super::hairpin):
+//!
+//! ```text
+//! request: NRCONNECT1 <nonce> <tcp-port>
+//! reply:   NRCONNECT1 <nonce> <source-ip> <open|closed|timeout>
+//! ```
+//!
+//! The server opens a TCP connection to `<source-ip>:<tcp-port>` and reports
+//! whether the handshake completed, was refused or timed out.
+
+use super::resolver;
+use crate::api::{
+    constants::LibConstants,
+    models::{NatReport, NatType, NetworkError, ReflectionObservation, ServiceReachability},
+};
+use std::{
+    io,
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+use tokio::{net::UdpSocket, time::timeout};
+
+const MAGIC: &str = "NRREFLECT1";
+const CONNECT_MAGIC: &str = "NRCONNECT1";
+
+/// Probes every server in `servers` (`host:port`) from one UDP socket.
+///
+/// The first server that answers is probed a second time at the end to
+/// detect rebinding. Only IPv4 addresses are used. Fails if no server
+/// answers within `timeout_ms` (0 means
+/// [DEFAULT_REFLECTION_TIMEOUT_MS](LibConstants::DEFAULT_REFLECTION_TIMEOUT_MS)).
+pub async fn probe_nat(servers: Vec<String>, timeout_ms: u64) -> Result<NatReport, NetworkError> {
+    let wait = Duration::from_millis(if timeout_ms == 0 {
+        LibConstants::DEFAULT_REFLECTION_TIMEOUT_MS
+    } else {
+        timeout_ms
+    });
+
+    let socket = UdpSocket::bind("0.0.0.0:0").await?;
+    socket.set_ttl(u32::from(LibConstants::REFLECTION_PROBE_TTL))?;
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    ttl::enable_recv_ttl(&socket)?;
+    let local_port = socket.local_addr()?.port();
+
+    let mut answered: Vec<(SocketAddr, SocketAddr, ReflectionObservation)> = Vec::new();
+    for server in &servers {
+        let Some(addr) = resolve_v4(server).await else {
+            continue;
+        };
+        if let Ok(Some((observed, observation, _))) =
+            timeout(wait, reflect(&socket, addr, server)).await
+        {
+            answered.push((addr, observed, observation));
+        }
+    }
+
+    let Some((first_addr, first_observed, first)) = answered.first().cloned() else {
+        return Err(NetworkError::TimeoutError);
+    };
+    let repeat = timeout(wait, reflect(&socket, first_addr, &first.server))
+        .await
+        .ok()
+        .flatten();
+    let rebinding_detected = repeat.is_some_and(|(observed, _, _)| observed != first_observed);
+
+    let local_ip = route_local_ip(first_addr).await;
+    let mappings: Vec<(SocketAddr, SocketAddr)> = answered
+        .iter()
+        .map(|(server, observed, _)| (*server, *observed))
+        .collect();
+    let observations: Vec<ReflectionObservation> =
+        answered.into_iter().map(|(_, _, o)| o).collect();
+
+    Ok(NatReport {
+        nat_type: classify_nat(
+            local_ip.map(|ip| SocketAddr::new(ip, local_port)),
+            &mappings,
+        ),
+        local_address: local_ip.map(|ip| ip.to_string()),
+        asymmetric_routing_suspected: observations.iter().any(|o| {
+            matches!((o.forward_hops, o.reverse_hops), (Some(f), Some(r))
+                if f.abs_diff(r) >= LibConstants::ASYMMETRIC_ROUTE_HOP_DIFFERENCE)
+        }),
+        observations,
+        rebinding_detected,
+    })
+}
+
+/// Sends a probe with the TOS byte `tos` to each of `servers` in turn and
+/// returns the TOS the first server that reports one saw.
+pub(crate) async fn reflected_tos(servers: &[String], tos: u8, wait: Duration) -> Option<u8> {
+    let socket = UdpSocket::bind("0.0.0.0:0").await.ok()?;
+    socket2::SockRef::from(&socket)
+        .set_tos_v4(u32::from(tos))
+        .ok()?;
+    for server in servers {
+        let Some(addr) = resolve_v4(server).await else {
+            continue;
+        };
+        if let Ok(Some((_, _, Some(observed)))) =
+            timeout(wait, reflect(&socket, addr, server)).await
+        {
+            return Some(observed);
+        }
+    }
+    None
+}
+
+/// Asks `server` to connect back to `port` on our public address.
+///
+/// Returns the public address the server saw and the outcome of its
+/// connection attempt, or `None` if the server did not answer in time.
+pub(crate) async fn request_connect_back(
+    server: &str,
+    port: u16,
+    wait: Duration,
+) -> Option<(IpAddr, ServiceReachability)> {
+    let addr = resolve_v4(server).await?;
+    let socket = UdpSocket::bind("0.0.0.0:0").await.ok()?;
+    socket.connect(addr).await.ok()?;
+    let nonce = format!("{:016x}", rand::random::<u64>());
+    socket
+        .send(format!("{} {} {}\n", CONNECT_MAGIC, nonce, port).as_bytes())
+        .await
+        .ok()?;
+
+    let mut buf = [0u8; 512];
+    timeout(wait, async {
+        loop {
+            let len = socket.recv(&mut buf).await.ok()?;
+            if let Some(reply) = parse_connect_reply(&buf[..len], &nonce) {
+                return Some(reply);
+            }
+        }
+    })
+    .await
+    .ok()
+    .flatten()
+}
+
+/// Parses a connect-back reply to the request carrying `nonce`.
+pub(crate) fn parse_connect_reply(
+    reply: &[u8],
+    nonce: &str,
+) -> Option<(IpAddr, ServiceReachability)> {
+    let text = std::str::from_utf8(reply).ok()?;
+    let mut fields = text.split_whitespace();
+    if fields.next()? != CONNECT_MAGIC || fields.next()? != nonce {
+        return None;
+    }
+    let ip: IpAddr = fields.next()?.parse().ok()?;
+    let outcome = match fields.next()? {
+        "open" => ServiceReachability::Reachable,
+        "closed" => ServiceReachability::Refused,
+        "timeout" => ServiceReachability::TimedOut,
+        _ => return None,
+    };
+    Some((ip, outcome))
+}
+
+/// Sends one probe to `addr` and waits for the matching reply, returning
+/// the observed source, the observation and the TOS the server reported.
+///
+/// Returns `None` if sending or receiving fails; the caller bounds the wait.
+async fn reflect(
+    socket: &UdpSocket,
+    addr: SocketAddr,
+    server: &str,
+) -> Option<(SocketAddr, ReflectionObservation, Option<u8>)> {
+    let nonce = format!("{:016x}", rand::random::<u64>());
+    socket
+        .send_to(format!("{} {}\n", MAGIC, nonce).as_bytes(), addr)
+        .await
+        .ok()?;
+
+    let mut buf = [0u8; 512];
+    loop {
+        let (len, from, reply_ttl) = recv_with_ttl(socket, &mut buf).await.ok()?;
+        if from != addr {
+            continue;
+        }
+        let Some((observed, observed_ttl, observed_tos)) = parse_reply(&buf[..len], &nonce) else {
+            continue;
+        };
+        return Some((
+            observed,
+            ReflectionObservation {
+                server: server.to_string(),
+                observed_address: observed.ip().to_string(),
+                observed_port: observed.port(),
+                observed_ttl,
+                forward_hops: observed_ttl
+                    .and_then(|ttl| LibConstants::REFLECTION_PROBE_TTL.checked_sub(ttl)),
+                reverse_hops: reply_ttl.map(hops_from_ttl),
+            },
+            observed_tos,
+        ));
+    }
+}
+
+/// Parses a reply to the probe carrying `nonce` into the observed source,
+/// TTL and TOS.
+pub(crate) fn parse_reply(
+    reply: &[u8],
+    nonce: &str,
+) -> Option<(SocketAddr, Option<u8>, Option<u8>)> {
+    let text = std::str::from_utf8(reply).ok()?;
+    let mut fields = text.split_whitespace();
+    if fields.next()? != MAGIC || fields.next()? != nonce {
+        return None;
+    }
+    let ip: IpAddr = fields.next()?.parse().ok()?;
+    let port: u16 = fields.next()?.parse().ok()?;
+    let mut optional = || match fields.next() {
+        Some("-") | None => Some(None),
+        Some(value) => value.parse().ok().map(Some),
+    };
+    let ttl = optional()?;
+    let tos = optional()?;
+    Some((SocketAddr::new(ip, port), ttl, tos))
+}
+
+/// Derives the mapping behavior from `(server, observed source)` pairs.
+fn classify_nat(local: Option<SocketAddr>, mappings: &[(SocketAddr, SocketAddr)]) -> NatType {
+    if mappings.is_empty() {
+        return NatType::Unknown;
+    }
+    if local.is_some_and(|local| mappings.iter().any(|(_, observed)| *observed == local)) {
+        return NatType::None;
+    }
+
+    let mut server_ips: Vec<IpAddr> = mappings.iter().map(|(server, _)| server.ip()).collect();
+    server_ips.sort();
+    server_ips.dedup();
+    if server_ips.len() < 2 {
+        return NatType::Unknown;
+    }
+
+    let first = mappings[0].1;
+    if mappings.iter().all(|(_, observed)| *observed == first) {
+        NatType::EndpointIndependent
+    } else {
+        NatType::EndpointDependent
+    }
+}
+
+/// Hop count of a received packet, assuming the sender started from the
+/// nearest common initial TTL (64, 128 or 255) at or above `ttl`.
+fn hops_from_ttl(ttl: u8) -> u8 {
+    let initial = [64u8, 128, 255]
+        .into_iter()
+        .find(|&initial| initial >= ttl)
+        .unwrap_or(255);
+    initial - ttl
+}
+
+async fn resolve_v4(server: &str) -> Option<SocketAddr> {
+    resolver::resolve_server(server)
+        .await
+        .ok()?
+        .into_iter()
+        .find(SocketAddr::is_ipv4)
+}
+
+/// The local address the OS would use towards `addr` (no packet is sent).
+async fn route_local_ip(addr: SocketAddr) -> Option<IpAddr> {
+    let socket = UdpSocket::bind("0.0.0.0:0").await.ok()?;
+    socket.connect(addr).await.ok()?;
+    Some(socket.local_addr().ok()?.ip())
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+async fn recv_with_ttl(
+    socket: &UdpSocket,
+    buf: &mut [u8],
+) -> io::Result<(usize, SocketAddr, Option<u8>)> {
+    socket
+        .async_io(tokio::io::Interest::READABLE, || ttl::recv(socket, buf))
+        .await
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "android")))]
+async fn recv_with_ttl(
+    socket: &UdpSocket,
+    buf: &mut [u8],
+) -> io::Result<(usize, SocketAddr, Option<u8>)> {
+    let (len, from) = socket.recv_from(buf).await?;
+    Ok((len, from, None))
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod ttl {
+    //! `IP_RECVTTL`: the kernel attaches the TTL of each datagram as ancillary data.
+
+    use std::{
+        io,
+        net::{Ipv4Addr, SocketAddr, SocketAddrV4},
+        os::fd::AsRawFd,
+    };
+    use tokio::net::UdpSocket;
+
+    pub(super) fn enable_recv_ttl(socket: &UdpSocket) -> io::Result<()> {
+        let on: libc::c_int = 1;
+        // SAFETY: `on` is a valid c_int for the duration of the call.
+        let result = unsafe {
+            libc::setsockopt(
+                socket.as_raw_fd(),
+                libc::IPPROTO_IP,
+                libc::IP_RECVTTL,
+                (&on as *const libc::c_int).cast(),
+                std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+            )
+        };
+        if result < 0 {
+            return Err(io::Error::last_os_error());
+        }
+        Ok(())
+    }
+
+    /// One non-blocking `recvmsg`, returning the payload length, the sender
+    /// and the TTL if the kernel supplied it.
+    pub(super) fn recv(
+        socket: &UdpSocket,
+        buf: &mut [u8],
+    ) -> io::Result<(usize, SocketAddr, Option<u8>)> {
+        // SAFETY: all-zero sockaddr_in and msghdr are valid; pointers set below
+        // reference locals that outlive the recvmsg call.
+        let mut from: libc::sockaddr_in = unsafe { std::mem::zeroed() };
+        let mut control = [0u8; 64];
+        let mut iov = libc::iovec {
+            iov_base: buf.as_mut_ptr().cast(),
+            iov_len: buf.len(),
+        };
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_name = (&mut from as *mut libc::sockaddr_in).cast();
+        msg.msg_namelen = std::mem::size_of::<libc::sockaddr_in>() as libc::socklen_t;
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = control.len() as _;
+
+        // SAFETY: `msg` points at valid, writable buffers of the stated sizes.
+        let len = unsafe { libc::recvmsg(socket.as_raw_fd(), &mut msg, libc::MSG_DONTWAIT) };
+        if len < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        let mut ttl = None;
+        // SAFETY: the CMSG_* macros walk the control buffer the kernel filled
+        // in, bounded by `msg.msg_controllen`.
+        unsafe {
+            let mut cmsg = libc::CMSG_FIRSTHDR(&msg);
+            while !cmsg.is_null() {
+                if (*cmsg).cmsg_level == libc::IPPROTO_IP && (*cmsg).cmsg_type == libc::IP_TTL {
+                    let value =
+                        std::ptr::read_unaligned(libc::CMSG_DATA(cmsg).cast::<libc::c_int>());
+                    ttl = u8::try_from(value).ok();
+                }
+                cmsg = libc::CMSG_NXTHDR(&msg, cmsg);
+            }
+        }
+
+        let sender = SocketAddrV4::new(
+            Ipv4Addr::from(u32::from_be(from.sin_addr.s_addr)),
+            u16::from_be(from.sin_port),
+        );
+        Ok((len as usize, SocketAddr::V4(sender), ttl))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_reply_and_classify() {
+        let reply = b"NRREFLECT1 00ff 203.0.113.5 40001 57\n";
+        assert_eq!(
+            parse_reply(reply, "00ff"),
+            Some(("203.0.113.5:40001".parse().unwrap(), Some(57), None))
+        );
+        assert_eq!(parse_reply(reply, "abcd"), None);
+        assert_eq!(
+            parse_reply(b"NRREFLECT1 00ff 203.0.113.5 40001 - 2", "00ff"),
+            Some(("203.0.113.5:40001".parse().unwrap(), None, Some(2)))
+        );
+
+        let a: SocketAddr = "198.51.100.1:3478".parse().unwrap();
+        let b: SocketAddr = "192.0.2.1:3478".parse().unwrap();
+        let public: SocketAddr = "203.0.113.5:40001".parse().unwrap();
+        let other: SocketAddr = "203.0.113.5:40002".parse().unwrap();
+        let local: SocketAddr = "192.168.1.20:50000".parse().unwrap();
+
+        assert_eq!(
+            classify_nat(Some(local), &[(a, public), (b, public)]),
+            NatType::EndpointIndependent
+        );
+        assert_eq!(
+            classify_nat(Some(local), &[(a, public), (b, other)]),
+            NatType::EndpointDependent
+        );
+        assert_eq!(classify_nat(Some(local), &[(a, public)]), NatType::Unknown);
+        assert_eq!(classify_nat(Some(local), &[(a, local)]), NatType::None);
+
+        assert_eq!(
+            parse_connect_reply(b"NRCONNECT1 00ff 203.0.113.5 closed", "00ff"),
+            Some(("203.0.113.5".parse().unwrap(), ServiceReachability::Refused))
+        );
+        assert_eq!(
+            parse_connect_reply(b"NRCONNECT1 00ff 203.0.113.5 maybe", "00ff"),
+            None
+        );
+
+        assert_eq!(hops_from_ttl(57), 7);
+        assert_eq!(hops_from_ttl(118), 10);
+    }
+
+    #[tokio::test]
+    async fn test_probe_nat_against_loopback_reflector() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 128];
+            while let Ok((len, from)) = server.recv_from(&mut buf).await {
+                let request = std::str::from_utf8(&buf[..len]).unwrap();
+                let nonce = request.split_whitespace().nth(1).unwrap().to_string();
+                let reply = format!("{} {} {} {} 64", MAGIC, nonce, from.ip(), from.port());
+                server.send_to(reply.as_bytes(), from).await.unwrap();
+            }
+        });
+
+        let report = probe_nat(vec![addr.to_string()], 1000).await.unwrap();
+        assert_eq!(report.nat_type, NatType::None);
+        assert!(!report.rebinding_detected);
+        assert_eq!(report.observations[0].forward_hops, Some(0));
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        assert_eq!(report.observations[0].reverse_hops, Some(0));
+    }
+}
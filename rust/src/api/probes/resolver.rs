@@ -0,0 +1,223 @@
+//! Shared async DNS resolvers.
+//!
+//! Building a resolver reads the system configuration and sets up its name
+//! server pool and cache, so each one is built once on first use and shared
+//! by every probe afterwards. A resolver runs its connections as tasks of the
+//! runtime it is used on and stalls once that runtime is gone, so each tokio
+//! runtime gets resolvers of its own.
+
+use crate::api::{constants::LibConstants, models::NetworkError};
+use flutter_rust_bridge::frb;
+use std::{
+    collections::VecDeque,
+    net::{IpAddr, SocketAddr},
+    sync::{Arc, Mutex, OnceLock},
+};
+use tokio::runtime::{self, Handle};
+use trust_dns_resolver::{
+    config::{NameServerConfig, Protocol, ResolverConfig, ResolverOpts},
+    TokioAsyncResolver,
+};
+
+/// The resolvers of one runtime, each built on first use.
+#[frb(ignore)]
+#[derive(Default)]
+struct Resolvers {
+    system: OnceLock<TokioAsyncResolver>,
+    trusted: OnceLock<TokioAsyncResolver>,
+    /// The resolver last built by [resolver_for], with its servers.
+    configured: Mutex<Option<(Vec<String>, TokioAsyncResolver)>>,
+}
+
+/// The resolvers of the current runtime. Only the
+/// [MAX_RESOLVER_RUNTIMES](LibConstants::MAX_RESOLVER_RUNTIMES) most recently
+/// used runtimes are remembered.
+///
+/// # Panics
+///
+/// Panics if called outside a tokio runtime.
+fn runtime_resolvers() -> Arc<Resolvers> {
+    static RUNTIMES: Mutex<VecDeque<(runtime::Id, Arc<Resolvers>)>> = Mutex::new(VecDeque::new());
+    let id = Handle::current().id();
+    let mut runtimes = RUNTIMES.lock().unwrap_or_else(|e| e.into_inner());
+    if let Some(index) = runtimes.iter().position(|(r, _)| *r == id) {
+        let entry = runtimes.remove(index).expect("index exists");
+        runtimes.push_back(entry);
+        return Arc::clone(&runtimes.back().expect("just pushed").1);
+    }
+    if runtimes.len() == LibConstants::MAX_RESOLVER_RUNTIMES {
+        runtimes.pop_front();
+    }
+    let resolvers = Arc::new(Resolvers::default());
+    runtimes.push_back((id, Arc::clone(&resolvers)));
+    resolvers
+}
+
+/// The resolver configured in the operating system, e.g. `/etc/resolv.conf`.
+///
+/// Fails with [NetworkError::DnsResolutionError] if the system configuration
+/// is unreadable; later calls retry reading it.
+pub(crate) fn system_resolver() -> Result<TokioAsyncResolver, NetworkError> {
+    let resolvers = runtime_resolvers();
+    if let Some(resolver) = resolvers.system.get() {
+        return Ok(resolver.clone());
+    }
+    let resolver = TokioAsyncResolver::tokio_from_system_conf()
+        .map_err(|e| NetworkError::DnsResolutionError(e.to_string()))?;
+    Ok(resolvers.system.get_or_init(|| resolver).clone())
+}
+
+/// A resolver that bypasses the system configuration and asks Cloudflare.
+pub(crate) fn trusted_resolver() -> TokioAsyncResolver {
+    runtime_resolvers()
+        .trusted
+        .get_or_init(|| {
+            TokioAsyncResolver::tokio(ResolverConfig::cloudflare(), ResolverOpts::default())
+        })
+        .clone()
+}
+
+/// A resolver asking only `servers` (`ip:port`), e.g. the
+/// [trusted_dns_servers](crate::api::models::EndpointConfig::trusted_dns_servers)
+/// of a configuration.
+///
+/// Consecutive calls with the same servers share one resolver and its cache.
+///
+/// Fails with [NetworkError::InvalidConfiguration] naming the first entry
+/// that is not a socket address.
+pub(crate) fn resolver_for(servers: &[String]) -> Result<TokioAsyncResolver, NetworkError> {
+    let resolvers = runtime_resolvers();
+    let mut configured = resolvers
+        .configured
+        .lock()
+        .unwrap_or_else(|e| e.into_inner());
+    if let Some((cached, resolver)) = configured.as_ref() {
+        if cached == servers {
+            return Ok(resolver.clone());
+        }
+    }
+    let mut config = ResolverConfig::new();
+    for server in servers {
+        let addr: SocketAddr = server.trim().parse().map_err(|_| {
+            NetworkError::InvalidConfiguration(format!("invalid DNS server: '{}'", server))
+        })?;
+        config.add_name_server(NameServerConfig::new(addr, Protocol::Udp));
+    }
+    let resolver = TokioAsyncResolver::tokio(config, ResolverOpts::default());
+    *configured = Some((servers.to_vec(), resolver.clone()));
+    Ok(resolver)
+}
+
+/// [resolver_for] `servers`, or the [trusted_resolver] if there are none.
+pub(crate) fn trusted_resolver_for(servers: &[String]) -> Result<TokioAsyncResolver, NetworkError> {
+    if servers.is_empty() {
+        Ok(trusted_resolver())
+    } else {
+        resolver_for(servers)
+    }
+}
+
+/// The addresses the system resolver returns for `host`, paired with `port`.
+/// IP literals are returned as they are.
+pub(crate) async fn resolve_host(host: &str, port: u16) -> Result<Vec<SocketAddr>, NetworkError> {
+    let ips = lookup_ips(&system_resolver()?, host).await?;
+    if ips.is_empty() {
+        return Err(NetworkError::DnsResolutionError(format!(
+            "no addresses for '{}'",
+            host
+        )));
+    }
+    Ok(ips
+        .into_iter()
+        .map(|ip| SocketAddr::new(ip, port))
+        .collect())
+}
+
+/// [resolve_host] for a `host:port` server address such as `time.example:123`.
+///
+/// Fails with [NetworkError::InvalidConfiguration] if `server` has no port.
+pub(crate) async fn resolve_server(server: &str) -> Result<Vec<SocketAddr>, NetworkError> {
+    if let Ok(addr) = server.parse::<SocketAddr>() {
+        return Ok(vec![addr]);
+    }
+    let (host, port) = server
+        .rsplit_once(':')
+        .and_then(|(host, port)| Some((host, port.parse().ok()?)))
+        .ok_or_else(|| {
+            NetworkError::InvalidConfiguration(format!("invalid server address: '{}'", server))
+        })?;
+    resolve_host(host, port).await
+}
+
+/// The addresses `resolver` returns for `host`.
+pub(crate) async fn lookup_ips(
+    resolver: &TokioAsyncResolver,
+    host: &str,
+) -> Result<Vec<IpAddr>, NetworkError> {
+    let lookup = resolver
+        .lookup_ip(host)
+        .await
+        .map_err(|e| NetworkError::DnsResolutionError(e.to_string()))?;
+    Ok(lookup.iter().collect())
+}
+
+/// The host name the system resolver returns for `ip`, if any.
+pub(crate) async fn reverse_name(ip: IpAddr) -> Option<String> {
+    let lookup = system_resolver().ok()?.reverse_lookup(ip).await.ok()?;
+    let name = lookup.iter().next()?.to_utf8();
+    let name = name.trim_end_matches('.');
+    (!name.is_empty()).then(|| name.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_resolvers_are_shared_per_runtime() {
+        let shared = runtime_resolvers();
+        assert!(Arc::ptr_eq(&shared, &runtime_resolvers()));
+        // IP literals are answered without a query.
+        let ips = lookup_ips(&trusted_resolver(), "192.0.2.7").await.unwrap();
+        assert_eq!(ips, ["192.0.2.7".parse::<IpAddr>().unwrap()]);
+
+        let other = std::thread::spawn(|| {
+            let runtime = tokio::runtime::Builder::new_current_thread()
+                .build()
+                .unwrap();
+            runtime.block_on(async { runtime_resolvers() })
+        })
+        .join()
+        .unwrap();
+        assert!(!Arc::ptr_eq(&shared, &other));
+    }
+
+    #[tokio::test]
+    async fn test_resolve_server() {
+        let addrs = resolve_server("192.0.2.7:123").await.unwrap();
+        assert_eq!(addrs, ["192.0.2.7:123".parse::<SocketAddr>().unwrap()]);
+        let addrs = resolve_server("127.0.0.1:53").await.unwrap();
+        assert_eq!(addrs[0].port(), 53);
+        assert!(matches!(
+            resolve_server("time.example").await,
+            Err(NetworkError::InvalidConfiguration(_))
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_resolver_for_rejects_invalid_servers() {
+        assert!(resolver_for(&["127.0.0.1:5353".into()]).is_ok());
+        assert!(matches!(
+            resolver_for(&["127.0.0.1:5353".into(), "dns.example".into()]),
+            Err(NetworkError::InvalidConfiguration(e)) if e.contains("dns.example")
+        ));
+        let servers = ["127.0.0.1:5353".to_string()];
+        assert!(resolver_for(&servers).is_ok());
+        assert!(runtime_resolvers()
+            .configured
+            .lock()
+            .unwrap()
+            .as_ref()
+            .is_some_and(|(cached, _)| cached == &servers));
+    }
+}
@@ -0,0 +1,405 @@
+//! Probing another routing domain: a network namespace or a VRF (Linux).
+//!
+//! Routers and CPEs separate routing domains with network namespaces
+//! (`ip netns add mgmt`) or VRF devices (`ip link add vrf-blue type vrf`). A
+//! [RoutingDomainTransport] creates every probe socket inside the chosen
+//! namespace and binds it to the VRF device, so a check measures that domain
+//! instead of the one the process runs in:
+//!
+//! ```ignore
+//! let transport = RoutingDomainTransport::namespace("mgmt")?.with_vrf("vrf-blue");
+//! let engine = ReachabilityEngine::new(config).with_transport(Arc::new(transport));
+//! ```
+//!
+//! A socket stays in the namespace it was created in, so each namespace gets
+//! one long-lived thread that joined it (`setns`, which needs
+//! `CAP_SYS_ADMIN`) and creates the sockets and runs the name lookups; all
+//! other I/O happens on the caller's runtime as usual. Binding to a VRF needs
+//! `CAP_NET_RAW`. HTTP(S) targets are probed through a VRF but not inside a
+//! namespace, and ICMP targets are not probed (see [super::transport]).
+
+use super::{
+    qos::apply_dscp,
+    transport::{
+        exchange_udp, NetworkTransport, TokioTransport, TransportFuture, TransportInterface,
+        TransportStream,
+    },
+};
+use flutter_rust_bridge::frb;
+use socket2::{Domain, Protocol, SockRef, Socket, Type};
+use std::{
+    fs::File,
+    future::Future,
+    io,
+    net::SocketAddr,
+    os::fd::AsRawFd,
+    path::{Path, PathBuf},
+    pin::Pin,
+    sync::{mpsc as std_mpsc, Arc},
+};
+use tokio::{
+    net::{TcpSocket, UdpSocket},
+    sync::{mpsc, oneshot},
+};
+use trust_dns_resolver::{
+    config::{ResolverConfig, ResolverOpts},
+    system_conf::parse_resolv_conf,
+    TokioAsyncResolver,
+};
+
+/// Where `ip netns` keeps the namespaces it names.
+const NETNS_RUN_DIR: &str = "/var/run/netns";
+/// Where `ip netns exec` finds the configuration files of a named namespace.
+const NETNS_ETC_DIR: &str = "/etc/netns";
+const RESOLV_CONF: &str = "/etc/resolv.conf";
+
+/// A [NetworkTransport] whose sockets live in another network namespace
+/// and/or are bound to a VRF device.
+#[frb(ignore)]
+#[derive(Debug, Clone, Default)]
+pub struct RoutingDomainTransport {
+    namespace: Option<Arc<NamespaceThread>>,
+    vrf: Option<String>,
+}
+
+impl RoutingDomainTransport {
+    /// Probes inside the network namespace `name`, either one named by
+    /// `ip netns` or the path of a namespace file such as `/proc/<pid>/ns/net`.
+    ///
+    /// Names are resolved with the namespace's `/etc/netns/<name>/resolv.conf`
+    /// as `ip netns exec` would, else with `/etc/resolv.conf`, else with the
+    /// trusted resolver (Cloudflare); the queries leave through the namespace.
+    ///
+    /// Fails if the namespace file cannot be opened or joined.
+    pub fn namespace(name: &str) -> io::Result<Self> {
+        let (path, resolv_conf) = if name.contains('/') {
+            (Path::new(name).to_path_buf(), None)
+        } else {
+            let resolv_conf = Path::new(NETNS_ETC_DIR).join(name).join("resolv.conf");
+            (Path::new(NETNS_RUN_DIR).join(name), Some(resolv_conf))
+        };
+        let namespace = File::open(path)?;
+        let resolver = namespace_resolver(resolv_conf);
+        Ok(Self {
+            namespace: Some(Arc::new(NamespaceThread::spawn(namespace, resolver)?)),
+            vrf: None,
+        })
+    }
+
+    /// Probes through the VRF device `device` of the current namespace.
+    pub fn vrf(device: impl Into<String>) -> Self {
+        Self::default().with_vrf(device)
+    }
+
+    /// Also binds every socket to the VRF device `device`.
+    ///
+    /// Name resolution does not go through the VRF; the resolver of the
+    /// namespace answers as for any other process.
+    pub fn with_vrf(mut self, device: impl Into<String>) -> Self {
+        self.vrf = Some(device.into());
+        self
+    }
+
+    /// A non-blocking socket in this routing domain.
+    async fn socket(&self, addr: SocketAddr, ty: Type, protocol: Protocol) -> io::Result<Socket> {
+        let domain = Domain::for_address(addr);
+        let socket = match &self.namespace {
+            Some(namespace) => {
+                namespace
+                    .run(async move { Socket::new(domain, ty, Some(protocol)) })
+                    .await?
+            }
+            None => Socket::new(domain, ty, Some(protocol))?,
+        };
+        if let Some(vrf) = &self.vrf {
+            socket.bind_device(Some(vrf.as_bytes()))?;
+        }
+        socket.set_nonblocking(true)?;
+        Ok(socket)
+    }
+}
+
+#[frb(ignore)]
+impl NetworkTransport for RoutingDomainTransport {
+    fn connect_tcp(
+        &self,
+        addr: SocketAddr,
+        dscp: Option<u8>,
+    ) -> TransportFuture<'_, Box<dyn TransportStream>> {
+        Box::pin(async move {
+            let socket = self.socket(addr, Type::STREAM, Protocol::TCP).await?;
+            if let Some(dscp) = dscp {
+                apply_dscp(SockRef::from(&socket), addr.is_ipv4(), dscp)?;
+            }
+            let socket = TcpSocket::from_std_stream(socket.into());
+            let stream = socket.connect(addr).await?;
+            Ok(Box::new(stream) as Box<dyn TransportStream>)
+        })
+    }
+
+    fn send_udp<'a>(
+        &'a self,
+        addr: SocketAddr,
+        payload: &'a [u8],
+        dscp: Option<u8>,
+        matches: &'a (dyn Fn(&[u8]) -> bool + Send + Sync),
+    ) -> TransportFuture<'a, Vec<u8>> {
+        Box::pin(async move {
+            let socket = self.socket(addr, Type::DGRAM, Protocol::UDP).await?;
+            let unspecified = match addr {
+                SocketAddr::V4(_) => SocketAddr::from(([0, 0, 0, 0], 0)),
+                SocketAddr::V6(_) => SocketAddr::from(([0u16; 8], 0)),
+            };
+            socket.bind(&unspecified.into())?;
+            let socket = UdpSocket::from_std(socket.into())?;
+            exchange_udp(socket, addr, payload, dscp, matches).await
+        })
+    }
+
+    fn resolve<'a>(&'a self, host: &'a str, port: u16) -> TransportFuture<'a, Vec<SocketAddr>> {
+        let Some(namespace) = self.namespace.clone() else {
+            return TokioTransport.resolve(host, port);
+        };
+        // The resolver's sockets are created, and stay, in the namespace.
+        let resolver = namespace.resolver.clone();
+        let host = host.to_string();
+        Box::pin(async move {
+            namespace
+                .run(async move {
+                    let lookup = resolver.lookup_ip(host).await.map_err(io::Error::other)?;
+                    Ok(lookup.iter().map(|ip| SocketAddr::new(ip, port)).collect())
+                })
+                .await
+        })
+    }
+
+    fn list_interfaces(&self) -> io::Result<Vec<TransportInterface>> {
+        match &self.namespace {
+            Some(namespace) => namespace.run_blocking(|| TokioTransport.list_interfaces()),
+            None => TokioTransport.list_interfaces(),
+        }
+    }
+
+    fn supports_http(&self) -> bool {
+        // reqwest creates its sockets on the caller's thread, which is in the
+        // process's namespace, but can bind them to a device.
+        self.namespace.is_none()
+    }
+
+    fn http_client(&self) -> reqwest::ClientBuilder {
+        let builder = reqwest::Client::builder();
+        match &self.vrf {
+            Some(vrf) => builder.interface(vrf),
+            None => builder,
+        }
+    }
+}
+
+/// The resolver used inside a namespace: the first readable of `resolv_conf`
+/// and `/etc/resolv.conf`, else Cloudflare.
+fn namespace_resolver(resolv_conf: Option<PathBuf>) -> TokioAsyncResolver {
+    let (config, opts) = resolv_conf
+        .into_iter()
+        .chain([PathBuf::from(RESOLV_CONF)])
+        .find_map(|path| parse_resolv_conf(std::fs::read(path).ok()?).ok())
+        .unwrap_or_else(|| (ResolverConfig::cloudflare(), ResolverOpts::default()));
+    TokioAsyncResolver::tokio(config, opts)
+}
+
+type Job = Pin<Box<dyn Future<Output = ()> + Send>>;
+
+/// A thread that joined a network namespace for good, running the jobs sent
+/// to it on a runtime of its own. It exits once the last transport sharing
+/// it is dropped.
+#[derive(Debug)]
+struct NamespaceThread {
+    jobs: mpsc::UnboundedSender<Job>,
+    /// Only used from jobs, so its connections run in the namespace.
+    resolver: TokioAsyncResolver,
+}
+
+impl NamespaceThread {
+    /// Starts the thread, failing if it cannot join `namespace`.
+    fn spawn(namespace: File, resolver: TokioAsyncResolver) -> io::Result<Self> {
+        let runtime = tokio::runtime::Builder::new_current_thread()
+            .enable_all()
+            .build()?;
+        let (jobs, mut queue) = mpsc::unbounded_channel::<Job>();
+        let (joined_tx, joined_rx) = std_mpsc::channel();
+        std::thread::Builder::new()
+            .name("netns-probe".into())
+            .spawn(move || {
+                let joined = join_namespace(&namespace);
+                let ok = joined.is_ok();
+                let _ = joined_tx.send(joined);
+                if !ok {
+                    return;
+                }
+                runtime.block_on(async move {
+                    while let Some(job) = queue.recv().await {
+                        tokio::spawn(job);
+                    }
+                });
+            })?;
+        joined_rx
+            .recv()
+            .unwrap_or_else(|_| Err(io::Error::other("namespace thread panicked")))?;
+        Ok(Self { jobs, resolver })
+    }
+
+    /// Runs `job` on the namespace thread.
+    async fn run<T: Send + 'static>(
+        &self,
+        job: impl Future<Output = io::Result<T>> + Send + 'static,
+    ) -> io::Result<T> {
+        let (tx, rx) = oneshot::channel();
+        self.jobs
+            .send(Box::pin(async move {
+                let _ = tx.send(job.await);
+            }))
+            .map_err(|_| io::Error::other("namespace thread exited"))?;
+        rx.await
+            .unwrap_or_else(|_| Err(io::Error::other("namespace thread panicked")))
+    }
+
+    /// Runs `job` on the namespace thread, blocking until it is done.
+    fn run_blocking<T: Send + 'static>(
+        &self,
+        job: impl FnOnce() -> io::Result<T> + Send + 'static,
+    ) -> io::Result<T> {
+        let (tx, rx) = std_mpsc::channel();
+        self.jobs
+            .send(Box::pin(async move {
+                let _ = tx.send(job());
+            }))
+            .map_err(|_| io::Error::other("namespace thread exited"))?;
+        rx.recv()
+            .unwrap_or_else(|_| Err(io::Error::other("namespace thread panicked")))
+    }
+}
+
+/// Moves the calling thread into the network namespace of `namespace`.
+fn join_namespace(namespace: &File) -> io::Result<()> {
+    // SAFETY: the descriptor is valid for the call, and setns only affects
+    // the calling thread, which is dedicated to the namespace.
+    if unsafe { libc::setns(namespace.as_raw_fd(), libc::CLONE_NEWNET) } == 0 {
+        Ok(())
+    } else {
+        Err(io::Error::last_os_error())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{
+        engine::check_network,
+        models::{NetworkConfiguration, NetworkTarget, TargetProtocol},
+        probes::{check_target, with_transport},
+        testsupport::TestServer,
+    };
+    use std::process::Command;
+
+    #[tokio::test]
+    async fn test_probes_run_in_the_namespace() {
+        assert_eq!(
+            RoutingDomainTransport::namespace("no-such-namespace")
+                .unwrap_err()
+                .kind(),
+            io::ErrorKind::NotFound
+        );
+
+        // Our own namespace; joining it still needs CAP_SYS_ADMIN.
+        let transport = match RoutingDomainTransport::namespace("/proc/self/ns/net") {
+            Ok(transport) => transport,
+            Err(e) => {
+                assert_eq!(e.kind(), io::ErrorKind::PermissionDenied);
+                return;
+            }
+        };
+        let tcp = TestServer::tcp_echo().start().await.unwrap();
+        let udp = TestServer::udp_echo().start().await.unwrap();
+        let http = TestServer::http().start().await.unwrap();
+        let config = NetworkConfiguration::builder()
+            .add_target(tcp.target("tcp"))
+            .add_target(udp.target("udp"))
+            .add_target(http.target("http"))
+            .build()
+            .unwrap();
+
+        let transport = Arc::new(transport);
+        let report = with_transport(transport.clone(), check_network(config)).await;
+        let success: Vec<bool> = report.target_reports.iter().map(|r| r.success).collect();
+        assert_eq!(success, [true, true, false]);
+        let addrs = transport.resolve("192.0.2.7", 53).await.unwrap();
+        assert_eq!(addrs, ["192.0.2.7:53".parse::<SocketAddr>().unwrap()]);
+        assert!(!transport.list_interfaces().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_probes_are_bound_to_the_device() {
+        let tcp = TestServer::tcp_echo().start().await.unwrap();
+        let http = TestServer::http().start().await.unwrap();
+        for (device, reachable) in [("lo", true), ("no-such-device", false)] {
+            let transport = Arc::new(RoutingDomainTransport::vrf(device));
+            for target in [tcp.target("tcp"), http.target("http")] {
+                let report = with_transport(transport.clone(), check_target(&target)).await;
+                assert_eq!(report.success, reachable, "{}: {:?}", device, report.error);
+            }
+        }
+    }
+
+    /// Removes the VRF device of the test, also if it fails.
+    struct VrfDevice(&'static str);
+
+    impl Drop for VrfDevice {
+        fn drop(&mut self) {
+            let _ = Command::new("ip").args(["link", "del", self.0]).status();
+        }
+    }
+
+    fn ip(args: &[&str]) -> bool {
+        Command::new("ip")
+            .args(args)
+            .status()
+            .is_ok_and(|s| s.success())
+    }
+
+    #[tokio::test]
+    async fn test_probes_run_in_the_vrf() {
+        // Needs CAP_NET_ADMIN and the vrf module.
+        const VRF: &str = "vrf-nrtest";
+        if !ip(&["link", "add", VRF, "type", "vrf", "table", "1042"]) {
+            return;
+        }
+        let _device = VrfDevice(VRF);
+        assert!(ip(&["link", "set", VRF, "up"]));
+        assert!(ip(&["addr", "add", "127.0.0.1/8", "dev", VRF]));
+
+        // A listener inside the VRF, which the default table cannot reach.
+        let socket = Socket::new(Domain::IPV4, Type::STREAM, Some(Protocol::TCP)).unwrap();
+        socket.bind_device(Some(VRF.as_bytes())).unwrap();
+        socket
+            .bind(&SocketAddr::from(([127, 0, 0, 1], 0)).into())
+            .unwrap();
+        socket.listen(8).unwrap();
+        socket.set_nonblocking(true).unwrap();
+        let listener = tokio::net::TcpListener::from_std(socket.into()).unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            while let Ok((mut stream, _)) = listener.accept().await {
+                let _ = tokio::io::AsyncWriteExt::write_all(&mut stream, b"ok").await;
+            }
+        });
+
+        let target = NetworkTarget::builder("127.0.0.1", TargetProtocol::Tcp)
+            .port(port)
+            .timeout_ms(500)
+            .build();
+        let transport = Arc::new(RoutingDomainTransport::vrf(VRF));
+        assert!(transport.supports_http());
+        let report = with_transport(transport, check_target(&target)).await;
+        assert!(report.success, "{:?}", report.error);
+        assert!(!check_target(&target).await.success);
+    }
+}
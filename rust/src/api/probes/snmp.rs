@@ -0,0 +1,447 @@
+//! SNMP v2c queries against managed network devices (feature `snmp`).
+//!
+//! Reads the system description and the per-interface error and discard
+//! counters of the `ifTable` (RFC 2863), so interface problems on the gateway
+//! or a managed switch show up next to the reachability results. Messages are
+//! BER-encoded by hand; only the handful of types SNMP v2c uses are supported.
+
+use super::parse::Reader;
+use crate::api::{
+    constants::LibConstants,
+    models::{NetworkError, SnmpDeviceInfo, SnmpInterface},
+};
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+use tokio::{
+    net::UdpSocket,
+    time::{timeout, Instant},
+};
+
+const SNMP_PORT: u16 = 161;
+const SNMP_VERSION_2C: i64 = 1;
+
+const TAG_INTEGER: u8 = 0x02;
+const TAG_OCTET_STRING: u8 = 0x04;
+const TAG_NULL: u8 = 0x05;
+const TAG_OID: u8 = 0x06;
+const TAG_SEQUENCE: u8 = 0x30;
+const TAG_COUNTER32: u8 = 0x41;
+const TAG_GAUGE32: u8 = 0x42;
+const TAG_TIMETICKS: u8 = 0x43;
+const TAG_COUNTER64: u8 = 0x46;
+const PDU_GET: u8 = 0xa0;
+const PDU_GET_NEXT: u8 = 0xa1;
+const PDU_RESPONSE: u8 = 0xa2;
+
+const SYS_DESCR: &[u32] = &[1, 3, 6, 1, 2, 1, 1, 1, 0];
+const IF_ENTRY: &[u32] = &[1, 3, 6, 1, 2, 1, 2, 2, 1];
+const IF_INDEX: u32 = 1;
+const IF_DESCR: u32 = 2;
+const IF_IN_DISCARDS: u32 = 13;
+const IF_IN_ERRORS: u32 = 14;
+const IF_OUT_DISCARDS: u32 = 19;
+const IF_OUT_ERRORS: u32 = 20;
+
+/// Decoded variable bindings: `(OID, value)` pairs in request order.
+type VarBinds = Vec<(Vec<u32>, SnmpValue)>;
+
+/// A decoded variable binding value.
+#[derive(Debug, Clone, PartialEq)]
+pub(crate) enum SnmpValue {
+    Integer(i64),
+    Bytes(Vec<u8>),
+    Oid(Vec<u32>),
+    Unsigned(u64),
+    Null,
+    /// `noSuchObject`, `noSuchInstance`, `endOfMibView` or an unknown type.
+    Missing,
+}
+
+/// Queries `host` for its system description and interface counters.
+///
+/// At most [MAX_SNMP_INTERFACES](LibConstants::MAX_SNMP_INTERFACES) interfaces
+/// are read. The whole query is bounded by `timeout_ms`.
+pub async fn query_snmp_device(
+    host: IpAddr,
+    community: &str,
+    timeout_ms: u64,
+) -> Result<SnmpDeviceInfo, NetworkError> {
+    let deadline = Instant::now() + Duration::from_millis(timeout_ms);
+    let bind: SocketAddr = if host.is_ipv4() {
+        "0.0.0.0:0".parse().unwrap()
+    } else {
+        "[::]:0".parse().unwrap()
+    };
+    let socket = UdpSocket::bind(bind).await?;
+    socket.connect(SocketAddr::new(host, SNMP_PORT)).await?;
+    let session = Session {
+        socket,
+        community,
+        deadline,
+    };
+
+    let sys_descr = match session.request(PDU_GET, &[SYS_DESCR.to_vec()]).await?.pop() {
+        Some((_, SnmpValue::Bytes(bytes))) => {
+            Some(String::from_utf8_lossy(&bytes).trim().to_string())
+        }
+        _ => None,
+    };
+
+    // Walk the ifIndex column to learn which interfaces exist.
+    let column = column_oid(IF_INDEX, None);
+    let mut next = column.clone();
+    let mut indices = Vec::new();
+    while indices.len() < LibConstants::MAX_SNMP_INTERFACES {
+        let Some((oid, value)) = session.request(PDU_GET_NEXT, &[next]).await?.pop() else {
+            break;
+        };
+        if !oid.starts_with(&column) || value == SnmpValue::Missing {
+            break;
+        }
+        indices.extend(oid.last().copied());
+        next = oid;
+    }
+
+    let mut interfaces = Vec::with_capacity(indices.len());
+    for index in indices {
+        let oids: Vec<Vec<u32>> = [
+            IF_DESCR,
+            IF_IN_ERRORS,
+            IF_OUT_ERRORS,
+            IF_IN_DISCARDS,
+            IF_OUT_DISCARDS,
+        ]
+        .iter()
+        .map(|&column| column_oid(column, Some(index)))
+        .collect();
+        let values: Vec<SnmpValue> = session
+            .request(PDU_GET, &oids)
+            .await?
+            .into_iter()
+            .map(|(_, v)| v)
+            .collect();
+        let counter = |i: usize| match values.get(i) {
+            Some(SnmpValue::Unsigned(n)) => *n,
+            _ => 0,
+        };
+        interfaces.push(SnmpInterface {
+            index,
+            description: match values.first() {
+                Some(SnmpValue::Bytes(bytes)) => String::from_utf8_lossy(bytes).trim().to_string(),
+                _ => String::new(),
+            },
+            in_errors: counter(1),
+            out_errors: counter(2),
+            in_discards: counter(3),
+            out_discards: counter(4),
+        });
+    }
+
+    Ok(SnmpDeviceInfo {
+        address: host.to_string(),
+        sys_descr,
+        interfaces,
+        error: None,
+    })
+}
+
+fn column_oid(column: u32, index: Option<u32>) -> Vec<u32> {
+    let mut oid = IF_ENTRY.to_vec();
+    oid.push(column);
+    oid.extend(index);
+    oid
+}
+
+struct Session<'a> {
+    socket: UdpSocket,
+    community: &'a str,
+    deadline: Instant,
+}
+
+impl Session<'_> {
+    /// Sends one PDU and waits for the response with the matching request ID.
+    async fn request(&self, pdu: u8, oids: &[Vec<u32>]) -> Result<VarBinds, NetworkError> {
+        let request_id = i64::from(rand::random::<u16>());
+        let message = build_request(pdu, self.community, request_id, oids);
+        self.socket.send(&message).await?;
+
+        let mut buf = vec![0u8; 65_507];
+        loop {
+            let remaining = self.deadline.saturating_duration_since(Instant::now());
+            let len = timeout(remaining, self.socket.recv(&mut buf)).await??;
+            if let Some(bindings) = parse_response(&buf[..len], request_id)? {
+                return Ok(bindings);
+            }
+        }
+    }
+}
+
+/// Encodes an SNMP v2c message carrying `pdu` with a NULL value per OID.
+pub(crate) fn build_request(
+    pdu: u8,
+    community: &str,
+    request_id: i64,
+    oids: &[Vec<u32>],
+) -> Vec<u8> {
+    let bindings: Vec<u8> = oids
+        .iter()
+        .flat_map(|oid| {
+            tlv(
+                TAG_SEQUENCE,
+                &[tlv(TAG_OID, &encode_oid(oid)), tlv(TAG_NULL, &[])].concat(),
+            )
+        })
+        .collect();
+    let pdu = tlv(
+        pdu,
+        &[
+            tlv(TAG_INTEGER, &encode_integer(request_id)),
+            tlv(TAG_INTEGER, &encode_integer(0)),
+            tlv(TAG_INTEGER, &encode_integer(0)),
+            tlv(TAG_SEQUENCE, &bindings),
+        ]
+        .concat(),
+    );
+    tlv(
+        TAG_SEQUENCE,
+        &[
+            tlv(TAG_INTEGER, &encode_integer(SNMP_VERSION_2C)),
+            tlv(TAG_OCTET_STRING, community.as_bytes()),
+            pdu,
+        ]
+        .concat(),
+    )
+}
+
+/// Decodes a response message.
+///
+/// Returns `Ok(None)` for a well-formed message that answers a different
+/// request, and an error if the agent reported one.
+pub(crate) fn parse_response(
+    buf: &[u8],
+    request_id: i64,
+) -> Result<Option<VarBinds>, NetworkError> {
+    let malformed = || NetworkError::ConnectionError("Malformed SNMP response".to_string());
+    let (tag, message, _) = read_tlv(buf).ok_or_else(malformed)?;
+    if tag != TAG_SEQUENCE {
+        return Err(malformed());
+    }
+    let (_, _version, rest) = read_tlv(message).ok_or_else(malformed)?;
+    let (_, _community, rest) = read_tlv(rest).ok_or_else(malformed)?;
+    let (tag, pdu, _) = read_tlv(rest).ok_or_else(malformed)?;
+    if tag != PDU_RESPONSE {
+        return Err(malformed());
+    }
+
+    let (_, id, rest) = read_tlv(pdu).ok_or_else(malformed)?;
+    if decode_integer(id) != request_id {
+        return Ok(None);
+    }
+    let (_, status, rest) = read_tlv(rest).ok_or_else(malformed)?;
+    let status = decode_integer(status);
+    if status != 0 {
+        return Err(NetworkError::ConnectionError(format!(
+            "SNMP agent returned error-status {}",
+            status
+        )));
+    }
+    let (_, _index, rest) = read_tlv(rest).ok_or_else(malformed)?;
+    let (_, mut list, _) = read_tlv(rest).ok_or_else(malformed)?;
+
+    let mut bindings = Vec::new();
+    while !list.is_empty() {
+        let (_, binding, rest) = read_tlv(list).ok_or_else(malformed)?;
+        let (_, oid, value) = read_tlv(binding).ok_or_else(malformed)?;
+        let (tag, value, _) = read_tlv(value).ok_or_else(malformed)?;
+        bindings.push((decode_oid(oid), decode_value(tag, value)));
+        list = rest;
+    }
+    Ok(Some(bindings))
+}
+
+fn decode_value(tag: u8, content: &[u8]) -> SnmpValue {
+    match tag {
+        TAG_INTEGER => SnmpValue::Integer(decode_integer(content)),
+        TAG_OCTET_STRING => SnmpValue::Bytes(content.to_vec()),
+        TAG_OID => SnmpValue::Oid(decode_oid(content)),
+        TAG_COUNTER32 | TAG_GAUGE32 | TAG_TIMETICKS | TAG_COUNTER64 => {
+            SnmpValue::Unsigned(content.iter().fold(0u64, |n, b| (n << 8) | u64::from(*b)))
+        }
+        TAG_NULL => SnmpValue::Null,
+        _ => SnmpValue::Missing,
+    }
+}
+
+/// Splits the first TLV off `buf`: `(tag, content, rest)`.
+fn read_tlv(buf: &[u8]) -> Option<(u8, &[u8], &[u8])> {
+    let mut reader = Reader::new(buf);
+    let tag = reader.u8()?;
+    let first = reader.u8()?;
+    let len = if first & 0x80 == 0 {
+        usize::from(first)
+    } else {
+        let count = usize::from(first & 0x7f);
+        if count == 0 || count > 4 {
+            return None;
+        }
+        let len = reader.bytes(count)?;
+        usize::try_from(len.iter().fold(0u32, |n, b| (n << 8) | u32::from(*b))).ok()?
+    };
+    let content = reader.bytes(len)?;
+    Some((tag, content, reader.rest()))
+}
+
+fn tlv(tag: u8, content: &[u8]) -> Vec<u8> {
+    let mut out = vec![tag];
+    let len = content.len();
+    if len < 0x80 {
+        out.push(len as u8);
+    } else {
+        let bytes: Vec<u8> = len
+            .to_be_bytes()
+            .into_iter()
+            .skip_while(|b| *b == 0)
+            .collect();
+        out.push(0x80 | bytes.len() as u8);
+        out.extend(bytes);
+    }
+    out.extend_from_slice(content);
+    out
+}
+
+fn encode_integer(value: i64) -> Vec<u8> {
+    let bytes = value.to_be_bytes();
+    // Drop redundant leading bytes while keeping the sign bit intact.
+    let mut start = 0;
+    while start < 7
+        && ((bytes[start] == 0 && bytes[start + 1] & 0x80 == 0)
+            || (bytes[start] == 0xff && bytes[start + 1] & 0x80 != 0))
+    {
+        start += 1;
+    }
+    bytes[start..].to_vec()
+}
+
+fn decode_integer(content: &[u8]) -> i64 {
+    let negative = content.first().is_some_and(|b| b & 0x80 != 0);
+    let init = if negative { -1i64 } else { 0 };
+    content
+        .iter()
+        .take(8)
+        .fold(init, |n, b| (n << 8) | i64::from(*b))
+}
+
+fn encode_oid(oid: &[u32]) -> Vec<u8> {
+    let mut out = Vec::new();
+    if let [first, second, rest @ ..] = oid {
+        out.extend(encode_base128(
+            first.saturating_mul(40).saturating_add(*second),
+        ));
+        for arc in rest {
+            out.extend(encode_base128(*arc));
+        }
+    }
+    out
+}
+
+fn encode_base128(mut value: u32) -> Vec<u8> {
+    let mut out = vec![(value & 0x7f) as u8];
+    value >>= 7;
+    while value > 0 {
+        out.push(0x80 | (value & 0x7f) as u8);
+        value >>= 7;
+    }
+    out.reverse();
+    out
+}
+
+fn decode_oid(content: &[u8]) -> Vec<u32> {
+    let mut arcs = Vec::new();
+    let mut value = 0u32;
+    for byte in content {
+        value = (value << 7) | u32::from(byte & 0x7f);
+        if byte & 0x80 == 0 {
+            if arcs.is_empty() {
+                let first = (value / 40).min(2);
+                arcs.push(first);
+                arcs.push(value - first * 40);
+            } else {
+                arcs.push(value);
+            }
+            value = 0;
+        }
+    }
+    arcs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Encodes a GetResponse the way an agent would.
+    fn response(request_id: i64, bindings: &[(Vec<u32>, u8, Vec<u8>)]) -> Vec<u8> {
+        let list: Vec<u8> = bindings
+            .iter()
+            .flat_map(|(oid, tag, value)| {
+                tlv(
+                    TAG_SEQUENCE,
+                    &[tlv(TAG_OID, &encode_oid(oid)), tlv(*tag, value)].concat(),
+                )
+            })
+            .collect();
+        let pdu = tlv(
+            PDU_RESPONSE,
+            &[
+                tlv(TAG_INTEGER, &encode_integer(request_id)),
+                tlv(TAG_INTEGER, &[0]),
+                tlv(TAG_INTEGER, &[0]),
+                tlv(TAG_SEQUENCE, &list),
+            ]
+            .concat(),
+        );
+        let body = [
+            tlv(TAG_INTEGER, &[1]),
+            tlv(TAG_OCTET_STRING, b"public"),
+            pdu,
+        ]
+        .concat();
+        tlv(TAG_SEQUENCE, &body)
+    }
+
+    #[test]
+    fn test_snmp_message_round_trip() {
+        let request = build_request(PDU_GET, "public", 300, &[SYS_DESCR.to_vec()]);
+        // SEQUENCE { INTEGER 1, OCTET STRING "public", GetRequest { ... } }
+        assert_eq!(&request[..2], &[TAG_SEQUENCE, request.len() as u8 - 2]);
+        assert_eq!(&request[2..5], &[TAG_INTEGER, 1, 1]);
+        assert_eq!(encode_integer(300), vec![0x01, 0x2c]);
+        assert_eq!(encode_integer(128), vec![0x00, 0x80]);
+        assert_eq!(decode_integer(&encode_integer(-129)), -129);
+
+        let long_descr = vec![b'x'; 200];
+        let reply = response(
+            300,
+            &[
+                (SYS_DESCR.to_vec(), TAG_OCTET_STRING, long_descr.clone()),
+                (
+                    column_oid(IF_IN_ERRORS, Some(3)),
+                    TAG_COUNTER32,
+                    vec![0x01, 0x00],
+                ),
+                (column_oid(IF_OUT_ERRORS, Some(3)), 0x81, vec![]),
+            ],
+        );
+        assert_eq!(parse_response(&reply, 301).unwrap(), None);
+        assert_eq!(
+            parse_response(&reply, 300).unwrap().unwrap(),
+            vec![
+                (SYS_DESCR.to_vec(), SnmpValue::Bytes(long_descr)),
+                (column_oid(IF_IN_ERRORS, Some(3)), SnmpValue::Unsigned(256)),
+                (column_oid(IF_OUT_ERRORS, Some(3)), SnmpValue::Missing),
+            ]
+        );
+        assert!(parse_response(&reply[..reply.len() - 1], 300).is_err());
+    }
+}
@@ -1,41 +1,96 @@
 //! Probe for checking a single network target.
 
-use crate::api::models::{NetworkTarget, TargetProtocol, TargetReport};
+use crate::api::constants::LibConstants;
+use crate::api::models::{
+    AddressFamily, NetworkError, NetworkTarget, ProbeTimings, TargetProtocol, TargetReport,
+};
 use crate::api::probes::base::NetworkProbe;
+use crate::api::probes::capabilities::{detect_probe_capabilities, ping_fallback};
+use crate::api::probes::parse::read_body;
+use crate::api::probes::qos::ping_marked;
+use crate::api::probes::transport;
+use std::net::SocketAddr;
+use surge_ping::SurgeError;
 
 /// Native implementation of network reachability checks.
 pub struct NativeProbe {}
 
 impl NetworkProbe for NativeProbe {
     async fn check(&self, target: &NetworkTarget) -> TargetReport {
-        use crate::api::models::NetworkError;
+        self.check_resolved(target, None).await
+    }
+}
+
+impl NativeProbe {
+    /// Checks `target`, connecting to `resolved` instead of resolving the host
+    /// when an address is given.
+    ///
+    /// The reported latency then covers the probe alone and `dns_ms` is left
+    /// `None` for the caller to fill in.
+    pub(crate) async fn check_resolved(
+        &self,
+        target: &NetworkTarget,
+        resolved: Option<SocketAddr>,
+    ) -> TargetReport {
         use std::time::{Duration, Instant};
         use tokio::{
             io::{AsyncReadExt, AsyncWriteExt},
-            net::TcpStream,
             time::timeout,
         };
 
         let start = Instant::now();
-        let addr_str = format!("{}:{}", target.host, target.port);
         let timeout_duration = Duration::from_millis(target.timeout_ms);
 
+        // Filled in from inside the timeout block so that a timeout still
+        // reports the phases that did complete.
+        let mut dns_ms = None;
+        let mut connect_ms = None;
+        // The probe's own round trip, where it measures one; otherwise the
+        // reported latency is the whole elapsed time.
+        let mut rtt_ms = None;
+        let mut last_progress_ms = None;
+        let mut probed_addr = None;
+        let mut fallback = None;
+        let is_ip_literal = target.host.parse::<std::net::IpAddr>().is_ok();
+
         let result = timeout(timeout_duration, async {
-            let mut addrs = tokio::net::lookup_host(&addr_str)
-                .await
-                .map_err(|e| NetworkError::DnsResolutionError(e.to_string()))?;
+            let addrs = match resolved {
+                Some(addr) => vec![addr],
+                None => {
+                    let dns_start = Instant::now();
+                    let addrs = resolve_target_addrs(target).await?;
+                    if !is_ip_literal {
+                        dns_ms = Some(dns_start.elapsed().as_millis() as u64);
+                        last_progress_ms = Some(start.elapsed().as_millis() as u64);
+                    }
+                    addrs
+                }
+            };
+            let addr = addrs[0];
+            probed_addr = Some(addr);
 
-            let addr = addrs.next().ok_or_else(|| {
-                NetworkError::DnsResolutionError(
-                    "DNS resolution failed to return any addresses.".to_string(),
-                )
-            })?;
+            let transport = transport::current();
+            let supported = match target.protocol {
+                TargetProtocol::Http | TargetProtocol::Https => transport.supports_http(),
+                TargetProtocol::Icmp => transport.supports_icmp(),
+                _ => true,
+            };
+            if !supported {
+                return Err(NetworkError::ProbeFailed(format!(
+                    "{:?} probes are not supported by the installed transport",
+                    target.protocol
+                )));
+            }
 
             match target.protocol {
                 TargetProtocol::Tcp => {
-                    let mut stream = TcpStream::connect(&addr)
+                    let connect_start = Instant::now();
+                    let mut stream = transport
+                        .connect_tcp(addr, target.dscp)
                         .await
                         .map_err(|e| NetworkError::ConnectionError(e.to_string()))?;
+                    connect_ms = Some(connect_start.elapsed().as_millis() as u64);
+                    last_progress_ms = Some(start.elapsed().as_millis() as u64);
 
                     let probe = format!(
                         "HEAD / HTTP/1.0\r\nHost: {}\r\nConnection: close\r\n\r\n",
@@ -62,17 +117,28 @@ impl NetworkProbe for NativeProbe {
                         "http"
                     };
                     let url = format!("{}://{}:{}", scheme, target.host, target.port);
-                    let client = reqwest::Client::builder()
+                    let mut builder = transport
+                        .http_client()
                         .danger_accept_invalid_certs(true)
-                        .timeout(timeout_duration)
+                        .timeout(timeout_duration);
+                    if !is_ip_literal {
+                        // Keep the host name for SNI and the Host header, but
+                        // connect to the addresses resolved above, so that
+                        // `dns_ms` is the only lookup of the request.
+                        builder = builder.resolve_to_addrs(&target.host, &addrs);
+                    }
+                    let client = builder
                         .build()
                         .map_err(|e| NetworkError::ConnectionError(e.to_string()))?;
 
-                    let res = client
+                    let mut res = client
                         .get(&url)
                         .send()
                         .await
                         .map_err(|e| NetworkError::ConnectionError(e.to_string()))?;
+                    last_progress_ms = Some(start.elapsed().as_millis() as u64);
+                    // reqwest picks among the addresses on its own.
+                    probed_addr = res.remote_addr().or(probed_addr);
 
                     let status = res.status();
                     if status.is_server_error() && target.is_essential {
@@ -82,25 +148,51 @@ impl NetworkProbe for NativeProbe {
                         )));
                     }
 
-                    let _ = res.bytes().await.map_err(|e| {
-                        NetworkError::ConnectionError(format!(
-                            "Failed to read response body: {}",
-                            e
-                        ))
-                    })?;
+                    let mut body = Vec::new();
+                    read_body(&mut res, &mut body, LibConstants::MAX_RESPONSE_BODY_BYTES)
+                        .await
+                        .map_err(|e| {
+                            NetworkError::ConnectionError(format!(
+                                "Failed to read response body: {}",
+                                e
+                            ))
+                        })?;
+                }
+
+                TargetProtocol::Udp => {
+                    super::udp::probe_udp_marked(addr, target.udp_validation, target.dscp).await?;
+                }
+
+                TargetProtocol::Dns => {
+                    let name = target
+                        .dns_query_name
+                        .as_deref()
+                        .unwrap_or(LibConstants::DEFAULT_DNS_QUERY_NAME);
+                    super::udp::probe_dns_marked(addr, name, target.dscp).await?;
                 }
 
                 TargetProtocol::Icmp => {
-                    let payload = [0u8; 8];
+                    if let Some(used) = ping_fallback(&detect_probe_capabilities()) {
+                        fallback = Some(used);
+                        let port = match target.port {
+                            0 => LibConstants::TCP_PING_FALLBACK_PORT,
+                            port => port,
+                        };
+                        let connect_start = Instant::now();
+                        tcp_ping(SocketAddr::new(addr.ip(), port), target.dscp).await?;
+                        connect_ms = Some(connect_start.elapsed().as_millis() as u64);
+                        return Ok(());
+                    }
 
-                    let ping_result = surge_ping::ping(addr.ip(), &payload).await.map_err(|e| {
-                        NetworkError::ConnectionError(format!("Ping failed: {}", e))
-                    })?;
+                    let payload = [0u8; 8];
 
-                    let (_packet, rtt) = ping_result;
-                    let is_loopback = addr.ip().is_loopback();
+                    let (_packet, rtt) = ping_marked(addr.ip(), &payload, target.dscp)
+                        .await
+                        .map_err(ping_error)?;
+                    connect_ms = Some(rtt.as_millis() as u64);
+                    rtt_ms = connect_ms;
 
-                    if !is_loopback && rtt < Duration::from_micros(100) {
+                    if !addr.ip().is_loopback() && rtt < Duration::from_micros(100) {
                         return Err(NetworkError::ConnectionError(
                             "Suspiciously low RTT - possible local interception".to_string(),
                         ));
@@ -111,23 +203,54 @@ impl NetworkProbe for NativeProbe {
         })
         .await;
 
+        let elapsed_ms = start.elapsed().as_millis() as u64;
+        let timings = Some(ProbeTimings::new(
+            target.timeout_ms,
+            elapsed_ms,
+            dns_ms,
+            connect_ms,
+        ));
+        let resolved_addr = probed_addr.map(|addr| addr.ip().to_string());
+        let address_family = probed_addr.map(|addr| AddressFamily::from(addr.ip()));
         match result {
-            Ok(Ok(_)) => {
-                let latency = start.elapsed().as_millis() as u64;
-                TargetReport {
-                    label: target.label.clone(),
-                    success: true,
-                    latency_ms: latency,
-                    error: None,
-                    is_essential: target.is_essential,
-                }
-            }
+            Ok(Ok(_)) => TargetReport {
+                label: target.label.clone(),
+                success: true,
+                latency_ms: rtt_ms.unwrap_or(elapsed_ms),
+                error: None,
+                is_essential: target.is_essential,
+                dns_ms,
+                connect_ms,
+                unreachable: None,
+                timed_out_after_ms: None,
+                last_progress_ms: None,
+                resolved_addr,
+                address_family,
+                skipped: false,
+                timings,
+                samples: Vec::new(),
+                fallback,
+            },
             Ok(Err(e)) => TargetReport {
                 label: target.label.clone(),
                 success: false,
                 latency_ms: 0,
                 error: Some(e.to_string()),
                 is_essential: target.is_essential,
+                dns_ms,
+                connect_ms,
+                unreachable: match e {
+                    NetworkError::Unreachable(kind) => Some(kind),
+                    _ => None,
+                },
+                timed_out_after_ms: None,
+                last_progress_ms: None,
+                resolved_addr,
+                address_family,
+                skipped: false,
+                timings,
+                samples: Vec::new(),
+                fallback,
             },
             Err(_) => TargetReport {
                 label: target.label.clone(),
@@ -135,22 +258,78 @@ impl NetworkProbe for NativeProbe {
                 latency_ms: 0,
                 error: Some("Timeout Error".to_string()),
                 is_essential: target.is_essential,
+                dns_ms,
+                connect_ms,
+                unreachable: None,
+                timed_out_after_ms: target.report_timeout_progress.then_some(elapsed_ms),
+                last_progress_ms: last_progress_ms.filter(|_| target.report_timeout_progress),
+                resolved_addr,
+                address_family,
+                skipped: false,
+                timings,
+                samples: Vec::new(),
+                fallback,
             },
         }
     }
 }
 
+/// Connects to `addr` in place of an ICMP echo
+/// ([TcpPing](crate::api::models::ProbeFallback::TcpPing));
+/// a refused connection still proves the host is up.
+async fn tcp_ping(addr: SocketAddr, dscp: Option<u8>) -> Result<(), NetworkError> {
+    match transport::current().connect_tcp(addr, dscp).await {
+        Ok(_) => Ok(()),
+        Err(e) if e.kind() == std::io::ErrorKind::ConnectionRefused => Ok(()),
+        Err(e) => Err(NetworkError::ConnectionError(format!(
+            "TCP ping failed: {}",
+            e
+        ))),
+    }
+}
+
+/// Maps a failed echo to an error, spelling out the missing permission when
+/// neither an unprivileged nor a raw ICMP socket could be opened.
+fn ping_error(e: SurgeError) -> NetworkError {
+    match e {
+        SurgeError::IOError(e) if e.kind() == std::io::ErrorKind::PermissionDenied => {
+            NetworkError::ConnectionError(
+                "Ping failed: ICMP sockets not permitted (needs net.ipv4.ping_group_range or CAP_NET_RAW)"
+                    .to_string(),
+            )
+        }
+        e => NetworkError::ConnectionError(format!("Ping failed: {}", e)),
+    }
+}
+
+/// Resolves `target`'s host to the first address returned by the current
+/// [transport], normally the system resolver.
+pub(crate) async fn resolve_target(target: &NetworkTarget) -> Result<SocketAddr, NetworkError> {
+    Ok(resolve_target_addrs(target).await?[0])
+}
+
+/// [resolve_target], returning every address in resolver order. Never empty.
+async fn resolve_target_addrs(target: &NetworkTarget) -> Result<Vec<SocketAddr>, NetworkError> {
+    let addrs = transport::current()
+        .resolve(&target.host, target.port)
+        .await
+        .map_err(|e| NetworkError::DnsResolutionError(e.to_string()))?;
+    if addrs.is_empty() {
+        return Err(NetworkError::DnsResolutionError(
+            "DNS resolution failed to return any addresses.".to_string(),
+        ));
+    }
+    Ok(addrs)
+}
+
 /// Web-specific implementation stub (WASM removed).
 pub struct WebProbe {}
 
 impl NetworkProbe for WebProbe {
     async fn check(&self, target: &NetworkTarget) -> TargetReport {
         TargetReport {
-            label: target.label.clone(),
-            success: false,
-            latency_ms: 0,
             error: Some("WebProbe is not available (WASM support removed)".into()),
-            is_essential: target.is_essential,
+            ..TargetReport::unprobed(target.label.clone(), target.is_essential)
         }
     }
 }
@@ -159,3 +338,106 @@ impl NetworkProbe for WebProbe {
 pub async fn check_target(target: &NetworkTarget) -> TargetReport {
     NativeProbe {}.check(target).await
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::io::AsyncWriteExt;
+
+    #[tokio::test]
+    async fn test_tcp_check_splits_dns_and_connect_time() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let port = listener.local_addr().unwrap().port();
+        tokio::spawn(async move {
+            let (mut socket, _) = listener.accept().await.unwrap();
+            let _ = socket.write_all(b"ok").await;
+        });
+
+        let mut target = NetworkTarget::builder("127.0.0.1", TargetProtocol::Tcp)
+            .label("local")
+            .port(port)
+            .timeout_ms(1000)
+            .build();
+        let report = check_target(&target).await;
+        assert!(report.success, "{:?}", report.error);
+        assert_eq!(report.dns_ms, None);
+        assert!(report.connect_ms.is_some());
+        assert_eq!(report.resolved_addr.as_deref(), Some("127.0.0.1"));
+        assert_eq!(report.address_family, Some(AddressFamily::V4));
+        let timings = report.timings.unwrap();
+        assert_eq!(timings.elapsed_ms, report.latency_ms);
+        assert!(timings.used_percent <= 100.0);
+
+        // Nothing listens here any more, but the name still resolves.
+        target.host = "localhost".into();
+        let report = check_target(&target).await;
+        assert!(report.dns_ms.is_some());
+    }
+
+    #[tokio::test]
+    async fn test_http_check_connects_to_the_resolved_address() {
+        let server = crate::api::testsupport::TestServer::http()
+            .start()
+            .await
+            .unwrap();
+        let mut target = server.target("web");
+        target.host = "localhost".into();
+
+        let report = check_target(&target).await;
+        assert!(report.success, "{:?}", report.error);
+        assert!(report.dns_ms.is_some());
+        assert_eq!(report.connect_ms, None);
+        assert_eq!(report.resolved_addr.as_deref(), Some("127.0.0.1"));
+    }
+
+    #[tokio::test]
+    async fn test_timeout_reports_progress() {
+        // Accepts the connection but answers the probe too late.
+        let server = crate::api::testsupport::TestServer::tcp_echo()
+            .latency_ms(5000)
+            .start()
+            .await
+            .unwrap();
+        let port = server.addr().port();
+
+        let target = NetworkTarget::builder("127.0.0.1", TargetProtocol::Tcp)
+            .port(port)
+            .timeout_ms(200)
+            .report_timeout_progress(true)
+            .build();
+        let report = check_target(&target).await;
+        assert!(!report.success);
+        assert_eq!(report.latency_ms, 0);
+        assert!(report.timed_out_after_ms.unwrap() >= 200);
+        assert!(report.last_progress_ms.unwrap() < 200);
+    }
+
+    #[tokio::test]
+    async fn test_tcp_ping_counts_refused_connections() {
+        // Nothing listens on the discard port, so loopback refuses at once.
+        assert!(tcp_ping("127.0.0.1:9".parse().unwrap(), None).await.is_ok());
+        assert!(tcp_ping("127.0.0.1:9".parse().unwrap(), Some(46))
+            .await
+            .is_ok());
+    }
+
+    #[test]
+    fn test_ping_error_names_missing_permission() {
+        let denied = SurgeError::IOError(std::io::ErrorKind::PermissionDenied.into());
+        assert!(ping_error(denied).to_string().contains("CAP_NET_RAW"));
+        let other = ping_error(SurgeError::NetworkError).to_string();
+        assert!(other.contains("Ping failed"));
+    }
+
+    #[tokio::test]
+    async fn test_icmp_latency_is_the_echo_round_trip() {
+        let target = NetworkTarget::builder("localhost", TargetProtocol::Icmp).build();
+        let report = check_target(&target).await;
+        if report.fallback.is_some() || !report.success {
+            // No ICMP socket in this environment.
+            return;
+        }
+        assert_eq!(Some(report.latency_ms), report.connect_ms);
+        assert!(report.latency_ms <= report.timings.unwrap().elapsed_ms);
+    }
+}
@@ -0,0 +1,58 @@
+//! `TCP_INFO` of connected sockets (Linux/Android).
+
+use std::{io, os::fd::AsRawFd};
+use tokio::net::TcpStream;
+
+/// `tcpi_options` bit: the timestamp option was negotiated.
+pub(crate) const TCPI_OPT_TIMESTAMPS: u8 = 1;
+/// `tcpi_options` bit: ECN was negotiated.
+pub(crate) const TCPI_OPT_ECN: u8 = 8;
+
+/// The leading part of the kernel's `struct tcp_info`; the kernel copies no
+/// more than the length we pass, so later fields can be left out.
+#[repr(C)]
+#[derive(Debug, Default, Clone, Copy)]
+pub(crate) struct TcpInfo {
+    pub state: u8,
+    pub ca_state: u8,
+    pub retransmits: u8,
+    pub probes: u8,
+    pub backoff: u8,
+    pub options: u8,
+    pub wscale: u8,
+    pub flags: u8,
+    pub rto: u32,
+    pub ato: u32,
+    pub snd_mss: u32,
+    pub rcv_mss: u32,
+    pub unacked: u32,
+    pub sacked: u32,
+    pub lost: u32,
+    pub retrans: u32,
+    pub fackets: u32,
+    pub last_data_sent: u32,
+    pub last_ack_sent: u32,
+    pub last_data_recv: u32,
+    pub last_ack_recv: u32,
+    pub pmtu: u32,
+}
+
+/// Reads `TCP_INFO` of `stream`.
+pub(crate) fn read(stream: &TcpStream) -> io::Result<TcpInfo> {
+    let mut info = TcpInfo::default();
+    let mut len = std::mem::size_of::<TcpInfo>() as libc::socklen_t;
+    // SAFETY: `info` is a plain repr(C) struct of `len` writable bytes.
+    let result = unsafe {
+        libc::getsockopt(
+            stream.as_raw_fd(),
+            libc::IPPROTO_TCP,
+            libc::TCP_INFO,
+            (&mut info as *mut TcpInfo).cast(),
+            &mut len,
+        )
+    };
+    if result < 0 {
+        return Err(io::Error::last_os_error());
+    }
+    Ok(info)
+}
@@ -0,0 +1,565 @@
+//! Probe for mapping the path to a host (traceroute).
+
+use super::{
+    capabilities::{detect_probe_capabilities, traceroute_fallback},
+    resolver::{self, reverse_name},
+};
+use crate::api::{
+    constants::LibConstants,
+    models::{MultiRouteTrace, NetworkError, PathSegment, ProbeFallback, RouteTrace, TraceHop},
+};
+use flutter_rust_bridge::frb;
+use futures::future::join_all;
+use std::{net::IpAddr, time::Duration};
+use surge_ping::{Client, Config, IcmpPacket, PingIdentifier, PingSequence, ICMP};
+use tokio_util::sync::CancellationToken;
+
+/// Traces the route to `host` using ICMP echo requests with increasing TTL.
+///
+/// Each hop is probed once; hops that do not answer within `timeout_per_hop_ms`
+/// are reported with an `ip_address` of `"*"`. The trace stops as soon as the
+/// destination itself replies, or after `max_hops` hops (0 means
+/// [DEFAULT_MAX_TRACEROUTE_HOPS](LibConstants::DEFAULT_MAX_TRACEROUTE_HOPS)).
+///
+/// # Platform Notes
+///
+/// Intermediate routers answer with ICMP "Time Exceeded". Unprivileged ICMP
+/// sockets (e.g. on Linux without `CAP_NET_RAW`) usually do not deliver those.
+/// On Linux and Android the trace then sends UDP datagrams instead, whose ICMP
+/// errors any process may read ([ProbeFallback::UdpTraceroute], noted in
+/// [RouteTrace::fallback] by [trace_routes]); elsewhere only the final hop is
+/// visible.
+///
+/// The hops come back as a [RouteTrace], which also tells whether the
+/// destination answered, where the path broke and which fallback was used.
+/// With a single destination the whole path counts as shared, so a break is
+/// placed in [PathSegment::Lan] or [PathSegment::Isp].
+pub async fn trace_route(
+    host: String,
+    max_hops: u8,
+    timeout_per_hop_ms: u64,
+) -> Result<RouteTrace, NetworkError> {
+    trace_route_cancellable(host, max_hops, timeout_per_hop_ms, CancellationToken::new()).await
+}
+
+/// [trace_route], stopping once `cancel` is cancelled. The hops probed until
+/// then are returned, without host names.
+#[frb(ignore)]
+pub async fn trace_route_cancellable(
+    host: String,
+    max_hops: u8,
+    timeout_per_hop_ms: u64,
+    cancel: CancellationToken,
+) -> Result<RouteTrace, NetworkError> {
+    let result = trace(&host, max_hops, timeout_per_hop_ms, &cancel).await?;
+    let fallback = traceroute_fallback(&detect_probe_capabilities());
+    let mut compared = compare_routes(vec![(host, Ok(result))], fallback);
+    Ok(compared.routes.remove(0))
+}
+
+/// [trace_route], also returning whether the destination itself answered.
+async fn trace(
+    host: &str,
+    max_hops: u8,
+    timeout_per_hop_ms: u64,
+    cancel: &CancellationToken,
+) -> Result<(Vec<TraceHop>, bool), NetworkError> {
+    let destination = resolve(host).await?;
+    let max_hops = if max_hops == 0 {
+        LibConstants::DEFAULT_MAX_TRACEROUTE_HOPS
+    } else {
+        max_hops
+    };
+    let timeout = Duration::from_millis(timeout_per_hop_ms);
+    let fallback = traceroute_fallback(&detect_probe_capabilities());
+
+    let mut hops = Vec::new();
+    let mut reached = false;
+    for ttl in 1..=max_hops {
+        let reply = tokio::select! {
+            reply = probe_hop(destination, ttl, timeout, fallback) => reply?,
+            _ = cancel.cancelled() => break,
+        };
+        reached = reply.is_some_and(|(addr, _)| addr == destination);
+
+        hops.push(TraceHop {
+            hop_number: ttl,
+            ip_address: reply.map_or_else(|| "*".to_string(), |(addr, _)| addr.to_string()),
+            hostname: None,
+            latency_ms: reply.map(|(_, rtt)| rtt.as_millis() as u64),
+        });
+
+        if reached {
+            break;
+        }
+    }
+
+    tokio::select! {
+        _ = resolve_hostnames(&mut hops) => {}
+        _ = cancel.cancelled() => {}
+    }
+    Ok((hops, reached))
+}
+
+/// The hops of one trace and whether the destination answered.
+type TraceResult = Result<(Vec<TraceHop>, bool), NetworkError>;
+
+/// Traces several destinations concurrently and compares their paths.
+///
+/// Hops are split into the LAN (leading private addresses), the shared prefix
+/// all destinations pass through (the ISP), and the destination-specific rest.
+/// A trace that stops short of its destination is attributed to the segment
+/// of its last answering hop, which tells "my Wi-Fi", "my ISP" and "that one
+/// site" apart. With a single host, the whole path counts as shared.
+///
+/// See [trace_route] for the meaning of `max_hops` and `timeout_per_hop_ms`.
+pub async fn trace_routes(
+    hosts: Vec<String>,
+    max_hops: u8,
+    timeout_per_hop_ms: u64,
+) -> MultiRouteTrace {
+    let traces = join_all(hosts.into_iter().map(|host| async move {
+        let result = trace(
+            &host,
+            max_hops,
+            timeout_per_hop_ms,
+            &CancellationToken::new(),
+        )
+        .await;
+        (host, result)
+    }))
+    .await;
+    compare_routes(traces, traceroute_fallback(&detect_probe_capabilities()))
+}
+
+/// Computes the shared prefix and locates the failures of finished traces,
+/// which all used the method `fallback`.
+fn compare_routes(
+    traces: Vec<(String, TraceResult)>,
+    fallback: Option<ProbeFallback>,
+) -> MultiRouteTrace {
+    let successful: Vec<&[TraceHop]> = traces
+        .iter()
+        .filter_map(|(_, r)| r.as_ref().ok().map(|(hops, _)| hops.as_slice()))
+        .filter(|hops| !hops.is_empty())
+        .collect();
+
+    let shared_len = (0..)
+        .take_while(|&i| {
+            let mut answered = successful
+                .iter()
+                .map(|hops| hops.get(i).map(|h| h.ip_address.as_str()))
+                .collect::<Option<Vec<_>>>()
+                .unwrap_or_default()
+                .into_iter()
+                .filter(|ip| *ip != "*");
+            let Some(first) = answered.next() else {
+                return false;
+            };
+            answered.all(|ip| ip == first)
+        })
+        .count();
+    let shared_prefix: Vec<TraceHop> = successful
+        .first()
+        .map(|hops| hops[..shared_len].to_vec())
+        .unwrap_or_default();
+    let lan_hop_count = shared_prefix
+        .iter()
+        .take_while(|h| h.ip_address == "*" || is_lan_hop(&h.ip_address))
+        .count();
+    // Trailing unanswered hops of the LAN run are not evidence of the LAN.
+    let lan_hop_count = shared_prefix[..lan_hop_count]
+        .iter()
+        .rposition(|h| h.ip_address != "*")
+        .map_or(0, |i| i + 1);
+
+    let routes: Vec<RouteTrace> = traces
+        .into_iter()
+        .map(|(host, result)| match result {
+            Ok((hops, reached)) => {
+                locate_problem(host, hops, reached, lan_hop_count, shared_len, fallback)
+            }
+            Err(e) => RouteTrace {
+                host,
+                hops: Vec::new(),
+                error: Some(e.to_string()),
+                reached: false,
+                problem_hop: None,
+                problem_segment: None,
+                fallback,
+            },
+        })
+        .collect();
+
+    MultiRouteTrace {
+        problem_segment: routes.iter().filter_map(|r| r.problem_segment).min(),
+        routes,
+        shared_prefix,
+        lan_hop_count: lan_hop_count as u8,
+    }
+}
+
+fn locate_problem(
+    host: String,
+    hops: Vec<TraceHop>,
+    reached: bool,
+    lan_hop_count: usize,
+    shared_len: usize,
+    fallback: Option<ProbeFallback>,
+) -> RouteTrace {
+    let last_answer = hops.iter().rposition(|h| h.ip_address != "*");
+    let segment = |index: Option<usize>| match index {
+        None => PathSegment::Lan,
+        Some(i) if i < lan_hop_count => PathSegment::Lan,
+        Some(i) if i < shared_len => PathSegment::Isp,
+        Some(_) => PathSegment::Destination,
+    };
+
+    RouteTrace {
+        host,
+        problem_hop: (!reached).then(|| last_answer.map_or(1, |i| i as u8 + 2)),
+        problem_segment: (!reached).then(|| segment(last_answer)),
+        reached,
+        hops,
+        error: None,
+        fallback,
+    }
+}
+
+/// True for addresses that cannot be routed on the internet (RFC 1918,
+/// link-local, unique local). Carrier-grade NAT space belongs to the ISP.
+fn is_lan_hop(ip: &str) -> bool {
+    match ip.parse::<IpAddr>() {
+        Ok(IpAddr::V4(v4)) => v4.is_private() || v4.is_link_local(),
+        Ok(IpAddr::V6(v6)) => {
+            let first = v6.segments()[0];
+            (first & 0xfe00) == 0xfc00 || (first & 0xffc0) == 0xfe80
+        }
+        Err(_) => false,
+    }
+}
+
+/// Resolves `host` (a name or an IP literal) to its first address.
+async fn resolve(host: &str) -> Result<IpAddr, NetworkError> {
+    if let Ok(ip) = host.parse::<IpAddr>() {
+        return Ok(ip);
+    }
+    Ok(resolver::resolve_host(host, 0).await?[0].ip())
+}
+
+/// Probes the hop at `ttl` with an ICMP echo, or a UDP datagram under
+/// [ProbeFallback::UdpTraceroute].
+///
+/// Returns the responding address and RTT, `None` on timeout, or an error if
+/// no socket could be opened at all.
+async fn probe_hop(
+    destination: IpAddr,
+    ttl: u8,
+    timeout: Duration,
+    fallback: Option<ProbeFallback>,
+) -> Result<Option<(IpAddr, Duration)>, NetworkError> {
+    match fallback {
+        #[cfg(any(target_os = "linux", target_os = "android"))]
+        Some(ProbeFallback::UdpTraceroute) => recverr::probe_hop(destination, ttl, timeout).await,
+        _ => probe_hop_icmp(destination, ttl, timeout).await,
+    }
+}
+
+/// Sends a single echo request with the given TTL.
+async fn probe_hop_icmp(
+    destination: IpAddr,
+    ttl: u8,
+    timeout: Duration,
+) -> Result<Option<(IpAddr, Duration)>, NetworkError> {
+    let kind = match destination {
+        IpAddr::V4(_) => ICMP::V4,
+        IpAddr::V6(_) => ICMP::V6,
+    };
+    let config = Config::builder().kind(kind).ttl(u32::from(ttl)).build();
+    let client = Client::new(&config)
+        .map_err(|e| NetworkError::ConnectionError(format!("ICMP socket unavailable: {}", e)))?;
+
+    let mut pinger = client
+        .pinger(destination, PingIdentifier(rand::random()))
+        .await;
+    pinger.timeout(timeout);
+
+    let reply = match pinger.ping(PingSequence(u16::from(ttl)), &[0u8; 8]).await {
+        Ok((IcmpPacket::V4(packet), rtt)) => Some((IpAddr::V4(packet.get_source()), rtt)),
+        Ok((IcmpPacket::V6(packet), rtt)) => Some((IpAddr::V6(packet.get_source()), rtt)),
+        Err(_) => None,
+    };
+    Ok(reply)
+}
+
+/// Fills in [TraceHop::hostname] via reverse DNS for every responding hop.
+async fn resolve_hostnames(hops: &mut [TraceHop]) {
+    let ips: Vec<Option<IpAddr>> = hops.iter().map(|h| h.ip_address.parse().ok()).collect();
+    let lookups = ips
+        .into_iter()
+        .map(|ip| async move { reverse_name(ip?).await });
+
+    for (hop, hostname) in hops.iter_mut().zip(join_all(lookups).await) {
+        hop.hostname = hostname;
+    }
+}
+
+#[cfg(any(target_os = "linux", target_os = "android"))]
+mod recverr {
+    //! UDP traceroute reading ICMP errors from the socket error queue
+    //! (`IP_RECVERR`), which needs no privileges.
+
+    use crate::api::{constants::LibConstants, models::NetworkError};
+    use socket2::{Domain, Protocol, SockAddr, Socket, Type};
+    use std::{
+        io,
+        net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr},
+        os::fd::{AsRawFd, RawFd},
+        time::{Duration, Instant},
+    };
+    use tokio::{io::Interest, net::UdpSocket};
+
+    /// Sends one datagram with the given TTL to an unused port of
+    /// `destination`. Routers on the way answer with Time Exceeded, the
+    /// destination itself with Port Unreachable.
+    pub(super) async fn probe_hop(
+        destination: IpAddr,
+        ttl: u8,
+        timeout: Duration,
+    ) -> Result<Option<(IpAddr, Duration)>, NetworkError> {
+        let socket = open(destination, ttl)
+            .map_err(|e| NetworkError::ConnectionError(format!("UDP socket unavailable: {}", e)))?;
+        let port = LibConstants::UDP_TRACEROUTE_BASE_PORT.wrapping_add(u16::from(ttl));
+
+        let start = Instant::now();
+        if socket
+            .send_to(&[0u8; 8], SocketAddr::new(destination, port))
+            .await
+            .is_err()
+        {
+            return Ok(None);
+        }
+        let offender = tokio::time::timeout(
+            timeout,
+            socket.async_io(Interest::ERROR, || read_offender(socket.as_raw_fd())),
+        )
+        .await;
+        Ok(match offender {
+            Ok(Ok(offender)) => Some((offender, start.elapsed())),
+            _ => None,
+        })
+    }
+
+    fn open(destination: IpAddr, ttl: u8) -> io::Result<UdpSocket> {
+        let (domain, level, option, any) = match destination {
+            IpAddr::V4(_) => (
+                Domain::IPV4,
+                libc::SOL_IP,
+                libc::IP_RECVERR,
+                IpAddr::V4(Ipv4Addr::UNSPECIFIED),
+            ),
+            IpAddr::V6(_) => (
+                Domain::IPV6,
+                libc::SOL_IPV6,
+                libc::IPV6_RECVERR,
+                IpAddr::V6(Ipv6Addr::UNSPECIFIED),
+            ),
+        };
+        let socket = Socket::new(domain, Type::DGRAM, Some(Protocol::UDP))?;
+        match destination {
+            IpAddr::V4(_) => socket.set_ttl_v4(u32::from(ttl))?,
+            IpAddr::V6(_) => socket.set_unicast_hops_v6(u32::from(ttl))?,
+        }
+        let enable: libc::c_int = 1;
+        // SAFETY: `enable` is a valid c_int of the given size.
+        let result = unsafe {
+            libc::setsockopt(
+                socket.as_raw_fd(),
+                level,
+                option,
+                (&enable as *const libc::c_int).cast(),
+                std::mem::size_of::<libc::c_int>() as libc::socklen_t,
+            )
+        };
+        if result != 0 {
+            return Err(io::Error::last_os_error());
+        }
+        socket.set_nonblocking(true)?;
+        socket.bind(&SockAddr::from(SocketAddr::new(any, 0)))?;
+        UdpSocket::from_std(socket.into())
+    }
+
+    /// Takes one error off the queue of `fd` and returns the address of the
+    /// host that sent the ICMP error; `WouldBlock` while the queue is empty.
+    fn read_offender(fd: RawFd) -> io::Result<IpAddr> {
+        let mut data = [0u8; 64];
+        let mut control = [0u8; 256];
+        let mut iov = libc::iovec {
+            iov_base: data.as_mut_ptr().cast(),
+            iov_len: data.len(),
+        };
+        // SAFETY: an all-zero msghdr is valid; the buffers are set below.
+        let mut msg: libc::msghdr = unsafe { std::mem::zeroed() };
+        msg.msg_iov = &mut iov;
+        msg.msg_iovlen = 1;
+        msg.msg_control = control.as_mut_ptr().cast();
+        msg.msg_controllen = control.len() as _;
+
+        // SAFETY: `msg` points to buffers that outlive the call.
+        if unsafe { libc::recvmsg(fd, &mut msg, libc::MSG_ERRQUEUE | libc::MSG_DONTWAIT) } < 0 {
+            return Err(io::Error::last_os_error());
+        }
+
+        // SAFETY: `msg` was filled in by recvmsg, so the control messages
+        // lie within `control`.
+        let mut cmsg = unsafe { libc::CMSG_FIRSTHDR(&msg) };
+        while !cmsg.is_null() {
+            // SAFETY: `cmsg` is a valid header within `control`.
+            let (level, kind) = unsafe { ((*cmsg).cmsg_level, (*cmsg).cmsg_type) };
+            if (level == libc::SOL_IP && kind == libc::IP_RECVERR)
+                || (level == libc::SOL_IPV6 && kind == libc::IPV6_RECVERR)
+            {
+                // SAFETY: a RECVERR message holds a sock_extended_err followed
+                // by the offender's address (SO_EE_OFFENDER).
+                let offender = unsafe {
+                    let err = libc::CMSG_DATA(cmsg) as *const libc::sock_extended_err;
+                    let origin = (*err).ee_origin;
+                    (origin == libc::SO_EE_ORIGIN_ICMP || origin == libc::SO_EE_ORIGIN_ICMP6)
+                        .then(|| offender_addr(err.add(1).cast()))
+                        .flatten()
+                };
+                if let Some(offender) = offender {
+                    return Ok(offender);
+                }
+            }
+            // SAFETY: as above.
+            cmsg = unsafe { libc::CMSG_NXTHDR(&msg, cmsg) };
+        }
+        // An error not caused by ICMP; the next one may still come.
+        Err(io::ErrorKind::WouldBlock.into())
+    }
+
+    /// Reads the IPv4 or IPv6 address of a `sockaddr`.
+    ///
+    /// # Safety
+    ///
+    /// `addr` must point to a `sockaddr_in` or `sockaddr_in6` as indicated by
+    /// its family.
+    unsafe fn offender_addr(addr: *const u8) -> Option<IpAddr> {
+        let family = addr.cast::<libc::sa_family_t>().read_unaligned();
+        match i32::from(family) {
+            libc::AF_INET => {
+                let sin = addr.cast::<libc::sockaddr_in>().read_unaligned();
+                Some(IpAddr::V4(Ipv4Addr::from(u32::from_be(
+                    sin.sin_addr.s_addr,
+                ))))
+            }
+            libc::AF_INET6 => {
+                let sin6 = addr.cast::<libc::sockaddr_in6>().read_unaligned();
+                Some(IpAddr::V6(Ipv6Addr::from(sin6.sin6_addr.s6_addr)))
+            }
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn hops(ips: &[&str]) -> Vec<TraceHop> {
+        ips.iter()
+            .enumerate()
+            .map(|(i, ip)| TraceHop {
+                hop_number: i as u8 + 1,
+                ip_address: ip.to_string(),
+                hostname: None,
+                latency_ms: (*ip != "*").then_some(10),
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_compare_routes_locates_failures() {
+        let trace = compare_routes(
+            vec![
+                (
+                    "a.example".into(),
+                    Ok((
+                        hops(&["192.168.1.1", "100.64.0.1", "203.0.113.1", "198.51.100.7"]),
+                        true,
+                    )),
+                ),
+                (
+                    "b.example".into(),
+                    Ok((
+                        hops(&["192.168.1.1", "*", "203.0.113.1", "192.0.2.9", "*"]),
+                        false,
+                    )),
+                ),
+                (
+                    "c.invalid".into(),
+                    Err(NetworkError::DnsResolutionError("nxdomain".into())),
+                ),
+            ],
+            None,
+        );
+
+        assert_eq!(trace.shared_prefix.len(), 3);
+        assert_eq!(trace.lan_hop_count, 1);
+        assert!(trace.routes[0].reached);
+        assert_eq!(trace.routes[0].problem_segment, None);
+        assert_eq!(trace.routes[1].problem_hop, Some(5));
+        assert_eq!(
+            trace.routes[1].problem_segment,
+            Some(PathSegment::Destination)
+        );
+        assert!(trace.routes[2].error.is_some());
+        assert_eq!(trace.problem_segment, Some(PathSegment::Destination));
+
+        let stuck_at_router = compare_routes(
+            vec![
+                ("a".into(), Ok((hops(&["10.0.0.1", "*", "*"]), false))),
+                ("b".into(), Ok((hops(&["10.0.0.1", "*", "*"]), false))),
+            ],
+            Some(ProbeFallback::UdpTraceroute),
+        );
+        assert_eq!(stuck_at_router.problem_segment, Some(PathSegment::Lan));
+        assert_eq!(stuck_at_router.routes[0].problem_hop, Some(2));
+        assert_eq!(
+            stuck_at_router.routes[1].fallback,
+            Some(ProbeFallback::UdpTraceroute)
+        );
+    }
+
+    #[tokio::test]
+    async fn test_trace_route_reports_reach_and_fallback() {
+        let trace = trace_route("127.0.0.1".into(), 4, 2000).await.unwrap();
+        assert!(trace.reached, "{:?}", trace.hops);
+        assert_eq!(trace.hops.last().unwrap().ip_address, "127.0.0.1");
+        assert_eq!(trace.problem_hop, None);
+        assert_eq!(
+            trace.fallback,
+            traceroute_fallback(&detect_probe_capabilities())
+        );
+
+        let cancel = CancellationToken::new();
+        cancel.cancel();
+        let cancelled = trace_route_cancellable("127.0.0.1".into(), 4, 2000, cancel)
+            .await
+            .unwrap();
+        assert!(cancelled.hops.is_empty() && !cancelled.reached);
+    }
+
+    #[cfg(any(target_os = "linux", target_os = "android"))]
+    #[tokio::test]
+    async fn test_udp_trace_reaches_loopback() {
+        // Loopback answers the unused port with Port Unreachable, which
+        // lands in the error queue without any privileges.
+        let localhost = IpAddr::from([127, 0, 0, 1]);
+        let reply = recverr::probe_hop(localhost, 1, Duration::from_secs(2))
+            .await
+            .unwrap();
+        assert_eq!(reply.map(|(addr, _)| addr), Some(localhost));
+    }
+}
@@ -0,0 +1,463 @@
+//! The network primitives probes are built on, behind a trait so that checks
+//! can run against a [MockTransport] instead of the real network.
+//!
+//! Probes use the transport installed for the current task via
+//! [with_transport], and the [TokioTransport] otherwise:
+//!
+//! ```ignore
+//! let mock = MockTransport::new()
+//!     .with_interface("wlan0", vec!["192.168.1.20".parse()?])
+//!     .accept_tcp("192.0.2.1:443".parse()?);
+//! let report = with_transport(Arc::new(mock), check_network(config)).await;
+//! ```
+//!
+//! TCP connects, UDP exchanges (including DNS probes), name resolution and
+//! the interface list go through the transport. HTTP(S) requests, ICMP
+//! echoes and the OS routing table are used only as far as the transport's
+//! capabilities ([supports_http](NetworkTransport::supports_http),
+//! [supports_icmp](NetworkTransport::supports_icmp),
+//! [uses_system_routes](NetworkTransport::uses_system_routes)) allow: an
+//! unsupported HTTP(S) or ICMP target fails with
+//! [NetworkError::ProbeFailed](crate::api::models::NetworkError::ProbeFailed),
+//! and the checks needing the rest are skipped. Opt-in probes such as path or
+//! address family comparison always use the OS directly.
+
+use super::{qos::apply_dscp, resolver};
+use flutter_rust_bridge::frb;
+use socket2::SockRef;
+use std::{
+    collections::HashMap,
+    fmt,
+    future::Future,
+    io,
+    net::{IpAddr, SocketAddr},
+    pin::Pin,
+    sync::{Arc, OnceLock},
+    time::Duration,
+};
+use tokio::{
+    io::{AsyncRead, AsyncReadExt, AsyncWrite, AsyncWriteExt},
+    net::UdpSocket,
+};
+
+/// The future returned by the asynchronous [NetworkTransport] methods.
+pub type TransportFuture<'a, T> = Pin<Box<dyn Future<Output = io::Result<T>> + Send + 'a>>;
+
+/// A connected byte stream returned by [NetworkTransport::connect_tcp].
+pub trait TransportStream: AsyncRead + AsyncWrite + Send + Unpin {}
+
+impl<T: AsyncRead + AsyncWrite + Send + Unpin> TransportStream for T {}
+
+/// A local network interface as listed by [NetworkTransport::list_interfaces].
+#[frb(ignore)]
+#[derive(Debug, Clone, PartialEq)]
+pub struct TransportInterface {
+    /// The OS name of the interface, e.g. `wlan0` or `en0`.
+    pub name: String,
+
+    /// The addresses assigned to it; empty for an interface that is down.
+    pub addrs: Vec<IpAddr>,
+}
+
+/// The network operations probes perform.
+///
+/// Implementations must be cheap to share; one instance serves every probe
+/// of a check concurrently. The caller bounds every operation with the
+/// target's timeout, so implementations need not time out on their own.
+#[frb(ignore)]
+pub trait NetworkTransport: fmt::Debug + Send + Sync {
+    /// Opens a TCP connection to `addr`, marking its packets with `dscp` if given.
+    #[frb(ignore)]
+    fn connect_tcp(
+        &self,
+        addr: SocketAddr,
+        dscp: Option<u8>,
+    ) -> TransportFuture<'_, Box<dyn TransportStream>>;
+
+    /// Sends `payload` to `addr` in one UDP datagram and returns the first
+    /// reply accepted by `matches`, ignoring any other reply.
+    ///
+    /// An ICMP "Destination Unreachable" is reported as the matching
+    /// [io::ErrorKind], e.g. [ConnectionRefused](io::ErrorKind::ConnectionRefused)
+    /// for an unreachable port.
+    #[frb(ignore)]
+    fn send_udp<'a>(
+        &'a self,
+        addr: SocketAddr,
+        payload: &'a [u8],
+        dscp: Option<u8>,
+        matches: &'a (dyn Fn(&[u8]) -> bool + Send + Sync),
+    ) -> TransportFuture<'a, Vec<u8>>;
+
+    /// Resolves `host` (a name or an IP literal), pairing every address with `port`.
+    #[frb(ignore)]
+    fn resolve<'a>(&'a self, host: &'a str, port: u16) -> TransportFuture<'a, Vec<SocketAddr>>;
+
+    /// The local network interfaces, up or down.
+    #[frb(ignore)]
+    fn list_interfaces(&self) -> io::Result<Vec<TransportInterface>>;
+
+    /// Whether HTTP(S) requests can go through [http_client](Self::http_client).
+    ///
+    /// Defaults to false: HTTP(S) targets then fail and the captive portal
+    /// check is skipped.
+    #[frb(ignore)]
+    fn supports_http(&self) -> bool {
+        false
+    }
+
+    /// A client builder whose connections use this transport's network.
+    ///
+    /// Only called if [supports_http](Self::supports_http) is true.
+    #[frb(ignore)]
+    fn http_client(&self) -> reqwest::ClientBuilder {
+        reqwest::Client::builder()
+    }
+
+    /// Whether ICMP echoes sent by the process reach this transport's network.
+    ///
+    /// Defaults to false: ICMP targets then fail.
+    #[frb(ignore)]
+    fn supports_icmp(&self) -> bool {
+        false
+    }
+
+    /// Whether the OS routing table and interface details describe this
+    /// transport's network.
+    ///
+    /// Defaults to false: there is then no fast offline verdict, interfaces
+    /// are classified by name only and the gateway probes are skipped.
+    #[frb(ignore)]
+    fn uses_system_routes(&self) -> bool {
+        false
+    }
+}
+
+tokio::task_local! {
+    static TRANSPORT: Arc<dyn NetworkTransport>;
+}
+
+/// Runs `future` with every probe it starts going through `transport`.
+///
+/// The transport applies to the current task only; tasks spawned by the
+/// future fall back to the [TokioTransport].
+#[frb(ignore)]
+pub async fn with_transport<F: Future>(
+    transport: Arc<dyn NetworkTransport>,
+    future: F,
+) -> F::Output {
+    TRANSPORT.scope(transport, future).await
+}
+
+/// The transport probes of the current task use.
+pub(crate) fn current() -> Arc<dyn NetworkTransport> {
+    TRANSPORT.try_with(Arc::clone).unwrap_or_else(|_| system())
+}
+
+fn system() -> Arc<dyn NetworkTransport> {
+    static SYSTEM: OnceLock<Arc<dyn NetworkTransport>> = OnceLock::new();
+    SYSTEM.get_or_init(|| Arc::new(TokioTransport)).clone()
+}
+
+/// The real network, via tokio sockets and the shared system resolver
+/// (see [resolver]).
+#[frb(ignore, opaque)]
+#[derive(Debug, Clone, Copy, Default)]
+pub struct TokioTransport;
+
+#[frb(ignore)]
+impl NetworkTransport for TokioTransport {
+    fn connect_tcp(
+        &self,
+        addr: SocketAddr,
+        dscp: Option<u8>,
+    ) -> TransportFuture<'_, Box<dyn TransportStream>> {
+        Box::pin(async move {
+            let stream = super::qos::connect_tcp(addr, dscp).await?;
+            Ok(Box::new(stream) as Box<dyn TransportStream>)
+        })
+    }
+
+    fn send_udp<'a>(
+        &'a self,
+        addr: SocketAddr,
+        payload: &'a [u8],
+        dscp: Option<u8>,
+        matches: &'a (dyn Fn(&[u8]) -> bool + Send + Sync),
+    ) -> TransportFuture<'a, Vec<u8>> {
+        Box::pin(async move {
+            let bind: SocketAddr = if addr.is_ipv4() {
+                "0.0.0.0:0".parse().unwrap()
+            } else {
+                "[::]:0".parse().unwrap()
+            };
+            let socket = UdpSocket::bind(bind).await?;
+            exchange_udp(socket, addr, payload, dscp, matches).await
+        })
+    }
+
+    fn resolve<'a>(&'a self, host: &'a str, port: u16) -> TransportFuture<'a, Vec<SocketAddr>> {
+        Box::pin(async move {
+            let resolver =
+                resolver::system_resolver().map_err(|e| io::Error::other(e.to_string()))?;
+            let lookup = resolver.lookup_ip(host).await?;
+            Ok(lookup.iter().map(|ip| SocketAddr::new(ip, port)).collect())
+        })
+    }
+
+    fn list_interfaces(&self) -> io::Result<Vec<TransportInterface>> {
+        use network_interface::{NetworkInterface, NetworkInterfaceConfig};
+
+        let interfaces = NetworkInterface::show().map_err(io::Error::other)?;
+        Ok(interfaces
+            .into_iter()
+            .map(|iface| TransportInterface {
+                addrs: iface.addr.iter().map(|addr| addr.ip()).collect(),
+                name: iface.name,
+            })
+            .collect())
+    }
+
+    fn supports_http(&self) -> bool {
+        true
+    }
+
+    fn supports_icmp(&self) -> bool {
+        true
+    }
+
+    fn uses_system_routes(&self) -> bool {
+        true
+    }
+}
+
+/// [NetworkTransport::send_udp] over an already bound `socket`.
+pub(crate) async fn exchange_udp(
+    socket: UdpSocket,
+    addr: SocketAddr,
+    payload: &[u8],
+    dscp: Option<u8>,
+    matches: &(dyn Fn(&[u8]) -> bool + Send + Sync),
+) -> io::Result<Vec<u8>> {
+    if let Some(dscp) = dscp {
+        apply_dscp(SockRef::from(&socket), addr.is_ipv4(), dscp)?;
+    }
+    socket.connect(addr).await?;
+    socket.send(payload).await?;
+
+    let mut buf = vec![0u8; super::udp::MAX_REPLY_LEN];
+    loop {
+        let len = socket.recv(&mut buf).await?;
+        if matches(&buf[..len]) {
+            buf.truncate(len);
+            return Ok(buf);
+        }
+        // A connected socket only receives from the target; anything
+        // that does not match is a late reply to an earlier probe.
+    }
+}
+
+/// Answers a UDP datagram sent to a [MockTransport]; `None` stays silent.
+type UdpResponder = Arc<dyn Fn(&[u8]) -> Option<Vec<u8>> + Send + Sync>;
+
+/// A scripted network for tests, with no I/O at all.
+///
+/// Everything not configured behaves like an empty network: names do not
+/// resolve, TCP connects and UDP datagrams are refused (as by a closed port),
+/// and there are no interfaces. Every operation takes
+/// [with_latency](Self::with_latency), so latency figures are predictable.
+#[frb(ignore)]
+#[derive(Clone, Default)]
+pub struct MockTransport {
+    hosts: HashMap<String, Vec<IpAddr>>,
+    tcp: Vec<SocketAddr>,
+    udp: HashMap<SocketAddr, UdpResponder>,
+    interfaces: Vec<TransportInterface>,
+    latency: Duration,
+}
+
+impl MockTransport {
+    /// An empty network.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Resolves `host` to `addrs`.
+    pub fn with_host(mut self, host: impl Into<String>, addrs: Vec<IpAddr>) -> Self {
+        self.hosts.insert(host.into(), addrs);
+        self
+    }
+
+    /// Accepts TCP connections to `addr`. The peer answers the first thing
+    /// it is sent with an HTTP `200 OK`, then closes.
+    pub fn accept_tcp(mut self, addr: SocketAddr) -> Self {
+        self.tcp.push(addr);
+        self
+    }
+
+    /// Answers UDP datagrams sent to `addr` with whatever `respond` returns.
+    pub fn with_udp_responder(
+        mut self,
+        addr: SocketAddr,
+        respond: impl Fn(&[u8]) -> Option<Vec<u8>> + Send + Sync + 'static,
+    ) -> Self {
+        self.udp.insert(addr, Arc::new(respond));
+        self
+    }
+
+    /// Runs a DNS server at `addr` that answers every query with an empty
+    /// `NOERROR` response.
+    pub fn with_dns_server(self, addr: SocketAddr) -> Self {
+        self.with_udp_responder(addr, |query| {
+            let mut reply = query.to_vec();
+            // QR = response; opcode and RD are kept, RA and RCODE cleared.
+            *reply.get_mut(2)? |= 0x80;
+            *reply.get_mut(3)? = 0;
+            Some(reply)
+        })
+    }
+
+    /// Adds a local interface; an empty `addrs` makes it a down interface.
+    pub fn with_interface(mut self, name: impl Into<String>, addrs: Vec<IpAddr>) -> Self {
+        self.interfaces.push(TransportInterface {
+            name: name.into(),
+            addrs,
+        });
+        self
+    }
+
+    /// Delays every operation by `latency`.
+    pub fn with_latency(mut self, latency: Duration) -> Self {
+        self.latency = latency;
+        self
+    }
+}
+
+impl fmt::Debug for MockTransport {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockTransport")
+            .field("hosts", &self.hosts)
+            .field("tcp", &self.tcp)
+            .field("udp", &self.udp.keys().collect::<Vec<_>>())
+            .field("interfaces", &self.interfaces)
+            .field("latency", &self.latency)
+            .finish()
+    }
+}
+
+#[frb(ignore)]
+impl NetworkTransport for MockTransport {
+    fn connect_tcp(
+        &self,
+        addr: SocketAddr,
+        _dscp: Option<u8>,
+    ) -> TransportFuture<'_, Box<dyn TransportStream>> {
+        Box::pin(async move {
+            tokio::time::sleep(self.latency).await;
+            if !self.tcp.contains(&addr) {
+                return Err(io::ErrorKind::ConnectionRefused.into());
+            }
+            let (client, mut server) = tokio::io::duplex(1024);
+            tokio::spawn(async move {
+                let mut request = [0u8; 1024];
+                if server.read(&mut request).await.is_ok() {
+                    let _ = server.write_all(b"HTTP/1.0 200 OK\r\n\r\n").await;
+                }
+            });
+            Ok(Box::new(client) as Box<dyn TransportStream>)
+        })
+    }
+
+    fn send_udp<'a>(
+        &'a self,
+        addr: SocketAddr,
+        payload: &'a [u8],
+        _dscp: Option<u8>,
+        matches: &'a (dyn Fn(&[u8]) -> bool + Send + Sync),
+    ) -> TransportFuture<'a, Vec<u8>> {
+        Box::pin(async move {
+            tokio::time::sleep(self.latency).await;
+            let Some(respond) = self.udp.get(&addr) else {
+                return Err(io::ErrorKind::ConnectionRefused.into());
+            };
+            match respond(payload).filter(|reply| matches(reply)) {
+                Some(reply) => Ok(reply),
+                // Nothing usable comes back; the caller's timeout ends the wait.
+                None => std::future::pending().await,
+            }
+        })
+    }
+
+    fn resolve<'a>(&'a self, host: &'a str, port: u16) -> TransportFuture<'a, Vec<SocketAddr>> {
+        Box::pin(async move {
+            if let Ok(ip) = host.parse::<IpAddr>() {
+                return Ok(vec![SocketAddr::new(ip, port)]);
+            }
+            tokio::time::sleep(self.latency).await;
+            match self.hosts.get(host) {
+                Some(addrs) => Ok(addrs.iter().map(|&ip| SocketAddr::new(ip, port)).collect()),
+                None => Err(io::Error::new(
+                    io::ErrorKind::NotFound,
+                    format!("no such host: {host}"),
+                )),
+            }
+        })
+    }
+
+    fn list_interfaces(&self) -> io::Result<Vec<TransportInterface>> {
+        Ok(self.interfaces.clone())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::{
+        models::{NetworkTarget, TargetProtocol, UdpValidation},
+        probes::check_target,
+        testsupport::TestServer,
+    };
+
+    #[tokio::test]
+    async fn test_check_target_through_mock_transport() {
+        let echo: SocketAddr = "192.0.2.7:7".parse().unwrap();
+        let mock: Arc<dyn NetworkTransport> = Arc::new(
+            MockTransport::new().with_udp_responder(echo, |datagram| Some(datagram.to_vec())),
+        );
+        let target = |host: &str, protocol| {
+            NetworkTarget::builder(host, protocol)
+                .port(7)
+                .udp_validation(UdpValidation::AnyResponse)
+                .timeout_ms(500)
+                .build()
+        };
+
+        let udp = target("192.0.2.7", TargetProtocol::Udp);
+        let report = with_transport(mock.clone(), check_target(&udp)).await;
+        assert!(report.success, "{:?}", report.error);
+
+        // Closed ports are refused; unknown names do not resolve.
+        let closed = target("192.0.2.8", TargetProtocol::Tcp);
+        let report = with_transport(mock.clone(), check_target(&closed)).await;
+        assert!(!report.success);
+        let unknown = target("missing.example", TargetProtocol::Tcp);
+        let report = with_transport(mock.clone(), check_target(&unknown)).await;
+        assert!(report.error.unwrap().starts_with("DNS Resolution Error"));
+
+        // The mock cannot carry HTTP.
+        let http = target("192.0.2.7", TargetProtocol::Http);
+        let report = with_transport(mock, check_target(&http)).await;
+        assert!(report.error.unwrap().starts_with("Probe Failed"));
+    }
+
+    #[tokio::test]
+    async fn test_installed_transport_with_http_probes_http() {
+        let server = TestServer::http().start().await.unwrap();
+        let report = with_transport(
+            Arc::new(TokioTransport),
+            check_target(&server.target("http")),
+        )
+        .await;
+        assert!(report.success, "{:?}", report.error);
+    }
+}
@@ -0,0 +1,401 @@
+//! UDP probes with application-level reply validation.
+//!
+//! A UDP `send()` succeeds as soon as the datagram leaves the local stack, so
+//! it proves nothing about the target. These probes instead send a payload the
+//! target's service understands and only report success for a reply that
+//! matches it.
+
+use super::parse::Reader;
+use crate::api::models::{target::is_valid_dns_name, IcmpUnreachable, NetworkError, UdpValidation};
+use flutter_rust_bridge::frb;
+use std::net::SocketAddr;
+
+/// Largest datagram we expect back (QUIC Version Negotiation, DNS over UDP).
+pub(crate) const MAX_REPLY_LEN: usize = 1500;
+
+/// QUIC Initial packets must be padded to at least this size (RFC 9000 §14.1).
+const QUIC_MIN_INITIAL_LEN: usize = 1200;
+
+/// A reserved version of the form `0x?a?a?a?a` (RFC 9000 §15); servers must
+/// answer it with Version Negotiation.
+const QUIC_GREASE_VERSION: u32 = 0x1a2a_3a4a;
+
+/// Sends one validated probe to `addr` and waits for a matching reply.
+///
+/// The caller is responsible for bounding the wait with a timeout.
+#[frb(ignore)]
+pub async fn probe_udp(addr: SocketAddr, validation: UdpValidation) -> Result<(), NetworkError> {
+    probe_udp_marked(addr, validation, None).await
+}
+
+/// Same as [probe_udp], marking the probe with `dscp` if given.
+pub(crate) async fn probe_udp_marked(
+    addr: SocketAddr,
+    validation: UdpValidation,
+    dscp: Option<u8>,
+) -> Result<(), NetworkError> {
+    let request = build_request(validation);
+    exchange(addr, &request, dscp, |reply| {
+        validate_reply(validation, &request, reply)
+    })
+    .await?;
+    Ok(())
+}
+
+/// Sends a DNS query for `name` (type A) to the server at `addr`.
+///
+/// Succeeds for any well-formed answer to our query in which the server
+/// actually served it (`NOERROR` or `NXDOMAIN`). `SERVFAIL`, `REFUSED` and
+/// friends prove the server is up but not resolving, and are reported as
+/// errors.
+#[frb(ignore)]
+pub async fn probe_dns(addr: SocketAddr, name: &str) -> Result<(), NetworkError> {
+    probe_dns_marked(addr, name, None).await
+}
+
+/// Same as [probe_dns], marking the query with `dscp` if given.
+pub(crate) async fn probe_dns_marked(
+    addr: SocketAddr,
+    name: &str,
+    dscp: Option<u8>,
+) -> Result<(), NetworkError> {
+    if !is_valid_dns_name(name) {
+        return Err(NetworkError::InvalidConfiguration(format!(
+            "invalid DNS query name: '{}'",
+            name
+        )));
+    }
+    let query = build_dns_query(rand::random(), name, DNS_TYPE_A);
+    let reply = exchange(addr, &query, dscp, |reply| {
+        validate_dns_response(&query, reply)
+    })
+    .await?;
+    match dns_rcode(&reply) {
+        DNS_RCODE_NOERROR | DNS_RCODE_NXDOMAIN => Ok(()),
+        rcode => Err(NetworkError::ConnectionError(format!(
+            "DNS server answered with RCODE {}",
+            rcode
+        ))),
+    }
+}
+
+/// Sends `request` to `addr` through the current
+/// [transport](super::transport) and returns the first reply accepted by
+/// `matches`.
+async fn exchange(
+    addr: SocketAddr,
+    request: &[u8],
+    dscp: Option<u8>,
+    matches: impl Fn(&[u8]) -> bool + Send + Sync,
+) -> Result<Vec<u8>, NetworkError> {
+    super::transport::current()
+        .send_udp(addr, request, dscp, &matches)
+        .await
+        .map_err(socket_error)
+}
+
+/// Maps an error from a connected UDP socket, recognizing the ICMP
+/// "Destination Unreachable" errors the OS queues on it.
+///
+/// Linux reports Port Unreachable as `ECONNREFUSED`, Windows as
+/// `WSAECONNRESET`. Platforms that do not surface ICMP errors on UDP sockets
+/// (e.g. macOS for unreachable hosts) simply let the probe time out.
+fn socket_error(err: std::io::Error) -> NetworkError {
+    use std::io::ErrorKind;
+    match err.kind() {
+        ErrorKind::ConnectionRefused | ErrorKind::ConnectionReset => {
+            NetworkError::Unreachable(IcmpUnreachable::Port)
+        }
+        ErrorKind::HostUnreachable => NetworkError::Unreachable(IcmpUnreachable::Host),
+        ErrorKind::NetworkUnreachable => NetworkError::Unreachable(IcmpUnreachable::Network),
+        _ => err.into(),
+    }
+}
+
+/// Builds the probe payload for `validation`, randomized where the protocol
+/// lets us match the reply to the request.
+pub fn build_request(validation: UdpValidation) -> Vec<u8> {
+    match validation {
+        UdpValidation::AnyResponse => vec![0],
+        UdpValidation::Dns => build_dns_query(rand::random(), ".", DNS_TYPE_NS),
+        UdpValidation::Ntp => {
+            let mut packet = vec![0u8; 48];
+            // LI = 0, VN = 4, Mode = 3 (client)
+            packet[0] = 0x23;
+            // The server echoes the transmit timestamp as its originate timestamp.
+            packet[40..48].copy_from_slice(&rand::random::<u64>().to_be_bytes());
+            packet
+        }
+        UdpValidation::Quic => {
+            let dcid: [u8; 8] = rand::random();
+            let scid: [u8; 8] = rand::random();
+            let mut packet = Vec::with_capacity(QUIC_MIN_INITIAL_LEN);
+            // Long header, fixed bit set, Initial packet type.
+            packet.push(0xc0);
+            packet.extend_from_slice(&QUIC_GREASE_VERSION.to_be_bytes());
+            packet.push(dcid.len() as u8);
+            packet.extend_from_slice(&dcid);
+            packet.push(scid.len() as u8);
+            packet.extend_from_slice(&scid);
+            packet.resize(QUIC_MIN_INITIAL_LEN, 0);
+            packet
+        }
+    }
+}
+
+/// Checks that `reply` is a valid answer to `request`.
+pub fn validate_reply(validation: UdpValidation, request: &[u8], reply: &[u8]) -> bool {
+    match validation {
+        UdpValidation::AnyResponse => !reply.is_empty(),
+        UdpValidation::Dns => validate_dns_response(request, reply),
+        UdpValidation::Ntp => {
+            reply.len() >= 48
+                // Mode = 4 (server)
+                && reply[0] & 0x07 == 4
+                && request.get(40..48).is_some_and(|sent| reply[24..32] == *sent)
+        }
+        UdpValidation::Quic => {
+            // Version Negotiation: long header, version 0, connection IDs swapped.
+            let Some((dcid, scid)) = quic_connection_ids(request) else {
+                return false;
+            };
+
+            reply.len() >= 7
+                && reply[0] & 0x80 != 0
+                && reply[1..5] == [0, 0, 0, 0]
+                && reply.get(5) == Some(&(scid.len() as u8))
+                && reply.get(6..6 + scid.len()) == Some(scid)
+                && reply.get(6 + scid.len()) == Some(&(dcid.len() as u8))
+                && reply.get(7 + scid.len()..7 + scid.len() + dcid.len()) == Some(dcid)
+        }
+    }
+}
+
+/// Extracts the destination and source connection IDs of a long-header packet.
+fn quic_connection_ids(packet: &[u8]) -> Option<(&[u8], &[u8])> {
+    let dcid_len = usize::from(*packet.get(5)?);
+    let dcid = packet.get(6..6 + dcid_len)?;
+    let scid_len = usize::from(*packet.get(6 + dcid_len)?);
+    let scid = packet.get(7 + dcid_len..7 + dcid_len + scid_len)?;
+    Some((dcid, scid))
+}
+
+pub(crate) const DNS_TYPE_A: u16 = 1;
+pub(crate) const DNS_TYPE_NS: u16 = 2;
+const DNS_RCODE_NOERROR: u8 = 0;
+const DNS_RCODE_NXDOMAIN: u8 = 3;
+
+/// The response code (low 4 bits of the flags) of a DNS message.
+fn dns_rcode(message: &[u8]) -> u8 {
+    message.get(3).map_or(0, |flags| flags & 0x0f)
+}
+
+/// Serializes a recursive DNS query for `name` (class IN).
+pub(crate) fn build_dns_query(id: u16, name: &str, qtype: u16) -> Vec<u8> {
+    let labels = name.split('.').filter(|l| !l.is_empty());
+    build_dns_query_labels(id, labels.map(str::as_bytes), qtype)
+}
+
+/// [build_dns_query] for the name made of `labels`, which may contain dots.
+pub(crate) fn build_dns_query_labels<'a>(
+    id: u16,
+    labels: impl IntoIterator<Item = &'a [u8]>,
+    qtype: u16,
+) -> Vec<u8> {
+    let mut packet = Vec::with_capacity(64);
+    packet.extend_from_slice(&id.to_be_bytes());
+    // Flags: standard query, recursion desired.
+    packet.extend_from_slice(&0x0100u16.to_be_bytes());
+    // QDCOUNT = 1, ANCOUNT = NSCOUNT = ARCOUNT = 0
+    packet.extend_from_slice(&[0, 1, 0, 0, 0, 0, 0, 0]);
+    for label in labels {
+        packet.push(label.len() as u8);
+        packet.extend_from_slice(label);
+    }
+    packet.push(0);
+    packet.extend_from_slice(&qtype.to_be_bytes());
+    packet.extend_from_slice(&1u16.to_be_bytes());
+    packet
+}
+
+/// Checks that `reply` is a DNS response to `query`: same ID, QR bit set and
+/// the question echoed back.
+pub(crate) fn validate_dns_response(query: &[u8], reply: &[u8]) -> bool {
+    const HEADER_LEN: usize = 12;
+    if reply.len() < HEADER_LEN || query.len() < HEADER_LEN {
+        return false;
+    }
+    let is_response = reply[2] & 0x80 != 0;
+    let same_id = reply[0..2] == query[0..2];
+    let question = &query[HEADER_LEN..];
+    let echoes_question = reply[4..6] == [0, 1] && reply[HEADER_LEN..].starts_with(question);
+    is_response && same_id && echoes_question
+}
+
+/// Reads the possibly compressed domain name at `offset` of a DNS message.
+///
+/// Returns the dotted name (without trailing dot) and the offset just past
+/// the name's encoding at `offset`. Fails on truncated input, compression
+/// loops, reserved label types and names longer than RFC 1035 allows.
+pub(crate) fn read_dns_name(message: &[u8], offset: usize) -> Option<(String, usize)> {
+    let (labels, end) = read_dns_labels(message, offset)?;
+    let labels: Vec<_> = labels.iter().map(|l| String::from_utf8_lossy(l)).collect();
+    Some((labels.join("."), end))
+}
+
+/// [read_dns_name], returning the labels as sent. Unlike the dotted name,
+/// they tell a dot inside a label (`Dr. Who._ipp._tcp.local`) apart from one
+/// between labels.
+pub(crate) fn read_dns_labels(message: &[u8], offset: usize) -> Option<(Vec<&[u8]>, usize)> {
+    const MAX_POINTERS: usize = 16;
+    const MAX_NAME_LEN: usize = 255;
+    let mut labels = Vec::new();
+    let mut reader = Reader::at(message, offset);
+    let mut end = None;
+    let mut pointers = 0;
+    let mut name_len = 1;
+    loop {
+        match usize::from(reader.u8()?) {
+            0 => {
+                end.get_or_insert(reader.position());
+                break;
+            }
+            l if l & 0xc0 == 0xc0 => {
+                pointers += 1;
+                if pointers > MAX_POINTERS {
+                    return None;
+                }
+                let target = (l & 0x3f) << 8 | usize::from(reader.u8()?);
+                end.get_or_insert(reader.position());
+                reader = Reader::at(message, target);
+            }
+            l if l & 0xc0 != 0 => return None,
+            l => {
+                name_len += 1 + l;
+                if name_len > MAX_NAME_LEN {
+                    return None;
+                }
+                labels.push(reader.bytes(l)?);
+            }
+        }
+    }
+    Some((labels, end?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use tokio::net::UdpSocket;
+
+    #[test]
+    fn test_reply_validation() {
+        // DNS: flip the QR bit of our own query to fake a response.
+        let query = build_request(UdpValidation::Dns);
+        let mut reply = query.clone();
+        reply[2] |= 0x80;
+        assert!(validate_reply(UdpValidation::Dns, &query, &reply));
+        reply[0] ^= 0xff;
+        assert!(!validate_reply(UdpValidation::Dns, &query, &reply));
+
+        // NTP: server mode with our transmit timestamp as originate timestamp.
+        let request = build_request(UdpValidation::Ntp);
+        let mut reply = vec![0u8; 48];
+        reply[0] = 0x24;
+        reply[24..32].copy_from_slice(&request[40..48]);
+        assert!(validate_reply(UdpValidation::Ntp, &request, &reply));
+        assert!(!validate_reply(UdpValidation::Ntp, &request, &reply[..40]));
+
+        // QUIC: Version Negotiation with swapped connection IDs.
+        let request = build_request(UdpValidation::Quic);
+        assert_eq!(request.len(), QUIC_MIN_INITIAL_LEN);
+        let (dcid, scid) = (&request[6..14], &request[15..23]);
+        let mut reply = vec![0x80, 0, 0, 0, 0, 8];
+        reply.extend_from_slice(scid);
+        reply.push(8);
+        reply.extend_from_slice(dcid);
+        reply.extend_from_slice(&1u32.to_be_bytes());
+        assert!(validate_reply(UdpValidation::Quic, &request, &reply));
+        reply[1] = 1;
+        assert!(!validate_reply(UdpValidation::Quic, &request, &reply));
+    }
+
+    #[tokio::test]
+    async fn test_probe_udp_against_local_echo() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 64];
+            let (len, peer) = server.recv_from(&mut buf).await.unwrap();
+            server.send_to(&buf[..len], peer).await.unwrap();
+        });
+
+        probe_udp(addr, UdpValidation::AnyResponse).await.unwrap();
+    }
+
+    #[tokio::test]
+    async fn test_probe_dns_checks_rcode() {
+        let server = UdpSocket::bind("127.0.0.1:0").await.unwrap();
+        let addr = server.local_addr().unwrap();
+        tokio::spawn(async move {
+            let mut buf = [0u8; 512];
+            for rcode in [DNS_RCODE_NXDOMAIN, 5] {
+                let (len, peer) = server.recv_from(&mut buf).await.unwrap();
+                let mut reply = buf[..len].to_vec();
+                reply[2] |= 0x80;
+                reply[3] = rcode;
+                server.send_to(&reply, peer).await.unwrap();
+            }
+        });
+
+        probe_dns(addr, "example.com").await.unwrap();
+        let refused = probe_dns(addr, "example.com").await.unwrap_err();
+        assert!(refused.to_string().contains("RCODE 5"));
+    }
+
+    #[tokio::test]
+    async fn test_closed_port_reports_port_unreachable() {
+        use crate::api::probes::transport::{with_transport, MockTransport};
+        use std::io::{Error, ErrorKind};
+
+        // The mock refuses every datagram, as the OS does for a closed port.
+        let closed: SocketAddr = "192.0.2.9:9".parse().unwrap();
+        let result = with_transport(
+            std::sync::Arc::new(MockTransport::new()),
+            probe_udp(closed, UdpValidation::AnyResponse),
+        )
+        .await;
+        assert!(matches!(
+            result,
+            Err(NetworkError::Unreachable(IcmpUnreachable::Port))
+        ));
+
+        // Windows reports the same ICMP error as a reset.
+        assert!(matches!(
+            socket_error(Error::from(ErrorKind::ConnectionReset)),
+            NetworkError::Unreachable(IcmpUnreachable::Port)
+        ));
+        assert!(matches!(
+            socket_error(Error::from(ErrorKind::HostUnreachable)),
+            NetworkError::Unreachable(IcmpUnreachable::Host)
+        ));
+    }
+
+    #[test]
+    fn test_read_dns_name_follows_compression() {
+        let mut message = build_dns_query(7, "printer.local", 12);
+        let answer_at = message.len();
+        // "office" followed by a pointer to "local" in the question.
+        message.extend_from_slice(&[6, b'o', b'f', b'f', b'i', b'c', b'e', 0xc0, 20]);
+        assert_eq!(
+            read_dns_name(&message, 12),
+            Some(("printer.local".to_string(), answer_at - 4))
+        );
+        assert_eq!(
+            read_dns_name(&message, answer_at),
+            Some(("office.local".to_string(), answer_at + 9))
+        );
+
+        // A pointer to itself must not loop forever.
+        assert_eq!(read_dns_name(&[0xc0, 0], 0), None);
+    }
+}
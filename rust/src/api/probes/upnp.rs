@@ -0,0 +1,129 @@
+//! UPnP device discovery (SSDP) and device description parsing.
+
+use super::parse::read_text;
+use crate::api::constants::LibConstants;
+use std::{net::IpAddr, time::Duration};
+use tokio::{
+    net::UdpSocket,
+    time::{timeout, Instant},
+};
+
+const SSDP_ADDR: &str = "239.255.255.250:1900";
+
+/// The identifying fields of a UPnP root device description.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub(crate) struct UpnpDescription {
+    pub friendly_name: Option<String>,
+    pub manufacturer: Option<String>,
+    pub model_name: Option<String>,
+    pub model_number: Option<String>,
+}
+
+/// Discovers `device` via SSDP and fetches its device description.
+///
+/// Only a description served by `device` itself is fetched, whatever the
+/// `LOCATION` header claims.
+pub(crate) async fn describe_device(device: IpAddr, wait: Duration) -> Option<UpnpDescription> {
+    let deadline = Instant::now() + wait;
+    let location = discover_location(device, deadline).await?;
+    let url = reqwest::Url::parse(&location).ok()?;
+    if url.host_str()?.trim_matches(['[', ']']) != device.to_string() {
+        return None;
+    }
+
+    let client = reqwest::Client::builder()
+        .timeout(deadline.saturating_duration_since(Instant::now()))
+        .build()
+        .ok()?;
+    let response = client.get(url).send().await.ok()?;
+    let xml = read_text(response, LibConstants::MAX_RESPONSE_BODY_BYTES)
+        .await
+        .ok()?;
+    Some(parse_description(&xml))
+}
+
+/// Sends an SSDP `M-SEARCH` and returns the `LOCATION` of `device`'s answer.
+async fn discover_location(device: IpAddr, deadline: Instant) -> Option<String> {
+    let socket = UdpSocket::bind("0.0.0.0:0").await.ok()?;
+    let search = "M-SEARCH * HTTP/1.1\r\n\
+                  HOST: 239.255.255.250:1900\r\n\
+                  MAN: \"ssdp:discover\"\r\n\
+                  MX: 1\r\n\
+                  ST: upnp:rootdevice\r\n\r\n";
+    socket.send_to(search.as_bytes(), SSDP_ADDR).await.ok()?;
+
+    let mut buf = [0u8; 2048];
+    loop {
+        let remaining = deadline.saturating_duration_since(Instant::now());
+        let (len, from) = timeout(remaining, socket.recv_from(&mut buf))
+            .await
+            .ok()?
+            .ok()?;
+        if from.ip() != device {
+            continue;
+        }
+        if let Some(location) = header(&String::from_utf8_lossy(&buf[..len]), "location") {
+            return Some(location);
+        }
+    }
+}
+
+/// The value of the first header called `name` (case-insensitive).
+pub(crate) fn header(response: &str, name: &str) -> Option<String> {
+    response.lines().skip(1).find_map(|line| {
+        let (key, value) = line.split_once(':')?;
+        key.trim()
+            .eq_ignore_ascii_case(name)
+            .then(|| value.trim().to_string())
+    })
+}
+
+pub(crate) fn parse_description(xml: &str) -> UpnpDescription {
+    UpnpDescription {
+        friendly_name: element_text(xml, "friendlyName"),
+        manufacturer: element_text(xml, "manufacturer"),
+        model_name: element_text(xml, "modelName"),
+        model_number: element_text(xml, "modelNumber"),
+    }
+}
+
+/// The trimmed text of the first `<tag>` element, if non-empty.
+///
+/// The root device's fields come before any embedded device's, so the first
+/// match describes the device itself.
+pub(crate) fn element_text(xml: &str, tag: &str) -> Option<String> {
+    let open = format!("<{}>", tag);
+    let close = format!("</{}>", tag);
+    let start = xml.find(&open)? + open.len();
+    let end = start + xml[start..].find(&close)?;
+    let text = xml[start..end].trim();
+    (!text.is_empty()).then(|| text.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_upnp_description_parsing() {
+        let reply = "HTTP/1.1 200 OK\r\nCACHE-CONTROL: max-age=1800\r\nLocation: http://192.168.178.1:49000/igddesc.xml\r\n\r\n";
+        assert_eq!(
+            header(reply, "LOCATION").as_deref(),
+            Some("http://192.168.178.1:49000/igddesc.xml")
+        );
+
+        let xml = "<root><device><friendlyName>FRITZ!Box 7590</friendlyName>\
+                   <manufacturer>AVM Berlin</manufacturer><modelName> FRITZ!Box 7590 </modelName>\
+                   <modelNumber></modelNumber><deviceList><device>\
+                   <manufacturer>Other</manufacturer></device></deviceList></device></root>";
+        assert_eq!(
+            parse_description(xml),
+            UpnpDescription {
+                friendly_name: Some("FRITZ!Box 7590".into()),
+                manufacturer: Some("AVM Berlin".into()),
+                model_name: Some("FRITZ!Box 7590".into()),
+                model_number: None,
+            }
+        );
+    }
+}
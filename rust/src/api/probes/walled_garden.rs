@@ -0,0 +1,165 @@
+//! Probing what a captive portal's walled garden still lets through.
+//!
+//! Before login, portals typically allow a few things (DNS, often HTTPS to
+//! app stores or identity providers) and block or intercept the rest. The
+//! probe tries a small matrix of destinations so apps can tell which of their
+//! features still work, e.g. "DNS works, port 443 is blocked".
+//!
+//! Portals commonly intercept rather than block: a TCP handshake then
+//! completes with the portal itself. The HTTPS check is therefore a full
+//! request with certificate validation, which interception cannot pass, and
+//! the ports are tested against the address a trusted resolver returns, as a
+//! portal's resolver may hand out the portal's own address instead.
+
+use super::{
+    hairpin::connect,
+    resolver::{lookup_ips, system_resolver, trusted_resolver_for},
+    udp::probe_dns,
+};
+use crate::api::{
+    constants::LibConstants,
+    models::{EndpointConfig, PortReachability, ServiceReachability, WalledGardenReport},
+};
+use futures::future::join_all;
+use std::{
+    net::{IpAddr, SocketAddr},
+    time::Duration,
+};
+use tokio::time::timeout;
+use trust_dns_resolver::TokioAsyncResolver;
+
+/// Probes the walled garden, waiting at most `timeout_ms` per destination
+/// (0 uses [DEFAULT_WALLED_GARDEN_TIMEOUT_MS](LibConstants::DEFAULT_WALLED_GARDEN_TIMEOUT_MS)).
+///
+/// All destinations are tried concurrently.
+pub async fn probe_walled_garden(timeout_ms: u64) -> WalledGardenReport {
+    probe_walled_garden_with(&EndpointConfig::default(), timeout_ms).await
+}
+
+/// [probe_walled_garden] against the walled garden destinations of
+/// `endpoints`.
+pub async fn probe_walled_garden_with(
+    endpoints: &EndpointConfig,
+    timeout_ms: u64,
+) -> WalledGardenReport {
+    let wait = Duration::from_millis(if timeout_ms == 0 {
+        LibConstants::DEFAULT_WALLED_GARDEN_TIMEOUT_MS
+    } else {
+        timeout_ms
+    });
+
+    let host = &endpoints.walled_garden_port_host;
+    let (system_ip, trusted_ip, public_dns, https) = tokio::join!(
+        timeout(wait, resolve(system_resolver().ok(), host)),
+        timeout(
+            wait,
+            resolve(
+                trusted_resolver_for(&endpoints.trusted_dns_servers).ok(),
+                host
+            )
+        ),
+        public_dns(&endpoints.public_dns_server, wait),
+        https(&endpoints.walled_garden_url, wait),
+    );
+    let ports = match trusted_ip.ok().flatten() {
+        Some(ip) => port_matrix(ip, LibConstants::WALLED_GARDEN_PORTS, wait).await,
+        None => LibConstants::WALLED_GARDEN_PORTS
+            .iter()
+            .map(|&port| PortReachability {
+                port,
+                reachability: ServiceReachability::NotTested,
+            })
+            .collect(),
+    };
+
+    WalledGardenReport {
+        system_dns: system_ip.ok().flatten().is_some(),
+        public_dns,
+        https,
+        ports,
+    }
+}
+
+async fn resolve(resolver: Option<TokioAsyncResolver>, host: &str) -> Option<IpAddr> {
+    lookup_ips(&resolver?, host).await.ok()?.first().copied()
+}
+
+/// Queries a public resolver directly, bypassing the one handed out by DHCP.
+async fn public_dns(server: &str, wait: Duration) -> ServiceReachability {
+    let Ok(server) = server.parse::<SocketAddr>() else {
+        return ServiceReachability::NotTested;
+    };
+    match timeout(
+        wait,
+        probe_dns(server, LibConstants::DEFAULT_DNS_QUERY_NAME),
+    )
+    .await
+    {
+        Ok(Ok(())) => ServiceReachability::Reachable,
+        Ok(Err(_)) => ServiceReachability::Refused,
+        Err(_) => ServiceReachability::TimedOut,
+    }
+}
+
+/// Whether a certificate-validated HTTPS request completes.
+async fn https(url: &str, wait: Duration) -> ServiceReachability {
+    let Ok(client) = reqwest::Client::builder()
+        .timeout(wait)
+        .redirect(reqwest::redirect::Policy::none())
+        .build()
+    else {
+        return ServiceReachability::NotTested;
+    };
+    match client.head(url).send().await {
+        Ok(_) => ServiceReachability::Reachable,
+        Err(e) if e.is_timeout() => ServiceReachability::TimedOut,
+        Err(_) => ServiceReachability::Refused,
+    }
+}
+
+/// TCP connects to `ip` on every port, concurrently.
+async fn port_matrix(ip: IpAddr, ports: &[u16], wait: Duration) -> Vec<PortReachability> {
+    join_all(ports.iter().map(|&port| async move {
+        PortReachability {
+            port,
+            reachability: connect(SocketAddr::new(ip, port), wait).await,
+        }
+    }))
+    .await
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::net::Ipv4Addr;
+
+    #[tokio::test]
+    async fn test_port_matrix_against_local_listener() {
+        let listener = tokio::net::TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let open = listener.local_addr().unwrap().port();
+        let closed = {
+            let probe = std::net::TcpListener::bind("127.0.0.1:0").unwrap();
+            probe.local_addr().unwrap().port()
+        };
+
+        let ports = port_matrix(
+            IpAddr::V4(Ipv4Addr::LOCALHOST),
+            &[open, closed],
+            Duration::from_secs(2),
+        )
+        .await;
+        assert_eq!(
+            ports,
+            vec![
+                PortReachability {
+                    port: open,
+                    reachability: ServiceReachability::Reachable,
+                },
+                PortReachability {
+                    port: closed,
+                    reachability: ServiceReachability::Refused,
+                },
+            ]
+        );
+    }
+}
@@ -0,0 +1,337 @@
+//! Local servers to point targets and probes at in tests.
+//!
+//! Compiled for the crate's own tests and, with the `testsupport` feature,
+//! for the integration tests of applications. Every server listens on an
+//! ephemeral port of `127.0.0.1`, can delay or drop exchanges, and stops when
+//! dropped:
+//!
+//! ```ignore
+//! let server = TestServer::http()
+//!     .redirect("/", "/login")
+//!     .respond("/login", 200, "<form><input type=password></form>")
+//!     .latency_ms(50)
+//!     .start()
+//!     .await?;
+//! let report = check_target(&server.target("portal")).await;
+//! ```
+
+use crate::api::models::{NetworkTarget, TargetProtocol};
+use rand::Rng;
+use std::{io, net::SocketAddr, sync::Arc, time::Duration};
+use tokio::{
+    io::{AsyncReadExt, AsyncWriteExt},
+    net::{TcpListener, TcpStream, UdpSocket},
+    task::JoinHandle,
+};
+
+/// What a [TestServer] speaks.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum ServerKind {
+    TcpEcho,
+    UdpEcho,
+    Http,
+}
+
+/// A canned HTTP response.
+#[derive(Debug, Clone)]
+struct HttpReply {
+    status: u16,
+    location: Option<String>,
+    body: String,
+}
+
+/// Settings of a [TestServer] before it starts.
+#[derive(Debug, Clone)]
+pub struct TestServerBuilder {
+    kind: ServerKind,
+    latency: Duration,
+    loss_percent: u8,
+    routes: Vec<(String, HttpReply)>,
+}
+
+impl TestServerBuilder {
+    fn new(kind: ServerKind) -> Self {
+        Self {
+            kind,
+            latency: Duration::ZERO,
+            loss_percent: 0,
+            routes: Vec::new(),
+        }
+    }
+
+    /// Delays every reply by `latency_ms`.
+    pub fn latency_ms(mut self, latency_ms: u64) -> Self {
+        self.latency = Duration::from_millis(latency_ms);
+        self
+    }
+
+    /// Drops this share of exchanges (0-100): UDP datagrams go unanswered,
+    /// TCP connections are closed without a reply.
+    pub fn loss_percent(mut self, percent: u8) -> Self {
+        self.loss_percent = percent.min(100);
+        self
+    }
+
+    /// Answers `path` with a `302 Found` to `location` (HTTP only).
+    pub fn redirect(mut self, path: impl Into<String>, location: impl Into<String>) -> Self {
+        self.routes.push((
+            path.into(),
+            HttpReply {
+                status: 302,
+                location: Some(location.into()),
+                body: String::new(),
+            },
+        ));
+        self
+    }
+
+    /// Answers `path` with `status` and `body` (HTTP only). Unrouted paths
+    /// get an empty `200 OK`.
+    pub fn respond(
+        mut self,
+        path: impl Into<String>,
+        status: u16,
+        body: impl Into<String>,
+    ) -> Self {
+        self.routes.push((
+            path.into(),
+            HttpReply {
+                status,
+                location: None,
+                body: body.into(),
+            },
+        ));
+        self
+    }
+
+    /// Binds an ephemeral port and starts serving on the current runtime.
+    pub async fn start(self) -> io::Result<TestServer> {
+        let settings = Arc::new(self);
+        let (addr, task) = match settings.kind {
+            ServerKind::UdpEcho => {
+                let socket = UdpSocket::bind("127.0.0.1:0").await?;
+                let addr = socket.local_addr()?;
+                (addr, tokio::spawn(serve_udp(socket, settings.clone())))
+            }
+            ServerKind::TcpEcho | ServerKind::Http => {
+                let listener = TcpListener::bind("127.0.0.1:0").await?;
+                let addr = listener.local_addr()?;
+                (addr, tokio::spawn(serve_tcp(listener, settings.clone())))
+            }
+        };
+        Ok(TestServer {
+            addr,
+            kind: settings.kind,
+            task,
+        })
+    }
+
+    /// Rolls the dice for one exchange.
+    fn drops(&self) -> bool {
+        self.loss_percent > 0 && rand::rng().random_range(0..100) < self.loss_percent
+    }
+
+    fn route(&self, path: &str) -> HttpReply {
+        self.routes
+            .iter()
+            .find(|(route, _)| route == path)
+            .map(|(_, reply)| reply.clone())
+            .unwrap_or(HttpReply {
+                status: 200,
+                location: None,
+                body: String::new(),
+            })
+    }
+}
+
+/// A running local server. Stops when dropped.
+#[derive(Debug)]
+pub struct TestServer {
+    addr: SocketAddr,
+    kind: ServerKind,
+    task: JoinHandle<()>,
+}
+
+impl TestServer {
+    /// A TCP server that echoes everything it receives.
+    pub fn tcp_echo() -> TestServerBuilder {
+        TestServerBuilder::new(ServerKind::TcpEcho)
+    }
+
+    /// A UDP server that echoes every datagram.
+    pub fn udp_echo() -> TestServerBuilder {
+        TestServerBuilder::new(ServerKind::UdpEcho)
+    }
+
+    /// An HTTP/1.1 server answering with the configured routes.
+    pub fn http() -> TestServerBuilder {
+        TestServerBuilder::new(ServerKind::Http)
+    }
+
+    /// The address the server listens on.
+    pub fn addr(&self) -> SocketAddr {
+        self.addr
+    }
+
+    /// `http://127.0.0.1:<port><path>`, for HTTP servers.
+    pub fn url(&self, path: &str) -> String {
+        format!("http://{}{}", self.addr, path)
+    }
+
+    /// A target pointing at the server, probed with the server's protocol.
+    pub fn target(&self, label: &str) -> NetworkTarget {
+        let protocol = match self.kind {
+            ServerKind::TcpEcho => TargetProtocol::Tcp,
+            ServerKind::UdpEcho => TargetProtocol::Udp,
+            ServerKind::Http => TargetProtocol::Http,
+        };
+        NetworkTarget::builder(self.addr.ip().to_string(), protocol)
+            .label(label)
+            .port(self.addr.port())
+            .build()
+    }
+}
+
+impl Drop for TestServer {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn serve_udp(socket: UdpSocket, settings: Arc<TestServerBuilder>) {
+    let socket = Arc::new(socket);
+    let mut buf = [0u8; 2048];
+    while let Ok((len, from)) = socket.recv_from(&mut buf).await {
+        if settings.drops() {
+            continue;
+        }
+        let (socket, datagram, latency) = (socket.clone(), buf[..len].to_vec(), settings.latency);
+        tokio::spawn(async move {
+            tokio::time::sleep(latency).await;
+            let _ = socket.send_to(&datagram, from).await;
+        });
+    }
+}
+
+async fn serve_tcp(listener: TcpListener, settings: Arc<TestServerBuilder>) {
+    while let Ok((stream, _)) = listener.accept().await {
+        if settings.drops() {
+            continue;
+        }
+        let settings = settings.clone();
+        tokio::spawn(async move {
+            let _ = match settings.kind {
+                ServerKind::Http => serve_http(stream, &settings).await,
+                _ => serve_echo(stream, settings.latency).await,
+            };
+        });
+    }
+}
+
+async fn serve_echo(mut stream: TcpStream, latency: Duration) -> io::Result<()> {
+    let mut buf = [0u8; 2048];
+    loop {
+        let len = stream.read(&mut buf).await?;
+        if len == 0 {
+            return Ok(());
+        }
+        tokio::time::sleep(latency).await;
+        stream.write_all(&buf[..len]).await?;
+    }
+}
+
+/// Answers a single request, then closes the connection.
+async fn serve_http(mut stream: TcpStream, settings: &TestServerBuilder) -> io::Result<()> {
+    let mut request = Vec::new();
+    let mut buf = [0u8; 1024];
+    while !request.windows(4).any(|w| w == b"\r\n\r\n") && request.len() < 16 * 1024 {
+        let len = stream.read(&mut buf).await?;
+        if len == 0 {
+            break;
+        }
+        request.extend_from_slice(&buf[..len]);
+    }
+    let request = String::from_utf8_lossy(&request);
+    let mut request_line = request.lines().next().unwrap_or_default().split(' ');
+    let method = request_line.next().unwrap_or_default();
+    let path = request_line.next().unwrap_or("/");
+
+    let reply = settings.route(path);
+    let mut response = format!(
+        "HTTP/1.1 {} {}\r\nContent-Length: {}\r\nConnection: close\r\n",
+        reply.status,
+        reason_phrase(reply.status),
+        reply.body.len()
+    );
+    if let Some(location) = &reply.location {
+        response.push_str(&format!("Location: {}\r\n", location));
+    }
+    response.push_str("\r\n");
+    if method != "HEAD" {
+        response.push_str(&reply.body);
+    }
+
+    tokio::time::sleep(settings.latency).await;
+    stream.write_all(response.as_bytes()).await?;
+    stream.shutdown().await
+}
+
+fn reason_phrase(status: u16) -> &'static str {
+    match status {
+        200 => "OK",
+        204 => "No Content",
+        301 => "Moved Permanently",
+        302 => "Found",
+        403 => "Forbidden",
+        404 => "Not Found",
+        500 => "Internal Server Error",
+        511 => "Network Authentication Required",
+        _ => "Unknown",
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::api::probes::check_target;
+
+    #[tokio::test]
+    async fn test_servers_answer_targets_and_impairments() {
+        for server in [
+            TestServer::tcp_echo(),
+            TestServer::udp_echo(),
+            TestServer::http(),
+        ] {
+            let server = server.start().await.unwrap();
+            let report = check_target(&server.target("local")).await;
+            assert!(report.success, "{:?}: {:?}", server.kind, report.error);
+        }
+
+        let slow = TestServer::udp_echo()
+            .latency_ms(150)
+            .start()
+            .await
+            .unwrap();
+        let report = check_target(&slow.target("slow")).await;
+        assert!(report.success && report.latency_ms >= 150);
+
+        let lossy = TestServer::udp_echo()
+            .loss_percent(100)
+            .start()
+            .await
+            .unwrap();
+        let mut target = lossy.target("lossy");
+        target.timeout_ms = 200;
+        assert!(!check_target(&target).await.success);
+
+        let redirecting = TestServer::http()
+            .redirect("/", "/elsewhere")
+            .respond("/elsewhere", 204, "")
+            .start()
+            .await
+            .unwrap();
+        let response = reqwest::get(redirecting.url("/")).await.unwrap();
+        assert_eq!(response.status().as_u16(), 204);
+        assert_eq!(response.url().path(), "/elsewhere");
+    }
+}